@@ -21,6 +21,7 @@ CREATE TABLE dispute_queue (
     `profile_id` Nullable(String),
     `merchant_connector_id` Nullable(String),
     `organization_id` String,
+    `evidence_submitted_at` Nullable(DateTime) CODEC(T64, LZ4),
     `sign_flag` Int8
 ) ENGINE = Kafka SETTINGS kafka_broker_list = 'kafka0:29092',
 kafka_topic_list = 'hyperswitch-dispute-events',
@@ -52,6 +53,7 @@ CREATE TABLE dispute (
     `merchant_connector_id` Nullable(String),
     `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `organization_id` String,
+    `evidence_submitted_at` Nullable(DateTime) CODEC(T64, LZ4),
     `sign_flag` Int8,
     INDEX connectorIndex connector TYPE bloom_filter GRANULARITY 1,
     INDEX disputeStatusIndex dispute_status TYPE bloom_filter GRANULARITY 1,
@@ -83,6 +85,7 @@ CREATE MATERIALIZED VIEW dispute_mv TO dispute (
     `profile_id` Nullable(String),
     `merchant_connector_id` Nullable(String),
     `organization_id` String,
+    `evidence_submitted_at` Nullable(DateTime64(3)),
     `inserted_at` DateTime64(3),
     `sign_flag` Int8
 ) AS
@@ -109,6 +112,7 @@ SELECT
     profile_id,
     merchant_connector_id,
     organization_id,
+    evidence_submitted_at,
     now() AS inserted_at,
     sign_flag
 FROM
@@ -195,7 +195,10 @@ impl super::outgoing_webhook_event::events::OutgoingWebhookLogsFilterAnalytics
 {
 }
 impl super::disputes::filters::DisputeFilterAnalytics for ClickhouseClient {}
-impl super::disputes::metrics::DisputeMetricAnalytics for ClickhouseClient {}
+impl super::disputes::metrics::DisputeMetricAnalytics for ClickhouseClient {
+    const EVIDENCE_SUBMISSION_LATENCY: &'static str =
+        "dateDiff('second', created_at, evidence_submitted_at)";
+}
 
 #[derive(Debug, serde::Serialize)]
 struct CkhQuery {
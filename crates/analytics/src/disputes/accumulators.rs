@@ -7,6 +7,9 @@ pub struct DisputeMetricsAccumulator {
     pub disputes_status_rate: RateAccumulator,
     pub disputed_amount: DisputedAmountAccumulator,
     pub dispute_lost_amount: DisputedAmountAccumulator,
+    pub dispute_win_rate: WinRateAccumulator,
+    pub dispute_count: CountAccumulator,
+    pub evidence_submission_latency: AverageAccumulator,
 }
 #[derive(Debug, Default)]
 pub struct RateAccumulator {
@@ -20,6 +23,21 @@ pub struct RateAccumulator {
 pub struct DisputedAmountAccumulator {
     pub total: Option<i64>,
 }
+#[derive(Debug, Default)]
+pub struct WinRateAccumulator {
+    pub won_count: i64,
+    pub lost_count: i64,
+}
+#[derive(Debug, Default)]
+#[repr(transparent)]
+pub struct CountAccumulator {
+    pub count: Option<i64>,
+}
+#[derive(Debug, Default)]
+pub struct AverageAccumulator {
+    pub total: f64,
+    pub count: i64,
+}
 
 pub trait DisputeMetricAccumulator {
     type MetricOutput;
@@ -84,6 +102,71 @@ impl DisputeMetricAccumulator for RateAccumulator {
     }
 }
 
+impl DisputeMetricAccumulator for WinRateAccumulator {
+    type MetricOutput = Option<f64>;
+
+    fn add_metrics_bucket(&mut self, metrics: &DisputeMetricRow) {
+        match metrics.dispute_status.as_ref().map(AsRef::as_ref) {
+            Some(storage_enums::DisputeStatus::DisputeWon) => {
+                self.won_count += metrics.count.unwrap_or_default()
+            }
+            Some(storage_enums::DisputeStatus::DisputeLost) => {
+                self.lost_count += metrics.count.unwrap_or_default()
+            }
+            _ => {}
+        }
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        let resolved_count = self.won_count + self.lost_count;
+        if resolved_count <= 0 {
+            return None;
+        }
+        Some(
+            f64::from(u32::try_from(self.won_count).ok()?) * 100.0
+                / f64::from(u32::try_from(resolved_count).ok()?),
+        )
+    }
+}
+
+impl DisputeMetricAccumulator for CountAccumulator {
+    type MetricOutput = Option<u64>;
+
+    fn add_metrics_bucket(&mut self, metrics: &DisputeMetricRow) {
+        self.count = match (self.count, metrics.count) {
+            (None, None) => None,
+            (None, i @ Some(_)) | (i @ Some(_), None) => i,
+            (Some(a), Some(b)) => Some(a + b),
+        }
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        self.count.and_then(|i| u64::try_from(i).ok())
+    }
+}
+
+impl DisputeMetricAccumulator for AverageAccumulator {
+    type MetricOutput = Option<f64>;
+
+    fn add_metrics_bucket(&mut self, metrics: &DisputeMetricRow) {
+        if let Some(total) = metrics
+            .total
+            .as_ref()
+            .and_then(bigdecimal::ToPrimitive::to_f64)
+        {
+            self.total += total;
+        }
+        self.count += metrics.count.unwrap_or_default();
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        if self.count <= 0 {
+            return None;
+        }
+        Some(self.total / f64::from(u32::try_from(self.count).ok()?))
+    }
+}
+
 impl DisputeMetricsAccumulator {
     pub fn collect(self) -> DisputeMetricsBucketValue {
         let (challenge_rate, won_rate, lost_rate, total_dispute) =
@@ -95,6 +178,10 @@ impl DisputeMetricsAccumulator {
             disputed_amount: self.disputed_amount.collect(),
             dispute_lost_amount: self.dispute_lost_amount.collect(),
             total_dispute,
+            dispute_win_rate: self.dispute_win_rate.collect(),
+            // Depends on the disputes of the other buckets, and is computed once all are collected
+            dispute_rate: None,
+            avg_evidence_submission_latency: self.evidence_submission_latency.collect(),
         }
     }
 }
@@ -13,6 +13,7 @@ use router_env::{
     logger,
     tracing::{self, Instrument},
 };
+use time::PrimitiveDateTime;
 
 use super::{
     filters::{get_dispute_filter_for_dimension, DisputeFilterRow},
@@ -98,6 +99,15 @@ pub async fn get_metrics(
                 | DisputeMetrics::SessionizedTotalDisputeLostAmount => metrics_builder
                     .dispute_lost_amount
                     .add_metrics_bucket(&value),
+                DisputeMetrics::DisputeWinRate => {
+                    metrics_builder.dispute_win_rate.add_metrics_bucket(&value)
+                }
+                DisputeMetrics::DisputeRate => {
+                    metrics_builder.dispute_count.add_metrics_bucket(&value)
+                }
+                DisputeMetrics::EvidenceSubmissionLatency => metrics_builder
+                    .evidence_submission_latency
+                    .add_metrics_bucket(&value),
             }
         }
 
@@ -109,9 +119,11 @@ pub async fn get_metrics(
     }
     let mut total_disputed_amount = 0;
     let mut total_dispute_lost_amount = 0;
-    let query_data: Vec<DisputeMetricsBucketResponse> = metrics_accumulator
+    let mut dispute_count_by_time_bucket: HashMap<PrimitiveDateTime, u64> = HashMap::new();
+    let collected_data: Vec<_> = metrics_accumulator
         .into_iter()
-        .map(|(id, val)| {
+        .map(|(id, mut val)| {
+            let dispute_count = std::mem::take(&mut val.dispute_count).collect();
             let collected_values = val.collect();
             if let Some(amount) = collected_values.disputed_amount {
                 total_disputed_amount += amount;
@@ -119,6 +131,27 @@ pub async fn get_metrics(
             if let Some(amount) = collected_values.dispute_lost_amount {
                 total_dispute_lost_amount += amount;
             }
+            if let Some(count) = dispute_count {
+                *dispute_count_by_time_bucket
+                    .entry(id.start_time)
+                    .or_default() += count;
+            }
+            (id, collected_values, dispute_count)
+        })
+        .collect();
+    let query_data: Vec<DisputeMetricsBucketResponse> = collected_data
+        .into_iter()
+        .map(|(id, mut collected_values, dispute_count)| {
+            collected_values.dispute_rate = match (
+                dispute_count,
+                dispute_count_by_time_bucket.get(&id.start_time),
+            ) {
+                (Some(count), Some(total_count)) if *total_count > 0 => u32::try_from(count)
+                    .ok()
+                    .zip(u32::try_from(*total_count).ok())
+                    .map(|(count, total_count)| f64::from(count) * 100.0 / f64::from(total_count)),
+                _ => None,
+            };
 
             DisputeMetricsBucketResponse {
                 values: collected_values,
@@ -205,6 +238,7 @@ pub async fn get_filters(
             DisputeDimensions::DisputeStage => fil.dispute_stage,
             DisputeDimensions::Connector => fil.connector,
             DisputeDimensions::Currency => fil.currency.map(|i| i.as_ref().to_string()),
+            DisputeDimensions::ConnectorReasonCode => fil.connector_reason_code,
         })
         .collect::<Vec<String>>();
         res.query_data.push(DisputeFilterValue {
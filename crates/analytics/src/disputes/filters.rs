@@ -53,4 +53,5 @@ pub struct DisputeFilterRow {
     pub connector_status: Option<String>,
     pub dispute_stage: Option<String>,
     pub currency: Option<DBEnumWrapper<Currency>>,
+    pub connector_reason_code: Option<String>,
 }
@@ -1,4 +1,5 @@
 mod dispute_status_metric;
+mod evidence_submission_latency;
 mod sessionized_metrics;
 mod total_amount_disputed;
 mod total_dispute_lost_amount;
@@ -14,8 +15,9 @@ use diesel_models::enums as storage_enums;
 use time::PrimitiveDateTime;
 
 use self::{
-    dispute_status_metric::DisputeStatusMetric, total_amount_disputed::TotalAmountDisputed,
-    total_dispute_lost_amount::TotalDisputeLostAmount,
+    dispute_status_metric::DisputeStatusMetric,
+    evidence_submission_latency::EvidenceSubmissionLatency,
+    total_amount_disputed::TotalAmountDisputed, total_dispute_lost_amount::TotalDisputeLostAmount,
 };
 use crate::{
     enums::AuthInfo,
@@ -28,6 +30,7 @@ pub struct DisputeMetricRow {
     pub dispute_status: Option<DBEnumWrapper<storage_enums::DisputeStatus>>,
     pub connector: Option<String>,
     pub currency: Option<DBEnumWrapper<storage_enums::Currency>>,
+    pub connector_reason_code: Option<String>,
     pub total: Option<bigdecimal::BigDecimal>,
     pub count: Option<i64>,
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
@@ -36,7 +39,11 @@ pub struct DisputeMetricRow {
     pub end_bucket: Option<PrimitiveDateTime>,
 }
 
-pub trait DisputeMetricAnalytics: LoadRow<DisputeMetricRow> {}
+pub trait DisputeMetricAnalytics: LoadRow<DisputeMetricRow> {
+    /// Expression of the time (in seconds) taken to submit the evidence of a dispute after it
+    /// was received, which differs across the data sources
+    const EVIDENCE_SUBMISSION_LATENCY: &'static str;
+}
 
 #[async_trait::async_trait]
 pub trait DisputeMetric<T>
@@ -84,7 +91,8 @@ where
                     .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
                     .await
             }
-            Self::DisputeStatusMetric => {
+            // The win rate and the dispute rate are derived from the count of disputes by status
+            Self::DisputeStatusMetric | Self::DisputeWinRate | Self::DisputeRate => {
                 DisputeStatusMetric::default()
                     .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
                     .await
@@ -94,6 +102,11 @@ where
                     .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
                     .await
             }
+            Self::EvidenceSubmissionLatency => {
+                EvidenceSubmissionLatency::default()
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
             Self::SessionizedTotalAmountDisputed => {
                 sessionized_metrics::TotalAmountDisputed::default()
                     .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
@@ -30,6 +30,15 @@ where
                 .attach_printable("Error adding currency filter")?;
         }
 
+        if !self.connector_reason_code.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    DisputeDimensions::ConnectorReasonCode,
+                    &self.connector_reason_code,
+                )
+                .attach_printable("Error adding connector reason code filter")?;
+        }
+
         Ok(())
     }
 }
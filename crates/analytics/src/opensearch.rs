@@ -477,7 +477,12 @@ pub struct OpenSearchQueryBuilder {
     pub offset: Option<i64>,
     pub count: Option<i64>,
     pub filters: Vec<(String, Vec<Value>)>,
+    pub range_filters: Vec<(String, Value)>,
+    pub phrase_filters: Vec<(String, String)>,
     pub time_range: Option<OpensearchTimeRange>,
+    pub search_after: Option<Vec<Value>>,
+    pub sort_tiebreaker: Option<String>,
+    pub source_fields: Option<Vec<String>>,
     search_params: Vec<AuthInfo>,
     case_sensitive_fields: HashSet<&'static str>,
 }
@@ -491,7 +496,12 @@ impl OpenSearchQueryBuilder {
             offset: Default::default(),
             count: Default::default(),
             filters: Default::default(),
+            range_filters: Default::default(),
+            phrase_filters: Default::default(),
             time_range: Default::default(),
+            search_after: Default::default(),
+            sort_tiebreaker: Default::default(),
+            source_fields: Default::default(),
             case_sensitive_fields: HashSet::from([
                 "customer_email.keyword",
                 "search_tags.keyword",
@@ -519,6 +529,38 @@ impl OpenSearchQueryBuilder {
         Ok(())
     }
 
+    /// Adds a range filter, the range being an object of `gte` and `lte` bounds
+    pub fn add_range_clause(&mut self, field: String, range: Value) -> QueryResult<()> {
+        self.range_filters.push((field, range));
+        Ok(())
+    }
+
+    /// Adds a filter matching the words of the phrase in the same order in the field
+    pub fn add_phrase_clause(&mut self, field: String, phrase: String) -> QueryResult<()> {
+        self.phrase_filters.push((field, phrase));
+        Ok(())
+    }
+
+    /// Sorts the hits with the same timestamp on a field unique to each hit, which makes the sort
+    /// order stable across pages
+    pub fn set_sort_tiebreaker(&mut self, sort_tiebreaker: String) -> QueryResult<()> {
+        self.sort_tiebreaker = Some(sort_tiebreaker);
+        Ok(())
+    }
+
+    /// Fetches the hits after the hit with the given sort values, instead of the hits at an
+    /// offset. The sort values are only stable across pages with a sort tiebreaker.
+    pub fn set_search_after(&mut self, search_after: Vec<Value>) -> QueryResult<()> {
+        self.search_after = Some(search_after);
+        Ok(())
+    }
+
+    /// Restricts the fields of the hits to the given fields
+    pub fn set_source_fields(&mut self, source_fields: Vec<String>) -> QueryResult<()> {
+        self.source_fields = Some(source_fields);
+        Ok(())
+    }
+
     pub fn get_status_field(&self, index: SearchIndex) -> &str {
         match index {
             SearchIndex::Refunds | SearchIndex::SessionizerRefunds => "refund_status.keyword",
@@ -565,6 +607,18 @@ impl OpenSearchQueryBuilder {
 
         filter_array.extend(case_sensitive_json_filters);
 
+        filter_array.extend(
+            self.range_filters
+                .iter()
+                .map(|(field, range)| json!({"range": {field: range}})),
+        );
+
+        filter_array.extend(
+            self.phrase_filters
+                .iter()
+                .map(|(field, phrase)| json!({"match_phrase": {field: phrase}})),
+        );
+
         if let Some(ref time_range) = self.time_range {
             let range = json!(time_range);
             filter_array.push(json!({
@@ -746,16 +800,30 @@ impl OpenSearchQueryBuilder {
                 "order": "desc"
             }),
         );
+        let mut sort_array = vec![Value::Object(sort_obj)];
+        if let Some(ref sort_tiebreaker) = self.sort_tiebreaker {
+            sort_array.push(json!({
+                sort_tiebreaker: {
+                    "order": "desc"
+                }
+            }));
+        }
 
         Ok(indexes
             .iter()
             .map(|index| {
                 let mut payload = json!({
                     "query": query_obj.clone(),
-                    "sort": [
-                        Value::Object(sort_obj.clone())
-                    ]
+                    "sort": sort_array.clone()
                 });
+                if let Some(payload_map) = payload.as_object_mut() {
+                    if let Some(ref search_after) = self.search_after {
+                        payload_map.insert("search_after".to_string(), json!(search_after));
+                    }
+                    if let Some(ref source_fields) = self.source_fields {
+                        payload_map.insert("_source".to_string(), json!(source_fields));
+                    }
+                }
                 let filter_array = self.build_filter_array(case_sensitive_filters.clone(), *index);
                 if !filter_array.is_empty() {
                     payload
@@ -146,6 +146,7 @@ impl PaymentIntentMetricAccumulator for PaymentsSuccessRateAccumulator {
             if status.as_ref() != &storage_enums::IntentStatus::RequiresCustomerAction
                 && status.as_ref() != &storage_enums::IntentStatus::RequiresPaymentMethod
                 && status.as_ref() != &storage_enums::IntentStatus::RequiresMerchantAction
+                && status.as_ref() != &storage_enums::IntentStatus::RequiresMerchantReview
                 && status.as_ref() != &storage_enums::IntentStatus::RequiresConfirmation
             {
                 if let Some(total) = metrics.count.and_then(|total| u32::try_from(total).ok()) {
@@ -276,6 +277,7 @@ impl PaymentIntentMetricAccumulator for PaymentsDistributionAccumulator {
             if status.as_ref() != &storage_enums::IntentStatus::RequiresCustomerAction
                 && status.as_ref() != &storage_enums::IntentStatus::RequiresPaymentMethod
                 && status.as_ref() != &storage_enums::IntentStatus::RequiresMerchantAction
+                && status.as_ref() != &storage_enums::IntentStatus::RequiresMerchantReview
                 && status.as_ref() != &storage_enums::IntentStatus::RequiresConfirmation
             {
                 if let Some(total) = metrics.count.and_then(|total| u32::try_from(total).ok()) {
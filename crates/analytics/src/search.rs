@@ -1,6 +1,6 @@
 use api_models::analytics::search::{
     GetGlobalSearchRequest, GetSearchRequestWithIndex, GetSearchResponse, OpenMsearchOutput,
-    OpensearchOutput, SearchIndex, SearchStatus,
+    OpensearchOutput, PaymentSearchRequest, SearchIndex, SearchStatus,
 };
 use common_utils::errors::{CustomResult, ReportSwitchExt};
 use error_stack::ResultExt;
@@ -426,3 +426,168 @@ pub async fn search_results(
         }
     }
 }
+
+/// The payments index joins the details of the attempts of a payment, like the card used and the
+/// connector transaction id, onto the payment
+const PAYMENT_SEARCH_INDEX: SearchIndex = SearchIndex::SessionizerPaymentIntents;
+
+/// Payment ids are unique within a merchant, which keeps the order of the payments created at
+/// the same time stable across pages
+const PAYMENT_SEARCH_SORT_TIEBREAKER: &str = "payment_id.keyword";
+
+#[derive(Debug)]
+pub struct PaymentSearchResults {
+    pub count: u64,
+    pub hits: Vec<Value>,
+    /// The sort values of the last hit, set when more hits may follow
+    pub next_search_after: Option<Vec<Value>>,
+}
+
+/// Searches the payments matching all the filters of the request, latest payment first. A page
+/// starts after the hit with the `search_after` sort values, or at the top if not set.
+pub async fn payment_search_results(
+    client: &OpenSearchClient,
+    req: PaymentSearchRequest,
+    count: i64,
+    search_after: Option<Vec<Value>>,
+    search_params: Vec<AuthInfo>,
+) -> CustomResult<PaymentSearchResults, OpenSearchError> {
+    if req.is_all_none() {
+        return Err(OpenSearchError::BadRequestError(
+            "Both query and filters are empty".to_string(),
+        )
+        .into());
+    }
+
+    let mut query_builder = OpenSearchQueryBuilder::new(
+        OpenSearchQuery::Search(PAYMENT_SEARCH_INDEX),
+        req.query.unwrap_or_default().trim().to_string(),
+        search_params,
+    );
+
+    if let Some(customer_email) = req.customer_email {
+        if !customer_email.is_empty() {
+            query_builder
+                .add_filter_clause(
+                    "customer_email.keyword".to_string(),
+                    convert_to_value(
+                        customer_email
+                            .iter()
+                            .filter_map(|email| {
+                                serde_json::to_value(email)
+                                    .ok()
+                                    .and_then(|a| a.as_str().map(|a| a.to_string()))
+                            })
+                            .collect(),
+                    ),
+                )
+                .switch()?;
+        }
+    };
+    if let Some(card_last_4) = req.card_last_4 {
+        if !card_last_4.is_empty() {
+            query_builder
+                .add_filter_clause(
+                    "card_last_4.keyword".to_string(),
+                    convert_to_value(card_last_4),
+                )
+                .switch()?;
+        }
+    };
+    if let Some(connector_transaction_id) = req.connector_transaction_id {
+        if !connector_transaction_id.is_empty() {
+            query_builder
+                .add_filter_clause(
+                    "connector_transaction_id.keyword".to_string(),
+                    convert_to_value(connector_transaction_id),
+                )
+                .switch()?;
+        }
+    };
+    if let Some(amount) = req.amount {
+        query_builder
+            .add_range_clause(
+                "amount".to_string(),
+                serde_json::json!({
+                    "gte": amount.start_amount,
+                    "lte": amount.end_amount,
+                }),
+            )
+            .switch()?;
+    };
+    // The metadata of a payment is indexed as text, hence a key value pair is matched as the key
+    // directly followed by the value
+    for metadata_filter in req.metadata.unwrap_or_default() {
+        query_builder
+            .add_phrase_clause(
+                "metadata".to_string(),
+                format!("{} {}", metadata_filter.key, metadata_filter.value),
+            )
+            .switch()?;
+    }
+
+    if let Some(fields) = req.fields {
+        if let Some(field) = fields.iter().find(|field| !is_valid_field_name(field)) {
+            return Err(OpenSearchError::BadRequestError(format!("Invalid field: {field}")).into());
+        }
+        query_builder.set_source_fields(fields).switch()?;
+    }
+
+    if let Some(time_range) = req.time_range {
+        query_builder.set_time_range(time_range.into()).switch()?;
+    };
+
+    query_builder
+        .set_sort_tiebreaker(PAYMENT_SEARCH_SORT_TIEBREAKER.to_string())
+        .switch()?;
+    if let Some(search_after) = search_after {
+        query_builder.set_search_after(search_after).switch()?;
+    }
+    query_builder.set_offset_n_count(0, count).switch()?;
+
+    let response_body: OpensearchOutput = client
+        .execute(query_builder)
+        .await
+        .change_context(OpenSearchError::ConnectionError)?
+        .text()
+        .await
+        .change_context(OpenSearchError::ResponseError)
+        .and_then(|body: String| {
+            serde_json::from_str::<OpensearchOutput>(&body)
+                .change_context(OpenSearchError::DeserialisationError)
+                .attach_printable(body.clone())
+        })?;
+
+    match response_body {
+        OpensearchOutput::Success(success) => {
+            let is_last_page = success.hits.hits.len() < usize::try_from(count).unwrap_or(0);
+            let next_search_after = if is_last_page {
+                None
+            } else {
+                success.hits.hits.last().and_then(|hit| hit.sort.clone())
+            };
+            Ok(PaymentSearchResults {
+                count: success.hits.total.value,
+                hits: success
+                    .hits
+                    .hits
+                    .into_iter()
+                    .map(|hit| hit.source)
+                    .collect(),
+                next_search_after,
+            })
+        }
+        OpensearchOutput::Error(error) => {
+            tracing::error!(error_response = ?error, "Payment search error");
+            Err(OpenSearchError::ResponseNotOK(error.error.reason).into())
+        }
+    }
+}
+
+/// Field names are passed on to the search as is, hence they are restricted to plain names
+fn is_valid_field_name(field: &str) -> bool {
+    !field.is_empty()
+        && field
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
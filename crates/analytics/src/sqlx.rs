@@ -164,7 +164,10 @@ impl super::refunds::metrics::RefundMetricAnalytics for SqlxClient {}
 impl super::refunds::filters::RefundFilterAnalytics for SqlxClient {}
 impl super::refunds::distribution::RefundDistributionAnalytics for SqlxClient {}
 impl super::disputes::filters::DisputeFilterAnalytics for SqlxClient {}
-impl super::disputes::metrics::DisputeMetricAnalytics for SqlxClient {}
+impl super::disputes::metrics::DisputeMetricAnalytics for SqlxClient {
+    const EVIDENCE_SUBMISSION_LATENCY: &'static str =
+        "EXTRACT(EPOCH FROM (evidence_submitted_at - created_at))";
+}
 impl super::frm::metrics::FrmMetricAnalytics for SqlxClient {}
 impl super::frm::filters::FrmFilterAnalytics for SqlxClient {}
 impl super::auth_events::metrics::AuthEventMetricAnalytics for SqlxClient {}
@@ -1376,12 +1379,18 @@ impl<'a> FromRow<'a, PgRow> for super::disputes::filters::DisputeFilterRow {
                 ColumnNotFound(_) => Ok(Default::default()),
                 e => Err(e),
             })?;
+        let connector_reason_code: Option<String> =
+            row.try_get("connector_reason_code").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
         Ok(Self {
             dispute_stage,
             dispute_status,
             connector,
             connector_status,
             currency,
+            connector_reason_code,
         })
     }
 }
@@ -1406,6 +1415,11 @@ impl<'a> FromRow<'a, PgRow> for super::disputes::metrics::DisputeMetricRow {
                 ColumnNotFound(_) => Ok(Default::default()),
                 e => Err(e),
             })?;
+        let connector_reason_code: Option<String> =
+            row.try_get("connector_reason_code").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
         let total: Option<bigdecimal::BigDecimal> = row.try_get("total").or_else(|e| match e {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
@@ -1426,6 +1440,7 @@ impl<'a> FromRow<'a, PgRow> for super::disputes::metrics::DisputeMetricRow {
             dispute_status,
             connector,
             currency,
+            connector_reason_code,
             total,
             count,
             start_bucket,
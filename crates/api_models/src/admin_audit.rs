@@ -0,0 +1,57 @@
+use utoipa::ToSchema;
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+/// The constraints to apply when listing the audit events of a merchant or an organization
+pub struct AdminAuditEventListQuery {
+    /// Filter the events of the specified business profile
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    /// Filter the events performed by the specified user or API key
+    pub actor_id: Option<String>,
+    /// Filter the events of the specified API flow, for example `RoutingUpdateConfig`
+    pub api_flow: Option<String>,
+    /// Filter the events authenticated with the specified type, for example `api_key`
+    pub auth_type: Option<String>,
+    /// Filter the events created at or after the specified time
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub start_time: Option<time::PrimitiveDateTime>,
+    /// Filter the events created at or before the specified time
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub end_time: Option<time::PrimitiveDateTime>,
+    /// Include at most the specified number of events
+    pub limit: Option<u16>,
+    /// A cursor for pagination, the `id` of the last event of the previous page
+    pub starting_after: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+/// An immutable record of a mutating admin API call
+pub struct AdminAuditEventRecord {
+    pub id: String,
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    /// The dashboard user or API key that made the call
+    pub actor_id: Option<String>,
+    /// The type of authentication used for the call
+    pub auth_type: String,
+    /// The IP address the call was made from
+    pub ip_address: Option<String>,
+    pub api_flow: String,
+    pub http_method: String,
+    pub url_path: String,
+    pub request_id: Option<String>,
+    pub status_code: i64,
+    /// The masked request body of the call
+    #[schema(value_type = Option<Object>)]
+    pub request_body: Option<serde_json::Value>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct AdminAuditEventListResponse {
+    pub count: usize,
+    pub data: Vec<AdminAuditEventRecord>,
+    /// The cursor to fetch the next page with, absent when there are no more events
+    pub next_starting_after: Option<String>,
+}
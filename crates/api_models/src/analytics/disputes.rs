@@ -3,7 +3,9 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-use super::{ForexMetric, NameDescription, TimeRange};
+use super::{
+    ForexMetric, GetDisputeMetricRequest, Granularity, NameDescription, TimeRange, TimeSeries,
+};
 use crate::enums::{Currency, DisputeStage};
 
 #[derive(
@@ -16,6 +18,7 @@ use crate::enums::{Currency, DisputeStage};
     serde::Deserialize,
     strum::Display,
     strum::EnumIter,
+    strum::EnumString,
     strum::AsRefStr,
 )]
 #[strum(serialize_all = "snake_case")]
@@ -24,6 +27,9 @@ pub enum DisputeMetrics {
     DisputeStatusMetric,
     TotalAmountDisputed,
     TotalDisputeLostAmount,
+    DisputeWinRate,
+    DisputeRate,
+    EvidenceSubmissionLatency,
     SessionizedDisputeStatusMetric,
     SessionizedTotalAmountDisputed,
     SessionizedTotalDisputeLostAmount,
@@ -48,6 +54,7 @@ impl ForexMetric for DisputeMetrics {
     Ord,
     strum::Display,
     strum::EnumIter,
+    strum::EnumString,
     Clone,
     Copy,
 )]
@@ -59,6 +66,7 @@ pub enum DisputeDimensions {
     Connector,
     DisputeStage,
     Currency,
+    ConnectorReasonCode,
 }
 
 impl From<DisputeDimensions> for NameDescription {
@@ -87,6 +95,72 @@ pub struct DisputeFilters {
     pub connector: Vec<String>,
     #[serde(default)]
     pub currency: Vec<Currency>,
+    #[serde(default)]
+    pub connector_reason_code: Vec<String>,
+}
+
+/// Query parameters of the dispute metrics endpoint, where the lists are comma separated values
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct DisputeMetricsQuery {
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub start_time: time::PrimitiveDateTime,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub end_time: Option<time::PrimitiveDateTime>,
+    pub granularity: Option<Granularity>,
+    #[serde(deserialize_with = "parse_comma_separated")]
+    pub metrics: Vec<DisputeMetrics>,
+    #[serde(default, deserialize_with = "parse_comma_separated")]
+    pub group_by: Vec<DisputeDimensions>,
+    #[serde(default, deserialize_with = "parse_comma_separated")]
+    pub connector: Vec<String>,
+    #[serde(default, deserialize_with = "parse_comma_separated")]
+    pub dispute_stage: Vec<DisputeStage>,
+    #[serde(default, deserialize_with = "parse_comma_separated")]
+    pub currency: Vec<Currency>,
+    #[serde(default, deserialize_with = "parse_comma_separated")]
+    pub connector_reason_code: Vec<String>,
+}
+
+fn parse_comma_separated<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: std::str::FromStr,
+    <T as std::str::FromStr>::Err: std::fmt::Display,
+{
+    let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(|item| {
+            item.parse::<T>().map_err(|error| {
+                <D::Error as serde::de::Error>::custom(format!("Invalid value '{item}': {error}"))
+            })
+        })
+        .collect()
+}
+
+impl From<DisputeMetricsQuery> for GetDisputeMetricRequest {
+    fn from(query: DisputeMetricsQuery) -> Self {
+        Self {
+            time_series: query
+                .granularity
+                .map(|granularity| TimeSeries { granularity }),
+            time_range: TimeRange {
+                start_time: query.start_time,
+                end_time: query.end_time,
+            },
+            group_by_names: query.group_by,
+            filters: DisputeFilters {
+                dispute_stage: query.dispute_stage,
+                connector: query.connector,
+                currency: query.currency,
+                connector_reason_code: query.connector_reason_code,
+            },
+            metrics: query.metrics.into_iter().collect(),
+            delta: false,
+        }
+    }
 }
 
 #[derive(Debug, serde::Serialize, Eq)]
@@ -94,6 +168,7 @@ pub struct DisputeMetricsBucketIdentifier {
     pub dispute_stage: Option<DisputeStage>,
     pub connector: Option<String>,
     pub currency: Option<Currency>,
+    pub connector_reason_code: Option<String>,
     #[serde(rename = "time_range")]
     pub time_bucket: TimeRange,
     #[serde(rename = "time_bucket")]
@@ -106,6 +181,7 @@ impl Hash for DisputeMetricsBucketIdentifier {
         self.dispute_stage.hash(state);
         self.connector.hash(state);
         self.currency.hash(state);
+        self.connector_reason_code.hash(state);
         self.time_bucket.hash(state);
     }
 }
@@ -124,12 +200,14 @@ impl DisputeMetricsBucketIdentifier {
         dispute_stage: Option<DisputeStage>,
         connector: Option<String>,
         currency: Option<Currency>,
+        connector_reason_code: Option<String>,
         normalized_time_range: TimeRange,
     ) -> Self {
         Self {
             dispute_stage,
             connector,
             currency,
+            connector_reason_code,
             time_bucket: normalized_time_range,
             start_time: normalized_time_range.start_time,
         }
@@ -144,6 +222,12 @@ pub struct DisputeMetricsBucketValue {
     pub disputed_amount: Option<u64>,
     pub dispute_lost_amount: Option<u64>,
     pub total_dispute: Option<u64>,
+    /// Percentage of the disputes won out of the disputes which were won or lost
+    pub dispute_win_rate: Option<f64>,
+    /// Percentage of the disputes of the time bucket which belong to the bucket
+    pub dispute_rate: Option<f64>,
+    /// Average time (in seconds) taken to submit the evidence of a dispute after it was received
+    pub avg_evidence_submission_latency: Option<f64>,
 }
 #[derive(Debug, serde::Serialize)]
 pub struct DisputeMetricsBucketResponse {
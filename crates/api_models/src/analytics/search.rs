@@ -63,6 +63,59 @@ pub struct GetSearchRequestWithIndex {
     pub search_req: GetSearchRequest,
 }
 
+/// A search of the payments of a merchant. The filters are combined, a payment has to match all
+/// of them to be returned.
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentSearchRequest {
+    /// Free text matched as a phrase against all the fields of the payment
+    #[serde(default)]
+    pub query: Option<String>,
+    pub customer_email: Option<Vec<HashedString<common_utils::pii::EmailStrategy>>>,
+    pub card_last_4: Option<Vec<String>>,
+    pub connector_transaction_id: Option<Vec<String>>,
+    pub amount: Option<crate::payments::AmountFilter>,
+    /// Key value pairs which have to be present in the metadata of the payment
+    pub metadata: Option<Vec<PaymentSearchMetadataFilter>>,
+    #[serde(default)]
+    pub time_range: Option<TimeRange>,
+    /// The maximum number of payments to return
+    pub limit: Option<u32>,
+    /// The cursor returned with the previous page of the search
+    pub cursor: Option<String>,
+    /// The fields of the payments to return, all the fields are returned if not set
+    pub fields: Option<Vec<String>>,
+}
+
+impl PaymentSearchRequest {
+    pub fn is_all_none(&self) -> bool {
+        self.query
+            .as_ref()
+            .is_none_or(|query| query.trim().is_empty())
+            && self.customer_email.is_none()
+            && self.card_last_4.is_none()
+            && self.connector_transaction_id.is_none()
+            && self.amount.is_none()
+            && self.metadata.is_none()
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentSearchMetadataFilter {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct PaymentSearchResponse {
+    /// The total number of payments matching the search
+    pub count: u64,
+    pub data: Vec<Value>,
+    /// The cursor to pass to fetch the next page, not set on the last page
+    pub next_cursor: Option<String>,
+}
+
 #[derive(
     Debug, strum::EnumIter, Clone, serde::Deserialize, serde::Serialize, Copy, Eq, PartialEq,
 )]
@@ -140,4 +193,7 @@ pub struct OpensearchResultsTotal {
 pub struct OpensearchHit {
     #[serde(rename = "_source")]
     pub source: Value,
+    /// The sort values of the hit, used to fetch the hits after it
+    #[serde(default)]
+    pub sort: Option<Vec<Value>>,
 }
@@ -23,6 +23,16 @@ pub struct CreateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The resources the API Key is permitted to access. The API Key can access all the resources
+    /// when no scopes are provided.
+    #[schema(example = json!(["payments:read", "refunds:write"]))]
+    pub scopes: Option<Vec<common_enums::ApiKeyScope>>,
+
+    /// The IP address ranges, in CIDR notation, from which the API Key can be used. The API Key can
+    /// be used from any IP address when no ranges are provided.
+    #[schema(example = json!(["203.0.113.0/24", "2001:db8::/32"]))]
+    pub allowed_ip_ranges: Option<Vec<String>>,
 }
 
 /// The response body for creating an API Key.
@@ -60,6 +70,18 @@ pub struct CreateApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The resources the API Key is permitted to access, all the resources being accessible when
+    /// absent.
+    #[schema(example = json!(["payments:read", "refunds:write"]))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<common_enums::ApiKeyScope>>,
+
+    /// The IP address ranges, in CIDR notation, from which the API Key can be used, the API Key
+    /// being usable from any IP address when absent.
+    #[schema(example = json!(["203.0.113.0/24", "2001:db8::/32"]))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_ip_ranges: Option<Vec<String>>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -102,6 +124,18 @@ pub struct RetrieveApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The resources the API Key is permitted to access, all the resources being accessible when
+    /// absent.
+    #[schema(example = json!(["payments:read", "refunds:write"]))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<common_enums::ApiKeyScope>>,
+
+    /// The IP address ranges, in CIDR notation, from which the API Key can be used, the API Key
+    /// being usable from any IP address when absent.
+    #[schema(example = json!(["203.0.113.0/24", "2001:db8::/32"]))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_ip_ranges: Option<Vec<String>>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -130,6 +164,11 @@ pub struct UpdateApiKeyRequest {
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: Option<ApiKeyExpiration>,
 
+    /// The IP address ranges, in CIDR notation, from which the API Key can be used. Providing an
+    /// empty list permits using the API Key from any IP address.
+    #[schema(example = json!(["203.0.113.0/24", "2001:db8::/32"]))]
+    pub allowed_ip_ranges: Option<Vec<String>>,
+
     #[serde(skip_deserializing)]
     #[schema(value_type = String)]
     pub key_id: common_utils::id_type::ApiKeyId,
@@ -139,6 +178,46 @@ pub struct UpdateApiKeyRequest {
     pub merchant_id: common_utils::id_type::MerchantId,
 }
 
+/// The request body for rotating an API Key.
+#[derive(Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RotateApiKeyRequest {
+    /// The number of seconds for which the rotated API Key remains valid alongside the newly
+    /// issued API Key. Defaults to the grace period configured on the server.
+    #[schema(example = 86400)]
+    pub grace_period_in_secs: Option<u32>,
+
+    /// An expiration date for the newly issued API Key. The expiration of the rotated API Key is
+    /// retained when not provided.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    pub expiration: Option<ApiKeyExpiration>,
+
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub key_id: common_utils::id_type::ApiKeyId,
+
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
+
+/// The response body for rotating an API Key.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RotateApiKeyResponse {
+    /// The newly issued API Key.
+    pub api_key: CreateApiKeyResponse,
+
+    /// The identifier for the rotated API Key.
+    #[schema(max_length = 64, example = "5hEEqkgJUyuxgSKGArHA4mWSnX", value_type = String)]
+    pub rotated_key_id: common_utils::id_type::ApiKeyId,
+
+    /// The time at which the rotated API Key expires, after which only the newly issued API Key
+    /// can be used.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub rotated_key_expiration: PrimitiveDateTime,
+}
+
 /// The response body for revoking an API Key.
 #[derive(Debug, Serialize, ToSchema)]
 pub struct RevokeApiKeyResponse {
@@ -165,6 +244,62 @@ pub struct ListApiKeyConstraints {
     pub skip: Option<i64>,
 }
 
+/// The response body for creating a signing key.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CreateSigningKeyResponse {
+    /// The identifier for the signing key, to be sent in the `X-Signature-Key-Id` header of the
+    /// signed requests.
+    #[schema(max_length = 64, example = "sigk_5hEEqkgJUyuxgSKGArHA4mWSnX")]
+    pub key_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The secret used to sign the requests. The secret is only returned once, when the signing
+    /// key is created.
+    #[schema(value_type = String, max_length = 64)]
+    pub signing_secret: StrongSecret<String>,
+
+    /// The time at which the signing key was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+/// The response body for retrieving a signing key.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SigningKeyResponse {
+    /// The identifier for the signing key.
+    #[schema(max_length = 64, example = "sigk_5hEEqkgJUyuxgSKGArHA4mWSnX")]
+    pub key_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The time at which the signing key was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+/// The response body for revoking a signing key.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RevokeSigningKeyResponse {
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The identifier for the signing key.
+    #[schema(max_length = 64, example = "sigk_5hEEqkgJUyuxgSKGArHA4mWSnX")]
+    pub key_id: String,
+
+    /// Indicates whether the signing key was revoked or not.
+    #[schema(example = "true")]
+    pub revoked: bool,
+}
+
 /// The expiration date and time for an API Key.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 #[serde(untagged)]
@@ -0,0 +1,97 @@
+use common_enums::AuthRateAlertStatus;
+use common_utils::pii;
+use utoipa::ToSchema;
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// The authorization rate alerting of a merchant. The authorization rate of each connector and
+/// payment method is evaluated on every evaluation window against its baseline, the rate over
+/// the baseline window before it, and an alert is raised when the rate drops below the baseline
+/// by at least the threshold.
+pub struct AuthRateAlertConfigRequest {
+    /// The drop of the authorization rate below its baseline, in percentage points, which raises
+    /// an alert
+    #[schema(example = 10.0)]
+    pub drop_threshold_percentage: f64,
+    /// The number of days of payments the baseline is computed over
+    #[schema(example = 7)]
+    pub baseline_window_in_days: Option<u16>,
+    /// The number of minutes of payments the current authorization rate is computed over, which
+    /// is also how often the rate is evaluated
+    #[schema(example = 60)]
+    pub evaluation_window_in_minutes: Option<u16>,
+    /// The minimum number of payment attempts in both the evaluation window and the baseline
+    /// window for the authorization rate to be evaluated
+    #[schema(example = 20)]
+    pub min_attempts: Option<u32>,
+    /// The URL the alerts are posted to when raised and when resolved. The alerts are signed
+    /// with the payment response hash key of the default profile, in the
+    /// `X-Webhook-Signature-512` header, as for outgoing webhooks.
+    #[schema(example = "https://example.com/alerts")]
+    pub webhook_url: Option<String>,
+    /// The emails the alerts are sent to when raised and when resolved
+    #[schema(value_type = Option<Vec<String>>, example = json!(["payments-oncall@example.com"]))]
+    pub recipients: Option<Vec<pii::Email>>,
+    /// Whether the authorization rate is evaluated, the alerts are kept when disabled
+    pub is_enabled: Option<bool>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct AuthRateAlertConfigResponse {
+    #[schema(value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub drop_threshold_percentage: f64,
+    pub baseline_window_in_days: u16,
+    pub evaluation_window_in_minutes: u16,
+    pub min_attempts: u32,
+    pub webhook_url: Option<String>,
+    #[schema(value_type = Vec<String>)]
+    pub recipients: Vec<pii::Email>,
+    pub is_enabled: bool,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+/// A drop of the authorization rate of a connector and payment method below its baseline. The
+/// alert is also the payload posted to the webhook URL of the alerting, when the alert is raised
+/// and when it is resolved.
+pub struct AuthRateAlertResponse {
+    pub alert_id: String,
+    #[schema(value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+    #[schema(example = "stripe")]
+    pub connector: String,
+    #[schema(example = "card")]
+    pub payment_method: String,
+    pub status: AuthRateAlertStatus,
+    /// The authorization rate over the baseline window, in percent
+    pub baseline_rate: f64,
+    /// The authorization rate over the last evaluation window, in percent
+    pub current_rate: f64,
+    /// The number of payment attempts in the last evaluation window
+    pub attempt_count: i64,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub triggered_at: time::PrimitiveDateTime,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub resolved_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AuthRateAlertListQuery {
+    /// Only return the alerts of this status
+    pub status: Option<AuthRateAlertStatus>,
+    /// The maximum number of alerts to return
+    pub limit: Option<u32>,
+    /// The number of alerts to skip
+    pub offset: Option<u32>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct AuthRateAlertListResponse {
+    pub count: usize,
+    pub data: Vec<AuthRateAlertResponse>,
+}
@@ -9,6 +9,44 @@ pub enum BlocklistRequest {
     CardBin(String),
     Fingerprint(String),
     ExtendedCardBin(String),
+    /// Email of the customer, matched case insensitively
+    Email(String),
+    /// IP address of the customer, or a network of addresses in CIDR notation
+    IpAddress(String),
+    CustomerId(String),
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct AddToBlocklistRequest {
+    #[serde(flatten)]
+    pub entry: BlocklistRequest,
+    /// Time after which the entry no longer blocks payments. Entries without it never expire.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct BulkAddToBlocklistRequest {
+    /// Entries to add to the blocklist, each one is added independently of the others
+    pub entries: Vec<AddToBlocklistRequest>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct BulkAddToBlocklistResult {
+    /// Position of the entry in the request
+    pub index: usize,
+    /// The added entry, if it was added
+    pub entry: Option<BlocklistResponse>,
+    /// Reason for which the entry was not added
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct BulkAddToBlocklistResponse {
+    pub added_count: usize,
+    pub failed_count: usize,
+    pub results: Vec<BulkAddToBlocklistResult>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -20,7 +58,6 @@ pub struct GenerateFingerprintRequest {
 pub struct Card {
     pub card_number: StrongSecret<String>,
 }
-pub type AddToBlocklistRequest = BlocklistRequest;
 pub type DeleteFromBlocklistRequest = BlocklistRequest;
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -30,6 +67,8 @@ pub struct BlocklistResponse {
     pub data_kind: enums::BlocklistDataKind,
     #[serde(with = "common_utils::custom_serde::iso8601")]
     pub created_at: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
@@ -66,6 +105,9 @@ pub struct ToggleBlocklistQuery {
 }
 
 impl ApiEventMetric for BlocklistRequest {}
+impl ApiEventMetric for AddToBlocklistRequest {}
+impl ApiEventMetric for BulkAddToBlocklistRequest {}
+impl ApiEventMetric for BulkAddToBlocklistResponse {}
 impl ApiEventMetric for BlocklistResponse {}
 impl ApiEventMetric for ToggleBlocklistResponse {}
 impl ApiEventMetric for ListBlocklistQuery {}
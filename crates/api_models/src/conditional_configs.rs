@@ -42,6 +42,55 @@ impl events::ApiEventMetric for DecisionManager {
 
 pub type DecisionManagerResponse = DecisionManagerRecord;
 
+/// Result of linting a decision manager config without persisting it
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DecisionManagerValidationResponse {
+    /// Whether the config can be upserted, which is the case when no errors were found
+    pub is_valid: bool,
+    pub diagnostics: Vec<DecisionManagerDiagnostic>,
+}
+
+impl events::ApiEventMetric for DecisionManagerValidationResponse {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Routing)
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DecisionManagerDiagnostic {
+    /// Index of the rule in the program the diagnostic refers to, absent for program level
+    /// diagnostics
+    pub rule_index: Option<usize>,
+    pub rule_name: Option<String>,
+    pub severity: DiagnosticSeverity,
+    pub code: DiagnosticCode,
+    pub message: String,
+    /// Indices of the earlier rules involved in the diagnostic
+    pub related_rule_indices: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticSeverity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticCode {
+    /// The config does not contain a program
+    MissingProgram,
+    /// A condition uses an unknown key, an invalid value or an unsupported comparison
+    TypeError,
+    /// The conditions of a rule can never be satisfied together
+    ConflictingConditions,
+    /// The rule never applies since earlier rules match all of its conditions
+    UnreachableRule,
+    /// Earlier rules match some of the conditions of the rule
+    OverlappingRule,
+}
+
 #[cfg(feature = "v2")]
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct DecisionManagerRequest {
@@ -0,0 +1,39 @@
+/// The connection pools of the tenant in this instance of the application
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct ConnectionPoolsResponse {
+    pub database: Vec<DatabasePoolResponse>,
+    pub redis: Option<RedisPoolResponse>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct DatabasePoolResponse {
+    /// The name of the pool, such as `master` or `replica`
+    pub pool: String,
+    pub host: String,
+    pub schema: String,
+    /// The maximum number of connections of the pool
+    pub pool_size: u32,
+    /// The number of open connections, idle or in use
+    pub connections: u32,
+    pub idle_connections: u32,
+    pub in_use_connections: u32,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct RedisPoolResponse {
+    /// The number of clients of the pool, each of which multiplexes the commands over a connection
+    pub pool_size: usize,
+    pub connected_clients: usize,
+    /// The number of commands waiting to be sent or answered on the clients of the pool
+    pub queued_commands: usize,
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DatabasePoolResizeRequest {
+    /// The name of the pools to resize, such as `master` or `replica`. The pools of all the hosts
+    /// with this name are resized.
+    pub pool: String,
+    /// The new maximum number of connections of each pool
+    pub pool_size: u32,
+}
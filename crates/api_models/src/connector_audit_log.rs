@@ -0,0 +1,45 @@
+use utoipa::ToSchema;
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+/// The constraints to apply when listing the connector audit logs of a payment
+pub struct ConnectorAuditLogListQuery {
+    /// Filter the logs of the specified payment attempt
+    pub attempt_id: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+/// A request sent to a connector for a payment attempt, along with the response received for it
+pub struct ConnectorAuditLogRecord {
+    pub id: String,
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub refund_id: Option<String>,
+    pub dispute_id: Option<String>,
+    pub connector: String,
+    /// The connector flow for which the request was sent, for example `Authorize`
+    pub flow: String,
+    pub http_method: String,
+    pub url: String,
+    /// The request body sent to the connector, with sensitive data masked
+    #[schema(value_type = Option<Object>)]
+    pub masked_request: Option<serde_json::Value>,
+    /// The response body received from the connector, with sensitive data masked
+    #[schema(value_type = Option<Object>)]
+    pub masked_response: Option<serde_json::Value>,
+    /// The error received from the connector, or encountered while processing its response
+    #[schema(value_type = Option<Object>)]
+    pub error: Option<serde_json::Value>,
+    pub status_code: i32,
+    pub latency_in_ms: i64,
+    pub request_id: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct ConnectorAuditLogListResponse {
+    pub count: usize,
+    pub data: Vec<ConnectorAuditLogRecord>,
+}
@@ -3,3 +3,14 @@ pub const MAX_ORDER_FULFILLMENT_EXPIRY: i64 = 1800;
 
 /// Min payment intent fulfillment expiry
 pub const MIN_ORDER_FULFILLMENT_EXPIRY: i64 = 60;
+
+/// Max number of additional webhook endpoints in a business profile
+pub const MAX_WEBHOOK_ENDPOINTS_PER_PROFILE: usize = 10;
+
+/// Max length of the identifier of a business profile webhook endpoint
+pub const MAX_WEBHOOK_ENDPOINT_ID_LENGTH: usize = 32;
+
+/// Headers set on outgoing webhooks which cannot be overridden by merchant configured custom
+/// headers
+pub const RESERVED_OUTGOING_WEBHOOK_HEADERS: [&str; 3] =
+    ["Content-Type", "X-Webhook-Signature-512", "Stripe-Signature"];
@@ -1,4 +1,7 @@
-use common_utils::{events::ApiEventMetric, types::MinorUnit};
+use common_utils::{events::ApiEventMetric, id_type, types::MinorUnit};
+use time::PrimitiveDateTime;
+
+use crate::enums;
 
 /// QueryParams to be send to convert the amount -> from_currency -> to_currency
 #[derive(Debug, serde::Deserialize)]
@@ -19,3 +22,67 @@ pub struct CurrencyConversionResponse {
 
 impl ApiEventMetric for CurrencyConversionResponse {}
 impl ApiEventMetric for CurrencyConversionParams {}
+
+/// QueryParams to be sent to fetch the current conversion of an amount
+#[derive(Debug, serde::Deserialize)]
+pub struct CurrencyConversionRequest {
+    /// The amount in the minor unit of `from_currency`
+    pub amount: MinorUnit,
+    /// The currency of the amount
+    pub from_currency: enums::Currency,
+    /// The currency to which the amount is converted
+    pub to_currency: enums::Currency,
+}
+
+/// Conversion of an amount at the current rate, which is not guaranteed
+#[derive(Debug, serde::Serialize)]
+pub struct CurrencyConversionDetailsResponse {
+    /// The amount in the minor unit of `from_currency`
+    pub amount: MinorUnit,
+    pub from_currency: enums::Currency,
+    /// The converted amount in the minor unit of `to_currency`
+    pub converted_amount: MinorUnit,
+    pub to_currency: enums::Currency,
+    /// The rate at which a major unit of `from_currency` is converted to `to_currency`
+    pub rate: String,
+}
+
+/// Request to lock the conversion rate of an amount for a payment
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FxQuoteCreateRequest {
+    /// The amount presented to the customer, in the minor unit of `from_currency`
+    pub amount: MinorUnit,
+    /// The currency in which the amount is presented to the customer
+    pub from_currency: enums::Currency,
+    /// The currency in which the amount is settled to the merchant
+    pub to_currency: enums::Currency,
+}
+
+/// A conversion rate guaranteed until the quote expires. A quote can be used by a single payment,
+/// which is created with `fx_quote_id` and the amount and currency of the quote.
+#[derive(Debug, serde::Serialize)]
+pub struct FxQuoteResponse {
+    /// The identifier of the quote
+    pub quote_id: String,
+    /// The amount presented to the customer, in the minor unit of `from_currency`
+    pub amount: MinorUnit,
+    pub from_currency: enums::Currency,
+    /// The amount settled to the merchant, in the minor unit of `to_currency`
+    pub converted_amount: MinorUnit,
+    pub to_currency: enums::Currency,
+    /// The guaranteed rate at which a major unit of `from_currency` is converted to `to_currency`
+    pub rate: String,
+    /// The payment which used the quote
+    pub payment_id: Option<id_type::PaymentId>,
+    /// Time until which the rate is guaranteed
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+impl ApiEventMetric for CurrencyConversionRequest {}
+impl ApiEventMetric for CurrencyConversionDetailsResponse {}
+impl ApiEventMetric for FxQuoteCreateRequest {}
+impl ApiEventMetric for FxQuoteResponse {}
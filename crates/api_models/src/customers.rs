@@ -3,6 +3,8 @@ use masking::Secret;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+#[cfg(feature = "v1")]
+use crate::payment_methods;
 use crate::payments;
 
 /// The customer details
@@ -52,6 +54,9 @@ pub struct CustomerListRequest {
     /// Limit
     #[schema(example = 32)]
     pub limit: Option<u16>,
+    /// An opaque cursor returned in the `X-Next-Cursor` header of a previous list call, to fetch the page after it. Takes precedence over `offset`
+    #[schema(value_type = Option<String>)]
+    pub cursor: Option<common_utils::types::ListCursor>,
 }
 
 #[cfg(feature = "v1")]
@@ -168,6 +173,10 @@ pub struct CustomerResponse {
     /// The customer's tax registration number.
     #[schema(max_length = 255, value_type = Option<String>, example = "123456789")]
     pub tax_registration_id: crypto::OptionalEncryptableSecretString,
+    /// The customer which this customer was merged into. A merged customer is no longer active.
+    #[schema(value_type = Option<String>, max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merged_into_customer_id: Option<id_type::CustomerId>,
 }
 
 #[cfg(feature = "v1")]
@@ -281,6 +290,156 @@ pub struct CustomerDeleteResponse {
     pub payment_methods_deleted: bool,
 }
 
+/// Request to merge a duplicate customer into the customer of the path
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomerMergeRequest {
+    /// The identifier of the duplicate customer, which is deactivated once merged
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub duplicate_customer_id: id_type::CustomerId,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CustomerMergeResponse {
+    /// The identifier of the customer which the duplicate customer was merged into
+    #[schema(value_type = String, max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+    /// The identifier of the merged duplicate customer
+    #[schema(value_type = String, max_length = 64, example = "cus_abcdefgh")]
+    pub duplicate_customer_id: id_type::CustomerId,
+    /// Number of payment methods moved to the customer
+    pub payment_methods_moved: usize,
+    /// Number of mandates moved to the customer
+    pub mandates_moved: usize,
+    /// Number of payments moved to the customer
+    pub payments_moved: usize,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CustomerRedactResponse {
+    /// The identifier for the customer object
+    #[schema(value_type = String, max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+    /// Whether the personal data of the customer was redacted or not
+    #[schema(example = true)]
+    pub customer_redacted: bool,
+    /// Whether the addresses of the customer were redacted or not
+    #[schema(example = true)]
+    pub address_redacted: bool,
+    /// Whether the payment methods of the customer were deleted or not
+    #[schema(example = true)]
+    pub payment_methods_deleted: bool,
+    /// Number of payments of the customer whose personal data was redacted. The financial details
+    /// of these payments are retained against the customer identifier.
+    pub payments_pseudonymized: usize,
+}
+
+/// A machine readable export of all the data stored for a customer
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerDataExportResponse {
+    /// The details of the customer
+    pub customer: CustomerResponse,
+    /// The payment methods saved for the customer
+    pub payment_methods: Vec<CustomerDataExportPaymentMethod>,
+    /// The mandates given by the customer
+    pub mandates: Vec<CustomerDataExportMandate>,
+    /// The payments made by the customer
+    pub payments: Vec<CustomerDataExportPayment>,
+    /// Time at which the export was generated
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub exported_at: time::PrimitiveDateTime,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerDataExportPaymentMethod {
+    /// The identifier for the payment method
+    #[schema(example = "pm_iouuy468iyuowqs")]
+    pub payment_method_id: String,
+    /// The type of payment method
+    #[schema(value_type = Option<PaymentMethod>, example = "card")]
+    pub payment_method: Option<common_enums::PaymentMethod>,
+    /// The sub type of payment method
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<common_enums::PaymentMethodType>,
+    /// The card details, without the card number
+    pub card: Option<payment_methods::CardDetailFromLocker>,
+    /// The status of the payment method
+    #[schema(value_type = PaymentMethodStatus)]
+    pub status: common_enums::PaymentMethodStatus,
+    /// Time at which the payment method was saved
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    /// Time at which the payment method was last used
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub last_used_at: time::PrimitiveDateTime,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerDataExportMandate {
+    /// The identifier for the mandate
+    #[schema(example = "mandate_end38934n12s923d0")]
+    pub mandate_id: String,
+    /// The status of the mandate
+    #[schema(value_type = MandateStatus)]
+    pub status: common_enums::MandateStatus,
+    /// The identifier of the payment method used for the mandate
+    pub payment_method_id: String,
+    /// The IP address from which the customer accepted the mandate
+    #[schema(value_type = Option<String>, example = "127.0.0.1")]
+    pub customer_ip_address: Option<Secret<String, pii::IpAddress>>,
+    /// The user agent with which the customer accepted the mandate
+    pub customer_user_agent: Option<String>,
+    /// Time at which the customer accepted the mandate
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub customer_accepted_at: Option<time::PrimitiveDateTime>,
+    /// Time at which the mandate was created
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerDataExportPayment {
+    /// The identifier for the payment
+    #[schema(value_type = String, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: id_type::PaymentId,
+    /// The status of the payment
+    #[schema(value_type = IntentStatus, example = "succeeded")]
+    pub status: common_enums::IntentStatus,
+    /// The amount of the payment in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: common_utils::types::MinorUnit,
+    /// The currency of the payment
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<common_enums::Currency>,
+    /// The description of the payment
+    pub description: Option<String>,
+    /// The customer details given with the payment
+    #[schema(value_type = Option<Object>)]
+    pub customer_details: Option<pii::SecretSerdeValue>,
+    /// The billing details given with the payment
+    #[schema(value_type = Option<Object>)]
+    pub billing_details: Option<pii::SecretSerdeValue>,
+    /// The shipping details given with the payment
+    #[schema(value_type = Option<Object>)]
+    pub shipping_details: Option<pii::SecretSerdeValue>,
+    /// Time at which the payment was created
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
 /// The identifier for the customer object. If not provided the customer ID will be autogenerated.
 #[cfg(feature = "v1")]
 #[derive(Debug, Default, Clone, Deserialize, Serialize, ToSchema)]
@@ -386,3 +545,10 @@ pub struct CustomerUpdateRequestInternal {
     pub id: id_type::GlobalCustomerId,
     pub request: CustomerUpdateRequest,
 }
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Serialize)]
+pub struct CustomerMergeRequestInternal {
+    pub customer_id: id_type::CustomerId,
+    pub request: CustomerMergeRequest,
+}
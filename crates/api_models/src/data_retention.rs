@@ -0,0 +1,78 @@
+use utoipa::ToSchema;
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// The retention policy of a merchant. A retention which is not set is not enforced, and the
+/// data is kept indefinitely.
+pub struct DataRetentionPolicyRequest {
+    /// The number of days for which the raw requests and responses exchanged with the connectors
+    /// are kept
+    #[schema(example = 90)]
+    pub connector_payload_retention_days: Option<u32>,
+    /// The number of days after which the personal data of a customer is redacted, counted from
+    /// the last update of the customer and from the last payment made by them
+    #[schema(example = 1825)]
+    pub inactive_customer_retention_days: Option<u32>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct DataRetentionPolicyResponse {
+    #[schema(value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub connector_payload_retention_days: Option<u32>,
+    pub inactive_customer_retention_days: Option<u32>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DataRetentionPurgeRequest {
+    /// Only report the data which would be purged under the current policy, without purging it
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+/// The outcome of a purge of the data which is past the retention policy of the merchant
+pub struct DataRetentionPurgeRunResponse {
+    pub id: String,
+    #[schema(value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub dry_run: bool,
+    /// Who triggered the purge, `scheduler` for the scheduled purges
+    pub triggered_by: String,
+    /// The connector payloads logged before this time were purged
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub connector_payloads_created_before: Option<time::PrimitiveDateTime>,
+    /// The number of connector payloads purged, or which would be purged in a dry run
+    pub connector_payloads_purged: i64,
+    /// The customers without any activity since this time were redacted
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub customers_inactive_since: Option<time::PrimitiveDateTime>,
+    /// The customers redacted, or which would be redacted in a dry run
+    #[schema(value_type = Vec<String>)]
+    pub redacted_customer_ids: Vec<common_utils::id_type::CustomerId>,
+    /// The customers which could not be redacted, these are retried in the next purge
+    #[schema(value_type = Vec<String>)]
+    pub failed_customer_ids: Vec<common_utils::id_type::CustomerId>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DataRetentionPurgeRunListQuery {
+    /// The maximum number of purge runs to return
+    pub limit: Option<u32>,
+    /// The number of purge runs to skip
+    pub offset: Option<u32>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct DataRetentionPurgeRunListResponse {
+    pub count: usize,
+    pub data: Vec<DataRetentionPurgeRunResponse>,
+}
@@ -0,0 +1,110 @@
+use common_utils::{events, types::MinorUnit};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    disputes::SubmitEvidenceRequest,
+    enums::{Currency, DisputeDecisionAction},
+};
+
+/// A rule of the dispute decision config. A dispute matches the rule when all of its conditions
+/// which are set match the dispute.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeDecisionRule {
+    /// Name of the rule, recorded on the disputes it is applied to
+    pub name: String,
+    /// Action taken on the disputes which match the rule
+    pub action: DisputeDecisionAction,
+    /// Disputes with an amount (in minor units) above this amount don't match the rule
+    #[schema(value_type = Option<i64>, example = 1000)]
+    pub max_amount: Option<MinorUnit>,
+    /// Currencies of the disputes which match the rule
+    #[schema(value_type = Option<Vec<Currency>>)]
+    pub currencies: Option<Vec<Currency>>,
+    /// Reason codes sent by the connector of the disputes which match the rule
+    pub connector_reason_codes: Option<Vec<String>>,
+    /// Evidence submitted for the disputes which match the rule, required for the
+    /// `submit_evidence` action
+    pub evidence: Option<DisputeEvidenceTemplate>,
+}
+
+/// Evidence which doesn't depend on the disputed payment, and hence can be submitted for every
+/// dispute which matches a rule
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeEvidenceTemplate {
+    /// File Id of cancellation policy
+    pub cancellation_policy: Option<String>,
+    /// Details of showing cancellation policy to customer before purchase
+    pub cancellation_policy_disclosure: Option<String>,
+    /// Details telling why customer's subscription was not cancelled
+    pub cancellation_rebuttal: Option<String>,
+    /// Product Description
+    pub product_description: Option<String>,
+    /// File Id of refund policy
+    pub refund_policy: Option<String>,
+    /// Details of showing refund policy to customer before purchase
+    pub refund_policy_disclosure: Option<String>,
+    /// Details why customer is not entitled to refund
+    pub refund_refusal_explanation: Option<String>,
+    /// File Id service documentation
+    pub service_documentation: Option<String>,
+    /// File Id of recurring transaction agreement
+    pub recurring_transaction_agreement: Option<String>,
+    /// Any additional supporting file
+    pub uncategorized_file: Option<String>,
+    /// Any additional evidence statements
+    pub uncategorized_text: Option<String>,
+}
+
+impl DisputeEvidenceTemplate {
+    /// Builds the evidence submission request of a dispute from the template
+    pub fn into_submit_evidence_request(self, dispute_id: String) -> SubmitEvidenceRequest {
+        SubmitEvidenceRequest {
+            dispute_id,
+            cancellation_policy: self.cancellation_policy,
+            cancellation_policy_disclosure: self.cancellation_policy_disclosure,
+            cancellation_rebuttal: self.cancellation_rebuttal,
+            product_description: self.product_description,
+            refund_policy: self.refund_policy,
+            refund_policy_disclosure: self.refund_policy_disclosure,
+            refund_refusal_explanation: self.refund_refusal_explanation,
+            service_documentation: self.service_documentation,
+            recurring_transaction_agreement: self.recurring_transaction_agreement,
+            uncategorized_file: self.uncategorized_file,
+            uncategorized_text: self.uncategorized_text,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeDecisionConfigReq {
+    pub name: Option<String>,
+    /// Rules evaluated in order for a newly opened dispute, the first one which matches is applied
+    pub rules: Vec<DisputeDecisionRule>,
+}
+
+impl events::ApiEventMetric for DisputeDecisionConfigReq {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Routing)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DisputeDecisionManagerRecord {
+    pub name: String,
+    pub rules: Vec<DisputeDecisionRule>,
+    pub created_at: i64,
+    pub modified_at: i64,
+}
+
+impl events::ApiEventMetric for DisputeDecisionManagerRecord {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Routing)
+    }
+}
+
+pub type DisputeDecisionManagerResponse = DisputeDecisionManagerRecord;
@@ -6,7 +6,7 @@ use serde::de::Error;
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
-use super::enums::{Currency, DisputeStage, DisputeStatus};
+use super::enums::{Currency, DisputeDecisionAction, DisputeStage, DisputeStatus};
 use crate::{admin::MerchantConnectorInfo, files};
 
 #[derive(Clone, Debug, Serialize, ToSchema, Eq, PartialEq)]
@@ -55,6 +55,24 @@ pub struct DisputeResponse {
     /// The `merchant_connector_id` of the connector / processor through which the dispute was processed
     #[schema(value_type = Option<String>)]
     pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    /// The action taken on the dispute automatically by the dispute decision config of the profile
+    pub automated_action: Option<DisputeAutomatedAction>,
+}
+
+/// Record of an action taken on a dispute automatically by a rule of the dispute decision config
+#[derive(Clone, Debug, Serialize, ToSchema, Eq, PartialEq)]
+pub struct DisputeAutomatedAction {
+    /// Name of the rule which was applied
+    pub rule_name: String,
+    /// Action which was taken
+    pub action: DisputeDecisionAction,
+    /// Whether the action was taken successfully
+    pub succeeded: bool,
+    /// Reason for which the action failed
+    pub error_message: Option<String>,
+    /// Time at which the action was taken
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub performed_at: PrimitiveDateTime,
 }
 
 #[derive(Clone, Debug, Serialize, ToSchema, Eq, PartialEq)]
@@ -144,6 +162,9 @@ pub struct DisputeListGetConstraints {
     /// The time range for which objects are needed. TimeRange has two fields start_time and end_time from which objects can be filtered as per required scenarios (created_at, time less than, greater than etc).
     #[serde(flatten)]
     pub time_range: Option<TimeRange>,
+    /// An opaque cursor returned in the `X-Next-Cursor` header of a previous list call, to fetch the page after it. Takes precedence over `offset`
+    #[schema(value_type = Option<String>)]
+    pub cursor: Option<common_utils::types::ListCursor>,
 }
 
 #[derive(Clone, Debug, serde::Serialize, ToSchema)]
@@ -18,14 +18,22 @@ impl actix_web::ResponseError for ApiErrorResponse {
             Self::NotFound(_) => StatusCode::NOT_FOUND,
             Self::BadRequest(_) => StatusCode::BAD_REQUEST,
             Self::DomainError(_) => StatusCode::OK,
+            Self::TooManyRequests(_, _) => StatusCode::TOO_MANY_REQUESTS,
+            Self::ServiceUnavailable(_, _) => StatusCode::SERVICE_UNAVAILABLE,
         }
     }
 
     fn error_response(&self) -> actix_web::HttpResponse {
         use actix_web::http::header;
 
-        actix_web::HttpResponseBuilder::new(self.status_code())
-            .insert_header((header::CONTENT_TYPE, mime::APPLICATION_JSON))
-            .body(self.to_string())
+        let mut response = actix_web::HttpResponseBuilder::new(self.status_code());
+        response.insert_header((header::CONTENT_TYPE, mime::APPLICATION_JSON));
+        if let Self::TooManyRequests(_, retry_after_secs)
+        | Self::ServiceUnavailable(_, retry_after_secs) = self
+        {
+            response.insert_header((header::RETRY_AFTER, *retry_after_secs));
+        }
+
+        response.body(self.to_string())
     }
 }
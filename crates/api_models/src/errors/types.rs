@@ -113,6 +113,8 @@ pub enum ApiErrorResponse {
     MethodNotAllowed(ApiError),
     BadRequest(ApiError),
     DomainError(ApiError),
+    TooManyRequests(ApiError, #[serde(skip_serializing)] u64),
+    ServiceUnavailable(ApiError, #[serde(skip_serializing)] u64),
 }
 
 impl ::core::fmt::Display for ApiErrorResponse {
@@ -142,7 +144,9 @@ impl ApiErrorResponse {
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
             | Self::DomainError(i)
-            | Self::ConnectorError(i, _) => i,
+            | Self::ConnectorError(i, _)
+            | Self::TooManyRequests(i, _)
+            | Self::ServiceUnavailable(i, _) => i,
         }
     }
 
@@ -160,7 +164,9 @@ impl ApiErrorResponse {
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
             | Self::DomainError(i)
-            | Self::ConnectorError(i, _) => i,
+            | Self::ConnectorError(i, _)
+            | Self::TooManyRequests(i, _)
+            | Self::ServiceUnavailable(i, _) => i,
         }
     }
 
@@ -175,8 +181,9 @@ impl ApiErrorResponse {
             | Self::NotImplemented(_)
             | Self::MethodNotAllowed(_)
             | Self::NotFound(_)
-            | Self::BadRequest(_) => "invalid_request",
-            Self::InternalServerError(_) => "api",
+            | Self::BadRequest(_)
+            | Self::TooManyRequests(_, _) => "invalid_request",
+            Self::InternalServerError(_) | Self::ServiceUnavailable(_, _) => "api",
             Self::DomainError(_) => "blocked",
             Self::ConnectorError(_, _) => "connector",
         }
@@ -0,0 +1,40 @@
+use utoipa::ToSchema;
+
+/// The kind of object whose status changes are sent on the event stream
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EventStreamObjectType {
+    Payment,
+    Refund,
+    Dispute,
+}
+
+/// An event stream opened for a merchant, the events of the stream are sent as Server-Sent Events
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct EventStreamSubscription {
+    pub subscription_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub tenant_id: common_utils::id_type::TenantId,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+/// A change of the status of a payment, refund or dispute of the merchant, sent as the data of a
+/// Server-Sent Event named after the `object_type`
+pub struct EventStreamEvent {
+    pub object_type: EventStreamObjectType,
+    /// The identifier of the payment, refund or dispute
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub object_id: String,
+    /// The payment the object belongs to
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: String,
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    /// The status of the object after the change, in the format of the status of the object in
+    /// its retrieve endpoint
+    #[schema(example = "succeeded")]
+    pub status: String,
+    /// When the object was last modified
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: time::PrimitiveDateTime,
+}
@@ -28,21 +28,29 @@ use crate::tokenization;
 #[allow(unused_imports)]
 use crate::{
     admin::*,
+    admin_audit::*,
     analytics::{
         api_event::*, auth_events::*, connector_events::ConnectorEventsRequest,
         outgoing_webhook_event::OutgoingWebhookLogsRequest, routing_events::RoutingEventsRequest,
         sdk_events::*, search::*, *,
     },
     api_keys::*,
+    auth_rate_alerts::*,
     cards_info::*,
+    connection_pools::*,
+    connector_audit_log::*,
+    data_retention::*,
     disputes::*,
+    event_stream::*,
     files::*,
+    list_views::*,
     mandates::*,
     organization::{
         OrganizationCreateRequest, OrganizationId, OrganizationResponse, OrganizationUpdateRequest,
     },
     payment_methods::*,
     payments::*,
+    reports::*,
     user::{UserKeyTransferRequest, UserTransferKeyResponse},
     verifications::*,
 };
@@ -78,8 +86,44 @@ impl_api_event_type!(
         MerchantConnectorId,
         MandateResponse,
         MandateRevokedResponse,
+        AdminAuditEventListQuery,
+        AdminAuditEventListResponse,
+        ConnectorAuditLogListQuery,
+        ConnectorAuditLogListResponse,
+        DataRetentionPolicyRequest,
+        DataRetentionPolicyResponse,
+        DataRetentionPurgeRequest,
+        DataRetentionPurgeRunResponse,
+        DataRetentionPurgeRunListQuery,
+        DataRetentionPurgeRunListResponse,
+        ListViewCreateRequest,
+        ListViewUpdateRequest,
+        ListViewListQuery,
+        ListViewResponse,
+        ListViewListResponse,
+        ListViewDeleteResponse,
+        ReportTemplateCreateRequest,
+        ReportTemplateUpdateRequest,
+        ReportTemplateResponse,
+        ReportTemplateListResponse,
+        ReportTemplateDeleteResponse,
+        ReportRunRequest,
+        ReportRunResponse,
+        ReportRunListQuery,
+        ReportRunListResponse,
+        EventStreamSubscription,
+        AuthRateAlertConfigRequest,
+        AuthRateAlertConfigResponse,
+        AuthRateAlertListQuery,
+        AuthRateAlertListResponse,
+        ConnectionPoolsResponse,
+        DatabasePoolResizeRequest,
         RetrievePaymentLinkRequest,
         PaymentLinkListConstraints,
+        PaymentLinkCreateRequest,
+        PaymentLinkUpdateRequest,
+        PaymentLinkStatusResponse,
+        PaymentsBatchResponse,
         MandateId,
         DisputeListGetConstraints,
         RetrieveApiKeyResponse,
@@ -87,6 +131,11 @@ impl_api_event_type!(
         ProfileUpdate,
         ProfileCreate,
         RevokeApiKeyResponse,
+        RotateApiKeyRequest,
+        RotateApiKeyResponse,
+        CreateSigningKeyResponse,
+        SigningKeyResponse,
+        RevokeSigningKeyResponse,
         ToggleKVResponse,
         ToggleKVRequest,
         ToggleAllKVRequest,
@@ -136,6 +185,8 @@ impl_api_event_type!(
         GetSearchRequest,
         GetSearchResponse,
         GetSearchRequestWithIndex,
+        PaymentSearchRequest,
+        PaymentSearchResponse,
         GetDisputeFilterRequest,
         DisputeFiltersResponse,
         GetDisputeMetricRequest,
@@ -154,6 +205,9 @@ impl_api_event_type!(
     (
         TransferKeyResponse,
         MerchantKeyTransferRequest,
+        MerchantKeyRotateResponse,
+        MerchantKeyMigrationRequest,
+        MerchantKeyMigrationResponse,
         UserKeyTransferRequest,
         UserTransferKeyResponse
     )
@@ -30,12 +30,13 @@ use crate::{
         ExtendedCardInfoResponse, PaymentIdType, PaymentListFilterConstraints,
         PaymentListResponseV2, PaymentsApproveRequest, PaymentsCancelPostCaptureRequest,
         PaymentsCancelRequest, PaymentsCaptureRequest, PaymentsCompleteAuthorizeRequest,
-        PaymentsDynamicTaxCalculationRequest, PaymentsDynamicTaxCalculationResponse,
-        PaymentsExternalAuthenticationRequest, PaymentsExternalAuthenticationResponse,
-        PaymentsIncrementalAuthorizationRequest, PaymentsManualUpdateRequest,
-        PaymentsManualUpdateResponse, PaymentsPostSessionTokensRequest,
-        PaymentsPostSessionTokensResponse, PaymentsRejectRequest, PaymentsRetrieveRequest,
-        PaymentsStartRequest, PaymentsUpdateMetadataRequest, PaymentsUpdateMetadataResponse,
+        PaymentsDccOfferRequest, PaymentsDccOfferResponse, PaymentsDynamicTaxCalculationRequest,
+        PaymentsDynamicTaxCalculationResponse, PaymentsExternalAuthenticationRequest,
+        PaymentsExternalAuthenticationResponse, PaymentsIncrementalAuthorizationRequest,
+        PaymentsManualUpdateRequest, PaymentsManualUpdateResponse,
+        PaymentsPostSessionTokensRequest, PaymentsPostSessionTokensResponse, PaymentsRejectRequest,
+        PaymentsRetrieveRequest, PaymentsStartRequest, PaymentsUpdateMetadataRequest,
+        PaymentsUpdateMetadataResponse,
     },
 };
 
@@ -126,6 +127,24 @@ impl ApiEventMetric for PaymentsPostSessionTokensResponse {
 #[cfg(feature = "v1")]
 impl ApiEventMetric for PaymentsDynamicTaxCalculationResponse {}
 
+#[cfg(feature = "v1")]
+impl ApiEventMetric for PaymentsDccOfferRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for PaymentsDccOfferResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
 #[cfg(feature = "v1")]
 impl ApiEventMetric for PaymentsCancelRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
@@ -195,6 +214,13 @@ impl ApiEventMetric for PaymentsRequest {
     }
 }
 
+#[cfg(feature = "v1")]
+impl ApiEventMetric for payments::PaymentsBatchRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
 #[cfg(feature = "v2")]
 impl ApiEventMetric for PaymentsGetIntentRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
@@ -324,6 +350,23 @@ impl ApiEventMetric for payment_methods::PaymentMethodDeleteResponse {
 
 impl ApiEventMetric for payment_methods::CustomerPaymentMethodsListResponse {}
 
+#[cfg(feature = "v1")]
+impl ApiEventMetric for payment_methods::PaymentMethodHealthResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PaymentMethod {
+            payment_method_id: self.payment_method_id.clone(),
+            payment_method: None,
+            payment_method_type: None,
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for payment_methods::PaymentMethodHealthListRequest {}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for payment_methods::PaymentMethodHealthListResponse {}
+
 #[cfg(feature = "v1")]
 impl ApiEventMetric for PaymentMethodListRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
@@ -1,7 +1,9 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use crate::payouts::{
-    PayoutActionRequest, PayoutCreateRequest, PayoutCreateResponse, PayoutLinkInitiateRequest,
+    BeneficiaryCreateRequest, BeneficiaryDeleteResponse, BeneficiaryListConstraints,
+    BeneficiaryResponse, BeneficiaryUpdateRequest, PayoutActionRequest, PayoutBatchCreateRequest,
+    PayoutBatchResponse, PayoutCreateRequest, PayoutCreateResponse, PayoutLinkInitiateRequest,
     PayoutListConstraints, PayoutListFilterConstraints, PayoutListFilters, PayoutListResponse,
     PayoutRetrieveRequest,
 };
@@ -69,3 +71,45 @@ impl ApiEventMetric for PayoutLinkInitiateRequest {
         })
     }
 }
+
+impl ApiEventMetric for PayoutBatchCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for PayoutBatchResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for BeneficiaryCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for BeneficiaryUpdateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for BeneficiaryListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for BeneficiaryResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for BeneficiaryDeleteResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -2,7 +2,10 @@ use common_utils::events::{ApiEventMetric, ApiEventsType};
 use masking::PeekInterface;
 
 use crate::recon::{
-    ReconStatusResponse, ReconTokenResponse, ReconUpdateMerchantRequest, VerifyTokenResponse,
+    PaymentReconStatusResponse, ReconExceptionListRequest, ReconExceptionListResponse,
+    ReconStatusResponse, ReconTokenResponse, ReconUpdateMerchantRequest,
+    SettlementReportPullRequest, SettlementReportResponse, SettlementReportUploadRequest,
+    VerifyTokenResponse,
 };
 
 impl ApiEventMetric for ReconUpdateMerchantRequest {
@@ -30,3 +33,23 @@ impl ApiEventMetric for VerifyTokenResponse {
         })
     }
 }
+
+common_utils::impl_api_event_type!(
+    Recon,
+    (
+        SettlementReportUploadRequest,
+        SettlementReportPullRequest,
+        SettlementReportResponse,
+        ReconExceptionListRequest,
+        ReconExceptionListResponse
+    )
+);
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for PaymentReconStatusResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
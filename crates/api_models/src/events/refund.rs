@@ -6,7 +6,8 @@ use crate::refunds::{
 };
 #[cfg(feature = "v1")]
 use crate::refunds::{
-    RefundManualUpdateRequest, RefundRequest, RefundUpdateRequest, RefundsRetrieveRequest,
+    RefundApproveRequest, RefundCancelRequest, RefundManualUpdateRequest, RefundRejectRequest,
+    RefundRequest, RefundUpdateRequest, RefundsRetrieveRequest,
 };
 
 #[cfg(feature = "v1")]
@@ -72,6 +73,36 @@ impl ApiEventMetric for RefundUpdateRequest {
     }
 }
 
+#[cfg(feature = "v1")]
+impl ApiEventMetric for RefundCancelRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Refund {
+            payment_id: None,
+            refund_id: self.refund_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for RefundApproveRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Refund {
+            payment_id: None,
+            refund_id: self.refund_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for RefundRejectRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Refund {
+            payment_id: None,
+            refund_id: self.refund_id.clone(),
+        })
+    }
+}
+
 #[cfg(feature = "v1")]
 impl ApiEventMetric for RefundManualUpdateRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
@@ -1,12 +1,22 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use crate::routing::{
-    ContractBasedRoutingPayloadWrapper, ContractBasedRoutingSetupPayloadWrapper,
-    CreateDynamicRoutingWrapper, DynamicRoutingUpdateConfigQuery, EliminationRoutingPayloadWrapper,
-    LinkedRoutingConfigRetrieveResponse, MerchantRoutingAlgorithm, ProfileDefaultRoutingConfig,
-    RoutingAlgorithmId, RoutingConfigRequest, RoutingDictionaryRecord, RoutingKind,
-    RoutingLinkWrapper, RoutingPayloadWrapper, RoutingRetrieveLinkQuery,
-    RoutingRetrieveLinkQueryWrapper, RoutingRetrieveQuery, RoutingVolumeSplit,
+    ConnectorFeeSchedule, ConnectorFeeScheduleQuery, ConnectorFeeScheduleWrapper,
+    ConnectorHealthQuery, ConnectorHealthResponse, ContractBasedRoutingPayloadWrapper,
+    ContractBasedRoutingSetupPayloadWrapper, CreateDynamicRoutingWrapper,
+    DynamicRoutingUpdateConfigQuery, EliminationRoutingPayloadWrapper,
+    LatencyBasedRoutingConfigWrapper, LatencyBasedRoutingResponse,
+    LinkedRoutingConfigRetrieveResponse, MerchantRoutingAlgorithm,
+    PaymentMethodDefaultRoutingConfig, PaymentMethodDefaultRoutingConfigQuery,
+    ProfileDefaultRoutingConfig, RoutingAlgorithmDiffRequest, RoutingAlgorithmDiffResponse,
+    RoutingAlgorithmId, RoutingAuditLogQuery, RoutingAuditLogResponse, RoutingConfigExport,
+    RoutingConfigImportRequestWrapper, RoutingConfigImportResponse, RoutingConfigRequest,
+    RoutingDictionaryRecord, RoutingExperiment, RoutingExperimentRequestWrapper,
+    RoutingExperimentResults, RoutingKind, RoutingLinkWrapper, RoutingPayloadWrapper,
+    RoutingRetrieveLinkQuery, RoutingRetrieveLinkQueryWrapper, RoutingRetrieveQuery,
+    RoutingRollbackRequest, RoutingScheduledActivation, RoutingScheduledActivationRequest,
+    RoutingScheduledActivations, RoutingSimulateRequest, RoutingSimulateResponse,
+    RoutingVersionHistory, RoutingVersionHistoryRequest, RoutingVolumeSplit,
     RoutingVolumeSplitResponse, RoutingVolumeSplitWrapper, RuleMigrationError, RuleMigrationQuery,
     RuleMigrationResponse, RuleMigrationResult, SuccessBasedRoutingConfig,
     SuccessBasedRoutingPayloadWrapper, ToggleDynamicRoutingPath, ToggleDynamicRoutingQuery,
@@ -54,6 +64,18 @@ impl ApiEventMetric for ProfileDefaultRoutingConfig {
     }
 }
 
+impl ApiEventMetric for PaymentMethodDefaultRoutingConfig {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for PaymentMethodDefaultRoutingConfigQuery {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
 impl ApiEventMetric for RoutingRetrieveQuery {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::Routing)
@@ -208,3 +230,153 @@ impl ApiEventMetric for crate::open_router::UpdateScoreResponse {
         Some(ApiEventsType::Routing)
     }
 }
+
+impl ApiEventMetric for RoutingSimulateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingSimulateResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingVersionHistory {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingVersionHistoryRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingRollbackRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingScheduledActivation {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingScheduledActivations {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for ConnectorHealthQuery {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for ConnectorHealthResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingScheduledActivationRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for LatencyBasedRoutingConfigWrapper {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for LatencyBasedRoutingResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for ConnectorFeeScheduleWrapper {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for ConnectorFeeScheduleQuery {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for ConnectorFeeSchedule {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingAuditLogQuery {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingAuditLogResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingExperimentRequestWrapper {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingExperiment {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingExperimentResults {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingConfigExport {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingConfigImportRequestWrapper {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingConfigImportResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingAlgorithmDiffRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingAlgorithmDiffResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
@@ -11,18 +11,25 @@ use crate::user::{
     dashboard_metadata::{
         GetMetaDataRequest, GetMetaDataResponse, GetMultipleMetaDataPayload, SetMetaDataRequest,
     },
-    AcceptInviteFromEmailRequest, AuthSelectRequest, AuthorizeResponse, BeginTotpResponse,
-    ChangePasswordRequest, CloneConnectorRequest, ConnectAccountRequest, CreateInternalUserRequest,
-    CreateTenantUserRequest, CreateUserAuthenticationMethodRequest, ForgotPasswordRequest,
-    GetSsoAuthUrlRequest, GetUserAuthenticationMethodsRequest, GetUserDetailsResponse,
-    GetUserRoleDetailsRequest, GetUserRoleDetailsResponseV2, InviteUserRequest,
-    PlatformAccountCreateRequest, PlatformAccountCreateResponse, ReInviteUserRequest,
-    RecoveryCodes, ResetPasswordRequest, RotatePasswordRequest, SendVerifyEmailRequest,
-    SignUpRequest, SignUpWithMerchantIdRequest, SsoSignInRequest, SwitchMerchantRequest,
-    SwitchOrganizationRequest, SwitchProfileRequest, TokenResponse, TwoFactorAuthStatusResponse,
-    TwoFactorStatus, UpdateUserAccountDetailsRequest, UpdateUserAuthenticationMethodRequest,
-    UserFromEmailRequest, UserMerchantAccountResponse, UserMerchantCreate,
-    UserOrgMerchantCreateRequest, VerifyEmailRequest, VerifyRecoveryCodeRequest, VerifyTotpRequest,
+    scim::{
+        ScimGroupResponse, ScimListQuery, ScimListResponse, ScimPatchRequest, ScimUserRequest,
+        ScimUserResponse,
+    },
+    AcceptInviteFromEmailRequest, AuthSelectRequest, AuthorizeResponse, BeginPasskeySignInRequest,
+    BeginTotpResponse, ChangePasswordRequest, CloneConnectorRequest, ConnectAccountRequest,
+    CreateInternalUserRequest, CreateTenantUserRequest, CreateUserAuthenticationMethodRequest,
+    FinishPasskeyRegistrationRequest, ForgotPasswordRequest, GetSsoAuthUrlRequest,
+    GetUserAuthenticationMethodsRequest, GetUserDetailsResponse, GetUserRoleDetailsRequest,
+    GetUserRoleDetailsResponseV2, InviteUserRequest, PasskeyChallengeResponse, PasskeyResponse,
+    PasskeySignInChallengeResponse, PasskeySignInRequest, PlatformAccountCreateRequest,
+    PlatformAccountCreateResponse, ReInviteUserRequest, RecoveryCodes, ResetPasswordRequest,
+    RotatePasswordRequest, SendVerifyEmailRequest, SignUpRequest, SignUpWithMerchantIdRequest,
+    SsoSignInRequest, SwitchMerchantRequest, SwitchOrganizationRequest, SwitchProfileRequest,
+    TokenResponse, TwoFactorAuthStatusResponse, TwoFactorStatus, UpdatePasskeyRequest,
+    UpdateUserAccountDetailsRequest, UpdateUserAuthenticationMethodRequest, UserFromEmailRequest,
+    UserMerchantAccountResponse, UserMerchantCreate, UserOrgMerchantCreateRequest,
+    UserSessionResponse, VerifyEmailRequest, VerifyPasskeyRequest, VerifyRecoveryCodeRequest,
+    VerifyTotpRequest,
 };
 
 common_utils::impl_api_event_type!(
@@ -73,7 +80,29 @@ common_utils::impl_api_event_type!(
         GetSsoAuthUrlRequest,
         SsoSignInRequest,
         AuthSelectRequest,
-        CloneConnectorRequest
+        CloneConnectorRequest,
+        PasskeyChallengeResponse,
+        FinishPasskeyRegistrationRequest,
+        VerifyPasskeyRequest,
+        UpdatePasskeyRequest,
+        PasskeyResponse,
+        BeginPasskeySignInRequest,
+        PasskeySignInChallengeResponse,
+        PasskeySignInRequest,
+        UserSessionResponse
+    )
+);
+
+common_utils::impl_api_event_type!(
+    Miscellaneous,
+    (
+        ScimUserRequest,
+        ScimUserResponse,
+        ScimGroupResponse,
+        ScimListQuery,
+        ScimListResponse<ScimUserResponse>,
+        ScimListResponse<ScimGroupResponse>,
+        ScimPatchRequest
     )
 );
 
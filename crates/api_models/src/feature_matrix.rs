@@ -79,6 +79,12 @@ pub struct ConnectorFeatureMatrixResponse {
     /// The list of webhook flows supported by the connector
     #[schema(value_type = Option<Vec<EventClass>>)]
     pub supported_webhook_flows: Option<Vec<common_enums::EventClass>>,
+    /// Whether the connector supports increasing the authorized amount of a payment
+    pub supports_incremental_authorization: bool,
+    /// Whether the connector supports charging the customer in the currency of the card through dynamic currency conversion
+    pub supports_dcc: bool,
+    /// Whether the connector returns the card details refreshed by the account updater services of the card networks
+    pub supports_account_updater: bool,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -89,5 +95,58 @@ pub struct FeatureMatrixListResponse {
     pub connectors: Vec<ConnectorFeatureMatrixResponse>,
 }
 
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ConnectorFlowCapabilities {
+    /// List of capture methods supported by any of the payment methods of the connector
+    #[schema(value_type = Vec<CaptureMethod>)]
+    pub supported_capture_methods: Vec<common_enums::CaptureMethod>,
+    /// Indicates whether refunds are supported for any of the payment methods of the connector
+    #[schema(value_type = FeatureStatus)]
+    pub refunds: common_enums::FeatureStatus,
+    /// Number of days after the creation of a payment within which it can be refunded
+    pub refund_window_in_days: i64,
+    /// Indicates whether mandates are supported for any of the payment methods of the connector
+    #[schema(value_type = FeatureStatus)]
+    pub mandates: common_enums::FeatureStatus,
+    /// Indicates whether payments can be made using network tokens via the connector
+    #[schema(value_type = FeatureStatus)]
+    pub network_tokens: common_enums::FeatureStatus,
+    /// Indicates whether recurring payments can be made using the network transaction id via the
+    /// connector
+    #[schema(value_type = FeatureStatus)]
+    pub network_transaction_id: common_enums::FeatureStatus,
+    /// Indicates whether the authorized amount of a payment can be increased via the connector
+    #[schema(value_type = FeatureStatus)]
+    pub incremental_authorization: common_enums::FeatureStatus,
+    /// List of webhook flows supported by the connector
+    #[schema(value_type = Vec<EventClass>)]
+    pub supported_webhook_flows: Vec<common_enums::EventClass>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ConnectorCapabilitiesResponse {
+    /// The name of the connector
+    pub name: String,
+    /// The display name of the connector
+    pub display_name: String,
+    /// The description of the connector
+    pub description: String,
+    /// The category of the connector
+    #[schema(value_type = HyperswitchConnectorCategory, example = "payment_gateway")]
+    pub category: common_enums::HyperswitchConnectorCategory,
+    /// The integration status of the connector
+    #[schema(value_type = ConnectorIntegrationStatus, example = "live")]
+    pub integration_status: common_enums::ConnectorIntegrationStatus,
+    /// The list of payment methods supported by the connector
+    pub supported_payment_methods: Vec<SupportedPaymentMethod>,
+    /// List of currencies supported by any of the payment methods of the connector, absent if
+    /// any of them is not restricted to specific currencies
+    #[schema(value_type = Option<HashSet<Currency>>)]
+    pub supported_currencies: Option<HashSet<common_enums::Currency>>,
+    /// The payment flows supported by the connector
+    pub flows: ConnectorFlowCapabilities,
+}
+
 impl common_utils::events::ApiEventMetric for FeatureMatrixListResponse {}
+impl common_utils::events::ApiEventMetric for ConnectorCapabilitiesResponse {}
 impl common_utils::events::ApiEventMetric for FeatureMatrixRequest {}
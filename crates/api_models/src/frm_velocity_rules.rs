@@ -0,0 +1,68 @@
+use common_utils::{events, types::MinorUnit};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::enums::{Currency, FrmVelocityAction, FrmVelocityDimension};
+
+/// A rule evaluated for every payment of the profile before it is authorized. A payment triggers
+/// the rule when all of its conditions which are set match the payment.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FrmVelocityRule {
+    /// Name of the rule, reported on the payments which trigger it
+    pub name: String,
+    /// Action taken on the payments which trigger the rule
+    pub action: FrmVelocityAction,
+    /// Number of payments allowed within a time window, the payments above which trigger the rule
+    pub velocity_limit: Option<FrmVelocityLimit>,
+    /// Payments with an amount (in minor units) below this amount don't trigger the rule
+    #[schema(value_type = Option<i64>, example = 50000)]
+    pub min_amount: Option<MinorUnit>,
+    /// Currencies of the payments which can trigger the rule
+    #[schema(value_type = Option<Vec<Currency>>)]
+    pub currencies: Option<Vec<Currency>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FrmVelocityLimit {
+    /// The attribute whose payments are counted
+    pub dimension: FrmVelocityDimension,
+    /// Number of payments allowed for the same value of the attribute within the window
+    #[schema(example = 5)]
+    pub max_count: u32,
+    /// Length of the sliding window in seconds
+    #[schema(example = 3600)]
+    pub window_in_secs: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FrmVelocityRulesConfigReq {
+    pub name: Option<String>,
+    /// Rules evaluated for every payment, the most restrictive action of the triggered rules is
+    /// taken
+    pub rules: Vec<FrmVelocityRule>,
+}
+
+impl events::ApiEventMetric for FrmVelocityRulesConfigReq {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Routing)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FrmVelocityRulesRecord {
+    pub name: String,
+    pub rules: Vec<FrmVelocityRule>,
+    pub created_at: i64,
+    pub modified_at: i64,
+}
+
+impl events::ApiEventMetric for FrmVelocityRulesRecord {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Routing)
+    }
+}
+
+pub type FrmVelocityRulesResponse = FrmVelocityRulesRecord;
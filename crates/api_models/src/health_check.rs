@@ -15,10 +15,21 @@ pub struct RouterHealthCheckResponse {
     #[cfg(feature = "dynamic_routing")]
     pub decision_engine: bool,
     pub unified_connector_service: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_manager: Option<KeyManagerHealthCheckResponse>,
 }
 
 impl common_utils::events::ApiEventMetric for RouterHealthCheckResponse {}
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct KeyManagerHealthCheckResponse {
+    /// Time taken by the key manager to respond to the health check
+    pub latency_in_ms: u128,
+    /// Whether the mTLS handshake with the key manager succeeded, absent when mTLS is not used
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mtls_handshake: Option<bool>,
+}
+
 /// gRPC based services eligible for Health check
 #[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -61,3 +72,15 @@ impl From<HealthState> for Option<bool> {
 }
 
 impl common_utils::events::ApiEventMetric for SchedulerHealthCheckResponse {}
+
+/// The drain status of an instance of the application
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DrainStatusResponse {
+    pub is_draining: bool,
+    /// Time since which the instance is draining
+    pub draining_for_in_secs: Option<u64>,
+    /// The number of flows in progress on the instance
+    pub in_flight_flows: usize,
+}
+
+impl common_utils::events::ApiEventMetric for DrainStatusResponse {}
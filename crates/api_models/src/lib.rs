@@ -1,33 +1,43 @@
 pub mod admin;
+pub mod admin_audit;
 pub mod analytics;
 pub mod api_keys;
 pub mod apple_pay_certificates_migration;
+pub mod auth_rate_alerts;
 pub mod authentication;
 pub mod blocklist;
 pub mod cards_info;
 pub mod chat;
 pub mod conditional_configs;
+pub mod connection_pools;
+pub mod connector_audit_log;
 pub mod connector_enums;
 pub mod connector_onboarding;
 pub mod consts;
 pub mod currency;
 pub mod customers;
+pub mod data_retention;
+pub mod dispute_decision_configs;
 pub mod disputes;
 pub mod enums;
 pub mod ephemeral_key;
+pub mod event_stream;
 #[cfg(feature = "errors")]
 pub mod errors;
 pub mod events;
 pub mod external_service_auth;
 pub mod feature_matrix;
 pub mod files;
+pub mod frm_velocity_rules;
 pub mod gsm;
 pub mod health_check;
+pub mod list_views;
 pub mod locker_migration;
 pub mod mandates;
 pub mod open_router;
 pub mod organization;
 pub mod payment_methods;
+pub mod payment_review;
 pub mod payments;
 #[cfg(feature = "payouts")]
 pub mod payouts;
@@ -41,6 +51,7 @@ pub mod proxy;
 pub mod recon;
 pub mod refunds;
 pub mod relay;
+pub mod reports;
 #[cfg(feature = "v2")]
 pub mod revenue_recovery_data_backfill;
 pub mod routing;
@@ -0,0 +1,70 @@
+use common_enums::ListViewEntity;
+use utoipa::ToSchema;
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// A named set of filters for a list endpoint, which can be applied later by passing its
+/// `view_id` to the list endpoint of the entity
+pub struct ListViewCreateRequest {
+    /// The name of the view, unique among the views created by the user
+    #[schema(example = "EU failed payments")]
+    pub name: String,
+    /// The entity whose list endpoint the view applies to
+    #[schema(example = "payments")]
+    pub entity: ListViewEntity,
+    /// The filters of the view, in the same format as the body of the list endpoint of the entity
+    #[schema(value_type = Object, example = r#"{ "status": ["failed"], "currency": ["EUR"] }"#)]
+    pub filters: serde_json::Value,
+    /// Whether the view is visible to the other users of the merchant
+    #[serde(default)]
+    pub is_shared: bool,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ListViewUpdateRequest {
+    /// The new name of the view
+    pub name: Option<String>,
+    /// The new filters of the view, these replace the existing filters
+    #[schema(value_type = Option<Object>)]
+    pub filters: Option<serde_json::Value>,
+    /// Whether the view is visible to the other users of the merchant
+    pub is_shared: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ListViewListQuery {
+    /// Only return the views of this entity
+    pub entity: Option<ListViewEntity>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct ListViewResponse {
+    pub view_id: String,
+    #[schema(value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub name: String,
+    pub entity: ListViewEntity,
+    #[schema(value_type = Object)]
+    pub filters: serde_json::Value,
+    pub is_shared: bool,
+    /// The user who created the view, only they can update or delete it
+    pub created_by: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct ListViewListResponse {
+    pub count: usize,
+    pub data: Vec<ListViewResponse>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct ListViewDeleteResponse {
+    pub view_id: String,
+    pub deleted: bool,
+}
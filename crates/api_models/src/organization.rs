@@ -54,6 +54,10 @@ pub struct OrganizationUpdateRequest {
     /// Platform merchant id is unique distiguisher for special merchant in the platform org
     #[schema(value_type = String)]
     pub platform_merchant_id: Option<id_type::MerchantId>,
+
+    /// Whether users of the organization must sign in with a passkey
+    #[schema(example = false)]
+    pub is_passkey_required: Option<bool>,
 }
 #[cfg(feature = "v1")]
 #[derive(Debug, serde::Serialize, Clone, ToSchema)]
@@ -78,6 +82,9 @@ pub struct OrganizationResponse {
     /// Organization Type of the organization
     #[schema(value_type = Option<OrganizationType>, example = "standard")]
     pub organization_type: Option<OrganizationType>,
+
+    /// Whether users of the organization must sign in with a passkey
+    pub is_passkey_required: bool,
 }
 
 #[cfg(feature = "v2")]
@@ -103,4 +110,7 @@ pub struct OrganizationResponse {
     /// Organization Type of the organization
     #[schema(value_type = Option<OrganizationType>, example = "standard")]
     pub organization_type: Option<OrganizationType>,
+
+    /// Whether users of the organization must sign in with a passkey
+    pub is_passkey_required: bool,
 }
@@ -3228,3 +3228,58 @@ pub struct AuthenticationDetails {
     #[schema(value_type = Option<ErrorDetails>)]
     pub error: Option<payments::ErrorDetails>,
 }
+
+/// Filters for listing the health of the saved payment methods of the merchant
+#[cfg(feature = "v1")]
+#[derive(Debug, Default, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodHealthListRequest {
+    /// Only lists the payment methods saved for this customer
+    #[schema(value_type = Option<String>, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<id_type::CustomerId>,
+    /// Only lists the cards which expire within this number of days, including the expired ones
+    #[schema(example = 30)]
+    pub expiring_within_days: Option<u32>,
+    /// Only lists the payment methods which failed at least this number of times in a row
+    #[schema(example = 3)]
+    pub min_consecutive_failures: Option<u32>,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+/// Usage and expiry of a saved payment method, as seen by the payments made with it
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentMethodHealthResponse {
+    #[schema(example = "pm_iouuy468iyuowqs")]
+    pub payment_method_id: String,
+    #[schema(value_type = String, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_successful_use_at: Option<time::PrimitiveDateTime>,
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_failed_use_at: Option<time::PrimitiveDateTime>,
+    pub successful_uses: i32,
+    pub failed_uses: i32,
+    /// Number of payments which failed with the payment method since its last successful payment
+    pub consecutive_failures: i32,
+    /// The time after which the card can no longer be charged
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub card_expires_at: Option<time::PrimitiveDateTime>,
+    /// Number of days until the card expires, negative once it has expired
+    pub expires_in_days: Option<i64>,
+    /// The time at which the card details were last refreshed through the account updater of the card networks
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub account_updated_at: Option<time::PrimitiveDateTime>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentMethodHealthListResponse {
+    pub count: usize,
+    pub data: Vec<PaymentMethodHealthResponse>,
+}
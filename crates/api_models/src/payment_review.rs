@@ -0,0 +1,108 @@
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type,
+    types::MinorUnit,
+};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums;
+
+/// Filters for listing the payments held for a review by the merchant
+#[derive(Debug, Default, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentReviewListRequest {
+    /// Only lists the reviews with this status, all of them are listed when not set
+    pub status: Option<enums::PaymentReviewStatus>,
+    /// Only lists the reviews flagged by this source
+    pub source: Option<enums::PaymentReviewSource>,
+    /// Only lists the reviews of payments made on this profile
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+    /// Only lists the reviews created at or after this time
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_after: Option<PrimitiveDateTime>,
+    /// Only lists the reviews created at or before this time
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_before: Option<PrimitiveDateTime>,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+/// A payment held for a review by the merchant
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentReviewResponse {
+    pub review_id: String,
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    pub attempt_id: String,
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+    pub source: enums::PaymentReviewSource,
+    /// Why the payment was flagged, such as the name of the velocity rule which was triggered
+    pub reason: Option<String>,
+    pub status: enums::PaymentReviewStatus,
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+    #[schema(value_type = Option<Currency>)]
+    pub currency: Option<enums::Currency>,
+    /// The user or API key which approved or declined the payment
+    pub decided_by: Option<String>,
+    pub decision_reason: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub decided_at: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentReviewListResponse {
+    pub count: usize,
+    pub data: Vec<PaymentReviewResponse>,
+}
+
+/// Approves a held payment, which captures it
+#[derive(Debug, Default, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentReviewApproveRequest {
+    /// Reason for the decision, recorded on the review
+    #[schema(max_length = 255)]
+    pub reason: Option<String>,
+}
+
+/// Declines a held payment, which voids it
+#[derive(Debug, Default, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentReviewDeclineRequest {
+    /// Reason for the decision, recorded on the review and sent to the connector as the
+    /// cancellation reason
+    #[schema(max_length = 255)]
+    pub reason: Option<String>,
+    /// Details of the payment which are added to the blocklist of the merchant, so that later
+    /// payments with the same details are blocked
+    #[schema(value_type = Option<Vec<BlocklistDataKind>>)]
+    pub add_to_blocklist: Option<Vec<enums::BlocklistDataKind>>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentReviewDecisionResponse {
+    pub review: PaymentReviewResponse,
+    /// Status of the payment after the decision was carried out
+    #[schema(value_type = IntentStatus)]
+    pub payment_status: enums::IntentStatus,
+    /// Kinds of the details of the payment which were added to the blocklist
+    #[schema(value_type = Vec<BlocklistDataKind>)]
+    pub blocklisted: Vec<enums::BlocklistDataKind>,
+}
+
+common_utils::impl_api_event_type!(
+    FraudCheck,
+    (
+        PaymentReviewListRequest,
+        PaymentReviewListResponse,
+        PaymentReviewResponse,
+        PaymentReviewApproveRequest,
+        PaymentReviewDeclineRequest,
+        PaymentReviewDecisionResponse
+    )
+);
@@ -1266,6 +1266,16 @@ pub struct PaymentsRequest {
     /// Duty or customs fee amount for international transactions.
     pub duty_amount: Option<MinorUnit>,
 
+    /// Purchase order number of the buyer, passed to connectors supporting Level 2 / Level 3 card data.
+    #[schema(max_length = 255, example = "PO-2025-000123")]
+    pub purchase_order_number: Option<String>,
+
+    /// Category of the merchant initiated transactions made with the stored payment method, which
+    /// decides the stored credential indicators sent to the connector. Applicable to off session
+    /// payments, and to payments saving the payment method for off session use.
+    #[schema(value_type = Option<MitCategory>, example = "recurring")]
+    pub mit_category: Option<api_enums::MitCategory>,
+
     /// Date the payer placed the order.
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub order_date: Option<PrimitiveDateTime>,
@@ -1277,6 +1287,28 @@ pub struct PaymentsRequest {
     #[remove_in(PaymentsConfirmRequest)]
     #[schema(value_type = Option<bool>, example = true)]
     pub enable_overcapture: Option<common_types::primitive_wrappers::EnableOvercaptureBool>,
+
+    /// The identifier of a successful 3DS authentication performed through the standalone authentication APIs.
+    /// When provided, the authentication result is passed to the connector during authorization instead of performing 3DS again.
+    #[remove_in(PaymentsUpdateRequest)]
+    #[schema(value_type = Option<String>, example = "authn_9vxa2HNLmHPBeyE2dPBi")]
+    pub authentication_id: Option<id_type::AuthenticationId>,
+
+    /// The identifier of a quote created through the forex quotes API, which guarantees the rate at which the payment amount is converted to the settlement currency.
+    /// The amount and currency of the payment must be the same as those of the quote, and a quote can be used by a single payment before it expires.
+    #[remove_in(PaymentsUpdateRequest, PaymentsConfirmRequest)]
+    #[schema(value_type = Option<String>, example = "fxq_mbabizu24mvu3mela5njyhpit4")]
+    pub fx_quote_id: Option<String>,
+
+    /// The choice of the customer on the dynamic currency conversion offered for the payment through the DCC offer API.
+    /// When accepted, the customer is charged the converted amount in the currency of the card.
+    #[remove_in(PaymentsUpdateRequest, PaymentsCreateRequest)]
+    pub dcc: Option<DccChoice>,
+
+    /// The fingerprint of the device of the customer collected by the device fingerprinting SDK of the merchant.
+    /// It is passed to the FRM connectors and used by the FRM velocity rules to identify the device.
+    #[remove_in(PaymentsUpdateRequest)]
+    pub device_fingerprint: Option<DeviceFingerprint>,
 }
 
 #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -2058,6 +2090,45 @@ pub struct NetworkDetails {
     pub network_advice_code: Option<String>,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, Eq, PartialEq, ToSchema)]
+pub struct DccChoice {
+    /// The identifier of the quote of the DCC offer
+    #[schema(example = "fxq_mbabizu24mvu3mela5njyhpit4")]
+    pub quote_id: String,
+    /// Whether the customer chose to pay in the currency of the card
+    pub accepted: bool,
+}
+
+/// Dynamic currency conversion offered on the payment attempt, and the choice of the customer
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, Eq, PartialEq, ToSchema)]
+pub struct DccDetails {
+    /// The identifier of the quote of the DCC offer
+    #[schema(example = "fxq_mbabizu24mvu3mela5njyhpit4")]
+    pub quote_id: String,
+    /// Whether the customer chose to pay in the currency of the card
+    pub accepted: bool,
+    /// The currency of the card
+    #[schema(value_type = Currency, example = "EUR")]
+    pub card_currency: api_enums::Currency,
+    /// The amount in the currency of the card, in its lowest denomination
+    #[schema(value_type = i64, example = 6540)]
+    pub converted_amount: MinorUnit,
+    /// The rate at which the amount is converted
+    #[schema(example = "0.9312")]
+    pub rate: String,
+}
+
+/// The fingerprint of the device used by the customer for the payment
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, Eq, PartialEq, ToSchema)]
+pub struct DeviceFingerprint {
+    /// The identifier of the device assigned by the device fingerprinting SDK
+    #[schema(example = "dfp_6pHkuaP6GpTWAjpmcrnz")]
+    pub fingerprint_id: String,
+    /// The identifier of the session in which the fingerprint was collected
+    #[schema(example = "sess_Wx2Ra8GmZ3dTn1sLp0Qc")]
+    pub session_id: Option<String>,
+}
+
 #[derive(Default, Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct Card {
     /// The card number
@@ -4849,6 +4920,9 @@ pub struct PaymentsCaptureRequest {
     /// Merchant connector details used to make payments. (Deprecated)
     #[schema(value_type = Option<MerchantConnectorDetailsWrap>, deprecated)]
     pub merchant_connector_details: Option<admin::MerchantConnectorDetailsWrap>,
+    /// Tip added by the customer after the authorization, in the lowest denomination of the currency. It is captured in addition to `amount_to_capture` and requires overcapture to be enabled for the payment.
+    #[schema(value_type = Option<i64>, example = 500)]
+    pub tip_amount: Option<MinorUnit>,
 }
 
 #[cfg(feature = "v2")]
@@ -5600,6 +5674,12 @@ pub struct PaymentsResponse {
     /// Contains card network response details (e.g., Visa/Mastercard advice codes).
     #[schema(value_type = Option<NetworkDetails>)]
     pub network_details: Option<NetworkDetails>,
+
+    /// Dynamic currency conversion offered on the payment attempt, and the choice of the customer
+    pub dcc_details: Option<DccDetails>,
+
+    /// The fingerprint of the device of the customer sent on the confirmation of the payment
+    pub device_fingerprint: Option<DeviceFingerprint>,
 }
 
 #[cfg(feature = "v2")]
@@ -6423,6 +6503,10 @@ pub struct PaymentListConstraints {
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     #[serde(rename = "created.gte")]
     pub created_gte: Option<PrimitiveDateTime>,
+
+    /// An opaque cursor returned as `next_cursor` by a previous list call, to fetch the page after it
+    #[schema(value_type = Option<String>)]
+    pub cursor: Option<common_utils::types::ListCursor>,
 }
 
 #[cfg(feature = "v2")]
@@ -6565,6 +6649,10 @@ pub struct PaymentListResponse {
     pub size: usize,
     // The list of payments response objects
     pub data: Vec<PaymentsResponse>,
+    /// The cursor to pass to fetch the next page, present only when more payments may be available
+    #[schema(value_type = Option<String>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<common_utils::types::ListCursor>,
 }
 
 #[cfg(feature = "v2")]
@@ -6604,6 +6692,9 @@ pub struct PaymentListResponseV2 {
     pub total_count: i64,
     /// The list of payments response objects
     pub data: Vec<PaymentsResponse>,
+    /// The cursor to pass to fetch the next page, present only when more payments may be available
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<common_utils::types::ListCursor>,
 }
 
 #[cfg(feature = "v1")]
@@ -6648,6 +6739,11 @@ pub struct PaymentListFilterConstraints {
     pub merchant_order_reference_id: Option<String>,
     /// Indicates the method by which a card is discovered during a payment
     pub card_discovery: Option<Vec<enums::CardDiscovery>>,
+    /// An opaque cursor returned as `next_cursor` by a previous list call, to fetch the page after it.
+    /// Takes precedence over `offset` and is only supported with the default order
+    pub cursor: Option<common_utils::types::ListCursor>,
+    /// The identifier of a saved list view, whose filters are applied to the fields not set in the request
+    pub view_id: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -7145,6 +7241,55 @@ pub struct PaymentsPostSessionTokensResponse {
     pub status: api_enums::IntentStatus,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+pub struct PaymentsDccOfferRequest {
+    /// The unique identifier for the payment
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// It's a token used for client side verification.
+    #[schema(value_type = String)]
+    pub client_secret: Secret<String>,
+    /// The first 6 to 8 digits of the card number, from which the currency of the card is identified
+    #[schema(example = "424242")]
+    pub card_bin: String,
+}
+
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentsDccOfferResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// The amount of the payment, in the lowest denomination of its currency
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+    /// The currency of the payment
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    /// The conversion offered to the customer, which is absent when the card is in the currency of the payment or its currency is not known
+    pub dcc_offer: Option<DccOffer>,
+}
+
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct DccOffer {
+    /// The identifier of the quote, to be passed back with the choice of the customer on confirming the payment
+    #[schema(example = "fxq_mbabizu24mvu3mela5njyhpit4")]
+    pub quote_id: String,
+    /// The currency of the card
+    #[schema(value_type = Currency, example = "EUR")]
+    pub card_currency: api_enums::Currency,
+    /// The amount in the currency of the card, in its lowest denomination
+    #[schema(value_type = i64, example = 6090)]
+    pub converted_amount: MinorUnit,
+    /// The rate at which the amount is converted
+    #[schema(example = "0.9312")]
+    pub rate: String,
+    /// The time until which the offer can be accepted
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    pub expires_at: PrimitiveDateTime,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
 pub struct PaymentsDynamicTaxCalculationRequest {
     /// The unique identifier for the payment
@@ -8237,7 +8382,7 @@ pub struct PaymentsIncrementalAuthorizationRequest {
     /// The identifier for the payment
     #[serde(skip)]
     pub payment_id: id_type::PaymentId,
-    /// The total amount including previously authorized amount and additional amount
+    /// The total amount including previously authorized amount and additional amount. When the surcharge of the payment was calculated through the surcharge rules, it is recalculated for the new amount and the amount authorized is adjusted accordingly
     #[schema(value_type = i64, example = 6540)]
     pub amount: MinorUnit,
     /// Reason for incremental authorization
@@ -9044,6 +9189,163 @@ pub struct PaymentLinkListResponse {
     pub data: Vec<PaymentLinkResponse>,
 }
 
+/// Request to create a standalone shareable payment link
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentLinkCreateRequest {
+    /// The amount to be collected through the payment link, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+
+    /// The three-letter ISO 4217 currency code of the amount
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// Description shown to the customer on the hosted payment page
+    #[schema(example = "Invoice #1234")]
+    pub description: Option<String>,
+
+    /// Number of seconds after which the payment link expires. Defaults to the session expiry configured on the business profile
+    #[schema(example = 900)]
+    pub session_expiry: Option<u32>,
+
+    /// Restricts the payment method types offered on the hosted payment page
+    #[schema(value_type = Option<Vec<PaymentMethodType>>)]
+    pub allowed_payment_method_types: Option<Vec<api_enums::PaymentMethodType>>,
+
+    /// Branding and layout of the hosted payment page. Fields which are not provided fall back to the business profile configuration
+    #[schema(value_type = Option<PaymentLinkConfigRequest>)]
+    pub payment_link_config: Option<admin::PaymentLinkConfigRequest>,
+
+    /// The business profile to create the payment link under
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+
+    /// The URL to redirect the customer to once the payment is completed
+    #[schema(value_type = Option<String>, example = "https://hyperswitch.io", max_length = 2048)]
+    pub return_url: Option<Url>,
+}
+
+/// Request to update the description and branding of a payment link
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentLinkUpdateRequest {
+    /// Description shown to the customer on the hosted payment page
+    pub description: Option<String>,
+    /// Custom theme for the payment link
+    #[schema(value_type = Option<String>, max_length = 255, example = "#4E6ADD")]
+    pub theme: Option<String>,
+    /// Merchant display logo
+    #[schema(value_type = Option<String>, max_length = 255)]
+    pub logo: Option<String>,
+    /// Merchant name displayed on the payment link
+    #[schema(value_type = Option<String>, max_length = 255, example = "hyperswitch")]
+    pub seller_name: Option<String>,
+    /// Custom background colour for the payment link
+    pub background_colour: Option<String>,
+    /// Text for payment link's handle confirm button
+    pub payment_button_text: Option<String>,
+    /// Custom background colour for payment link's handle confirm button
+    pub payment_button_colour: Option<String>,
+    /// Custom text colour for payment link's handle confirm button
+    pub payment_button_text_colour: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PaymentLinkStatusResponse {
+    /// Identifier for Payment Link
+    pub payment_link_id: String,
+    /// Identifier of the payment backing the payment link
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// Status of the payment link
+    pub status: PaymentLinkStatus,
+    /// Status of the payment backing the payment link
+    #[schema(value_type = IntentStatus)]
+    pub payment_status: api_enums::IntentStatus,
+    /// Date and time of Expiration for Payment Link
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub expiry: Option<PrimitiveDateTime>,
+}
+
+/// Request to create and confirm a batch of payments
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentsBatchRequest {
+    /// The payments of the batch, processed concurrently
+    pub items: Vec<PaymentsBatchItemRequest>,
+}
+
+/// A single payment of a batch
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentsBatchItemRequest {
+    /// Unique key of the payment, used as its `payment_id`. Submitting a key again returns the
+    /// payment created for it instead of creating another one.
+    #[schema(max_length = 64, example = "debit_2025_10_17_cus_123")]
+    pub idempotency_key: String,
+
+    /// The payment to create. It is confirmed unless `confirm` is explicitly set to false.
+    #[schema(value_type = PaymentsCreateRequest)]
+    pub payment: PaymentsRequest,
+}
+
+/// A row of a CSV upload of a batch of payments. Rows are charged off-session with a saved
+/// payment method or mandate of the customer.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct PaymentsBatchCsvRecord {
+    pub idempotency_key: String,
+    pub amount: MinorUnit,
+    pub currency: api_enums::Currency,
+    pub customer_id: id_type::CustomerId,
+    pub payment_method_id: Option<String>,
+    pub mandate_id: Option<String>,
+    pub description: Option<String>,
+}
+
+/// Processing status and per payment outcomes of a batch of payments
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentsBatchResponse {
+    /// Identifier of the batch
+    pub batch_id: String,
+    /// Processing status of the batch
+    #[schema(value_type = PaymentBatchStatus)]
+    pub status: api_enums::PaymentBatchStatus,
+    /// Number of payments in the batch
+    pub total_count: i32,
+    /// Number of payments which were created
+    pub processed_count: usize,
+    /// Number of payments which could not be created
+    pub failed_count: usize,
+    /// Outcome of every payment, in the order they were submitted
+    pub items: Vec<PaymentsBatchItemResponse>,
+    /// Date and time at which the batch was submitted
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+/// Outcome of a single payment of a batch
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentsBatchItemResponse {
+    /// Key the payment was submitted with
+    pub idempotency_key: String,
+    /// Outcome of the payment within the batch
+    #[schema(value_type = PaymentBatchItemStatus)]
+    pub status: api_enums::PaymentBatchItemStatus,
+    /// Identifier of the payment, once created
+    #[schema(value_type = Option<String>)]
+    pub payment_id: Option<id_type::PaymentId>,
+    /// Status of the payment, once created
+    #[schema(value_type = Option<IntentStatus>)]
+    pub payment_status: Option<api_enums::IntentStatus>,
+    /// Error code, if the payment could not be created
+    pub error_code: Option<String>,
+    /// Error message, if the payment could not be created
+    pub error_message: Option<String>,
+}
+
 /// Configure a custom payment link for the particular payment
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, PartialEq, ToSchema)]
 pub struct PaymentCreatePaymentLinkConfig {
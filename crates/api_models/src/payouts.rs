@@ -152,6 +152,10 @@ pub struct PayoutCreateRequest {
     #[schema(example = "187282ab-40ef-47a9-9206-5099ba31e432", value_type = Option<String>)]
     pub payout_token: Option<String>,
 
+    /// Identifier of a stored beneficiary, whose payout method data is used for the payout instead of `payout_method_data`
+    #[schema(example = "ben_2bdacf398vwzq5n422S1", value_type = Option<String>)]
+    pub beneficiary_id: Option<String>,
+
     /// The business profile to use for this payout, especially if there are multiple business profiles associated with the account, otherwise default business profile associated with the merchant account will be used.
     #[schema(value_type = Option<String>)]
     pub profile_id: Option<id_type::ProfileId>,
@@ -823,6 +827,165 @@ pub struct PayoutListFilters {
     pub payout_method: Vec<common_enums::PayoutType>,
 }
 
+/// Request to execute a batch of payouts at a scheduled cutoff
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBatchCreateRequest {
+    /// The payouts of the batch, which must be awaiting either confirmation or fulfillment
+    #[schema(value_type = Vec<String>, example = json!(["187282ab-40ef-47a9-9206-5099ba31e432"]))]
+    pub payout_ids: Vec<id_type::PayoutId>,
+
+    /// Date and time at which the payouts of the batch are executed. Defaults to the next
+    /// batching cutoff of the server.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2025-10-21T16:00:00Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub scheduled_at: Option<PrimitiveDateTime>,
+}
+
+/// Execution status and per payout outcomes of a batch of payouts
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PayoutBatchResponse {
+    /// Identifier of the batch
+    pub batch_id: String,
+    /// Execution status of the batch
+    #[schema(value_type = PayoutBatchStatus)]
+    pub status: api_enums::PayoutBatchStatus,
+    /// Number of payouts in the batch
+    pub total_count: i32,
+    /// Number of payouts which were executed
+    pub processed_count: usize,
+    /// Number of payouts which could not be executed
+    pub failed_count: usize,
+    /// Outcome of every payout, in the order they were submitted
+    pub items: Vec<PayoutBatchItemResponse>,
+    /// Date and time at which the payouts of the batch are executed
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub scheduled_at: PrimitiveDateTime,
+    /// Date and time at which the batch was submitted
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+/// Outcome of a single payout of a batch
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PayoutBatchItemResponse {
+    /// Identifier of the payout
+    #[schema(value_type = String)]
+    pub payout_id: id_type::PayoutId,
+    /// Outcome of the payout within the batch
+    #[schema(value_type = PayoutBatchItemStatus)]
+    pub status: api_enums::PayoutBatchItemStatus,
+    /// Status of the payout, once executed
+    #[schema(value_type = Option<PayoutStatus>)]
+    pub payout_status: Option<api_enums::PayoutStatus>,
+    /// Connector the payout was executed with
+    pub connector: Option<String>,
+    /// Error code, if the payout could not be executed or failed at the connector
+    pub error_code: Option<String>,
+    /// Error message, if the payout could not be executed or failed at the connector
+    pub error_message: Option<String>,
+}
+
+/// Request to store the payout method details of a recipient of payouts
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BeneficiaryCreateRequest {
+    /// The customer the beneficiary belongs to
+    #[schema(value_type = Option<String>, max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<id_type::CustomerId>,
+
+    /// The bank account or wallet details of the beneficiary
+    #[schema(value_type = PayoutMethodData)]
+    pub payout_method_data: PayoutMethodData,
+
+    /// The KYC verification status of the beneficiary
+    #[schema(value_type = Option<BeneficiaryKycStatus>, example = "verified")]
+    pub kyc_status: Option<api_enums::BeneficiaryKycStatus>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+/// Request to update the details of a beneficiary
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BeneficiaryUpdateRequest {
+    /// The bank account or wallet details of the beneficiary
+    #[schema(value_type = Option<PayoutMethodData>)]
+    pub payout_method_data: Option<PayoutMethodData>,
+
+    /// The KYC verification status of the beneficiary
+    #[schema(value_type = Option<BeneficiaryKycStatus>, example = "verified")]
+    pub kyc_status: Option<api_enums::BeneficiaryKycStatus>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+/// A recipient of payouts, with its payout method details masked
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct BeneficiaryResponse {
+    /// Identifier of the beneficiary, which can be passed as `beneficiary_id` while creating a payout
+    #[schema(example = "ben_2bdacf398vwzq5n422S1")]
+    pub beneficiary_id: String,
+
+    /// The customer the beneficiary belongs to
+    #[schema(value_type = Option<String>, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<id_type::CustomerId>,
+
+    /// The payout method type of the beneficiary
+    #[schema(value_type = PayoutType, example = "bank")]
+    pub payout_type: api_enums::PayoutType,
+
+    /// The masked bank account or wallet details of the beneficiary
+    #[schema(value_type = Option<PayoutMethodDataResponse>)]
+    pub payout_method_data: Option<PayoutMethodDataResponse>,
+
+    /// The KYC verification status of the beneficiary
+    #[schema(value_type = BeneficiaryKycStatus, example = "verified")]
+    pub kyc_status: api_enums::BeneficiaryKycStatus,
+
+    /// Metadata of the beneficiary
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+
+    /// Date and time at which the beneficiary was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// Date and time at which the beneficiary was last modified
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+/// Constraints for listing the beneficiaries of a merchant
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BeneficiaryListConstraints {
+    /// Only list the beneficiaries of this customer
+    #[schema(value_type = Option<String>, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<id_type::CustomerId>,
+
+    /// The limit on the number of objects. The default limit is 10 and max limit is 100
+    #[schema(default = 10, maximum = 100)]
+    #[serde(default = "default_payouts_list_limit")]
+    pub limit: u32,
+
+    /// The starting point within a list of objects
+    pub offset: Option<u32>,
+}
+
+/// Response of deleting a beneficiary
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct BeneficiaryDeleteResponse {
+    /// Identifier of the deleted beneficiary
+    pub beneficiary_id: String,
+    /// Whether the beneficiary was deleted
+    pub deleted: bool,
+}
+
 #[derive(Clone, Debug, serde::Serialize, ToSchema)]
 pub struct PayoutLinkResponse {
     pub payout_link_id: String,
@@ -1,5 +1,6 @@
-use common_utils::{id_type, pii};
+use common_utils::{id_type, pii, types::MinorUnit};
 use masking::Secret;
+use time::{Date, PrimitiveDateTime};
 
 use crate::enums;
 
@@ -26,3 +27,114 @@ pub struct VerifyTokenResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub acl: Option<String>,
 }
+
+/// Details of a settlement report file uploaded for reconciliation
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct SettlementReportUploadRequest {
+    /// Connector which settled the transactions of the report
+    pub connector: String,
+    pub merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+    pub file_name: Option<String>,
+    /// Start of the period in which the payments settled by the report were made. Successful
+    /// payments of this period which are missing in the report are flagged.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub transactions_from: Option<PrimitiveDateTime>,
+    /// End of the period in which the payments settled by the report were made
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub transactions_to: Option<PrimitiveDateTime>,
+}
+
+/// Downloads the settlement report of a day from the connector of a merchant connector account
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct SettlementReportPullRequest {
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+    /// Day whose settlement report is downloaded
+    pub report_date: Date,
+    /// Start of the period in which the payments settled by the report were made. Successful
+    /// payments of this period which are missing in the report are flagged.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub transactions_from: Option<PrimitiveDateTime>,
+    /// End of the period in which the payments settled by the report were made
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub transactions_to: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct SettlementReportResponse {
+    pub report_id: String,
+    pub connector: String,
+    pub merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+    pub source: enums::SettlementReportSource,
+    pub file_name: Option<String>,
+    pub status: enums::SettlementReportStatus,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub transactions_from: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub transactions_to: Option<PrimitiveDateTime>,
+    /// Number of transactions which were reconciled
+    pub total_count: i32,
+    /// Number of transactions which match the transactions recorded by Hyperswitch
+    pub matched_count: i32,
+    /// Number of transactions whose amount, currency or status differ from the transactions
+    /// recorded by Hyperswitch
+    pub mismatched_count: i32,
+    /// Number of transactions missing either in the report or in Hyperswitch
+    pub missing_count: i32,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+/// Outcome of reconciling a single transaction
+#[derive(Debug, serde::Serialize)]
+pub struct ReconRecordResponse {
+    pub record_id: String,
+    pub report_id: String,
+    pub connector: String,
+    pub transaction_type: enums::ReconTransactionType,
+    pub connector_transaction_id: Option<String>,
+    pub payment_id: Option<id_type::PaymentId>,
+    pub attempt_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub recon_status: enums::SettlementReconStatus,
+    /// Amount settled by the connector
+    pub settled_amount: Option<MinorUnit>,
+    pub settled_currency: Option<enums::Currency>,
+    /// Amount recorded by Hyperswitch
+    pub expected_amount: Option<MinorUnit>,
+    pub expected_currency: Option<enums::Currency>,
+    /// Fee charged by the connector
+    pub fee: Option<MinorUnit>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub settled_at: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ReconExceptionListRequest {
+    /// Only lists the exceptions of this settlement report
+    pub report_id: Option<String>,
+    /// Only lists the exceptions with this status
+    pub recon_status: Option<enums::SettlementReconStatus>,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+/// Transactions which did not match, most recent first
+#[derive(Debug, serde::Serialize)]
+pub struct ReconExceptionListResponse {
+    pub count: usize,
+    pub data: Vec<ReconRecordResponse>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct PaymentReconStatusResponse {
+    pub payment_id: id_type::PaymentId,
+    /// Outcome of the latest reconciliation of the payment, if it has been reconciled
+    pub recon_status: Option<enums::SettlementReconStatus>,
+    /// Outcomes of reconciling the payment and its refunds, most recent first
+    pub records: Vec<ReconRecordResponse>,
+}
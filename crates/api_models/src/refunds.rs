@@ -59,6 +59,11 @@ pub struct RefundRequest {
     /// Charge specific fields for controlling the revert of funds from either platform or connected account
     #[schema(value_type = Option<SplitRefund>)]
     pub split_refunds: Option<common_types::refunds::SplitRefund>,
+
+    /// Time at which the refund is to be sent to the connector. The refund is in the `scheduled` state, and can be cancelled, until then
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub execute_at: Option<PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v2")]
@@ -183,6 +188,34 @@ pub struct RefundUpdateRequest {
     pub metadata: Option<pii::SecretSerdeValue>,
 }
 
+#[cfg(feature = "v1")]
+#[derive(Default, Debug, Clone, Deserialize, Serialize)]
+pub struct RefundCancelRequest {
+    /// The identifier of the scheduled refund to be cancelled
+    pub refund_id: String,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Default, Debug, Clone, Deserialize, Serialize)]
+pub struct RefundApproveRequest {
+    /// The identifier of the refund pending approval
+    pub refund_id: String,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Default, Debug, ToSchema, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RefundRejectRequest {
+    #[serde(skip)]
+    pub refund_id: String,
+    /// The reason for rejecting the refund
+    #[schema(
+        max_length = 255,
+        example = "Refund amount does not match the returned items"
+    )]
+    pub reason: String,
+}
+
 #[cfg(feature = "v2")]
 #[derive(Default, Debug, ToSchema, Clone, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
@@ -391,6 +424,11 @@ pub struct RefundListRequest {
     /// The list of refund statuses to filter refunds list
     #[schema(value_type = Option<Vec<RefundStatus>>)]
     pub refund_status: Option<Vec<enums::RefundStatus>>,
+    /// An opaque cursor returned as `next_cursor` by a previous list call, to fetch the page after it. Takes precedence over `offset`
+    #[schema(value_type = Option<String>)]
+    pub cursor: Option<common_utils::types::ListCursor>,
+    /// The identifier of a saved list view, whose filters are applied to the fields not set in the request
+    pub view_id: Option<String>,
 }
 #[cfg(feature = "v2")]
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
@@ -430,6 +468,10 @@ pub struct RefundListResponse {
     pub total_count: i64,
     /// The List of refund response object
     pub data: Vec<RefundResponse>,
+    /// The cursor to pass to fetch the next page, present only when more refunds may be available
+    #[schema(value_type = Option<String>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<common_utils::types::ListCursor>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq, ToSchema)]
@@ -483,6 +525,9 @@ pub enum RefundStatus {
     #[default]
     Pending,
     Review,
+    Scheduled,
+    Cancelled,
+    PendingApproval,
 }
 
 impl From<enums::RefundStatus> for RefundStatus {
@@ -492,6 +537,9 @@ impl From<enums::RefundStatus> for RefundStatus {
             enums::RefundStatus::ManualReview => Self::Review,
             enums::RefundStatus::Pending => Self::Pending,
             enums::RefundStatus::Success => Self::Succeeded,
+            enums::RefundStatus::Scheduled => Self::Scheduled,
+            enums::RefundStatus::Cancelled => Self::Cancelled,
+            enums::RefundStatus::PendingApproval => Self::PendingApproval,
         }
     }
 }
@@ -503,6 +551,9 @@ impl From<RefundStatus> for enums::RefundStatus {
             RefundStatus::Review => Self::ManualReview,
             RefundStatus::Pending => Self::Pending,
             RefundStatus::Succeeded => Self::Success,
+            RefundStatus::Scheduled => Self::Scheduled,
+            RefundStatus::Cancelled => Self::Cancelled,
+            RefundStatus::PendingApproval => Self::PendingApproval,
         }
     }
 }
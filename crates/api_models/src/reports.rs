@@ -0,0 +1,150 @@
+use common_enums::{
+    ReportDeliveryMethod, ReportDomain, ReportFormat, ReportFrequency, ReportRunStatus,
+};
+use common_utils::{pii, types::TimeRange};
+use utoipa::ToSchema;
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// A report of the analytics metrics of a domain, which is generated for the last period on the
+/// given frequency and delivered by the given method
+pub struct ReportTemplateCreateRequest {
+    /// The name of the report
+    #[schema(example = "Daily payments by connector")]
+    pub name: String,
+    /// The analytics domain whose metrics are included in the report
+    #[schema(example = "payments")]
+    pub domain: ReportDomain,
+    /// The metrics included in the report, as accepted by the metrics endpoint of the domain
+    #[schema(example = json!(["payment_success_rate", "payment_processed_amount"]))]
+    pub metrics: Vec<String>,
+    /// The dimensions the metrics are grouped by, as accepted by the metrics endpoint of the
+    /// domain. Each group is a row of the report.
+    #[serde(default)]
+    #[schema(example = json!(["connector", "currency"]))]
+    pub dimensions: Vec<String>,
+    /// The filters applied to the metrics, in the same format as the filters of the metrics
+    /// endpoint of the domain
+    #[schema(value_type = Option<Object>, example = json!({ "currency": ["EUR"] }))]
+    pub filters: Option<serde_json::Value>,
+    /// The file format of the report
+    pub format: ReportFormat,
+    /// How often the report is generated, each report covers the last complete period in UTC
+    pub frequency: ReportFrequency,
+    /// Where the report is delivered once generated
+    pub delivery_method: ReportDeliveryMethod,
+    /// The emails the report is sent to, required when the report is delivered by email
+    #[schema(value_type = Option<Vec<String>>, example = json!(["reports@example.com"]))]
+    pub recipients: Option<Vec<pii::Email>>,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReportTemplateUpdateRequest {
+    /// The new name of the report
+    pub name: Option<String>,
+    /// The metrics included in the report, these replace the existing metrics
+    pub metrics: Option<Vec<String>>,
+    /// The dimensions the metrics are grouped by, these replace the existing dimensions
+    pub dimensions: Option<Vec<String>>,
+    /// The filters applied to the metrics, these replace the existing filters
+    #[schema(value_type = Option<Object>)]
+    pub filters: Option<serde_json::Value>,
+    pub format: Option<ReportFormat>,
+    /// How often the report is generated, the change applies from the next scheduled report
+    pub frequency: Option<ReportFrequency>,
+    pub delivery_method: Option<ReportDeliveryMethod>,
+    /// The emails the report is sent to, these replace the existing recipients
+    #[schema(value_type = Option<Vec<String>>)]
+    pub recipients: Option<Vec<pii::Email>>,
+    /// Whether the report is generated on its schedule, an inactive report can still be run on
+    /// demand
+    pub is_active: Option<bool>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct ReportTemplateResponse {
+    pub template_id: String,
+    #[schema(value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub name: String,
+    pub domain: ReportDomain,
+    pub metrics: Vec<String>,
+    pub dimensions: Vec<String>,
+    #[schema(value_type = Object)]
+    pub filters: serde_json::Value,
+    pub format: ReportFormat,
+    pub frequency: ReportFrequency,
+    pub delivery_method: ReportDeliveryMethod,
+    #[schema(value_type = Vec<String>)]
+    pub recipients: Vec<pii::Email>,
+    pub is_active: bool,
+    /// The user who created the report
+    pub created_by: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct ReportTemplateListResponse {
+    pub count: usize,
+    pub data: Vec<ReportTemplateResponse>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct ReportTemplateDeleteResponse {
+    pub template_id: String,
+    pub deleted: bool,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReportRunRequest {
+    /// The period covered by the report, the last complete period of the frequency of the report
+    /// when not given
+    pub time_range: Option<TimeRange>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+/// A generation of a report, scheduled or on demand
+pub struct ReportRunResponse {
+    pub run_id: String,
+    pub template_id: String,
+    #[schema(value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub status: ReportRunStatus,
+    /// Who triggered the run, `scheduler` for the scheduled runs
+    pub triggered_by: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub start_time: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub end_time: time::PrimitiveDateTime,
+    pub format: ReportFormat,
+    /// The number of rows in the report, present when the report was generated
+    pub row_count: Option<i64>,
+    /// Whether the report file can be downloaded
+    pub is_file_available: bool,
+    /// The reason the run failed, present when the run failed
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReportRunListQuery {
+    /// Only return the runs of this report
+    pub template_id: Option<String>,
+    /// The maximum number of runs to return
+    pub limit: Option<u32>,
+    /// The number of runs to skip
+    pub offset: Option<u32>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct ReportRunListResponse {
+    pub count: usize,
+    pub data: Vec<ReportRunResponse>,
+}
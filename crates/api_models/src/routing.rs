@@ -28,6 +28,8 @@ const DEFAULT_BUCKET_SIZE: i32 = 200;
 const DEFAULT_HEDGING_PERCENT: f64 = 5.0;
 const DEFAULT_ELIMINATION_THRESHOLD: f64 = 0.35;
 const DEFAULT_PAYMENT_METHOD: &str = "CARD";
+const DEFAULT_LATENCY_ROUTING_THRESHOLD_MS: u64 = 3000;
+const DEFAULT_LATENCY_ROUTING_MIN_SAMPLE_COUNT: usize = 20;
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(tag = "type", content = "data", rename_all = "snake_case")]
@@ -65,6 +67,11 @@ pub struct RoutingConfigRequest {
     #[schema(value_type = Option<String>)]
     pub profile_id: Option<common_utils::id_type::ProfileId>,
     pub transaction_type: Option<TransactionType>,
+    /// The time at which the created config should be activated for the profile.
+    /// If not provided, the config has to be activated by linking it explicitly.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub activate_at: Option<time::PrimitiveDateTime>,
 }
 
 #[derive(Debug, serde::Serialize, ToSchema)]
@@ -74,6 +81,23 @@ pub struct ProfileDefaultRoutingConfig {
     pub connectors: Vec<RoutableConnectorChoice>,
 }
 
+/// The default fallback connectors of a profile, used for the payments of a payment method type
+/// in place of the default fallback connectors of the profile
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct PaymentMethodDefaultRoutingConfig {
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    #[schema(value_type = PaymentMethodType)]
+    pub payment_method_type: common_enums::PaymentMethodType,
+    pub connectors: Vec<RoutableConnectorChoice>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PaymentMethodDefaultRoutingConfigQuery {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub payment_method_type: common_enums::PaymentMethodType,
+}
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 pub struct RoutingRetrieveQuery {
     pub limit: Option<u16>,
@@ -139,6 +163,7 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::CryptoType,
         DirKeyKind::MetaData,
         DirKeyKind::PaymentAmount,
+        DirKeyKind::NormalizedAmount,
         DirKeyKind::PaymentCurrency,
         DirKeyKind::AuthenticationType,
         DirKeyKind::MandateAcceptanceType,
@@ -147,6 +172,8 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::SetupFutureUsage,
         DirKeyKind::CaptureMethod,
         DirKeyKind::BillingCountry,
+        DirKeyKind::IpCountry,
+        DirKeyKind::BillingIpCountryMatch,
         DirKeyKind::BusinessCountry,
         DirKeyKind::BusinessLabel,
         DirKeyKind::MetaData,
@@ -305,6 +332,7 @@ pub enum RoutingAlgorithmKind {
     Advanced,
     Dynamic,
     ThreeDsDecisionRule,
+    Cost,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -338,10 +366,17 @@ pub enum StaticRoutingAlgorithm {
     Single(Box<RoutableConnectorChoice>),
     Priority(Vec<RoutableConnectorChoice>),
     VolumeSplit(Vec<ConnectorVolumeSplit>),
+    /// Volume split in which the connector is picked by hashing the customer id of the payment,
+    /// so that the payments of a customer consistently land on the same connector
+    StickyVolumeSplit(Vec<ConnectorVolumeSplit>),
     #[schema(value_type=ProgramConnectorSelection)]
     Advanced(Program<ConnectorSelection>),
     #[schema(value_type=ProgramThreeDsDecisionRule)]
     ThreeDsDecisionRule(Program<ThreeDSDecisionRule>),
+    /// Connectors ordered by the fee charged by each of them for the payment, as per the fee
+    /// schedules of their merchant connector accounts. Connectors without an applicable fee rule
+    /// are tried after the priced ones, in the given order.
+    Cost(Vec<RoutableConnectorChoice>),
 }
 
 #[derive(Serialize, Deserialize, ToSchema)]
@@ -366,7 +401,12 @@ pub struct RuleThreeDsDecisionRule {
 impl StaticRoutingAlgorithm {
     pub fn should_validate_connectors_in_routing_config(&self) -> bool {
         match self {
-            Self::Single(_) | Self::Priority(_) | Self::VolumeSplit(_) | Self::Advanced(_) => true,
+            Self::Single(_)
+            | Self::Priority(_)
+            | Self::VolumeSplit(_)
+            | Self::StickyVolumeSplit(_)
+            | Self::Advanced(_)
+            | Self::Cost(_) => true,
             Self::ThreeDsDecisionRule(_) => false,
         }
     }
@@ -378,8 +418,10 @@ pub enum RoutingAlgorithmSerde {
     Single(Box<RoutableConnectorChoice>),
     Priority(Vec<RoutableConnectorChoice>),
     VolumeSplit(Vec<ConnectorVolumeSplit>),
+    StickyVolumeSplit(Vec<ConnectorVolumeSplit>),
     Advanced(Program<ConnectorSelection>),
     ThreeDsDecisionRule(Program<ThreeDSDecisionRule>),
+    Cost(Vec<RoutableConnectorChoice>),
 }
 
 impl TryFrom<RoutingAlgorithmSerde> for StaticRoutingAlgorithm {
@@ -392,7 +434,14 @@ impl TryFrom<RoutingAlgorithmSerde> for StaticRoutingAlgorithm {
                     "Connectors list can't be empty for Priority Algorithm",
                 ))?
             }
-            RoutingAlgorithmSerde::VolumeSplit(i) if i.is_empty() => {
+            RoutingAlgorithmSerde::Cost(i) if i.is_empty() => {
+                Err(ParsingError::StructParseFailure(
+                    "Connectors list can't be empty for Cost Algorithm",
+                ))?
+            }
+            RoutingAlgorithmSerde::VolumeSplit(i) | RoutingAlgorithmSerde::StickyVolumeSplit(i)
+                if i.is_empty() =>
+            {
                 Err(ParsingError::StructParseFailure(
                     "Connectors list can't be empty for Volume split Algorithm",
                 ))?
@@ -403,8 +452,10 @@ impl TryFrom<RoutingAlgorithmSerde> for StaticRoutingAlgorithm {
             RoutingAlgorithmSerde::Single(i) => Self::Single(i),
             RoutingAlgorithmSerde::Priority(i) => Self::Priority(i),
             RoutingAlgorithmSerde::VolumeSplit(i) => Self::VolumeSplit(i),
+            RoutingAlgorithmSerde::StickyVolumeSplit(i) => Self::StickyVolumeSplit(i),
             RoutingAlgorithmSerde::Advanced(i) => Self::Advanced(i),
             RoutingAlgorithmSerde::ThreeDsDecisionRule(i) => Self::ThreeDsDecisionRule(i),
+            RoutingAlgorithmSerde::Cost(i) => Self::Cost(i),
         })
     }
 }
@@ -505,9 +556,10 @@ impl StaticRoutingAlgorithm {
         match self {
             Self::Single(_) => RoutingAlgorithmKind::Single,
             Self::Priority(_) => RoutingAlgorithmKind::Priority,
-            Self::VolumeSplit(_) => RoutingAlgorithmKind::VolumeSplit,
+            Self::VolumeSplit(_) | Self::StickyVolumeSplit(_) => RoutingAlgorithmKind::VolumeSplit,
             Self::Advanced(_) => RoutingAlgorithmKind::Advanced,
             Self::ThreeDsDecisionRule(_) => RoutingAlgorithmKind::ThreeDsDecisionRule,
+            Self::Cost(_) => RoutingAlgorithmKind::Cost,
         }
     }
 }
@@ -518,6 +570,7 @@ pub struct RoutingAlgorithmRef {
     pub timestamp: i64,
     pub config_algo_id: Option<String>,
     pub surcharge_config_algo_id: Option<String>,
+    pub three_ds_decision_config_algo_id: Option<String>,
 }
 
 impl RoutingAlgorithmRef {
@@ -536,6 +589,11 @@ impl RoutingAlgorithmRef {
         self.timestamp = common_utils::date_time::now_unix_timestamp();
     }
 
+    pub fn update_three_ds_decision_config_id(&mut self, ids: String) {
+        self.three_ds_decision_config_algo_id = Some(ids);
+        self.timestamp = common_utils::date_time::now_unix_timestamp();
+    }
+
     pub fn parse_routing_algorithm(
         value: Option<pii::SecretSerdeValue>,
     ) -> Result<Option<Self>, error_stack::Report<ParsingError>> {
@@ -587,6 +645,188 @@ pub struct RoutingLinkWrapper {
     pub algorithm_id: RoutingAlgorithmId,
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+/// A single activation of a routing algorithm under a profile
+pub struct RoutingConfigVersion {
+    /// Monotonically increasing version number of the activation under the profile
+    pub version: u32,
+    #[schema(value_type = String)]
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    /// Unix timestamp of when this version was activated
+    pub activated_at: i64,
+    /// The version which was restored, if this version was created by a rollback
+    pub rolled_back_from: Option<u32>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+/// Activation history of the routing algorithms under a profile
+pub struct RoutingVersionHistory {
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub transaction_type: TransactionType,
+    pub versions: Vec<RoutingConfigVersion>,
+}
+
+impl RoutingVersionHistory {
+    pub fn new(
+        profile_id: common_utils::id_type::ProfileId,
+        transaction_type: TransactionType,
+    ) -> Self {
+        Self {
+            profile_id,
+            transaction_type,
+            versions: Vec::new(),
+        }
+    }
+
+    pub fn get_version(&self, version: u32) -> Option<&RoutingConfigVersion> {
+        self.versions
+            .iter()
+            .find(|record| record.version == version)
+    }
+
+    pub fn get_latest_version(&self) -> Option<&RoutingConfigVersion> {
+        self.versions.iter().max_by_key(|record| record.version)
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct RoutingVersionHistoryRequest {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub transaction_type: Option<TransactionType>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct RoutingRollbackRequest {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub version: u32,
+    pub transaction_type: Option<TransactionType>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RoutingActivationStatus {
+    /// The routing algorithm is yet to be activated at the scheduled time
+    Scheduled,
+    /// The routing algorithm was activated at the scheduled time
+    Completed,
+    /// The routing algorithm could not be activated at the scheduled time
+    Failed,
+    /// The activation was cancelled before the scheduled time
+    Cancelled,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+/// An activation of a routing algorithm which was scheduled while creating the algorithm
+pub struct RoutingScheduledActivation {
+    #[schema(value_type = String)]
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    pub transaction_type: TransactionType,
+    /// The time at which the routing algorithm is to be activated
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub activate_at: time::PrimitiveDateTime,
+    pub status: RoutingActivationStatus,
+    /// The reason for which the activation failed
+    pub failure_reason: Option<String>,
+}
+
+impl RoutingScheduledActivation {
+    pub fn is_scheduled(&self) -> bool {
+        self.status == RoutingActivationStatus::Scheduled
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+/// Scheduled activations of the routing algorithms under a profile, oldest first
+pub struct RoutingScheduledActivations {
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub activations: Vec<RoutingScheduledActivation>,
+}
+
+impl RoutingScheduledActivations {
+    pub fn new(profile_id: common_utils::id_type::ProfileId) -> Self {
+        Self {
+            profile_id,
+            activations: Vec::new(),
+        }
+    }
+
+    pub fn get_activation_mut(
+        &mut self,
+        algorithm_id: &common_utils::id_type::RoutingId,
+    ) -> Option<&mut RoutingScheduledActivation> {
+        self.activations
+            .iter_mut()
+            .find(|activation| &activation.algorithm_id == algorithm_id)
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct RoutingScheduledActivationRequest {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub algorithm_id: common_utils::id_type::RoutingId,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+/// Request to simulate the connector selection of a routing algorithm without creating a payment
+pub struct RoutingSimulateRequest {
+    /// The routing algorithm to be simulated
+    #[schema(value_type = String)]
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    /// Synthetic payment attributes against which the algorithm is evaluated
+    pub payment: RoutingSimulatePaymentData,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+/// Payment attributes which are made available to the routing algorithm during a simulation
+pub struct RoutingSimulatePaymentData {
+    /// The payment amount in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: common_utils::types::MinorUnit,
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: common_enums::Currency,
+    #[schema(value_type = Option<PaymentMethod>, example = "card")]
+    pub payment_method: Option<common_enums::PaymentMethod>,
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<common_enums::PaymentMethodType>,
+    #[schema(value_type = Option<CardNetwork>, example = "Visa")]
+    pub card_network: Option<common_enums::CardNetwork>,
+    /// The first six digits of the card number
+    #[schema(example = "424242")]
+    pub card_bin: Option<String>,
+    #[schema(value_type = Option<AuthenticationType>, example = "no_three_ds")]
+    pub authentication_type: Option<common_enums::AuthenticationType>,
+    #[schema(value_type = Option<CaptureMethod>, example = "automatic")]
+    pub capture_method: Option<common_enums::CaptureMethod>,
+    #[schema(value_type = Option<CountryAlpha2>, example = "US")]
+    pub business_country: Option<common_enums::CountryAlpha2>,
+    #[schema(value_type = Option<CountryAlpha2>, example = "US")]
+    pub billing_country: Option<common_enums::CountryAlpha2>,
+    /// The country resolved from the customer's IP address
+    #[schema(value_type = Option<CountryAlpha2>, example = "DE")]
+    pub ip_country: Option<common_enums::CountryAlpha2>,
+    pub business_label: Option<String>,
+    #[schema(value_type = Option<FutureUsage>, example = "off_session")]
+    pub setup_future_usage: Option<common_enums::FutureUsage>,
+    /// Key-value pairs made available to metadata based routing rules
+    pub metadata: Option<std::collections::HashMap<String, String>>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+/// Connector ordering produced by simulating a routing algorithm
+pub struct RoutingSimulateResponse {
+    #[schema(value_type = String)]
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    pub kind: RoutingAlgorithmKind,
+    /// Connectors in the order in which they would be attempted for the payment. When the volume is
+    /// split, every connector of the split is listed, from the largest share of the volume.
+    pub connectors: Vec<RoutableConnectorChoice>,
+    /// Configured share of the volume of each connector, when the volume is split
+    pub volume_splits: Option<Vec<ConnectorVolumeSplit>>,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct DynamicAlgorithmWithTimestamp<T> {
     pub algorithm_id: Option<T>,
@@ -808,6 +1048,27 @@ impl SuccessBasedAlgorithm {
 }
 
 impl DynamicRoutingAlgorithmRef {
+    /// Provides the id of the active algorithm of the specified dynamic routing type, if any
+    pub fn get_algorithm_id(
+        &self,
+        dynamic_routing_type: DynamicRoutingType,
+    ) -> Option<common_utils::id_type::RoutingId> {
+        match dynamic_routing_type {
+            DynamicRoutingType::SuccessRateBasedRouting => self
+                .success_based_algorithm
+                .as_ref()
+                .and_then(|algo| algo.algorithm_id_with_timestamp.algorithm_id.clone()),
+            DynamicRoutingType::EliminationRouting => self
+                .elimination_routing_algorithm
+                .as_ref()
+                .and_then(|algo| algo.algorithm_id_with_timestamp.algorithm_id.clone()),
+            DynamicRoutingType::ContractBasedRouting => self
+                .contract_based_routing
+                .as_ref()
+                .and_then(|algo| algo.algorithm_id_with_timestamp.algorithm_id.clone()),
+        }
+    }
+
     pub fn update_algorithm_id(
         &mut self,
         new_id: common_utils::id_type::RoutingId,
@@ -958,6 +1219,523 @@ pub struct RoutingVolumeSplitResponse {
     pub split: u8,
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// Latency based routing deprioritizes connectors whose p95 authorization latency exceeds the
+/// configured threshold, by moving them to the end of the routing output
+pub struct LatencyBasedRoutingConfig {
+    /// Whether latency based routing is applied to the payments of the profile
+    pub enabled: bool,
+    /// Connectors whose p95 authorization latency (in milliseconds) exceeds this value are deprioritized
+    #[schema(example = 3000)]
+    pub latency_threshold_ms: u64,
+    /// Minimum number of recorded authorizations required before a connector can be deprioritized
+    #[schema(example = 20)]
+    pub min_sample_count: Option<usize>,
+}
+
+impl Default for LatencyBasedRoutingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            latency_threshold_ms: DEFAULT_LATENCY_ROUTING_THRESHOLD_MS,
+            min_sample_count: None,
+        }
+    }
+}
+
+impl LatencyBasedRoutingConfig {
+    pub fn get_min_sample_count(&self) -> usize {
+        self.min_sample_count
+            .unwrap_or(DEFAULT_LATENCY_ROUTING_MIN_SAMPLE_COUNT)
+    }
+
+    /// A connector is considered slow only when enough samples have been recorded for it
+    pub fn is_latency_above_threshold(&self, latency_stats: &ConnectorLatencyStats) -> bool {
+        latency_stats.sample_count >= self.get_min_sample_count()
+            && latency_stats
+                .p95_latency_ms
+                .is_some_and(|latency| latency > self.latency_threshold_ms)
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct LatencyBasedRoutingConfigWrapper {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub config: LatencyBasedRoutingConfig,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+/// Recent authorization latency of a connector under a profile
+pub struct ConnectorLatencyStats {
+    pub connector: RoutableConnectorChoice,
+    /// p95 of the recorded authorization latencies in milliseconds
+    pub p95_latency_ms: Option<u64>,
+    /// Number of authorizations considered for the latency computation
+    pub sample_count: usize,
+    /// Whether the connector is currently deprioritized by latency based routing
+    pub is_deprioritized: bool,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct LatencyBasedRoutingResponse {
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub config: LatencyBasedRoutingConfig,
+    pub connector_latencies: Vec<ConnectorLatencyStats>,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+/// State of the health circuit breaker of a merchant connector account
+pub enum ConnectorCircuitBreakerState {
+    /// The connector is healthy and receives traffic
+    Closed,
+    /// The error rate of the connector exceeded the threshold, it is skipped during routing
+    Open,
+    /// The open period has elapsed, a single trial request is routed to the connector and the
+    /// breaker closes if it succeeds or reopens if it fails
+    HalfOpen,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+/// Health of a merchant connector account as tracked by the connector health monitor
+pub struct ConnectorHealthStatus {
+    #[schema(value_type = Connector)]
+    pub connector: RoutableConnectors,
+    #[schema(value_type = String)]
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub state: ConnectorCircuitBreakerState,
+    /// Number of requests to the connector recorded in the current window
+    pub request_count: u64,
+    /// Number of failed requests to the connector recorded in the current window
+    pub failure_count: u64,
+    /// The time at which the breaker was last opened
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub opened_at: Option<time::PrimitiveDateTime>,
+    /// The time until which the connector is skipped during routing
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub open_until: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct ConnectorHealthQuery {
+    /// Only include the connectors of the specified business profile
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct ConnectorHealthResponse {
+    /// Whether connectors with an open breaker are skipped during routing
+    pub enabled: bool,
+    pub connectors: Vec<ConnectorHealthStatus>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy, Default, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// A single component of the fee charged for a payment
+pub struct FeeComponent {
+    /// Fee charged as a percentage of the payment amount, in basis points
+    #[serde(default)]
+    #[schema(example = 150)]
+    pub percentage_bps: u32,
+    /// Fee charged per payment in the lowest denomination of the payment currency
+    #[serde(default)]
+    #[schema(value_type = i64, example = 10)]
+    pub fixed_amount: common_utils::types::MinorUnit,
+}
+
+impl FeeComponent {
+    pub fn calculate_fee(
+        self,
+        amount: common_utils::types::MinorUnit,
+    ) -> common_utils::types::MinorUnit {
+        let percentage_fee = amount
+            .get_amount_as_i64()
+            .saturating_mul(i64::from(self.percentage_bps))
+            / 10_000;
+        common_utils::types::MinorUnit::new(
+            percentage_fee.saturating_add(self.fixed_amount.get_amount_as_i64()),
+        )
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// Fee charged by a connector for the payments matching the card network, region and currency
+/// of the rule. Filters which are not set match every payment.
+pub struct ConnectorFeeRule {
+    #[schema(value_type = Option<CardNetwork>, example = "Visa")]
+    pub card_network: Option<common_enums::CardNetwork>,
+    /// Billing countries of the payments to which the rule applies
+    #[schema(value_type = Option<Vec<CountryAlpha2>>, example = json!(["US", "CA"]))]
+    pub countries: Option<Vec<common_enums::CountryAlpha2>>,
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<common_enums::Currency>,
+    #[serde(default)]
+    pub interchange: FeeComponent,
+    #[serde(default)]
+    pub scheme: FeeComponent,
+    #[serde(default)]
+    pub markup: FeeComponent,
+}
+
+impl ConnectorFeeRule {
+    pub fn is_applicable(
+        &self,
+        currency: common_enums::Currency,
+        card_network: Option<&common_enums::CardNetwork>,
+        billing_country: Option<common_enums::CountryAlpha2>,
+    ) -> bool {
+        self.currency
+            .is_none_or(|rule_currency| rule_currency == currency)
+            && self
+                .card_network
+                .as_ref()
+                .is_none_or(|rule_network| Some(rule_network) == card_network)
+            && self.countries.as_ref().is_none_or(|countries| {
+                billing_country.is_some_and(|country| countries.contains(&country))
+            })
+    }
+
+    pub fn calculate_fee(
+        &self,
+        amount: common_utils::types::MinorUnit,
+    ) -> common_utils::types::MinorUnit {
+        self.interchange.calculate_fee(amount)
+            + self.scheme.calculate_fee(amount)
+            + self.markup.calculate_fee(amount)
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorFeeScheduleRequest {
+    /// Fee rules of the connector, evaluated in order. The first applicable rule determines the
+    /// fee of a payment.
+    pub rules: Vec<ConnectorFeeRule>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorFeeScheduleWrapper {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    pub rules: Vec<ConnectorFeeRule>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorFeeScheduleQuery {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+/// Fee schedule of a merchant connector account, used by cost based routing
+pub struct ConnectorFeeSchedule {
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    #[schema(value_type = String)]
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    pub rules: Vec<ConnectorFeeRule>,
+    pub created_at: i64,
+    pub modified_at: i64,
+}
+
+impl ConnectorFeeSchedule {
+    /// Computes the fee of a payment as per the first applicable rule of the schedule
+    pub fn calculate_fee(
+        &self,
+        amount: common_utils::types::MinorUnit,
+        currency: common_enums::Currency,
+        card_network: Option<&common_enums::CardNetwork>,
+        billing_country: Option<common_enums::CountryAlpha2>,
+    ) -> Option<common_utils::types::MinorUnit> {
+        self.rules
+            .iter()
+            .find(|rule| rule.is_applicable(currency, card_network, billing_country))
+            .map(|rule| rule.calculate_fee(amount))
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+/// The constraints to apply when listing the routing audit log
+pub struct RoutingAuditLogQuery {
+    /// Filter the records of the specified business profile
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    /// Filter the records created at or after the specified time
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub start_time: Option<time::PrimitiveDateTime>,
+    /// Filter the records created at or before the specified time
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub end_time: Option<time::PrimitiveDateTime>,
+    /// Include at most the specified number of records
+    pub limit: Option<u16>,
+    /// Include records after the specified offset
+    pub offset: Option<u16>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+/// An immutable record of a change made to the routing configuration of a profile
+pub struct RoutingAuditLogRecord {
+    pub id: String,
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    #[schema(value_type = RoutingAuditAction)]
+    pub action: common_enums::RoutingAuditAction,
+    #[schema(value_type = TransactionType)]
+    pub transaction_type: TransactionType,
+    /// The routing algorithm which was active before the change
+    #[schema(value_type = Option<String>)]
+    pub previous_algorithm_id: Option<common_utils::id_type::RoutingId>,
+    /// The routing algorithm which was created or activated by the change
+    #[schema(value_type = Option<String>)]
+    pub new_algorithm_id: Option<common_utils::id_type::RoutingId>,
+    /// The changed fields of the routing config, keyed by their path, with the old and new values
+    #[schema(value_type = Option<Object>)]
+    pub config_diff: Option<serde_json::Value>,
+    /// The API key merchant or dashboard user that made the change
+    pub created_by: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct RoutingAuditLogResponse {
+    pub count: usize,
+    pub data: Vec<RoutingAuditLogRecord>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RoutingExperimentStatus {
+    /// Payments of the profile are being split between the arms of the experiment
+    Running,
+    /// The experiment has been stopped, payments are routed with the active routing algorithm
+    Stopped,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// Request to start a routing experiment between two routing algorithms of a profile
+pub struct RoutingExperimentRequest {
+    /// Name of the experiment
+    pub name: String,
+    /// The routing algorithm used for the control arm of the experiment
+    #[schema(value_type = String)]
+    pub control_algorithm_id: common_utils::id_type::RoutingId,
+    /// The routing algorithm used for the treatment arm of the experiment
+    #[schema(value_type = String)]
+    pub treatment_algorithm_id: common_utils::id_type::RoutingId,
+    /// The percentage of payments to be routed with the treatment routing algorithm
+    #[schema(maximum = 100, example = 10)]
+    pub treatment_percentage: u8,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct RoutingExperimentRequestWrapper {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub request: RoutingExperimentRequest,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+/// A routing experiment which splits the payments of a profile between two routing algorithms
+pub struct RoutingExperiment {
+    pub experiment_id: String,
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub name: String,
+    #[schema(value_type = String)]
+    pub control_algorithm_id: common_utils::id_type::RoutingId,
+    #[schema(value_type = String)]
+    pub treatment_algorithm_id: common_utils::id_type::RoutingId,
+    pub treatment_percentage: u8,
+    pub status: RoutingExperimentStatus,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub started_at: time::PrimitiveDateTime,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub stopped_at: Option<time::PrimitiveDateTime>,
+}
+
+impl RoutingExperiment {
+    pub fn is_running(&self) -> bool {
+        self.status == RoutingExperimentStatus::Running
+    }
+
+    pub fn get_algorithm_id(
+        &self,
+        arm: common_enums::RoutingExperimentArm,
+    ) -> &common_utils::id_type::RoutingId {
+        match arm {
+            common_enums::RoutingExperimentArm::Control => &self.control_algorithm_id,
+            common_enums::RoutingExperimentArm::Treatment => &self.treatment_algorithm_id,
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+/// The outcome of the payments routed with an arm of a routing experiment
+pub struct RoutingExperimentArmResult {
+    #[schema(value_type = RoutingExperimentArm)]
+    pub arm: common_enums::RoutingExperimentArm,
+    #[schema(value_type = String)]
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    /// The number of payments which reached a final status
+    pub total_payments: i64,
+    /// The number of payments which were authorized or charged
+    pub successful_payments: i64,
+    /// The percentage of payments which were authorized or charged
+    pub authorization_rate: Option<f64>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct RoutingExperimentResults {
+    pub experiment: RoutingExperiment,
+    pub arms: Vec<RoutingExperimentArmResult>,
+}
+
+/// The version of the routing config export document generated by this application
+pub const ROUTING_CONFIG_EXPORT_VERSION: u16 = 1;
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// A static routing algorithm of a profile, as included in a routing config export
+pub struct RoutingAlgorithmExport {
+    /// The identifier of the routing algorithm in the exporting profile
+    #[schema(value_type = String)]
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    pub name: String,
+    pub description: String,
+    pub algorithm: StaticRoutingAlgorithm,
+    #[schema(value_type = TransactionType)]
+    pub transaction_type: TransactionType,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// A portable document holding the full static routing configuration of a profile
+pub struct RoutingConfigExport {
+    /// The version of the export document format
+    pub version: u16,
+    /// The profile the routing configuration was exported from
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub exported_at: time::PrimitiveDateTime,
+    /// The static routing algorithms of the profile
+    pub algorithms: Vec<RoutingAlgorithmExport>,
+    /// The routing algorithm active for payments, one of `algorithms`
+    #[schema(value_type = Option<String>)]
+    pub active_payment_algorithm_id: Option<common_utils::id_type::RoutingId>,
+    /// The routing algorithm active for payouts, one of `algorithms`
+    #[schema(value_type = Option<String>)]
+    pub active_payout_algorithm_id: Option<common_utils::id_type::RoutingId>,
+    /// The default fallback connectors of the profile for payments
+    pub default_fallback_connectors: Vec<RoutableConnectorChoice>,
+    /// The default fallback connectors of the profile for specific payment method types
+    pub payment_method_default_fallback_connectors: Vec<PaymentMethodDefaultRoutingConfig>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// Request to import a routing config export into a profile
+pub struct RoutingConfigImportRequest {
+    pub config: RoutingConfigExport,
+    /// Merchant connector accounts of the exporting profile, mapped to the merchant connector
+    /// accounts of the importing profile that should replace them. Accounts which are not
+    /// mapped are replaced by the only account of the same connector in the importing profile.
+    #[schema(value_type = Option<HashMap<String, String>>)]
+    pub merchant_connector_id_mapping: Option<
+        std::collections::HashMap<
+            common_utils::id_type::MerchantConnectorAccountId,
+            common_utils::id_type::MerchantConnectorAccountId,
+        >,
+    >,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct RoutingConfigImportRequestWrapper {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub request: RoutingConfigImportRequest,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+/// A routing algorithm created by an import, along with the algorithm it was created from
+pub struct ImportedRoutingAlgorithm {
+    #[schema(value_type = String)]
+    pub source_algorithm_id: common_utils::id_type::RoutingId,
+    pub algorithm: RoutingDictionaryRecord,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct RoutingConfigImportResponse {
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub algorithms: Vec<ImportedRoutingAlgorithm>,
+    #[schema(value_type = Option<String>)]
+    pub active_payment_algorithm_id: Option<common_utils::id_type::RoutingId>,
+    #[schema(value_type = Option<String>)]
+    pub active_payout_algorithm_id: Option<common_utils::id_type::RoutingId>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct RoutingAlgorithmDiffRequest {
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    pub other_algorithm_id: common_utils::id_type::RoutingId,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+/// A rule which is present in only one of the compared routing algorithms
+pub struct RoutingRuleDiff {
+    /// The name of the rule, `default_selection` for the default connector selection
+    pub name: String,
+    #[schema(value_type = Object)]
+    pub rule: serde_json::Value,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+/// A rule which is present in both the compared routing algorithms, but differs between them
+pub struct RoutingRuleChange {
+    /// The name of the rule, `default_selection` for the default connector selection
+    pub name: String,
+    /// The fields of the rule which differ, keyed by their JSON path
+    #[schema(value_type = Object)]
+    pub changes: serde_json::Value,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+/// Structured diff of two routing algorithms, from `algorithm_id` to `other_algorithm_id`
+pub struct RoutingAlgorithmDiffResponse {
+    #[schema(value_type = String)]
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    #[schema(value_type = String)]
+    pub other_algorithm_id: common_utils::id_type::RoutingId,
+    pub algorithm_kind: RoutingAlgorithmKind,
+    pub other_algorithm_kind: RoutingAlgorithmKind,
+    /// Rules present only in `other_algorithm_id`
+    pub added: Vec<RoutingRuleDiff>,
+    /// Rules present only in `algorithm_id`
+    pub removed: Vec<RoutingRuleDiff>,
+    /// Rules present in both the algorithms with different definitions
+    pub changed: Vec<RoutingRuleChange>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, ToSchema)]
 #[serde(deny_unknown_fields)]
 pub struct EliminationRoutingConfig {
@@ -1609,3 +2387,64 @@ pub enum ComparisonType {
     GreaterThan,
     GreaterThanEqual,
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_utils::types::MinorUnit;
+
+    use super::*;
+
+    #[test]
+    fn test_connector_fee_schedule_calculation() {
+        let fee_schedule: ConnectorFeeSchedule = serde_json::from_value(serde_json::json!({
+            "profile_id": "pro_abcdefghijklmnop",
+            "merchant_connector_id": "mca_abcdefghijklmnop",
+            "rules": [
+                {
+                    "card_network": "Visa",
+                    "countries": ["US"],
+                    "interchange": { "percentage_bps": 150 },
+                    "scheme": { "percentage_bps": 10, "fixed_amount": 5 },
+                    "markup": { "fixed_amount": 20 }
+                },
+                {
+                    "currency": "USD",
+                    "markup": { "percentage_bps": 300 }
+                }
+            ],
+            "created_at": 0,
+            "modified_at": 0
+        }))
+        .unwrap();
+
+        let amount = MinorUnit::new(10_000);
+        assert_eq!(
+            fee_schedule.calculate_fee(
+                amount,
+                common_enums::Currency::USD,
+                Some(&common_enums::CardNetwork::Visa),
+                Some(common_enums::CountryAlpha2::US),
+            ),
+            Some(MinorUnit::new(185))
+        );
+        assert_eq!(
+            fee_schedule.calculate_fee(
+                amount,
+                common_enums::Currency::USD,
+                Some(&common_enums::CardNetwork::Visa),
+                Some(common_enums::CountryAlpha2::GB),
+            ),
+            Some(MinorUnit::new(300))
+        );
+        assert_eq!(
+            fee_schedule.calculate_fee(
+                amount,
+                common_enums::Currency::EUR,
+                Some(&common_enums::CardNetwork::Mastercard),
+                None,
+            ),
+            None
+        );
+    }
+}
@@ -1,7 +1,10 @@
-use common_utils::events::ApiEventMetric;
+use common_utils::{events::ApiEventMetric, types::MinorUnit};
 use masking::Secret;
+use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
+use crate::enums as api_enums;
+
 // use crate::{
 //     customers::{CustomerRequest, CustomerResponse},
 //     payments::CustomerDetailsResponse,
@@ -62,9 +65,13 @@ pub struct CreateSubscriptionResponse {
 /// Possible states of a subscription lifecycle.
 ///
 /// - `Created`: Subscription was created but not yet activated.
+/// - `Trial`: Subscription is in the trial period of its plan.
 /// - `Active`: Subscription is currently active.
-/// - `InActive`: Subscription is inactive (e.g., cancelled or expired).
-#[derive(Debug, Clone, serde::Serialize, strum::EnumString, strum::Display, ToSchema)]
+/// - `PastDue`: The payment of the current billing cycle failed and is being retried.
+/// - `InActive`: Subscription is inactive (e.g., cancelled, expired or payment retries exhausted).
+#[derive(
+    Debug, Clone, Copy, PartialEq, serde::Serialize, strum::EnumString, strum::Display, ToSchema,
+)]
 pub enum SubscriptionStatus {
     /// Subscription is active.
     Active,
@@ -74,6 +81,169 @@ pub enum SubscriptionStatus {
     InActive,
     /// Subscription is in pending state.
     Pending,
+    /// Subscription is in the trial period of its plan.
+    Trial,
+    /// The payment of the current billing cycle failed and is being retried.
+    PastDue,
+}
+
+impl SubscriptionStatus {
+    /// Whether the subscription should still be billed at the end of its current period.
+    pub fn is_billable(&self) -> bool {
+        matches!(self, Self::Active | Self::Trial | Self::PastDue)
+    }
+}
+
+/// Request payload for creating a subscription plan.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CreateSubscriptionPlanRequest {
+    /// Name of the plan.
+    pub name: String,
+
+    /// Amount charged on every billing cycle, in the lowest denomination of the currency.
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+
+    /// Currency of the amount charged on every billing cycle.
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// Unit of time after which the customer is billed again.
+    #[schema(value_type = SubscriptionBillingInterval, example = "month")]
+    pub billing_interval: api_enums::SubscriptionBillingInterval,
+
+    /// Number of `billing_interval` units between two billing cycles. Defaults to 1.
+    #[schema(example = 1)]
+    pub billing_interval_count: Option<u16>,
+
+    /// Number of days the customer is not billed for after attaching a payment method.
+    #[schema(example = 14)]
+    pub trial_period_days: Option<u16>,
+
+    /// Number of times a failed billing cycle payment is retried before the subscription
+    /// is deactivated. Defaults to 3.
+    #[schema(example = 3)]
+    pub max_payment_retries: Option<u8>,
+
+    /// Number of hours to wait between two retries of a failed billing cycle payment.
+    /// Defaults to 24.
+    #[schema(example = 24)]
+    pub payment_retry_interval_hours: Option<u16>,
+}
+
+/// Response payload of a subscription plan.
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct SubscriptionPlanResponse {
+    /// Unique identifier of the plan.
+    pub id: String,
+
+    /// Name of the plan.
+    pub name: String,
+
+    /// Merchant identifier owning this plan.
+    #[schema(value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// Profile identifier owning this plan.
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+
+    /// Amount charged on every billing cycle.
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+
+    /// Currency of the amount charged on every billing cycle.
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// Unit of time after which the customer is billed again.
+    #[schema(value_type = SubscriptionBillingInterval, example = "month")]
+    pub billing_interval: api_enums::SubscriptionBillingInterval,
+
+    /// Number of `billing_interval` units between two billing cycles.
+    pub billing_interval_count: i32,
+
+    /// Number of days the customer is not billed for after attaching a payment method.
+    pub trial_period_days: Option<i32>,
+
+    /// Number of times a failed billing cycle payment is retried.
+    pub max_payment_retries: i32,
+
+    /// Number of hours to wait between two retries of a failed billing cycle payment.
+    pub payment_retry_interval_hours: i32,
+
+    /// Time at which the plan was created.
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+/// Request payload for attaching the payment method a subscription is billed with.
+///
+/// Exactly one of `payment_method_id` and `mandate_id` must be provided.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AttachSubscriptionPaymentMethodRequest {
+    /// Saved payment method of the customer to charge off-session.
+    pub payment_method_id: Option<String>,
+
+    /// Mandate of the customer to charge off-session.
+    pub mandate_id: Option<String>,
+}
+
+/// Response payload of a subscription, also delivered as the content of subscription webhooks.
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct SubscriptionResponse {
+    /// Unique identifier for the subscription.
+    #[schema(value_type = String)]
+    pub id: common_utils::id_type::SubscriptionId,
+
+    /// Merchant specific Unique identifier.
+    pub merchant_reference_id: Option<String>,
+
+    /// Current status of the subscription.
+    pub status: SubscriptionStatus,
+
+    /// Identifier for the associated subscription plan.
+    pub plan_id: Option<String>,
+
+    /// Customer ID associated with this subscription.
+    #[schema(value_type = String)]
+    pub customer_id: common_utils::id_type::CustomerId,
+
+    /// Associated profile ID.
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+
+    /// Merchant identifier owning this subscription.
+    #[schema(value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// Saved payment method the subscription is billed with.
+    pub payment_method_id: Option<String>,
+
+    /// Mandate the subscription is billed with.
+    pub mandate_id: Option<String>,
+
+    /// Time at which the trial period ends.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub trial_end: Option<PrimitiveDateTime>,
+
+    /// Start of the current billing period.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub current_period_start: Option<PrimitiveDateTime>,
+
+    /// End of the current billing period, at which the next billing cycle is charged.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub current_period_end: Option<PrimitiveDateTime>,
+
+    /// Payment created for the latest billing cycle.
+    #[schema(value_type = Option<String>)]
+    pub last_payment_id: Option<common_utils::id_type::PaymentId>,
+
+    /// Time at which the subscription was created.
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
 }
 
 impl CreateSubscriptionResponse {
@@ -107,3 +277,7 @@ impl CreateSubscriptionResponse {
 
 impl ApiEventMetric for CreateSubscriptionResponse {}
 impl ApiEventMetric for CreateSubscriptionRequest {}
+impl ApiEventMetric for CreateSubscriptionPlanRequest {}
+impl ApiEventMetric for SubscriptionPlanResponse {}
+impl ApiEventMetric for AttachSubscriptionPaymentMethodRequest {}
+impl ApiEventMetric for SubscriptionResponse {}
@@ -35,6 +35,7 @@ impl EuclidDirFilter for SurchargeDecisionConfigs {
         DirKeyKind::PaymentCurrency,
         DirKeyKind::BillingCountry,
         DirKeyKind::CardNetwork,
+        DirKeyKind::CardType,
         DirKeyKind::PayLaterType,
         DirKeyKind::WalletType,
         DirKeyKind::BankTransferType,
@@ -79,3 +80,49 @@ pub struct MerchantSurchargeConfigs {
 }
 
 pub type SurchargeDecisionManagerResponse = SurchargeDecisionManagerRecord;
+
+/// A hypothetical payment against which the surcharge decision config of the merchant is evaluated
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SurchargePreviewRequest {
+    pub amount: MinorUnit,
+    /// The shipping cost of the order, which the surcharge is applied on along with the amount
+    pub shipping_cost: Option<MinorUnit>,
+    /// The tax amount of the order, which the surcharge is applied on along with the amount
+    pub order_tax_amount: Option<MinorUnit>,
+    pub currency: common_enums::Currency,
+    pub payment_method: common_enums::PaymentMethod,
+    pub payment_method_type: Option<common_enums::PaymentMethodType>,
+    pub card_network: Option<common_enums::CardNetwork>,
+    /// First six digits of the card number, used to look up the card network and funding type
+    /// when they are not provided
+    pub card_bin: Option<String>,
+    pub billing_country: Option<common_enums::Country>,
+    /// The profile whose surcharge decision config takes precedence over the merchant config
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+}
+
+impl events::ApiEventMetric for SurchargePreviewRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Routing)
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SurchargePreviewResponse {
+    pub amount: MinorUnit,
+    pub currency: common_enums::Currency,
+    /// The surcharge output of the matching rule, absent if no rule matched
+    pub surcharge_details: Option<SurchargeDetailsOutput>,
+    pub surcharge_amount: MinorUnit,
+    pub tax_on_surcharge_amount: MinorUnit,
+    /// Sum of the amount, the shipping cost, the order tax amount, the surcharge amount and the tax
+    /// on surcharge amount
+    pub net_amount: MinorUnit,
+}
+
+impl events::ApiEventMetric for SurchargePreviewResponse {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Routing)
+    }
+}
@@ -1,5 +1,7 @@
-use euclid::frontend::dir::enums::{
-    CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType,
+use common_types::three_ds_decision_rule_engine::ThreeDSDecisionRule;
+use euclid::frontend::{
+    ast::Program,
+    dir::enums::{CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType},
 };
 use utoipa::ToSchema;
 
@@ -91,3 +93,41 @@ impl common_utils::events::ApiEventMetric for ThreeDsDecisionRuleExecuteResponse
         Some(common_utils::events::ApiEventsType::ThreeDsDecisionRule)
     }
 }
+
+/// Represents the request to create or update the 3DS decision manager config of a merchant,
+/// which is used when the business profile does not have a 3DS decision rule linked.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ThreeDsDecisionManagerConfigRequest {
+    /// The name of the config, required when creating it.
+    pub name: Option<String>,
+    /// The rules deciding whether to force, skip or request an exemption from 3DS.
+    pub algorithm: Option<Program<ThreeDSDecisionRule>>,
+}
+
+impl common_utils::events::ApiEventMetric for ThreeDsDecisionManagerConfigRequest {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::ThreeDsDecisionRule)
+    }
+}
+
+/// Represents the 3DS decision manager config of a merchant.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ThreeDsDecisionManagerRecord {
+    /// The name of the config.
+    pub name: String,
+    /// The rules deciding whether to force, skip or request an exemption from 3DS.
+    pub algorithm: Program<ThreeDSDecisionRule>,
+    /// The time at which the config was created, as a unix timestamp.
+    pub created_at: i64,
+    /// The time at which the config was last modified, as a unix timestamp.
+    pub modified_at: i64,
+}
+
+impl common_utils::events::ApiEventMetric for ThreeDsDecisionManagerRecord {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::ThreeDsDecisionRule)
+    }
+}
+
+pub type ThreeDsDecisionManagerResponse = ThreeDsDecisionManagerRecord;
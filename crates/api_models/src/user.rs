@@ -9,6 +9,7 @@ use crate::user_role::UserStatus;
 pub mod dashboard_metadata;
 #[cfg(feature = "dummy_connector")]
 pub mod sample_data;
+pub mod scim;
 #[cfg(feature = "control_center_theme")]
 pub mod theme;
 
@@ -250,6 +251,7 @@ pub struct TokenResponse {
 pub struct TwoFactorAuthStatusResponse {
     pub totp: bool,
     pub recovery_code: bool,
+    pub passkey: bool,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
@@ -268,6 +270,12 @@ pub struct TwoFactorAuthStatusResponseWithAttempts {
 pub struct TwoFactorStatus {
     pub status: Option<TwoFactorAuthStatusResponseWithAttempts>,
     pub is_skippable: bool,
+    pub passkey: Option<PasskeyAuthStatus>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct PasskeyAuthStatus {
+    pub is_completed: bool,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
@@ -301,6 +309,71 @@ pub struct RecoveryCodes {
     pub recovery_codes: Vec<Secret<String>>,
 }
 
+/// Public key credential options to be passed to `navigator.credentials` in the browser
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct PasskeyChallengeResponse {
+    pub options: serde_json::Value,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct FinishPasskeyRegistrationRequest {
+    pub name: String,
+    pub credential: serde_json::Value,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct VerifyPasskeyRequest {
+    pub credential: serde_json::Value,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct UpdatePasskeyRequest {
+    pub name: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct PasskeyResponse {
+    pub id: String,
+    pub name: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_used_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct UserSessionResponse {
+    pub session_id: String,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub issued_at: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: time::PrimitiveDateTime,
+    /// Whether this is the session of the token used for the request
+    pub is_current: bool,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct BeginPasskeySignInRequest {
+    pub email: pii::Email,
+}
+
+/// Challenge for signing in with a passkey. The `ceremony_id` has to be sent back along with the
+/// assertion.
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct PasskeySignInChallengeResponse {
+    pub ceremony_id: String,
+    pub options: serde_json::Value,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct PasskeySignInRequest {
+    pub email: pii::Email,
+    pub ceremony_id: String,
+    pub credential: serde_json::Value,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 #[serde(tag = "auth_type")]
 #[serde(rename_all = "snake_case")]
@@ -324,6 +397,17 @@ pub struct OpenIdConnectPrivateConfig {
 #[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
 pub struct OpenIdConnectPublicConfig {
     pub name: OpenIdProvider,
+    /// Provisions users signing in for the first time into the organization owning the
+    /// authentication method, when present
+    pub jit_provisioning: Option<OpenIdConnectJitProvisioningConfig>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct OpenIdConnectJitProvisioningConfig {
+    /// The merchant account the provisioned users are added to
+    pub merchant_id: id_type::MerchantId,
+    /// The merchant level role assigned to the provisioned users
+    pub role_id: String,
 }
 
 #[derive(
@@ -333,6 +417,7 @@ pub struct OpenIdConnectPublicConfig {
 #[strum(serialize_all = "snake_case")]
 pub enum OpenIdProvider {
     Okta,
+    AzureAd,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
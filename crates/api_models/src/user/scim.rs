@@ -0,0 +1,134 @@
+use common_utils::pii;
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+pub const SCIM_USER_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:User";
+pub const SCIM_GROUP_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:Group";
+pub const SCIM_LIST_RESPONSE_SCHEMA: &str = "urn:ietf:params:scim:api:messages:2.0:ListResponse";
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimUserRequest {
+    /// The email of the user, which identifies them across identity providers
+    pub user_name: pii::Email,
+    pub name: Option<ScimName>,
+    pub display_name: Option<Secret<String>>,
+    pub active: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimName {
+    pub formatted: Option<Secret<String>>,
+    pub given_name: Option<Secret<String>>,
+    pub family_name: Option<Secret<String>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimUserResponse {
+    pub schemas: Vec<&'static str>,
+    pub id: String,
+    pub user_name: pii::Email,
+    pub display_name: Secret<String>,
+    pub emails: Vec<ScimEmail>,
+    pub active: bool,
+    /// The group the user belongs to, which is their role in the merchant account
+    pub groups: Vec<ScimGroupReference>,
+    pub meta: ScimMeta,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ScimEmail {
+    pub value: pii::Email,
+    pub primary: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ScimGroupReference {
+    pub value: String,
+    pub display: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimGroupResponse {
+    pub schemas: Vec<&'static str>,
+    /// The role id
+    pub id: String,
+    /// The role name
+    pub display_name: String,
+    pub members: Vec<ScimGroupMember>,
+    pub meta: ScimMeta,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ScimGroupMember {
+    /// The user id
+    pub value: String,
+    pub display: Option<pii::Email>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimMeta {
+    pub resource_type: ScimResourceType,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub created: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_modified: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub enum ScimResourceType {
+    User,
+    Group,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimListResponse<T> {
+    pub schemas: Vec<&'static str>,
+    pub total_results: usize,
+    pub start_index: usize,
+    pub items_per_page: usize,
+    #[serde(rename = "Resources")]
+    pub resources: Vec<T>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimListQuery {
+    /// Only equality filters on `userName` for users and on `displayName` for groups are
+    /// supported, e.g. `userName eq "user@example.com"`
+    pub filter: Option<String>,
+    /// The 1-based index of the first result
+    pub start_index: Option<usize>,
+    pub count: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ScimPatchRequest {
+    #[serde(rename = "Operations")]
+    pub operations: Vec<ScimPatchOperation>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ScimPatchOperation {
+    pub op: ScimPatchOp,
+    pub path: Option<String>,
+    pub value: Option<serde_json::Value>,
+}
+
+/// The operation of a patch, some identity providers capitalize it
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScimPatchOp {
+    #[serde(alias = "Add")]
+    Add,
+    #[serde(alias = "Remove")]
+    Remove,
+    #[serde(alias = "Replace")]
+    Replace,
+}
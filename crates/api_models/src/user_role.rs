@@ -38,6 +38,7 @@ pub struct UpdateUserRoleRequest {
 pub enum UserStatus {
     Active,
     InvitationSent,
+    Deactivated,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
@@ -1,11 +1,15 @@
 use common_enums::{
-    EntityType, ParentGroup, PermissionGroup, PermissionScope, Resource, RoleScope,
+    EntityType, ParentGroup, PermissionGroup, PermissionScope, Resource, RolePermission, RoleScope,
 };
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub struct CreateRoleRequest {
     pub role_name: String,
+    #[serde(default)]
     pub groups: Vec<PermissionGroup>,
+    /// Individual permissions granted by the role in addition to the ones of its groups
+    #[serde(default)]
+    pub permissions: Vec<RolePermission>,
     pub role_scope: RoleScope,
     pub entity_type: Option<EntityType>,
 }
@@ -21,6 +25,7 @@ pub struct CreateRoleV2Request {
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub struct UpdateRoleRequest {
     pub groups: Option<Vec<PermissionGroup>>,
+    pub permissions: Option<Vec<RolePermission>>,
     pub role_name: Option<String>,
 }
 
@@ -28,6 +33,7 @@ pub struct UpdateRoleRequest {
 pub struct RoleInfoWithGroupsResponse {
     pub role_id: String,
     pub groups: Vec<PermissionGroup>,
+    pub permissions: Vec<RolePermission>,
     pub role_name: String,
     pub role_scope: RoleScope,
     pub entity_type: EntityType,
@@ -37,6 +43,7 @@ pub struct RoleInfoWithGroupsResponse {
 pub struct RoleInfoWithParents {
     pub role_id: String,
     pub parent_groups: Vec<ParentGroupInfo>,
+    pub permissions: Vec<RolePermission>,
     pub role_name: String,
     pub role_scope: RoleScope,
 }
@@ -66,6 +73,7 @@ pub struct RoleInfoResponseNew {
     pub role_name: String,
     pub entity_type: EntityType,
     pub groups: Vec<PermissionGroup>,
+    pub permissions: Vec<RolePermission>,
     pub scope: RoleScope,
 }
 
@@ -75,6 +83,7 @@ pub struct RoleInfoResponseWithParentsGroup {
     pub role_name: String,
     pub entity_type: EntityType,
     pub parent_groups: Vec<ParentGroupInfo>,
+    pub permissions: Vec<RolePermission>,
     pub role_scope: RoleScope,
 }
 
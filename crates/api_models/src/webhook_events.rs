@@ -84,6 +84,17 @@ pub struct EventListItemResponse {
     /// Indicates whether the webhook was ultimately delivered or not.
     pub is_delivery_successful: Option<bool>,
 
+    /// Time at which the automatic retries of the webhook were exhausted without delivering it,
+    /// after which the webhook can only be delivered by a manual retry.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub dead_lettered_at: Option<PrimitiveDateTime>,
+
+    /// The identifier of the additional webhook endpoint of the Business Profile to which the
+    /// webhook is delivered. This is not set for webhooks delivered to the primary webhook URL.
+    #[schema(max_length = 32, example = "refund_alerts")]
+    pub webhook_endpoint_id: Option<String>,
+
     /// The identifier for the initial delivery attempt. This will be the same as `event_id` for
     /// the initial delivery attempt.
     #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
@@ -121,6 +132,76 @@ impl common_utils::events::ApiEventMetric for TotalEventsResponse {
     }
 }
 
+/// The time range over which the delivery health of webhooks is aggregated.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookDeliveryHealthConstraints {
+    /// Include webhooks created after the specified time. Defaults to one day before
+    /// `created_before`.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_after: Option<PrimitiveDateTime>,
+
+    /// Include webhooks created before the specified time. Defaults to the current time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_before: Option<PrimitiveDateTime>,
+}
+
+/// The aggregated delivery health of the webhooks of a Business Profile.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookDeliveryHealthResponse {
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, value_type = String, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: common_utils::id_type::ProfileId,
+
+    /// Start of the time range over which the delivery health is aggregated.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_after: PrimitiveDateTime,
+
+    /// End of the time range over which the delivery health is aggregated.
+    #[schema(example = "2022-09-11T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_before: PrimitiveDateTime,
+
+    /// Number of webhooks created in the time range.
+    #[schema(example = 120)]
+    pub total_count: i64,
+
+    /// Number of webhooks which were ultimately delivered, either on the initial attempt or on a
+    /// retry.
+    #[schema(example = 114)]
+    pub delivered_count: i64,
+
+    /// Percentage of webhooks which were ultimately delivered. This is not set if no webhooks were
+    /// created in the time range.
+    #[schema(example = 95.0)]
+    pub delivery_success_rate: Option<f64>,
+
+    /// The 95th percentile of the time taken by the webhook endpoints to respond to delivery
+    /// attempts, in milliseconds. This is not set if no response was received in the time range.
+    #[schema(example = 350.5)]
+    pub p95_delivery_latency_in_ms: Option<f64>,
+
+    /// Number of webhooks which were not delivered yet and may still be retried.
+    #[schema(example = 4)]
+    pub pending_retry_count: i64,
+
+    /// Number of webhooks for which the automatic retries were exhausted without delivering them.
+    #[schema(example = 2)]
+    pub dead_lettered_count: i64,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookDeliveryHealthResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
 /// The response body for retrieving an event.
 #[derive(Debug, Serialize, ToSchema)]
 pub struct EventRetrieveResponse {
@@ -226,3 +307,17 @@ impl common_utils::events::ApiEventMetric for WebhookDeliveryRetryRequestInterna
         })
     }
 }
+
+#[derive(Debug, serde::Serialize)]
+pub struct WebhookDeliveryHealthRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub constraints: WebhookDeliveryHealthConstraints,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookDeliveryHealthRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
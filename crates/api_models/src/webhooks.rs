@@ -5,7 +5,7 @@ use utoipa::ToSchema;
 
 #[cfg(feature = "payouts")]
 use crate::payouts;
-use crate::{disputes, enums as api_enums, mandates, payments, refunds};
+use crate::{disputes, enums as api_enums, mandates, payments, refunds, routing, subscription};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy)]
 #[serde(rename_all = "snake_case")]
@@ -416,6 +416,31 @@ pub struct OutgoingWebhook {
     pub timestamp: PrimitiveDateTime,
 }
 
+/// The payload of the events published to the event sink configured on a profile, tagged with
+/// the version of its schema
+#[derive(Debug, Serialize)]
+#[serde(tag = "schema_version", rename_all = "snake_case")]
+pub enum EventSinkPayload {
+    V1(EventSinkPayloadV1),
+}
+
+#[derive(Debug, Serialize)]
+pub struct EventSinkPayloadV1 {
+    /// The profile id of the profile on which the event sink is configured
+    pub profile_id: common_utils::id_type::ProfileId,
+
+    /// The class of event this payload corresponds to
+    pub event_class: api_enums::EventClass,
+
+    /// Identifies the event across publications, events published more than once share the same
+    /// key
+    pub idempotency_key: String,
+
+    /// The same content as the outgoing webhook sent for the event
+    #[serde(flatten)]
+    pub webhook: OutgoingWebhook,
+}
+
 #[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(tag = "type", content = "object", rename_all = "snake_case")]
 #[cfg(feature = "v1")]
@@ -431,6 +456,10 @@ pub enum OutgoingWebhookContent {
     #[cfg(feature = "payouts")]
     #[schema(value_type = PayoutCreateResponse, title = "PayoutCreateResponse")]
     PayoutDetails(Box<payouts::PayoutCreateResponse>),
+    #[schema(value_type = RoutingAuditLogRecord, title = "RoutingAuditLogRecord")]
+    RoutingDetails(Box<routing::RoutingAuditLogRecord>),
+    #[schema(value_type = SubscriptionResponse, title = "SubscriptionResponse")]
+    SubscriptionDetails(Box<subscription::SubscriptionResponse>),
 }
 
 #[derive(Debug, Clone, Serialize, ToSchema)]
@@ -448,6 +477,10 @@ pub enum OutgoingWebhookContent {
     #[cfg(feature = "payouts")]
     #[schema(value_type = PayoutCreateResponse, title = "PayoutCreateResponse")]
     PayoutDetails(Box<payouts::PayoutCreateResponse>),
+    #[schema(value_type = RoutingAuditLogRecord, title = "RoutingAuditLogRecord")]
+    RoutingDetails(Box<routing::RoutingAuditLogRecord>),
+    #[schema(value_type = SubscriptionResponse, title = "SubscriptionResponse")]
+    SubscriptionDetails(Box<subscription::SubscriptionResponse>),
 }
 
 #[derive(Debug, Clone, Serialize)]
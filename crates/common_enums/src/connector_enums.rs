@@ -59,6 +59,10 @@ pub enum RoutableConnectors {
     #[serde(rename = "paypal_test")]
     #[strum(serialize = "paypal_test")]
     DummyConnector7,
+    #[cfg(feature = "dummy_connector")]
+    #[serde(rename = "dummy_advanced")]
+    #[strum(serialize = "dummy_advanced")]
+    DummyAdvancedConnector,
     Aci,
     Adyen,
     Affirm,
@@ -101,6 +105,7 @@ pub enum RoutableConnectors {
     Fiuu,
     Flexiti,
     Forte,
+    Genericrest,
     Getnet,
     Globalpay,
     Globepay,
@@ -231,6 +236,10 @@ pub enum Connector {
     #[serde(rename = "paypal_test")]
     #[strum(serialize = "paypal_test")]
     DummyConnector7,
+    #[cfg(feature = "dummy_connector")]
+    #[serde(rename = "dummy_advanced")]
+    #[strum(serialize = "dummy_advanced")]
+    DummyAdvancedConnector,
     Aci,
     Adyen,
     Affirm,
@@ -276,6 +285,7 @@ pub enum Connector {
     Fiuu,
     Flexiti,
     Forte,
+    Genericrest,
     Getnet,
     Globalpay,
     Globepay,
@@ -431,7 +441,8 @@ impl Connector {
             | Self::DummyConnector4
             | Self::DummyConnector5
             | Self::DummyConnector6
-            | Self::DummyConnector7 => false,
+            | Self::DummyConnector7
+            | Self::DummyAdvancedConnector => false,
             Self::Aci
             // Add Separate authentication support for connectors
 			| Self::Authipay
@@ -473,6 +484,7 @@ impl Connector {
             | Self::Fiuu
             | Self::Flexiti
             | Self::Forte
+            | Self::Genericrest
             | Self::Getnet
             | Self::Globalpay
             | Self::Globepay
@@ -571,7 +583,16 @@ impl Connector {
     }
 
     pub fn is_overcapture_supported_by_connector(self) -> bool {
-        matches!(self, Self::Stripe | Self::Adyen)
+        self.get_overcapture_limit_percentage().is_some()
+    }
+
+    /// Percentage of the authorized amount by which a capture is allowed to exceed it, for
+    /// connectors supporting overcapture
+    pub fn get_overcapture_limit_percentage(self) -> Option<u16> {
+        match self {
+            Self::Stripe | Self::Adyen => Some(20),
+            _ => None,
+        }
     }
 
     pub fn should_acknowledge_webhook_for_resource_not_found_errors(self) -> bool {
@@ -591,6 +612,7 @@ impl Connector {
                 | Self::DummyConnector5
                 | Self::DummyConnector6
                 | Self::DummyConnector7
+                | Self::DummyAdvancedConnector
         ) && !is_dummy_connector_enabled
     }
 }
@@ -617,6 +639,8 @@ impl From<RoutableConnectors> for Connector {
             RoutableConnectors::DummyConnector6 => Self::DummyConnector6,
             #[cfg(feature = "dummy_connector")]
             RoutableConnectors::DummyConnector7 => Self::DummyConnector7,
+            #[cfg(feature = "dummy_connector")]
+            RoutableConnectors::DummyAdvancedConnector => Self::DummyAdvancedConnector,
             RoutableConnectors::Aci => Self::Aci,
             RoutableConnectors::Adyen => Self::Adyen,
             RoutableConnectors::Affirm => Self::Affirm,
@@ -658,6 +682,7 @@ impl From<RoutableConnectors> for Connector {
             RoutableConnectors::Fiuu => Self::Fiuu,
             RoutableConnectors::Flexiti => Self::Flexiti,
             RoutableConnectors::Forte => Self::Forte,
+            RoutableConnectors::Genericrest => Self::Genericrest,
             RoutableConnectors::Getnet => Self::Getnet,
             RoutableConnectors::Globalpay => Self::Globalpay,
             RoutableConnectors::Globepay => Self::Globepay,
@@ -753,6 +778,8 @@ impl TryFrom<Connector> for RoutableConnectors {
             Connector::DummyConnector6 => Ok(Self::DummyConnector6),
             #[cfg(feature = "dummy_connector")]
             Connector::DummyConnector7 => Ok(Self::DummyConnector7),
+            #[cfg(feature = "dummy_connector")]
+            Connector::DummyAdvancedConnector => Ok(Self::DummyAdvancedConnector),
             Connector::Aci => Ok(Self::Aci),
             Connector::Adyen => Ok(Self::Adyen),
             Connector::Affirm => Ok(Self::Affirm),
@@ -855,6 +882,7 @@ impl TryFrom<Connector> for RoutableConnectors {
             Connector::Plaid => Ok(Self::Plaid),
             Connector::Zsl => Ok(Self::Zsl),
             Connector::Recurly => Ok(Self::Recurly),
+            Connector::Genericrest => Ok(Self::Genericrest),
             Connector::Getnet => Ok(Self::Getnet),
             Connector::Hipay => Ok(Self::Hipay),
             Connector::Inespay => Ok(Self::Inespay),
@@ -149,6 +149,9 @@ pub enum AttemptStatus {
     AutoRefunded,
     PartialCharged,
     PartiallyAuthorized,
+    /// The attempt is authorized and an increase of the authorized amount is pending with the
+    /// connector
+    IncrementalAuthorizationPending,
     PartialChargedAndChargeable,
     Unresolved,
     #[default]
@@ -180,6 +183,7 @@ impl AttemptStatus {
             | Self::AuthenticationSuccessful
             | Self::Authorized
             | Self::PartiallyAuthorized
+            | Self::IncrementalAuthorizationPending
             | Self::AuthorizationFailed
             | Self::Authorizing
             | Self::CodInitiated
@@ -472,6 +476,9 @@ pub enum BlocklistDataKind {
     PaymentMethod,
     CardBin,
     ExtendedCardBin,
+    Email,
+    IpAddress,
+    CustomerId,
 }
 
 /// Specifies how the payment is captured.
@@ -1520,6 +1527,8 @@ pub enum EventClass {
     Mandates,
     #[cfg(feature = "payouts")]
     Payouts,
+    Routing,
+    Subscriptions,
 }
 
 impl EventClass {
@@ -1558,6 +1567,22 @@ impl EventClass {
                 EventType::PayoutExpired,
                 EventType::PayoutReversed,
             ]),
+            Self::Routing => HashSet::from([
+                EventType::RoutingConfigLinked,
+                EventType::RoutingConfigUnlinked,
+                EventType::RoutingDefaultUpdated,
+                EventType::RoutingDynamicUpdated,
+                EventType::RoutingVolumeSplitUpdated,
+                EventType::RoutingExperimentUpdated,
+                EventType::RoutingLatencyConfigUpdated,
+                EventType::RoutingFeeScheduleUpdated,
+            ]),
+            Self::Subscriptions => HashSet::from([
+                EventType::SubscriptionPaymentSucceeded,
+                EventType::SubscriptionPaymentProcessing,
+                EventType::SubscriptionPaymentFailed,
+                EventType::SubscriptionInactive,
+            ]),
         }
     }
 }
@@ -1617,6 +1642,30 @@ pub enum EventType {
     PayoutExpired,
     #[cfg(feature = "payouts")]
     PayoutReversed,
+    /// A routing algorithm was activated for a profile
+    RoutingConfigLinked,
+    /// The active routing algorithm of a profile was deactivated
+    RoutingConfigUnlinked,
+    /// The default fallback connectors of a profile were updated
+    RoutingDefaultUpdated,
+    /// A dynamic routing algorithm of a profile was enabled, disabled or linked
+    RoutingDynamicUpdated,
+    /// The dynamic routing volume split of a profile was updated
+    RoutingVolumeSplitUpdated,
+    /// A routing experiment of a profile was started or stopped
+    RoutingExperimentUpdated,
+    /// The latency based routing config of a profile was updated
+    RoutingLatencyConfigUpdated,
+    /// The fee schedule of a connector of a profile was updated or deleted
+    RoutingFeeScheduleUpdated,
+    /// The payment of a subscription billing cycle succeeded
+    SubscriptionPaymentSucceeded,
+    /// The payment of a subscription billing cycle is being processed
+    SubscriptionPaymentProcessing,
+    /// The payment of a subscription billing cycle failed, and will be retried if the plan allows
+    SubscriptionPaymentFailed,
+    /// A subscription was deactivated after exhausting the payment retries of a billing cycle
+    SubscriptionInactive,
 }
 
 #[derive(
@@ -1640,6 +1689,76 @@ pub enum WebhookDeliveryAttempt {
     ManualRetry,
 }
 
+/// Strategy used to compute the delay between two automatic retries of a webhook delivery
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookRetryBackoffStrategy {
+    /// Every retry happens after the initial delay
+    Fixed,
+    /// The delay grows by the initial delay with every retry
+    Linear,
+    /// The delay doubles with every retry
+    #[default]
+    Exponential,
+}
+
+/// Version of the schema of the payloads published to an event sink
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EventSinkSchemaVersion {
+    #[default]
+    V1,
+}
+
+/// SASL mechanism used to authenticate with the Kafka brokers of an event sink
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum KafkaSaslMechanism {
+    #[strum(serialize = "PLAIN")]
+    Plain,
+    #[strum(serialize = "SCRAM-SHA-256")]
+    ScramSha256,
+    #[strum(serialize = "SCRAM-SHA-512")]
+    ScramSha512,
+}
+
 // TODO: This decision about using KV mode or not,
 // should be taken at a top level rather than pushing it down to individual functions via an enum.
 #[derive(
@@ -1702,6 +1821,9 @@ pub enum IntentStatus {
     /// The payment is waiting on some action from the merchant
     /// This would be in case of manual fraud approval
     RequiresMerchantAction,
+    /// The payment has been flagged by a velocity rule, and is held until the merchant approves
+    /// or declines it from the review queue.
+    RequiresMerchantReview,
     /// The payment is waiting to be confirmed with the payment method by the customer.
     RequiresPaymentMethod,
     #[default]
@@ -1714,6 +1836,9 @@ pub enum IntentStatus {
     PartiallyCapturedAndCapturable,
     /// The payment has been authorized for a partial amount and requires capture
     PartiallyAuthorizedAndRequiresCapture,
+    /// The payment has been authorized and an increase of the authorized amount is pending with
+    /// the payment processor. The payment can be captured once the increase completes.
+    IncrementalAuthorizationPending,
     /// There has been a discrepancy between the amount/currency sent in the request and the amount/currency received by the processor
     Conflicted,
     /// The payment expired before it could be captured.
@@ -1733,11 +1858,13 @@ impl IntentStatus {
             Self::Processing
             | Self::RequiresCustomerAction
             | Self::RequiresMerchantAction
+            | Self::RequiresMerchantReview
             | Self::RequiresPaymentMethod
             | Self::RequiresConfirmation
             | Self::RequiresCapture
             | Self::PartiallyCapturedAndCapturable
             | Self::PartiallyAuthorizedAndRequiresCapture
+            | Self::IncrementalAuthorizationPending
             | Self::Conflicted => false,
         }
     }
@@ -1754,12 +1881,15 @@ impl IntentStatus {
             | Self::Cancelled
             | Self::CancelledPostCapture
             |  Self::PartiallyCaptured
-            |  Self::RequiresCapture | Self::Conflicted | Self::Expired=> false,
+            |  Self::RequiresCapture | Self::Conflicted | Self::Expired
+            // The payment is held until the merchant decides on it
+            | Self::RequiresMerchantReview => false,
             Self::Processing
             | Self::RequiresCustomerAction
             | Self::RequiresMerchantAction
             | Self::PartiallyCapturedAndCapturable
-            | Self::PartiallyAuthorizedAndRequiresCapture => true,
+            | Self::PartiallyAuthorizedAndRequiresCapture
+            | Self::IncrementalAuthorizationPending => true,
         }
     }
 }
@@ -1890,7 +2020,9 @@ impl From<AttemptStatus> for PaymentMethodStatus {
             | AttemptStatus::DeviceDataCollectionPending
             | AttemptStatus::IntegrityFailure
             | AttemptStatus::Expired => Self::Inactive,
-            AttemptStatus::Charged | AttemptStatus::Authorized => Self::Active,
+            AttemptStatus::Charged
+            | AttemptStatus::Authorized
+            | AttemptStatus::IncrementalAuthorizationPending => Self::Active,
         }
     }
 }
@@ -2355,6 +2487,36 @@ pub enum PaymentChannel {
     Other(String),
 }
 
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::VariantNames,
+    strum::EnumIter,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+/// Category of a merchant initiated transaction made with stored credentials.
+pub enum MitCategory {
+    /// Payment of a purchase in a fixed number of installments
+    Installment,
+    /// Payment made at a fixed interval, like a subscription
+    Recurring,
+    /// Payment made at no fixed interval, like an account top up
+    Unscheduled,
+    /// Reattempt of a payment which was previously declined
+    Resubmission,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -2404,6 +2566,12 @@ pub enum RefundStatus {
     Success,
     #[serde(alias = "TransactionFailure")]
     TransactionFailure,
+    /// Refund which is scheduled to be sent to the connector at a later time
+    Scheduled,
+    /// Refund which was cancelled or rejected before it was sent to the connector
+    Cancelled,
+    /// Refund which has to be approved by a second user before it is sent to the connector
+    PendingApproval,
 }
 
 #[derive(
@@ -2726,6 +2894,29 @@ pub enum DisputeStatus {
     DisputeLost,
 }
 
+/// Action taken automatically on a dispute which matches a rule of the dispute decision config
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisputeDecisionAction {
+    /// Accept the dispute
+    Accept,
+    /// Submit the evidence template of the rule for the dispute
+    SubmitEvidence,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -7929,6 +8120,7 @@ pub enum PaymentSource {
     Sdk,
     Webhook,
     ExternalAuthenticator,
+    ScheduledRetry,
 }
 
 #[derive(Default, Debug, Clone, serde::Deserialize, serde::Serialize, strum::EnumString)]
@@ -8021,6 +8213,61 @@ pub enum FrmSuggestion {
     FrmAuthorizeTransaction, // When manual capture payment which was marked fraud and held, when approved needs to be authorized.
 }
 
+/// The attribute of a payment whose payments are counted by an FRM velocity rule
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FrmVelocityDimension {
+    /// Payments made with the same card number
+    Card,
+    /// Payments made by the same customer
+    Customer,
+    /// Payments made from the same IP address
+    IpAddress,
+    /// Payments made from the same device, as identified by the browser information
+    Device,
+}
+
+/// Action taken on a payment which triggers an FRM velocity rule. When several rules are
+/// triggered, the most restrictive action is taken.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Ord,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FrmVelocityAction {
+    /// Authenticate the customer with 3DS
+    ThreeDsChallenge,
+    /// Authorize the payment without capturing it, so that the merchant can review it
+    ManualReview,
+    /// Reject the payment before it is authorized
+    Block,
+}
+
 #[derive(
     Clone,
     Debug,
@@ -8242,121 +8489,481 @@ impl TransactionType {
     }
 }
 
+/// The change made to the routing configuration of a profile, as recorded in the routing audit log
 #[derive(
     Clone,
     Copy,
     Debug,
     Eq,
     PartialEq,
+    strum::Display,
+    strum::EnumString,
     serde::Deserialize,
     serde::Serialize,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RoutingAuditAction {
+    /// A routing algorithm was created under the profile
+    Create,
+    /// A routing algorithm was activated for the profile
+    Link,
+    /// The active routing algorithm of the profile was deactivated
+    Unlink,
+    /// The default fallback connectors of the profile were updated
+    Update,
+    /// The dynamic routing algorithms of the profile, or their configs, were updated
+    DynamicRoutingUpdate,
+    /// The share of payments routed with dynamic routing was updated
+    VolumeSplitUpdate,
+    /// A routing experiment of the profile was started or stopped
+    ExperimentUpdate,
+    /// The latency based routing config of the profile was updated
+    LatencyRoutingUpdate,
+    /// The fee schedule of a connector of the profile was created, updated or deleted
+    FeeScheduleUpdate,
+}
+
+/// The unit of time after which a subscription plan bills the customer again
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
     strum::Display,
     strum::EnumString,
+    serde::Deserialize,
+    serde::Serialize,
+    ToSchema,
 )]
-#[router_derive::diesel_enum(storage_type = "db_enum")]
-#[serde(rename_all = "snake_case")]
+#[router_derive::diesel_enum(storage_type = "text")]
 #[strum(serialize_all = "snake_case")]
-pub enum RoleScope {
-    Organization,
-    Merchant,
-    Profile,
-}
-
-impl From<RoleScope> for EntityType {
-    fn from(role_scope: RoleScope) -> Self {
-        match role_scope {
-            RoleScope::Organization => Self::Organization,
-            RoleScope::Merchant => Self::Merchant,
-            RoleScope::Profile => Self::Profile,
-        }
-    }
+#[serde(rename_all = "snake_case")]
+pub enum SubscriptionBillingInterval {
+    Day,
+    Week,
+    Month,
+    Year,
 }
 
-/// Indicates the transaction status
+/// The processing status of a batch of payments
 #[derive(
     Clone,
-    Default,
+    Copy,
     Debug,
-    serde::Serialize,
-    serde::Deserialize,
     Eq,
-    Hash,
     PartialEq,
-    ToSchema,
     strum::Display,
     strum::EnumString,
+    serde::Deserialize,
+    serde::Serialize,
+    ToSchema,
 )]
 #[router_derive::diesel_enum(storage_type = "text")]
-pub enum TransactionStatus {
-    /// Authentication/ Account Verification Successful
-    #[serde(rename = "Y")]
-    Success,
-    /// Not Authenticated /Account Not Verified; Transaction denied
-    #[default]
-    #[serde(rename = "N")]
-    Failure,
-    /// Authentication/ Account Verification Could Not Be Performed; Technical or other problem, as indicated in Authentication Response(ARes) or Result Request (RReq)
-    #[serde(rename = "U")]
-    VerificationNotPerformed,
-    /// Attempts Processing Performed; Not Authenticated/Verified , but a proof of attempted authentication/verification is provided
-    #[serde(rename = "A")]
-    NotVerified,
-    /// Authentication/ Account Verification Rejected; Issuer is rejecting authentication/verification and request that authorisation not be attempted.
-    #[serde(rename = "R")]
-    Rejected,
-    /// Challenge Required; Additional authentication is required using the Challenge Request (CReq) / Challenge Response (CRes)
-    #[serde(rename = "C")]
-    ChallengeRequired,
-    /// Challenge Required; Decoupled Authentication confirmed.
-    #[serde(rename = "D")]
-    ChallengeRequiredDecoupledAuthentication,
-    /// Informational Only; 3DS Requestor challenge preference acknowledged.
-    #[serde(rename = "I")]
-    InformationOnly,
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentBatchStatus {
+    /// The payments of the batch are being created
+    Processing,
+    /// Every payment of the batch has been processed
+    Completed,
 }
 
-impl TransactionStatus {
-    pub fn is_pending(self) -> bool {
-        matches!(
-            self,
-            Self::ChallengeRequired | Self::ChallengeRequiredDecoupledAuthentication
-        )
-    }
-
-    pub fn is_terminal_state(self) -> bool {
-        matches!(self, Self::Success | Self::Failure)
-    }
+/// The outcome of a single payment of a batch
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Deserialize,
+    serde::Serialize,
+    ToSchema,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentBatchItemStatus {
+    /// The payment has not been created yet
+    Pending,
+    /// The payment was created, and its outcome is reflected by its own status
+    Processed,
+    /// The payment could not be created
+    Failed,
 }
 
+/// The execution status of a batch of payouts
 #[derive(
     Clone,
     Copy,
     Debug,
     Eq,
     PartialEq,
-    Hash,
-    serde::Serialize,
-    serde::Deserialize,
     strum::Display,
     strum::EnumString,
-    strum::EnumIter,
+    serde::Deserialize,
+    serde::Serialize,
+    ToSchema,
 )]
 #[router_derive::diesel_enum(storage_type = "text")]
-#[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
-pub enum PermissionGroup {
-    OperationsView,
-    OperationsManage,
-    ConnectorsView,
-    ConnectorsManage,
-    WorkflowsView,
-    WorkflowsManage,
-    AnalyticsView,
-    UsersView,
-    UsersManage,
-    // TODO: To be deprecated, make sure DB is migrated before removing
-    MerchantDetailsView,
-    // TODO: To be deprecated, make sure DB is migrated before removing
+#[serde(rename_all = "snake_case")]
+pub enum PayoutBatchStatus {
+    /// The payouts of the batch are waiting for the cutoff of the batch
+    Scheduled,
+    /// The payouts of the batch are being executed
+    Processing,
+    /// Every payout of the batch has been executed
+    Completed,
+    /// The batch was cancelled before its cutoff
+    Cancelled,
+}
+
+/// The outcome of a single payout of a batch
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Deserialize,
+    serde::Serialize,
+    ToSchema,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutBatchItemStatus {
+    /// The payout has not been executed yet
+    Pending,
+    /// The payout was executed, and its outcome is reflected by its own status
+    Processed,
+    /// The payout could not be executed
+    Failed,
+}
+
+/// The KYC verification status of a payout beneficiary
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Deserialize,
+    serde::Serialize,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum BeneficiaryKycStatus {
+    /// The verification of the beneficiary has not been started
+    #[default]
+    NotStarted,
+    /// The beneficiary is being verified
+    Pending,
+    /// The beneficiary has been verified
+    Verified,
+    /// The verification of the beneficiary was rejected, payouts can't be made to it
+    Rejected,
+}
+
+/// The processing status of a connector settlement report ingested for reconciliation
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Deserialize,
+    serde::Serialize,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum SettlementReportStatus {
+    /// The transactions of the report are being matched
+    Processing,
+    /// Every transaction of the report has been matched
+    Completed,
+    /// The report could not be reconciled
+    Failed,
+}
+
+/// How a connector settlement report was obtained
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Deserialize,
+    serde::Serialize,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum SettlementReportSource {
+    /// The report file was uploaded by the merchant
+    FileUpload,
+    /// The report was downloaded from the connector
+    ApiPull,
+}
+
+/// The state of the review of a payment held for the merchant
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Deserialize,
+    serde::Serialize,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentReviewStatus {
+    /// The payment is waiting for a decision of the merchant
+    Pending,
+    /// The payment was approved and captured
+    Approved,
+    /// The payment was declined and voided
+    Declined,
+}
+
+/// What flagged a payment for a review by the merchant
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Deserialize,
+    serde::Serialize,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentReviewSource {
+    /// The fraud and risk management connector suggested a manual review
+    Frm,
+    /// A velocity rule of the profile with the manual review action was triggered
+    VelocityRule,
+}
+
+/// The kind of transaction of a settlement report that was reconciled
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Deserialize,
+    serde::Serialize,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ReconTransactionType {
+    Payment,
+    Refund,
+}
+
+/// The outcome of reconciling a transaction settled by a connector with the transaction recorded
+/// by Hyperswitch
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Deserialize,
+    serde::Serialize,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum SettlementReconStatus {
+    /// The settled transaction matches the transaction recorded by Hyperswitch
+    Matched,
+    /// The settled amount differs from the amount recorded by Hyperswitch
+    AmountMismatch,
+    /// The settlement currency differs from the currency recorded by Hyperswitch
+    CurrencyMismatch,
+    /// The transaction was settled, but it has not succeeded as per Hyperswitch
+    StatusMismatch,
+    /// The settled transaction is not known to Hyperswitch
+    MissingInHyperswitch,
+    /// The transaction succeeded as per Hyperswitch, but it is missing in the settlement report
+    MissingInSettlement,
+}
+
+/// The arm of a routing experiment that a payment was routed with
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Deserialize,
+    serde::Serialize,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RoutingExperimentArm {
+    /// The payment was routed with the control routing algorithm of the experiment
+    Control,
+    /// The payment was routed with the treatment routing algorithm of the experiment
+    Treatment,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "db_enum")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RoleScope {
+    Organization,
+    Merchant,
+    Profile,
+}
+
+impl From<RoleScope> for EntityType {
+    fn from(role_scope: RoleScope) -> Self {
+        match role_scope {
+            RoleScope::Organization => Self::Organization,
+            RoleScope::Merchant => Self::Merchant,
+            RoleScope::Profile => Self::Profile,
+        }
+    }
+}
+
+/// Indicates the transaction status
+#[derive(
+    Clone,
+    Default,
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+pub enum TransactionStatus {
+    /// Authentication/ Account Verification Successful
+    #[serde(rename = "Y")]
+    Success,
+    /// Not Authenticated /Account Not Verified; Transaction denied
+    #[default]
+    #[serde(rename = "N")]
+    Failure,
+    /// Authentication/ Account Verification Could Not Be Performed; Technical or other problem, as indicated in Authentication Response(ARes) or Result Request (RReq)
+    #[serde(rename = "U")]
+    VerificationNotPerformed,
+    /// Attempts Processing Performed; Not Authenticated/Verified , but a proof of attempted authentication/verification is provided
+    #[serde(rename = "A")]
+    NotVerified,
+    /// Authentication/ Account Verification Rejected; Issuer is rejecting authentication/verification and request that authorisation not be attempted.
+    #[serde(rename = "R")]
+    Rejected,
+    /// Challenge Required; Additional authentication is required using the Challenge Request (CReq) / Challenge Response (CRes)
+    #[serde(rename = "C")]
+    ChallengeRequired,
+    /// Challenge Required; Decoupled Authentication confirmed.
+    #[serde(rename = "D")]
+    ChallengeRequiredDecoupledAuthentication,
+    /// Informational Only; 3DS Requestor challenge preference acknowledged.
+    #[serde(rename = "I")]
+    InformationOnly,
+}
+
+impl TransactionStatus {
+    pub fn is_pending(self) -> bool {
+        matches!(
+            self,
+            Self::ChallengeRequired | Self::ChallengeRequiredDecoupledAuthentication
+        )
+    }
+
+    pub fn is_terminal_state(self) -> bool {
+        matches!(self, Self::Success | Self::Failure)
+    }
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PermissionGroup {
+    OperationsView,
+    OperationsManage,
+    ConnectorsView,
+    ConnectorsManage,
+    WorkflowsView,
+    WorkflowsManage,
+    AnalyticsView,
+    UsersView,
+    UsersManage,
+    // TODO: To be deprecated, make sure DB is migrated before removing
+    MerchantDetailsView,
+    // TODO: To be deprecated, make sure DB is migrated before removing
     MerchantDetailsManage,
     // TODO: To be deprecated, make sure DB is migrated before removing
     OrganizationManage,
@@ -8369,6 +8976,9 @@ pub enum PermissionGroup {
     InternalManage,
     ThemeView,
     ThemeManage,
+    RefundApprovalManage,
+    PaymentReviewView,
+    PaymentReviewManage,
 }
 
 #[derive(
@@ -8385,6 +8995,8 @@ pub enum ParentGroup {
     Account,
     Internal,
     Theme,
+    RefundApproval,
+    PaymentReview,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
@@ -8417,6 +9029,8 @@ pub enum Resource {
     Subscription,
     InternalConnector,
     Theme,
+    RefundApproval,
+    PaymentReview,
 }
 
 #[derive(
@@ -8428,6 +9042,149 @@ pub enum PermissionScope {
     Write = 1,
 }
 
+/// Scopes granted to an API key, each of them permitting either reading or writing a resource.
+/// The write scope of a resource also permits reading it.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+pub enum ApiKeyScope {
+    #[serde(rename = "payments:read")]
+    #[strum(serialize = "payments:read")]
+    PaymentsRead,
+    #[serde(rename = "payments:write")]
+    #[strum(serialize = "payments:write")]
+    PaymentsWrite,
+    #[serde(rename = "refunds:read")]
+    #[strum(serialize = "refunds:read")]
+    RefundsRead,
+    #[serde(rename = "refunds:write")]
+    #[strum(serialize = "refunds:write")]
+    RefundsWrite,
+    #[serde(rename = "disputes:read")]
+    #[strum(serialize = "disputes:read")]
+    DisputesRead,
+    #[serde(rename = "disputes:write")]
+    #[strum(serialize = "disputes:write")]
+    DisputesWrite,
+    #[serde(rename = "mandates:read")]
+    #[strum(serialize = "mandates:read")]
+    MandatesRead,
+    #[serde(rename = "mandates:write")]
+    #[strum(serialize = "mandates:write")]
+    MandatesWrite,
+    /// Customers along with their payment methods
+    #[serde(rename = "customers:read")]
+    #[strum(serialize = "customers:read")]
+    CustomersRead,
+    #[serde(rename = "customers:write")]
+    #[strum(serialize = "customers:write")]
+    CustomersWrite,
+    #[serde(rename = "payouts:read")]
+    #[strum(serialize = "payouts:read")]
+    PayoutsRead,
+    #[serde(rename = "payouts:write")]
+    #[strum(serialize = "payouts:write")]
+    PayoutsWrite,
+    #[serde(rename = "routing:read")]
+    #[strum(serialize = "routing:read")]
+    RoutingRead,
+    #[serde(rename = "routing:write")]
+    #[strum(serialize = "routing:write")]
+    RoutingWrite,
+    #[serde(rename = "connectors:read")]
+    #[strum(serialize = "connectors:read")]
+    ConnectorsRead,
+    #[serde(rename = "connectors:write")]
+    #[strum(serialize = "connectors:write")]
+    ConnectorsWrite,
+    #[serde(rename = "api_keys:read")]
+    #[strum(serialize = "api_keys:read")]
+    ApiKeysRead,
+    #[serde(rename = "api_keys:write")]
+    #[strum(serialize = "api_keys:write")]
+    ApiKeysWrite,
+    /// Dashboard users, provisioned through the SCIM endpoints
+    #[serde(rename = "users:read")]
+    #[strum(serialize = "users:read")]
+    UsersRead,
+    #[serde(rename = "users:write")]
+    #[strum(serialize = "users:write")]
+    UsersWrite,
+}
+
+impl ApiKeyScope {
+    pub fn resource(self) -> Resource {
+        match self {
+            Self::PaymentsRead | Self::PaymentsWrite => Resource::Payment,
+            Self::RefundsRead | Self::RefundsWrite => Resource::Refund,
+            Self::DisputesRead | Self::DisputesWrite => Resource::Dispute,
+            Self::MandatesRead | Self::MandatesWrite => Resource::Mandate,
+            Self::CustomersRead | Self::CustomersWrite => Resource::Customer,
+            Self::PayoutsRead | Self::PayoutsWrite => Resource::Payout,
+            Self::RoutingRead | Self::RoutingWrite => Resource::Routing,
+            Self::ConnectorsRead | Self::ConnectorsWrite => Resource::Connector,
+            Self::ApiKeysRead | Self::ApiKeysWrite => Resource::ApiKey,
+            Self::UsersRead | Self::UsersWrite => Resource::User,
+        }
+    }
+
+    pub fn permission_scope(self) -> PermissionScope {
+        match self {
+            Self::PaymentsRead
+            | Self::RefundsRead
+            | Self::DisputesRead
+            | Self::MandatesRead
+            | Self::CustomersRead
+            | Self::PayoutsRead
+            | Self::RoutingRead
+            | Self::ConnectorsRead
+            | Self::ApiKeysRead
+            | Self::UsersRead => PermissionScope::Read,
+            Self::PaymentsWrite
+            | Self::RefundsWrite
+            | Self::DisputesWrite
+            | Self::MandatesWrite
+            | Self::CustomersWrite
+            | Self::PayoutsWrite
+            | Self::RoutingWrite
+            | Self::ConnectorsWrite
+            | Self::ApiKeysWrite
+            | Self::UsersWrite => PermissionScope::Write,
+        }
+    }
+
+    /// Whether the scope permits the access to the resource with the permission scope
+    pub fn permits(self, resource: Resource, permission_scope: PermissionScope) -> bool {
+        self.resource() == resource && self.permission_scope() >= permission_scope
+    }
+}
+
+/// A permission which a custom role can be composed of, permitting either reading or writing a
+/// resource. The write permission of a resource also permits reading it.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct RolePermission {
+    pub resource: Resource,
+    pub scope: PermissionScope,
+}
+
+impl RolePermission {
+    /// Whether the permission permits the access to the resource with the permission scope
+    pub fn permits(self, resource: Resource, permission_scope: PermissionScope) -> bool {
+        self.resource == resource && self.scope >= permission_scope
+    }
+}
+
 /// Name of banks supported by Hyperswitch
 #[derive(
     Clone,
@@ -8927,8 +9684,13 @@ impl From<ConnectorType> for TransactionType {
 impl From<RefundStatus> for RelayStatus {
     fn from(refund_status: RefundStatus) -> Self {
         match refund_status {
-            RefundStatus::Failure | RefundStatus::TransactionFailure => Self::Failure,
-            RefundStatus::ManualReview | RefundStatus::Pending => Self::Pending,
+            RefundStatus::Failure | RefundStatus::TransactionFailure | RefundStatus::Cancelled => {
+                Self::Failure
+            }
+            RefundStatus::ManualReview
+            | RefundStatus::Pending
+            | RefundStatus::Scheduled
+            | RefundStatus::PendingApproval => Self::Pending,
             RefundStatus::Success => Self::Success,
         }
     }
@@ -9371,6 +10133,19 @@ pub enum ProcessTrackerRunner {
     PassiveRecoveryWorkflow,
     ProcessDisputeWorkflow,
     DisputeListWorkflow,
+    RoutingActivationWorkflow,
+    MerchantKeyReEncryptionWorkflow,
+    MerchantKeyMigrationWorkflow,
+    PaymentLinkExpiryWorkflow,
+    SubscriptionBillingWorkflow,
+    PaymentIntentExpiryWorkflow,
+    PayoutBatchWorkflow,
+    SoftDeclineRetryWorkflow,
+    PaymentMethodExpiryWorkflow,
+    DataRetentionPurgeWorkflow,
+    ReportGenerationWorkflow,
+    AuthRateAlertWorkflow,
+    PaymentArchivalWorkflow,
 }
 
 #[derive(Debug)]
@@ -9478,3 +10253,160 @@ impl RoutingApproach {
 pub enum CallbackMapperIdType {
     NetworkTokenRequestorReferenceID,
 }
+
+/// The list endpoint a saved list view applies to
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+    strum::Display,
+    strum::EnumString,
+    Hash,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+#[router_derive::diesel_enum(storage_type = "text")]
+pub enum ListViewEntity {
+    Payments,
+    Refunds,
+}
+
+/// The analytics domain whose metrics are included in a scheduled report
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+    strum::Display,
+    strum::EnumString,
+    Hash,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+#[router_derive::diesel_enum(storage_type = "text")]
+pub enum ReportDomain {
+    Payments,
+    Refunds,
+}
+
+/// The file format of a scheduled report
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+    strum::Display,
+    strum::EnumString,
+    Hash,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+#[router_derive::diesel_enum(storage_type = "text")]
+pub enum ReportFormat {
+    Csv,
+    Xlsx,
+}
+
+/// How often a scheduled report is generated
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+    strum::Display,
+    strum::EnumString,
+    Hash,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+#[router_derive::diesel_enum(storage_type = "text")]
+pub enum ReportFrequency {
+    Daily,
+    Weekly,
+}
+
+/// Where a scheduled report is delivered once generated. The report file is kept in the file
+/// storage in either case, so that it can be downloaded from the run history
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+    strum::Display,
+    strum::EnumString,
+    Hash,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+#[router_derive::diesel_enum(storage_type = "text")]
+pub enum ReportDeliveryMethod {
+    Email,
+    FileStorage,
+}
+
+/// The outcome of a run of a scheduled report
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+    strum::Display,
+    strum::EnumString,
+    Hash,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+#[router_derive::diesel_enum(storage_type = "text")]
+pub enum ReportRunStatus {
+    Succeeded,
+    Failed,
+}
+
+/// The status of an authorization rate alert
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+    strum::Display,
+    strum::EnumString,
+    Hash,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+#[router_derive::diesel_enum(storage_type = "text")]
+pub enum AuthRateAlertStatus {
+    /// The authorization rate is below its baseline by more than the configured threshold
+    Active,
+    /// The authorization rate has recovered
+    Resolved,
+}
@@ -5,8 +5,8 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "payouts")]
 use crate::enums::PayoutStatus;
 use crate::enums::{
-    AttemptStatus, Country, CountryAlpha2, CountryAlpha3, DisputeStatus, EventType, IntentStatus,
-    MandateStatus, PaymentMethod, PaymentMethodType, RefundStatus,
+    AttemptStatus, Country, CountryAlpha2, CountryAlpha3, Currency, DisputeStatus, EventType,
+    IntentStatus, MandateStatus, PaymentMethod, PaymentMethodType, RefundStatus,
 };
 
 impl Display for NumericCountryCodeParseError {
@@ -269,7 +269,240 @@ impl CountryAlpha2 {
             Self::ZW => CountryAlpha3::ZWE,
         }
     }
+
+    /// Currency in legal use in the country, which is `None` for territories without one
+    pub const fn get_local_currency(self) -> Option<Currency> {
+        match self {
+            Self::AX
+            | Self::AD
+            | Self::AT
+            | Self::BE
+            | Self::HR
+            | Self::CY
+            | Self::EE
+            | Self::FI
+            | Self::FR
+            | Self::GF
+            | Self::TF
+            | Self::DE
+            | Self::GR
+            | Self::GP
+            | Self::VA
+            | Self::IE
+            | Self::IT
+            | Self::LV
+            | Self::LT
+            | Self::LU
+            | Self::MT
+            | Self::MQ
+            | Self::YT
+            | Self::MC
+            | Self::ME
+            | Self::NL
+            | Self::PT
+            | Self::RE
+            | Self::BL
+            | Self::MF
+            | Self::PM
+            | Self::SM
+            | Self::SK
+            | Self::SI
+            | Self::ES => Some(Currency::EUR),
+            Self::AS
+            | Self::BQ
+            | Self::IO
+            | Self::EC
+            | Self::SV
+            | Self::GU
+            | Self::MH
+            | Self::FM
+            | Self::MP
+            | Self::PW
+            | Self::PR
+            | Self::TL
+            | Self::TC
+            | Self::UM
+            | Self::US
+            | Self::VG
+            | Self::VI => Some(Currency::USD),
+            Self::AI
+            | Self::AG
+            | Self::DM
+            | Self::GD
+            | Self::MS
+            | Self::KN
+            | Self::LC
+            | Self::VC => Some(Currency::XCD),
+            Self::BJ
+            | Self::BF
+            | Self::CI
+            | Self::GW
+            | Self::ML
+            | Self::NE
+            | Self::SN
+            | Self::TG => Some(Currency::XOF),
+            Self::CM | Self::CF | Self::TD | Self::CG | Self::GQ | Self::GA => Some(Currency::XAF),
+            Self::PF | Self::NC | Self::WF => Some(Currency::XPF),
+            Self::AU
+            | Self::CX
+            | Self::CC
+            | Self::HM
+            | Self::KI
+            | Self::NR
+            | Self::NF
+            | Self::TV => Some(Currency::AUD),
+            Self::CK | Self::NZ | Self::NU | Self::PN | Self::TK => Some(Currency::NZD),
+            Self::BV | Self::NO | Self::SJ => Some(Currency::NOK),
+            Self::DK | Self::FO | Self::GL => Some(Currency::DKK),
+            Self::GG | Self::IM | Self::JE | Self::GS | Self::GB => Some(Currency::GBP),
+            Self::LI | Self::CH => Some(Currency::CHF),
+            Self::MA | Self::EH => Some(Currency::MAD),
+            Self::IL | Self::PS => Some(Currency::ILS),
+            Self::CW | Self::SX => Some(Currency::ANG),
+            Self::AF => Some(Currency::AFN),
+            Self::AL => Some(Currency::ALL),
+            Self::DZ => Some(Currency::DZD),
+            Self::AO => Some(Currency::AOA),
+            Self::AR => Some(Currency::ARS),
+            Self::AM => Some(Currency::AMD),
+            Self::AW => Some(Currency::AWG),
+            Self::AZ => Some(Currency::AZN),
+            Self::BS => Some(Currency::BSD),
+            Self::BH => Some(Currency::BHD),
+            Self::BD => Some(Currency::BDT),
+            Self::BB => Some(Currency::BBD),
+            Self::BY => Some(Currency::BYN),
+            Self::BZ => Some(Currency::BZD),
+            Self::BM => Some(Currency::BMD),
+            Self::BT => Some(Currency::BTN),
+            Self::BO => Some(Currency::BOB),
+            Self::BA => Some(Currency::BAM),
+            Self::BW => Some(Currency::BWP),
+            Self::BR => Some(Currency::BRL),
+            Self::BN => Some(Currency::BND),
+            Self::BG => Some(Currency::BGN),
+            Self::BI => Some(Currency::BIF),
+            Self::CV => Some(Currency::CVE),
+            Self::KH => Some(Currency::KHR),
+            Self::CA => Some(Currency::CAD),
+            Self::KY => Some(Currency::KYD),
+            Self::CL => Some(Currency::CLP),
+            Self::CN => Some(Currency::CNY),
+            Self::CO => Some(Currency::COP),
+            Self::KM => Some(Currency::KMF),
+            Self::CD => Some(Currency::CDF),
+            Self::CR => Some(Currency::CRC),
+            Self::CU => Some(Currency::CUP),
+            Self::CZ => Some(Currency::CZK),
+            Self::DJ => Some(Currency::DJF),
+            Self::DO => Some(Currency::DOP),
+            Self::EG => Some(Currency::EGP),
+            Self::ER => Some(Currency::ERN),
+            Self::ET => Some(Currency::ETB),
+            Self::FK => Some(Currency::FKP),
+            Self::FJ => Some(Currency::FJD),
+            Self::GM => Some(Currency::GMD),
+            Self::GE => Some(Currency::GEL),
+            Self::GH => Some(Currency::GHS),
+            Self::GI => Some(Currency::GIP),
+            Self::GT => Some(Currency::GTQ),
+            Self::GN => Some(Currency::GNF),
+            Self::GY => Some(Currency::GYD),
+            Self::HT => Some(Currency::HTG),
+            Self::HN => Some(Currency::HNL),
+            Self::HK => Some(Currency::HKD),
+            Self::HU => Some(Currency::HUF),
+            Self::IS => Some(Currency::ISK),
+            Self::IN => Some(Currency::INR),
+            Self::ID => Some(Currency::IDR),
+            Self::IR => Some(Currency::IRR),
+            Self::IQ => Some(Currency::IQD),
+            Self::JM => Some(Currency::JMD),
+            Self::JP => Some(Currency::JPY),
+            Self::JO => Some(Currency::JOD),
+            Self::KZ => Some(Currency::KZT),
+            Self::KE => Some(Currency::KES),
+            Self::KP => Some(Currency::KPW),
+            Self::KR => Some(Currency::KRW),
+            Self::KW => Some(Currency::KWD),
+            Self::KG => Some(Currency::KGS),
+            Self::LA => Some(Currency::LAK),
+            Self::LB => Some(Currency::LBP),
+            Self::LS => Some(Currency::LSL),
+            Self::LR => Some(Currency::LRD),
+            Self::LY => Some(Currency::LYD),
+            Self::MO => Some(Currency::MOP),
+            Self::MK => Some(Currency::MKD),
+            Self::MG => Some(Currency::MGA),
+            Self::MW => Some(Currency::MWK),
+            Self::MY => Some(Currency::MYR),
+            Self::MV => Some(Currency::MVR),
+            Self::MR => Some(Currency::MRU),
+            Self::MU => Some(Currency::MUR),
+            Self::MX => Some(Currency::MXN),
+            Self::MD => Some(Currency::MDL),
+            Self::MN => Some(Currency::MNT),
+            Self::MZ => Some(Currency::MZN),
+            Self::MM => Some(Currency::MMK),
+            Self::NA => Some(Currency::NAD),
+            Self::NP => Some(Currency::NPR),
+            Self::NI => Some(Currency::NIO),
+            Self::NG => Some(Currency::NGN),
+            Self::OM => Some(Currency::OMR),
+            Self::PK => Some(Currency::PKR),
+            Self::PA => Some(Currency::PAB),
+            Self::PG => Some(Currency::PGK),
+            Self::PY => Some(Currency::PYG),
+            Self::PE => Some(Currency::PEN),
+            Self::PH => Some(Currency::PHP),
+            Self::PL => Some(Currency::PLN),
+            Self::QA => Some(Currency::QAR),
+            Self::RO => Some(Currency::RON),
+            Self::RU => Some(Currency::RUB),
+            Self::RW => Some(Currency::RWF),
+            Self::SH => Some(Currency::SHP),
+            Self::WS => Some(Currency::WST),
+            Self::ST => Some(Currency::STN),
+            Self::SA => Some(Currency::SAR),
+            Self::RS => Some(Currency::RSD),
+            Self::SC => Some(Currency::SCR),
+            Self::SL => Some(Currency::SLE),
+            Self::SG => Some(Currency::SGD),
+            Self::SB => Some(Currency::SBD),
+            Self::SO => Some(Currency::SOS),
+            Self::ZA => Some(Currency::ZAR),
+            Self::SS => Some(Currency::SSP),
+            Self::LK => Some(Currency::LKR),
+            Self::SD => Some(Currency::SDG),
+            Self::SR => Some(Currency::SRD),
+            Self::SZ => Some(Currency::SZL),
+            Self::SE => Some(Currency::SEK),
+            Self::SY => Some(Currency::SYP),
+            Self::TW => Some(Currency::TWD),
+            Self::TJ => Some(Currency::TJS),
+            Self::TZ => Some(Currency::TZS),
+            Self::TH => Some(Currency::THB),
+            Self::TO => Some(Currency::TOP),
+            Self::TT => Some(Currency::TTD),
+            Self::TN => Some(Currency::TND),
+            Self::TR => Some(Currency::TRY),
+            Self::TM => Some(Currency::TMT),
+            Self::UG => Some(Currency::UGX),
+            Self::UA => Some(Currency::UAH),
+            Self::AE => Some(Currency::AED),
+            Self::UY => Some(Currency::UYU),
+            Self::UZ => Some(Currency::UZS),
+            Self::VU => Some(Currency::VUV),
+            Self::VE => Some(Currency::VES),
+            Self::VN => Some(Currency::VND),
+            Self::YE => Some(Currency::YER),
+            Self::ZM => Some(Currency::ZMW),
+            Self::ZW => Some(Currency::ZWL),
+            Self::AQ => None,
+        }
+    }
 }
+
 impl Country {
     pub const fn from_alpha2(code: CountryAlpha2) -> Self {
         match code {
@@ -1790,6 +2023,41 @@ impl Country {
             Self::Zimbabwe => 716,
         }
     }
+
+    /// Whether the country is part of the PSD2 region, where Strong Customer Authentication
+    /// applies to payments
+    pub const fn is_psd2_country(self) -> bool {
+        matches!(
+            self,
+            Self::Austria
+                | Self::Belgium
+                | Self::Bulgaria
+                | Self::Croatia
+                | Self::Cyprus
+                | Self::Czechia
+                | Self::Denmark
+                | Self::Estonia
+                | Self::Finland
+                | Self::France
+                | Self::Germany
+                | Self::Greece
+                | Self::Hungary
+                | Self::Ireland
+                | Self::Italy
+                | Self::Latvia
+                | Self::Lithuania
+                | Self::Luxembourg
+                | Self::Malta
+                | Self::Netherlands
+                | Self::Poland
+                | Self::Portugal
+                | Self::Romania
+                | Self::Slovakia
+                | Self::Slovenia
+                | Self::Spain
+                | Self::Sweden
+        )
+    }
 }
 
 impl From<PaymentMethodType> for PaymentMethod {
@@ -2127,6 +2395,7 @@ impl From<AttemptStatus> for IntentStatus {
             AttemptStatus::VoidedPostCharge => Self::CancelledPostCapture,
             AttemptStatus::Expired => Self::Expired,
             AttemptStatus::PartiallyAuthorized => Self::PartiallyAuthorizedAndRequiresCapture,
+            AttemptStatus::IncrementalAuthorizationPending => Self::IncrementalAuthorizationPending,
         }
     }
 }
@@ -2138,6 +2407,7 @@ impl From<IntentStatus> for Option<EventType> {
             IntentStatus::Failed => Some(EventType::PaymentFailed),
             IntentStatus::Processing => Some(EventType::PaymentProcessing),
             IntentStatus::RequiresMerchantAction
+            | IntentStatus::RequiresMerchantReview
             | IntentStatus::RequiresCustomerAction
             | IntentStatus::Conflicted => Some(EventType::ActionRequired),
             IntentStatus::Cancelled => Some(EventType::PaymentCancelled),
@@ -2147,7 +2417,9 @@ impl From<IntentStatus> for Option<EventType> {
                 Some(EventType::PaymentCaptured)
             }
             IntentStatus::RequiresCapture => Some(EventType::PaymentAuthorized),
-            IntentStatus::RequiresPaymentMethod | IntentStatus::RequiresConfirmation => None,
+            IntentStatus::RequiresPaymentMethod
+            | IntentStatus::RequiresConfirmation
+            | IntentStatus::IncrementalAuthorizationPending => None,
             IntentStatus::PartiallyAuthorizedAndRequiresCapture => {
                 Some(EventType::PaymentPartiallyAuthorized)
             }
@@ -2162,7 +2434,10 @@ impl From<RefundStatus> for Option<EventType> {
             RefundStatus::Failure => Some(EventType::RefundFailed),
             RefundStatus::ManualReview
             | RefundStatus::Pending
-            | RefundStatus::TransactionFailure => None,
+            | RefundStatus::TransactionFailure
+            | RefundStatus::Scheduled
+            | RefundStatus::PendingApproval
+            | RefundStatus::Cancelled => None,
         }
     }
 }
@@ -2402,4 +2677,12 @@ mod tests {
             serde_json::from_str::<HyperswitchRequestNumeric>(request_str);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_get_local_currency() {
+        assert_eq!(CountryAlpha2::IN.get_local_currency(), Some(Currency::INR));
+        assert_eq!(CountryAlpha2::FR.get_local_currency(), Some(Currency::EUR));
+        assert_eq!(CountryAlpha2::PR.get_local_currency(), Some(Currency::USD));
+        assert_eq!(CountryAlpha2::AQ.get_local_currency(), None);
+    }
 }
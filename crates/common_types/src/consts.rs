@@ -20,6 +20,7 @@ pub const DEFAULT_PAYMENT_WEBHOOK_TRIGGER_STATUSES: &[common_enums::IntentStatus
     common_enums::IntentStatus::Failed,
     common_enums::IntentStatus::PartiallyCaptured,
     common_enums::IntentStatus::RequiresMerchantAction,
+    common_enums::IntentStatus::RequiresMerchantReview,
 ];
 
 /// Default refund statuses that trigger a webhook
@@ -36,3 +37,38 @@ pub const DEFAULT_PAYOUT_WEBHOOK_TRIGGER_STATUSES: &[common_enums::PayoutStatus]
     common_enums::PayoutStatus::Initiated,
     common_enums::PayoutStatus::Pending,
 ];
+
+/// Maximum number of retry attempts that can be configured for a decline category in a profile retry policy
+pub const MAX_RETRY_POLICY_ATTEMPTS: u8 = 5;
+
+/// Maximum delay (in milliseconds) that can be configured between retry attempts in a profile retry policy
+pub const MAX_RETRY_POLICY_DELAY_IN_MILLIS: u32 = 5000;
+
+/// Maximum delay (in milliseconds) waited for across all the retry attempts of a payment, as the
+/// retries are made while the payment request is being served
+pub const MAX_RETRY_POLICY_TOTAL_DELAY_IN_MILLIS: u32 = 5000;
+
+/// Maximum number of automatic retries that can be configured in a profile webhook retry policy
+pub const MAX_WEBHOOK_RETRY_ATTEMPTS: u8 = 30;
+
+/// Maximum delay (in seconds) that can be configured between webhook delivery retries, i.e. 1 day
+pub const MAX_WEBHOOK_RETRY_DELAY_IN_SECONDS: u32 = 24 * 60 * 60;
+
+/// Maximum duration (in seconds) during which webhook deliveries can be retried, i.e. 7 days
+pub const MAX_WEBHOOK_RETRY_WINDOW_IN_SECONDS: u32 = 7 * 24 * 60 * 60;
+
+/// Maximum number of bootstrap brokers that can be configured for a profile Kafka event sink
+pub const MAX_EVENT_SINK_KAFKA_BROKERS: usize = 10;
+
+/// Maximum length of the topic of a profile Kafka event sink, as limited by Kafka
+pub const MAX_EVENT_SINK_KAFKA_TOPIC_LENGTH: usize = 249;
+
+/// Maximum number of retries that can be configured in a profile soft decline retry config, which
+/// is the number of reattempts allowed by Visa for a declined transaction
+pub const MAX_SOFT_DECLINE_RETRY_ATTEMPTS: usize = 15;
+
+/// Minimum interval (in seconds) that can be configured between soft decline retries, i.e. 1 hour
+pub const MIN_SOFT_DECLINE_RETRY_INTERVAL_IN_SECONDS: u32 = 60 * 60;
+
+/// Maximum interval (in seconds) that can be configured between soft decline retries, i.e. 15 days
+pub const MAX_SOFT_DECLINE_RETRY_INTERVAL_IN_SECONDS: u32 = 15 * 24 * 60 * 60;
@@ -5,11 +5,12 @@ use std::collections::HashMap;
 use common_enums::enums;
 use common_utils::{impl_to_sql_from_sql_json, types::MinorUnit};
 use diesel::{sql_types::Jsonb, AsExpression, FromSqlRow};
-#[cfg(feature = "v2")]
-use masking::Secret;
+use masking::{PeekInterface, Secret};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+use crate::consts;
+
 #[derive(
     Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
 )]
@@ -181,3 +182,518 @@ impl RetryFeatureData {
         self.decision
     }
 }
+
+/// Retry policy configured on a profile, which decides per decline category whether a failed
+/// authorization has to be retried on the next connector present in the routing output
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+#[serde(deny_unknown_fields)]
+pub struct RetryPolicy {
+    /// Retry rules to be applied, one per decline category
+    pub rules: Vec<RetryPolicyRule>,
+}
+
+/// Retry rule applied to the declines of a particular category
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RetryPolicyRule {
+    /// The decline category, as mapped in the gateway status mapping, for which this rule applies
+    #[schema(value_type = ErrorCategory, example = "soft_decline")]
+    pub error_category: enums::ErrorCategory,
+    /// Indicates if the payment has to be retried on the next connector
+    pub retry: bool,
+    /// Maximum number of retry attempts allowed for this decline category
+    #[schema(example = 2)]
+    pub max_attempts: u8,
+    /// Delay (in milliseconds) to wait for before each retry attempt
+    #[serde(default)]
+    #[schema(example = 500)]
+    pub delay_in_millis: u32,
+}
+
+impl_to_sql_from_sql_json!(RetryPolicy);
+
+impl RetryPolicy {
+    /// Validates the retry rules configured in the policy
+    pub fn validate(&self) -> Result<(), String> {
+        let mut error_categories = std::collections::BTreeSet::new();
+        for rule in &self.rules {
+            if !error_categories.insert(rule.error_category) {
+                return Err(format!(
+                    "Duplicate retry rule received for error_category `{}` in retry_policy",
+                    rule.error_category
+                ));
+            }
+            if rule.retry && !(1..=consts::MAX_RETRY_POLICY_ATTEMPTS).contains(&rule.max_attempts) {
+                return Err(format!(
+                    "max_attempts in retry_policy should be between 1 and {}",
+                    consts::MAX_RETRY_POLICY_ATTEMPTS
+                ));
+            }
+            if rule.delay_in_millis > consts::MAX_RETRY_POLICY_DELAY_IN_MILLIS {
+                return Err(format!(
+                    "delay_in_millis in retry_policy should not exceed {}",
+                    consts::MAX_RETRY_POLICY_DELAY_IN_MILLIS
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Retrieves the retry rule configured for the given decline category, if any
+    pub fn get_rule(&self, error_category: enums::ErrorCategory) -> Option<&RetryPolicyRule> {
+        self.rules
+            .iter()
+            .find(|rule| rule.error_category == error_category)
+    }
+}
+
+impl RetryPolicyRule {
+    /// Retrieves the decision to be taken for auto retries flow.
+    pub fn get_decision(&self) -> common_enums::GsmDecision {
+        if self.retry {
+            common_enums::GsmDecision::Retry
+        } else {
+            common_enums::GsmDecision::DoDefault
+        }
+    }
+
+    /// Retrieves the delay to wait for before a retry attempt.
+    pub fn get_delay(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(u64::from(self.delay_in_millis))
+    }
+}
+
+/// Retry policy configured on a profile for the automatic retries of outgoing webhook deliveries.
+/// Deliveries which are still failing once the retries are exhausted are dead-lettered.
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookRetryPolicy {
+    /// Maximum number of automatic retries of a webhook delivery
+    #[schema(example = 10)]
+    pub max_attempts: u8,
+    /// Strategy used to compute the delay between two retries
+    #[serde(default)]
+    #[schema(value_type = WebhookRetryBackoffStrategy, example = "exponential")]
+    pub backoff_strategy: enums::WebhookRetryBackoffStrategy,
+    /// Delay (in seconds) before the first retry, on which the backoff strategy is applied
+    #[schema(example = 60)]
+    pub initial_delay_in_seconds: u32,
+    /// Upper bound (in seconds) of the delay between two retries
+    #[schema(example = 3600)]
+    pub max_delay_in_seconds: Option<u32>,
+    /// Duration (in seconds) since the first delivery attempt after which no more retries are made
+    #[schema(example = 86400)]
+    pub retry_window_in_seconds: Option<u32>,
+}
+
+impl_to_sql_from_sql_json!(WebhookRetryPolicy);
+
+impl WebhookRetryPolicy {
+    /// Validates the limits configured in the policy
+    pub fn validate(&self) -> Result<(), String> {
+        if !(1..=consts::MAX_WEBHOOK_RETRY_ATTEMPTS).contains(&self.max_attempts) {
+            return Err(format!(
+                "max_attempts in webhook_retry_policy should be between 1 and {}",
+                consts::MAX_WEBHOOK_RETRY_ATTEMPTS
+            ));
+        }
+        for (field_name, delay) in [
+            (
+                "initial_delay_in_seconds",
+                Some(self.initial_delay_in_seconds),
+            ),
+            ("max_delay_in_seconds", self.max_delay_in_seconds),
+        ] {
+            if delay.is_some_and(|delay| {
+                !(1..=consts::MAX_WEBHOOK_RETRY_DELAY_IN_SECONDS).contains(&delay)
+            }) {
+                return Err(format!(
+                    "{field_name} in webhook_retry_policy should be between 1 and {}",
+                    consts::MAX_WEBHOOK_RETRY_DELAY_IN_SECONDS
+                ));
+            }
+        }
+        if self
+            .max_delay_in_seconds
+            .is_some_and(|max_delay| max_delay < self.initial_delay_in_seconds)
+        {
+            return Err(
+                "max_delay_in_seconds in webhook_retry_policy should not be less than initial_delay_in_seconds"
+                    .to_string(),
+            );
+        }
+        if self.retry_window_in_seconds.is_some_and(|retry_window| {
+            !(1..=consts::MAX_WEBHOOK_RETRY_WINDOW_IN_SECONDS).contains(&retry_window)
+        }) {
+            return Err(format!(
+                "retry_window_in_seconds in webhook_retry_policy should be between 1 and {}",
+                consts::MAX_WEBHOOK_RETRY_WINDOW_IN_SECONDS
+            ));
+        }
+        Ok(())
+    }
+
+    /// Retrieves the delay (in seconds) before the retry with the given zero based index, or
+    /// `None` once the retries are exhausted
+    pub fn get_retry_delay_in_seconds(&self, retry_count: i32) -> Option<i32> {
+        let retry_count = u32::try_from(retry_count).ok()?;
+        if retry_count >= u32::from(self.max_attempts) {
+            return None;
+        }
+
+        let initial_delay = u64::from(self.initial_delay_in_seconds);
+        let delay = match self.backoff_strategy {
+            enums::WebhookRetryBackoffStrategy::Fixed => initial_delay,
+            enums::WebhookRetryBackoffStrategy::Linear => {
+                initial_delay.saturating_mul(u64::from(retry_count) + 1)
+            }
+            enums::WebhookRetryBackoffStrategy::Exponential => {
+                initial_delay.saturating_mul(2_u64.saturating_pow(retry_count))
+            }
+        };
+        let delay = self
+            .max_delay_in_seconds
+            .map_or(delay, |max_delay| delay.min(u64::from(max_delay)));
+
+        Some(i32::try_from(delay).unwrap_or(i32::MAX))
+    }
+
+    /// Indicates if a retry happening the given number of seconds after the first delivery
+    /// attempt is within the retry window of the policy
+    pub fn is_within_retry_window(&self, elapsed_seconds: i64) -> bool {
+        self.retry_window_in_seconds
+            .is_none_or(|retry_window| elapsed_seconds <= i64::from(retry_window))
+    }
+}
+
+/// Event sink configured on a profile, to which the events sent as outgoing webhooks are also
+/// published. This is meant for merchants receiving a volume of events too high for HTTPS webhooks.
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+#[serde(deny_unknown_fields)]
+pub struct EventSinkConfig {
+    /// Destination to which the events are published
+    pub destination: EventSinkDestination,
+    /// Version of the schema of the published payloads
+    #[serde(default)]
+    #[schema(value_type = EventSinkSchemaVersion, example = "v1")]
+    pub schema_version: enums::EventSinkSchemaVersion,
+    /// Types of the events published to the sink. All events are published if this is not set.
+    #[schema(value_type = Option<Vec<EventType>>, example = json!(["payment_succeeded"]))]
+    pub enabled_events: Option<Vec<enums::EventType>>,
+}
+
+impl_to_sql_from_sql_json!(EventSinkConfig);
+
+impl EventSinkConfig {
+    /// Validates the destination and the event types configured for the sink
+    pub fn validate(&self) -> Result<(), String> {
+        if self
+            .enabled_events
+            .as_ref()
+            .is_some_and(|enabled_events| enabled_events.is_empty())
+        {
+            return Err("enabled_events in event_sink_config should not be empty".to_string());
+        }
+        self.destination.validate()
+    }
+
+    /// Indicates if events of the given type are published to the sink
+    pub fn is_event_enabled(&self, event_type: enums::EventType) -> bool {
+        self.enabled_events
+            .as_ref()
+            .is_none_or(|enabled_events| enabled_events.contains(&event_type))
+    }
+}
+
+/// Destination to which the events of an event sink are published
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum EventSinkDestination {
+    /// A topic of a Kafka cluster operated by the merchant
+    Kafka {
+        /// Bootstrap brokers of the cluster, as `host:port`
+        #[schema(example = json!(["kafka-1.example.com:9093"]))]
+        brokers: Vec<String>,
+        /// Topic to which the events are published
+        #[schema(example = "hyperswitch-events")]
+        topic: String,
+        /// Credentials used to authenticate with the brokers. The connection is always encrypted,
+        /// and is authenticated with SASL only if the credentials are provided.
+        sasl_credentials: Option<KafkaSaslCredentials>,
+    },
+    /// An AWS SNS topic
+    AwsSns {
+        /// ARN of the topic to which the events are published
+        #[schema(example = "arn:aws:sns:us-east-1:123456789012:hyperswitch-events")]
+        topic_arn: String,
+        /// Region of the topic
+        #[schema(example = "us-east-1")]
+        region: String,
+        /// ARN of the role in the merchant account assumed to publish the events
+        #[schema(example = "arn:aws:iam::123456789012:role/hyperswitch-event-publisher")]
+        role_arn: String,
+        /// External ID required by the trust policy of the role, if any
+        #[schema(value_type = Option<String>)]
+        external_id: Option<Secret<String>>,
+    },
+    /// An AWS SQS queue
+    AwsSqs {
+        /// URL of the queue to which the events are sent
+        #[schema(example = "https://sqs.us-east-1.amazonaws.com/123456789012/hyperswitch-events")]
+        queue_url: String,
+        /// Region of the queue
+        #[schema(example = "us-east-1")]
+        region: String,
+        /// ARN of the role in the merchant account assumed to send the events
+        #[schema(example = "arn:aws:iam::123456789012:role/hyperswitch-event-publisher")]
+        role_arn: String,
+        /// External ID required by the trust policy of the role, if any
+        #[schema(value_type = Option<String>)]
+        external_id: Option<Secret<String>>,
+    },
+}
+
+impl EventSinkDestination {
+    fn validate(&self) -> Result<(), String> {
+        match self {
+            Self::Kafka {
+                brokers,
+                topic,
+                sasl_credentials,
+            } => {
+                if brokers.is_empty() || brokers.len() > consts::MAX_EVENT_SINK_KAFKA_BROKERS {
+                    return Err(format!(
+                        "brokers in event_sink_config should contain between 1 and {} brokers",
+                        consts::MAX_EVENT_SINK_KAFKA_BROKERS
+                    ));
+                }
+                if let Some(broker) = brokers.iter().find(|broker| {
+                    broker
+                        .rsplit_once(':')
+                        .is_none_or(|(host, port)| host.is_empty() || port.parse::<u16>().is_err())
+                }) {
+                    return Err(format!(
+                        "broker `{broker}` in event_sink_config should be of the form `host:port`"
+                    ));
+                }
+                // Topic names are restricted to these characters by Kafka
+                if topic.is_empty()
+                    || topic.len() > consts::MAX_EVENT_SINK_KAFKA_TOPIC_LENGTH
+                    || !topic.chars().all(|character| {
+                        character.is_ascii_alphanumeric() || matches!(character, '.' | '_' | '-')
+                    })
+                {
+                    return Err(format!(
+                        "topic in event_sink_config should contain at most {} alphanumeric, `.`, `_` or `-` characters",
+                        consts::MAX_EVENT_SINK_KAFKA_TOPIC_LENGTH
+                    ));
+                }
+                if sasl_credentials.as_ref().is_some_and(|sasl_credentials| {
+                    sasl_credentials.username.peek().is_empty()
+                        || sasl_credentials.password.peek().is_empty()
+                }) {
+                    return Err(
+                        "sasl_credentials in event_sink_config should not contain empty values"
+                            .to_string(),
+                    );
+                }
+                Ok(())
+            }
+            Self::AwsSns {
+                topic_arn,
+                region,
+                role_arn,
+                ..
+            } => {
+                if !topic_arn.starts_with("arn:aws:sns:") {
+                    return Err(
+                        "topic_arn in event_sink_config should be an SNS topic ARN".to_string()
+                    );
+                }
+                Self::validate_aws_region_and_role(region, role_arn)
+            }
+            Self::AwsSqs {
+                queue_url,
+                region,
+                role_arn,
+                ..
+            } => {
+                if !queue_url.starts_with("https://sqs.") {
+                    return Err(
+                        "queue_url in event_sink_config should be an SQS queue URL".to_string()
+                    );
+                }
+                Self::validate_aws_region_and_role(region, role_arn)
+            }
+        }
+    }
+
+    fn validate_aws_region_and_role(region: &str, role_arn: &str) -> Result<(), String> {
+        if region.is_empty() {
+            return Err("region in event_sink_config should not be empty".to_string());
+        }
+        if !role_arn.starts_with("arn:aws:iam::") {
+            return Err("role_arn in event_sink_config should be an IAM role ARN".to_string());
+        }
+        Ok(())
+    }
+
+    /// Name of the kind of destination, used when logging and recording metrics
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Self::Kafka { .. } => "kafka",
+            Self::AwsSns { .. } => "aws_sns",
+            Self::AwsSqs { .. } => "aws_sqs",
+        }
+    }
+}
+
+/// SASL credentials used to authenticate with the Kafka brokers of an event sink
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct KafkaSaslCredentials {
+    /// SASL mechanism supported by the brokers
+    #[schema(value_type = KafkaSaslMechanism, example = "scram_sha512")]
+    pub mechanism: enums::KafkaSaslMechanism,
+    /// Username used to authenticate
+    #[schema(value_type = String)]
+    pub username: Secret<String>,
+    /// Password used to authenticate
+    #[schema(value_type = String)]
+    pub password: Secret<String>,
+}
+
+/// Retries scheduled on a profile for the merchant initiated payments failing with a retriable
+/// decline. Such payments are retried with their saved payment method once the configured
+/// intervals have elapsed, within the reattempt limits of the card networks.
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+#[serde(deny_unknown_fields)]
+pub struct SoftDeclineRetryConfig {
+    /// Decline categories, as mapped in the gateway status mapping, for which payments are retried
+    #[serde(default = "SoftDeclineRetryConfig::default_error_categories")]
+    #[schema(value_type = Vec<ErrorCategory>, example = json!(["soft_decline"]))]
+    pub error_categories: Vec<enums::ErrorCategory>,
+    /// Delays (in seconds) after which the retries are made, each counted from the previous
+    /// decline. The number of delays is the maximum number of retries of a payment.
+    #[schema(example = json!([86400, 259200, 604800]))]
+    pub retry_intervals_in_seconds: Vec<u32>,
+}
+
+impl_to_sql_from_sql_json!(SoftDeclineRetryConfig);
+
+impl SoftDeclineRetryConfig {
+    fn default_error_categories() -> Vec<enums::ErrorCategory> {
+        vec![enums::ErrorCategory::SoftDecline]
+    }
+
+    /// Validates the decline categories and retry intervals configured
+    pub fn validate(&self) -> Result<(), String> {
+        if self.error_categories.is_empty() {
+            return Err(
+                "error_categories in soft_decline_retry_config should not be empty".to_string(),
+            );
+        }
+        // Issuers never approve a retry of a hard decline, and retrying them is penalised by the
+        // card networks
+        if let Some(error_category) = self.error_categories.iter().find(|error_category| {
+            matches!(
+                error_category,
+                enums::ErrorCategory::HardDecline | enums::ErrorCategory::FrmDecline
+            )
+        }) {
+            return Err(format!(
+                "error_category `{error_category}` cannot be retried in soft_decline_retry_config"
+            ));
+        }
+        if !(1..=consts::MAX_SOFT_DECLINE_RETRY_ATTEMPTS)
+            .contains(&self.retry_intervals_in_seconds.len())
+        {
+            return Err(format!(
+                "retry_intervals_in_seconds in soft_decline_retry_config should contain between 1 and {} intervals",
+                consts::MAX_SOFT_DECLINE_RETRY_ATTEMPTS
+            ));
+        }
+        if self.retry_intervals_in_seconds.iter().any(|interval| {
+            !(consts::MIN_SOFT_DECLINE_RETRY_INTERVAL_IN_SECONDS
+                ..=consts::MAX_SOFT_DECLINE_RETRY_INTERVAL_IN_SECONDS)
+                .contains(interval)
+        }) {
+            return Err(format!(
+                "retry_intervals_in_seconds in soft_decline_retry_config should be between {} and {}",
+                consts::MIN_SOFT_DECLINE_RETRY_INTERVAL_IN_SECONDS,
+                consts::MAX_SOFT_DECLINE_RETRY_INTERVAL_IN_SECONDS
+            ));
+        }
+        Ok(())
+    }
+
+    /// Indicates if payments declined with the given decline category are retried
+    pub fn is_retriable(&self, error_category: enums::ErrorCategory) -> bool {
+        self.error_categories.contains(&error_category)
+    }
+
+    /// Retrieves the delay (in seconds) before the retry with the given zero based index, or
+    /// `None` once the retries are exhausted
+    pub fn get_retry_interval_in_seconds(&self, retry_count: usize) -> Option<u32> {
+        self.retry_intervals_in_seconds.get(retry_count).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_retry_policy_validation() {
+        let get_policy = |rules: serde_json::Value| -> RetryPolicy {
+            serde_json::from_value(serde_json::json!({ "rules": rules })).unwrap()
+        };
+
+        assert!(get_policy(serde_json::json!([
+            {"error_category": "soft_decline", "retry": true, "max_attempts": 2, "delay_in_millis": 500},
+            {"error_category": "hard_decline", "retry": false, "max_attempts": 0}
+        ]))
+        .validate()
+        .is_ok());
+
+        // Duplicate decline categories
+        assert!(get_policy(serde_json::json!([
+            {"error_category": "soft_decline", "retry": true, "max_attempts": 2},
+            {"error_category": "soft_decline", "retry": false, "max_attempts": 0}
+        ]))
+        .validate()
+        .is_err());
+
+        // Attempts out of bounds
+        assert!(get_policy(serde_json::json!([
+            {"error_category": "soft_decline", "retry": true, "max_attempts": 0}
+        ]))
+        .validate()
+        .is_err());
+        assert!(get_policy(serde_json::json!([
+            {"error_category": "soft_decline", "retry": true, "max_attempts": consts::MAX_RETRY_POLICY_ATTEMPTS + 1}
+        ]))
+        .validate()
+        .is_err());
+
+        // Delay out of bounds
+        assert!(get_policy(serde_json::json!([
+            {"error_category": "soft_decline", "retry": true, "max_attempts": 1, "delay_in_millis": consts::MAX_RETRY_POLICY_DELAY_IN_MILLIS + 1}
+        ]))
+        .validate()
+        .is_err());
+    }
+}
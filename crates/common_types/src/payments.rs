@@ -12,9 +12,13 @@ use diesel::{
     AsExpression, FromSqlRow,
 };
 use error_stack::{Report, Result, ResultExt};
-use euclid::frontend::{
-    ast::Program,
-    dir::{DirKeyKind, EuclidDirFilter},
+use euclid::{
+    dssa::types::EuclidAnalysable,
+    frontend::{
+        ast::Program,
+        dir::{DirKeyKind, DirValue, EuclidDirFilter},
+    },
+    types::Metadata,
 };
 use masking::{ExposeInterface, PeekInterface, Secret};
 use serde::{Deserialize, Serialize};
@@ -157,6 +161,15 @@ pub struct ConditionalConfigs {
     /// Override 3DS
     pub override_3ds: Option<common_enums::AuthenticationType>,
 }
+
+impl EuclidAnalysable for ConditionalConfigs {
+    fn get_dir_value_for_analysis(&self, rule_name: String) -> Vec<(DirValue, Metadata)> {
+        self.override_3ds
+            .map(|override_3ds| override_3ds.get_dir_value_for_analysis(rule_name))
+            .unwrap_or_default()
+    }
+}
+
 impl EuclidDirFilter for ConditionalConfigs {
     const ALLOWED: &'static [DirKeyKind] = &[
         DirKeyKind::PaymentMethod,
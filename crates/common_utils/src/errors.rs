@@ -186,6 +186,8 @@ pub enum KeyManagerClientError {
     Unexpected(bytes::Bytes),
     #[error("Response Decoding failed")]
     ResponseDecodingFailed,
+    #[error("KeyManager is unavailable as the circuit breaker is open")]
+    CircuitOpen,
 }
 
 #[allow(missing_docs)]
@@ -195,6 +197,8 @@ pub enum KeyManagerError {
     KeyAddFailed,
     #[error("Failed to transfer the key to the KeyManager")]
     KeyTransferFailed,
+    #[error("Failed to rotate the key in the KeyManager")]
+    KeyRotationFailed,
     #[error("Failed to Encrypt the data in the KeyManager")]
     EncryptionFailed,
     #[error("Failed to Decrypt the data in the KeyManager")]
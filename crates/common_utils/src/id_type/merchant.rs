@@ -132,6 +132,11 @@ impl MerchantId {
         format!("surcharge_dsl_{}", self.get_string_repr())
     }
 
+    /// get_three_ds_decision_dsl_key
+    pub fn get_three_ds_decision_dsl_key(&self) -> String {
+        format!("three_ds_decision_dsl_{}", self.get_string_repr())
+    }
+
     /// get_dsk_key
     pub fn get_dsl_config(&self) -> String {
         format!("dsl_{}", self.get_string_repr())
@@ -173,6 +178,11 @@ impl MerchantId {
         format!("payment_method_surcharge_id_{}", self.get_string_repr())
     }
 
+    /// get_three_ds_decision_routing_id
+    pub fn get_three_ds_decision_routing_id(&self) -> String {
+        format!("three_ds_decision_id_{}", self.get_string_repr())
+    }
+
     /// get_webhook_config_disabled_events_key
     pub fn get_webhook_config_disabled_events_key(&self, connector_id: &str) -> String {
         format!(
@@ -22,6 +22,11 @@ impl MerchantConnectorAccountId {
     pub fn wrap(merchant_connector_account_id: String) -> CustomResult<Self, ValidationError> {
         Self::try_from(std::borrow::Cow::from(merchant_connector_account_id))
     }
+
+    /// Get the key for storing the outbound rate limits of the merchant connector account
+    pub fn get_outbound_rate_limit_key(&self) -> String {
+        format!("outbound_rate_limit_{}", self.get_string_repr())
+    }
 }
 
 impl FromStr for MerchantConnectorAccountId {
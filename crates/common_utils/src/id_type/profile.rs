@@ -22,6 +22,31 @@ impl crate::events::ApiEventMetric for ProfileId {
     }
 }
 
+impl ProfileId {
+    /// get_surcharge_dsl_key
+    pub fn get_surcharge_dsl_key(&self) -> String {
+        format!("profile_surcharge_dsl_{}", self.get_string_repr())
+    }
+
+    /// get_payment_method_surcharge_routing_id
+    pub fn get_payment_method_surcharge_routing_id(&self) -> String {
+        format!(
+            "profile_payment_method_surcharge_id_{}",
+            self.get_string_repr()
+        )
+    }
+
+    /// get_dispute_decision_config_key
+    pub fn get_dispute_decision_config_key(&self) -> String {
+        format!("profile_dispute_decision_{}", self.get_string_repr())
+    }
+
+    /// get_frm_velocity_rules_key
+    pub fn get_frm_velocity_rules_key(&self) -> String {
+        format!("profile_frm_velocity_rules_{}", self.get_string_repr())
+    }
+}
+
 impl FromStr for ProfileId {
     type Err = error_stack::Report<crate::errors::ValidationError>;
 
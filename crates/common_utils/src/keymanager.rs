@@ -1,30 +1,242 @@
 //! Consists of all the common functions to use the Keymanager.
 
 use core::fmt::Debug;
-use std::str::FromStr;
+use std::{
+    str::FromStr,
+    sync::{Mutex, PoisonError, RwLock},
+    time::{Duration, Instant},
+};
 
 use base64::Engine;
 use error_stack::ResultExt;
 use http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode};
 use masking::{PeekInterface, StrongSecret};
-use once_cell::sync::OnceCell;
+use once_cell::sync::Lazy;
+use rand::Rng;
 use router_env::{instrument, logger, tracing};
 
 use crate::{
     consts::{BASE64_ENGINE, TENANT_HEADER},
     errors,
     types::keymanager::{
-        BatchDecryptDataRequest, DataKeyCreateResponse, DecryptDataRequest,
-        EncryptionCreateRequest, EncryptionTransferRequest, GetKeymanagerTenant, KeyManagerState,
-        TransientBatchDecryptDataRequest, TransientDecryptDataRequest,
+        BatchDecryptDataRequest, BatchDecryptDataResponse, BatchEncryptDataRequest,
+        BatchEncryptDataResponse, DataKeyCreateResponse, DecryptDataRequest, DecryptDataResponse,
+        EncryptDataRequest, EncryptDataResponse, EncryptionCreateRequest, EncryptionRotateRequest,
+        EncryptionTransferRequest, GetKeymanagerTenant, KeyManagerCircuitBreakerConfig,
+        KeyManagerRetryConfig, KeyManagerState, TransientBatchDecryptDataRequest,
+        TransientDecryptDataRequest,
     },
 };
 
 const CONTENT_TYPE: &str = "Content-Type";
-static ENCRYPTION_API_CLIENT: OnceCell<reqwest::Client> = OnceCell::new();
+static ENCRYPTION_API_CLIENT: Lazy<RwLock<Option<EncryptionApiClient>>> =
+    Lazy::new(|| RwLock::new(None));
 static DEFAULT_ENCRYPTION_VERSION: &str = "v1";
 #[cfg(feature = "km_forward_x_request_id")]
 const X_REQUEST_ID: &str = "X-Request-Id";
+/// Endpoints which can be safely retried, as calling them multiple times has no side effects
+const IDEMPOTENT_ENDPOINTS: [&str; 2] = ["data/encrypt", "data/decrypt"];
+const HEALTH_ENDPOINT: &str = "health";
+/// Time after which the health check of the Keymanager is considered to have failed
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+static CIRCUIT_BREAKER: Lazy<Mutex<CircuitBreaker>> =
+    Lazy::new(|| Mutex::new(CircuitBreaker::default()));
+
+/// Interface for serving the key manager requests in process, in place of the key manager service.
+/// The requests and responses are the same as the ones exchanged with the key manager service.
+#[async_trait::async_trait]
+pub trait KeyManagerInterface: Debug + Send + Sync {
+    /// Creates a data key for the identifier
+    async fn create_key(
+        &self,
+        request: EncryptionCreateRequest,
+    ) -> errors::CustomResult<DataKeyCreateResponse, errors::KeyManagerClientError>;
+
+    /// Imports an existing data key for the identifier
+    async fn transfer_key(
+        &self,
+        request: EncryptionTransferRequest,
+    ) -> errors::CustomResult<DataKeyCreateResponse, errors::KeyManagerClientError>;
+
+    /// Rotates the data key of the identifier
+    async fn rotate_key(
+        &self,
+        request: EncryptionRotateRequest,
+    ) -> errors::CustomResult<DataKeyCreateResponse, errors::KeyManagerClientError>;
+
+    /// Encrypts a single value
+    async fn encrypt_data(
+        &self,
+        request: EncryptDataRequest,
+    ) -> errors::CustomResult<EncryptDataResponse, errors::KeyManagerClientError>;
+
+    /// Encrypts a group of values
+    async fn batch_encrypt_data(
+        &self,
+        request: BatchEncryptDataRequest,
+    ) -> errors::CustomResult<BatchEncryptDataResponse, errors::KeyManagerClientError>;
+
+    /// Decrypts a single value
+    async fn decrypt_data(
+        &self,
+        request: DecryptDataRequest,
+    ) -> errors::CustomResult<DecryptDataResponse, errors::KeyManagerClientError>;
+
+    /// Decrypts a group of values
+    async fn batch_decrypt_data(
+        &self,
+        request: BatchDecryptDataRequest,
+    ) -> errors::CustomResult<BatchDecryptDataResponse, errors::KeyManagerClientError>;
+}
+
+/// Client for the key manager, along with the fingerprint of the mTLS certificates it was built with
+struct EncryptionApiClient {
+    client: reqwest::Client,
+    certificates_fingerprint: Option<blake3::Hash>,
+}
+
+mod metrics {
+    use router_env::{counter_metric, gauge_metric, global_meter};
+
+    global_meter!(GLOBAL_METER, "ROUTER_API");
+
+    counter_metric!(KEY_MANAGER_REQUEST_RETRIES, GLOBAL_METER);
+    counter_metric!(KEY_MANAGER_CIRCUIT_BREAKER_REJECTIONS, GLOBAL_METER);
+    // 0 - closed, 1 - open, 2 - half open
+    gauge_metric!(KEY_MANAGER_CIRCUIT_BREAKER_STATE, GLOBAL_METER);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CircuitState {
+    /// Requests are sent to the key manager
+    Closed,
+    /// Requests are rejected without calling the key manager
+    Open,
+    /// A single trial request is allowed to check whether the key manager has recovered
+    HalfOpen,
+}
+
+impl CircuitState {
+    fn as_metric_value(self) -> u64 {
+        match self {
+            Self::Closed => 0,
+            Self::Open => 1,
+            Self::HalfOpen => 2,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct CircuitBreaker {
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    trial_in_progress: bool,
+}
+
+impl CircuitBreaker {
+    fn state(&self, config: &KeyManagerCircuitBreakerConfig, now: Instant) -> CircuitState {
+        match self.opened_at {
+            None => CircuitState::Closed,
+            Some(opened_at)
+                if now.saturating_duration_since(opened_at)
+                    >= Duration::from_secs(config.open_duration_in_secs) =>
+            {
+                CircuitState::HalfOpen
+            }
+            Some(_) => CircuitState::Open,
+        }
+    }
+
+    /// Checks whether a request can be sent to the key manager
+    fn try_acquire(&mut self, config: &KeyManagerCircuitBreakerConfig, now: Instant) -> bool {
+        match self.state(config, now) {
+            CircuitState::Closed => true,
+            CircuitState::Open => false,
+            CircuitState::HalfOpen if self.trial_in_progress => false,
+            CircuitState::HalfOpen => {
+                self.trial_in_progress = true;
+                true
+            }
+        }
+    }
+
+    fn record_success(&mut self) {
+        self.consecutive_failures = 0;
+        self.opened_at = None;
+        self.trial_in_progress = false;
+    }
+
+    fn record_failure(&mut self, config: &KeyManagerCircuitBreakerConfig, now: Instant) {
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+        // A failed trial request re-opens the circuit straight away
+        if self.trial_in_progress || self.consecutive_failures >= config.failure_threshold {
+            self.opened_at = Some(now);
+        }
+        self.trial_in_progress = false;
+    }
+}
+
+fn circuit_breaker_try_acquire(config: &KeyManagerCircuitBreakerConfig) -> bool {
+    if !config.enabled {
+        return true;
+    }
+    let now = Instant::now();
+    let mut breaker = CIRCUIT_BREAKER
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    let acquired = breaker.try_acquire(config, now);
+    metrics::KEY_MANAGER_CIRCUIT_BREAKER_STATE
+        .record(breaker.state(config, now).as_metric_value(), &[]);
+    acquired
+}
+
+fn circuit_breaker_record_result<R>(
+    config: &KeyManagerCircuitBreakerConfig,
+    result: &errors::CustomResult<R, errors::KeyManagerClientError>,
+) {
+    if !config.enabled {
+        return;
+    }
+    let now = Instant::now();
+    let mut breaker = CIRCUIT_BREAKER
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    match result {
+        Err(error) if is_key_manager_unavailable(error.current_context()) => {
+            breaker.record_failure(config, now);
+            if breaker.state(config, now) == CircuitState::Open {
+                logger::error!(
+                    consecutive_failures = breaker.consecutive_failures,
+                    "KeyManager circuit breaker is open, falling back to application encryption"
+                );
+            }
+        }
+        _ => breaker.record_success(),
+    }
+    metrics::KEY_MANAGER_CIRCUIT_BREAKER_STATE
+        .record(breaker.state(config, now).as_metric_value(), &[]);
+}
+
+/// Errors which indicate that the key manager could not serve the request, as opposed to the
+/// request itself being invalid
+fn is_key_manager_unavailable(error: &errors::KeyManagerClientError) -> bool {
+    matches!(
+        error,
+        errors::KeyManagerClientError::RequestSendFailed
+            | errors::KeyManagerClientError::RequestNotSent(_)
+            | errors::KeyManagerClientError::InternalServerError(_)
+            | errors::KeyManagerClientError::Unexpected(_)
+    )
+}
+
+/// Exponential backoff with full jitter for the given retry attempt, starting from 1
+fn get_retry_delay(config: &KeyManagerRetryConfig, attempt: u32) -> Duration {
+    let exponential_delay = config
+        .base_delay_in_ms
+        .saturating_mul(2_u64.saturating_pow(attempt.saturating_sub(1)));
+    let max_delay = exponential_delay.min(config.max_delay_in_ms);
+    Duration::from_millis(rand::thread_rng().gen_range(0..=max_delay))
+}
 
 /// Get keymanager client constructed from the url and state
 #[instrument(skip_all)]
@@ -61,7 +273,54 @@ fn get_api_encryption_client(
             .change_context(errors::KeyManagerClientError::ClientConstructionFailed)
     };
 
-    Ok(ENCRYPTION_API_CLIENT.get_or_try_init(get_client)?.clone())
+    // The client is rebuilt whenever the certificates in the state differ from the ones it was
+    // built with, so that rotated certificates are picked up without a restart
+    let certificates_fingerprint = get_certificates_fingerprint(state);
+    if let Some(cached) = ENCRYPTION_API_CLIENT
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .as_ref()
+        .filter(|cached| cached.certificates_fingerprint == certificates_fingerprint)
+    {
+        return Ok(cached.client.clone());
+    }
+
+    let client = get_client()?;
+    let mut cached = ENCRYPTION_API_CLIENT
+        .write()
+        .unwrap_or_else(PoisonError::into_inner);
+    if cached
+        .as_ref()
+        .is_some_and(|cached| cached.certificates_fingerprint != certificates_fingerprint)
+    {
+        logger::info!("Rebuilt the key manager client as the mTLS certificates have changed");
+    }
+    *cached = Some(EncryptionApiClient {
+        client: client.clone(),
+        certificates_fingerprint,
+    });
+
+    Ok(client)
+}
+
+#[cfg(feature = "keymanager_mtls")]
+fn get_certificates_fingerprint(state: &KeyManagerState) -> Option<blake3::Hash> {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(blake3::hash(state.cert.peek().as_bytes()).as_bytes());
+    hasher.update(blake3::hash(state.ca.peek().as_bytes()).as_bytes());
+    Some(hasher.finalize())
+}
+
+#[cfg(not(feature = "keymanager_mtls"))]
+fn get_certificates_fingerprint(_state: &KeyManagerState) -> Option<blake3::Hash> {
+    None
+}
+
+/// Drops the cached key manager client, so that it is built again on the next request
+pub fn reset_encryption_client() {
+    *ENCRYPTION_API_CLIENT
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = None;
 }
 
 /// Generic function to send the request to keymanager
@@ -91,8 +350,40 @@ where
         ))
 }
 
-/// Generic function to call the Keymanager and parse the response back
+/// Calls the health endpoint of the Keymanager through the shared client, bypassing the retries
+/// and the circuit breaker, and returns the time taken by the call
 #[instrument(skip_all)]
+pub async fn health_check_key_manager(
+    state: &KeyManagerState,
+) -> errors::CustomResult<Duration, errors::KeyManagerClientError> {
+    let client = get_api_encryption_client(state)?;
+    let url = reqwest::Url::parse(&format!("{}/{HEALTH_ENDPOINT}", &state.url))
+        .change_context(errors::KeyManagerClientError::UrlEncodingFailed)?;
+
+    let started_at = Instant::now();
+    let response = client
+        .get(url)
+        .timeout(HEALTH_CHECK_TIMEOUT)
+        .send()
+        .await
+        .change_context(errors::KeyManagerClientError::RequestSendFailed)?;
+    let latency = started_at.elapsed();
+
+    if !response.status().is_success() {
+        return Err(errors::KeyManagerClientError::Unexpected(
+            response
+                .bytes()
+                .await
+                .change_context(errors::KeyManagerClientError::ResponseDecodingFailed)?,
+        )
+        .into());
+    }
+
+    Ok(latency)
+}
+
+/// Generic function to call the Keymanager and parse the response back
+#[instrument(skip_all, fields(key_manager_endpoint = endpoint))]
 pub async fn call_encryption_service<T, R>(
     state: &KeyManagerState,
     method: Method,
@@ -106,6 +397,17 @@ where
     let url = format!("{}/{endpoint}", &state.url);
 
     logger::info!(key_manager_request=?request_body);
+    let tenant_id = request_body.get_tenant_id(state);
+    // The request body is converted once, so that it can be sent again while retrying
+    let request_body = serde_json::to_value(ConvertRaw::convert_raw(request_body)?)
+        .change_context(errors::KeyManagerClientError::RequestNotSent(
+            "Unable to serialize request for encryption service".to_string(),
+        ))?;
+
+    if let Some(backend) = &state.backend {
+        return call_key_manager_backend(backend.as_ref(), endpoint, request_body).await;
+    }
+
     let mut header = vec![];
     header.push((
         HeaderName::from_str(CONTENT_TYPE)
@@ -127,19 +429,127 @@ where
     header.push((
         HeaderName::from_str(TENANT_HEADER)
             .change_context(errors::KeyManagerClientError::FailedtoConstructHeader)?,
-        HeaderValue::from_str(request_body.get_tenant_id(state).get_string_repr())
+        HeaderValue::from_str(tenant_id.get_string_repr())
             .change_context(errors::KeyManagerClientError::FailedtoConstructHeader)?,
     ));
 
-    let response = send_encryption_request(
-        state,
-        HeaderMap::from_iter(header.into_iter()),
-        url,
-        method,
-        request_body,
-    )
-    .await
-    .map_err(|err| err.change_context(errors::KeyManagerClientError::RequestSendFailed))?;
+    // Propagate the trace context, so that the request can be traced across services
+    for (name, value) in router_env::logger::get_trace_context_headers() {
+        header.push((
+            HeaderName::from_str(&name)
+                .change_context(errors::KeyManagerClientError::FailedtoConstructHeader)?,
+            HeaderValue::from_str(&value)
+                .change_context(errors::KeyManagerClientError::FailedtoConstructHeader)?,
+        ));
+    }
+
+    let headers = HeaderMap::from_iter(header.into_iter());
+    let max_retries = if IDEMPOTENT_ENDPOINTS.contains(&endpoint) {
+        state.retry_config.max_retries
+    } else {
+        0
+    };
+
+    let mut attempt = 0;
+    loop {
+        if !circuit_breaker_try_acquire(&state.circuit_breaker_config) {
+            metrics::KEY_MANAGER_CIRCUIT_BREAKER_REJECTIONS.add(1, &[]);
+            return Err(errors::KeyManagerClientError::CircuitOpen.into());
+        }
+
+        let result = execute_encryption_request(
+            state,
+            headers.clone(),
+            url.clone(),
+            method.clone(),
+            request_body.clone(),
+        )
+        .await;
+        circuit_breaker_record_result(&state.circuit_breaker_config, &result);
+
+        match result {
+            Err(error)
+                if attempt < max_retries && is_key_manager_unavailable(error.current_context()) =>
+            {
+                attempt += 1;
+                let delay = get_retry_delay(&state.retry_config, attempt);
+                logger::warn!(?error, attempt, ?delay, "Retrying request to KeyManager");
+                metrics::KEY_MANAGER_REQUEST_RETRIES.add(1, &[]);
+                tokio::time::sleep(delay).await;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Serves the request with the configured backend instead of calling the Keymanager
+async fn call_key_manager_backend<R>(
+    backend: &dyn KeyManagerInterface,
+    endpoint: &str,
+    request_body: serde_json::Value,
+) -> errors::CustomResult<R, errors::KeyManagerClientError>
+where
+    R: serde::de::DeserializeOwned,
+{
+    fn parse<T: serde::de::DeserializeOwned>(
+        request_body: serde_json::Value,
+    ) -> errors::CustomResult<T, errors::KeyManagerClientError> {
+        serde_json::from_value(request_body).change_context(
+            errors::KeyManagerClientError::RequestNotSent(
+                "Unable to parse request for key manager backend".to_string(),
+            ),
+        )
+    }
+
+    // Single and batch data requests share the endpoint, and differ in the shape of `data`
+    let is_batch_request = request_body
+        .get("data")
+        .is_some_and(serde_json::Value::is_object);
+    let response = match (endpoint, is_batch_request) {
+        ("key/create", _) => serde_json::to_value(backend.create_key(parse(request_body)?).await?),
+        ("key/transfer", _) => {
+            serde_json::to_value(backend.transfer_key(parse(request_body)?).await?)
+        }
+        ("key/rotate", _) => serde_json::to_value(backend.rotate_key(parse(request_body)?).await?),
+        ("data/encrypt", false) => {
+            serde_json::to_value(backend.encrypt_data(parse(request_body)?).await?)
+        }
+        ("data/encrypt", true) => {
+            serde_json::to_value(backend.batch_encrypt_data(parse(request_body)?).await?)
+        }
+        ("data/decrypt", false) => {
+            serde_json::to_value(backend.decrypt_data(parse(request_body)?).await?)
+        }
+        ("data/decrypt", true) => {
+            serde_json::to_value(backend.batch_decrypt_data(parse(request_body)?).await?)
+        }
+        _ => {
+            return Err(errors::KeyManagerClientError::BadRequest(
+                format!("Endpoint {endpoint} is not supported by the key manager backend").into(),
+            )
+            .into())
+        }
+    }
+    .change_context(errors::KeyManagerClientError::ResponseDecodingFailed)?;
+
+    serde_json::from_value(response)
+        .change_context(errors::KeyManagerClientError::ResponseDecodingFailed)
+}
+
+/// Sends a single request to the Keymanager and parses the response back
+async fn execute_encryption_request<R>(
+    state: &KeyManagerState,
+    headers: HeaderMap,
+    url: String,
+    method: Method,
+    request_body: serde_json::Value,
+) -> errors::CustomResult<R, errors::KeyManagerClientError>
+where
+    R: serde::de::DeserializeOwned,
+{
+    let response = send_encryption_request(state, headers, url, method, request_body)
+        .await
+        .map_err(|err| err.change_context(errors::KeyManagerClientError::RequestSendFailed))?;
 
     logger::info!(key_manager_response=?response);
 
@@ -251,3 +661,84 @@ pub async fn transfer_key_to_key_manager(
         .await
         .change_context(errors::KeyManagerError::KeyTransferFailed)
 }
+
+/// A function to rotate the key in keymanager
+#[instrument(skip_all)]
+pub async fn rotate_key_in_key_manager(
+    state: &KeyManagerState,
+    request_body: EncryptionRotateRequest,
+) -> errors::CustomResult<DataKeyCreateResponse, errors::KeyManagerError> {
+    call_encryption_service(state, Method::POST, "key/rotate", request_body)
+        .await
+        .change_context(errors::KeyManagerError::KeyRotationFailed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: KeyManagerCircuitBreakerConfig = KeyManagerCircuitBreakerConfig {
+        enabled: true,
+        failure_threshold: 2,
+        open_duration_in_secs: 30,
+    };
+
+    #[test]
+    fn test_circuit_breaker_opens_after_consecutive_failures() {
+        let now = Instant::now();
+        let mut breaker = CircuitBreaker::default();
+
+        breaker.record_failure(&CONFIG, now);
+        assert_eq!(breaker.state(&CONFIG, now), CircuitState::Closed);
+        breaker.record_success();
+        breaker.record_failure(&CONFIG, now);
+        assert_eq!(breaker.state(&CONFIG, now), CircuitState::Closed);
+        breaker.record_failure(&CONFIG, now);
+        assert_eq!(breaker.state(&CONFIG, now), CircuitState::Open);
+        assert!(!breaker.try_acquire(&CONFIG, now));
+    }
+
+    #[test]
+    fn test_circuit_breaker_allows_single_trial_when_half_open() {
+        let now = Instant::now();
+        let after_open_duration = now + Duration::from_secs(CONFIG.open_duration_in_secs);
+        let mut breaker = CircuitBreaker::default();
+        breaker.record_failure(&CONFIG, now);
+        breaker.record_failure(&CONFIG, now);
+
+        assert_eq!(
+            breaker.state(&CONFIG, after_open_duration),
+            CircuitState::HalfOpen
+        );
+        assert!(breaker.try_acquire(&CONFIG, after_open_duration));
+        assert!(!breaker.try_acquire(&CONFIG, after_open_duration));
+
+        breaker.record_failure(&CONFIG, after_open_duration);
+        assert_eq!(
+            breaker.state(&CONFIG, after_open_duration),
+            CircuitState::Open
+        );
+
+        let after_reopen_duration =
+            after_open_duration + Duration::from_secs(CONFIG.open_duration_in_secs);
+        assert!(breaker.try_acquire(&CONFIG, after_reopen_duration));
+        breaker.record_success();
+        assert_eq!(
+            breaker.state(&CONFIG, after_reopen_duration),
+            CircuitState::Closed
+        );
+    }
+
+    #[test]
+    fn test_retry_delay_is_bounded() {
+        let config = KeyManagerRetryConfig {
+            max_retries: 5,
+            base_delay_in_ms: 100,
+            max_delay_in_ms: 250,
+        };
+
+        assert!(get_retry_delay(&config, 1) <= Duration::from_millis(100));
+        assert!(get_retry_delay(&config, 2) <= Duration::from_millis(200));
+        assert!(get_retry_delay(&config, 5) <= Duration::from_millis(250));
+    }
+}
@@ -765,6 +765,123 @@ pub struct TimeRange {
     pub end_time: Option<PrimitiveDateTime>,
 }
 
+/// An opaque keyset cursor for paginating list endpoints.
+///
+/// It holds the sort timestamp and the identifier of the last object of a page, and is exposed
+/// to clients as a url safe base64 encoded string. Fetching the next page continues strictly after
+/// the `(sort_key, id)` pair, so rows inserted in between pages never shift the results.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ListCursor {
+    /// The value of the sort timestamp of the last object returned
+    pub sort_key: PrimitiveDateTime,
+    /// The identifier of the last object returned, used to break ties on `sort_key`
+    pub id: String,
+}
+
+/// Timestamps are kept as unix microseconds, matching the precision of postgres timestamps, so
+/// that the decoded cursor compares equal to the stored value of the last row.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ListCursorRepr {
+    sort_key: i64,
+    id: String,
+}
+
+impl ListCursor {
+    /// Create a new cursor pointing after the given sort key and identifier
+    pub fn new(sort_key: PrimitiveDateTime, id: impl Into<String>) -> Self {
+        Self {
+            sort_key,
+            id: id.into(),
+        }
+    }
+
+    /// Encode the cursor into the string handed out to clients
+    pub fn encode(&self) -> CustomResult<String, ParsingError> {
+        use base64::Engine;
+
+        let sort_key = i64::try_from(self.sort_key.assume_utc().unix_timestamp_nanos() / 1000)
+            .change_context(ParsingError::EncodeError("json"))
+            .attach_printable("List cursor timestamp is out of range")?;
+        let repr = ListCursorRepr {
+            sort_key,
+            id: self.id.clone(),
+        };
+        let json = serde_json::to_vec(&repr)
+            .change_context(ParsingError::EncodeError("json"))
+            .attach_printable("Failed to serialize list cursor")?;
+
+        Ok(consts::BASE64_ENGINE_URL_SAFE.encode(json))
+    }
+
+    /// Decode a cursor previously returned by [`ListCursor::encode`]
+    pub fn decode(cursor: &str) -> CustomResult<Self, ParsingError> {
+        use base64::Engine;
+
+        let json = consts::BASE64_ENGINE_URL_SAFE
+            .decode(cursor)
+            .change_context(ParsingError::StructParseFailure("ListCursor"))
+            .attach_printable("List cursor is not valid base64")?;
+        let ListCursorRepr { sort_key, id } = serde_json::from_slice(&json)
+            .change_context(ParsingError::StructParseFailure("ListCursor"))
+            .attach_printable("List cursor has an invalid structure")?;
+        let sort_key = time::OffsetDateTime::from_unix_timestamp_nanos(i128::from(sort_key) * 1000)
+            .change_context(ParsingError::DateTimeParsingError)
+            .attach_printable("List cursor timestamp is out of range")?;
+
+        Ok(Self {
+            sort_key: PrimitiveDateTime::new(sort_key.date(), sort_key.time()),
+            id,
+        })
+    }
+}
+
+impl Serialize for ListCursor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let encoded = self.encode().map_err(serde::ser::Error::custom)?;
+        serializer.serialize_str(&encoded)
+    }
+}
+
+impl<'de> Deserialize<'de> for ListCursor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let cursor = String::deserialize(deserializer)?;
+        Self::decode(&cursor).map_err(|_| serde::de::Error::custom("invalid list cursor"))
+    }
+}
+
+#[cfg(test)]
+mod list_cursor_tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn list_cursor_round_trip() {
+        let sort_key = PrimitiveDateTime::new(
+            time::Date::from_calendar_date(2024, time::Month::March, 1).unwrap(),
+            time::Time::from_hms_micro(10, 15, 30, 123_456).unwrap(),
+        );
+        let cursor = ListCursor::new(sort_key, "pay_abc123");
+        let encoded = cursor.encode().unwrap();
+        assert_eq!(ListCursor::decode(&encoded).unwrap(), cursor);
+
+        let from_json: ListCursor =
+            serde_json::from_value(serde_json::to_value(&cursor).unwrap()).unwrap();
+        assert_eq!(from_json, cursor);
+    }
+
+    #[test]
+    fn list_cursor_rejects_garbage() {
+        assert!(ListCursor::decode("not a cursor").is_err());
+        assert!(serde_json::from_str::<ListCursor>("\"e30\"").is_err());
+    }
+}
+
 #[cfg(test)]
 mod amount_conversion_tests {
     #![allow(clippy::unwrap_used)]
@@ -50,6 +50,54 @@ pub struct KeyManagerState {
     #[cfg(feature = "keymanager_mtls")]
     pub cert: Secret<String>,
     pub infra_values: Option<serde_json::Value>,
+    pub retry_config: KeyManagerRetryConfig,
+    pub circuit_breaker_config: KeyManagerCircuitBreakerConfig,
+    /// Backend serving the requests in process, the key manager service is called if not set
+    #[cfg(feature = "keymanager")]
+    pub backend: Option<std::sync::Arc<dyn crate::keymanager::KeyManagerInterface>>,
+}
+
+/// Retry configuration for the idempotent calls made to the key manager
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default)]
+pub struct KeyManagerRetryConfig {
+    /// Maximum number of retries after the initial attempt
+    pub max_retries: u32,
+    /// Delay in milliseconds before the first retry, doubled for every subsequent retry
+    pub base_delay_in_ms: u64,
+    /// Upper bound in milliseconds for the delay between two attempts
+    pub max_delay_in_ms: u64,
+}
+
+impl Default for KeyManagerRetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 2,
+            base_delay_in_ms: 50,
+            max_delay_in_ms: 1000,
+        }
+    }
+}
+
+/// Circuit breaker configuration for the calls made to the key manager
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default)]
+pub struct KeyManagerCircuitBreakerConfig {
+    pub enabled: bool,
+    /// Number of consecutive failed calls after which the circuit is opened
+    pub failure_threshold: u32,
+    /// Duration in seconds for which calls are short circuited once the circuit is opened
+    pub open_duration_in_secs: u64,
+}
+
+impl Default for KeyManagerCircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            failure_threshold: 5,
+            open_duration_in_secs: 30,
+        }
+    }
 }
 
 impl KeyManagerState {
@@ -96,6 +144,12 @@ pub struct EncryptionTransferRequest {
     pub key: String,
 }
 
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+pub struct EncryptionRotateRequest {
+    #[serde(flatten)]
+    pub identifier: Identifier,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct DataKeyCreateResponse {
     #[serde(flatten)]
@@ -112,6 +166,7 @@ pub struct BatchEncryptDataRequest {
 
 impl_get_tenant_for_request!(EncryptionCreateRequest);
 impl_get_tenant_for_request!(EncryptionTransferRequest);
+impl_get_tenant_for_request!(EncryptionRotateRequest);
 impl_get_tenant_for_request!(BatchEncryptDataRequest);
 
 impl<S> From<(Secret<Vec<u8>, S>, Identifier)> for EncryptDataRequest
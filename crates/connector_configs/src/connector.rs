@@ -267,6 +267,7 @@ pub struct ConnectorConfig {
     pub fiuu: Option<ConnectorTomlConfig>,
     pub flexiti: Option<ConnectorTomlConfig>,
     pub forte: Option<ConnectorTomlConfig>,
+    pub genericrest: Option<ConnectorTomlConfig>,
     pub getnet: Option<ConnectorTomlConfig>,
     pub globalpay: Option<ConnectorTomlConfig>,
     pub globepay: Option<ConnectorTomlConfig>,
@@ -478,6 +479,7 @@ impl ConnectorConfig {
             Connector::Fiuu => Ok(connector_data.fiuu),
             Connector::Flexiti => Ok(connector_data.flexiti),
             Connector::Forte => Ok(connector_data.forte),
+            Connector::Genericrest => Ok(connector_data.genericrest),
             Connector::Getnet => Ok(connector_data.getnet),
             Connector::Globalpay => Ok(connector_data.globalpay),
             Connector::Globepay => Ok(connector_data.globepay),
@@ -558,6 +560,8 @@ impl ConnectorConfig {
             Connector::DummyConnector6 => Ok(connector_data.dummy_connector),
             #[cfg(feature = "dummy_connector")]
             Connector::DummyConnector7 => Ok(connector_data.paypal_test),
+            #[cfg(feature = "dummy_connector")]
+            Connector::DummyAdvancedConnector => Ok(connector_data.dummy_connector),
             Connector::Netcetera => Ok(connector_data.netcetera),
             Connector::CtpMastercard => Ok(connector_data.ctp_mastercard),
             Connector::Xendit => Ok(connector_data.xendit),
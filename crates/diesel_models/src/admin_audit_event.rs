@@ -0,0 +1,64 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+
+use crate::schema::admin_audit_events;
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable)]
+#[diesel(table_name = admin_audit_events)]
+pub struct AdminAuditEventNew {
+    pub id: String,
+    pub org_id: Option<common_utils::id_type::OrganizationId>,
+    pub merchant_id: Option<common_utils::id_type::MerchantId>,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub actor_id: Option<String>,
+    pub auth_type: String,
+    pub ip_address: Option<String>,
+    pub api_flow: String,
+    pub http_method: String,
+    pub url_path: String,
+    pub request_id: Option<String>,
+    pub status_code: i64,
+    /// The masked request body of the call
+    pub request_body: Option<serde_json::Value>,
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = admin_audit_events, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct AdminAuditEvent {
+    pub id: String,
+    pub org_id: Option<common_utils::id_type::OrganizationId>,
+    pub merchant_id: Option<common_utils::id_type::MerchantId>,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub actor_id: Option<String>,
+    pub auth_type: String,
+    pub ip_address: Option<String>,
+    pub api_flow: String,
+    pub http_method: String,
+    pub url_path: String,
+    pub request_id: Option<String>,
+    pub status_code: i64,
+    /// The masked request body of the call
+    pub request_body: Option<serde_json::Value>,
+    pub created_at: time::PrimitiveDateTime,
+}
+
+/// The entity whose audit events are listed
+#[derive(Clone, Debug)]
+pub enum AdminAuditEventEntity {
+    Merchant(common_utils::id_type::MerchantId),
+    Organization(common_utils::id_type::OrganizationId),
+}
+
+/// Filters for listing audit events of a merchant or an organization, newest first.
+#[derive(Clone, Debug, Default)]
+pub struct AdminAuditEventListConstraints {
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub actor_id: Option<String>,
+    pub api_flow: Option<String>,
+    pub auth_type: Option<String>,
+    pub created_after: Option<time::PrimitiveDateTime>,
+    pub created_before: Option<time::PrimitiveDateTime>,
+    /// Only events older than this event are returned
+    pub starting_after: Option<String>,
+    pub limit: i64,
+}
@@ -18,6 +18,12 @@ pub struct ApiKey {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    /// Scopes restricting the resources the key can access, all the resources being accessible
+    /// when absent
+    pub scopes: Option<Vec<Option<common_enums::ApiKeyScope>>>,
+    /// CIDR ranges of the IP addresses the key can be used from, the key being usable from any IP
+    /// address when absent
+    pub allowed_ip_ranges: Option<Vec<Option<String>>>,
 }
 
 #[derive(Debug, Insertable)]
@@ -32,6 +38,12 @@ pub struct ApiKeyNew {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    /// Scopes restricting the resources the key can access, all the resources being accessible
+    /// when absent
+    pub scopes: Option<Vec<Option<common_enums::ApiKeyScope>>>,
+    /// CIDR ranges of the IP addresses the key can be used from, the key being usable from any IP
+    /// address when absent
+    pub allowed_ip_ranges: Option<Vec<Option<String>>>,
 }
 
 #[derive(Debug)]
@@ -41,6 +53,7 @@ pub enum ApiKeyUpdate {
         description: Option<String>,
         expires_at: Option<Option<PrimitiveDateTime>>,
         last_used: Option<PrimitiveDateTime>,
+        allowed_ip_ranges: Option<Option<Vec<Option<String>>>>,
     },
     LastUsedUpdate {
         last_used: PrimitiveDateTime,
@@ -54,6 +67,7 @@ pub(crate) struct ApiKeyUpdateInternal {
     pub description: Option<String>,
     pub expires_at: Option<Option<PrimitiveDateTime>>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub allowed_ip_ranges: Option<Option<Vec<Option<String>>>>,
 }
 
 impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
@@ -64,17 +78,20 @@ impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
                 description,
                 expires_at,
                 last_used,
+                allowed_ip_ranges,
             } => Self {
                 name,
                 description,
                 expires_at,
                 last_used,
+                allowed_ip_ranges,
             },
             ApiKeyUpdate::LastUsedUpdate { last_used } => Self {
                 last_used: Some(last_used),
                 name: None,
                 description: None,
                 expires_at: None,
+                allowed_ip_ranges: None,
             },
         }
     }
@@ -84,6 +101,20 @@ impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
 #[diesel(sql_type = diesel::sql_types::Text)]
 pub struct HashedApiKey(String);
 
+impl ApiKey {
+    pub fn get_scopes(&self) -> Option<Vec<common_enums::ApiKeyScope>> {
+        self.scopes
+            .as_ref()
+            .map(|scopes| scopes.iter().flatten().copied().collect())
+    }
+
+    pub fn get_allowed_ip_ranges(&self) -> Option<Vec<String>> {
+        self.allowed_ip_ranges
+            .as_ref()
+            .map(|allowed_ip_ranges| allowed_ip_ranges.iter().flatten().cloned().collect())
+    }
+}
+
 impl HashedApiKey {
     pub fn into_inner(self) -> String {
         self.0
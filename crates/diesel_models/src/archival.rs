@@ -0,0 +1,18 @@
+/// Hot tables of the payments, whose rows are moved to their archive tables once they are past
+/// the archival age. The tables are archived in this order, and a row is only archived once no
+/// row of the same payment is left in the tables before it, so that a payment is archived after
+/// its refunds and attempts, even when they were created later on.
+#[derive(Clone, Copy, Debug, strum::Display, strum::EnumIter)]
+#[strum(serialize_all = "snake_case")]
+pub enum ArchivalTable {
+    Refund,
+    PaymentAttempt,
+    PaymentIntent,
+}
+
+impl ArchivalTable {
+    /// Name of the cold storage table, partitioned by month, which the rows are moved to
+    pub fn get_archive_table_name(self) -> String {
+        format!("{self}_archive")
+    }
+}
@@ -0,0 +1,96 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums as storage_enums,
+    schema::{auth_rate_alert, auth_rate_alert_config},
+};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = auth_rate_alert_config)]
+pub struct AuthRateAlertConfigNew {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub drop_threshold_percentage: f64,
+    pub baseline_window_in_days: i32,
+    pub evaluation_window_in_minutes: i32,
+    pub min_attempts: i64,
+    pub webhook_url: Option<String>,
+    pub recipients: serde_json::Value,
+    pub is_enabled: bool,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Deserialize, Serialize)]
+#[diesel(
+    table_name = auth_rate_alert_config,
+    primary_key(merchant_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct AuthRateAlertConfig {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub drop_threshold_percentage: f64,
+    pub baseline_window_in_days: i32,
+    pub evaluation_window_in_minutes: i32,
+    pub min_attempts: i64,
+    pub webhook_url: Option<String>,
+    pub recipients: serde_json::Value,
+    pub is_enabled: bool,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = auth_rate_alert_config, treat_none_as_null = true)]
+pub struct AuthRateAlertConfigUpdate {
+    pub drop_threshold_percentage: f64,
+    pub baseline_window_in_days: i32,
+    pub evaluation_window_in_minutes: i32,
+    pub min_attempts: i64,
+    pub webhook_url: Option<String>,
+    pub recipients: serde_json::Value,
+    pub is_enabled: bool,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = auth_rate_alert)]
+pub struct AuthRateAlertNew {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub connector: String,
+    pub payment_method: String,
+    pub status: storage_enums::AuthRateAlertStatus,
+    pub baseline_rate: f64,
+    pub current_rate: f64,
+    pub attempt_count: i64,
+    pub triggered_at: time::PrimitiveDateTime,
+    pub resolved_at: Option<time::PrimitiveDateTime>,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Deserialize, Serialize)]
+#[diesel(table_name = auth_rate_alert, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct AuthRateAlert {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub connector: String,
+    pub payment_method: String,
+    pub status: storage_enums::AuthRateAlertStatus,
+    pub baseline_rate: f64,
+    pub current_rate: f64,
+    pub attempt_count: i64,
+    pub triggered_at: time::PrimitiveDateTime,
+    pub resolved_at: Option<time::PrimitiveDateTime>,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = auth_rate_alert)]
+pub struct AuthRateAlertUpdate {
+    pub status: storage_enums::AuthRateAlertStatus,
+    pub current_rate: f64,
+    pub attempt_count: i64,
+    pub resolved_at: Option<time::PrimitiveDateTime>,
+    pub modified_at: time::PrimitiveDateTime,
+}
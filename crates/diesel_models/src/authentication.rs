@@ -203,6 +203,9 @@ pub enum AuthenticationUpdate {
         trans_status: common_enums::TransactionStatus,
         authentication_status: common_enums::AuthenticationStatus,
     },
+    PaymentIdUpdate {
+        payment_id: common_utils::id_type::PaymentId,
+    },
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, AsChangeset, Serialize, Deserialize)]
@@ -249,6 +252,7 @@ pub struct AuthenticationUpdateInternal {
     pub challenge_code_reason: Option<String>,
     pub message_extension: Option<pii::SecretSerdeValue>,
     pub challenge_request_key: Option<String>,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
 }
 
 impl Default for AuthenticationUpdateInternal {
@@ -294,6 +298,7 @@ impl Default for AuthenticationUpdateInternal {
             challenge_code_reason: Default::default(),
             message_extension: Default::default(),
             challenge_request_key: Default::default(),
+            payment_id: Default::default(),
         }
     }
 }
@@ -341,6 +346,7 @@ impl AuthenticationUpdateInternal {
             challenge_code_reason,
             message_extension,
             challenge_request_key,
+            payment_id,
         } = self;
         Authentication {
             connector_authentication_id: connector_authentication_id
@@ -387,6 +393,7 @@ impl AuthenticationUpdateInternal {
             challenge_code_reason: challenge_code_reason.or(source.challenge_code_reason),
             message_extension: message_extension.or(source.message_extension),
             challenge_request_key: challenge_request_key.or(source.challenge_request_key),
+            payment_id: payment_id.or(source.payment_id),
             ..source
         }
     }
@@ -545,6 +552,10 @@ impl From<AuthenticationUpdate> for AuthenticationUpdateInternal {
                 authentication_status: Some(authentication_status),
                 ..Default::default()
             },
+            AuthenticationUpdate::PaymentIdUpdate { payment_id } => Self {
+                payment_id: Some(payment_id),
+                ..Default::default()
+            },
         }
     }
 }
@@ -11,6 +11,7 @@ pub struct BlocklistNew {
     pub data_kind: common_enums::BlocklistDataKind,
     pub metadata: Option<serde_json::Value>,
     pub created_at: time::PrimitiveDateTime,
+    pub expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[derive(
@@ -23,4 +24,13 @@ pub struct Blocklist {
     pub data_kind: common_enums::BlocklistDataKind,
     pub metadata: Option<serde_json::Value>,
     pub created_at: time::PrimitiveDateTime,
+    pub expires_at: Option<time::PrimitiveDateTime>,
+}
+
+impl Blocklist {
+    /// Expired entries are kept until they are deleted, but no longer block payments
+    pub fn is_active(&self) -> bool {
+        self.expires_at
+            .is_none_or(|expires_at| expires_at > common_utils::date_time::now())
+    }
 }
@@ -80,6 +80,14 @@ pub struct Profile {
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
     pub is_manual_retry_enabled: Option<bool>,
     pub always_enable_overcapture: Option<primitive_wrappers::AlwaysEnableOvercaptureBool>,
+    pub retry_policy: Option<common_types::domain::RetryPolicy>,
+    pub intent_expiry: Option<i64>,
+    pub refund_approval_threshold: Option<common_utils::types::MinorUnit>,
+    pub webhook_retry_policy: Option<common_types::domain::WebhookRetryPolicy>,
+    pub event_sink_config: Option<common_types::domain::EventSinkConfig>,
+    pub is_connector_audit_capture_enabled: Option<bool>,
+    pub soft_decline_retry_config: Option<common_types::domain::SoftDeclineRetryConfig>,
+    pub webhook_endpoint_secrets: Option<Encryption>,
 }
 
 #[cfg(feature = "v1")]
@@ -139,6 +147,14 @@ pub struct ProfileNew {
     pub merchant_country_code: Option<common_types::payments::MerchantCountryCode>,
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
     pub is_manual_retry_enabled: Option<bool>,
+    pub retry_policy: Option<common_types::domain::RetryPolicy>,
+    pub intent_expiry: Option<i64>,
+    pub refund_approval_threshold: Option<common_utils::types::MinorUnit>,
+    pub webhook_retry_policy: Option<common_types::domain::WebhookRetryPolicy>,
+    pub event_sink_config: Option<common_types::domain::EventSinkConfig>,
+    pub is_connector_audit_capture_enabled: Option<bool>,
+    pub soft_decline_retry_config: Option<common_types::domain::SoftDeclineRetryConfig>,
+    pub webhook_endpoint_secrets: Option<Encryption>,
 }
 
 #[cfg(feature = "v1")]
@@ -199,6 +215,14 @@ pub struct ProfileUpdateInternal {
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
     pub is_manual_retry_enabled: Option<bool>,
     pub always_enable_overcapture: Option<primitive_wrappers::AlwaysEnableOvercaptureBool>,
+    pub retry_policy: Option<common_types::domain::RetryPolicy>,
+    pub intent_expiry: Option<i64>,
+    pub refund_approval_threshold: Option<common_utils::types::MinorUnit>,
+    pub webhook_retry_policy: Option<common_types::domain::WebhookRetryPolicy>,
+    pub event_sink_config: Option<common_types::domain::EventSinkConfig>,
+    pub is_connector_audit_capture_enabled: Option<bool>,
+    pub soft_decline_retry_config: Option<common_types::domain::SoftDeclineRetryConfig>,
+    pub webhook_endpoint_secrets: Option<Encryption>,
 }
 
 #[cfg(feature = "v1")]
@@ -256,6 +280,14 @@ impl ProfileUpdateInternal {
             dispute_polling_interval,
             is_manual_retry_enabled,
             always_enable_overcapture,
+            retry_policy,
+            intent_expiry,
+            refund_approval_threshold,
+            webhook_retry_policy,
+            event_sink_config,
+            is_connector_audit_capture_enabled,
+            soft_decline_retry_config,
+            webhook_endpoint_secrets,
         } = self;
         Profile {
             profile_id: source.profile_id,
@@ -345,6 +377,17 @@ impl ProfileUpdateInternal {
             is_manual_retry_enabled: is_manual_retry_enabled.or(source.is_manual_retry_enabled),
             always_enable_overcapture: always_enable_overcapture
                 .or(source.always_enable_overcapture),
+            retry_policy: retry_policy.or(source.retry_policy),
+            intent_expiry: intent_expiry.or(source.intent_expiry),
+            refund_approval_threshold: refund_approval_threshold
+                .or(source.refund_approval_threshold),
+            webhook_retry_policy: webhook_retry_policy.or(source.webhook_retry_policy),
+            event_sink_config: event_sink_config.or(source.event_sink_config),
+            is_connector_audit_capture_enabled: is_connector_audit_capture_enabled
+                .or(source.is_connector_audit_capture_enabled),
+            soft_decline_retry_config: soft_decline_retry_config
+                .or(source.soft_decline_retry_config),
+            webhook_endpoint_secrets: webhook_endpoint_secrets.or(source.webhook_endpoint_secrets),
         }
     }
 }
@@ -411,6 +454,14 @@ pub struct Profile {
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
     pub is_manual_retry_enabled: Option<bool>,
     pub always_enable_overcapture: Option<primitive_wrappers::AlwaysEnableOvercaptureBool>,
+    pub retry_policy: Option<common_types::domain::RetryPolicy>,
+    pub intent_expiry: Option<i64>,
+    pub refund_approval_threshold: Option<common_utils::types::MinorUnit>,
+    pub webhook_retry_policy: Option<common_types::domain::WebhookRetryPolicy>,
+    pub event_sink_config: Option<common_types::domain::EventSinkConfig>,
+    pub is_connector_audit_capture_enabled: Option<bool>,
+    pub soft_decline_retry_config: Option<common_types::domain::SoftDeclineRetryConfig>,
+    pub webhook_endpoint_secrets: Option<Encryption>,
     pub routing_algorithm_id: Option<common_utils::id_type::RoutingId>,
     pub order_fulfillment_time: Option<i64>,
     pub order_fulfillment_time_origin: Option<common_enums::OrderFulfillmentTimeOrigin>,
@@ -717,6 +768,14 @@ impl ProfileUpdateInternal {
             split_txns_enabled: split_txns_enabled.or(source.split_txns_enabled),
             is_manual_retry_enabled: None,
             always_enable_overcapture: None,
+            retry_policy: None,
+            intent_expiry: None,
+            refund_approval_threshold: None,
+            webhook_retry_policy: None,
+            event_sink_config: None,
+            is_connector_audit_capture_enabled: None,
+            soft_decline_retry_config: None,
+            webhook_endpoint_secrets: None,
         }
     }
 }
@@ -786,10 +845,29 @@ pub struct WebhookDetails {
     pub payment_statuses_enabled: Option<Vec<common_enums::IntentStatus>>,
     pub refund_statuses_enabled: Option<Vec<common_enums::RefundStatus>>,
     pub payout_statuses_enabled: Option<Vec<common_enums::PayoutStatus>>,
+    pub webhook_endpoints: Option<Vec<WebhookEndpoint>>,
 }
 
 common_utils::impl_to_sql_from_sql_json!(WebhookDetails);
 
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct WebhookEndpoint {
+    pub endpoint_id: String,
+    pub url: Secret<String>,
+    pub secret: Secret<String>,
+    pub enabled_events: Option<Vec<common_enums::EventType>>,
+    pub client_certificate: Option<Secret<String>>,
+    pub ca_certificate: Option<Secret<String>>,
+}
+
+/// Credentials of a webhook endpoint, stored encrypted in the business profile keyed by the
+/// endpoint ID instead of in plain `webhook_details`
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct WebhookEndpointSecrets {
+    pub custom_headers: Option<HashMap<String, Secret<String>>>,
+    pub client_certificate_key: Option<Secret<String>>,
+}
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
 #[diesel(sql_type = diesel::sql_types::Jsonb)]
 pub struct BusinessPaymentLinkConfig {
@@ -0,0 +1,49 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+
+use crate::schema::connector_audit_logs;
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable)]
+#[diesel(table_name = connector_audit_logs)]
+pub struct ConnectorAuditLogNew {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub refund_id: Option<String>,
+    pub dispute_id: Option<String>,
+    pub connector: String,
+    pub flow: String,
+    pub http_method: String,
+    pub url: String,
+    pub masked_request: Option<serde_json::Value>,
+    pub masked_response: Option<serde_json::Value>,
+    pub error: Option<serde_json::Value>,
+    pub status_code: i32,
+    pub latency_in_ms: i64,
+    pub request_id: Option<String>,
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = connector_audit_logs, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct ConnectorAuditLog {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub refund_id: Option<String>,
+    pub dispute_id: Option<String>,
+    pub connector: String,
+    pub flow: String,
+    pub http_method: String,
+    pub url: String,
+    pub masked_request: Option<serde_json::Value>,
+    pub masked_response: Option<serde_json::Value>,
+    pub error: Option<serde_json::Value>,
+    pub status_code: i32,
+    pub latency_in_ms: i64,
+    pub request_id: Option<String>,
+    pub created_at: time::PrimitiveDateTime,
+}
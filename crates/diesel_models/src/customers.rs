@@ -60,6 +60,7 @@ impl From<CustomerNew> for Customer {
             updated_by: customer_new.updated_by,
             version: customer_new.version,
             tax_registration_id: customer_new.tax_registration_id,
+            merged_into_customer_id: None,
         }
     }
 }
@@ -147,6 +148,7 @@ pub struct Customer {
     pub updated_by: Option<String>,
     pub version: ApiVersion,
     pub tax_registration_id: Option<Encryption>,
+    pub merged_into_customer_id: Option<common_utils::id_type::CustomerId>,
 }
 
 #[cfg(feature = "v2")]
@@ -195,6 +197,7 @@ pub struct CustomerUpdateInternal {
     pub default_payment_method_id: Option<Option<String>>,
     pub updated_by: Option<String>,
     pub tax_registration_id: Option<Encryption>,
+    pub merged_into_customer_id: Option<common_utils::id_type::CustomerId>,
 }
 
 #[cfg(feature = "v1")]
@@ -211,6 +214,7 @@ impl CustomerUpdateInternal {
             address_id,
             default_payment_method_id,
             tax_registration_id,
+            merged_into_customer_id,
             ..
         } = self;
 
@@ -228,6 +232,8 @@ impl CustomerUpdateInternal {
                 .flatten()
                 .map_or(source.default_payment_method_id, Some),
             tax_registration_id: tax_registration_id.map_or(source.tax_registration_id, Some),
+            merged_into_customer_id: merged_into_customer_id
+                .map_or(source.merged_into_customer_id, Some),
             ..source
         }
     }
@@ -0,0 +1,71 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{data_retention_policy, data_retention_purge_run};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = data_retention_policy)]
+pub struct DataRetentionPolicyNew {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub connector_payload_retention_days: Option<i32>,
+    pub inactive_customer_retention_days: Option<i32>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Deserialize, Serialize)]
+#[diesel(
+    table_name = data_retention_policy,
+    primary_key(merchant_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct DataRetentionPolicy {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub connector_payload_retention_days: Option<i32>,
+    pub inactive_customer_retention_days: Option<i32>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+/// The policy is replaced as a whole, hence a retention which is not set is cleared
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = data_retention_policy, treat_none_as_null = true)]
+pub struct DataRetentionPolicyUpdate {
+    pub connector_payload_retention_days: Option<i32>,
+    pub inactive_customer_retention_days: Option<i32>,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = data_retention_purge_run)]
+pub struct DataRetentionPurgeRunNew {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub is_dry_run: bool,
+    pub triggered_by: String,
+    pub connector_payloads_created_before: Option<time::PrimitiveDateTime>,
+    pub connector_payloads_purged: i64,
+    pub customers_inactive_since: Option<time::PrimitiveDateTime>,
+    pub redacted_customer_ids: serde_json::Value,
+    pub failed_customer_ids: serde_json::Value,
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Deserialize, Serialize)]
+#[diesel(
+    table_name = data_retention_purge_run,
+    primary_key(id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct DataRetentionPurgeRun {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub is_dry_run: bool,
+    pub triggered_by: String,
+    pub connector_payloads_created_before: Option<time::PrimitiveDateTime>,
+    pub connector_payloads_purged: i64,
+    pub customers_inactive_since: Option<time::PrimitiveDateTime>,
+    pub redacted_customer_ids: serde_json::Value,
+    pub failed_customer_ids: serde_json::Value,
+    pub created_at: time::PrimitiveDateTime,
+}
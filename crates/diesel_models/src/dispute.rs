@@ -35,6 +35,7 @@ pub struct DisputeNew {
     pub dispute_amount: MinorUnit,
     pub organization_id: common_utils::id_type::OrganizationId,
     pub dispute_currency: Option<storage_enums::Currency>,
+    pub automated_action: Option<DisputeAutomatedAction>,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Identifiable, Queryable, Selectable)]
@@ -66,8 +67,25 @@ pub struct Dispute {
     pub dispute_amount: MinorUnit,
     pub organization_id: common_utils::id_type::OrganizationId,
     pub dispute_currency: Option<storage_enums::Currency>,
+    pub automated_action: Option<DisputeAutomatedAction>,
+    pub evidence_submitted_at: Option<PrimitiveDateTime>,
 }
 
+/// Record of an action taken on a dispute automatically by a rule of the dispute decision config
+/// of its profile
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, serde::Deserialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct DisputeAutomatedAction {
+    pub rule_name: String,
+    pub action: storage_enums::DisputeDecisionAction,
+    pub succeeded: bool,
+    pub error_message: Option<String>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub performed_at: PrimitiveDateTime,
+}
+
+common_utils::impl_to_sql_from_sql_json!(DisputeAutomatedAction);
+
 #[derive(Debug)]
 pub enum DisputeUpdate {
     Update {
@@ -83,9 +101,17 @@ pub enum DisputeUpdate {
         dispute_status: storage_enums::DisputeStatus,
         connector_status: Option<String>,
     },
+    EvidenceSubmissionUpdate {
+        dispute_status: storage_enums::DisputeStatus,
+        connector_status: Option<String>,
+        evidence_submitted_at: PrimitiveDateTime,
+    },
     EvidenceUpdate {
         evidence: Secret<serde_json::Value>,
     },
+    AutomatedActionUpdate {
+        automated_action: DisputeAutomatedAction,
+    },
 }
 
 #[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
@@ -100,6 +126,8 @@ pub struct DisputeUpdateInternal {
     connector_updated_at: Option<PrimitiveDateTime>,
     modified_at: PrimitiveDateTime,
     evidence: Option<Secret<serde_json::Value>>,
+    automated_action: Option<DisputeAutomatedAction>,
+    evidence_submitted_at: Option<PrimitiveDateTime>,
 }
 
 impl From<DisputeUpdate> for DisputeUpdateInternal {
@@ -123,6 +151,8 @@ impl From<DisputeUpdate> for DisputeUpdateInternal {
                 connector_updated_at,
                 modified_at: common_utils::date_time::now(),
                 evidence: None,
+                automated_action: None,
+                evidence_submitted_at: None,
             },
             DisputeUpdate::StatusUpdate {
                 dispute_status,
@@ -137,6 +167,25 @@ impl From<DisputeUpdate> for DisputeUpdateInternal {
                 challenge_required_by: None,
                 connector_updated_at: None,
                 evidence: None,
+                automated_action: None,
+                evidence_submitted_at: None,
+            },
+            DisputeUpdate::EvidenceSubmissionUpdate {
+                dispute_status,
+                connector_status,
+                evidence_submitted_at,
+            } => Self {
+                dispute_status: Some(dispute_status),
+                connector_status,
+                evidence_submitted_at: Some(evidence_submitted_at),
+                modified_at: common_utils::date_time::now(),
+                dispute_stage: None,
+                connector_reason: None,
+                connector_reason_code: None,
+                challenge_required_by: None,
+                connector_updated_at: None,
+                evidence: None,
+                automated_action: None,
             },
             DisputeUpdate::EvidenceUpdate { evidence } => Self {
                 evidence: Some(evidence),
@@ -148,6 +197,21 @@ impl From<DisputeUpdate> for DisputeUpdateInternal {
                 challenge_required_by: None,
                 connector_updated_at: None,
                 modified_at: common_utils::date_time::now(),
+                automated_action: None,
+                evidence_submitted_at: None,
+            },
+            DisputeUpdate::AutomatedActionUpdate { automated_action } => Self {
+                automated_action: Some(automated_action),
+                evidence: None,
+                dispute_stage: None,
+                dispute_status: None,
+                connector_status: None,
+                connector_reason: None,
+                connector_reason_code: None,
+                challenge_required_by: None,
+                connector_updated_at: None,
+                modified_at: common_utils::date_time::now(),
+                evidence_submitted_at: None,
             },
         }
     }
@@ -59,6 +59,7 @@ pub enum RoutingAlgorithmKind {
     Advanced,
     Dynamic,
     ThreeDsDecisionRule,
+    Cost,
 }
 
 #[derive(
@@ -81,6 +82,8 @@ pub enum EventObjectType {
     DisputeDetails,
     MandateDetails,
     PayoutDetails,
+    RoutingDetails,
+    SubscriptionDetails,
 }
 
 // Refund
@@ -218,6 +221,8 @@ pub enum UserStatus {
     Active,
     #[default]
     InvitationSent,
+    /// Deprovisioned through SCIM, the user role no longer grants access
+    Deactivated,
 }
 
 #[derive(
@@ -27,6 +27,9 @@ pub struct EventNew {
     pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
     pub metadata: Option<EventMetadata>,
     pub is_overall_delivery_successful: Option<bool>,
+    pub dead_lettered_at: Option<PrimitiveDateTime>,
+    pub webhook_endpoint_id: Option<String>,
+    pub delivery_latency_in_ms: Option<i32>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -35,6 +38,8 @@ pub struct EventUpdateInternal {
     pub is_webhook_notified: Option<bool>,
     pub response: Option<Encryption>,
     pub is_overall_delivery_successful: Option<bool>,
+    pub dead_lettered_at: Option<PrimitiveDateTime>,
+    pub delivery_latency_in_ms: Option<i32>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable, Selectable)]
@@ -60,6 +65,19 @@ pub struct Event {
     pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
     pub metadata: Option<EventMetadata>,
     pub is_overall_delivery_successful: Option<bool>,
+    pub dead_lettered_at: Option<PrimitiveDateTime>,
+    pub webhook_endpoint_id: Option<String>,
+    pub delivery_latency_in_ms: Option<i32>,
+}
+
+/// Aggregated delivery statistics of the outgoing webhooks of a business profile.
+#[derive(Clone, Copy, Debug, Default, Queryable)]
+pub struct EventDeliveryHealth {
+    pub total_events: i64,
+    pub delivered_events: i64,
+    pub pending_retry_events: i64,
+    pub dead_lettered_events: i64,
+    pub p95_delivery_latency_in_ms: Option<f64>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, AsExpression, diesel::FromSqlRow)]
@@ -102,6 +120,14 @@ pub enum EventMetadata {
         payment_method_id: String,
         mandate_id: String,
     },
+    Routing {
+        profile_id: common_utils::id_type::ProfileId,
+        routing_audit_log_id: String,
+    },
+    Subscription {
+        subscription_id: common_utils::id_type::SubscriptionId,
+        payment_id: Option<common_utils::id_type::PaymentId>,
+    },
 }
 
 common_utils::impl_to_sql_from_sql_json!(EventMetadata);
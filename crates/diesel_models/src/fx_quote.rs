@@ -0,0 +1,44 @@
+use common_utils::types::MinorUnit;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::{enums, schema::fx_quote};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = fx_quote)]
+pub struct FxQuoteNew {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub amount: MinorUnit,
+    pub from_currency: enums::Currency,
+    pub converted_amount: MinorUnit,
+    pub to_currency: enums::Currency,
+    pub rate: String,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub expires_at: time::PrimitiveDateTime,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Deserialize, Serialize)]
+#[diesel(table_name = fx_quote, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct FxQuote {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub amount: MinorUnit,
+    pub from_currency: enums::Currency,
+    pub converted_amount: MinorUnit,
+    pub to_currency: enums::Currency,
+    pub rate: String,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub expires_at: time::PrimitiveDateTime,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = fx_quote)]
+pub struct FxQuoteUpdate {
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub modified_at: time::PrimitiveDateTime,
+}
@@ -1,10 +1,14 @@
 pub mod address;
+pub mod admin_audit_event;
 pub mod api_keys;
+pub mod archival;
+pub mod auth_rate_alert;
 pub mod blocklist_lookup;
 pub mod business_profile;
 pub mod capture;
 pub mod cards_info;
 pub mod configs;
+pub mod connector_audit_log;
 
 pub mod authentication;
 pub mod authorization;
@@ -12,6 +16,7 @@ pub mod blocklist;
 pub mod blocklist_fingerprint;
 pub mod callback_mapper;
 pub mod customers;
+pub mod data_retention;
 pub mod dispute;
 pub mod dynamic_routing_stats;
 pub mod enums;
@@ -21,30 +26,42 @@ pub mod events;
 pub mod file;
 #[allow(unused)]
 pub mod fraud_check;
+pub mod fx_quote;
 pub mod generic_link;
 pub mod gsm;
 pub mod hyperswitch_ai_interaction;
 #[cfg(feature = "kv_store")]
 pub mod kv;
+pub mod list_view;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod merchant_key_store;
+pub mod merchant_signing_key;
 pub mod organization;
 pub mod payment_attempt;
+pub mod payment_batch;
 pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_method_health;
+pub mod payment_review;
 pub mod payout_attempt;
+pub mod payout_batch;
+pub mod payout_beneficiary;
 pub mod payouts;
 pub mod process_tracker;
 pub mod query;
 pub mod refund;
 pub mod relay;
+pub mod report;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod routing_audit_log;
+pub mod routing_experiment_payment;
+pub mod settlement_recon;
 pub mod subscription;
 pub mod types;
 pub mod unified_translations;
@@ -60,6 +77,7 @@ pub mod schema_v2;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
+pub mod user_passkey;
 pub mod user_role;
 
 use diesel_impl::{DieselArray, OptionalDieselArray};
@@ -0,0 +1,41 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::{enums as storage_enums, schema::list_view};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = list_view)]
+pub struct ListViewNew {
+    pub view_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub created_by: String,
+    pub name: String,
+    pub entity: storage_enums::ListViewEntity,
+    pub filters: serde_json::Value,
+    pub is_shared: bool,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Deserialize, Serialize)]
+#[diesel(table_name = list_view, primary_key(view_id), check_for_backend(diesel::pg::Pg))]
+pub struct ListView {
+    pub view_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub created_by: String,
+    pub name: String,
+    pub entity: storage_enums::ListViewEntity,
+    pub filters: serde_json::Value,
+    pub is_shared: bool,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = list_view)]
+pub struct ListViewUpdate {
+    pub name: Option<String>,
+    pub filters: Option<serde_json::Value>,
+    pub is_shared: Option<bool>,
+    pub modified_at: time::PrimitiveDateTime,
+}
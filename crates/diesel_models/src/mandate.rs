@@ -117,6 +117,9 @@ pub enum MandateUpdate {
         payment_method_id: String,
         original_payment_id: Option<common_utils::id_type::PaymentId>,
     },
+    CustomerIdUpdate {
+        customer_id: common_utils::id_type::CustomerId,
+    },
 }
 
 impl MandateUpdate {
@@ -154,6 +157,7 @@ pub struct MandateUpdateInternal {
     payment_method_id: Option<String>,
     original_payment_id: Option<common_utils::id_type::PaymentId>,
     updated_by: Option<String>,
+    customer_id: Option<common_utils::id_type::CustomerId>,
 }
 
 impl From<MandateUpdate> for MandateUpdateInternal {
@@ -167,6 +171,7 @@ impl From<MandateUpdate> for MandateUpdateInternal {
                 payment_method_id: None,
                 original_payment_id: None,
                 updated_by: None,
+                customer_id: None,
             },
             MandateUpdate::CaptureAmountUpdate { amount_captured } => Self {
                 mandate_status: None,
@@ -176,6 +181,7 @@ impl From<MandateUpdate> for MandateUpdateInternal {
                 payment_method_id: None,
                 original_payment_id: None,
                 updated_by: None,
+                customer_id: None,
             },
             MandateUpdate::ConnectorReferenceUpdate {
                 connector_mandate_ids,
@@ -195,6 +201,10 @@ impl From<MandateUpdate> for MandateUpdateInternal {
                 original_payment_id,
                 ..Default::default()
             },
+            MandateUpdate::CustomerIdUpdate { customer_id } => Self {
+                customer_id: Some(customer_id),
+                ..Default::default()
+            },
         }
     }
 }
@@ -209,6 +219,7 @@ impl MandateUpdateInternal {
             payment_method_id,
             original_payment_id,
             updated_by,
+            customer_id,
         } = self;
 
         Mandate {
@@ -219,6 +230,7 @@ impl MandateUpdateInternal {
             payment_method_id: payment_method_id.unwrap_or(source.payment_method_id),
             original_payment_id: original_payment_id.map_or(source.original_payment_id, Some),
             updated_by: updated_by.map_or(source.updated_by, Some),
+            customer_id: customer_id.unwrap_or(source.customer_id),
             ..source
         }
     }
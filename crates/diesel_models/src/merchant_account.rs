@@ -55,6 +55,7 @@ pub struct MerchantAccount {
     pub id: Option<common_utils::id_type::MerchantId>,
     pub product_type: Option<common_enums::MerchantProductType>,
     pub merchant_account_type: Option<common_enums::MerchantAccountType>,
+    pub allowed_ip_ranges: Option<Vec<Option<String>>>,
 }
 
 #[cfg(feature = "v1")]
@@ -90,6 +91,7 @@ pub struct MerchantAccountSetter {
     pub is_platform_account: bool,
     pub product_type: Option<common_enums::MerchantProductType>,
     pub merchant_account_type: common_enums::MerchantAccountType,
+    pub allowed_ip_ranges: Option<Vec<Option<String>>>,
 }
 
 #[cfg(feature = "v1")]
@@ -128,6 +130,7 @@ impl From<MerchantAccountSetter> for MerchantAccount {
             is_platform_account: item.is_platform_account,
             product_type: item.product_type,
             merchant_account_type: Some(item.merchant_account_type),
+            allowed_ip_ranges: item.allowed_ip_ranges,
         }
     }
 }
@@ -162,6 +165,7 @@ pub struct MerchantAccount {
     pub id: common_utils::id_type::MerchantId,
     pub product_type: Option<common_enums::MerchantProductType>,
     pub merchant_account_type: Option<common_enums::MerchantAccountType>,
+    pub allowed_ip_ranges: Option<Vec<Option<String>>>,
 }
 
 #[cfg(feature = "v2")]
@@ -182,6 +186,7 @@ impl From<MerchantAccountSetter> for MerchantAccount {
             is_platform_account: item.is_platform_account,
             product_type: item.product_type,
             merchant_account_type: Some(item.merchant_account_type),
+            allowed_ip_ranges: item.allowed_ip_ranges,
         }
     }
 }
@@ -202,6 +207,7 @@ pub struct MerchantAccountSetter {
     pub is_platform_account: bool,
     pub product_type: Option<common_enums::MerchantProductType>,
     pub merchant_account_type: common_enums::MerchantAccountType,
+    pub allowed_ip_ranges: Option<Vec<Option<String>>>,
 }
 
 impl MerchantAccount {
@@ -252,6 +258,7 @@ pub struct MerchantAccountNew {
     pub id: Option<common_utils::id_type::MerchantId>,
     pub product_type: Option<common_enums::MerchantProductType>,
     pub merchant_account_type: common_enums::MerchantAccountType,
+    pub allowed_ip_ranges: Option<Vec<Option<String>>>,
 }
 
 #[cfg(feature = "v2")]
@@ -271,6 +278,7 @@ pub struct MerchantAccountNew {
     pub is_platform_account: bool,
     pub product_type: Option<common_enums::MerchantProductType>,
     pub merchant_account_type: common_enums::MerchantAccountType,
+    pub allowed_ip_ranges: Option<Vec<Option<String>>>,
 }
 
 #[cfg(feature = "v2")]
@@ -287,6 +295,7 @@ pub struct MerchantAccountUpdateInternal {
     pub recon_status: Option<storage_enums::ReconStatus>,
     pub is_platform_account: Option<bool>,
     pub product_type: Option<common_enums::MerchantProductType>,
+    pub allowed_ip_ranges: Option<Option<Vec<Option<String>>>>,
 }
 
 #[cfg(feature = "v2")]
@@ -303,6 +312,7 @@ impl MerchantAccountUpdateInternal {
             recon_status,
             is_platform_account,
             product_type,
+            allowed_ip_ranges,
         } = self;
 
         MerchantAccount {
@@ -320,6 +330,7 @@ impl MerchantAccountUpdateInternal {
             is_platform_account: is_platform_account.unwrap_or(source.is_platform_account),
             product_type: product_type.or(source.product_type),
             merchant_account_type: source.merchant_account_type,
+            allowed_ip_ranges: allowed_ip_ranges.unwrap_or(source.allowed_ip_ranges),
         }
     }
 }
@@ -355,6 +366,7 @@ pub struct MerchantAccountUpdateInternal {
     pub pm_collect_link_config: Option<serde_json::Value>,
     pub is_platform_account: Option<bool>,
     pub product_type: Option<common_enums::MerchantProductType>,
+    pub allowed_ip_ranges: Option<Option<Vec<Option<String>>>>,
 }
 
 #[cfg(feature = "v1")]
@@ -388,6 +400,7 @@ impl MerchantAccountUpdateInternal {
             pm_collect_link_config,
             is_platform_account,
             product_type,
+            allowed_ip_ranges,
         } = self;
 
         MerchantAccount {
@@ -427,6 +440,7 @@ impl MerchantAccountUpdateInternal {
             id: source.id,
             product_type: product_type.or(source.product_type),
             merchant_account_type: source.merchant_account_type,
+            allowed_ip_ranges: allowed_ip_ranges.unwrap_or(source.allowed_ip_ranges),
         }
     }
 }
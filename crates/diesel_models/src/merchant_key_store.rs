@@ -40,3 +40,52 @@ pub struct MerchantKeyStoreUpdateInternal {
     pub merchant_id: common_utils::id_type::MerchantId,
     pub key: Encryption,
 }
+
+/// Tracking data of the process tracker task which re-encrypts a merchant's data after the
+/// merchant key has been rotated in the key manager
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct MerchantKeyReEncryptionTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub key_version: String,
+    /// The entity which is currently being re-encrypted
+    pub stage: MerchantKeyReEncryptionStage,
+    /// Number of records of the current stage which have already been re-encrypted
+    pub offset: u32,
+}
+
+/// Tracking data of the process tracker task which transfers the locally encrypted merchant keys
+/// to the key manager, batch by batch
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct MerchantKeyMigrationTrackingData {
+    /// Number of merchant keys transferred in a single run of the task
+    pub batch_size: u32,
+    pub max_transfers_per_second: Option<u32>,
+    /// Whether the merchant keys are only counted, without being transferred
+    pub dry_run: bool,
+    /// Whether the re-encryption of the data of every migrated merchant is scheduled
+    pub re_encrypt_data: bool,
+    /// Number of merchant keys which have already been processed
+    pub offset: u32,
+    /// Number of merchant keys which have been transferred to the key manager
+    pub transferred: u32,
+    pub failed_merchant_ids: Vec<common_utils::id_type::MerchantId>,
+}
+
+/// Entities holding merchant encrypted data, in the order in which they are re-encrypted
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MerchantKeyReEncryptionStage {
+    MerchantAccount,
+    MerchantConnectorAccount,
+    Customer,
+}
+
+impl MerchantKeyReEncryptionStage {
+    pub fn next(self) -> Option<Self> {
+        match self {
+            Self::MerchantAccount => Some(Self::MerchantConnectorAccount),
+            Self::MerchantConnectorAccount => Some(Self::Customer),
+            Self::Customer => None,
+        }
+    }
+}
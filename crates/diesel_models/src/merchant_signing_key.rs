@@ -0,0 +1,24 @@
+use common_utils::encryption::Encryption;
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+
+use crate::schema::merchant_signing_keys;
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable)]
+#[diesel(table_name = merchant_signing_keys)]
+pub struct MerchantSigningKeyNew {
+    pub key_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub signing_secret: Encryption,
+    pub created_at: time::PrimitiveDateTime,
+}
+
+/// A secret used by a merchant to sign its requests. The secret is encrypted with the key of the
+/// merchant, as it is required in plaintext to verify the signatures.
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = merchant_signing_keys, primary_key(key_id), check_for_backend(diesel::pg::Pg))]
+pub struct MerchantSigningKey {
+    pub key_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub signing_secret: Encryption,
+    pub created_at: time::PrimitiveDateTime,
+}
@@ -31,6 +31,7 @@ pub struct Organization {
     pub version: common_enums::ApiVersion,
     pub organization_type: Option<common_enums::OrganizationType>,
     pub platform_merchant_id: Option<id_type::MerchantId>,
+    pub is_passkey_required: Option<bool>,
 }
 
 #[cfg(feature = "v2")]
@@ -50,6 +51,7 @@ pub struct Organization {
     pub version: common_enums::ApiVersion,
     pub organization_type: Option<common_enums::OrganizationType>,
     pub platform_merchant_id: Option<id_type::MerchantId>,
+    pub is_passkey_required: Option<bool>,
 }
 
 #[cfg(feature = "v1")]
@@ -67,6 +69,7 @@ impl Organization {
             version,
             organization_type,
             platform_merchant_id,
+            is_passkey_required,
         } = org_new;
         Self {
             id: Some(org_id.clone()),
@@ -80,6 +83,7 @@ impl Organization {
             version,
             organization_type: Some(organization_type),
             platform_merchant_id,
+            is_passkey_required,
         }
     }
 
@@ -101,6 +105,7 @@ impl Organization {
             version,
             organization_type,
             platform_merchant_id,
+            is_passkey_required,
         } = org_new;
         Self {
             id,
@@ -112,6 +117,7 @@ impl Organization {
             version,
             organization_type: Some(organization_type),
             platform_merchant_id,
+            is_passkey_required,
         }
     }
 
@@ -135,6 +141,7 @@ pub struct OrganizationNew {
     pub version: common_enums::ApiVersion,
     pub organization_type: common_enums::OrganizationType,
     pub platform_merchant_id: Option<id_type::MerchantId>,
+    pub is_passkey_required: Option<bool>,
 }
 
 #[cfg(feature = "v2")]
@@ -150,6 +157,7 @@ pub struct OrganizationNew {
     pub version: common_enums::ApiVersion,
     pub organization_type: common_enums::OrganizationType,
     pub platform_merchant_id: Option<id_type::MerchantId>,
+    pub is_passkey_required: Option<bool>,
 }
 
 #[cfg(feature = "v1")]
@@ -171,6 +179,7 @@ impl OrganizationNew {
             version: common_types::consts::API_VERSION,
             organization_type,
             platform_merchant_id: None,
+            is_passkey_required: None,
         }
     }
 }
@@ -192,6 +201,7 @@ impl OrganizationNew {
             version: common_types::consts::API_VERSION,
             organization_type,
             platform_merchant_id: None,
+            is_passkey_required: None,
         }
     }
 }
@@ -206,6 +216,7 @@ pub struct OrganizationUpdateInternal {
     metadata: Option<pii::SecretSerdeValue>,
     modified_at: time::PrimitiveDateTime,
     platform_merchant_id: Option<id_type::MerchantId>,
+    is_passkey_required: Option<bool>,
 }
 
 #[cfg(feature = "v2")]
@@ -217,6 +228,7 @@ pub struct OrganizationUpdateInternal {
     metadata: Option<pii::SecretSerdeValue>,
     modified_at: time::PrimitiveDateTime,
     platform_merchant_id: Option<id_type::MerchantId>,
+    is_passkey_required: Option<bool>,
 }
 
 pub enum OrganizationUpdate {
@@ -225,6 +237,7 @@ pub enum OrganizationUpdate {
         organization_details: Option<pii::SecretSerdeValue>,
         metadata: Option<pii::SecretSerdeValue>,
         platform_merchant_id: Option<id_type::MerchantId>,
+        is_passkey_required: Option<bool>,
     },
 }
 
@@ -237,6 +250,7 @@ impl From<OrganizationUpdate> for OrganizationUpdateInternal {
                 organization_details,
                 metadata,
                 platform_merchant_id,
+                is_passkey_required,
             } => Self {
                 org_name: organization_name.clone(),
                 organization_name,
@@ -244,6 +258,7 @@ impl From<OrganizationUpdate> for OrganizationUpdateInternal {
                 metadata,
                 modified_at: common_utils::date_time::now(),
                 platform_merchant_id,
+                is_passkey_required,
             },
         }
     }
@@ -258,12 +273,14 @@ impl From<OrganizationUpdate> for OrganizationUpdateInternal {
                 organization_details,
                 metadata,
                 platform_merchant_id,
+                is_passkey_required,
             } => Self {
                 organization_name,
                 organization_details,
                 metadata,
                 modified_at: common_utils::date_time::now(),
                 platform_merchant_id,
+                is_passkey_required,
             },
         }
     }
@@ -43,6 +43,31 @@ pub struct NetworkDetails {
     pub network_advice_code: Option<String>,
 }
 
+common_utils::impl_to_sql_from_sql_json!(DccDetails);
+/// Dynamic currency conversion offered to the customer on the attempt, and whether it was accepted
+#[derive(
+    Clone, Debug, serde::Deserialize, Eq, PartialEq, serde::Serialize, diesel::AsExpression,
+)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct DccDetails {
+    pub quote_id: String,
+    pub accepted: bool,
+    pub card_currency: storage_enums::Currency,
+    pub converted_amount: MinorUnit,
+    pub rate: String,
+}
+
+common_utils::impl_to_sql_from_sql_json!(DeviceFingerprint);
+/// Fingerprint of the device of the customer collected by the merchant for fraud analysis
+#[derive(
+    Clone, Debug, serde::Deserialize, Eq, PartialEq, serde::Serialize, diesel::AsExpression,
+)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct DeviceFingerprint {
+    pub fingerprint_id: String,
+    pub session_id: Option<String>,
+}
+
 #[cfg(feature = "v2")]
 #[derive(
     Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize, Selectable,
@@ -107,6 +132,8 @@ pub struct PaymentAttempt {
     pub network_transaction_id: Option<String>,
     pub is_overcapture_enabled: Option<OvercaptureEnabledBool>,
     pub network_details: Option<NetworkDetails>,
+    pub dcc_details: Option<DccDetails>,
+    pub device_fingerprint: Option<DeviceFingerprint>,
     #[diesel(deserialize_as = RequiredFromNullable<storage_enums::PaymentMethod>)]
     pub payment_method_type_v2: storage_enums::PaymentMethod,
     pub connector_payment_id: Option<ConnectorTransactionId>,
@@ -224,6 +251,8 @@ pub struct PaymentAttempt {
     pub network_transaction_id: Option<String>,
     pub is_overcapture_enabled: Option<OvercaptureEnabledBool>,
     pub network_details: Option<NetworkDetails>,
+    pub dcc_details: Option<DccDetails>,
+    pub device_fingerprint: Option<DeviceFingerprint>,
 }
 
 #[cfg(feature = "v1")]
@@ -325,6 +354,8 @@ pub struct PaymentAttemptNew {
     pub connector_response_reference_id: Option<String>,
     pub network_transaction_id: Option<String>,
     pub network_details: Option<NetworkDetails>,
+    pub dcc_details: Option<DccDetails>,
+    pub device_fingerprint: Option<DeviceFingerprint>,
     pub multiple_capture_count: Option<i16>,
     pub amount_capturable: MinorUnit,
     pub updated_by: String,
@@ -449,6 +480,8 @@ pub struct PaymentAttemptNew {
     pub connector_request_reference_id: Option<String>,
     pub network_transaction_id: Option<String>,
     pub network_details: Option<NetworkDetails>,
+    pub dcc_details: Option<DccDetails>,
+    pub device_fingerprint: Option<DeviceFingerprint>,
 }
 
 #[cfg(feature = "v1")]
@@ -526,6 +559,8 @@ pub enum PaymentAttemptUpdate {
         routing_approach: Option<storage_enums::RoutingApproach>,
         connector_request_reference_id: Option<String>,
         network_transaction_id: Option<String>,
+        dcc_details: Option<DccDetails>,
+        device_fingerprint: Option<DeviceFingerprint>,
     },
     VoidUpdate {
         status: storage_enums::AttemptStatus,
@@ -642,6 +677,9 @@ pub enum PaymentAttemptUpdate {
     IncrementalAuthorizationAmountUpdate {
         amount: MinorUnit,
         amount_capturable: MinorUnit,
+        status: storage_enums::AttemptStatus,
+        surcharge_amount: Option<MinorUnit>,
+        tax_amount: Option<MinorUnit>,
     },
     AuthenticationUpdate {
         status: storage_enums::AttemptStatus,
@@ -1011,6 +1049,8 @@ impl PaymentAttemptUpdateInternal {
                 .or(source.connector_request_reference_id),
             is_overcapture_enabled: source.is_overcapture_enabled,
             network_details: source.network_details,
+            dcc_details: source.dcc_details,
+            device_fingerprint: source.device_fingerprint,
         }
     }
 }
@@ -1080,6 +1120,8 @@ pub struct PaymentAttemptUpdateInternal {
     pub network_transaction_id: Option<String>,
     pub is_overcapture_enabled: Option<OvercaptureEnabledBool>,
     pub network_details: Option<NetworkDetails>,
+    pub dcc_details: Option<DccDetails>,
+    pub device_fingerprint: Option<DeviceFingerprint>,
 }
 
 #[cfg(feature = "v1")]
@@ -1274,6 +1316,8 @@ impl PaymentAttemptUpdate {
             network_transaction_id,
             is_overcapture_enabled,
             network_details,
+            dcc_details,
+            device_fingerprint,
         } = PaymentAttemptUpdateInternal::from(self).populate_derived_fields(&source);
         PaymentAttempt {
             amount: amount.unwrap_or(source.amount),
@@ -1346,6 +1390,8 @@ impl PaymentAttemptUpdate {
             network_transaction_id: network_transaction_id.or(source.network_transaction_id),
             is_overcapture_enabled: is_overcapture_enabled.or(source.is_overcapture_enabled),
             network_details: network_details.or(source.network_details),
+            dcc_details: dcc_details.or(source.dcc_details),
+            device_fingerprint: device_fingerprint.or(source.device_fingerprint),
             ..source
         }
     }
@@ -2680,6 +2726,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id,
                 is_overcapture_enabled: None,
                 network_details: None,
+                dcc_details: None,
+                device_fingerprint: None,
             },
             PaymentAttemptUpdate::AuthenticationTypeUpdate {
                 authentication_type,
@@ -2747,6 +2795,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id: None,
                 is_overcapture_enabled: None,
                 network_details: None,
+                dcc_details: None,
+                device_fingerprint: None,
             },
             PaymentAttemptUpdate::ConfirmUpdate {
                 amount,
@@ -2785,6 +2835,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 routing_approach,
                 connector_request_reference_id,
                 network_transaction_id,
+                dcc_details,
+                device_fingerprint,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -2848,6 +2900,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id,
                 is_overcapture_enabled: None,
                 network_details: None,
+                dcc_details,
+                device_fingerprint,
             },
             PaymentAttemptUpdate::VoidUpdate {
                 status,
@@ -2916,6 +2970,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id: None,
                 is_overcapture_enabled: None,
                 network_details: None,
+                dcc_details: None,
+                device_fingerprint: None,
             },
             PaymentAttemptUpdate::RejectUpdate {
                 status,
@@ -2985,6 +3041,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id: None,
                 is_overcapture_enabled: None,
                 network_details: None,
+                dcc_details: None,
+                device_fingerprint: None,
             },
             PaymentAttemptUpdate::BlocklistUpdate {
                 status,
@@ -3054,6 +3112,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id: None,
                 is_overcapture_enabled: None,
                 network_details: None,
+                dcc_details: None,
+                device_fingerprint: None,
             },
             PaymentAttemptUpdate::ConnectorMandateDetailUpdate {
                 connector_mandate_detail,
@@ -3121,6 +3181,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id: None,
                 is_overcapture_enabled: None,
                 network_details: None,
+                dcc_details: None,
+                device_fingerprint: None,
             },
             PaymentAttemptUpdate::PaymentMethodDetailsUpdate {
                 payment_method_id,
@@ -3188,6 +3250,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id: None,
                 is_overcapture_enabled: None,
                 network_details: None,
+                dcc_details: None,
+                device_fingerprint: None,
             },
             PaymentAttemptUpdate::ResponseUpdate {
                 status,
@@ -3285,6 +3349,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     network_transaction_id,
                     is_overcapture_enabled,
                     network_details: None,
+                    dcc_details: None,
+                    device_fingerprint: None,
                 }
             }
             PaymentAttemptUpdate::ErrorUpdate {
@@ -3372,6 +3438,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     network_transaction_id: None,
                     is_overcapture_enabled: None,
                     network_details,
+                    dcc_details: None,
+                    device_fingerprint: None,
                 }
             }
             PaymentAttemptUpdate::StatusUpdate { status, updated_by } => Self {
@@ -3437,6 +3505,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id: None,
                 is_overcapture_enabled: None,
                 network_details: None,
+                dcc_details: None,
+                device_fingerprint: None,
             },
             PaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
@@ -3511,6 +3581,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id: None,
                 is_overcapture_enabled: None,
                 network_details: None,
+                dcc_details: None,
+                device_fingerprint: None,
             },
             PaymentAttemptUpdate::UnresolvedResponseUpdate {
                 status,
@@ -3591,6 +3663,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     network_transaction_id: None,
                     is_overcapture_enabled: None,
                     network_details: None,
+                    dcc_details: None,
+                    device_fingerprint: None,
                 }
             }
             PaymentAttemptUpdate::PreprocessingUpdate {
@@ -3670,6 +3744,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     network_transaction_id: None,
                     is_overcapture_enabled: None,
                     network_details: None,
+                    dcc_details: None,
+                    device_fingerprint: None,
                 }
             }
             PaymentAttemptUpdate::CaptureUpdate {
@@ -3739,6 +3815,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id: None,
                 is_overcapture_enabled: None,
                 network_details: None,
+                dcc_details: None,
+                device_fingerprint: None,
             },
             PaymentAttemptUpdate::AmountToCaptureUpdate {
                 status,
@@ -3807,6 +3885,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id: None,
                 is_overcapture_enabled: None,
                 network_details: None,
+                dcc_details: None,
+                device_fingerprint: None,
             },
             PaymentAttemptUpdate::ConnectorResponse {
                 authentication_data,
@@ -3884,18 +3964,23 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     network_transaction_id: None,
                     is_overcapture_enabled: None,
                     network_details: None,
+                    dcc_details: None,
+                    device_fingerprint: None,
                 }
             }
             PaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
                 amount,
                 amount_capturable,
+                status,
+                surcharge_amount,
+                tax_amount,
             } => Self {
                 amount: Some(amount),
                 modified_at: common_utils::date_time::now(),
                 amount_capturable: Some(amount_capturable),
                 net_amount: None,
                 currency: None,
-                status: None,
+                status: Some(status),
                 connector_transaction_id: None,
                 amount_to_capture: None,
                 connector: None,
@@ -3919,8 +4004,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 capture_method: None,
                 connector_response_reference_id: None,
                 multiple_capture_count: None,
-                surcharge_amount: None,
-                tax_amount: None,
+                surcharge_amount,
+                tax_amount,
                 updated_by: String::default(),
                 merchant_connector_id: None,
                 authentication_data: None,
@@ -3952,6 +4037,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id: None,
                 is_overcapture_enabled: None,
                 network_details: None,
+                dcc_details: None,
+                device_fingerprint: None,
             },
             PaymentAttemptUpdate::AuthenticationUpdate {
                 status,
@@ -4022,6 +4109,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id: None,
                 is_overcapture_enabled: None,
                 network_details: None,
+                dcc_details: None,
+                device_fingerprint: None,
             },
             PaymentAttemptUpdate::ManualUpdate {
                 status,
@@ -4101,6 +4190,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     network_transaction_id: None,
                     is_overcapture_enabled: None,
                     network_details: None,
+                    dcc_details: None,
+                    device_fingerprint: None,
                 }
             }
             PaymentAttemptUpdate::PostSessionTokensUpdate {
@@ -4169,6 +4260,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id: None,
                 is_overcapture_enabled: None,
                 network_details: None,
+                dcc_details: None,
+                device_fingerprint: None,
             },
         }
     }
@@ -0,0 +1,78 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::{enums, schema::payment_batch};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_batch)]
+pub struct PaymentBatchNew {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub status: enums::PaymentBatchStatus,
+    pub total_count: i32,
+    pub items: PaymentBatchItems,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Deserialize, Serialize)]
+#[diesel(table_name = payment_batch, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct PaymentBatch {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub status: enums::PaymentBatchStatus,
+    pub total_count: i32,
+    pub items: PaymentBatchItems,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_batch)]
+pub struct PaymentBatchUpdate {
+    pub status: enums::PaymentBatchStatus,
+    pub items: PaymentBatchItems,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+impl PaymentBatchUpdate {
+    pub fn new(status: enums::PaymentBatchStatus, items: Vec<PaymentBatchItem>) -> Self {
+        Self {
+            status,
+            items: PaymentBatchItems(items),
+            modified_at: common_utils::date_time::now(),
+        }
+    }
+}
+
+/// The outcome of every payment of a batch, in the order they were submitted
+#[derive(Clone, Debug, Deserialize, Serialize, diesel::AsExpression, diesel::FromSqlRow)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct PaymentBatchItems(pub Vec<PaymentBatchItem>);
+
+common_utils::impl_to_sql_from_sql_json!(PaymentBatchItems);
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PaymentBatchItem {
+    pub idempotency_key: String,
+    pub status: enums::PaymentBatchItemStatus,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub payment_status: Option<enums::IntentStatus>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
+
+impl PaymentBatchItem {
+    pub fn pending(idempotency_key: String) -> Self {
+        Self {
+            idempotency_key,
+            status: enums::PaymentBatchItemStatus::Pending,
+            payment_id: None,
+            payment_status: None,
+            error_code: None,
+            error_message: None,
+        }
+    }
+}
@@ -75,6 +75,8 @@ pub struct PaymentIntent {
     pub discount_amount: Option<MinorUnit>,
     pub shipping_amount_tax: Option<MinorUnit>,
     pub duty_amount: Option<MinorUnit>,
+    pub purchase_order_number: Option<String>,
+    pub mit_category: Option<common_enums::MitCategory>,
     pub order_date: Option<PrimitiveDateTime>,
     pub enable_partial_authorization: Option<bool>,
     pub enable_overcapture: Option<common_types::primitive_wrappers::EnableOvercaptureBool>,
@@ -177,6 +179,8 @@ pub struct PaymentIntent {
     pub discount_amount: Option<MinorUnit>,
     pub shipping_amount_tax: Option<MinorUnit>,
     pub duty_amount: Option<MinorUnit>,
+    pub purchase_order_number: Option<String>,
+    pub mit_category: Option<common_enums::MitCategory>,
     pub order_date: Option<PrimitiveDateTime>,
     pub enable_partial_authorization: Option<bool>,
     pub enable_overcapture: Option<common_types::primitive_wrappers::EnableOvercaptureBool>,
@@ -385,6 +389,8 @@ pub struct PaymentIntentNew {
     pub discount_amount: Option<MinorUnit>,
     pub shipping_amount_tax: Option<MinorUnit>,
     pub duty_amount: Option<MinorUnit>,
+    pub purchase_order_number: Option<String>,
+    pub mit_category: Option<common_enums::MitCategory>,
     pub order_date: Option<PrimitiveDateTime>,
 }
 
@@ -468,6 +474,8 @@ pub struct PaymentIntentNew {
     pub order_date: Option<PrimitiveDateTime>,
     pub shipping_amount_tax: Option<MinorUnit>,
     pub duty_amount: Option<MinorUnit>,
+    pub purchase_order_number: Option<String>,
+    pub mit_category: Option<common_enums::MitCategory>,
     pub enable_partial_authorization: Option<bool>,
     pub enable_overcapture: Option<common_types::primitive_wrappers::EnableOvercaptureBool>,
 }
@@ -553,9 +561,11 @@ pub enum PaymentIntentUpdate {
     },
     IncrementalAuthorizationAmountUpdate {
         amount: MinorUnit,
+        status: storage_enums::IntentStatus,
     },
     AuthorizationCountUpdate {
         authorization_count: i32,
+        status: storage_enums::IntentStatus,
     },
     CompleteAuthorizeUpdate {
         shipping_address_id: Option<String>,
@@ -570,6 +580,12 @@ pub enum PaymentIntentUpdate {
         updated_by: String,
         shipping_details: Option<Encryption>,
     },
+    PiiRedactionUpdate {
+        customer_details: Option<Encryption>,
+        billing_details: Option<Encryption>,
+        shipping_details: Option<Encryption>,
+        updated_by: String,
+    },
 }
 
 #[cfg(feature = "v2")]
@@ -639,6 +655,8 @@ pub struct PaymentIntentUpdateFields {
     pub order_date: Option<PrimitiveDateTime>,
     pub shipping_amount_tax: Option<MinorUnit>,
     pub duty_amount: Option<MinorUnit>,
+    pub purchase_order_number: Option<String>,
+    pub mit_category: Option<common_enums::MitCategory>,
     pub enable_partial_authorization: Option<bool>,
     pub enable_overcapture: Option<common_types::primitive_wrappers::EnableOvercaptureBool>,
 }
@@ -804,6 +822,8 @@ impl PaymentIntentUpdateInternal {
             discount_amount: source.discount_amount,
             shipping_amount_tax: source.shipping_amount_tax,
             duty_amount: source.duty_amount,
+            purchase_order_number: source.purchase_order_number,
+            mit_category: source.mit_category,
             order_date: source.order_date,
             enable_partial_authorization: None,
             split_txns_enabled: source.split_txns_enabled,
@@ -863,6 +883,8 @@ pub struct PaymentIntentUpdateInternal {
     pub order_date: Option<PrimitiveDateTime>,
     pub shipping_amount_tax: Option<MinorUnit>,
     pub duty_amount: Option<MinorUnit>,
+    pub purchase_order_number: Option<String>,
+    pub mit_category: Option<common_enums::MitCategory>,
     pub enable_partial_authorization: Option<bool>,
     pub enable_overcapture: Option<common_types::primitive_wrappers::EnableOvercaptureBool>,
 }
@@ -917,6 +939,8 @@ impl PaymentIntentUpdate {
             order_date,
             shipping_amount_tax,
             duty_amount,
+            purchase_order_number,
+            mit_category,
             enable_partial_authorization,
             enable_overcapture,
         } = self.into();
@@ -977,6 +1001,8 @@ impl PaymentIntentUpdate {
             order_date: order_date.or(source.order_date),
             shipping_amount_tax: shipping_amount_tax.or(source.shipping_amount_tax),
             duty_amount: duty_amount.or(source.duty_amount),
+            purchase_order_number: purchase_order_number.or(source.purchase_order_number),
+            mit_category: mit_category.or(source.mit_category),
             enable_partial_authorization: enable_partial_authorization
                 .or(source.enable_partial_authorization),
             enable_overcapture: enable_overcapture.or(source.enable_overcapture),
@@ -1039,6 +1065,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 order_date: None,
                 shipping_amount_tax: None,
                 duty_amount: None,
+                purchase_order_number: None,
+                mit_category: None,
                 enable_partial_authorization: None,
                 enable_overcapture: None,
             },
@@ -1090,6 +1118,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 order_date: value.order_date,
                 shipping_amount_tax: value.shipping_amount_tax,
                 duty_amount: value.duty_amount,
+                purchase_order_number: value.purchase_order_number,
+                mit_category: value.mit_category,
                 enable_partial_authorization: value.enable_partial_authorization,
                 enable_overcapture: value.enable_overcapture,
             },
@@ -1148,6 +1178,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 order_date: None,
                 shipping_amount_tax: None,
                 duty_amount: None,
+                purchase_order_number: None,
+                mit_category: None,
                 enable_partial_authorization: None,
                 enable_overcapture: None,
             },
@@ -1203,6 +1235,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 order_date: None,
                 shipping_amount_tax: None,
                 duty_amount: None,
+                purchase_order_number: None,
+                mit_category: None,
                 enable_partial_authorization: None,
                 enable_overcapture: None,
             },
@@ -1258,6 +1292,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 order_date: None,
                 shipping_amount_tax: None,
                 duty_amount: None,
+                purchase_order_number: None,
+                mit_category: None,
                 enable_partial_authorization: None,
                 enable_overcapture: None,
             },
@@ -1321,6 +1357,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 order_date: None,
                 shipping_amount_tax: None,
                 duty_amount: None,
+                purchase_order_number: None,
+                mit_category: None,
                 enable_partial_authorization: None,
                 enable_overcapture: None,
             },
@@ -1375,6 +1413,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 order_date: None,
                 shipping_amount_tax: None,
                 duty_amount: None,
+                purchase_order_number: None,
+                mit_category: None,
                 enable_partial_authorization: None,
                 enable_overcapture: None,
             },
@@ -1430,6 +1470,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 order_date: None,
                 shipping_amount_tax: None,
                 duty_amount: None,
+                purchase_order_number: None,
+                mit_category: None,
                 enable_partial_authorization: None,
                 enable_overcapture: None,
             },
@@ -1484,6 +1526,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 order_date: None,
                 shipping_amount_tax: None,
                 duty_amount: None,
+                purchase_order_number: None,
+                mit_category: None,
                 enable_partial_authorization: None,
                 enable_overcapture: None,
             },
@@ -1538,6 +1582,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 order_date: None,
                 shipping_amount_tax: None,
                 duty_amount: None,
+                purchase_order_number: None,
+                mit_category: None,
                 enable_partial_authorization: None,
                 enable_overcapture: None,
             },
@@ -1591,13 +1637,15 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 order_date: None,
                 shipping_amount_tax: None,
                 duty_amount: None,
+                purchase_order_number: None,
+                mit_category: None,
                 enable_partial_authorization: None,
                 enable_overcapture: None,
             },
-            PaymentIntentUpdate::IncrementalAuthorizationAmountUpdate { amount } => Self {
+            PaymentIntentUpdate::IncrementalAuthorizationAmountUpdate { amount, status } => Self {
                 amount: Some(amount),
                 currency: None,
-                status: None,
+                status: Some(status),
                 amount_captured: None,
                 customer_id: None,
                 return_url: None,
@@ -1641,16 +1689,19 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 order_date: None,
                 shipping_amount_tax: None,
                 duty_amount: None,
+                purchase_order_number: None,
+                mit_category: None,
                 enable_partial_authorization: None,
                 enable_overcapture: None,
             },
             PaymentIntentUpdate::AuthorizationCountUpdate {
                 authorization_count,
+                status,
             } => Self {
                 authorization_count: Some(authorization_count),
                 amount: None,
                 currency: None,
-                status: None,
+                status: Some(status),
                 amount_captured: None,
                 customer_id: None,
                 return_url: None,
@@ -1693,6 +1744,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 order_date: None,
                 shipping_amount_tax: None,
                 duty_amount: None,
+                purchase_order_number: None,
+                mit_category: None,
                 enable_partial_authorization: None,
                 enable_overcapture: None,
             },
@@ -1745,6 +1798,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 order_date: None,
                 shipping_amount_tax: None,
                 duty_amount: None,
+                purchase_order_number: None,
+                mit_category: None,
                 enable_partial_authorization: None,
                 enable_overcapture: None,
             },
@@ -1795,6 +1850,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 order_date: None,
                 shipping_amount_tax: None,
                 duty_amount: None,
+                purchase_order_number: None,
+                mit_category: None,
                 enable_partial_authorization: None,
                 enable_overcapture: None,
             },
@@ -1850,6 +1907,65 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 order_date: None,
                 shipping_amount_tax: None,
                 duty_amount: None,
+                purchase_order_number: None,
+                mit_category: None,
+                enable_partial_authorization: None,
+                enable_overcapture: None,
+            },
+            PaymentIntentUpdate::PiiRedactionUpdate {
+                customer_details,
+                billing_details,
+                shipping_details,
+                updated_by,
+            } => Self {
+                shipping_address_id: None,
+                amount: None,
+                tax_details: None,
+                currency: None,
+                status: None,
+                amount_captured: None,
+                customer_id: None,
+                return_url: None,
+                setup_future_usage: None,
+                off_session: None,
+                metadata: None,
+                billing_address_id: None,
+                modified_at: common_utils::date_time::now(),
+                active_attempt_id: None,
+                business_country: None,
+                business_label: None,
+                description: None,
+                statement_descriptor_name: None,
+                statement_descriptor_suffix: None,
+                order_details: None,
+                attempt_count: None,
+                merchant_decision: None,
+                payment_confirm_source: None,
+                updated_by,
+                surcharge_applicable: None,
+                incremental_authorization_allowed: None,
+                authorization_count: None,
+                session_expiry: None,
+                fingerprint_id: None,
+                request_external_three_ds_authentication: None,
+                frm_metadata: None,
+                customer_details,
+                billing_details,
+                merchant_order_reference_id: None,
+                shipping_details,
+                is_payment_processor_token_flow: None,
+                force_3ds_challenge: None,
+                is_iframe_redirection_enabled: None,
+                extended_return_url: None,
+                payment_channel: None,
+                feature_metadata: None,
+                tax_status: None,
+                discount_amount: None,
+                order_date: None,
+                shipping_amount_tax: None,
+                duty_amount: None,
+                purchase_order_number: None,
+                mit_category: None,
                 enable_partial_authorization: None,
                 enable_overcapture: None,
             },
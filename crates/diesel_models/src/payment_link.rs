@@ -1,5 +1,5 @@
 use common_utils::types::MinorUnit;
-use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
 use serde::{self, Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
@@ -57,3 +57,58 @@ pub struct PaymentLinkNew {
     pub profile_id: Option<common_utils::id_type::ProfileId>,
     pub secure_link: Option<String>,
 }
+
+#[derive(Debug)]
+pub enum PaymentLinkUpdate {
+    Update {
+        description: Option<String>,
+        custom_merchant_name: Option<String>,
+        payment_link_config: Option<serde_json::Value>,
+    },
+    ExpiryUpdate {
+        fulfilment_time: PrimitiveDateTime,
+    },
+}
+
+#[derive(Debug, AsChangeset)]
+#[diesel(table_name = payment_link)]
+pub struct PaymentLinkUpdateInternal {
+    pub description: Option<String>,
+    pub custom_merchant_name: Option<String>,
+    pub payment_link_config: Option<serde_json::Value>,
+    pub fulfilment_time: Option<PrimitiveDateTime>,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+impl From<PaymentLinkUpdate> for PaymentLinkUpdateInternal {
+    fn from(payment_link_update: PaymentLinkUpdate) -> Self {
+        let last_modified_at = common_utils::date_time::now();
+        match payment_link_update {
+            PaymentLinkUpdate::Update {
+                description,
+                custom_merchant_name,
+                payment_link_config,
+            } => Self {
+                description,
+                custom_merchant_name,
+                payment_link_config,
+                fulfilment_time: None,
+                last_modified_at,
+            },
+            PaymentLinkUpdate::ExpiryUpdate { fulfilment_time } => Self {
+                description: None,
+                custom_merchant_name: None,
+                payment_link_config: None,
+                fulfilment_time: Some(fulfilment_time),
+                last_modified_at,
+            },
+        }
+    }
+}
+
+/// Tracking data of the process tracker task which expires a payment link at its fulfilment time
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PaymentLinkExpiryTrackingData {
+    pub payment_link_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
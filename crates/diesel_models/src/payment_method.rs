@@ -250,6 +250,11 @@ pub enum PaymentMethodUpdate {
         network_transaction_id: Option<String>,
         status: Option<storage_enums::PaymentMethodStatus>,
     },
+    CustomerIdUpdate {
+        customer_id: common_utils::id_type::CustomerId,
+        locker_id: Option<String>,
+        network_token_locker_id: Option<String>,
+    },
 }
 
 #[cfg(feature = "v2")]
@@ -396,6 +401,7 @@ pub struct PaymentMethodUpdateInternal {
     network_token_locker_id: Option<String>,
     network_token_payment_method_data: Option<Encryption>,
     scheme: Option<String>,
+    customer_id: Option<common_utils::id_type::CustomerId>,
 }
 
 #[cfg(feature = "v1")]
@@ -418,10 +424,11 @@ impl PaymentMethodUpdateInternal {
             network_token_locker_id,
             network_token_payment_method_data,
             scheme,
+            customer_id,
         } = self;
 
         PaymentMethod {
-            customer_id: source.customer_id,
+            customer_id: customer_id.unwrap_or(source.customer_id),
             merchant_id: source.merchant_id,
             payment_method_id: source.payment_method_id,
             accepted_currency: source.accepted_currency,
@@ -483,6 +490,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 payment_method_issuer: None,
                 payment_method_type: None,
                 last_modified: common_utils::date_time::now(),
+                customer_id: None,
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme: None,
@@ -503,6 +511,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 payment_method_issuer: None,
                 payment_method_type: None,
                 last_modified: common_utils::date_time::now(),
+                customer_id: None,
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme: None,
@@ -521,6 +530,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 payment_method_issuer: None,
                 payment_method_type: None,
                 last_modified: common_utils::date_time::now(),
+                customer_id: None,
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme: None,
@@ -543,6 +553,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 payment_method_issuer: None,
                 payment_method_type: None,
                 last_modified: common_utils::date_time::now(),
+                customer_id: None,
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme,
@@ -564,6 +575,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 payment_method_issuer: None,
                 payment_method_type: None,
                 last_modified: common_utils::date_time::now(),
+                customer_id: None,
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme: None,
@@ -582,6 +594,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 payment_method_issuer: None,
                 payment_method_type: None,
                 last_modified: common_utils::date_time::now(),
+                customer_id: None,
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme: None,
@@ -610,6 +623,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 payment_method_issuer,
                 payment_method_type,
                 last_modified: common_utils::date_time::now(),
+                customer_id: None,
                 network_token_locker_id,
                 network_token_payment_method_data,
                 scheme: None,
@@ -630,6 +644,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 payment_method_issuer: None,
                 payment_method_type: None,
                 last_modified: common_utils::date_time::now(),
+                customer_id: None,
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme: None,
@@ -650,6 +665,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 payment_method_issuer: None,
                 payment_method_type: None,
                 last_modified: common_utils::date_time::now(),
+                customer_id: None,
                 network_transaction_id: None,
                 network_token_requestor_reference_id,
                 network_token_locker_id,
@@ -664,6 +680,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                     .map(|mandate_details| mandate_details.expose()),
                 network_transaction_id: network_transaction_id.map(|txn_id| txn_id.expose()),
                 last_modified: common_utils::date_time::now(),
+                customer_id: None,
                 status: None,
                 metadata: None,
                 payment_method_data: None,
@@ -697,10 +714,34 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 payment_method_issuer: None,
                 payment_method_type: None,
                 last_modified: common_utils::date_time::now(),
+                customer_id: None,
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme: None,
             },
+            PaymentMethodUpdate::CustomerIdUpdate {
+                customer_id,
+                locker_id,
+                network_token_locker_id,
+            } => Self {
+                metadata: None,
+                payment_method_data: None,
+                last_used_at: None,
+                network_transaction_id: None,
+                status: None,
+                locker_id,
+                network_token_requestor_reference_id: None,
+                payment_method: None,
+                connector_mandate_details: None,
+                updated_by: None,
+                payment_method_issuer: None,
+                payment_method_type: None,
+                last_modified: common_utils::date_time::now(),
+                customer_id: Some(customer_id),
+                network_token_locker_id,
+                network_token_payment_method_data: None,
+                scheme: None,
+            },
         }
     }
 }
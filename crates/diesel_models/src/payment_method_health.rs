@@ -0,0 +1,110 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::schema::payment_method_health;
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_method_health)]
+pub struct PaymentMethodHealthNew {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_method_id: String,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub last_successful_use_at: Option<time::PrimitiveDateTime>,
+    pub last_failed_use_at: Option<time::PrimitiveDateTime>,
+    pub successful_uses: i32,
+    pub failed_uses: i32,
+    pub consecutive_failures: i32,
+    pub card_expires_at: Option<time::PrimitiveDateTime>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Deserialize, Serialize)]
+#[diesel(
+    table_name = payment_method_health,
+    primary_key(merchant_id, payment_method_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct PaymentMethodHealth {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_method_id: String,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub last_successful_use_at: Option<time::PrimitiveDateTime>,
+    pub last_failed_use_at: Option<time::PrimitiveDateTime>,
+    pub successful_uses: i32,
+    pub failed_uses: i32,
+    pub consecutive_failures: i32,
+    pub card_expires_at: Option<time::PrimitiveDateTime>,
+    pub account_updated_at: Option<time::PrimitiveDateTime>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug)]
+pub enum PaymentMethodHealthUpdate {
+    UsageUpdate {
+        is_success: bool,
+        card_expires_at: Option<time::PrimitiveDateTime>,
+    },
+    AccountUpdaterUpdate {
+        card_expires_at: time::PrimitiveDateTime,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_method_health)]
+pub struct PaymentMethodHealthUpdateInternal {
+    pub last_successful_use_at: Option<time::PrimitiveDateTime>,
+    pub last_failed_use_at: Option<time::PrimitiveDateTime>,
+    pub successful_uses: Option<i32>,
+    pub failed_uses: Option<i32>,
+    pub consecutive_failures: Option<i32>,
+    pub card_expires_at: Option<time::PrimitiveDateTime>,
+    pub account_updated_at: Option<time::PrimitiveDateTime>,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+impl PaymentMethodHealthUpdate {
+    /// Builds the changeset of the update on top of the current counters of the payment method
+    pub fn to_internal(self, source: &PaymentMethodHealth) -> PaymentMethodHealthUpdateInternal {
+        let now = common_utils::date_time::now();
+        match self {
+            Self::UsageUpdate {
+                is_success: true,
+                card_expires_at,
+            } => PaymentMethodHealthUpdateInternal {
+                last_successful_use_at: Some(now),
+                last_failed_use_at: None,
+                successful_uses: Some(source.successful_uses.saturating_add(1)),
+                failed_uses: None,
+                consecutive_failures: Some(0),
+                card_expires_at,
+                account_updated_at: None,
+                modified_at: now,
+            },
+            Self::UsageUpdate {
+                is_success: false,
+                card_expires_at,
+            } => PaymentMethodHealthUpdateInternal {
+                last_successful_use_at: None,
+                last_failed_use_at: Some(now),
+                successful_uses: None,
+                failed_uses: Some(source.failed_uses.saturating_add(1)),
+                consecutive_failures: Some(source.consecutive_failures.saturating_add(1)),
+                card_expires_at,
+                account_updated_at: None,
+                modified_at: now,
+            },
+            Self::AccountUpdaterUpdate { card_expires_at } => PaymentMethodHealthUpdateInternal {
+                last_successful_use_at: None,
+                last_failed_use_at: None,
+                successful_uses: None,
+                failed_uses: None,
+                consecutive_failures: None,
+                card_expires_at: Some(card_expires_at),
+                account_updated_at: Some(now),
+                modified_at: now,
+            },
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use common_utils::types::MinorUnit;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::{enums, schema::payment_review};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_review)]
+pub struct PaymentReviewNew {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub attempt_id: String,
+    pub source: enums::PaymentReviewSource,
+    pub reason: Option<String>,
+    pub status: enums::PaymentReviewStatus,
+    pub amount: MinorUnit,
+    pub currency: Option<enums::Currency>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Deserialize, Serialize)]
+#[diesel(table_name = payment_review, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct PaymentReview {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub attempt_id: String,
+    pub source: enums::PaymentReviewSource,
+    pub reason: Option<String>,
+    pub status: enums::PaymentReviewStatus,
+    pub amount: MinorUnit,
+    pub currency: Option<enums::Currency>,
+    pub decided_by: Option<String>,
+    pub decision_reason: Option<String>,
+    pub decided_at: Option<time::PrimitiveDateTime>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_review)]
+pub struct PaymentReviewUpdate {
+    pub status: enums::PaymentReviewStatus,
+    pub decided_by: Option<String>,
+    pub decision_reason: Option<String>,
+    pub decided_at: Option<time::PrimitiveDateTime>,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+impl PaymentReviewUpdate {
+    pub fn decision(
+        status: enums::PaymentReviewStatus,
+        decided_by: Option<String>,
+        decision_reason: Option<String>,
+    ) -> Self {
+        let now = common_utils::date_time::now();
+        Self {
+            status,
+            decided_by,
+            decision_reason,
+            decided_at: Some(now),
+            modified_at: now,
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::{enums, schema::payout_batch};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch)]
+pub struct PayoutBatchNew {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub status: enums::PayoutBatchStatus,
+    pub total_count: i32,
+    pub items: PayoutBatchItems,
+    pub scheduled_at: time::PrimitiveDateTime,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Deserialize, Serialize)]
+#[diesel(table_name = payout_batch, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct PayoutBatch {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub status: enums::PayoutBatchStatus,
+    pub total_count: i32,
+    pub items: PayoutBatchItems,
+    pub scheduled_at: time::PrimitiveDateTime,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch)]
+pub struct PayoutBatchUpdate {
+    pub status: enums::PayoutBatchStatus,
+    pub items: PayoutBatchItems,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+impl PayoutBatchUpdate {
+    pub fn new(status: enums::PayoutBatchStatus, items: Vec<PayoutBatchItem>) -> Self {
+        Self {
+            status,
+            items: PayoutBatchItems(items),
+            modified_at: common_utils::date_time::now(),
+        }
+    }
+}
+
+/// The outcome of every payout of a batch, in the order they were submitted
+#[derive(Clone, Debug, Deserialize, Serialize, diesel::AsExpression, diesel::FromSqlRow)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct PayoutBatchItems(pub Vec<PayoutBatchItem>);
+
+common_utils::impl_to_sql_from_sql_json!(PayoutBatchItems);
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PayoutBatchItem {
+    pub payout_id: common_utils::id_type::PayoutId,
+    pub status: enums::PayoutBatchItemStatus,
+    pub payout_status: Option<enums::PayoutStatus>,
+    pub connector: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
+
+impl PayoutBatchItem {
+    pub fn pending(payout_id: common_utils::id_type::PayoutId) -> Self {
+        Self {
+            payout_id,
+            status: enums::PayoutBatchItemStatus::Pending,
+            payout_status: None,
+            connector: None,
+            error_code: None,
+            error_message: None,
+        }
+    }
+}
+
+/// Tracking data of the task executing a batch of payouts at its cutoff
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PayoutBatchTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub batch_id: String,
+}
@@ -0,0 +1,47 @@
+use common_utils::{encryption::Encryption, payout_method_utils, pii};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+
+use crate::{enums, schema::payout_beneficiary};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_beneficiary)]
+pub struct PayoutBeneficiaryNew {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: Option<common_utils::id_type::CustomerId>,
+    pub payout_type: enums::PayoutType,
+    pub payout_method_data: Encryption,
+    pub additional_payout_method_data: Option<payout_method_utils::AdditionalPayoutMethodData>,
+    pub kyc_status: enums::BeneficiaryKycStatus,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+/// A recipient of payouts, whose payout method data is stored encrypted with the key of the
+/// merchant so that payouts can reference it instead of carrying the payout method data
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = payout_beneficiary, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct PayoutBeneficiary {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: Option<common_utils::id_type::CustomerId>,
+    pub payout_type: enums::PayoutType,
+    pub payout_method_data: Encryption,
+    pub additional_payout_method_data: Option<payout_method_utils::AdditionalPayoutMethodData>,
+    pub kyc_status: enums::BeneficiaryKycStatus,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_beneficiary)]
+pub struct PayoutBeneficiaryUpdate {
+    pub payout_type: Option<enums::PayoutType>,
+    pub payout_method_data: Option<Encryption>,
+    pub additional_payout_method_data: Option<payout_method_utils::AdditionalPayoutMethodData>,
+    pub kyc_status: Option<enums::BeneficiaryKycStatus>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub modified_at: time::PrimitiveDateTime,
+}
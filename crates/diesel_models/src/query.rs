@@ -1,10 +1,14 @@
 pub mod address;
+pub mod admin_audit_event;
 pub mod api_keys;
+pub mod archival;
+pub mod auth_rate_alert;
 pub mod blocklist_lookup;
 pub mod business_profile;
 mod capture;
 pub mod cards_info;
 pub mod configs;
+pub mod connector_audit_log;
 
 pub mod authentication;
 pub mod authorization;
@@ -13,33 +17,46 @@ pub mod blocklist_fingerprint;
 pub mod callback_mapper;
 pub mod customers;
 pub mod dashboard_metadata;
+pub mod data_retention;
 pub mod dispute;
 pub mod dynamic_routing_stats;
 pub mod events;
 pub mod file;
 pub mod fraud_check;
+pub mod fx_quote;
 pub mod generic_link;
 pub mod generics;
 pub mod gsm;
 pub mod hyperswitch_ai_interaction;
+pub mod list_view;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod merchant_key_store;
+pub mod merchant_signing_key;
 pub mod organization;
 pub mod payment_attempt;
+pub mod payment_batch;
 pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_method_health;
+pub mod payment_review;
 pub mod payout_attempt;
+pub mod payout_batch;
+pub mod payout_beneficiary;
 pub mod payouts;
 pub mod process_tracker;
 pub mod refund;
 pub mod relay;
+pub mod report;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod routing_audit_log;
+pub mod routing_experiment_payment;
+pub mod settlement_recon;
 pub mod subscription;
 #[cfg(feature = "tokenization_v2")]
 pub mod tokenization;
@@ -47,5 +64,6 @@ pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
+pub mod user_passkey;
 pub mod user_role;
 mod utils;
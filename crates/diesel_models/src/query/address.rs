@@ -1,4 +1,8 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods,
+};
+use error_stack::ResultExt;
 
 use super::generics;
 use crate::{
@@ -12,6 +16,24 @@ impl AddressNew {
     pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Address> {
         generics::generic_insert(conn, self).await
     }
+
+    /// Inserts the addresses in a single statement, such as the addresses of a payment
+    pub async fn batch_insert(
+        conn: &PgPooledConn,
+        batch: Vec<Self>,
+    ) -> StorageResult<Vec<Address>> {
+        let query = diesel::insert_into(<Address as HasTable>::table()).values(batch);
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<<Address as HasTable>::Table, _, _>(
+            query.get_results_async(conn),
+            generics::db_metrics::DatabaseOperation::Insert,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while inserting addresses")
+    }
 }
 
 impl Address {
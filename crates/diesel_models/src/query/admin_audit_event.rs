@@ -0,0 +1,78 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{associations::HasTable, debug_query, pg::Pg, ExpressionMethods, QueryDsl};
+use error_stack::ResultExt;
+use router_env::logger;
+
+use super::generics::{
+    self,
+    db_metrics::{track_database_call, DatabaseOperation},
+};
+use crate::{
+    admin_audit_event::{
+        AdminAuditEvent, AdminAuditEventEntity, AdminAuditEventListConstraints, AdminAuditEventNew,
+    },
+    errors::DatabaseError,
+    schema::admin_audit_events::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl AdminAuditEventNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<AdminAuditEvent> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl AdminAuditEvent {
+    /// Event ids are time ordered, so they are used both for ordering and as the cursor.
+    pub async fn list_by_entity_constraints(
+        conn: &PgPooledConn,
+        entity: AdminAuditEventEntity,
+        constraints: AdminAuditEventListConstraints,
+    ) -> StorageResult<Vec<Self>> {
+        let mut query = Self::table().order(dsl::id.desc()).into_boxed();
+
+        query = match entity {
+            AdminAuditEventEntity::Merchant(merchant_id) => {
+                query.filter(dsl::merchant_id.eq(merchant_id))
+            }
+            AdminAuditEventEntity::Organization(org_id) => query.filter(dsl::org_id.eq(org_id)),
+        };
+
+        if let Some(profile_id) = constraints.profile_id {
+            query = query.filter(dsl::profile_id.eq(profile_id));
+        }
+
+        if let Some(actor_id) = constraints.actor_id {
+            query = query.filter(dsl::actor_id.eq(actor_id));
+        }
+
+        if let Some(api_flow) = constraints.api_flow {
+            query = query.filter(dsl::api_flow.eq(api_flow));
+        }
+
+        if let Some(auth_type) = constraints.auth_type {
+            query = query.filter(dsl::auth_type.eq(auth_type));
+        }
+
+        if let Some(created_after) = constraints.created_after {
+            query = query.filter(dsl::created_at.ge(created_after));
+        }
+
+        if let Some(created_before) = constraints.created_before {
+            query = query.filter(dsl::created_at.le(created_before));
+        }
+
+        if let Some(starting_after) = constraints.starting_after {
+            query = query.filter(dsl::id.lt(starting_after));
+        }
+
+        query = query.limit(constraints.limit);
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error filtering admin audit events by constraints")
+    }
+}
@@ -0,0 +1,63 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{debug_query, pg::Pg, sql_types};
+use error_stack::ResultExt;
+
+use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+use crate::{archival::ArchivalTable, errors, schema, PgPooledConn, StorageResult};
+
+diesel::define_sql_function! {
+    /// Defined by the migration which creates the archive tables
+    fn archive_rows_created_before(
+        source_table: sql_types::Text,
+        archive_table: sql_types::Text,
+        created_before: sql_types::Timestamp,
+        batch_size: sql_types::Integer,
+    ) -> sql_types::BigInt;
+}
+
+impl ArchivalTable {
+    /// Moves up to `batch_size` of the oldest rows created before `created_before` to the archive
+    /// table, and returns the number of rows moved, which is zero once there is nothing to archive
+    pub async fn archive_rows_created_before(
+        self,
+        conn: &PgPooledConn,
+        created_before: time::PrimitiveDateTime,
+        batch_size: i32,
+    ) -> StorageResult<i64> {
+        let query = diesel::select(archive_rows_created_before(
+            self.to_string(),
+            self.get_archive_table_name(),
+            created_before,
+            batch_size,
+        ));
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        let archived_rows = query.get_result_async::<i64>(conn);
+        match self {
+            Self::PaymentIntent => {
+                track_database_call::<schema::payment_intent::table, _, _>(
+                    archived_rows,
+                    DatabaseOperation::Delete,
+                )
+                .await
+            }
+            Self::PaymentAttempt => {
+                track_database_call::<schema::payment_attempt::table, _, _>(
+                    archived_rows,
+                    DatabaseOperation::Delete,
+                )
+                .await
+            }
+            Self::Refund => {
+                track_database_call::<schema::refund::table, _, _>(
+                    archived_rows,
+                    DatabaseOperation::Delete,
+                )
+                .await
+            }
+        }
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable_lazy(|| format!("Failed to archive the rows of {self}"))
+    }
+}
@@ -0,0 +1,128 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    auth_rate_alert::{
+        AuthRateAlert, AuthRateAlertConfig, AuthRateAlertConfigNew, AuthRateAlertConfigUpdate,
+        AuthRateAlertNew, AuthRateAlertUpdate,
+    },
+    enums as storage_enums,
+    schema::{auth_rate_alert, auth_rate_alert_config},
+    PgPooledConn, StorageResult,
+};
+
+impl AuthRateAlertConfigNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<AuthRateAlertConfig> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl AuthRateAlertConfig {
+    pub async fn find_optional_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            auth_rate_alert_config::dsl::merchant_id.eq(merchant_id.to_owned()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        auth_rate_alert_config_update: AuthRateAlertConfigUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            auth_rate_alert_config::dsl::merchant_id.eq(merchant_id.to_owned()),
+            auth_rate_alert_config_update,
+        )
+        .await
+    }
+}
+
+impl AuthRateAlertNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<AuthRateAlert> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl AuthRateAlert {
+    pub async fn find_active_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            auth_rate_alert::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(auth_rate_alert::dsl::status.eq(storage_enums::AuthRateAlertStatus::Active)),
+            None,
+            None,
+            Some(auth_rate_alert::dsl::triggered_at.desc()),
+        )
+        .await
+    }
+
+    /// Lists the alerts of a merchant, optionally of a single status, the latest alert at the top
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        status: Option<storage_enums::AuthRateAlertStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        match status {
+            Some(status) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    auth_rate_alert::dsl::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(auth_rate_alert::dsl::status.eq(status)),
+                    Some(limit),
+                    Some(offset),
+                    Some(auth_rate_alert::dsl::triggered_at.desc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    auth_rate_alert::dsl::merchant_id.eq(merchant_id.to_owned()),
+                    Some(limit),
+                    Some(offset),
+                    Some(auth_rate_alert::dsl::triggered_at.desc()),
+                )
+                .await
+            }
+        }
+    }
+
+    pub async fn update_by_merchant_id_alert_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        alert_id: &str,
+        auth_rate_alert_update: AuthRateAlertUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            auth_rate_alert::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(auth_rate_alert::dsl::id.eq(alert_id.to_owned())),
+            auth_rate_alert_update,
+        )
+        .await
+    }
+}
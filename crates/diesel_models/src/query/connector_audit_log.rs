@@ -0,0 +1,104 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+
+use super::generics;
+use crate::{
+    connector_audit_log::{ConnectorAuditLog, ConnectorAuditLogNew},
+    errors,
+    schema::connector_audit_logs::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl ConnectorAuditLogNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ConnectorAuditLog> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ConnectorAuditLog {
+    /// Log ids are time ordered, so the logs are listed in the order in which the connector was
+    /// called.
+    pub async fn list_by_merchant_id_payment_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &str,
+        attempt_id: Option<&str>,
+    ) -> StorageResult<Vec<Self>> {
+        let predicate = dsl::merchant_id
+            .eq(merchant_id.to_owned())
+            .and(dsl::payment_id.eq(payment_id.to_owned()));
+
+        match attempt_id {
+            Some(attempt_id) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate.and(dsl::attempt_id.eq(attempt_id.to_owned())),
+                    None,
+                    None,
+                    Some(dsl::id.asc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate,
+                    None,
+                    None,
+                    Some(dsl::id.asc()),
+                )
+                .await
+            }
+        }
+    }
+
+    pub async fn count_by_merchant_id_created_before(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_before: time::PrimitiveDateTime,
+    ) -> StorageResult<i64> {
+        let query = <Self as HasTable>::table().count().filter(
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::created_at.lt(created_before)),
+        );
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_result_async::<i64>(conn),
+            generics::db_metrics::DatabaseOperation::Count,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to get a count of connector audit logs")
+    }
+
+    /// Returns the number of logs deleted, which is zero when there is nothing to purge
+    pub async fn delete_by_merchant_id_created_before(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_before: time::PrimitiveDateTime,
+    ) -> StorageResult<usize> {
+        let query = diesel::delete(
+            <Self as HasTable>::table().filter(
+                dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(dsl::created_at.lt(created_before)),
+            ),
+        );
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.execute_async(conn),
+            generics::db_metrics::DatabaseOperation::Delete,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to delete connector audit logs")
+    }
+}
@@ -20,6 +20,7 @@ impl CustomerNew {
 pub struct CustomerListConstraints {
     pub limit: i64,
     pub offset: Option<i64>,
+    pub cursor: Option<common_utils::types::ListCursor>,
 }
 
 impl Customer {
@@ -59,14 +60,37 @@ impl Customer {
         merchant_id: &id_type::MerchantId,
         constraints: CustomerListConstraints,
     ) -> StorageResult<Vec<Self>> {
-        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
-            conn,
-            dsl::merchant_id.eq(merchant_id.to_owned()),
-            Some(constraints.limit),
-            constraints.offset,
-            Some(dsl::created_at),
-        )
-        .await
+        #[cfg(feature = "v1")]
+        let id_column = dsl::customer_id;
+        #[cfg(feature = "v2")]
+        let id_column = dsl::id;
+
+        match constraints.cursor {
+            Some(cursor) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    dsl::merchant_id.eq(merchant_id.to_owned()).and(
+                        dsl::created_at.gt(cursor.sort_key).or(dsl::created_at
+                            .eq(cursor.sort_key)
+                            .and(id_column.gt(cursor.id))),
+                    ),
+                    Some(constraints.limit),
+                    None,
+                    Some((dsl::created_at, id_column)),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    dsl::merchant_id.eq(merchant_id.to_owned()),
+                    Some(constraints.limit),
+                    constraints.offset,
+                    Some((dsl::created_at, id_column)),
+                )
+                .await
+            }
+        }
     }
 
     #[cfg(feature = "v2")]
@@ -125,6 +149,34 @@ impl Customer {
         }
     }
 
+    /// Customers which were last modified before the given time and are not yet redacted, the
+    /// least recently modified customer at the top
+    #[cfg(feature = "v1")]
+    pub async fn list_inactive_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        modified_before: time::PrimitiveDateTime,
+        redacted_description: common_utils::types::Description,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::modified_at.lt(modified_before))
+                .and(
+                    dsl::description
+                        .ne(redacted_description)
+                        .or(dsl::description.is_null()),
+                ),
+            Some(limit),
+            Some(offset),
+            Some(dsl::modified_at.asc()),
+        )
+        .await
+    }
+
     #[cfg(feature = "v1")]
     pub async fn delete_by_customer_id_merchant_id(
         conn: &PgPooledConn,
@@ -0,0 +1,73 @@
+use diesel::{associations::HasTable, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    data_retention::{
+        DataRetentionPolicy, DataRetentionPolicyNew, DataRetentionPolicyUpdate,
+        DataRetentionPurgeRun, DataRetentionPurgeRunNew,
+    },
+    schema::{data_retention_policy, data_retention_purge_run},
+    PgPooledConn, StorageResult,
+};
+
+impl DataRetentionPolicyNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<DataRetentionPolicy> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl DataRetentionPolicy {
+    pub async fn find_optional_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            data_retention_policy::dsl::merchant_id.eq(merchant_id.to_owned()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        data_retention_policy_update: DataRetentionPolicyUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            data_retention_policy::dsl::merchant_id.eq(merchant_id.to_owned()),
+            data_retention_policy_update,
+        )
+        .await
+    }
+}
+
+impl DataRetentionPurgeRunNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<DataRetentionPurgeRun> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl DataRetentionPurgeRun {
+    /// Lists the purge runs of a merchant, the latest run at the top
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            data_retention_purge_run::dsl::merchant_id.eq(merchant_id.to_owned()),
+            Some(limit),
+            Some(offset),
+            Some(data_retention_purge_run::dsl::created_at.desc()),
+        )
+        .await
+    }
+}
@@ -6,7 +6,7 @@ use diesel::{
 
 use super::generics;
 use crate::{
-    events::{Event, EventNew, EventUpdateInternal},
+    events::{Event, EventDeliveryHealth, EventNew, EventUpdateInternal},
     schema::events::dsl,
     PgPooledConn, StorageResult,
 };
@@ -344,4 +344,64 @@ impl Event {
         .change_context(DatabaseError::Others)
         .attach_printable("Error counting events by constraints")
     }
+
+    pub async fn get_delivery_health_by_profile_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+    ) -> StorageResult<EventDeliveryHealth> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{
+            debug_query,
+            dsl::sql,
+            pg::Pg,
+            sql_types::{BigInt, Double, Nullable},
+            QueryDsl,
+        };
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+        use crate::errors::DatabaseError;
+
+        let query = Self::table()
+            .select((
+                sql::<BigInt>("COUNT(*) FILTER (WHERE event_id = initial_attempt_id)"),
+                sql::<BigInt>(
+                    "COUNT(*) FILTER (WHERE event_id = initial_attempt_id \
+                     AND is_overall_delivery_successful IS TRUE)",
+                ),
+                sql::<BigInt>(
+                    "COUNT(*) FILTER (WHERE event_id = initial_attempt_id \
+                     AND is_overall_delivery_successful IS NOT TRUE AND dead_lettered_at IS NULL)",
+                ),
+                sql::<BigInt>(
+                    "COUNT(*) FILTER (WHERE event_id = initial_attempt_id \
+                     AND dead_lettered_at IS NOT NULL)",
+                ),
+                // Latency is measured for every delivery attempt, including retries
+                sql::<Nullable<Double>>(
+                    "PERCENTILE_CONT(0.95) WITHIN GROUP (ORDER BY delivery_latency_in_ms)",
+                ),
+            ))
+            .filter(
+                dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(dsl::business_profile_id.eq(profile_id.to_owned()))
+                    .and(dsl::created_at.ge(created_after))
+                    .and(dsl::created_at.le(created_before)),
+            );
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(
+            query.get_result_async::<EventDeliveryHealth>(conn),
+            DatabaseOperation::Count,
+        )
+        .await
+        .change_context(DatabaseError::Others)
+        .attach_printable("Error computing delivery health of events")
+    }
 }
@@ -0,0 +1,55 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use crate::{
+    errors::DatabaseError,
+    fx_quote::{FxQuote, FxQuoteNew, FxQuoteUpdate},
+    query::generics,
+    schema::fx_quote::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl FxQuoteNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<FxQuote> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl FxQuote {
+    pub async fn find_by_merchant_id_quote_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        quote_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.eq(quote_id.to_owned())),
+        )
+        .await
+    }
+
+    /// Marks an unused quote as used by a payment. The update fails with `NotFound` if the quote
+    /// has already been used by another payment.
+    pub async fn update_unused_by_merchant_id_quote_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        quote_id: &str,
+        fx_quote_update: FxQuoteUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.eq(quote_id.to_owned()))
+                .and(dsl::payment_id.is_null()),
+            fx_quote_update,
+        )
+        .await?
+        .pop()
+        {
+            Some(fx_quote) => Ok(fx_quote),
+            None => Err(DatabaseError::NotFound.into()),
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    enums as storage_enums,
+    list_view::{ListView, ListViewNew, ListViewUpdate},
+    schema::list_view::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl ListViewNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ListView> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ListView {
+    pub async fn find_by_merchant_id_view_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        view_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::view_id.eq(view_id.to_owned())),
+        )
+        .await
+    }
+
+    /// Lists the views the user created along with the views shared within the merchant
+    pub async fn list_visible_to_user(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        user_id: &str,
+        entity: Option<storage_enums::ListViewEntity>,
+    ) -> StorageResult<Vec<Self>> {
+        let visible_to_user = dsl::merchant_id
+            .eq(merchant_id.to_owned())
+            .and(dsl::created_by.eq(user_id.to_owned()).or(dsl::is_shared.eq(true)));
+
+        match entity {
+            Some(entity) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    visible_to_user.and(dsl::entity.eq(entity)),
+                    None,
+                    None,
+                    Some(dsl::name.asc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    visible_to_user,
+                    None,
+                    None,
+                    Some(dsl::name.asc()),
+                )
+                .await
+            }
+        }
+    }
+
+    pub async fn update_by_merchant_id_view_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        view_id: &str,
+        list_view_update: ListViewUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::view_id.eq(view_id.to_owned())),
+            list_view_update,
+        )
+        .await
+    }
+
+    pub async fn delete_by_merchant_id_view_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        view_id: &str,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::view_id.eq(view_id.to_owned())),
+        )
+        .await
+    }
+}
@@ -0,0 +1,53 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use crate::{
+    merchant_signing_key::*, query::generics, schema::merchant_signing_keys::dsl, PgPooledConn,
+    StorageResult,
+};
+
+impl MerchantSigningKeyNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<MerchantSigningKey> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl MerchantSigningKey {
+    pub async fn find_optional_by_key_id(
+        conn: &PgPooledConn,
+        key_id: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::key_id.eq(key_id.to_owned()),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn delete_by_merchant_id_key_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        key_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::key_id.eq(key_id.to_owned())),
+        )
+        .await
+    }
+}
@@ -210,6 +210,34 @@ impl PaymentAttempt {
         .await
     }
 
+    /// Lists a page of the attempts of a merchant processed by a connector within a time range,
+    /// which have one of the given statuses
+    #[cfg(feature = "v1")]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn find_by_merchant_id_connector_statuses_created_at_range(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector: &str,
+        statuses: Vec<enums::AttemptStatus>,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, Self>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::connector.eq(connector.to_owned()))
+                .and(dsl::status.eq_any(statuses))
+                .and(dsl::created_at.between(created_after, created_before)),
+            Some(limit),
+            Some(offset),
+            Some((dsl::created_at.asc(), dsl::attempt_id.asc())),
+        )
+        .await
+    }
+
     #[cfg(feature = "v2")]
     pub async fn find_by_profile_id_connector_transaction_id(
         conn: &PgPooledConn,
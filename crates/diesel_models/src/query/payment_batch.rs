@@ -0,0 +1,51 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use crate::{
+    payment_batch::{PaymentBatch, PaymentBatchNew, PaymentBatchUpdate},
+    query::generics,
+    schema::payment_batch::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl PaymentBatchNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentBatch> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PaymentBatch {
+    pub async fn find_by_merchant_id_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.eq(batch_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        payment_batch_update: PaymentBatchUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.eq(batch_id.to_owned())),
+            payment_batch_update,
+        )
+        .await
+    }
+}
@@ -0,0 +1,105 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+use router_env::logger;
+
+use crate::{
+    errors::DatabaseError,
+    payment_method_health::{
+        PaymentMethodHealth, PaymentMethodHealthNew, PaymentMethodHealthUpdate,
+    },
+    query::generics::{self, db_metrics},
+    schema::payment_method_health,
+    PgPooledConn, StorageResult,
+};
+
+impl PaymentMethodHealthNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentMethodHealth> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PaymentMethodHealth {
+    pub async fn find_optional_by_merchant_id_payment_method_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_id: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            payment_method_health::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(
+                    payment_method_health::dsl::payment_method_id.eq(payment_method_id.to_owned()),
+                ),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        payment_method_health_update: PaymentMethodHealthUpdate,
+    ) -> StorageResult<Self> {
+        let changeset = payment_method_health_update.to_internal(&self);
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            payment_method_health::dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(payment_method_health::dsl::payment_method_id.eq(self.payment_method_id)),
+            changeset,
+        )
+        .await
+    }
+
+    /// Lists the payment methods of a merchant, the ones expiring first at the top
+    pub async fn filter_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: Option<common_utils::id_type::CustomerId>,
+        card_expires_before: Option<time::PrimitiveDateTime>,
+        min_consecutive_failures: Option<i32>,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        let mut query = Self::table()
+            .filter(payment_method_health::dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order((
+                payment_method_health::dsl::card_expires_at.asc(),
+                payment_method_health::dsl::payment_method_id.asc(),
+            ))
+            .limit(limit)
+            .offset(offset)
+            .into_boxed();
+
+        if let Some(customer_id) = customer_id {
+            query = query.filter(payment_method_health::dsl::customer_id.eq(customer_id));
+        }
+        if let Some(card_expires_before) = card_expires_before {
+            query =
+                query.filter(payment_method_health::dsl::card_expires_at.le(card_expires_before));
+        }
+        if let Some(min_consecutive_failures) = min_consecutive_failures {
+            query = query.filter(
+                payment_method_health::dsl::consecutive_failures.ge(min_consecutive_failures),
+            );
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<Self, _, _>(
+            query.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(DatabaseError::Others) // Query returns empty Vec when no records are found
+        .attach_printable("Error filtering payment method health records")
+    }
+}
@@ -0,0 +1,107 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+use router_env::logger;
+
+use crate::{
+    enums,
+    errors::DatabaseError,
+    payment_review::{PaymentReview, PaymentReviewNew, PaymentReviewUpdate},
+    query::generics::{self, db_metrics},
+    schema::payment_review,
+    PgPooledConn, StorageResult,
+};
+
+impl PaymentReviewNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentReview> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PaymentReview {
+    /// Finds the review of a payment which is still waiting for a decision
+    pub async fn find_pending_by_merchant_id_payment_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            payment_review::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(payment_review::dsl::payment_id.eq(payment_id.to_owned()))
+                .and(payment_review::dsl::status.eq(enums::PaymentReviewStatus::Pending)),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        id: &str,
+        payment_review_update: PaymentReviewUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            payment_review::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(payment_review::dsl::id.eq(id.to_owned())),
+            payment_review_update,
+        )
+        .await
+    }
+
+    /// Lists the reviews of a merchant, oldest first so that the queue is worked in order
+    #[allow(clippy::too_many_arguments)]
+    pub async fn filter_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        status: Option<enums::PaymentReviewStatus>,
+        source: Option<enums::PaymentReviewSource>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        let mut query = Self::table()
+            .filter(payment_review::dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(payment_review::dsl::created_at.asc())
+            .limit(limit)
+            .offset(offset)
+            .into_boxed();
+
+        if let Some(profile_id) = profile_id {
+            query = query.filter(payment_review::dsl::profile_id.eq(profile_id));
+        }
+        if let Some(status) = status {
+            query = query.filter(payment_review::dsl::status.eq(status));
+        }
+        if let Some(source) = source {
+            query = query.filter(payment_review::dsl::source.eq(source));
+        }
+        if let Some(created_after) = created_after {
+            query = query.filter(payment_review::dsl::created_at.ge(created_after));
+        }
+        if let Some(created_before) = created_before {
+            query = query.filter(payment_review::dsl::created_at.le(created_before));
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<Self, _, _>(
+            query.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(DatabaseError::Others) // Query returns empty Vec when no records are found
+        .attach_printable("Error filtering payment reviews")
+    }
+}
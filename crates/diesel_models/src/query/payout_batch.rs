@@ -0,0 +1,51 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use crate::{
+    payout_batch::{PayoutBatch, PayoutBatchNew, PayoutBatchUpdate},
+    query::generics,
+    schema::payout_batch::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl PayoutBatchNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PayoutBatch> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PayoutBatch {
+    pub async fn find_by_merchant_id_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.eq(batch_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        payout_batch_update: PayoutBatchUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.eq(batch_id.to_owned())),
+            payout_batch_update,
+        )
+        .await
+    }
+}
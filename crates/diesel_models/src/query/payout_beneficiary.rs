@@ -0,0 +1,98 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use crate::{
+    payout_beneficiary::{PayoutBeneficiary, PayoutBeneficiaryNew, PayoutBeneficiaryUpdate},
+    query::generics,
+    schema::payout_beneficiary::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl PayoutBeneficiaryNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PayoutBeneficiary> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PayoutBeneficiary {
+    pub async fn find_by_merchant_id_beneficiary_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        beneficiary_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.eq(beneficiary_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: Option<&common_utils::id_type::CustomerId>,
+        limit: i64,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        match customer_id {
+            Some(customer_id) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    dsl::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(dsl::customer_id.eq(customer_id.to_owned())),
+                    Some(limit),
+                    offset,
+                    Some(dsl::created_at.desc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    dsl::merchant_id.eq(merchant_id.to_owned()),
+                    Some(limit),
+                    offset,
+                    Some(dsl::created_at.desc()),
+                )
+                .await
+            }
+        }
+    }
+
+    pub async fn update_by_merchant_id_beneficiary_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        beneficiary_id: &str,
+        payout_beneficiary_update: PayoutBeneficiaryUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.eq(beneficiary_id.to_owned())),
+            payout_beneficiary_update,
+        )
+        .await
+    }
+
+    pub async fn delete_by_merchant_id_beneficiary_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        beneficiary_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.eq(beneficiary_id.to_owned())),
+        )
+        .await
+    }
+}
@@ -0,0 +1,137 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    report::{ReportRun, ReportRunNew, ReportTemplate, ReportTemplateNew, ReportTemplateUpdate},
+    schema::{report_run, report_template},
+    PgPooledConn, StorageResult,
+};
+
+impl ReportTemplateNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ReportTemplate> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ReportTemplate {
+    pub async fn find_by_merchant_id_template_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            report_template::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(report_template::dsl::id.eq(template_id.to_owned())),
+        )
+        .await
+    }
+
+    /// Lists the report templates of a merchant, the latest template at the top
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            report_template::dsl::merchant_id.eq(merchant_id.to_owned()),
+            None,
+            None,
+            Some(report_template::dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_template_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+        report_template_update: ReportTemplateUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            report_template::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(report_template::dsl::id.eq(template_id.to_owned())),
+            report_template_update,
+        )
+        .await
+    }
+
+    pub async fn delete_by_merchant_id_template_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            report_template::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(report_template::dsl::id.eq(template_id.to_owned())),
+        )
+        .await
+    }
+}
+
+impl ReportRunNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ReportRun> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ReportRun {
+    pub async fn find_by_merchant_id_run_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        run_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            report_run::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(report_run::dsl::id.eq(run_id.to_owned())),
+        )
+        .await
+    }
+
+    /// Lists the report runs of a merchant, optionally of a single template, the latest run at
+    /// the top
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        match template_id {
+            Some(template_id) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    report_run::dsl::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(report_run::dsl::template_id.eq(template_id)),
+                    Some(limit),
+                    Some(offset),
+                    Some(report_run::dsl::created_at.desc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    report_run::dsl::merchant_id.eq(merchant_id.to_owned()),
+                    Some(limit),
+                    Some(offset),
+                    Some(report_run::dsl::created_at.desc()),
+                )
+                .await
+            }
+        }
+    }
+}
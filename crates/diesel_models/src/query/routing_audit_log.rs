@@ -0,0 +1,60 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{associations::HasTable, debug_query, pg::Pg, ExpressionMethods, QueryDsl};
+use error_stack::ResultExt;
+use router_env::logger;
+
+use super::generics::{
+    self,
+    db_metrics::{track_database_call, DatabaseOperation},
+};
+use crate::{
+    errors::DatabaseError,
+    routing_audit_log::{RoutingAuditLog, RoutingAuditLogNew},
+    schema::routing_audit_log::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl RoutingAuditLogNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<RoutingAuditLog> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl RoutingAuditLog {
+    #[allow(clippy::too_many_arguments)]
+    pub async fn list_by_merchant_id_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        let mut query = Self::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .into_boxed();
+
+        if let Some(profile_id) = profile_id {
+            query = query.filter(dsl::profile_id.eq(profile_id));
+        }
+
+        if let Some(created_after) = created_after {
+            query = query.filter(dsl::created_at.ge(created_after));
+        }
+
+        if let Some(created_before) = created_before {
+            query = query.filter(dsl::created_at.le(created_before));
+        }
+
+        query = query.limit(limit).offset(offset);
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error filtering routing audit log by constraints")
+    }
+}
@@ -0,0 +1,119 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, dsl, pg::Pg, BoolExpressionMethods, ExpressionMethods,
+    NullableExpressionMethods, QueryDsl,
+};
+use error_stack::{report, ResultExt};
+use router_env::logger;
+
+use super::generics::{
+    self,
+    db_metrics::{track_database_call, DatabaseOperation},
+};
+use crate::{
+    enums,
+    errors::DatabaseError,
+    routing_experiment_payment::{
+        RoutingExperimentPayment, RoutingExperimentPaymentNew, RoutingExperimentPaymentUpdate,
+    },
+    schema::routing_experiment_payment::dsl as experiment_dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl RoutingExperimentPaymentNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<RoutingExperimentPayment> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl RoutingExperimentPayment {
+    /// Finds the payment of an experiment made with the attempt, which has not reached a final
+    /// status yet
+    pub async fn find_optional_pending_by_merchant_id_attempt_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        attempt_id: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            experiment_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(experiment_dsl::attempt_id.eq(attempt_id.to_owned()))
+                .and(experiment_dsl::attempt_status.is_null()),
+        )
+        .await
+    }
+
+    /// Only updates the payment while it has not reached a final status, so that the attempt is
+    /// counted with its first final status
+    pub async fn update_pending_by_experiment_id_attempt_id(
+        conn: &PgPooledConn,
+        experiment_id: &str,
+        attempt_id: &str,
+        routing_experiment_payment: RoutingExperimentPaymentUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_results::<
+            <Self as HasTable>::Table,
+            RoutingExperimentPaymentUpdate,
+            _,
+            _,
+        >(
+            conn,
+            experiment_dsl::experiment_id
+                .eq(experiment_id.to_owned())
+                .and(experiment_dsl::attempt_id.eq(attempt_id.to_owned()))
+                .and(experiment_dsl::attempt_status.is_null()),
+            routing_experiment_payment,
+        )
+        .await?
+        .first()
+        .cloned()
+        .ok_or_else(|| {
+            report!(DatabaseError::NotFound)
+                .attach_printable("Error while updating routing_experiment_payment entry")
+        })
+    }
+
+    pub async fn delete_by_experiment_id_attempt_id(
+        conn: &PgPooledConn,
+        experiment_id: &str,
+        attempt_id: &str,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            experiment_dsl::experiment_id
+                .eq(experiment_id.to_owned())
+                .and(experiment_dsl::attempt_id.eq(attempt_id.to_owned())),
+        )
+        .await
+    }
+
+    /// Counts the payments of an experiment which reached a final status, grouped by the arm and
+    /// the status of the attempt
+    pub async fn get_status_count_by_experiment_id(
+        conn: &PgPooledConn,
+        experiment_id: &str,
+    ) -> StorageResult<Vec<(enums::RoutingExperimentArm, enums::AttemptStatus, i64)>> {
+        let query = Self::table()
+            .filter(experiment_dsl::experiment_id.eq(experiment_id.to_owned()))
+            .filter(experiment_dsl::attempt_status.is_not_null())
+            .group_by((experiment_dsl::arm, experiment_dsl::attempt_status))
+            .select((
+                experiment_dsl::arm,
+                experiment_dsl::attempt_status.assume_not_null(),
+                dsl::count_star(),
+            ));
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(
+            query.get_results_async::<(enums::RoutingExperimentArm, enums::AttemptStatus, i64)>(
+                conn,
+            ),
+            DatabaseOperation::Count,
+        )
+        .await
+        .change_context(DatabaseError::Others)
+        .attach_printable("Error counting routing experiment payments by status")
+    }
+}
@@ -0,0 +1,123 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+use router_env::logger;
+
+use crate::{
+    enums,
+    errors::DatabaseError,
+    query::generics::{self, db_metrics},
+    schema::{recon_record, settlement_report},
+    settlement_recon::{
+        ReconRecord, ReconRecordNew, SettlementReport, SettlementReportNew, SettlementReportUpdate,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl SettlementReportNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<SettlementReport> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl SettlementReport {
+    pub async fn find_by_merchant_id_report_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            settlement_report::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(settlement_report::dsl::id.eq(report_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_report_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_id: &str,
+        settlement_report_update: SettlementReportUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            settlement_report::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(settlement_report::dsl::id.eq(report_id.to_owned())),
+            settlement_report_update,
+        )
+        .await
+    }
+}
+
+impl ReconRecordNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ReconRecord> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ReconRecord {
+    pub async fn find_by_merchant_id_payment_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            recon_record::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(recon_record::dsl::payment_id.eq(payment_id.to_owned())),
+            None,
+            None,
+            Some(recon_record::dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    /// Lists the records of a merchant which were not matched, most recent first
+    pub async fn filter_exceptions_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_id: Option<String>,
+        recon_status: Option<enums::SettlementReconStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        let mut query = Self::table()
+            .filter(
+                recon_record::dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(recon_record::dsl::recon_status.ne(enums::SettlementReconStatus::Matched)),
+            )
+            .order(recon_record::dsl::created_at.desc())
+            .limit(limit)
+            .offset(offset)
+            .into_boxed();
+
+        if let Some(report_id) = report_id {
+            query = query.filter(recon_record::dsl::report_id.eq(report_id));
+        }
+        if let Some(recon_status) = recon_status {
+            query = query.filter(recon_record::dsl::recon_status.eq(recon_status));
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<Self, _, _>(
+            query.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(DatabaseError::Others) // Query returns empty Vec when no records are found
+        .attach_printable("Error filtering recon exceptions")
+    }
+}
@@ -4,8 +4,10 @@ use error_stack::report;
 use super::generics;
 use crate::{
     errors,
-    schema::subscription::dsl,
-    subscription::{Subscription, SubscriptionNew, SubscriptionUpdate},
+    schema::{subscription::dsl, subscription_plan::dsl as subscription_plan_dsl},
+    subscription::{
+        Subscription, SubscriptionNew, SubscriptionPlan, SubscriptionPlanNew, SubscriptionUpdate,
+    },
     PgPooledConn, StorageResult,
 };
 
@@ -57,3 +59,25 @@ impl Subscription {
         })
     }
 }
+
+impl SubscriptionPlanNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<SubscriptionPlan> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl SubscriptionPlan {
+    pub async fn find_by_merchant_id_plan_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            subscription_plan_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(subscription_plan_dsl::id.eq(plan_id.to_owned())),
+        )
+        .await
+    }
+}
@@ -67,6 +67,8 @@ pub struct Refund {
     pub processor_transaction_data: Option<String>,
     pub issuer_error_code: Option<String>,
     pub issuer_error_message: Option<String>,
+    pub created_by: Option<String>,
+    pub reviewed_by: Option<String>,
 }
 
 #[cfg(feature = "v2")]
@@ -166,6 +168,7 @@ pub struct RefundNew {
     pub split_refunds: Option<common_types::refunds::SplitRefund>,
     pub processor_refund_data: Option<String>,
     pub processor_transaction_data: Option<String>,
+    pub created_by: Option<String>,
 }
 
 #[cfg(feature = "v2")]
@@ -257,6 +260,12 @@ pub enum RefundUpdate {
         refund_error_code: Option<String>,
         updated_by: String,
     },
+    ReviewUpdate {
+        refund_status: storage_enums::RefundStatus,
+        refund_error_message: Option<String>,
+        reviewed_by: String,
+        updated_by: String,
+    },
 }
 
 #[cfg(feature = "v2")]
@@ -320,6 +329,7 @@ pub struct RefundUpdateInternal {
     unified_message: Option<String>,
     issuer_error_code: Option<String>,
     issuer_error_message: Option<String>,
+    reviewed_by: Option<String>,
 }
 
 #[cfg(feature = "v2")]
@@ -358,6 +368,7 @@ impl RefundUpdateInternal {
             processor_refund_data: self.processor_refund_data,
             unified_code: self.unified_code,
             unified_message: self.unified_message,
+            reviewed_by: self.reviewed_by.or(source.reviewed_by),
             ..source
         }
     }
@@ -413,6 +424,7 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 unified_message: None,
                 issuer_error_code: None,
                 issuer_error_message: None,
+                reviewed_by: None,
             },
             RefundUpdate::MetadataAndReasonUpdate {
                 metadata,
@@ -434,6 +446,7 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 unified_message: None,
                 issuer_error_code: None,
                 issuer_error_message: None,
+                reviewed_by: None,
             },
             RefundUpdate::StatusUpdate {
                 connector_refund_id,
@@ -457,6 +470,7 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 unified_message: None,
                 issuer_error_code: None,
                 issuer_error_message: None,
+                reviewed_by: None,
             },
             RefundUpdate::ErrorUpdate {
                 refund_status,
@@ -485,6 +499,7 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 unified_message,
                 issuer_error_code,
                 issuer_error_message,
+                reviewed_by: None,
             },
             RefundUpdate::ManualUpdate {
                 refund_status,
@@ -507,6 +522,30 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 unified_message: None,
                 issuer_error_code: None,
                 issuer_error_message: None,
+                reviewed_by: None,
+            },
+            RefundUpdate::ReviewUpdate {
+                refund_status,
+                refund_error_message,
+                reviewed_by,
+                updated_by,
+            } => Self {
+                refund_status: Some(refund_status),
+                refund_error_message,
+                reviewed_by: Some(reviewed_by),
+                updated_by,
+                connector_refund_id: None,
+                sent_to_gateway: None,
+                refund_arn: None,
+                metadata: None,
+                refund_reason: None,
+                refund_error_code: None,
+                modified_at: common_utils::date_time::now(),
+                processor_refund_data: None,
+                unified_code: None,
+                unified_message: None,
+                issuer_error_code: None,
+                issuer_error_message: None,
             },
         }
     }
@@ -646,6 +685,7 @@ impl RefundUpdate {
             unified_message,
             issuer_error_code,
             issuer_error_message,
+            reviewed_by,
         } = self.into();
         Refund {
             connector_refund_id: connector_refund_id.or(source.connector_refund_id),
@@ -663,6 +703,7 @@ impl RefundUpdate {
             unified_message: unified_message.or(source.unified_message),
             issuer_error_code: issuer_error_code.or(source.issuer_error_code),
             issuer_error_message: issuer_error_message.or(source.issuer_error_message),
+            reviewed_by: reviewed_by.or(source.reviewed_by),
             ..source
         }
     }
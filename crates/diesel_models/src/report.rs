@@ -0,0 +1,96 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums as storage_enums,
+    schema::{report_run, report_template},
+};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = report_template)]
+pub struct ReportTemplateNew {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub created_by: String,
+    pub name: String,
+    pub domain: storage_enums::ReportDomain,
+    pub metrics: serde_json::Value,
+    pub dimensions: serde_json::Value,
+    pub filters: serde_json::Value,
+    pub format: storage_enums::ReportFormat,
+    pub frequency: storage_enums::ReportFrequency,
+    pub delivery_method: storage_enums::ReportDeliveryMethod,
+    pub recipients: serde_json::Value,
+    pub is_active: bool,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Deserialize, Serialize)]
+#[diesel(table_name = report_template, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct ReportTemplate {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub created_by: String,
+    pub name: String,
+    pub domain: storage_enums::ReportDomain,
+    pub metrics: serde_json::Value,
+    pub dimensions: serde_json::Value,
+    pub filters: serde_json::Value,
+    pub format: storage_enums::ReportFormat,
+    pub frequency: storage_enums::ReportFrequency,
+    pub delivery_method: storage_enums::ReportDeliveryMethod,
+    pub recipients: serde_json::Value,
+    pub is_active: bool,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = report_template)]
+pub struct ReportTemplateUpdate {
+    pub name: Option<String>,
+    pub metrics: Option<serde_json::Value>,
+    pub dimensions: Option<serde_json::Value>,
+    pub filters: Option<serde_json::Value>,
+    pub format: Option<storage_enums::ReportFormat>,
+    pub frequency: Option<storage_enums::ReportFrequency>,
+    pub delivery_method: Option<storage_enums::ReportDeliveryMethod>,
+    pub recipients: Option<serde_json::Value>,
+    pub is_active: Option<bool>,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = report_run)]
+pub struct ReportRunNew {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub template_id: String,
+    pub status: storage_enums::ReportRunStatus,
+    pub triggered_by: String,
+    pub start_time: time::PrimitiveDateTime,
+    pub end_time: time::PrimitiveDateTime,
+    pub format: storage_enums::ReportFormat,
+    pub row_count: Option<i64>,
+    pub file_key: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Deserialize, Serialize)]
+#[diesel(table_name = report_run, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct ReportRun {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub template_id: String,
+    pub status: storage_enums::ReportRunStatus,
+    pub triggered_by: String,
+    pub start_time: time::PrimitiveDateTime,
+    pub end_time: time::PrimitiveDateTime,
+    pub format: storage_enums::ReportFormat,
+    pub row_count: Option<i64>,
+    pub file_key: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: time::PrimitiveDateTime,
+}
@@ -21,6 +21,8 @@ pub struct Role {
     pub entity_type: enums::EntityType,
     pub profile_id: Option<id_type::ProfileId>,
     pub tenant_id: id_type::TenantId,
+    /// Permissions granted by the role in addition to the ones of its groups
+    pub permissions: Option<RolePermissions>,
 }
 
 #[derive(router_derive::Setter, Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -40,6 +42,8 @@ pub struct RoleNew {
     pub entity_type: enums::EntityType,
     pub profile_id: Option<id_type::ProfileId>,
     pub tenant_id: id_type::TenantId,
+    /// Permissions granted by the role in addition to the ones of its groups
+    pub permissions: Option<RolePermissions>,
 }
 
 #[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
@@ -49,11 +53,13 @@ pub struct RoleUpdateInternal {
     role_name: Option<String>,
     last_modified_by: String,
     last_modified_at: PrimitiveDateTime,
+    permissions: Option<RolePermissions>,
 }
 
 pub enum RoleUpdate {
     UpdateDetails {
         groups: Option<Vec<enums::PermissionGroup>>,
+        permissions: Option<Vec<enums::RolePermission>>,
         role_name: Option<String>,
         last_modified_at: PrimitiveDateTime,
         last_modified_by: String,
@@ -65,6 +71,7 @@ impl From<RoleUpdate> for RoleUpdateInternal {
         match value {
             RoleUpdate::UpdateDetails {
                 groups,
+                permissions,
                 role_name,
                 last_modified_by,
                 last_modified_at,
@@ -73,11 +80,30 @@ impl From<RoleUpdate> for RoleUpdateInternal {
                 role_name,
                 last_modified_at,
                 last_modified_by,
+                permissions: permissions.map(RolePermissions),
             },
         }
     }
 }
 
+impl Role {
+    pub fn get_permissions(&self) -> Vec<enums::RolePermission> {
+        self.permissions
+            .as_ref()
+            .map(|permissions| permissions.0.clone())
+            .unwrap_or_default()
+    }
+}
+
+/// The resource and scope pairs of the individual permissions of a role
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression, diesel::FromSqlRow,
+)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct RolePermissions(pub Vec<enums::RolePermission>);
+
+common_utils::impl_to_sql_from_sql_json!(RolePermissions);
+
 #[derive(Clone, Debug)]
 pub enum ListRolesByEntityPayload {
     Profile(id_type::MerchantId, id_type::ProfileId),
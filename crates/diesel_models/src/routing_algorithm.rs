@@ -48,3 +48,13 @@ impl RoutingProfileMetadata {
             && matches!(self.algorithm_for, enums::TransactionType::Payment)
     }
 }
+
+/// Tracking data of the process tracker task which activates a routing algorithm at a scheduled time
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RoutingActivationTrackingData {
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub algorithm_id: id_type::RoutingId,
+    pub transaction_type: enums::TransactionType,
+    pub created_by: Option<common_utils::types::CreatedBy>,
+}
@@ -0,0 +1,33 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+
+use crate::{enums, schema::routing_audit_log};
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable)]
+#[diesel(table_name = routing_audit_log)]
+pub struct RoutingAuditLogNew {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub action: enums::RoutingAuditAction,
+    pub transaction_type: enums::TransactionType,
+    pub previous_algorithm_id: Option<common_utils::id_type::RoutingId>,
+    pub new_algorithm_id: Option<common_utils::id_type::RoutingId>,
+    pub config_diff: Option<serde_json::Value>,
+    pub created_by: Option<String>,
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = routing_audit_log, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct RoutingAuditLog {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub action: enums::RoutingAuditAction,
+    pub transaction_type: enums::TransactionType,
+    pub previous_algorithm_id: Option<common_utils::id_type::RoutingId>,
+    pub new_algorithm_id: Option<common_utils::id_type::RoutingId>,
+    pub config_diff: Option<serde_json::Value>,
+    pub created_by: Option<String>,
+    pub created_at: time::PrimitiveDateTime,
+}
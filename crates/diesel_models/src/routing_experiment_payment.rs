@@ -0,0 +1,52 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+
+use crate::{enums, schema::routing_experiment_payment};
+
+/// Recorded when a payment attempt is routed with an arm of a routing experiment. The status of
+/// the attempt is set once the attempt reaches a final status.
+#[derive(Clone, Debug, Eq, PartialEq, Insertable)]
+#[diesel(table_name = routing_experiment_payment)]
+pub struct RoutingExperimentPaymentNew {
+    pub experiment_id: String,
+    pub attempt_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub arm: enums::RoutingExperimentArm,
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    /// The merchant connector accounts chosen by the algorithm of the arm
+    pub merchant_connector_ids: Vec<Option<common_utils::id_type::MerchantConnectorAccountId>>,
+    pub connector: Option<String>,
+    pub attempt_status: Option<enums::AttemptStatus>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = routing_experiment_payment,
+    primary_key(experiment_id, attempt_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct RoutingExperimentPayment {
+    pub experiment_id: String,
+    pub attempt_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub arm: enums::RoutingExperimentArm,
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    pub merchant_connector_ids: Vec<Option<common_utils::id_type::MerchantConnectorAccountId>>,
+    pub connector: Option<String>,
+    pub attempt_status: Option<enums::AttemptStatus>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = routing_experiment_payment)]
+pub struct RoutingExperimentPaymentUpdate {
+    pub connector: Option<String>,
+    pub attempt_status: Option<enums::AttemptStatus>,
+    pub modified_at: time::PrimitiveDateTime,
+}
@@ -35,6 +35,39 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    admin_audit_events (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 32]
+        org_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        merchant_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 255]
+        actor_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        auth_type -> Varchar,
+        #[max_length = 64]
+        ip_address -> Nullable<Varchar>,
+        #[max_length = 64]
+        api_flow -> Varchar,
+        #[max_length = 16]
+        http_method -> Varchar,
+        #[max_length = 2048]
+        url_path -> Varchar,
+        #[max_length = 64]
+        request_id -> Nullable<Varchar>,
+        status_code -> Int8,
+        request_body -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -55,6 +88,52 @@ diesel::table! {
         created_at -> Timestamp,
         expires_at -> Nullable<Timestamp>,
         last_used -> Nullable<Timestamp>,
+        scopes -> Nullable<Array<Nullable<Text>>>,
+        allowed_ip_ranges -> Nullable<Array<Nullable<Text>>>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    auth_rate_alert (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        connector -> Varchar,
+        #[max_length = 64]
+        payment_method -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        baseline_rate -> Float8,
+        current_rate -> Float8,
+        attempt_count -> Int8,
+        triggered_at -> Timestamp,
+        resolved_at -> Nullable<Timestamp>,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    auth_rate_alert_config (merchant_id) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        drop_threshold_percentage -> Float8,
+        baseline_window_in_days -> Int4,
+        evaluation_window_in_minutes -> Int4,
+        min_attempts -> Int8,
+        #[max_length = 2048]
+        webhook_url -> Nullable<Varchar>,
+        recipients -> Jsonb,
+        is_enabled -> Bool,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
     }
 }
 
@@ -154,11 +233,12 @@ diesel::table! {
     blocklist (merchant_id, fingerprint_id) {
         #[max_length = 64]
         merchant_id -> Varchar,
-        #[max_length = 64]
+        #[max_length = 255]
         fingerprint_id -> Varchar,
         data_kind -> BlocklistDataKind,
         metadata -> Nullable<Jsonb>,
         created_at -> Timestamp,
+        expires_at -> Nullable<Timestamp>,
     }
 }
 
@@ -257,6 +337,14 @@ diesel::table! {
         dispute_polling_interval -> Nullable<Int4>,
         is_manual_retry_enabled -> Nullable<Bool>,
         always_enable_overcapture -> Nullable<Bool>,
+        retry_policy -> Nullable<Jsonb>,
+        intent_expiry -> Nullable<Int8>,
+        refund_approval_threshold -> Nullable<Int8>,
+        webhook_retry_policy -> Nullable<Jsonb>,
+        event_sink_config -> Nullable<Jsonb>,
+        is_connector_audit_capture_enabled -> Nullable<Bool>,
+        soft_decline_retry_config -> Nullable<Jsonb>,
+        webhook_endpoint_secrets -> Nullable<Bytea>,
     }
 }
 
@@ -349,6 +437,44 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    connector_audit_logs (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        attempt_id -> Varchar,
+        #[max_length = 64]
+        refund_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        dispute_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        connector -> Varchar,
+        #[max_length = 64]
+        flow -> Varchar,
+        #[max_length = 16]
+        http_method -> Varchar,
+        #[max_length = 2048]
+        url -> Varchar,
+        masked_request -> Nullable<Jsonb>,
+        masked_response -> Nullable<Jsonb>,
+        error -> Nullable<Jsonb>,
+        status_code -> Int4,
+        latency_in_ms -> Int8,
+        #[max_length = 64]
+        request_id -> Nullable<Varchar>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -377,6 +503,8 @@ diesel::table! {
         updated_by -> Nullable<Varchar>,
         version -> ApiVersion,
         tax_registration_id -> Nullable<Bytea>,
+        #[max_length = 64]
+        merged_into_customer_id -> Nullable<Varchar>,
     }
 }
 
@@ -403,6 +531,41 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    data_retention_policy (merchant_id) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        connector_payload_retention_days -> Nullable<Int4>,
+        inactive_customer_retention_days -> Nullable<Int4>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    data_retention_purge_run (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        is_dry_run -> Bool,
+        #[max_length = 255]
+        triggered_by -> Varchar,
+        connector_payloads_created_before -> Nullable<Timestamp>,
+        connector_payloads_purged -> Int8,
+        customers_inactive_since -> Nullable<Timestamp>,
+        redacted_customer_ids -> Jsonb,
+        failed_customer_ids -> Jsonb,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -446,6 +609,8 @@ diesel::table! {
         #[max_length = 32]
         organization_id -> Varchar,
         dispute_currency -> Nullable<Currency>,
+        automated_action -> Nullable<Jsonb>,
+        evidence_submitted_at -> Nullable<Timestamp>,
     }
 }
 
@@ -510,6 +675,10 @@ diesel::table! {
         delivery_attempt -> Nullable<WebhookDeliveryAttempt>,
         metadata -> Nullable<Jsonb>,
         is_overall_delivery_successful -> Nullable<Bool>,
+        dead_lettered_at -> Nullable<Timestamp>,
+        #[max_length = 32]
+        webhook_endpoint_id -> Nullable<Varchar>,
+        delivery_latency_in_ms -> Nullable<Int4>,
     }
 }
 
@@ -575,6 +744,29 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    fx_quote (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        amount -> Int8,
+        from_currency -> Currency,
+        converted_amount -> Int8,
+        to_currency -> Currency,
+        #[max_length = 64]
+        rate -> Varchar,
+        #[max_length = 64]
+        payment_id -> Nullable<Varchar>,
+        expires_at -> Timestamp,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -715,6 +907,28 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    list_view (view_id) {
+        #[max_length = 64]
+        view_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        created_by -> Varchar,
+        #[max_length = 255]
+        name -> Varchar,
+        #[max_length = 32]
+        entity -> Varchar,
+        filters -> Jsonb,
+        is_shared -> Bool,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -846,6 +1060,7 @@ diesel::table! {
         product_type -> Nullable<Varchar>,
         #[max_length = 64]
         merchant_account_type -> Nullable<Varchar>,
+        allowed_ip_ranges -> Nullable<Array<Nullable<Text>>>,
     }
 }
 
@@ -903,6 +1118,20 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    merchant_signing_keys (key_id) {
+        #[max_length = 64]
+        key_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        signing_secret -> Bytea,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -923,6 +1152,7 @@ diesel::table! {
         organization_type -> Nullable<Varchar>,
         #[max_length = 64]
         platform_merchant_id -> Nullable<Varchar>,
+        is_passkey_required -> Nullable<Bool>,
     }
 }
 
@@ -1045,6 +1275,28 @@ diesel::table! {
         network_transaction_id -> Nullable<Varchar>,
         is_overcapture_enabled -> Nullable<Bool>,
         network_details -> Nullable<Jsonb>,
+        dcc_details -> Nullable<Jsonb>,
+        device_fingerprint -> Nullable<Jsonb>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_batch (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        status -> Varchar,
+        total_count -> Int4,
+        items -> Jsonb,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
     }
 }
 
@@ -1146,6 +1398,10 @@ diesel::table! {
         discount_amount -> Nullable<Int8>,
         shipping_amount_tax -> Nullable<Int8>,
         duty_amount -> Nullable<Int8>,
+        #[max_length = 255]
+        purchase_order_number -> Nullable<Varchar>,
+        #[max_length = 32]
+        mit_category -> Nullable<Varchar>,
         order_date -> Nullable<Timestamp>,
         enable_partial_authorization -> Nullable<Bool>,
         enable_overcapture -> Nullable<Bool>,
@@ -1182,6 +1438,29 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_method_health (merchant_id, payment_method_id) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_method_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        last_successful_use_at -> Nullable<Timestamp>,
+        last_failed_use_at -> Nullable<Timestamp>,
+        successful_uses -> Int4,
+        failed_uses -> Int4,
+        consecutive_failures -> Int4,
+        card_expires_at -> Nullable<Timestamp>,
+        account_updated_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1243,6 +1522,39 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_review (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        attempt_id -> Varchar,
+        #[max_length = 32]
+        source -> Varchar,
+        #[max_length = 255]
+        reason -> Nullable<Varchar>,
+        #[max_length = 32]
+        status -> Varchar,
+        amount -> Int8,
+        currency -> Nullable<Currency>,
+        #[max_length = 255]
+        decided_by -> Nullable<Varchar>,
+        #[max_length = 255]
+        decision_reason -> Nullable<Varchar>,
+        decided_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1289,6 +1601,49 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payout_batch (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        status -> Varchar,
+        total_count -> Int4,
+        items -> Jsonb,
+        scheduled_at -> Timestamp,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payout_beneficiary (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Nullable<Varchar>,
+        payout_type -> PayoutType,
+        payout_method_data -> Bytea,
+        additional_payout_method_data -> Nullable<Jsonb>,
+        #[max_length = 32]
+        kyc_status -> Varchar,
+        metadata -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1360,6 +1715,41 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    recon_record (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        report_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        connector -> Varchar,
+        #[max_length = 32]
+        transaction_type -> Varchar,
+        #[max_length = 255]
+        connector_transaction_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        payment_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        attempt_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        refund_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        recon_status -> Varchar,
+        settled_amount -> Nullable<Int8>,
+        settled_currency -> Nullable<Currency>,
+        expected_amount -> Nullable<Int8>,
+        expected_currency -> Nullable<Currency>,
+        fee -> Nullable<Int8>,
+        settled_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1423,6 +1813,10 @@ diesel::table! {
         #[max_length = 64]
         issuer_error_code -> Nullable<Varchar>,
         issuer_error_message -> Nullable<Text>,
+        #[max_length = 255]
+        created_by -> Nullable<Varchar>,
+        #[max_length = 255]
+        reviewed_by -> Nullable<Varchar>,
     }
 }
 
@@ -1455,6 +1849,64 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    report_run (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        template_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 255]
+        triggered_by -> Varchar,
+        start_time -> Timestamp,
+        end_time -> Timestamp,
+        #[max_length = 16]
+        format -> Varchar,
+        row_count -> Nullable<Int8>,
+        #[max_length = 255]
+        file_key -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    report_template (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        created_by -> Varchar,
+        #[max_length = 255]
+        name -> Varchar,
+        #[max_length = 32]
+        domain -> Varchar,
+        metrics -> Jsonb,
+        dimensions -> Jsonb,
+        filters -> Jsonb,
+        #[max_length = 16]
+        format -> Varchar,
+        #[max_length = 16]
+        frequency -> Varchar,
+        #[max_length = 32]
+        delivery_method -> Varchar,
+        recipients -> Jsonb,
+        is_active -> Bool,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1500,6 +1952,7 @@ diesel::table! {
         profile_id -> Nullable<Varchar>,
         #[max_length = 64]
         tenant_id -> Varchar,
+        permissions -> Nullable<Jsonb>,
     }
 }
 
@@ -1528,6 +1981,90 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    routing_audit_log (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 32]
+        action -> Varchar,
+        transaction_type -> TransactionType,
+        #[max_length = 64]
+        previous_algorithm_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        new_algorithm_id -> Nullable<Varchar>,
+        config_diff -> Nullable<Jsonb>,
+        #[max_length = 255]
+        created_by -> Nullable<Varchar>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    routing_experiment_payment (experiment_id, attempt_id) {
+        #[max_length = 64]
+        experiment_id -> Varchar,
+        #[max_length = 64]
+        attempt_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 32]
+        arm -> Varchar,
+        #[max_length = 64]
+        algorithm_id -> Varchar,
+        merchant_connector_ids -> Array<Nullable<Text>>,
+        #[max_length = 64]
+        connector -> Nullable<Varchar>,
+        attempt_status -> Nullable<AttemptStatus>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    settlement_report (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        connector -> Varchar,
+        #[max_length = 64]
+        merchant_connector_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        source -> Varchar,
+        #[max_length = 255]
+        file_name -> Nullable<Varchar>,
+        #[max_length = 32]
+        status -> Varchar,
+        transactions_from -> Nullable<Timestamp>,
+        transactions_to -> Nullable<Timestamp>,
+        total_count -> Int4,
+        matched_count -> Int4,
+        mismatched_count -> Int4,
+        missing_count -> Int4,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1558,6 +2095,41 @@ diesel::table! {
         profile_id -> Varchar,
         #[max_length = 128]
         merchant_reference_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        plan_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        mandate_id -> Nullable<Varchar>,
+        trial_end -> Nullable<Timestamp>,
+        current_period_start -> Nullable<Timestamp>,
+        current_period_end -> Nullable<Timestamp>,
+        #[max_length = 64]
+        last_payment_id -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    subscription_plan (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 255]
+        name -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        #[max_length = 32]
+        billing_interval -> Varchar,
+        billing_interval_count -> Int4,
+        trial_period_days -> Nullable<Int4>,
+        max_payment_retries -> Int4,
+        payment_retry_interval_hours -> Int4,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
     }
 }
 
@@ -1649,6 +2221,23 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    user_passkeys (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        user_id -> Varchar,
+        #[max_length = 64]
+        name -> Varchar,
+        passkey -> Jsonb,
+        created_at -> Timestamp,
+        last_used_at -> Nullable<Timestamp>,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1708,7 +2297,10 @@ diesel::table! {
 
 diesel::allow_tables_to_appear_in_same_query!(
     address,
+    admin_audit_events,
     api_keys,
+    auth_rate_alert,
+    auth_rate_alert_config,
     authentication,
     blocklist,
     blocklist_fingerprint,
@@ -1718,41 +2310,60 @@ diesel::allow_tables_to_appear_in_same_query!(
     captures,
     cards_info,
     configs,
+    connector_audit_logs,
     customers,
     dashboard_metadata,
+    data_retention_policy,
+    data_retention_purge_run,
     dispute,
     dynamic_routing_stats,
     events,
     file_metadata,
     fraud_check,
+    fx_quote,
     gateway_status_map,
     generic_link,
     hyperswitch_ai_interaction,
     hyperswitch_ai_interaction_default,
     incremental_authorization,
+    list_view,
     locker_mock_up,
     mandate,
     merchant_account,
     merchant_connector_account,
     merchant_key_store,
+    merchant_signing_keys,
     organization,
     payment_attempt,
+    payment_batch,
     payment_intent,
     payment_link,
+    payment_method_health,
     payment_methods,
+    payment_review,
     payout_attempt,
+    payout_batch,
+    payout_beneficiary,
     payouts,
     process_tracker,
+    recon_record,
     refund,
     relay,
+    report_run,
+    report_template,
     reverse_lookup,
     roles,
     routing_algorithm,
+    routing_audit_log,
+    routing_experiment_payment,
+    settlement_report,
     subscription,
+    subscription_plan,
     themes,
     unified_translations,
     user_authentication_methods,
     user_key_store,
+    user_passkeys,
     user_roles,
     users,
 );
@@ -0,0 +1,120 @@
+use common_utils::types::MinorUnit;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums,
+    schema::{recon_record, settlement_report},
+};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = settlement_report)]
+pub struct SettlementReportNew {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub connector: String,
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub source: enums::SettlementReportSource,
+    pub file_name: Option<String>,
+    pub status: enums::SettlementReportStatus,
+    pub transactions_from: Option<time::PrimitiveDateTime>,
+    pub transactions_to: Option<time::PrimitiveDateTime>,
+    pub total_count: i32,
+    pub matched_count: i32,
+    pub mismatched_count: i32,
+    pub missing_count: i32,
+    pub error_message: Option<String>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Deserialize, Serialize)]
+#[diesel(table_name = settlement_report, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct SettlementReport {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub connector: String,
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub source: enums::SettlementReportSource,
+    pub file_name: Option<String>,
+    pub status: enums::SettlementReportStatus,
+    pub transactions_from: Option<time::PrimitiveDateTime>,
+    pub transactions_to: Option<time::PrimitiveDateTime>,
+    pub total_count: i32,
+    pub matched_count: i32,
+    pub mismatched_count: i32,
+    pub missing_count: i32,
+    pub error_message: Option<String>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = settlement_report)]
+pub struct SettlementReportUpdate {
+    pub status: enums::SettlementReportStatus,
+    pub total_count: Option<i32>,
+    pub matched_count: Option<i32>,
+    pub mismatched_count: Option<i32>,
+    pub missing_count: Option<i32>,
+    pub error_message: Option<String>,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+impl SettlementReportUpdate {
+    pub fn failed(error_message: String) -> Self {
+        Self {
+            status: enums::SettlementReportStatus::Failed,
+            total_count: None,
+            matched_count: None,
+            mismatched_count: None,
+            missing_count: None,
+            error_message: Some(error_message),
+            modified_at: common_utils::date_time::now(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = recon_record)]
+pub struct ReconRecordNew {
+    pub id: String,
+    pub report_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub connector: String,
+    pub transaction_type: enums::ReconTransactionType,
+    pub connector_transaction_id: Option<String>,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub attempt_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub recon_status: enums::SettlementReconStatus,
+    pub settled_amount: Option<MinorUnit>,
+    pub settled_currency: Option<enums::Currency>,
+    pub expected_amount: Option<MinorUnit>,
+    pub expected_currency: Option<enums::Currency>,
+    pub fee: Option<MinorUnit>,
+    pub settled_at: Option<time::PrimitiveDateTime>,
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Deserialize, Serialize)]
+#[diesel(table_name = recon_record, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct ReconRecord {
+    pub id: String,
+    pub report_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub connector: String,
+    pub transaction_type: enums::ReconTransactionType,
+    pub connector_transaction_id: Option<String>,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub attempt_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub recon_status: enums::SettlementReconStatus,
+    pub settled_amount: Option<MinorUnit>,
+    pub settled_currency: Option<enums::Currency>,
+    pub expected_amount: Option<MinorUnit>,
+    pub expected_currency: Option<enums::Currency>,
+    pub fee: Option<MinorUnit>,
+    pub settled_at: Option<time::PrimitiveDateTime>,
+    pub created_at: time::PrimitiveDateTime,
+}
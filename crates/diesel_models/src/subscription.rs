@@ -1,9 +1,12 @@
-use common_utils::{generate_id_with_default_len, pii::SecretSerdeValue};
+use common_utils::{generate_id_with_default_len, pii::SecretSerdeValue, types::MinorUnit};
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
 use masking::Secret;
 use serde::{Deserialize, Serialize};
 
-use crate::schema::subscription;
+use crate::{
+    enums,
+    schema::{subscription, subscription_plan},
+};
 
 #[derive(Clone, Debug, Eq, Insertable, PartialEq, Serialize, Deserialize)]
 #[diesel(table_name = subscription)]
@@ -22,6 +25,7 @@ pub struct SubscriptionNew {
     modified_at: time::PrimitiveDateTime,
     profile_id: common_utils::id_type::ProfileId,
     merchant_reference_id: Option<String>,
+    plan_id: Option<String>,
 }
 
 #[derive(
@@ -43,6 +47,12 @@ pub struct Subscription {
     pub modified_at: time::PrimitiveDateTime,
     pub profile_id: common_utils::id_type::ProfileId,
     pub merchant_reference_id: Option<String>,
+    pub plan_id: Option<String>,
+    pub mandate_id: Option<String>,
+    pub trial_end: Option<time::PrimitiveDateTime>,
+    pub current_period_start: Option<time::PrimitiveDateTime>,
+    pub current_period_end: Option<time::PrimitiveDateTime>,
+    pub last_payment_id: Option<common_utils::id_type::PaymentId>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, AsChangeset, router_derive::DebugAsDisplay, Deserialize)]
@@ -51,6 +61,11 @@ pub struct SubscriptionUpdate {
     pub payment_method_id: Option<String>,
     pub status: Option<String>,
     pub modified_at: time::PrimitiveDateTime,
+    pub mandate_id: Option<String>,
+    pub trial_end: Option<time::PrimitiveDateTime>,
+    pub current_period_start: Option<time::PrimitiveDateTime>,
+    pub current_period_end: Option<time::PrimitiveDateTime>,
+    pub last_payment_id: Option<common_utils::id_type::PaymentId>,
 }
 
 impl SubscriptionNew {
@@ -68,6 +83,7 @@ impl SubscriptionNew {
         metadata: Option<SecretSerdeValue>,
         profile_id: common_utils::id_type::ProfileId,
         merchant_reference_id: Option<String>,
+        plan_id: Option<String>,
     ) -> Self {
         let now = common_utils::date_time::now();
         Self {
@@ -85,6 +101,7 @@ impl SubscriptionNew {
             modified_at: now,
             profile_id,
             merchant_reference_id,
+            plan_id,
         }
     }
 
@@ -102,6 +119,58 @@ impl SubscriptionUpdate {
             payment_method_id,
             status,
             modified_at: common_utils::date_time::now(),
+            mandate_id: None,
+            trial_end: None,
+            current_period_start: None,
+            current_period_end: None,
+            last_payment_id: None,
         }
     }
 }
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = subscription_plan)]
+pub struct SubscriptionPlanNew {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub name: String,
+    pub amount: MinorUnit,
+    pub currency: enums::Currency,
+    pub billing_interval: enums::SubscriptionBillingInterval,
+    pub billing_interval_count: i32,
+    pub trial_period_days: Option<i32>,
+    pub max_payment_retries: i32,
+    pub payment_retry_interval_hours: i32,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Deserialize, Serialize,
+)]
+#[diesel(table_name = subscription_plan, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct SubscriptionPlan {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub name: String,
+    pub amount: MinorUnit,
+    pub currency: enums::Currency,
+    pub billing_interval: enums::SubscriptionBillingInterval,
+    pub billing_interval_count: i32,
+    pub trial_period_days: Option<i32>,
+    pub max_payment_retries: i32,
+    pub payment_retry_interval_hours: i32,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+/// Tracking data of the process tracker task which charges a subscription at the end of each
+/// billing period
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SubscriptionBillingTrackingData {
+    pub subscription_id: common_utils::id_type::SubscriptionId,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+}
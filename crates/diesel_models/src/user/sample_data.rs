@@ -18,7 +18,7 @@ use time::PrimitiveDateTime;
 use crate::{
     enums::{MandateDataType, MandateDetails},
     schema::payment_attempt,
-    ConnectorMandateReferenceId, NetworkDetails, PaymentAttemptNew,
+    ConnectorMandateReferenceId, DccDetails, DeviceFingerprint, NetworkDetails, PaymentAttemptNew,
 };
 
 // #[cfg(feature = "v2")]
@@ -220,6 +220,8 @@ pub struct PaymentAttemptBatchNew {
     pub connector_request_reference_id: Option<String>,
     pub network_transaction_id: Option<String>,
     pub network_details: Option<NetworkDetails>,
+    pub dcc_details: Option<DccDetails>,
+    pub device_fingerprint: Option<DeviceFingerprint>,
 }
 
 #[cfg(feature = "v1")]
@@ -309,6 +311,8 @@ impl PaymentAttemptBatchNew {
             connector_request_reference_id: self.connector_request_reference_id,
             network_transaction_id: self.network_transaction_id,
             network_details: self.network_details,
+            dcc_details: self.dcc_details,
+            device_fingerprint: self.device_fingerprint,
         }
     }
 }
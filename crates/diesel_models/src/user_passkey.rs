@@ -0,0 +1,56 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::schema::user_passkeys;
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = user_passkeys, check_for_backend(diesel::pg::Pg))]
+pub struct UserPasskey {
+    pub id: String,
+    pub user_id: String,
+    pub name: String,
+    pub passkey: serde_json::Value,
+    pub created_at: PrimitiveDateTime,
+    pub last_used_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = user_passkeys)]
+pub struct UserPasskeyNew {
+    pub id: String,
+    pub user_id: String,
+    pub name: String,
+    pub passkey: serde_json::Value,
+    pub created_at: PrimitiveDateTime,
+    pub last_used_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = user_passkeys)]
+pub struct UserPasskeyUpdateInternal {
+    pub name: Option<String>,
+    pub passkey: Option<serde_json::Value>,
+    pub last_used_at: Option<PrimitiveDateTime>,
+}
+
+pub enum UserPasskeyUpdate {
+    Rename { name: String },
+    CredentialUsed { passkey: serde_json::Value },
+}
+
+impl From<UserPasskeyUpdate> for UserPasskeyUpdateInternal {
+    fn from(value: UserPasskeyUpdate) -> Self {
+        match value {
+            UserPasskeyUpdate::Rename { name } => Self {
+                name: Some(name),
+                passkey: None,
+                last_used_at: None,
+            },
+            UserPasskeyUpdate::CredentialUsed { passkey } => Self {
+                name: None,
+                passkey: Some(passkey),
+                last_used_at: Some(common_utils::date_time::now()),
+            },
+        }
+    }
+}
@@ -27,6 +27,22 @@ pub enum HealthCheckError {
     RedisError { message: String },
 }
 
+#[derive(Debug, Error, Clone, serde::Serialize)]
+pub enum DrainerApiError {
+    #[error("API key not provided or invalid API key used")]
+    Unauthorized,
+    #[error("Tenant `{tenant_id}` does not exist")]
+    TenantNotFound { tenant_id: String },
+    #[error("Shard {shard} does not exist, the streams are split into {num_partitions} shards")]
+    ShardNotFound { shard: u8, num_partitions: u8 },
+    #[error("Entry `{entry_id}` does not exist in the stream")]
+    EntryNotFound { entry_id: String },
+    #[error("The stream is being drained, retry after some time")]
+    StreamBusy,
+    #[error("Failed to access the stream")]
+    StreamAccessError,
+}
+
 impl From<std::io::Error> for DrainerError {
     fn from(err: std::io::Error) -> Self {
         Self::IoError(err)
@@ -56,3 +72,18 @@ impl actix_web::ResponseError for HealthCheckError {
         }
     }
 }
+
+impl actix_web::ResponseError for DrainerApiError {
+    fn status_code(&self) -> reqwest::StatusCode {
+        use reqwest::StatusCode;
+
+        match self {
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::TenantNotFound { .. }
+            | Self::ShardNotFound { .. }
+            | Self::EntryNotFound { .. } => StatusCode::NOT_FOUND,
+            Self::StreamBusy => StatusCode::CONFLICT,
+            Self::StreamAccessError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
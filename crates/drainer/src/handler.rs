@@ -11,8 +11,9 @@ use tokio::{
 };
 
 use crate::{
-    errors, instrument, logger, metrics, query::ExecuteQuery, tracing, utils, DrainerSettings,
-    Store, StreamData,
+    errors, instrument, logger, metrics,
+    query::{self, ExecuteQuery},
+    tracing, utils, DrainerSettings, Store, StreamData,
 };
 
 /// Handler handles the spawning and closing of drainer
@@ -82,6 +83,7 @@ impl Handler {
                             store.clone(),
                             stream_index,
                             self.conf.max_read_count,
+                            self.conf.max_query_attempts,
                             self.active_tasks.clone(),
                             jobs_picked.clone(),
                         )
@@ -174,6 +176,7 @@ async fn drainer_handler(
     store: Arc<Store>,
     stream_index: u8,
     max_read_count: u64,
+    max_query_attempts: u32,
     active_tasks: Arc<atomic::AtomicU64>,
     jobs_picked: Arc<atomic::AtomicU8>,
 ) -> errors::DrainerResult<()> {
@@ -183,7 +186,9 @@ async fn drainer_handler(
 
     let drainer_result = Box::pin(drainer(
         store.clone(),
+        stream_index,
         max_read_count,
+        max_query_attempts,
         stream_name.as_str(),
         jobs_picked,
     ))
@@ -203,7 +208,9 @@ async fn drainer_handler(
 #[instrument(skip_all, fields(global_id, request_id, session_id))]
 async fn drainer(
     store: Arc<Store>,
+    stream_index: u8,
     max_read_count: u64,
+    max_query_attempts: u32,
     stream_name: &str,
     jobs_picked: Arc<atomic::AtomicU8>,
 ) -> errors::DrainerResult<()> {
@@ -218,6 +225,10 @@ async fn drainer(
                     redis_err.current_context()
                 {
                     metrics::STREAM_EMPTY.add(1, &[]);
+                    metrics::STREAM_DRAIN_LAG_SECONDS.record(
+                        0,
+                        router_env::metric_attributes!(("stream", stream_name.to_owned())),
+                    );
                     return Ok(());
                 } else {
                     return Err(error);
@@ -239,13 +250,22 @@ async fn drainer(
         u64::try_from(read_count).unwrap_or(u64::MIN),
         router_env::metric_attributes!(("stream", stream_name.to_owned())),
     );
+    if let Some(drain_lag) = entries
+        .first()
+        .and_then(|(entry_id, _)| utils::get_entry_age_in_secs(entry_id))
+    {
+        metrics::STREAM_DRAIN_LAG_SECONDS.record(
+            drain_lag,
+            router_env::metric_attributes!(("stream", stream_name.to_owned())),
+        );
+    }
 
     let session_id = common_utils::generate_id_with_default_len("drainer_session");
 
     let mut last_processed_id = String::new();
 
-    for (entry_id, entry) in entries.clone() {
-        let data = match StreamData::from_hashmap(entry) {
+    for (entry_id, entry) in entries {
+        let data = match StreamData::from_hashmap(entry.clone()) {
             Ok(data) => data,
             Err(err) => {
                 logger::error!(operation = "deserialization", err=?err);
@@ -254,8 +274,19 @@ async fn drainer(
                     router_env::metric_attributes!(("operation", "deserialization")),
                 );
 
-                // break from the loop in case of a deser error
-                break;
+                // A malformed entry can never be drained, so it is quarantined right away instead
+                // of stalling the stream
+                store
+                    .quarantine_entry(stream_index, entry_id, entry, &format!("{err:?}"))
+                    .await?;
+                last_processed_id.clone_from(entry_id);
+
+                if store.use_legacy_version() {
+                    store
+                        .delete_from_stream(stream_name, &last_processed_id)
+                        .await?;
+                }
+                continue;
             }
         };
 
@@ -265,16 +296,31 @@ async fn drainer(
 
         match data.typed_sql.execute_query(&store, data.pushed_at).await {
             Ok(_) => {
-                last_processed_id = entry_id;
+                last_processed_id.clone_from(entry_id);
             }
-            Err(err) => match err.current_context() {
+            Err(err) => match query::get_failed_query_action(&err) {
                 // In case of Uniqueviolation we can't really do anything to fix it so just clear
                 // it from the stream
-                diesel_models::errors::DatabaseError::UniqueViolation => {
-                    last_processed_id = entry_id;
+                query::FailedQueryAction::Skip => {
+                    last_processed_id.clone_from(entry_id);
+                }
+                // The stream is not drained any further until the database is reachable again,
+                // so that the later writes to the same rows are not applied before this one
+                query::FailedQueryAction::Retry => break,
+                // The entry is retried in the next session, until it has failed as many times as
+                // allowed, after which it is quarantined so that the entries after it are drained
+                query::FailedQueryAction::RetryOrQuarantine => {
+                    let attempts = store
+                        .record_failed_query_attempt(stream_index, entry_id)
+                        .await?;
+                    if !query::should_quarantine_entry(attempts, max_query_attempts) {
+                        break;
+                    }
+                    store
+                        .quarantine_entry(stream_index, entry_id, entry, &format!("{err:?}"))
+                        .await?;
+                    last_processed_id.clone_from(entry_id);
                 }
-                // break from the loop in case of an error in query
-                _ => break,
             },
         }
 
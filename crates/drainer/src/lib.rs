@@ -8,6 +8,7 @@ mod query;
 pub mod services;
 pub mod settings;
 mod stream;
+mod streams;
 mod types;
 mod utils;
 use std::{collections::HashMap, sync::Arc};
@@ -26,9 +27,7 @@ use tokio::sync::mpsc;
 
 pub(crate) type Settings = settings::Settings<RawSecret>;
 
-use crate::{
-    connection::pg_connection, services::Store, settings::DrainerSettings, types::StreamData,
-};
+use crate::{services::Store, settings::DrainerSettings, types::StreamData};
 
 pub async fn start_drainer(
     stores: HashMap<id_type::TenantId, Arc<Store>>,
@@ -66,7 +65,9 @@ pub async fn start_web_server(
 ) -> Result<Server, errors::DrainerError> {
     let server = conf.server.clone();
     let web_server = actix_web::HttpServer::new(move || {
-        actix_web::App::new().service(health_check::Health::server(conf.clone(), stores.clone()))
+        actix_web::App::new()
+            .service(health_check::Health::server(conf.clone(), stores.clone()))
+            .service(streams::Streams::server(conf.clone(), stores.clone()))
     })
     .bind((server.host.as_str(), server.port))?
     .run();
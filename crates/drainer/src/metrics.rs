@@ -1,4 +1,6 @@
-use router_env::{counter_metric, global_meter, histogram_metric_f64, histogram_metric_u64};
+use router_env::{
+    counter_metric, gauge_metric, global_meter, histogram_metric_f64, histogram_metric_u64,
+};
 
 global_meter!(DRAINER_METER, "DRAINER");
 
@@ -12,6 +14,10 @@ counter_metric!(SUCCESSFUL_SHUTDOWN, DRAINER_METER);
 counter_metric!(STREAM_EMPTY, DRAINER_METER);
 counter_metric!(STREAM_PARSE_FAIL, DRAINER_METER);
 counter_metric!(DRAINER_HEALTH, DRAINER_METER);
+counter_metric!(ENTRIES_QUARANTINED, DRAINER_METER); // Entries moved to the dead letter stream
+counter_metric!(ENTRIES_REPLAYED, DRAINER_METER); // Entries moved back from the dead letter stream
+
+gauge_metric!(STREAM_DRAIN_LAG_SECONDS, DRAINER_METER); // Age of the oldest entry of the stream
 
 histogram_metric_f64!(QUERY_EXECUTION_TIME, DRAINER_METER); // Time in (ms) milliseconds
 histogram_metric_f64!(REDIS_STREAM_READ_TIME, DRAINER_METER); // Time in (ms) milliseconds
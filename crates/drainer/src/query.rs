@@ -2,8 +2,9 @@ use std::sync::Arc;
 
 use common_utils::errors::CustomResult;
 use diesel_models::errors::DatabaseError;
+use error_stack::{Report, ResultExt};
 
-use crate::{kv, logger, metrics, pg_connection, services::Store};
+use crate::{kv, logger, metrics, services::Store};
 
 #[async_trait::async_trait]
 pub trait ExecuteQuery {
@@ -21,7 +22,12 @@ impl ExecuteQuery for kv::DBOperation {
         store: &Arc<Store>,
         pushed_at: i64,
     ) -> CustomResult<(), DatabaseError> {
-        let conn = pg_connection(&store.master_pool).await;
+        let conn = store
+            .master_pool
+            .get()
+            .await
+            .change_context(DatabaseError::DatabaseConnectionError)
+            .attach_printable("Couldn't retrieve PostgreSQL connection")?;
         let operation = self.operation();
         let table = self.table();
 
@@ -48,6 +54,72 @@ impl ExecuteQuery for kv::DBOperation {
     }
 }
 
+/// Action taken on an entry of the stream whose query failed
+#[derive(Debug, PartialEq, Eq)]
+pub enum FailedQueryAction {
+    /// The entry is cleared from the stream, as in case of a unique violation there is nothing
+    /// left to apply
+    Skip,
+    /// The entry is retried in the next session without counting the failure, since the query
+    /// failed for reasons unrelated to the entry, such as the database being unreachable. Such
+    /// entries are never quarantined, as replaying them later could apply stale writes over the
+    /// newer writes drained in the meantime.
+    Retry,
+    /// The failure is counted, and the entry is retried in the next session until it has failed
+    /// as many times as allowed, after which it is quarantined
+    RetryOrQuarantine,
+}
+
+/// Decides what is to be done with an entry whose query failed, based on whether the query
+/// could ever succeed
+pub fn get_failed_query_action(error: &Report<DatabaseError>) -> FailedQueryAction {
+    match error.current_context() {
+        DatabaseError::UniqueViolation => FailedQueryAction::Skip,
+        DatabaseError::DatabaseConnectionError => FailedQueryAction::Retry,
+        DatabaseError::NotFound
+        | DatabaseError::NoFieldsToUpdate
+        | DatabaseError::QueryGenerationFailed => FailedQueryAction::RetryOrQuarantine,
+        DatabaseError::Others => {
+            let diesel_error = match error.downcast_ref::<async_bb8_diesel::ConnectionError>() {
+                Some(async_bb8_diesel::ConnectionError::Connection(_)) => {
+                    return FailedQueryAction::Retry
+                }
+                Some(async_bb8_diesel::ConnectionError::Query(diesel_error)) => Some(diesel_error),
+                None => error.downcast_ref::<diesel::result::Error>(),
+            };
+
+            match diesel_error {
+                Some(diesel::result::Error::DatabaseError(kind, _))
+                    if matches!(
+                        kind,
+                        diesel::result::DatabaseErrorKind::UniqueViolation
+                            | diesel::result::DatabaseErrorKind::ForeignKeyViolation
+                            | diesel::result::DatabaseErrorKind::NotNullViolation
+                            | diesel::result::DatabaseErrorKind::CheckViolation
+                    ) =>
+                {
+                    FailedQueryAction::RetryOrQuarantine
+                }
+                Some(
+                    diesel::result::Error::NotFound
+                    | diesel::result::Error::QueryBuilderError(_)
+                    | diesel::result::Error::DeserializationError(_)
+                    | diesel::result::Error::SerializationError(_)
+                    | diesel::result::Error::InvalidCString(_),
+                ) => FailedQueryAction::RetryOrQuarantine,
+                // Connection failures, statement timeouts and any other error which might
+                // resolve on its own
+                _ => FailedQueryAction::Retry,
+            }
+        }
+    }
+}
+
+/// Whether an entry whose query has failed the given number of times is to be quarantined
+pub fn should_quarantine_entry(failed_attempts: u32, max_query_attempts: u32) -> bool {
+    failed_attempts >= max_query_attempts
+}
+
 #[inline(always)]
 fn push_drainer_delay(
     pushed_at: i64,
@@ -71,3 +143,105 @@ fn push_drainer_delay(
         ),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use diesel::result::{DatabaseErrorKind, Error as DieselError};
+    use error_stack::report;
+
+    use super::*;
+
+    fn get_diesel_error(kind: DatabaseErrorKind, message: &str) -> Report<DatabaseError> {
+        report!(DieselError::DatabaseError(
+            kind,
+            Box::new(message.to_string())
+        ))
+        .change_context(DatabaseError::Others)
+    }
+
+    #[test]
+    fn test_get_failed_query_action() {
+        assert_eq!(
+            get_failed_query_action(&report!(DatabaseError::UniqueViolation)),
+            FailedQueryAction::Skip
+        );
+        assert_eq!(
+            get_failed_query_action(&report!(DatabaseError::DatabaseConnectionError)),
+            FailedQueryAction::Retry
+        );
+        assert_eq!(
+            get_failed_query_action(&get_diesel_error(
+                DatabaseErrorKind::ClosedConnection,
+                "server closed the connection unexpectedly"
+            )),
+            FailedQueryAction::Retry
+        );
+        assert_eq!(
+            get_failed_query_action(&get_diesel_error(
+                DatabaseErrorKind::Unknown,
+                "canceling statement due to statement timeout"
+            )),
+            FailedQueryAction::Retry
+        );
+        assert_eq!(
+            get_failed_query_action(
+                &report!(async_bb8_diesel::ConnectionError::Query(
+                    DieselError::DatabaseError(
+                        DatabaseErrorKind::SerializationFailure,
+                        Box::new("could not serialize access".to_string()),
+                    )
+                ))
+                .change_context(DatabaseError::Others)
+            ),
+            FailedQueryAction::Retry
+        );
+        assert_eq!(
+            get_failed_query_action(&get_diesel_error(
+                DatabaseErrorKind::ForeignKeyViolation,
+                "violates foreign key constraint"
+            )),
+            FailedQueryAction::RetryOrQuarantine
+        );
+        assert_eq!(
+            get_failed_query_action(
+                &report!(DieselError::DeserializationError(
+                    "invalid enum value".into()
+                ))
+                .change_context(DatabaseError::Others)
+            ),
+            FailedQueryAction::RetryOrQuarantine
+        );
+        assert_eq!(
+            get_failed_query_action(&report!(DatabaseError::QueryGenerationFailed)),
+            FailedQueryAction::RetryOrQuarantine
+        );
+    }
+
+    #[test]
+    fn test_entries_are_retried_before_quarantine() {
+        let max_query_attempts = 3;
+        let error = get_diesel_error(DatabaseErrorKind::CheckViolation, "violates check");
+        let connection_error = report!(DatabaseError::DatabaseConnectionError);
+
+        let mut failed_attempts = 0;
+        let mut quarantined_after_sessions = None;
+        for session in 1..=10 {
+            // Connection failures in between are never counted towards the attempts
+            if get_failed_query_action(&connection_error) == FailedQueryAction::RetryOrQuarantine {
+                failed_attempts += 1;
+            }
+
+            assert_eq!(
+                get_failed_query_action(&error),
+                FailedQueryAction::RetryOrQuarantine
+            );
+            failed_attempts += 1;
+            if should_quarantine_entry(failed_attempts, max_query_attempts) {
+                quarantined_after_sessions = Some(session);
+                break;
+            }
+        }
+
+        assert_eq!(quarantined_after_sessions, Some(max_query_attempts));
+    }
+}
@@ -66,7 +66,7 @@ where
         "message": error.to_string()
     })
     .to_string();
-    HttpResponse::InternalServerError()
+    HttpResponse::build(error.current_context().status_code())
         .content_type(mime::APPLICATION_JSON)
         .body(body)
 }
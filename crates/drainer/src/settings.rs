@@ -68,6 +68,7 @@ impl AppState {
 pub struct Settings<S: SecretState> {
     pub server: Server,
     pub master_database: SecretStateContainer<Database, S>,
+    pub secrets: SecretStateContainer<Secrets, S>,
     pub redis: redis::RedisSettings,
     pub log: Log,
     pub drainer: DrainerSettings,
@@ -106,6 +107,13 @@ impl DbConnectionParams for Database {
     }
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct Secrets {
+    /// Key authenticating the admin APIs of the drainer, which are disabled if it is empty
+    pub admin_api_key: Secret<String>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
 pub struct DrainerSettings {
@@ -114,6 +122,9 @@ pub struct DrainerSettings {
     pub max_read_count: u64,
     pub shutdown_interval: u32, // in milliseconds
     pub loop_interval: u32,     // in milliseconds
+    /// Number of times the query of an entry is attempted before the entry is moved to the dead
+    /// letter stream of its shard
+    pub max_query_attempts: u32,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -224,6 +235,7 @@ impl Default for DrainerSettings {
             max_read_count: 100,
             shutdown_interval: 1000, // in milliseconds
             loop_interval: 100,      // in milliseconds
+            max_query_attempts: 5,
         }
     }
 }
@@ -270,10 +282,18 @@ impl Database {
 
 impl DrainerSettings {
     fn validate(&self) -> Result<(), errors::DrainerError> {
-        common_utils::fp_utils::when(self.stream_name.is_default_or_empty(), || {
+        use common_utils::fp_utils::when;
+
+        when(self.stream_name.is_default_or_empty(), || {
             Err(errors::DrainerError::ConfigParsingError(
                 "drainer stream name must not be empty".into(),
             ))
+        })?;
+
+        when(self.max_query_attempts == 0, || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer max query attempts must be greater than 0".into(),
+            ))
         })
     }
 }
@@ -307,7 +327,8 @@ impl Settings<SecuredSecret> {
                     .try_parsing(true)
                     .separator("__")
                     .list_separator(",")
-                    .with_list_parse_key("redis.cluster_urls"),
+                    .with_list_parse_key("redis.cluster_urls")
+                    .with_list_parse_key("redis.sentinel_urls"),
             )
             .build()?;
 
@@ -8,11 +8,24 @@ use crate::{errors, metrics, Store};
 pub type StreamEntries = Vec<(String, HashMap<String, String>)>;
 pub type StreamReadResult = HashMap<String, StreamEntries>;
 
+/// Field added to the entries in the dead letter stream, holding the ID of the entry in the
+/// drainer stream
+pub const DEAD_LETTER_SOURCE_ID_FIELD: &str = "dead_letter_source_id";
+/// Field added to the entries in the dead letter stream, holding the reason they were quarantined
+pub const DEAD_LETTER_REASON_FIELD: &str = "dead_letter_reason";
+
+/// The failed attempts of entries which are drained eventually are left to expire
+const QUERY_ATTEMPTS_TTL_IN_SECS: i64 = 24 * 60 * 60;
+
 impl Store {
     #[inline(always)]
     pub fn drainer_stream(&self, shard_key: &str) -> String {
         // Example: {shard_5}_drainer_stream
-        format!("{{{}}}_{}", shard_key, self.config.drainer_stream_name,)
+        format!(
+            "{}_{}",
+            redis::hash_tag(shard_key),
+            self.config.drainer_stream_name
+        )
     }
 
     #[inline(always)]
@@ -25,6 +38,20 @@ impl Store {
         self.drainer_stream(format!("shard_{stream_index}").as_str())
     }
 
+    /// The dead letter stream shares the hash tag of the drainer stream of the shard
+    #[inline(always)]
+    pub(crate) fn get_dead_letter_stream_name(&self, stream_index: u8) -> String {
+        format!("{}_dead_letter", self.get_drainer_stream_name(stream_index))
+    }
+
+    #[inline(always)]
+    pub(crate) fn get_query_attempts_key(&self, stream_index: u8) -> String {
+        format!(
+            "{}_query_attempts",
+            self.get_drainer_stream_name(stream_index)
+        )
+    }
+
     #[router_env::instrument(skip_all)]
     pub async fn is_stream_available(&self, stream_index: u8) -> bool {
         let stream_key_flag = self.get_stream_key_flag(stream_index);
@@ -133,4 +160,141 @@ impl Store {
 
         Ok(())
     }
+
+    /// Records a failed attempt at executing the query of the entry, and returns the number of
+    /// attempts made so far
+    pub async fn record_failed_query_attempt(
+        &self,
+        stream_index: u8,
+        entry_id: &str,
+    ) -> errors::DrainerResult<u32> {
+        let query_attempts_key = self.get_query_attempts_key(stream_index);
+        let attempts = self
+            .redis_conn
+            .increment_fields_in_hash(&query_attempts_key.as_str().into(), &[(entry_id, 1)])
+            .await
+            .map_err(errors::DrainerError::from)?;
+        self.redis_conn
+            .set_expiry(
+                &query_attempts_key.as_str().into(),
+                QUERY_ATTEMPTS_TTL_IN_SECS,
+            )
+            .await
+            .map_err(errors::DrainerError::from)?;
+
+        Ok(attempts
+            .first()
+            .and_then(|attempts| u32::try_from(*attempts).ok())
+            .unwrap_or(u32::MAX))
+    }
+
+    /// Copies the entry to the dead letter stream of the shard, along with the reason it could
+    /// not be drained. The entry is not removed from the drainer stream.
+    pub async fn quarantine_entry(
+        &self,
+        stream_index: u8,
+        entry_id: &str,
+        entry: &HashMap<String, String>,
+        reason: &str,
+    ) -> errors::DrainerResult<()> {
+        let dead_letter_stream_name = self.get_dead_letter_stream_name(stream_index);
+        let fields = entry
+            .iter()
+            .map(|(field, value)| (field.clone(), value.clone()))
+            .chain([
+                (DEAD_LETTER_SOURCE_ID_FIELD.to_owned(), entry_id.to_owned()),
+                (DEAD_LETTER_REASON_FIELD.to_owned(), reason.to_owned()),
+            ])
+            .collect::<Vec<_>>();
+
+        self.redis_conn
+            .stream_append_entry(
+                &dead_letter_stream_name.as_str().into(),
+                &redis::RedisEntryId::AutoGeneratedID,
+                fields,
+            )
+            .await
+            .map_err(errors::DrainerError::from)?;
+
+        logger::error!(
+            stream_index,
+            entry_id,
+            reason,
+            "Moved the entry to the dead letter stream"
+        );
+        metrics::ENTRIES_QUARANTINED.add(
+            1,
+            router_env::metric_attributes!(("stream", self.get_drainer_stream_name(stream_index))),
+        );
+
+        Ok(())
+    }
+
+    /// Moves the entries back from the dead letter stream of the shard to the end of the drainer
+    /// stream, so that they are drained again
+    pub async fn replay_dead_letter_entries(
+        &self,
+        stream_index: u8,
+        entries: StreamEntries,
+    ) -> errors::DrainerResult<usize> {
+        let stream_name = self.get_drainer_stream_name(stream_index);
+        let dead_letter_stream_name = self.get_dead_letter_stream_name(stream_index);
+
+        for (dead_letter_entry_id, entry) in &entries {
+            let fields = entry
+                .iter()
+                .filter(|(field, _)| {
+                    ![DEAD_LETTER_SOURCE_ID_FIELD, DEAD_LETTER_REASON_FIELD]
+                        .contains(&field.as_str())
+                })
+                .map(|(field, value)| (field.clone(), value.clone()))
+                .collect::<Vec<_>>();
+
+            self.redis_conn
+                .stream_append_entry(
+                    &stream_name.as_str().into(),
+                    &redis::RedisEntryId::AutoGeneratedID,
+                    fields,
+                )
+                .await
+                .map_err(errors::DrainerError::from)?;
+            self.redis_conn
+                .stream_delete_entries(
+                    &dead_letter_stream_name.as_str().into(),
+                    dead_letter_entry_id.as_str(),
+                )
+                .await
+                .map_err(errors::DrainerError::from)?;
+        }
+
+        metrics::ENTRIES_REPLAYED.add(
+            u64::try_from(entries.len()).unwrap_or(u64::MAX),
+            router_env::metric_attributes!(("stream", stream_name)),
+        );
+
+        Ok(entries.len())
+    }
+
+    /// Returns the entries of the stream with IDs between `start` and `end`, both inclusive
+    pub async fn read_stream_range(
+        &self,
+        stream_name: &str,
+        start: &str,
+        end: &str,
+        count: Option<u64>,
+    ) -> errors::DrainerResult<StreamEntries> {
+        Ok(self
+            .redis_conn
+            .stream_read_range(&stream_name.into(), start, end, count)
+            .await
+            .map_err(errors::DrainerError::from)?)
+    }
+
+    pub async fn get_stream_length(&self, stream_name: &str) -> errors::DrainerResult<usize> {
+        Ok(self
+            .redis_conn
+            .stream_get_length(&stream_name.into())
+            .await
+            .map_err(errors::DrainerError::from)?)
+    }
 }
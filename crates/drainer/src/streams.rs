@@ -0,0 +1,352 @@
+use std::{collections::HashMap, sync::Arc};
+
+use actix_web::{web, HttpRequest, HttpResponse, Scope};
+use common_utils::id_type;
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    errors::DrainerApiError,
+    services::{self, log_and_return_error_response, Store},
+    stream::{DEAD_LETTER_REASON_FIELD, DEAD_LETTER_SOURCE_ID_FIELD},
+    utils, Settings,
+};
+
+type ApiResult<T> = error_stack::Result<T, DrainerApiError>;
+
+/// Admin APIs to inspect the drainer streams, and to move the entries which cannot be drained to
+/// the dead letter streams and back
+pub struct Streams;
+
+impl Streams {
+    pub fn server(conf: Settings, stores: HashMap<id_type::TenantId, Arc<Store>>) -> Scope {
+        web::scope("streams")
+            .app_data(web::Data::new(conf))
+            .app_data(web::Data::new(stores))
+            .service(web::resource("/{tenant_id}").route(web::get().to(retrieve_backlog)))
+            .service(
+                web::resource("/{tenant_id}/{shard}/dead_letter")
+                    .route(web::get().to(list_dead_letter_entries)),
+            )
+            .service(
+                web::resource("/{tenant_id}/{shard}/dead_letter/replay")
+                    .route(web::post().to(replay_dead_letter_entries)),
+            )
+            .service(
+                web::resource("/{tenant_id}/{shard}/quarantine")
+                    .route(web::post().to(quarantine_entry)),
+            )
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct BacklogResponse {
+    /// The shards which have entries pending to be drained or in their dead letter stream
+    pub shards: Vec<ShardBacklog>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ShardBacklog {
+    pub shard: u8,
+    pub pending_entries: usize,
+    pub oldest_entry_id: Option<String>,
+    /// Seconds since the oldest entry pending to be drained was added to the stream
+    pub drain_lag_in_secs: Option<u64>,
+    pub dead_letter_entries: usize,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeadLetterListQuery {
+    pub count: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DeadLetterEntry {
+    pub entry_id: String,
+    /// ID of the entry in the drainer stream, before it was quarantined
+    pub source_entry_id: Option<String>,
+    pub reason: Option<String>,
+    pub fields: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReplayRequest {
+    /// The entries of the dead letter stream to replay. The oldest entries are replayed if not
+    /// specified, up to the maximum read count of the drainer.
+    pub entry_ids: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReplayResponse {
+    pub replayed_entries: usize,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct QuarantineRequest {
+    pub entry_id: String,
+    pub reason: Option<String>,
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_backlog(
+    req: HttpRequest,
+    conf: web::Data<Settings>,
+    stores: web::Data<HashMap<id_type::TenantId, Arc<Store>>>,
+    path: web::Path<id_type::TenantId>,
+) -> HttpResponse {
+    let result: ApiResult<_> = async {
+        authenticate(&req, &conf)?;
+        let store = get_store(&stores, &path)?;
+
+        let mut shards = Vec::new();
+        for shard in 0..conf.drainer.num_partitions {
+            let stream_name = store.get_drainer_stream_name(shard);
+            let pending_entries = store
+                .get_stream_length(&stream_name)
+                .await
+                .change_context(DrainerApiError::StreamAccessError)?;
+            let dead_letter_entries = store
+                .get_stream_length(&store.get_dead_letter_stream_name(shard))
+                .await
+                .change_context(DrainerApiError::StreamAccessError)?;
+            if pending_entries == 0 && dead_letter_entries == 0 {
+                continue;
+            }
+
+            let oldest_entry_id = store
+                .read_stream_range(&stream_name, "-", "+", Some(1))
+                .await
+                .change_context(DrainerApiError::StreamAccessError)?
+                .into_iter()
+                .next()
+                .map(|(entry_id, _)| entry_id);
+            shards.push(ShardBacklog {
+                shard,
+                pending_entries,
+                drain_lag_in_secs: oldest_entry_id
+                    .as_deref()
+                    .and_then(utils::get_entry_age_in_secs),
+                oldest_entry_id,
+                dead_letter_entries,
+            });
+        }
+
+        Ok(BacklogResponse { shards })
+    }
+    .await;
+
+    to_http_response(result)
+}
+
+#[instrument(skip_all)]
+pub async fn list_dead_letter_entries(
+    req: HttpRequest,
+    conf: web::Data<Settings>,
+    stores: web::Data<HashMap<id_type::TenantId, Arc<Store>>>,
+    path: web::Path<(id_type::TenantId, u8)>,
+    query: web::Query<DeadLetterListQuery>,
+) -> HttpResponse {
+    let (tenant_id, shard) = path.into_inner();
+    let result: ApiResult<_> = async {
+        authenticate(&req, &conf)?;
+        let store = get_store(&stores, &tenant_id)?;
+        validate_shard(&conf, shard)?;
+
+        let entries = store
+            .read_stream_range(
+                &store.get_dead_letter_stream_name(shard),
+                "-",
+                "+",
+                Some(query.count.unwrap_or(conf.drainer.max_read_count)),
+            )
+            .await
+            .change_context(DrainerApiError::StreamAccessError)?;
+
+        Ok(entries
+            .into_iter()
+            .map(|(entry_id, mut fields)| DeadLetterEntry {
+                entry_id,
+                source_entry_id: fields.remove(DEAD_LETTER_SOURCE_ID_FIELD),
+                reason: fields.remove(DEAD_LETTER_REASON_FIELD),
+                fields,
+            })
+            .collect::<Vec<_>>())
+    }
+    .await;
+
+    to_http_response(result)
+}
+
+/// Moves the entries from the dead letter stream to the end of the drainer stream of the shard.
+/// The entries are drained after the entries already in the stream.
+#[instrument(skip_all)]
+pub async fn replay_dead_letter_entries(
+    req: HttpRequest,
+    conf: web::Data<Settings>,
+    stores: web::Data<HashMap<id_type::TenantId, Arc<Store>>>,
+    path: web::Path<(id_type::TenantId, u8)>,
+    json_payload: web::Json<ReplayRequest>,
+) -> HttpResponse {
+    let (tenant_id, shard) = path.into_inner();
+    let result: ApiResult<_> = async {
+        authenticate(&req, &conf)?;
+        let store = get_store(&stores, &tenant_id)?;
+        validate_shard(&conf, shard)?;
+
+        let dead_letter_stream_name = store.get_dead_letter_stream_name(shard);
+        let entries = match json_payload.into_inner().entry_ids {
+            Some(entry_ids) => {
+                let mut entries = Vec::with_capacity(entry_ids.len());
+                for entry_id in entry_ids {
+                    let entry = store
+                        .read_stream_range(&dead_letter_stream_name, &entry_id, &entry_id, Some(1))
+                        .await
+                        .change_context(DrainerApiError::StreamAccessError)?
+                        .into_iter()
+                        .next()
+                        .ok_or(DrainerApiError::EntryNotFound { entry_id })?;
+                    entries.push(entry);
+                }
+                entries
+            }
+            None => store
+                .read_stream_range(
+                    &dead_letter_stream_name,
+                    "-",
+                    "+",
+                    Some(conf.drainer.max_read_count),
+                )
+                .await
+                .change_context(DrainerApiError::StreamAccessError)?,
+        };
+
+        let replayed_entries = store
+            .replay_dead_letter_entries(shard, entries)
+            .await
+            .change_context(DrainerApiError::StreamAccessError)?;
+        logger::info!(shard, replayed_entries, "Replayed dead letter entries");
+
+        Ok(ReplayResponse { replayed_entries })
+    }
+    .await;
+
+    to_http_response(result)
+}
+
+/// Moves an entry which is stalling the drainer stream of the shard to its dead letter stream
+#[instrument(skip_all)]
+pub async fn quarantine_entry(
+    req: HttpRequest,
+    conf: web::Data<Settings>,
+    stores: web::Data<HashMap<id_type::TenantId, Arc<Store>>>,
+    path: web::Path<(id_type::TenantId, u8)>,
+    json_payload: web::Json<QuarantineRequest>,
+) -> HttpResponse {
+    let (tenant_id, shard) = path.into_inner();
+    let request = json_payload.into_inner();
+    let result: ApiResult<_> = async {
+        authenticate(&req, &conf)?;
+        let store = get_store(&stores, &tenant_id)?;
+        validate_shard(&conf, shard)?;
+
+        // The stream is locked like the drainer does, so that the entry is not drained meanwhile
+        if !store.is_stream_available(shard).await {
+            return Err(report!(DrainerApiError::StreamBusy));
+        }
+        let result = quarantine_stream_entry(store, shard, request).await;
+        let stream_key_flag = store.get_stream_key_flag(shard);
+        if let Err(error) = store.make_stream_available(&stream_key_flag).await {
+            logger::error!(?error, "Failed to unlock the stream");
+        }
+
+        result
+    }
+    .await;
+
+    to_http_response(result)
+}
+
+async fn quarantine_stream_entry(
+    store: &Store,
+    shard: u8,
+    request: QuarantineRequest,
+) -> ApiResult<()> {
+    let stream_name = store.get_drainer_stream_name(shard);
+    let (entry_id, entry) = store
+        .read_stream_range(&stream_name, &request.entry_id, &request.entry_id, Some(1))
+        .await
+        .change_context(DrainerApiError::StreamAccessError)?
+        .into_iter()
+        .next()
+        .ok_or(DrainerApiError::EntryNotFound {
+            entry_id: request.entry_id,
+        })?;
+
+    store
+        .quarantine_entry(
+            shard,
+            &entry_id,
+            &entry,
+            request
+                .reason
+                .as_deref()
+                .unwrap_or("Quarantined through the admin API"),
+        )
+        .await
+        .change_context(DrainerApiError::StreamAccessError)?;
+    store
+        .delete_from_stream(&stream_name, &entry_id)
+        .await
+        .change_context(DrainerApiError::StreamAccessError)
+}
+
+fn authenticate(req: &HttpRequest, conf: &Settings) -> ApiResult<()> {
+    let admin_api_key = conf.secrets.get_inner().admin_api_key.peek();
+    let request_api_key = req
+        .headers()
+        .get("api-key")
+        .and_then(|header| header.to_str().ok());
+
+    // The admin APIs are disabled unless an admin API key is configured
+    if admin_api_key.is_empty() || request_api_key != Some(admin_api_key.as_str()) {
+        return Err(report!(DrainerApiError::Unauthorized));
+    }
+
+    Ok(())
+}
+
+fn get_store<'a>(
+    stores: &'a HashMap<id_type::TenantId, Arc<Store>>,
+    tenant_id: &id_type::TenantId,
+) -> ApiResult<&'a Store> {
+    stores.get(tenant_id).map(Arc::as_ref).ok_or_else(|| {
+        report!(DrainerApiError::TenantNotFound {
+            tenant_id: tenant_id.get_string_repr().to_owned(),
+        })
+    })
+}
+
+fn validate_shard(conf: &Settings, shard: u8) -> ApiResult<()> {
+    let num_partitions = conf.drainer.num_partitions;
+    common_utils::fp_utils::when(shard >= num_partitions, || {
+        Err(report!(DrainerApiError::ShardNotFound {
+            shard,
+            num_partitions,
+        }))
+    })
+}
+
+fn to_http_response<T: Serialize>(result: ApiResult<T>) -> HttpResponse {
+    match result {
+        Ok(response) => services::http_response_json(
+            serde_json::to_string(&response)
+                .map_err(|err| {
+                    logger::error!(serialization_error=?err);
+                })
+                .unwrap_or_default(),
+        ),
+        Err(error) => log_and_return_error_response(error),
+    }
+}
@@ -72,3 +72,12 @@ pub async fn increment_stream_index(
         index + 1
     }
 }
+
+/// Returns the number of seconds since the entry was added to the stream, from the timestamp in
+/// milliseconds which prefixes its ID
+pub fn get_entry_age_in_secs(entry_id: &str) -> Option<u64> {
+    let added_at = entry_id.split_once('-')?.0.parse::<i64>().ok()? / 1000;
+    let age = common_utils::date_time::now_unix_timestamp() - added_at;
+
+    u64::try_from(age).ok()
+}
@@ -45,6 +45,8 @@ fn get_program_data() -> (ast::Program<DummyOutput>, inputs::BackendInput) {
             capture_method: Some(enums::CaptureMethod::Automatic),
             business_country: Some(enums::Country::UnitedStatesOfAmerica),
             billing_country: Some(enums::Country::France),
+            ip_country: None,
+            normalized_amount: None,
             business_label: None,
             setup_future_usage: None,
         },
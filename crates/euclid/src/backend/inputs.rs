@@ -3,7 +3,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     enums,
-    frontend::dir::enums::{CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType},
+    frontend::dir::enums::{
+        BillingIpCountryMatch, CustomerDeviceDisplaySize, CustomerDevicePlatform,
+        CustomerDeviceType, ScaApplicability,
+    },
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -29,10 +32,30 @@ pub struct PaymentInput {
     pub capture_method: Option<enums::CaptureMethod>,
     pub business_country: Option<enums::Country>,
     pub billing_country: Option<enums::Country>,
+    /// Country of the IP address of the customer
+    pub ip_country: Option<enums::Country>,
+    /// Amount of the payment converted to the routing reference currency
+    pub normalized_amount: Option<common_utils::types::MinorUnit>,
     pub business_label: Option<String>,
     pub setup_future_usage: Option<enums::SetupFutureUsage>,
 }
 
+impl PaymentInput {
+    /// Whether the billing country of the payment matches the country of the customer's IP
+    /// address, available only when both of them are known
+    pub fn get_billing_ip_country_match(&self) -> Option<BillingIpCountryMatch> {
+        self.billing_country
+            .zip(self.ip_country)
+            .map(|(billing_country, ip_country)| {
+                if billing_country == ip_country {
+                    BillingIpCountryMatch::Match
+                } else {
+                    BillingIpCountryMatch::Mismatch
+                }
+            })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AcquirerDataInput {
     pub country: Option<enums::Country>,
@@ -62,3 +85,25 @@ pub struct BackendInput {
     pub issuer_data: Option<IssuerDataInput>,
     pub mandate: MandateData,
 }
+
+impl BackendInput {
+    /// Whether Strong Customer Authentication applies to the payment, available only when the
+    /// countries of both the issuer and the acquirer are known
+    pub fn get_sca_applicability(&self) -> Option<ScaApplicability> {
+        let issuer_country = self.issuer_data.as_ref().and_then(|issuer| issuer.country);
+        let acquirer_country = self
+            .acquirer_data
+            .as_ref()
+            .and_then(|acquirer| acquirer.country);
+
+        issuer_country
+            .zip(acquirer_country)
+            .map(|(issuer_country, acquirer_country)| {
+                if issuer_country.is_psd2_country() && acquirer_country.is_psd2_country() {
+                    ScaApplicability::Applicable
+                } else {
+                    ScaApplicability::NotApplicable
+                }
+            })
+    }
+}
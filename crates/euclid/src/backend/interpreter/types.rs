@@ -70,6 +70,10 @@ impl From<inputs::BackendInput> for Context {
                 EuclidKey::PaymentAmount.to_string(),
                 Some(ValueType::Number(input.payment.amount)),
             ),
+            (
+                EuclidKey::NormalizedAmount.to_string(),
+                input.payment.normalized_amount.map(ValueType::Number),
+            ),
             (
                 EuclidKey::PaymentCurrency.to_string(),
                 Some(ValueType::EnumVariant(input.payment.currency.to_string())),
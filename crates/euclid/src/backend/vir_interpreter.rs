@@ -11,6 +11,7 @@ use crate::{
         dir::{self, EuclidDirFilter},
         vir,
     },
+    types::EuclidKey,
 };
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -76,6 +77,28 @@ where
                 },
             )
     }
+
+    fn statement_references_key(stmt: &vir::ValuedIfStatement, key: &EuclidKey) -> bool {
+        stmt.condition
+            .iter()
+            .flat_map(|comp| comp.values.iter())
+            .any(|value| value.get_key() == *key)
+            || stmt.nested.as_ref().is_some_and(|nested_stmts| {
+                nested_stmts
+                    .iter()
+                    .any(|s| Self::statement_references_key(s, key))
+            })
+    }
+
+    /// Checks whether any rule of the program compares the given key, so that inputs which are
+    /// costly to resolve can be skipped when no rule depends on them
+    pub fn references_key(&self, key: &EuclidKey) -> bool {
+        self.program
+            .rules
+            .iter()
+            .flat_map(|rule| rule.statements.iter())
+            .any(|stmt| Self::statement_references_key(stmt, key))
+    }
 }
 
 impl<O> EuclidBackend<O> for VirInterpreterBackend<O>
@@ -140,6 +163,8 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                ip_country: None,
+                normalized_amount: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -183,6 +208,8 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                ip_country: None,
+                normalized_amount: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -227,6 +254,8 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                ip_country: None,
+                normalized_amount: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -271,6 +300,8 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                ip_country: None,
+                normalized_amount: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -315,6 +346,8 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                ip_country: None,
+                normalized_amount: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -359,6 +392,8 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                ip_country: None,
+                normalized_amount: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -403,6 +438,8 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                ip_country: None,
+                normalized_amount: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -447,6 +484,8 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                ip_country: None,
+                normalized_amount: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -491,6 +530,8 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                ip_country: None,
+                normalized_amount: None,
                 business_label: None,
                 setup_future_usage: Some(enums::SetupFutureUsage::OffSession),
             },
@@ -537,6 +578,8 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                ip_country: None,
+                normalized_amount: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -581,6 +624,8 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                ip_country: None,
+                normalized_amount: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -634,6 +679,8 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                ip_country: None,
+                normalized_amount: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -665,4 +712,205 @@ mod test {
             "rule_1"
         );
     }
+
+    #[test]
+    fn test_billing_ip_country_match() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        rule_1: ["adyen"]
+        {
+           billing_ip_country_match = mismatch
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let inp_mismatch = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(120),
+                card_bin: None,
+                currency: enums::Currency::EUR,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::Germany),
+                billing_country: Some(enums::Country::Germany),
+                ip_country: Some(enums::Country::Brazil),
+                normalized_amount: None,
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::PayLater),
+                payment_method_type: Some(enums::PaymentMethodType::Affirm),
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            acquirer_data: None,
+            customer_device_data: None,
+            issuer_data: None,
+        };
+        let mut inp_match = inp_mismatch.clone();
+        inp_match.payment.ip_country = Some(enums::Country::Germany);
+        let mut inp_unknown = inp_mismatch.clone();
+        inp_unknown.payment.ip_country = None;
+
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        let result_mismatch = backend.execute(inp_mismatch).expect("Execution");
+        let result_match = backend.execute(inp_match).expect("Execution");
+        let result_unknown = backend.execute(inp_unknown).expect("Execution");
+        assert_eq!(
+            result_mismatch.rule_name.expect("Rule Name").as_str(),
+            "rule_1"
+        );
+        assert!(result_match.rule_name.is_none());
+        assert!(result_unknown.rule_name.is_none());
+    }
+
+    #[test]
+    fn test_references_key() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        rule_1: ["adyen"]
+        {
+           amount > 1000 {
+               ip_country = Brazil
+           }
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+
+        assert!(backend.references_key(&EuclidKey::IpCountry));
+        assert!(backend.references_key(&EuclidKey::PaymentAmount));
+        assert!(!backend.references_key(&EuclidKey::BillingIpCountryMatch));
+        assert!(!backend.references_key(&EuclidKey::BillingCountry));
+    }
+
+    #[test]
+    fn test_sca_applicability() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        rule_1: ["adyen"]
+        {
+           sca_applicability = applicable
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let inp_applicable = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(120),
+                card_bin: None,
+                currency: enums::Currency::EUR,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::Germany),
+                billing_country: Some(enums::Country::Germany),
+                ip_country: None,
+                normalized_amount: None,
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::Card),
+                payment_method_type: Some(enums::PaymentMethodType::Credit),
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            acquirer_data: Some(inputs::AcquirerDataInput {
+                country: Some(enums::Country::France),
+                fraud_rate: None,
+            }),
+            customer_device_data: None,
+            issuer_data: Some(inputs::IssuerDataInput {
+                name: None,
+                country: Some(enums::Country::Germany),
+            }),
+        };
+        let mut inp_not_applicable = inp_applicable.clone();
+        inp_not_applicable.issuer_data = Some(inputs::IssuerDataInput {
+            name: None,
+            country: Some(enums::Country::UnitedStatesOfAmerica),
+        });
+        let mut inp_unknown = inp_applicable.clone();
+        inp_unknown.issuer_data = None;
+
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        let result_applicable = backend.execute(inp_applicable).expect("Execution");
+        let result_not_applicable = backend.execute(inp_not_applicable).expect("Execution");
+        let result_unknown = backend.execute(inp_unknown).expect("Execution");
+        assert_eq!(
+            result_applicable.rule_name.expect("Rule Name").as_str(),
+            "rule_1"
+        );
+        assert!(result_not_applicable.rule_name.is_none());
+        assert!(result_unknown.rule_name.is_none());
+    }
+
+    #[test]
+    fn test_normalized_amount() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        rule_1: ["adyen"]
+        {
+           normalized_amount >= 1000
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let inp_above = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(1400),
+                card_bin: None,
+                currency: enums::Currency::EUR,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::Germany),
+                billing_country: Some(enums::Country::Germany),
+                ip_country: None,
+                normalized_amount: Some(MinorUnit::new(1500)),
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::PayLater),
+                payment_method_type: Some(enums::PaymentMethodType::Affirm),
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            acquirer_data: None,
+            customer_device_data: None,
+            issuer_data: None,
+        };
+        let mut inp_below = inp_above.clone();
+        inp_below.payment.normalized_amount = Some(MinorUnit::new(900));
+        let mut inp_unknown = inp_above.clone();
+        inp_unknown.payment.normalized_amount = None;
+
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        let result_above = backend.execute(inp_above).expect("Execution");
+        let result_below = backend.execute(inp_below).expect("Execution");
+        let result_unknown = backend.execute(inp_unknown).expect("Execution");
+        assert_eq!(
+            result_above.rule_name.expect("Rule Name").as_str(),
+            "rule_1"
+        );
+        assert!(result_below.rule_name.is_none());
+        assert!(result_unknown.rule_name.is_none());
+    }
 }
@@ -51,6 +51,7 @@ impl Context {
     }
 
     pub fn from_input(input: BackendInput) -> Self {
+        let sca_applicability = input.get_sca_applicability();
         let payment = input.payment;
         let payment_method = input.payment_method;
         let meta_data = input.metadata;
@@ -95,6 +96,12 @@ impl Context {
         if let Some(country) = payment.billing_country {
             enum_values.insert(EuclidValue::BillingCountry(country));
         }
+        if let Some(country_match) = payment.get_billing_ip_country_match() {
+            enum_values.insert(EuclidValue::BillingIpCountryMatch(country_match));
+        }
+        if let Some(country) = payment.ip_country {
+            enum_values.insert(EuclidValue::IpCountry(country));
+        }
         if let Some(card_bin) = payment.card_bin {
             enum_values.insert(EuclidValue::CardBin(StrValue { value: card_bin }));
         }
@@ -133,6 +140,10 @@ impl Context {
             }
         }
 
+        if let Some(sca_applicability) = sca_applicability {
+            enum_values.insert(EuclidValue::ScaApplicability(sca_applicability));
+        }
+
         // Handle issuer data
         if let Some(issuer) = issuer_data {
             if let Some(name) = issuer.name {
@@ -143,13 +154,22 @@ impl Context {
             }
         }
 
-        let numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
+        let mut numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
             EuclidKey::PaymentAmount,
             EuclidValue::PaymentAmount(types::NumValue {
                 number: payment.amount,
                 refinement: None,
             }),
         )]);
+        if let Some(normalized_amount) = payment.normalized_amount {
+            numeric_values.insert(
+                EuclidKey::NormalizedAmount,
+                EuclidValue::NormalizedAmount(types::NumValue {
+                    number: normalized_amount,
+                    refinement: None,
+                }),
+            );
+        }
 
         Self {
             atomic_values: enum_values,
@@ -238,6 +238,110 @@ pub fn analyze<O: EuclidAnalysable + EuclidDirFilter>(
     dir::lowering::lower_program(dir_program)
 }
 
+/// Flattens the statements of a rule into the conjunctions of comparisons that make it match,
+/// one for every path from a top level statement to a statement without nested statements.
+fn flatten_rule_statements<'a>(
+    statements: &'a [ast::IfStatement],
+    prefix: &mut Vec<&'a ast::Comparison>,
+    paths: &mut Vec<Vec<&'a ast::Comparison>>,
+) {
+    for statement in statements {
+        let prefix_len = prefix.len();
+        prefix.extend(statement.condition.iter());
+        match &statement.nested {
+            Some(nested) => flatten_rule_statements(nested, prefix, paths),
+            None => paths.push(prefix.clone()),
+        }
+        prefix.truncate(prefix_len);
+    }
+}
+
+/// Whenever every comparison of `broader` is also present in `narrower`, any input matching
+/// `narrower` matches `broader` as well.
+fn path_covers(broader: &[&ast::Comparison], narrower: &[&ast::Comparison]) -> bool {
+    broader.iter().all(|broader_cmp| {
+        narrower.iter().any(|narrower_cmp| {
+            broader_cmp.lhs == narrower_cmp.lhs
+                && broader_cmp.comparison == narrower_cmp.comparison
+                && broader_cmp.value == narrower_cmp.value
+        })
+    })
+}
+
+/// Lints every rule of a program, reporting all findings instead of stopping at the first one.
+///
+/// Each rule is analysed on its own for invalid keys, type errors and conflicting conditions.
+/// Since the first matching rule wins, a rule is additionally reported as unreachable when all
+/// of its conditions are subsumed by earlier rules, and as overlapping when only some of them are.
+pub fn lint_program<O>(program: &ast::Program<O>) -> Vec<types::RuleDiagnostic>
+where
+    O: EuclidAnalysable + EuclidDirFilter + Clone,
+{
+    let mut diagnostics = Vec::new();
+    let rule_paths = program
+        .rules
+        .iter()
+        .map(|rule| {
+            let mut paths = Vec::new();
+            flatten_rule_statements(&rule.statements, &mut Vec::new(), &mut paths);
+            paths
+        })
+        .collect::<Vec<_>>();
+
+    for (rule_index, rule) in program.rules.iter().enumerate() {
+        let single_rule_program = ast::Program {
+            default_selection: program.default_selection.clone(),
+            rules: vec![rule.clone()],
+            metadata: program.metadata.clone(),
+        };
+        if let Err(error) = analyze(single_rule_program, None) {
+            diagnostics.push(types::RuleDiagnostic {
+                rule_index,
+                rule_name: rule.name.clone(),
+                diagnostic_type: types::RuleDiagnosticType::AnalysisFailure(error),
+            });
+        }
+
+        let mut covering_rules = Vec::new();
+        let mut covered_paths = 0;
+        for path in &rule_paths[rule_index] {
+            let covering_rule = rule_paths[..rule_index].iter().position(|earlier_paths| {
+                earlier_paths
+                    .iter()
+                    .any(|earlier_path| path_covers(earlier_path, path))
+            });
+            if let Some(covering_rule) = covering_rule {
+                covered_paths += 1;
+                if !covering_rules.contains(&covering_rule) {
+                    covering_rules.push(covering_rule);
+                }
+            }
+        }
+        covering_rules.sort_unstable();
+
+        let diagnostic_type = if covered_paths == 0 {
+            None
+        } else if covered_paths == rule_paths[rule_index].len() {
+            Some(types::RuleDiagnosticType::UnreachableRule {
+                shadowed_by: covering_rules,
+            })
+        } else {
+            Some(types::RuleDiagnosticType::OverlappingRule {
+                overlaps_with: covering_rules,
+            })
+        };
+        if let Some(diagnostic_type) = diagnostic_type {
+            diagnostics.push(types::RuleDiagnostic {
+                rule_index,
+                rule_name: rule.name.clone(),
+                diagnostic_type,
+            });
+        }
+    }
+
+    diagnostics
+}
+
 #[cfg(all(test, feature = "ast_parser"))]
 mod tests {
     #![allow(clippy::panic, clippy::expect_used)]
@@ -456,4 +560,64 @@ mod tests {
             }
         ));
     }
+
+    #[test]
+    fn test_lint_program() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen"]
+            {
+                payment_method = card
+            }
+
+            rule_2: ["checkout"]
+            {
+                payment_method = card & amount > 500
+            }
+
+            rule_3: ["stripe"]
+            {
+                payment_method = card & capture_method = manual
+                payment_method = wallet
+            }
+
+            rule_4: ["adyen"]
+            {
+                payment_method = pay_later & payment_method = bank_redirect
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let diagnostics = lint_program(&program);
+
+        assert_eq!(diagnostics.len(), 3, "Expected three diagnostics");
+        assert!(matches!(
+            diagnostics[0],
+            types::RuleDiagnostic {
+                rule_index: 1,
+                diagnostic_type: types::RuleDiagnosticType::UnreachableRule { ref shadowed_by },
+                ..
+            } if shadowed_by == &[0]
+        ));
+        assert!(matches!(
+            diagnostics[1],
+            types::RuleDiagnostic {
+                rule_index: 2,
+                diagnostic_type: types::RuleDiagnosticType::OverlappingRule { ref overlaps_with },
+                ..
+            } if overlaps_with == &[0]
+        ));
+        assert!(matches!(
+            diagnostics[2],
+            types::RuleDiagnostic {
+                rule_index: 3,
+                diagnostic_type: types::RuleDiagnosticType::AnalysisFailure(types::AnalysisError {
+                    error_type: types::AnalysisErrorType::ConflictingAssertions { .. },
+                    ..
+                }),
+                ..
+            }
+        ));
+    }
 }
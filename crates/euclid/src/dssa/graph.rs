@@ -82,6 +82,10 @@ impl cgraph::NodeViz for dir::DirValue {
             }
             Self::AcquirerCountry(acquirer_country) => acquirer_country.to_string(),
             Self::AcquirerFraudRate(acquirer_fraud_rate) => acquirer_fraud_rate.number.to_string(),
+            Self::IpCountry(ip_country) => ip_country.to_string(),
+            Self::BillingIpCountryMatch(country_match) => country_match.to_string(),
+            Self::NormalizedAmount(normalized_amount) => normalized_amount.number.to_string(),
+            Self::ScaApplicability(sca_applicability) => sca_applicability.to_string(),
         }
     }
 }
@@ -154,6 +154,27 @@ pub enum AnalysisErrorType {
     NotSupported,
 }
 
+/// A finding about a single rule of a program, reported by
+/// [`lint_program`](crate::dssa::analyzer::lint_program)
+#[derive(Debug, Clone, Serialize)]
+pub struct RuleDiagnostic {
+    pub rule_index: usize,
+    pub rule_name: String,
+    #[serde(flatten)]
+    pub diagnostic_type: RuleDiagnosticType,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", content = "info", rename_all = "snake_case")]
+pub enum RuleDiagnosticType {
+    /// The rule failed static analysis, for example due to an invalid key or conflicting conditions
+    AnalysisFailure(AnalysisError),
+    /// Every condition of the rule is also matched by earlier rules, so the rule never applies
+    UnreachableRule { shadowed_by: Vec<usize> },
+    /// Some, but not all, conditions of the rule are also matched by earlier rules
+    OverlappingRule { overlaps_with: Vec<usize> },
+}
+
 #[derive(Debug, Clone)]
 pub enum ValueType {
     EnumVariants(Vec<EuclidValue>),
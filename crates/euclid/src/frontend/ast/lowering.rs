@@ -275,6 +275,10 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
         dir::DirKeyKind::CustomerDeviceDisplaySize => lower_enum!(CustomerDeviceDisplaySize, value),
         dir::DirKeyKind::AcquirerCountry => lower_enum!(AcquirerCountry, value),
         dir::DirKeyKind::AcquirerFraudRate => lower_number!(AcquirerFraudRate, value, comparison),
+        dir::DirKeyKind::IpCountry => lower_enum!(IpCountry, value),
+        dir::DirKeyKind::BillingIpCountryMatch => lower_enum!(BillingIpCountryMatch, value),
+        dir::DirKeyKind::NormalizedAmount => lower_number!(NormalizedAmount, value, comparison),
+        dir::DirKeyKind::ScaApplicability => lower_enum!(ScaApplicability, value),
     }
 }
 
@@ -334,6 +334,34 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "acquirer_fraud_rate")]
     AcquirerFraudRate,
+    #[strum(
+        serialize = "ip_country",
+        detailed_message = "Country of the IP address of the customer",
+        props(Category = "Customer")
+    )]
+    #[serde(rename = "ip_country")]
+    IpCountry,
+    #[strum(
+        serialize = "billing_ip_country_match",
+        detailed_message = "Whether the country of the billing address of the customer matches the country of their IP address",
+        props(Category = "Customer")
+    )]
+    #[serde(rename = "billing_ip_country_match")]
+    BillingIpCountryMatch,
+    #[strum(
+        serialize = "normalized_amount",
+        detailed_message = "Value of the transaction converted to the routing reference currency",
+        props(Category = "Payments")
+    )]
+    #[serde(rename = "normalized_amount")]
+    NormalizedAmount,
+    #[strum(
+        serialize = "sca_applicability",
+        detailed_message = "Whether Strong Customer Authentication applies to the payment, based on the countries of the issuer and the acquirer",
+        props(Category = "3DS Decision")
+    )]
+    #[serde(rename = "sca_applicability")]
+    ScaApplicability,
 }
 
 pub trait EuclidDirFilter: Sized
@@ -391,6 +419,10 @@ impl DirKeyKind {
             Self::CustomerDeviceDisplaySize => types::DataType::EnumVariant,
             Self::AcquirerCountry => types::DataType::EnumVariant,
             Self::AcquirerFraudRate => types::DataType::Number,
+            Self::IpCountry => types::DataType::EnumVariant,
+            Self::BillingIpCountryMatch => types::DataType::EnumVariant,
+            Self::NormalizedAmount => types::DataType::Number,
+            Self::ScaApplicability => types::DataType::EnumVariant,
         }
     }
     pub fn get_value_set(&self) -> Option<Vec<DirValue>> {
@@ -555,6 +587,18 @@ impl DirKeyKind {
                     .collect(),
             ),
             Self::AcquirerFraudRate => None,
+            Self::IpCountry => Some(enums::Country::iter().map(DirValue::IpCountry).collect()),
+            Self::BillingIpCountryMatch => Some(
+                enums::BillingIpCountryMatch::iter()
+                    .map(DirValue::BillingIpCountryMatch)
+                    .collect(),
+            ),
+            Self::NormalizedAmount => None,
+            Self::ScaApplicability => Some(
+                enums::ScaApplicability::iter()
+                    .map(DirValue::ScaApplicability)
+                    .collect(),
+            ),
         }
     }
 }
@@ -640,6 +684,14 @@ pub enum DirValue {
     AcquirerCountry(enums::Country),
     #[serde(rename = "acquirer_fraud_rate")]
     AcquirerFraudRate(types::NumValue),
+    #[serde(rename = "ip_country")]
+    IpCountry(enums::Country),
+    #[serde(rename = "billing_ip_country_match")]
+    BillingIpCountryMatch(enums::BillingIpCountryMatch),
+    #[serde(rename = "normalized_amount")]
+    NormalizedAmount(types::NumValue),
+    #[serde(rename = "sca_applicability")]
+    ScaApplicability(enums::ScaApplicability),
 }
 
 impl DirValue {
@@ -683,6 +735,10 @@ impl DirValue {
             Self::CustomerDeviceDisplaySize(_) => (DirKeyKind::CustomerDeviceDisplaySize, None),
             Self::AcquirerCountry(_) => (DirKeyKind::AcquirerCountry, None),
             Self::AcquirerFraudRate(_) => (DirKeyKind::AcquirerFraudRate, None),
+            Self::IpCountry(_) => (DirKeyKind::IpCountry, None),
+            Self::BillingIpCountryMatch(_) => (DirKeyKind::BillingIpCountryMatch, None),
+            Self::NormalizedAmount(_) => (DirKeyKind::NormalizedAmount, None),
+            Self::ScaApplicability(_) => (DirKeyKind::ScaApplicability, None),
         };
 
         DirKey::new(kind, data)
@@ -727,6 +783,10 @@ impl DirValue {
             Self::CustomerDeviceDisplaySize(_) => None,
             Self::AcquirerCountry(_) => None,
             Self::AcquirerFraudRate(_) => None,
+            Self::IpCountry(_) => None,
+            Self::BillingIpCountryMatch(_) => None,
+            Self::NormalizedAmount(_) => None,
+            Self::ScaApplicability(_) => None,
         }
     }
 
@@ -742,6 +802,7 @@ impl DirValue {
         match self {
             Self::PaymentAmount(val) => Some(val.clone()),
             Self::AcquirerFraudRate(val) => Some(val.clone()),
+            Self::NormalizedAmount(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -782,6 +843,10 @@ impl DirValue {
             (Self::CustomerDeviceDisplaySize(s1), Self::CustomerDeviceDisplaySize(s2)) => s1 == s2,
             (Self::AcquirerCountry(c1), Self::AcquirerCountry(c2)) => c1 == c2,
             (Self::AcquirerFraudRate(r1), Self::AcquirerFraudRate(r2)) => r1 == r2,
+            (Self::IpCountry(c1), Self::IpCountry(c2)) => c1 == c2,
+            (Self::BillingIpCountryMatch(m1), Self::BillingIpCountryMatch(m2)) => m1 == m2,
+            (Self::NormalizedAmount(a1), Self::NormalizedAmount(a2)) => a1 == a2,
+            (Self::ScaApplicability(s1), Self::ScaApplicability(s2)) => s1 == s2,
             _ => false,
         }
     }
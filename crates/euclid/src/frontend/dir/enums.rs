@@ -4,9 +4,9 @@ use utoipa::ToSchema;
 use crate::enums::collect_variants;
 pub use crate::enums::{
     AuthenticationType, CaptureMethod, CardNetwork, Country, Country as BusinessCountry,
-    Country as BillingCountry, Country as IssuerCountry, Country as AcquirerCountry, CountryAlpha2,
-    Currency as PaymentCurrency, MandateAcceptanceType, MandateType, PaymentMethod, PaymentType,
-    RoutableConnectors, SetupFutureUsage,
+    Country as BillingCountry, Country as IssuerCountry, Country as AcquirerCountry,
+    Country as IpCountry, CountryAlpha2, Currency as PaymentCurrency, MandateAcceptanceType,
+    MandateType, PaymentMethod, PaymentType, RoutableConnectors, SetupFutureUsage,
 };
 #[cfg(feature = "payouts")]
 pub use crate::enums::{PayoutBankTransferType, PayoutType, PayoutWalletType};
@@ -488,6 +488,52 @@ pub enum CustomerDeviceDisplaySize {
     Size800x1280, // Common Android tablet
 }
 
+/// Whether the country of the billing address of the customer matches the country of the
+/// customer's IP address
+#[derive(
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    strum::Display,
+    strum::VariantNames,
+    strum::EnumIter,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum BillingIpCountryMatch {
+    Match,
+    Mismatch,
+}
+
+/// Whether Strong Customer Authentication applies to the payment, which is the case when both the
+/// issuer and the acquirer are located in the PSD2 region
+#[derive(
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    strum::Display,
+    strum::VariantNames,
+    strum::EnumIter,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ScaApplicability {
+    Applicable,
+    NotApplicable,
+}
+
 collect_variants!(CardType);
 collect_variants!(PayLaterType);
 collect_variants!(WalletType);
@@ -506,3 +552,5 @@ collect_variants!(MobilePaymentType);
 collect_variants!(CustomerDeviceType);
 collect_variants!(CustomerDevicePlatform);
 collect_variants!(CustomerDeviceDisplaySize);
+collect_variants!(BillingIpCountryMatch);
+collect_variants!(ScaApplicability);
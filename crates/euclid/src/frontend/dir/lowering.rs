@@ -285,6 +285,14 @@ fn lower_value(dir_value: dir::DirValue) -> Result<EuclidValue, AnalysisErrorTyp
         }
         dir::DirValue::AcquirerCountry(country) => EuclidValue::AcquirerCountry(country),
         dir::DirValue::AcquirerFraudRate(num_value) => EuclidValue::AcquirerFraudRate(num_value),
+        dir::DirValue::IpCountry(country) => EuclidValue::IpCountry(country),
+        dir::DirValue::BillingIpCountryMatch(country_match) => {
+            EuclidValue::BillingIpCountryMatch(country_match)
+        }
+        dir::DirValue::NormalizedAmount(num_value) => EuclidValue::NormalizedAmount(num_value),
+        dir::DirValue::ScaApplicability(sca_applicability) => {
+            EuclidValue::ScaApplicability(sca_applicability)
+        }
     })
 }
 
@@ -11,7 +11,10 @@ use crate::{
     frontend::{
         ast,
         dir::{
-            enums::{CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType},
+            enums::{
+                BillingIpCountryMatch, CustomerDeviceDisplaySize, CustomerDevicePlatform,
+                CustomerDeviceType, ScaApplicability,
+            },
             DirKeyKind, DirValue, EuclidDirFilter,
         },
     },
@@ -77,6 +80,14 @@ pub enum EuclidKey {
     CustomerDeviceDisplaySize,
     #[strum(serialize = "customer_device_platform")]
     CustomerDevicePlatform,
+    #[strum(serialize = "ip_country")]
+    IpCountry,
+    #[strum(serialize = "billing_ip_country_match")]
+    BillingIpCountryMatch,
+    #[strum(serialize = "normalized_amount")]
+    NormalizedAmount,
+    #[strum(serialize = "sca_applicability")]
+    ScaApplicability,
 }
 
 impl EuclidDirFilter for DummyOutput {
@@ -163,6 +174,10 @@ impl EuclidKey {
             Self::CustomerDeviceType => DataType::EnumVariant,
             Self::CustomerDeviceDisplaySize => DataType::EnumVariant,
             Self::CustomerDevicePlatform => DataType::EnumVariant,
+            Self::IpCountry => DataType::EnumVariant,
+            Self::BillingIpCountryMatch => DataType::EnumVariant,
+            Self::NormalizedAmount => DataType::Number,
+            Self::ScaApplicability => DataType::EnumVariant,
         }
     }
 }
@@ -285,12 +300,16 @@ pub enum EuclidValue {
     CustomerDeviceType(CustomerDeviceType),
     CustomerDeviceDisplaySize(CustomerDeviceDisplaySize),
     CustomerDevicePlatform(CustomerDevicePlatform),
+    IpCountry(enums::Country),
+    BillingIpCountryMatch(BillingIpCountryMatch),
+    NormalizedAmount(NumValue),
+    ScaApplicability(ScaApplicability),
 }
 
 impl EuclidValue {
     pub fn get_num_value(&self) -> Option<NumValue> {
         match self {
-            Self::PaymentAmount(val) => Some(val.clone()),
+            Self::PaymentAmount(val) | Self::NormalizedAmount(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -320,6 +339,10 @@ impl EuclidValue {
             Self::CustomerDeviceType(_) => EuclidKey::CustomerDeviceType,
             Self::CustomerDeviceDisplaySize(_) => EuclidKey::CustomerDeviceDisplaySize,
             Self::CustomerDevicePlatform(_) => EuclidKey::CustomerDevicePlatform,
+            Self::IpCountry(_) => EuclidKey::IpCountry,
+            Self::BillingIpCountryMatch(_) => EuclidKey::BillingIpCountryMatch,
+            Self::NormalizedAmount(_) => EuclidKey::NormalizedAmount,
+            Self::ScaApplicability(_) => EuclidKey::ScaApplicability,
         }
     }
 }
@@ -33,12 +33,12 @@ use wasm_bindgen::prelude::*;
 
 use crate::utils::JsResultExt;
 type JsResult = Result<JsValue, JsValue>;
-use api_models::payment_methods::CountryCodeWithName;
+use api_models::{payment_methods::CountryCodeWithName, subscription::SubscriptionStatus};
 #[cfg(feature = "payouts")]
 use common_enums::PayoutStatus;
 use common_enums::{
     CountryAlpha2, DisputeStatus, EventClass, EventType, IntentStatus, MandateStatus,
-    MerchantCategoryCode, MerchantCategoryCodeWithName, RefundStatus,
+    MerchantCategoryCode, MerchantCategoryCodeWithName, RefundStatus, RoutingAuditAction,
 };
 use strum::IntoEnumIterator;
 
@@ -331,6 +331,9 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         dir::DirKeyKind::CustomerDeviceDisplaySize => {
             dir_enums::CustomerDeviceDisplaySize::VARIANTS
         }
+        dir::DirKeyKind::IpCountry => dir_enums::Country::VARIANTS,
+        dir::DirKeyKind::BillingIpCountryMatch => dir_enums::BillingIpCountryMatch::VARIANTS,
+        dir::DirKeyKind::ScaApplicability => dir_enums::ScaApplicability::VARIANTS,
 
         dir::DirKeyKind::PaymentAmount
         | dir::DirKeyKind::Connector
@@ -338,7 +341,8 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         | dir::DirKeyKind::BusinessLabel
         | dir::DirKeyKind::MetaData
         | dir::DirKeyKind::IssuerName
-        | dir::DirKeyKind::AcquirerFraudRate => Err("Key does not have variants".to_string())?,
+        | dir::DirKeyKind::AcquirerFraudRate
+        | dir::DirKeyKind::NormalizedAmount => Err("Key does not have variants".to_string())?,
     };
 
     Ok(serde_wasm_bindgen::to_value(variants)?)
@@ -514,5 +518,26 @@ pub fn get_valid_webhook_status(key: &str) -> JsResult {
                 .collect();
             Ok(serde_wasm_bindgen::to_value(&statuses)?)
         }
+        EventClass::Routing => {
+            let statuses: Vec<RoutingAuditAction> = vec![
+                RoutingAuditAction::Link,
+                RoutingAuditAction::Unlink,
+                RoutingAuditAction::Update,
+                RoutingAuditAction::DynamicRoutingUpdate,
+                RoutingAuditAction::VolumeSplitUpdate,
+                RoutingAuditAction::ExperimentUpdate,
+                RoutingAuditAction::LatencyRoutingUpdate,
+                RoutingAuditAction::FeeScheduleUpdate,
+            ];
+            Ok(serde_wasm_bindgen::to_value(&statuses)?)
+        }
+        EventClass::Subscriptions => {
+            let statuses: Vec<SubscriptionStatus> = vec![
+                SubscriptionStatus::Active,
+                SubscriptionStatus::PastDue,
+                SubscriptionStatus::InActive,
+            ];
+            Ok(serde_wasm_bindgen::to_value(&statuses)?)
+        }
     }
 }
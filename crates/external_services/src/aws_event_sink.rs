@@ -0,0 +1,175 @@
+//! Publishing events to AWS SNS topics and AWS SQS queues operated by merchants.
+
+use std::collections::HashMap;
+
+use aws_config::{sts::AssumeRoleProvider, Region, SdkConfig};
+use common_utils::errors::CustomResult;
+use error_stack::{report, ResultExt};
+
+/// Name of the session used when assuming the role of the merchant
+const ROLE_SESSION_NAME: &str = "hyperswitch-event-sink";
+
+/// Suffix of the names of FIFO topics and queues
+const FIFO_SUFFIX: &str = ".fifo";
+
+/// Errors that could occur when publishing events to an AWS event sink
+#[derive(Debug, thiserror::Error)]
+pub enum AwsEventSinkError {
+    /// An error occurred when building a message attribute
+    #[error("Failed to build message attribute")]
+    MessageAttributeBuildingFailed,
+
+    /// An error occurred when publishing the event to an SNS topic
+    #[error("Failed to publish event to SNS topic")]
+    SnsPublishFailed,
+
+    /// An error occurred when sending the event to an SQS queue
+    #[error("Failed to send event to SQS queue")]
+    SqsSendFailed,
+}
+
+/// Location of the AWS event sink and the role of the merchant assumed to publish to it
+#[derive(Debug, Clone)]
+pub struct AwsEventSinkConfig {
+    /// Region of the topic or queue
+    pub region: String,
+
+    /// ARN of the role assumed to publish the events
+    pub role_arn: String,
+
+    /// External ID required by the trust policy of the role, if any
+    pub external_id: Option<String>,
+}
+
+/// Client publishing events to an SNS topic or an SQS queue of a merchant. The credentials of the
+/// assumed role are refreshed as they expire, so a client can be reused across events.
+#[derive(Debug, Clone)]
+pub enum AwsEventSink {
+    /// An SNS topic
+    Sns {
+        /// Client used to publish events
+        client: aws_sdk_sns::Client,
+        /// ARN of the topic
+        topic_arn: String,
+    },
+    /// An SQS queue
+    Sqs {
+        /// Client used to send events
+        client: aws_sdk_sqs::Client,
+        /// URL of the queue
+        queue_url: String,
+    },
+}
+
+impl AwsEventSink {
+    /// Constructs a client publishing events to the given SNS topic
+    pub async fn create_sns(config: &AwsEventSinkConfig, topic_arn: String) -> Self {
+        let sdk_config = Self::load_assumed_role_config(config).await;
+        Self::Sns {
+            client: aws_sdk_sns::Client::new(&sdk_config),
+            topic_arn,
+        }
+    }
+
+    /// Constructs a client sending events to the given SQS queue
+    pub async fn create_sqs(config: &AwsEventSinkConfig, queue_url: String) -> Self {
+        let sdk_config = Self::load_assumed_role_config(config).await;
+        Self::Sqs {
+            client: aws_sdk_sqs::Client::new(&sdk_config),
+            queue_url,
+        }
+    }
+
+    async fn load_assumed_role_config(config: &AwsEventSinkConfig) -> SdkConfig {
+        let region = Region::new(config.region.clone());
+        let base_config = aws_config::from_env().region(region.clone()).load().await;
+
+        let mut assume_role_provider = AssumeRoleProvider::builder(&config.role_arn)
+            .session_name(ROLE_SESSION_NAME)
+            .region(region.clone());
+        if let Some(external_id) = &config.external_id {
+            assume_role_provider = assume_role_provider.external_id(external_id);
+        }
+        let credentials_provider = assume_role_provider.configure(&base_config).build().await;
+
+        aws_config::from_env()
+            .region(region)
+            .credentials_provider(credentials_provider)
+            .load()
+            .await
+    }
+
+    /// Publishes the message to the topic or queue, along with the attributes. Events for the
+    /// same object share the `message_group_id` and events are deduplicated by the
+    /// `deduplication_id`, which are only used by FIFO topics and queues.
+    pub async fn publish(
+        &self,
+        message: String,
+        attributes: &HashMap<String, String>,
+        message_group_id: &str,
+        deduplication_id: &str,
+    ) -> CustomResult<(), AwsEventSinkError> {
+        match self {
+            Self::Sns { client, topic_arn } => {
+                let message_attributes = attributes
+                    .iter()
+                    .map(|(name, value)| {
+                        aws_sdk_sns::types::MessageAttributeValue::builder()
+                            .data_type("String")
+                            .string_value(value)
+                            .build()
+                            .map(|attribute| (name.clone(), attribute))
+                    })
+                    .collect::<Result<HashMap<_, _>, _>>()
+                    .change_context(AwsEventSinkError::MessageAttributeBuildingFailed)?;
+
+                let mut request = client
+                    .publish()
+                    .topic_arn(topic_arn)
+                    .message(message)
+                    .set_message_attributes(Some(message_attributes));
+                if topic_arn.ends_with(FIFO_SUFFIX) {
+                    request = request
+                        .message_group_id(message_group_id)
+                        .message_deduplication_id(deduplication_id);
+                }
+                request
+                    .send()
+                    .await
+                    .map_err(|error| report!(error))
+                    .change_context(AwsEventSinkError::SnsPublishFailed)?;
+            }
+            Self::Sqs { client, queue_url } => {
+                let message_attributes = attributes
+                    .iter()
+                    .map(|(name, value)| {
+                        aws_sdk_sqs::types::MessageAttributeValue::builder()
+                            .data_type("String")
+                            .string_value(value)
+                            .build()
+                            .map(|attribute| (name.clone(), attribute))
+                    })
+                    .collect::<Result<HashMap<_, _>, _>>()
+                    .change_context(AwsEventSinkError::MessageAttributeBuildingFailed)?;
+
+                let mut request = client
+                    .send_message()
+                    .queue_url(queue_url)
+                    .message_body(message)
+                    .set_message_attributes(Some(message_attributes));
+                if queue_url.ends_with(FIFO_SUFFIX) {
+                    request = request
+                        .message_group_id(message_group_id)
+                        .message_deduplication_id(deduplication_id);
+                }
+                request
+                    .send()
+                    .await
+                    .map_err(|error| report!(error))
+                    .change_context(AwsEventSinkError::SqsSendFailed)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -20,6 +20,11 @@ pub struct AwsFileStorageConfig {
     region: String,
     /// The AWS s3 bucket to send file uploads
     bucket_name: String,
+    /// The endpoint of an S3 compatible storage service, used instead of the AWS endpoint
+    endpoint_url: Option<String>,
+    /// Whether the bucket is addressed as a part of the path instead of the host, which is
+    /// required by most S3 compatible storage services
+    force_path_style: bool,
 }
 
 impl AwsFileStorageConfig {
@@ -53,8 +58,13 @@ impl AwsFileStorageClient {
     pub(super) async fn new(config: &AwsFileStorageConfig) -> Self {
         let region_provider = RegionProviderChain::first_try(Region::new(config.region.clone()));
         let sdk_config = aws_config::from_env().region(region_provider).load().await;
+        let mut s3_config_builder = aws_sdk_s3::config::Builder::from(&sdk_config)
+            .force_path_style(config.force_path_style);
+        if let Some(endpoint_url) = &config.endpoint_url {
+            s3_config_builder = s3_config_builder.endpoint_url(endpoint_url);
+        }
         Self {
-            inner_client: Client::new(&sdk_config),
+            inner_client: Client::from_conf(s3_config_builder.build()),
             bucket_name: config.bucket_name.clone(),
         }
     }
@@ -0,0 +1,5 @@
+//! Interactions with the GCP KMS API
+
+pub mod core;
+
+pub mod implementers;
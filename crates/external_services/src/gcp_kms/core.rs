@@ -0,0 +1,254 @@
+//! Interactions with the GCP KMS API
+
+use std::{
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+use base64::Engine;
+use common_utils::{errors::CustomResult, ext_traits::ConfigExt, fp_utils::when};
+use error_stack::{report, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::logger;
+
+use crate::consts;
+
+/// The GCP KMS API endpoint used when none is configured.
+const DEFAULT_GCP_KMS_ENDPOINT: &str = "https://cloudkms.googleapis.com";
+
+/// The metadata server endpoint issuing access tokens for the service account of the workload.
+const METADATA_SERVER_TOKEN_URL: &str =
+    "http://metadata.google.internal/computeMetadata/v1/instance/service-accounts/default/token";
+
+/// Access tokens are refreshed once they are this close to expiring.
+const ACCESS_TOKEN_EXPIRY_BUFFER: Duration = Duration::from_secs(60);
+
+/// Configuration parameters required for constructing a [`GcpKmsClient`].
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct GcpKmsConfig {
+    /// The resource name of the KMS key used to encrypt or decrypt data, of the format
+    /// `projects/{project}/locations/{location}/keyRings/{key_ring}/cryptoKeys/{crypto_key}`.
+    pub key_name: String,
+
+    /// The GCP KMS API endpoint to send requests to, `https://cloudkms.googleapis.com` if not set.
+    pub endpoint: Option<String>,
+}
+
+impl GcpKmsConfig {
+    /// Verifies that the [`GcpKmsClient`] configuration is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        when(self.key_name.is_default_or_empty(), || {
+            Err("GCP KMS key name must not be empty")
+        })?;
+
+        when(
+            self.endpoint
+                .as_ref()
+                .is_some_and(|endpoint| endpoint.is_default_or_empty()),
+            || Err("GCP KMS endpoint must not be empty"),
+        )
+    }
+}
+
+/// Client for GCP KMS operations. The requests are authorized with access tokens of the service
+/// account attached to the workload, which are fetched from the GCP metadata server.
+#[derive(Debug, Clone)]
+pub struct GcpKmsClient {
+    http_client: reqwest::Client,
+    key_url: String,
+    access_token: Arc<Mutex<Option<AccessToken>>>,
+}
+
+#[derive(Debug)]
+struct AccessToken {
+    token: Secret<String>,
+    expires_at: Instant,
+}
+
+#[derive(serde::Deserialize)]
+struct AccessTokenResponse {
+    access_token: Secret<String>,
+    expires_in: u64,
+}
+
+#[derive(serde::Serialize)]
+struct EncryptRequest {
+    plaintext: String,
+}
+
+#[derive(serde::Deserialize)]
+struct EncryptResponse {
+    ciphertext: String,
+}
+
+#[derive(serde::Serialize)]
+struct DecryptRequest<'a> {
+    ciphertext: &'a str,
+}
+
+#[derive(serde::Deserialize)]
+struct DecryptResponse {
+    #[serde(default)]
+    plaintext: Secret<String>,
+}
+
+impl GcpKmsClient {
+    /// Constructs a new GCP KMS client.
+    pub fn new(config: &GcpKmsConfig) -> CustomResult<Self, GcpKmsError> {
+        let http_client = reqwest::Client::builder()
+            .build()
+            .change_context(GcpKmsError::ClientCreationFailed)?;
+        let endpoint = config
+            .endpoint
+            .as_deref()
+            .unwrap_or(DEFAULT_GCP_KMS_ENDPOINT)
+            .trim_end_matches('/');
+
+        Ok(Self {
+            http_client,
+            key_url: format!("{endpoint}/v1/{}", config.key_name),
+            access_token: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    /// Returns the cached access token, fetching a new one from the metadata server once it is
+    /// about to expire.
+    async fn get_access_token(&self) -> CustomResult<Secret<String>, GcpKmsError> {
+        let cached_token = self
+            .access_token
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_ref()
+            .filter(|token| {
+                token.expires_at.saturating_duration_since(Instant::now())
+                    > ACCESS_TOKEN_EXPIRY_BUFFER
+            })
+            .map(|token| token.token.clone());
+        if let Some(token) = cached_token {
+            return Ok(token);
+        }
+
+        let response = self
+            .http_client
+            .get(METADATA_SERVER_TOKEN_URL)
+            .header("Metadata-Flavor", "Google")
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .change_context(GcpKmsError::AccessTokenFetchFailed)?
+            .bytes()
+            .await
+            .change_context(GcpKmsError::AccessTokenFetchFailed)?;
+        let response: AccessTokenResponse = serde_json::from_slice(&response)
+            .change_context(GcpKmsError::AccessTokenFetchFailed)?;
+
+        *self
+            .access_token
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(AccessToken {
+            token: response.access_token.clone(),
+            expires_at: Instant::now() + Duration::from_secs(response.expires_in),
+        });
+
+        Ok(response.access_token)
+    }
+
+    async fn send_request<Req, Res>(
+        &self,
+        operation: &str,
+        request: &Req,
+    ) -> CustomResult<Res, GcpKmsError>
+    where
+        Req: serde::Serialize,
+        Res: serde::de::DeserializeOwned,
+    {
+        let access_token = self.get_access_token().await?;
+        let body = serde_json::to_vec(request).change_context(GcpKmsError::RequestFailed)?;
+
+        let response = self
+            .http_client
+            .post(format!("{}:{operation}", self.key_url))
+            .bearer_auth(access_token.peek())
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body)
+            .send()
+            .await
+            .change_context(GcpKmsError::RequestFailed)?;
+
+        let status = response.status();
+        let response = response
+            .bytes()
+            .await
+            .change_context(GcpKmsError::RequestFailed)?;
+        if !status.is_success() {
+            logger::error!(
+                ?status,
+                gcp_kms_error = %String::from_utf8_lossy(&response),
+                "Failed to GCP KMS {operation} data"
+            );
+            return Err(report!(GcpKmsError::RequestFailed));
+        }
+
+        serde_json::from_slice(&response).change_context(GcpKmsError::RequestFailed)
+    }
+
+    /// Encrypts the provided data using GCP KMS, returning the base64-encoded ciphertext.
+    pub async fn encrypt(&self, data: impl AsRef<[u8]>) -> CustomResult<String, GcpKmsError> {
+        let request = EncryptRequest {
+            plaintext: consts::BASE64_ENGINE.encode(data),
+        };
+
+        self.send_request::<_, EncryptResponse>("encrypt", &request)
+            .await
+            .change_context(GcpKmsError::EncryptionFailed)
+            .map(|response| response.ciphertext)
+    }
+
+    /// Decrypts the provided base64-encoded ciphertext using GCP KMS.
+    pub async fn decrypt(&self, data: impl AsRef<[u8]>) -> CustomResult<Vec<u8>, GcpKmsError> {
+        let ciphertext =
+            std::str::from_utf8(data.as_ref()).change_context(GcpKmsError::Utf8DecodingFailed)?;
+
+        let response = self
+            .send_request::<_, DecryptResponse>("decrypt", &DecryptRequest { ciphertext })
+            .await
+            .change_context(GcpKmsError::DecryptionFailed)?;
+
+        consts::BASE64_ENGINE
+            .decode(response.plaintext.peek())
+            .change_context(GcpKmsError::Base64DecodingFailed)
+    }
+}
+
+/// Errors that could occur during GCP KMS operations.
+#[derive(Debug, thiserror::Error)]
+pub enum GcpKmsError {
+    /// Failed while creating the HTTP client used to call GCP KMS.
+    #[error("Failed while creating the GCP KMS client")]
+    ClientCreationFailed,
+
+    /// Failed while fetching an access token from the metadata server.
+    #[error("Failed to fetch an access token from the metadata server")]
+    AccessTokenFetchFailed,
+
+    /// The request to GCP KMS failed or returned an unexpected response.
+    #[error("Failed while sending the request to GCP KMS")]
+    RequestFailed,
+
+    /// An error occurred when GCP KMS encrypting input data.
+    #[error("Failed to GCP KMS encrypt input data")]
+    EncryptionFailed,
+
+    /// An error occurred when GCP KMS decrypting input data.
+    #[error("Failed to GCP KMS decrypt input data")]
+    DecryptionFailed,
+
+    /// An error occurred when base64 decoding the GCP KMS decrypted output.
+    #[error("Failed to base64 decode decryption output")]
+    Base64DecodingFailed,
+
+    /// An error occurred UTF-8 decoding the ciphertext.
+    #[error("Failed to UTF-8 decode input data")]
+    Utf8DecodingFailed,
+}
@@ -0,0 +1,25 @@
+//! Trait implementations for gcp kms client
+
+use common_utils::errors::CustomResult;
+use error_stack::ResultExt;
+use hyperswitch_interfaces::encryption_interface::{
+    EncryptionError, EncryptionManagementInterface,
+};
+
+use crate::gcp_kms::core::GcpKmsClient;
+
+#[async_trait::async_trait]
+impl EncryptionManagementInterface for GcpKmsClient {
+    async fn encrypt(&self, input: &[u8]) -> CustomResult<Vec<u8>, EncryptionError> {
+        self.encrypt(input)
+            .await
+            .change_context(EncryptionError::EncryptionFailed)
+            .map(|val| val.into_bytes())
+    }
+
+    async fn decrypt(&self, input: &[u8]) -> CustomResult<Vec<u8>, EncryptionError> {
+        self.decrypt(input)
+            .await
+            .change_context(EncryptionError::DecryptionFailed)
+    }
+}
@@ -0,0 +1,157 @@
+use std::{net::IpAddr, str::FromStr, sync::Arc};
+
+use common_enums::CountryAlpha2;
+use common_utils::{
+    errors::CustomResult,
+    ext_traits::ConfigExt,
+    request::{Method, RequestBuilder},
+};
+use error_stack::ResultExt;
+use hyperswitch_interfaces::{
+    geo_ip::{GeoIpError, GeoIpInterface},
+    types::Proxy,
+};
+use router_env::logger;
+
+use crate::http_client;
+
+/// Placeholder in the lookup url which is replaced by the IP address being resolved
+const IP_ADDRESS_PLACEHOLDER: &str = "{ip}";
+
+/// Configuration for a GeoIP provider exposing a JSON HTTP API
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct HttpGeoIpConfig {
+    /// The lookup url, containing an `{ip}` placeholder for the IP address
+    pub url: String,
+
+    /// The field of the JSON response which holds the ISO Alpha-2 country code
+    pub country_code_field: String,
+
+    /// Timeout for the lookup request, in seconds
+    pub timeout_secs: Option<u64>,
+}
+
+impl HttpGeoIpConfig {
+    /// Validates the GeoIP HTTP API configuration
+    pub(super) fn validate(&self) -> Result<(), InvalidGeoIpConfig> {
+        use common_utils::fp_utils::when;
+
+        when(!self.url.contains(IP_ADDRESS_PLACEHOLDER), || {
+            Err(InvalidGeoIpConfig(
+                "url must contain the `{ip}` placeholder",
+            ))
+        })?;
+
+        when(self.country_code_field.is_default_or_empty(), || {
+            Err(InvalidGeoIpConfig("country_code_field must not be empty"))
+        })
+    }
+}
+
+/// Error thrown when the geo_ip config is invalid
+#[derive(Debug, Clone)]
+pub struct InvalidGeoIpConfig(pub &'static str);
+
+impl std::error::Error for InvalidGeoIpConfig {}
+
+impl std::fmt::Display for InvalidGeoIpConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "geo_ip: {}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+/// NoGeoIp struct
+pub struct NoGeoIp;
+
+/// Enum representing different GeoIP provider configurations
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(tag = "geo_ip_provider")]
+#[serde(rename_all = "snake_case")]
+pub enum GeoIpConfig {
+    /// GeoIP provider exposing a JSON HTTP API
+    HttpApi {
+        /// GeoIP HTTP API configuration
+        http_api: HttpGeoIpConfig,
+    },
+
+    /// No GeoIP provider configured
+    #[default]
+    NoGeoIp,
+}
+
+impl GeoIpConfig {
+    /// Verifies that the client configuration is usable
+    pub fn validate(&self) -> Result<(), InvalidGeoIpConfig> {
+        match self {
+            Self::HttpApi { http_api } => http_api.validate(),
+            Self::NoGeoIp => Ok(()),
+        }
+    }
+
+    /// Retrieves the appropriate GeoIP client based on the configuration.
+    pub async fn get_geo_ip_client(&self) -> Arc<dyn GeoIpInterface> {
+        match self {
+            Self::HttpApi { http_api } => Arc::new(http_api.clone()),
+            Self::NoGeoIp => Arc::new(NoGeoIp),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl GeoIpInterface for NoGeoIp {
+    async fn get_country(
+        &self,
+        _proxy: &Proxy,
+        _ip_address: IpAddr,
+    ) -> CustomResult<Option<CountryAlpha2>, GeoIpError> {
+        logger::debug!("No GeoIP provider configured!");
+        Ok(None)
+    }
+}
+
+#[async_trait::async_trait]
+impl GeoIpInterface for HttpGeoIpConfig {
+    async fn get_country(
+        &self,
+        proxy: &Proxy,
+        ip_address: IpAddr,
+    ) -> CustomResult<Option<CountryAlpha2>, GeoIpError> {
+        let request = RequestBuilder::new()
+            .method(Method::Get)
+            .url(
+                &self
+                    .url
+                    .replace(IP_ADDRESS_PLACEHOLDER, &ip_address.to_string()),
+            )
+            .attach_default_headers()
+            .build();
+
+        let response = http_client::send_request(proxy, request, self.timeout_secs)
+            .await
+            .change_context(GeoIpError::RequestFailed)?;
+
+        if !response.status().is_success() {
+            return Err(GeoIpError::RequestFailed).attach_printable(format!(
+                "GeoIP provider responded with status {}",
+                response.status()
+            ));
+        }
+
+        let body = response
+            .bytes()
+            .await
+            .change_context(GeoIpError::ResponseParsingFailed)?;
+        let response_json: serde_json::Value = serde_json::from_slice(&body)
+            .change_context(GeoIpError::ResponseParsingFailed)
+            .attach_printable("GeoIP provider response is not valid JSON")?;
+
+        response_json
+            .get(&self.country_code_field)
+            .and_then(serde_json::Value::as_str)
+            .map(|country_code| CountryAlpha2::from_str(&country_code.to_uppercase()))
+            .transpose()
+            .change_context(GeoIpError::ResponseParsingFailed)
+            .attach_printable("GeoIP provider returned an invalid country code")
+    }
+}
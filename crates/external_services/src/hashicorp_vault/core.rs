@@ -1,12 +1,15 @@
 //! Interactions with the HashiCorp Vault
 
-use std::{collections::HashMap, future::Future, pin::Pin};
+use std::{collections::HashMap, future::Future, pin::Pin, sync::Arc};
 
+use base64::Engine as _;
 use common_utils::{ext_traits::ConfigExt, fp_utils::when};
 use error_stack::{Report, ResultExt};
 use masking::{PeekInterface, Secret};
 use vaultrs::client::{VaultClient, VaultClientSettingsBuilder};
 
+use crate::consts;
+
 static HC_CLIENT: tokio::sync::OnceCell<HashiCorpVault> = tokio::sync::OnceCell::const_new();
 
 #[allow(missing_debug_implementations)]
@@ -39,6 +42,104 @@ impl HashiCorpVaultConfig {
     }
 }
 
+/// Configuration for encrypting data with the transit secrets engine of HashiCorp Vault.
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct HashiCorpVaultTransitConfig {
+    /// The URL of the HashiCorp Vault server.
+    pub url: String,
+    /// The authentication token used to access HashiCorp Vault.
+    pub token: Secret<String>,
+    /// The path at which the transit secrets engine is mounted.
+    pub mount: String,
+    /// The name of the transit key used to encrypt or decrypt data.
+    pub key_name: String,
+}
+
+impl HashiCorpVaultTransitConfig {
+    /// Verifies that the [`HashiCorpVaultTransit`] configuration is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        HashiCorpVaultConfig::from(self).validate()?;
+
+        when(self.mount.is_default_or_empty(), || {
+            Err("HashiCorp vault transit mount must not be empty")
+        })?;
+
+        when(self.key_name.is_default_or_empty(), || {
+            Err("HashiCorp vault transit key name must not be empty")
+        })
+    }
+}
+
+impl From<&HashiCorpVaultTransitConfig> for HashiCorpVaultConfig {
+    fn from(config: &HashiCorpVaultTransitConfig) -> Self {
+        Self {
+            url: config.url.clone(),
+            token: config.token.clone(),
+        }
+    }
+}
+
+/// A client encrypting and decrypting data with the transit secrets engine of HashiCorp Vault,
+/// the key material never leaves Vault.
+#[allow(missing_debug_implementations)]
+#[derive(Clone)]
+pub struct HashiCorpVaultTransit {
+    vault: Arc<HashiCorpVault>,
+    mount: String,
+    key_name: String,
+}
+
+impl HashiCorpVaultTransit {
+    /// Creates a new instance of HashiCorpVaultTransit based on the provided configuration.
+    pub fn new(config: &HashiCorpVaultTransitConfig) -> error_stack::Result<Self, HashiCorpError> {
+        HashiCorpVault::new(&HashiCorpVaultConfig::from(config)).map(|vault| Self {
+            vault: Arc::new(vault),
+            mount: config.mount.clone(),
+            key_name: config.key_name.clone(),
+        })
+    }
+
+    /// Encrypts the provided data with the transit key, returning the Vault ciphertext of the
+    /// format `vault:{key_version}:{base64_encoded_data}`.
+    pub async fn encrypt(
+        &self,
+        data: impl AsRef<[u8]>,
+    ) -> error_stack::Result<String, HashiCorpError> {
+        let plaintext = consts::BASE64_ENGINE.encode(data);
+
+        vaultrs::transit::data::encrypt(
+            &self.vault.client,
+            &self.mount,
+            &self.key_name,
+            &plaintext,
+            None,
+        )
+        .await
+        .map_err(Into::<Report<_>>::into)
+        .change_context(HashiCorpError::EncryptionFailed)
+        .map(|response| response.ciphertext)
+    }
+
+    /// Decrypts the provided Vault ciphertext with the transit key.
+    pub async fn decrypt(&self, ciphertext: &str) -> error_stack::Result<Vec<u8>, HashiCorpError> {
+        let response = vaultrs::transit::data::decrypt(
+            &self.vault.client,
+            &self.mount,
+            &self.key_name,
+            ciphertext,
+            None,
+        )
+        .await
+        .map_err(Into::<Report<_>>::into)
+        .change_context(HashiCorpError::DecryptionFailed)?;
+
+        consts::BASE64_ENGINE
+            .decode(response.plaintext)
+            .change_context(HashiCorpError::Base64DecodingFailed)
+    }
+}
+
 /// Asynchronously retrieves a HashiCorp Vault client based on the provided configuration.
 ///
 /// # Parameters
@@ -204,6 +305,10 @@ pub enum HashiCorpError {
     #[error("Failed to KMS decrypt input data")]
     DecryptionFailed,
 
+    /// An error occurred when encrypting input data with the transit secrets engine.
+    #[error("Failed to encrypt input data")]
+    EncryptionFailed,
+
     /// The KMS decrypted output does not include a plaintext output.
     #[error("Missing plaintext KMS decryption output")]
     MissingPlaintextDecryptionOutput,
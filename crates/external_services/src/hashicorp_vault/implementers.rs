@@ -2,12 +2,13 @@
 
 use common_utils::errors::CustomResult;
 use error_stack::ResultExt;
-use hyperswitch_interfaces::secrets_interface::{
-    SecretManagementInterface, SecretsManagementError,
+use hyperswitch_interfaces::{
+    encryption_interface::{EncryptionError, EncryptionManagementInterface},
+    secrets_interface::{SecretManagementInterface, SecretsManagementError},
 };
 use masking::{ExposeInterface, Secret};
 
-use crate::hashicorp_vault::core::{HashiCorpVault, Kv2};
+use crate::hashicorp_vault::core::{HashiCorpVault, HashiCorpVaultTransit, Kv2};
 
 #[async_trait::async_trait]
 impl SecretManagementInterface for HashiCorpVault {
@@ -22,3 +23,21 @@ impl SecretManagementInterface for HashiCorpVault {
             .map(Into::into)
     }
 }
+
+#[async_trait::async_trait]
+impl EncryptionManagementInterface for HashiCorpVaultTransit {
+    async fn encrypt(&self, input: &[u8]) -> CustomResult<Vec<u8>, EncryptionError> {
+        self.encrypt(input)
+            .await
+            .change_context(EncryptionError::EncryptionFailed)
+            .map(|val| val.into_bytes())
+    }
+
+    async fn decrypt(&self, input: &[u8]) -> CustomResult<Vec<u8>, EncryptionError> {
+        let ciphertext =
+            std::str::from_utf8(input).change_context(EncryptionError::DecryptionFailed)?;
+        self.decrypt(ciphertext)
+            .await
+            .change_context(EncryptionError::DecryptionFailed)
+    }
+}
@@ -32,7 +32,16 @@ pub async fn send_request(
         request.ca_certificate,
     )?;
 
-    let headers = request.headers.construct_header_map()?;
+    let mut headers = request.headers.construct_header_map()?;
+    // Propagate the trace context, so that the request can be traced across services
+    for (name, value) in router_env::logger::get_trace_context_headers() {
+        headers.insert(
+            reqwest::header::HeaderName::try_from(name)
+                .change_context(HttpClientError::HeaderMapConstructionFailed)?,
+            reqwest::header::HeaderValue::try_from(value)
+                .change_context(HttpClientError::HeaderMapConstructionFailed)?,
+        );
+    }
     let metrics_tag = router_env::metric_attributes!((
         consts::METRICS_HOST_TAG_NAME,
         url.host_str().unwrap_or_default().to_owned()
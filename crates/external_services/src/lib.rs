@@ -8,6 +8,12 @@ pub mod email;
 #[cfg(feature = "aws_kms")]
 pub mod aws_kms;
 
+#[cfg(feature = "aws_event_sink")]
+pub mod aws_event_sink;
+
+#[cfg(feature = "gcp_kms")]
+pub mod gcp_kms;
+
 pub mod file_storage;
 #[cfg(feature = "hashicorp-vault")]
 pub mod hashicorp_vault;
@@ -28,6 +34,9 @@ pub mod managers;
 /// crm module
 pub mod crm;
 
+/// geo_ip module
+pub mod geo_ip;
+
 /// deserializers module_path
 pub mod utils;
 
@@ -70,7 +79,7 @@ pub mod date_time {
 /// Crate specific constants
 pub mod consts {
     /// General purpose base64 engine
-    #[cfg(feature = "aws_kms")]
+    #[cfg(any(feature = "aws_kms", feature = "gcp_kms", feature = "hashicorp-vault"))]
     pub(crate) const BASE64_ENGINE: base64::engine::GeneralPurpose =
         base64::engine::general_purpose::STANDARD;
 
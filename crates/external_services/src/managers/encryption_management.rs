@@ -3,12 +3,18 @@
 use std::sync::Arc;
 
 use common_utils::errors::CustomResult;
+#[cfg(any(feature = "gcp_kms", feature = "hashicorp-vault"))]
+use error_stack::ResultExt;
 use hyperswitch_interfaces::encryption_interface::{
     EncryptionError, EncryptionManagementInterface,
 };
 
 #[cfg(feature = "aws_kms")]
 use crate::aws_kms;
+#[cfg(feature = "gcp_kms")]
+use crate::gcp_kms;
+#[cfg(feature = "hashicorp-vault")]
+use crate::hashicorp_vault;
 use crate::no_encryption::core::NoEncryption;
 
 /// Enum representing configuration options for encryption management.
@@ -23,6 +29,20 @@ pub enum EncryptionManagementConfig {
         aws_kms: aws_kms::core::AwsKmsConfig,
     },
 
+    /// GCP KMS configuration
+    #[cfg(feature = "gcp_kms")]
+    GcpKms {
+        /// GCP KMS config
+        gcp_kms: gcp_kms::core::GcpKmsConfig,
+    },
+
+    /// HashiCorp-Vault transit secrets engine configuration
+    #[cfg(feature = "hashicorp-vault")]
+    HashiCorpVaultTransit {
+        /// HC-Vault transit config
+        hc_vault_transit: hashicorp_vault::core::HashiCorpVaultTransitConfig,
+    },
+
     /// Variant representing no encryption
     #[default]
     NoEncryption,
@@ -34,6 +54,10 @@ impl EncryptionManagementConfig {
         match self {
             #[cfg(feature = "aws_kms")]
             Self::AwsKms { aws_kms } => aws_kms.validate(),
+            #[cfg(feature = "gcp_kms")]
+            Self::GcpKms { gcp_kms } => gcp_kms.validate(),
+            #[cfg(feature = "hashicorp-vault")]
+            Self::HashiCorpVaultTransit { hc_vault_transit } => hc_vault_transit.validate(),
 
             Self::NoEncryption => Ok(()),
         }
@@ -46,6 +70,16 @@ impl EncryptionManagementConfig {
         Ok(match self {
             #[cfg(feature = "aws_kms")]
             Self::AwsKms { aws_kms } => Arc::new(aws_kms::core::AwsKmsClient::new(aws_kms).await),
+            #[cfg(feature = "gcp_kms")]
+            Self::GcpKms { gcp_kms } => Arc::new(
+                gcp_kms::core::GcpKmsClient::new(gcp_kms)
+                    .change_context(EncryptionError::ClientCreationFailed)?,
+            ),
+            #[cfg(feature = "hashicorp-vault")]
+            Self::HashiCorpVaultTransit { hc_vault_transit } => Arc::new(
+                hashicorp_vault::core::HashiCorpVaultTransit::new(hc_vault_transit)
+                    .change_context(EncryptionError::ClientCreationFailed)?,
+            ),
 
             Self::NoEncryption => Arc::new(NoEncryption),
         })
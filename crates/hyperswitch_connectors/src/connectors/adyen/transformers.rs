@@ -125,6 +125,20 @@ pub enum AdyenShopperInteraction {
 pub enum AdyenRecurringModel {
     UnscheduledCardOnFile,
     CardOnFile,
+    Subscription,
+}
+
+impl From<storage_enums::MitCategory> for AdyenRecurringModel {
+    fn from(mit_category: storage_enums::MitCategory) -> Self {
+        match mit_category {
+            storage_enums::MitCategory::Installment | storage_enums::MitCategory::Recurring => {
+                Self::Subscription
+            }
+            storage_enums::MitCategory::Unscheduled | storage_enums::MitCategory::Resubmission => {
+                Self::UnscheduledCardOnFile
+            }
+        }
+    }
 }
 
 #[derive(Clone, Default, Debug, Serialize, Deserialize)]
@@ -1876,20 +1890,23 @@ fn get_recurring_processing_model(
         }
     };
 
+    let recurring_model = item
+        .request
+        .get_transaction_initiation()
+        .get_mit_category()
+        .map(AdyenRecurringModel::from)
+        .unwrap_or(AdyenRecurringModel::UnscheduledCardOnFile);
+
     match (item.request.setup_future_usage, item.request.off_session) {
         (Some(storage_enums::FutureUsage::OffSession), _) => {
             let store_payment_method = item.request.is_mandate_payment();
             Ok((
-                Some(AdyenRecurringModel::UnscheduledCardOnFile),
+                Some(recurring_model),
                 Some(store_payment_method),
                 shopper_reference,
             ))
         }
-        (_, Some(true)) => Ok((
-            Some(AdyenRecurringModel::UnscheduledCardOnFile),
-            None,
-            shopper_reference,
-        )),
+        (_, Some(true)) => Ok((Some(recurring_model), None, shopper_reference)),
         _ => Ok((None, None, None)),
     }
 }
@@ -1155,4 +1155,8 @@ impl ConnectorSpecifications for Archipel {
     fn get_supported_webhook_flows(&self) -> Option<&'static [enums::EventClass]> {
         Some(&ARCHIPEL_SUPPORTED_WEBHOOK_FLOWS)
     }
+
+    fn is_incremental_authorization_supported(&self) -> bool {
+        true
+    }
 }
@@ -2283,4 +2283,12 @@ impl ConnectorSpecifications for Cybersource {
     fn get_supported_webhook_flows(&self) -> Option<&'static [enums::EventClass]> {
         Some(&CYBERSOURCE_SUPPORTED_WEBHOOK_FLOWS)
     }
+
+    fn is_incremental_authorization_supported(&self) -> bool {
+        true
+    }
+
+    fn is_dcc_supported(&self) -> bool {
+        true
+    }
 }
@@ -8,7 +8,7 @@ use common_utils::{
     consts, date_time,
     ext_traits::{OptionExt, ValueExt},
     pii,
-    types::{SemanticVersion, StringMajorUnit},
+    types::{SemanticVersion, StringMajorUnit, StringMajorUnitForConnector},
 };
 use error_stack::ResultExt;
 #[cfg(feature = "payouts")]
@@ -140,9 +140,13 @@ impl TryFrom<&SetupMandateRouterData> for CybersourceZeroMandateRequest {
         let order_information = OrderInformationWithBill {
             amount_details: Amount {
                 total_amount: StringMajorUnit::zero(),
+                tax_amount: None,
                 currency: item.request.currency,
+                foreign_amount_details: None,
             },
             bill_to: Some(bill_to),
+            line_items: None,
+            invoice_details: None,
         };
         let connector_merchant_config =
             CybersourceConnectorMetadataObject::try_from(&item.connector_meta_data)?;
@@ -664,6 +668,10 @@ pub struct Card {
 pub struct OrderInformationWithBill {
     amount_details: Amount,
     bill_to: Option<BillTo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line_items: Option<Vec<LineItem>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invoice_details: Option<InvoiceDetails>,
 }
 
 #[derive(Debug, Serialize)]
@@ -683,6 +691,40 @@ pub struct OrderInformation {
 pub struct Amount {
     total_amount: StringMajorUnit,
     currency: api_models::enums::Currency,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_amount: Option<StringMajorUnit>,
+    #[serde(flatten)]
+    foreign_amount_details: Option<ForeignAmountDetails>,
+}
+
+/// Amount in the currency of the card, when the customer accepted dynamic currency conversion
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ForeignAmountDetails {
+    foreign_amount: StringMajorUnit,
+    foreign_currency: api_models::enums::Currency,
+    exchange_rate: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LineItem {
+    product_name: String,
+    product_code: Option<String>,
+    product_sku: Option<String>,
+    quantity: u16,
+    unit_price: StringMajorUnit,
+    unit_of_measure: Option<String>,
+    total_amount: Option<StringMajorUnit>,
+    tax_amount: Option<StringMajorUnit>,
+    discount_amount: Option<StringMajorUnit>,
+    commodity_code: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvoiceDetails {
+    purchase_order_number: String,
 }
 
 #[derive(Debug, Serialize)]
@@ -798,6 +840,7 @@ impl
 
         let connector_merchant_config =
             CybersourceConnectorMetadataObject::try_from(&item.router_data.connector_meta_data)?;
+        let transaction_initiation = item.router_data.request.get_transaction_initiation();
 
         let (action_list, action_token_types, authorization_options) = if item
             .router_data
@@ -879,7 +922,7 @@ impl
                         }),
                     )
                 }
-                Some(payments::MandateReferenceId::NetworkMandateId(network_transaction_id)) => {
+                Some(payments::MandateReferenceId::NetworkMandateId(_)) => {
                     let (original_amount, original_currency) = match network
                         .clone()
                         .map(|network| network.to_lowercase())
@@ -927,7 +970,8 @@ impl
                         ),
                         None => None,
                     };
-                    commerce_indicator = "recurring".to_string();
+                    commerce_indicator =
+                        get_commerce_indicator_for_mit(transaction_initiation.get_mit_category());
                     (
                         None,
                         None,
@@ -938,16 +982,18 @@ impl
                                 stored_credential_used: Some(true),
                             }),
                             merchant_intitiated_transaction: Some(MerchantInitiatedTransaction {
-                                reason: Some("7".to_string()),
+                                reason: get_mit_reason(transaction_initiation.get_mit_category()),
                                 original_authorized_amount,
-                                previous_transaction_id: Some(Secret::new(network_transaction_id)),
+                                previous_transaction_id: transaction_initiation
+                                    .get_network_transaction_id()
+                                    .map(Secret::new),
                             }),
                             ignore_avs_result: connector_merchant_config.disable_avs,
                             ignore_cv_result: connector_merchant_config.disable_cvn,
                         }),
                     )
                 }
-                Some(payments::MandateReferenceId::NetworkTokenWithNTI(mandate_data)) => {
+                Some(payments::MandateReferenceId::NetworkTokenWithNTI(_)) => {
                     let (original_amount, original_currency) = match network
                         .clone()
                         .map(|network| network.to_lowercase())
@@ -995,7 +1041,8 @@ impl
                         ),
                         None => None,
                     };
-                    commerce_indicator = "recurring".to_string(); //
+                    commerce_indicator =
+                        get_commerce_indicator_for_mit(transaction_initiation.get_mit_category());
                     (
                         None,
                         None,
@@ -1006,11 +1053,11 @@ impl
                                 stored_credential_used: Some(true),
                             }),
                             merchant_intitiated_transaction: Some(MerchantInitiatedTransaction {
-                                reason: Some("7".to_string()), // 7 is for MIT using NTI
+                                reason: get_mit_reason(transaction_initiation.get_mit_category()),
                                 original_authorized_amount,
-                                previous_transaction_id: Some(Secret::new(
-                                    mandate_data.network_transaction_id,
-                                )),
+                                previous_transaction_id: transaction_initiation
+                                    .get_network_transaction_id()
+                                    .map(Secret::new),
                             }),
                             ignore_avs_result: connector_merchant_config.disable_avs,
                             ignore_cv_result: connector_merchant_config.disable_cvn,
@@ -1060,6 +1107,30 @@ impl
     }
 }
 
+/// Commerce indicator of a merchant initiated payment made with a network transaction id
+fn get_commerce_indicator_for_mit(mit_category: Option<enums::MitCategory>) -> String {
+    match mit_category {
+        Some(enums::MitCategory::Installment) => "install",
+        Some(enums::MitCategory::Recurring)
+        | Some(enums::MitCategory::Unscheduled)
+        | Some(enums::MitCategory::Resubmission)
+        | None => "recurring",
+    }
+    .to_string()
+}
+
+/// Reason of a merchant initiated payment, "7" being the reason for a payment made with a network
+/// transaction id and "1" the reason for the resubmission of a declined payment
+fn get_mit_reason(mit_category: Option<enums::MitCategory>) -> Option<String> {
+    match mit_category {
+        Some(enums::MitCategory::Resubmission) => Some("1".to_string()),
+        Some(enums::MitCategory::Installment)
+        | Some(enums::MitCategory::Recurring)
+        | Some(enums::MitCategory::Unscheduled)
+        | None => Some("7".to_string()),
+    }
+}
+
 fn get_commerce_indicator_for_external_authentication(
     card_network: Option<String>,
     eci: String,
@@ -1172,27 +1243,98 @@ impl
 }
 
 impl
-    From<(
+    TryFrom<(
         &CybersourceRouterData<&PaymentsAuthorizeRouterData>,
         Option<BillTo>,
     )> for OrderInformationWithBill
 {
-    fn from(
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
         (item, bill_to): (
             &CybersourceRouterData<&PaymentsAuthorizeRouterData>,
             Option<BillTo>,
         ),
-    ) -> Self {
-        Self {
+    ) -> Result<Self, Self::Error> {
+        let currency = item.router_data.request.currency;
+        let l2_l3_data = item.router_data.get_optional_l2_l3_data();
+
+        let tax_amount = l2_l3_data
+            .as_ref()
+            .and_then(|l2_l3_data| l2_l3_data.order_tax_amount)
+            .map(|tax_amount| {
+                utils::convert_amount(&StringMajorUnitForConnector, tax_amount, currency)
+            })
+            .transpose()?;
+
+        let line_items = l2_l3_data
+            .as_ref()
+            .and_then(|l2_l3_data| l2_l3_data.order_details.as_ref())
+            .map(|order_details| {
+                order_details
+                    .iter()
+                    .map(|order| get_line_item(order, currency))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?;
+
+        let invoice_details = l2_l3_data
+            .and_then(|l2_l3_data| l2_l3_data.purchase_order_number)
+            .map(|purchase_order_number| InvoiceDetails {
+                purchase_order_number,
+            });
+
+        let foreign_amount_details = item
+            .router_data
+            .request
+            .dcc_data
+            .as_ref()
+            .map(|dcc_data| {
+                utils::convert_amount(
+                    &StringMajorUnitForConnector,
+                    dcc_data.converted_amount,
+                    dcc_data.card_currency,
+                )
+                .map(|foreign_amount| ForeignAmountDetails {
+                    foreign_amount,
+                    foreign_currency: dcc_data.card_currency,
+                    exchange_rate: dcc_data.rate.clone(),
+                })
+            })
+            .transpose()?;
+
+        Ok(Self {
             amount_details: Amount {
                 total_amount: item.amount.to_owned(),
-                currency: item.router_data.request.currency,
+                currency,
+                tax_amount,
+                foreign_amount_details,
             },
             bill_to,
-        }
+            line_items,
+            invoice_details,
+        })
     }
 }
 
+fn get_line_item(
+    order: &payments::OrderDetailsWithAmount,
+    currency: enums::Currency,
+) -> Result<LineItem, error_stack::Report<errors::ConnectorError>> {
+    let convert = |amount| utils::convert_amount(&StringMajorUnitForConnector, amount, currency);
+    Ok(LineItem {
+        product_name: order.product_name.clone(),
+        product_code: order.product_id.clone(),
+        product_sku: order.sku.clone(),
+        quantity: order.quantity,
+        unit_price: convert(order.amount)?,
+        unit_of_measure: order.unit_of_measure.clone(),
+        total_amount: order.total_amount.map(convert).transpose()?,
+        tax_amount: order.total_tax_amount.map(convert).transpose()?,
+        discount_amount: order.unit_discount_amount.map(convert).transpose()?,
+        commodity_code: order.commodity_code.clone(),
+    })
+}
+
 impl
     From<(
         &CybersourceRouterData<&PaymentsCompleteAuthorizeRouterData>,
@@ -1208,9 +1350,13 @@ impl
         Self {
             amount_details: Amount {
                 total_amount: item.amount.to_owned(),
+                tax_amount: None,
                 currency: item.router_data.request.currency,
+                foreign_amount_details: None,
             },
             bill_to: Some(bill_to),
+            line_items: None,
+            invoice_details: None,
         }
     }
 }
@@ -1389,7 +1535,7 @@ impl
             .get_billing_email()
             .or(item.router_data.request.get_email())?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
 
         let additional_card_network = item
             .router_data
@@ -1544,7 +1690,7 @@ impl
             .get_billing_email()
             .or(item.router_data.request.get_email())?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
 
         let card_issuer = ccard.get_card_issuer();
         let card_type = match card_issuer {
@@ -1671,7 +1817,7 @@ impl
 
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
 
         let card_issuer = token_data.get_card_issuer();
         let card_type = match card_issuer {
@@ -1837,7 +1983,7 @@ impl
             country: paze_data.billing_address.country_code,
             email,
         };
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
 
         let payment_information =
             PaymentInformation::NetworkToken(Box::new(NetworkTokenPaymentInformation {
@@ -1999,7 +2145,7 @@ impl
             .get_billing_email()
             .or(item.router_data.request.get_email())?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
         let processing_information = ProcessingInformation::try_from((
             item,
             Some(PaymentSolution::ApplePay),
@@ -2085,7 +2231,7 @@ impl
             .get_billing_email()
             .or(item.router_data.request.get_email())?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
 
         let payment_information =
             PaymentInformation::GooglePayToken(Box::new(GooglePayTokenPaymentInformation {
@@ -2152,7 +2298,7 @@ impl
             .get_billing_email()
             .or(item.router_data.request.get_email())?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
 
         let payment_information =
             PaymentInformation::GooglePay(Box::new(GooglePayPaymentInformation {
@@ -2241,7 +2387,7 @@ impl
             .get_billing_email()
             .or(item.router_data.request.get_email())?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
 
         let payment_information = get_samsung_pay_payment_information(&samsung_pay_data)
             .attach_printable("Failed to get samsung pay payment information")?;
@@ -2366,7 +2512,7 @@ impl TryFrom<&CybersourceRouterData<&PaymentsAuthorizeRouterData>> for Cybersour
                                         email,
                                     )?;
                                     let order_information =
-                                        OrderInformationWithBill::from((item, Some(bill_to)));
+                                        OrderInformationWithBill::try_from((item, Some(bill_to)))?;
                                     let processing_information =
                                         ProcessingInformation::try_from((
                                             item,
@@ -2589,7 +2735,7 @@ impl TryFrom<(&CybersourceRouterData<&PaymentsAuthorizeRouterData>, String)>
             .get_optional_billing_email()
             .or(item.router_data.request.get_optional_email())
             .and_then(|email| build_bill_to(item.router_data.get_optional_billing(), email).ok());
-        let order_information = OrderInformationWithBill::from((item, bill_to));
+        let order_information = OrderInformationWithBill::try_from((item, bill_to))?;
         let payment_information =
             PaymentInformation::MandatePayment(Box::new(MandatePaymentInformation {
                 payment_instrument,
@@ -2808,9 +2954,13 @@ impl TryFrom<&CybersourceRouterData<&PaymentsCaptureRouterData>>
             order_information: OrderInformationWithBill {
                 amount_details: Amount {
                     total_amount: item.amount.clone(),
+                    tax_amount: None,
                     currency: item.router_data.request.currency,
+                    foreign_amount_details: None,
                 },
                 bill_to: None,
+                line_items: None,
+                invoice_details: None,
             },
             client_reference_information: ClientReferenceInformation {
                 code: Some(item.router_data.connector_request_reference_id.clone()),
@@ -2899,11 +3049,13 @@ impl TryFrom<&CybersourceRouterData<&PaymentsCancelRouterData>> for CybersourceV
             reversal_information: ReversalInformation {
                 amount_details: Amount {
                     total_amount: value.amount.to_owned(),
+                    tax_amount: None,
                     currency: value.router_data.request.currency.ok_or(
                         errors::ConnectorError::MissingRequiredField {
                             field_name: "Currency",
                         },
                     )?,
+                    foreign_amount_details: None,
                 },
                 reason: value
                     .router_data
@@ -3447,11 +3599,13 @@ impl TryFrom<&CybersourceRouterData<&PaymentsPreProcessingRouterData>>
 
         let amount_details = Amount {
             total_amount: item.amount.clone(),
+            tax_amount: None,
             currency: item.router_data.request.currency.ok_or(
                 errors::ConnectorError::MissingRequiredField {
                     field_name: "currency",
                 },
             )?,
+            foreign_amount_details: None,
         };
 
         match redirect_response.params {
@@ -3473,6 +3627,8 @@ impl TryFrom<&CybersourceRouterData<&PaymentsPreProcessingRouterData>>
                 let order_information = OrderInformationWithBill {
                     amount_details,
                     bill_to: Some(bill_to),
+                    line_items: None,
+                    invoice_details: None,
                 };
                 Ok(Self::AuthEnrollment(Box::new(
                     CybersourceAuthEnrollmentRequest {
@@ -3588,11 +3744,13 @@ impl TryFrom<&CybersourceRouterData<&PaymentsAuthenticateRouterData>>
 
         let amount_details = Amount {
             total_amount: item.amount.clone(),
+            tax_amount: None,
             currency: item.router_data.request.currency.ok_or(
                 errors::ConnectorError::MissingRequiredField {
                     field_name: "currency",
                 },
             )?,
+            foreign_amount_details: None,
         };
 
         let param = redirect_response.params.ok_or(
@@ -3620,6 +3778,8 @@ impl TryFrom<&CybersourceRouterData<&PaymentsAuthenticateRouterData>>
         let order_information = OrderInformationWithBill {
             amount_details,
             bill_to: Some(bill_to),
+            line_items: None,
+            invoice_details: None,
         };
         Ok(Self {
             payment_information,
@@ -3709,11 +3869,13 @@ impl TryFrom<&CybersourceRouterData<&PaymentsPostAuthenticateRouterData>>
 
         let amount_details = Amount {
             total_amount: item.amount.clone(),
+            tax_amount: None,
             currency: item.router_data.request.currency.ok_or(
                 errors::ConnectorError::MissingRequiredField {
                     field_name: "currency",
                 },
             )?,
+            foreign_amount_details: None,
         };
 
         let redirect_payload: CybersourceRedirectionAuthResponse = redirect_response
@@ -4703,7 +4865,9 @@ impl<F> TryFrom<&CybersourceRouterData<&RefundsRouterData<F>>> for CybersourceRe
             order_information: OrderInformation {
                 amount_details: Amount {
                     total_amount: item.amount.clone(),
+                    tax_amount: None,
                     currency: item.router_data.request.currency,
+                    foreign_amount_details: None,
                 },
             },
             client_reference_information: ClientReferenceInformation {
@@ -4935,7 +5099,9 @@ impl TryFrom<&CybersourceRouterData<&PayoutsRouterData<PoFulfill>>>
                 let order_information = OrderInformation {
                     amount_details: Amount {
                         total_amount: item.amount.to_owned(),
+                        tax_amount: None,
                         currency: item.router_data.request.destination_currency,
+                        foreign_amount_details: None,
                     },
                 };
 
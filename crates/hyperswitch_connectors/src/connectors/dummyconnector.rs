@@ -9,7 +9,7 @@ use common_utils::{
     request::{Method, Request, RequestBuilder, RequestContent},
     types::{AmountConvertor, MinorUnit, MinorUnitForConnector},
 };
-use error_stack::{report, ResultExt};
+use error_stack::ResultExt;
 use hyperswitch_domain_models::{
     router_data::{AccessToken, ConnectorAuthType, ErrorResponse, RouterData},
     router_flow_types::{
@@ -631,23 +631,39 @@ impl<const T: u8> ConnectorIntegration<RSync, RefundsData, RefundsResponseData>
 impl<const T: u8> IncomingWebhook for DummyConnector<T> {
     fn get_webhook_object_reference_id(
         &self,
-        _request: &IncomingWebhookRequestDetails<'_>,
+        request: &IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<ObjectReferenceId, ConnectorError> {
-        Err(report!(ConnectorError::WebhooksNotImplemented))
+        let webhook: transformers::DummyConnectorWebhook = request
+            .body
+            .parse_struct("DummyConnectorWebhook")
+            .change_context(ConnectorError::WebhookReferenceIdNotFound)?;
+        Ok(ObjectReferenceId::PaymentId(
+            api_models::payments::PaymentIdType::ConnectorTransactionId(
+                webhook.data.get_id().to_owned(),
+            ),
+        ))
     }
 
     fn get_webhook_event_type(
         &self,
-        _request: &IncomingWebhookRequestDetails<'_>,
+        request: &IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<IncomingWebhookEvent, ConnectorError> {
-        Ok(IncomingWebhookEvent::EventNotSupported)
+        let webhook: transformers::DummyConnectorWebhook = request
+            .body
+            .parse_struct("DummyConnectorWebhook")
+            .change_context(ConnectorError::WebhookEventTypeNotFound)?;
+        Ok(IncomingWebhookEvent::from(webhook.event_type))
     }
 
     fn get_webhook_resource_object(
         &self,
-        _request: &IncomingWebhookRequestDetails<'_>,
+        request: &IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<Box<dyn masking::ErasedMaskSerialize>, ConnectorError> {
-        Err(report!(ConnectorError::WebhooksNotImplemented))
+        let webhook: transformers::DummyConnectorWebhook = request
+            .body
+            .parse_struct("DummyConnectorWebhook")
+            .change_context(ConnectorError::WebhookResourceObjectNotFound)?;
+        Ok(Box::new(webhook.data))
     }
 }
 
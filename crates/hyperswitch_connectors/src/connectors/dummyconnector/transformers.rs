@@ -1,10 +1,12 @@
+use api_models::webhooks::IncomingWebhookEvent;
 use common_enums::{AttemptStatus, Currency, RefundStatus};
 use common_utils::{pii, request::Method, types::MinorUnit};
+use error_stack::ResultExt;
 use hyperswitch_domain_models::{
     payment_method_data::{
         Card, PayLaterData, PaymentMethodData, UpiCollectData, UpiData, WalletData,
     },
-    router_data::{ConnectorAuthType, RouterData},
+    router_data::{ConnectorAuthType, ErrorResponse, RouterData},
     router_flow_types::{Execute, RSync},
     router_request_types::ResponseId,
     router_response_types::{PaymentsResponseData, RedirectForm, RefundsResponseData},
@@ -52,6 +54,7 @@ pub enum DummyConnectors {
     AdyenTest,
     CheckoutTest,
     PaypalTest,
+    DummyAdvanced,
 }
 
 impl DummyConnectors {
@@ -64,6 +67,7 @@ impl DummyConnectors {
             Self::AdyenTest => "adyen_test",
             Self::CheckoutTest => "checkout_test",
             Self::PaypalTest => "paypal_test",
+            Self::DummyAdvanced => "dummy_advanced",
         }
     }
 }
@@ -78,6 +82,7 @@ impl From<u8> for DummyConnectors {
             5 => Self::AdyenTest,
             6 => Self::CheckoutTest,
             7 => Self::PaypalTest,
+            9 => Self::DummyAdvanced,
             _ => Self::PhonyPay,
         }
     }
@@ -90,6 +95,49 @@ pub struct DummyConnectorPaymentsRequest<const T: u8> {
     payment_method_data: DummyPaymentMethodData,
     return_url: Option<String>,
     connector: DummyConnectors,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    simulation: Option<DummyConnectorSimulation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    webhook_url: Option<String>,
+}
+
+/// Key of the payment metadata holding the outcome the `dummy_advanced` connector has to simulate
+const SIMULATION_METADATA_KEY: &str = "simulation";
+
+/// Outcome of a payment simulated by the `dummy_advanced` connector
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct DummyConnectorSimulation {
+    /// Decline the payment with this error code
+    decline_code: Option<String>,
+    /// Message of the error the payment is declined with
+    decline_message: Option<String>,
+    /// Time taken by the connector to respond
+    latency_in_ms: Option<u64>,
+    /// Require the customer to complete a 3DS challenge
+    #[serde(default)]
+    three_ds_challenge: bool,
+    /// Capture only this amount of the payment
+    captured_amount: Option<MinorUnit>,
+    /// Webhooks sent for the payment, in order
+    #[serde(default)]
+    webhook_events: Vec<DummyConnectorWebhookEventType>,
+    /// Time between two webhooks sent for the payment
+    webhook_interval_in_ms: Option<u64>,
+}
+
+impl DummyConnectorSimulation {
+    fn from_metadata(
+        metadata: Option<&serde_json::Value>,
+    ) -> Result<Option<Self>, error_stack::Report<ConnectorError>> {
+        metadata
+            .and_then(|metadata| metadata.get(SIMULATION_METADATA_KEY))
+            .map(|simulation| serde_json::from_value(simulation.clone()))
+            .transpose()
+            .change_context(ConnectorError::InvalidDataFormat {
+                field_name: "metadata.simulation",
+            })
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -222,12 +270,26 @@ impl<const T: u8> TryFrom<&DummyConnectorRouterData<&PaymentsAuthorizeRouterData
                 ),
                 _ => Err(ConnectorError::NotImplemented("Payment methods".to_string()).into()),
             };
+        let connector = Into::<DummyConnectors>::into(T);
+        // Only the dummy_advanced connector can be told how the payment has to be processed
+        let simulation = match connector {
+            DummyConnectors::DummyAdvanced => {
+                DummyConnectorSimulation::from_metadata(item.router_data.request.metadata.as_ref())?
+            }
+            _ => None,
+        };
+        let webhook_url = simulation
+            .as_ref()
+            .filter(|simulation| !simulation.webhook_events.is_empty())
+            .and(item.router_data.request.webhook_url.clone());
         Ok(Self {
             amount: item.router_data.request.minor_amount,
             currency: item.router_data.request.currency,
             payment_method_data: payment_method_data?,
             return_url: item.router_data.request.router_return_url.clone(),
-            connector: Into::<DummyConnectors>::into(T),
+            connector,
+            simulation,
+            webhook_url,
         })
     }
 }
@@ -278,6 +340,10 @@ pub struct PaymentsResponse {
     created: String,
     payment_method_type: PaymentMethodType,
     next_action: Option<DummyConnectorNextAction>,
+    #[serde(default)]
+    amount_captured: Option<MinorUnit>,
+    #[serde(default)]
+    error: Option<ErrorData>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -301,13 +367,52 @@ impl<F, T> TryFrom<ResponseRouterData<F, PaymentsResponse, T, PaymentsResponseDa
     fn try_from(
         item: ResponseRouterData<F, PaymentsResponse, T, PaymentsResponseData>,
     ) -> Result<Self, Self::Error> {
+        if let Some(error) = item.response.error {
+            return Ok(Self {
+                status: AttemptStatus::Failure,
+                response: Err(ErrorResponse {
+                    code: error.code,
+                    message: error.message,
+                    reason: error.reason,
+                    status_code: item.http_code,
+                    attempt_status: Some(AttemptStatus::Failure),
+                    connector_transaction_id: Some(item.response.id),
+                    network_advice_code: None,
+                    network_decline_code: None,
+                    network_error_message: None,
+                    connector_metadata: None,
+                }),
+                ..item.data
+            });
+        }
+
         let redirection_data = item
             .response
             .next_action
             .and_then(|redirection_data| redirection_data.get_url())
             .map(|redirection_url| RedirectForm::from((redirection_url, Method::Get)));
+        let status = match AttemptStatus::from(item.response.status) {
+            AttemptStatus::Charged
+                if item
+                    .response
+                    .amount_captured
+                    .is_some_and(|amount_captured| amount_captured < item.response.amount) =>
+            {
+                AttemptStatus::PartialCharged
+            }
+            status => status,
+        };
         Ok(Self {
-            status: AttemptStatus::from(item.response.status),
+            status,
+            amount_captured: item
+                .response
+                .amount_captured
+                .map(|amount_captured| amount_captured.get_amount_as_i64())
+                .or(item.data.amount_captured),
+            minor_amount_captured: item
+                .response
+                .amount_captured
+                .or(item.data.minor_amount_captured),
             response: Ok(PaymentsResponseData::TransactionResponse {
                 resource_id: ResponseId::ConnectorTransactionId(item.response.id),
                 redirection_data: Box::new(redirection_data),
@@ -323,6 +428,36 @@ impl<F, T> TryFrom<ResponseRouterData<F, PaymentsResponse, T, PaymentsResponseDa
     }
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorWebhookEventType {
+    PaymentProcessing,
+    PaymentSucceeded,
+    PaymentFailed,
+}
+
+impl From<DummyConnectorWebhookEventType> for IncomingWebhookEvent {
+    fn from(event_type: DummyConnectorWebhookEventType) -> Self {
+        match event_type {
+            DummyConnectorWebhookEventType::PaymentProcessing => Self::PaymentIntentProcessing,
+            DummyConnectorWebhookEventType::PaymentSucceeded => Self::PaymentIntentSuccess,
+            DummyConnectorWebhookEventType::PaymentFailed => Self::PaymentIntentFailure,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DummyConnectorWebhook {
+    pub event_type: DummyConnectorWebhookEventType,
+    pub data: PaymentsResponse,
+}
+
+impl PaymentsResponse {
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub enum DummyConnectorNextAction {
@@ -423,7 +558,7 @@ pub struct DummyConnectorErrorResponse {
     pub error: ErrorData,
 }
 
-#[derive(Default, Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Default, Debug, Serialize, Deserialize, PartialEq)]
 pub struct ErrorData {
     pub code: String,
     pub message: String,
@@ -0,0 +1,722 @@
+pub mod transformers;
+
+use std::sync::LazyLock;
+
+use base64::Engine;
+use common_enums::enums;
+use common_utils::{
+    consts::BASE64_ENGINE,
+    errors::CustomResult,
+    ext_traits::BytesExt,
+    request::{Method, Request, RequestBuilder, RequestContent},
+    types::{
+        AmountConvertor, MinorUnit, MinorUnitForConnector, StringMajorUnit,
+        StringMajorUnitForConnector,
+    },
+};
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::{
+    router_data::{AccessToken, ErrorResponse, RouterData},
+    router_flow_types::{
+        access_token_auth::AccessTokenAuth,
+        payments::{Authorize, Capture, PSync, PaymentMethodToken, Session, SetupMandate, Void},
+        refunds::{Execute, RSync},
+    },
+    router_request_types::{
+        AccessTokenRequestData, PaymentMethodTokenizationData, PaymentsAuthorizeData,
+        PaymentsCancelData, PaymentsCaptureData, PaymentsSessionData, PaymentsSyncData,
+        RefundsData, SetupMandateRequestData,
+    },
+    router_response_types::{
+        ConnectorInfo, PaymentMethodDetails, PaymentsResponseData, RefundsResponseData,
+        SupportedPaymentMethods, SupportedPaymentMethodsExt,
+    },
+    types::{
+        PaymentsAuthorizeRouterData, PaymentsSyncRouterData, RefundSyncRouterData,
+        RefundsRouterData,
+    },
+};
+use hyperswitch_interfaces::{
+    api::{
+        self, ConnectorCommon, ConnectorCommonExt, ConnectorIntegration, ConnectorSpecifications,
+        ConnectorValidation,
+    },
+    configs::Connectors,
+    errors,
+    events::connector_api_logs::ConnectorEvent,
+    types::{self, Response},
+    webhooks,
+};
+use masking::{Mask, PeekInterface};
+use transformers as genericrest;
+
+use crate::{
+    constants::headers,
+    types::ResponseRouterData,
+    utils::{self, PaymentsSyncRequestData, RefundsRequestData},
+};
+
+/// Connector for processors with a simple REST API, whose requests and responses are mapped
+/// using a template configured in the metadata of the merchant connector account instead of code
+#[derive(Clone)]
+pub struct Genericrest {
+    amount_converter: &'static (dyn AmountConvertor<Output = MinorUnit> + Sync),
+    amount_converter_base_unit: &'static (dyn AmountConvertor<Output = StringMajorUnit> + Sync),
+}
+
+impl Genericrest {
+    pub fn new() -> &'static Self {
+        &Self {
+            amount_converter: &MinorUnitForConnector,
+            amount_converter_base_unit: &StringMajorUnitForConnector,
+        }
+    }
+
+    fn build_url(
+        &self,
+        connectors: &Connectors,
+        config: &genericrest::GenericrestConfig,
+        path: &str,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        genericrest::build_endpoint_url(&connectors.genericrest, &config.endpoint_prefix, path)
+    }
+
+    fn convert_amount<T>(
+        &self,
+        router_data: T,
+        amount: MinorUnit,
+        currency: enums::Currency,
+    ) -> CustomResult<genericrest::GenericrestRouterData<T>, errors::ConnectorError> {
+        let amount_in_base_unit =
+            utils::convert_amount(self.amount_converter_base_unit, amount, currency)?;
+        let amount = utils::convert_amount(self.amount_converter, amount, currency)?;
+        Ok(genericrest::GenericrestRouterData::from((
+            amount,
+            amount_in_base_unit,
+            router_data,
+        )))
+    }
+
+    fn build_template_request<F, Req, Res>(
+        &self,
+        req: &RouterData<F, Req, Res>,
+        connectors: &Connectors,
+        flow_config: &genericrest::GenericrestFlowConfig,
+        url: String,
+        body: Option<RequestContent>,
+    ) -> CustomResult<Request, errors::ConnectorError>
+    where
+        Self: ConnectorIntegration<F, Req, Res>,
+    {
+        let request_builder = RequestBuilder::new()
+            .method(Method::from(flow_config.method))
+            .url(&url)
+            .attach_default_headers()
+            .headers(self.build_headers(req, connectors)?);
+        let request_builder = match body {
+            Some(body) if !matches!(flow_config.method, genericrest::GenericrestMethod::Get) => {
+                request_builder.set_body(body)
+            }
+            _ => request_builder,
+        };
+        Ok(request_builder.build())
+    }
+
+    fn parse_template_response(
+        &self,
+        res: &Response,
+        flow_config: &genericrest::GenericrestFlowConfig,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<genericrest::GenericrestResponse, errors::ConnectorError> {
+        let response: serde_json::Value = res
+            .response
+            .parse_struct("GenericrestResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&masking::Secret::new(response.clone())));
+        let response = genericrest::GenericrestResponse::from_value(&response, flow_config)?;
+        router_env::logger::info!(connector_response=?response);
+        Ok(response)
+    }
+}
+
+impl api::Payment for Genericrest {}
+impl api::PaymentSession for Genericrest {}
+impl api::ConnectorAccessToken for Genericrest {}
+impl api::MandateSetup for Genericrest {}
+impl api::PaymentAuthorize for Genericrest {}
+impl api::PaymentSync for Genericrest {}
+impl api::PaymentCapture for Genericrest {}
+impl api::PaymentVoid for Genericrest {}
+impl api::Refund for Genericrest {}
+impl api::RefundExecute for Genericrest {}
+impl api::RefundSync for Genericrest {}
+impl api::PaymentToken for Genericrest {}
+
+impl ConnectorIntegration<PaymentMethodToken, PaymentMethodTokenizationData, PaymentsResponseData>
+    for Genericrest
+{
+    // Not Implemented (R)
+}
+
+impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for Genericrest
+where
+    Self: ConnectorIntegration<Flow, Request, Response>,
+{
+    fn build_headers(
+        &self,
+        req: &RouterData<Flow, Request, Response>,
+        _connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        let config = genericrest::GenericrestConfig::try_from(&req.connector_meta_data)?;
+        let auth = genericrest::GenericrestAuthType::try_from(&req.connector_auth_type)
+            .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
+
+        let auth_header = match config.auth_style {
+            genericrest::GenericrestAuthStyle::Bearer => (
+                headers::AUTHORIZATION.to_string(),
+                format!("Bearer {}", auth.api_key.peek()).into_masked(),
+            ),
+            genericrest::GenericrestAuthStyle::Basic => {
+                let key1 = auth
+                    .key1
+                    .ok_or(errors::ConnectorError::FailedToObtainAuthType)?;
+                let encoded_api_key =
+                    BASE64_ENGINE.encode(format!("{}:{}", auth.api_key.peek(), key1.peek()));
+                (
+                    headers::AUTHORIZATION.to_string(),
+                    format!("Basic {encoded_api_key}").into_masked(),
+                )
+            }
+            genericrest::GenericrestAuthStyle::Header { header_name } => {
+                (header_name, auth.api_key.peek().to_owned().into_masked())
+            }
+        };
+
+        Ok(vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                self.get_content_type().to_string().into(),
+            ),
+            auth_header,
+        ])
+    }
+}
+
+impl ConnectorCommon for Genericrest {
+    fn id(&self) -> &'static str {
+        "genericrest"
+    }
+
+    fn get_currency_unit(&self) -> api::CurrencyUnit {
+        api::CurrencyUnit::Minor
+    }
+
+    fn common_get_content_type(&self) -> &'static str {
+        "application/json"
+    }
+
+    fn base_url<'a>(&self, connectors: &'a Connectors) -> &'a str {
+        connectors.genericrest.base_url.as_ref()
+    }
+
+    fn build_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        let (code, message) = genericrest::get_error_details(&res.response);
+
+        if let Some(event) = event_builder {
+            event.set_error(serde_json::json!({ "code": code, "message": message }));
+        }
+        router_env::logger::info!(connector_error_code=?code, connector_error_message=?message);
+
+        Ok(ErrorResponse {
+            status_code: res.status_code,
+            code,
+            message: message.clone(),
+            reason: Some(message),
+            attempt_status: None,
+            connector_transaction_id: None,
+            network_advice_code: None,
+            network_decline_code: None,
+            network_error_message: None,
+            connector_metadata: None,
+        })
+    }
+}
+
+impl ConnectorValidation for Genericrest {
+    fn validate_connector_against_payment_request(
+        &self,
+        capture_method: Option<enums::CaptureMethod>,
+        _payment_method: enums::PaymentMethod,
+        _pmt: Option<enums::PaymentMethodType>,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        // Payments are captured by the processor in the authorize request, as templates are not
+        // supported for the capture flow
+        let capture_method = capture_method.unwrap_or_default();
+        match capture_method {
+            enums::CaptureMethod::Automatic | enums::CaptureMethod::SequentialAutomatic => Ok(()),
+            enums::CaptureMethod::Manual
+            | enums::CaptureMethod::ManualMultiple
+            | enums::CaptureMethod::Scheduled => Err(errors::ConnectorError::NotSupported {
+                message: capture_method.to_string(),
+                connector: self.id(),
+            }
+            .into()),
+        }
+    }
+}
+
+impl ConnectorIntegration<Session, PaymentsSessionData, PaymentsResponseData> for Genericrest {}
+
+impl ConnectorIntegration<AccessTokenAuth, AccessTokenRequestData, AccessToken> for Genericrest {}
+
+impl ConnectorIntegration<SetupMandate, SetupMandateRequestData, PaymentsResponseData>
+    for Genericrest
+{
+}
+
+impl ConnectorIntegration<Authorize, PaymentsAuthorizeData, PaymentsResponseData> for Genericrest {
+    fn get_headers(
+        &self,
+        req: &PaymentsAuthorizeRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &PaymentsAuthorizeRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let config = genericrest::GenericrestConfig::try_from(&req.connector_meta_data)?;
+        self.build_url(connectors, &config, &config.authorize.get_path(None, None))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &PaymentsAuthorizeRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let connector_router_data =
+            self.convert_amount(req, req.request.minor_amount, req.request.currency)?;
+        let connector_req = genericrest::GenericrestRequest::try_from(&connector_router_data)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &PaymentsAuthorizeRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        let config = genericrest::GenericrestConfig::try_from(&req.connector_meta_data)?;
+        Ok(Some(self.build_template_request(
+            req,
+            connectors,
+            &config.authorize,
+            types::PaymentsAuthorizeType::get_url(self, req, connectors)?,
+            Some(types::PaymentsAuthorizeType::get_request_body(
+                self, req, connectors,
+            )?),
+        )?))
+    }
+
+    fn handle_response(
+        &self,
+        data: &PaymentsAuthorizeRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<PaymentsAuthorizeRouterData, errors::ConnectorError> {
+        let config = genericrest::GenericrestConfig::try_from(&data.connector_meta_data)?;
+        let response = self.parse_template_response(&res, &config.authorize, event_builder)?;
+        RouterData::try_from(ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+impl ConnectorIntegration<PSync, PaymentsSyncData, PaymentsResponseData> for Genericrest {
+    fn get_headers(
+        &self,
+        req: &PaymentsSyncRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &PaymentsSyncRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let config = genericrest::GenericrestConfig::try_from(&req.connector_meta_data)?;
+        let flow_config = genericrest::GenericrestConfig::get_flow_config(
+            config.payment_sync.as_ref(),
+            "Payment sync",
+        )?;
+        let connector_transaction_id = req.request.get_connector_transaction_id()?;
+        self.build_url(
+            connectors,
+            &config,
+            &flow_config.get_path(Some(&connector_transaction_id), None),
+        )
+    }
+
+    fn get_request_body(
+        &self,
+        req: &PaymentsSyncRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let config = genericrest::GenericrestConfig::try_from(&req.connector_meta_data)?;
+        let connector_req = genericrest::GenericrestConfig::get_flow_config(
+            config.payment_sync.as_ref(),
+            "Payment sync",
+        )?
+        .build_request_body(&genericrest::get_payment_sync_field_values(req)?)?;
+        Ok(RequestContent::Json(Box::new(
+            genericrest::GenericrestRequest::from(connector_req),
+        )))
+    }
+
+    fn build_request(
+        &self,
+        req: &PaymentsSyncRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        let config = genericrest::GenericrestConfig::try_from(&req.connector_meta_data)?;
+        let flow_config = genericrest::GenericrestConfig::get_flow_config(
+            config.payment_sync.as_ref(),
+            "Payment sync",
+        )?;
+        Ok(Some(self.build_template_request(
+            req,
+            connectors,
+            flow_config,
+            types::PaymentsSyncType::get_url(self, req, connectors)?,
+            Some(types::PaymentsSyncType::get_request_body(
+                self, req, connectors,
+            )?),
+        )?))
+    }
+
+    fn handle_response(
+        &self,
+        data: &PaymentsSyncRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<PaymentsSyncRouterData, errors::ConnectorError> {
+        let config = genericrest::GenericrestConfig::try_from(&data.connector_meta_data)?;
+        let flow_config = genericrest::GenericrestConfig::get_flow_config(
+            config.payment_sync.as_ref(),
+            "Payment sync",
+        )?;
+        let response = self.parse_template_response(&res, flow_config, event_builder)?;
+        RouterData::try_from(ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+impl ConnectorIntegration<Capture, PaymentsCaptureData, PaymentsResponseData> for Genericrest {}
+
+impl ConnectorIntegration<Void, PaymentsCancelData, PaymentsResponseData> for Genericrest {}
+
+impl ConnectorIntegration<Execute, RefundsData, RefundsResponseData> for Genericrest {
+    fn get_headers(
+        &self,
+        req: &RefundsRouterData<Execute>,
+        connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &RefundsRouterData<Execute>,
+        connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let config = genericrest::GenericrestConfig::try_from(&req.connector_meta_data)?;
+        let flow_config =
+            genericrest::GenericrestConfig::get_flow_config(config.refund.as_ref(), "Refunds")?;
+        self.build_url(
+            connectors,
+            &config,
+            &flow_config.get_path(Some(&req.request.connector_transaction_id), None),
+        )
+    }
+
+    fn get_request_body(
+        &self,
+        req: &RefundsRouterData<Execute>,
+        _connectors: &Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let connector_router_data =
+            self.convert_amount(req, req.request.minor_refund_amount, req.request.currency)?;
+        let connector_req = genericrest::GenericrestRequest::try_from(&connector_router_data)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &RefundsRouterData<Execute>,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        let config = genericrest::GenericrestConfig::try_from(&req.connector_meta_data)?;
+        let flow_config =
+            genericrest::GenericrestConfig::get_flow_config(config.refund.as_ref(), "Refunds")?;
+        Ok(Some(self.build_template_request(
+            req,
+            connectors,
+            flow_config,
+            types::RefundExecuteType::get_url(self, req, connectors)?,
+            Some(types::RefundExecuteType::get_request_body(
+                self, req, connectors,
+            )?),
+        )?))
+    }
+
+    fn handle_response(
+        &self,
+        data: &RefundsRouterData<Execute>,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<RefundsRouterData<Execute>, errors::ConnectorError> {
+        let config = genericrest::GenericrestConfig::try_from(&data.connector_meta_data)?;
+        let flow_config =
+            genericrest::GenericrestConfig::get_flow_config(config.refund.as_ref(), "Refunds")?;
+        let response = self.parse_template_response(&res, flow_config, event_builder)?;
+        RouterData::try_from(ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+impl ConnectorIntegration<RSync, RefundsData, RefundsResponseData> for Genericrest {
+    fn get_headers(
+        &self,
+        req: &RefundSyncRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &RefundSyncRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let config = genericrest::GenericrestConfig::try_from(&req.connector_meta_data)?;
+        let flow_config = genericrest::GenericrestConfig::get_flow_config(
+            config.refund_sync.as_ref(),
+            "Refund sync",
+        )?;
+        let connector_refund_id = req.request.get_connector_refund_id()?;
+        self.build_url(
+            connectors,
+            &config,
+            &flow_config.get_path(
+                Some(&req.request.connector_transaction_id),
+                Some(&connector_refund_id),
+            ),
+        )
+    }
+
+    fn get_request_body(
+        &self,
+        req: &RefundSyncRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let config = genericrest::GenericrestConfig::try_from(&req.connector_meta_data)?;
+        let connector_req = genericrest::GenericrestConfig::get_flow_config(
+            config.refund_sync.as_ref(),
+            "Refund sync",
+        )?
+        .build_request_body(&genericrest::get_refund_sync_field_values(req)?)?;
+        Ok(RequestContent::Json(Box::new(
+            genericrest::GenericrestRequest::from(connector_req),
+        )))
+    }
+
+    fn build_request(
+        &self,
+        req: &RefundSyncRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        let config = genericrest::GenericrestConfig::try_from(&req.connector_meta_data)?;
+        let flow_config = genericrest::GenericrestConfig::get_flow_config(
+            config.refund_sync.as_ref(),
+            "Refund sync",
+        )?;
+        Ok(Some(self.build_template_request(
+            req,
+            connectors,
+            flow_config,
+            types::RefundSyncType::get_url(self, req, connectors)?,
+            Some(types::RefundSyncType::get_request_body(
+                self, req, connectors,
+            )?),
+        )?))
+    }
+
+    fn handle_response(
+        &self,
+        data: &RefundSyncRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<RefundSyncRouterData, errors::ConnectorError> {
+        let config = genericrest::GenericrestConfig::try_from(&data.connector_meta_data)?;
+        let flow_config = genericrest::GenericrestConfig::get_flow_config(
+            config.refund_sync.as_ref(),
+            "Refund sync",
+        )?;
+        let response = self.parse_template_response(&res, flow_config, event_builder)?;
+        RouterData::try_from(ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+#[async_trait::async_trait]
+impl webhooks::IncomingWebhook for Genericrest {
+    fn get_webhook_object_reference_id(
+        &self,
+        _request: &webhooks::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api_models::webhooks::ObjectReferenceId, errors::ConnectorError> {
+        Err(report!(errors::ConnectorError::WebhooksNotImplemented))
+    }
+
+    fn get_webhook_event_type(
+        &self,
+        _request: &webhooks::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api_models::webhooks::IncomingWebhookEvent, errors::ConnectorError> {
+        Err(report!(errors::ConnectorError::WebhooksNotImplemented))
+    }
+
+    fn get_webhook_resource_object(
+        &self,
+        _request: &webhooks::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Box<dyn masking::ErasedMaskSerialize>, errors::ConnectorError> {
+        Err(report!(errors::ConnectorError::WebhooksNotImplemented))
+    }
+}
+
+static GENERICREST_SUPPORTED_PAYMENT_METHODS: LazyLock<SupportedPaymentMethods> =
+    LazyLock::new(|| {
+        let supported_capture_methods = vec![
+            enums::CaptureMethod::Automatic,
+            enums::CaptureMethod::SequentialAutomatic,
+        ];
+
+        let mut genericrest_supported_payment_methods = SupportedPaymentMethods::new();
+
+        for payment_method_type in [
+            enums::PaymentMethodType::Credit,
+            enums::PaymentMethodType::Debit,
+        ] {
+            genericrest_supported_payment_methods.add(
+                enums::PaymentMethod::Card,
+                payment_method_type,
+                PaymentMethodDetails {
+                    mandates: enums::FeatureStatus::NotSupported,
+                    refunds: enums::FeatureStatus::Supported,
+                    supported_capture_methods: supported_capture_methods.clone(),
+                    specific_features: Some(
+                        api_models::feature_matrix::PaymentMethodSpecificFeatures::Card(
+                            api_models::feature_matrix::CardSpecificFeatures {
+                                three_ds: common_enums::FeatureStatus::NotSupported,
+                                no_three_ds: common_enums::FeatureStatus::Supported,
+                                supported_card_networks: vec![
+                                    common_enums::CardNetwork::Mastercard,
+                                    common_enums::CardNetwork::Visa,
+                                ],
+                            },
+                        ),
+                    ),
+                },
+            );
+        }
+
+        genericrest_supported_payment_methods
+    });
+
+static GENERICREST_CONNECTOR_INFO: ConnectorInfo = ConnectorInfo {
+    display_name: "Generic REST",
+    description: "Connector for processors with a simple REST API, whose requests and responses are mapped using a template configured in the metadata of the merchant connector account",
+    connector_type: common_enums::HyperswitchConnectorCategory::PaymentGateway,
+    integration_status: common_enums::ConnectorIntegrationStatus::Alpha,
+};
+
+static GENERICREST_SUPPORTED_WEBHOOK_FLOWS: [enums::EventClass; 0] = [];
+
+impl ConnectorSpecifications for Genericrest {
+    fn get_connector_about(&self) -> Option<&'static ConnectorInfo> {
+        Some(&GENERICREST_CONNECTOR_INFO)
+    }
+
+    fn get_supported_payment_methods(&self) -> Option<&'static SupportedPaymentMethods> {
+        Some(&*GENERICREST_SUPPORTED_PAYMENT_METHODS)
+    }
+
+    fn get_supported_webhook_flows(&self) -> Option<&'static [enums::EventClass]> {
+        Some(&GENERICREST_SUPPORTED_WEBHOOK_FLOWS)
+    }
+}
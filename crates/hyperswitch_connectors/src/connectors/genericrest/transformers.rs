@@ -0,0 +1,844 @@
+use std::collections::{BTreeMap, HashMap};
+
+use common_enums::enums;
+use common_utils::{
+    pii,
+    types::{MinorUnit, StringMajorUnit},
+};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::{
+    payment_method_data::PaymentMethodData,
+    router_data::{ConnectorAuthType, ErrorResponse, RouterData},
+    router_flow_types::refunds::{Execute, RSync},
+    router_request_types::ResponseId,
+    router_response_types::{PaymentsResponseData, RefundsResponseData},
+    types::{
+        PaymentsAuthorizeRouterData, PaymentsSyncRouterData, RefundSyncRouterData,
+        RefundsRouterData,
+    },
+};
+use hyperswitch_interfaces::{consts, errors};
+use masking::{ExposeInterface, Secret};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    types::{RefundsResponseRouterData, ResponseRouterData},
+    utils::{
+        self, CardData, PaymentsAuthorizeRequestData, PaymentsSyncRequestData, RefundsRequestData,
+        RouterData as _,
+    },
+};
+
+pub struct GenericrestRouterData<T> {
+    pub amount: MinorUnit,
+    pub amount_in_base_unit: StringMajorUnit,
+    pub router_data: T,
+}
+
+impl<T> From<(MinorUnit, StringMajorUnit, T)> for GenericrestRouterData<T> {
+    fn from((amount, amount_in_base_unit, router_data): (MinorUnit, StringMajorUnit, T)) -> Self {
+        Self {
+            amount,
+            amount_in_base_unit,
+            router_data,
+        }
+    }
+}
+
+/// Declarative definition of the API of the processor, configured in the metadata of the merchant
+/// connector account
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GenericrestConfig {
+    /// Host of the processor, substituted for `{{merchant_endpoint_prefix}}` in the base URL of
+    /// the connector. It must be one of the hosts allowed for the connector in the configuration.
+    pub endpoint_prefix: String,
+    #[serde(default)]
+    pub auth_style: GenericrestAuthStyle,
+    pub authorize: GenericrestFlowConfig,
+    pub payment_sync: Option<GenericrestFlowConfig>,
+    pub refund: Option<GenericrestFlowConfig>,
+    pub refund_sync: Option<GenericrestFlowConfig>,
+}
+
+impl TryFrom<&Option<pii::SecretSerdeValue>> for GenericrestConfig {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(connector_metadata: &Option<pii::SecretSerdeValue>) -> Result<Self, Self::Error> {
+        utils::to_connector_meta_from_secret::<Self>(connector_metadata.clone()).change_context(
+            errors::ConnectorError::InvalidConnectorConfig {
+                config: "metadata. Required fields: endpoint_prefix, authorize",
+            },
+        )
+    }
+}
+
+impl GenericrestConfig {
+    pub fn get_flow_config(
+        flow_config: Option<&GenericrestFlowConfig>,
+        flow_name: &str,
+    ) -> Result<&GenericrestFlowConfig, error_stack::Report<errors::ConnectorError>> {
+        flow_config.ok_or_else(|| {
+            errors::ConnectorError::NotSupported {
+                message: format!("{flow_name} without a configured template"),
+                connector: "Genericrest",
+            }
+            .into()
+        })
+    }
+}
+
+/// How the API key of the merchant connector account is sent to the processor
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GenericrestAuthStyle {
+    /// `Authorization: Bearer <api_key>`
+    #[default]
+    Bearer,
+    /// `Authorization: Basic <base64(api_key:key1)>`
+    Basic,
+    /// `<header_name>: <api_key>`
+    Header { header_name: String },
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum GenericrestMethod {
+    Get,
+    #[default]
+    Post,
+    Put,
+    Patch,
+}
+
+impl From<GenericrestMethod> for common_utils::request::Method {
+    fn from(method: GenericrestMethod) -> Self {
+        match method {
+            GenericrestMethod::Get => Self::Get,
+            GenericrestMethod::Post => Self::Post,
+            GenericrestMethod::Put => Self::Put,
+            GenericrestMethod::Patch => Self::Patch,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GenericrestFlowConfig {
+    #[serde(default)]
+    pub method: GenericrestMethod,
+    /// Appended to the endpoint, with `{connector_transaction_id}` and `{connector_refund_id}`
+    /// substituted
+    pub path: String,
+    /// Fields of the JSON request body, keyed by their dot separated path in the body
+    #[serde(default)]
+    pub request_fields: BTreeMap<String, GenericrestFieldValue>,
+    pub response_fields: GenericrestResponseFields,
+    /// Statuses of the processor and the outcome they correspond to. A response with a status
+    /// which is not listed fails to be handled.
+    pub status_mapping: HashMap<String, GenericrestStatus>,
+}
+
+/// Builds the URL of a request from the endpoint and the path configured by the merchant. The
+/// endpoint must be one of the hosts allowed in the configuration, and the path must not be able
+/// to move the request to any other host, as the requests carry raw card details.
+pub fn build_endpoint_url(
+    params: &hyperswitch_domain_models::connector_endpoints::ConnectorParamsWithAllowedHosts,
+    endpoint_prefix: &str,
+    path: &str,
+) -> Result<String, error_stack::Report<errors::ConnectorError>> {
+    if !params.is_host_allowed(endpoint_prefix) {
+        return Err(
+            error_stack::report!(errors::ConnectorError::InvalidConnectorConfig {
+                config: "metadata.endpoint_prefix",
+            })
+            .attach_printable(format!(
+                "Endpoint `{endpoint_prefix}` is not an allowed host of the connector"
+            )),
+        );
+    }
+
+    let invalid_path_error = || {
+        error_stack::report!(errors::ConnectorError::InvalidConnectorConfig {
+            config: "metadata.path",
+        })
+        .attach_printable(format!("Path `{path}` is not a valid path of the endpoint"))
+    };
+
+    if !path.starts_with('/') || path.contains(['@', '\\', '?', '#']) {
+        return Err(invalid_path_error());
+    }
+
+    let url = format!(
+        "{}{path}",
+        params
+            .base_url
+            .replace("{{merchant_endpoint_prefix}}", endpoint_prefix)
+    );
+
+    // The allowed hosts are compared with the host the URL actually resolves to, in case the
+    // path or the substituted identifiers still manage to alter the authority of the URL
+    let parsed_url = url::Url::parse(&url).map_err(|_| invalid_path_error())?;
+    let host = match (parsed_url.host_str(), parsed_url.port()) {
+        (Some(host), Some(port)) => format!("{host}:{port}"),
+        (Some(host), None) => host.to_string(),
+        (None, _) => return Err(invalid_path_error()),
+    };
+    if !parsed_url.username().is_empty()
+        || parsed_url.password().is_some()
+        || !params.is_host_allowed(&host)
+    {
+        return Err(invalid_path_error());
+    }
+
+    Ok(url)
+}
+
+impl GenericrestFlowConfig {
+    pub fn get_path(
+        &self,
+        connector_transaction_id: Option<&str>,
+        connector_refund_id: Option<&str>,
+    ) -> String {
+        let path = match connector_transaction_id {
+            Some(id) => self.path.replace("{connector_transaction_id}", id),
+            None => self.path.clone(),
+        };
+        match connector_refund_id {
+            Some(id) => path.replace("{connector_refund_id}", id),
+            None => path,
+        }
+    }
+
+    /// Builds the request body from the configured fields, failing if a field is not available
+    /// for the payment
+    pub fn build_request_body(
+        &self,
+        field_values: &HashMap<GenericrestField, Value>,
+    ) -> Result<Value, error_stack::Report<errors::ConnectorError>> {
+        let mut body = Value::Object(serde_json::Map::new());
+        for (path, field_value) in &self.request_fields {
+            let value = match field_value {
+                GenericrestFieldValue::Field(field) => field_values
+                    .get(field)
+                    .cloned()
+                    .ok_or_else(utils::missing_field_err(field.into()))?,
+                GenericrestFieldValue::Constant(value) => value.clone(),
+            };
+            insert_at_path(&mut body, path, value)?;
+        }
+        Ok(body)
+    }
+}
+
+/// Value of a field of the request body
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GenericrestFieldValue {
+    /// Value of the payment or refund
+    Field(GenericrestField),
+    /// Value which is the same for every request
+    Constant(Value),
+}
+
+/// Values of the payment or refund which can be sent to the processor
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, Deserialize, strum::IntoStaticStr)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum GenericrestField {
+    /// Amount in the minor unit of the currency, as a number
+    Amount,
+    /// Amount in the base unit of the currency, as a string
+    AmountInBaseUnit,
+    Currency,
+    Reference,
+    Description,
+    Email,
+    ReturnUrl,
+    CardNumber,
+    CardExpiryMonth,
+    CardExpiryYear,
+    CardCvc,
+    CardHolderName,
+    ConnectorTransactionId,
+    RefundReference,
+}
+
+/// JSONPaths of the fields of the response, such as `$.data.id` or `$.errors[0].code`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GenericrestResponseFields {
+    /// Identifier of the payment or refund at the processor
+    pub id: Option<String>,
+    pub status: String,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GenericrestStatus {
+    Succeeded,
+    Authorized,
+    Pending,
+    Failed,
+}
+
+impl From<GenericrestStatus> for enums::AttemptStatus {
+    fn from(status: GenericrestStatus) -> Self {
+        match status {
+            GenericrestStatus::Succeeded => Self::Charged,
+            GenericrestStatus::Authorized => Self::Authorized,
+            GenericrestStatus::Pending => Self::Pending,
+            GenericrestStatus::Failed => Self::Failure,
+        }
+    }
+}
+
+impl From<GenericrestStatus> for enums::RefundStatus {
+    fn from(status: GenericrestStatus) -> Self {
+        match status {
+            GenericrestStatus::Succeeded => Self::Success,
+            GenericrestStatus::Authorized | GenericrestStatus::Pending => Self::Pending,
+            GenericrestStatus::Failed => Self::Failure,
+        }
+    }
+}
+
+pub struct GenericrestAuthType {
+    pub(super) api_key: Secret<String>,
+    pub(super) key1: Option<Secret<String>>,
+}
+
+impl TryFrom<&ConnectorAuthType> for GenericrestAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(auth_type: &ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            ConnectorAuthType::HeaderKey { api_key } => Ok(Self {
+                api_key: api_key.to_owned(),
+                key1: None,
+            }),
+            ConnectorAuthType::BodyKey { api_key, key1 } => Ok(Self {
+                api_key: api_key.to_owned(),
+                key1: Some(key1.to_owned()),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+/// JSON request body built from the template, which is masked as a whole since it can contain
+/// card details
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+pub struct GenericrestRequest(Secret<Value>);
+
+impl From<Value> for GenericrestRequest {
+    fn from(body: Value) -> Self {
+        Self(Secret::new(body))
+    }
+}
+
+impl TryFrom<&GenericrestRouterData<&PaymentsAuthorizeRouterData>> for GenericrestRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &GenericrestRouterData<&PaymentsAuthorizeRouterData>,
+    ) -> Result<Self, Self::Error> {
+        let router_data = item.router_data;
+        let config = GenericrestConfig::try_from(&router_data.connector_meta_data)?;
+
+        let mut field_values = HashMap::from([
+            (
+                GenericrestField::Amount,
+                Value::from(item.amount.get_amount_as_i64()),
+            ),
+            (
+                GenericrestField::AmountInBaseUnit,
+                Value::from(item.amount_in_base_unit.get_amount_as_string()),
+            ),
+            (
+                GenericrestField::Currency,
+                Value::from(router_data.request.currency.to_string()),
+            ),
+            (
+                GenericrestField::Reference,
+                Value::from(router_data.connector_request_reference_id.clone()),
+            ),
+        ]);
+        if let Some(description) = router_data.description.clone() {
+            field_values.insert(GenericrestField::Description, Value::from(description));
+        }
+        if let Some(email) = router_data.request.get_optional_email() {
+            field_values.insert(
+                GenericrestField::Email,
+                Value::from(email.expose().expose()),
+            );
+        }
+        if let Ok(return_url) = router_data.request.get_router_return_url() {
+            field_values.insert(GenericrestField::ReturnUrl, Value::from(return_url));
+        }
+
+        match &router_data.request.payment_method_data {
+            PaymentMethodData::Card(card) => {
+                field_values.extend([
+                    (
+                        GenericrestField::CardNumber,
+                        Value::from(card.card_number.get_card_no()),
+                    ),
+                    (
+                        GenericrestField::CardExpiryMonth,
+                        Value::from(card.get_card_expiry_month_2_digit()?.expose()),
+                    ),
+                    (
+                        GenericrestField::CardExpiryYear,
+                        Value::from(card.get_expiry_year_4_digit().expose()),
+                    ),
+                    (
+                        GenericrestField::CardCvc,
+                        Value::from(card.card_cvc.clone().expose()),
+                    ),
+                ]);
+                if let Some(card_holder_name) = card
+                    .card_holder_name
+                    .clone()
+                    .or_else(|| router_data.get_optional_billing_full_name())
+                {
+                    field_values.insert(
+                        GenericrestField::CardHolderName,
+                        Value::from(card_holder_name.expose()),
+                    );
+                }
+            }
+            _ => Err(errors::ConnectorError::NotImplemented(
+                utils::get_unimplemented_payment_method_error_message("Genericrest"),
+            ))?,
+        }
+
+        config
+            .authorize
+            .build_request_body(&field_values)
+            .map(Self::from)
+    }
+}
+
+impl TryFrom<&GenericrestRouterData<&RefundsRouterData<Execute>>> for GenericrestRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &GenericrestRouterData<&RefundsRouterData<Execute>>,
+    ) -> Result<Self, Self::Error> {
+        let router_data = item.router_data;
+        let config = GenericrestConfig::try_from(&router_data.connector_meta_data)?;
+        let field_values = HashMap::from([
+            (
+                GenericrestField::Amount,
+                Value::from(item.amount.get_amount_as_i64()),
+            ),
+            (
+                GenericrestField::AmountInBaseUnit,
+                Value::from(item.amount_in_base_unit.get_amount_as_string()),
+            ),
+            (
+                GenericrestField::Currency,
+                Value::from(router_data.request.currency.to_string()),
+            ),
+            (
+                GenericrestField::Reference,
+                Value::from(router_data.connector_request_reference_id.clone()),
+            ),
+            (
+                GenericrestField::ConnectorTransactionId,
+                Value::from(router_data.request.connector_transaction_id.clone()),
+            ),
+            (
+                GenericrestField::RefundReference,
+                Value::from(router_data.request.refund_id.clone()),
+            ),
+        ]);
+
+        GenericrestConfig::get_flow_config(config.refund.as_ref(), "Refunds")?
+            .build_request_body(&field_values)
+            .map(Self::from)
+    }
+}
+
+pub fn get_payment_sync_field_values(
+    req: &PaymentsSyncRouterData,
+) -> Result<HashMap<GenericrestField, Value>, error_stack::Report<errors::ConnectorError>> {
+    Ok(HashMap::from([
+        (
+            GenericrestField::ConnectorTransactionId,
+            Value::from(req.request.get_connector_transaction_id()?),
+        ),
+        (
+            GenericrestField::Reference,
+            Value::from(req.connector_request_reference_id.clone()),
+        ),
+    ]))
+}
+
+pub fn get_refund_sync_field_values(
+    req: &RefundSyncRouterData,
+) -> Result<HashMap<GenericrestField, Value>, error_stack::Report<errors::ConnectorError>> {
+    Ok(HashMap::from([
+        (
+            GenericrestField::ConnectorTransactionId,
+            Value::from(req.request.connector_transaction_id.clone()),
+        ),
+        (
+            GenericrestField::RefundReference,
+            Value::from(req.request.get_connector_refund_id()?),
+        ),
+    ]))
+}
+
+/// Fields extracted from the response of the processor using the template
+#[derive(Debug, Clone)]
+pub struct GenericrestResponse {
+    pub id: Option<String>,
+    pub status: GenericrestStatus,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
+
+impl GenericrestResponse {
+    pub fn from_value(
+        value: &Value,
+        flow_config: &GenericrestFlowConfig,
+    ) -> Result<Self, error_stack::Report<errors::ConnectorError>> {
+        let response_fields = &flow_config.response_fields;
+        let get_string = |path: &Option<String>| {
+            path.as_deref()
+                .and_then(|path| select_json_path(value, path))
+                .and_then(json_value_to_string)
+        };
+
+        // A status which is missing or not present in the status mapping is not assumed to be
+        // pending, as the outcome of the payment at the processor is unknown
+        let status = select_json_path(value, &response_fields.status)
+            .and_then(json_value_to_string)
+            .ok_or(errors::ConnectorError::MissingRequiredField {
+                field_name: "status",
+            })
+            .attach_printable_lazy(|| format!("Status not found at `{}`", response_fields.status))
+            .and_then(|status| {
+                flow_config
+                    .status_mapping
+                    .get(&status)
+                    .copied()
+                    .ok_or(errors::ConnectorError::ResponseHandlingFailed)
+                    .attach_printable_lazy(|| {
+                        format!("Status `{status}` not found in the status mapping")
+                    })
+            })?;
+
+        Ok(Self {
+            id: get_string(&response_fields.id),
+            status,
+            error_code: get_string(&response_fields.error_code),
+            error_message: get_string(&response_fields.error_message),
+        })
+    }
+
+    fn get_error_response(&self, status_code: u16) -> ErrorResponse {
+        ErrorResponse {
+            code: self
+                .error_code
+                .clone()
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: self
+                .error_message
+                .clone()
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: self.error_message.clone(),
+            status_code,
+            attempt_status: None,
+            connector_transaction_id: self.id.clone(),
+            network_advice_code: None,
+            network_decline_code: None,
+            network_error_message: None,
+            connector_metadata: None,
+        }
+    }
+}
+
+impl<F, T> TryFrom<ResponseRouterData<F, GenericrestResponse, T, PaymentsResponseData>>
+    for RouterData<F, T, PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: ResponseRouterData<F, GenericrestResponse, T, PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        let status = enums::AttemptStatus::from(item.response.status);
+        let response = if status == enums::AttemptStatus::Failure {
+            Err(item.response.get_error_response(item.http_code))
+        } else {
+            let resource_id = match item.response.id.clone() {
+                Some(id) => ResponseId::ConnectorTransactionId(id),
+                None => ResponseId::NoResponseId,
+            };
+            Ok(PaymentsResponseData::TransactionResponse {
+                resource_id,
+                redirection_data: Box::new(None),
+                mandate_reference: Box::new(None),
+                connector_metadata: None,
+                network_txn_id: None,
+                connector_response_reference_id: item.response.id,
+                incremental_authorization_allowed: None,
+                charges: None,
+            })
+        };
+        Ok(Self {
+            status,
+            response,
+            ..item.data
+        })
+    }
+}
+
+impl TryFrom<RefundsResponseRouterData<Execute, GenericrestResponse>>
+    for RefundsRouterData<Execute>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: RefundsResponseRouterData<Execute, GenericrestResponse>,
+    ) -> Result<Self, Self::Error> {
+        let connector_refund_id = item
+            .response
+            .id
+            .clone()
+            .ok_or(errors::ConnectorError::MissingConnectorRefundID)?;
+        Ok(Self {
+            response: get_refunds_response(item.response, connector_refund_id, item.http_code),
+            ..item.data
+        })
+    }
+}
+
+impl TryFrom<RefundsResponseRouterData<RSync, GenericrestResponse>> for RefundSyncRouterData {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: RefundsResponseRouterData<RSync, GenericrestResponse>,
+    ) -> Result<Self, Self::Error> {
+        let connector_refund_id = match item.response.id.clone() {
+            Some(id) => id,
+            None => item.data.request.get_connector_refund_id()?,
+        };
+        Ok(Self {
+            response: get_refunds_response(item.response, connector_refund_id, item.http_code),
+            ..item.data
+        })
+    }
+}
+
+fn get_refunds_response(
+    response: GenericrestResponse,
+    connector_refund_id: String,
+    status_code: u16,
+) -> Result<RefundsResponseData, ErrorResponse> {
+    let refund_status = enums::RefundStatus::from(response.status);
+    if refund_status == enums::RefundStatus::Failure {
+        Err(response.get_error_response(status_code))
+    } else {
+        Ok(RefundsResponseData {
+            connector_refund_id,
+            refund_status,
+        })
+    }
+}
+
+/// Extracts the error of a response with an unsuccessful status code, for which the template of
+/// the flow is not available, from the fields commonly used for it
+pub fn get_error_details(body: &[u8]) -> (String, String) {
+    let value = serde_json::from_slice::<Value>(body).unwrap_or(Value::Null);
+    let find_string = |paths: &[&str]| {
+        paths
+            .iter()
+            .find_map(|path| select_json_path(&value, path).and_then(json_value_to_string))
+    };
+    let code = find_string(&["$.error.code", "$.error_code", "$.code"])
+        .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string());
+    let message = find_string(&["$.error.message", "$.error_message", "$.message", "$.error"])
+        .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string());
+    (code, message)
+}
+
+/// Selects a value using a JSONPath consisting only of object keys and array indices, such as
+/// `$.data.transactions[0].id`
+fn select_json_path<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.strip_prefix('$')
+        .unwrap_or(path)
+        .split('.')
+        .filter(|segment| !segment.is_empty())
+        .try_fold(value, |value, segment| {
+            let mut parts = segment.split('[');
+            let key = parts.next().unwrap_or_default();
+            let value = if key.is_empty() {
+                value
+            } else {
+                value.get(key)?
+            };
+            parts.try_fold(value, |value, index| {
+                value.get(index.strip_suffix(']')?.parse::<usize>().ok()?)
+            })
+        })
+}
+
+fn json_value_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(string) => Some(string.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Bool(boolean) => Some(boolean.to_string()),
+        Value::Null | Value::Array(_) | Value::Object(_) => None,
+    }
+}
+
+fn insert_at_path(
+    body: &mut Value,
+    path: &str,
+    value: Value,
+) -> Result<(), error_stack::Report<errors::ConnectorError>> {
+    let invalid_path = || errors::ConnectorError::InvalidConnectorConfig {
+        config: "metadata.request_fields",
+    };
+    let mut keys = path.split('.').peekable();
+    let mut current = body;
+    while let Some(key) = keys.next() {
+        let object = current.as_object_mut().ok_or_else(invalid_path)?;
+        if keys.peek().is_none() {
+            object.insert(key.to_owned(), value);
+            return Ok(());
+        }
+        current = object
+            .entry(key.to_owned())
+            .or_insert_with(|| Value::Object(serde_json::Map::new()));
+    }
+    Err(invalid_path().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select_json_path() {
+        let value = serde_json::json!({
+            "data": {"id": "txn_1", "status": 2},
+            "errors": [{"code": "insufficient_funds"}]
+        });
+        assert_eq!(
+            select_json_path(&value, "$.data.id").and_then(json_value_to_string),
+            Some("txn_1".to_string())
+        );
+        assert_eq!(
+            select_json_path(&value, "$.data.status").and_then(json_value_to_string),
+            Some("2".to_string())
+        );
+        assert_eq!(
+            select_json_path(&value, "$.errors[0].code").and_then(json_value_to_string),
+            Some("insufficient_funds".to_string())
+        );
+        assert_eq!(select_json_path(&value, "$.errors[1].code"), None);
+    }
+
+    #[test]
+    fn test_build_request_body() {
+        let flow_config: GenericrestFlowConfig = serde_json::from_value(serde_json::json!({
+            "path": "/payments",
+            "request_fields": {
+                "amount.value": {"field": "amount"},
+                "amount.currency": {"field": "currency"},
+                "capture": {"constant": true}
+            },
+            "response_fields": {"id": "$.id", "status": "$.status"},
+            "status_mapping": {"APPROVED": "succeeded"}
+        }))
+        .expect("valid flow config");
+        let field_values = HashMap::from([
+            (GenericrestField::Amount, Value::from(1000)),
+            (GenericrestField::Currency, Value::from("USD")),
+        ]);
+        assert_eq!(
+            flow_config
+                .build_request_body(&field_values)
+                .expect("request body"),
+            serde_json::json!({
+                "amount": {"value": 1000, "currency": "USD"},
+                "capture": true
+            })
+        );
+
+        let response = GenericrestResponse::from_value(
+            &serde_json::json!({"id": "txn_1", "status": "APPROVED"}),
+            &flow_config,
+        )
+        .expect("mapped status");
+        assert_eq!(response.id, Some("txn_1".to_string()));
+        assert!(matches!(response.status, GenericrestStatus::Succeeded));
+        assert!(GenericrestResponse::from_value(
+            &serde_json::json!({"id": "txn_1", "status": "UNDER_REVIEW"}),
+            &flow_config,
+        )
+        .is_err());
+    }
+
+    fn get_params(
+    ) -> hyperswitch_domain_models::connector_endpoints::ConnectorParamsWithAllowedHosts {
+        hyperswitch_domain_models::connector_endpoints::ConnectorParamsWithAllowedHosts {
+            base_url: "https://{{merchant_endpoint_prefix}}".to_string(),
+            allowed_hosts: vec!["api.processor.com".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_build_endpoint_url() {
+        let params = get_params();
+        assert_eq!(
+            build_endpoint_url(&params, "api.processor.com", "/v1/payments/txn_1")
+                .expect("valid url"),
+            "https://api.processor.com/v1/payments/txn_1"
+        );
+        assert!(build_endpoint_url(&params, "evil.com", "/v1/payments").is_err());
+    }
+
+    #[test]
+    fn test_build_endpoint_url_rejects_paths_changing_the_host() {
+        let params = get_params();
+        for path in [
+            ".evil.com/x",
+            "@evil.com/x",
+            "evil.com/x",
+            ":443@evil.com/x",
+            "\\@evil.com/x",
+            "/x\\@evil.com",
+            "?@evil.com/x",
+            "#@evil.com/x",
+            "/v1/payments?redirect=https://evil.com",
+            "/v1/payments#fragment",
+            "",
+        ] {
+            assert!(
+                build_endpoint_url(&params, "api.processor.com", path).is_err(),
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_build_endpoint_url_rejects_substituted_ids_changing_the_host() {
+        let params = get_params();
+        let flow_config: GenericrestFlowConfig = serde_json::from_value(serde_json::json!({
+            "path": "{connector_transaction_id}/status",
+            "response_fields": {"id": "$.id", "status": "$.status"},
+            "status_mapping": {}
+        }))
+        .expect("valid flow config");
+
+        for connector_transaction_id in ["@evil.com", ".evil.com", "/txn_1?x=@evil.com"] {
+            let path = flow_config.get_path(Some(connector_transaction_id), None);
+            assert!(
+                build_endpoint_url(&params, "api.processor.com", &path).is_err(),
+                "{path}"
+            );
+        }
+    }
+}
@@ -2480,4 +2480,8 @@ impl ConnectorSpecifications for Paypal {
     fn get_supported_webhook_flows(&self) -> Option<&'static [enums::EventClass]> {
         Some(&PAYPAL_SUPPORTED_WEBHOOK_FLOWS)
     }
+
+    fn is_incremental_authorization_supported(&self) -> bool {
+        true
+    }
 }
@@ -282,6 +282,7 @@ impl TryFrom<enums::AttemptStatus> for RecurlyRecordStatus {
             | enums::AttemptStatus::AuthenticationSuccessful
             | enums::AttemptStatus::Authorized
             | enums::AttemptStatus::PartiallyAuthorized
+            | enums::AttemptStatus::IncrementalAuthorizationPending
             | enums::AttemptStatus::AuthorizationFailed
             | enums::AttemptStatus::Authorizing
             | enums::AttemptStatus::CodInitiated
@@ -219,7 +219,13 @@ impl TryFrom<&RiskifiedRouterData<&FrmCheckoutRouterData>> for RiskifiedPayments
                 updated_at: common_utils::date_time::now(),
                 gateway: payment_data.request.gateway.clone(),
                 total_price: payment.amount.clone(),
-                cart_token: payment_data.attempt_id.clone(),
+                // The session of the beacon of the merchant identifies the device, when sent
+                cart_token: payment_data
+                    .request
+                    .device_fingerprint
+                    .as_ref()
+                    .and_then(|device_fingerprint| device_fingerprint.session_id.clone())
+                    .unwrap_or_else(|| payment_data.attempt_id.clone()),
                 line_items,
                 source: Source::DesktopWeb,
                 billing_address: OrderAddress::try_from(billing_address).ok(),
@@ -135,6 +135,14 @@ pub struct SignifydPaymentsSaleRequest {
     purchase: Purchase,
     decision_delivery: DecisionDelivery,
     coverage_requests: Option<CoverageRequests>,
+    device: Option<Device>,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Device {
+    client_ip_address: Option<std::net::IpAddr>,
+    session_id: Option<String>,
 }
 
 #[derive(Debug, Serialize, Eq, PartialEq, Deserialize, Clone)]
@@ -224,6 +232,14 @@ impl TryFrom<&FrmSaleRouterData> for SignifydPaymentsSaleRequest {
             purchase,
             decision_delivery: DecisionDelivery::Sync, // Specify SYNC if you require the Response to contain a decision field. If you have registered for a webhook associated with this checkpoint, then the webhook will also be sent when SYNC is specified. If ASYNC_ONLY is specified, then the decision field in the response will be null, and you will require a Webhook integration to receive Signifyd's final decision
             coverage_requests: metadata.coverage_request,
+            device: item
+                .request
+                .device_fingerprint
+                .as_ref()
+                .map(|device_fingerprint| Device {
+                    client_ip_address: None,
+                    session_id: device_fingerprint.session_id.clone(),
+                }),
         })
     }
 }
@@ -378,6 +394,7 @@ pub struct SignifydPaymentsCheckoutRequest {
     order_id: String,
     purchase: Purchase,
     coverage_requests: Option<CoverageRequests>,
+    device: Option<Device>,
 }
 
 impl TryFrom<&FrmCheckoutRouterData> for SignifydPaymentsCheckoutRequest {
@@ -457,6 +474,18 @@ impl TryFrom<&FrmCheckoutRouterData> for SignifydPaymentsCheckoutRequest {
             order_id: item.attempt_id.clone(),
             purchase,
             coverage_requests: metadata.coverage_request,
+            device: item
+                .request
+                .device_fingerprint
+                .as_ref()
+                .map(|device_fingerprint| Device {
+                    client_ip_address: item
+                        .request
+                        .browser_info
+                        .as_ref()
+                        .and_then(|browser_info| browser_info.ip_address),
+                    session_id: device_fingerprint.session_id.clone(),
+                }),
         })
     }
 }
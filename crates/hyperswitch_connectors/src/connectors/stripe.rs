@@ -3301,4 +3301,12 @@ impl ConnectorSpecifications for Stripe {
     fn get_supported_webhook_flows(&self) -> Option<&'static [common_enums::EventClass]> {
         Some(&STRIPE_SUPPORTED_WEBHOOK_FLOWS)
     }
+
+    fn is_incremental_authorization_supported(&self) -> bool {
+        true
+    }
+
+    fn is_account_updater_supported(&self) -> bool {
+        true
+    }
 }
@@ -21,8 +21,8 @@ use hyperswitch_domain_models::{
         PayLaterData, PaymentMethodData, VoucherData, WalletData,
     },
     router_data::{
-        AdditionalPaymentMethodConnectorResponse, ConnectorAuthType, ConnectorResponseData,
-        ExtendedAuthorizationResponseData, PaymentMethodToken, RouterData,
+        AccountUpdaterCardDetails, AdditionalPaymentMethodConnectorResponse, ConnectorAuthType,
+        ConnectorResponseData, ExtendedAuthorizationResponseData, PaymentMethodToken, RouterData,
     },
     router_flow_types::{Execute, RSync},
     router_request_types::{
@@ -2672,6 +2672,8 @@ pub struct StripeAdditionalCardDetails {
     #[serde(default, with = "common_utils::custom_serde::timestamp::option")]
     capture_before: Option<PrimitiveDateTime>,
     overcapture: Option<StripeOvercaptureResponse>,
+    exp_month: Option<u8>,
+    exp_year: Option<u16>,
 }
 
 #[derive(Deserialize, Clone, Debug, PartialEq, Eq, Serialize)]
@@ -2750,6 +2752,7 @@ pub struct AdditionalPaymentMethodDetails {
     pub authentication_details: Option<Value>,
     pub extended_authorization: Option<StripeExtendedAuthorizationResponse>,
     pub capture_before: Option<PrimitiveDateTime>,
+    pub account_updater_card_details: Option<AccountUpdaterCardDetails>,
 }
 
 impl From<&AdditionalPaymentMethodDetails> for AdditionalPaymentMethodConnectorResponse {
@@ -2787,6 +2790,14 @@ impl StripePaymentMethodDetailsResponse {
                 authentication_details: card.three_d_secure.clone(),
                 extended_authorization: card.extended_authorization.clone(),
                 capture_before: card.capture_before,
+                // Stripe keeps the saved card up to date with the card networks, hence the
+                // expiry of the charged card includes the account updater changes
+                account_updater_card_details: card.exp_month.zip(card.exp_year).map(
+                    |(exp_month, exp_year)| AccountUpdaterCardDetails {
+                        card_exp_month: Secret::new(format!("{exp_month:02}")),
+                        card_exp_year: Secret::new(exp_year.to_string()),
+                    },
+                ),
             }),
             Self::Ideal { .. }
             | Self::Bancontact { .. }
@@ -2898,11 +2909,18 @@ fn extract_payment_method_connector_response_from_latest_charge(
         || extended_authorization_data.is_some()
         || is_overcapture_enabled.is_some()
     {
-        Some(ConnectorResponseData::new(
-            additional_payment_method_data,
-            is_overcapture_enabled,
-            extended_authorization_data,
-        ))
+        Some(
+            ConnectorResponseData::new(
+                additional_payment_method_data,
+                is_overcapture_enabled,
+                extended_authorization_data,
+            )
+            .with_account_updater_card_details(
+                additional_payment_method_details.and_then(|additional_payment_method_details| {
+                    additional_payment_method_details.account_updater_card_details
+                }),
+            ),
+        )
     } else {
         None
     }
@@ -1438,4 +1438,8 @@ impl ConnectorSpecifications for Wellsfargo {
     fn get_supported_webhook_flows(&self) -> Option<&'static [enums::EventClass]> {
         Some(&WELLSFARGO_SUPPORTED_WEBHOOK_FLOWS)
     }
+
+    fn is_incremental_authorization_supported(&self) -> bool {
+        true
+    }
 }
@@ -966,8 +966,12 @@ where
             Some(common_enums::TaxStatus::Taxable) => Some(false),
             None => None,
         };
-        let customer_reference =
-            get_vantiv_customer_reference(&l2_l3_data.merchant_order_reference_id);
+        let customer_reference = get_vantiv_customer_reference(
+            &l2_l3_data
+                .purchase_order_number
+                .clone()
+                .or(l2_l3_data.merchant_order_reference_id.clone()),
+        );
 
         let detail_tax: Option<DetailTax> = if l2_l3_data.merchant_tax_registration_id.is_some()
             && l2_l3_data.order_details.is_some()
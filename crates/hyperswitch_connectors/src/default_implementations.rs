@@ -203,6 +203,7 @@ default_imp_for_authorize_session_token!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -345,6 +346,7 @@ default_imp_for_calculate_tax!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -486,6 +488,7 @@ default_imp_for_session_update!(
     connectors::Fiserv,
     connectors::Fiservemea,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Helcim,
     connectors::HyperswitchVault,
@@ -629,6 +632,7 @@ default_imp_for_post_session_tokens!(
     connectors::Fiserv,
     connectors::Fiservemea,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Helcim,
     connectors::HyperswitchVault,
@@ -770,6 +774,7 @@ default_imp_for_create_order!(
     connectors::Fiserv,
     connectors::Fiservemea,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Helcim,
     connectors::HyperswitchVault,
@@ -913,6 +918,7 @@ default_imp_for_update_metadata!(
     connectors::Fiserv,
     connectors::Fiservemea,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Helcim,
     connectors::HyperswitchVault,
@@ -1056,6 +1062,7 @@ default_imp_for_cancel_post_capture!(
     connectors::Fiserv,
     connectors::Fiservemea,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Helcim,
     connectors::HyperswitchVault,
@@ -1194,6 +1201,7 @@ default_imp_for_complete_authorize!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globepay,
     connectors::Gocardless,
@@ -1323,6 +1331,7 @@ default_imp_for_incremental_authorization!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -1460,6 +1469,7 @@ default_imp_for_create_customer!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -1597,6 +1607,7 @@ default_imp_for_connector_redirect_response!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globepay,
     connectors::Gocardless,
@@ -1724,6 +1735,7 @@ default_imp_for_pre_authenticate_steps!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -1866,6 +1878,7 @@ default_imp_for_authenticate_steps!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -2008,6 +2021,7 @@ default_imp_for_post_authenticate_steps!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -2148,6 +2162,7 @@ default_imp_for_pre_processing_steps!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -2279,6 +2294,7 @@ default_imp_for_post_processing_steps!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -2423,6 +2439,7 @@ default_imp_for_approve!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -2568,6 +2585,7 @@ default_imp_for_reject!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -2714,6 +2732,7 @@ default_imp_for_webhook_source_verification!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -2856,6 +2875,7 @@ default_imp_for_accept_dispute!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -2998,6 +3018,7 @@ default_imp_for_submit_evidence!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -3139,6 +3160,7 @@ default_imp_for_defend_dispute!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -3283,6 +3305,7 @@ default_imp_for_fetch_disputes!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Flexiti,
     connectors::Getnet,
     connectors::Globalpay,
@@ -3427,6 +3450,7 @@ default_imp_for_dispute_sync!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Flexiti,
     connectors::Getnet,
     connectors::Globalpay,
@@ -3579,6 +3603,7 @@ default_imp_for_file_upload!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -3712,6 +3737,7 @@ default_imp_for_payouts!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -3851,6 +3877,7 @@ default_imp_for_payouts_create!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -3994,6 +4021,7 @@ default_imp_for_payouts_retrieve!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -4135,6 +4163,7 @@ default_imp_for_payouts_eligibility!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -4277,6 +4306,7 @@ default_imp_for_payouts_fulfill!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -4416,6 +4446,7 @@ default_imp_for_payouts_cancel!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -4559,6 +4590,7 @@ default_imp_for_payouts_quote!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -4703,6 +4735,7 @@ default_imp_for_payouts_recipient!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -4847,6 +4880,7 @@ default_imp_for_payouts_recipient_account!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -4992,6 +5026,7 @@ default_imp_for_frm_sale!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -5137,6 +5172,7 @@ default_imp_for_frm_checkout!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -5282,6 +5318,7 @@ default_imp_for_frm_transaction!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -5427,6 +5464,7 @@ default_imp_for_frm_fulfillment!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -5572,6 +5610,7 @@ default_imp_for_frm_record_return!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -5712,6 +5751,7 @@ default_imp_for_revoking_mandates!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -5855,6 +5895,7 @@ default_imp_for_uas_pre_authentication!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -5997,6 +6038,7 @@ default_imp_for_uas_post_authentication!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -6140,6 +6182,7 @@ default_imp_for_uas_authentication_confirmation!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -6275,6 +6318,7 @@ default_imp_for_connector_request_id!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -6412,6 +6456,7 @@ default_imp_for_fraud_check!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -6579,6 +6624,7 @@ default_imp_for_connector_authentication!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -6719,6 +6765,7 @@ default_imp_for_uas_authentication!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -6854,6 +6901,7 @@ default_imp_for_revenue_recovery!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -6998,6 +7046,7 @@ default_imp_for_subscriptions!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -7144,6 +7193,7 @@ default_imp_for_billing_connector_payment_sync!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -7288,6 +7338,7 @@ default_imp_for_revenue_recovery_record_back!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -7432,6 +7483,7 @@ default_imp_for_billing_connector_invoice_sync!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -7570,6 +7622,7 @@ default_imp_for_external_vault!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -7714,6 +7767,7 @@ default_imp_for_external_vault_insert!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -7856,6 +7910,7 @@ default_imp_for_gift_card_balance_check!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -8001,6 +8056,7 @@ default_imp_for_external_vault_retrieve!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -8145,6 +8201,7 @@ default_imp_for_external_vault_delete!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -8291,6 +8348,7 @@ default_imp_for_external_vault_create!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -8434,6 +8492,7 @@ default_imp_for_connector_authentication_token!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -8576,6 +8635,7 @@ default_imp_for_external_vault_proxy_payments_create!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -313,6 +313,7 @@ default_imp_for_new_connector_integration_payment!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -459,6 +460,7 @@ default_imp_for_new_connector_integration_refund!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -596,6 +598,7 @@ default_imp_for_new_connector_integration_connector_authentication_token!(
     connectors::Fiservemea,
     connectors::Fiuu,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -731,6 +734,7 @@ default_imp_for_new_connector_integration_connector_access_token!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -876,6 +880,7 @@ default_imp_for_new_connector_integration_accept_dispute!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -1021,6 +1026,7 @@ default_imp_for_new_connector_integration_fetch_disputes!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -1167,6 +1173,7 @@ default_imp_for_new_connector_integration_dispute_sync!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -1313,6 +1320,7 @@ default_imp_for_new_connector_integration_defend_dispute!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -1457,6 +1465,7 @@ default_imp_for_new_connector_integration_submit_evidence!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -1612,6 +1621,7 @@ default_imp_for_new_connector_integration_file_upload!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -1759,6 +1769,7 @@ default_imp_for_new_connector_integration_payouts_create!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -1906,6 +1917,7 @@ default_imp_for_new_connector_integration_payouts_eligibility!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -2053,6 +2065,7 @@ default_imp_for_new_connector_integration_payouts_fulfill!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -2200,6 +2213,7 @@ default_imp_for_new_connector_integration_payouts_cancel!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -2347,6 +2361,7 @@ default_imp_for_new_connector_integration_payouts_quote!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -2494,6 +2509,7 @@ default_imp_for_new_connector_integration_payouts_recipient!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -2641,6 +2657,7 @@ default_imp_for_new_connector_integration_payouts_sync!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -2788,6 +2805,7 @@ default_imp_for_new_connector_integration_payouts_recipient_account!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -2933,6 +2951,7 @@ default_imp_for_new_connector_integration_webhook_source_verification!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -3080,6 +3099,7 @@ default_imp_for_new_connector_integration_frm_sale!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -3227,6 +3247,7 @@ default_imp_for_new_connector_integration_frm_checkout!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -3374,6 +3395,7 @@ default_imp_for_new_connector_integration_frm_transaction!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -3521,6 +3543,7 @@ default_imp_for_new_connector_integration_frm_fulfillment!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -3668,6 +3691,7 @@ default_imp_for_new_connector_integration_frm_record_return!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -3813,6 +3837,7 @@ default_imp_for_new_connector_integration_revoking_mandates!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -3940,6 +3965,7 @@ default_imp_for_new_connector_integration_frm!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Gpayments,
@@ -4084,6 +4110,7 @@ default_imp_for_new_connector_integration_connector_authentication!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Gpayments,
@@ -4217,6 +4244,7 @@ default_imp_for_new_connector_integration_revenue_recovery!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Globepay,
     connectors::Gocardless,
     connectors::Gpayments,
@@ -4370,6 +4398,7 @@ default_imp_for_new_connector_integration_external_vault!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
@@ -4514,6 +4543,7 @@ default_imp_for_new_connector_integration_external_vault_proxy!(
     connectors::Fiuu,
     connectors::Flexiti,
     connectors::Forte,
+    connectors::Genericrest,
     connectors::Getnet,
     connectors::Globalpay,
     connectors::Globepay,
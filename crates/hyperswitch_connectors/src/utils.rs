@@ -251,6 +251,48 @@ pub struct CardMandateInfo {
     pub card_exp_year: Secret<String>,
 }
 
+/// Initiator of a payment and the stored credential it is made with, derived from the mandate
+/// details of the request
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransactionInitiation {
+    /// Payment made by the customer, without storing the payment method
+    CustomerInitiated,
+    /// Payment made by the customer, storing the payment method for merchant initiated payments
+    CustomerInitiatedWithStoredCredential {
+        mit_category: Option<enums::MitCategory>,
+    },
+    /// Payment made by the merchant with a stored payment method, in the absence of the customer
+    MerchantInitiated {
+        mit_category: enums::MitCategory,
+        network_transaction_id: Option<String>,
+        connector_mandate_id: Option<String>,
+    },
+}
+
+impl TransactionInitiation {
+    pub fn is_merchant_initiated(&self) -> bool {
+        matches!(self, Self::MerchantInitiated { .. })
+    }
+
+    pub fn get_mit_category(&self) -> Option<enums::MitCategory> {
+        match self {
+            Self::CustomerInitiated => None,
+            Self::CustomerInitiatedWithStoredCredential { mit_category } => *mit_category,
+            Self::MerchantInitiated { mit_category, .. } => Some(*mit_category),
+        }
+    }
+
+    pub fn get_network_transaction_id(&self) -> Option<String> {
+        match self {
+            Self::MerchantInitiated {
+                network_transaction_id,
+                ..
+            } => network_transaction_id.clone(),
+            Self::CustomerInitiated | Self::CustomerInitiatedWithStoredCredential { .. } => None,
+        }
+    }
+}
+
 impl TryFrom<payment_method_data::GooglePayWalletData> for GooglePayWalletData {
     type Error = common_utils::errors::ValidationError;
 
@@ -460,7 +502,8 @@ pub(crate) fn is_payment_failure(status: AttemptStatus) -> bool {
         | AttemptStatus::ConfirmationAwaited
         | AttemptStatus::DeviceDataCollectionPending
         | AttemptStatus::IntegrityFailure
-        | AttemptStatus::PartiallyAuthorized => false,
+        | AttemptStatus::PartiallyAuthorized
+        | AttemptStatus::IncrementalAuthorizationPending => false,
     }
 }
 
@@ -471,7 +514,10 @@ pub fn is_refund_failure(status: enums::RefundStatus) -> bool {
         }
         common_enums::RefundStatus::ManualReview
         | common_enums::RefundStatus::Pending
-        | common_enums::RefundStatus::Success => false,
+        | common_enums::RefundStatus::Success
+        | common_enums::RefundStatus::Scheduled
+        | common_enums::RefundStatus::PendingApproval
+        | common_enums::RefundStatus::Cancelled => false,
     }
 }
 // TODO: Make all traits as `pub(crate) trait` once all connectors are moved.
@@ -1809,6 +1855,7 @@ pub trait PaymentsAuthorizeRequestData {
     fn get_connector_testing_data(&self) -> Option<pii::SecretSerdeValue>;
     fn get_order_id(&self) -> Result<String, errors::ConnectorError>;
     fn get_card_mandate_info(&self) -> Result<CardMandateInfo, Error>;
+    fn get_transaction_initiation(&self) -> TransactionInitiation;
 }
 
 impl PaymentsAuthorizeRequestData for PaymentsAuthorizeData {
@@ -2028,6 +2075,36 @@ impl PaymentsAuthorizeRequestData for PaymentsAuthorizeData {
     fn get_optional_email(&self) -> Option<Email> {
         self.email.clone()
     }
+    /// Payments made with a mandate reference, or off session, are merchant initiated. An
+    /// unscheduled category is assumed when the merchant has not specified one.
+    fn get_transaction_initiation(&self) -> TransactionInitiation {
+        let mandate_reference_id = self
+            .mandate_id
+            .as_ref()
+            .and_then(|mandate_ids| mandate_ids.mandate_reference_id.as_ref());
+        if mandate_reference_id.is_some() || self.off_session == Some(true) {
+            let network_transaction_id = match mandate_reference_id {
+                Some(payments::MandateReferenceId::NetworkMandateId(network_transaction_id)) => {
+                    Some(network_transaction_id.clone())
+                }
+                Some(payments::MandateReferenceId::NetworkTokenWithNTI(mandate_data)) => {
+                    Some(mandate_data.network_transaction_id.clone())
+                }
+                Some(payments::MandateReferenceId::ConnectorMandateId(_)) | None => None,
+            };
+            TransactionInitiation::MerchantInitiated {
+                mit_category: self.mit_category.unwrap_or(enums::MitCategory::Unscheduled),
+                network_transaction_id,
+                connector_mandate_id: self.connector_mandate_id(),
+            }
+        } else if self.is_cit_mandate_payment() {
+            TransactionInitiation::CustomerInitiatedWithStoredCredential {
+                mit_category: self.mit_category,
+            }
+        } else {
+            TransactionInitiation::CustomerInitiated
+        }
+    }
     fn get_card_network_from_additional_payment_method_data(
         &self,
     ) -> Result<enums::CardNetwork, Error> {
@@ -6759,6 +6836,8 @@ pub(crate) fn convert_setup_mandate_router_data_to_authorize_router_data(
         payment_channel: data.request.payment_channel.clone(),
         enable_partial_authorization: data.request.enable_partial_authorization,
         enable_overcapture: None,
+        mit_category: None,
+        dcc_data: None,
     }
 }
 
@@ -6803,6 +6882,7 @@ pub(crate) fn convert_payment_authorize_router_response<F1, F2, T1, T2>(
         payment_method_status: None,
         payment_method_balance: data.payment_method_balance.clone(),
         connector_api_version: data.connector_api_version.clone(),
+        merchant_connector_id: data.merchant_connector_id.clone(),
         connector_http_status_code: data.connector_http_status_code,
         external_latency: data.external_latency,
         apple_pay_flow: data.apple_pay_flow.clone(),
@@ -6888,7 +6968,8 @@ impl FrmTransactionRouterDataRequest for FrmTransactionRouterData {
             | AttemptStatus::Authorized
             | AttemptStatus::Charged
             | AttemptStatus::IntegrityFailure
-            | AttemptStatus::PartiallyAuthorized => Some(true),
+            | AttemptStatus::PartiallyAuthorized
+            | AttemptStatus::IncrementalAuthorizationPending => Some(true),
 
             AttemptStatus::Started
             | AttemptStatus::AuthenticationPending
@@ -20,6 +20,28 @@ pub trait Conversion {
     where
         Self: Sized;
 
+    /// Converts multiple storage models belonging to the same key manager identifier.
+    ///
+    /// Types whose encrypted fields can be decrypted together should override this, so that a
+    /// list of resources is decrypted with a single call to the encryption service.
+    async fn convert_back_multiple(
+        state: &KeyManagerState,
+        items: Vec<Self::DstType>,
+        key: &Secret<Vec<u8>>,
+        key_manager_identifier: Identifier,
+    ) -> CustomResult<Vec<Self>, ValidationError>
+    where
+        Self: Sized + Send,
+        Self::DstType: Send,
+    {
+        futures::future::try_join_all(
+            items
+                .into_iter()
+                .map(|item| Self::convert_back(state, item, key, key_manager_identifier.clone())),
+        )
+        .await
+    }
+
     async fn construct_new(self) -> CustomResult<Self::NewDstType, ValidationError>;
 }
 
@@ -11,9 +11,11 @@ use common_utils::{
     pii, type_name,
     types::keymanager,
 };
+#[cfg(feature = "v1")]
+use diesel_models::business_profile::WebhookEndpointSecrets;
 use diesel_models::business_profile::{
     AuthenticationConnectorDetails, BusinessPaymentLinkConfig, BusinessPayoutLinkConfig,
-    CardTestingGuardConfig, ProfileUpdateInternal, WebhookDetails,
+    CardTestingGuardConfig, ProfileUpdateInternal, WebhookDetails, WebhookEndpoint,
 };
 #[cfg(feature = "v2")]
 use diesel_models::business_profile::{
@@ -86,6 +88,14 @@ pub struct Profile {
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
     pub is_manual_retry_enabled: Option<bool>,
     pub always_enable_overcapture: Option<primitive_wrappers::AlwaysEnableOvercaptureBool>,
+    pub retry_policy: Option<common_types::domain::RetryPolicy>,
+    pub intent_expiry: Option<i64>,
+    pub refund_approval_threshold: Option<common_utils::types::MinorUnit>,
+    pub webhook_retry_policy: Option<common_types::domain::WebhookRetryPolicy>,
+    pub event_sink_config: Option<common_types::domain::EventSinkConfig>,
+    pub is_connector_audit_capture_enabled: Option<bool>,
+    pub soft_decline_retry_config: Option<common_types::domain::SoftDeclineRetryConfig>,
+    pub webhook_endpoint_secrets: OptionalEncryptableValue,
 }
 
 #[cfg(feature = "v1")]
@@ -144,6 +154,14 @@ pub struct ProfileSetter {
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
     pub is_manual_retry_enabled: Option<bool>,
     pub always_enable_overcapture: Option<primitive_wrappers::AlwaysEnableOvercaptureBool>,
+    pub retry_policy: Option<common_types::domain::RetryPolicy>,
+    pub intent_expiry: Option<i64>,
+    pub refund_approval_threshold: Option<common_utils::types::MinorUnit>,
+    pub webhook_retry_policy: Option<common_types::domain::WebhookRetryPolicy>,
+    pub event_sink_config: Option<common_types::domain::EventSinkConfig>,
+    pub is_connector_audit_capture_enabled: Option<bool>,
+    pub soft_decline_retry_config: Option<common_types::domain::SoftDeclineRetryConfig>,
+    pub webhook_endpoint_secrets: OptionalEncryptableValue,
 }
 
 #[cfg(feature = "v1")]
@@ -209,6 +227,14 @@ impl From<ProfileSetter> for Profile {
             dispute_polling_interval: value.dispute_polling_interval,
             is_manual_retry_enabled: value.is_manual_retry_enabled,
             always_enable_overcapture: value.always_enable_overcapture,
+            retry_policy: value.retry_policy,
+            intent_expiry: value.intent_expiry,
+            refund_approval_threshold: value.refund_approval_threshold,
+            webhook_retry_policy: value.webhook_retry_policy,
+            event_sink_config: value.event_sink_config,
+            is_connector_audit_capture_enabled: value.is_connector_audit_capture_enabled,
+            soft_decline_retry_config: value.soft_decline_retry_config,
+            webhook_endpoint_secrets: value.webhook_endpoint_secrets,
         }
     }
 }
@@ -276,6 +302,14 @@ pub struct ProfileGeneralUpdate {
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
     pub is_manual_retry_enabled: Option<bool>,
     pub always_enable_overcapture: Option<primitive_wrappers::AlwaysEnableOvercaptureBool>,
+    pub retry_policy: Option<common_types::domain::RetryPolicy>,
+    pub intent_expiry: Option<i64>,
+    pub refund_approval_threshold: Option<common_utils::types::MinorUnit>,
+    pub webhook_retry_policy: Option<common_types::domain::WebhookRetryPolicy>,
+    pub event_sink_config: Option<common_types::domain::EventSinkConfig>,
+    pub is_connector_audit_capture_enabled: Option<bool>,
+    pub soft_decline_retry_config: Option<common_types::domain::SoftDeclineRetryConfig>,
+    pub webhook_endpoint_secrets: OptionalEncryptableValue,
 }
 
 #[cfg(feature = "v1")]
@@ -361,6 +395,14 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     always_request_extended_authorization,
                     is_manual_retry_enabled,
                     always_enable_overcapture,
+                    retry_policy,
+                    intent_expiry,
+                    refund_approval_threshold,
+                    webhook_retry_policy,
+                    event_sink_config,
+                    is_connector_audit_capture_enabled,
+                    soft_decline_retry_config,
+                    webhook_endpoint_secrets,
                 } = *update;
 
                 Self {
@@ -416,6 +458,14 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     dispute_polling_interval,
                     is_manual_retry_enabled,
                     always_enable_overcapture,
+                    retry_policy,
+                    intent_expiry,
+                    refund_approval_threshold,
+                    webhook_retry_policy,
+                    event_sink_config,
+                    is_connector_audit_capture_enabled,
+                    soft_decline_retry_config,
+                    webhook_endpoint_secrets: webhook_endpoint_secrets.map(Encryption::from),
                 }
             }
             ProfileUpdate::RoutingAlgorithmUpdate {
@@ -474,6 +524,14 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 dispute_polling_interval: None,
                 is_manual_retry_enabled: None,
                 always_enable_overcapture: None,
+                retry_policy: None,
+                intent_expiry: None,
+                refund_approval_threshold: None,
+                webhook_retry_policy: None,
+                event_sink_config: None,
+                is_connector_audit_capture_enabled: None,
+                soft_decline_retry_config: None,
+                webhook_endpoint_secrets: None,
             },
             ProfileUpdate::DynamicRoutingAlgorithmUpdate {
                 dynamic_routing_algorithm,
@@ -529,6 +587,14 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 dispute_polling_interval: None,
                 is_manual_retry_enabled: None,
                 always_enable_overcapture: None,
+                retry_policy: None,
+                intent_expiry: None,
+                refund_approval_threshold: None,
+                webhook_retry_policy: None,
+                event_sink_config: None,
+                is_connector_audit_capture_enabled: None,
+                soft_decline_retry_config: None,
+                webhook_endpoint_secrets: None,
             },
             ProfileUpdate::ExtendedCardInfoUpdate {
                 is_extended_card_info_enabled,
@@ -584,6 +650,14 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 dispute_polling_interval: None,
                 is_manual_retry_enabled: None,
                 always_enable_overcapture: None,
+                retry_policy: None,
+                intent_expiry: None,
+                refund_approval_threshold: None,
+                webhook_retry_policy: None,
+                event_sink_config: None,
+                is_connector_audit_capture_enabled: None,
+                soft_decline_retry_config: None,
+                webhook_endpoint_secrets: None,
             },
             ProfileUpdate::ConnectorAgnosticMitUpdate {
                 is_connector_agnostic_mit_enabled,
@@ -639,6 +713,14 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 dispute_polling_interval: None,
                 is_manual_retry_enabled: None,
                 always_enable_overcapture: None,
+                retry_policy: None,
+                intent_expiry: None,
+                refund_approval_threshold: None,
+                webhook_retry_policy: None,
+                event_sink_config: None,
+                is_connector_audit_capture_enabled: None,
+                soft_decline_retry_config: None,
+                webhook_endpoint_secrets: None,
             },
             ProfileUpdate::NetworkTokenizationUpdate {
                 is_network_tokenization_enabled,
@@ -694,6 +776,14 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 dispute_polling_interval: None,
                 is_manual_retry_enabled: None,
                 always_enable_overcapture: None,
+                retry_policy: None,
+                intent_expiry: None,
+                refund_approval_threshold: None,
+                webhook_retry_policy: None,
+                event_sink_config: None,
+                is_connector_audit_capture_enabled: None,
+                soft_decline_retry_config: None,
+                webhook_endpoint_secrets: None,
             },
             ProfileUpdate::CardTestingSecretKeyUpdate {
                 card_testing_secret_key,
@@ -749,6 +839,14 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 dispute_polling_interval: None,
                 is_manual_retry_enabled: None,
                 always_enable_overcapture: None,
+                retry_policy: None,
+                intent_expiry: None,
+                refund_approval_threshold: None,
+                webhook_retry_policy: None,
+                event_sink_config: None,
+                is_connector_audit_capture_enabled: None,
+                soft_decline_retry_config: None,
+                webhook_endpoint_secrets: None,
             },
             ProfileUpdate::AcquirerConfigMapUpdate {
                 acquirer_config_map,
@@ -804,6 +902,14 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 dispute_polling_interval: None,
                 is_manual_retry_enabled: None,
                 always_enable_overcapture: None,
+                retry_policy: None,
+                intent_expiry: None,
+                refund_approval_threshold: None,
+                webhook_retry_policy: None,
+                event_sink_config: None,
+                is_connector_audit_capture_enabled: None,
+                soft_decline_retry_config: None,
+                webhook_endpoint_secrets: None,
             },
         }
     }
@@ -879,6 +985,14 @@ impl super::behaviour::Conversion for Profile {
             dispute_polling_interval: self.dispute_polling_interval,
             is_manual_retry_enabled: self.is_manual_retry_enabled,
             always_enable_overcapture: self.always_enable_overcapture,
+            retry_policy: self.retry_policy,
+            intent_expiry: self.intent_expiry,
+            refund_approval_threshold: self.refund_approval_threshold,
+            webhook_retry_policy: self.webhook_retry_policy,
+            event_sink_config: self.event_sink_config,
+            is_connector_audit_capture_enabled: self.is_connector_audit_capture_enabled,
+            soft_decline_retry_config: self.soft_decline_retry_config,
+            webhook_endpoint_secrets: self.webhook_endpoint_secrets.map(Encryption::from),
         })
     }
 
@@ -980,6 +1094,27 @@ impl super::behaviour::Conversion for Profile {
                 dispute_polling_interval: item.dispute_polling_interval,
                 is_manual_retry_enabled: item.is_manual_retry_enabled,
                 always_enable_overcapture: item.always_enable_overcapture,
+                retry_policy: item.retry_policy,
+                intent_expiry: item.intent_expiry,
+                refund_approval_threshold: item.refund_approval_threshold,
+                webhook_retry_policy: item.webhook_retry_policy,
+                event_sink_config: item.event_sink_config,
+                is_connector_audit_capture_enabled: item.is_connector_audit_capture_enabled,
+                soft_decline_retry_config: item.soft_decline_retry_config,
+                webhook_endpoint_secrets: item
+                    .webhook_endpoint_secrets
+                    .async_lift(|inner| async {
+                        crypto_operation(
+                            state,
+                            type_name!(Self::DstType),
+                            CryptoOperation::DecryptOptional(inner),
+                            key_manager_identifier.clone(),
+                            key.peek(),
+                        )
+                        .await
+                        .and_then(|val| val.try_into_optionaloperation())
+                    })
+                    .await?,
             })
         }
         .await
@@ -1047,6 +1182,14 @@ impl super::behaviour::Conversion for Profile {
             merchant_country_code: self.merchant_country_code,
             dispute_polling_interval: self.dispute_polling_interval,
             is_manual_retry_enabled: self.is_manual_retry_enabled,
+            retry_policy: self.retry_policy,
+            intent_expiry: self.intent_expiry,
+            refund_approval_threshold: self.refund_approval_threshold,
+            webhook_retry_policy: self.webhook_retry_policy,
+            event_sink_config: self.event_sink_config,
+            is_connector_audit_capture_enabled: self.is_connector_audit_capture_enabled,
+            soft_decline_retry_config: self.soft_decline_retry_config,
+            webhook_endpoint_secrets: self.webhook_endpoint_secrets.map(Encryption::from),
         })
     }
 }
@@ -1261,6 +1404,65 @@ impl Profile {
             .map(ExposeInterface::expose)
     }
 
+    pub fn get_webhook_endpoint(&self, webhook_endpoint_id: &str) -> Option<&WebhookEndpoint> {
+        self.webhook_details
+            .as_ref()
+            .and_then(|details| details.webhook_endpoints.as_ref())
+            .and_then(|webhook_endpoints| {
+                webhook_endpoints
+                    .iter()
+                    .find(|webhook_endpoint| webhook_endpoint.endpoint_id == webhook_endpoint_id)
+            })
+    }
+
+    /// Get the decrypted credentials of the additional webhook endpoints, keyed by endpoint ID
+    #[cfg(feature = "v1")]
+    pub fn get_webhook_endpoint_secrets(
+        &self,
+    ) -> CustomResult<
+        std::collections::HashMap<String, WebhookEndpointSecrets>,
+        api_error_response::ApiErrorResponse,
+    > {
+        self.webhook_endpoint_secrets
+            .clone()
+            .map(|webhook_endpoint_secrets| {
+                webhook_endpoint_secrets
+                    .into_inner()
+                    .expose()
+                    .parse_value::<std::collections::HashMap<String, WebhookEndpointSecrets>>(
+                        "WebhookEndpointSecrets",
+                    )
+            })
+            .transpose()
+            .change_context(api_error_response::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "unable to deserialize webhook endpoint secrets from business profile",
+            )
+            .map(Option::unwrap_or_default)
+    }
+
+    /// Get the additional webhook endpoints subscribed to the specified event type
+    pub fn get_webhook_endpoints_subscribed_to(
+        &self,
+        event_type: api_enums::EventType,
+    ) -> Vec<&WebhookEndpoint> {
+        self.webhook_details
+            .as_ref()
+            .and_then(|details| details.webhook_endpoints.as_ref())
+            .map(|webhook_endpoints| {
+                webhook_endpoints
+                    .iter()
+                    .filter(|webhook_endpoint| {
+                        webhook_endpoint
+                            .enabled_events
+                            .as_ref()
+                            .is_none_or(|enabled_events| enabled_events.contains(&event_type))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     #[cfg(feature = "v2")]
     pub fn is_external_vault_enabled(&self) -> bool {
         self.is_external_vault_enabled.unwrap_or(false)
@@ -2134,6 +2336,14 @@ impl super::behaviour::Conversion for Profile {
             split_txns_enabled: Some(self.split_txns_enabled),
             is_manual_retry_enabled: None,
             always_enable_overcapture: None,
+            retry_policy: None,
+            intent_expiry: None,
+            refund_approval_threshold: None,
+            webhook_retry_policy: None,
+            event_sink_config: None,
+            is_connector_audit_capture_enabled: None,
+            soft_decline_retry_config: None,
+            webhook_endpoint_secrets: None,
         })
     }
 
@@ -60,6 +60,7 @@ pub struct Connectors {
     pub fiuu: ConnectorParamsWithThreeUrls,
     pub flexiti: ConnectorParams,
     pub forte: ConnectorParams,
+    pub genericrest: ConnectorParamsWithAllowedHosts,
     pub getnet: ConnectorParams,
     pub globalpay: ConnectorParams,
     pub globepay: ConnectorParams,
@@ -180,6 +181,35 @@ impl NoParams {
     }
 }
 
+/// struct ConnectorParamsWithAllowedHosts
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ConnectorParamsWithAllowedHosts {
+    /// base url
+    pub base_url: String,
+    /// Hosts which merchants can configure as the endpoint of the connector. Requests are not
+    /// sent to any other host.
+    pub allowed_hosts: Vec<String>,
+}
+
+impl ConnectorParamsWithAllowedHosts {
+    /// Validates that the configuration provided for the `parent_field` does not contain empty or default values
+    pub fn validate(&self, parent_field: &str) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.base_url.is_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(format!(
+                "base_url must not be empty for {parent_field}"
+            )))
+        })
+    }
+
+    /// Checks whether the host is allowed to be configured as the endpoint of the connector
+    pub fn is_host_allowed(&self, host: &str) -> bool {
+        self.allowed_hosts
+            .iter()
+            .any(|allowed_host| allowed_host.eq_ignore_ascii_case(host))
+    }
+}
+
 /// struct ConnectorParamsWithKeys
 #[derive(Debug, Deserialize, Clone, Default, router_derive::ConfigValidate)]
 #[serde(default)]
@@ -47,6 +47,8 @@ pub struct Customer {
     pub version: common_enums::ApiVersion,
     #[encrypt]
     pub tax_registration_id: Option<Encryptable<Secret<String>>>,
+    /// The customer which this customer was merged into, after which this customer is inactive
+    pub merged_into_customer_id: Option<id_type::CustomerId>,
 }
 
 #[cfg(feature = "v2")]
@@ -122,6 +124,55 @@ impl Customer {
     }
 }
 
+fn get_encrypted_customer_fields(item: &storage_types::Customer) -> FxHashMap<String, Encryption> {
+    EncryptedCustomer::to_encryptable(EncryptedCustomer {
+        name: item.name.clone(),
+        phone: item.phone.clone(),
+        email: item.email.clone(),
+        tax_registration_id: item.tax_registration_id.clone(),
+    })
+}
+
+#[cfg(feature = "v1")]
+impl Customer {
+    fn from_storage_with_decrypted_fields(
+        item: storage_types::Customer,
+        decrypted: FxHashMap<String, Encryptable<Secret<String>>>,
+    ) -> CustomResult<Self, ValidationError> {
+        let encryptable_customer = EncryptedCustomer::from_encryptable(decrypted).change_context(
+            ValidationError::InvalidValue {
+                message: "Failed while decrypting customer data".to_string(),
+            },
+        )?;
+
+        Ok(Self {
+            customer_id: item.customer_id,
+            merchant_id: item.merchant_id,
+            name: encryptable_customer.name,
+            email: encryptable_customer.email.map(|email| {
+                let encryptable: Encryptable<Secret<String, pii::EmailStrategy>> = Encryptable::new(
+                    email.clone().into_inner().switch_strategy(),
+                    email.into_encrypted(),
+                );
+                encryptable
+            }),
+            phone: encryptable_customer.phone,
+            phone_country_code: item.phone_country_code,
+            description: item.description,
+            created_at: item.created_at,
+            metadata: item.metadata,
+            modified_at: item.modified_at,
+            connector_customer: item.connector_customer,
+            address_id: item.address_id,
+            default_payment_method_id: item.default_payment_method_id,
+            updated_by: item.updated_by,
+            version: item.version,
+            tax_registration_id: encryptable_customer.tax_registration_id,
+            merged_into_customer_id: item.merged_into_customer_id,
+        })
+    }
+}
+
 #[cfg(feature = "v1")]
 #[async_trait::async_trait]
 impl behaviour::Conversion for Customer {
@@ -145,6 +196,7 @@ impl behaviour::Conversion for Customer {
             updated_by: self.updated_by,
             version: self.version,
             tax_registration_id: self.tax_registration_id.map(Encryption::from),
+            merged_into_customer_id: self.merged_into_customer_id,
         })
     }
 
@@ -160,14 +212,7 @@ impl behaviour::Conversion for Customer {
         let decrypted = types::crypto_operation(
             state,
             common_utils::type_name!(Self::DstType),
-            types::CryptoOperation::BatchDecrypt(EncryptedCustomer::to_encryptable(
-                EncryptedCustomer {
-                    name: item.name.clone(),
-                    phone: item.phone.clone(),
-                    email: item.email.clone(),
-                    tax_registration_id: item.tax_registration_id.clone(),
-                },
-            )),
+            types::CryptoOperation::BatchDecrypt(get_encrypted_customer_fields(&item)),
             keymanager::Identifier::Merchant(item.merchant_id.clone()),
             key.peek(),
         )
@@ -176,6 +221,70 @@ impl behaviour::Conversion for Customer {
         .change_context(ValidationError::InvalidValue {
             message: "Failed while decrypting customer data".to_string(),
         })?;
+
+        Self::from_storage_with_decrypted_fields(item, decrypted)
+    }
+
+    async fn convert_back_multiple(
+        state: &KeyManagerState,
+        items: Vec<Self::DstType>,
+        key: &Secret<Vec<u8>>,
+        key_manager_identifier: keymanager::Identifier,
+    ) -> CustomResult<Vec<Self>, ValidationError>
+    where
+        Self: Sized + Send,
+        Self::DstType: Send,
+    {
+        let decrypted = types::crypto_operation(
+            state,
+            common_utils::type_name!(Self::DstType),
+            types::CryptoOperation::MultipleBatchDecrypt(
+                items.iter().map(get_encrypted_customer_fields).collect(),
+            ),
+            key_manager_identifier,
+            key.peek(),
+        )
+        .await
+        .and_then(|val| val.try_into_multiplebatchoperation())
+        .change_context(ValidationError::InvalidValue {
+            message: "Failed while decrypting customer data".to_string(),
+        })?;
+
+        items
+            .into_iter()
+            .zip(decrypted)
+            .map(|(item, decrypted)| Self::from_storage_with_decrypted_fields(item, decrypted))
+            .collect()
+    }
+
+    async fn construct_new(self) -> CustomResult<Self::NewDstType, ValidationError> {
+        let now = date_time::now();
+        Ok(diesel_models::customers::CustomerNew {
+            customer_id: self.customer_id,
+            merchant_id: self.merchant_id,
+            name: self.name.map(Encryption::from),
+            email: self.email.map(Encryption::from),
+            phone: self.phone.map(Encryption::from),
+            description: self.description,
+            phone_country_code: self.phone_country_code,
+            metadata: self.metadata,
+            created_at: now,
+            modified_at: now,
+            connector_customer: self.connector_customer,
+            address_id: self.address_id,
+            updated_by: self.updated_by,
+            version: self.version,
+            tax_registration_id: self.tax_registration_id.map(Encryption::from),
+        })
+    }
+}
+
+#[cfg(feature = "v2")]
+impl Customer {
+    fn from_storage_with_decrypted_fields(
+        item: storage_types::Customer,
+        decrypted: FxHashMap<String, Encryptable<Secret<String>>>,
+    ) -> CustomResult<Self, ValidationError> {
         let encryptable_customer = EncryptedCustomer::from_encryptable(decrypted).change_context(
             ValidationError::InvalidValue {
                 message: "Failed while decrypting customer data".to_string(),
@@ -183,7 +292,8 @@ impl behaviour::Conversion for Customer {
         )?;
 
         Ok(Self {
-            customer_id: item.customer_id,
+            id: item.id,
+            merchant_reference_id: item.merchant_reference_id,
             merchant_id: item.merchant_id,
             name: encryptable_customer.name,
             email: encryptable_customer.email.map(|email| {
@@ -200,34 +310,15 @@ impl behaviour::Conversion for Customer {
             metadata: item.metadata,
             modified_at: item.modified_at,
             connector_customer: item.connector_customer,
-            address_id: item.address_id,
             default_payment_method_id: item.default_payment_method_id,
             updated_by: item.updated_by,
+            default_billing_address: item.default_billing_address,
+            default_shipping_address: item.default_shipping_address,
             version: item.version,
+            status: item.status,
             tax_registration_id: encryptable_customer.tax_registration_id,
         })
     }
-
-    async fn construct_new(self) -> CustomResult<Self::NewDstType, ValidationError> {
-        let now = date_time::now();
-        Ok(diesel_models::customers::CustomerNew {
-            customer_id: self.customer_id,
-            merchant_id: self.merchant_id,
-            name: self.name.map(Encryption::from),
-            email: self.email.map(Encryption::from),
-            phone: self.phone.map(Encryption::from),
-            description: self.description,
-            phone_country_code: self.phone_country_code,
-            metadata: self.metadata,
-            created_at: now,
-            modified_at: now,
-            connector_customer: self.connector_customer,
-            address_id: self.address_id,
-            updated_by: self.updated_by,
-            version: self.version,
-            tax_registration_id: self.tax_registration_id.map(Encryption::from),
-        })
-    }
 }
 
 #[cfg(feature = "v2")]
@@ -271,14 +362,7 @@ impl behaviour::Conversion for Customer {
         let decrypted = types::crypto_operation(
             state,
             common_utils::type_name!(Self::DstType),
-            types::CryptoOperation::BatchDecrypt(EncryptedCustomer::to_encryptable(
-                EncryptedCustomer {
-                    name: item.name.clone(),
-                    phone: item.phone.clone(),
-                    email: item.email.clone(),
-                    tax_registration_id: item.tax_registration_id.clone(),
-                },
-            )),
+            types::CryptoOperation::BatchDecrypt(get_encrypted_customer_fields(&item)),
             keymanager::Identifier::Merchant(item.merchant_id.clone()),
             key.peek(),
         )
@@ -287,39 +371,40 @@ impl behaviour::Conversion for Customer {
         .change_context(ValidationError::InvalidValue {
             message: "Failed while decrypting customer data".to_string(),
         })?;
-        let encryptable_customer = EncryptedCustomer::from_encryptable(decrypted).change_context(
-            ValidationError::InvalidValue {
-                message: "Failed while decrypting customer data".to_string(),
-            },
-        )?;
 
-        Ok(Self {
-            id: item.id,
-            merchant_reference_id: item.merchant_reference_id,
-            merchant_id: item.merchant_id,
-            name: encryptable_customer.name,
-            email: encryptable_customer.email.map(|email| {
-                let encryptable: Encryptable<Secret<String, pii::EmailStrategy>> = Encryptable::new(
-                    email.clone().into_inner().switch_strategy(),
-                    email.into_encrypted(),
-                );
-                encryptable
-            }),
-            phone: encryptable_customer.phone,
-            phone_country_code: item.phone_country_code,
-            description: item.description,
-            created_at: item.created_at,
-            metadata: item.metadata,
-            modified_at: item.modified_at,
-            connector_customer: item.connector_customer,
-            default_payment_method_id: item.default_payment_method_id,
-            updated_by: item.updated_by,
-            default_billing_address: item.default_billing_address,
-            default_shipping_address: item.default_shipping_address,
-            version: item.version,
-            status: item.status,
-            tax_registration_id: encryptable_customer.tax_registration_id,
-        })
+        Self::from_storage_with_decrypted_fields(item, decrypted)
+    }
+
+    async fn convert_back_multiple(
+        state: &KeyManagerState,
+        items: Vec<Self::DstType>,
+        key: &Secret<Vec<u8>>,
+        key_manager_identifier: keymanager::Identifier,
+    ) -> CustomResult<Vec<Self>, ValidationError>
+    where
+        Self: Sized + Send,
+        Self::DstType: Send,
+    {
+        let decrypted = types::crypto_operation(
+            state,
+            common_utils::type_name!(Self::DstType),
+            types::CryptoOperation::MultipleBatchDecrypt(
+                items.iter().map(get_encrypted_customer_fields).collect(),
+            ),
+            key_manager_identifier,
+            key.peek(),
+        )
+        .await
+        .and_then(|val| val.try_into_multiplebatchoperation())
+        .change_context(ValidationError::InvalidValue {
+            message: "Failed while decrypting customer data".to_string(),
+        })?;
+
+        items
+            .into_iter()
+            .zip(decrypted)
+            .map(|(item, decrypted)| Self::from_storage_with_decrypted_fields(item, decrypted))
+            .collect()
     }
 
     async fn construct_new(self) -> CustomResult<Self::NewDstType, ValidationError> {
@@ -471,6 +556,9 @@ pub enum CustomerUpdate {
     UpdateDefaultPaymentMethod {
         default_payment_method_id: Option<Option<String>>,
     },
+    MergeUpdate {
+        merged_into_customer_id: id_type::CustomerId,
+    },
 }
 
 #[cfg(feature = "v1")]
@@ -500,6 +588,7 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 default_payment_method_id: None,
                 updated_by: None,
                 tax_registration_id: tax_registration_id.map(Encryption::from),
+                merged_into_customer_id: None,
             },
             CustomerUpdate::ConnectorCustomer { connector_customer } => Self {
                 connector_customer,
@@ -514,6 +603,7 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 updated_by: None,
                 address_id: None,
                 tax_registration_id: None,
+                merged_into_customer_id: None,
             },
             CustomerUpdate::UpdateDefaultPaymentMethod {
                 default_payment_method_id,
@@ -530,6 +620,24 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 updated_by: None,
                 address_id: None,
                 tax_registration_id: None,
+                merged_into_customer_id: None,
+            },
+            CustomerUpdate::MergeUpdate {
+                merged_into_customer_id,
+            } => Self {
+                merged_into_customer_id: Some(merged_into_customer_id),
+                modified_at: date_time::now(),
+                name: None,
+                email: None,
+                phone: None,
+                description: None,
+                phone_country_code: None,
+                metadata: None,
+                connector_customer: None,
+                default_payment_method_id: None,
+                updated_by: None,
+                address_id: None,
+                tax_registration_id: None,
             },
         }
     }
@@ -538,6 +646,7 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
 pub struct CustomerListConstraints {
     pub limit: u16,
     pub offset: Option<u32>,
+    pub cursor: Option<common_utils::types::ListCursor>,
 }
 
 impl From<CustomerListConstraints> for query::CustomerListConstraints {
@@ -545,6 +654,7 @@ impl From<CustomerListConstraints> for query::CustomerListConstraints {
         Self {
             limit: i64::from(value.limit),
             offset: value.offset.map(i64::from),
+            cursor: value.cursor,
         }
     }
 }
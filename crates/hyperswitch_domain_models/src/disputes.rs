@@ -13,6 +13,7 @@ pub struct DisputeListConstraints {
     pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
     pub currency: Option<Vec<common_enums::Currency>>,
     pub time_range: Option<common_utils::types::TimeRange>,
+    pub cursor: Option<common_utils::types::ListCursor>,
 }
 
 impl
@@ -41,6 +42,7 @@ impl
             merchant_connector_id,
             currency,
             time_range,
+            cursor,
         } = value;
         let profile_id_from_request_body = profile_id;
         // Match both the profile ID from the request body and the list of authenticated profile IDs coming from auth layer
@@ -84,6 +86,7 @@ impl
             merchant_connector_id,
             currency,
             time_range,
+            cursor,
         })
     }
 }
@@ -55,6 +55,8 @@ pub enum ApiErrorResponse {
     ResourceBusy,
     #[error(error_type = ErrorType::ServerNotAvailable, code = "HE_00", message = "Something went wrong")]
     InternalServerError,
+    #[error(error_type = ErrorType::ServerNotAvailable, code = "HE_00", message = "The server is not accepting new payments, retry after {retry_after_secs} seconds")]
+    ServiceDraining { retry_after_secs: u64 },
     #[error(error_type = ErrorType::ServerNotAvailable, code= "HE_00", message = "{component} health check is failing with error: {message}")]
     HealthCheckError {
         component: &'static str,
@@ -301,6 +303,16 @@ pub enum ApiErrorResponse {
         max_length: usize,
         received_length: usize,
     },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_48", message = "IP address {ip_address} is not permitted to use this API key")]
+    ApiKeyIpAddressNotAllowed { ip_address: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_49", message = "IP address {ip_address} is not permitted to access this merchant account")]
+    MerchantIpAddressNotAllowed { ip_address: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_50", message = "Too many requests, retry after {retry_after_secs} seconds")]
+    TooManyRequests { retry_after_secs: u64 },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_51", message = "Idempotency key {idempotency_key} has already been used with a different request")]
+    IdempotencyKeyReused { idempotency_key: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_52", message = "Another request with idempotency key {idempotency_key} is currently being processed")]
+    IdempotencyKeyInProgress { idempotency_key: String },
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_01", message = "Failed to authenticate the webhook")]
     WebhookAuthenticationFailed,
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_02", message = "Bad request received in webhook")]
@@ -393,6 +405,9 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::InternalServerError => {
                 AER::InternalServerError(ApiError::new("HE", 0, "Something went wrong", None))
             },
+            Self::ServiceDraining { retry_after_secs } => {
+                AER::ServiceUnavailable(ApiError::new("HE", 0, format!("The server is not accepting new payments, retry after {retry_after_secs} seconds"), None), *retry_after_secs)
+            },
             Self::HealthCheckError { message,component } => {
                 AER::InternalServerError(ApiError::new("HE",0,format!("{component} health check failed with error: {message}"),None))
             },
@@ -670,6 +685,21 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::MaxFieldLengthViolated { connector, field_name,  max_length, received_length} => {
                 AER::BadRequest(ApiError::new("IR", 47, format!("Connector '{connector}' rejected field '{field_name}': length {received_length} exceeds maximum of {max_length}"), Some(Extra {connector: Some(connector.to_string()), ..Default::default()})))
             }
+            Self::ApiKeyIpAddressNotAllowed { ip_address } => {
+                AER::ForbiddenCommonResource(ApiError::new("IR", 48, format!("IP address {ip_address} is not permitted to use this API key"), None))
+            }
+            Self::MerchantIpAddressNotAllowed { ip_address } => {
+                AER::ForbiddenCommonResource(ApiError::new("IR", 49, format!("IP address {ip_address} is not permitted to access this merchant account"), None))
+            }
+            Self::TooManyRequests { retry_after_secs } => {
+                AER::TooManyRequests(ApiError::new("IR", 50, format!("Too many requests, retry after {retry_after_secs} seconds"), None), *retry_after_secs)
+            }
+            Self::IdempotencyKeyReused { idempotency_key } => {
+                AER::Conflict(ApiError::new("IR", 51, format!("Idempotency key {idempotency_key} has already been used with a different request"), None))
+            }
+            Self::IdempotencyKeyInProgress { idempotency_key } => {
+                AER::Conflict(ApiError::new("IR", 52, format!("Another request with idempotency key {idempotency_key} is currently being processed"), None))
+            }
             Self::WebhookAuthenticationFailed => {
                 AER::Unauthorized(ApiError::new("WE", 1, "Webhook authentication failed", None))
             }
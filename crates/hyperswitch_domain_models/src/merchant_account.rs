@@ -50,6 +50,7 @@ pub struct MerchantAccount {
     pub is_platform_account: bool,
     pub product_type: Option<common_enums::MerchantProductType>,
     pub merchant_account_type: common_enums::MerchantAccountType,
+    pub allowed_ip_ranges: Option<Vec<String>>,
 }
 
 #[cfg(feature = "v1")]
@@ -87,6 +88,7 @@ pub struct MerchantAccountSetter {
     pub is_platform_account: bool,
     pub product_type: Option<common_enums::MerchantProductType>,
     pub merchant_account_type: common_enums::MerchantAccountType,
+    pub allowed_ip_ranges: Option<Vec<String>>,
 }
 
 #[cfg(feature = "v1")]
@@ -124,6 +126,7 @@ impl From<MerchantAccountSetter> for MerchantAccount {
             is_platform_account: item.is_platform_account,
             product_type: item.product_type,
             merchant_account_type: item.merchant_account_type,
+            allowed_ip_ranges: item.allowed_ip_ranges,
         }
     }
 }
@@ -146,6 +149,7 @@ pub struct MerchantAccountSetter {
     pub version: common_enums::ApiVersion,
     pub product_type: Option<common_enums::MerchantProductType>,
     pub merchant_account_type: common_enums::MerchantAccountType,
+    pub allowed_ip_ranges: Option<Vec<String>>,
 }
 
 #[cfg(feature = "v2")]
@@ -166,6 +170,7 @@ impl From<MerchantAccountSetter> for MerchantAccount {
             version,
             product_type,
             merchant_account_type,
+            allowed_ip_ranges,
         } = item;
         Self {
             id,
@@ -182,6 +187,7 @@ impl From<MerchantAccountSetter> for MerchantAccount {
             version,
             product_type,
             merchant_account_type,
+            allowed_ip_ranges,
         }
     }
 }
@@ -203,6 +209,7 @@ pub struct MerchantAccount {
     pub version: common_enums::ApiVersion,
     pub product_type: Option<common_enums::MerchantProductType>,
     pub merchant_account_type: common_enums::MerchantAccountType,
+    pub allowed_ip_ranges: Option<Vec<String>>,
 }
 
 impl MerchantAccount {
@@ -273,6 +280,7 @@ pub enum MerchantAccountUpdate {
         default_profile: Option<Option<common_utils::id_type::ProfileId>>,
         payment_link_config: Option<serde_json::Value>,
         pm_collect_link_config: Option<serde_json::Value>,
+        allowed_ip_ranges: Option<Option<Vec<String>>>,
     },
     StorageSchemeUpdate {
         storage_scheme: MerchantStorageScheme,
@@ -293,6 +301,7 @@ pub enum MerchantAccountUpdate {
         merchant_details: OptionalEncryptableValue,
         publishable_key: Option<String>,
         metadata: Option<Box<pii::SecretSerdeValue>>,
+        allowed_ip_ranges: Option<Option<Vec<String>>>,
     },
     StorageSchemeUpdate {
         storage_scheme: MerchantStorageScheme,
@@ -331,6 +340,7 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 default_profile,
                 payment_link_config,
                 pm_collect_link_config,
+                allowed_ip_ranges,
             } => Self {
                 merchant_name: merchant_name.map(Encryption::from),
                 merchant_details: merchant_details.map(Encryption::from),
@@ -359,6 +369,10 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 recon_status: None,
                 is_platform_account: None,
                 product_type: None,
+                allowed_ip_ranges: allowed_ip_ranges.map(|allowed_ip_ranges| {
+                    allowed_ip_ranges
+                        .map(|allowed_ip_ranges| allowed_ip_ranges.into_iter().map(Some).collect())
+                }),
             },
             MerchantAccountUpdate::StorageSchemeUpdate { storage_scheme } => Self {
                 storage_scheme: Some(storage_scheme),
@@ -388,6 +402,7 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 pm_collect_link_config: None,
                 is_platform_account: None,
                 product_type: None,
+                allowed_ip_ranges: None,
             },
             MerchantAccountUpdate::ReconUpdate { recon_status } => Self {
                 recon_status: Some(recon_status),
@@ -417,6 +432,7 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 pm_collect_link_config: None,
                 is_platform_account: None,
                 product_type: None,
+                allowed_ip_ranges: None,
             },
             MerchantAccountUpdate::UnsetDefaultProfile => Self {
                 default_profile: Some(None),
@@ -446,6 +462,7 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 pm_collect_link_config: None,
                 is_platform_account: None,
                 product_type: None,
+                allowed_ip_ranges: None,
             },
             MerchantAccountUpdate::ModifiedAtUpdate => Self {
                 modified_at: now,
@@ -475,6 +492,7 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 pm_collect_link_config: None,
                 is_platform_account: None,
                 product_type: None,
+                allowed_ip_ranges: None,
             },
             MerchantAccountUpdate::ToPlatformAccount => Self {
                 modified_at: now,
@@ -504,6 +522,7 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 pm_collect_link_config: None,
                 is_platform_account: Some(true),
                 product_type: None,
+                allowed_ip_ranges: None,
             },
         }
     }
@@ -520,6 +539,7 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 merchant_details,
                 publishable_key,
                 metadata,
+                allowed_ip_ranges,
             } => Self {
                 merchant_name: merchant_name.map(Encryption::from),
                 merchant_details: merchant_details.map(Encryption::from),
@@ -531,6 +551,10 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 recon_status: None,
                 is_platform_account: None,
                 product_type: None,
+                allowed_ip_ranges: allowed_ip_ranges.map(|allowed_ip_ranges| {
+                    allowed_ip_ranges
+                        .map(|allowed_ip_ranges| allowed_ip_ranges.into_iter().map(Some).collect())
+                }),
             },
             MerchantAccountUpdate::StorageSchemeUpdate { storage_scheme } => Self {
                 storage_scheme: Some(storage_scheme),
@@ -543,6 +567,7 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 recon_status: None,
                 is_platform_account: None,
                 product_type: None,
+                allowed_ip_ranges: None,
             },
             MerchantAccountUpdate::ReconUpdate { recon_status } => Self {
                 recon_status: Some(recon_status),
@@ -555,6 +580,7 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 organization_id: None,
                 is_platform_account: None,
                 product_type: None,
+                allowed_ip_ranges: None,
             },
             MerchantAccountUpdate::ModifiedAtUpdate => Self {
                 modified_at: now,
@@ -567,6 +593,7 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 recon_status: None,
                 is_platform_account: None,
                 product_type: None,
+                allowed_ip_ranges: None,
             },
             MerchantAccountUpdate::ToPlatformAccount => Self {
                 modified_at: now,
@@ -579,6 +606,7 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 recon_status: None,
                 is_platform_account: Some(true),
                 product_type: None,
+                allowed_ip_ranges: None,
             },
         }
     }
@@ -607,6 +635,9 @@ impl super::behaviour::Conversion for MerchantAccount {
             is_platform_account: self.is_platform_account,
             product_type: self.product_type,
             merchant_account_type: self.merchant_account_type,
+            allowed_ip_ranges: self
+                .allowed_ip_ranges
+                .map(|allowed_ip_ranges| allowed_ip_ranges.into_iter().map(Some).collect()),
         };
 
         Ok(diesel_models::MerchantAccount::from(setter))
@@ -670,6 +701,9 @@ impl super::behaviour::Conversion for MerchantAccount {
                 version: item.version,
                 product_type: item.product_type,
                 merchant_account_type: item.merchant_account_type.unwrap_or_default(),
+                allowed_ip_ranges: item
+                    .allowed_ip_ranges
+                    .map(|allowed_ip_ranges| allowed_ip_ranges.into_iter().flatten().collect()),
             })
         }
         .await
@@ -696,6 +730,9 @@ impl super::behaviour::Conversion for MerchantAccount {
                 .product_type
                 .or(Some(common_enums::MerchantProductType::Orchestration)),
             merchant_account_type: self.merchant_account_type,
+            allowed_ip_ranges: self
+                .allowed_ip_ranges
+                .map(|allowed_ip_ranges| allowed_ip_ranges.into_iter().map(Some).collect()),
         })
     }
 }
@@ -738,6 +775,9 @@ impl super::behaviour::Conversion for MerchantAccount {
             is_platform_account: self.is_platform_account,
             product_type: self.product_type,
             merchant_account_type: self.merchant_account_type,
+            allowed_ip_ranges: self
+                .allowed_ip_ranges
+                .map(|allowed_ip_ranges| allowed_ip_ranges.into_iter().map(Some).collect()),
         };
 
         Ok(diesel_models::MerchantAccount::from(setter))
@@ -818,6 +858,9 @@ impl super::behaviour::Conversion for MerchantAccount {
                 is_platform_account: item.is_platform_account,
                 product_type: item.product_type,
                 merchant_account_type: item.merchant_account_type.unwrap_or_default(),
+                allowed_ip_ranges: item
+                    .allowed_ip_ranges
+                    .map(|allowed_ip_ranges| allowed_ip_ranges.into_iter().flatten().collect()),
             })
         }
         .await
@@ -862,6 +905,9 @@ impl super::behaviour::Conversion for MerchantAccount {
                 .product_type
                 .or(Some(common_enums::MerchantProductType::Orchestration)),
             merchant_account_type: self.merchant_account_type,
+            allowed_ip_ranges: self
+                .allowed_ip_ranges
+                .map(|allowed_ip_ranges| allowed_ip_ranges.into_iter().map(Some).collect()),
         })
     }
 }
@@ -23,7 +23,8 @@ use common_utils::{
 };
 #[cfg(feature = "v1")]
 use diesel_models::{
-    ConnectorMandateReferenceId, NetworkDetails, PaymentAttemptUpdate as DieselPaymentAttemptUpdate,
+    ConnectorMandateReferenceId, DccDetails, DeviceFingerprint, NetworkDetails,
+    PaymentAttemptUpdate as DieselPaymentAttemptUpdate,
 };
 use diesel_models::{
     PaymentAttempt as DieselPaymentAttempt, PaymentAttemptNew as DieselPaymentAttemptNew,
@@ -1032,6 +1033,8 @@ pub struct PaymentAttempt {
     pub network_transaction_id: Option<String>,
     pub is_overcapture_enabled: Option<OvercaptureEnabledBool>,
     pub network_details: Option<NetworkDetails>,
+    pub dcc_details: Option<DccDetails>,
+    pub device_fingerprint: Option<DeviceFingerprint>,
 }
 
 #[cfg(feature = "v1")]
@@ -1330,6 +1333,8 @@ pub struct PaymentAttemptNew {
     pub connector_request_reference_id: Option<String>,
     pub network_transaction_id: Option<String>,
     pub network_details: Option<NetworkDetails>,
+    pub dcc_details: Option<DccDetails>,
+    pub device_fingerprint: Option<DeviceFingerprint>,
 }
 
 #[cfg(feature = "v1")]
@@ -1401,6 +1406,8 @@ pub enum PaymentAttemptUpdate {
         routing_approach: Option<storage_enums::RoutingApproach>,
         connector_request_reference_id: Option<String>,
         network_transaction_id: Option<String>,
+        dcc_details: Option<DccDetails>,
+        device_fingerprint: Option<DeviceFingerprint>,
     },
     RejectUpdate {
         status: storage_enums::AttemptStatus,
@@ -1518,6 +1525,7 @@ pub enum PaymentAttemptUpdate {
     IncrementalAuthorizationAmountUpdate {
         net_amount: NetAmount,
         amount_capturable: MinorUnit,
+        status: storage_enums::AttemptStatus,
     },
     AuthenticationUpdate {
         status: storage_enums::AttemptStatus,
@@ -1676,6 +1684,8 @@ impl PaymentAttemptUpdate {
                 routing_approach,
                 connector_request_reference_id,
                 network_transaction_id,
+                dcc_details,
+                device_fingerprint,
             } => DieselPaymentAttemptUpdate::ConfirmUpdate {
                 amount: net_amount.get_order_amount(),
                 currency,
@@ -1720,6 +1730,8 @@ impl PaymentAttemptUpdate {
                 }),
                 connector_request_reference_id,
                 network_transaction_id,
+                dcc_details,
+                device_fingerprint,
             },
             Self::VoidUpdate {
                 status,
@@ -1907,9 +1919,13 @@ impl PaymentAttemptUpdate {
             Self::IncrementalAuthorizationAmountUpdate {
                 net_amount,
                 amount_capturable,
+                status,
             } => DieselPaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
                 amount: net_amount.get_order_amount(),
                 amount_capturable,
+                status,
+                surcharge_amount: net_amount.get_surcharge_amount(),
+                tax_amount: net_amount.get_tax_on_surcharge(),
             },
             Self::AuthenticationUpdate {
                 status,
@@ -2164,6 +2180,8 @@ impl behaviour::Conversion for PaymentAttempt {
             network_transaction_id: self.network_transaction_id,
             is_overcapture_enabled: self.is_overcapture_enabled,
             network_details: self.network_details,
+            dcc_details: self.dcc_details,
+            device_fingerprint: self.device_fingerprint,
         })
     }
 
@@ -2265,6 +2283,8 @@ impl behaviour::Conversion for PaymentAttempt {
                 network_transaction_id: storage_model.network_transaction_id,
                 is_overcapture_enabled: storage_model.is_overcapture_enabled,
                 network_details: storage_model.network_details,
+                dcc_details: storage_model.dcc_details,
+                device_fingerprint: storage_model.device_fingerprint,
             })
         }
         .await
@@ -2357,6 +2377,8 @@ impl behaviour::Conversion for PaymentAttempt {
             connector_request_reference_id: self.connector_request_reference_id,
             network_transaction_id: self.network_transaction_id,
             network_details: self.network_details,
+            dcc_details: self.dcc_details,
+            device_fingerprint: self.device_fingerprint,
         })
     }
 }
@@ -2528,6 +2550,8 @@ impl behaviour::Conversion for PaymentAttempt {
             network_transaction_id,
             is_overcapture_enabled: None,
             network_details: None,
+            dcc_details: None,
+            device_fingerprint: None,
         })
     }
 
@@ -2806,6 +2830,8 @@ impl behaviour::Conversion for PaymentAttempt {
             created_by: created_by.map(|cb| cb.to_string()),
             connector_request_reference_id,
             network_details: None,
+            dcc_details: None,
+            device_fingerprint: None,
         })
     }
 }
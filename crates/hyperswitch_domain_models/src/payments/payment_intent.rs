@@ -247,6 +247,8 @@ pub struct PaymentIntentUpdateFields {
     pub order_date: Option<PrimitiveDateTime>,
     pub shipping_amount_tax: Option<MinorUnit>,
     pub duty_amount: Option<MinorUnit>,
+    pub purchase_order_number: Option<String>,
+    pub mit_category: Option<common_enums::MitCategory>,
     pub is_confirm_operation: bool,
     pub payment_channel: Option<common_enums::PaymentChannel>,
     pub feature_metadata: Option<Secret<serde_json::Value>>,
@@ -318,9 +320,11 @@ pub enum PaymentIntentUpdate {
     },
     IncrementalAuthorizationAmountUpdate {
         amount: MinorUnit,
+        status: common_enums::IntentStatus,
     },
     AuthorizationCountUpdate {
         authorization_count: i32,
+        status: common_enums::IntentStatus,
     },
     CompleteAuthorizeUpdate {
         shipping_address_id: Option<String>,
@@ -335,6 +339,12 @@ pub enum PaymentIntentUpdate {
         updated_by: String,
         shipping_details: Option<Encryptable<Secret<serde_json::Value>>>,
     },
+    PiiRedactionUpdate {
+        customer_details: Option<Encryptable<Secret<serde_json::Value>>>,
+        billing_details: Option<Encryptable<Secret<serde_json::Value>>>,
+        shipping_details: Option<Encryptable<Secret<serde_json::Value>>>,
+        updated_by: String,
+    },
 }
 
 #[cfg(feature = "v1")]
@@ -447,6 +457,8 @@ pub struct PaymentIntentUpdateInternal {
     pub order_date: Option<PrimitiveDateTime>,
     pub shipping_amount_tax: Option<MinorUnit>,
     pub duty_amount: Option<MinorUnit>,
+    pub purchase_order_number: Option<String>,
+    pub mit_category: Option<common_enums::MitCategory>,
     pub enable_partial_authorization: Option<bool>,
     pub enable_overcapture: Option<common_types::primitive_wrappers::EnableOvercaptureBool>,
 }
@@ -854,6 +866,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 order_date: value.order_date,
                 shipping_amount_tax: value.shipping_amount_tax,
                 duty_amount: value.duty_amount,
+                purchase_order_number: value.purchase_order_number,
+                mit_category: value.mit_category,
                 ..Default::default()
             },
             PaymentIntentUpdate::PaymentCreateUpdate {
@@ -974,14 +988,17 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 updated_by,
                 ..Default::default()
             },
-            PaymentIntentUpdate::IncrementalAuthorizationAmountUpdate { amount } => Self {
+            PaymentIntentUpdate::IncrementalAuthorizationAmountUpdate { amount, status } => Self {
                 amount: Some(amount),
+                status: Some(status),
                 ..Default::default()
             },
             PaymentIntentUpdate::AuthorizationCountUpdate {
                 authorization_count,
+                status,
             } => Self {
                 authorization_count: Some(authorization_count),
+                status: Some(status),
                 ..Default::default()
             },
             PaymentIntentUpdate::CompleteAuthorizeUpdate {
@@ -1008,6 +1025,19 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details,
                 ..Default::default()
             },
+            PaymentIntentUpdate::PiiRedactionUpdate {
+                customer_details,
+                billing_details,
+                shipping_details,
+                updated_by,
+            } => Self {
+                customer_details,
+                billing_details,
+                shipping_details,
+                modified_at: Some(common_utils::date_time::now()),
+                updated_by,
+                ..Default::default()
+            },
         }
     }
 }
@@ -1098,6 +1128,8 @@ impl From<PaymentIntentUpdate> for DieselPaymentIntentUpdate {
                     order_date: value.order_date,
                     shipping_amount_tax: value.shipping_amount_tax,
                     duty_amount: value.duty_amount,
+                    purchase_order_number: value.purchase_order_number,
+                    mit_category: value.mit_category,
                     enable_partial_authorization: value.enable_partial_authorization,
                     enable_overcapture: value.enable_overcapture,
                 }))
@@ -1186,13 +1218,15 @@ impl From<PaymentIntentUpdate> for DieselPaymentIntentUpdate {
                 surcharge_applicable: Some(surcharge_applicable),
                 updated_by,
             },
-            PaymentIntentUpdate::IncrementalAuthorizationAmountUpdate { amount } => {
-                Self::IncrementalAuthorizationAmountUpdate { amount }
+            PaymentIntentUpdate::IncrementalAuthorizationAmountUpdate { amount, status } => {
+                Self::IncrementalAuthorizationAmountUpdate { amount, status }
             }
             PaymentIntentUpdate::AuthorizationCountUpdate {
                 authorization_count,
+                status,
             } => Self::AuthorizationCountUpdate {
                 authorization_count,
+                status,
             },
             PaymentIntentUpdate::CompleteAuthorizeUpdate {
                 shipping_address_id,
@@ -1213,6 +1247,17 @@ impl From<PaymentIntentUpdate> for DieselPaymentIntentUpdate {
                 updated_by,
                 shipping_details: shipping_details.map(Encryption::from),
             },
+            PaymentIntentUpdate::PiiRedactionUpdate {
+                customer_details,
+                billing_details,
+                shipping_details,
+                updated_by,
+            } => Self::PiiRedactionUpdate {
+                customer_details: customer_details.map(Encryption::from),
+                billing_details: billing_details.map(Encryption::from),
+                shipping_details: shipping_details.map(Encryption::from),
+                updated_by,
+            },
         }
     }
 }
@@ -1267,6 +1312,8 @@ impl From<PaymentIntentUpdateInternal> for diesel_models::PaymentIntentUpdateInt
             order_date,
             shipping_amount_tax,
             duty_amount,
+            purchase_order_number,
+            mit_category,
             enable_partial_authorization,
             enable_overcapture,
         } = value;
@@ -1317,6 +1364,8 @@ impl From<PaymentIntentUpdateInternal> for diesel_models::PaymentIntentUpdateInt
             order_date,
             shipping_amount_tax,
             duty_amount,
+            purchase_order_number,
+            mit_category,
             enable_partial_authorization,
             enable_overcapture,
         }
@@ -1377,6 +1426,7 @@ pub struct PaymentIntentListParams {
     pub card_network: Option<Vec<common_enums::CardNetwork>>,
     pub card_discovery: Option<Vec<common_enums::CardDiscovery>>,
     pub merchant_order_reference_id: Option<String>,
+    pub cursor: Option<common_utils::types::ListCursor>,
 }
 
 #[cfg(feature = "v2")]
@@ -1416,6 +1466,7 @@ impl From<api_models::payments::PaymentListConstraints> for PaymentIntentFetchCo
             created_gt,
             created_lte,
             created_gte,
+            cursor,
         } = value;
         Self::List(Box::new(PaymentIntentListParams {
             offset: 0,
@@ -1438,6 +1489,7 @@ impl From<api_models::payments::PaymentListConstraints> for PaymentIntentFetchCo
             card_network: None,
             card_discovery: None,
             merchant_order_reference_id: None,
+            cursor,
         }))
     }
 }
@@ -1529,6 +1581,7 @@ impl From<common_utils::types::TimeRange> for PaymentIntentFetchConstraints {
             card_network: None,
             card_discovery: None,
             merchant_order_reference_id: None,
+            cursor: None,
         }))
     }
 }
@@ -1555,6 +1608,8 @@ impl From<api_models::payments::PaymentListFilterConstraints> for PaymentIntentF
             card_network,
             card_discovery,
             merchant_order_reference_id,
+            cursor,
+            view_id: _,
         } = value;
         if let Some(payment_intent_id) = payment_id {
             Self::Single { payment_intent_id }
@@ -1580,6 +1635,7 @@ impl From<api_models::payments::PaymentListFilterConstraints> for PaymentIntentF
                 card_network,
                 card_discovery,
                 merchant_order_reference_id,
+                cursor,
             }))
         }
     }
@@ -1787,6 +1843,8 @@ impl behaviour::Conversion for PaymentIntent {
             discount_amount: None,
             shipping_amount_tax: None,
             duty_amount: None,
+            purchase_order_number: None,
+            mit_category: None,
             order_date: None,
             enable_partial_authorization: None,
             enable_overcapture: None,
@@ -2027,6 +2085,8 @@ impl behaviour::Conversion for PaymentIntent {
             discount_amount: None,
             shipping_amount_tax: None,
             duty_amount: None,
+            purchase_order_number: None,
+            mit_category: None,
             order_date: None,
             enable_partial_authorization: None,
         })
@@ -2110,6 +2170,8 @@ impl behaviour::Conversion for PaymentIntent {
             order_date: self.order_date,
             shipping_amount_tax: self.shipping_amount_tax,
             duty_amount: self.duty_amount,
+            purchase_order_number: self.purchase_order_number,
+            mit_category: self.mit_category,
             enable_partial_authorization: self.enable_partial_authorization,
             enable_overcapture: self.enable_overcapture,
         })
@@ -2218,6 +2280,8 @@ impl behaviour::Conversion for PaymentIntent {
                 discount_amount: storage_model.discount_amount,
                 shipping_amount_tax: storage_model.shipping_amount_tax,
                 duty_amount: storage_model.duty_amount,
+                purchase_order_number: storage_model.purchase_order_number,
+                mit_category: storage_model.mit_category,
                 order_date: storage_model.order_date,
                 enable_partial_authorization: storage_model.enable_partial_authorization,
                 enable_overcapture: storage_model.enable_overcapture,
@@ -2300,6 +2364,8 @@ impl behaviour::Conversion for PaymentIntent {
             order_date: self.order_date,
             shipping_amount_tax: self.shipping_amount_tax,
             duty_amount: self.duty_amount,
+            purchase_order_number: self.purchase_order_number,
+            mit_category: self.mit_category,
             enable_partial_authorization: self.enable_partial_authorization,
             enable_overcapture: self.enable_overcapture,
         })
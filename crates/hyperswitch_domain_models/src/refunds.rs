@@ -16,6 +16,7 @@ pub struct RefundListConstraints {
     pub merchant_connector_id: Option<Vec<common_utils::id_type::MerchantConnectorAccountId>>,
     pub currency: Option<Vec<common_enums::Currency>>,
     pub refund_status: Option<Vec<common_enums::RefundStatus>>,
+    pub cursor: Option<common_utils::types::ListCursor>,
 }
 
 #[cfg(feature = "v2")]
@@ -60,6 +61,8 @@ impl
             time_range,
             amount_filter,
             merchant_connector_id,
+            cursor,
+            view_id: _,
         } = value;
         let profile_id_from_request_body = profile_id;
         let profile_id_list = match (profile_id_from_request_body, auth_profile_id_list) {
@@ -97,6 +100,7 @@ impl
             merchant_connector_id,
             currency,
             refund_status,
+            cursor,
         })
     }
 }
@@ -56,6 +56,9 @@ pub struct RouterData<Flow, Request, Response> {
     ///for switching between two different versions of the same connector
     pub connector_api_version: Option<String>,
 
+    /// Merchant connector account with which the request is sent to the connector
+    pub merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+
     /// Contains flow-specific data required to construct a request and send it to the connector.
     pub request: Request,
 
@@ -126,6 +129,7 @@ pub struct L2L3Data {
     pub duty_amount: Option<MinorUnit>,
     pub order_tax_amount: Option<MinorUnit>,
     pub merchant_order_reference_id: Option<String>,
+    pub purchase_order_number: Option<String>,
     pub customer_id: Option<id_type::CustomerId>,
     pub shipping_origin_zip: Option<Secret<String>>,
     pub shipping_state: Option<Secret<String>>,
@@ -465,6 +469,7 @@ pub struct ConnectorResponseData {
     pub additional_payment_method_data: Option<AdditionalPaymentMethodConnectorResponse>,
     extended_authorization_response_data: Option<ExtendedAuthorizationResponseData>,
     is_overcapture_enabled: Option<primitive_wrappers::OvercaptureEnabledBool>,
+    account_updater_card_details: Option<AccountUpdaterCardDetails>,
 }
 
 impl ConnectorResponseData {
@@ -475,6 +480,7 @@ impl ConnectorResponseData {
             additional_payment_method_data: Some(additional_payment_method_data),
             extended_authorization_response_data: None,
             is_overcapture_enabled: None,
+            account_updater_card_details: None,
         }
     }
     pub fn new(
@@ -486,9 +492,18 @@ impl ConnectorResponseData {
             additional_payment_method_data,
             extended_authorization_response_data,
             is_overcapture_enabled,
+            account_updater_card_details: None,
         }
     }
 
+    pub fn with_account_updater_card_details(
+        mut self,
+        account_updater_card_details: Option<AccountUpdaterCardDetails>,
+    ) -> Self {
+        self.account_updater_card_details = account_updater_card_details;
+        self
+    }
+
     pub fn get_extended_authorization_response_data(
         &self,
     ) -> Option<&ExtendedAuthorizationResponseData> {
@@ -498,6 +513,18 @@ impl ConnectorResponseData {
     pub fn is_overcapture_enabled(&self) -> Option<primitive_wrappers::OvercaptureEnabledBool> {
         self.is_overcapture_enabled
     }
+
+    pub fn get_account_updater_card_details(&self) -> Option<&AccountUpdaterCardDetails> {
+        self.account_updater_card_details.as_ref()
+    }
+}
+
+/// Card details as currently known to the connector, which include the changes received from the
+/// account updater services of the card networks
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct AccountUpdaterCardDetails {
+    pub card_exp_month: Secret<String>,
+    pub card_exp_year: Secret<String>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -824,7 +851,9 @@ impl
             // For these statuses, update the capturable amount when it reaches terminal / capturable state
             common_enums::IntentStatus::RequiresCustomerAction
             | common_enums::IntentStatus::RequiresMerchantAction
-            | common_enums::IntentStatus::Processing => None,
+            | common_enums::IntentStatus::RequiresMerchantReview
+            | common_enums::IntentStatus::Processing
+            | common_enums::IntentStatus::IncrementalAuthorizationPending => None,
             // Invalid states for this flow
             common_enums::IntentStatus::RequiresPaymentMethod
             | common_enums::IntentStatus::RequiresConfirmation
@@ -863,7 +892,9 @@ impl
             // For these statuses, update the amount captured when it reaches terminal state
             common_enums::IntentStatus::RequiresCustomerAction
             | common_enums::IntentStatus::RequiresMerchantAction
-            | common_enums::IntentStatus::Processing => None,
+            | common_enums::IntentStatus::RequiresMerchantReview
+            | common_enums::IntentStatus::Processing
+            | common_enums::IntentStatus::IncrementalAuthorizationPending => None,
             // Invalid states for this flow
             common_enums::IntentStatus::RequiresPaymentMethod
             | common_enums::IntentStatus::RequiresConfirmation => None,
@@ -1043,7 +1074,9 @@ impl
             // For these statuses, update the capturable amount when it reaches terminal / capturable state
             common_enums::IntentStatus::RequiresCustomerAction
             | common_enums::IntentStatus::RequiresMerchantAction
-            | common_enums::IntentStatus::Processing => None,
+            | common_enums::IntentStatus::RequiresMerchantReview
+            | common_enums::IntentStatus::Processing
+            | common_enums::IntentStatus::IncrementalAuthorizationPending => None,
             // Invalid states for this flow
             common_enums::IntentStatus::RequiresPaymentMethod
             | common_enums::IntentStatus::RequiresConfirmation => None,
@@ -1090,7 +1123,9 @@ impl
             // For these statuses, update the amount captured when it reaches terminal state
             common_enums::IntentStatus::RequiresCustomerAction
             | common_enums::IntentStatus::RequiresMerchantAction
-            | common_enums::IntentStatus::Processing => None,
+            | common_enums::IntentStatus::RequiresMerchantReview
+            | common_enums::IntentStatus::Processing
+            | common_enums::IntentStatus::IncrementalAuthorizationPending => None,
             // Invalid states for this flow
             common_enums::IntentStatus::RequiresPaymentMethod
             | common_enums::IntentStatus::RequiresConfirmation => None,
@@ -1285,7 +1320,9 @@ impl
             // For these statuses, update the capturable amount when it reaches terminal / capturable state
             common_enums::IntentStatus::RequiresCustomerAction
             | common_enums::IntentStatus::RequiresMerchantAction
-            | common_enums::IntentStatus::Processing => None,
+            | common_enums::IntentStatus::RequiresMerchantReview
+            | common_enums::IntentStatus::Processing
+            | common_enums::IntentStatus::IncrementalAuthorizationPending => None,
             // Invalid states for this flow
             common_enums::IntentStatus::RequiresPaymentMethod
             | common_enums::IntentStatus::RequiresConfirmation => None,
@@ -1326,7 +1363,9 @@ impl
             // For these statuses, update the amount captured when it reaches terminal state
             common_enums::IntentStatus::RequiresCustomerAction
             | common_enums::IntentStatus::RequiresMerchantAction
-            | common_enums::IntentStatus::Processing => None,
+            | common_enums::IntentStatus::RequiresMerchantReview
+            | common_enums::IntentStatus::Processing
+            | common_enums::IntentStatus::IncrementalAuthorizationPending => None,
             // Invalid states for this flow
             common_enums::IntentStatus::RequiresPaymentMethod
             | common_enums::IntentStatus::RequiresConfirmation => None,
@@ -1537,7 +1576,9 @@ impl
             | common_enums::IntentStatus::Expired => Some(MinorUnit::zero()),
             common_enums::IntentStatus::RequiresCustomerAction
             | common_enums::IntentStatus::RequiresMerchantAction
-            | common_enums::IntentStatus::Processing => None,
+            | common_enums::IntentStatus::RequiresMerchantReview
+            | common_enums::IntentStatus::Processing
+            | common_enums::IntentStatus::IncrementalAuthorizationPending => None,
             common_enums::IntentStatus::RequiresPaymentMethod
             | common_enums::IntentStatus::RequiresConfirmation => None,
             common_enums::IntentStatus::RequiresCapture
@@ -1567,7 +1608,9 @@ impl
             | common_enums::IntentStatus::Expired => Some(MinorUnit::zero()),
             common_enums::IntentStatus::RequiresCustomerAction
             | common_enums::IntentStatus::RequiresMerchantAction
-            | common_enums::IntentStatus::Processing => None,
+            | common_enums::IntentStatus::RequiresMerchantReview
+            | common_enums::IntentStatus::Processing
+            | common_enums::IntentStatus::IncrementalAuthorizationPending => None,
             common_enums::IntentStatus::RequiresPaymentMethod
             | common_enums::IntentStatus::RequiresConfirmation => None,
             common_enums::IntentStatus::RequiresCapture
@@ -1760,7 +1803,9 @@ impl
             // For these statuses, update the capturable amount when it reaches terminal / capturable state
             common_enums::IntentStatus::RequiresCustomerAction
             | common_enums::IntentStatus::RequiresMerchantAction
-            | common_enums::IntentStatus::Processing => None,
+            | common_enums::IntentStatus::RequiresMerchantReview
+            | common_enums::IntentStatus::Processing
+            | common_enums::IntentStatus::IncrementalAuthorizationPending => None,
             // Invalid states for this flow
             common_enums::IntentStatus::RequiresPaymentMethod
             | common_enums::IntentStatus::RequiresConfirmation => None,
@@ -1799,7 +1844,9 @@ impl
             // For these statuses, update the amount captured when it reaches terminal state
             common_enums::IntentStatus::RequiresCustomerAction
             | common_enums::IntentStatus::RequiresMerchantAction
-            | common_enums::IntentStatus::Processing => None,
+            | common_enums::IntentStatus::RequiresMerchantReview
+            | common_enums::IntentStatus::Processing
+            | common_enums::IntentStatus::IncrementalAuthorizationPending => None,
             // Invalid states for this flow
             common_enums::IntentStatus::RequiresPaymentMethod
             | common_enums::IntentStatus::RequiresConfirmation => None,
@@ -87,6 +87,18 @@ pub struct PaymentsAuthorizeData {
     pub payment_channel: Option<common_enums::PaymentChannel>,
     pub enable_partial_authorization: Option<bool>,
     pub enable_overcapture: Option<common_types::primitive_wrappers::EnableOvercaptureBool>,
+    pub mit_category: Option<common_enums::MitCategory>,
+    pub dcc_data: Option<DccData>,
+}
+
+/// Dynamic currency conversion accepted by the customer, with which the converted amount is charged
+/// in the currency of the card
+#[derive(Debug, Clone)]
+pub struct DccData {
+    pub quote_id: String,
+    pub card_currency: storage_enums::Currency,
+    pub converted_amount: MinorUnit,
+    pub rate: String,
 }
 
 #[derive(Debug, Clone)]
@@ -16,6 +16,7 @@ pub struct FraudCheckSaleData {
     pub order_details: Option<Vec<OrderDetailsWithAmount>>,
     pub currency: Option<common_enums::Currency>,
     pub email: Option<Email>,
+    pub device_fingerprint: Option<api_models::payments::DeviceFingerprint>,
 }
 
 #[derive(Debug, Clone)]
@@ -27,6 +28,7 @@ pub struct FraudCheckCheckoutData {
     pub payment_method_data: Option<api_models::payments::AdditionalPaymentData>,
     pub email: Option<Email>,
     pub gateway: Option<String>,
+    pub device_fingerprint: Option<api_models::payments::DeviceFingerprint>,
 }
 
 #[derive(Debug, Clone)]
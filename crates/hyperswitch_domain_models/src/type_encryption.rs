@@ -8,6 +8,7 @@ use common_utils::{
     types::keymanager::{Identifier, KeyManagerState},
 };
 use encrypt::TypeEncryption;
+use error_stack::ResultExt;
 use masking::Secret;
 use router_env::{instrument, tracing};
 use rustc_hash::FxHashMap;
@@ -1262,6 +1263,10 @@ pub enum CryptoOperation<T: Clone, S: masking::Strategy<T>> {
     DecryptOptional(Option<Encryption>),
     BatchEncrypt(FxHashMap<String, Secret<T, S>>),
     BatchDecrypt(FxHashMap<String, Encryption>),
+    /// Encrypts the fields of multiple entities belonging to the same identifier in a single call
+    MultipleBatchEncrypt(Vec<FxHashMap<String, Secret<T, S>>>),
+    /// Decrypts the fields of multiple entities belonging to the same identifier in a single call
+    MultipleBatchDecrypt(Vec<FxHashMap<String, Encryption>>),
 }
 
 use errors::CryptoError;
@@ -1272,6 +1277,7 @@ pub enum CryptoOutput<T: Clone, S: masking::Strategy<T>> {
     Operation(crypto::Encryptable<Secret<T, S>>),
     OptionalOperation(Option<crypto::Encryptable<Secret<T, S>>>),
     BatchOperation(FxHashMap<String, crypto::Encryptable<Secret<T, S>>>),
+    MultipleBatchOperation(Vec<FxHashMap<String, crypto::Encryptable<Secret<T, S>>>>),
 }
 
 // Do not remove the `skip_all` as the key would be logged otherwise
@@ -1312,7 +1318,62 @@ where
             let data = batch_decrypt(state, data, identifier, key).await?;
             Ok(CryptoOutput::BatchOperation(data))
         }
+        CryptoOperation::MultipleBatchEncrypt(data) => {
+            let count = data.len();
+            let data = batch_encrypt(state, flatten_batches(data), identifier, key).await?;
+            Ok(CryptoOutput::MultipleBatchOperation(unflatten_batches(
+                data, count,
+            )?))
+        }
+        CryptoOperation::MultipleBatchDecrypt(data) => {
+            let count = data.len();
+            let data = batch_decrypt(state, flatten_batches(data), identifier, key).await?;
+            Ok(CryptoOutput::MultipleBatchOperation(unflatten_batches(
+                data, count,
+            )?))
+        }
+    }
+}
+
+const MULTIPLE_BATCH_KEY_SEPARATOR: char = '.';
+
+/// Merges the batches into a single batch, prefixing every key with the index of its batch, so
+/// that all of them can be sent to the encryption service in a single request
+fn flatten_batches<T>(batches: Vec<FxHashMap<String, T>>) -> FxHashMap<String, T> {
+    batches
+        .into_iter()
+        .enumerate()
+        .flat_map(|(index, batch)| {
+            batch.into_iter().map(move |(key, value)| {
+                (format!("{index}{MULTIPLE_BATCH_KEY_SEPARATOR}{key}"), value)
+            })
+        })
+        .collect()
+}
+
+/// Splits a batch created by [`flatten_batches`] back into `count` batches
+fn unflatten_batches<T>(
+    flattened: FxHashMap<String, T>,
+    count: usize,
+) -> CustomResult<Vec<FxHashMap<String, T>>, CryptoError> {
+    let mut batches = std::iter::repeat_with(FxHashMap::default)
+        .take(count)
+        .collect::<Vec<_>>();
+    for (flattened_key, value) in flattened {
+        let (index, key) = flattened_key
+            .split_once(MULTIPLE_BATCH_KEY_SEPARATOR)
+            .and_then(|(index, key)| Some((index.parse::<usize>().ok()?, key)))
+            .ok_or(CryptoError::DecodingFailed)
+            .attach_printable_lazy(|| {
+                format!("Invalid key {flattened_key} received in batch encryption response")
+            })?;
+        batches
+            .get_mut(index)
+            .ok_or(CryptoError::DecodingFailed)
+            .attach_printable("Invalid batch index received in batch encryption response")?
+            .insert(key.to_string(), value);
     }
+    Ok(batches)
 }
 
 pub(crate) mod metrics {
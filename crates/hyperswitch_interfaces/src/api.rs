@@ -401,6 +401,24 @@ pub trait ConnectorSpecifications {
         false
     }
 
+    /// Whether the connector supports increasing the authorized amount of a payment
+    /// Connectors should override this method if they implement the incremental authorization flow
+    fn is_incremental_authorization_supported(&self) -> bool {
+        false
+    }
+
+    /// Whether the connector supports charging the converted amount in the currency of the card
+    /// when the customer accepts dynamic currency conversion
+    fn is_dcc_supported(&self) -> bool {
+        false
+    }
+
+    /// Whether the connector returns the card details updated by the account updater services of
+    /// the card networks in its payment responses
+    fn is_account_updater_supported(&self) -> bool {
+        false
+    }
+
     #[cfg(not(feature = "v2"))]
     /// Generate connector request reference ID
     fn generate_connector_request_reference_id(
@@ -527,6 +527,27 @@ impl ConnectorSpecifications for ConnectorEnum {
         }
     }
 
+    fn is_incremental_authorization_supported(&self) -> bool {
+        match self {
+            Self::Old(connector) => connector.is_incremental_authorization_supported(),
+            Self::New(connector) => connector.is_incremental_authorization_supported(),
+        }
+    }
+
+    fn is_dcc_supported(&self) -> bool {
+        match self {
+            Self::Old(connector) => connector.is_dcc_supported(),
+            Self::New(connector) => connector.is_dcc_supported(),
+        }
+    }
+
+    fn is_account_updater_supported(&self) -> bool {
+        match self {
+            Self::Old(connector) => connector.is_account_updater_supported(),
+            Self::New(connector) => connector.is_account_updater_supported(),
+        }
+    }
+
     #[cfg(feature = "v1")]
     fn generate_connector_request_reference_id(
         &self,
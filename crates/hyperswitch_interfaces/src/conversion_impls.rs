@@ -59,6 +59,7 @@ fn get_default_router_data<F, Req, Resp>(
         preprocessing_id: None,
         payment_method_balance: None,
         connector_api_version: None,
+        merchant_connector_id: None,
         request,
         response,
         connector_request_reference_id: get_irrelevant_id_string(
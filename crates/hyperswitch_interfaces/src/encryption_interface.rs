@@ -26,4 +26,8 @@ pub enum EncryptionError {
     /// An error occurred when decrypting input data.
     #[error("Failed to decrypt input data")]
     DecryptionFailed,
+
+    /// Failed while creating the encryption client.
+    #[error("Failed while creating the encryption client")]
+    ClientCreationFailed,
 }
@@ -96,4 +96,49 @@ impl ConnectorEvent {
     pub fn set_error(&mut self, error: serde_json::Value) {
         self.error = Some(error.to_string());
     }
+
+    /// fn get_connector_name
+    pub fn get_connector_name(&self) -> &str {
+        &self.connector_name
+    }
+
+    /// fn get_flow
+    pub fn get_flow(&self) -> &str {
+        &self.flow
+    }
+
+    /// fn get_url
+    pub fn get_url(&self) -> &str {
+        &self.url
+    }
+
+    /// fn get_method
+    pub fn get_method(&self) -> &str {
+        &self.method
+    }
+
+    /// fn get_masked_request
+    pub fn get_masked_request(&self) -> &str {
+        &self.request
+    }
+
+    /// fn get_masked_response
+    pub fn get_masked_response(&self) -> Option<&str> {
+        self.masked_response.as_deref()
+    }
+
+    /// fn get_error
+    pub fn get_error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// fn get_latency
+    pub fn get_latency(&self) -> u128 {
+        self.latency
+    }
+
+    /// fn get_status_code
+    pub fn get_status_code(&self) -> u16 {
+        self.status_code
+    }
 }
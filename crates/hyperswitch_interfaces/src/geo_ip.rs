@@ -0,0 +1,28 @@
+use std::net::IpAddr;
+
+use common_enums::CountryAlpha2;
+use common_utils::errors::CustomResult;
+
+use super::types::Proxy;
+
+/// Errors that can occur while resolving the country of an IP address
+#[derive(Debug, thiserror::Error)]
+pub enum GeoIpError {
+    /// The request to the GeoIP provider failed
+    #[error("Failed to send request to the GeoIP provider")]
+    RequestFailed,
+    /// The response from the GeoIP provider could not be parsed
+    #[error("Failed to parse the response from the GeoIP provider")]
+    ResponseParsingFailed,
+}
+
+/// Trait defining the interface for resolving the geolocation of an IP address
+#[async_trait::async_trait]
+pub trait GeoIpInterface: Send + Sync {
+    /// Resolve the country of the given IP address, `None` if it could not be determined
+    async fn get_country(
+        &self,
+        proxy: &Proxy,
+        ip_address: IpAddr,
+    ) -> CustomResult<Option<CountryAlpha2>, GeoIpError>;
+}
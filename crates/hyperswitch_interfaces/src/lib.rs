@@ -26,3 +26,6 @@ pub mod webhooks;
 
 /// Crm interface
 pub mod crm;
+
+/// GeoIp interface
+pub mod geo_ip;
@@ -82,9 +82,14 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payments::payments_list,
         routes::payments::payments_incremental_authorization,
         routes::payment_link::payment_link_retrieve,
+        routes::payment_link::payment_link_create,
+        routes::payment_link::payment_link_update,
+        routes::payment_link::payment_link_deactivate,
+        routes::payment_link::payment_link_status_retrieve,
         routes::payments::payments_external_authentication,
         routes::payments::payments_complete_authorize,
         routes::payments::payments_post_session_tokens,
+        routes::payments::payments_dcc_offer,
         routes::payments::payments_update_metadata,
 
         // Routes for relay
@@ -95,6 +100,9 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::refunds::refunds_create,
         routes::refunds::refunds_retrieve,
         routes::refunds::refunds_update,
+        routes::refunds::refunds_cancel,
+        routes::refunds::refunds_approve,
+        routes::refunds::refunds_reject,
         routes::refunds::refunds_list,
 
         // Routes for Organization
@@ -133,6 +141,9 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::customers::customers_list,
         routes::customers::customers_update,
         routes::customers::customers_delete,
+        routes::customers::customers_merge,
+        routes::customers::customers_redact,
+        routes::customers::customers_export,
 
         //Routes for payment methods
         routes::payment_method::create_payment_method_api,
@@ -143,6 +154,8 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payment_method::payment_method_retrieve_api,
         routes::payment_method::payment_method_update_api,
         routes::payment_method::payment_method_delete_api,
+        routes::payment_method::list_payment_method_health,
+        routes::payment_method::retrieve_payment_method_health,
 
         // Routes for Profile
         routes::profile::profile_create,
@@ -174,13 +187,71 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::routing::call_decide_gateway_open_router,
         routes::routing::call_update_gateway_score_open_router,
         routes::routing::evaluate_routing_rule,
+        routes::routing::routing_simulate_config,
+        routes::routing::routing_retrieve_version_history,
+        routes::routing::routing_rollback_config,
+        routes::routing::routing_list_scheduled_activations,
+        routes::routing::routing_cancel_scheduled_activation,
+        routes::routing::upsert_latency_based_routing_config,
+        routes::routing::retrieve_latency_based_routing_config,
+        routes::routing::upsert_connector_fee_schedule,
+        routes::routing::retrieve_connector_fee_schedule,
+        routes::routing::delete_connector_fee_schedule,
+        routes::routing::retrieve_routing_audit_log,
+        routes::routing::start_routing_experiment,
+        routes::routing::stop_routing_experiment,
+        routes::routing::retrieve_routing_experiment_results,
+        routes::routing::routing_update_payment_method_default_config,
+        routes::routing::routing_retrieve_payment_method_default_config,
+        routes::routing::routing_delete_payment_method_default_config,
+        routes::routing::export_routing_config,
+        routes::routing::import_routing_config,
+        routes::routing::retrieve_connector_health,
+        routes::routing::routing_diff_config,
 
         // Routes for blocklist
         routes::blocklist::remove_entry_from_blocklist,
         routes::blocklist::list_blocked_payment_methods,
         routes::blocklist::add_entry_to_blocklist,
+        routes::blocklist::bulk_add_entries_to_blocklist,
         routes::blocklist::toggle_blocklist_guard,
 
+        // Routes for payment reviews
+        routes::payment_review::list_payment_reviews,
+        routes::payment_review::approve_payment_review,
+        routes::payment_review::decline_payment_review,
+
+        // Routes for data retention
+        routes::data_retention::retrieve_data_retention_policy,
+        routes::data_retention::upsert_data_retention_policy,
+        routes::data_retention::purge_data,
+        routes::data_retention::list_data_retention_purge_runs,
+
+        // Routes for list views
+        routes::list_views::create_list_view,
+        routes::list_views::list_list_views,
+        routes::list_views::retrieve_list_view,
+        routes::list_views::update_list_view,
+        routes::list_views::delete_list_view,
+
+        // Routes for reports
+        routes::reports::create_report_template,
+        routes::reports::list_report_templates,
+        routes::reports::retrieve_report_template,
+        routes::reports::update_report_template,
+        routes::reports::delete_report_template,
+        routes::reports::run_report,
+        routes::reports::list_report_runs,
+        routes::reports::download_report_run_file,
+
+        // Routes for event stream
+        routes::event_stream::stream_events,
+
+        // Routes for authorization rate alerts
+        routes::auth_rate_alerts::retrieve_auth_rate_alert_config,
+        routes::auth_rate_alerts::upsert_auth_rate_alert_config,
+        routes::auth_rate_alerts::list_auth_rate_alerts,
+
         // Routes for payouts
         routes::payouts::payouts_create,
         routes::payouts::payouts_retrieve,
@@ -197,11 +268,13 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::api_keys::api_key_retrieve,
         routes::api_keys::api_key_update,
         routes::api_keys::api_key_revoke,
+        routes::api_keys::api_key_rotate,
         routes::api_keys::api_key_list,
 
         // Routes for events
         routes::webhook_events::list_initial_webhook_delivery_attempts,
         routes::webhook_events::list_initial_webhook_delivery_attempts_with_jwtauth,
+        routes::webhook_events::get_webhook_delivery_health,
         routes::webhook_events::list_webhook_delivery_attempts,
         routes::webhook_events::retry_webhook_delivery_attempt,
 
@@ -265,6 +338,7 @@ Never share your secret api keys. Keep them guarded and secure.
         common_types::three_ds_decision_rule_engine::ThreeDSDecision,
         common_types::payments::MerchantCountryCode,
         api_models::enums::PaymentChannel,
+        api_models::enums::MitCategory,
         api_models::three_ds_decision_rule::ThreeDsDecisionRuleExecuteRequest,
         api_models::three_ds_decision_rule::ThreeDsDecisionRuleExecuteResponse,
         api_models::three_ds_decision_rule::PaymentData,
@@ -277,6 +351,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::refunds::RefundResponse,
         api_models::refunds::RefundStatus,
         api_models::refunds::RefundUpdateRequest,
+        api_models::refunds::RefundRejectRequest,
         api_models::organization::OrganizationCreateRequest,
         api_models::organization::OrganizationUpdateRequest,
         api_models::organization::OrganizationResponse,
@@ -295,6 +370,13 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::CardTestingGuardStatus,
         api_models::customers::CustomerRequest,
         api_models::customers::CustomerUpdateRequest,
+        api_models::customers::CustomerMergeRequest,
+        api_models::customers::CustomerMergeResponse,
+        api_models::customers::CustomerRedactResponse,
+        api_models::customers::CustomerDataExportResponse,
+        api_models::customers::CustomerDataExportPaymentMethod,
+        api_models::customers::CustomerDataExportMandate,
+        api_models::customers::CustomerDataExportPayment,
         api_models::customers::CustomerDeleteResponse,
         api_models::payment_methods::PaymentMethodCreate,
         api_models::payment_methods::PaymentMethodResponse,
@@ -302,6 +384,13 @@ Never share your secret api keys. Keep them guarded and secure.
         common_types::three_ds_decision_rule_engine::ThreeDSDecisionRule,
         common_types::domain::AcquirerConfigMap,
         common_types::domain::AcquirerConfig,
+        common_types::domain::RetryPolicy,
+        common_types::domain::RetryPolicyRule,
+        common_types::domain::WebhookRetryPolicy,
+        common_types::domain::EventSinkConfig,
+        common_types::domain::EventSinkDestination,
+        common_types::domain::KafkaSaslCredentials,
+        common_types::domain::SoftDeclineRetryConfig,
         api_models::payment_methods::PaymentMethodListResponse,
         api_models::payment_methods::ResponsePaymentMethodsEnabled,
         api_models::payment_methods::ResponsePaymentMethodTypes,
@@ -317,6 +406,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::PaymentMethodCreateData,
         api_models::payment_methods::CardDetail,
         api_models::payment_methods::CardDetailUpdate,
+        api_models::payment_methods::PaymentMethodHealthResponse,
+        api_models::payment_methods::PaymentMethodHealthListResponse,
         api_models::payment_methods::RequestPaymentMethodTypes,
         api_models::poll::PollResponse,
         api_models::poll::PollStatus,
@@ -336,6 +427,11 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::Currency,
         api_models::enums::DocumentKind,
         api_models::enums::IntentStatus,
+        api_models::enums::PaymentBatchStatus,
+        api_models::enums::PaymentBatchItemStatus,
+        api_models::enums::PayoutBatchStatus,
+        api_models::enums::PayoutBatchItemStatus,
+        api_models::enums::BeneficiaryKycStatus,
         api_models::enums::CaptureMethod,
         api_models::enums::FutureUsage,
         api_models::enums::AuthenticationType,
@@ -351,6 +447,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::CardNetwork,
         api_models::enums::MerchantCategoryCode,
         api_models::enums::DisputeStage,
+        api_models::enums::DisputeDecisionAction,
+        api_models::enums::FrmVelocityAction,
+        api_models::enums::FrmVelocityDimension,
         api_models::enums::DisputeStatus,
         api_models::enums::CountryAlpha2,
         api_models::enums::Country,
@@ -404,6 +503,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::PaymentLinkTransactionDetails,
         api_models::admin::TransactionDetailsUiConfiguration,
         api_models::disputes::DisputeResponse,
+        api_models::disputes::DisputeAutomatedAction,
         api_models::disputes::DisputeResponsePaymentsRetrieve,
         api_models::gsm::GsmCreateRequest,
         api_models::gsm::GsmRetrieveRequest,
@@ -577,6 +677,12 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::GpayBillingAddressParameters,
         api_models::payments::GpayBillingAddressFormat,
         api_models::payments::NetworkDetails,
+        api_models::payments::DccChoice,
+        api_models::payments::DccDetails,
+        api_models::payments::DeviceFingerprint,
+        api_models::payments::DccOffer,
+        api_models::payments::PaymentsDccOfferRequest,
+        api_models::payments::PaymentsDccOfferResponse,
         api_models::payments::SepaBankTransferInstructions,
         api_models::payments::BacsBankTransferInstructions,
         api_models::payments::RedirectResponse,
@@ -668,6 +774,14 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payouts::PayoutListFilters,
         api_models::payouts::PayoutListFilterConstraints,
         api_models::payouts::PayoutListResponse,
+        api_models::payouts::PayoutBatchCreateRequest,
+        api_models::payouts::PayoutBatchResponse,
+        api_models::payouts::PayoutBatchItemResponse,
+        api_models::payouts::BeneficiaryCreateRequest,
+        api_models::payouts::BeneficiaryUpdateRequest,
+        api_models::payouts::BeneficiaryResponse,
+        api_models::payouts::BeneficiaryListConstraints,
+        api_models::payouts::BeneficiaryDeleteResponse,
         api_models::payouts::PayoutRetrieveBody,
         api_models::payouts::PayoutMethodData,
         api_models::payouts::PayoutMethodDataResponse,
@@ -679,6 +793,11 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::PayoutStatus,
         api_models::enums::PayoutType,
         api_models::enums::TransactionType,
+        api_models::enums::RoutingAuditAction,
+        api_models::enums::RoutingExperimentArm,
+        api_models::enums::SubscriptionBillingInterval,
+        api_models::subscription::SubscriptionStatus,
+        api_models::subscription::SubscriptionResponse,
         api_models::payments::FrmMessage,
         api_models::webhooks::OutgoingWebhook,
         api_models::webhooks::OutgoingWebhookContent,
@@ -692,15 +811,26 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::ToggleKVRequest,
         api_models::admin::ToggleKVResponse,
         api_models::admin::WebhookDetails,
+        api_models::admin::WebhookEndpoint,
         api_models::api_keys::ApiKeyExpiration,
+        api_models::enums::ApiKeyScope,
         api_models::api_keys::CreateApiKeyRequest,
         api_models::api_keys::CreateApiKeyResponse,
         api_models::api_keys::RetrieveApiKeyResponse,
         api_models::api_keys::RevokeApiKeyResponse,
+        api_models::api_keys::RotateApiKeyRequest,
+        api_models::api_keys::RotateApiKeyResponse,
         api_models::api_keys::UpdateApiKeyRequest,
         api_models::payments::RetrievePaymentLinkRequest,
         api_models::payments::PaymentLinkResponse,
         api_models::payments::RetrievePaymentLinkResponse,
+        api_models::payments::PaymentLinkCreateRequest,
+        api_models::payments::PaymentLinkUpdateRequest,
+        api_models::payments::PaymentLinkStatusResponse,
+        api_models::payments::PaymentsBatchRequest,
+        api_models::payments::PaymentsBatchItemRequest,
+        api_models::payments::PaymentsBatchResponse,
+        api_models::payments::PaymentsBatchItemResponse,
         api_models::payments::PaymentLinkInitiateRequest,
         api_models::payouts::PayoutLinkInitiateRequest,
         api_models::payments::ExtendedCardInfoResponse,
@@ -739,6 +869,40 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::routing::ProgramThreeDsDecisionRule,
         api_models::routing::RuleThreeDsDecisionRule,
         api_models::routing::RoutingVolumeSplitResponse,
+        api_models::routing::RoutingSimulateRequest,
+        api_models::routing::RoutingSimulatePaymentData,
+        api_models::routing::RoutingSimulateResponse,
+        api_models::routing::RoutingConfigVersion,
+        api_models::routing::RoutingVersionHistory,
+        api_models::routing::RoutingActivationStatus,
+        api_models::routing::RoutingScheduledActivation,
+        api_models::routing::RoutingScheduledActivations,
+        api_models::routing::LatencyBasedRoutingConfig,
+        api_models::routing::LatencyBasedRoutingResponse,
+        api_models::routing::ConnectorLatencyStats,
+        api_models::routing::ConnectorFeeSchedule,
+        api_models::routing::ConnectorFeeScheduleRequest,
+        api_models::routing::ConnectorFeeRule,
+        api_models::routing::FeeComponent,
+        api_models::routing::RoutingAuditLogRecord,
+        api_models::routing::RoutingAuditLogResponse,
+        api_models::routing::RoutingExperimentRequest,
+        api_models::routing::RoutingExperiment,
+        api_models::routing::RoutingExperimentStatus,
+        api_models::routing::RoutingExperimentArmResult,
+        api_models::routing::RoutingExperimentResults,
+        api_models::routing::PaymentMethodDefaultRoutingConfig,
+        api_models::routing::RoutingAlgorithmExport,
+        api_models::routing::RoutingConfigExport,
+        api_models::routing::RoutingConfigImportRequest,
+        api_models::routing::ImportedRoutingAlgorithm,
+        api_models::routing::RoutingConfigImportResponse,
+        api_models::routing::ConnectorCircuitBreakerState,
+        api_models::routing::ConnectorHealthStatus,
+        api_models::routing::ConnectorHealthResponse,
+        api_models::routing::RoutingAlgorithmDiffResponse,
+        api_models::routing::RoutingRuleDiff,
+        api_models::routing::RoutingRuleChange,
         api_models::routing::ast::RoutableChoiceKind,
         api_models::enums::RoutableConnectors,
         api_models::routing::ast::ProgramConnectorSelection,
@@ -752,10 +916,52 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::RequestPaymentMethodTypes,
         api_models::payments::PaymentLinkStatus,
         api_models::blocklist::BlocklistRequest,
+        api_models::blocklist::AddToBlocklistRequest,
+        api_models::blocklist::BulkAddToBlocklistRequest,
+        api_models::blocklist::BulkAddToBlocklistResult,
+        api_models::blocklist::BulkAddToBlocklistResponse,
         api_models::blocklist::BlocklistResponse,
         api_models::blocklist::ToggleBlocklistResponse,
         api_models::blocklist::ListBlocklistQuery,
         api_models::enums::BlocklistDataKind,
+        api_models::enums::PaymentReviewStatus,
+        api_models::enums::PaymentReviewSource,
+        api_models::payment_review::PaymentReviewResponse,
+        api_models::payment_review::PaymentReviewListResponse,
+        api_models::payment_review::PaymentReviewApproveRequest,
+        api_models::payment_review::PaymentReviewDeclineRequest,
+        api_models::payment_review::PaymentReviewDecisionResponse,
+        api_models::data_retention::DataRetentionPolicyRequest,
+        api_models::data_retention::DataRetentionPolicyResponse,
+        api_models::data_retention::DataRetentionPurgeRequest,
+        api_models::data_retention::DataRetentionPurgeRunResponse,
+        api_models::data_retention::DataRetentionPurgeRunListResponse,
+        api_models::enums::ListViewEntity,
+        api_models::list_views::ListViewCreateRequest,
+        api_models::list_views::ListViewUpdateRequest,
+        api_models::list_views::ListViewResponse,
+        api_models::list_views::ListViewListResponse,
+        api_models::list_views::ListViewDeleteResponse,
+        api_models::enums::ReportDomain,
+        api_models::enums::ReportFormat,
+        api_models::enums::ReportFrequency,
+        api_models::enums::ReportDeliveryMethod,
+        api_models::enums::ReportRunStatus,
+        api_models::reports::ReportTemplateCreateRequest,
+        api_models::reports::ReportTemplateUpdateRequest,
+        api_models::reports::ReportTemplateResponse,
+        api_models::reports::ReportTemplateListResponse,
+        api_models::reports::ReportTemplateDeleteResponse,
+        api_models::reports::ReportRunRequest,
+        api_models::reports::ReportRunResponse,
+        api_models::reports::ReportRunListResponse,
+        api_models::event_stream::EventStreamObjectType,
+        api_models::event_stream::EventStreamEvent,
+        api_models::enums::AuthRateAlertStatus,
+        api_models::auth_rate_alerts::AuthRateAlertConfigRequest,
+        api_models::auth_rate_alerts::AuthRateAlertConfigResponse,
+        api_models::auth_rate_alerts::AuthRateAlertResponse,
+        api_models::auth_rate_alerts::AuthRateAlertListResponse,
         api_models::enums::ErrorCategory,
         api_models::webhook_events::EventListConstraints,
         api_models::webhook_events::EventListItemResponse,
@@ -763,7 +969,11 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::webhook_events::OutgoingWebhookRequestContent,
         api_models::webhook_events::OutgoingWebhookResponseContent,
         api_models::webhook_events::TotalEventsResponse,
+        api_models::webhook_events::WebhookDeliveryHealthResponse,
         api_models::enums::WebhookDeliveryAttempt,
+        api_models::enums::WebhookRetryBackoffStrategy,
+        api_models::enums::EventSinkSchemaVersion,
+        api_models::enums::KafkaSaslMechanism,
         api_models::enums::PaymentChargeType,
         api_models::enums::StripeChargeType,
         api_models::payments::CustomerDetailsResponse,
@@ -814,6 +1024,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::feature_matrix::FeatureMatrixListResponse,
         api_models::feature_matrix::FeatureMatrixRequest,
         api_models::feature_matrix::ConnectorFeatureMatrixResponse,
+        api_models::feature_matrix::ConnectorCapabilitiesResponse,
+        api_models::feature_matrix::ConnectorFlowCapabilities,
         api_models::feature_matrix::PaymentMethodSpecificFeatures,
         api_models::feature_matrix::CardSpecificFeatures,
         api_models::feature_matrix::SupportedPaymentMethod,
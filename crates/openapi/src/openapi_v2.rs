@@ -109,6 +109,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::api_keys::api_key_retrieve,
         routes::api_keys::api_key_update,
         routes::api_keys::api_key_revoke,
+        routes::api_keys::api_key_rotate,
         routes::api_keys::api_key_list,
 
         //Routes for customers
@@ -305,6 +306,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::MerchantCategoryCode,
         api_models::enums::TokenDataType,
         api_models::enums::DisputeStage,
+        api_models::enums::DisputeDecisionAction,
         api_models::enums::DisputeStatus,
         api_models::enums::CountryAlpha2,
         api_models::enums::CountryAlpha3,
@@ -358,6 +360,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::PaymentLinkTransactionDetails,
         api_models::admin::TransactionDetailsUiConfiguration,
         api_models::disputes::DisputeResponse,
+        api_models::disputes::DisputeAutomatedAction,
         api_models::disputes::DisputeResponsePaymentsRetrieve,
         api_models::gsm::GsmCreateRequest,
         api_models::gsm::GsmRetrieveRequest,
@@ -675,10 +678,13 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::ToggleKVResponse,
         api_models::admin::WebhookDetails,
         api_models::api_keys::ApiKeyExpiration,
+        api_models::enums::ApiKeyScope,
         api_models::api_keys::CreateApiKeyRequest,
         api_models::api_keys::CreateApiKeyResponse,
         api_models::api_keys::RetrieveApiKeyResponse,
         api_models::api_keys::RevokeApiKeyResponse,
+        api_models::api_keys::RotateApiKeyRequest,
+        api_models::api_keys::RotateApiKeyResponse,
         api_models::api_keys::UpdateApiKeyRequest,
         api_models::payments::RetrievePaymentLinkRequest,
         api_models::payments::PaymentLinkResponse,
@@ -735,6 +741,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::RequestPaymentMethodTypes,
         api_models::payments::PaymentLinkStatus,
         api_models::blocklist::BlocklistRequest,
+        api_models::blocklist::AddToBlocklistRequest,
+        api_models::blocklist::BulkAddToBlocklistRequest,
+        api_models::blocklist::BulkAddToBlocklistResult,
+        api_models::blocklist::BulkAddToBlocklistResponse,
         api_models::blocklist::BlocklistResponse,
         api_models::blocklist::ToggleBlocklistResponse,
         api_models::blocklist::ListBlocklistQuery,
@@ -791,6 +801,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::feature_matrix::FeatureMatrixListResponse,
         api_models::feature_matrix::FeatureMatrixRequest,
         api_models::feature_matrix::ConnectorFeatureMatrixResponse,
+        api_models::feature_matrix::ConnectorCapabilitiesResponse,
+        api_models::feature_matrix::ConnectorFlowCapabilities,
         api_models::feature_matrix::PaymentMethodSpecificFeatures,
         api_models::feature_matrix::CardSpecificFeatures,
         api_models::feature_matrix::SupportedPaymentMethod,
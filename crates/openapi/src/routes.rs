@@ -1,17 +1,22 @@
 #![allow(unused)]
 
 pub mod api_keys;
+pub mod auth_rate_alerts;
 pub mod authentication;
 pub mod blocklist;
 pub mod customers;
+pub mod data_retention;
 pub mod disputes;
+pub mod event_stream;
 pub mod gsm;
+pub mod list_views;
 pub mod mandates;
 pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod organization;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_review;
 pub mod payments;
 pub mod payouts;
 pub mod platform;
@@ -21,6 +26,7 @@ pub mod profile_acquirer;
 pub mod proxy;
 pub mod refunds;
 pub mod relay;
+pub mod reports;
 pub mod revenue_recovery;
 pub mod routing;
 pub mod three_ds_decision_rule;
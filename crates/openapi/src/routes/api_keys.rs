@@ -121,6 +121,51 @@ pub async fn api_key_update() {}
 )]
 pub async fn api_key_update() {}
 
+#[cfg(feature = "v1")]
+/// API Key - Rotate
+///
+/// Issue a replacement for the specified API Key. The rotated API Key remains valid for the
+/// specified grace period, allowing the replacement API Key to be rolled out before it expires.
+#[utoipa::path(
+    post,
+    path = "/api_keys/{merchant_id}/{key_id}/rotate",
+    request_body = RotateApiKeyRequest,
+    params (
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("key_id" = String, Path, description = "The unique identifier for the API Key")
+    ),
+    responses(
+        (status = 200, description = "API Key rotated", body = RotateApiKeyResponse),
+        (status = 404, description = "API Key not found")
+    ),
+    tag = "API Key",
+    operation_id = "Rotate an API Key",
+    security(("admin_api_key" = []))
+)]
+pub async fn api_key_rotate() {}
+
+#[cfg(feature = "v2")]
+/// API Key - Rotate
+///
+/// Issue a replacement for the specified API Key. The rotated API Key remains valid for the
+/// specified grace period, allowing the replacement API Key to be rolled out before it expires.
+#[utoipa::path(
+    post,
+    path = "/v2/api-keys/{id}/rotate",
+    request_body = RotateApiKeyRequest,
+    params (
+        ("id" = String, Path, description = "The unique identifier for the API Key")
+    ),
+    responses(
+        (status = 200, description = "API Key rotated", body = RotateApiKeyResponse),
+        (status = 404, description = "API Key not found")
+    ),
+    tag = "API Key",
+    operation_id = "Rotate an API Key",
+    security(("admin_api_key" = []))
+)]
+pub async fn api_key_rotate() {}
+
 #[cfg(feature = "v1")]
 /// API Key - Revoke
 ///
@@ -0,0 +1,55 @@
+/// Authorization Rate Alerts - Retrieve Config
+///
+/// To retrieve the authorization rate alerting of the merchant
+#[utoipa::path(
+    get,
+    path = "/auth_rate_alerts/config",
+    responses(
+        (status = 200, description = "Authorization rate alert config retrieved", body = AuthRateAlertConfigResponse),
+        (status = 404, description = "Authorization rate alert config does not exist for the merchant")
+    ),
+    tag = "Authorization Rate Alerts",
+    operation_id = "Retrieve the Authorization Rate Alert Config",
+    security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn retrieve_auth_rate_alert_config() {}
+
+/// Authorization Rate Alerts - Set Config
+///
+/// To set the authorization rate alerting of the merchant, which replaces any existing alerting.
+/// The authorization rate of each connector and payment method is evaluated at the end of every
+/// evaluation window, and the alerts are posted to the webhook URL and emailed to the recipients
+/// when raised and when resolved.
+#[utoipa::path(
+    post,
+    path = "/auth_rate_alerts/config",
+    request_body = AuthRateAlertConfigRequest,
+    responses(
+        (status = 200, description = "Authorization rate alert config set", body = AuthRateAlertConfigResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Authorization Rate Alerts",
+    operation_id = "Set the Authorization Rate Alert Config",
+    security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn upsert_auth_rate_alert_config() {}
+
+/// Authorization Rate Alerts - List
+///
+/// To list the authorization rate alerts of the merchant, the latest alert at the top
+#[utoipa::path(
+    get,
+    path = "/auth_rate_alerts",
+    params(
+        ("status" = Option<AuthRateAlertStatus>, Query, description = "Only include the alerts of this status"),
+        ("limit" = Option<u32>, Query, description = "The maximum number of alerts to include in the response"),
+        ("offset" = Option<u32>, Query, description = "The number of alerts to skip")
+    ),
+    responses(
+        (status = 200, description = "Authorization rate alerts retrieved", body = AuthRateAlertListResponse)
+    ),
+    tag = "Authorization Rate Alerts",
+    operation_id = "List Authorization Rate Alerts",
+    security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn list_auth_rate_alerts() {}
@@ -17,7 +17,7 @@ pub async fn toggle_blocklist_guard() {}
 #[utoipa::path(
     post,
     path = "/blocklist",
-    request_body = BlocklistRequest,
+    request_body = AddToBlocklistRequest,
     responses(
         (status = 200, description = "Fingerprint Blocked", body = BlocklistResponse),
         (status = 400, description = "Invalid Data")
@@ -28,6 +28,20 @@ pub async fn toggle_blocklist_guard() {}
 )]
 pub async fn add_entry_to_blocklist() {}
 
+#[utoipa::path(
+    post,
+    path = "/blocklist/bulk",
+    request_body = BulkAddToBlocklistRequest,
+    responses(
+        (status = 200, description = "Entries processed", body = BulkAddToBlocklistResponse),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Blocklist",
+    operation_id = "Bulk add entries to the blocklist",
+    security(("api_key" = []))
+)]
+pub async fn bulk_add_entries_to_blocklist() {}
+
 #[utoipa::path(
     delete,
     path = "/blocklist",
@@ -96,9 +96,10 @@ pub async fn customers_delete() {}
     get,
     path = "/customers/list",
     params (("offset" = Option<u32>, Query, description = "Offset for pagination"),
-    ("limit" = Option<u16>, Query, description = "Limit for pagination")),
+    ("limit" = Option<u16>, Query, description = "Limit for pagination"),
+    ("cursor" = Option<String>, Query, description = "An opaque cursor returned in the `X-Next-Cursor` header of a previous list call, to fetch the page after it. Takes precedence over `offset`")),
     responses(
-        (status = 200, description = "Customers retrieved", body = Vec<CustomerResponse>),
+        (status = 200, description = "Customers retrieved. The `X-Next-Cursor` header is set when more customers may be available", body = Vec<CustomerResponse>),
         (status = 400, description = "Invalid Data"),
     ),
     tag = "Customers",
@@ -108,6 +109,75 @@ pub async fn customers_delete() {}
 #[cfg(feature = "v1")]
 pub async fn customers_list() {}
 
+/// Customers - Merge
+///
+/// Merges a duplicate customer into the customer. The payment methods, mandates and payments of
+/// the duplicate customer are moved to the customer, and the duplicate customer is deactivated.
+#[utoipa::path(
+    post,
+    path = "/customers/{customer_id}/merge",
+    request_body (
+        content = CustomerMergeRequest,
+        examples  (( "Merge a duplicate customer" =(
+        value =json!( {
+            "duplicate_customer_id": "cus_abcdefgh"
+        })
+        )))
+    ),
+    params (("customer_id" = String, Path, description = "The unique identifier for the Customer to merge into")),
+    responses(
+        (status = 200, description = "Customers were merged", body = CustomerMergeResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Customer was not found"),
+        (status = 412, description = "Customer was already merged")
+    ),
+    tag = "Customers",
+    operation_id = "Merge Customers",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn customers_merge() {}
+
+/// Customers - Redact
+///
+/// Irreversibly redacts the personal data of a customer. The saved payment methods of the
+/// customer are deleted, and the personal data on their payments is redacted while the financial
+/// details of the payments are retained.
+#[utoipa::path(
+    post,
+    path = "/customers/{customer_id}/redact",
+    params (("customer_id" = String, Path, description = "The unique identifier for the Customer")),
+    responses(
+        (status = 200, description = "Customer was Redacted", body = CustomerRedactResponse),
+        (status = 400, description = "Customer has an active mandate"),
+        (status = 404, description = "Customer was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Redact a Customer",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn customers_redact() {}
+
+/// Customers - Export
+///
+/// Exports all the data stored for a customer, including their payment methods, mandates and
+/// payments.
+#[utoipa::path(
+    get,
+    path = "/customers/{customer_id}/export",
+    params (("customer_id" = String, Path, description = "The unique identifier for the Customer")),
+    responses(
+        (status = 200, description = "Customer data was exported", body = CustomerDataExportResponse),
+        (status = 404, description = "Customer was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Export a Customer's Data",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn customers_export() {}
+
 /// Customers - Create
 ///
 /// Creates a customer object and stores the customer details to be reused for future payments.
@@ -0,0 +1,70 @@
+/// Data Retention - Retrieve Policy
+///
+/// To retrieve the data retention policy of the merchant
+#[utoipa::path(
+    get,
+    path = "/data_retention/policy",
+    responses(
+        (status = 200, description = "Data retention policy retrieved", body = DataRetentionPolicyResponse),
+        (status = 404, description = "Data retention policy does not exist for the merchant")
+    ),
+    tag = "Data Retention",
+    operation_id = "Retrieve the Data Retention Policy",
+    security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn retrieve_data_retention_policy() {}
+
+/// Data Retention - Set Policy
+///
+/// To set the data retention policy of the merchant, which replaces any existing policy. The data
+/// past the policy is purged once a day, starting a day after the policy is set.
+#[utoipa::path(
+    post,
+    path = "/data_retention/policy",
+    request_body = DataRetentionPolicyRequest,
+    responses(
+        (status = 200, description = "Data retention policy set", body = DataRetentionPolicyResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Data Retention",
+    operation_id = "Set the Data Retention Policy",
+    security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn upsert_data_retention_policy() {}
+
+/// Data Retention - Purge
+///
+/// To purge the data which is past the retention policy of the merchant, or to only report it
+/// with a dry run
+#[utoipa::path(
+    post,
+    path = "/data_retention/purge",
+    request_body = DataRetentionPurgeRequest,
+    responses(
+        (status = 200, description = "Data purged", body = DataRetentionPurgeRunResponse),
+        (status = 412, description = "Data retention policy does not exist for the merchant")
+    ),
+    tag = "Data Retention",
+    operation_id = "Purge Data past the Retention Policy",
+    security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn purge_data() {}
+
+/// Data Retention - List Purge Runs
+///
+/// To list the purges run for the merchant, the latest purge at the top
+#[utoipa::path(
+    get,
+    path = "/data_retention/purge_runs",
+    params(
+        ("limit" = Option<u32>, Query, description = "The maximum number of purge runs to include in the response"),
+        ("offset" = Option<u32>, Query, description = "The number of purge runs to skip")
+    ),
+    responses(
+        (status = 200, description = "Purge runs retrieved", body = DataRetentionPurgeRunListResponse)
+    ),
+    tag = "Data Retention",
+    operation_id = "List Data Retention Purge Runs",
+    security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn list_data_retention_purge_runs() {}
@@ -33,9 +33,10 @@ pub async fn retrieve_dispute() {}
         ("received_time.gt" = Option<PrimitiveDateTime>, Query, description = "Time greater than the dispute received time"),
         ("received_time.lte" = Option<PrimitiveDateTime>, Query, description = "Time less than or equals to the dispute received time"),
         ("received_time.gte" = Option<PrimitiveDateTime>, Query, description = "Time greater than or equals to the dispute received time"),
+        ("cursor" = Option<String>, Query, description = "An opaque cursor returned in the `X-Next-Cursor` header of a previous list call, to fetch the page after it"),
     ),
     responses(
-        (status = 200, description = "The dispute list was retrieved successfully", body = Vec<DisputeResponse>),
+        (status = 200, description = "The dispute list was retrieved successfully. The `X-Next-Cursor` header is set when more disputes may be available", body = Vec<DisputeResponse>),
         (status = 401, description = "Unauthorized request")
     ),
     tag = "Disputes",
@@ -60,9 +61,10 @@ pub async fn retrieve_disputes_list() {}
         ("received_time.gt" = Option<PrimitiveDateTime>, Query, description = "Time greater than the dispute received time"),
         ("received_time.lte" = Option<PrimitiveDateTime>, Query, description = "Time less than or equals to the dispute received time"),
         ("received_time.gte" = Option<PrimitiveDateTime>, Query, description = "Time greater than or equals to the dispute received time"),
+        ("cursor" = Option<String>, Query, description = "An opaque cursor returned in the `X-Next-Cursor` header of a previous list call, to fetch the page after it"),
     ),
     responses(
-        (status = 200, description = "The dispute list was retrieved successfully", body = Vec<DisputeResponse>),
+        (status = 200, description = "The dispute list was retrieved successfully. The `X-Next-Cursor` header is set when more disputes may be available", body = Vec<DisputeResponse>),
         (status = 401, description = "Unauthorized request")
     ),
     tag = "Disputes",
@@ -0,0 +1,18 @@
+/// Events - Stream
+///
+/// To stream the status changes of the payments, refunds and disputes of the merchant as
+/// Server-Sent Events. Each event is named after the type of the object, and its data is an
+/// `EventStreamEvent`. A `keep-alive` comment is sent when no event was sent for 15 seconds, and
+/// the client is expected to reconnect when the stream ends.
+#[utoipa::path(
+    get,
+    path = "/events/stream",
+    responses(
+        (status = 200, description = "Event stream opened", content_type = "text/event-stream", body = EventStreamEvent),
+        (status = 400, description = "Event stream is not enabled")
+    ),
+    tag = "Event Stream",
+    operation_id = "Stream Events",
+    security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn stream_events() {}
@@ -0,0 +1,95 @@
+/// List Views - Create
+///
+/// To save a named set of filters of a list endpoint. The filters are applied by passing the
+/// `view_id` of the view to the list endpoint, and the fields set in the list request take
+/// precedence over the filters of the view.
+#[utoipa::path(
+    post,
+    path = "/list_views",
+    request_body = ListViewCreateRequest,
+    responses(
+        (status = 200, description = "List view created", body = ListViewResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "List Views",
+    operation_id = "Create a List View",
+    security(("jwt_key" = []))
+)]
+pub async fn create_list_view() {}
+
+/// List Views - List
+///
+/// To list the views created by the user and the views shared by the other users of the merchant
+#[utoipa::path(
+    get,
+    path = "/list_views",
+    params(
+        ("entity" = Option<ListViewEntity>, Query, description = "Only include the views of this entity")
+    ),
+    responses(
+        (status = 200, description = "List views retrieved", body = ListViewListResponse)
+    ),
+    tag = "List Views",
+    operation_id = "List List Views",
+    security(("jwt_key" = []))
+)]
+pub async fn list_list_views() {}
+
+/// List Views - Retrieve
+///
+/// To retrieve a view created by the user or shared by another user of the merchant
+#[utoipa::path(
+    get,
+    path = "/list_views/{view_id}",
+    params(
+        ("view_id" = String, Path, description = "The identifier for the list view")
+    ),
+    responses(
+        (status = 200, description = "List view retrieved", body = ListViewResponse),
+        (status = 404, description = "List view does not exist in our records")
+    ),
+    tag = "List Views",
+    operation_id = "Retrieve a List View",
+    security(("jwt_key" = []))
+)]
+pub async fn retrieve_list_view() {}
+
+/// List Views - Update
+///
+/// To update a view, only the user who created the view can update it
+#[utoipa::path(
+    post,
+    path = "/list_views/{view_id}",
+    params(
+        ("view_id" = String, Path, description = "The identifier for the list view")
+    ),
+    request_body = ListViewUpdateRequest,
+    responses(
+        (status = 200, description = "List view updated", body = ListViewResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "List view does not exist in our records")
+    ),
+    tag = "List Views",
+    operation_id = "Update a List View",
+    security(("jwt_key" = []))
+)]
+pub async fn update_list_view() {}
+
+/// List Views - Delete
+///
+/// To delete a view, only the user who created the view can delete it
+#[utoipa::path(
+    delete,
+    path = "/list_views/{view_id}",
+    params(
+        ("view_id" = String, Path, description = "The identifier for the list view")
+    ),
+    responses(
+        (status = 200, description = "List view deleted", body = ListViewDeleteResponse),
+        (status = 404, description = "List view does not exist in our records")
+    ),
+    tag = "List Views",
+    operation_id = "Delete a List View",
+    security(("jwt_key" = []))
+)]
+pub async fn delete_list_view() {}
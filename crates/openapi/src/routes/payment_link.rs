@@ -17,3 +17,79 @@
     security(("api_key" = []), ("publishable_key" = []))
 )]
 pub async fn payment_link_retrieve() {}
+
+/// Payment Link - Create
+///
+/// To create a shareable payment link along with the payment backing it. The link expires after `session_expiry` seconds, after which an unpaid payment is moved to `expired`
+#[utoipa::path(
+    post,
+    path = "/payment_link",
+    request_body = PaymentLinkCreateRequest,
+    responses(
+        (status = 200, description = "Payment link created", body = RetrievePaymentLinkResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Payments",
+    operation_id = "Create a Payment Link",
+    security(("api_key" = []))
+)]
+pub async fn payment_link_create() {}
+
+/// Payment Link - Update
+///
+/// To update the description and branding of an active payment link
+#[utoipa::path(
+    post,
+    path = "/payment_link/{payment_link_id}",
+    params(
+        ("payment_link_id" = String, Path, description = "The identifier for payment link"),
+    ),
+    request_body = PaymentLinkUpdateRequest,
+    responses(
+        (status = 200, description = "Payment link updated", body = RetrievePaymentLinkResponse),
+        (status = 404, description = "No payment link found"),
+        (status = 412, description = "Payment link has expired")
+    ),
+    tag = "Payments",
+    operation_id = "Update a Payment Link",
+    security(("api_key" = []))
+)]
+pub async fn payment_link_update() {}
+
+/// Payment Link - Deactivate
+///
+/// To expire a payment link before its expiry time. The payment backing the link is moved to `expired` if it is yet to be confirmed
+#[utoipa::path(
+    delete,
+    path = "/payment_link/{payment_link_id}",
+    params(
+        ("payment_link_id" = String, Path, description = "The identifier for payment link"),
+    ),
+    responses(
+        (status = 200, description = "Payment link deactivated", body = RetrievePaymentLinkResponse),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payments",
+    operation_id = "Deactivate a Payment Link",
+    security(("api_key" = []))
+)]
+pub async fn payment_link_deactivate() {}
+
+/// Payment Link - Status
+///
+/// To retrieve the status of a payment link and the payment backing it
+#[utoipa::path(
+    get,
+    path = "/payment_link/{payment_link_id}/status",
+    params(
+        ("payment_link_id" = String, Path, description = "The identifier for payment link"),
+    ),
+    responses(
+        (status = 200, description = "Gets the status of the payment link", body = PaymentLinkStatusResponse),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payments",
+    operation_id = "Retrieve the status of a Payment Link",
+    security(("api_key" = []))
+)]
+pub async fn payment_link_status_retrieve() {}
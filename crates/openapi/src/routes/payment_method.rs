@@ -180,6 +180,49 @@ pub async fn payment_method_update_api() {}
 #[cfg(feature = "v1")]
 pub async fn payment_method_delete_api() {}
 
+/// Payment Method Health - List
+///
+/// To list the usage and expiry of the saved payment methods of the merchant, the ones expiring first coming first
+#[utoipa::path(
+    get,
+    path = "/payment_methods/health",
+    params(
+        ("customer_id" = Option<String>, Query, description = "Only lists the payment methods saved for this customer"),
+        ("expiring_within_days" = Option<u32>, Query, description = "Only lists the cards which expire within this number of days, including the expired ones"),
+        ("min_consecutive_failures" = Option<u32>, Query, description = "Only lists the payment methods which failed at least this number of times in a row"),
+        ("limit" = Option<u32>, Query, description = "The maximum number of payment methods to include in the response"),
+        ("offset" = Option<u32>, Query, description = "The number of payment methods to skip")
+    ),
+    responses(
+        (status = 200, description = "Payment method health retrieved", body = PaymentMethodHealthListResponse)
+    ),
+    tag = "Payment Methods",
+    operation_id = "List Payment Method Health",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn list_payment_method_health() {}
+
+/// Payment Method Health - Retrieve
+///
+/// To retrieve the usage and expiry of a saved payment method
+#[utoipa::path(
+    get,
+    path = "/payment_methods/{method_id}/health",
+    params(
+        ("method_id" = String, Path, description = "The unique identifier for the Payment Method"),
+    ),
+    responses(
+        (status = 200, description = "Payment method health retrieved", body = PaymentMethodHealthResponse),
+        (status = 404, description = "Payment Method does not exist in records")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Retrieve Payment Method Health",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn retrieve_payment_method_health() {}
+
 /// Payment Method - Set Default Payment Method for Customer
 ///
 /// Set the Payment Method as Default for the Customer.
@@ -0,0 +1,63 @@
+/// Payment Reviews - List
+///
+/// To list the payments held for a review by the merchant, oldest first
+#[utoipa::path(
+    get,
+    path = "/payment_reviews",
+    params(
+        ("status" = Option<PaymentReviewStatus>, Query, description = "Only lists the reviews with this status"),
+        ("source" = Option<PaymentReviewSource>, Query, description = "Only lists the reviews flagged by this source"),
+        ("profile_id" = Option<String>, Query, description = "Only lists the reviews of payments made on this profile"),
+        ("created_after" = Option<PrimitiveDateTime>, Query, description = "Only lists the reviews created at or after this time"),
+        ("created_before" = Option<PrimitiveDateTime>, Query, description = "Only lists the reviews created at or before this time"),
+        ("limit" = Option<u32>, Query, description = "The maximum number of reviews to include in the response"),
+        ("offset" = Option<u32>, Query, description = "The number of reviews to skip")
+    ),
+    responses(
+        (status = 200, description = "Payment reviews retrieved", body = PaymentReviewListResponse)
+    ),
+    tag = "Payment Reviews",
+    operation_id = "List Payment Reviews",
+    security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn list_payment_reviews() {}
+
+/// Payment Reviews - Approve
+///
+/// To approve a payment held for review, which captures it
+#[utoipa::path(
+    post,
+    path = "/payment_reviews/{payment_id}/approve",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    request_body = PaymentReviewApproveRequest,
+    responses(
+        (status = 200, description = "Payment approved", body = PaymentReviewDecisionResponse),
+        (status = 404, description = "Payment is not pending review")
+    ),
+    tag = "Payment Reviews",
+    operation_id = "Approve a Payment Review",
+    security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn approve_payment_review() {}
+
+/// Payment Reviews - Decline
+///
+/// To decline a payment held for review, which voids it. The requested details of the payment are added to the blocklist
+#[utoipa::path(
+    post,
+    path = "/payment_reviews/{payment_id}/decline",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    request_body = PaymentReviewDeclineRequest,
+    responses(
+        (status = 200, description = "Payment declined", body = PaymentReviewDecisionResponse),
+        (status = 404, description = "Payment is not pending review")
+    ),
+    tag = "Payment Reviews",
+    operation_id = "Decline a Payment Review",
+    security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn decline_payment_review() {}
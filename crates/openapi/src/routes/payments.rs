@@ -712,7 +712,8 @@ pub fn payments_confirm() {}
     post,
     path = "/payments/{payment_id}/capture",
     params(
-        ("payment_id" = String, Path, description = "The identifier for payment")
+        ("payment_id" = String, Path, description = "The identifier for payment"),
+        ("Idempotency-Key" = Option<String>, Header, description = "Key with which the response of the request is replayed for retries of the same request")
     ),
     request_body (
         content = PaymentsCaptureRequest,
@@ -731,7 +732,8 @@ pub fn payments_confirm() {}
     ),
     responses(
         (status = 200, description = "Payment captured", body = PaymentsResponse),
-        (status = 400, description = "Missing mandatory fields", body = GenericErrorResponseOpenApi)
+        (status = 400, description = "Missing mandatory fields", body = GenericErrorResponseOpenApi),
+        (status = 409, description = "Idempotency key reused with a different request", body = GenericErrorResponseOpenApi)
     ),
     tag = "Payments",
     operation_id = "Capture a Payment",
@@ -800,11 +802,13 @@ pub fn payments_connector_session() {}
         )
     ),
     params(
-        ("payment_id" = String, Path, description = "The identifier for payment")
+        ("payment_id" = String, Path, description = "The identifier for payment"),
+        ("Idempotency-Key" = Option<String>, Header, description = "Key with which the response of the request is replayed for retries of the same request")
     ),
     responses(
         (status = 200, description = "Payment canceled"),
-        (status = 400, description = "Missing mandatory fields", body = GenericErrorResponseOpenApi)
+        (status = 400, description = "Missing mandatory fields", body = GenericErrorResponseOpenApi),
+        (status = 409, description = "Idempotency key reused with a different request", body = GenericErrorResponseOpenApi)
     ),
     tag = "Payments",
     operation_id = "Cancel a Payment",
@@ -862,7 +866,8 @@ pub fn payments_cancel_post_capture() {}
         ("created_lt" = Option<PrimitiveDateTime>, Query, description = "Time less than the payment created time"),
         ("created_gt" = Option<PrimitiveDateTime>, Query, description = "Time greater than the payment created time"),
         ("created_lte" = Option<PrimitiveDateTime>, Query, description = "Time less than or equals to the payment created time"),
-        ("created_gte" = Option<PrimitiveDateTime>, Query, description = "Time greater than or equals to the payment created time")
+        ("created_gte" = Option<PrimitiveDateTime>, Query, description = "Time greater than or equals to the payment created time"),
+        ("cursor" = Option<String>, Query, description = "An opaque cursor returned as `next_cursor` by a previous list call, to fetch the page after it")
     ),
     responses(
         (status = 200, description = "Successfully retrieved a payment list", body = Vec<PaymentListResponse>),
@@ -994,6 +999,26 @@ pub fn payments_dynamic_tax_calculation() {}
 
 pub fn payments_post_session_tokens() {}
 
+/// Payments - DCC Offer
+///
+/// Identifies the currency of the card from its BIN and, when it differs from the currency of the payment, offers the customer to pay the converted amount in the currency of the card at a rate guaranteed until the offer expires. The choice of the customer is passed in `dcc` on confirming the payment.
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/dcc_offer",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    request_body=PaymentsDccOfferRequest,
+    responses(
+        (status = 200, description = "DCC offer for the card", body = PaymentsDccOfferResponse),
+        (status = 400, description = "Missing mandatory fields", body = GenericErrorResponseOpenApi)
+    ),
+    tag = "Payments",
+    operation_id = "Get a DCC offer for a Payment",
+    security(("publishable_key" = []))
+)]
+pub fn payments_dcc_offer() {}
+
 /// Payments - Update Metadata
 #[utoipa::path(
     post,
@@ -3,9 +3,13 @@
     post,
     path = "/payouts/create",
     request_body=PayoutsCreateRequest,
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "Key with which the response of the request is replayed for retries of the same request")
+    ),
     responses(
         (status = 200, description = "Payout created", body = PayoutCreateResponse),
-        (status = 400, description = "Missing Mandatory fields")
+        (status = 400, description = "Missing Mandatory fields"),
+        (status = 409, description = "Idempotency key reused with a different request")
     ),
     tag = "Payouts",
     operation_id = "Create a Payout",
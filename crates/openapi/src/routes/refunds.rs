@@ -36,9 +36,13 @@
             ),
         )
     ),
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "Key with which the response of the request is replayed for retries of the same request")
+    ),
     responses(
         (status = 200, description = "Refund created", body = RefundResponse),
-        (status = 400, description = "Missing Mandatory fields", body = GenericErrorResponseOpenApi)
+        (status = 400, description = "Missing Mandatory fields", body = GenericErrorResponseOpenApi),
+        (status = 409, description = "Idempotency key reused with a different request", body = GenericErrorResponseOpenApi)
     ),
     tag = "Refunds",
     operation_id = "Create a Refund",
@@ -115,6 +119,70 @@ pub async fn refunds_retrieve_with_body() {}
 #[cfg(feature = "v1")]
 pub async fn refunds_update() {}
 
+/// Refunds - Cancel
+///
+/// To cancel a scheduled refund, before it is sent to the connector
+#[utoipa::path(
+    post,
+    path = "/refunds/{refund_id}/cancel",
+    params(
+        ("refund_id" = String, Path, description = "The identifier for refund")
+    ),
+    responses(
+        (status = 200, description = "Refund cancelled", body = RefundResponse),
+        (status = 400, description = "Refund is not in scheduled state", body = GenericErrorResponseOpenApi),
+        (status = 404, description = "Refund does not exist in our records")
+    ),
+    tag = "Refunds",
+    operation_id = "Cancel a Refund",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn refunds_cancel() {}
+
+/// Refunds - Approve
+///
+/// To approve a refund pending approval, which sends it to the connector. The refund can't be approved by the user who created it
+#[utoipa::path(
+    post,
+    path = "/refunds/{refund_id}/approve",
+    params(
+        ("refund_id" = String, Path, description = "The identifier for refund")
+    ),
+    responses(
+        (status = 200, description = "Refund approved", body = RefundResponse),
+        (status = 400, description = "Refund is not pending approval", body = GenericErrorResponseOpenApi),
+        (status = 404, description = "Refund does not exist in our records")
+    ),
+    tag = "Refunds",
+    operation_id = "Approve a Refund",
+    security(("jwt_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn refunds_approve() {}
+
+/// Refunds - Reject
+///
+/// To reject a refund pending approval, which cancels it. The refund can't be rejected by the user who created it
+#[utoipa::path(
+    post,
+    path = "/refunds/{refund_id}/reject",
+    params(
+        ("refund_id" = String, Path, description = "The identifier for refund")
+    ),
+    request_body=RefundRejectRequest,
+    responses(
+        (status = 200, description = "Refund rejected", body = RefundResponse),
+        (status = 400, description = "Refund is not pending approval", body = GenericErrorResponseOpenApi),
+        (status = 404, description = "Refund does not exist in our records")
+    ),
+    tag = "Refunds",
+    operation_id = "Reject a Refund",
+    security(("jwt_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn refunds_reject() {}
+
 /// Refunds - List
 ///
 /// Lists all the refunds associated with the merchant, or for a specific payment if payment_id is provided
@@ -0,0 +1,155 @@
+/// Reports - Create Template
+///
+/// To create a report template. The report covers the last complete period of the frequency of
+/// the template in UTC, and is generated at the end of every period and delivered by email or to
+/// the file storage.
+#[utoipa::path(
+    post,
+    path = "/reports/templates",
+    request_body = ReportTemplateCreateRequest,
+    responses(
+        (status = 200, description = "Report template created", body = ReportTemplateResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Reports",
+    operation_id = "Create a Report Template",
+    security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn create_report_template() {}
+
+/// Reports - List Templates
+///
+/// To list the report templates of the merchant, the latest template at the top
+#[utoipa::path(
+    get,
+    path = "/reports/templates",
+    responses(
+        (status = 200, description = "Report templates retrieved", body = ReportTemplateListResponse)
+    ),
+    tag = "Reports",
+    operation_id = "List Report Templates",
+    security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn list_report_templates() {}
+
+/// Reports - Retrieve Template
+///
+/// To retrieve a report template of the merchant
+#[utoipa::path(
+    get,
+    path = "/reports/templates/{template_id}",
+    params(
+        ("template_id" = String, Path, description = "The identifier for the report template")
+    ),
+    responses(
+        (status = 200, description = "Report template retrieved", body = ReportTemplateResponse),
+        (status = 404, description = "Report template does not exist in our records")
+    ),
+    tag = "Reports",
+    operation_id = "Retrieve a Report Template",
+    security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn retrieve_report_template() {}
+
+/// Reports - Update Template
+///
+/// To update a report template, the changes apply from the next report of the template
+#[utoipa::path(
+    post,
+    path = "/reports/templates/{template_id}",
+    params(
+        ("template_id" = String, Path, description = "The identifier for the report template")
+    ),
+    request_body = ReportTemplateUpdateRequest,
+    responses(
+        (status = 200, description = "Report template updated", body = ReportTemplateResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Report template does not exist in our records")
+    ),
+    tag = "Reports",
+    operation_id = "Update a Report Template",
+    security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn update_report_template() {}
+
+/// Reports - Delete Template
+///
+/// To delete a report template, which stops its scheduled reports. The runs of the template are
+/// kept.
+#[utoipa::path(
+    delete,
+    path = "/reports/templates/{template_id}",
+    params(
+        ("template_id" = String, Path, description = "The identifier for the report template")
+    ),
+    responses(
+        (status = 200, description = "Report template deleted", body = ReportTemplateDeleteResponse),
+        (status = 404, description = "Report template does not exist in our records")
+    ),
+    tag = "Reports",
+    operation_id = "Delete a Report Template",
+    security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn delete_report_template() {}
+
+/// Reports - Run
+///
+/// To generate and deliver the report of a template on demand. A report which could not be
+/// generated or delivered is recorded as a failed run.
+#[utoipa::path(
+    post,
+    path = "/reports/templates/{template_id}/run",
+    params(
+        ("template_id" = String, Path, description = "The identifier for the report template")
+    ),
+    request_body = ReportRunRequest,
+    responses(
+        (status = 200, description = "Report run", body = ReportRunResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Report template does not exist in our records")
+    ),
+    tag = "Reports",
+    operation_id = "Run a Report",
+    security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn run_report() {}
+
+/// Reports - List Runs
+///
+/// To list the scheduled and on demand runs of the reports of the merchant, the latest run at the
+/// top
+#[utoipa::path(
+    get,
+    path = "/reports/runs",
+    params(
+        ("template_id" = Option<String>, Query, description = "Only include the runs of this report template"),
+        ("limit" = Option<u32>, Query, description = "The maximum number of runs to return"),
+        ("offset" = Option<u32>, Query, description = "The number of runs to skip")
+    ),
+    responses(
+        (status = 200, description = "Report runs retrieved", body = ReportRunListResponse)
+    ),
+    tag = "Reports",
+    operation_id = "List Report Runs",
+    security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn list_report_runs() {}
+
+/// Reports - Download Run File
+///
+/// To download the CSV or XLSX file of the report generated by a run
+#[utoipa::path(
+    get,
+    path = "/reports/runs/{run_id}/file",
+    params(
+        ("run_id" = String, Path, description = "The identifier for the report run")
+    ),
+    responses(
+        (status = 200, description = "Report file downloaded"),
+        (status = 404, description = "Report run does not exist in our records or its report was not generated")
+    ),
+    tag = "Reports",
+    operation_id = "Download a Report Run File",
+    security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn download_report_run_file() {}
@@ -452,3 +452,504 @@ pub async fn call_update_gateway_score_open_router() {}
    security(("api_key" = []))
 )]
 pub async fn evaluate_routing_rule() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Simulate config
+///
+/// Simulate the connector selection of a routing config against a synthetic payment, without creating a payment
+#[utoipa::path(
+    post,
+    path = "/routing/simulate",
+    request_body = RoutingSimulateRequest,
+    responses(
+        (status = 200, description = "Routing config simulated", body = RoutingSimulateResponse),
+        (status = 400, description = "Request body is malformed"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 412, description = "Precondition failed"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Simulate a routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_simulate_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Retrieve version history
+///
+/// Retrieve the activation history of routing configs under a profile
+#[utoipa::path(
+    get,
+    path = "/routing/{profile_id}/versions",
+    params(
+        ("profile_id" = String, Path, description = "The unique identifier for a profile"),
+        ("transaction_type" = Option<TransactionType>, Query, description = "The transaction type of the routing configs"),
+    ),
+    responses(
+        (status = 200, description = "Routing version history retrieved", body = RoutingVersionHistory),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Retrieve routing version history",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_retrieve_version_history() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Rollback config
+///
+/// Re-activate the routing config which was active at the given version of the profile's history
+#[utoipa::path(
+    post,
+    path = "/routing/{profile_id}/rollback/{version}",
+    params(
+        ("profile_id" = String, Path, description = "The unique identifier for a profile"),
+        ("version" = u32, Path, description = "The version to roll back to"),
+    ),
+    responses(
+        (status = 200, description = "Routing config rolled back", body = RoutingDictionaryRecord),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 412, description = "Algorithm is already active"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Rollback a routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_rollback_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - List scheduled activations
+///
+/// List the activations of routing configs which were scheduled under a profile, along with their status
+#[utoipa::path(
+    get,
+    path = "/routing/{profile_id}/activations",
+    params(
+        ("profile_id" = String, Path, description = "The unique identifier for a profile"),
+    ),
+    responses(
+        (status = 200, description = "Scheduled routing activations retrieved", body = RoutingScheduledActivations),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "List scheduled routing activations",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_list_scheduled_activations() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Cancel scheduled activation
+///
+/// Cancel the activation of a routing config which is yet to be activated at its scheduled time
+#[utoipa::path(
+    post,
+    path = "/routing/{profile_id}/activations/{algorithm_id}/cancel",
+    params(
+        ("profile_id" = String, Path, description = "The unique identifier for a profile"),
+        ("algorithm_id" = String, Path, description = "The unique identifier for a routing config"),
+    ),
+    responses(
+        (status = 200, description = "Scheduled routing activation cancelled", body = RoutingScheduledActivation),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 412, description = "Routing activation is not scheduled"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Cancel scheduled routing activation",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_cancel_scheduled_activation() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Update latency based routing config
+///
+/// Configure the latency threshold above which connectors are deprioritized for a profile
+#[utoipa::path(
+    post,
+    path = "/routing/latency/profile/{profile_id}",
+    request_body = LatencyBasedRoutingConfig,
+    params(
+        ("profile_id" = String, Path, description = "The unique identifier for a profile"),
+    ),
+    responses(
+        (status = 200, description = "Latency based routing config updated", body = LatencyBasedRoutingResponse),
+        (status = 400, description = "Request body is malformed"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Update latency based routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn upsert_latency_based_routing_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Retrieve latency based routing config
+///
+/// Retrieve the latency based routing config of a profile along with the recent latency of its connectors
+#[utoipa::path(
+    get,
+    path = "/routing/latency/profile/{profile_id}",
+    params(
+        ("profile_id" = String, Path, description = "The unique identifier for a profile"),
+    ),
+    responses(
+        (status = 200, description = "Latency based routing config retrieved", body = LatencyBasedRoutingResponse),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Retrieve latency based routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn retrieve_latency_based_routing_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Create or update connector fee schedule
+///
+/// Configure the fees charged by a merchant connector account, used by cost routing algorithms
+#[utoipa::path(
+    post,
+    path = "/routing/cost/profile/{profile_id}/fee_schedule/{merchant_connector_id}",
+    request_body = ConnectorFeeScheduleRequest,
+    params(
+        ("profile_id" = String, Path, description = "The unique identifier for a profile"),
+        ("merchant_connector_id" = String, Path, description = "The unique identifier for a merchant connector account"),
+    ),
+    responses(
+        (status = 200, description = "Fee schedule created or updated", body = ConnectorFeeSchedule),
+        (status = 400, description = "Request body is malformed"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Upsert connector fee schedule",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn upsert_connector_fee_schedule() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Retrieve connector fee schedule
+///
+/// Retrieve the fee schedule of a merchant connector account
+#[utoipa::path(
+    get,
+    path = "/routing/cost/profile/{profile_id}/fee_schedule/{merchant_connector_id}",
+    params(
+        ("profile_id" = String, Path, description = "The unique identifier for a profile"),
+        ("merchant_connector_id" = String, Path, description = "The unique identifier for a merchant connector account"),
+    ),
+    responses(
+        (status = 200, description = "Fee schedule retrieved", body = ConnectorFeeSchedule),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Retrieve connector fee schedule",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn retrieve_connector_fee_schedule() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Delete connector fee schedule
+///
+/// Delete the fee schedule of a merchant connector account
+#[utoipa::path(
+    delete,
+    path = "/routing/cost/profile/{profile_id}/fee_schedule/{merchant_connector_id}",
+    params(
+        ("profile_id" = String, Path, description = "The unique identifier for a profile"),
+        ("merchant_connector_id" = String, Path, description = "The unique identifier for a merchant connector account"),
+    ),
+    responses(
+        (status = 200, description = "Fee schedule deleted", body = ConnectorFeeSchedule),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Delete connector fee schedule",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn delete_connector_fee_schedule() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Retrieve audit log
+///
+/// Retrieve the audit log of changes made to the routing configs of the merchant
+#[utoipa::path(
+    get,
+    path = "/routing/audit",
+    params(
+        ("profile_id" = Option<String>, Query, description = "The unique identifier for a profile"),
+        ("start_time" = Option<PrimitiveDateTime>, Query, description = "Filter the records created at or after the specified time"),
+        ("end_time" = Option<PrimitiveDateTime>, Query, description = "Filter the records created at or before the specified time"),
+        ("limit" = Option<u16>, Query, description = "The number of records to be returned"),
+        ("offset" = Option<u16>, Query, description = "The number of records to be skipped"),
+    ),
+    responses(
+        (status = 200, description = "Routing audit log retrieved", body = RoutingAuditLogResponse),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Retrieve routing audit log",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn retrieve_routing_audit_log() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Start experiment
+///
+/// Start an experiment which splits the payments of a profile between two routing algorithms
+#[utoipa::path(
+    post,
+    path = "/routing/experiment/profile/{profile_id}/start",
+    request_body = RoutingExperimentRequest,
+    params(
+        ("profile_id" = String, Path, description = "The unique identifier for a profile"),
+    ),
+    responses(
+        (status = 200, description = "Routing experiment started", body = RoutingExperiment),
+        (status = 400, description = "Request body is malformed"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Start routing experiment",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn start_routing_experiment() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Stop experiment
+///
+/// Stop the running routing experiment of a profile
+#[utoipa::path(
+    post,
+    path = "/routing/experiment/profile/{profile_id}/stop",
+    params(
+        ("profile_id" = String, Path, description = "The unique identifier for a profile"),
+    ),
+    responses(
+        (status = 200, description = "Routing experiment stopped", body = RoutingExperiment),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Stop routing experiment",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn stop_routing_experiment() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Retrieve experiment results
+///
+/// Retrieve the latest routing experiment of a profile, along with the authorization rate of each
+/// of its arms
+#[utoipa::path(
+    get,
+    path = "/routing/experiment/profile/{profile_id}",
+    params(
+        ("profile_id" = String, Path, description = "The unique identifier for a profile"),
+    ),
+    responses(
+        (status = 200, description = "Routing experiment results retrieved", body = RoutingExperimentResults),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Retrieve routing experiment results",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn retrieve_routing_experiment_results() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Update payment method default config
+///
+/// Update the default fallback connectors used for the payments of a payment method type, in place
+/// of the default fallback connectors of the profile
+#[utoipa::path(
+    post,
+    path = "/routing/default/profile/{profile_id}/payment_method_type/{payment_method_type}",
+    params(
+        ("profile_id" = String, Path, description = "The unique identifier for a profile"),
+        ("payment_method_type" = PaymentMethodType, Path, description = "The payment method type the connectors apply to"),
+    ),
+    request_body = Vec<RoutableConnectorChoice>,
+    responses(
+        (status = 200, description = "Payment method default config updated", body = PaymentMethodDefaultRoutingConfig),
+        (status = 400, description = "Malformed request"),
+        (status = 404, description = "Resource missing"),
+        (status = 422, description = "Unprocessable request"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Update payment method default config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_update_payment_method_default_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Retrieve payment method default config
+///
+/// Retrieve the default fallback connectors configured for the payments of a payment method type
+#[utoipa::path(
+    get,
+    path = "/routing/default/profile/{profile_id}/payment_method_type/{payment_method_type}",
+    params(
+        ("profile_id" = String, Path, description = "The unique identifier for a profile"),
+        ("payment_method_type" = PaymentMethodType, Path, description = "The payment method type the connectors apply to"),
+    ),
+    responses(
+        (status = 200, description = "Payment method default config retrieved", body = PaymentMethodDefaultRoutingConfig),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Retrieve payment method default config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_retrieve_payment_method_default_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Delete payment method default config
+///
+/// Delete the default fallback connectors configured for the payments of a payment method type, so
+/// that the default fallback connectors of the profile are used instead
+#[utoipa::path(
+    delete,
+    path = "/routing/default/profile/{profile_id}/payment_method_type/{payment_method_type}",
+    params(
+        ("profile_id" = String, Path, description = "The unique identifier for a profile"),
+        ("payment_method_type" = PaymentMethodType, Path, description = "The payment method type the connectors apply to"),
+    ),
+    responses(
+        (status = 200, description = "Payment method default config deleted", body = PaymentMethodDefaultRoutingConfig),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Delete payment method default config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_delete_payment_method_default_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Export config
+///
+/// Export the static routing algorithms, the active routing algorithms and the default fallback
+/// connectors of a profile as a single document, which can be imported into another profile
+#[utoipa::path(
+    get,
+    path = "/routing/export/profile/{profile_id}",
+    params(
+        ("profile_id" = String, Path, description = "The unique identifier for a profile"),
+    ),
+    responses(
+        (status = 200, description = "Routing config exported", body = RoutingConfigExport),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Export routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn export_routing_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Import config
+///
+/// Import a routing config export into a profile. The exported routing algorithms are created
+/// under the profile with new ids, with their merchant connector accounts replaced by those of the
+/// profile, and the exported active routing algorithms and default fallback connectors are applied.
+/// The import is validated in full before anything is written, but the writes are not atomic. If
+/// the import fails part way, the routing algorithms created before the failure remain under the
+/// profile without being activated.
+#[utoipa::path(
+    post,
+    path = "/routing/import/profile/{profile_id}",
+    params(
+        ("profile_id" = String, Path, description = "The unique identifier for a profile"),
+    ),
+    request_body = RoutingConfigImportRequest,
+    responses(
+        (status = 200, description = "Routing config imported", body = RoutingConfigImportResponse),
+        (status = 400, description = "Request body is malformed"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 422, description = "Unprocessable request"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Import routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn import_routing_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Retrieve connector health
+///
+/// Retrieve the state of the health circuit breaker of the merchant connector accounts. Connectors
+/// whose breaker is open are skipped during routing.
+#[utoipa::path(
+    get,
+    path = "/routing/connector-health",
+    params(
+        ("profile_id" = Option<String>, Query, description = "The unique identifier for a profile"),
+    ),
+    responses(
+        (status = 200, description = "Connector health retrieved", body = ConnectorHealthResponse),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Retrieve connector health",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn retrieve_connector_health() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Diff
+///
+/// Compare two static routing algorithms and retrieve the rules added, removed and changed from
+/// the first algorithm to the second. Rules of advanced algorithms are matched by their name.
+#[utoipa::path(
+    get,
+    path = "/routing/{routing_algorithm_id}/diff/{other_routing_algorithm_id}",
+    params(
+        ("routing_algorithm_id" = String, Path, description = "The unique identifier for the base config"),
+        ("other_routing_algorithm_id" = String, Path, description = "The unique identifier for the config to compare against"),
+    ),
+    responses(
+        (status = 200, description = "Routing configs compared", body = RoutingAlgorithmDiffResponse),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 412, description = "Routing config is not a static routing config"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Diff routing configs",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_diff_config() {}
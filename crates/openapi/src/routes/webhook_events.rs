@@ -73,6 +73,27 @@ pub fn list_initial_webhook_delivery_attempts() {}
 )]
 pub fn list_initial_webhook_delivery_attempts_with_jwtauth() {}
 
+/// Events - Delivery Health
+///
+/// Retrieve the aggregated delivery health of the webhooks of a Profile, including the delivery
+/// success rate, the 95th percentile delivery latency and the number of webhooks pending retries or
+/// dead-lettered.
+#[utoipa::path(
+    get,
+    path = "/events/profile/delivery_health",
+    params(
+        ("created_after" = Option<PrimitiveDateTime>, Query, description = "Include webhooks created after the specified time"),
+        ("created_before" = Option<PrimitiveDateTime>, Query, description = "Include webhooks created before the specified time"),
+    ),
+    responses(
+        (status = 200, description = "Delivery health of webhooks retrieved successfully", body = WebhookDeliveryHealthResponse),
+    ),
+    tag = "Event",
+    operation_id = "Retrieve the delivery health of webhooks of a Profile",
+    security(("jwt_key" = []))
+)]
+pub fn get_webhook_delivery_health() {}
+
 /// Events - Delivery Attempt List
 ///
 /// List all delivery attempts for the specified Event.
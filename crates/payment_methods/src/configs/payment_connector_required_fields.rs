@@ -1407,6 +1407,11 @@ fn get_cards_required_fields() -> HashMap<Connector, RequiredFieldFinal> {
             Connector::DummyConnector7,
             fields(vec![], vec![], card_basic()),
         ),
+        #[cfg(feature = "dummy_connector")]
+        (
+            Connector::DummyAdvancedConnector,
+            fields(vec![], vec![], card_basic()),
+        ),
         (
             Connector::Elavon,
             fields(vec![], [card_basic(), billing_email()].concat(), vec![]),
@@ -1424,6 +1429,7 @@ fn get_cards_required_fields() -> HashMap<Connector, RequiredFieldFinal> {
             ),
         ),
         (Connector::Forte, fields(vec![], card_with_name(), vec![])),
+        (Connector::Genericrest, fields(vec![], vec![], card_basic())),
         (Connector::Globalpay, fields(vec![], vec![], card_basic())),
         (
             Connector::Hipay,
@@ -4,7 +4,7 @@
 //! and deserialization while calling redis.
 //! It also includes instruments to provide tracing.
 
-use std::fmt::Debug;
+use std::{collections::HashMap, fmt::Debug};
 
 use common_utils::{
     errors::CustomResult,
@@ -635,10 +635,16 @@ impl super::RedisConnectionPool {
         count: Option<u32>,
         scan_type: Option<ScanType>,
     ) -> CustomResult<Vec<String>, errors::RedisError> {
-        Ok(self
-            .pool
-            .next()
-            .scan(pattern.tenant_aware_key(self), count, scan_type)
+        let client = self.pool.next();
+        let pattern = pattern.tenant_aware_key(self);
+        let scan_results = if self.config.cluster_enabled {
+            // The keys matching the pattern may be spread across all the nodes of the cluster
+            client.scan_cluster(pattern, count, scan_type).left_stream()
+        } else {
+            client.scan(pattern, count, scan_type).right_stream()
+        };
+
+        Ok(scan_results
             .filter_map(|value| async move {
                 match value {
                     Ok(mut v) => {
@@ -741,6 +747,18 @@ impl super::RedisConnectionPool {
         }
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn delete_hash_fields(
+        &self,
+        key: &RedisKey,
+        fields: Vec<String>,
+    ) -> CustomResult<i64, errors::RedisError> {
+        self.pool
+            .hdel(key.tenant_aware_key(self), fields)
+            .await
+            .change_context(errors::RedisError::DeleteFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn get_hash_field_and_deserialize<V>(
         &self,
@@ -853,6 +871,22 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::GetLengthFailed)
     }
 
+    /// Returns the entries of the stream with IDs between `start` and `end`, both inclusive. `-`
+    /// and `+` stand for the lowest and the highest IDs in the stream.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn stream_read_range(
+        &self,
+        stream: &RedisKey,
+        start: &str,
+        end: &str,
+        count: Option<u64>,
+    ) -> CustomResult<Vec<(String, HashMap<String, String>)>, errors::RedisError> {
+        self.pool
+            .xrange_values(stream.tenant_aware_key(self), start, end, count)
+            .await
+            .change_context(errors::RedisError::StreamReadFailed)
+    }
+
     pub fn get_keys_with_prefix<K>(&self, keys: K) -> MultipleKeys
     where
         K: Into<MultipleKeys> + Debug + Send + Sync,
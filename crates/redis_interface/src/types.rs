@@ -51,6 +51,12 @@ pub struct RedisSettings {
     pub port: u16,
     pub cluster_enabled: bool,
     pub cluster_urls: Vec<String>,
+    /// Whether the primary is discovered through the sentinels in `sentinel_urls`, in which case
+    /// `host` and `port` are ignored
+    pub sentinel_enabled: bool,
+    pub sentinel_urls: Vec<String>,
+    /// Name of the primary monitored by the sentinels
+    pub sentinel_service_name: String,
     pub use_legacy_version: bool,
     pub pool_size: usize,
     pub reconnect_max_attempts: u32,
@@ -86,6 +92,34 @@ impl RedisSettings {
             ))
         })?;
 
+        when(self.cluster_enabled && self.sentinel_enabled, || {
+            Err(errors::RedisError::InvalidConfiguration(
+                "Redis `cluster_enabled` and `sentinel_enabled` cannot both be `true`".into(),
+            ))
+        })?;
+
+        when(
+            self.sentinel_enabled && self.sentinel_urls.is_empty(),
+            || {
+                Err(errors::RedisError::InvalidConfiguration(
+                    "Redis `sentinel_urls` must be specified if `sentinel_enabled` is `true`"
+                        .into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.sentinel_enabled && self.sentinel_service_name.is_default_or_empty(),
+            || {
+                Err(errors::RedisError::InvalidConfiguration(
+                    "Redis `sentinel_service_name` must be specified if `sentinel_enabled` is `true`"
+                        .into(),
+                ))
+            },
+        )?;
+
+        self.get_server_config()?;
+
         when(
             self.default_command_timeout < self.unresponsive_timeout,
             || {
@@ -96,6 +130,51 @@ impl RedisSettings {
             },
         )
     }
+
+    /// Returns the servers to connect to for the configured topology. Cluster nodes and sentinels
+    /// are specified as `host:port`.
+    pub fn get_server_config(&self) -> CustomResult<fred::types::ServerConfig, errors::RedisError> {
+        if self.cluster_enabled {
+            let mut hosts = vec![(self.host.clone(), self.port)];
+            for url in &self.cluster_urls {
+                hosts.push(parse_server_address(url)?);
+            }
+            Ok(fred::types::ServerConfig::new_clustered(hosts))
+        } else if self.sentinel_enabled {
+            let hosts = self
+                .sentinel_urls
+                .iter()
+                .map(|url| parse_server_address(url))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(fred::types::ServerConfig::new_sentinel(
+                hosts,
+                self.sentinel_service_name.clone(),
+            ))
+        } else {
+            Ok(fred::types::ServerConfig::new_centralized(
+                self.host.clone(),
+                self.port,
+            ))
+        }
+    }
+}
+
+fn parse_server_address(url: &str) -> CustomResult<(String, u16), errors::RedisError> {
+    url.rsplit_once(':')
+        .and_then(|(host, port)| Some((host.to_owned(), port.parse().ok()?)))
+        .filter(|(host, _)| !host.is_empty())
+        .ok_or_else(|| {
+            errors::RedisError::InvalidConfiguration(format!(
+                "Redis server address `{url}` must be of the form `host:port`"
+            ))
+            .into()
+        })
+}
+
+/// Wraps the tag in braces, so that redis cluster assigns all the keys containing it to the same
+/// hash slot. Keys which are accessed together by a transaction or a script must share a tag.
+pub fn hash_tag(tag: &str) -> String {
+    format!("{{{tag}}}")
 }
 
 impl Default for RedisSettings {
@@ -105,6 +184,9 @@ impl Default for RedisSettings {
             port: 6379,
             cluster_enabled: false,
             cluster_urls: vec![],
+            sentinel_enabled: false,
+            sentinel_urls: vec![],
+            sentinel_service_name: String::new(),
             use_legacy_version: false,
             pool_size: 5,
             reconnect_max_attempts: 5,
@@ -15,6 +15,7 @@ pub mod routes {
     };
     use api_models::analytics::{
         api_event::QueryType,
+        disputes::DisputeMetricsQuery,
         search::{
             GetGlobalSearchRequest, GetSearchRequest, GetSearchRequestWithIndex, SearchIndex,
         },
@@ -47,6 +48,12 @@ pub mod routes {
 
     pub struct Analytics;
 
+    impl crate::routes::lock_utils::GetApiIdentifier for AnalyticsFlow {
+        fn get_api_identifier(&self) -> Option<crate::routes::lock_utils::ApiIdentifier> {
+            None
+        }
+    }
+
     impl Analytics {
         #[cfg(feature = "v2")]
         pub fn server(state: AppState) -> Scope {
@@ -165,6 +172,10 @@ pub mod routes {
                             web::resource("metrics/disputes")
                                 .route(web::post().to(get_merchant_dispute_metrics)),
                         )
+                        .service(
+                            web::resource("disputes/metrics")
+                                .route(web::get().to(get_merchant_dispute_metrics_by_query)),
+                        )
                         .service(
                             web::resource("filters/disputes")
                                 .route(web::post().to(get_merchant_dispute_filters)),
@@ -2894,6 +2905,37 @@ pub mod routes {
         .await
     }
 
+    pub async fn get_merchant_dispute_metrics_by_query(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        query_params: web::Query<DisputeMetricsQuery>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetDisputeMetrics;
+        let payload: GetDisputeMetricRequest = query_params.into_inner().into();
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let auth: AuthInfo = AuthInfo::MerchantLevel {
+                    org_id: org_id.clone(),
+                    merchant_ids: vec![merchant_id.clone()],
+                };
+                analytics::disputes::get_metrics(&state.pool, &auth, req)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
     #[cfg(feature = "v1")]
     /// # Panics
     ///
@@ -44,6 +44,11 @@ async fn main() -> ApplicationResult<()> {
         .expect("Failed to create the server");
     let _ = server.await;
 
+    if router::services::drain::is_draining() {
+        logger::info!("Server shut down after draining");
+        return Ok(());
+    }
+
     Err(error_stack::Report::from(ApplicationError::from(
         std::io::Error::other("Server shut down"),
     )))
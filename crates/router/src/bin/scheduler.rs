@@ -8,16 +8,19 @@ use error_stack::ResultExt;
 use router::{
     configs::settings::{CmdLineConf, Settings},
     core::{
+        api_locking,
         errors::{self, CustomResult},
         health_check::HealthCheckInterface,
+        payment_archival,
     },
     logger, routes,
-    services::{self, api},
+    services::{self, api, authentication as auth},
     workflows,
 };
 use router_env::{
     instrument,
     tracing::{self, Instrument},
+    Flow,
 };
 use scheduler::{
     consumer::workflows::ProcessTrackerWorkflow, errors::ProcessTrackerError,
@@ -78,6 +81,7 @@ async fn main() -> CustomResult<(), ProcessTrackerError> {
     let web_server = Box::pin(start_web_server(
         state.clone(),
         scheduler_flow_str.to_string(),
+        tx.clone(),
     ))
     .await
     .expect("Failed to create the server");
@@ -101,6 +105,7 @@ async fn main() -> CustomResult<(), ProcessTrackerError> {
 pub async fn start_web_server(
     state: routes::AppState,
     service: String,
+    shutdown_tx: mpsc::Sender<()>,
 ) -> errors::ApplicationResult<Server> {
     let server = state
         .conf
@@ -113,7 +118,9 @@ pub async fn start_web_server(
         .clone();
 
     let web_server = actix_web::HttpServer::new(move || {
-        actix_web::App::new().service(Health::server(state.clone(), service.clone()))
+        actix_web::App::new()
+            .service(Health::server(state.clone(), service.clone()))
+            .service(Drain::server(state.clone(), shutdown_tx.clone()))
     })
     .bind((server.host.as_str(), server.port))
     .change_context(ApplicationError::ConfigurationError)?
@@ -136,6 +143,51 @@ impl Health {
     }
 }
 
+pub struct Drain;
+
+impl Drain {
+    pub fn server(state: routes::AppState, shutdown_tx: mpsc::Sender<()>) -> Scope {
+        web::scope("drain")
+            .app_data(web::Data::new(state))
+            .app_data(web::Data::new(shutdown_tx))
+            .service(web::resource("").route(web::post().to(start_drain)))
+    }
+}
+
+/// Stops the scheduler from picking up new tasks, the scheduler exits once the tasks in progress
+/// are complete
+#[instrument(skip_all, fields(flow = ?Flow::DrainStart))]
+pub async fn start_drain(
+    state: web::Data<routes::AppState>,
+    shutdown_tx: web::Data<mpsc::Sender<()>>,
+    req: actix_web::HttpRequest,
+) -> impl actix_web::Responder {
+    let flow = Flow::DrainStart;
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |_, _, _, _| {
+            let shutdown_tx = shutdown_tx.clone();
+            async move {
+                // The channel is full or closed only if the scheduler is already shutting down
+                match shutdown_tx.try_send(()) {
+                    Ok(()) => logger::info!("Started draining the scheduler"),
+                    Err(_) => logger::info!("The scheduler is already shutting down"),
+                }
+                Ok::<_, error_stack::Report<errors::ApiErrorResponse>>(
+                    services::ApplicationResponse::StatusOk,
+                )
+            }
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
 #[instrument(skip_all)]
 pub async fn health() -> impl actix_web::Responder {
     logger::info!("Scheduler health was called");
@@ -330,6 +382,77 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::PassiveRecoveryWorkflow => {
                     Ok(Box::new(workflows::revenue_recovery::ExecutePcrWorkflow))
                 }
+                storage::ProcessTrackerRunner::RoutingActivationWorkflow => Ok(Box::new(
+                    workflows::routing_activation::RoutingActivationWorkflow,
+                )),
+                storage::ProcessTrackerRunner::MerchantKeyReEncryptionWorkflow => Ok(Box::new(
+                    workflows::merchant_key_re_encryption::MerchantKeyReEncryptionWorkflow,
+                )),
+                storage::ProcessTrackerRunner::MerchantKeyMigrationWorkflow => Ok(Box::new(
+                    workflows::merchant_key_migration::MerchantKeyMigrationWorkflow,
+                )),
+                storage::ProcessTrackerRunner::PaymentLinkExpiryWorkflow => Ok(Box::new(
+                    workflows::payment_link_expiry::PaymentLinkExpiryWorkflow,
+                )),
+                storage::ProcessTrackerRunner::SubscriptionBillingWorkflow => Ok(Box::new(
+                    workflows::subscription_billing::SubscriptionBillingWorkflow,
+                )),
+                storage::ProcessTrackerRunner::PaymentIntentExpiryWorkflow => Ok(Box::new(
+                    workflows::payment_intent_expiry::PaymentIntentExpiryWorkflow,
+                )),
+                storage::ProcessTrackerRunner::PayoutBatchWorkflow => {
+                    #[cfg(feature = "payouts")]
+                    {
+                        Ok(Box::new(workflows::payout_batch::PayoutBatchWorkflow))
+                    }
+                    #[cfg(not(feature = "payouts"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run payout batch workflow when payouts feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::SoftDeclineRetryWorkflow => Ok(Box::new(
+                    workflows::soft_decline_retry::SoftDeclineRetryWorkflow,
+                )),
+                storage::ProcessTrackerRunner::PaymentMethodExpiryWorkflow => Ok(Box::new(
+                    workflows::payment_method_expiry::PaymentMethodExpiryWorkflow,
+                )),
+                storage::ProcessTrackerRunner::DataRetentionPurgeWorkflow => Ok(Box::new(
+                    workflows::data_retention_purge::DataRetentionPurgeWorkflow,
+                )),
+                storage::ProcessTrackerRunner::PaymentArchivalWorkflow => Ok(Box::new(
+                    workflows::payment_archival::PaymentArchivalWorkflow,
+                )),
+                storage::ProcessTrackerRunner::ReportGenerationWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(
+                            workflows::report_generation::ReportGenerationWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                            "Cannot run report generation workflow when olap feature is disabled",
+                        )
+                    }
+                }
+                storage::ProcessTrackerRunner::AuthRateAlertWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(workflows::auth_rate_alert::AuthRateAlertWorkflow))
+                    }
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                            "Cannot run authorization rate alert workflow when olap feature is disabled",
+                        )
+                    }
+                }
             }
         };
 
@@ -378,6 +501,18 @@ async fn start_scheduler(
         .scheduler
         .clone()
         .ok_or(ProcessTrackerError::ConfigurationError)?;
+
+    // The producer schedules the archival of the payments, which is a recurring task of each tenant
+    if state.conf.payment_archival.enabled
+        && matches!(scheduler_flow, scheduler::SchedulerFlow::Producer)
+    {
+        for (tenant, store) in state.stores.iter() {
+            if let Err(error) = payment_archival::add_payment_archival_task(&**store).await {
+                logger::error!(?error, ?tenant, "Failed to schedule the payment archival");
+            }
+        }
+    }
+
     scheduler::start_process_tracker(
         state,
         scheduler_flow,
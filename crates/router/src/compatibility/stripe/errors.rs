@@ -285,6 +285,12 @@ pub enum StripeErrorCode {
     PlatformUnauthorizedRequest,
     #[error(error_type = StripeErrorType::HyperswitchError, code = "", message = "Profile Acquirer not found")]
     ProfileAcquirerNotFound,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "rate_limit", message = "Too many requests, retry after {retry_after_secs} seconds")]
+    RateLimit { retry_after_secs: u64 },
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "idempotency_key_in_use", message = "{message}")]
+    IdempotencyKeyInUse { message: String },
+    #[error(error_type = StripeErrorType::ApiError, code = "service_unavailable", message = "The server is not accepting new payments, retry after {retry_after_secs} seconds")]
+    ServiceUnavailable { retry_after_secs: u64 },
     // [#216]: https://github.com/juspay/hyperswitch/issues/216
     // Implement the remaining stripe error codes
 
@@ -327,7 +333,6 @@ pub enum StripeErrorCode {
         DebitNotAuthorized,
         EmailInvalid,
         ExpiredCard,
-        IdempotencyKeyInUse,
         IncorrectAddress,
         IncorrectCvc,
         IncorrectNumber,
@@ -393,7 +398,6 @@ pub enum StripeErrorCode {
         PostalCodeInvalid,
         ProcessingError,
         ProductInactive,
-        RateLimit,
         ReferToCustomer,
         RefundDisputedPayment,
         ResourceAlreadyExists,
@@ -450,6 +454,8 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             | errors::ApiErrorResponse::InvalidJwtToken
             | errors::ApiErrorResponse::GenericUnauthorized { .. }
             | errors::ApiErrorResponse::AccessForbidden { .. }
+            | errors::ApiErrorResponse::ApiKeyIpAddressNotAllowed { .. }
+            | errors::ApiErrorResponse::MerchantIpAddressNotAllowed { .. }
             | errors::ApiErrorResponse::InvalidCookie
             | errors::ApiErrorResponse::InvalidEphemeralKey
             | errors::ApiErrorResponse::CookieNotFound => Self::Unauthorized,
@@ -697,6 +703,22 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
                 object: "tokenization record".to_owned(),
                 id,
             },
+            errors::ApiErrorResponse::TooManyRequests { retry_after_secs } => {
+                Self::RateLimit { retry_after_secs }
+            }
+            errors::ApiErrorResponse::IdempotencyKeyReused { idempotency_key } => {
+                Self::IdempotencyKeyInUse {
+                    message: format!("Idempotency key {idempotency_key} has already been used with a different request"),
+                }
+            }
+            errors::ApiErrorResponse::IdempotencyKeyInProgress { idempotency_key } => {
+                Self::IdempotencyKeyInUse {
+                    message: format!("Another request with idempotency key {idempotency_key} is currently being processed"),
+                }
+            }
+            errors::ApiErrorResponse::ServiceDraining { retry_after_secs } => {
+                Self::ServiceUnavailable { retry_after_secs }
+            }
         }
     }
 }
@@ -792,15 +814,24 @@ impl actix_web::ResponseError for StripeErrorCode {
             }
             Self::LockTimeout => StatusCode::LOCKED,
             Self::ProfileAcquirerNotFound => StatusCode::NOT_FOUND,
+            Self::RateLimit { .. } => StatusCode::TOO_MANY_REQUESTS,
+            Self::IdempotencyKeyInUse { .. } => StatusCode::CONFLICT,
+            Self::ServiceUnavailable { .. } => StatusCode::SERVICE_UNAVAILABLE,
         }
     }
 
     fn error_response(&self) -> actix_web::HttpResponse {
         use actix_web::http::header;
 
-        actix_web::HttpResponseBuilder::new(self.status_code())
-            .insert_header((header::CONTENT_TYPE, mime::APPLICATION_JSON))
-            .body(self.to_string())
+        let mut response = actix_web::HttpResponseBuilder::new(self.status_code());
+        response.insert_header((header::CONTENT_TYPE, mime::APPLICATION_JSON));
+        if let Self::RateLimit { retry_after_secs }
+        | Self::ServiceUnavailable { retry_after_secs } = self
+        {
+            response.insert_header((header::RETRY_AFTER, *retry_after_secs));
+        }
+
+        response.body(self.to_string())
     }
 }
 
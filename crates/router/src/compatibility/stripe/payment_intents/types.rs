@@ -427,14 +427,14 @@ impl From<api_enums::IntentStatus> for StripePaymentStatus {
             api_enums::IntentStatus::Processing => Self::Processing,
             api_enums::IntentStatus::RequiresCustomerAction
             | api_enums::IntentStatus::RequiresMerchantAction
+            | api_enums::IntentStatus::RequiresMerchantReview
             | api_enums::IntentStatus::Conflicted => Self::RequiresAction,
             api_enums::IntentStatus::RequiresPaymentMethod => Self::RequiresPaymentMethod,
             api_enums::IntentStatus::RequiresConfirmation => Self::RequiresConfirmation,
             api_enums::IntentStatus::RequiresCapture
             | api_enums::IntentStatus::PartiallyCapturedAndCapturable
-            | api_enums::IntentStatus::PartiallyAuthorizedAndRequiresCapture => {
-                Self::RequiresCapture
-            }
+            | api_enums::IntentStatus::PartiallyAuthorizedAndRequiresCapture
+            | api_enums::IntentStatus::IncrementalAuthorizationPending => Self::RequiresCapture,
             api_enums::IntentStatus::Cancelled | api_enums::IntentStatus::CancelledPostCapture => {
                 Self::Canceled
             }
@@ -653,6 +653,7 @@ impl TryFrom<StripePaymentListConstraints> for payments::PaymentListConstraints
             created_gt: from_timestamp_to_datetime(item.created_gt)?,
             created_lte: from_timestamp_to_datetime(item.created_lte)?,
             created_gte: from_timestamp_to_datetime(item.created_gte)?,
+            cursor: None,
         })
     }
 }
@@ -50,11 +50,15 @@ pub async fn refund_create(
         state.into_inner(),
         &req,
         create_refund_req,
-        |state, auth: auth::AuthenticationData, req, _| {
+        |state, auth: auth::AuthenticationData, req, req_state| {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            refunds::refund_create_core(state, merchant_context, None, req)
+            let created_by = req_state
+                .auth_type
+                .as_ref()
+                .and_then(|auth_type| auth_type.get_created_by());
+            refunds::refund_create_core(state, merchant_context, None, req, created_by)
         },
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
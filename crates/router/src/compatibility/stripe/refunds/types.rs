@@ -40,6 +40,7 @@ pub enum StripeRefundStatus {
     Failed,
     Pending,
     RequiresAction,
+    Canceled,
 }
 
 impl From<StripeCreateRefundRequest> for refunds::RefundRequest {
@@ -72,8 +73,11 @@ impl From<refunds::RefundStatus> for StripeRefundStatus {
         match status {
             refunds::RefundStatus::Succeeded => Self::Succeeded,
             refunds::RefundStatus::Failed => Self::Failed,
-            refunds::RefundStatus::Pending => Self::Pending,
-            refunds::RefundStatus::Review => Self::RequiresAction,
+            refunds::RefundStatus::Pending | refunds::RefundStatus::Scheduled => Self::Pending,
+            refunds::RefundStatus::Review | refunds::RefundStatus::PendingApproval => {
+                Self::RequiresAction
+            }
+            refunds::RefundStatus::Cancelled => Self::Canceled,
         }
     }
 }
@@ -314,9 +314,11 @@ impl From<api_enums::IntentStatus> for StripeSetupStatus {
             }
             api_enums::IntentStatus::Failed | api_enums::IntentStatus::Expired => Self::Canceled,
             api_enums::IntentStatus::Processing
-            | api_enums::IntentStatus::PartiallyAuthorizedAndRequiresCapture => Self::Processing,
+            | api_enums::IntentStatus::PartiallyAuthorizedAndRequiresCapture
+            | api_enums::IntentStatus::IncrementalAuthorizationPending => Self::Processing,
             api_enums::IntentStatus::RequiresCustomerAction => Self::RequiresAction,
             api_enums::IntentStatus::RequiresMerchantAction
+            | api_enums::IntentStatus::RequiresMerchantReview
             | api_enums::IntentStatus::Conflicted => Self::RequiresAction,
             api_enums::IntentStatus::RequiresPaymentMethod => Self::RequiresPaymentMethod,
             api_enums::IntentStatus::RequiresConfirmation => Self::RequiresConfirmation,
@@ -599,6 +601,7 @@ impl TryFrom<StripePaymentListConstraints> for payments::PaymentListConstraints
             created_gt: from_timestamp_to_datetime(item.created_gt)?,
             created_lte: from_timestamp_to_datetime(item.created_lte)?,
             created_gte: from_timestamp_to_datetime(item.created_gte)?,
+            cursor: None,
         })
     }
 }
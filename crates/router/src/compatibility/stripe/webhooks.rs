@@ -90,6 +90,8 @@ pub enum StripeWebhookObject {
     Mandate(StripeMandateResponse),
     #[cfg(feature = "payouts")]
     Payout(StripePayoutResponse),
+    Routing(Box<api_models::routing::RoutingAuditLogRecord>),
+    Subscription(Box<api_models::subscription::SubscriptionResponse>),
 }
 
 #[derive(Serialize, Debug)]
@@ -302,6 +304,18 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PayoutProcessing => "payout.created",
         api_models::enums::EventType::PayoutExpired => "payout.failed",
         api_models::enums::EventType::PayoutReversed => "payout.reconciliation_completed",
+        api_models::enums::EventType::RoutingConfigLinked => "routing.config.linked",
+        api_models::enums::EventType::RoutingConfigUnlinked => "routing.config.unlinked",
+        api_models::enums::EventType::RoutingDefaultUpdated => "routing.default.updated",
+        api_models::enums::EventType::RoutingDynamicUpdated => "routing.dynamic.updated",
+        api_models::enums::EventType::RoutingVolumeSplitUpdated => "routing.volume_split.updated",
+        api_models::enums::EventType::RoutingExperimentUpdated => "routing.experiment.updated",
+        api_models::enums::EventType::RoutingLatencyConfigUpdated => "routing.latency.updated",
+        api_models::enums::EventType::RoutingFeeScheduleUpdated => "routing.fee_schedule.updated",
+        api_models::enums::EventType::SubscriptionPaymentSucceeded => "invoice.payment_succeeded",
+        api_models::enums::EventType::SubscriptionPaymentProcessing => "invoice.created",
+        api_models::enums::EventType::SubscriptionPaymentFailed => "invoice.payment_failed",
+        api_models::enums::EventType::SubscriptionInactive => "customer.subscription.deleted",
     }
 }
 
@@ -344,6 +358,10 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             }
             #[cfg(feature = "payouts")]
             api::OutgoingWebhookContent::PayoutDetails(payout) => Self::Payout((*payout).into()),
+            api::OutgoingWebhookContent::RoutingDetails(routing) => Self::Routing(routing),
+            api::OutgoingWebhookContent::SubscriptionDetails(subscription) => {
+                Self::Subscription(subscription)
+            }
         }
     }
 }
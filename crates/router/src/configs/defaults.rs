@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use common_utils::id_type;
 
@@ -19,6 +19,25 @@ impl Default for super::settings::Server {
     }
 }
 
+impl Default for super::settings::ForexApi {
+    fn default() -> Self {
+        Self {
+            api_key: masking::Secret::default(),
+            fallback_api_key: masking::Secret::default(),
+            data_expiration_delay_in_seconds: 0,
+            redis_lock_timeout_in_seconds: 0,
+            redis_ttl_in_seconds: 0,
+            providers: vec![
+                super::settings::ForexProvider::OpenExchangeRates,
+                super::settings::ForexProvider::CurrencyLayer,
+            ],
+            fixed_rates: None,
+            // 15 minutes
+            quote_validity_in_seconds: 900,
+        }
+    }
+}
+
 impl Default for super::settings::CorsSettings {
     fn default() -> Self {
         Self {
@@ -49,6 +68,16 @@ impl Default for super::settings::Database {
         }
     }
 }
+impl Default for super::settings::ReadReplicaSettings {
+    fn default() -> Self {
+        Self {
+            additional_hosts: Vec::new(),
+            max_replication_lag_in_ms: 1000,
+            lag_check_interval_in_secs: 5,
+        }
+    }
+}
+
 impl Default for super::settings::Locker {
     fn default() -> Self {
         Self {
@@ -95,6 +124,146 @@ impl Default for super::settings::Refund {
     }
 }
 
+impl Default for super::settings::ConnectorHealthSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            failure_rate_threshold: 50,
+            minimum_request_count: 20,
+            window_secs: 60,
+            open_duration_secs: 30,
+            half_open_trial_timeout_secs: 60,
+        }
+    }
+}
+
+impl Default for super::settings::RateLimitSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            api_key: super::settings::RateLimitBucket {
+                capacity: 100,
+                refill_per_sec: 50,
+            },
+            merchant: super::settings::RateLimitBucket {
+                capacity: 500,
+                refill_per_sec: 200,
+            },
+            route_groups: HashMap::new(),
+        }
+    }
+}
+
+impl Default for super::settings::ConnectorRateLimitSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_queue_wait_in_ms: 2000,
+            queue_poll_interval_in_ms: 20,
+            concurrency_slot_ttl_in_secs: 120,
+        }
+    }
+}
+
+impl Default for super::settings::DrainSettings {
+    fn default() -> Self {
+        Self {
+            retry_after_in_secs: 5,
+            grace_period_in_secs: 5,
+            in_flight_timeout_in_secs: 60,
+        }
+    }
+}
+
+impl Default for super::settings::PaymentArchivalSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            archive_after_days: 365,
+            batch_size: 10_000,
+            max_batches_per_run: 100,
+        }
+    }
+}
+
+impl Default for super::settings::SettlementReconSettings {
+    fn default() -> Self {
+        Self {
+            max_report_rows: 100_000,
+            connectors: HashMap::new(),
+        }
+    }
+}
+
+impl Default for super::settings::SettlementReportFormat {
+    fn default() -> Self {
+        Self {
+            connector_transaction_id_column: "connector_transaction_id".to_string(),
+            transaction_type_column: "transaction_type".to_string(),
+            amount_column: "amount".to_string(),
+            currency_column: "currency".to_string(),
+            fee_column: Some("fee".to_string()),
+            settled_at_column: Some("settled_at".to_string()),
+            payment_types: HashSet::from(["payment".to_string()]),
+            refund_types: HashSet::from(["refund".to_string()]),
+            amount_unit: super::settings::SettlementAmountUnit::Minor,
+            report_url: None,
+        }
+    }
+}
+
+impl Default for super::settings::RoutingAmountNormalizationSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            reference_currency: common_enums::Currency::USD,
+        }
+    }
+}
+
+impl Default for super::settings::IncomingWebhookSourceVerificationSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            trusted_proxy_count: 1,
+            published_ip_ranges_refresh_interval_secs: 6 * 60 * 60,
+            connectors: HashMap::new(),
+        }
+    }
+}
+
+impl Default for super::settings::BinLookupSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            provider_url: String::new(),
+            api_key: String::new().into(),
+            refresh_after_days: 30,
+        }
+    }
+}
+
+impl Default for super::settings::IdempotencySettings {
+    fn default() -> Self {
+        Self {
+            ttl_secs: 86400,
+            in_progress_ttl_secs: 900,
+        }
+    }
+}
+
+impl Default for super::settings::DisputeEvidenceSettings {
+    fn default() -> Self {
+        Self {
+            // 10 Megabytes (MB)
+            max_file_size: 10_000_000,
+            allowed_file_types: HashSet::from_iter(
+                ["image/jpeg", "image/png", "application/pdf"].map(String::from),
+            ),
+        }
+    }
+}
+
 impl Default for super::settings::EphemeralConfig {
     fn default() -> Self {
         Self { validity: 1 }
@@ -147,6 +316,18 @@ impl Default for super::settings::ApiKeys {
             #[cfg(feature = "email")]
             expiry_reminder_days: vec![7, 3, 1],
 
+            // Specifies the number of seconds for which a rotated API key remains valid, unless
+            // specified in the rotation request
+            rotation_grace_period_in_secs: 24 * 60 * 60,
+
+            // Specifies the number of trusted proxies appending to the `X-Forwarded-For` header, used
+            // to determine the IP address of the client when enforcing IP allowlists
+            trusted_proxy_count: 1,
+
+            // Specifies the number of seconds by which the timestamp of a signed request may differ
+            // from the time it is received at
+            signed_request_tolerance_in_secs: 5 * 60,
+
             // Hex-encoded key used for calculating checksum for partial auth
             #[cfg(feature = "partial-auth")]
             checksum_auth_key: String::new().into(),
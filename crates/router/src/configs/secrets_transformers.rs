@@ -114,6 +114,10 @@ impl SecretsHandler for settings::ApiKeys {
         #[cfg(feature = "email")]
         let expiry_reminder_days = api_keys.expiry_reminder_days.clone();
 
+        let rotation_grace_period_in_secs = api_keys.rotation_grace_period_in_secs;
+        let trusted_proxy_count = api_keys.trusted_proxy_count;
+        let signed_request_tolerance_in_secs = api_keys.signed_request_tolerance_in_secs;
+
         #[cfg(feature = "partial-auth")]
         let enable_partial_auth = api_keys.enable_partial_auth;
 
@@ -136,6 +140,9 @@ impl SecretsHandler for settings::ApiKeys {
             hash_key,
             #[cfg(feature = "email")]
             expiry_reminder_days,
+            rotation_grace_period_in_secs,
+            trusted_proxy_count,
+            signed_request_tolerance_in_secs,
 
             #[cfg(feature = "partial-auth")]
             checksum_auth_key,
@@ -234,7 +241,7 @@ impl SecretsHandler for settings::KeyManagerConfig {
         let keyconfig = value.get_inner();
 
         #[cfg(feature = "keymanager_mtls")]
-        let ca = if keyconfig.enabled {
+        let ca = if keyconfig.is_key_manager_service_enabled() {
             _secret_management_client
                 .get_secret(keyconfig.ca.clone())
                 .await?
@@ -243,7 +250,7 @@ impl SecretsHandler for settings::KeyManagerConfig {
         };
 
         #[cfg(feature = "keymanager_mtls")]
-        let cert = if keyconfig.enabled {
+        let cert = if keyconfig.is_key_manager_service_enabled() {
             _secret_management_client
                 .get_secret(keyconfig.cert.clone())
                 .await?
@@ -493,6 +500,8 @@ pub(crate) async fn fetch_raw_secrets(
         key_manager,
         #[cfg(feature = "olap")]
         replica_database,
+        #[cfg(feature = "olap")]
+        read_replica: conf.read_replica,
         secrets,
         fallback_merchant_ids_api_key_auth: conf.fallback_merchant_ids_api_key_auth,
         locker: conf.locker,
@@ -560,6 +569,7 @@ pub(crate) async fn fetch_raw_secrets(
         locker_based_open_banking_connectors: conf.locker_based_open_banking_connectors,
         grpc_client: conf.grpc_client,
         crm: conf.crm,
+        geo_ip: conf.geo_ip,
         #[cfg(feature = "v2")]
         cell_information: conf.cell_information,
         network_tokenization_supported_card_networks: conf
@@ -571,11 +581,19 @@ pub(crate) async fn fetch_raw_secrets(
         l2_l3_data_config: conf.l2_l3_data_config,
         authentication_providers: conf.authentication_providers,
         open_router: conf.open_router,
+        connector_health: conf.connector_health,
+        routing_amount_normalization: conf.routing_amount_normalization,
+        bin_lookup: conf.bin_lookup,
         #[cfg(feature = "v2")]
         revenue_recovery: conf.revenue_recovery,
         debit_routing_config: conf.debit_routing_config,
         clone_connector_allowlist: conf.clone_connector_allowlist,
         merchant_id_auth: conf.merchant_id_auth,
+        rate_limit: conf.rate_limit,
+        idempotency: conf.idempotency,
+        dispute_evidence: conf.dispute_evidence,
+        drain: conf.drain,
+        payment_archival: conf.payment_archival,
         infra_values: conf.infra_values,
         enhancement: conf.enhancement,
         proxy_status_mapping: conf.proxy_status_mapping,
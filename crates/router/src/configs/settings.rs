@@ -1,4 +1,5 @@
 use std::{
+    borrow::Cow,
     collections::{HashMap, HashSet},
     path::PathBuf,
     sync::Arc,
@@ -15,6 +16,7 @@ use external_services::email::EmailSettings;
 use external_services::{
     crm::CrmManagerConfig,
     file_storage::FileStorageConfig,
+    geo_ip::GeoIpConfig,
     grpc_client::GrpcClientSettings,
     managers::{
         encryption_management::EncryptionManagementConfig,
@@ -75,6 +77,8 @@ pub struct Settings<S: SecretState> {
     pub master_database: SecretStateContainer<Database, S>,
     #[cfg(feature = "olap")]
     pub replica_database: SecretStateContainer<Database, S>,
+    #[cfg(feature = "olap")]
+    pub read_replica: ReadReplicaSettings,
     pub redis: RedisSettings,
     pub log: Log,
     pub secrets: SecretStateContainer<Secrets, S>,
@@ -104,6 +108,7 @@ pub struct Settings<S: SecretState> {
     pub email: EmailSettings,
     pub user: UserSettings,
     pub crm: CrmManagerConfig,
+    pub geo_ip: GeoIpConfig,
     pub cors: CorsSettings,
     pub mandates: Mandates,
     pub zero_mandates: ZeroMandates,
@@ -159,10 +164,20 @@ pub struct Settings<S: SecretState> {
     pub platform: Platform,
     pub authentication_providers: AuthenticationProviders,
     pub open_router: OpenRouter,
+    pub connector_health: ConnectorHealthSettings,
+    pub routing_amount_normalization: RoutingAmountNormalizationSettings,
+    pub bin_lookup: BinLookupSettings,
     #[cfg(feature = "v2")]
     pub revenue_recovery: revenue_recovery::RevenueRecoverySettings,
     pub clone_connector_allowlist: Option<CloneConnectorAllowlistConfig>,
     pub merchant_id_auth: MerchantIdAuthSettings,
+    pub rate_limit: RateLimitSettings,
+    pub connector_rate_limit: ConnectorRateLimitSettings,
+    pub settlement_recon: SettlementReconSettings,
+    pub idempotency: IdempotencySettings,
+    pub dispute_evidence: DisputeEvidenceSettings,
+    pub drain: DrainSettings,
+    pub payment_archival: PaymentArchivalSettings,
     #[serde(default)]
     pub infra_values: Option<HashMap<String, String>>,
     #[serde(default)]
@@ -187,6 +202,198 @@ pub struct OpenRouter {
     pub url: String,
 }
 
+/// Error-rate circuit breaker applied to every merchant connector account during routing
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorHealthSettings {
+    /// Whether connectors with an open breaker are skipped during routing
+    pub enabled: bool,
+    /// Failure rate (in percent) of a connector within a window at which its breaker opens
+    pub failure_rate_threshold: u8,
+    /// Minimum number of requests within a window before the breaker of a connector can open
+    pub minimum_request_count: u64,
+    /// Duration (in seconds) of the window over which requests are counted
+    pub window_secs: i64,
+    /// Duration (in seconds) for which a connector is skipped once its breaker opens
+    pub open_duration_secs: i64,
+    /// Duration (in seconds) after which another trial request is let through to a connector whose
+    /// breaker is half open, when the outcome of the previous one was not recorded
+    pub half_open_trial_timeout_secs: i64,
+}
+
+/// Conversion of the payment amount to a reference currency, for routing rules matching on the
+/// `normalized_amount` key
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RoutingAmountNormalizationSettings {
+    /// Whether the payment amount is converted to the reference currency during routing
+    pub enabled: bool,
+    /// The currency in which `normalized_amount` routing rules are expressed
+    pub reference_currency: enums::Currency,
+}
+
+/// External provider consulted for card BIN metadata missing from, or stale in, the local card
+/// info dataset
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct BinLookupSettings {
+    /// Whether the external provider is consulted
+    pub enabled: bool,
+    /// Base URL of the provider, the BIN is appended to it as the last path segment
+    pub provider_url: String,
+    /// API key sent to the provider in the `x-api-key` header
+    pub api_key: Secret<String>,
+    /// Age (in days) after which a locally stored BIN record is refreshed from the provider
+    pub refresh_after_days: i64,
+}
+
+/// Replay of the responses of requests made with an `Idempotency-Key` header
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct IdempotencySettings {
+    /// Duration (in seconds) for which the response of a request is replayed for its key
+    pub ttl_secs: i64,
+    /// Duration (in seconds) for which a key is claimed by a request that never completes, which
+    /// must be well above the time taken to process a request
+    pub in_progress_ttl_secs: i64,
+}
+
+/// Limits applied to the evidence files uploaded for disputes, in addition to the ones of the
+/// connector of the dispute
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct DisputeEvidenceSettings {
+    /// Maximum size (in bytes) of an evidence file
+    pub max_file_size: i32,
+    /// MIME types of the evidence files which are accepted
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub allowed_file_types: HashSet<String>,
+}
+
+/// Token bucket rate limits applied to the requests authenticated for a merchant
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RateLimitSettings {
+    /// Whether requests exceeding the rate limits are rejected
+    pub enabled: bool,
+    /// Limit applied to the requests made with an API key, per API key
+    pub api_key: RateLimitBucket,
+    /// Limit applied to all the requests of a merchant
+    pub merchant: RateLimitBucket,
+    /// Limits applied to the requests of a merchant to a group of routes, keyed by the name of
+    /// the group, such as `payments` or `refunds`
+    pub route_groups: HashMap<String, RateLimitBucket>,
+}
+
+/// Outbound rate limits and concurrency caps applied to the requests sent to connectors, which
+/// are configured per merchant connector account
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorRateLimitSettings {
+    /// Whether the limits configured for merchant connector accounts are enforced
+    pub enabled: bool,
+    /// Maximum time for which a request waits for the limits to allow it, after which it fails
+    /// without being sent to the connector
+    pub max_queue_wait_in_ms: u64,
+    /// Interval at which a waiting request checks the limits again
+    pub queue_poll_interval_in_ms: u64,
+    /// Time after which a concurrency slot which was not released, such as by an instance which
+    /// crashed, is freed
+    pub concurrency_slot_ttl_in_secs: u32,
+}
+
+/// Drain mode of an instance, in which it stops accepting new payments and shuts down once the
+/// flows in progress are complete
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct DrainSettings {
+    /// Value of the `Retry-After` header of the payment creation requests rejected while draining
+    pub retry_after_in_secs: u64,
+    /// Time for which the instance keeps serving once draining, while its readiness check fails,
+    /// so that the load balancers stop sending requests to it
+    pub grace_period_in_secs: u64,
+    /// Maximum time for which the instance waits for the flows in progress to complete, after
+    /// which it shuts down regardless
+    pub in_flight_timeout_in_secs: u64,
+}
+
+/// Archival of the payment intents, attempts and refunds to their cold storage tables, which keeps
+/// the hot tables small
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct PaymentArchivalSettings {
+    /// Whether the scheduler runs the archival job
+    pub enabled: bool,
+    /// Age after which the rows are archived. Archived payments can no longer be retrieved or
+    /// refunded, hence this must be longer than the refund window of the connectors.
+    pub archive_after_days: u32,
+    /// Number of rows moved from a table to its archive table in a single transaction
+    pub batch_size: u32,
+    /// Maximum number of batches archived from a table in a single run of the archival job
+    pub max_batches_per_run: u32,
+}
+
+/// Formats of the connector settlement reports which are ingested for reconciliation
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct SettlementReconSettings {
+    /// Maximum number of rows which are read from a single settlement report
+    pub max_report_rows: usize,
+    /// Report formats keyed by connector name. Reports of the other connectors are expected in
+    /// the default format.
+    pub connectors: HashMap<String, SettlementReportFormat>,
+}
+
+impl SettlementReconSettings {
+    pub fn get_report_format(&self, connector: &str) -> Cow<'_, SettlementReportFormat> {
+        self.connectors
+            .get(connector)
+            .map(Cow::Borrowed)
+            .unwrap_or_default()
+    }
+}
+
+/// Columns of a settlement report, and the values identifying the transactions which are
+/// reconciled. Rows of other transaction types, such as fees and chargebacks, are skipped.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct SettlementReportFormat {
+    pub connector_transaction_id_column: String,
+    pub transaction_type_column: String,
+    pub amount_column: String,
+    pub currency_column: String,
+    pub fee_column: Option<String>,
+    pub settled_at_column: Option<String>,
+    /// Values of the transaction type column of settled payments
+    pub payment_types: HashSet<String>,
+    /// Values of the transaction type column of settled refunds
+    pub refund_types: HashSet<String>,
+    pub amount_unit: SettlementAmountUnit,
+    /// HTTPS URL from which the report of a day is downloaded, with a `{report_date}` placeholder
+    /// for the date. The request is authenticated with the headers the connector uses to
+    /// authenticate its payment requests, built from the merchant connector account. Reports
+    /// delivered over SFTP are not pulled, they have to be uploaded.
+    pub report_url: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SettlementAmountUnit {
+    /// Amounts are in the major unit of the currency, such as `10.50`
+    Major,
+    /// Amounts are in the minor unit of the currency, such as `1050`
+    #[default]
+    Minor,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+pub struct RateLimitBucket {
+    /// Maximum number of requests which can be made in a burst
+    pub capacity: u32,
+    /// Number of requests replenished every second
+    pub refill_per_sec: u32,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 #[serde(default)]
 pub struct CloneConnectorAllowlistConfig {
@@ -414,10 +621,81 @@ pub struct KvConfig {
 pub struct KeyManagerConfig {
     pub enabled: bool,
     pub url: String,
+    pub backend: KeyManagerBackendConfig,
+    pub retry: common_utils::types::keymanager::KeyManagerRetryConfig,
+    pub circuit_breaker: common_utils::types::keymanager::KeyManagerCircuitBreakerConfig,
+    pub data_key_cache: DataKeyCacheConfig,
     #[cfg(feature = "keymanager_mtls")]
     pub cert: Secret<String>,
     #[cfg(feature = "keymanager_mtls")]
     pub ca: Secret<String>,
+    #[cfg(feature = "keymanager_mtls")]
+    pub certificate_reload: KeyManagerCertificateReloadConfig,
+}
+
+impl KeyManagerConfig {
+    /// Whether the requests are sent to the key manager service configured at `url`
+    pub fn is_key_manager_service_enabled(&self) -> bool {
+        self.enabled && matches!(self.backend, KeyManagerBackendConfig::KeyManagerService)
+    }
+}
+
+/// Files from which the mTLS certificate and CA of the key manager are read, in place of `cert`
+/// and `ca`, and reloaded on change so that they can be rotated without a restart
+#[cfg(feature = "keymanager_mtls")]
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct KeyManagerCertificateReloadConfig {
+    /// File holding the PEM encoded client certificate along with its private key
+    pub cert_path: Option<PathBuf>,
+    /// File holding the PEM encoded CA certificate
+    pub ca_path: Option<PathBuf>,
+    /// Interval in seconds at which the files are checked for changes
+    pub interval_in_secs: u64,
+}
+
+#[cfg(feature = "keymanager_mtls")]
+impl Default for KeyManagerCertificateReloadConfig {
+    fn default() -> Self {
+        Self {
+            cert_path: None,
+            ca_path: None,
+            interval_in_secs: 60,
+        }
+    }
+}
+
+/// In-memory cache of the merchant data keys decrypted by the key manager
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[serde(default)]
+pub struct DataKeyCacheConfig {
+    pub enabled: bool,
+    /// Time in seconds for which a decrypted data key is reused
+    pub ttl_in_secs: u64,
+    /// Maximum number of data keys held, the least recently used ones being evicted first
+    pub max_entries: u64,
+}
+
+impl Default for DataKeyCacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            ttl_in_secs: 300,
+            max_entries: 10_000,
+        }
+    }
+}
+
+/// Backend serving the key manager requests
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(tag = "backend")]
+#[serde(rename_all = "snake_case")]
+pub enum KeyManagerBackendConfig {
+    /// The key manager service configured at `key_manager.url`
+    #[default]
+    KeyManagerService,
+    /// Envelope encryption in the application, with the data keys wrapped by the KMS
+    Kms { kms: EncryptionManagementConfig },
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -483,7 +761,7 @@ impl Default for PaymentLink {
     }
 }
 
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct ForexApi {
     pub api_key: Secret<String>,
@@ -491,6 +769,37 @@ pub struct ForexApi {
     pub data_expiration_delay_in_seconds: u32,
     pub redis_lock_timeout_in_seconds: u32,
     pub redis_ttl_in_seconds: u32,
+    /// Providers from which the rates are fetched, in the order of preference. A provider is
+    /// used only when the rates could not be fetched from the ones before it.
+    pub providers: Vec<ForexProvider>,
+    /// Rates served by the `fixed` provider
+    pub fixed_rates: Option<DefaultExchangeRates>,
+    /// Duration for which the rate of a quote is guaranteed
+    pub quote_validity_in_seconds: u32,
+}
+
+impl ForexApi {
+    /// Indicates if the rates can be fetched from the provider with the current config
+    pub fn is_provider_configured(&self, provider: &ForexProvider) -> bool {
+        use masking::PeekInterface;
+
+        match provider {
+            ForexProvider::OpenExchangeRates => !self.api_key.peek().is_empty(),
+            ForexProvider::CurrencyLayer => !self.fallback_api_key.peek().is_empty(),
+            ForexProvider::Fixed => self.fixed_rates.is_some(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ForexProvider {
+    /// Open Exchange Rates, authenticated with `api_key`
+    OpenExchangeRates,
+    /// Currencylayer, authenticated with `fallback_api_key`
+    CurrencyLayer,
+    /// Rates configured in `fixed_rates`, which are meant for testing environments
+    Fixed,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -735,6 +1044,10 @@ pub struct UserSettings {
     pub totp_issuer_name: String,
     pub base_url: String,
     pub force_two_factor_auth: bool,
+    /// Roles granting any of these permission groups require their users to set up two factor
+    /// authentication
+    #[serde(default)]
+    pub two_factor_auth_required_groups: HashSet<common_enums::PermissionGroup>,
     pub force_cookies: bool,
 }
 
@@ -826,6 +1139,29 @@ impl From<Database> for storage_impl::config::Database {
     }
 }
 
+/// Routing of the read-only queries to the read replicas of the database. The replicas whose
+/// replication lag exceeds `max_replication_lag_in_ms` are not queried until they catch up, and
+/// the queries are served by the master database if all the replicas are stale.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ReadReplicaSettings {
+    /// Hosts of the replicas in addition to the one in `replica_database`, which are connected to
+    /// with the configuration of `replica_database`
+    pub additional_hosts: Vec<String>,
+    pub max_replication_lag_in_ms: u64,
+    pub lag_check_interval_in_secs: u64,
+}
+
+impl From<ReadReplicaSettings> for storage_impl::config::ReadReplicaConfig {
+    fn from(val: ReadReplicaSettings) -> Self {
+        Self {
+            additional_hosts: val.additional_hosts,
+            max_replication_lag: std::time::Duration::from_millis(val.max_replication_lag_in_ms),
+            lag_check_interval: std::time::Duration::from_secs(val.lag_check_interval_in_secs),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct SupportedConnectors {
@@ -861,6 +1197,35 @@ pub struct WebhooksSettings {
     pub outgoing_enabled: bool,
     pub ignore_error: WebhookIgnoreErrorSettings,
     pub redis_lock_expiry_seconds: u32,
+    pub incoming_source_verification: IncomingWebhookSourceVerificationSettings,
+}
+
+/// Verification of the source of incoming connector webhooks, performed before the webhooks are
+/// processed
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct IncomingWebhookSourceVerificationSettings {
+    /// Whether the source of incoming connector webhooks is verified
+    pub enabled: bool,
+    /// Number of trusted proxies in front of the application appending to `X-Forwarded-For`
+    pub trusted_proxy_count: usize,
+    /// Interval (in seconds) after which the IP ranges published by a connector are fetched again
+    pub published_ip_ranges_refresh_interval_secs: i64,
+    /// Source verification configuration of each connector, keyed by connector name
+    pub connectors: HashMap<String, ConnectorWebhookSourceSettings>,
+}
+
+/// Source verification configuration of the incoming webhooks of a connector
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ConnectorWebhookSourceSettings {
+    /// IP address ranges (in CIDR notation) from which the connector sends webhooks
+    pub allowed_ip_ranges: Vec<String>,
+    /// URL at which the connector publishes the IP address ranges from which it sends webhooks
+    pub published_ip_ranges_url: Option<String>,
+    /// Whether webhooks failing the signature verification of the connector are rejected, rather
+    /// than processed as unverified webhooks
+    pub require_signature_verification: bool,
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -881,6 +1246,18 @@ pub struct ApiKeys {
     #[cfg(feature = "email")]
     pub expiry_reminder_days: Vec<u8>,
 
+    // Specifies the number of seconds for which a rotated API key remains valid, unless
+    // specified in the rotation request
+    pub rotation_grace_period_in_secs: u32,
+
+    // Specifies the number of trusted proxies appending to the `X-Forwarded-For` header, used to
+    // determine the IP address of the client when enforcing IP allowlists
+    pub trusted_proxy_count: usize,
+
+    // Specifies the number of seconds by which the timestamp of a signed request may differ from
+    // the time it is received at
+    pub signed_request_tolerance_in_secs: u32,
+
     #[cfg(feature = "partial-auth")]
     pub checksum_auth_context: Secret<String>,
 
@@ -998,8 +1375,10 @@ impl Settings<SecuredSecret> {
                     .list_separator(",")
                     .with_list_parse_key("log.telemetry.route_to_trace")
                     .with_list_parse_key("redis.cluster_urls")
+                    .with_list_parse_key("redis.sentinel_urls")
                     .with_list_parse_key("events.kafka.brokers")
                     .with_list_parse_key("connectors.supported.wallets")
+                    .with_list_parse_key("connectors.genericrest.allowed_hosts")
                     .with_list_parse_key("connector_request_reference_id_config.merchant_ids_send_payment_id_as_connector_request_id"),
 
             )
@@ -1021,6 +1400,8 @@ impl Settings<SecuredSecret> {
         self.master_database.get_inner().validate()?;
         #[cfg(feature = "olap")]
         self.replica_database.get_inner().validate()?;
+        #[cfg(feature = "olap")]
+        self.read_replica.validate()?;
 
         // The logger may not yet be initialized when validating the application configuration
         #[allow(clippy::print_stderr)]
@@ -1068,6 +1449,10 @@ impl Settings<SecuredSecret> {
             .validate()
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
 
+        self.geo_ip
+            .validate()
+            .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
+
         self.lock_settings.validate()?;
         self.events.validate()?;
 
@@ -1115,6 +1500,19 @@ impl Settings<SecuredSecret> {
         self.platform.validate()?;
 
         self.open_router.validate()?;
+        self.connector_health.validate()?;
+        self.webhooks.incoming_source_verification.validate()?;
+        self.rate_limit.validate()?;
+        self.connector_rate_limit.validate()?;
+        self.settlement_recon.validate()?;
+        self.forex_api.get_inner().validate()?;
+        self.bin_lookup.validate()?;
+        self.idempotency.validate()?;
+        #[cfg(feature = "payouts")]
+        self.payouts.validate()?;
+        self.dispute_evidence.validate()?;
+        self.drain.validate()?;
+        self.payment_archival.validate()?;
 
         // Validate gRPC client settings
         #[cfg(feature = "revenue_recovery")]
@@ -1147,6 +1545,10 @@ pub struct Payouts {
     pub payout_eligibility: bool,
     #[serde(default)]
     pub required_fields: PayoutRequiredFields,
+    /// Hours of the day (in UTC) at which batches of payouts are executed, for the batches which
+    /// are created without a scheduled time
+    #[serde(default, deserialize_with = "deserialize_hashset")]
+    pub batch_cutoff_hours: HashSet<u8>,
 }
 
 #[derive(Debug, Clone, Default)]
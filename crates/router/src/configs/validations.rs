@@ -2,6 +2,8 @@ use common_utils::ext_traits::ConfigExt;
 use masking::PeekInterface;
 use storage_impl::errors::ApplicationError;
 
+use crate::services::authentication::ip_allowlist;
+
 impl super::settings::Secrets {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -95,6 +97,29 @@ impl super::settings::Database {
     }
 }
 
+impl super::settings::ReadReplicaSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.lag_check_interval_in_secs == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "read replica lag check interval must be greater than zero".into(),
+            ))
+        })?;
+
+        when(
+            self.additional_hosts
+                .iter()
+                .any(|host| host.is_default_or_empty()),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "read replica hosts must not be empty".into(),
+                ))
+            },
+        )
+    }
+}
+
 impl super::settings::SupportedConnectors {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         common_utils::fp_utils::when(self.wallets.is_empty(), || {
@@ -149,6 +174,18 @@ impl super::settings::ApiKeys {
             ))
         })?;
 
+        when(self.trusted_proxy_count == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "API key trusted proxy count must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.signed_request_tolerance_in_secs == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Signed request tolerance must be greater than 0".into(),
+            ))
+        })?;
+
         Ok(())
     }
 }
@@ -193,6 +230,48 @@ impl super::settings::WebhooksSettings {
     }
 }
 
+impl super::settings::IncomingWebhookSourceVerificationSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.published_ip_ranges_refresh_interval_secs <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "published IP ranges refresh interval must be greater than 0".into(),
+            ))
+        })?;
+
+        self.connectors
+            .iter()
+            .try_for_each(|(connector_name, connector_settings)| {
+                when(
+                    connector_settings
+                        .allowed_ip_ranges
+                        .iter()
+                        .any(|ip_range| ip_allowlist::parse_ip_range(ip_range).is_none()),
+                    || {
+                        Err(ApplicationError::InvalidConfigurationValueError(format!(
+                            "allowed IP ranges of connector {connector_name} contain an invalid \
+                             IP address range"
+                        )))
+                    },
+                )?;
+
+                when(
+                    connector_settings
+                        .published_ip_ranges_url
+                        .as_ref()
+                        .is_some_and(|url| url.is_default_or_empty()),
+                    || {
+                        Err(ApplicationError::InvalidConfigurationValueError(format!(
+                            "published IP ranges URL of connector {connector_name} must not be \
+                             empty"
+                        )))
+                    },
+                )
+            })
+    }
+}
+
 impl super::settings::GenericLinkEnvConfig {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -296,10 +375,14 @@ impl super::settings::GooglePayDecryptConfig {
 impl super::settings::KeyManagerConfig {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
+        use external_services::managers::encryption_management::EncryptionManagementConfig;
 
         #[cfg(feature = "keymanager_mtls")]
         when(
-            self.enabled && (self.ca.is_default_or_empty() || self.cert.is_default_or_empty()),
+            self.is_key_manager_service_enabled()
+                && ((self.ca.is_default_or_empty() && self.certificate_reload.ca_path.is_none())
+                    || (self.cert.is_default_or_empty()
+                        && self.certificate_reload.cert_path.is_none())),
             || {
                 Err(ApplicationError::InvalidConfigurationValueError(
                     "Invalid CA or Certificate for Keymanager.".into(),
@@ -307,11 +390,71 @@ impl super::settings::KeyManagerConfig {
             },
         )?;
 
-        when(self.enabled && self.url.is_default_or_empty(), || {
+        #[cfg(feature = "keymanager_mtls")]
+        when(self.certificate_reload.interval_in_secs == 0, || {
             Err(ApplicationError::InvalidConfigurationValueError(
-                "Invalid URL for Keymanager".into(),
+                "key_manager.certificate_reload.interval_in_secs must be greater than zero".into(),
             ))
-        })
+        })?;
+
+        when(
+            self.is_key_manager_service_enabled() && self.url.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "Invalid URL for Keymanager".into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.data_key_cache.enabled
+                && (self.data_key_cache.ttl_in_secs == 0 || self.data_key_cache.max_entries == 0),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "key_manager.data_key_cache.ttl_in_secs and max_entries must be greater than zero"
+                        .into(),
+                ))
+            },
+        )?;
+
+        if let super::settings::KeyManagerBackendConfig::Kms { kms } = &self.backend {
+            when(!self.enabled, || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "key_manager.enabled must be set to use the KMS backend".into(),
+                ))
+            })?;
+
+            when(
+                matches!(kms, EncryptionManagementConfig::NoEncryption),
+                || {
+                    Err(ApplicationError::InvalidConfigurationValueError(
+                        "key_manager.backend.kms must be configured with a KMS".into(),
+                    ))
+                },
+            )?;
+
+            kms.validate()
+                .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.into()))?;
+        }
+
+        when(
+            self.retry.base_delay_in_ms > self.retry.max_delay_in_ms,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "key_manager.retry.base_delay_in_ms must not exceed max_delay_in_ms".into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.circuit_breaker.enabled && self.circuit_breaker.failure_threshold == 0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "key_manager.circuit_breaker.failure_threshold must be greater than zero"
+                        .into(),
+                ))
+            },
+        )
     }
 }
 
@@ -344,6 +487,254 @@ impl super::settings::OpenRouter {
     }
 }
 
+impl super::settings::ConnectorHealthSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(!(1..=100).contains(&self.failure_rate_threshold), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector health failure rate threshold must be between 1 and 100".into(),
+            ))
+        })?;
+
+        when(self.minimum_request_count == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector health minimum request count must be greater than 0".into(),
+            ))
+        })?;
+
+        when(
+            self.window_secs <= 0
+                || self.open_duration_secs <= 0
+                || self.half_open_trial_timeout_secs <= 0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "connector health window, open duration and trial timeout must be greater than 0"
+                        .into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::BinLookupSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.enabled && self.provider_url.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "BIN lookup provider URL must not be empty when it is enabled".into(),
+                ))
+            },
+        )?;
+
+        when(self.refresh_after_days <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "BIN lookup refresh interval must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::IdempotencySettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.ttl_secs <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "idempotency TTL must be greater than 0".into(),
+            ))
+        })?;
+
+        common_utils::fp_utils::when(self.in_progress_ttl_secs <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "idempotency in progress TTL must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+#[cfg(feature = "payouts")]
+impl super::settings::Payouts {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(
+            self.batch_cutoff_hours.iter().any(|hour| *hour >= 24),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "payout batch cutoff hours must be between 0 and 23".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::DisputeEvidenceSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.max_file_size <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "dispute evidence max file size must be greater than 0".into(),
+            ))
+        })?;
+
+        common_utils::fp_utils::when(self.allowed_file_types.is_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "dispute evidence allowed file types must not be empty".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::RateLimitSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        [("api_key", &self.api_key), ("merchant", &self.merchant)]
+            .into_iter()
+            .chain(
+                self.route_groups
+                    .iter()
+                    .map(|(route_group, bucket)| (route_group.as_str(), bucket)),
+            )
+            .try_for_each(|(name, bucket)| {
+                when(bucket.capacity == 0 || bucket.refill_per_sec == 0, || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "rate limit capacity and refill rate of `{name}` must be greater than 0"
+                    )))
+                })
+            })
+    }
+}
+
+impl super::settings::ConnectorRateLimitSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.queue_poll_interval_in_ms == 0 || self.concurrency_slot_ttl_in_secs == 0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "connector rate limit queue poll interval and concurrency slot TTL must be \
+                     greater than 0"
+                        .into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::DrainSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.retry_after_in_secs == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "drain retry after must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::PaymentArchivalSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.archive_after_days == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "payment archival archive after days must be greater than 0".into(),
+            ))
+        })?;
+
+        when(
+            self.batch_size == 0 || i32::try_from(self.batch_size).is_err(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "payment archival batch size must be a positive 32-bit integer".into(),
+                ))
+            },
+        )?;
+
+        when(self.max_batches_per_run == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "payment archival max batches per run must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::SettlementReconSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.max_report_rows == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "settlement recon max report rows must be greater than 0".into(),
+            ))
+        })?;
+
+        self.connectors.iter().try_for_each(|(connector, format)| {
+            when(
+                format.payment_types.is_empty() && format.refund_types.is_empty(),
+                || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "settlement report format of {connector} must have at least one \
+                             payment or refund type"
+                    )))
+                },
+            )?;
+            when(
+                !format.payment_types.is_disjoint(&format.refund_types),
+                || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "settlement report format of {connector} must not have a transaction \
+                             type which is both a payment and a refund type"
+                    )))
+                },
+            )?;
+            // Reports delivered over SFTP are not pulled, they have to be uploaded instead
+            when(
+                format
+                    .report_url
+                    .as_ref()
+                    .is_some_and(|report_url| !report_url.starts_with("https://")),
+                || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "settlement report url of {connector} must be an HTTPS url"
+                    )))
+                },
+            )
+        })
+    }
+}
+
+impl super::settings::ForexApi {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.providers.is_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "forex providers must not be empty".into(),
+            ))
+        })?;
+
+        when(
+            self.providers
+                .contains(&super::settings::ForexProvider::Fixed)
+                && self.fixed_rates.is_none(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "forex fixed rates must be set if the fixed provider is used".into(),
+                ))
+            },
+        )?;
+
+        when(self.quote_validity_in_seconds == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "forex quote validity must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
 impl super::settings::ChatSettings {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
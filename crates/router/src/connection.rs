@@ -1,6 +1,5 @@
 use bb8::PooledConnection;
 use diesel::PgConnection;
-use error_stack::ResultExt;
 use storage_impl::errors as storage_errors;
 
 use crate::errors;
@@ -43,9 +42,19 @@ pub async fn pg_connection_read<T: storage_impl::DatabaseStore>(
     ))]
     let pool = store.get_master_pool();
 
-    pool.get()
-        .await
-        .change_context(storage_errors::StorageError::DatabaseConnectionError)
+    pool.get_connection().await
+}
+
+/// Returns a connection to a read replica, for the read-only queries which can be served with
+/// slightly stale data, such as the list queries of the dashboard. The connection is to the master
+/// database if there are no replicas, or if all the replicas are stale.
+pub async fn pg_connection_read_replica<T: storage_impl::DatabaseStore>(
+    store: &T,
+) -> errors::CustomResult<
+    PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>>,
+    storage_errors::StorageError,
+> {
+    store.get_replica_pool().get_connection().await
 }
 
 pub async fn pg_accounts_connection_read<T: storage_impl::DatabaseStore>(
@@ -69,9 +78,7 @@ pub async fn pg_accounts_connection_read<T: storage_impl::DatabaseStore>(
     ))]
     let pool = store.get_accounts_master_pool();
 
-    pool.get()
-        .await
-        .change_context(storage_errors::StorageError::DatabaseConnectionError)
+    pool.get_connection().await
 }
 
 pub async fn pg_connection_write<T: storage_impl::DatabaseStore>(
@@ -83,9 +90,7 @@ pub async fn pg_connection_write<T: storage_impl::DatabaseStore>(
     // Since all writes should happen to master DB only choose master DB.
     let pool = store.get_master_pool();
 
-    pool.get()
-        .await
-        .change_context(storage_errors::StorageError::DatabaseConnectionError)
+    pool.get_connection().await
 }
 
 pub async fn pg_accounts_connection_write<T: storage_impl::DatabaseStore>(
@@ -97,7 +102,5 @@ pub async fn pg_accounts_connection_write<T: storage_impl::DatabaseStore>(
     // Since all writes should happen to master DB only choose master DB.
     let pool = store.get_accounts_master_pool();
 
-    pool.get()
-        .await
-        .change_context(storage_errors::StorageError::DatabaseConnectionError)
+    pool.get_connection().await
 }
@@ -19,11 +19,12 @@ pub use hyperswitch_connectors::connectors::{
     deutschebank::Deutschebank, digitalvirgo, digitalvirgo::Digitalvirgo, dlocal, dlocal::Dlocal,
     dwolla, dwolla::Dwolla, ebanx, ebanx::Ebanx, elavon, elavon::Elavon, facilitapay,
     facilitapay::Facilitapay, fiserv, fiserv::Fiserv, fiservemea, fiservemea::Fiservemea, fiuu,
-    fiuu::Fiuu, flexiti, flexiti::Flexiti, forte, forte::Forte, getnet, getnet::Getnet, globalpay,
-    globalpay::Globalpay, globepay, globepay::Globepay, gocardless, gocardless::Gocardless,
-    gpayments, gpayments::Gpayments, helcim, helcim::Helcim, hipay, hipay::Hipay,
-    hyperswitch_vault, hyperswitch_vault::HyperswitchVault, hyperwallet, hyperwallet::Hyperwallet,
-    iatapay, iatapay::Iatapay, inespay, inespay::Inespay, itaubank, itaubank::Itaubank, jpmorgan,
+    fiuu::Fiuu, flexiti, flexiti::Flexiti, forte, forte::Forte, genericrest,
+    genericrest::Genericrest, getnet, getnet::Getnet, globalpay, globalpay::Globalpay, globepay,
+    globepay::Globepay, gocardless, gocardless::Gocardless, gpayments, gpayments::Gpayments,
+    helcim, helcim::Helcim, hipay, hipay::Hipay, hyperswitch_vault,
+    hyperswitch_vault::HyperswitchVault, hyperwallet, hyperwallet::Hyperwallet, iatapay,
+    iatapay::Iatapay, inespay, inespay::Inespay, itaubank, itaubank::Itaubank, jpmorgan,
     jpmorgan::Jpmorgan, juspaythreedsserver, juspaythreedsserver::Juspaythreedsserver, katapult,
     katapult::Katapult, klarna, klarna::Klarna, mifinity, mifinity::Mifinity, mollie,
     mollie::Mollie, moneris, moneris::Moneris, mpgs, mpgs::Mpgs, multisafepay,
@@ -2324,7 +2324,8 @@ impl FrmTransactionRouterDataRequest for fraud_check::FrmTransactionRouterData {
             | storage_enums::AttemptStatus::PartialChargedAndChargeable
             | storage_enums::AttemptStatus::Authorized
             | storage_enums::AttemptStatus::Charged
-            | storage_enums::AttemptStatus::PartiallyAuthorized => Some(true),
+            | storage_enums::AttemptStatus::PartiallyAuthorized
+            | storage_enums::AttemptStatus::IncrementalAuthorizationPending => Some(true),
 
             storage_enums::AttemptStatus::Started
             | storage_enums::AttemptStatus::AuthenticationPending
@@ -2373,7 +2374,8 @@ pub fn is_payment_failure(status: enums::AttemptStatus) -> bool {
         | common_enums::AttemptStatus::ConfirmationAwaited
         | common_enums::AttemptStatus::DeviceDataCollectionPending
         | common_enums::AttemptStatus::IntegrityFailure
-        | common_enums::AttemptStatus::PartiallyAuthorized => false,
+        | common_enums::AttemptStatus::PartiallyAuthorized
+        | common_enums::AttemptStatus::IncrementalAuthorizationPending => false,
     }
 }
 
@@ -2384,7 +2386,10 @@ pub fn is_refund_failure(status: enums::RefundStatus) -> bool {
         }
         common_enums::RefundStatus::ManualReview
         | common_enums::RefundStatus::Pending
-        | common_enums::RefundStatus::Success => false,
+        | common_enums::RefundStatus::Success
+        | common_enums::RefundStatus::Scheduled
+        | common_enums::RefundStatus::PendingApproval
+        | common_enums::RefundStatus::Cancelled => false,
     }
 }
 
@@ -4,7 +4,6 @@ pub mod user;
 pub mod user_role;
 use std::collections::HashSet;
 
-use api_models::enums::Country;
 use common_utils::consts;
 pub use hyperswitch_domain_models::consts::{
     CONNECTOR_MANDATE_REQUEST_REFERENCE_ID_LENGTH, ROUTING_ENABLED_PAYMENT_METHODS,
@@ -40,6 +39,9 @@ pub const DEFAULT_SESSION_EXPIRY: i64 = 15 * 60;
 /// The length of a merchant fingerprint secret
 pub const FINGERPRINT_SECRET_LENGTH: usize = 64;
 
+/// Maximum number of entries which can be added to the blocklist in a single request
+pub const BLOCKLIST_BULK_ADD_MAX_ENTRIES: usize = 1000;
+
 pub const DEFAULT_LIST_API_LIMIT: u16 = 10;
 
 // String literals
@@ -51,6 +53,11 @@ pub(crate) const BASE64_ENGINE: base64::engine::GeneralPurpose = consts::BASE64_
 
 pub(crate) const API_KEY_LENGTH: usize = 64;
 
+pub(crate) const SIGNING_SECRET_LENGTH: usize = 64;
+
+/// Two signing keys allow a merchant to rotate its signing secret without downtime
+pub const MAX_SIGNING_KEYS_PER_MERCHANT: usize = 2;
+
 // OID (Object Identifier) for the merchant ID field extension.
 pub(crate) const MERCHANT_ID_FIELD_EXTENSION_ID: &str = "1.2.840.113635.100.6.32";
 
@@ -72,6 +79,10 @@ pub const USER_BLACKLIST_PREFIX: &str = "BU_";
 
 pub const ROLE_BLACKLIST_PREFIX: &str = "BR_";
 
+pub const USER_SESSION_PREFIX: &str = "US_";
+
+pub const USER_SESSIONS_LIST_PREFIX: &str = "USL_";
+
 #[cfg(feature = "email")]
 pub const EMAIL_TOKEN_TIME_IN_SECS: u64 = 60 * 60 * 24; // 1 day
 
@@ -91,6 +102,32 @@ pub const GUEST_USER_CARD_BLOCKING_CACHE_KEY_PREFIX: &str = "GUEST_USER_CARD_BLO
 
 pub const CUSTOMER_ID_BLOCKING_PREFIX: &str = "CUSTOMER_ID_BLOCKING";
 
+pub const RATE_LIMIT_PREFIX: &str = "RL_";
+
+pub const CONNECTOR_RATE_LIMIT_PREFIX: &str = "CONNECTOR_RL_";
+
+pub const CONNECTOR_RATE_LIMITED_ERROR_CODE: &str = "CONNECTOR_RATE_LIMITED";
+
+pub const CONNECTOR_RATE_LIMITED_ERROR_MESSAGE: &str =
+    "Request was not sent to the connector as the outbound rate limit or concurrency cap was exceeded";
+
+pub const FRM_VELOCITY_PREFIX: &str = "FRM_VELOCITY_";
+
+/// Longest sliding window of an FRM velocity rule, 30 days
+pub const FRM_VELOCITY_MAX_WINDOW_IN_SECS: u32 = 30 * 24 * 60 * 60;
+
+pub const FRM_VELOCITY_BLOCKED_ERROR_CODE: &str = "FRM_VELOCITY_BLOCKED";
+
+pub const FRM_VELOCITY_BLOCKED_ERROR_MESSAGE: &str =
+    "This payment was blocked by the velocity rule";
+
+pub const IDEMPOTENCY_KEY_PREFIX: &str = "IDEMPOTENCY_";
+
+/// Maximum length of the `Idempotency-Key` header
+pub const MAX_IDEMPOTENCY_KEY_LENGTH: usize = 255;
+
+pub const SIGNED_REQUEST_NONCE_PREFIX: &str = "SRN_";
+
 #[cfg(feature = "olap")]
 pub const VERIFY_CONNECTOR_ID_PREFIX: &str = "conn_verify";
 #[cfg(feature = "olap")]
@@ -210,6 +247,46 @@ pub const VAULT_GET_FINGERPRINT_FLOW_TYPE: &str = "get_fingerprint_vault";
 /// Max volume split for Dynamic routing
 pub const DYNAMIC_ROUTING_MAX_VOLUME: u8 = 100;
 
+/// Default number of records returned when listing the routing audit log
+pub const ROUTING_AUDIT_LOG_DEFAULT_LIMIT: i64 = 100;
+
+/// Max number of records returned when listing the routing audit log
+pub const ROUTING_AUDIT_LOG_MAX_LIMIT: i64 = 1000;
+
+/// Default number of events returned when listing the admin audit events
+pub const ADMIN_AUDIT_EVENTS_DEFAULT_LIMIT: i64 = 100;
+
+/// Max number of events returned when listing the admin audit events
+pub const ADMIN_AUDIT_EVENTS_MAX_LIMIT: i64 = 1000;
+
+/// Default number of records returned when listing the recon exceptions
+pub const RECON_EXCEPTIONS_DEFAULT_LIMIT: i64 = 100;
+
+/// Max number of records returned when listing the recon exceptions
+pub const RECON_EXCEPTIONS_MAX_LIMIT: i64 = 1000;
+
+/// Number of payment attempts read at once when looking for the attempts missing in a settlement
+/// report
+pub const SETTLEMENT_RECON_ATTEMPTS_BATCH_SIZE: i64 = 1000;
+
+/// Default number of reviews returned when listing the payment review queue
+pub const PAYMENT_REVIEWS_DEFAULT_LIMIT: i64 = 100;
+
+/// Max number of reviews returned when listing the payment review queue
+pub const PAYMENT_REVIEWS_MAX_LIMIT: i64 = 1000;
+
+/// Default number of records returned when listing the health of the saved payment methods
+pub const PAYMENT_METHOD_HEALTH_DEFAULT_LIMIT: i64 = 100;
+
+/// Max number of records returned when listing the health of the saved payment methods
+pub const PAYMENT_METHOD_HEALTH_MAX_LIMIT: i64 = 1000;
+
+/// Cancellation reason sent to the connector for payments declined in a review without a reason
+pub const PAYMENT_REVIEW_DECLINED_CANCELLATION_REASON: &str = "Declined in review by merchant";
+
+/// Max number of routing algorithms of a profile included in a routing config export
+pub const ROUTING_CONFIG_EXPORT_MAX_ALGORITHMS: i64 = 1000;
+
 /// Click To Pay
 pub const CLICK_TO_PAY: &str = "click_to_pay";
 
@@ -281,37 +358,6 @@ pub const IRRELEVANT_CONNECTOR_REQUEST_REFERENCE_ID: &str =
 // Default payment method storing TTL in redis in seconds
 pub const DEFAULT_PAYMENT_METHOD_STORE_TTL: i64 = 86400; // 1 day
 
-// List of countries that are part of the PSD2 region
-pub const PSD2_COUNTRIES: [Country; 27] = [
-    Country::Austria,
-    Country::Belgium,
-    Country::Bulgaria,
-    Country::Croatia,
-    Country::Cyprus,
-    Country::Czechia,
-    Country::Denmark,
-    Country::Estonia,
-    Country::Finland,
-    Country::France,
-    Country::Germany,
-    Country::Greece,
-    Country::Hungary,
-    Country::Ireland,
-    Country::Italy,
-    Country::Latvia,
-    Country::Lithuania,
-    Country::Luxembourg,
-    Country::Malta,
-    Country::Netherlands,
-    Country::Poland,
-    Country::Portugal,
-    Country::Romania,
-    Country::Slovakia,
-    Country::Slovenia,
-    Country::Spain,
-    Country::Sweden,
-];
-
 // Rollout percentage config prefix
 pub const UCS_ROLLOUT_PERCENT_CONFIG_PREFIX: &str = "ucs_rollout_config";
 
@@ -332,3 +378,73 @@ pub const UCS_AUTH_CURRENCY_AUTH_KEY: &str = "currency-auth-key";
 
 /// Form field name for challenge request during creq submission
 pub const CREQ_CHALLENGE_REQUEST_KEY: &str = "creq";
+
+/// Maximum number of payments accepted in a single batch
+pub const MAX_PAYMENTS_BATCH_SIZE: usize = 1000;
+
+/// Number of payments of a batch which are processed concurrently
+pub const PAYMENTS_BATCH_CONCURRENCY: usize = 10;
+
+/// Cancellation reason recorded on payments cancelled for not being confirmed within the profile's intent expiry
+pub const PAYMENT_INTENT_EXPIRED_CANCELLATION_REASON: &str = "payment_intent_expired";
+
+/// Maximum number of payouts accepted in a single batch
+pub const MAX_PAYOUTS_BATCH_SIZE: usize = 1000;
+
+/// Interval between the scheduled purges of the data past the retention policy of a merchant
+pub const DATA_RETENTION_PURGE_INTERVAL_IN_SECONDS: i64 = 86400; // 1 day
+
+/// Maximum number of inactive customers redacted in a single purge
+pub const DATA_RETENTION_PURGE_CUSTOMER_BATCH_SIZE: i64 = 100;
+
+/// Default number of purge runs returned when listing the purge runs of a merchant
+pub const DATA_RETENTION_PURGE_RUNS_DEFAULT_LIMIT: i64 = 20;
+
+/// Maximum number of purge runs returned when listing the purge runs of a merchant
+pub const DATA_RETENTION_PURGE_RUNS_MAX_LIMIT: i64 = 100;
+
+/// Interval between the scheduled archivals of the payments past the archival age
+pub const PAYMENT_ARCHIVAL_INTERVAL_IN_SECONDS: i64 = 86400; // 1 day
+
+/// Default number of report runs returned when listing the report runs of a merchant
+pub const REPORT_RUNS_DEFAULT_LIMIT: i64 = 20;
+
+/// Maximum number of report runs returned when listing the report runs of a merchant
+pub const REPORT_RUNS_MAX_LIMIT: i64 = 100;
+
+/// Maximum number of rows of a report included in the body of the report email, the full report
+/// is downloaded from the report run
+pub const REPORT_EMAIL_MAX_ROWS: usize = 50;
+
+/// Interval after which a keep-alive comment is sent on an event stream with no events
+pub const EVENT_STREAM_KEEP_ALIVE_INTERVAL_IN_SECONDS: u64 = 15;
+
+/// Maximum number of objects whose last status is tracked by an event stream, beyond which the
+/// tracked statuses are reset
+pub const EVENT_STREAM_MAX_TRACKED_OBJECTS: usize = 10_000;
+
+/// Default number of payments returned in a page of a payments search
+pub const PAYMENTS_SEARCH_DEFAULT_LIMIT: u32 = 20;
+
+/// Default number of days of payments the baseline authorization rate is computed over
+pub const AUTH_RATE_ALERT_DEFAULT_BASELINE_WINDOW_IN_DAYS: u16 = 7;
+
+/// Default number of minutes of payments the current authorization rate is computed over
+pub const AUTH_RATE_ALERT_DEFAULT_EVALUATION_WINDOW_IN_MINUTES: u16 = 60;
+
+/// Default minimum number of payment attempts for an authorization rate to be evaluated
+pub const AUTH_RATE_ALERT_DEFAULT_MIN_ATTEMPTS: u32 = 20;
+
+/// Timeout for posting an authorization rate alert to the webhook URL of the merchant
+pub const AUTH_RATE_ALERT_WEBHOOK_TIMEOUT_IN_SECONDS: u64 = 10;
+
+/// Default number of alerts returned when listing the authorization rate alerts of a merchant
+pub const AUTH_RATE_ALERTS_DEFAULT_LIMIT: i64 = 20;
+
+/// Maximum number of alerts returned when listing the authorization rate alerts of a merchant
+pub const AUTH_RATE_ALERTS_MAX_LIMIT: i64 = 100;
+
+/// Time after which the mTLS handshake with the key manager is considered to have failed
+#[cfg(feature = "keymanager_mtls")]
+pub const KEY_MANAGER_MTLS_HANDSHAKE_TIMEOUT: std::time::Duration =
+    std::time::Duration::from_secs(5);
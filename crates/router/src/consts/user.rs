@@ -38,6 +38,14 @@ pub const REDIS_RECOVERY_CODE_ATTEMPTS_TTL_IN_SECS: i64 = 10 * 60; // 10 mins
 pub const REDIS_SSO_PREFIX: &str = "SSO_";
 pub const REDIS_SSO_TTL: i64 = 5 * 60; // 5 minutes
 
+pub const REDIS_PASSKEY_PREFIX: &str = "PASSKEY_";
+pub const REDIS_PASSKEY_REGISTRATION_PREFIX: &str = "PASSKEY_REG_";
+pub const REDIS_PASSKEY_AUTHENTICATION_PREFIX: &str = "PASSKEY_AUTH_";
+pub const REDIS_PASSKEY_SIGNIN_PREFIX: &str = "PASSKEY_SIGNIN_";
+pub const REDIS_PASSKEY_CHALLENGE_TTL_IN_SECS: i64 = 5 * 60; // 5 minutes
+pub const MAX_PASSKEYS_PER_USER: usize = 10;
+pub const MAX_PASSKEY_NAME_LENGTH: usize = 64;
+
 pub const DEFAULT_PROFILE_NAME: &str = "default";
 pub const DEFAULT_PRODUCT_TYPE: common_enums::MerchantProductType =
     common_enums::MerchantProductType::Orchestration;
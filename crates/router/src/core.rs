@@ -1,8 +1,11 @@
 pub mod admin;
+pub mod admin_audit;
 pub mod api_keys;
 pub mod api_locking;
 #[cfg(feature = "v1")]
 pub mod apple_pay_certificates_migration;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod auth_rate_alerts;
 pub mod authentication;
 #[cfg(feature = "v1")]
 pub mod blocklist;
@@ -11,29 +14,45 @@ pub mod card_testing_guard;
 pub mod cards_info;
 pub mod conditional_config;
 pub mod configs;
+pub mod connection_pools;
+pub mod connector_audit_log;
 #[cfg(feature = "olap")]
 pub mod connector_onboarding;
 pub mod connector_validation;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 pub mod currency;
 pub mod customers;
+#[cfg(feature = "v1")]
+pub mod data_retention;
+#[cfg(feature = "v1")]
+pub mod dispute_decision_config;
 pub mod disputes;
+pub mod drain;
 pub mod encryption;
 pub mod errors;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod event_stream;
 pub mod external_service_auth;
 pub mod files;
 #[cfg(feature = "frm")]
 pub mod fraud_check;
+#[cfg(feature = "v1")]
+pub mod frm_velocity_rules;
 #[cfg(feature = "v2")]
 pub mod gift_card;
 pub mod gsm;
 pub mod health_check;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod list_views;
 #[cfg(feature = "v1")]
 pub mod locker_migration;
 pub mod mandate;
 pub mod metrics;
+pub mod payment_archival;
 pub mod payment_link;
 pub mod payment_methods;
+#[cfg(feature = "v1")]
+pub mod payment_review;
 pub mod payments;
 #[cfg(feature = "payouts")]
 pub mod payout_link;
@@ -51,6 +70,7 @@ pub mod refunds_v2;
 #[cfg(feature = "v1")]
 pub mod debit_routing;
 pub mod routing;
+pub mod signing_keys;
 #[cfg(feature = "v1")]
 pub mod subscription;
 pub mod surcharge_decision_config;
@@ -73,6 +93,8 @@ pub mod unified_connector_service;
 #[cfg(feature = "v2")]
 pub mod proxy;
 pub mod relay;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod reports;
 #[cfg(feature = "v2")]
 pub mod revenue_recovery;
 
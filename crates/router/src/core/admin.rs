@@ -145,6 +145,7 @@ pub async fn update_organization(
         organization_details: req.organization_details,
         metadata: req.metadata,
         platform_merchant_id: req.platform_merchant_id,
+        is_passkey_required: req.is_passkey_required,
     };
     state
         .accounts_store
@@ -488,6 +489,7 @@ impl MerchantAccountCreateBridge for api::MerchantAccountCreate {
                     is_platform_account: false,
                     product_type: self.product_type,
                     merchant_account_type,
+                    allowed_ip_ranges: None,
                 },
             )
         }
@@ -766,6 +768,7 @@ impl MerchantAccountCreateBridge for api::MerchantAccountCreate {
                     version: common_types::consts::API_VERSION,
                     product_type: self.product_type,
                     merchant_account_type,
+                    allowed_ip_ranges: None,
                 }),
             )
         }
@@ -1080,6 +1083,9 @@ impl MerchantAccountUpdateBridge for api::MerchantAccountUpdate {
             payment_link_config: None,
             pm_collect_link_config,
             routing_algorithm: self.routing_algorithm,
+            allowed_ip_ranges: authentication::ip_allowlist::get_allowed_ip_ranges_update(
+                self.allowed_ip_ranges,
+            )?,
         })
     }
 }
@@ -1144,6 +1150,9 @@ impl MerchantAccountUpdateBridge for api::MerchantAccountUpdate {
                 .attach_printable("Unable to encrypt merchant details")?,
             metadata: metadata.map(Box::new),
             publishable_key: None,
+            allowed_ip_ranges: authentication::ip_allowlist::get_allowed_ip_ranges_update(
+                self.allowed_ip_ranges,
+            )?,
         })
     }
 }
@@ -3227,6 +3236,132 @@ pub async fn create_and_insert_business_profile(
         .attach_printable("Failed to insert Business profile because of duplication error")
 }
 
+/// Validate that the custom headers sent along with all webhooks of the business profile do not
+/// override the headers set by the webhook delivery
+pub(crate) fn validate_outgoing_webhook_custom_http_headers(
+    outgoing_webhook_custom_http_headers: Option<&std::collections::HashMap<String, String>>,
+) -> RouterResult<()> {
+    outgoing_webhook_custom_http_headers
+        .into_iter()
+        .flatten()
+        .try_for_each(|(header_name, _)| {
+            admin_types::validate_webhook_custom_header_name(header_name).map_err(|message| {
+                report!(errors::ApiErrorResponse::InvalidRequestData { message })
+            })
+        })
+}
+
+/// Get the encrypted credentials of the additional webhook endpoints provided in the webhook
+/// details, which are stored separately from the webhook details. The stored credentials of an
+/// endpoint are retained when they are not provided again in the request.
+#[cfg(feature = "v1")]
+pub(crate) async fn get_encrypted_webhook_endpoint_secrets(
+    key_manager_state: &KeyManagerState,
+    key_store: &domain::MerchantKeyStore,
+    webhook_details: Option<&admin_types::WebhookDetails>,
+    mut stored_webhook_endpoint_secrets: std::collections::HashMap<
+        String,
+        diesel_models::business_profile::WebhookEndpointSecrets,
+    >,
+) -> RouterResult<common_utils::crypto::OptionalEncryptableValue> {
+    let Some(webhook_details) = webhook_details else {
+        return Ok(None);
+    };
+
+    let mut webhook_endpoint_secrets = std::collections::HashMap::new();
+    for webhook_endpoint in webhook_details.webhook_endpoints.iter().flatten() {
+        let stored_secrets = stored_webhook_endpoint_secrets
+            .remove(&webhook_endpoint.endpoint_id)
+            .unwrap_or_default();
+        let client_certificate_key = webhook_endpoint.client_certificate.as_ref().and_then(|_| {
+            webhook_endpoint
+                .client_certificate_key
+                .clone()
+                .or(stored_secrets.client_certificate_key)
+        });
+        validate_webhook_endpoint_certificates(webhook_endpoint, client_certificate_key.as_ref())?;
+
+        let custom_headers = webhook_endpoint
+            .custom_headers
+            .clone()
+            .or(stored_secrets.custom_headers);
+        if custom_headers.is_some() || client_certificate_key.is_some() {
+            webhook_endpoint_secrets.insert(
+                webhook_endpoint.endpoint_id.clone(),
+                diesel_models::business_profile::WebhookEndpointSecrets {
+                    custom_headers,
+                    client_certificate_key,
+                },
+            );
+        }
+    }
+
+    cards::create_encrypted_data(key_manager_state, key_store, webhook_endpoint_secrets)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to encrypt webhook endpoint secrets")
+        .map(Some)
+}
+
+/// Validate a policy or config of the profile, if it has been provided
+#[cfg(all(feature = "olap", feature = "v1"))]
+fn validate_profile_config<T>(
+    config: Option<T>,
+    validate: impl FnOnce(&T) -> Result<(), String>,
+) -> RouterResult<Option<T>> {
+    config
+        .map(|config| match validate(&config) {
+            Ok(()) => Ok(config),
+            Err(message) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message
+            })),
+        })
+        .transpose()
+}
+
+/// Validate that the certificates of the webhook endpoint can be used for the webhook delivery
+#[cfg(feature = "v1")]
+fn validate_webhook_endpoint_certificates(
+    webhook_endpoint: &admin_types::WebhookEndpoint,
+    client_certificate_key: Option<&Secret<String>>,
+) -> RouterResult<()> {
+    let endpoint_id = webhook_endpoint.endpoint_id.as_str();
+
+    if let Some(client_certificate) = &webhook_endpoint.client_certificate {
+        let client_certificate_key = client_certificate_key.ok_or_else(|| {
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "client_certificate_key of webhook endpoint `{endpoint_id}` should be \
+                     provided along with client_certificate"
+                ),
+            })
+        })?;
+        client::create_identity_from_certificate_and_key(
+            client_certificate.clone(),
+            client_certificate_key.clone(),
+        )
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "client_certificate and client_certificate_key of webhook endpoint \
+                 `{endpoint_id}` should be a base64 encoded PEM certificate and its private key"
+            ),
+        })?;
+    }
+
+    if let Some(ca_certificate) = &webhook_endpoint.ca_certificate {
+        client::create_certificate(ca_certificate.clone()).change_context(
+            errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "ca_certificate of webhook endpoint `{endpoint_id}` should be a base64 \
+                     encoded PEM certificate"
+                ),
+            },
+        )?;
+    }
+
+    Ok(())
+}
+
 #[cfg(feature = "olap")]
 #[async_trait::async_trait]
 trait ProfileCreateBridge {
@@ -3261,6 +3396,10 @@ impl ProfileCreateBridge for api::ProfileCreate {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
 
+        if let Some(intent_expiry) = self.intent_expiry {
+            helpers::validate_intent_expiry(intent_expiry)?;
+        }
+
         if let Some(intent_fulfillment_expiry) = self.intent_fulfillment_time {
             helpers::validate_intent_fulfillment_expiry(intent_fulfillment_expiry)?;
         }
@@ -3281,8 +3420,6 @@ impl ProfileCreateBridge for api::ProfileCreate {
 
         let current_time = date_time::now();
 
-        let webhook_details = self.webhook_details.map(ForeignInto::foreign_into);
-
         let payment_response_hash_key = self
             .payment_response_hash_key
             .or(merchant_context
@@ -3292,6 +3429,9 @@ impl ProfileCreateBridge for api::ProfileCreate {
             .unwrap_or(common_utils::crypto::generate_cryptographically_secure_random_string(64));
 
         let payment_link_config = self.payment_link_config.map(ForeignInto::foreign_into);
+        validate_outgoing_webhook_custom_http_headers(
+            self.outgoing_webhook_custom_http_headers.as_ref(),
+        )?;
         let key_manager_state = state.into();
         let outgoing_webhook_custom_http_headers = self
             .outgoing_webhook_custom_http_headers
@@ -3307,6 +3447,15 @@ impl ProfileCreateBridge for api::ProfileCreate {
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Unable to encrypt outgoing webhook custom HTTP headers")?;
 
+        let webhook_endpoint_secrets = get_encrypted_webhook_endpoint_secrets(
+            &key_manager_state,
+            merchant_context.get_merchant_key_store(),
+            self.webhook_details.as_ref(),
+            std::collections::HashMap::new(),
+        )
+        .await?;
+        let webhook_details = self.webhook_details.map(ForeignInto::foreign_into);
+
         let payout_link_config = self
             .payout_link_config
             .map(|payout_conf| match payout_conf.config.validate() {
@@ -3319,6 +3468,17 @@ impl ProfileCreateBridge for api::ProfileCreate {
             })
             .transpose()?;
 
+        let retry_policy = validate_profile_config(self.retry_policy, |config| config.validate())?;
+
+        let webhook_retry_policy =
+            validate_profile_config(self.webhook_retry_policy, |config| config.validate())?;
+
+        let event_sink_config =
+            validate_profile_config(self.event_sink_config, |config| config.validate())?;
+
+        let soft_decline_retry_config =
+            validate_profile_config(self.soft_decline_retry_config, |config| config.validate())?;
+
         let key = merchant_context
             .get_merchant_key_store()
             .key
@@ -3478,6 +3638,14 @@ impl ProfileCreateBridge for api::ProfileCreate {
             dispute_polling_interval: self.dispute_polling_interval,
             is_manual_retry_enabled: self.is_manual_retry_enabled,
             always_enable_overcapture: self.always_enable_overcapture,
+            retry_policy,
+            intent_expiry: self.intent_expiry.map(i64::from),
+            refund_approval_threshold: self.refund_approval_threshold,
+            webhook_retry_policy,
+            event_sink_config,
+            is_connector_audit_capture_enabled: self.is_connector_audit_capture_enabled,
+            soft_decline_retry_config,
+            webhook_endpoint_secrets,
         }))
     }
 
@@ -3506,6 +3674,9 @@ impl ProfileCreateBridge for api::ProfileCreate {
             .unwrap_or(common_utils::crypto::generate_cryptographically_secure_random_string(64));
 
         let payment_link_config = self.payment_link_config.map(ForeignInto::foreign_into);
+        validate_outgoing_webhook_custom_http_headers(
+            self.outgoing_webhook_custom_http_headers.as_ref(),
+        )?;
         let key_manager_state = state.into();
         let outgoing_webhook_custom_http_headers = self
             .outgoing_webhook_custom_http_headers
@@ -3790,12 +3961,14 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
 
+        if let Some(intent_expiry) = self.intent_expiry {
+            helpers::validate_intent_expiry(intent_expiry)?;
+        }
+
         if let Some(intent_fulfillment_expiry) = self.intent_fulfillment_time {
             helpers::validate_intent_fulfillment_expiry(intent_fulfillment_expiry)?;
         }
 
-        let webhook_details = self.webhook_details.map(ForeignInto::foreign_into);
-
         if let Some(ref routing_algorithm) = self.routing_algorithm {
             let _: api_models::routing::StaticRoutingAlgorithm = routing_algorithm
                 .clone()
@@ -3828,6 +4001,9 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
             })
             .transpose()?
             .map(Secret::new);
+        validate_outgoing_webhook_custom_http_headers(
+            self.outgoing_webhook_custom_http_headers.as_ref(),
+        )?;
         let key_manager_state = state.into();
         let outgoing_webhook_custom_http_headers = self
             .outgoing_webhook_custom_http_headers
@@ -3839,6 +4015,15 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Unable to encrypt outgoing webhook custom HTTP headers")?;
 
+        let webhook_endpoint_secrets = get_encrypted_webhook_endpoint_secrets(
+            &key_manager_state,
+            key_store,
+            self.webhook_details.as_ref(),
+            business_profile.get_webhook_endpoint_secrets()?,
+        )
+        .await?;
+        let webhook_details = self.webhook_details.map(ForeignInto::foreign_into);
+
         let payout_link_config = self
             .payout_link_config
             .map(|payout_conf| match payout_conf.config.validate() {
@@ -3849,6 +4034,17 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
             })
             .transpose()?;
 
+        let retry_policy = validate_profile_config(self.retry_policy, |config| config.validate())?;
+
+        let webhook_retry_policy =
+            validate_profile_config(self.webhook_retry_policy, |config| config.validate())?;
+
+        let event_sink_config =
+            validate_profile_config(self.event_sink_config, |config| config.validate())?;
+
+        let soft_decline_retry_config =
+            validate_profile_config(self.soft_decline_retry_config, |config| config.validate())?;
+
         let key = key_store.key.clone().into_inner();
         let key_manager_state = state.into();
 
@@ -3976,6 +4172,14 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
                 dispute_polling_interval: self.dispute_polling_interval,
                 is_manual_retry_enabled: self.is_manual_retry_enabled,
                 always_enable_overcapture: self.always_enable_overcapture,
+                retry_policy,
+                intent_expiry: self.intent_expiry.map(i64::from),
+                refund_approval_threshold: self.refund_approval_threshold,
+                webhook_retry_policy,
+                event_sink_config,
+                is_connector_audit_capture_enabled: self.is_connector_audit_capture_enabled,
+                soft_decline_retry_config,
+                webhook_endpoint_secrets,
             },
         )))
     }
@@ -4018,6 +4222,9 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
             })
             .transpose()?
             .map(Secret::new);
+        validate_outgoing_webhook_custom_http_headers(
+            self.outgoing_webhook_custom_http_headers.as_ref(),
+        )?;
         let key_manager_state = state.into();
         let outgoing_webhook_custom_http_headers = self
             .outgoing_webhook_custom_http_headers
@@ -4454,6 +4661,119 @@ pub async fn transfer_key_store_to_key_manager(
     ))
 }
 
+#[cfg(feature = "v1")]
+pub async fn schedule_merchant_key_migration(
+    state: SessionState,
+    req: admin_types::MerchantKeyMigrationRequest,
+) -> RouterResponse<admin_types::MerchantKeyMigrationResponse> {
+    let key_manager_state: &KeyManagerState = &(&state).into();
+    if !key_manager_state.enabled {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Key migration is only supported when the key manager is enabled".to_string(),
+        }
+        .into());
+    }
+
+    let batch_size = req
+        .batch_size
+        .unwrap_or(crate::core::encryption::DEFAULT_KEY_MIGRATION_BATCH_SIZE);
+    if batch_size == 0 {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "batch_size must be greater than zero".to_string(),
+        }
+        .into());
+    }
+
+    let tracking_data = storage::MerchantKeyMigrationTrackingData {
+        batch_size,
+        max_transfers_per_second: req.max_transfers_per_second,
+        dry_run: req.dry_run,
+        re_encrypt_data: req.re_encrypt_data,
+        offset: 0,
+        transferred: 0,
+        failed_merchant_ids: Vec::new(),
+    };
+    let migration_id = crate::core::encryption::add_merchant_key_migration_task(
+        state.store.as_ref(),
+        tracking_data.clone(),
+    )
+    .await?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        get_merchant_key_migration_response(
+            migration_id,
+            storage::enums::ProcessTrackerStatus::New,
+            tracking_data,
+        ),
+    ))
+}
+
+#[cfg(feature = "v1")]
+pub async fn retrieve_merchant_key_migration(
+    state: SessionState,
+    migration_id: String,
+) -> RouterResponse<admin_types::MerchantKeyMigrationResponse> {
+    let not_found_error = || errors::ApiErrorResponse::GenericNotFoundError {
+        message: "Merchant key migration does not exist".to_string(),
+    };
+    let process = state
+        .store
+        .find_process_by_id(&migration_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch merchant key migration task")?
+        .filter(|process| {
+            process.runner.as_deref()
+                == Some(
+                    storage::ProcessTrackerRunner::MerchantKeyMigrationWorkflow
+                        .to_string()
+                        .as_str(),
+                )
+        })
+        .ok_or_else(not_found_error)?;
+
+    let tracking_data: storage::MerchantKeyMigrationTrackingData = process
+        .tracking_data
+        .parse_value("MerchantKeyMigrationTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        get_merchant_key_migration_response(process.id, process.status, tracking_data),
+    ))
+}
+
+#[cfg(feature = "v1")]
+fn get_merchant_key_migration_response(
+    migration_id: String,
+    status: storage::enums::ProcessTrackerStatus,
+    tracking_data: storage::MerchantKeyMigrationTrackingData,
+) -> admin_types::MerchantKeyMigrationResponse {
+    admin_types::MerchantKeyMigrationResponse {
+        migration_id,
+        status,
+        dry_run: tracking_data.dry_run,
+        processed: tracking_data.offset,
+        transferred: tracking_data.transferred,
+        failed_merchant_ids: tracking_data.failed_merchant_ids,
+    }
+}
+
+/// Reloads the mTLS certificates of the key manager from the configured files and rebuilds the
+/// key manager client. Only the instance serving the request is affected, the other instances
+/// pick up the changed files at their next check.
+#[cfg(feature = "keymanager_mtls")]
+pub async fn reload_key_manager_certificates(state: SessionState) -> RouterResponse<()> {
+    state
+        .key_manager_certificates
+        .reload()
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to reload the key manager certificates")?;
+    common_utils::keymanager::reset_encryption_client();
+
+    Ok(service_api::ApplicationResponse::StatusOk)
+}
+
 async fn process_open_banking_connectors(
     state: &SessionState,
     merchant_id: &id_type::MerchantId,
@@ -4702,6 +5022,49 @@ async fn locker_recipient_create_call(
     Ok(store_resp.card_reference)
 }
 
+#[cfg(feature = "v1")]
+pub async fn rotate_merchant_key(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<admin_types::MerchantKeyRotateResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state: &KeyManagerState = &(&state).into();
+    if !key_manager_state.enabled {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Key rotation is only supported when the key manager is enabled".to_string(),
+        }
+        .into());
+    }
+
+    db.get_merchant_key_store_by_merchant_id(
+        key_manager_state,
+        &merchant_id,
+        &db.get_master_key().to_vec().into(),
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let key_version = crate::core::encryption::rotate_encryption_key(&state, &merchant_id).await?;
+    let re_encryption_task_id = crate::core::encryption::add_merchant_key_re_encryption_task(
+        db,
+        storage::MerchantKeyReEncryptionTrackingData {
+            merchant_id: merchant_id.clone(),
+            key_version: key_version.clone(),
+            stage: storage::MerchantKeyReEncryptionStage::MerchantAccount,
+            offset: 0,
+        },
+    )
+    .await?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        admin_types::MerchantKeyRotateResponse {
+            merchant_id,
+            key_version,
+            re_encryption_task_id,
+        },
+    ))
+}
+
 pub async fn enable_platform_account(
     state: SessionState,
     merchant_id: id_type::MerchantId,
@@ -0,0 +1,239 @@
+use actix_web::HttpRequest;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use api_models::admin_audit as admin_audit_api;
+use common_utils::id_type;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use error_stack::ResultExt;
+use router_env::{logger, tracing::Instrument};
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+use crate::{
+    core::errors::{self, RouterResponse},
+    services::ApplicationResponse,
+    types::storage,
+    utils,
+};
+use crate::{
+    routes::{
+        lock_utils::{ApiIdentifier, GetApiIdentifier},
+        SessionState,
+    },
+    services::authentication::AuthenticationType,
+};
+
+/// APIs whose mutating calls are recorded in the admin audit trail
+fn is_admin_api(api_identifier: &ApiIdentifier) -> bool {
+    matches!(
+        api_identifier,
+        ApiIdentifier::Organization
+            | ApiIdentifier::MerchantAccount
+            | ApiIdentifier::MerchantConnector
+            | ApiIdentifier::Configs
+            | ApiIdentifier::Profile
+            | ApiIdentifier::ApiKeys
+            | ApiIdentifier::Routing
+            | ApiIdentifier::Blocklist
+            | ApiIdentifier::Role
+            | ApiIdentifier::UserRole
+            | ApiIdentifier::ProfileAcquirer
+            | ApiIdentifier::ThreeDsDecisionRule
+            | ApiIdentifier::PaymentReview
+            | ApiIdentifier::DataRetention
+    )
+}
+
+fn is_mutating_method(method: &actix_web::http::Method) -> bool {
+    use actix_web::http::Method;
+
+    [Method::POST, Method::PUT, Method::PATCH, Method::DELETE].contains(method)
+}
+
+/// Records a mutating call to an admin API in the audit store. The audit trail must never fail
+/// the call it records, so the event is written in the background and errors are only logged.
+#[allow(clippy::too_many_arguments)]
+pub fn record_admin_audit_event(
+    state: &SessionState,
+    flow: &(impl router_env::types::FlowMetric + GetApiIdentifier),
+    request: &HttpRequest,
+    auth_type: &AuthenticationType,
+    request_id: Option<String>,
+    status_code: i64,
+    request_body: &serde_json::Value,
+    response_body: Option<&serde_json::Value>,
+) {
+    let Some(api_identifier) = flow
+        .get_api_identifier()
+        .filter(|api_identifier| is_admin_api(api_identifier))
+    else {
+        return;
+    };
+    if !is_mutating_method(request.method()) {
+        return;
+    }
+
+    let (org_id, merchant_id, profile_id) = get_audited_entity_ids(
+        &api_identifier,
+        request,
+        auth_type,
+        request_body,
+        response_body,
+    );
+    let admin_audit_event = diesel_models::admin_audit_event::AdminAuditEventNew {
+        id: common_utils::generate_time_ordered_id("audit"),
+        org_id,
+        merchant_id,
+        profile_id,
+        actor_id: auth_type.get_actor_id(),
+        auth_type: <&'static str>::from(auth_type).to_owned(),
+        ip_address: state.client_details.ip_address.clone(),
+        api_flow: flow.to_string(),
+        http_method: request.method().to_string(),
+        url_path: request.path().to_owned(),
+        request_id,
+        status_code,
+        request_body: (!request_body.is_null()).then(|| request_body.clone()),
+        created_at: common_utils::date_time::now(),
+    };
+
+    let store = state.store.clone();
+    tokio::spawn(
+        async move {
+            let _ = store
+                .insert_admin_audit_event(admin_audit_event)
+                .await
+                .inspect_err(|error| logger::error!(?error, "Failed to record admin audit event"));
+        }
+        .in_current_span(),
+    );
+}
+
+/// Organization, merchant and profile the audited call was made on. They are taken from the
+/// authentication, and for the admin API key and organization calls, which are not made on behalf
+/// of a merchant, from the route or the request and response of the call, such as the merchant
+/// account being created.
+fn get_audited_entity_ids(
+    api_identifier: &ApiIdentifier,
+    request: &HttpRequest,
+    auth_type: &AuthenticationType,
+    request_body: &serde_json::Value,
+    response_body: Option<&serde_json::Value>,
+) -> (
+    Option<id_type::OrganizationId>,
+    Option<id_type::MerchantId>,
+    Option<id_type::ProfileId>,
+) {
+    let get_field = |route_params: &[&str], body_field: &str| {
+        route_params
+            .iter()
+            .find_map(|param| request.match_info().get(param))
+            .map(ToOwned::to_owned)
+            .or_else(|| {
+                [Some(request_body), response_body]
+                    .into_iter()
+                    .flatten()
+                    .find_map(|body| body.get(body_field)?.as_str().map(ToOwned::to_owned))
+            })
+    };
+
+    let org_id = match auth_type {
+        AuthenticationType::OrganizationJwt { org_id, .. } => Some(org_id.to_owned()),
+        _ => {
+            let route_params: &[&str] = match api_identifier {
+                ApiIdentifier::Organization => &["id", "org_id"],
+                _ => &["org_id"],
+            };
+            get_field(route_params, "organization_id")
+                .and_then(|org_id| id_type::OrganizationId::try_from_string(org_id).ok())
+        }
+    };
+    let merchant_id = auth_type.get_merchant_id().cloned().or_else(|| {
+        let route_params: &[&str] = match api_identifier {
+            ApiIdentifier::MerchantAccount => &["id", "merchant_id", "account_id"],
+            _ => &["merchant_id", "account_id"],
+        };
+        get_field(route_params, "merchant_id")
+            .and_then(|merchant_id| id_type::MerchantId::wrap(merchant_id).ok())
+    });
+    let profile_id = match auth_type {
+        AuthenticationType::MerchantJwtWithProfileId { profile_id, .. } => profile_id.to_owned(),
+        _ => None,
+    };
+
+    (org_id, merchant_id, profile_id)
+}
+
+/// Lists the audit events of a merchant, or of an organization, which holds the events of the
+/// calls made on the organization itself.
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn list_admin_audit_events(
+    state: SessionState,
+    entity: storage::AdminAuditEventEntity,
+    authentication_profile_id: Option<id_type::ProfileId>,
+    query: admin_audit_api::AdminAuditEventListQuery,
+) -> RouterResponse<admin_audit_api::AdminAuditEventListResponse> {
+    // Profile level users can only see the events of their own profile
+    let profile_id = match (authentication_profile_id, query.profile_id) {
+        (Some(authentication_profile_id), Some(profile_id)) => {
+            utils::when(authentication_profile_id != profile_id, || {
+                Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Profile id does not match the authenticated profile".to_string(),
+                })
+            })?;
+            Some(profile_id)
+        }
+        (authentication_profile_id, profile_id) => authentication_profile_id.or(profile_id),
+    };
+
+    let limit = query
+        .limit
+        .map_or(crate::consts::ADMIN_AUDIT_EVENTS_DEFAULT_LIMIT, i64::from)
+        .min(crate::consts::ADMIN_AUDIT_EVENTS_MAX_LIMIT);
+
+    let events = state
+        .store
+        .list_admin_audit_events_by_entity_constraints(
+            entity,
+            storage::AdminAuditEventListConstraints {
+                profile_id,
+                actor_id: query.actor_id,
+                api_flow: query.api_flow,
+                auth_type: query.auth_type,
+                created_after: query.start_time,
+                created_before: query.end_time,
+                starting_after: query.starting_after,
+                limit,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch admin audit events")?;
+
+    let next_starting_after = (i64::try_from(events.len()).ok() == Some(limit))
+        .then(|| events.last().map(|event| event.id.clone()))
+        .flatten();
+    let data = events
+        .into_iter()
+        .map(|event| admin_audit_api::AdminAuditEventRecord {
+            id: event.id,
+            profile_id: event.profile_id,
+            actor_id: event.actor_id,
+            auth_type: event.auth_type,
+            ip_address: event.ip_address,
+            api_flow: event.api_flow,
+            http_method: event.http_method,
+            url_path: event.url_path,
+            request_id: event.request_id,
+            status_code: event.status_code,
+            request_body: event.request_body,
+            created_at: event.created_at,
+        })
+        .collect::<Vec<_>>();
+
+    Ok(ApplicationResponse::Json(
+        admin_audit_api::AdminAuditEventListResponse {
+            count: data.len(),
+            data,
+            next_starting_after,
+        },
+    ))
+}
@@ -113,13 +113,90 @@ pub async fn create_api_key(
     state: SessionState,
     api_key: api::CreateApiKeyRequest,
     key_store: domain::MerchantKeyStore,
+    caller_scopes: Option<Vec<common_enums::ApiKeyScope>>,
 ) -> RouterResponse<api::CreateApiKeyResponse> {
+    let (api_key, plaintext_api_key) = generate_api_key(
+        &state,
+        key_store.merchant_id.clone(),
+        api_key,
+        caller_scopes.as_deref(),
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(
+        (api_key, plaintext_api_key).foreign_into(),
+    ))
+}
+
+/// Ensures that an API key can only issue API keys that are at most as privileged as itself. API
+/// keys authenticated without any scopes (and other forms of authentication) are unrestricted.
+fn validate_requested_scopes(
+    caller_scopes: Option<&[common_enums::ApiKeyScope]>,
+    requested_scopes: Option<&[common_enums::ApiKeyScope]>,
+) -> errors::RouterResult<()> {
+    match (caller_scopes, requested_scopes) {
+        (_, Some([])) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "scopes must not be empty, omit them to permit all the resources".to_string(),
+        })),
+        (None, _) => Ok(()),
+        (Some(_), None) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "scopes must be provided when authenticated with a scoped API key".to_string(),
+        })),
+        (Some(caller_scopes), Some(requested_scopes)) => {
+            let exceeding_scopes = requested_scopes
+                .iter()
+                .filter(|requested_scope| {
+                    !caller_scopes.iter().any(|caller_scope| {
+                        caller_scope.permits(
+                            requested_scope.resource(),
+                            requested_scope.permission_scope(),
+                        )
+                    })
+                })
+                .map(ToString::to_string)
+                .collect::<Vec<_>>();
+
+            if exceeding_scopes.is_empty() {
+                Ok(())
+            } else {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "scopes {} exceed the scopes of the API key used to authenticate",
+                        exceeding_scopes.join(", ")
+                    ),
+                }))
+            }
+        }
+    }
+}
+
+/// Scopes of the API key used to authenticate the request, if any
+pub fn get_caller_scopes(
+    auth_type: Option<&authentication::AuthenticationType>,
+) -> Option<Vec<common_enums::ApiKeyScope>> {
+    match auth_type {
+        Some(authentication::AuthenticationType::ApiKey { scopes, .. }) => scopes.clone(),
+        _ => None,
+    }
+}
+
+// Generate a new API key for the merchant and persist its hash, returning the stored API key along
+// with the plaintext API key, which cannot be obtained once this function returns.
+async fn generate_api_key(
+    state: &SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    api_key: api::CreateApiKeyRequest,
+    caller_scopes: Option<&[common_enums::ApiKeyScope]>,
+) -> errors::RouterResult<(storage::ApiKey, PlaintextApiKey)> {
     let api_key_config = state.conf.api_keys.get_inner();
     let store = state.store.as_ref();
 
-    let merchant_id = key_store.merchant_id.clone();
-
     let hash_key = api_key_config.get_hash_key()?;
+    validate_requested_scopes(caller_scopes, api_key.scopes.as_deref())?;
+    let allowed_ip_ranges =
+        authentication::ip_allowlist::get_allowed_ip_ranges_update(api_key.allowed_ip_ranges)?
+            .flatten();
+
     let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
     let api_key = storage::ApiKeyNew {
         key_id: PlaintextApiKey::new_key_id(),
@@ -131,6 +208,11 @@ pub async fn create_api_key(
         created_at: date_time::now(),
         expires_at: api_key.expiration.into(),
         last_used: None,
+        scopes: api_key
+            .scopes
+            .map(|scopes| scopes.into_iter().map(Some).collect()),
+        allowed_ip_ranges: allowed_ip_ranges
+            .map(|allowed_ip_ranges| allowed_ip_ranges.into_iter().map(Some).collect()),
     };
 
     let api_key = store
@@ -178,9 +260,7 @@ pub async fn create_api_key(
         }
     }
 
-    Ok(ApplicationResponse::Json(
-        (api_key, plaintext_api_key).foreign_into(),
-    ))
+    Ok((api_key, plaintext_api_key))
 }
 
 // Add api_key_expiry task to the process_tracker table.
@@ -276,6 +356,8 @@ pub async fn update_api_key(
     let key_id = api_key.key_id.clone();
     let store = state.store.as_ref();
 
+    authentication::ip_allowlist::get_allowed_ip_ranges_update(api_key.allowed_ip_ranges.clone())?;
+
     let api_key = store
         .update_api_key(
             merchant_id.to_owned(),
@@ -486,6 +568,127 @@ pub async fn revoke_api_key(
     }))
 }
 
+#[instrument(skip_all)]
+pub async fn rotate_api_key(
+    state: SessionState,
+    api_key: api::RotateApiKeyRequest,
+    caller_scopes: Option<Vec<common_enums::ApiKeyScope>>,
+) -> RouterResponse<api::RotateApiKeyResponse> {
+    let store = state.store.as_ref();
+    let merchant_id = api_key.merchant_id.clone();
+    let grace_period_in_secs = api_key.grace_period_in_secs.unwrap_or(
+        state
+            .conf
+            .api_keys
+            .get_inner()
+            .rotation_grace_period_in_secs,
+    );
+
+    let existing_api_key = store
+        .find_api_key_by_merchant_id_key_id_optional(&merchant_id, &api_key.key_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve API key")?
+        .ok_or(report!(errors::ApiErrorResponse::ApiKeyNotFound))?;
+
+    // The rotated API key is never made to outlive its existing expiry
+    let grace_period_expiry =
+        date_time::now().saturating_add(time::Duration::seconds(i64::from(grace_period_in_secs)));
+    let rotated_key_expiration = existing_api_key
+        .expires_at
+        .map_or(grace_period_expiry, |expires_at| {
+            expires_at.min(grace_period_expiry)
+        });
+
+    // The replacement API key is issued before the expiry of the rotated API key is shortened, so
+    // that the merchant is never left without a valid API key if either of the steps fails.
+    let (new_api_key, plaintext_api_key) = generate_api_key(
+        &state,
+        merchant_id.clone(),
+        api::CreateApiKeyRequest {
+            name: existing_api_key.name.clone(),
+            description: existing_api_key.description.clone(),
+            expiration: api_key
+                .expiration
+                .unwrap_or_else(|| existing_api_key.expires_at.into()),
+            scopes: existing_api_key.get_scopes(),
+            allowed_ip_ranges: existing_api_key.get_allowed_ip_ranges(),
+        },
+        caller_scopes.as_deref(),
+    )
+    .await?;
+
+    let rotated_api_key = store
+        .update_api_key(
+            merchant_id.clone(),
+            existing_api_key.key_id.clone(),
+            storage::ApiKeyUpdate::Update {
+                name: None,
+                description: None,
+                expires_at: Some(Some(rotated_key_expiration)),
+                last_used: None,
+                allowed_ip_ranges: None,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound)
+        .attach_printable("Failed to update the expiry of the rotated API key")?;
+
+    let state_inner = state.clone();
+    let hashed_api_key = rotated_api_key.hashed_api_key.clone();
+    let merchant_id_inner = merchant_id.clone();
+    let key_id_inner = rotated_api_key.key_id.clone();
+
+    authentication::decision::spawn_tracked_job(
+        async move {
+            authentication::decision::add_api_key(
+                &state_inner,
+                hashed_api_key.into_inner().into(),
+                merchant_id_inner,
+                key_id_inner,
+                Some(authentication::decision::convert_expiry(
+                    rotated_key_expiration,
+                )),
+            )
+            .await
+        },
+        authentication::decision::ADD,
+    );
+
+    metrics::API_KEY_ROTATED.add(
+        1,
+        router_env::metric_attributes!(("merchant", merchant_id.clone())),
+    );
+
+    // Expiry reminders are no longer sent for the rotated API key, since it has been replaced
+    #[cfg(feature = "email")]
+    {
+        let task_id = generate_task_id_for_api_key_expiry_workflow(&rotated_api_key.key_id);
+        let existing_process_tracker_task = store
+            .find_process_by_id(task_id.as_str())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
+            .attach_printable(
+                "Failed to retrieve API key expiry reminder task from process tracker",
+            )?;
+
+        if existing_process_tracker_task.is_some() {
+            revoke_api_key_expiry_task(store, &rotated_api_key.key_id)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed to revoke API key expiry reminder task in process tracker",
+                )?;
+        }
+    }
+
+    Ok(ApplicationResponse::Json(api::RotateApiKeyResponse {
+        api_key: (new_api_key, plaintext_api_key).foreign_into(),
+        rotated_key_id: rotated_api_key.key_id,
+        rotated_key_expiration,
+    }))
+}
+
 // Function to revoke api_key_expiry task in the process_tracker table when API key is revoked.
 // Construct StatusUpdate variant of ProcessTrackerUpdate by setting status to 'finish'.
 #[cfg(feature = "email")]
@@ -585,4 +788,38 @@ mod tests {
         let new_hashed_api_key = plaintext_api_key.keyed_hash(hash_key.peek());
         assert_eq!(hashed_api_key, new_hashed_api_key)
     }
+
+    #[test]
+    fn test_validate_requested_scopes() {
+        use common_enums::ApiKeyScope;
+
+        // Unscoped callers can issue any key
+        assert!(validate_requested_scopes(None, None).is_ok());
+        assert!(validate_requested_scopes(None, Some(&[ApiKeyScope::PaymentsWrite])).is_ok());
+        assert!(validate_requested_scopes(None, Some(&[])).is_err());
+
+        let caller_scopes = [ApiKeyScope::ApiKeysWrite, ApiKeyScope::PaymentsWrite];
+
+        // Scoped callers cannot issue unscoped keys
+        assert!(validate_requested_scopes(Some(&caller_scopes), None).is_err());
+        assert!(validate_requested_scopes(Some(&caller_scopes), Some(&[])).is_err());
+
+        // Write scopes of the caller permit issuing read scopes of the same resource
+        assert!(validate_requested_scopes(
+            Some(&caller_scopes),
+            Some(&[ApiKeyScope::PaymentsRead, ApiKeyScope::PaymentsWrite])
+        )
+        .is_ok());
+
+        assert!(validate_requested_scopes(
+            Some(&caller_scopes),
+            Some(&[ApiKeyScope::PaymentsRead, ApiKeyScope::RefundsWrite])
+        )
+        .is_err());
+        assert!(validate_requested_scopes(
+            Some(&[ApiKeyScope::ApiKeysWrite, ApiKeyScope::PaymentsRead]),
+            Some(&[ApiKeyScope::PaymentsWrite])
+        )
+        .is_err());
+    }
 }
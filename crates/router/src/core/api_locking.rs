@@ -10,6 +10,21 @@ use crate::routes::{app::SessionStateInfo, lock_utils};
 
 pub const API_LOCK_PREFIX: &str = "API_LOCK";
 
+/// Acquires all the locks, unless any of them is held by another request. The keys must share a
+/// hash tag when redis runs in cluster mode.
+const ACQUIRE_LOCKS_SCRIPT: &str = r#"
+    for _, key in ipairs(KEYS) do
+        local value = redis.call("GET", key)
+        if value and value ~= ARGV[1] then
+            return 0
+        end
+    end
+    for _, key in ipairs(KEYS) do
+        redis.call("SET", key, ARGV[1], "EX", ARGV[2])
+    end
+    return 1
+"#;
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum LockStatus {
     // status when the lock is acquired by the caller
@@ -40,11 +55,13 @@ pub struct LockingInput {
 }
 
 impl LockingInput {
+    /// The locking keys of a merchant share a hash tag, so that multiple locks can be acquired
+    /// together when redis runs in cluster mode
     fn get_redis_locking_key(&self, merchant_id: &common_utils::id_type::MerchantId) -> String {
         format!(
             "{}_{}_{}_{}",
             API_LOCK_PREFIX,
-            merchant_id.get_string_repr(),
+            redis::hash_tag(merchant_id.get_string_repr()),
             self.api_identifier,
             self.unique_locking_key
         )
@@ -74,29 +91,32 @@ impl LockAction {
                     .store()
                     .get_redis_conn()
                     .change_context(errors::ApiErrorResponse::InternalServerError)?;
-                let redis_key_values = inputs
+                // Scripts are not given the tenant prefix of the connection, so it is added to the
+                // keys here
+                let redis_locking_keys = inputs
                     .iter()
-                    .map(|input| input.get_redis_locking_key(&merchant_id))
-                    .map(|key| (RedisKey::from(key.as_str()), request_id.clone()))
+                    .map(|input| redis_conn.add_prefix(&input.get_redis_locking_key(&merchant_id)))
                     .collect::<Vec<_>>();
+                let values = [
+                    request_id.unwrap_or_default(),
+                    redis_lock_expiry_seconds.to_string(),
+                ];
                 let delay_between_retries_in_milliseconds = state
                     .conf()
                     .lock_settings
                     .delay_between_retries_in_milliseconds;
                 for _retry in 0..lock_retries {
-                    let results: Vec<redis::SetGetReply<_>> = redis_conn
-                        .set_multiple_keys_if_not_exists_and_get_values(
-                            &redis_key_values,
-                            Some(i64::from(redis_lock_expiry_seconds)),
+                    // Either all the locks are acquired or none, so that concurrent requests
+                    // cannot each hold a part of the locks
+                    let is_lock_acquired = redis_conn
+                        .evaluate_redis_script::<_, u64>(
+                            ACQUIRE_LOCKS_SCRIPT,
+                            redis_locking_keys.clone(),
+                            values.to_vec(),
                         )
                         .await
                         .change_context(errors::ApiErrorResponse::InternalServerError)?;
-                    let lock_aqcuired = results.iter().all(|res| {
-                        // each redis value must match the request_id
-                        // if even 1 does match, the lock is not acquired
-                        *res.get_value() == request_id
-                    });
-                    if lock_aqcuired {
+                    if is_lock_acquired == 1 {
                         logger::info!("Lock acquired for locking inputs {:?}", inputs);
                         return Ok(());
                     } else {
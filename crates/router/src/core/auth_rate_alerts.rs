@@ -0,0 +1,694 @@
+use std::collections::{HashMap, HashSet};
+
+use api_models::{
+    analytics::{
+        payments::{PaymentDimensions, PaymentMetrics},
+        AnalyticsRequest, GetPaymentMetricRequest,
+    },
+    auth_rate_alerts as auth_rate_alerts_api,
+};
+use common_utils::{
+    crypto::{self, SignMessage},
+    date_time,
+    ext_traits::Encode,
+    pii,
+    request::RequestContent,
+    types::{authentication::AuthInfo, TimeRange},
+};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use scheduler::utils as pt_utils;
+
+use crate::{
+    analytics_validator, consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    headers,
+    routes::{metrics, SessionState},
+    services::{self, ApplicationResponse},
+    types::{
+        domain,
+        storage::{self, auth_rate_alert::AuthRateAlertTrackingData, enums},
+    },
+};
+#[cfg(feature = "email")]
+use crate::{services::email::types as email_types, utils::user as user_utils};
+
+pub const AUTH_RATE_ALERT_TASK: &str = "AUTH_RATE_ALERT";
+
+/// The payment attempts of a connector and payment method which reached the connector, and how
+/// many of them were authorized
+#[derive(Clone, Copy, Debug, Default)]
+struct AuthRate {
+    attempt_count: u64,
+    authorized_count: u64,
+}
+
+impl AuthRate {
+    /// The authorization rate in percent
+    #[allow(clippy::as_conversions)]
+    fn get_rate(&self) -> f64 {
+        if self.attempt_count == 0 {
+            return 0.0;
+        }
+        self.authorized_count as f64 * 100.0 / self.attempt_count as f64
+    }
+}
+
+/// The connector and payment method the authorization rate is evaluated for
+type AuthRateGroup = (String, String);
+
+/// Sets the authorization rate alerting of the merchant, replacing any existing alerting, and
+/// schedules the recurring evaluation of the authorization rates
+#[instrument(skip_all)]
+pub async fn upsert_auth_rate_alert_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: auth_rate_alerts_api::AuthRateAlertConfigRequest,
+) -> RouterResponse<auth_rate_alerts_api::AuthRateAlertConfigResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    if !(request.drop_threshold_percentage > 0.0 && request.drop_threshold_percentage <= 100.0) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "drop_threshold_percentage must be greater than 0 and at most 100".to_string(),
+        }));
+    }
+    let baseline_window_in_days = get_positive_window(
+        request
+            .baseline_window_in_days
+            .unwrap_or(consts::AUTH_RATE_ALERT_DEFAULT_BASELINE_WINDOW_IN_DAYS),
+        "baseline_window_in_days",
+    )?;
+    let evaluation_window_in_minutes = get_positive_window(
+        request
+            .evaluation_window_in_minutes
+            .unwrap_or(consts::AUTH_RATE_ALERT_DEFAULT_EVALUATION_WINDOW_IN_MINUTES),
+        "evaluation_window_in_minutes",
+    )?;
+    let min_attempts = i64::from(
+        request
+            .min_attempts
+            .unwrap_or(consts::AUTH_RATE_ALERT_DEFAULT_MIN_ATTEMPTS)
+            .max(1),
+    );
+    if let Some(webhook_url) = &request.webhook_url {
+        validate_webhook_url(webhook_url)?;
+    }
+    let recipients = request.recipients.unwrap_or_default();
+    if cfg!(not(feature = "email")) && !recipients.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: "Email delivery of authorization rate alerts is not enabled".to_string(),
+        }));
+    }
+    let recipients = recipients
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let is_enabled = request.is_enabled.unwrap_or(true);
+
+    let existing_config = db
+        .find_optional_auth_rate_alert_config(merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the authorization rate alert config")?;
+
+    let now = date_time::now();
+    let config = match existing_config {
+        Some(_) => db
+            .update_auth_rate_alert_config(
+                merchant_id,
+                storage::AuthRateAlertConfigUpdate {
+                    drop_threshold_percentage: request.drop_threshold_percentage,
+                    baseline_window_in_days,
+                    evaluation_window_in_minutes,
+                    min_attempts,
+                    webhook_url: request.webhook_url,
+                    recipients,
+                    is_enabled,
+                    modified_at: now,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the authorization rate alert config")?,
+        None => db
+            .insert_auth_rate_alert_config(storage::AuthRateAlertConfigNew {
+                merchant_id: merchant_id.clone(),
+                drop_threshold_percentage: request.drop_threshold_percentage,
+                baseline_window_in_days,
+                evaluation_window_in_minutes,
+                min_attempts,
+                webhook_url: request.webhook_url,
+                recipients,
+                is_enabled,
+                created_at: now,
+                modified_at: now,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the authorization rate alert config")?,
+    };
+
+    add_auth_rate_alert_task(db, &config).await?;
+
+    Ok(ApplicationResponse::Json(get_config_response(config)))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_auth_rate_alert_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+) -> RouterResponse<auth_rate_alerts_api::AuthRateAlertConfigResponse> {
+    let config = state
+        .store
+        .find_optional_auth_rate_alert_config(merchant_context.get_merchant_account().get_id())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the authorization rate alert config")?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Authorization rate alert config does not exist for the merchant".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(get_config_response(config)))
+}
+
+#[instrument(skip_all)]
+pub async fn list_auth_rate_alerts(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    query: auth_rate_alerts_api::AuthRateAlertListQuery,
+) -> RouterResponse<auth_rate_alerts_api::AuthRateAlertListResponse> {
+    let limit = query
+        .limit
+        .map(i64::from)
+        .unwrap_or(consts::AUTH_RATE_ALERTS_DEFAULT_LIMIT)
+        .min(consts::AUTH_RATE_ALERTS_MAX_LIMIT);
+    let offset = query.offset.map(i64::from).unwrap_or_default();
+
+    let alerts = state
+        .store
+        .list_auth_rate_alerts(
+            merchant_context.get_merchant_account().get_id(),
+            query.status,
+            limit,
+            offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the authorization rate alerts")?;
+
+    let data = alerts
+        .into_iter()
+        .map(get_alert_response)
+        .collect::<Vec<_>>();
+
+    Ok(ApplicationResponse::Json(
+        auth_rate_alerts_api::AuthRateAlertListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+/// Evaluates the authorization rate of each connector and payment method of the merchant over the
+/// last evaluation window against its baseline, the rate over the baseline window before it. An
+/// alert is raised for a rate which dropped below its baseline by at least the threshold, and an
+/// active alert is resolved once its rate recovers. A rate with fewer attempts than the minimum,
+/// in either window, is not evaluated and its alert is left as is.
+#[instrument(skip_all)]
+pub async fn evaluate_auth_rates(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    config: &storage::AuthRateAlertConfig,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let now = date_time::now();
+    let evaluation_start_time =
+        now - time::Duration::minutes(i64::from(config.evaluation_window_in_minutes));
+    let baseline_start_time =
+        evaluation_start_time - time::Duration::days(i64::from(config.baseline_window_in_days));
+
+    let current_rates = get_auth_rates(
+        state,
+        merchant_account,
+        TimeRange {
+            start_time: evaluation_start_time,
+            end_time: Some(now),
+        },
+    )
+    .await?;
+    let baseline_rates = get_auth_rates(
+        state,
+        merchant_account,
+        TimeRange {
+            start_time: baseline_start_time,
+            end_time: Some(evaluation_start_time),
+        },
+    )
+    .await?;
+    let mut active_alerts = db
+        .find_active_auth_rate_alerts(&config.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the active authorization rate alerts")?
+        .into_iter()
+        .map(|alert| {
+            (
+                (alert.connector.clone(), alert.payment_method.clone()),
+                alert,
+            )
+        })
+        .collect::<HashMap<_, _>>();
+    let min_attempts = u64::try_from(config.min_attempts).unwrap_or_default();
+    let payment_response_hash_key = match config.webhook_url {
+        Some(_) => get_alert_signing_key(state, merchant_account, key_store).await?,
+        None => None,
+    };
+
+    for (group, current_rate) in current_rates {
+        let Some(baseline_rate) = baseline_rates.get(&group).filter(|baseline_rate| {
+            baseline_rate.attempt_count >= min_attempts
+                && current_rate.attempt_count >= min_attempts
+        }) else {
+            continue;
+        };
+        let is_breaching =
+            baseline_rate.get_rate() - current_rate.get_rate() >= config.drop_threshold_percentage;
+        let attempt_count = i64::try_from(current_rate.attempt_count).unwrap_or(i64::MAX);
+
+        let alert = match (is_breaching, active_alerts.remove(&group)) {
+            (true, None) => db
+                .insert_auth_rate_alert(storage::AuthRateAlertNew {
+                    id: common_utils::generate_time_ordered_id("auth_rate_alert"),
+                    merchant_id: config.merchant_id.clone(),
+                    connector: group.0,
+                    payment_method: group.1,
+                    status: enums::AuthRateAlertStatus::Active,
+                    baseline_rate: baseline_rate.get_rate(),
+                    current_rate: current_rate.get_rate(),
+                    attempt_count,
+                    triggered_at: now,
+                    resolved_at: None,
+                    modified_at: now,
+                })
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert the authorization rate alert")?,
+            (false, Some(alert)) => db
+                .update_auth_rate_alert(
+                    &config.merchant_id,
+                    &alert.id,
+                    storage::AuthRateAlertUpdate {
+                        status: enums::AuthRateAlertStatus::Resolved,
+                        current_rate: current_rate.get_rate(),
+                        attempt_count,
+                        resolved_at: Some(now),
+                        modified_at: now,
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to resolve the authorization rate alert")?,
+            (true, Some(alert)) => {
+                // The alert is kept up to date with the latest rate, without notifying again
+                db.update_auth_rate_alert(
+                    &config.merchant_id,
+                    &alert.id,
+                    storage::AuthRateAlertUpdate {
+                        status: enums::AuthRateAlertStatus::Active,
+                        current_rate: current_rate.get_rate(),
+                        attempt_count,
+                        resolved_at: None,
+                        modified_at: now,
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update the authorization rate alert")?;
+                continue;
+            }
+            (false, None) => continue,
+        };
+
+        logger::info!(
+            auth_rate_alert_id = %alert.id,
+            connector = %alert.connector,
+            payment_method = %alert.payment_method,
+            status = %alert.status,
+            baseline_rate = alert.baseline_rate,
+            current_rate = alert.current_rate,
+            "Authorization rate alert changed status"
+        );
+        notify_auth_rate_alert(state, config, payment_response_hash_key.as_deref(), alert).await;
+    }
+
+    Ok(())
+}
+
+/// Fetches the authorization rate of each connector and payment method over the time range, from
+/// the payment attempts grouped by their status. The attempts which did not reach an outcome are
+/// left out, so that the payments in flight do not lower the rate.
+async fn get_auth_rates(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    time_range: TimeRange,
+) -> RouterResult<HashMap<AuthRateGroup, AuthRate>> {
+    let request = GetPaymentMetricRequest {
+        time_series: None,
+        time_range,
+        group_by_names: vec![
+            PaymentDimensions::Connector,
+            PaymentDimensions::PaymentMethod,
+            PaymentDimensions::PaymentStatus,
+        ],
+        filters: Default::default(),
+        metrics: HashSet::from([PaymentMetrics::PaymentCount]),
+        distribution: None,
+        delta: false,
+    };
+    let auth = AuthInfo::MerchantLevel {
+        org_id: merchant_account.get_org_id().clone(),
+        merchant_ids: vec![merchant_account.get_id().clone()],
+    };
+    let ex_rates = analytics_validator::request_validator(
+        AnalyticsRequest {
+            payment_attempt: Some(request.clone()),
+            ..Default::default()
+        },
+        state,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let buckets = analytics::payments::get_metrics(&state.pool, &ex_rates, &auth, request)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payment attempts by status")?
+        .query_data;
+
+    let mut auth_rates = HashMap::<AuthRateGroup, AuthRate>::new();
+    for bucket in buckets {
+        let (Some(connector), Some(payment_method), Some(status)) = (
+            bucket.dimensions.connector,
+            bucket.dimensions.payment_method,
+            bucket.dimensions.status,
+        ) else {
+            continue;
+        };
+        let Some(is_authorized) = get_authorization_outcome(status) else {
+            continue;
+        };
+        let count = bucket.values.payment_count.unwrap_or_default();
+        let auth_rate = auth_rates.entry((connector, payment_method)).or_default();
+        auth_rate.attempt_count += count;
+        if is_authorized {
+            auth_rate.authorized_count += count;
+        }
+    }
+
+    Ok(auth_rates)
+}
+
+/// Whether a payment attempt in the status was authorized, or `None` when the attempt did not
+/// reach an authorization outcome
+fn get_authorization_outcome(status: enums::AttemptStatus) -> Option<bool> {
+    match status {
+        enums::AttemptStatus::Authorized
+        | enums::AttemptStatus::PartiallyAuthorized
+        | enums::AttemptStatus::Charged
+        | enums::AttemptStatus::PartialCharged
+        | enums::AttemptStatus::PartialChargedAndChargeable
+        | enums::AttemptStatus::CaptureInitiated
+        | enums::AttemptStatus::CaptureFailed
+        | enums::AttemptStatus::Voided
+        | enums::AttemptStatus::VoidedPostCharge
+        | enums::AttemptStatus::VoidInitiated
+        | enums::AttemptStatus::VoidFailed
+        | enums::AttemptStatus::AutoRefunded => Some(true),
+        enums::AttemptStatus::AuthorizationFailed | enums::AttemptStatus::Failure => Some(false),
+        _ => None,
+    }
+}
+
+/// Gets the key the alerts posted to the webhook URL are signed with, which is the payment
+/// response hash key of the default profile of the merchant, as for outgoing webhooks
+async fn get_alert_signing_key(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<Option<String>> {
+    let Some(profile_id) = merchant_account.default_profile.as_ref() else {
+        return Ok(None);
+    };
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&state.into(), key_store, profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    Ok(business_profile.payment_response_hash_key)
+}
+
+/// Posts the alert to the webhook URL and emails it to the recipients of the alerting. A failure
+/// to notify is logged rather than returned, so that the other alerts are still evaluated.
+async fn notify_auth_rate_alert(
+    state: &SessionState,
+    config: &storage::AuthRateAlertConfig,
+    payment_response_hash_key: Option<&str>,
+    alert: storage::AuthRateAlert,
+) {
+    let alert = get_alert_response(alert);
+
+    if let Some(webhook_url) = &config.webhook_url {
+        if let Err(error) =
+            post_auth_rate_alert(state, webhook_url, payment_response_hash_key, &alert).await
+        {
+            logger::error!(?error, "Failed to post the authorization rate alert");
+        }
+    }
+
+    send_auth_rate_alert_emails(state, &alert, &get_recipients(&config.recipients)).await;
+}
+
+/// Posts the alert signed with the key, in the signature header of the outgoing webhooks. Alerts
+/// are not posted unsigned, as the merchant couldn't verify where they come from.
+async fn post_auth_rate_alert(
+    state: &SessionState,
+    webhook_url: &str,
+    payment_response_hash_key: Option<&str>,
+    alert: &auth_rate_alerts_api::AuthRateAlertResponse,
+) -> RouterResult<()> {
+    let payment_response_hash_key =
+        payment_response_hash_key.ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The default profile of the merchant has no payment response hash key to \
+                      sign the authorization rate alerts with"
+                .to_string(),
+        })?;
+    let payload = alert
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the authorization rate alert")?;
+    let signature = crypto::HmacSha512
+        .sign_message(payment_response_hash_key.as_bytes(), payload.as_bytes())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to sign the authorization rate alert")?;
+
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(webhook_url)
+        .attach_default_headers()
+        .header(headers::CONTENT_TYPE, mime::APPLICATION_JSON.essence_str())
+        .header(headers::X_WEBHOOK_SIGNATURE, &hex::encode(signature))
+        .set_body(RequestContent::RawBytes(payload.into_bytes()))
+        .build();
+
+    let response = state
+        .api_client
+        .send_request(
+            state,
+            request,
+            Some(consts::AUTH_RATE_ALERT_WEBHOOK_TIMEOUT_IN_SECONDS),
+            false,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to send the authorization rate alert to the webhook URL")?;
+
+    if !response.status().is_success() {
+        return Err(report!(errors::ApiErrorResponse::InternalServerError)).attach_printable(
+            format!(
+                "The webhook URL responded to the authorization rate alert with status {}",
+                response.status()
+            ),
+        );
+    }
+    Ok(())
+}
+
+#[cfg(feature = "email")]
+async fn send_auth_rate_alert_emails(
+    state: &SessionState,
+    alert: &auth_rate_alerts_api::AuthRateAlertResponse,
+    recipients: &[pii::Email],
+) {
+    for recipient in recipients {
+        let recipient_email = match domain::UserEmail::from_pii_email(recipient.clone()) {
+            Ok(recipient_email) => recipient_email,
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    "Failed to convert the recipient of the authorization rate alert"
+                );
+                continue;
+            }
+        };
+        let email_contents = email_types::AuthRateAlert {
+            recipient_email,
+            alert_id: alert.alert_id.clone(),
+            status: alert.status,
+            connector: alert.connector.clone(),
+            payment_method: alert.payment_method.clone(),
+            baseline_rate: alert.baseline_rate,
+            current_rate: alert.current_rate,
+            attempt_count: alert.attempt_count,
+        };
+
+        if let Err(error) = state
+            .email_client
+            .clone()
+            .compose_and_send_email(
+                user_utils::get_base_url(state),
+                Box::new(email_contents),
+                state.conf.proxy.https_url.as_ref(),
+            )
+            .await
+        {
+            logger::error!(?error, "Failed to send the authorization rate alert email");
+        }
+    }
+}
+
+#[cfg(not(feature = "email"))]
+async fn send_auth_rate_alert_emails(
+    _state: &SessionState,
+    _alert: &auth_rate_alerts_api::AuthRateAlertResponse,
+    recipients: &[pii::Email],
+) {
+    if !recipients.is_empty() {
+        logger::error!("Email delivery of authorization rate alerts is not enabled");
+    }
+}
+
+/// The next evaluation of the authorization rates, at the end of the current evaluation window
+pub fn get_next_evaluation_time(
+    config: &storage::AuthRateAlertConfig,
+    now: time::PrimitiveDateTime,
+) -> time::PrimitiveDateTime {
+    now + time::Duration::minutes(i64::from(config.evaluation_window_in_minutes))
+}
+
+/// Schedules the recurring evaluation of the authorization rates. A config which is updated keeps
+/// its task, which picks up the latest config when it runs.
+async fn add_auth_rate_alert_task(
+    db: &dyn StorageInterface,
+    config: &storage::AuthRateAlertConfig,
+) -> RouterResult<()> {
+    let merchant_id = &config.merchant_id;
+    let runner = storage::ProcessTrackerRunner::AuthRateAlertWorkflow;
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        AUTH_RATE_ALERT_TASK,
+        merchant_id.get_string_repr(),
+        merchant_id,
+    );
+    let tracking_data = AuthRateAlertTrackingData {
+        merchant_id: merchant_id.clone(),
+    };
+    let schedule_time = get_next_evaluation_time(config, date_time::now());
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        AUTH_RATE_ALERT_TASK,
+        runner,
+        ["AUTH_RATE_ALERT"],
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct authorization rate alert process tracker task")?;
+
+    match db.insert_process(process_tracker_entry).await {
+        Ok(_) => {
+            metrics::TASKS_ADDED_COUNT
+                .add(1, router_env::metric_attributes!(("flow", "AuthRateAlert")));
+            Ok(())
+        }
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to add authorization rate alert task to process tracker"),
+    }
+}
+
+fn get_positive_window(window: u16, field_name: &str) -> RouterResult<i32> {
+    if window == 0 {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("{field_name} must be greater than 0"),
+        }));
+    }
+    Ok(i32::from(window))
+}
+
+fn validate_webhook_url(webhook_url: &str) -> RouterResult<()> {
+    let is_valid = url::Url::parse(webhook_url)
+        .map(|url| matches!(url.scheme(), "http" | "https"))
+        .unwrap_or(false);
+    if !is_valid || webhook_url.len() > 2048 {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "webhook_url must be a valid HTTP or HTTPS URL".to_string(),
+        }));
+    }
+    Ok(())
+}
+
+fn get_recipients(value: &serde_json::Value) -> Vec<pii::Email> {
+    serde_json::from_value(value.clone()).unwrap_or_default()
+}
+
+fn get_config_response(
+    config: storage::AuthRateAlertConfig,
+) -> auth_rate_alerts_api::AuthRateAlertConfigResponse {
+    auth_rate_alerts_api::AuthRateAlertConfigResponse {
+        recipients: get_recipients(&config.recipients),
+        merchant_id: config.merchant_id,
+        drop_threshold_percentage: config.drop_threshold_percentage,
+        baseline_window_in_days: u16::try_from(config.baseline_window_in_days).unwrap_or_default(),
+        evaluation_window_in_minutes: u16::try_from(config.evaluation_window_in_minutes)
+            .unwrap_or_default(),
+        min_attempts: u32::try_from(config.min_attempts).unwrap_or_default(),
+        webhook_url: config.webhook_url,
+        is_enabled: config.is_enabled,
+        created_at: config.created_at,
+        modified_at: config.modified_at,
+    }
+}
+
+fn get_alert_response(
+    alert: storage::AuthRateAlert,
+) -> auth_rate_alerts_api::AuthRateAlertResponse {
+    auth_rate_alerts_api::AuthRateAlertResponse {
+        alert_id: alert.id,
+        merchant_id: alert.merchant_id,
+        connector: alert.connector,
+        payment_method: alert.payment_method,
+        status: alert.status,
+        baseline_rate: alert.baseline_rate,
+        current_rate: alert.current_rate,
+        attempt_count: alert.attempt_count,
+        triggered_at: alert.triggered_at,
+        resolved_at: alert.resolved_at,
+    }
+}
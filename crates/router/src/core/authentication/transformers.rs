@@ -183,6 +183,7 @@ pub fn construct_router_data<F: Clone, Req, Res>(
         preprocessing_id: None,
         payment_method_balance: None,
         connector_api_version: None,
+        merchant_connector_id: None,
         request: request_data,
         response: Err(types::ErrorResponse::default()),
         connector_request_reference_id:
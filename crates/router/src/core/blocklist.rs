@@ -24,6 +24,20 @@ pub async fn add_entry_to_blocklist(
     .map(services::ApplicationResponse::Json)
 }
 
+pub async fn bulk_add_entries_to_blocklist(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    body: api_blocklist::BulkAddToBlocklistRequest,
+) -> RouterResponse<api_blocklist::BulkAddToBlocklistResponse> {
+    utils::bulk_insert_entries_into_blocklist(
+        &state,
+        merchant_context.get_merchant_account().get_id(),
+        body,
+    )
+    .await
+    .map(services::ApplicationResponse::Json)
+}
+
 pub async fn remove_entry_from_blocklist(
     state: SessionState,
     merchant_context: domain::MerchantContext,
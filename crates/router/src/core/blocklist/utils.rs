@@ -1,9 +1,14 @@
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    str::FromStr,
+};
+
 use api_models::blocklist as api_blocklist;
 use common_enums::MerchantDecision;
-use common_utils::errors::CustomResult;
+use common_utils::{errors::CustomResult, pii};
 use diesel_models::configs;
 use error_stack::ResultExt;
-use masking::StrongSecret;
+use masking::{PeekInterface, StrongSecret};
 
 use super::{errors, transformers::generate_fingerprint, SessionState};
 use crate::{
@@ -13,7 +18,7 @@ use crate::{
         payments::PaymentData,
     },
     logger,
-    types::{domain, storage, transformers::ForeignInto},
+    types::{domain, storage, transformers::ForeignInto, BrowserInformation},
     utils,
 };
 
@@ -22,23 +27,14 @@ pub async fn delete_entry_from_blocklist(
     merchant_id: &common_utils::id_type::MerchantId,
     request: api_blocklist::DeleteFromBlocklistRequest,
 ) -> RouterResult<api_blocklist::DeleteFromBlocklistResponse> {
-    let blocklist_entry = match request {
-        api_blocklist::DeleteFromBlocklistRequest::CardBin(bin) => {
-            delete_card_bin_blocklist_entry(state, &bin, merchant_id).await?
-        }
-
-        api_blocklist::DeleteFromBlocklistRequest::ExtendedCardBin(xbin) => {
-            delete_card_bin_blocklist_entry(state, &xbin, merchant_id).await?
-        }
-
-        api_blocklist::DeleteFromBlocklistRequest::Fingerprint(fingerprint_id) => state
-            .store
-            .delete_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, &fingerprint_id)
-            .await
-            .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
-                message: "no blocklist record for the given fingerprint id was found".to_string(),
-            })?,
-    };
+    let (data_kind, fingerprint_id) = get_blocklist_entry_data(&request)?;
+    let blocklist_entry = state
+        .store
+        .delete_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, &fingerprint_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("no blocklist record for the given {data_kind} was found"),
+        })?;
 
     Ok(blocklist_entry.foreign_into())
 }
@@ -131,73 +127,152 @@ fn validate_extended_card_bin(bin: &str) -> RouterResult<()> {
     }
 }
 
-pub async fn insert_entry_into_blocklist(
-    state: &SessionState,
-    merchant_id: &common_utils::id_type::MerchantId,
-    to_block: api_blocklist::AddToBlocklistRequest,
-) -> RouterResult<api_blocklist::AddToBlocklistResponse> {
-    let blocklist_entry = match &to_block {
-        api_blocklist::AddToBlocklistRequest::CardBin(bin) => {
+/// Emails are stored in lower case, so that they are matched case insensitively
+fn normalize_email(email: &str) -> RouterResult<String> {
+    let email = email.trim().to_lowercase();
+    pii::Email::from_str(&email).change_context(errors::ApiErrorResponse::InvalidDataFormat {
+        field_name: "data".to_string(),
+        expected_format: "a valid email address".to_string(),
+    })?;
+    Ok(email)
+}
+
+/// Networks are stored with their host bits cleared, and single addresses without a prefix
+fn normalize_ip_network(ip_network: &str) -> RouterResult<String> {
+    let (network, prefix_length) =
+        parse_ip_network(ip_network.trim()).ok_or(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "data".to_string(),
+            expected_format: "an IP address or a network in CIDR notation".to_string(),
+        })?;
+    Ok(match network {
+        IpAddr::V4(_) if prefix_length == 32 => network.to_string(),
+        IpAddr::V6(_) if prefix_length == 128 => network.to_string(),
+        _ => format!("{network}/{prefix_length}"),
+    })
+}
+
+fn validate_customer_id(customer_id: &str) -> RouterResult<String> {
+    common_utils::id_type::CustomerId::try_from(std::borrow::Cow::from(customer_id.to_owned()))
+        .change_context(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "data".to_string(),
+            expected_format: "a valid customer id".to_string(),
+        })
+        .map(|customer_id| customer_id.get_string_repr().to_owned())
+}
+
+/// Returns the kind of the entry and the value stored as its fingerprint
+fn get_blocklist_entry_data(
+    entry: &api_blocklist::BlocklistRequest,
+) -> RouterResult<(common_enums::BlocklistDataKind, String)> {
+    match entry {
+        api_blocklist::BlocklistRequest::CardBin(bin) => {
             validate_card_bin(bin)?;
-            duplicate_check_insert_bin(
-                bin,
-                state,
-                merchant_id,
-                common_enums::BlocklistDataKind::CardBin,
-            )
-            .await?
+            Ok((common_enums::BlocklistDataKind::CardBin, bin.clone()))
         }
-
-        api_blocklist::AddToBlocklistRequest::ExtendedCardBin(bin) => {
+        api_blocklist::BlocklistRequest::ExtendedCardBin(bin) => {
             validate_extended_card_bin(bin)?;
-            duplicate_check_insert_bin(
-                bin,
-                state,
-                merchant_id,
+            Ok((
                 common_enums::BlocklistDataKind::ExtendedCardBin,
-            )
-            .await?
+                bin.clone(),
+            ))
         }
+        api_blocklist::BlocklistRequest::Fingerprint(fingerprint_id) => Ok((
+            common_enums::BlocklistDataKind::PaymentMethod,
+            fingerprint_id.clone(),
+        )),
+        api_blocklist::BlocklistRequest::Email(email) => Ok((
+            common_enums::BlocklistDataKind::Email,
+            normalize_email(email)?,
+        )),
+        api_blocklist::BlocklistRequest::IpAddress(ip_network) => Ok((
+            common_enums::BlocklistDataKind::IpAddress,
+            normalize_ip_network(ip_network)?,
+        )),
+        api_blocklist::BlocklistRequest::CustomerId(customer_id) => Ok((
+            common_enums::BlocklistDataKind::CustomerId,
+            validate_customer_id(customer_id)?,
+        )),
+    }
+}
 
-        api_blocklist::AddToBlocklistRequest::Fingerprint(fingerprint_id) => {
-            let blocklist_entry_result = state
-                .store
-                .find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, fingerprint_id)
-                .await;
-
-            match blocklist_entry_result {
-                Ok(_) => {
-                    return Err(errors::ApiErrorResponse::PreconditionFailed {
-                        message: "data associated with the given fingerprint is already blocked"
-                            .to_string(),
-                    }
-                    .into());
-                }
-
-                // if it is a db not found error, we can proceed as normal
-                Err(inner) if inner.current_context().is_db_not_found() => {}
+pub async fn insert_entry_into_blocklist(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    to_block: api_blocklist::AddToBlocklistRequest,
+) -> RouterResult<api_blocklist::AddToBlocklistResponse> {
+    let (data_kind, fingerprint_id) = get_blocklist_entry_data(&to_block.entry)?;
+    utils::when(
+        to_block
+            .expires_at
+            .is_some_and(|expires_at| expires_at <= common_utils::date_time::now()),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "expires_at must be in the future".to_string(),
+            })
+        },
+    )?;
+
+    let blocklist_entry = duplicate_check_insert_entry(
+        state,
+        merchant_id,
+        &fingerprint_id,
+        data_kind,
+        to_block.expires_at,
+    )
+    .await?;
+    Ok(blocklist_entry.foreign_into())
+}
 
-                err @ Err(_) => {
-                    err.change_context(errors::ApiErrorResponse::InternalServerError)
-                        .attach_printable("error fetching blocklist entry from table")?;
-                }
-            }
+/// Adds every entry independently, so that invalid or duplicate entries don't prevent the other
+/// entries from being added
+pub async fn bulk_insert_entries_into_blocklist(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    request: api_blocklist::BulkAddToBlocklistRequest,
+) -> RouterResult<api_blocklist::BulkAddToBlocklistResponse> {
+    utils::when(request.entries.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "entries can't be empty".to_string(),
+        })
+    })?;
+    utils::when(
+        request.entries.len() > consts::BLOCKLIST_BULK_ADD_MAX_ENTRIES,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "at most {} entries can be added at once",
+                    consts::BLOCKLIST_BULK_ADD_MAX_ENTRIES
+                ),
+            })
+        },
+    )?;
+
+    let mut results = Vec::with_capacity(request.entries.len());
+    for (index, entry) in request.entries.into_iter().enumerate() {
+        let result = insert_entry_into_blocklist(state, merchant_id, entry).await;
+        results.push(match result {
+            Ok(entry) => api_blocklist::BulkAddToBlocklistResult {
+                index,
+                entry: Some(entry),
+                error_message: None,
+            },
+            Err(error) => api_blocklist::BulkAddToBlocklistResult {
+                index,
+                entry: None,
+                error_message: Some(error.current_context().error_message()),
+            },
+        });
+    }
 
-            state
-                .store
-                .insert_blocklist_entry(storage::BlocklistNew {
-                    merchant_id: merchant_id.to_owned(),
-                    fingerprint_id: fingerprint_id.clone(),
-                    data_kind: api_models::enums::enums::BlocklistDataKind::PaymentMethod,
-                    metadata: None,
-                    created_at: common_utils::date_time::now(),
-                })
-                .await
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("failed to add fingerprint to blocklist")?
-        }
-    };
-    Ok(blocklist_entry.foreign_into())
+    let added_count = results
+        .iter()
+        .filter(|result| result.entry.is_some())
+        .count();
+    Ok(api_blocklist::BulkAddToBlocklistResponse {
+        added_count,
+        failed_count: results.len() - added_count,
+        results,
+    })
 }
 
 pub async fn get_merchant_fingerprint_secret(
@@ -234,25 +309,37 @@ pub async fn get_merchant_fingerprint_secret(
     }
 }
 
-async fn duplicate_check_insert_bin(
-    bin: &str,
+async fn duplicate_check_insert_entry(
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
+    fingerprint_id: &str,
     data_kind: common_enums::BlocklistDataKind,
+    expires_at: Option<time::PrimitiveDateTime>,
 ) -> RouterResult<storage::Blocklist> {
     let blocklist_entry_result = state
         .store
-        .find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, bin)
+        .find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, fingerprint_id)
         .await;
 
     match blocklist_entry_result {
-        Ok(_) => {
+        Ok(blocklist_entry) if blocklist_entry.is_active() => {
             return Err(errors::ApiErrorResponse::PreconditionFailed {
-                message: "provided bin is already blocked".to_string(),
+                message: format!("provided {data_kind} is already blocked"),
             }
             .into());
         }
 
+        // An expired entry is replaced by the new one
+        Ok(_) => {
+            state
+                .store
+                .delete_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, fingerprint_id)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("unable to delete expired blocklist entry")?;
+        }
+
+        // if it is a db not found error, we can proceed as normal
         Err(e) if e.current_context().is_db_not_found() => {}
 
         err @ Err(_) => {
@@ -266,28 +353,15 @@ async fn duplicate_check_insert_bin(
         .store
         .insert_blocklist_entry(storage::BlocklistNew {
             merchant_id: merchant_id.to_owned(),
-            fingerprint_id: bin.to_string(),
+            fingerprint_id: fingerprint_id.to_string(),
             data_kind,
             metadata: None,
             created_at: common_utils::date_time::now(),
+            expires_at,
         })
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("error inserting pm blocklist item")
-}
-
-async fn delete_card_bin_blocklist_entry(
-    state: &SessionState,
-    bin: &str,
-    merchant_id: &common_utils::id_type::MerchantId,
-) -> RouterResult<storage::Blocklist> {
-    state
-        .store
-        .delete_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, bin)
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
-            message: "could not find a blocklist entry for the given bin".to_string(),
-        })
+        .attach_printable("error inserting blocklist item")
 }
 
 pub async fn validate_data_for_blocklist<F>(
@@ -347,45 +421,77 @@ where
                 _ => None,
             });
 
-    //validating the payment method.
-    let mut blocklist_futures = Vec::new();
-    if let Some(card_number_fingerprint) = card_number_fingerprint.as_ref() {
-        blocklist_futures.push(db.find_blocklist_entry_by_merchant_id_fingerprint_id(
-            merchant_id,
-            card_number_fingerprint,
-        ));
-    }
-
-    if let Some(card_bin_fingerprint) = card_bin_fingerprint.as_ref() {
-        blocklist_futures.push(
-            db.find_blocklist_entry_by_merchant_id_fingerprint_id(
-                merchant_id,
-                card_bin_fingerprint,
-            ),
-        );
-    }
+    let email = payment_data
+        .email
+        .as_ref()
+        .map(|email| email.peek().trim().to_lowercase());
+    let customer_id = payment_data
+        .payment_intent
+        .customer_id
+        .as_ref()
+        .map(|customer_id| customer_id.get_string_repr().to_owned());
 
-    if let Some(extended_card_bin_fingerprint) = extended_card_bin_fingerprint.as_ref() {
-        blocklist_futures.push(db.find_blocklist_entry_by_merchant_id_fingerprint_id(
-            merchant_id,
+    //validating the payment method and the customer.
+    let blocklist_fingerprints = [
+        (
+            common_enums::BlocklistDataKind::PaymentMethod,
+            card_number_fingerprint,
+        ),
+        (
+            common_enums::BlocklistDataKind::CardBin,
+            card_bin_fingerprint,
+        ),
+        (
+            common_enums::BlocklistDataKind::ExtendedCardBin,
             extended_card_bin_fingerprint,
-        ));
-    }
+        ),
+        (common_enums::BlocklistDataKind::Email, email),
+        (common_enums::BlocklistDataKind::CustomerId, customer_id),
+    ]
+    .into_iter()
+    .filter_map(|(data_kind, fingerprint)| fingerprint.map(|fingerprint| (data_kind, fingerprint)))
+    .collect::<Vec<_>>();
+
+    let blocklist_futures = blocklist_fingerprints
+        .iter()
+        .map(|(_, fingerprint)| {
+            db.find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, fingerprint)
+        })
+        .collect::<Vec<_>>();
 
     let blocklist_lookups = futures::future::join_all(blocklist_futures).await;
 
-    let mut should_payment_be_blocked = false;
-    for lookup in blocklist_lookups {
+    let mut matched_entry = None;
+    for ((data_kind, _), lookup) in blocklist_fingerprints.iter().zip(blocklist_lookups) {
         match lookup {
-            Ok(_) => {
-                should_payment_be_blocked = true;
+            // Values of different kinds can be equal, a customer id and a card bin for instance
+            Ok(entry) if entry.data_kind == *data_kind && entry.is_active() => {
+                matched_entry.get_or_insert(entry);
             }
+            Ok(_) => {}
+            Err(e) if e.current_context().is_db_not_found() => {}
             Err(e) => {
                 logger::error!(blocklist_db_error=?e, "failed db operations for blocklist");
             }
         }
     }
-    if should_payment_be_blocked {
+
+    if matched_entry.is_none() {
+        if let Some(ip_address) = get_browser_ip_address(&payment_data.payment_attempt) {
+            matched_entry = find_blocked_ip_network(state, merchant_id, ip_address).await;
+        }
+    }
+
+    if let Some(matched_entry) = matched_entry {
+        let block_reason = format!(
+            "This payment is blocked as its {} matched a blocklist entry",
+            matched_entry.data_kind
+        );
+        logger::info!(
+            data_kind = %matched_entry.data_kind,
+            "Payment matched a blocklist entry"
+        );
+
         // Update db for attempt and intent status.
         db.update_payment_intent(
             &state.into(),
@@ -411,7 +517,7 @@ where
         let attempt_update = storage::PaymentAttemptUpdate::BlocklistUpdate {
             status: common_enums::AttemptStatus::Failure,
             error_code: Some(Some("HE-03".to_string())),
-            error_message: Some(Some("This payment method is blocked".to_string())),
+            error_message: Some(Some(block_reason.clone())),
             updated_by: merchant_context
                 .get_merchant_account()
                 .storage_scheme
@@ -430,9 +536,9 @@ where
 
         Err(errors::ApiErrorResponse::PaymentBlockedError {
             code: 200,
-            message: "This payment method is blocked".to_string(),
+            message: block_reason,
             status: "Failed".to_string(),
-            reason: "Blocked".to_string(),
+            reason: format!("Blocked by {} entry", matched_entry.data_kind),
         }
         .into())
     } else {
@@ -477,3 +583,147 @@ pub async fn generate_payment_fingerprint(
         },
     )
 }
+
+fn get_browser_ip_address(payment_attempt: &storage::PaymentAttempt) -> Option<IpAddr> {
+    payment_attempt
+        .browser_info
+        .clone()
+        .and_then(|browser_info| {
+            serde_json::from_value::<BrowserInformation>(browser_info)
+                .map_err(|error| logger::warn!(?error, "Failed to parse the browser information"))
+                .ok()
+        })
+        .and_then(|browser_info| browser_info.ip_address)
+}
+
+/// IP entries can be networks, hence the address is checked against all of them rather than
+/// being looked up
+async fn find_blocked_ip_network(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    ip_address: IpAddr,
+) -> Option<storage::Blocklist> {
+    state
+        .store
+        .list_all_blocklist_entries_by_merchant_id_data_kind(
+            merchant_id,
+            common_enums::BlocklistDataKind::IpAddress,
+        )
+        .await
+        .map_err(|e| logger::error!(blocklist_db_error=?e, "failed db operations for blocklist"))
+        .ok()?
+        .into_iter()
+        .find(|entry| {
+            entry.is_active()
+                && parse_ip_network(&entry.fingerprint_id).is_some_and(
+                    |(network, prefix_length)| {
+                        is_ip_address_in_network(ip_address, network, prefix_length)
+                    },
+                )
+        })
+}
+
+/// Parses an IP address or a network in CIDR notation, into the network address with its host
+/// bits cleared and the prefix length
+fn parse_ip_network(ip_network: &str) -> Option<(IpAddr, u8)> {
+    let (address, prefix_length) = match ip_network.split_once('/') {
+        Some((address, prefix_length)) => (
+            address.parse::<IpAddr>().ok()?,
+            Some(prefix_length.parse::<u8>().ok()?),
+        ),
+        None => (ip_network.parse::<IpAddr>().ok()?, None),
+    };
+    match address {
+        IpAddr::V4(address) => {
+            let prefix_length = prefix_length.unwrap_or(32);
+            (prefix_length <= 32).then(|| {
+                let mask = u32::MAX
+                    .checked_shl(32 - u32::from(prefix_length))
+                    .unwrap_or(0);
+                (
+                    IpAddr::V4(Ipv4Addr::from(u32::from(address) & mask)),
+                    prefix_length,
+                )
+            })
+        }
+        IpAddr::V6(address) => {
+            let prefix_length = prefix_length.unwrap_or(128);
+            (prefix_length <= 128).then(|| {
+                let mask = u128::MAX
+                    .checked_shl(128 - u32::from(prefix_length))
+                    .unwrap_or(0);
+                (
+                    IpAddr::V6(Ipv6Addr::from(u128::from(address) & mask)),
+                    prefix_length,
+                )
+            })
+        }
+    }
+}
+
+fn is_ip_address_in_network(ip_address: IpAddr, network: IpAddr, prefix_length: u8) -> bool {
+    let ip_address = match (ip_address, network) {
+        (IpAddr::V6(address), IpAddr::V4(_)) => address
+            .to_ipv4_mapped()
+            .map(IpAddr::V4)
+            .unwrap_or(ip_address),
+        _ => ip_address,
+    };
+    match (ip_address, network) {
+        (IpAddr::V4(_), IpAddr::V4(_)) | (IpAddr::V6(_), IpAddr::V6(_)) => {
+            parse_ip_network(&format!("{ip_address}/{prefix_length}"))
+                .is_some_and(|(address_network, _)| address_network == network)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_ip_network() {
+        assert_eq!(normalize_ip_network("10.1.2.3").unwrap(), "10.1.2.3");
+        assert_eq!(normalize_ip_network("10.1.2.3/8").unwrap(), "10.0.0.0/8");
+        assert_eq!(
+            normalize_ip_network("2001:db8::1/32").unwrap(),
+            "2001:db8::/32"
+        );
+        assert!(normalize_ip_network("10.1.2.3/33").is_err());
+        assert!(normalize_ip_network("not an ip").is_err());
+    }
+
+    #[test]
+    fn test_is_ip_address_in_network() {
+        let (network, prefix_length) = parse_ip_network("192.168.0.0/16").unwrap();
+        let ip_address = |address: &str| address.parse::<IpAddr>().unwrap();
+        assert!(is_ip_address_in_network(
+            ip_address("192.168.10.20"),
+            network,
+            prefix_length
+        ));
+        assert!(!is_ip_address_in_network(
+            ip_address("192.169.0.1"),
+            network,
+            prefix_length
+        ));
+        assert!(is_ip_address_in_network(
+            ip_address("::ffff:192.168.1.1"),
+            network,
+            prefix_length
+        ));
+
+        let (network, prefix_length) = parse_ip_network("203.0.113.7").unwrap();
+        assert!(is_ip_address_in_network(
+            ip_address("203.0.113.7"),
+            network,
+            prefix_length
+        ));
+        assert!(!is_ip_address_in_network(
+            ip_address("203.0.113.8"),
+            network,
+            prefix_length
+        ));
+    }
+}
@@ -1,27 +1,28 @@
 use actix_multipart::form::{bytes::Bytes, MultipartForm};
 use api_models::cards_info as cards_info_api_types;
-use common_utils::fp_utils::when;
+use common_utils::{ext_traits::BytesExt, fp_utils::when};
 use csv::Reader;
 use diesel_models::cards_info as card_info_models;
 use error_stack::{report, ResultExt};
 use hyperswitch_domain_models::cards_info;
+use masking::{Mask, PeekInterface};
 use rdkafka::message::ToBytes;
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 
 use crate::{
     core::{
         errors::{self, RouterResponse, RouterResult, StorageErrorExt},
         payments::helpers,
     },
-    routes,
-    services::ApplicationResponse,
+    headers, routes,
+    services::{self, ApplicationResponse},
     types::{
         domain,
         transformers::{ForeignFrom, ForeignInto},
     },
 };
 
-fn verify_iin_length(card_iin: &str) -> Result<(), errors::ApiErrorResponse> {
+pub fn verify_iin_length(card_iin: &str) -> Result<(), errors::ApiErrorResponse> {
     let is_bin_length_in_range = card_iin.len() == 6 || card_iin.len() == 8;
     when(!is_bin_length_in_range, || {
         Err(errors::ApiErrorResponse::InvalidCardIinLength)
@@ -44,11 +45,8 @@ pub async fn retrieve_card_info(
     )
     .await?;
 
-    let card_info = db
-        .get_card_info(&request.card_iin)
-        .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to retrieve card information")?
+    let card_info = get_card_info_for_bin(&state, &request.card_iin)
+        .await?
         .ok_or(report!(errors::ApiErrorResponse::InvalidCardIin))?;
 
     Ok(ApplicationResponse::Json(
@@ -56,6 +54,138 @@ pub async fn retrieve_card_info(
     ))
 }
 
+const BIN_LOOKUP_PROVIDER: &str = "bin_lookup_provider";
+
+/// BIN metadata returned by the external BIN lookup provider
+#[derive(Debug, serde::Deserialize)]
+struct BinLookupProviderResponse {
+    card_issuer: Option<String>,
+    card_network: Option<common_enums::CardNetwork>,
+    card_type: Option<String>,
+    card_subtype: Option<String>,
+    card_issuing_country: Option<String>,
+    bank_code_id: Option<String>,
+    bank_code: Option<String>,
+    country_code: Option<String>,
+}
+
+/// Retrieves the metadata of a card BIN from the cards info table. If the BIN lookup provider is
+/// enabled and the BIN is unknown or its record is stale, the metadata is fetched from the provider
+/// and stored, falling back to the stored record if the provider cannot be reached.
+#[instrument(skip_all)]
+pub async fn get_card_info_for_bin(
+    state: &routes::SessionState,
+    card_iin: &str,
+) -> RouterResult<Option<card_info_models::CardInfo>> {
+    let db = state.store.as_ref();
+    let card_info = db
+        .get_card_info(card_iin)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve card information")?;
+
+    let bin_lookup = &state.conf.bin_lookup;
+    let refresh_before = common_utils::date_time::now()
+        .saturating_sub(time::Duration::days(bin_lookup.refresh_after_days));
+    let is_refresh_required = card_info.as_ref().is_none_or(|card_info| {
+        card_info.last_updated.unwrap_or(card_info.date_created) < refresh_before
+    });
+
+    if !bin_lookup.enabled || !is_refresh_required {
+        return Ok(card_info);
+    }
+
+    let provider_response = match fetch_card_info_from_provider(state, card_iin).await {
+        Ok(provider_response) => provider_response,
+        Err(error) => {
+            logger::warn!(bin_lookup_provider_error=?error);
+            return Ok(card_info);
+        }
+    };
+
+    let card_info_update = card_info_models::UpdateCardInfo {
+        card_issuer: provider_response.card_issuer,
+        card_network: provider_response.card_network,
+        card_type: provider_response.card_type,
+        card_subtype: provider_response.card_subtype,
+        card_issuing_country: provider_response.card_issuing_country,
+        bank_code_id: provider_response.bank_code_id,
+        bank_code: provider_response.bank_code,
+        country_code: provider_response.country_code,
+        last_updated: Some(common_utils::date_time::now()),
+        last_updated_provider: Some(BIN_LOOKUP_PROVIDER.to_string()),
+    };
+
+    let stored_card_info = match card_info {
+        Some(_) => {
+            cards_info::CardsInfoInterface::update_card_info(
+                db,
+                card_iin.to_string(),
+                card_info_update,
+            )
+            .await
+        }
+        None => {
+            cards_info::CardsInfoInterface::add_card_info(
+                db,
+                card_info_models::CardInfo {
+                    card_iin: card_iin.to_string(),
+                    card_issuer: card_info_update.card_issuer,
+                    card_network: card_info_update.card_network,
+                    card_type: card_info_update.card_type,
+                    card_subtype: card_info_update.card_subtype,
+                    card_issuing_country: card_info_update.card_issuing_country,
+                    bank_code_id: card_info_update.bank_code_id,
+                    bank_code: card_info_update.bank_code,
+                    country_code: card_info_update.country_code,
+                    date_created: common_utils::date_time::now(),
+                    last_updated: card_info_update.last_updated,
+                    last_updated_provider: card_info_update.last_updated_provider,
+                },
+            )
+            .await
+        }
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to store card information fetched from the BIN lookup provider")?;
+
+    Ok(Some(stored_card_info))
+}
+
+async fn fetch_card_info_from_provider(
+    state: &routes::SessionState,
+    card_iin: &str,
+) -> RouterResult<BinLookupProviderResponse> {
+    let bin_lookup = &state.conf.bin_lookup;
+    let url = format!(
+        "{}/{card_iin}",
+        bin_lookup.provider_url.trim_end_matches('/')
+    );
+    let mut request = services::Request::new(services::Method::Get, &url);
+    request.add_header(
+        headers::X_API_KEY,
+        bin_lookup.api_key.peek().clone().into_masked(),
+    );
+    request.add_default_headers();
+
+    let response = services::call_connector_api(state, request, "bin_lookup")
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to call the BIN lookup provider")?
+        .map_err(|error_response| {
+            report!(errors::ApiErrorResponse::InternalServerError).attach_printable(format!(
+                "BIN lookup provider responded with status code {}",
+                error_response.status_code
+            ))
+        })?;
+
+    response
+        .response
+        .parse_struct("BinLookupProviderResponse")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the BIN lookup provider response")
+}
+
 #[instrument(skip_all)]
 pub async fn create_card_info(
     state: routes::SessionState,
@@ -323,3 +323,104 @@ pub async fn retrieve_conditional_config(
     };
     Ok(service_api::ApplicationResponse::Json(response))
 }
+
+/// Lints a decision manager config without persisting it, reporting every problem found with the
+/// index of the rule it refers to
+#[cfg(feature = "v1")]
+pub async fn validate_conditional_config(
+    request: DecisionManager,
+) -> RouterResponse<api_models::conditional_configs::DecisionManagerValidationResponse> {
+    use api_models::conditional_configs::{
+        DecisionManagerDiagnostic, DecisionManagerValidationResponse, DiagnosticCode,
+        DiagnosticSeverity,
+    };
+    use euclid::dssa::{
+        analyzer,
+        types::{AnalysisErrorType, RuleDiagnosticType},
+    };
+
+    let program = match request {
+        DecisionManager::DecisionManagerv0(ccr) => ccr.algorithm,
+        DecisionManager::DecisionManagerv1(dmr) => dmr.program,
+    };
+    let Some(program) = program else {
+        return Ok(service_api::ApplicationResponse::Json(
+            DecisionManagerValidationResponse {
+                is_valid: false,
+                diagnostics: vec![DecisionManagerDiagnostic {
+                    rule_index: None,
+                    rule_name: None,
+                    severity: DiagnosticSeverity::Error,
+                    code: DiagnosticCode::MissingProgram,
+                    message: "No program was provided in the config".to_string(),
+                    related_rule_indices: Vec::new(),
+                }],
+            },
+        ));
+    };
+
+    let diagnostics = analyzer::lint_program(&program)
+        .into_iter()
+        .map(|diagnostic| {
+            let (severity, code, message, related_rule_indices) = match diagnostic.diagnostic_type {
+                RuleDiagnosticType::AnalysisFailure(error) => {
+                    let code = match error.error_type {
+                        AnalysisErrorType::InvalidKey(_)
+                        | AnalysisErrorType::InvalidVariant { .. }
+                        | AnalysisErrorType::InvalidType { .. }
+                        | AnalysisErrorType::InvalidComparison { .. }
+                        | AnalysisErrorType::InvalidValue { .. }
+                        | AnalysisErrorType::UnsupportedProgramKey(_)
+                        | AnalysisErrorType::NotImplemented
+                        | AnalysisErrorType::NotSupported => DiagnosticCode::TypeError,
+                        AnalysisErrorType::ConflictingAssertions { .. }
+                        | AnalysisErrorType::ExhaustiveNegation { .. }
+                        | AnalysisErrorType::NegatedAssertion { .. }
+                        | AnalysisErrorType::GraphAnalysis(..)
+                        | AnalysisErrorType::StateMachine(_) => {
+                            DiagnosticCode::ConflictingConditions
+                        }
+                    };
+                    (
+                        DiagnosticSeverity::Error,
+                        code,
+                        error.to_string(),
+                        Vec::new(),
+                    )
+                }
+                RuleDiagnosticType::UnreachableRule { shadowed_by } => (
+                    DiagnosticSeverity::Warning,
+                    DiagnosticCode::UnreachableRule,
+                    "The rule never applies since earlier rules match all of its conditions"
+                        .to_string(),
+                    shadowed_by,
+                ),
+                RuleDiagnosticType::OverlappingRule { overlaps_with } => (
+                    DiagnosticSeverity::Warning,
+                    DiagnosticCode::OverlappingRule,
+                    "Earlier rules match some of the conditions of the rule".to_string(),
+                    overlaps_with,
+                ),
+            };
+            DecisionManagerDiagnostic {
+                rule_index: Some(diagnostic.rule_index),
+                rule_name: Some(diagnostic.rule_name),
+                severity,
+                code,
+                message,
+                related_rule_indices,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let is_valid = diagnostics
+        .iter()
+        .all(|diagnostic| diagnostic.severity != DiagnosticSeverity::Error);
+
+    Ok(service_api::ApplicationResponse::Json(
+        DecisionManagerValidationResponse {
+            is_valid,
+            diagnostics,
+        },
+    ))
+}
@@ -0,0 +1,92 @@
+use api_models::connection_pools as connection_pools_api;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use storage_impl::redis::kv_store::RedisConnInterface;
+
+use crate::{
+    core::errors::{self, RouterResponse},
+    routes::SessionState,
+    services::ApplicationResponse,
+};
+
+/// Retrieves the connection pools of the tenant in this instance of the application
+#[instrument(skip_all)]
+pub async fn retrieve_connection_pools(
+    state: SessionState,
+) -> RouterResponse<connection_pools_api::ConnectionPoolsResponse> {
+    Ok(ApplicationResponse::Json(get_connection_pools(&state)))
+}
+
+/// Resizes the database connection pools of the tenant with the given name, across all the hosts
+/// of the pools. The pools are only resized in this instance of the application, and are created
+/// with the configured size again on restart.
+#[instrument(skip_all)]
+pub async fn resize_database_pool(
+    state: SessionState,
+    request: connection_pools_api::DatabasePoolResizeRequest,
+) -> RouterResponse<connection_pools_api::ConnectionPoolsResponse> {
+    if request.pool_size == 0 {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "pool_size must be greater than zero".to_string(),
+        }));
+    }
+
+    let pools = state
+        .store
+        .get_database_pools()
+        .into_iter()
+        .filter(|pool| pool.name() == request.pool)
+        .collect::<Vec<_>>();
+    if pools.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("No database connection pool named `{}`", request.pool),
+        }));
+    }
+
+    for pool in pools {
+        pool.resize(request.pool_size)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!("Failed to resize the connection pool `{}`", request.pool)
+            })?;
+    }
+    logger::info!(
+        tenant_id = state.tenant.tenant_id.get_string_repr(),
+        pool = request.pool,
+        pool_size = request.pool_size,
+        "Resized the database connection pools"
+    );
+
+    Ok(ApplicationResponse::Json(get_connection_pools(&state)))
+}
+
+fn get_connection_pools(state: &SessionState) -> connection_pools_api::ConnectionPoolsResponse {
+    let database = state
+        .store
+        .get_database_pools()
+        .iter()
+        .map(|pool| {
+            let stats = pool.get_stats();
+            connection_pools_api::DatabasePoolResponse {
+                pool: stats.name.to_owned(),
+                in_use_connections: stats.in_use_connections(),
+                host: stats.host,
+                schema: stats.schema,
+                pool_size: stats.pool_size,
+                connections: stats.connections,
+                idle_connections: stats.idle_connections,
+            }
+        })
+        .collect();
+    let redis = state.store.get_redis_conn().ok().map(|redis_conn| {
+        let stats = redis_conn.get_pool_stats();
+        connection_pools_api::RedisPoolResponse {
+            pool_size: stats.pool_size,
+            connected_clients: stats.connected_clients,
+            queued_commands: stats.queued_commands,
+        }
+    });
+
+    connection_pools_api::ConnectionPoolsResponse { database, redis }
+}
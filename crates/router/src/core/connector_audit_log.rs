@@ -0,0 +1,140 @@
+#[cfg(all(feature = "olap", feature = "v1"))]
+use api_models::connector_audit_log as connector_audit_log_api;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use error_stack::ResultExt;
+use router_env::{logger, tracing::Instrument};
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+use crate::{
+    core::errors::{self, RouterResponse},
+    services::ApplicationResponse,
+};
+use crate::{
+    events::connector_api_logs::ConnectorEvent,
+    routes::SessionState,
+    types::{self, storage},
+};
+
+/// Records the masked request sent to the connector and the masked response received from it,
+/// if the profile the request is served for has opted in to it. The audit log must never fail
+/// the connector call it records, so it is written in the background and errors are only logged.
+pub fn capture_connector_audit_log<F, Req, Resp>(
+    state: &SessionState,
+    router_data: &types::RouterData<F, Req, Resp>,
+    connector_event: &ConnectorEvent,
+) {
+    let Some(profile_id) = state.connector_audit_profile_id.clone() else {
+        return;
+    };
+
+    let connector_audit_log = storage::ConnectorAuditLogNew {
+        id: common_utils::generate_time_ordered_id("cal"),
+        merchant_id: router_data.merchant_id.clone(),
+        profile_id,
+        payment_id: router_data.payment_id.clone(),
+        attempt_id: router_data.attempt_id.clone(),
+        refund_id: router_data.refund_id.clone(),
+        dispute_id: router_data.dispute_id.clone(),
+        connector: connector_event.get_connector_name().to_owned(),
+        flow: connector_event.get_flow().to_owned(),
+        http_method: connector_event.get_method().to_owned(),
+        url: connector_event.get_url().to_owned(),
+        masked_request: parse_masked_payload(connector_event.get_masked_request()),
+        masked_response: connector_event
+            .get_masked_response()
+            .and_then(parse_masked_payload),
+        error: connector_event.get_error().and_then(parse_masked_payload),
+        status_code: i32::from(connector_event.get_status_code()),
+        latency_in_ms: i64::try_from(connector_event.get_latency()).unwrap_or(i64::MAX),
+        request_id: Some(connector_event.request_id.clone()),
+        created_at: common_utils::date_time::now(),
+    };
+
+    let store = state.store.clone();
+    tokio::spawn(
+        async move {
+            let _ = store
+                .insert_connector_audit_log(connector_audit_log)
+                .await
+                .inspect_err(|error| {
+                    logger::error!(?error, "Failed to record connector audit log")
+                });
+        }
+        .in_current_span(),
+    );
+}
+
+/// The payloads of connector events are masked JSON documents, any other payload is kept as a
+/// string.
+fn parse_masked_payload(payload: &str) -> Option<serde_json::Value> {
+    let value = serde_json::from_str::<serde_json::Value>(payload)
+        .unwrap_or_else(|_| serde_json::Value::String(payload.to_owned()));
+    (!value.is_null()).then_some(value)
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn list_connector_audit_logs(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    payment_id: common_utils::id_type::PaymentId,
+    query: connector_audit_log_api::ConnectorAuditLogListQuery,
+) -> RouterResponse<connector_audit_log_api::ConnectorAuditLogListResponse> {
+    let logs = state
+        .store
+        .list_connector_audit_logs_by_merchant_id_payment_id(
+            &merchant_id,
+            payment_id.get_string_repr(),
+            query.attempt_id.as_deref(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch connector audit logs")?;
+
+    let data = logs
+        .into_iter()
+        .map(|log| connector_audit_log_api::ConnectorAuditLogRecord {
+            id: log.id,
+            profile_id: log.profile_id,
+            payment_id: log.payment_id,
+            attempt_id: log.attempt_id,
+            refund_id: log.refund_id,
+            dispute_id: log.dispute_id,
+            connector: log.connector,
+            flow: log.flow,
+            http_method: log.http_method,
+            url: log.url,
+            masked_request: log.masked_request,
+            masked_response: log.masked_response,
+            error: log.error,
+            status_code: log.status_code,
+            latency_in_ms: log.latency_in_ms,
+            request_id: log.request_id,
+            created_at: log.created_at,
+        })
+        .collect::<Vec<_>>();
+
+    Ok(ApplicationResponse::Json(
+        connector_audit_log_api::ConnectorAuditLogListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_masked_payload() {
+        assert_eq!(
+            parse_masked_payload(r#"{"card_number":"*** alloc::string::String ***"}"#),
+            Some(serde_json::json!({"card_number": "*** alloc::string::String ***"}))
+        );
+        assert_eq!(
+            parse_masked_payload("<xml>response</xml>"),
+            Some(serde_json::Value::String("<xml>response</xml>".to_string()))
+        );
+        assert_eq!(parse_masked_payload("null"), None);
+    }
+}
@@ -65,7 +65,8 @@ impl ConnectorAuthTypeAndMetadataValidation<'_> {
             | api_enums::Connector::DummyConnector4
             | api_enums::Connector::DummyConnector5
             | api_enums::Connector::DummyConnector6
-            | api_enums::Connector::DummyConnector7 => {
+            | api_enums::Connector::DummyConnector7
+            | api_enums::Connector::DummyAdvancedConnector => {
                 hyperswitch_connectors::connectors::dummyconnector::transformers::DummyConnectorAuthType::try_from(self.auth_type)?;
                 Ok(())
             }
@@ -259,6 +260,11 @@ impl ConnectorAuthTypeAndMetadataValidation<'_> {
                 forte::transformers::ForteAuthType::try_from(self.auth_type)?;
                 Ok(())
             }
+            api_enums::Connector::Genericrest => {
+                genericrest::transformers::GenericrestAuthType::try_from(self.auth_type)?;
+                genericrest::transformers::GenericrestConfig::try_from(self.connector_meta_data)?;
+                Ok(())
+            }
             api_enums::Connector::Getnet => {
                 getnet::transformers::GetnetAuthType::try_from(self.auth_type)?;
                 Ok(())
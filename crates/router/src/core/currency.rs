@@ -1,13 +1,15 @@
 use analytics::errors::AnalyticsError;
-use common_utils::errors::CustomResult;
+use api_models::{currency as currency_api, enums};
+use common_utils::{errors::CustomResult, types::MinorUnit};
 use currency_conversion::types::ExchangeRates;
 use error_stack::ResultExt;
 use router_env::logger;
 
 use crate::{
-    consts::DEFAULT_ANALYTICS_FOREX_RETRY_ATTEMPTS,
-    core::errors::ApiErrorResponse,
+    consts::{self, DEFAULT_ANALYTICS_FOREX_RETRY_ATTEMPTS},
+    core::errors::{ApiErrorResponse, RouterResult, StorageErrorExt},
     services::ApplicationResponse,
+    types::{domain, storage},
     utils::currency::{self, convert_currency, get_forex_rates, ForexError as ForexCacheError},
     SessionState,
 };
@@ -92,3 +94,195 @@ pub async fn get_forex_exchange_rates(
         }
     }
 }
+
+/// Converts an amount at the current rates, returning the rate and the converted amount
+async fn get_conversion(
+    state: &SessionState,
+    amount: MinorUnit,
+    from_currency: enums::Currency,
+    to_currency: enums::Currency,
+) -> RouterResult<(rust_decimal::Decimal, MinorUnit)> {
+    let forex_api = state.conf.forex_api.get_inner();
+    let rates = get_forex_rates(state, forex_api.data_expiration_delay_in_seconds)
+        .await
+        .change_context(ApiErrorResponse::GenericNotFoundError {
+            message: "Unable to fetch forex rates".to_string(),
+        })?;
+
+    let rate = currency::get_conversion_rate(&rates.data, from_currency, to_currency)
+        .change_context(ApiErrorResponse::InvalidRequestData {
+            message: format!("Conversion from {from_currency} to {to_currency} is not supported"),
+        })?;
+    let converted_amount = currency::convert_minor_amount(amount, from_currency, to_currency, rate)
+        .change_context(ApiErrorResponse::InvalidRequestData {
+            message: "Unable to convert the amount".to_string(),
+        })?;
+
+    Ok((rate, converted_amount))
+}
+
+pub async fn get_currency_conversion(
+    state: SessionState,
+    request: currency_api::CurrencyConversionRequest,
+) -> CustomResult<
+    ApplicationResponse<currency_api::CurrencyConversionDetailsResponse>,
+    ApiErrorResponse,
+> {
+    let (rate, converted_amount) = get_conversion(
+        &state,
+        request.amount,
+        request.from_currency,
+        request.to_currency,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(
+        currency_api::CurrencyConversionDetailsResponse {
+            amount: request.amount,
+            from_currency: request.from_currency,
+            converted_amount,
+            to_currency: request.to_currency,
+            rate: rate.normalize().to_string(),
+        },
+    ))
+}
+
+/// Creates a quote which guarantees the current rate of an amount until the quote expires
+pub async fn create_quote(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    amount: MinorUnit,
+    from_currency: enums::Currency,
+    to_currency: enums::Currency,
+) -> RouterResult<storage::FxQuote> {
+    let (rate, converted_amount) =
+        get_conversion(state, amount, from_currency, to_currency).await?;
+
+    let now = common_utils::date_time::now();
+    let quote_validity = state.conf.forex_api.get_inner().quote_validity_in_seconds;
+    let fx_quote_new = storage::FxQuoteNew {
+        id: common_utils::generate_id(consts::ID_LENGTH, "fxq"),
+        merchant_id: merchant_id.clone(),
+        amount,
+        from_currency,
+        converted_amount,
+        to_currency,
+        rate: rate.normalize().to_string(),
+        payment_id: None,
+        expires_at: now.saturating_add(time::Duration::seconds(i64::from(quote_validity))),
+        created_at: now,
+        modified_at: now,
+    };
+
+    state
+        .store
+        .insert_fx_quote(fx_quote_new)
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert fx quote")
+}
+
+pub async fn create_fx_quote(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: currency_api::FxQuoteCreateRequest,
+) -> CustomResult<ApplicationResponse<currency_api::FxQuoteResponse>, ApiErrorResponse> {
+    let fx_quote = create_quote(
+        &state,
+        merchant_context.get_merchant_account().get_id(),
+        request.amount,
+        request.from_currency,
+        request.to_currency,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(get_fx_quote_response(fx_quote)))
+}
+
+pub async fn retrieve_fx_quote(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    quote_id: String,
+) -> CustomResult<ApplicationResponse<currency_api::FxQuoteResponse>, ApiErrorResponse> {
+    let fx_quote = state
+        .store
+        .find_fx_quote_by_merchant_id_quote_id(
+            merchant_context.get_merchant_account().get_id(),
+            &quote_id,
+        )
+        .await
+        .to_not_found_response(ApiErrorResponse::GenericNotFoundError {
+            message: "FX quote not found".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(get_fx_quote_response(fx_quote)))
+}
+
+/// Validates that a quote can be used by a payment of the given amount and currency, and marks it
+/// as used by the payment so that its rate is not guaranteed to any other payment. A quote which is
+/// already used by the payment is returned as is, as a payment can be confirmed again after a
+/// failed attempt.
+pub async fn use_fx_quote_for_payment(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    quote_id: &str,
+    payment_id: &common_utils::id_type::PaymentId,
+    amount: MinorUnit,
+    currency: enums::Currency,
+) -> RouterResult<storage::FxQuote> {
+    let fx_quote = state
+        .store
+        .find_fx_quote_by_merchant_id_quote_id(merchant_id, quote_id)
+        .await
+        .to_not_found_response(ApiErrorResponse::InvalidRequestData {
+            message: format!("FX quote {quote_id} not found"),
+        })?;
+
+    if fx_quote.payment_id.as_ref() == Some(payment_id) {
+        return Ok(fx_quote);
+    }
+    if fx_quote.expires_at < common_utils::date_time::now() {
+        return Err(ApiErrorResponse::PreconditionFailed {
+            message: format!("FX quote {quote_id} has expired"),
+        }
+        .into());
+    }
+    if fx_quote.amount != amount || fx_quote.from_currency != currency {
+        return Err(ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Amount and currency of the payment must be {} {} as per the FX quote",
+                fx_quote.amount, fx_quote.from_currency
+            ),
+        }
+        .into());
+    }
+
+    state
+        .store
+        .update_unused_fx_quote_by_merchant_id_quote_id(
+            merchant_id,
+            quote_id,
+            storage::FxQuoteUpdate {
+                payment_id: payment_id.clone(),
+                modified_at: common_utils::date_time::now(),
+            },
+        )
+        .await
+        .to_not_found_response(ApiErrorResponse::PreconditionFailed {
+            message: format!("FX quote {quote_id} has already been used by another payment"),
+        })
+}
+
+fn get_fx_quote_response(fx_quote: storage::FxQuote) -> currency_api::FxQuoteResponse {
+    currency_api::FxQuoteResponse {
+        quote_id: fx_quote.id,
+        amount: fx_quote.amount,
+        from_currency: fx_quote.from_currency,
+        converted_amount: fx_quote.converted_amount,
+        to_currency: fx_quote.to_currency,
+        rate: fx_quote.rate,
+        payment_id: fx_quote.payment_id,
+        expires_at: fx_quote.expires_at,
+        created_at: fx_quote.created_at,
+    }
+}
@@ -10,18 +10,31 @@ use common_utils::{
 };
 use error_stack::{report, ResultExt};
 use hyperswitch_domain_models::payment_methods as payment_methods_domain;
+#[cfg(all(feature = "v1", feature = "olap"))]
+use hyperswitch_domain_models::payments::payment_intent::{
+    PaymentIntentFetchConstraints, PaymentIntentListParams,
+};
 use masking::{ExposeInterface, Secret, SwitchStrategy};
 use payment_methods::controller::PaymentMethodsController;
+#[cfg(all(feature = "v1", feature = "olap"))]
+use router_env::logger;
 use router_env::{instrument, tracing};
 
 #[cfg(feature = "v2")]
 use crate::core::payment_methods::cards::create_encrypted_data;
 #[cfg(feature = "v1")]
 use crate::utils::CustomerAddress;
+#[cfg(all(feature = "v1", feature = "olap"))]
+use crate::{
+    core::payment_methods::transformers as payment_methods_transformers,
+    events::audit_events::{AuditEvent, AuditEventType},
+    routes::app::ReqState,
+};
 use crate::{
     core::{
         errors::{self, StorageErrorExt},
         payment_methods::{cards, network_tokenization},
+        utils as core_utils,
     },
     db::StorageInterface,
     pii::PeekInterface,
@@ -217,6 +230,7 @@ impl CustomerCreateBridge for customers::CustomerRequest {
             updated_by: None,
             version: common_types::consts::API_VERSION,
             tax_registration_id: encryptable_customer.tax_registration_id,
+            merged_into_customer_id: None,
         })
     }
 
@@ -585,11 +599,13 @@ pub async fn list_customers(
 ) -> errors::CustomerResponse<Vec<customers::CustomerResponse>> {
     let db = state.store.as_ref();
 
+    let limit = request
+        .limit
+        .unwrap_or(crate::consts::DEFAULT_LIST_API_LIMIT);
     let customer_list_constraints = crate::db::customers::CustomerListConstraints {
-        limit: request
-            .limit
-            .unwrap_or(crate::consts::DEFAULT_LIST_API_LIMIT),
+        limit,
         offset: request.offset,
+        cursor: request.cursor,
     };
 
     let domain_customers = db
@@ -602,6 +618,18 @@ pub async fn list_customers(
         .await
         .switch()?;
 
+    let next_cursor =
+        core_utils::get_next_list_cursor(&domain_customers, i64::from(limit), |customer| {
+            #[cfg(feature = "v1")]
+            let customer_id = customer.customer_id.get_string_repr();
+            #[cfg(feature = "v2")]
+            let customer_id = customer.id.get_string_repr();
+            common_utils::types::ListCursor::new(customer.created_at, customer_id)
+        });
+    let headers = core_utils::get_next_list_cursor_headers(next_cursor)
+        .change_context(errors::CustomersErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the next customers list cursor")?;
+
     #[cfg(feature = "v1")]
     let customers = domain_customers
         .into_iter()
@@ -614,7 +642,9 @@ pub async fn list_customers(
         .map(customers::CustomerResponse::foreign_from)
         .collect();
 
-    Ok(services::ApplicationResponse::Json(customers))
+    Ok(services::ApplicationResponse::JsonWithHeaders((
+        customers, headers,
+    )))
 }
 
 #[cfg(feature = "v2")]
@@ -935,34 +965,11 @@ impl CustomerDeleteBridge for id_type::CustomerId {
         .and_then(|val| val.try_into_operation())
         .switch()?;
 
-        let redacted_encrypted_email = Encryptable::new(
-            redacted_encrypted_value
-                .clone()
-                .into_inner()
-                .switch_strategy(),
-            redacted_encrypted_value.clone().into_encrypted(),
+        let update_address = get_redacted_address_update(
+            &redacted_encrypted_value,
+            merchant_context.get_merchant_account().storage_scheme,
         );
 
-        let update_address = storage::AddressUpdate::Update {
-            city: Some(REDACTED.to_string()),
-            country: None,
-            line1: Some(redacted_encrypted_value.clone()),
-            line2: Some(redacted_encrypted_value.clone()),
-            line3: Some(redacted_encrypted_value.clone()),
-            state: Some(redacted_encrypted_value.clone()),
-            zip: Some(redacted_encrypted_value.clone()),
-            first_name: Some(redacted_encrypted_value.clone()),
-            last_name: Some(redacted_encrypted_value.clone()),
-            phone_number: Some(redacted_encrypted_value.clone()),
-            country_code: Some(REDACTED.to_string()),
-            updated_by: merchant_context
-                .get_merchant_account()
-                .storage_scheme
-                .to_string(),
-            email: Some(redacted_encrypted_email),
-            origin_zip: Some(redacted_encrypted_value.clone()),
-        };
-
         match db
             .update_address_by_merchant_id_customer_id(
                 key_manager_state,
@@ -1031,6 +1038,37 @@ impl CustomerDeleteBridge for id_type::CustomerId {
     }
 }
 
+#[cfg(feature = "v1")]
+fn get_redacted_address_update(
+    redacted_encrypted_value: &Encryptable<Secret<String>>,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> storage::AddressUpdate {
+    let redacted_encrypted_email = Encryptable::new(
+        redacted_encrypted_value
+            .clone()
+            .into_inner()
+            .switch_strategy(),
+        redacted_encrypted_value.clone().into_encrypted(),
+    );
+
+    storage::AddressUpdate::Update {
+        city: Some(REDACTED.to_string()),
+        country: None,
+        line1: Some(redacted_encrypted_value.clone()),
+        line2: Some(redacted_encrypted_value.clone()),
+        line3: Some(redacted_encrypted_value.clone()),
+        state: Some(redacted_encrypted_value.clone()),
+        zip: Some(redacted_encrypted_value.clone()),
+        first_name: Some(redacted_encrypted_value.clone()),
+        last_name: Some(redacted_encrypted_value.clone()),
+        phone_number: Some(redacted_encrypted_value.clone()),
+        country_code: Some(REDACTED.to_string()),
+        updated_by: storage_scheme.to_string(),
+        email: Some(redacted_encrypted_email),
+        origin_zip: Some(redacted_encrypted_value.clone()),
+    }
+}
+
 #[instrument(skip(state))]
 pub async fn update_customer(
     state: SessionState,
@@ -1505,3 +1543,586 @@ pub async fn migrate_customers(
     }
     Ok(services::ApplicationResponse::Json(()))
 }
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip(state, req_state))]
+pub async fn merge_customers(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_context: domain::MerchantContext,
+    merge_request: customers::CustomerMergeRequestInternal,
+) -> errors::RouterResponse<customers::CustomerMergeResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let key_store = merchant_context.get_merchant_key_store();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+    let customer_id = merge_request.customer_id;
+    let duplicate_customer_id = merge_request.request.duplicate_customer_id;
+
+    // Payment methods and mandates stored in redis are keyed by the customer, hence they cannot
+    // be re-parented in place
+    if storage_scheme == enums::MerchantStorageScheme::RedisKv {
+        return Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: "Merging customers is not supported for merchants with KV storage".to_string(),
+        }));
+    }
+
+    if customer_id == duplicate_customer_id {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "duplicate_customer_id must be different from the customer_id".to_string(),
+        }));
+    }
+
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(
+            key_manager_state,
+            &customer_id,
+            merchant_id,
+            key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+    let duplicate_customer = db
+        .find_customer_by_customer_id_merchant_id(
+            key_manager_state,
+            &duplicate_customer_id,
+            merchant_id,
+            key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    if customer.merged_into_customer_id.is_some()
+        || duplicate_customer.merged_into_customer_id.is_some()
+    {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Customers which have already been merged cannot be merged again".to_string(),
+        }));
+    }
+
+    let payment_methods = match db
+        .find_payment_method_by_customer_id_merchant_id_list(
+            key_manager_state,
+            key_store,
+            &duplicate_customer_id,
+            merchant_id,
+            None,
+        )
+        .await
+    {
+        Ok(payment_methods) => payment_methods,
+        Err(error) if error.current_context().is_db_not_found() => Vec::new(),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list the payment methods of the duplicate customer")?,
+    };
+    let payment_methods_moved = payment_methods.len();
+    for payment_method in payment_methods {
+        let (locker_id, network_token_locker_id) =
+            if payment_method.get_payment_method_type() == Some(enums::PaymentMethod::Card) {
+                let locker_id = move_card_in_locker(
+                    &state,
+                    merchant_id,
+                    &duplicate_customer_id,
+                    &customer_id,
+                    payment_method
+                        .locker_id
+                        .as_ref()
+                        .unwrap_or(&payment_method.payment_method_id),
+                )
+                .await?;
+                let network_token_locker_id = payment_method
+                    .network_token_locker_id
+                    .as_ref()
+                    .async_map(|network_token_locker_id| {
+                        move_card_in_locker(
+                            &state,
+                            merchant_id,
+                            &duplicate_customer_id,
+                            &customer_id,
+                            network_token_locker_id,
+                        )
+                    })
+                    .await
+                    .transpose()?;
+                (Some(locker_id), network_token_locker_id)
+            } else {
+                (None, None)
+            };
+
+        db.update_payment_method(
+            key_manager_state,
+            key_store,
+            payment_method,
+            storage::PaymentMethodUpdate::CustomerIdUpdate {
+                customer_id: customer_id.clone(),
+                locker_id,
+                network_token_locker_id,
+            },
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
+        .attach_printable("Failed to move the payment method to the customer")?;
+    }
+
+    let mandates = db
+        .find_mandate_by_merchant_id_customer_id(merchant_id, &duplicate_customer_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the mandates of the duplicate customer")?;
+    let mandates_moved = mandates.len();
+    for mandate in mandates {
+        let mandate_id = mandate.mandate_id.clone();
+        db.update_mandate_by_merchant_id_mandate_id(
+            merchant_id,
+            &mandate_id,
+            storage::MandateUpdate::CustomerIdUpdate {
+                customer_id: customer_id.clone(),
+            },
+            mandate,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)
+        .attach_printable("Failed to move the mandate to the customer")?;
+    }
+
+    let payment_intents = list_customer_payment_intents(
+        db,
+        key_manager_state,
+        &merchant_context,
+        &duplicate_customer_id,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to list the payments of the duplicate customer")?;
+    let payments_moved = payment_intents.len();
+    for payment_intent in payment_intents {
+        db.update_payment_intent(
+            key_manager_state,
+            payment_intent,
+            storage::PaymentIntentUpdate::PaymentCreateUpdate {
+                return_url: None,
+                status: None,
+                customer_id: Some(customer_id.clone()),
+                shipping_address_id: None,
+                billing_address_id: None,
+                customer_details: None,
+                updated_by: storage_scheme.to_string(),
+            },
+            key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+        .attach_printable("Failed to move the payment to the customer")?;
+    }
+
+    db.update_customer_by_customer_id_merchant_id(
+        key_manager_state,
+        duplicate_customer_id.clone(),
+        merchant_id.to_owned(),
+        duplicate_customer,
+        storage::CustomerUpdate::MergeUpdate {
+            merged_into_customer_id: customer_id.clone(),
+        },
+        key_store,
+        storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)
+    .attach_printable("Failed to deactivate the duplicate customer")?;
+
+    logger::info!(
+        customer_id = ?customer_id,
+        duplicate_customer_id = ?duplicate_customer_id,
+        payment_methods_moved,
+        mandates_moved,
+        payments_moved,
+        "Merged the duplicate customer into the customer"
+    );
+    req_state
+        .event_context
+        .event(AuditEvent::new(AuditEventType::CustomerMerged {
+            merchant_id: merchant_id.to_owned(),
+            customer_id: customer_id.clone(),
+            duplicate_customer_id: duplicate_customer_id.clone(),
+            payment_methods_moved,
+            mandates_moved,
+            payments_moved,
+        }))
+        .emit();
+
+    Ok(services::ApplicationResponse::Json(
+        customers::CustomerMergeResponse {
+            customer_id,
+            duplicate_customer_id,
+            payment_methods_moved,
+            mandates_moved,
+            payments_moved,
+        },
+    ))
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip(state))]
+pub async fn redact_customer(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    customer_id: id_type::CustomerId,
+) -> errors::CustomerResponse<customers::CustomerRedactResponse> {
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let key_store = merchant_context.get_merchant_key_store();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+
+    // The payments are listed before redacting the customer, as a redacted customer cannot be
+    // looked up anymore
+    let payment_intents =
+        list_customer_payment_intents(db, key_manager_state, &merchant_context, &customer_id)
+            .await
+            .change_context(errors::CustomersErrorResponse::InternalServerError)
+            .attach_printable("Failed to list the payments of the customer")?;
+
+    // This deletes the payment methods of the customer from the locker, and overwrites the
+    // encrypted personal data of the customer and their addresses
+    customer_id
+        .redact_customer_details_and_generate_response(
+            db,
+            &merchant_context,
+            key_manager_state,
+            &state,
+        )
+        .await?;
+
+    let key = key_store.key.get_inner().peek();
+    let redacted_encrypted_value: Encryptable<Secret<String>> = types::crypto_operation(
+        key_manager_state,
+        type_name!(storage::Address),
+        types::CryptoOperation::Encrypt(REDACTED.to_string().into()),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key,
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .switch()?;
+    let redacted_details = cards::create_encrypted_data(
+        key_manager_state,
+        key_store,
+        serde_json::Value::Object(serde_json::Map::new()),
+    )
+    .await
+    .change_context(errors::CustomersErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt the redacted payment details")?;
+
+    // The payments are retained for the financial records, with only the customer identifier
+    // left to link them to the customer
+    let payments_pseudonymized = payment_intents.len();
+    for payment_intent in payment_intents {
+        for address_id in [
+            payment_intent.billing_address_id.as_ref(),
+            payment_intent.shipping_address_id.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            let address = match db
+                .find_address_by_merchant_id_payment_id_address_id(
+                    key_manager_state,
+                    merchant_id,
+                    &payment_intent.payment_id,
+                    address_id,
+                    key_store,
+                    storage_scheme,
+                )
+                .await
+            {
+                Ok(address) => address,
+                Err(error) if error.current_context().is_db_not_found() => continue,
+                Err(error) => Err(error)
+                    .change_context(errors::CustomersErrorResponse::InternalServerError)
+                    .attach_printable("Failed to find the address of the payment")?,
+            };
+            db.update_address_for_payments(
+                key_manager_state,
+                address,
+                get_redacted_address_update(&redacted_encrypted_value, storage_scheme),
+                payment_intent.payment_id.clone(),
+                key_store,
+                storage_scheme,
+            )
+            .await
+            .change_context(errors::CustomersErrorResponse::InternalServerError)
+            .attach_printable("Failed to redact the address of the payment")?;
+        }
+
+        let payment_intent_update = storage::PaymentIntentUpdate::PiiRedactionUpdate {
+            customer_details: payment_intent
+                .customer_details
+                .as_ref()
+                .map(|_| redacted_details.clone()),
+            billing_details: payment_intent
+                .billing_details
+                .as_ref()
+                .map(|_| redacted_details.clone()),
+            shipping_details: payment_intent
+                .shipping_details
+                .as_ref()
+                .map(|_| redacted_details.clone()),
+            updated_by: storage_scheme.to_string(),
+        };
+        db.update_payment_intent(
+            key_manager_state,
+            payment_intent,
+            payment_intent_update,
+            key_store,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::CustomersErrorResponse::InternalServerError)
+        .attach_printable("Failed to redact the personal data of the payment")?;
+    }
+
+    logger::info!(
+        customer_id = ?customer_id,
+        payments_pseudonymized,
+        "Redacted the personal data of the customer"
+    );
+
+    Ok(services::ApplicationResponse::Json(
+        customers::CustomerRedactResponse {
+            customer_id,
+            customer_redacted: true,
+            address_redacted: true,
+            payment_methods_deleted: true,
+            payments_pseudonymized,
+        },
+    ))
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip(state))]
+pub async fn export_customer_data(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    customer_id: id_type::CustomerId,
+) -> errors::CustomerResponse<customers::CustomerDataExportResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let key_store = merchant_context.get_merchant_key_store();
+
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(
+            key_manager_state,
+            &customer_id,
+            merchant_id,
+            key_store,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .switch()?;
+    let address = customer
+        .address_id
+        .as_ref()
+        .async_map(|address_id| {
+            db.find_address_by_address_id(key_manager_state, address_id, key_store)
+        })
+        .await
+        .transpose()
+        .switch()?
+        .map(api_models::payments::AddressDetails::from);
+
+    let payment_methods = match db
+        .find_payment_method_by_customer_id_merchant_id_list(
+            key_manager_state,
+            key_store,
+            &customer_id,
+            merchant_id,
+            None,
+        )
+        .await
+    {
+        Ok(payment_methods) => payment_methods,
+        Err(error) if error.current_context().is_db_not_found() => Vec::new(),
+        Err(error) => Err(error)
+            .change_context(errors::CustomersErrorResponse::InternalServerError)
+            .attach_printable("Failed to list the payment methods of the customer")?,
+    }
+    .into_iter()
+    .map(|payment_method| {
+        let card = payment_method
+            .payment_method_data
+            .clone()
+            .map(|data| data.into_inner().expose())
+            .and_then(|data| {
+                serde_json::from_value::<api_models::payment_methods::PaymentMethodsData>(data).ok()
+            })
+            .and_then(|data| match data {
+                api_models::payment_methods::PaymentMethodsData::Card(card) => Some(
+                    api_models::payment_methods::CardDetailFromLocker::from(card),
+                ),
+                _ => None,
+            });
+        customers::CustomerDataExportPaymentMethod {
+            payment_method_id: payment_method.payment_method_id,
+            payment_method: payment_method.payment_method,
+            payment_method_type: payment_method.payment_method_type,
+            card,
+            status: payment_method.status,
+            created_at: payment_method.created_at,
+            last_used_at: payment_method.last_used_at,
+        }
+    })
+    .collect();
+
+    let mandates = db
+        .find_mandate_by_merchant_id_customer_id(merchant_id, &customer_id)
+        .await
+        .switch()?
+        .into_iter()
+        .map(|mandate| customers::CustomerDataExportMandate {
+            mandate_id: mandate.mandate_id,
+            status: mandate.mandate_status,
+            payment_method_id: mandate.payment_method_id,
+            customer_ip_address: mandate.customer_ip_address,
+            customer_user_agent: mandate.customer_user_agent,
+            customer_accepted_at: mandate.customer_accepted_at,
+            created_at: mandate.created_at,
+        })
+        .collect();
+
+    let payments =
+        list_customer_payment_intents(db, key_manager_state, &merchant_context, &customer_id)
+            .await
+            .change_context(errors::CustomersErrorResponse::InternalServerError)
+            .attach_printable("Failed to list the payments of the customer")?
+            .into_iter()
+            .map(|payment_intent| customers::CustomerDataExportPayment {
+                payment_id: payment_intent.payment_id,
+                status: payment_intent.status,
+                amount: payment_intent.amount,
+                currency: payment_intent.currency,
+                description: payment_intent.description,
+                customer_details: payment_intent
+                    .customer_details
+                    .map(|details| details.into_inner()),
+                billing_details: payment_intent
+                    .billing_details
+                    .map(|details| details.into_inner()),
+                shipping_details: payment_intent
+                    .shipping_details
+                    .map(|details| details.into_inner()),
+                created_at: payment_intent.created_at,
+            })
+            .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        customers::CustomerDataExportResponse {
+            customer: customers::CustomerResponse::foreign_from((customer, address)).into_inner(),
+            payment_methods,
+            mandates,
+            payments,
+            exported_at: common_utils::date_time::now(),
+        },
+    ))
+}
+
+/// Lists all the payments of the customer. All of them are fetched upfront, so that the caller
+/// can update them without affecting the pagination.
+#[cfg(all(feature = "v1", feature = "olap"))]
+async fn list_customer_payment_intents(
+    db: &dyn StorageInterface,
+    key_manager_state: &KeyManagerState,
+    merchant_context: &domain::MerchantContext,
+    customer_id: &id_type::CustomerId,
+) -> errors::CustomResult<Vec<storage::PaymentIntent>, errors::StorageError> {
+    let limit = common_utils::consts::PAYMENTS_LIST_MAX_LIMIT_V1;
+    let mut offset = 0;
+    let mut payment_intents = Vec::new();
+    loop {
+        let page = db
+            .filter_payment_intent_by_constraints(
+                key_manager_state,
+                merchant_context.get_merchant_account().get_id(),
+                &PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+                    offset,
+                    starting_at: None,
+                    ending_at: None,
+                    amount_filter: None,
+                    connector: None,
+                    currency: None,
+                    status: None,
+                    payment_method: None,
+                    payment_method_type: None,
+                    authentication_type: None,
+                    merchant_connector_id: None,
+                    profile_id: None,
+                    customer_id: Some(customer_id.clone()),
+                    starting_after_id: None,
+                    ending_before_id: None,
+                    limit: Some(limit),
+                    order: Default::default(),
+                    card_network: None,
+                    card_discovery: None,
+                    merchant_order_reference_id: None,
+                    cursor: None,
+                })),
+                merchant_context.get_merchant_key_store(),
+                merchant_context.get_merchant_account().storage_scheme,
+            )
+            .await?;
+        let is_last_page = page.len() < usize::try_from(limit).unwrap_or(usize::MAX);
+        payment_intents.extend(page);
+        if is_last_page {
+            return Ok(payment_intents);
+        }
+        offset += limit;
+    }
+}
+
+/// Cards are stored in the locker against a customer, hence they are copied over to the new
+/// customer and removed from the old one. Returns the locker reference of the copied card.
+#[cfg(all(feature = "v1", feature = "olap"))]
+async fn move_card_in_locker(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    from_customer_id: &id_type::CustomerId,
+    to_customer_id: &id_type::CustomerId,
+    card_reference: &str,
+) -> errors::RouterResult<String> {
+    let card = cards::get_card_from_locker(state, from_customer_id, merchant_id, card_reference)
+        .await
+        .attach_printable("Failed to fetch the card of the duplicate customer from the locker")?;
+
+    let store_card_request = payment_methods_transformers::StoreLockerReq::LockerCard(
+        payment_methods_transformers::StoreCardReq {
+            merchant_id: merchant_id.to_owned(),
+            merchant_customer_id: to_customer_id.to_owned(),
+            requestor_card_reference: Some(card_reference.to_string()),
+            card,
+            ttl: state.conf.locker.ttl_for_storage_in_secs,
+        },
+    );
+    let stored_card = cards::add_card_to_hs_locker(
+        state,
+        &store_card_request,
+        to_customer_id,
+        api_models::enums::LockerChoice::HyperswitchCardVault,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to store the card against the customer in the locker")?;
+
+    cards::delete_card_from_hs_locker(state, from_customer_id, merchant_id, card_reference)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to delete the card of the duplicate customer from the locker")?;
+
+    Ok(stored_card.card_reference)
+}
@@ -0,0 +1,506 @@
+use api_models::data_retention as data_retention_api;
+use common_utils::{date_time, id_type, types::Description};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::payments::payment_intent::{
+    PaymentIntentFetchConstraints, PaymentIntentListParams,
+};
+use router_env::{instrument, logger, tracing};
+use scheduler::utils as pt_utils;
+
+use crate::{
+    consts,
+    core::{
+        customers,
+        errors::{self, RouterResponse, RouterResult},
+    },
+    db::StorageInterface,
+    routes::{metrics, SessionState},
+    services::ApplicationResponse,
+    types::{
+        domain,
+        storage::{self, data_retention::DataRetentionPurgeTrackingData, enums},
+    },
+};
+
+pub const DATA_RETENTION_PURGE_TASK: &str = "DATA_RETENTION_PURGE";
+
+/// The outcome of a purge, along with whether inactive customers were left to be redacted as the
+/// purge redacts a bounded number of customers
+pub struct DataRetentionPurgeOutcome {
+    pub purge_run: storage::DataRetentionPurgeRun,
+    pub has_pending_customers: bool,
+}
+
+/// Sets the retention policy of the merchant, replacing any existing policy, and schedules the
+/// recurring purge of the data past the policy
+#[instrument(skip_all)]
+pub async fn upsert_data_retention_policy(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: data_retention_api::DataRetentionPolicyRequest,
+) -> RouterResponse<data_retention_api::DataRetentionPolicyResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let connector_payload_retention_days = get_retention_days(
+        request.connector_payload_retention_days,
+        "connector_payload_retention_days",
+    )?;
+    let inactive_customer_retention_days = get_retention_days(
+        request.inactive_customer_retention_days,
+        "inactive_customer_retention_days",
+    )?;
+
+    let existing_policy = db
+        .find_optional_data_retention_policy(merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the data retention policy")?;
+
+    let now = date_time::now();
+    let policy = match existing_policy {
+        Some(_) => db
+            .update_data_retention_policy(
+                merchant_id,
+                storage::DataRetentionPolicyUpdate {
+                    connector_payload_retention_days,
+                    inactive_customer_retention_days,
+                    modified_at: now,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the data retention policy")?,
+        None => db
+            .insert_data_retention_policy(storage::DataRetentionPolicyNew {
+                merchant_id: merchant_id.clone(),
+                connector_payload_retention_days,
+                inactive_customer_retention_days,
+                created_at: now,
+                modified_at: now,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the data retention policy")?,
+    };
+
+    add_data_retention_purge_task(db, merchant_id).await?;
+
+    Ok(ApplicationResponse::Json(get_policy_response(policy)))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_data_retention_policy(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+) -> RouterResponse<data_retention_api::DataRetentionPolicyResponse> {
+    let policy = state
+        .store
+        .find_optional_data_retention_policy(merchant_context.get_merchant_account().get_id())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the data retention policy")?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Data retention policy does not exist for the merchant".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(get_policy_response(policy)))
+}
+
+/// Purges the data which is past the retention policy of the merchant on demand. A dry run only
+/// reports the data which would be purged.
+#[instrument(skip_all)]
+pub async fn purge_data(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: data_retention_api::DataRetentionPurgeRequest,
+    triggered_by: Option<common_utils::types::CreatedBy>,
+) -> RouterResponse<data_retention_api::DataRetentionPurgeRunResponse> {
+    let triggered_by = triggered_by
+        .map(|triggered_by| triggered_by.to_string())
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get the user triggering the purge")?;
+    let policy = state
+        .store
+        .find_optional_data_retention_policy(merchant_context.get_merchant_account().get_id())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the data retention policy")?
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Data retention policy must be set before purging data".to_string(),
+        })?;
+
+    let outcome = run_data_retention_purge(
+        &state,
+        &merchant_context,
+        &policy,
+        request.dry_run,
+        triggered_by,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(get_purge_run_response(
+        outcome.purge_run,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn list_data_retention_purge_runs(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    query: data_retention_api::DataRetentionPurgeRunListQuery,
+) -> RouterResponse<data_retention_api::DataRetentionPurgeRunListResponse> {
+    let limit = query
+        .limit
+        .map(i64::from)
+        .unwrap_or(consts::DATA_RETENTION_PURGE_RUNS_DEFAULT_LIMIT)
+        .min(consts::DATA_RETENTION_PURGE_RUNS_MAX_LIMIT);
+    let offset = query.offset.map(i64::from).unwrap_or_default();
+
+    let purge_runs = state
+        .store
+        .list_data_retention_purge_runs(
+            merchant_context.get_merchant_account().get_id(),
+            limit,
+            offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the data retention purge runs")?;
+
+    let data = purge_runs
+        .into_iter()
+        .map(get_purge_run_response)
+        .collect::<Vec<_>>();
+
+    Ok(ApplicationResponse::Json(
+        data_retention_api::DataRetentionPurgeRunListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+/// Purges the connector payloads and redacts the inactive customers which are past the retention
+/// policy, and records the purge run. At most
+/// [`consts::DATA_RETENTION_PURGE_CUSTOMER_BATCH_SIZE`] customers are redacted in a run.
+#[instrument(skip_all)]
+pub async fn run_data_retention_purge(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    policy: &storage::DataRetentionPolicy,
+    dry_run: bool,
+    triggered_by: String,
+) -> RouterResult<DataRetentionPurgeOutcome> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let now = date_time::now();
+
+    let connector_payloads_created_before = policy
+        .connector_payload_retention_days
+        .map(|retention_days| now - time::Duration::days(i64::from(retention_days)));
+    let connector_payloads_purged = match connector_payloads_created_before {
+        Some(created_before) if dry_run => db
+            .count_connector_audit_logs_created_before(merchant_id, created_before)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to count the connector audit logs to purge")?,
+        Some(created_before) => db
+            .delete_connector_audit_logs_created_before(merchant_id, created_before)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to purge the connector audit logs")
+            .map(|purged| i64::try_from(purged).unwrap_or(i64::MAX))?,
+        None => 0,
+    };
+
+    let customers_inactive_since = policy
+        .inactive_customer_retention_days
+        .map(|retention_days| now - time::Duration::days(i64::from(retention_days)));
+    let (redacted_customer_ids, failed_customer_ids, has_pending_customers) =
+        match customers_inactive_since {
+            Some(inactive_since) => {
+                redact_inactive_customers(state, merchant_context, inactive_since, dry_run).await?
+            }
+            None => (Vec::new(), Vec::new(), false),
+        };
+
+    let purge_run = db
+        .insert_data_retention_purge_run(storage::DataRetentionPurgeRunNew {
+            id: common_utils::generate_time_ordered_id("purge"),
+            merchant_id: merchant_id.clone(),
+            is_dry_run: dry_run,
+            triggered_by,
+            connector_payloads_created_before,
+            connector_payloads_purged,
+            customers_inactive_since,
+            redacted_customer_ids: serde_json::to_value(&redacted_customer_ids)
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
+            failed_customer_ids: serde_json::to_value(&failed_customer_ids)
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
+            created_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record the data retention purge run")?;
+
+    logger::info!(
+        purge_run_id = %purge_run.id,
+        dry_run,
+        connector_payloads_purged,
+        customers_redacted = redacted_customer_ids.len(),
+        customers_failed = failed_customer_ids.len(),
+        "Purged the data past the retention policy of the merchant"
+    );
+
+    Ok(DataRetentionPurgeOutcome {
+        purge_run,
+        has_pending_customers,
+    })
+}
+
+/// Redacts the customers which were neither updated nor made a payment since the given time.
+/// Customers with an active mandate are kept, as their mandate can still be charged. Redacted
+/// customers drop out of the inactive customers, hence only the skipped and failed customers
+/// advance the offset.
+async fn redact_inactive_customers(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    inactive_since: time::PrimitiveDateTime,
+    dry_run: bool,
+) -> RouterResult<(Vec<id_type::CustomerId>, Vec<id_type::CustomerId>, bool)> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let batch_size = consts::DATA_RETENTION_PURGE_CUSTOMER_BATCH_SIZE;
+    let mut redacted_customer_ids = Vec::new();
+    let mut failed_customer_ids = Vec::new();
+    let mut offset = 0;
+
+    loop {
+        let inactive_customers = db
+            .list_inactive_customers(
+                merchant_id,
+                inactive_since,
+                Description::from_str_unchecked(customers::REDACTED),
+                batch_size,
+                offset,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list the inactive customers")?;
+        let is_last_page =
+            inactive_customers.len() < usize::try_from(batch_size).unwrap_or(usize::MAX);
+
+        for customer in inactive_customers {
+            if redacted_customer_ids.len() >= usize::try_from(batch_size).unwrap_or(usize::MAX) {
+                return Ok((redacted_customer_ids, failed_customer_ids, true));
+            }
+
+            let customer_id = customer.customer_id;
+            match is_customer_active_since(state, merchant_context, &customer_id, inactive_since)
+                .await
+            {
+                Ok(false) => (),
+                Ok(true) => {
+                    offset += 1;
+                    continue;
+                }
+                Err(error) => {
+                    logger::error!(?error, "Failed to check the activity of the customer");
+                    failed_customer_ids.push(customer_id);
+                    offset += 1;
+                    continue;
+                }
+            }
+
+            if dry_run {
+                redacted_customer_ids.push(customer_id);
+                offset += 1;
+                continue;
+            }
+
+            match customers::redact_customer(
+                state.clone(),
+                merchant_context.clone(),
+                customer_id.clone(),
+            )
+            .await
+            {
+                Ok(_) => redacted_customer_ids.push(customer_id),
+                Err(error) => {
+                    logger::error!(?error, "Failed to redact the inactive customer");
+                    failed_customer_ids.push(customer_id);
+                    offset += 1;
+                }
+            }
+        }
+
+        if is_last_page {
+            return Ok((redacted_customer_ids, failed_customer_ids, false));
+        }
+    }
+}
+
+async fn is_customer_active_since(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    customer_id: &id_type::CustomerId,
+    since: time::PrimitiveDateTime,
+) -> errors::CustomResult<bool, errors::StorageError> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    let has_active_mandate = db
+        .find_mandate_by_merchant_id_customer_id(merchant_id, customer_id)
+        .await?
+        .iter()
+        .any(|mandate| mandate.mandate_status == enums::MandateStatus::Active);
+    if has_active_mandate {
+        return Ok(true);
+    }
+
+    let recent_payments = db
+        .filter_payment_intent_by_constraints(
+            &state.into(),
+            merchant_id,
+            &PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+                offset: 0,
+                starting_at: Some(since),
+                ending_at: None,
+                amount_filter: None,
+                connector: None,
+                currency: None,
+                status: None,
+                payment_method: None,
+                payment_method_type: None,
+                authentication_type: None,
+                merchant_connector_id: None,
+                profile_id: None,
+                customer_id: Some(customer_id.clone()),
+                starting_after_id: None,
+                ending_before_id: None,
+                limit: Some(1),
+                order: Default::default(),
+                card_network: None,
+                card_discovery: None,
+                merchant_order_reference_id: None,
+                cursor: None,
+            })),
+            merchant_context.get_merchant_key_store(),
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await?;
+
+    Ok(!recent_payments.is_empty())
+}
+
+/// Schedules the recurring purge of the merchant. The first purge runs a day after the policy is
+/// set, which leaves time for a dry run of the policy. A task which is already scheduled picks up
+/// the latest policy when it runs.
+async fn add_data_retention_purge_task(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::DataRetentionPurgeWorkflow;
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        DATA_RETENTION_PURGE_TASK,
+        merchant_id.get_string_repr(),
+        merchant_id,
+    );
+    let tracking_data = DataRetentionPurgeTrackingData {
+        merchant_id: merchant_id.clone(),
+    };
+    let schedule_time = date_time::now()
+        + time::Duration::seconds(consts::DATA_RETENTION_PURGE_INTERVAL_IN_SECONDS);
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id.clone(),
+        DATA_RETENTION_PURGE_TASK,
+        runner,
+        ["DATA_RETENTION", "PURGE"],
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct data retention purge process tracker task")?;
+
+    match db.insert_process(process_tracker_entry).await {
+        Ok(_) => {
+            metrics::TASKS_ADDED_COUNT.add(
+                1,
+                router_env::metric_attributes!(("flow", "DataRetentionPurge")),
+            );
+            Ok(())
+        }
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            // The task is finished once the policy is deleted, so it is resumed for a new policy
+            let finished_process = db
+                .find_process_by_id(&process_tracker_id)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to retrieve data retention purge task")?
+                .filter(|process| process.status == enums::ProcessTrackerStatus::Finish);
+            if let Some(process) = finished_process {
+                logger::info!("Resuming the finished data retention purge task");
+                db.reset_process(process, schedule_time)
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to resume data retention purge task")?;
+            }
+            Ok(())
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to add data retention purge task to process tracker"),
+    }
+}
+
+fn get_retention_days(retention_days: Option<u32>, field_name: &str) -> RouterResult<Option<i32>> {
+    retention_days
+        .map(|retention_days| {
+            i32::try_from(retention_days)
+                .ok()
+                .filter(|retention_days| *retention_days > 0)
+                .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("{field_name} must be a positive number of days"),
+                })
+        })
+        .transpose()
+        .map_err(error_stack::Report::from)
+}
+
+fn get_policy_response(
+    policy: storage::DataRetentionPolicy,
+) -> data_retention_api::DataRetentionPolicyResponse {
+    data_retention_api::DataRetentionPolicyResponse {
+        merchant_id: policy.merchant_id,
+        connector_payload_retention_days: policy
+            .connector_payload_retention_days
+            .and_then(|retention_days| u32::try_from(retention_days).ok()),
+        inactive_customer_retention_days: policy
+            .inactive_customer_retention_days
+            .and_then(|retention_days| u32::try_from(retention_days).ok()),
+        created_at: policy.created_at,
+        modified_at: policy.modified_at,
+    }
+}
+
+fn get_purge_run_response(
+    purge_run: storage::DataRetentionPurgeRun,
+) -> data_retention_api::DataRetentionPurgeRunResponse {
+    data_retention_api::DataRetentionPurgeRunResponse {
+        id: purge_run.id,
+        merchant_id: purge_run.merchant_id,
+        dry_run: purge_run.is_dry_run,
+        triggered_by: purge_run.triggered_by,
+        connector_payloads_created_before: purge_run.connector_payloads_created_before,
+        connector_payloads_purged: purge_run.connector_payloads_purged,
+        customers_inactive_since: purge_run.customers_inactive_since,
+        redacted_customer_ids: serde_json::from_value(purge_run.redacted_customer_ids)
+            .unwrap_or_default(),
+        failed_customer_ids: serde_json::from_value(purge_run.failed_customer_ids)
+            .unwrap_or_default(),
+        created_at: purge_run.created_at,
+    }
+}
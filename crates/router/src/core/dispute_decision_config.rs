@@ -0,0 +1,324 @@
+use api_models::dispute_decision_configs::{
+    DisputeDecisionConfigReq, DisputeDecisionManagerRecord, DisputeDecisionManagerResponse,
+    DisputeDecisionRule,
+};
+use common_utils::ext_traits::{Encode, OptionExt, StringExt};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        disputes,
+        errors::{self, RouterResponse},
+    },
+    routes::SessionState,
+    services::api as service_api,
+    types::{api, domain, storage::enums as storage_enums, transformers::ForeignInto},
+};
+
+pub async fn upsert_dispute_decision_config_for_profile(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: common_utils::id_type::ProfileId,
+    request: DisputeDecisionConfigReq,
+) -> RouterResponse<DisputeDecisionManagerRecord> {
+    use diesel_models::configs;
+
+    get_business_profile(&state, &merchant_context, &profile_id).await?;
+    validate_dispute_decision_rules(&request.rules)?;
+
+    let db = state.store.as_ref();
+    let key = profile_id.get_dispute_decision_config_key();
+    let timestamp = common_utils::date_time::now_unix_timestamp();
+
+    match db.find_config_by_key(&key).await {
+        Ok(config) => {
+            let previous_record: DisputeDecisionManagerRecord = config
+                .config
+                .parse_struct("DisputeDecisionManagerRecord")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse the dispute decision config")?;
+
+            let new_record = DisputeDecisionManagerRecord {
+                name: request.name.unwrap_or(previous_record.name),
+                rules: request.rules,
+                created_at: previous_record.created_at,
+                modified_at: timestamp,
+            };
+            let serialized_record = new_record
+                .encode_to_string_of_json()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to serialize config to string")?;
+
+            db.update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(serialized_record),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the dispute decision config")?;
+
+            Ok(service_api::ApplicationResponse::Json(new_record))
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            let new_record = DisputeDecisionManagerRecord {
+                name: request
+                    .name
+                    .get_required_value("name")
+                    .change_context(errors::ApiErrorResponse::MissingRequiredField {
+                        field_name: "name",
+                    })
+                    .attach_printable("name of the config not found")?,
+                rules: request.rules,
+                created_at: timestamp,
+                modified_at: timestamp,
+            };
+            let serialized_record = new_record
+                .encode_to_string_of_json()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Error serializing the config")?;
+
+            db.insert_config(configs::ConfigNew {
+                key,
+                config: serialized_record,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the dispute decision config")?;
+
+            Ok(service_api::ApplicationResponse::Json(new_record))
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error fetching the dispute decision config"),
+    }
+}
+
+pub async fn retrieve_dispute_decision_config_for_profile(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: common_utils::id_type::ProfileId,
+) -> RouterResponse<DisputeDecisionManagerResponse> {
+    get_business_profile(&state, &merchant_context, &profile_id).await?;
+    let config = state
+        .store
+        .find_config_by_key(&profile_id.get_dispute_decision_config_key())
+        .await
+        .change_context(errors::ApiErrorResponse::ResourceIdNotFound)
+        .attach_printable("The dispute decision config was not found in the DB")?;
+    let record: DisputeDecisionManagerRecord = config
+        .config
+        .parse_struct("DisputeDecisionManagerRecord")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the dispute decision config")?;
+    Ok(service_api::ApplicationResponse::Json(record))
+}
+
+pub async fn delete_dispute_decision_config_for_profile(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: common_utils::id_type::ProfileId,
+) -> RouterResponse<()> {
+    get_business_profile(&state, &merchant_context, &profile_id).await?;
+    state
+        .store
+        .delete_config_by_key(&profile_id.get_dispute_decision_config_key())
+        .await
+        .change_context(errors::ApiErrorResponse::ResourceIdNotFound)
+        .attach_printable("Failed to delete the dispute decision config from DB")?;
+    Ok(service_api::ApplicationResponse::StatusOk)
+}
+
+/// Applies the first rule of the dispute decision config of the profile which matches a newly
+/// opened dispute, and records the outcome of the action on the dispute. Disputes which already
+/// have an automated action recorded are left untouched, so that a rule is applied only once.
+#[instrument(skip_all)]
+pub async fn perform_dispute_decision_management(
+    state: &SessionState,
+    merchant_context: domain::MerchantContext,
+    business_profile: &domain::Profile,
+    dispute: diesel_models::dispute::Dispute,
+) -> errors::RouterResult<Option<diesel_models::dispute::Dispute>> {
+    if dispute.dispute_stage != storage_enums::DisputeStage::Dispute
+        || dispute.dispute_status != storage_enums::DisputeStatus::DisputeOpened
+        || dispute.automated_action.is_some()
+    {
+        return Ok(None);
+    }
+
+    let profile_id = business_profile.get_id();
+    let config = match state
+        .store
+        .find_config_by_key(&profile_id.get_dispute_decision_config_key())
+        .await
+    {
+        Ok(config) => config,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Error fetching the dispute decision config")
+        }
+    };
+    let record: DisputeDecisionManagerRecord = config
+        .config
+        .parse_struct("DisputeDecisionManagerRecord")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the dispute decision config")?;
+
+    let Some(rule) = record
+        .rules
+        .into_iter()
+        .find(|rule| is_rule_matching(rule, &dispute))
+    else {
+        return Ok(None);
+    };
+    logger::info!(
+        rule_name = %rule.name,
+        action = %rule.action,
+        "Applying the dispute decision rule to the dispute"
+    );
+
+    let action_result = match rule.action {
+        storage_enums::DisputeDecisionAction::Accept => Box::pin(disputes::accept_dispute(
+            state.clone(),
+            merchant_context.clone(),
+            Some(profile_id.clone()),
+            api::DisputeId {
+                dispute_id: dispute.dispute_id.clone(),
+            },
+        ))
+        .await
+        .map(|_| ()),
+        storage_enums::DisputeDecisionAction::SubmitEvidence => {
+            let evidence_request = rule
+                .evidence
+                .clone()
+                .get_required_value("evidence")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Evidence template not found for the rule")?
+                .into_submit_evidence_request(dispute.dispute_id.clone());
+            Box::pin(disputes::submit_evidence(
+                state.clone(),
+                merchant_context.clone(),
+                Some(profile_id.clone()),
+                evidence_request,
+            ))
+            .await
+            .map(|_| ())
+        }
+    };
+    if let Err(error) = &action_result {
+        logger::warn!(?error, rule_name = %rule.name, "Failed to apply the dispute decision rule");
+    }
+
+    let automated_action = diesel_models::dispute::DisputeAutomatedAction {
+        rule_name: rule.name.clone(),
+        action: rule.action,
+        succeeded: action_result.is_ok(),
+        error_message: action_result
+            .err()
+            .map(|error| error.current_context().to_string()),
+        performed_at: common_utils::date_time::now(),
+    };
+    let dispute_id = dispute.dispute_id.clone();
+    let updated_dispute = state
+        .store
+        .find_dispute_by_merchant_id_dispute_id(
+            merchant_context.get_merchant_account().get_id(),
+            &dispute_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the dispute")?;
+    let updated_dispute = state
+        .store
+        .update_dispute(
+            updated_dispute,
+            diesel_models::dispute::DisputeUpdate::AutomatedActionUpdate { automated_action },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Unable to record the automated action of dispute with dispute_id: {dispute_id}"
+            )
+        })?;
+
+    // Submitting evidence notifies the merchant about the status change by itself
+    if rule.action == storage_enums::DisputeDecisionAction::Accept
+        && updated_dispute.dispute_status != dispute.dispute_status
+    {
+        disputes::trigger_dispute_status_webhook(
+            state,
+            merchant_context,
+            &updated_dispute,
+            updated_dispute.clone().foreign_into(),
+        )
+        .await
+        .map_err(|error| logger::warn!(disputes_outgoing_webhook_error=?error))
+        .ok();
+    }
+
+    Ok(Some(updated_dispute))
+}
+
+fn is_rule_matching(rule: &DisputeDecisionRule, dispute: &diesel_models::dispute::Dispute) -> bool {
+    let is_amount_matching = rule
+        .max_amount
+        .is_none_or(|max_amount| dispute.dispute_amount <= max_amount);
+    let is_currency_matching = rule.currencies.as_ref().is_none_or(|currencies| {
+        dispute
+            .dispute_currency
+            .is_some_and(|currency| currencies.contains(&currency))
+    });
+    let is_reason_code_matching = rule
+        .connector_reason_codes
+        .as_ref()
+        .is_none_or(|reason_codes| {
+            dispute
+                .connector_reason_code
+                .as_ref()
+                .is_some_and(|reason_code| reason_codes.contains(reason_code))
+        });
+    is_amount_matching && is_currency_matching && is_reason_code_matching
+}
+
+fn validate_dispute_decision_rules(rules: &[DisputeDecisionRule]) -> errors::RouterResult<()> {
+    rules.iter().try_for_each(|rule| {
+        common_utils::fp_utils::when(
+            rule.action == storage_enums::DisputeDecisionAction::SubmitEvidence
+                && rule.evidence.is_none(),
+            || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "evidence is required for the rule {} with the submit_evidence action",
+                        rule.name
+                    ),
+                })
+            },
+        )
+    })?;
+    Ok(())
+}
+
+async fn get_business_profile(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    profile_id: &common_utils::id_type::ProfileId,
+) -> errors::RouterResult<domain::Profile> {
+    super::utils::validate_and_get_business_profile(
+        state.store.as_ref(),
+        &state.into(),
+        merchant_context.get_merchant_key_store(),
+        Some(profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })
+}
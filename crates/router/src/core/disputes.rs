@@ -182,11 +182,22 @@ pub async fn retrieve_disputes_list(
         .await
         .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Unable to retrieve disputes")?;
+    let next_cursor = constraints.limit.and_then(|limit| {
+        core_utils::get_next_list_cursor(&disputes, i64::from(limit), |dispute| {
+            common_utils::types::ListCursor::new(dispute.modified_at, dispute.dispute_id.clone())
+        })
+    });
+    let headers = core_utils::get_next_list_cursor_headers(next_cursor)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the next disputes list cursor")?;
     let disputes_list = disputes
         .into_iter()
         .map(api_models::disputes::DisputeResponse::foreign_from)
         .collect();
-    Ok(services::ApplicationResponse::Json(disputes_list))
+    Ok(services::ApplicationResponse::JsonWithHeaders((
+        disputes_list,
+        headers,
+    )))
 }
 
 #[cfg(feature = "v2")]
@@ -524,9 +535,10 @@ pub async fn submit_evidence(
             submit_evidence_response.connector_status,
         )
     };
-    let update_dispute = diesel_models::dispute::DisputeUpdate::StatusUpdate {
+    let update_dispute = diesel_models::dispute::DisputeUpdate::EvidenceSubmissionUpdate {
         dispute_status,
         connector_status,
+        evidence_submitted_at: common_utils::date_time::now(),
     };
     let updated_dispute = db
         .update_dispute(dispute.clone(), update_dispute)
@@ -537,10 +549,62 @@ pub async fn submit_evidence(
         .attach_printable_lazy(|| {
             format!("Unable to update dispute with dispute_id: {dispute_id}")
         })?;
-    let dispute_response = api_models::disputes::DisputeResponse::foreign_from(updated_dispute);
+    let dispute_response =
+        api_models::disputes::DisputeResponse::foreign_from(updated_dispute.clone());
+    if updated_dispute.dispute_status != dispute.dispute_status {
+        trigger_dispute_status_webhook(
+            &state,
+            merchant_context,
+            &updated_dispute,
+            dispute_response.clone(),
+        )
+        .await
+        .map_err(|error| logger::warn!(disputes_outgoing_webhook_error=?error))
+        .ok();
+    }
     Ok(services::ApplicationResponse::Json(dispute_response))
 }
 
+/// Notifies the merchant about the status a dispute moved to by an action taken on it
+#[cfg(feature = "v1")]
+pub(crate) async fn trigger_dispute_status_webhook(
+    state: &SessionState,
+    merchant_context: domain::MerchantContext,
+    dispute: &diesel_models::dispute::Dispute,
+    dispute_response: dispute_models::DisputeResponse,
+) -> errors::CustomResult<(), errors::ApiErrorResponse> {
+    let profile_id = dispute
+        .profile_id
+        .as_ref()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not find profile_id in dispute")?;
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+    let event_type: storage_enums::EventType = dispute.dispute_status.into();
+
+    Box::pin(webhooks::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_context,
+        business_profile,
+        event_type,
+        storage_enums::EventClass::Disputes,
+        dispute.dispute_id.clone(),
+        storage_enums::EventObjectType::DisputeDetails,
+        api::OutgoingWebhookContent::DisputeDetails(Box::new(dispute_response)),
+        Some(dispute.created_at),
+    ))
+    .await
+}
+
 pub async fn attach_evidence(
     state: SessionState,
     merchant_context: domain::MerchantContext,
@@ -648,6 +712,7 @@ pub async fn retrieve_dispute_evidence(
 pub async fn delete_evidence(
     state: SessionState,
     merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
     delete_evidence_request: dispute_models::DeleteEvidenceRequest,
 ) -> RouterResponse<serde_json::Value> {
     let dispute_id = delete_evidence_request.dispute_id.clone();
@@ -661,12 +726,29 @@ pub async fn delete_evidence(
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
             dispute_id: dispute_id.clone(),
         })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &dispute)?;
+    common_utils::fp_utils::when(
+        !(dispute.dispute_stage == storage_enums::DisputeStage::Dispute
+            && dispute.dispute_status == storage_enums::DisputeStatus::DisputeOpened),
+        || {
+            Err(errors::ApiErrorResponse::DisputeStatusValidationFailed {
+                reason: format!(
+                "Evidence cannot be deleted because the dispute is in {} stage and has {} status",
+                dispute.dispute_stage, dispute.dispute_status
+            ),
+            })
+        },
+    )?;
     let dispute_evidence: api::DisputeEvidence = dispute
         .evidence
         .clone()
         .parse_value("DisputeEvidence")
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Error while parsing dispute evidence record")?;
+    let deleted_file_id = transformers::get_evidence_file_id(
+        &dispute_evidence,
+        delete_evidence_request.evidence_type.clone(),
+    );
     let updated_dispute_evidence =
         transformers::delete_evidence_file(dispute_evidence, delete_evidence_request.evidence_type);
     let update_dispute = diesel_models::dispute::DisputeUpdate::EvidenceUpdate {
@@ -686,6 +768,17 @@ pub async fn delete_evidence(
         .attach_printable_lazy(|| {
             format!("Unable to update dispute with dispute_id: {dispute_id}")
         })?;
+    // The file is no longer referenced by the dispute, failing to delete it only leaves it orphaned
+    if let Some(file_id) = deleted_file_id {
+        Box::pin(files::files_delete_core(
+            state,
+            merchant_context,
+            api::FileId { file_id },
+        ))
+        .await
+        .map_err(|error| logger::warn!(delete_evidence_file_error=?error))
+        .ok();
+    }
     Ok(services::ApplicationResponse::StatusOk)
 }
 
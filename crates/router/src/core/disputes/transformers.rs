@@ -279,6 +279,28 @@ pub fn delete_evidence_file(
     }
 }
 
+pub fn get_evidence_file_id(
+    dispute_evidence: &DisputeEvidence,
+    evidence_type: EvidenceType,
+) -> Option<String> {
+    match evidence_type {
+        EvidenceType::CancellationPolicy => dispute_evidence.cancellation_policy.clone(),
+        EvidenceType::CustomerCommunication => dispute_evidence.customer_communication.clone(),
+        EvidenceType::CustomerSignature => dispute_evidence.customer_signature.clone(),
+        EvidenceType::Receipt => dispute_evidence.receipt.clone(),
+        EvidenceType::RefundPolicy => dispute_evidence.refund_policy.clone(),
+        EvidenceType::ServiceDocumentation => dispute_evidence.service_documentation.clone(),
+        EvidenceType::ShippingDocumentation => dispute_evidence.shipping_documentation.clone(),
+        EvidenceType::InvoiceShowingDistinctTransactions => dispute_evidence
+            .invoice_showing_distinct_transactions
+            .clone(),
+        EvidenceType::RecurringTransactionAgreement => {
+            dispute_evidence.recurring_transaction_agreement.clone()
+        }
+        EvidenceType::UncategorizedFile => dispute_evidence.uncategorized_file.clone(),
+    }
+}
+
 pub async fn get_dispute_evidence_vec(
     state: &SessionState,
     merchant_context: domain::MerchantContext,
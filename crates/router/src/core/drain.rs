@@ -0,0 +1,25 @@
+use api_models::health_check::DrainStatusResponse;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::RouterResponse,
+    routes::SessionState,
+    services::{drain, ApplicationResponse},
+};
+
+/// Starts draining this instance of the application. Draining is not undone, the instance shuts
+/// down once drained.
+#[instrument(skip_all)]
+pub async fn start_drain(state: SessionState) -> RouterResponse<DrainStatusResponse> {
+    drain::start_drain(&state.conf.drain);
+
+    retrieve_drain_status().await
+}
+
+pub async fn retrieve_drain_status() -> RouterResponse<DrainStatusResponse> {
+    Ok(ApplicationResponse::Json(DrainStatusResponse {
+        is_draining: drain::is_draining(),
+        draining_for_in_secs: drain::get_draining_duration().map(|duration| duration.as_secs()),
+        in_flight_flows: drain::get_in_flight_flows(),
+    }))
+}
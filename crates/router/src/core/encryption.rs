@@ -1,15 +1,30 @@
+#[cfg(feature = "v1")]
+use std::time::{Duration, Instant};
+
 use api_models::admin::MerchantKeyTransferRequest;
 use base64::Engine;
 use common_utils::{
-    keymanager::transfer_key_to_key_manager,
-    types::keymanager::{EncryptionTransferRequest, Identifier},
+    keymanager::{rotate_key_in_key_manager, transfer_key_to_key_manager},
+    types::keymanager::{EncryptionRotateRequest, EncryptionTransferRequest, Identifier},
 };
 use error_stack::ResultExt;
 use hyperswitch_domain_models::merchant_key_store::MerchantKeyStore;
 use masking::ExposeInterface;
+#[cfg(feature = "v1")]
+use router_env::logger;
+use storage_impl::redis::cache;
 
 use crate::{consts::BASE64_ENGINE, errors, types::domain::UserKeyStore, SessionState};
 
+/// Number of records which are re-encrypted in a single run of the re-encryption workflow
+#[cfg(feature = "v1")]
+pub const RE_ENCRYPTION_BATCH_SIZE: u16 = 100;
+
+/// Number of merchant keys transferred in a single run of the key migration workflow, unless
+/// specified in the request
+#[cfg(feature = "v1")]
+pub const DEFAULT_KEY_MIGRATION_BATCH_SIZE: u32 = 100;
+
 pub async fn transfer_encryption_key(
     state: &SessionState,
     req: MerchantKeyTransferRequest,
@@ -33,18 +48,28 @@ pub async fn send_request_to_key_service_for_merchant(
 ) -> errors::CustomResult<usize, errors::ApiErrorResponse> {
     let total = keys.len();
     for key in keys {
-        let key_encoded = BASE64_ENGINE.encode(key.key.clone().into_inner().expose());
-        let req = EncryptionTransferRequest {
-            identifier: Identifier::Merchant(key.merchant_id.clone()),
-            key: key_encoded,
-        };
-        transfer_key_to_key_manager(&state.into(), req)
+        transfer_merchant_key(state, &key)
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)?;
     }
     Ok(total)
 }
 
+/// Transfers the merchant key to the key manager, returning the version of the key
+async fn transfer_merchant_key(
+    state: &SessionState,
+    key: &MerchantKeyStore,
+) -> errors::CustomResult<String, common_utils::errors::KeyManagerError> {
+    let key_encoded = BASE64_ENGINE.encode(key.key.clone().into_inner().expose());
+    let req = EncryptionTransferRequest {
+        identifier: Identifier::Merchant(key.merchant_id.clone()),
+        key: key_encoded,
+    };
+    transfer_key_to_key_manager(&state.into(), req)
+        .await
+        .map(|response| response.key_version)
+}
+
 pub async fn send_request_to_key_service_for_user(
     state: &SessionState,
     keys: Vec<UserKeyStore>,
@@ -61,3 +86,486 @@ pub async fn send_request_to_key_service_for_user(
     .change_context(errors::ApiErrorResponse::InternalServerError)
     .map(|v| v.len())
 }
+
+pub async fn rotate_encryption_key(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> errors::CustomResult<String, errors::ApiErrorResponse> {
+    let req = EncryptionRotateRequest {
+        identifier: Identifier::Merchant(merchant_id.clone()),
+    };
+    let key_version = rotate_key_in_key_manager(&state.into(), req)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to rotate merchant key in key manager")
+        .map(|response| response.key_version)?;
+
+    let data_key_cache_key = format!("data_key_{}", merchant_id.get_string_repr());
+    cache::redact_from_redis_and_publish(
+        state.store.get_cache_store().as_ref(),
+        [cache::CacheKind::DataKey(data_key_cache_key.into())],
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to invalidate the cached merchant data key")?;
+
+    Ok(key_version)
+}
+
+/// Schedules the re-encryption of the merchant's data after the merchant key has been rotated
+#[cfg(feature = "v1")]
+pub async fn add_merchant_key_re_encryption_task(
+    db: &dyn crate::db::StorageInterface,
+    tracking_data: crate::types::storage::MerchantKeyReEncryptionTrackingData,
+) -> errors::CustomResult<String, errors::ApiErrorResponse> {
+    let runner = crate::types::storage::ProcessTrackerRunner::MerchantKeyReEncryptionWorkflow;
+    let task = "MERCHANT_KEY_RE_ENCRYPTION";
+    let tag = ["MERCHANT_KEY", "RE_ENCRYPTION"];
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        task,
+        &tracking_data.key_version,
+        &tracking_data.merchant_id,
+    );
+    let process_tracker_entry = crate::types::storage::ProcessTrackerNew::new(
+        process_tracker_id.clone(),
+        task,
+        runner,
+        tag,
+        tracking_data,
+        None,
+        common_utils::date_time::now(),
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct merchant key re-encryption process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert merchant key re-encryption task to process tracker")?;
+    crate::routes::metrics::TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "MerchantKeyReEncryption")),
+    );
+
+    Ok(process_tracker_id)
+}
+
+/// Schedules the transfer of the locally encrypted merchant keys to the key manager
+#[cfg(feature = "v1")]
+pub async fn add_merchant_key_migration_task(
+    db: &dyn crate::db::StorageInterface,
+    tracking_data: crate::types::storage::MerchantKeyMigrationTrackingData,
+) -> errors::CustomResult<String, errors::ApiErrorResponse> {
+    let runner = crate::types::storage::ProcessTrackerRunner::MerchantKeyMigrationWorkflow;
+    let task = "MERCHANT_KEY_MIGRATION";
+    let tag = ["MERCHANT_KEY", "MIGRATION"];
+    let process_tracker_id = common_utils::generate_time_ordered_id(&format!("{runner}_{task}"));
+    let process_tracker_entry = crate::types::storage::ProcessTrackerNew::new(
+        process_tracker_id.clone(),
+        task,
+        runner,
+        tag,
+        tracking_data,
+        None,
+        common_utils::date_time::now(),
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct merchant key migration process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert merchant key migration task to process tracker")?;
+    crate::routes::metrics::TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "MerchantKeyMigration")),
+    );
+
+    Ok(process_tracker_id)
+}
+
+/// Transfers the batch of merchant keys starting at the offset of the tracking data to the key
+/// manager, spacing out the transfers as per `max_transfers_per_second`. The merchants whose keys
+/// could not be migrated are recorded in the tracking data instead of failing the batch, so that
+/// a single merchant does not block the migration.
+///
+/// Returns the tracking data updated with the progress of the batch, and whether all the merchant
+/// keys have been processed.
+#[cfg(feature = "v1")]
+pub async fn migrate_merchant_keys(
+    state: &SessionState,
+    mut tracking_data: crate::types::storage::MerchantKeyMigrationTrackingData,
+) -> errors::CustomResult<
+    (
+        crate::types::storage::MerchantKeyMigrationTrackingData,
+        bool,
+    ),
+    errors::ApiErrorResponse,
+> {
+    let db = &*state.store;
+    let key_stores = db
+        .get_all_key_stores(
+            &state.into(),
+            &db.get_master_key().to_vec().into(),
+            tracking_data.offset,
+            tracking_data.batch_size,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch merchant key stores")?;
+    let processed = u32::try_from(key_stores.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let is_last_batch = processed < tracking_data.batch_size;
+
+    if !tracking_data.dry_run {
+        let transfer_interval = tracking_data
+            .max_transfers_per_second
+            .filter(|transfers_per_second| *transfers_per_second > 0)
+            .map(|transfers_per_second| Duration::from_secs(1) / transfers_per_second);
+
+        for key_store in key_stores {
+            let started_at = Instant::now();
+            match migrate_merchant_key(state, &key_store, tracking_data.re_encrypt_data).await {
+                Ok(()) => tracking_data.transferred = tracking_data.transferred.saturating_add(1),
+                Err(error) => {
+                    logger::error!(
+                        ?error,
+                        merchant_id = ?key_store.merchant_id,
+                        "Failed to migrate merchant key to key manager"
+                    );
+                    tracking_data
+                        .failed_merchant_ids
+                        .push(key_store.merchant_id);
+                }
+            }
+
+            if let Some(transfer_interval) = transfer_interval {
+                tokio::time::sleep(transfer_interval.saturating_sub(started_at.elapsed())).await;
+            }
+        }
+    }
+
+    tracking_data.offset = tracking_data.offset.saturating_add(processed);
+
+    Ok((tracking_data, is_last_batch))
+}
+
+#[cfg(feature = "v1")]
+async fn migrate_merchant_key(
+    state: &SessionState,
+    key_store: &MerchantKeyStore,
+    re_encrypt_data: bool,
+) -> errors::CustomResult<(), errors::ApiErrorResponse> {
+    let key_version = transfer_merchant_key(state, key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    if re_encrypt_data {
+        add_merchant_key_re_encryption_task(
+            &*state.store,
+            crate::types::storage::MerchantKeyReEncryptionTrackingData {
+                merchant_id: key_store.merchant_id.clone(),
+                key_version,
+                stage: crate::types::storage::MerchantKeyReEncryptionStage::MerchantAccount,
+                offset: 0,
+            },
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Re-encrypts a batch of records of the given stage, starting at `offset`.
+///
+/// Returns the number of records re-encrypted. A value lower than [`RE_ENCRYPTION_BATCH_SIZE`]
+/// indicates that all the records of the stage have been re-encrypted.
+#[cfg(feature = "v1")]
+pub async fn re_encrypt_merchant_data(
+    state: &SessionState,
+    merchant_account: &hyperswitch_domain_models::merchant_account::MerchantAccount,
+    key_store: &MerchantKeyStore,
+    stage: diesel_models::merchant_key_store::MerchantKeyReEncryptionStage,
+    offset: u32,
+) -> errors::CustomResult<usize, errors::ApiErrorResponse> {
+    use diesel_models::merchant_key_store::MerchantKeyReEncryptionStage;
+
+    match stage {
+        MerchantKeyReEncryptionStage::MerchantAccount => {
+            re_encrypt_merchant_account(state, merchant_account, key_store).await
+        }
+        MerchantKeyReEncryptionStage::MerchantConnectorAccount => {
+            re_encrypt_merchant_connector_accounts(state, key_store, offset).await
+        }
+        MerchantKeyReEncryptionStage::Customer => {
+            re_encrypt_customers(state, merchant_account, key_store, offset).await
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+async fn re_encrypt_merchant_account(
+    state: &SessionState,
+    merchant_account: &hyperswitch_domain_models::merchant_account::MerchantAccount,
+    key_store: &MerchantKeyStore,
+) -> errors::CustomResult<usize, errors::ApiErrorResponse> {
+    let key_manager_state = &state.into();
+    let table_name =
+        common_utils::type_name!(hyperswitch_domain_models::merchant_account::MerchantAccount);
+    let merchant_account_update = crate::types::storage::MerchantAccountUpdate::Update {
+        merchant_name: re_encrypt_optional(
+            key_manager_state,
+            table_name,
+            merchant_account.merchant_name.clone(),
+            key_store,
+        )
+        .await?,
+        merchant_details: re_encrypt_optional(
+            key_manager_state,
+            table_name,
+            merchant_account.merchant_details.clone(),
+            key_store,
+        )
+        .await?,
+        return_url: None,
+        webhook_details: None,
+        sub_merchants_enabled: None,
+        parent_merchant_id: None,
+        enable_payment_response_hash: None,
+        payment_response_hash_key: None,
+        redirect_to_merchant_with_http_post: None,
+        publishable_key: None,
+        locker_id: None,
+        metadata: None,
+        routing_algorithm: None,
+        primary_business_details: None,
+        intent_fulfillment_time: None,
+        frm_routing_algorithm: None,
+        payout_routing_algorithm: None,
+        default_profile: None,
+        payment_link_config: None,
+        pm_collect_link_config: None,
+        allowed_ip_ranges: None,
+    };
+
+    state
+        .store
+        .update_specific_fields_in_merchant(
+            key_manager_state,
+            merchant_account.get_id(),
+            merchant_account_update,
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update re-encrypted merchant account")?;
+
+    Ok(1)
+}
+
+#[cfg(feature = "v1")]
+async fn re_encrypt_merchant_connector_accounts(
+    state: &SessionState,
+    key_store: &MerchantKeyStore,
+    offset: u32,
+) -> errors::CustomResult<usize, errors::ApiErrorResponse> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+    let table_name = common_utils::type_name!(crate::types::domain::MerchantConnectorAccount);
+    let merchant_connector_accounts = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            key_manager_state,
+            &key_store.merchant_id,
+            true,
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch merchant connector accounts")?
+        .into_iter()
+        .skip(usize::try_from(offset).unwrap_or(usize::MAX))
+        .take(usize::from(RE_ENCRYPTION_BATCH_SIZE))
+        .collect::<Vec<_>>();
+    let total = merchant_connector_accounts.len();
+
+    for merchant_connector_account in merchant_connector_accounts {
+        let connector_account_details = re_encrypt_optional(
+            key_manager_state,
+            table_name,
+            Some(merchant_connector_account.connector_account_details.clone()),
+            key_store,
+        )
+        .await?;
+        let connector_wallets_details = re_encrypt_optional(
+            key_manager_state,
+            table_name,
+            merchant_connector_account.connector_wallets_details.clone(),
+            key_store,
+        )
+        .await?;
+        let additional_merchant_data = re_encrypt_optional(
+            key_manager_state,
+            table_name,
+            merchant_connector_account.additional_merchant_data.clone(),
+            key_store,
+        )
+        .await?;
+        let merchant_connector_account_update =
+            crate::types::storage::MerchantConnectorAccountUpdate::Update {
+                connector_type: None,
+                connector_name: None,
+                connector_account_details: Box::new(connector_account_details),
+                test_mode: None,
+                disabled: None,
+                merchant_connector_id: None,
+                payment_methods_enabled: None,
+                metadata: None,
+                frm_configs: None,
+                connector_webhook_details: Box::new(None),
+                applepay_verified_domains: None,
+                pm_auth_config: Box::new(None),
+                connector_label: None,
+                status: None,
+                connector_wallets_details: Box::new(connector_wallets_details),
+                additional_merchant_data: Box::new(additional_merchant_data),
+            };
+
+        db.update_merchant_connector_account(
+            key_manager_state,
+            merchant_connector_account,
+            merchant_connector_account_update.into(),
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update re-encrypted merchant connector account")?;
+    }
+
+    Ok(total)
+}
+
+#[cfg(feature = "v1")]
+async fn re_encrypt_customers(
+    state: &SessionState,
+    merchant_account: &hyperswitch_domain_models::merchant_account::MerchantAccount,
+    key_store: &MerchantKeyStore,
+    offset: u32,
+) -> errors::CustomResult<usize, errors::ApiErrorResponse> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+    let table_name = common_utils::type_name!(crate::types::domain::Customer);
+    let customers = db
+        .list_customers_by_merchant_id(
+            key_manager_state,
+            &key_store.merchant_id,
+            key_store,
+            crate::db::customers::CustomerListConstraints {
+                limit: RE_ENCRYPTION_BATCH_SIZE,
+                offset: Some(offset),
+                cursor: None,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list customers")?;
+    let total = customers.len();
+
+    for customer in customers {
+        let customer_update = crate::types::storage::CustomerUpdate::Update {
+            name: re_encrypt_optional(
+                key_manager_state,
+                table_name,
+                customer.name.clone(),
+                key_store,
+            )
+            .await?,
+            email: re_encrypt_optional(
+                key_manager_state,
+                table_name,
+                customer.email.clone(),
+                key_store,
+            )
+            .await?,
+            phone: Box::new(
+                re_encrypt_optional(
+                    key_manager_state,
+                    table_name,
+                    customer.phone.clone(),
+                    key_store,
+                )
+                .await?,
+            ),
+            description: None,
+            phone_country_code: None,
+            metadata: Box::new(None),
+            connector_customer: Box::new(None),
+            address_id: None,
+            tax_registration_id: re_encrypt_optional(
+                key_manager_state,
+                table_name,
+                customer.tax_registration_id.clone(),
+                key_store,
+            )
+            .await?,
+        };
+
+        db.update_customer_by_customer_id_merchant_id(
+            key_manager_state,
+            customer.customer_id.clone(),
+            key_store.merchant_id.clone(),
+            customer,
+            customer_update,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update re-encrypted customer")?;
+    }
+
+    Ok(total)
+}
+
+/// Encrypts the decrypted value again, so that it is encrypted with the currently active key
+#[cfg(feature = "v1")]
+async fn re_encrypt_optional<T, S>(
+    key_manager_state: &common_utils::types::keymanager::KeyManagerState,
+    table_name: &str,
+    data: Option<common_utils::crypto::Encryptable<masking::Secret<T, S>>>,
+    key_store: &MerchantKeyStore,
+) -> errors::CustomResult<
+    Option<common_utils::crypto::Encryptable<masking::Secret<T, S>>>,
+    errors::ApiErrorResponse,
+>
+where
+    T: Clone + Send,
+    S: masking::Strategy<T> + Send,
+    masking::Secret<T, S>: Send,
+    common_utils::crypto::Encryptable<masking::Secret<T, S>>:
+        hyperswitch_domain_models::type_encryption::TypeEncryption<
+            T,
+            common_utils::crypto::GcmAes256,
+            S,
+        >,
+{
+    use masking::PeekInterface;
+
+    use crate::types::domain::types as domain_types;
+
+    domain_types::crypto_operation(
+        key_manager_state,
+        table_name,
+        domain_types::CryptoOperation::EncryptOptional(
+            data.map(common_utils::crypto::Encryptable::into_inner),
+        ),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_optionaloperation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to re-encrypt data")
+}
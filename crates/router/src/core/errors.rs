@@ -391,6 +391,16 @@ pub enum RoutingError {
     InvalidTransactionType,
     #[error("Routing events error: {message}, status code: {status_code}")]
     RoutingEventsError { message: String, status_code: u16 },
+    #[error("Unable to retrieve latency based routing config")]
+    LatencyBasedRoutingConfigError,
+    #[error("Unable to retrieve connector latency stats")]
+    ConnectorLatencyFetchFailed,
+    #[error("Unable to retrieve connector fee schedule")]
+    ConnectorFeeScheduleFetchFailed,
+    #[error("Unable to retrieve routing experiment")]
+    RoutingExperimentFetchFailed,
+    #[error("Unable to retrieve connector health")]
+    ConnectorHealthFetchFailed,
 }
 
 #[derive(Debug, Clone, thiserror::Error)]
@@ -114,6 +114,26 @@ pub enum UserErrors {
     InvalidCloneConnectorOperation(String),
     #[error("Error cloning connector: {0}")]
     ErrorCloningConnector(String),
+    #[error("SCIM resource not found")]
+    ScimResourceNotFound,
+    #[error("SCIM resource already exists")]
+    ScimResourceAlreadyExists,
+    #[error("Invalid SCIM request: {0}")]
+    InvalidScimRequest(String),
+    #[error("PasskeyNotSetup")]
+    PasskeyNotSetup,
+    #[error("PasskeyRequired")]
+    PasskeyRequired,
+    #[error("InvalidPasskey")]
+    InvalidPasskey,
+    #[error("PasskeyChallengeNotFound")]
+    PasskeyChallengeNotFound,
+    #[error("PasskeyNotFound")]
+    PasskeyNotFound,
+    #[error("MaxPasskeysReached")]
+    MaxPasskeysReached,
+    #[error("SessionNotFound")]
+    SessionNotFound,
 }
 
 impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorResponse> for UserErrors {
@@ -298,6 +318,36 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
                 self.get_error_message(),
                 None,
             )),
+            Self::ScimResourceNotFound => {
+                AER::NotFound(ApiError::new(sub_code, 60, self.get_error_message(), None))
+            }
+            Self::ScimResourceAlreadyExists => {
+                AER::Conflict(ApiError::new(sub_code, 61, self.get_error_message(), None))
+            }
+            Self::InvalidScimRequest(_) => {
+                AER::BadRequest(ApiError::new(sub_code, 62, self.get_error_message(), None))
+            }
+            Self::PasskeyNotSetup => {
+                AER::BadRequest(ApiError::new(sub_code, 63, self.get_error_message(), None))
+            }
+            Self::PasskeyRequired => {
+                AER::BadRequest(ApiError::new(sub_code, 64, self.get_error_message(), None))
+            }
+            Self::InvalidPasskey => {
+                AER::BadRequest(ApiError::new(sub_code, 65, self.get_error_message(), None))
+            }
+            Self::PasskeyChallengeNotFound => {
+                AER::BadRequest(ApiError::new(sub_code, 66, self.get_error_message(), None))
+            }
+            Self::PasskeyNotFound => {
+                AER::NotFound(ApiError::new(sub_code, 67, self.get_error_message(), None))
+            }
+            Self::MaxPasskeysReached => {
+                AER::BadRequest(ApiError::new(sub_code, 68, self.get_error_message(), None))
+            }
+            Self::SessionNotFound => {
+                AER::NotFound(ApiError::new(sub_code, 69, self.get_error_message(), None))
+            }
         }
     }
 }
@@ -374,6 +424,16 @@ impl UserErrors {
             Self::ErrorCloningConnector(error_message) => {
                 format!("Error cloning connector: {error_message}")
             }
+            Self::ScimResourceNotFound => "SCIM resource not found".to_string(),
+            Self::ScimResourceAlreadyExists => "SCIM resource already exists".to_string(),
+            Self::InvalidScimRequest(message) => format!("Invalid SCIM request: {message}"),
+            Self::PasskeyNotSetup => "Passkey not setup".to_string(),
+            Self::PasskeyRequired => "Passkey authentication is required".to_string(),
+            Self::InvalidPasskey => "Invalid passkey".to_string(),
+            Self::PasskeyChallengeNotFound => "Passkey challenge expired or not found".to_string(),
+            Self::PasskeyNotFound => "Passkey not found".to_string(),
+            Self::MaxPasskeysReached => "Maximum number of passkeys reached".to_string(),
+            Self::SessionNotFound => "Session not found".to_string(),
         }
     }
 }
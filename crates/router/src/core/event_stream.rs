@@ -0,0 +1,211 @@
+use std::{collections::HashMap, time::Duration};
+
+use actix_web::web;
+use api_models::event_stream as event_stream_api;
+use common_utils::{date_time, id_type};
+use error_stack::{report, ResultExt};
+use futures::Stream;
+use rdkafka::{consumer::StreamConsumer, Message};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult},
+    events::EventsConfig,
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::domain,
+};
+
+/// The consolidated event produced to Kafka for every change of a payment, refund or dispute
+#[derive(Debug, serde::Deserialize)]
+struct ConsolidatedEvent {
+    log: serde_json::Value,
+    log_type: ConsolidatedEventType,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum ConsolidatedEventType {
+    PaymentIntent,
+    Refund,
+    Dispute,
+    #[serde(other)]
+    Other,
+}
+
+/// The fields of the consolidated payment intent, refund and dispute events which are sent on the
+/// event stream
+#[derive(Debug, serde::Deserialize)]
+struct ConsolidatedEventLog {
+    tenant_id: String,
+    merchant_id: id_type::MerchantId,
+    payment_id: String,
+    refund_id: Option<String>,
+    dispute_id: Option<String>,
+    status: Option<String>,
+    refund_status: Option<String>,
+    dispute_status: Option<String>,
+    profile_id: Option<id_type::ProfileId>,
+    #[serde(with = "time::serde::timestamp::nanoseconds")]
+    modified_at: time::OffsetDateTime,
+}
+
+struct EventStreamState {
+    consumer: StreamConsumer,
+    subscription: event_stream_api::EventStreamSubscription,
+    /// The last status sent for each object, which drops the changes of an object that leave its
+    /// status as is
+    statuses: HashMap<(&'static str, String), String>,
+}
+
+/// Opens an event stream for the merchant. The events are streamed by [`get_event_stream`] once
+/// the request is authenticated.
+#[instrument(skip_all)]
+pub async fn open_event_stream(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+) -> RouterResponse<event_stream_api::EventStreamSubscription> {
+    if state.conf.events.get_kafka_settings().is_none() {
+        return Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: "Event stream is not enabled".to_string(),
+        }));
+    }
+
+    Ok(ApplicationResponse::Json(
+        event_stream_api::EventStreamSubscription {
+            subscription_id: common_utils::generate_time_ordered_id("event_stream"),
+            merchant_id: merchant_context.get_merchant_account().get_id().clone(),
+            tenant_id: state.tenant.tenant_id.clone(),
+        },
+    ))
+}
+
+/// Streams the status changes of the payments, refunds and disputes of the merchant as
+/// Server-Sent Events, from the consolidated events produced to Kafka. A comment is sent when no
+/// event was streamed for a while, which keeps the connection open through proxies. The stream
+/// ends when the events can no longer be received, and the client is expected to reconnect.
+pub fn get_event_stream(
+    events_config: &EventsConfig,
+    subscription: event_stream_api::EventStreamSubscription,
+) -> RouterResult<impl Stream<Item = Result<web::Bytes, actix_web::Error>>> {
+    let consumer = events_config
+        .get_kafka_settings()
+        .ok_or(errors::ApiErrorResponse::NotSupported {
+            message: "Event stream is not enabled".to_string(),
+        })?
+        .create_consolidated_events_consumer(&subscription.subscription_id)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to create the consumer of the event stream")?;
+    let keep_alive_interval =
+        Duration::from_secs(consts::EVENT_STREAM_KEEP_ALIVE_INTERVAL_IN_SECONDS);
+
+    let stream_state = EventStreamState {
+        consumer,
+        subscription,
+        statuses: HashMap::new(),
+    };
+
+    Ok(futures::stream::unfold(
+        stream_state,
+        move |mut stream_state| async move {
+            loop {
+                let payload =
+                    match tokio::time::timeout(keep_alive_interval, stream_state.consumer.recv())
+                        .await
+                    {
+                        Err(_) => {
+                            return Some((
+                                Ok(web::Bytes::from_static(b": keep-alive\n\n")),
+                                stream_state,
+                            ))
+                        }
+                        Ok(Err(error)) => {
+                            logger::error!(
+                                ?error,
+                                "Failed to receive the events of the event stream"
+                            );
+                            return None;
+                        }
+                        Ok(Ok(message)) => message.payload().map(<[u8]>::to_vec),
+                    };
+
+                if let Some(frame) = payload.and_then(|payload| stream_state.get_frame(&payload)) {
+                    return Some((Ok(frame), stream_state));
+                }
+            }
+        },
+    ))
+}
+
+impl EventStreamState {
+    /// Builds the Server-Sent Event of a consolidated event, if the event is a status change of
+    /// an object of the merchant
+    fn get_frame(&mut self, payload: &[u8]) -> Option<web::Bytes> {
+        let consolidated_event = serde_json::from_slice::<ConsolidatedEvent>(payload)
+            .map_err(|error| logger::warn!(?error, "Failed to parse the consolidated event"))
+            .ok()?;
+        let object_type = match consolidated_event.log_type {
+            ConsolidatedEventType::PaymentIntent => {
+                event_stream_api::EventStreamObjectType::Payment
+            }
+            ConsolidatedEventType::Refund => event_stream_api::EventStreamObjectType::Refund,
+            ConsolidatedEventType::Dispute => event_stream_api::EventStreamObjectType::Dispute,
+            ConsolidatedEventType::Other => return None,
+        };
+        let log = serde_json::from_value::<ConsolidatedEventLog>(consolidated_event.log)
+            .map_err(|error| logger::warn!(?error, "Failed to parse the consolidated event log"))
+            .ok()?;
+        if log.merchant_id != self.subscription.merchant_id
+            || log.tenant_id != self.subscription.tenant_id.get_string_repr()
+        {
+            return None;
+        }
+
+        let (object_id, status) = match object_type {
+            event_stream_api::EventStreamObjectType::Payment => {
+                (Some(log.payment_id.clone()), log.status)
+            }
+            event_stream_api::EventStreamObjectType::Refund => (log.refund_id, log.refund_status),
+            event_stream_api::EventStreamObjectType::Dispute => {
+                (log.dispute_id, log.dispute_status)
+            }
+        };
+        let (object_id, status) = object_id.zip(status)?;
+        let event_name = get_event_name(object_type);
+
+        if self.statuses.len() >= consts::EVENT_STREAM_MAX_TRACKED_OBJECTS {
+            self.statuses.clear();
+        }
+        let previous_status = self
+            .statuses
+            .insert((event_name, object_id.clone()), status.clone());
+        if previous_status.as_ref() == Some(&status) {
+            return None;
+        }
+
+        let event = event_stream_api::EventStreamEvent {
+            object_type,
+            object_id,
+            payment_id: log.payment_id,
+            profile_id: log.profile_id,
+            status,
+            modified_at: date_time::convert_to_pdt(log.modified_at),
+        };
+        let data = serde_json::to_string(&event)
+            .map_err(|error| logger::error!(?error, "Failed to serialize the event stream event"))
+            .ok()?;
+
+        Some(web::Bytes::from(format!(
+            "event: {event_name}\ndata: {data}\n\n"
+        )))
+    }
+}
+
+fn get_event_name(object_type: event_stream_api::EventStreamObjectType) -> &'static str {
+    match object_type {
+        event_stream_api::EventStreamObjectType::Payment => "payment",
+        event_stream_api::EventStreamObjectType::Refund => "refund",
+        event_stream_api::EventStreamObjectType::Dispute => "dispute",
+    }
+}
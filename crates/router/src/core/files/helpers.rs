@@ -39,6 +39,7 @@ pub async fn validate_file_upload(
     //File Validation based on the purpose of file upload
     match create_file_request.purpose {
         api::FilePurpose::DisputeEvidence => {
+            validate_dispute_evidence_file(state, &create_file_request)?;
             let dispute_id = &create_file_request
                 .dispute_id
                 .ok_or(errors::ApiErrorResponse::MissingDisputeId)?;
@@ -84,6 +85,40 @@ pub async fn validate_file_upload(
     }
 }
 
+/// Validates the size and the type of an evidence file against the limits configured for all
+/// connectors, before the connector specific validation
+fn validate_dispute_evidence_file(
+    state: &SessionState,
+    create_file_request: &api::CreateFileRequest,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let evidence_settings = &state.conf.dispute_evidence;
+    common_utils::fp_utils::when(
+        create_file_request.file_size > evidence_settings.max_file_size,
+        || {
+            Err(errors::ApiErrorResponse::FileValidationFailed {
+                reason: format!(
+                    "file_size exceeds the maximum of {} bytes",
+                    evidence_settings.max_file_size
+                ),
+            })
+        },
+    )?;
+    common_utils::fp_utils::when(
+        !evidence_settings
+            .allowed_file_types
+            .contains(create_file_request.file_type.essence_str()),
+        || {
+            Err(errors::ApiErrorResponse::FileValidationFailed {
+                reason: format!(
+                    "file_type {} is not allowed for dispute evidence",
+                    create_file_request.file_type.essence_str()
+                ),
+            })
+        },
+    )?;
+    Ok(())
+}
+
 pub async fn delete_file_using_file_id(
     state: &SessionState,
     file_key: String,
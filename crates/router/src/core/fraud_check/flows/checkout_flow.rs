@@ -16,6 +16,7 @@ use crate::{
         domain,
         fraud_check::{FraudCheckCheckoutData, FraudCheckResponseData, FrmCheckoutRouterData},
         storage::enums as storage_enums,
+        transformers::ForeignFrom,
         BrowserInformation, ConnectorAuthType, MerchantRecipientData, ResponseId, RouterData,
     },
     SessionState,
@@ -122,6 +123,11 @@ impl ConstructFlowSpecificData<frm_api::Checkout, FraudCheckCheckoutData, FraudC
                         field_name: "customer.customer_data.email",
                     })?,
                 gateway: self.payment_attempt.connector.clone(),
+                device_fingerprint: self
+                    .payment_attempt
+                    .device_fingerprint
+                    .clone()
+                    .map(ForeignFrom::foreign_from),
             }, // self.order_details
             response: Ok(FraudCheckResponseData::TransactionResponse {
                 resource_id: ResponseId::ConnectorTransactionId("".to_string()),
@@ -147,6 +153,7 @@ impl ConstructFlowSpecificData<frm_api::Checkout, FraudCheckCheckoutData, FraudC
             connector_http_status_code: None,
             external_latency: None,
             connector_api_version: None,
+            merchant_connector_id: None,
             apple_pay_flow: None,
             frm_metadata: self.frm_metadata.clone(),
             refund_id: None,
@@ -119,6 +119,7 @@ pub async fn construct_fulfillment_router_data<'a>(
         quote_id: None,
         test_mode,
         connector_api_version: None,
+        merchant_connector_id: None,
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow: None,
@@ -118,6 +118,7 @@ impl ConstructFlowSpecificData<RecordReturn, FraudCheckRecordReturnData, FraudCh
             connector_http_status_code: None,
             external_latency: None,
             connector_api_version: None,
+            merchant_connector_id: None,
             apple_pay_flow: None,
             frm_metadata: None,
             refund_id: None,
@@ -15,6 +15,7 @@ use crate::{
         domain,
         fraud_check::{FraudCheckResponseData, FraudCheckSaleData, FrmSaleRouterData},
         storage::enums as storage_enums,
+        transformers::ForeignFrom,
         ConnectorAuthType, MerchantRecipientData, ResponseId, RouterData,
     },
     SessionState,
@@ -100,6 +101,11 @@ impl ConstructFlowSpecificData<frm_api::Sale, FraudCheckSaleData, FraudCheckResp
                     .change_context(errors::ApiErrorResponse::InvalidDataValue {
                         field_name: "customer.customer_data.email",
                     })?,
+                device_fingerprint: self
+                    .payment_attempt
+                    .device_fingerprint
+                    .clone()
+                    .map(ForeignFrom::foreign_from),
             },
             response: Ok(FraudCheckResponseData::TransactionResponse {
                 resource_id: ResponseId::ConnectorTransactionId("".to_string()),
@@ -126,6 +132,7 @@ impl ConstructFlowSpecificData<frm_api::Sale, FraudCheckSaleData, FraudCheckResp
             connector_http_status_code: None,
             external_latency: None,
             connector_api_version: None,
+            merchant_connector_id: None,
             apple_pay_flow: None,
             frm_metadata: self.frm_metadata.clone(),
             refund_id: None,
@@ -134,6 +134,7 @@ impl
             connector_http_status_code: None,
             external_latency: None,
             connector_api_version: None,
+            merchant_connector_id: None,
             payment_method_status: None,
             apple_pay_flow: None,
             frm_metadata: self.frm_metadata.clone(),
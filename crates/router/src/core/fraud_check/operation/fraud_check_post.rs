@@ -205,6 +205,7 @@ where
                 order_details: router_data.request.order_details,
                 currency: router_data.request.currency,
                 email: router_data.request.email,
+                device_fingerprint: router_data.request.device_fingerprint,
             }),
             response: FrmResponse::Sale(router_data.response),
         }))
@@ -308,6 +309,7 @@ where
                 statement_descriptor_suffix: None,
                 statement_descriptor_prefix: None,
                 merchant_connector_details: None,
+                tip_amount: None,
             };
             let capture_response = Box::pin(payments::payments_core::<
                 Capture,
@@ -366,6 +368,7 @@ where
                 order_details: router_data.request.order_details,
                 currency: router_data.request.currency,
                 email: router_data.request.email,
+                device_fingerprint: router_data.request.device_fingerprint,
             }),
             response: FrmResponse::Sale(router_data.response),
         })
@@ -242,6 +242,7 @@ where
                 payment_method_data: router_data.request.payment_method_data,
                 email: router_data.request.email,
                 gateway: router_data.request.gateway,
+                device_fingerprint: router_data.request.device_fingerprint,
             })),
             response: FrmResponse::Checkout(router_data.response),
         })
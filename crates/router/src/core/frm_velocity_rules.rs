@@ -0,0 +1,612 @@
+use std::collections::HashMap;
+
+use api_models::frm_velocity_rules::{
+    FrmVelocityRule, FrmVelocityRulesConfigReq, FrmVelocityRulesRecord, FrmVelocityRulesResponse,
+};
+use common_enums::{FrmVelocityAction, FrmVelocityDimension, MerchantDecision};
+use common_utils::{
+    crypto::{HmacSha256, SignMessage},
+    ext_traits::{Encode, OptionExt, StringExt},
+};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use serde::Deserialize;
+
+use crate::{
+    consts,
+    core::{
+        blocklist::utils as blocklist_utils,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    },
+    routes::{metrics, SessionState},
+    services::api as service_api,
+    types::{domain, storage, BrowserInformation},
+};
+
+/// Counts the payments recorded in the sliding window of a dimension value. The payment is added
+/// to the window first, so that it is included in the counts, and the entries older than the
+/// longest window are removed. Returns the count of every window passed after the longest one.
+const RECORD_PAYMENT_SCRIPT: &str = r#"
+    local time = redis.call("TIME")
+    local now = tonumber(time[1]) + tonumber(time[2]) / 1000000
+    local max_window = tonumber(ARGV[1])
+    redis.call("ZREMRANGEBYSCORE", KEYS[1], "-inf", now - max_window)
+    redis.call("ZADD", KEYS[1], now, ARGV[2])
+    redis.call("EXPIRE", KEYS[1], max_window)
+    local counts = {}
+    for index = 3, #ARGV do
+        counts[#counts + 1] = redis.call("ZCOUNT", KEYS[1], now - tonumber(ARGV[index]), "+inf")
+    end
+    return counts
+"#;
+
+/// Rules of a profile as read when evaluating payments. Profiles without rules get an empty
+/// config, which is cached.
+#[derive(Debug, Default, Deserialize)]
+struct FrmVelocityRules {
+    #[serde(default)]
+    rules: Vec<FrmVelocityRule>,
+}
+
+/// The rule whose action is taken on a payment
+#[derive(Debug, Clone)]
+pub struct FrmVelocityDecision {
+    pub rule_name: String,
+    pub action: FrmVelocityAction,
+}
+
+pub async fn upsert_frm_velocity_rules_for_profile(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: common_utils::id_type::ProfileId,
+    request: FrmVelocityRulesConfigReq,
+) -> RouterResponse<FrmVelocityRulesRecord> {
+    use diesel_models::configs;
+
+    get_business_profile(&state, &merchant_context, &profile_id).await?;
+    validate_frm_velocity_rules(&request.rules)?;
+
+    let db = state.store.as_ref();
+    let key = profile_id.get_frm_velocity_rules_key();
+    let timestamp = common_utils::date_time::now_unix_timestamp();
+
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(config) => {
+            let previous_record: FrmVelocityRulesRecord = config
+                .config
+                .parse_struct("FrmVelocityRulesRecord")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse the FRM velocity rules")?;
+
+            let new_record = FrmVelocityRulesRecord {
+                name: request.name.unwrap_or(previous_record.name),
+                rules: request.rules,
+                created_at: previous_record.created_at,
+                modified_at: timestamp,
+            };
+            let serialized_record = new_record
+                .encode_to_string_of_json()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to serialize config to string")?;
+
+            db.update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(serialized_record),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the FRM velocity rules")?;
+
+            Ok(service_api::ApplicationResponse::Json(new_record))
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            let new_record = FrmVelocityRulesRecord {
+                name: request
+                    .name
+                    .get_required_value("name")
+                    .change_context(errors::ApiErrorResponse::MissingRequiredField {
+                        field_name: "name",
+                    })
+                    .attach_printable("name of the config not found")?,
+                rules: request.rules,
+                created_at: timestamp,
+                modified_at: timestamp,
+            };
+            let serialized_record = new_record
+                .encode_to_string_of_json()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Error serializing the config")?;
+
+            db.insert_config(configs::ConfigNew {
+                key,
+                config: serialized_record,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the FRM velocity rules")?;
+
+            Ok(service_api::ApplicationResponse::Json(new_record))
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error fetching the FRM velocity rules"),
+    }
+}
+
+pub async fn retrieve_frm_velocity_rules_for_profile(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: common_utils::id_type::ProfileId,
+) -> RouterResponse<FrmVelocityRulesResponse> {
+    get_business_profile(&state, &merchant_context, &profile_id).await?;
+    // Evaluating payments caches an empty config for profiles without rules, hence the config is
+    // read from the DB
+    let config = state
+        .store
+        .find_config_by_key_from_db(&profile_id.get_frm_velocity_rules_key())
+        .await
+        .change_context(errors::ApiErrorResponse::ResourceIdNotFound)
+        .attach_printable("The FRM velocity rules were not found in the DB")?;
+    let record: FrmVelocityRulesRecord = config
+        .config
+        .parse_struct("FrmVelocityRulesRecord")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the FRM velocity rules")?;
+    Ok(service_api::ApplicationResponse::Json(record))
+}
+
+pub async fn delete_frm_velocity_rules_for_profile(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: common_utils::id_type::ProfileId,
+) -> RouterResponse<()> {
+    get_business_profile(&state, &merchant_context, &profile_id).await?;
+    state
+        .store
+        .delete_config_by_key(&profile_id.get_frm_velocity_rules_key())
+        .await
+        .change_context(errors::ApiErrorResponse::ResourceIdNotFound)
+        .attach_printable("Failed to delete the FRM velocity rules from DB")?;
+    Ok(service_api::ApplicationResponse::StatusOk)
+}
+
+/// Records the payment in the velocity counters of the profile and returns the most restrictive
+/// rule triggered by it. Payments are not held back if the counters can't be updated, so that an
+/// unavailable redis does not stop payments.
+#[instrument(skip_all)]
+pub async fn perform_frm_velocity_checks(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    payment_method_data: Option<&domain::PaymentMethodData>,
+) -> RouterResult<Option<FrmVelocityDecision>> {
+    let rules = get_frm_velocity_rules(state, business_profile.get_id()).await?;
+    if rules.is_empty() {
+        return Ok(None);
+    }
+
+    let dimension_values =
+        get_dimension_values(payment_intent, payment_attempt, payment_method_data);
+    let counts = match record_payment_in_velocity_counters(
+        state,
+        business_profile.get_id(),
+        &payment_intent.merchant_id,
+        &payment_attempt.attempt_id,
+        &rules,
+        &dimension_values,
+    )
+    .await
+    {
+        Ok(counts) => counts,
+        Err(error) => {
+            logger::error!(?error, "Failed to update the FRM velocity counters");
+            metrics::FRM_VELOCITY_CHECK_FAILURE.add(1, &[]);
+            return Ok(None);
+        }
+    };
+
+    let decision = rules
+        .iter()
+        .filter(|rule| {
+            is_rule_triggered(
+                rule,
+                payment_intent.amount,
+                payment_intent.currency,
+                &counts,
+            )
+        })
+        .max_by_key(|rule| rule.action)
+        .map(|rule| FrmVelocityDecision {
+            rule_name: rule.name.clone(),
+            action: rule.action,
+        });
+
+    if let Some(decision) = &decision {
+        logger::info!(
+            rule_name = %decision.rule_name,
+            action = %decision.action,
+            "Payment triggered the FRM velocity rule"
+        );
+        metrics::FRM_VELOCITY_RULE_TRIGGERED.add(
+            1,
+            router_env::metric_attributes!(("action", decision.action.to_string())),
+        );
+    }
+
+    Ok(decision)
+}
+
+/// Marks a payment which triggered a velocity rule with the block action as failed, without
+/// sending it to the connector
+pub async fn reject_payment_blocked_by_velocity_rule(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_intent: storage::PaymentIntent,
+    payment_attempt: storage::PaymentAttempt,
+    decision: &FrmVelocityDecision,
+) -> RouterResult<()> {
+    let db = &state.store;
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+
+    db.update_payment_intent(
+        &state.into(),
+        payment_intent,
+        storage::PaymentIntentUpdate::RejectUpdate {
+            status: common_enums::IntentStatus::Failed,
+            merchant_decision: Some(MerchantDecision::Rejected.to_string()),
+            updated_by: storage_scheme.to_string(),
+        },
+        merchant_context.get_merchant_key_store(),
+        storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+    .attach_printable(
+        "Failed to update status in Payment Intent to failed due to a velocity rule",
+    )?;
+
+    db.update_payment_attempt_with_attempt_id(
+        payment_attempt,
+        storage::PaymentAttemptUpdate::BlocklistUpdate {
+            status: common_enums::AttemptStatus::Failure,
+            error_code: Some(Some(consts::FRM_VELOCITY_BLOCKED_ERROR_CODE.to_string())),
+            error_message: Some(Some(format!(
+                "{}: {}",
+                consts::FRM_VELOCITY_BLOCKED_ERROR_MESSAGE,
+                decision.rule_name
+            ))),
+            updated_by: storage_scheme.to_string(),
+        },
+        storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+    .attach_printable(
+        "Failed to update status in Payment Attempt to failed due to a velocity rule",
+    )?;
+
+    Ok(())
+}
+
+async fn get_frm_velocity_rules(
+    state: &SessionState,
+    profile_id: &common_utils::id_type::ProfileId,
+) -> RouterResult<Vec<FrmVelocityRule>> {
+    let config = state
+        .store
+        .find_config_by_key_unwrap_or(
+            &profile_id.get_frm_velocity_rules_key(),
+            Some("{}".to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error fetching the FRM velocity rules")?;
+
+    config
+        .config
+        .parse_struct::<FrmVelocityRules>("FrmVelocityRules")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the FRM velocity rules")
+        .map(|config| config.rules)
+}
+
+fn get_dimension_values(
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    payment_method_data: Option<&domain::PaymentMethodData>,
+) -> HashMap<FrmVelocityDimension, String> {
+    let browser_info = payment_attempt
+        .browser_info
+        .clone()
+        .and_then(|browser_info| {
+            serde_json::from_value::<BrowserInformation>(browser_info)
+                .map_err(|error| logger::warn!(?error, "Failed to parse the browser information"))
+                .ok()
+        });
+
+    let card_number = payment_method_data.and_then(|pm_data| match pm_data {
+        domain::PaymentMethodData::Card(card) => Some(card.card_number.get_card_no()),
+        _ => None,
+    });
+    let customer_id = payment_intent
+        .customer_id
+        .as_ref()
+        .map(|customer_id| customer_id.get_string_repr().to_owned());
+    let ip_address = browser_info
+        .as_ref()
+        .and_then(|browser_info| browser_info.ip_address)
+        .map(|ip_address| ip_address.to_string());
+    let device = payment_attempt
+        .device_fingerprint
+        .as_ref()
+        .map(|device_fingerprint| device_fingerprint.fingerprint_id.clone())
+        .or_else(|| browser_info.as_ref().and_then(get_device_identifier));
+
+    [
+        (FrmVelocityDimension::Card, card_number),
+        (FrmVelocityDimension::Customer, customer_id),
+        (FrmVelocityDimension::IpAddress, ip_address),
+        (FrmVelocityDimension::Device, device),
+    ]
+    .into_iter()
+    .filter_map(|(dimension, value)| value.map(|value| (dimension, value)))
+    .collect()
+}
+
+/// Identifies the device of the customer by the attributes of the browser which don't change
+/// between payments, when the merchant didn't send a device fingerprint. Browsers which don't send
+/// their user agent are not identified.
+fn get_device_identifier(browser_info: &BrowserInformation) -> Option<String> {
+    browser_info.user_agent.as_ref().map(|user_agent| {
+        [
+            Some(user_agent.clone()),
+            browser_info.accept_language.clone(),
+            browser_info.os_type.clone(),
+            browser_info.os_version.clone(),
+            browser_info.device_model.clone(),
+            browser_info.screen_height.map(|height| height.to_string()),
+            browser_info.screen_width.map(|width| width.to_string()),
+            browser_info.color_depth.map(|depth| depth.to_string()),
+            browser_info
+                .time_zone
+                .map(|time_zone| time_zone.to_string()),
+        ]
+        .into_iter()
+        .map(Option::unwrap_or_default)
+        .collect::<Vec<_>>()
+        .join("|")
+    })
+}
+
+/// Adds the payment to the sliding windows of the dimension values used by the rules, and returns
+/// the number of payments in every window keyed by the dimension and the window length
+async fn record_payment_in_velocity_counters(
+    state: &SessionState,
+    profile_id: &common_utils::id_type::ProfileId,
+    merchant_id: &common_utils::id_type::MerchantId,
+    attempt_id: &str,
+    rules: &[FrmVelocityRule],
+    dimension_values: &HashMap<FrmVelocityDimension, String>,
+) -> RouterResult<HashMap<(FrmVelocityDimension, u32), u64>> {
+    let mut windows: HashMap<FrmVelocityDimension, Vec<u32>> = HashMap::new();
+    for limit in rules.iter().filter_map(|rule| rule.velocity_limit) {
+        if dimension_values.contains_key(&limit.dimension) {
+            let dimension_windows = windows.entry(limit.dimension).or_default();
+            if !dimension_windows.contains(&limit.window_in_secs) {
+                dimension_windows.push(limit.window_in_secs);
+            }
+        }
+    }
+    if windows.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    // Values are hashed with the secret of the merchant, so that card numbers are not stored
+    let secret = blocklist_utils::get_merchant_fingerprint_secret(state, merchant_id).await?;
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let mut counts = HashMap::new();
+    for (dimension, dimension_windows) in windows {
+        let Some(value) = dimension_values.get(&dimension) else {
+            continue;
+        };
+        let hashed_value = HmacSha256
+            .sign_message(secret.as_bytes(), value.as_bytes())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to hash the FRM velocity dimension value")?;
+        let key = format!(
+            "{}{}:{}:{}",
+            consts::FRM_VELOCITY_PREFIX,
+            profile_id.get_string_repr(),
+            dimension,
+            hex::encode(hashed_value)
+        );
+
+        let max_window = dimension_windows.iter().copied().max().unwrap_or_default();
+        let values = [max_window.to_string(), attempt_id.to_owned()]
+            .into_iter()
+            .chain(dimension_windows.iter().map(ToString::to_string))
+            .collect::<Vec<_>>();
+
+        // Scripts are not given the tenant prefix of the connection, so it is added to the key here
+        let window_counts = redis_conn
+            .evaluate_redis_script::<_, Vec<u64>>(
+                RECORD_PAYMENT_SCRIPT,
+                vec![redis_conn.add_prefix(&key)],
+                values,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the FRM velocity counter")?;
+
+        counts.extend(
+            dimension_windows
+                .into_iter()
+                .zip(window_counts)
+                .map(|(window, count)| ((dimension, window), count)),
+        );
+    }
+
+    Ok(counts)
+}
+
+fn is_rule_triggered(
+    rule: &FrmVelocityRule,
+    amount: common_utils::types::MinorUnit,
+    currency: Option<common_enums::Currency>,
+    counts: &HashMap<(FrmVelocityDimension, u32), u64>,
+) -> bool {
+    let is_amount_matching = rule
+        .min_amount
+        .is_none_or(|min_amount| amount >= min_amount);
+    let is_currency_matching = rule
+        .currencies
+        .as_ref()
+        .is_none_or(|currencies| currency.is_some_and(|currency| currencies.contains(&currency)));
+    // Payments without a value for the dimension of the rule are not counted, and hence can't
+    // exceed its limit
+    let is_limit_exceeded = rule.velocity_limit.is_none_or(|limit| {
+        counts
+            .get(&(limit.dimension, limit.window_in_secs))
+            .is_some_and(|count| *count > u64::from(limit.max_count))
+    });
+    is_amount_matching && is_currency_matching && is_limit_exceeded
+}
+
+fn validate_frm_velocity_rules(rules: &[FrmVelocityRule]) -> RouterResult<()> {
+    rules.iter().try_for_each(|rule| {
+        common_utils::fp_utils::when(
+            rule.velocity_limit.is_none() && rule.min_amount.is_none(),
+            || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "either velocity_limit or min_amount is required for the rule {}",
+                        rule.name
+                    ),
+                })
+            },
+        )?;
+        common_utils::fp_utils::when(
+            rule.velocity_limit.is_some_and(|limit| {
+                limit.window_in_secs == 0
+                    || limit.window_in_secs > consts::FRM_VELOCITY_MAX_WINDOW_IN_SECS
+            }),
+            || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "window_in_secs of the rule {} must be between 1 and {}",
+                        rule.name,
+                        consts::FRM_VELOCITY_MAX_WINDOW_IN_SECS
+                    ),
+                })
+            },
+        )
+    })?;
+    Ok(())
+}
+
+async fn get_business_profile(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    profile_id: &common_utils::id_type::ProfileId,
+) -> RouterResult<domain::Profile> {
+    super::utils::validate_and_get_business_profile(
+        state.store.as_ref(),
+        &state.into(),
+        merchant_context.get_merchant_key_store(),
+        Some(profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use api_models::frm_velocity_rules::FrmVelocityLimit;
+    use common_utils::types::MinorUnit;
+
+    use super::*;
+
+    fn velocity_rule(action: FrmVelocityAction, max_count: u32) -> FrmVelocityRule {
+        FrmVelocityRule {
+            name: "card_velocity".to_string(),
+            action,
+            velocity_limit: Some(FrmVelocityLimit {
+                dimension: FrmVelocityDimension::Card,
+                max_count,
+                window_in_secs: 3600,
+            }),
+            min_amount: None,
+            currencies: None,
+        }
+    }
+
+    #[test]
+    fn test_velocity_limit() {
+        let rule = velocity_rule(FrmVelocityAction::Block, 3);
+        let mut counts = HashMap::new();
+        assert!(!is_rule_triggered(
+            &rule,
+            MinorUnit::new(100),
+            None,
+            &counts
+        ));
+
+        counts.insert((FrmVelocityDimension::Card, 3600), 3);
+        assert!(!is_rule_triggered(
+            &rule,
+            MinorUnit::new(100),
+            None,
+            &counts
+        ));
+
+        counts.insert((FrmVelocityDimension::Card, 3600), 4);
+        assert!(is_rule_triggered(&rule, MinorUnit::new(100), None, &counts));
+    }
+
+    #[test]
+    fn test_amount_threshold() {
+        let rule = FrmVelocityRule {
+            velocity_limit: None,
+            min_amount: Some(MinorUnit::new(50000)),
+            currencies: Some(vec![common_enums::Currency::USD]),
+            ..velocity_rule(FrmVelocityAction::ThreeDsChallenge, 0)
+        };
+        let counts = HashMap::new();
+        assert!(!is_rule_triggered(
+            &rule,
+            MinorUnit::new(49999),
+            Some(common_enums::Currency::USD),
+            &counts
+        ));
+        assert!(!is_rule_triggered(
+            &rule,
+            MinorUnit::new(50000),
+            Some(common_enums::Currency::EUR),
+            &counts
+        ));
+        assert!(is_rule_triggered(
+            &rule,
+            MinorUnit::new(50000),
+            Some(common_enums::Currency::USD),
+            &counts
+        ));
+    }
+
+    #[test]
+    fn test_most_restrictive_action() {
+        assert!(FrmVelocityAction::Block > FrmVelocityAction::ManualReview);
+        assert!(FrmVelocityAction::ManualReview > FrmVelocityAction::ThreeDsChallenge);
+    }
+}
@@ -2,7 +2,8 @@
 use analytics::health_check::HealthCheck;
 #[cfg(feature = "dynamic_routing")]
 use api_models::health_check::HealthCheckMap;
-use api_models::health_check::HealthState;
+use api_models::health_check::{HealthState, KeyManagerHealthCheckResponse};
+use common_utils::{errors::KeyManagerClientError, keymanager};
 use error_stack::ResultExt;
 use router_env::logger;
 
@@ -44,6 +45,10 @@ pub trait HealthCheckInterface {
     async fn health_check_unified_connector_service(
         &self,
     ) -> CustomResult<HealthState, errors::HealthCheckUnifiedConnectorServiceError>;
+
+    async fn health_check_key_manager(
+        &self,
+    ) -> CustomResult<Option<KeyManagerHealthCheckResponse>, errors::HealthCheckKeyManagerError>;
 }
 
 #[async_trait::async_trait]
@@ -229,4 +234,120 @@ impl HealthCheckInterface for app::SessionState {
             Ok(HealthState::NotApplicable)
         }
     }
+
+    async fn health_check_key_manager(
+        &self,
+    ) -> CustomResult<Option<KeyManagerHealthCheckResponse>, errors::HealthCheckKeyManagerError>
+    {
+        // Requests served by a backend in the application do not depend on the key manager service
+        if !self
+            .conf
+            .key_manager
+            .get_inner()
+            .is_key_manager_service_enabled()
+        {
+            return Ok(None);
+        }
+
+        let key_manager_state = self.into();
+
+        // The handshake is checked on its own, as a failed handshake can't be told apart from
+        // an unreachable key manager by the health endpoint call
+        #[cfg(feature = "keymanager_mtls")]
+        check_key_manager_mtls_handshake(&key_manager_state).await?;
+
+        let latency = keymanager::health_check_key_manager(&key_manager_state)
+            .await
+            .map_err(|error| {
+                let context = match error.current_context() {
+                    // Includes the failures in the mTLS handshake
+                    KeyManagerClientError::RequestSendFailed => {
+                        errors::HealthCheckKeyManagerError::FailedToCallKeyManager
+                    }
+                    _ => errors::HealthCheckKeyManagerError::UnhealthyResponse,
+                };
+                error.change_context(context)
+            })?;
+
+        logger::debug!(?latency, "Key Manager health check was successful");
+
+        Ok(Some(KeyManagerHealthCheckResponse {
+            latency_in_ms: latency.as_millis(),
+            mtls_handshake: cfg!(feature = "keymanager_mtls").then_some(true),
+        }))
+    }
+}
+
+/// Performs a TLS handshake with the key manager, presenting the client certificate and only
+/// trusting the certificate authority the key manager client is configured with
+#[cfg(feature = "keymanager_mtls")]
+async fn check_key_manager_mtls_handshake(
+    key_manager_state: &common_utils::types::keymanager::KeyManagerState,
+) -> CustomResult<(), errors::HealthCheckKeyManagerError> {
+    use std::sync::Arc;
+
+    use masking::PeekInterface;
+
+    let url = reqwest::Url::parse(&key_manager_state.url)
+        .change_context(errors::HealthCheckKeyManagerError::FailedToCallKeyManager)
+        .attach_printable("Failed to parse the key manager url")?;
+    let host = url
+        .host_str()
+        .ok_or(errors::HealthCheckKeyManagerError::FailedToCallKeyManager)
+        .attach_printable("Key manager url does not have a host")?
+        .to_owned();
+    let port = url
+        .port_or_known_default()
+        .ok_or(errors::HealthCheckKeyManagerError::FailedToCallKeyManager)
+        .attach_printable("Key manager url does not have a port")?;
+
+    let client_certs = rustls_pemfile::certs(&mut key_manager_state.cert.peek().as_bytes())
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(errors::HealthCheckKeyManagerError::MtlsHandshakeFailed)
+        .attach_printable("Failed to parse the key manager client certificate")?;
+    let client_key = rustls_pemfile::private_key(&mut key_manager_state.cert.peek().as_bytes())
+        .change_context(errors::HealthCheckKeyManagerError::MtlsHandshakeFailed)
+        .attach_printable("Failed to parse the key manager client key")?
+        .ok_or(errors::HealthCheckKeyManagerError::MtlsHandshakeFailed)
+        .attach_printable("Key manager client key is missing")?;
+    let mut root_store = rustls::RootCertStore::empty();
+    for ca_cert in rustls_pemfile::certs(&mut key_manager_state.ca.peek().as_bytes()) {
+        let ca_cert = ca_cert
+            .change_context(errors::HealthCheckKeyManagerError::MtlsHandshakeFailed)
+            .attach_printable("Failed to parse the key manager CA certificate")?;
+        root_store
+            .add(ca_cert)
+            .change_context(errors::HealthCheckKeyManagerError::MtlsHandshakeFailed)
+            .attach_printable("Failed to add the key manager CA certificate")?;
+    }
+    let tls_config = rustls::ClientConfig::builder()
+        .with_root_certificates(root_store)
+        .with_client_auth_cert(client_certs, client_key)
+        .change_context(errors::HealthCheckKeyManagerError::MtlsHandshakeFailed)
+        .attach_printable("Failed to build the key manager TLS client config")?;
+    let server_name = rustls::pki_types::ServerName::try_from(host.clone())
+        .change_context(errors::HealthCheckKeyManagerError::MtlsHandshakeFailed)
+        .attach_printable("Key manager host is not a valid server name")?;
+
+    let tcp_stream = tokio::time::timeout(
+        consts::KEY_MANAGER_MTLS_HANDSHAKE_TIMEOUT,
+        tokio::net::TcpStream::connect((host, port)),
+    )
+    .await
+    .change_context(errors::HealthCheckKeyManagerError::FailedToCallKeyManager)
+    .attach_printable("Timed out connecting to the key manager")?
+    .change_context(errors::HealthCheckKeyManagerError::FailedToCallKeyManager)
+    .attach_printable("Failed to connect to the key manager")?;
+
+    tokio::time::timeout(
+        consts::KEY_MANAGER_MTLS_HANDSHAKE_TIMEOUT,
+        tokio_rustls::TlsConnector::from(Arc::new(tls_config)).connect(server_name, tcp_stream),
+    )
+    .await
+    .change_context(errors::HealthCheckKeyManagerError::MtlsHandshakeFailed)
+    .attach_printable("Timed out during the mTLS handshake with the key manager")?
+    .change_context(errors::HealthCheckKeyManagerError::MtlsHandshakeFailed)
+    .attach_printable("mTLS handshake with the key manager failed")?;
+
+    Ok(())
 }
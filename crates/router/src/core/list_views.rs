@@ -0,0 +1,326 @@
+use api_models::{list_views as list_views_api, payments::PaymentListFilterConstraints};
+use common_utils::{date_time, id_type};
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services::{authentication as auth, ApplicationResponse},
+    types::storage::{self, enums},
+};
+
+/// Maximum length of the name of a list view
+const LIST_VIEW_NAME_MAX_LENGTH: usize = 255;
+
+/// The fields of a list request which control the pagination rather than the filtering, and so
+/// can not be saved in a list view
+const LIST_VIEW_NON_FILTER_FIELDS: [&str; 4] = ["limit", "offset", "cursor", "view_id"];
+
+#[instrument(skip_all)]
+pub async fn create_list_view(
+    state: SessionState,
+    user: auth::UserFromToken,
+    request: list_views_api::ListViewCreateRequest,
+) -> RouterResponse<list_views_api::ListViewResponse> {
+    validate_list_view_name(&request.name)?;
+    validate_list_view_filters(request.entity, &request.filters)?;
+
+    let now = date_time::now();
+    let list_view = state
+        .store
+        .insert_list_view(storage::ListViewNew {
+            view_id: common_utils::generate_id_with_default_len("view"),
+            merchant_id: user.merchant_id,
+            created_by: user.user_id,
+            name: request.name,
+            entity: request.entity,
+            filters: request.filters,
+            is_shared: request.is_shared,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "A list view with the given name already exists".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(get_list_view_response(list_view)))
+}
+
+/// Lists the views created by the user along with the views shared by the other users of the
+/// merchant
+#[instrument(skip_all)]
+pub async fn list_list_views(
+    state: SessionState,
+    user: auth::UserFromToken,
+    query: list_views_api::ListViewListQuery,
+) -> RouterResponse<list_views_api::ListViewListResponse> {
+    let list_views = state
+        .store
+        .list_list_views_visible_to_user(&user.merchant_id, &user.user_id, query.entity)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the list views")?;
+
+    let data = list_views
+        .into_iter()
+        .map(get_list_view_response)
+        .collect::<Vec<_>>();
+
+    Ok(ApplicationResponse::Json(
+        list_views_api::ListViewListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_list_view(
+    state: SessionState,
+    user: auth::UserFromToken,
+    view_id: String,
+) -> RouterResponse<list_views_api::ListViewResponse> {
+    let list_view = find_list_view(&state, &user.merchant_id, &view_id).await?;
+    if !list_view.is_shared && list_view.created_by != user.user_id {
+        return Err(get_list_view_not_found_error().into());
+    }
+
+    Ok(ApplicationResponse::Json(get_list_view_response(list_view)))
+}
+
+/// Updates a view, only the user who created the view can update it
+#[instrument(skip_all)]
+pub async fn update_list_view(
+    state: SessionState,
+    user: auth::UserFromToken,
+    view_id: String,
+    request: list_views_api::ListViewUpdateRequest,
+) -> RouterResponse<list_views_api::ListViewResponse> {
+    let list_view = find_own_list_view(&state, &user, &view_id).await?;
+    if let Some(name) = &request.name {
+        validate_list_view_name(name)?;
+    }
+    if let Some(filters) = &request.filters {
+        validate_list_view_filters(list_view.entity, filters)?;
+    }
+
+    let list_view = state
+        .store
+        .update_list_view_by_merchant_id_view_id(
+            &user.merchant_id,
+            &view_id,
+            storage::ListViewUpdate {
+                name: request.name,
+                filters: request.filters,
+                is_shared: request.is_shared,
+                modified_at: date_time::now(),
+            },
+        )
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "A list view with the given name already exists".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(get_list_view_response(list_view)))
+}
+
+/// Deletes a view, only the user who created the view can delete it
+#[instrument(skip_all)]
+pub async fn delete_list_view(
+    state: SessionState,
+    user: auth::UserFromToken,
+    view_id: String,
+) -> RouterResponse<list_views_api::ListViewDeleteResponse> {
+    find_own_list_view(&state, &user, &view_id).await?;
+
+    let deleted = state
+        .store
+        .delete_list_view_by_merchant_id_view_id(&user.merchant_id, &view_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to delete the list view")?;
+
+    Ok(ApplicationResponse::Json(
+        list_views_api::ListViewDeleteResponse { view_id, deleted },
+    ))
+}
+
+/// Applies the filters of the view to a payments list request, the fields set in the request take
+/// precedence over the filters of the view
+pub async fn apply_payments_list_view(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    view_id: &str,
+    constraints: PaymentListFilterConstraints,
+) -> RouterResult<PaymentListFilterConstraints> {
+    let list_view = find_list_view(state, merchant_id, view_id).await?;
+    // The order is always present in the request as it has a default, and so it is treated as
+    // not set when it is the default order
+    let default_order = serde_json::to_value(api_models::payments::Order::default())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the default payments list order")?;
+
+    apply_list_view(
+        list_view,
+        enums::ListViewEntity::Payments,
+        &constraints,
+        &[("order", default_order)],
+    )
+}
+
+/// Applies the filters of the view to a refunds list request, the fields set in the request take
+/// precedence over the filters of the view
+pub async fn apply_refunds_list_view(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    view_id: &str,
+    request: api_models::refunds::RefundListRequest,
+) -> RouterResult<api_models::refunds::RefundListRequest> {
+    let list_view = find_list_view(state, merchant_id, view_id).await?;
+
+    apply_list_view(list_view, enums::ListViewEntity::Refunds, &request, &[])
+}
+
+fn apply_list_view<T: Serialize + DeserializeOwned>(
+    list_view: storage::ListView,
+    entity: enums::ListViewEntity,
+    request: &T,
+    request_defaults: &[(&str, serde_json::Value)],
+) -> RouterResult<T> {
+    if list_view.entity != entity {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "The list view {} is a view of {}, and can not be applied to {entity}",
+                list_view.view_id, list_view.entity
+            ),
+        }
+        .into());
+    }
+
+    let mut filters = match list_view.filters {
+        serde_json::Value::Object(filters) => filters,
+        _ => serde_json::Map::new(),
+    };
+    let request = serde_json::to_value(request)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the list request")?;
+    if let serde_json::Value::Object(request) = request {
+        let set_fields = request.into_iter().filter(|(field, value)| {
+            !value.is_null()
+                && !request_defaults
+                    .iter()
+                    .any(|(default_field, default)| default_field == field && default == value)
+        });
+        filters.extend(set_fields);
+    }
+    filters.remove("view_id");
+
+    serde_json::from_value(serde_json::Value::Object(filters))
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to apply the filters of the list view to the list request")
+}
+
+async fn find_list_view(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    view_id: &str,
+) -> RouterResult<storage::ListView> {
+    state
+        .store
+        .find_list_view_by_merchant_id_view_id(merchant_id, view_id)
+        .await
+        .to_not_found_response(get_list_view_not_found_error())
+}
+
+async fn find_own_list_view(
+    state: &SessionState,
+    user: &auth::UserFromToken,
+    view_id: &str,
+) -> RouterResult<storage::ListView> {
+    let list_view = find_list_view(state, &user.merchant_id, view_id).await?;
+    if list_view.created_by != user.user_id {
+        return Err(if list_view.is_shared {
+            errors::ApiErrorResponse::AccessForbidden {
+                resource: "list view".to_string(),
+            }
+        } else {
+            get_list_view_not_found_error()
+        }
+        .into());
+    }
+    Ok(list_view)
+}
+
+fn validate_list_view_name(name: &str) -> RouterResult<()> {
+    if name.trim().is_empty() || name.len() > LIST_VIEW_NAME_MAX_LENGTH {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "name must be non empty and at most {LIST_VIEW_NAME_MAX_LENGTH} characters long"
+            ),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Validates that the filters are a valid request body of the list endpoint of the entity,
+/// without any of the pagination fields
+fn validate_list_view_filters(
+    entity: enums::ListViewEntity,
+    filters: &serde_json::Value,
+) -> RouterResult<()> {
+    let Some(fields) = filters.as_object() else {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "filters must be an object".to_string(),
+        }
+        .into());
+    };
+    if let Some(field) = LIST_VIEW_NON_FILTER_FIELDS
+        .iter()
+        .find(|field| fields.contains_key(**field))
+    {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("{field} can not be saved in the filters of a list view"),
+        }
+        .into());
+    }
+
+    let result = match entity {
+        enums::ListViewEntity::Payments => {
+            serde_json::from_value::<PaymentListFilterConstraints>(filters.clone()).map(|_| ())
+        }
+        enums::ListViewEntity::Refunds => {
+            serde_json::from_value::<api_models::refunds::RefundListRequest>(filters.clone())
+                .map(|_| ())
+        }
+    };
+    result.map_err(|error| {
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("filters are not valid filters of {entity}: {error}"),
+        }
+        .into()
+    })
+}
+
+fn get_list_view_not_found_error() -> errors::ApiErrorResponse {
+    errors::ApiErrorResponse::GenericNotFoundError {
+        message: "List view does not exist in our records".to_string(),
+    }
+}
+
+fn get_list_view_response(list_view: storage::ListView) -> list_views_api::ListViewResponse {
+    list_views_api::ListViewResponse {
+        view_id: list_view.view_id,
+        merchant_id: list_view.merchant_id,
+        name: list_view.name,
+        entity: list_view.entity,
+        filters: list_view.filters,
+        is_shared: list_view.is_shared,
+        created_by: list_view.created_by,
+        created_at: list_view.created_at,
+        modified_at: list_view.modified_at,
+    }
+}
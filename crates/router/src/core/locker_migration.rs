@@ -54,6 +54,7 @@ pub async fn rust_locker_migration(
     let constraints = CustomerListConstraints {
         limit: u16::MAX,
         offset: None,
+        cursor: None,
     };
 
     let domain_customers = db
@@ -59,6 +59,7 @@ pub async fn construct_mandate_revoke_router_data(
         preprocessing_id: None,
         payment_method_balance: None,
         connector_api_version: None,
+        merchant_connector_id: None,
         payment_method_status: None,
         request: types::MandateRevokeRequestData {
             mandate_id: mandate.mandate_id,
@@ -1,4 +1,4 @@
-use router_env::{counter_metric, global_meter};
+use router_env::{counter_metric, global_meter, histogram_metric_f64};
 
 global_meter!(GLOBAL_METER, "ROUTER_API");
 
@@ -40,9 +40,13 @@ counter_metric!(
 counter_metric!(WEBHOOK_INCOMING_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_INCOMING_FILTERED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_SOURCE_VERIFIED_COUNT, GLOBAL_METER);
+counter_metric!(WEBHOOK_INCOMING_SOURCE_REJECTED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_RECEIVED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_NOT_RECEIVED_COUNT, GLOBAL_METER);
+histogram_metric_f64!(WEBHOOK_OUTGOING_DELIVERY_TIME, GLOBAL_METER); // Attributes needed
+counter_metric!(WEBHOOK_EVENT_SINK_PUBLISHED_COUNT, GLOBAL_METER);
+counter_metric!(WEBHOOK_EVENT_SINK_PUBLISH_FAILURE_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_PAYMENT_NOT_FOUND, GLOBAL_METER);
 counter_metric!(
     WEBHOOK_EVENT_TYPE_IDENTIFICATION_FAILURE_COUNT,
@@ -58,8 +62,16 @@ counter_metric!(
 );
 counter_metric!(ROUTING_LINK_CONFIG, GLOBAL_METER);
 counter_metric!(ROUTING_LINK_CONFIG_SUCCESS_RESPONSE, GLOBAL_METER);
+counter_metric!(ROUTING_ROLLBACK_CONFIG, GLOBAL_METER);
+counter_metric!(ROUTING_ROLLBACK_CONFIG_SUCCESS_RESPONSE, GLOBAL_METER);
+counter_metric!(ROUTING_UPSERT_FEE_SCHEDULE, GLOBAL_METER);
+counter_metric!(ROUTING_UPSERT_FEE_SCHEDULE_SUCCESS_RESPONSE, GLOBAL_METER);
+counter_metric!(ROUTING_DELETE_FEE_SCHEDULE, GLOBAL_METER);
+counter_metric!(ROUTING_DELETE_FEE_SCHEDULE_SUCCESS_RESPONSE, GLOBAL_METER);
 counter_metric!(ROUTING_RETRIEVE_CONFIG, GLOBAL_METER);
 counter_metric!(ROUTING_RETRIEVE_CONFIG_SUCCESS_RESPONSE, GLOBAL_METER);
+counter_metric!(ROUTING_SIMULATE_CONFIG, GLOBAL_METER);
+counter_metric!(ROUTING_SIMULATE_CONFIG_SUCCESS_RESPONSE, GLOBAL_METER);
 counter_metric!(ROUTING_RETRIEVE_DEFAULT_CONFIG, GLOBAL_METER);
 counter_metric!(
     ROUTING_RETRIEVE_DEFAULT_CONFIG_SUCCESS_RESPONSE,
@@ -0,0 +1,109 @@
+use common_utils::date_time;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use strum::IntoEnumIterator;
+
+use crate::{
+    core::errors::{self, RouterResult},
+    db::StorageInterface,
+    routes::{metrics, SessionState},
+    types::storage,
+};
+
+pub const PAYMENT_ARCHIVAL_TASK: &str = "PAYMENT_ARCHIVAL";
+
+/// Moves the payment intents, attempts and refunds which are past the archival age to their
+/// archive tables. At most `max_batches_per_run` batches are archived from a table in a run, and
+/// whether rows were left to be archived is returned.
+#[instrument(skip_all)]
+pub async fn archive_payments(state: &SessionState) -> RouterResult<bool> {
+    let db = &*state.store;
+    let settings = &state.conf.payment_archival;
+    let created_before =
+        date_time::now() - time::Duration::days(i64::from(settings.archive_after_days));
+    let batch_size = i32::try_from(settings.batch_size)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Payment archival batch size is out of range")?;
+
+    let mut has_pending_rows = false;
+    for table in storage::ArchivalTable::iter() {
+        let mut archived_rows = 0;
+        let mut is_table_archived = false;
+        for _ in 0..settings.max_batches_per_run {
+            let batch_rows = db
+                .archive_rows_created_before(table, created_before, batch_size)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| format!("Failed to archive the rows of {table}"))?;
+            if batch_rows == 0 {
+                is_table_archived = true;
+                break;
+            }
+            archived_rows += batch_rows;
+        }
+
+        metrics::PAYMENT_ROWS_ARCHIVED.add(
+            u64::try_from(archived_rows).unwrap_or_default(),
+            router_env::metric_attributes!(("table", table.to_string())),
+        );
+        logger::info!(
+            %table,
+            archived_rows,
+            is_table_archived,
+            %created_before,
+            "Archived the rows past the archival age"
+        );
+        has_pending_rows |= !is_table_archived;
+    }
+
+    Ok(has_pending_rows)
+}
+
+/// Schedules the recurring archival of the payments, which runs right away. A task which is
+/// already scheduled is left as is, while a task which has been finished, such as by an error
+/// which couldn't be handled, is resumed.
+pub async fn add_payment_archival_task(db: &dyn StorageInterface) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::PaymentArchivalWorkflow;
+    let process_tracker_id = format!("{runner}_{PAYMENT_ARCHIVAL_TASK}");
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id.clone(),
+        PAYMENT_ARCHIVAL_TASK,
+        runner,
+        ["PAYMENT", "ARCHIVAL"],
+        (),
+        None,
+        date_time::now(),
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct payment archival process tracker task")?;
+
+    match db.insert_process(process_tracker_entry).await {
+        Ok(_) => {
+            metrics::TASKS_ADDED_COUNT.add(
+                1,
+                router_env::metric_attributes!(("flow", "PaymentArchival")),
+            );
+            Ok(())
+        }
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            let finished_process = db
+                .find_process_by_id(&process_tracker_id)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to retrieve payment archival task")?
+                .filter(|process| process.status == storage::enums::ProcessTrackerStatus::Finish);
+            if let Some(process) = finished_process {
+                logger::info!("Resuming the finished payment archival task");
+                db.reset_process(process, date_time::now())
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to resume payment archival task")?;
+            }
+            Ok(())
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to add payment archival task to process tracker"),
+    }
+}
@@ -8,7 +8,7 @@ use api_models::{
 };
 use common_utils::{
     consts::{DEFAULT_LOCALE, DEFAULT_SESSION_EXPIRY},
-    ext_traits::{OptionExt, ValueExt},
+    ext_traits::{Encode, OptionExt, ValueExt},
     types::{AmountConvertor, StringMajorUnitForCore},
 };
 use error_stack::{report, ResultExt};
@@ -20,7 +20,7 @@ use time::PrimitiveDateTime;
 
 use super::{
     errors::{self, RouterResult, StorageErrorExt},
-    payments::helpers,
+    payments::{self, helpers},
 };
 use crate::{
     consts::{
@@ -29,14 +29,15 @@ use crate::{
         DEFAULT_HIDE_CARD_NICKNAME_FIELD, DEFAULT_MERCHANT_LOGO, DEFAULT_PRODUCT_IMG,
         DEFAULT_SDK_LAYOUT, DEFAULT_SHOW_CARD_FORM,
     },
+    db::StorageInterface,
     errors::RouterResponse,
     get_payment_link_config_value, get_payment_link_config_value_based_on_priority,
-    routes::SessionState,
+    routes::{app::ReqState, SessionState},
     services,
     types::{
-        api::payment_link::PaymentLinkResponseExt,
+        api::{self, payment_link::PaymentLinkResponseExt},
         domain,
-        storage::{enums as storage_enums, payment_link::PaymentLink},
+        storage::{self, enums as storage_enums, payment_link::PaymentLink},
         transformers::{ForeignFrom, ForeignInto},
     },
 };
@@ -209,6 +210,7 @@ pub async fn form_payment_link_data(
             storage_enums::IntentStatus::Processing,
             storage_enums::IntentStatus::RequiresCapture,
             storage_enums::IntentStatus::RequiresMerchantAction,
+            storage_enums::IntentStatus::RequiresMerchantReview,
             storage_enums::IntentStatus::Succeeded,
             storage_enums::IntentStatus::PartiallyCaptured,
             storage_enums::IntentStatus::RequiresCustomerAction,
@@ -632,6 +634,389 @@ pub async fn list_payment_link(
     Ok(services::ApplicationResponse::Json(payment_link_list))
 }
 
+#[cfg(feature = "v2")]
+pub async fn create_payment_link(
+    _state: SessionState,
+    _req_state: ReqState,
+    _merchant_context: domain::MerchantContext,
+    _profile_id: Option<common_utils::id_type::ProfileId>,
+    _request: api_models::payments::PaymentLinkCreateRequest,
+) -> RouterResponse<api_models::payments::RetrievePaymentLinkResponse> {
+    todo!()
+}
+
+/// Creates a payment in `requires_payment_method` state along with a shareable payment link for
+/// it, and schedules the expiry of the link at its fulfilment time
+#[cfg(feature = "v1")]
+pub async fn create_payment_link(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    request: api_models::payments::PaymentLinkCreateRequest,
+) -> RouterResponse<api_models::payments::RetrievePaymentLinkResponse> {
+    common_utils::fp_utils::when(request.amount.get_amount_as_i64() <= 0, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount must be greater than zero for a payment link".to_string(),
+        })
+    })?;
+
+    let payments_request = api_models::payments::PaymentsRequest {
+        amount: Some(request.amount.into()),
+        currency: Some(request.currency),
+        payment_id: Some(api_models::payments::PaymentIdType::PaymentIntentId(
+            common_utils::id_type::PaymentId::default(),
+        )),
+        description: request.description,
+        session_expiry: request.session_expiry,
+        allowed_payment_method_types: request.allowed_payment_method_types,
+        payment_link: Some(true),
+        payment_link_config: request.payment_link_config.map(|theme_config| {
+            api_models::payments::PaymentCreatePaymentLinkConfig { theme_config }
+        }),
+        profile_id: request.profile_id,
+        return_url: request.return_url,
+        confirm: Some(false),
+        ..Default::default()
+    };
+
+    let payments_response = Box::pin(payments::payments_core::<
+        api::Authorize,
+        api_models::payments::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api::Authorize>,
+    >(
+        state.clone(),
+        req_state,
+        merchant_context,
+        profile_id,
+        payments::PaymentCreate,
+        payments_request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+    ))
+    .await?
+    .get_json_body()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Unexpected response from payments core")?;
+
+    let payment_link_id = payments_response
+        .payment_link
+        .map(|payment_link| payment_link.payment_link_id)
+        .get_required_value("payment_link")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Payment link was not generated for the payment")?;
+
+    let db = &*state.store;
+    let payment_link = db
+        .find_payment_link_by_payment_link_id(&payment_link_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+    if let Some(fulfilment_time) = payment_link.fulfilment_time {
+        add_payment_link_expiry_task(db, &payment_link, fulfilment_time).await?;
+    }
+
+    let response = api_models::payments::RetrievePaymentLinkResponse::foreign_from((
+        payment_link,
+        api_models::payments::PaymentLinkStatus::Active,
+    ));
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+/// Updates the description and branding of an active payment link. The branding is merged onto
+/// the configuration resolved while creating the link.
+pub async fn update_payment_link(
+    state: SessionState,
+    merchant: domain::MerchantAccount,
+    payment_link_id: String,
+    request: api_models::payments::PaymentLinkUpdateRequest,
+) -> RouterResponse<api_models::payments::RetrievePaymentLinkResponse> {
+    let db = &*state.store;
+    let payment_link =
+        find_payment_link_by_merchant_id(db, merchant.get_id(), &payment_link_id).await?;
+
+    let status = check_payment_link_status(get_payment_link_expiry(&payment_link));
+    common_utils::fp_utils::when(
+        status == api_models::payments::PaymentLinkStatus::Expired,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "payment link has expired and can no longer be updated".to_string(),
+            })
+        },
+    )?;
+
+    let mut payment_link_config = payment_link
+        .payment_link_config
+        .clone()
+        .map(extract_payment_link_config)
+        .transpose()?
+        .get_required_value("payment_link_config")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Payment link config not found for the payment link")?;
+
+    let api_models::payments::PaymentLinkUpdateRequest {
+        description,
+        theme,
+        logo,
+        seller_name,
+        background_colour,
+        payment_button_text,
+        payment_button_colour,
+        payment_button_text_colour,
+    } = request;
+
+    if let Some(theme) = theme {
+        payment_link_config.theme = theme;
+    }
+    if let Some(logo) = logo {
+        payment_link_config.logo = logo;
+    }
+    if let Some(seller_name) = seller_name {
+        payment_link_config.seller_name = seller_name;
+    }
+    payment_link_config.background_colour =
+        background_colour.or(payment_link_config.background_colour);
+    payment_link_config.payment_button_text =
+        payment_button_text.or(payment_link_config.payment_button_text);
+    payment_link_config.payment_button_colour =
+        payment_button_colour.or(payment_link_config.payment_button_colour);
+    payment_link_config.payment_button_text_colour =
+        payment_button_text_colour.or(payment_link_config.payment_button_text_colour);
+
+    let custom_merchant_name = Some(payment_link_config.seller_name.clone());
+    let payment_link_config_encoded_value = payment_link_config.encode_to_value().change_context(
+        errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "payment_link_config",
+        },
+    )?;
+
+    let payment_link = db
+        .update_payment_link_by_merchant_id_payment_link_id(
+            merchant.get_id(),
+            &payment_link_id,
+            storage::PaymentLinkUpdate::Update {
+                description,
+                custom_merchant_name,
+                payment_link_config: Some(payment_link_config_encoded_value),
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+    let response =
+        api_models::payments::RetrievePaymentLinkResponse::foreign_from((payment_link, status));
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+#[cfg(feature = "v2")]
+pub async fn deactivate_payment_link(
+    _state: SessionState,
+    _merchant_context: domain::MerchantContext,
+    _payment_link_id: String,
+) -> RouterResponse<api_models::payments::RetrievePaymentLinkResponse> {
+    todo!()
+}
+
+/// Expires a payment link immediately, and the payment backing it if it is yet to be confirmed
+#[cfg(feature = "v1")]
+pub async fn deactivate_payment_link(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    payment_link_id: String,
+) -> RouterResponse<api_models::payments::RetrievePaymentLinkResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let payment_link = find_payment_link_by_merchant_id(db, merchant_id, &payment_link_id).await?;
+
+    let current_time = common_utils::date_time::now();
+    let payment_link = if payment_link
+        .fulfilment_time
+        .is_some_and(|fulfilment_time| fulfilment_time <= current_time)
+    {
+        payment_link
+    } else {
+        db.update_payment_link_by_merchant_id_payment_link_id(
+            merchant_id,
+            &payment_link_id,
+            storage::PaymentLinkUpdate::ExpiryUpdate {
+                fulfilment_time: current_time,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?
+    };
+
+    expire_payment_link_payment(&state, &merchant_context, &payment_link).await?;
+
+    let response = api_models::payments::RetrievePaymentLinkResponse::foreign_from((
+        payment_link,
+        api_models::payments::PaymentLinkStatus::Expired,
+    ));
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+#[cfg(feature = "v2")]
+pub async fn retrieve_payment_link_status(
+    _state: SessionState,
+    _merchant_context: domain::MerchantContext,
+    _payment_link_id: String,
+) -> RouterResponse<api_models::payments::PaymentLinkStatusResponse> {
+    todo!()
+}
+
+#[cfg(feature = "v1")]
+pub async fn retrieve_payment_link_status(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    payment_link_id: String,
+) -> RouterResponse<api_models::payments::PaymentLinkStatusResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let payment_link = find_payment_link_by_merchant_id(db, merchant_id, &payment_link_id).await?;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &payment_link.payment_id,
+            merchant_id,
+            merchant_context.get_merchant_key_store(),
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let response = api_models::payments::PaymentLinkStatusResponse {
+        status: check_payment_link_status(get_payment_link_expiry(&payment_link)),
+        payment_link_id: payment_link.payment_link_id,
+        payment_id: payment_link.payment_id,
+        payment_status: payment_intent.status,
+        expiry: payment_link.fulfilment_time,
+    };
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+/// Moves the payment backing a payment link to `expired`, if the customer is yet to pay
+#[cfg(feature = "v1")]
+pub async fn expire_payment_link_payment(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_link: &PaymentLink,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            key_manager_state,
+            &payment_link.payment_id,
+            &payment_link.merchant_id,
+            merchant_context.get_merchant_key_store(),
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    if matches!(
+        payment_intent.status,
+        storage_enums::IntentStatus::RequiresPaymentMethod
+            | storage_enums::IntentStatus::RequiresConfirmation
+    ) {
+        let payment_intent_update = storage::PaymentIntentUpdate::PGStatusUpdate {
+            status: storage_enums::IntentStatus::Expired,
+            updated_by: storage_scheme.to_string(),
+            incremental_authorization_allowed: None,
+            feature_metadata: payment_intent.feature_metadata.clone().map(Secret::new),
+        };
+        db.update_payment_intent(
+            key_manager_state,
+            payment_intent,
+            payment_intent_update,
+            merchant_context.get_merchant_key_store(),
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    }
+
+    Ok(())
+}
+
+/// Schedules the expiry of a payment link at the specified time
+#[cfg(feature = "v1")]
+async fn add_payment_link_expiry_task(
+    db: &dyn StorageInterface,
+    payment_link: &PaymentLink,
+    expire_at: PrimitiveDateTime,
+) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::PaymentLinkExpiryWorkflow;
+    let task = "PAYMENT_LINK_EXPIRY";
+    let tag = ["PAYMENT_LINK", "EXPIRY"];
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        task,
+        &payment_link.payment_link_id,
+        &payment_link.merchant_id,
+    );
+    let tracking_data = storage::PaymentLinkExpiryTrackingData {
+        payment_link_id: payment_link.payment_link_id.clone(),
+        merchant_id: payment_link.merchant_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        None,
+        expire_at,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct payment link expiry process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert payment link expiry task to process tracker")?;
+    crate::routes::metrics::TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "PaymentLinkExpiry")),
+    );
+
+    Ok(())
+}
+
+async fn find_payment_link_by_merchant_id(
+    db: &dyn StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+    payment_link_id: &str,
+) -> RouterResult<PaymentLink> {
+    let payment_link = db
+        .find_payment_link_by_payment_link_id(payment_link_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+    common_utils::fp_utils::when(&payment_link.merchant_id != merchant_id, || {
+        Err(report!(errors::ApiErrorResponse::PaymentLinkNotFound))
+    })?;
+
+    Ok(payment_link)
+}
+
+fn get_payment_link_expiry(payment_link: &PaymentLink) -> PrimitiveDateTime {
+    payment_link.fulfilment_time.unwrap_or_else(|| {
+        payment_link
+            .created_at
+            .saturating_add(time::Duration::seconds(DEFAULT_SESSION_EXPIRY))
+    })
+}
+
 pub fn check_payment_link_status(
     payment_link_expiry: PrimitiveDateTime,
 ) -> api_models::payments::PaymentLinkStatus {
@@ -1,4 +1,6 @@
 pub mod cards;
+#[cfg(feature = "v1")]
+pub mod health;
 pub mod migration;
 pub mod network_tokenization;
 pub mod surcharge_decision_configs;
@@ -999,6 +1001,29 @@ pub async fn create_payment_method_card_core(
 ) -> RouterResult<(api::PaymentMethodResponse, domain::PaymentMethod)> {
     let db = &*state.store;
 
+    let payment_method_data = domain::PaymentMethodVaultingData::try_from(req.payment_method_data)?
+        .populate_bin_details_for_payment_method(state)
+        .await;
+
+    // A card which is already saved for the customer is returned instead of being saved again
+    if let Some(existing_payment_method) = find_duplicate_payment_method(
+        state,
+        &payment_method_data,
+        merchant_context,
+        profile,
+        customer_id,
+    )
+    .await?
+    {
+        logger::info!(
+            payment_method_id = ?existing_payment_method.get_id(),
+            "Payment method is already saved for the customer"
+        );
+        let resp =
+            pm_transforms::generate_payment_method_response(&existing_payment_method, &None)?;
+        return Ok((resp, existing_payment_method));
+    }
+
     let payment_method = create_payment_method_for_intent(
         state,
         req.metadata.clone(),
@@ -1012,10 +1037,6 @@ pub async fn create_payment_method_card_core(
     .await
     .attach_printable("failed to add payment method to db")?;
 
-    let payment_method_data = domain::PaymentMethodVaultingData::try_from(req.payment_method_data)?
-        .populate_bin_details_for_payment_method(state)
-        .await;
-
     let vaulting_result = vault_payment_method(
         state,
         &payment_method_data,
@@ -2250,6 +2271,47 @@ pub async fn create_pm_additional_data_update(
     Ok(pm_update)
 }
 
+/// Finds the active payment method of the customer having the same fingerprint as the payment
+/// method data. Fingerprints are generated by the Hyperswitch vault, so payment methods stored in
+/// an external vault are not checked for duplicates.
+#[cfg(feature = "v2")]
+#[instrument(skip_all)]
+pub async fn find_duplicate_payment_method(
+    state: &SessionState,
+    pmd: &domain::PaymentMethodVaultingData,
+    merchant_context: &domain::MerchantContext,
+    profile: &domain::Profile,
+    customer_id: &id_type::GlobalCustomerId,
+) -> RouterResult<Option<domain::PaymentMethod>> {
+    if profile.is_external_vault_enabled() {
+        return Ok(None);
+    }
+
+    let fingerprint_id =
+        vault::get_fingerprint_id_from_vault(state, pmd, customer_id.get_string_repr().to_owned())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get fingerprint_id from vault")?;
+
+    match state
+        .store
+        .find_payment_method_by_fingerprint_id(
+            &(state.into()),
+            merchant_context.get_merchant_key_store(),
+            &fingerprint_id,
+        )
+        .await
+    {
+        Ok(payment_method) => Ok((payment_method.customer_id == *customer_id
+            && payment_method.status == enums::PaymentMethodStatus::Active)
+            .then_some(payment_method)),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find payment method by fingerprint_id"),
+    }
+}
+
 #[cfg(feature = "v2")]
 #[instrument(skip_all)]
 pub async fn vault_payment_method_internal(
@@ -3744,6 +3806,7 @@ async fn create_single_use_tokenization_flow(
             preprocessing_id: None,
             payment_method_balance: None,
             connector_api_version: None,
+            merchant_connector_id: None,
             request: payment_method_data_request.clone(),
             response: Err(hyperswitch_domain_models::router_data::ErrorResponse::default()),
             connector_request_reference_id: payment_method_session.id.get_string_repr().to_string(),
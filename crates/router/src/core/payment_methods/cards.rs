@@ -59,7 +59,7 @@ use strum::IntoEnumIterator;
 
 #[cfg(feature = "v1")]
 use super::surcharge_decision_configs::{
-    perform_surcharge_decision_management_for_payment_method_list,
+    get_surcharge_config_ref, perform_surcharge_decision_management_for_payment_method_list,
     perform_surcharge_decision_management_for_saved_cards,
 };
 #[cfg(feature = "v1")]
@@ -1120,6 +1120,48 @@ impl PaymentMethodsController for PmCards<'_> {
                         &card_details.card_exp_month,
                         &card_details.card_exp_year,
                     )?;
+
+                    // A card which is already saved for the customer is returned instead of
+                    // being saved again
+                    if let Some(existing_pm) = find_duplicate_card_payment_method(
+                        self.state,
+                        self.merchant_context,
+                        &customer_id,
+                        &card_details,
+                    )
+                    .await?
+                    {
+                        logger::info!(
+                            payment_method_id = %existing_pm.payment_method_id,
+                            "Card is already saved for the customer"
+                        );
+                        let card = self
+                            .get_card_details_without_locker_fallback(&existing_pm)
+                            .await?;
+
+                        return Ok(services::ApplicationResponse::Json(
+                            api::PaymentMethodResponse {
+                                merchant_id: existing_pm.merchant_id.clone(),
+                                customer_id: Some(existing_pm.customer_id.clone()),
+                                payment_method_id: existing_pm.payment_method_id.clone(),
+                                payment_method: existing_pm.get_payment_method_type(),
+                                payment_method_type: existing_pm.get_payment_method_subtype(),
+                                #[cfg(feature = "payouts")]
+                                bank_transfer: None,
+                                card: Some(card),
+                                metadata: existing_pm.metadata,
+                                created: Some(existing_pm.created_at),
+                                recurring_enabled: Some(false),
+                                installment_payment_enabled: Some(false),
+                                payment_experience: Some(vec![
+                                    api_models::enums::PaymentExperience::RedirectToUrl,
+                                ]),
+                                last_used_at: Some(existing_pm.last_used_at),
+                                client_secret: existing_pm.client_secret,
+                            },
+                        ));
+                    }
+
                     Box::pin(self.add_card_to_locker(
                         req.clone(),
                         &card_details,
@@ -1309,6 +1351,71 @@ impl PaymentMethodsController for PmCards<'_> {
     }
 }
 
+/// Finds the active card payment method of the customer saved with the same card number and
+/// expiry, whose holder name and nick name are not changed by the card being saved. Changes to
+/// these details are left to the card vault, which updates the existing payment method.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn find_duplicate_card_payment_method(
+    state: &routes::SessionState,
+    merchant_context: &domain::MerchantContext,
+    customer_id: &id_type::CustomerId,
+    card: &api::CardDetail,
+) -> errors::RouterResult<Option<domain::PaymentMethod>> {
+    let payment_methods = match state
+        .store
+        .find_payment_method_by_customer_id_merchant_id_status(
+            &(state.into()),
+            merchant_context.get_merchant_key_store(),
+            customer_id,
+            merchant_context.get_merchant_account().get_id(),
+            common_enums::PaymentMethodStatus::Active,
+            None,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+    {
+        Ok(payment_methods) => payment_methods,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find payment methods of the customer")?,
+    };
+
+    Ok(payment_methods.into_iter().find(|payment_method| {
+        payment_method.get_payment_method_type() == Some(enums::PaymentMethod::Card)
+            && payment_method
+                .payment_method_data
+                .clone()
+                .map(|data| data.into_inner().expose())
+                .and_then(|data| serde_json::from_value::<PaymentMethodsData>(data).ok())
+                .is_some_and(|data| match data {
+                    PaymentMethodsData::Card(saved_card) => is_duplicate_card(&saved_card, card),
+                    _ => false,
+                })
+    }))
+}
+
+#[cfg(feature = "v1")]
+fn is_duplicate_card(saved_card: &CardDetailsPaymentMethod, card: &api::CardDetail) -> bool {
+    let is_unchanged = |requested: &Option<Secret<String>>, saved: &Option<Secret<String>>| {
+        requested.as_ref().is_none_or(|requested| {
+            saved
+                .as_ref()
+                .is_some_and(|saved| saved.peek() == requested.peek())
+        })
+    };
+
+    saved_card.card_isin.as_deref() == Some(card.card_number.get_card_isin().as_str())
+        && saved_card.last4_digits.as_deref() == Some(card.card_number.get_last4().as_str())
+        && saved_card.expiry_month.as_ref().map(PeekInterface::peek)
+            == Some(card.card_exp_month.peek())
+        && saved_card.expiry_year.as_ref().map(PeekInterface::peek)
+            == Some(card.card_exp_year.peek())
+        && is_unchanged(&card.card_holder_name, &saved_card.card_holder_name)
+        && is_unchanged(&card.nick_name, &saved_card.nick_name)
+}
+
 #[cfg(feature = "v1")]
 #[instrument(skip_all)]
 pub async fn get_client_secret_or_add_payment_method(
@@ -3621,25 +3728,17 @@ pub async fn call_surcharge_decision_management(
     billing_address: Option<domain::Address>,
     response_payment_method_types: &mut [ResponsePaymentMethodsEnabled],
 ) -> errors::RouterResult<api_surcharge_decision_configs::MerchantSurchargeConfigs> {
-    #[cfg(feature = "v1")]
-    let algorithm_ref: routing_types::RoutingAlgorithmRef = merchant_context
-        .get_merchant_account()
-        .routing_algorithm
-        .clone()
-        .map(|val| val.parse_value("routing algorithm"))
-        .transpose()
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Could not decode the routing algorithm")?
-        .unwrap_or_default();
-
-    // TODO: Move to business profile surcharge decision column
-    #[cfg(feature = "v2")]
-    let algorithm_ref: routing_types::RoutingAlgorithmRef = todo!();
+    let surcharge_config = get_surcharge_config_ref(
+        merchant_context.get_merchant_account(),
+        Some(business_profile),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Could not resolve the surcharge decision config")?;
 
     let (surcharge_results, merchant_sucharge_configs) =
         perform_surcharge_decision_management_for_payment_method_list(
             &state,
-            algorithm_ref,
+            surcharge_config,
             payment_attempt,
             &payment_intent,
             billing_address.as_ref().map(Into::into),
@@ -3683,23 +3782,16 @@ pub async fn call_surcharge_decision_management_for_saved_card(
     payment_intent: storage::PaymentIntent,
     customer_payment_method_response: &mut api::CustomerPaymentMethodsListResponse,
 ) -> errors::RouterResult<()> {
-    #[cfg(feature = "v1")]
-    let algorithm_ref: routing_types::RoutingAlgorithmRef = merchant_context
-        .get_merchant_account()
-        .routing_algorithm
-        .clone()
-        .map(|val| val.parse_value("routing algorithm"))
-        .transpose()
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Could not decode the routing algorithm")?
-        .unwrap_or_default();
-    #[cfg(feature = "v2")]
-    let algorithm_ref: routing_types::RoutingAlgorithmRef = todo!();
+    let surcharge_config = get_surcharge_config_ref(
+        merchant_context.get_merchant_account(),
+        Some(business_profile),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Could not resolve the surcharge decision config")?;
 
-    // TODO: Move to business profile surcharge column
     let surcharge_results = perform_surcharge_decision_management_for_saved_cards(
         state,
-        algorithm_ref,
+        surcharge_config,
         payment_attempt,
         &payment_intent,
         &mut customer_payment_method_response.customer_payment_methods,
@@ -5142,3 +5234,75 @@ pub async fn execute_payment_method_tokenization(
 
     Ok(builder.build())
 }
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_card() -> api::CardDetail {
+        api::CardDetail {
+            card_number: cards::CardNumber::from_str("4111111145551142").unwrap(),
+            card_exp_month: Secret::new("10".to_string()),
+            card_exp_year: Secret::new("2030".to_string()),
+            card_holder_name: None,
+            nick_name: None,
+            card_issuing_country: None,
+            card_network: None,
+            card_issuer: None,
+            card_type: None,
+        }
+    }
+
+    fn get_saved_card() -> CardDetailsPaymentMethod {
+        CardDetailsPaymentMethod {
+            last4_digits: Some("1142".to_string()),
+            issuer_country: None,
+            expiry_month: Some(Secret::new("10".to_string())),
+            expiry_year: Some(Secret::new("2030".to_string())),
+            nick_name: None,
+            card_holder_name: Some(Secret::new("John Doe".to_string())),
+            card_isin: Some("411111".to_string()),
+            card_issuer: None,
+            card_network: None,
+            card_type: None,
+            saved_to_locker: true,
+            co_badged_card_data: None,
+        }
+    }
+
+    #[test]
+    fn test_same_card_is_duplicate() {
+        assert!(is_duplicate_card(&get_saved_card(), &get_card()));
+
+        let card = api::CardDetail {
+            card_holder_name: Some(Secret::new("John Doe".to_string())),
+            ..get_card()
+        };
+        assert!(is_duplicate_card(&get_saved_card(), &card));
+    }
+
+    #[test]
+    fn test_card_with_other_number_or_expiry_is_not_duplicate() {
+        let card = api::CardDetail {
+            card_number: cards::CardNumber::from_str("4242424242424242").unwrap(),
+            ..get_card()
+        };
+        assert!(!is_duplicate_card(&get_saved_card(), &card));
+
+        let card = api::CardDetail {
+            card_exp_year: Secret::new("2031".to_string()),
+            ..get_card()
+        };
+        assert!(!is_duplicate_card(&get_saved_card(), &card));
+    }
+
+    #[test]
+    fn test_card_with_changed_holder_name_is_not_duplicate() {
+        let card = api::CardDetail {
+            card_holder_name: Some(Secret::new("Jane Doe".to_string())),
+            ..get_card()
+        };
+        assert!(!is_duplicate_card(&get_saved_card(), &card));
+    }
+}
@@ -0,0 +1,394 @@
+use api_models::payment_methods as pm_api;
+use common_utils::{date_time, id_type};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::{
+    payment_method_data::PaymentMethodsData, router_data::AccountUpdaterCardDetails,
+};
+use masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+use scheduler::utils as pt_utils;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::cards,
+    },
+    db::StorageInterface,
+    routes::{metrics, SessionState},
+    services::ApplicationResponse,
+    types::{
+        api, domain,
+        storage::{self, enums, payment_method_health::PaymentMethodExpiryTrackingData},
+    },
+};
+
+pub const PAYMENT_METHOD_EXPIRY_TASK: &str = "PAYMENT_METHOD_EXPIRY";
+
+/// Retrieves the usage and expiry of a saved payment method
+#[instrument(skip_all)]
+pub async fn retrieve_payment_method_health(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    payment_method_id: String,
+) -> RouterResponse<pm_api::PaymentMethodHealthResponse> {
+    let db = &*state.store;
+    let payment_method = db
+        .find_payment_method(
+            &(&state).into(),
+            merchant_context.get_merchant_key_store(),
+            &payment_method_id,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    let payment_method_health = db
+        .find_optional_payment_method_health(
+            merchant_context.get_merchant_account().get_id(),
+            &payment_method_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payment method health")?;
+
+    // Payment methods which were not used since they were saved have no usage recorded yet
+    let response = match payment_method_health {
+        Some(payment_method_health) => get_payment_method_health_response(payment_method_health),
+        None => pm_api::PaymentMethodHealthResponse {
+            payment_method_id: payment_method.get_id().clone(),
+            customer_id: payment_method.customer_id.clone(),
+            last_successful_use_at: None,
+            last_failed_use_at: None,
+            successful_uses: 0,
+            failed_uses: 0,
+            consecutive_failures: 0,
+            card_expires_at: get_payment_method_card_expires_at(&payment_method),
+            expires_in_days: get_payment_method_card_expires_at(&payment_method)
+                .map(get_expires_in_days),
+            account_updated_at: None,
+        },
+    };
+    Ok(ApplicationResponse::Json(response))
+}
+
+/// Lists the usage and expiry of the saved payment methods of the merchant, the ones expiring
+/// first coming first
+#[instrument(skip_all)]
+pub async fn list_payment_method_health(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: pm_api::PaymentMethodHealthListRequest,
+) -> RouterResponse<pm_api::PaymentMethodHealthListResponse> {
+    let limit = request
+        .limit
+        .map_or(consts::PAYMENT_METHOD_HEALTH_DEFAULT_LIMIT, i64::from)
+        .min(consts::PAYMENT_METHOD_HEALTH_MAX_LIMIT);
+    let card_expires_before = request
+        .expiring_within_days
+        .map(|days| date_time::now().saturating_add(time::Duration::days(i64::from(days))));
+    let min_consecutive_failures = request
+        .min_consecutive_failures
+        .map(i32::try_from)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: "min_consecutive_failures is too large".to_string(),
+        })?;
+
+    let payment_method_health = state
+        .store
+        .filter_payment_method_health_by_merchant_id(
+            merchant_context.get_merchant_account().get_id(),
+            request.customer_id,
+            card_expires_before,
+            min_consecutive_failures,
+            limit,
+            request.offset.map(i64::from).unwrap_or_default(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the payment method health")?;
+
+    let data = payment_method_health
+        .into_iter()
+        .map(get_payment_method_health_response)
+        .collect::<Vec<_>>();
+    Ok(ApplicationResponse::Json(
+        pm_api::PaymentMethodHealthListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+/// Records the outcome of a payment made with a saved payment method.
+///
+/// If the connector reported newer card details from the account updater of the card network, the
+/// saved card is refreshed with them first. The expiry of the card is tracked by a process tracker
+/// task, which deactivates the payment method once the card has expired.
+#[instrument(skip_all)]
+pub async fn record_payment_method_usage(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    storage_scheme: enums::MerchantStorageScheme,
+    payment_method_id: &str,
+    is_success: bool,
+    account_updater_card_details: Option<AccountUpdaterCardDetails>,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let payment_method = db
+        .find_payment_method(&state.into(), key_store, payment_method_id, storage_scheme)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+    let card_expires_at = get_payment_method_card_expires_at(&payment_method);
+
+    // The card details reported with a declined payment are not trusted
+    let updated_card_expires_at = match account_updater_card_details {
+        Some(card_details) if is_success => {
+            apply_account_updater_card_details(
+                state,
+                key_store,
+                &payment_method,
+                card_expires_at,
+                card_details,
+            )
+            .await?
+        }
+        _ => None,
+    };
+
+    let payment_method_health = db
+        .find_optional_payment_method_health(&payment_method.merchant_id, payment_method_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payment method health")?;
+
+    // The expiry task is scheduled on the first use of the payment method, and whenever its card
+    // is refreshed by the account updater
+    let should_schedule_expiry_task =
+        payment_method_health.is_none() || updated_card_expires_at.is_some();
+    let payment_method_health = match payment_method_health {
+        Some(payment_method_health) => db
+            .update_payment_method_health(
+                payment_method_health,
+                storage::PaymentMethodHealthUpdate::UsageUpdate {
+                    is_success,
+                    card_expires_at,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to record the payment method usage")?,
+        None => {
+            let now = date_time::now();
+            db.insert_payment_method_health(storage::PaymentMethodHealthNew {
+                merchant_id: payment_method.merchant_id.clone(),
+                payment_method_id: payment_method_id.to_owned(),
+                customer_id: payment_method.customer_id.clone(),
+                last_successful_use_at: is_success.then_some(now),
+                last_failed_use_at: (!is_success).then_some(now),
+                successful_uses: i32::from(is_success),
+                failed_uses: i32::from(!is_success),
+                consecutive_failures: i32::from(!is_success),
+                card_expires_at,
+                created_at: now,
+                modified_at: now,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to record the payment method usage")?
+        }
+    };
+    let payment_method_health = match updated_card_expires_at {
+        Some(card_expires_at) => db
+            .update_payment_method_health(
+                payment_method_health,
+                storage::PaymentMethodHealthUpdate::AccountUpdaterUpdate { card_expires_at },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to record the account updater card details")?,
+        None => payment_method_health,
+    };
+
+    if let (true, Some(card_expires_at)) = (
+        should_schedule_expiry_task,
+        payment_method_health.card_expires_at,
+    ) {
+        add_payment_method_expiry_task(
+            db,
+            &payment_method.merchant_id,
+            payment_method_id,
+            card_expires_at,
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+/// Refreshes the expiry of the saved card with the one reported by the account updater, when it
+/// is newer than the saved one. Returns the new expiry of the card if it was refreshed.
+async fn apply_account_updater_card_details(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    payment_method: &domain::PaymentMethod,
+    card_expires_at: Option<time::PrimitiveDateTime>,
+    card_details: AccountUpdaterCardDetails,
+) -> RouterResult<Option<time::PrimitiveDateTime>> {
+    let Some(updated_card_expires_at) = get_card_expires_at(
+        card_details.card_exp_month.peek(),
+        card_details.card_exp_year.peek(),
+    ) else {
+        logger::warn!("Invalid card expiry received from the account updater");
+        return Ok(None);
+    };
+    if payment_method.payment_method != Some(enums::PaymentMethod::Card)
+        || card_expires_at.is_some_and(|card_expires_at| card_expires_at >= updated_card_expires_at)
+    {
+        return Ok(None);
+    }
+
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(&state.into(), &payment_method.merchant_id, key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+        merchant_account,
+        key_store.clone(),
+    )));
+
+    Box::pin(cards::update_customer_payment_method(
+        state.clone(),
+        merchant_context,
+        api::PaymentMethodUpdate {
+            card: Some(pm_api::CardDetailUpdate {
+                card_exp_month: Some(card_details.card_exp_month),
+                card_exp_year: Some(card_details.card_exp_year),
+                card_holder_name: None,
+                nick_name: None,
+            }),
+            client_secret: None,
+        },
+        payment_method.get_id(),
+    ))
+    .await
+    .attach_printable("Failed to apply the account updater card details")?;
+
+    logger::info!(
+        payment_method_id = %payment_method.get_id(),
+        "Refreshed the card expiry with the account updater card details"
+    );
+    Ok(Some(updated_card_expires_at))
+}
+
+/// Schedules the task which deactivates the payment method once its card has expired. A task which
+/// is already scheduled picks up the latest expiry of the card when it runs.
+async fn add_payment_method_expiry_task(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    payment_method_id: &str,
+    card_expires_at: time::PrimitiveDateTime,
+) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::PaymentMethodExpiryWorkflow;
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        PAYMENT_METHOD_EXPIRY_TASK,
+        payment_method_id,
+        merchant_id,
+    );
+    let tracking_data = PaymentMethodExpiryTrackingData {
+        payment_method_id: payment_method_id.to_owned(),
+        merchant_id: merchant_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        PAYMENT_METHOD_EXPIRY_TASK,
+        runner,
+        ["PAYMENT_METHOD", "EXPIRY"],
+        tracking_data,
+        None,
+        card_expires_at,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct payment method expiry process tracker task")?;
+
+    match db.insert_process(process_tracker_entry).await {
+        Ok(_) => {
+            metrics::TASKS_ADDED_COUNT.add(
+                1,
+                router_env::metric_attributes!(("flow", "PaymentMethodExpiry")),
+            );
+            Ok(())
+        }
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to add payment method expiry task to process tracker"),
+    }
+}
+
+/// Returns the expiry of the saved card of the payment method, if any
+pub fn get_payment_method_card_expires_at(
+    payment_method: &domain::PaymentMethod,
+) -> Option<time::PrimitiveDateTime> {
+    match payment_method.get_payment_methods_data()? {
+        PaymentMethodsData::Card(card) => get_card_expires_at(
+            card.expiry_month.as_ref()?.peek(),
+            card.expiry_year.as_ref()?.peek(),
+        ),
+        PaymentMethodsData::BankDetails(_)
+        | PaymentMethodsData::WalletDetails(_)
+        | PaymentMethodsData::NetworkToken(_) => None,
+    }
+}
+
+/// A card can be charged until the end of its expiry month, hence it expires at the start of the
+/// following month
+fn get_card_expires_at(
+    card_exp_month: &str,
+    card_exp_year: &str,
+) -> Option<time::PrimitiveDateTime> {
+    let card_exp_month = card_exp_month.trim().parse::<u8>().ok()?;
+    let card_exp_year = card_exp_year.trim().parse::<i32>().ok()?;
+    let card_exp_year = if card_exp_year < 100 {
+        card_exp_year + 2000
+    } else {
+        card_exp_year
+    };
+    let (year, month) = if card_exp_month == 12 {
+        (card_exp_year + 1, time::Month::January)
+    } else {
+        (
+            card_exp_year,
+            time::Month::try_from(card_exp_month.checked_add(1)?).ok()?,
+        )
+    };
+    time::Date::from_calendar_date(year, month, 1)
+        .ok()
+        .map(|date| date.midnight())
+}
+
+fn get_expires_in_days(card_expires_at: time::PrimitiveDateTime) -> i64 {
+    (card_expires_at - date_time::now()).whole_days()
+}
+
+fn get_payment_method_health_response(
+    payment_method_health: storage::PaymentMethodHealth,
+) -> pm_api::PaymentMethodHealthResponse {
+    pm_api::PaymentMethodHealthResponse {
+        payment_method_id: payment_method_health.payment_method_id,
+        customer_id: payment_method_health.customer_id,
+        last_successful_use_at: payment_method_health.last_successful_use_at,
+        last_failed_use_at: payment_method_health.last_failed_use_at,
+        successful_uses: payment_method_health.successful_uses,
+        failed_uses: payment_method_health.failed_uses,
+        consecutive_failures: payment_method_health.consecutive_failures,
+        card_expires_at: payment_method_health.card_expires_at,
+        expires_in_days: payment_method_health
+            .card_expires_at
+            .map(get_expires_in_days),
+        account_updated_at: payment_method_health.account_updated_at,
+    }
+}
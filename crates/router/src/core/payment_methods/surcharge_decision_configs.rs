@@ -1,15 +1,18 @@
 use api_models::{
     payment_methods::SurchargeDetailsResponse,
-    payments, routing,
+    payments,
     surcharge_decision_configs::{self, SurchargeDecisionConfigs, SurchargeDecisionManagerRecord},
 };
-#[cfg(feature = "v1")]
-use common_utils::{ext_traits::StringExt, types as common_utils_types};
 #[cfg(feature = "v2")]
 use common_utils::{
     ext_traits::{OptionExt, StringExt},
     types as common_utils_types,
 };
+#[cfg(feature = "v1")]
+use common_utils::{
+    ext_traits::{StringExt, ValueExt},
+    types as common_utils_types,
+};
 use error_stack::{self, ResultExt};
 use euclid::{
     backend,
@@ -56,6 +59,67 @@ impl TryFrom<SurchargeDecisionManagerRecord> for VirInterpreterBackendCacheWrapp
     }
 }
 
+/// Location of the surcharge decision config applicable to a payment
+#[derive(Debug, Clone)]
+pub struct SurchargeConfigRef {
+    /// Key under which the config is stored in the configs table
+    config_key: String,
+    /// Key under which the initialized interpreter is cached
+    cache_key: String,
+}
+
+/// Resolves the surcharge decision config to be applied, a config set on the profile takes
+/// precedence over the one set on the merchant
+#[cfg(feature = "v1")]
+pub fn get_surcharge_config_ref(
+    merchant_account: &hyperswitch_domain_models::merchant_account::MerchantAccount,
+    business_profile: Option<&hyperswitch_domain_models::business_profile::Profile>,
+) -> ConditionalConfigResult<Option<SurchargeConfigRef>> {
+    let profile_config = business_profile
+        .and_then(|profile| {
+            profile
+                .routing_algorithm
+                .clone()
+                .map(|value| {
+                    value.parse_value::<api_models::routing::RoutingAlgorithmRef>(
+                        "routing algorithm",
+                    )
+                })
+                .transpose()
+                .map(|algorithm_ref| {
+                    algorithm_ref
+                        .and_then(|algorithm_ref| algorithm_ref.surcharge_config_algo_id)
+                        .map(|config_key| SurchargeConfigRef {
+                            config_key,
+                            cache_key: profile.get_id().get_surcharge_dsl_key(),
+                        })
+                })
+                .transpose()
+        })
+        .transpose()
+        .change_context(ConfigError::DslParsingError)
+        .attach_printable("Could not decode the profile routing algorithm")?;
+    if profile_config.is_some() {
+        return Ok(profile_config);
+    }
+
+    let merchant_config = merchant_account
+        .routing_algorithm
+        .clone()
+        .map(|value| {
+            value.parse_value::<api_models::routing::RoutingAlgorithmRef>("routing algorithm")
+        })
+        .transpose()
+        .change_context(ConfigError::DslParsingError)
+        .attach_printable("Could not decode the merchant routing algorithm")?
+        .and_then(|algorithm_ref| algorithm_ref.surcharge_config_algo_id)
+        .map(|config_key| SurchargeConfigRef {
+            config_key,
+            cache_key: merchant_account.get_id().get_surcharge_dsk_key(),
+        });
+    Ok(merchant_config)
+}
+
 enum SurchargeSource {
     /// Surcharge will be generated through the surcharge rules
     Generate(VirInterpreterBackendCacheWrapper),
@@ -101,7 +165,7 @@ impl SurchargeSource {
 #[cfg(feature = "v2")]
 pub async fn perform_surcharge_decision_management_for_payment_method_list(
     _state: &SessionState,
-    _algorithm_ref: routing::RoutingAlgorithmRef,
+    _surcharge_config: Option<SurchargeConfigRef>,
     _payment_attempt: &storage::PaymentAttempt,
     _payment_intent: &storage::PaymentIntent,
     _billing_address: Option<payments::Address>,
@@ -116,7 +180,7 @@ pub async fn perform_surcharge_decision_management_for_payment_method_list(
 #[cfg(feature = "v1")]
 pub async fn perform_surcharge_decision_management_for_payment_method_list(
     state: &SessionState,
-    algorithm_ref: routing::RoutingAlgorithmRef,
+    surcharge_config: Option<SurchargeConfigRef>,
     payment_attempt: &storage::PaymentAttempt,
     payment_intent: &storage::PaymentIntent,
     billing_address: Option<hyperswitch_domain_models::address::Address>,
@@ -127,35 +191,28 @@ pub async fn perform_surcharge_decision_management_for_payment_method_list(
 )> {
     let mut surcharge_metadata = types::SurchargeMetadata::new(payment_attempt.attempt_id.clone());
 
-    let (surcharge_source, merchant_surcharge_configs) = match (
-        payment_attempt.get_surcharge_details(),
-        algorithm_ref.surcharge_config_algo_id,
-    ) {
-        (Some(request_surcharge_details), _) => (
-            SurchargeSource::Predetermined(request_surcharge_details),
-            surcharge_decision_configs::MerchantSurchargeConfigs::default(),
-        ),
-        (None, Some(algorithm_id)) => {
-            let cached_algo = ensure_algorithm_cached(
-                &*state.store,
-                &payment_attempt.merchant_id,
-                algorithm_id.as_str(),
-            )
-            .await?;
-
-            let merchant_surcharge_config = cached_algo.merchant_surcharge_configs.clone();
-            (
-                SurchargeSource::Generate(cached_algo),
-                merchant_surcharge_config,
-            )
-        }
-        (None, None) => {
-            return Ok((
-                surcharge_metadata,
+    let (surcharge_source, merchant_surcharge_configs) =
+        match (payment_attempt.get_surcharge_details(), surcharge_config) {
+            (Some(request_surcharge_details), _) => (
+                SurchargeSource::Predetermined(request_surcharge_details),
                 surcharge_decision_configs::MerchantSurchargeConfigs::default(),
-            ))
-        }
-    };
+            ),
+            (None, Some(surcharge_config)) => {
+                let cached_algo = ensure_algorithm_cached(&*state.store, &surcharge_config).await?;
+
+                let merchant_surcharge_config = cached_algo.merchant_surcharge_configs.clone();
+                (
+                    SurchargeSource::Generate(cached_algo),
+                    merchant_surcharge_config,
+                )
+            }
+            (None, None) => {
+                return Ok((
+                    surcharge_metadata,
+                    surcharge_decision_configs::MerchantSurchargeConfigs::default(),
+                ))
+            }
+        };
     let surcharge_source_log_message = match &surcharge_source {
         SurchargeSource::Generate(_) => "Surcharge was calculated through surcharge rules",
         SurchargeSource::Predetermined(_) => "Surcharge was sent in payment create request",
@@ -236,27 +293,19 @@ pub async fn perform_surcharge_decision_management_for_payment_method_list(
 #[cfg(feature = "v1")]
 pub async fn perform_surcharge_decision_management_for_session_flow(
     state: &SessionState,
-    algorithm_ref: routing::RoutingAlgorithmRef,
+    surcharge_config: Option<SurchargeConfigRef>,
     payment_attempt: &storage::PaymentAttempt,
     payment_intent: &storage::PaymentIntent,
     billing_address: Option<hyperswitch_domain_models::address::Address>,
     payment_method_type_list: &Vec<common_enums::PaymentMethodType>,
 ) -> ConditionalConfigResult<types::SurchargeMetadata> {
     let mut surcharge_metadata = types::SurchargeMetadata::new(payment_attempt.attempt_id.clone());
-    let surcharge_source = match (
-        payment_attempt.get_surcharge_details(),
-        algorithm_ref.surcharge_config_algo_id,
-    ) {
+    let surcharge_source = match (payment_attempt.get_surcharge_details(), surcharge_config) {
         (Some(request_surcharge_details), _) => {
             SurchargeSource::Predetermined(request_surcharge_details)
         }
-        (None, Some(algorithm_id)) => {
-            let cached_algo = ensure_algorithm_cached(
-                &*state.store,
-                &payment_attempt.merchant_id,
-                algorithm_id.as_str(),
-            )
-            .await?;
+        (None, Some(surcharge_config)) => {
+            let cached_algo = ensure_algorithm_cached(&*state.store, &surcharge_config).await?;
 
             SurchargeSource::Generate(cached_algo)
         }
@@ -288,26 +337,18 @@ pub async fn perform_surcharge_decision_management_for_session_flow(
 #[cfg(feature = "v1")]
 pub async fn perform_surcharge_decision_management_for_saved_cards(
     state: &SessionState,
-    algorithm_ref: routing::RoutingAlgorithmRef,
+    surcharge_config: Option<SurchargeConfigRef>,
     payment_attempt: &storage::PaymentAttempt,
     payment_intent: &storage::PaymentIntent,
     customer_payment_method_list: &mut [api_models::payment_methods::CustomerPaymentMethod],
 ) -> ConditionalConfigResult<types::SurchargeMetadata> {
     let mut surcharge_metadata = types::SurchargeMetadata::new(payment_attempt.attempt_id.clone());
-    let surcharge_source = match (
-        payment_attempt.get_surcharge_details(),
-        algorithm_ref.surcharge_config_algo_id,
-    ) {
+    let surcharge_source = match (payment_attempt.get_surcharge_details(), surcharge_config) {
         (Some(request_surcharge_details), _) => {
             SurchargeSource::Predetermined(request_surcharge_details)
         }
-        (None, Some(algorithm_id)) => {
-            let cached_algo = ensure_algorithm_cached(
-                &*state.store,
-                &payment_attempt.merchant_id,
-                algorithm_id.as_str(),
-            )
-            .await?;
+        (None, Some(surcharge_config)) => {
+            let cached_algo = ensure_algorithm_cached(&*state.store, &surcharge_config).await?;
 
             SurchargeSource::Generate(cached_algo)
         }
@@ -325,8 +366,14 @@ pub async fn perform_surcharge_decision_management_for_saved_cards(
         let payment_token = customer_payment_method.payment_token.clone();
 
         backend_input.payment_method.payment_method = Some(customer_payment_method.payment_method);
-        backend_input.payment_method.payment_method_type =
-            customer_payment_method.payment_method_type;
+        // the funding type of the saved card takes precedence, so that credit and debit rules
+        // are applied as per the BIN lookup done while saving the card
+        backend_input.payment_method.payment_method_type = customer_payment_method
+            .card
+            .as_ref()
+            .and_then(|card| card.card_type.as_deref())
+            .and_then(crate::core::payments::helpers::get_payment_method_type_from_card_type)
+            .or(customer_payment_method.payment_method_type);
 
         let card_network = customer_payment_method
             .card
@@ -452,10 +499,90 @@ fn get_surcharge_details_from_surcharge_output(
     surcharge_details: surcharge_decision_configs::SurchargeDetailsOutput,
     payment_attempt: &storage::PaymentAttempt,
 ) -> ConditionalConfigResult<types::SurchargeDetails> {
+    build_surcharge_details(
+        surcharge_details,
+        payment_attempt.net_amount.get_total_amount(),
+        payment_attempt.net_amount.get_order_amount(),
+    )
+}
+
+/// Builds the surcharge details for `amount`, which is the amount the surcharge is applied on
+#[cfg(feature = "v1")]
+fn build_surcharge_details(
+    surcharge_details: surcharge_decision_configs::SurchargeDetailsOutput,
+    amount: common_utils_types::MinorUnit,
+    original_amount: common_utils_types::MinorUnit,
+) -> ConditionalConfigResult<types::SurchargeDetails> {
+    let (surcharge_amount, tax_on_surcharge_amount) =
+        calculate_surcharge_amounts(&surcharge_details, amount)?;
+    Ok(types::SurchargeDetails {
+        original_amount,
+        surcharge: match surcharge_details.surcharge {
+            surcharge_decision_configs::SurchargeOutput::Fixed { amount } => {
+                common_utils_types::Surcharge::Fixed(amount)
+            }
+            surcharge_decision_configs::SurchargeOutput::Rate(percentage) => {
+                common_utils_types::Surcharge::Rate(percentage)
+            }
+        },
+        tax_on_surcharge: surcharge_details.tax_on_surcharge,
+        surcharge_amount,
+        tax_on_surcharge_amount,
+    })
+}
+
+/// Evaluates the surcharge rules for the payment attempt with its amount, excluding the surcharge,
+/// changed to `amount`. Used when the amount of an authorized payment is increased.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn perform_surcharge_decision_management_for_amount_update(
+    state: &SessionState,
+    surcharge_config: Option<SurchargeConfigRef>,
+    payment_attempt: &storage::PaymentAttempt,
+    payment_intent: &storage::PaymentIntent,
+    billing_address: Option<hyperswitch_domain_models::address::Address>,
+    amount: common_utils_types::MinorUnit,
+) -> ConditionalConfigResult<Option<types::SurchargeDetails>> {
+    let Some(surcharge_config) = surcharge_config else {
+        return Ok(None);
+    };
+    let mut backend_input =
+        make_dsl_input_for_surcharge(payment_attempt, payment_intent, billing_address)
+            .change_context(ConfigError::InputConstructionError)?;
+    backend_input.payment.amount = amount;
+    backend_input.payment_method.payment_method = payment_attempt.payment_method;
+    backend_input.payment_method.payment_method_type = payment_attempt.payment_method_type;
+    backend_input.payment_method.card_network = payment_attempt.extract_card_network();
+
+    let cached_algo = ensure_algorithm_cached(&*state.store, &surcharge_config).await?;
+    let surcharge_output =
+        execute_dsl_and_get_conditional_config(backend_input, &cached_algo.cached_algorithm)?;
+    let original_amount = amount
+        - payment_attempt
+            .net_amount
+            .get_shipping_cost()
+            .unwrap_or_default()
+        - payment_attempt
+            .net_amount
+            .get_order_tax_amount()
+            .unwrap_or_default();
+    surcharge_output
+        .surcharge_details
+        .map(|surcharge_details| {
+            build_surcharge_details(surcharge_details, amount, original_amount)
+        })
+        .transpose()
+}
+
+/// Returns the surcharge amount and the tax on surcharge amount for the given amount
+pub fn calculate_surcharge_amounts(
+    surcharge_details: &surcharge_decision_configs::SurchargeDetailsOutput,
+    amount: common_utils_types::MinorUnit,
+) -> ConditionalConfigResult<(common_utils_types::MinorUnit, common_utils_types::MinorUnit)> {
     let surcharge_amount = match surcharge_details.surcharge.clone() {
         surcharge_decision_configs::SurchargeOutput::Fixed { amount } => amount,
         surcharge_decision_configs::SurchargeOutput::Rate(percentage) => percentage
-            .apply_and_ceil_result(payment_attempt.net_amount.get_total_amount())
+            .apply_and_ceil_result(amount)
             .change_context(ConfigError::DslExecutionError)
             .attach_printable("Failed to Calculate surcharge amount by applying percentage")?,
     };
@@ -470,32 +597,37 @@ fn get_surcharge_details_from_surcharge_output(
         })
         .transpose()?
         .unwrap_or_default();
-    Ok(types::SurchargeDetails {
-        original_amount: payment_attempt.net_amount.get_order_amount(),
-        surcharge: match surcharge_details.surcharge {
-            surcharge_decision_configs::SurchargeOutput::Fixed { amount } => {
-                common_utils_types::Surcharge::Fixed(amount)
-            }
-            surcharge_decision_configs::SurchargeOutput::Rate(percentage) => {
-                common_utils_types::Surcharge::Rate(percentage)
-            }
-        },
-        tax_on_surcharge: surcharge_details.tax_on_surcharge,
-        surcharge_amount,
-        tax_on_surcharge_amount,
-    })
+    Ok((surcharge_amount, tax_on_surcharge_amount))
+}
+
+/// Evaluates the surcharge rules of the merchant against the given input, without requiring a
+/// payment to exist
+#[instrument(skip_all)]
+pub async fn perform_surcharge_decision_management_for_preview(
+    state: &SessionState,
+    surcharge_config: Option<SurchargeConfigRef>,
+    backend_input: dsl_inputs::BackendInput,
+) -> ConditionalConfigResult<Option<surcharge_decision_configs::SurchargeDetailsOutput>> {
+    let Some(surcharge_config) = surcharge_config else {
+        return Ok(None);
+    };
+    let cached_algo = ensure_algorithm_cached(&*state.store, &surcharge_config).await?;
+    let surcharge_output =
+        execute_dsl_and_get_conditional_config(backend_input, &cached_algo.cached_algorithm)?;
+    Ok(surcharge_output.surcharge_details)
 }
 
 #[instrument(skip_all)]
 pub async fn ensure_algorithm_cached(
     store: &dyn StorageInterface,
-    merchant_id: &common_utils::id_type::MerchantId,
-    algorithm_id: &str,
+    surcharge_config: &SurchargeConfigRef,
 ) -> ConditionalConfigResult<VirInterpreterBackendCacheWrapper> {
-    let key = merchant_id.get_surcharge_dsk_key();
+    let key = surcharge_config.cache_key.as_str();
 
     let value_to_cache = || async {
-        let config: diesel_models::Config = store.find_config_by_key(algorithm_id).await?;
+        let config: diesel_models::Config = store
+            .find_config_by_key(&surcharge_config.config_key)
+            .await?;
         let record: SurchargeDecisionManagerRecord = config
             .config
             .parse_struct("Program")
@@ -507,7 +639,7 @@ pub async fn ensure_algorithm_cached(
     };
     let interpreter = cache::get_or_populate_in_memory(
         store.get_cache_store().as_ref(),
-        &key,
+        key,
         value_to_cache,
         &SURCHARGE_CACHE,
     )
@@ -1638,6 +1638,7 @@ pub async fn delete_payment_token(
     if ![
         enums::IntentStatus::RequiresCustomerAction,
         enums::IntentStatus::RequiresMerchantAction,
+        enums::IntentStatus::RequiresMerchantReview,
     ]
     .contains(&intent_status)
     {
@@ -0,0 +1,515 @@
+use api_models::{blocklist as api_blocklist, payment_review as review_api};
+use common_utils::{fp_utils::when, id_type};
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::payments::HeaderPayload;
+use masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::{
+        blocklist::utils as blocklist_utils,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::{self, OperationSessionGetters, OperationSessionSetters},
+        utils as core_utils,
+    },
+    routes::{app::ReqState, SessionState},
+    services::{self, ApplicationResponse},
+    types::{
+        api::{self, payments as payment_types},
+        domain,
+        storage::{self, enums},
+    },
+    utils::OptionExt,
+};
+
+/// Max length of the reasons recorded on a review
+const PAYMENT_REVIEW_REASON_MAX_LENGTH: usize = 255;
+
+/// Holds a confirmed payment for a review by the merchant, and adds it to the review queue.
+///
+/// Payments for which the FRM connector suggested a manual review are already held by the FRM
+/// flow, and keep the `requires_merchant_action` status. Payments which triggered a velocity rule
+/// with the manual review action are authorized without being captured, and are held here once the
+/// authorization succeeds. Those which need an action of the customer first are left to be
+/// captured by the merchant.
+#[instrument(skip_all)]
+pub async fn hold_payment_for_review_if_required<F, D>(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_data: &mut D,
+    velocity_review_rule: Option<String>,
+) -> RouterResult<()>
+where
+    F: Send + Clone + Sync,
+    D: OperationSessionGetters<F> + OperationSessionSetters<F> + Send + Sync + Clone,
+{
+    let (source, reason) = match (
+        payment_data.get_payment_intent().status,
+        velocity_review_rule,
+    ) {
+        (enums::IntentStatus::RequiresMerchantAction, _) => {
+            let Some(frm_message) = payment_data.get_frm_message().filter(|frm_message| {
+                frm_message.frm_status == enums::FraudCheckStatus::ManualReview
+            }) else {
+                return Ok(());
+            };
+            (
+                enums::PaymentReviewSource::Frm,
+                Some(get_frm_review_reason(&frm_message)),
+            )
+        }
+        (enums::IntentStatus::RequiresCapture, Some(rule_name)) => {
+            let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+            let payment_intent = state
+                .store
+                .update_payment_intent(
+                    &state.into(),
+                    payment_data.get_payment_intent().clone(),
+                    storage::PaymentIntentUpdate::ManualUpdate {
+                        status: Some(enums::IntentStatus::RequiresMerchantReview),
+                        updated_by: storage_scheme.to_string(),
+                    },
+                    merchant_context.get_merchant_key_store(),
+                    storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+                .attach_printable("Failed to hold the payment for review")?;
+            payment_data.set_payment_intent(payment_intent);
+            (enums::PaymentReviewSource::VelocityRule, Some(rule_name))
+        }
+        _ => return Ok(()),
+    };
+
+    let payment_intent = payment_data.get_payment_intent();
+    let now = common_utils::date_time::now();
+    let payment_review = storage::PaymentReviewNew {
+        id: common_utils::generate_id_with_default_len("review"),
+        merchant_id: payment_intent.merchant_id.clone(),
+        profile_id: payment_intent
+            .profile_id
+            .clone()
+            .get_required_value("profile_id")?,
+        payment_id: payment_intent.get_id().to_owned(),
+        attempt_id: payment_data.get_payment_attempt().attempt_id.clone(),
+        source,
+        reason: reason.map(|reason| {
+            reason
+                .chars()
+                .take(PAYMENT_REVIEW_REASON_MAX_LENGTH)
+                .collect()
+        }),
+        status: enums::PaymentReviewStatus::Pending,
+        amount: payment_intent.amount,
+        currency: payment_intent.currency,
+        created_at: now,
+        modified_at: now,
+    };
+    state
+        .store
+        .insert_payment_review(payment_review)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add the payment to the review queue")?;
+
+    Ok(())
+}
+
+/// Records the decision taken on a payment through the payment approve and reject APIs on its
+/// pending review, so that the review doesn't stay in the queue. Decisions taken from the review
+/// queue overwrite it with the reviewer and the reason.
+#[instrument(skip_all)]
+pub async fn record_payment_decision_on_pending_review(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+    status: enums::PaymentReviewStatus,
+) -> RouterResult<()> {
+    let payment_review = match state
+        .store
+        .find_pending_payment_review_by_merchant_id_payment_id(merchant_id, payment_id)
+        .await
+    {
+        Ok(payment_review) => payment_review,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(()),
+        Err(error) => {
+            return Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find the pending review of the payment"))
+        }
+    };
+
+    state
+        .store
+        .update_payment_review_by_merchant_id_id(
+            merchant_id,
+            &payment_review.id,
+            storage::PaymentReviewUpdate::decision(status, None, None),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed to record the decision on payment review {}",
+                payment_review.id
+            )
+        })?;
+
+    Ok(())
+}
+
+fn get_frm_review_reason(frm_message: &storage::FraudCheck) -> String {
+    frm_message
+        .frm_reason
+        .as_ref()
+        .and_then(|frm_reason| frm_reason.as_str())
+        .map(|frm_reason| format!("{}: {frm_reason}", frm_message.frm_name))
+        .unwrap_or_else(|| frm_message.frm_name.clone())
+}
+
+/// Lists the review queue of the merchant, oldest first
+pub async fn list_payment_reviews(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    request: review_api::PaymentReviewListRequest,
+) -> RouterResponse<review_api::PaymentReviewListResponse> {
+    let limit = request
+        .limit
+        .map_or(consts::PAYMENT_REVIEWS_DEFAULT_LIMIT, i64::from)
+        .min(consts::PAYMENT_REVIEWS_MAX_LIMIT);
+
+    let payment_reviews = state
+        .store
+        .filter_payment_reviews_by_merchant_id(
+            merchant_context.get_merchant_account().get_id(),
+            // Users of a profile only see the reviews of their profile
+            profile_id.or(request.profile_id),
+            request.status,
+            request.source,
+            request.created_after,
+            request.created_before,
+            limit,
+            request.offset.map(i64::from).unwrap_or_default(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list payment reviews")?;
+
+    let data = payment_reviews
+        .into_iter()
+        .map(get_payment_review_response)
+        .collect::<Vec<_>>();
+    Ok(ApplicationResponse::Json(
+        review_api::PaymentReviewListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+/// Approves a held payment, which captures it
+#[instrument(skip_all)]
+pub async fn approve_payment_review(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    payment_id: id_type::PaymentId,
+    request: review_api::PaymentReviewApproveRequest,
+    decided_by: Option<common_utils::types::CreatedBy>,
+) -> RouterResponse<review_api::PaymentReviewDecisionResponse> {
+    validate_decision_reason(request.reason.as_deref())?;
+    let (payment_review, decided_by) = get_payment_review_for_decision(
+        &state,
+        &merchant_context,
+        profile_id.clone(),
+        &payment_id,
+        decided_by,
+    )
+    .await?;
+
+    let response = Box::pin(payments::payments_core::<
+        api::Capture,
+        payment_types::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api::Capture>,
+    >(
+        state.clone(),
+        req_state,
+        merchant_context.clone(),
+        profile_id,
+        payments::PaymentApprove,
+        payment_types::PaymentsCaptureRequest {
+            payment_id,
+            ..Default::default()
+        },
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        HeaderPayload::default(),
+    ))
+    .await?;
+    let payments_response = get_payments_response(response)?;
+
+    let payment_review = record_decision(
+        &state,
+        &merchant_context,
+        payment_review,
+        enums::PaymentReviewStatus::Approved,
+        decided_by,
+        request.reason,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(
+        review_api::PaymentReviewDecisionResponse {
+            review: get_payment_review_response(payment_review),
+            payment_status: payments_response.status,
+            blocklisted: Vec::new(),
+        },
+    ))
+}
+
+/// Declines a held payment, which voids it, and adds the requested details of the payment to the
+/// blocklist of the merchant
+#[instrument(skip_all)]
+pub async fn decline_payment_review(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    payment_id: id_type::PaymentId,
+    request: review_api::PaymentReviewDeclineRequest,
+    decided_by: Option<common_utils::types::CreatedBy>,
+) -> RouterResponse<review_api::PaymentReviewDecisionResponse> {
+    validate_decision_reason(request.reason.as_deref())?;
+    let (payment_review, decided_by) = get_payment_review_for_decision(
+        &state,
+        &merchant_context,
+        profile_id.clone(),
+        &payment_id,
+        decided_by,
+    )
+    .await?;
+
+    let response = Box::pin(payments::payments_core::<
+        api::Void,
+        payment_types::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api::Void>,
+    >(
+        state.clone(),
+        req_state,
+        merchant_context.clone(),
+        profile_id,
+        payments::PaymentReject,
+        payment_types::PaymentsCancelRequest {
+            payment_id,
+            cancellation_reason: Some(request.reason.clone().unwrap_or_else(|| {
+                consts::PAYMENT_REVIEW_DECLINED_CANCELLATION_REASON.to_string()
+            })),
+            ..Default::default()
+        },
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        HeaderPayload::default(),
+    ))
+    .await?;
+    let payments_response = get_payments_response(response)?;
+
+    let payment_review = record_decision(
+        &state,
+        &merchant_context,
+        payment_review,
+        enums::PaymentReviewStatus::Declined,
+        decided_by,
+        request.reason,
+    )
+    .await?;
+
+    // The payment is already voided, hence failing to block its details doesn't fail the decision
+    let mut blocklisted = Vec::new();
+    for data_kind in request.add_to_blocklist.unwrap_or_default() {
+        let Some(entry) = get_blocklist_entry(&payments_response, data_kind) else {
+            logger::warn!(
+                ?data_kind,
+                "Payment has no details of the kind to be blocklisted"
+            );
+            continue;
+        };
+        match blocklist_utils::insert_entry_into_blocklist(
+            &state,
+            merchant_context.get_merchant_account().get_id(),
+            api_blocklist::AddToBlocklistRequest {
+                entry,
+                expires_at: None,
+            },
+        )
+        .await
+        {
+            Ok(_) => blocklisted.push(data_kind),
+            Err(error) => {
+                logger::error!(?error, ?data_kind, "Failed to blocklist a declined payment")
+            }
+        }
+    }
+
+    Ok(ApplicationResponse::Json(
+        review_api::PaymentReviewDecisionResponse {
+            review: get_payment_review_response(payment_review),
+            payment_status: payments_response.status,
+            blocklisted,
+        },
+    ))
+}
+
+fn validate_decision_reason(reason: Option<&str>) -> RouterResult<()> {
+    when(
+        reason.is_some_and(|reason| reason.chars().count() > PAYMENT_REVIEW_REASON_MAX_LENGTH),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "reason can be at most {PAYMENT_REVIEW_REASON_MAX_LENGTH} characters long"
+                ),
+            }))
+        },
+    )
+}
+
+/// Fetches the pending review of a payment. Returns the review along with the reviewer to be
+/// recorded on it.
+async fn get_payment_review_for_decision(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    payment_id: &id_type::PaymentId,
+    decided_by: Option<common_utils::types::CreatedBy>,
+) -> RouterResult<(storage::PaymentReview, String)> {
+    let payment_review = state
+        .store
+        .find_pending_payment_review_by_merchant_id_payment_id(
+            merchant_context.get_merchant_account().get_id(),
+            payment_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "No pending review found for the payment".to_string(),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payment_review)?;
+
+    let decided_by = decided_by
+        .map(|decided_by| decided_by.to_string())
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get the user deciding on the payment review")?;
+
+    Ok((payment_review, decided_by))
+}
+
+async fn record_decision(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_review: storage::PaymentReview,
+    status: enums::PaymentReviewStatus,
+    decided_by: String,
+    decision_reason: Option<String>,
+) -> RouterResult<storage::PaymentReview> {
+    state
+        .store
+        .update_payment_review_by_merchant_id_id(
+            merchant_context.get_merchant_account().get_id(),
+            &payment_review.id,
+            storage::PaymentReviewUpdate::decision(status, Some(decided_by), decision_reason),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed to record the decision on payment review {}",
+                payment_review.id
+            )
+        })
+}
+
+fn get_payments_response(
+    response: services::ApplicationResponse<payment_types::PaymentsResponse>,
+) -> RouterResult<payment_types::PaymentsResponse> {
+    match response {
+        ApplicationResponse::Json(response)
+        | ApplicationResponse::JsonWithHeaders((response, _)) => Ok(response),
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get the response in json"),
+    }
+}
+
+/// Builds the blocklist entry of a kind from the details of a payment, if the payment has them
+fn get_blocklist_entry(
+    payments_response: &payment_types::PaymentsResponse,
+    data_kind: enums::BlocklistDataKind,
+) -> Option<api_blocklist::BlocklistRequest> {
+    let card = payments_response
+        .payment_method_data
+        .as_ref()
+        .and_then(|payment_method_data| payment_method_data.payment_method_data.as_ref())
+        .and_then(|payment_method_data| match payment_method_data {
+            payment_types::PaymentMethodDataResponse::Card(card) => Some(card),
+            _ => None,
+        });
+
+    match data_kind {
+        enums::BlocklistDataKind::PaymentMethod => payments_response
+            .fingerprint
+            .clone()
+            .map(api_blocklist::BlocklistRequest::Fingerprint),
+        enums::BlocklistDataKind::CardBin => card
+            .and_then(|card| card.card_isin.clone())
+            .map(api_blocklist::BlocklistRequest::CardBin),
+        enums::BlocklistDataKind::ExtendedCardBin => card
+            .and_then(|card| card.card_extended_bin.clone())
+            .map(api_blocklist::BlocklistRequest::ExtendedCardBin),
+        enums::BlocklistDataKind::Email => payments_response
+            .customer
+            .as_ref()
+            .and_then(|customer| customer.email.as_ref())
+            .map(|email| api_blocklist::BlocklistRequest::Email(email.peek().to_owned())),
+        enums::BlocklistDataKind::IpAddress => payments_response
+            .browser_info
+            .as_ref()
+            .and_then(|browser_info| browser_info.get("ip_address"))
+            .and_then(|ip_address| ip_address.as_str())
+            .map(|ip_address| api_blocklist::BlocklistRequest::IpAddress(ip_address.to_owned())),
+        enums::BlocklistDataKind::CustomerId => {
+            payments_response.customer_id.as_ref().map(|customer_id| {
+                api_blocklist::BlocklistRequest::CustomerId(
+                    customer_id.get_string_repr().to_owned(),
+                )
+            })
+        }
+    }
+}
+
+fn get_payment_review_response(
+    payment_review: storage::PaymentReview,
+) -> review_api::PaymentReviewResponse {
+    review_api::PaymentReviewResponse {
+        review_id: payment_review.id,
+        payment_id: payment_review.payment_id,
+        attempt_id: payment_review.attempt_id,
+        profile_id: payment_review.profile_id,
+        source: payment_review.source,
+        reason: payment_review.reason,
+        status: payment_review.status,
+        amount: payment_review.amount,
+        currency: payment_review.currency,
+        decided_by: payment_review.decided_by,
+        decision_reason: payment_review.decision_reason,
+        decided_at: payment_review.decided_at,
+        created_at: payment_review.created_at,
+    }
+}
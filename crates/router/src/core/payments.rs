@@ -1,6 +1,10 @@
 pub mod access_token;
+#[cfg(feature = "v1")]
+pub mod batch;
 pub mod conditional_configs;
 pub mod customers;
+#[cfg(feature = "v1")]
+pub mod dcc;
 pub mod flows;
 pub mod helpers;
 pub mod operations;
@@ -8,8 +12,12 @@ pub mod operations;
 #[cfg(feature = "retry")]
 pub mod retry;
 pub mod routing;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod search;
 #[cfg(feature = "v2")]
 pub mod session_operation;
+#[cfg(feature = "v1")]
+pub mod soft_decline_retry;
 pub mod tokenization;
 pub mod transformers;
 pub mod types;
@@ -101,9 +109,15 @@ use super::{
 use crate::core::debit_routing;
 #[cfg(feature = "frm")]
 use crate::core::fraud_check as frm_core;
+#[cfg(feature = "v1")]
+use crate::core::frm_velocity_rules;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use crate::core::list_views;
 #[cfg(feature = "v2")]
 use crate::core::payment_methods::vault;
 #[cfg(feature = "v1")]
+use crate::core::payment_review;
+#[cfg(feature = "v1")]
 use crate::core::routing::helpers as routing_helpers;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use crate::types::api::convert_connector_data_to_routable_connectors;
@@ -601,6 +615,9 @@ where
         &payment_data.get_payment_intent().clone(),
     )?;
 
+    let state_with_connector_audit_capture = state.with_connector_audit_capture(&business_profile);
+    let state = &*state_with_connector_audit_capture;
+
     let (operation, customer) = operation
         .to_domain()?
         // get_customer_details
@@ -620,6 +637,18 @@ where
 
     payment_data.set_authentication_type_in_attempt(authentication_type);
 
+    let velocity_review_rule = if is_operation_confirm(&operation) {
+        frm_velocity_guard(
+            state,
+            merchant_context,
+            &business_profile,
+            &mut payment_data,
+        )
+        .await?
+    } else {
+        None
+    };
+
     let connector = get_connector_choice(
         &operation,
         state,
@@ -654,7 +683,12 @@ where
 
     operation
         .to_domain()?
-        .apply_three_ds_authentication_strategy(state, &mut payment_data, &business_profile)
+        .apply_three_ds_authentication_strategy(
+            state,
+            &mut payment_data,
+            &business_profile,
+            merchant_context,
+        )
         .await?;
 
     let should_add_task_to_process_tracker = should_add_task_to_process_tracker(&payment_data);
@@ -712,7 +746,11 @@ where
         )
         .await?;
 
-        if is_eligible_for_uas {
+        if payment_data.get_authentication().is_some() {
+            logger::info!(
+                "skipping authentication since a completed authentication is already associated with the payment"
+            );
+        } else if is_eligible_for_uas {
             operation
                 .to_domain()?
                 .call_unified_authentication_service_if_eligible(
@@ -879,6 +917,24 @@ where
                         )
                         .await?;
 
+                    // Retries of the payment are rescheduled by the retry task itself
+                    if should_trigger_post_processing_flows
+                        && header_payload.payment_confirm_source
+                            != Some(enums::PaymentSource::ScheduledRetry)
+                    {
+                        soft_decline_retry::schedule_retry_if_required(
+                            state,
+                            &business_profile,
+                            payment_data.get_payment_intent(),
+                            payment_data.get_payment_attempt(),
+                        )
+                        .await
+                        .map_err(|error| {
+                            logger::error!(?error, "Failed to schedule soft decline retry")
+                        })
+                        .ok();
+                    }
+
                     if should_trigger_post_processing_flows {
                         complete_postprocessing_steps_if_required(
                             state,
@@ -1046,6 +1102,24 @@ where
                         )
                         .await?;
 
+                    // Retries of the payment are rescheduled by the retry task itself
+                    if should_trigger_post_processing_flows
+                        && header_payload.payment_confirm_source
+                            != Some(enums::PaymentSource::ScheduledRetry)
+                    {
+                        soft_decline_retry::schedule_retry_if_required(
+                            state,
+                            &business_profile,
+                            payment_data.get_payment_intent(),
+                            payment_data.get_payment_attempt(),
+                        )
+                        .await
+                        .map_err(|error| {
+                            logger::error!(?error, "Failed to schedule soft decline retry")
+                        })
+                        .ok();
+                    }
+
                     if should_trigger_post_processing_flows {
                         complete_postprocessing_steps_if_required(
                             state,
@@ -1173,6 +1247,16 @@ where
             .await?;
     }
 
+    if is_operation_confirm(&operation) {
+        payment_review::hold_payment_for_review_if_required(
+            state,
+            merchant_context,
+            &mut payment_data,
+            velocity_review_rule,
+        )
+        .await?;
+    }
+
     let cloned_payment_data = payment_data.clone();
     let cloned_customer = customer.clone();
 
@@ -1897,10 +1981,30 @@ where
             payment_data.surcharge_details = Some(surcharge_details);
             return Ok(());
         }
+        let card_type_from_bin_lookup = payment_data
+            .payment_attempt
+            .payment_method_data
+            .clone()
+            .map(|data| {
+                data.parse_value::<api_models::payments::AdditionalPaymentData>(
+                    "AdditionalPaymentData",
+                )
+            })
+            .transpose()
+            .map_err(|err| logger::error!("Failed to parse AdditionalPaymentData {err:?}"))
+            .ok()
+            .flatten()
+            .and_then(|additional_payment_data| additional_payment_data.get_additional_card_info())
+            .and_then(|additional_card_info| additional_card_info.card_type);
         let raw_card_key = payment_data
             .payment_method_data
             .as_ref()
-            .and_then(helpers::get_key_params_for_surcharge_details)
+            .and_then(|payment_method_data| {
+                helpers::get_key_params_for_surcharge_details(
+                    payment_method_data,
+                    card_type_from_bin_lookup.as_deref(),
+                )
+            })
             .map(|(payment_method, payment_method_type, card_network)| {
                 types::SurchargeKey::PaymentMethodData(
                     payment_method,
@@ -2018,7 +2122,7 @@ pub async fn call_surcharge_decision_management_for_session_flow(
 pub async fn call_surcharge_decision_management_for_session_flow(
     state: &SessionState,
     merchant_context: &domain::MerchantContext,
-    _business_profile: &domain::Profile,
+    business_profile: &domain::Profile,
     payment_attempt: &storage::PaymentAttempt,
     payment_intent: &storage::PaymentIntent,
     billing_address: Option<hyperswitch_domain_models::address::Address>,
@@ -2043,25 +2147,17 @@ pub async fn call_surcharge_decision_management_for_session_flow(
             .map(|session_connector_data| session_connector_data.payment_method_sub_type)
             .collect();
 
-        #[cfg(feature = "v1")]
-        let algorithm_ref: api::routing::RoutingAlgorithmRef = merchant_context
-            .get_merchant_account()
-            .routing_algorithm
-            .clone()
-            .map(|val| val.parse_value("routing algorithm"))
-            .transpose()
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Could not decode the routing algorithm")?
-            .unwrap_or_default();
-
-        // TODO: Move to business profile surcharge column
-        #[cfg(feature = "v2")]
-        let algorithm_ref: api::routing::RoutingAlgorithmRef = todo!();
+        let surcharge_config = surcharge_decision_configs::get_surcharge_config_ref(
+            merchant_context.get_merchant_account(),
+            Some(business_profile),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not resolve the surcharge decision config")?;
 
         let surcharge_results =
             surcharge_decision_configs::perform_surcharge_decision_management_for_session_flow(
                 state,
-                algorithm_ref,
+                surcharge_config,
                 payment_attempt,
                 payment_intent,
                 billing_address,
@@ -3361,8 +3457,10 @@ impl ValidateStatusForOperation for &PaymentRedirectSync {
             | common_enums::IntentStatus::Processing
             | common_enums::IntentStatus::RequiresPaymentMethod
             | common_enums::IntentStatus::RequiresMerchantAction
+            | common_enums::IntentStatus::RequiresMerchantReview
             | common_enums::IntentStatus::RequiresCapture
             | common_enums::IntentStatus::PartiallyAuthorizedAndRequiresCapture
+            | common_enums::IntentStatus::IncrementalAuthorizationPending
             | common_enums::IntentStatus::PartiallyCaptured
             | common_enums::IntentStatus::RequiresConfirmation
             | common_enums::IntentStatus::PartiallyCapturedAndCapturable
@@ -4084,6 +4182,15 @@ where
     dyn api::Connector:
         services::api::ConnectorIntegration<F, RouterDReq, router_types::PaymentsResponseData>,
 {
+    helpers::validate_split_payments_for_connector(
+        connector.connector_name,
+        payment_data.get_payment_intent().split_payments.as_ref(),
+    )?;
+    helpers::validate_dcc_for_connector(
+        &connector,
+        payment_data.get_payment_attempt().dcc_details.as_ref(),
+    )?;
+
     let merchant_connector_account = construct_profile_id_and_get_mca(
         state,
         merchant_context,
@@ -5926,6 +6033,68 @@ where
     }
 }
 
+/// Evaluates the FRM velocity rules of the profile for the payment before it is authorized, and
+/// takes the action of the most restrictive rule triggered by it. Returns the name of the rule
+/// when the payment is to be held for a review once authorized.
+#[cfg(feature = "v1")]
+async fn frm_velocity_guard<F, D>(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    business_profile: &domain::Profile,
+    payment_data: &mut D,
+) -> RouterResult<Option<String>>
+where
+    F: Send + Clone + Sync,
+    D: OperationSessionGetters<F> + OperationSessionSetters<F> + Send + Sync + Clone,
+{
+    let Some(decision) = frm_velocity_rules::perform_frm_velocity_checks(
+        state,
+        business_profile,
+        payment_data.get_payment_intent(),
+        payment_data.get_payment_attempt(),
+        payment_data.get_payment_method_data(),
+    )
+    .await?
+    else {
+        return Ok(None);
+    };
+
+    match decision.action {
+        enums::FrmVelocityAction::Block => {
+            frm_velocity_rules::reject_payment_blocked_by_velocity_rule(
+                state,
+                merchant_context,
+                payment_data.get_payment_intent().clone(),
+                payment_data.get_payment_attempt().clone(),
+                &decision,
+            )
+            .await?;
+            Err(errors::ApiErrorResponse::PaymentBlockedError {
+                code: 200,
+                message: consts::FRM_VELOCITY_BLOCKED_ERROR_MESSAGE.to_string(),
+                status: "Failed".to_string(),
+                reason: decision.rule_name,
+            }
+            .into())
+        }
+        enums::FrmVelocityAction::ThreeDsChallenge => {
+            payment_data
+                .set_authentication_type_in_attempt(Some(enums::AuthenticationType::ThreeDs));
+            Ok(None)
+        }
+        enums::FrmVelocityAction::ManualReview => {
+            // The payment is only authorized, and captured once approved from the review queue
+            if !matches!(
+                payment_data.get_payment_attempt().capture_method,
+                Some(enums::CaptureMethod::Manual | enums::CaptureMethod::ManualMultiple)
+            ) {
+                payment_data.set_capture_method_in_attempt(enums::CaptureMethod::Manual);
+            }
+            Ok(Some(decision.rule_name))
+        }
+    }
+}
+
 #[cfg(feature = "v2")]
 #[allow(clippy::too_many_arguments)]
 pub async fn call_multiple_connectors_service<F, Op, Req, D>(
@@ -6065,57 +6234,62 @@ where
         services::api::ConnectorIntegration<F, Req, router_types::PaymentsResponseData>,
 {
     let call_connectors_start_time = Instant::now();
-    let mut join_handlers = Vec::with_capacity(connectors.len());
-    for session_connector_data in connectors.iter() {
-        let connector_id = session_connector_data.connector.connector.id();
-
-        let merchant_connector_account = construct_profile_id_and_get_mca(
-            state,
-            merchant_context,
-            &payment_data,
-            &session_connector_data.connector.connector_name.to_string(),
-            session_connector_data
-                .connector
-                .merchant_connector_id
-                .as_ref(),
-            false,
-        )
-        .await?;
-
-        payment_data.set_surcharge_details(session_surcharge_details.as_ref().and_then(
-            |session_surcharge_details| {
-                session_surcharge_details.fetch_surcharge_details(
-                    session_connector_data.payment_method_sub_type.into(),
-                    session_connector_data.payment_method_sub_type,
-                    None,
-                )
-            },
-        ));
-
-        let router_data = payment_data
-            .construct_router_data(
+    // Each wallet is processed independently so that a failure while preparing or calling one
+    // connector does not prevent the session tokens of the other wallets from being returned
+    let join_handlers = connectors.iter().map(|session_connector_data| {
+        let mut payment_data = payment_data.clone();
+        let header_payload = header_payload.clone();
+        async move {
+            let connector_id = session_connector_data.connector.connector.id();
+
+            let merchant_connector_account = construct_profile_id_and_get_mca(
                 state,
-                connector_id,
                 merchant_context,
-                customer,
-                &merchant_connector_account,
-                None,
-                Some(header_payload.clone()),
+                &payment_data,
+                &session_connector_data.connector.connector_name.to_string(),
+                session_connector_data
+                    .connector
+                    .merchant_connector_id
+                    .as_ref(),
+                false,
             )
             .await?;
 
-        let res = router_data.decide_flows(
-            state,
-            &session_connector_data.connector,
-            CallConnectorAction::Trigger,
-            None,
-            business_profile,
-            header_payload.clone(),
-            return_raw_connector_response,
-        );
+            payment_data.set_surcharge_details(session_surcharge_details.as_ref().and_then(
+                |session_surcharge_details| {
+                    session_surcharge_details.fetch_surcharge_details(
+                        session_connector_data.payment_method_sub_type.into(),
+                        session_connector_data.payment_method_sub_type,
+                        None,
+                    )
+                },
+            ));
 
-        join_handlers.push(res);
-    }
+            let router_data = payment_data
+                .construct_router_data(
+                    state,
+                    connector_id,
+                    merchant_context,
+                    customer,
+                    &merchant_connector_account,
+                    None,
+                    Some(header_payload.clone()),
+                )
+                .await?;
+
+            router_data
+                .decide_flows(
+                    state,
+                    &session_connector_data.connector,
+                    CallConnectorAction::Trigger,
+                    None,
+                    business_profile,
+                    header_payload,
+                    return_raw_connector_response,
+                )
+                .await
+        }
+    });
 
     let result = join_all(join_handlers).await;
 
@@ -6154,7 +6328,7 @@ where
     // If click_to_pay is enabled and authentication_product_ids is configured in profile, we need to attach click_to_pay block in the session response for invoking click_to_pay SDK
     if business_profile.is_click_to_pay_enabled {
         if let Some(value) = business_profile.authentication_product_ids.clone() {
-            let session_token = get_session_token_for_click_to_pay(
+            match get_session_token_for_click_to_pay(
                 state,
                 merchant_context.get_merchant_account().get_id(),
                 merchant_context,
@@ -6162,8 +6336,13 @@ where
                 payment_data.get_payment_intent(),
                 business_profile.get_id(),
             )
-            .await?;
-            payment_data.push_sessions_token(session_token);
+            .await
+            {
+                Ok(session_token) => payment_data.push_sessions_token(session_token),
+                Err(api_error) => {
+                    logger::error!("sessions_api_error click_to_pay {:?}", api_error);
+                }
+            }
         }
     }
 
@@ -7614,6 +7793,7 @@ where
                     storage_enums::IntentStatus::Processing
                         | storage_enums::IntentStatus::RequiresCustomerAction
                         | storage_enums::IntentStatus::RequiresMerchantAction
+                        | storage_enums::IntentStatus::RequiresMerchantReview
                         | storage_enums::IntentStatus::RequiresCapture
                         | storage_enums::IntentStatus::PartiallyCapturedAndCapturable
                 ) && payment_data.get_force_sync().unwrap_or(false)
@@ -7674,6 +7854,7 @@ pub async fn list_payments(
     constraints: api::PaymentListConstraints,
 ) -> RouterResponse<api::PaymentListResponse> {
     helpers::validate_payment_list_request(&constraints)?;
+    let limit = i64::from(constraints.limit);
     let merchant_id = merchant_context.get_merchant_account().get_id();
     let db = state.store.as_ref();
     let payment_intents = helpers::filter_by_constraints(
@@ -7685,6 +7866,12 @@ pub async fn list_payments(
     )
     .await
     .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let next_cursor = core_utils::get_next_list_cursor(&payment_intents, limit, |payment_intent| {
+        common_utils::types::ListCursor::new(
+            payment_intent.created_at,
+            payment_intent.payment_id.get_string_repr(),
+        )
+    });
 
     let collected_futures = payment_intents.into_iter().map(|pi| {
         async {
@@ -7737,6 +7924,7 @@ pub async fn list_payments(
         api::PaymentListResponse {
             size: data.len(),
             data,
+            next_cursor,
         },
     ))
 }
@@ -7828,8 +8016,21 @@ pub async fn apply_filters_on_payments(
 ) -> RouterResponse<api::PaymentListResponseV2> {
     common_utils::metrics::utils::record_operation_time(
         async {
+            let constraints = match constraints.view_id.clone() {
+                Some(view_id) => {
+                    list_views::apply_payments_list_view(
+                        &state,
+                        merchant_context.get_merchant_account().get_id(),
+                        &view_id,
+                        constraints,
+                    )
+                    .await?
+                }
+                None => constraints,
+            };
             let limit = &constraints.limit;
             helpers::validate_payment_list_request_for_joins(*limit)?;
+            helpers::validate_payment_list_cursor(&constraints)?;
             let db: &dyn StorageInterface = state.store.as_ref();
             let pi_fetch_constraints = (constraints.clone(), profile_id_list.clone()).try_into()?;
             let list: Vec<(storage::PaymentIntent, storage::PaymentAttempt)> = db
@@ -7842,6 +8043,16 @@ pub async fn apply_filters_on_payments(
                 )
                 .await
                 .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+            let next_cursor = core_utils::get_next_list_cursor(
+                &list,
+                i64::from(*limit),
+                |(payment_intent, _)| {
+                    common_utils::types::ListCursor::new(
+                        payment_intent.created_at,
+                        payment_intent.payment_id.get_string_repr(),
+                    )
+                },
+            );
             let data: Vec<api::PaymentsResponse> =
                 list.into_iter().map(ForeignFrom::foreign_from).collect();
 
@@ -7880,6 +8091,7 @@ pub async fn apply_filters_on_payments(
                     count: data.len(),
                     total_count,
                     data,
+                    next_cursor,
                 },
             ))
         },
@@ -8126,6 +8338,43 @@ pub async fn reset_process_sync_task(
     Ok(())
 }
 
+/// Schedules the cancellation of a payment intent which is not confirmed by the specified time
+#[cfg(feature = "v1")]
+pub async fn add_payment_intent_expiry_task(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let tracking_data = api::PaymentsRetrieveRequest {
+        merchant_id: Some(payment_intent.merchant_id.clone()),
+        resource_id: api::PaymentIdType::PaymentIntentId(payment_intent.payment_id.clone()),
+        ..Default::default()
+    };
+    let runner = storage::ProcessTrackerRunner::PaymentIntentExpiryWorkflow;
+    let task = "PAYMENT_INTENT_EXPIRY";
+    let tag = ["PAYMENT", "EXPIRY"];
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        payment_intent.payment_id.get_string_repr(),
+        &payment_intent.merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
 #[cfg(feature = "v1")]
 pub fn update_straight_through_routing<F, D>(
     payment_data: &mut D,
@@ -9720,6 +9969,21 @@ where
         algorithm_ref.algorithm_id
     };
 
+    let routing_experiment = routing::get_routing_experiment_for_payment(
+        state,
+        business_profile,
+        &transaction_data.payment_attempt.payment_id,
+    )
+    .await
+    .map_err(|e| logger::error!(routing_experiment_error=?e))
+    .ok()
+    .flatten();
+
+    let routing_algorithm_id = routing_experiment
+        .as_ref()
+        .map(|(experiment, arm)| experiment.get_algorithm_id(*arm).to_owned())
+        .or(routing_algorithm_id);
+
     let (connectors, routing_approach) = routing::perform_static_routing_v1(
         state,
         merchant_context.get_merchant_account().get_id(),
@@ -9730,6 +9994,18 @@ where
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)?;
 
+    if let Some((experiment, arm)) = routing_experiment {
+        routing_helpers::store_routing_experiment_assignment(
+            state,
+            business_profile.get_id(),
+            transaction_data.payment_attempt,
+            routing_helpers::RoutingExperimentAssignment::new(&experiment, arm, &connectors),
+        )
+        .await
+        .map_err(|e| logger::error!(routing_experiment_error=?e))
+        .ok();
+    }
+
     payment_data.set_routing_approach_in_attempt(routing_approach);
 
     #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
@@ -9836,6 +10112,18 @@ where
         connectors
     };
 
+    let connectors =
+        routing::perform_latency_based_routing(state, business_profile, connectors.clone())
+            .await
+            .map_err(|e| logger::error!(latency_based_routing_error=?e))
+            .unwrap_or(connectors);
+
+    let connectors =
+        routing::perform_connector_health_filtering(state, business_profile, connectors.clone())
+            .await
+            .map_err(|e| logger::error!(connector_health_filtering_error=?e))
+            .unwrap_or(connectors);
+
     let connector_data = connectors
         .into_iter()
         .map(|conn| {
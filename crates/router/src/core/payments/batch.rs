@@ -0,0 +1,396 @@
+use std::{borrow::Cow, collections::HashSet};
+
+use actix_multipart::form::{bytes::Bytes, MultipartForm};
+use api_models::payments::{
+    PaymentsBatchCsvRecord, PaymentsBatchItemRequest, PaymentsBatchItemResponse,
+    PaymentsBatchRequest, PaymentsBatchResponse,
+};
+use common_utils::errors::ErrorSwitch;
+use error_stack::ResultExt;
+use futures::StreamExt;
+use router_env::{
+    instrument, logger,
+    tracing::{self, Instrument},
+};
+
+use super::{CallConnectorAction, PaymentCreate, PaymentData};
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::{app::ReqState, SessionState},
+    services::{self, ApplicationResponse},
+    types::{api, domain, storage, storage::enums},
+};
+
+/// Validates a batch of payments and accepts it for processing. The payments are created and
+/// confirmed in the background, and their outcomes are recorded on the batch as they complete.
+#[instrument(skip_all)]
+pub async fn create_payments_batch(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    request: PaymentsBatchRequest,
+) -> RouterResponse<PaymentsBatchResponse> {
+    let item_count = request.items.len();
+    common_utils::fp_utils::when(
+        item_count == 0 || item_count > consts::MAX_PAYMENTS_BATCH_SIZE,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "a batch must contain between 1 and {} payments",
+                    consts::MAX_PAYMENTS_BATCH_SIZE
+                ),
+            })
+        },
+    )?;
+
+    let mut idempotency_keys = HashSet::with_capacity(item_count);
+    for item in &request.items {
+        common_utils::fp_utils::when(!idempotency_keys.insert(&item.idempotency_key), || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "idempotency_key {} is repeated in the batch",
+                    item.idempotency_key
+                ),
+            })
+        })?;
+    }
+
+    let now = common_utils::date_time::now();
+    let payment_batch = storage::PaymentBatchNew {
+        id: common_utils::generate_id_with_default_len("batch"),
+        merchant_id: merchant_context.get_merchant_account().get_id().clone(),
+        profile_id: profile_id.clone(),
+        status: enums::PaymentBatchStatus::Processing,
+        total_count: i32::try_from(item_count)
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        items: storage::PaymentBatchItems(
+            request
+                .items
+                .iter()
+                .map(|item| storage::PaymentBatchItem::pending(item.idempotency_key.clone()))
+                .collect(),
+        ),
+        created_at: now,
+        modified_at: now,
+    };
+
+    let payment_batch = state
+        .store
+        .insert_payment_batch(payment_batch)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert payment batch")?;
+
+    let response = get_payments_batch_response(payment_batch.clone());
+
+    tokio::spawn(
+        process_payments_batch(
+            state,
+            req_state,
+            merchant_context,
+            profile_id,
+            payment_batch,
+            request.items,
+        )
+        .in_current_span(),
+    );
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+pub async fn retrieve_payments_batch(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    batch_id: String,
+) -> RouterResponse<PaymentsBatchResponse> {
+    let payment_batch = state
+        .store
+        .find_payment_batch_by_merchant_id_batch_id(
+            merchant_context.get_merchant_account().get_id(),
+            &batch_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Payment batch not found".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(get_payments_batch_response(
+        payment_batch,
+    )))
+}
+
+#[derive(Debug, MultipartForm)]
+pub struct PaymentsBatchUploadForm {
+    #[multipart(limit = "10MB")]
+    pub file: Bytes,
+}
+
+/// Parses a CSV upload of a batch of payments into payment requests, which are charged
+/// off-session with the saved payment method or mandate given on each row
+pub fn get_payments_batch_request_from_csv(data: &[u8]) -> RouterResult<PaymentsBatchRequest> {
+    let mut csv_reader = csv::Reader::from_reader(data);
+    let items = csv_reader
+        .deserialize::<PaymentsBatchCsvRecord>()
+        .enumerate()
+        .map(|(row, record)| {
+            let record = record.change_context(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("invalid payment on row {} of the batch", row + 1),
+            })?;
+            let recurring_details = match (record.mandate_id, record.payment_method_id) {
+                (Some(mandate_id), None) => {
+                    api_models::mandates::RecurringDetails::MandateId(mandate_id)
+                }
+                (None, Some(payment_method_id)) => {
+                    api_models::mandates::RecurringDetails::PaymentMethodId(payment_method_id)
+                }
+                _ => Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "exactly one of payment_method_id and mandate_id must be provided on row {} of the batch",
+                        row + 1
+                    ),
+                })?,
+            };
+
+            Ok(PaymentsBatchItemRequest {
+                idempotency_key: record.idempotency_key,
+                payment: api_models::payments::PaymentsRequest {
+                    amount: Some(record.amount.into()),
+                    currency: Some(record.currency),
+                    customer_id: Some(record.customer_id),
+                    description: record.description,
+                    recurring_details: Some(recurring_details),
+                    off_session: Some(true),
+                    confirm: Some(true),
+                    ..Default::default()
+                },
+            })
+        })
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    Ok(PaymentsBatchRequest { items })
+}
+
+/// Processes the payments of a batch concurrently, recording the outcomes on the batch after
+/// every round of concurrently processed payments
+async fn process_payments_batch(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    payment_batch: storage::PaymentBatch,
+    items: Vec<PaymentsBatchItemRequest>,
+) {
+    let mut outcomes = payment_batch.items.0;
+    let mut results = futures::stream::iter(items.into_iter().enumerate())
+        .map(|(index, item)| {
+            let state = &state;
+            let req_state = req_state.clone();
+            let merchant_context = &merchant_context;
+            let profile_id = profile_id.clone();
+            async move {
+                let outcome = Box::pin(process_payments_batch_item(
+                    state,
+                    req_state,
+                    merchant_context,
+                    profile_id,
+                    item,
+                ))
+                .await;
+                (index, outcome)
+            }
+        })
+        .buffer_unordered(consts::PAYMENTS_BATCH_CONCURRENCY)
+        .ready_chunks(consts::PAYMENTS_BATCH_CONCURRENCY);
+
+    while let Some(processed) = results.next().await {
+        for (index, outcome) in processed {
+            if let Some(item) = outcomes.get_mut(index) {
+                *item = outcome;
+            }
+        }
+
+        let status = if outcomes
+            .iter()
+            .any(|item| item.status == enums::PaymentBatchItemStatus::Pending)
+        {
+            enums::PaymentBatchStatus::Processing
+        } else {
+            enums::PaymentBatchStatus::Completed
+        };
+
+        state
+            .store
+            .update_payment_batch_by_merchant_id_batch_id(
+                &payment_batch.merchant_id,
+                &payment_batch.id,
+                storage::PaymentBatchUpdate::new(status, outcomes.clone()),
+            )
+            .await
+            .map_err(|error| {
+                logger::error!(?error, batch_id = %payment_batch.id, "Failed to update payment batch")
+            })
+            .ok();
+    }
+}
+
+/// Creates a single payment of a batch, using its idempotency key as the payment id. A payment
+/// which was already created for the key is reported instead of failing the item.
+async fn process_payments_batch_item(
+    state: &SessionState,
+    req_state: ReqState,
+    merchant_context: &domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    item: PaymentsBatchItemRequest,
+) -> storage::PaymentBatchItem {
+    let mut outcome = storage::PaymentBatchItem::pending(item.idempotency_key.clone());
+
+    let payment_id = match validate_payments_batch_item(&item) {
+        Ok(payment_id) => payment_id,
+        Err(error) => {
+            set_payments_batch_item_error(&mut outcome, error.current_context());
+            return outcome;
+        }
+    };
+
+    let mut payment = item.payment;
+    payment.payment_id = Some(api_models::payments::PaymentIdType::PaymentIntentId(
+        payment_id.clone(),
+    ));
+    payment.is_payment_id_from_merchant = true;
+    payment.confirm = Some(payment.confirm.unwrap_or(true));
+
+    let payment_status = match Box::pin(super::payments_core::<
+        api::Authorize,
+        api_models::payments::PaymentsResponse,
+        _,
+        _,
+        _,
+        PaymentData<api::Authorize>,
+    >(
+        state.clone(),
+        req_state,
+        merchant_context.clone(),
+        profile_id,
+        PaymentCreate,
+        payment,
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Trigger,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+    ))
+    .await
+    {
+        Ok(response) => response
+            .get_json_body()
+            .map(|payments_response| payments_response.status)
+            .change_context(errors::ApiErrorResponse::InternalServerError),
+        Err(error)
+            if matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::DuplicatePayment { .. }
+            ) =>
+        {
+            state
+                .store
+                .find_payment_intent_by_payment_id_merchant_id(
+                    &state.into(),
+                    &payment_id,
+                    merchant_context.get_merchant_account().get_id(),
+                    merchant_context.get_merchant_key_store(),
+                    merchant_context.get_merchant_account().storage_scheme,
+                )
+                .await
+                .map(|payment_intent| payment_intent.status)
+                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+        }
+        Err(error) => Err(error),
+    };
+
+    match payment_status {
+        Ok(payment_status) => {
+            outcome.status = enums::PaymentBatchItemStatus::Processed;
+            outcome.payment_id = Some(payment_id);
+            outcome.payment_status = Some(payment_status);
+        }
+        Err(error) => {
+            logger::info!(?error, idempotency_key = %outcome.idempotency_key, "Failed to create payment of batch");
+            set_payments_batch_item_error(&mut outcome, error.current_context());
+        }
+    }
+
+    outcome
+}
+
+fn validate_payments_batch_item(
+    item: &PaymentsBatchItemRequest,
+) -> RouterResult<common_utils::id_type::PaymentId> {
+    item.payment
+        .validate()
+        .map_err(|message| errors::ApiErrorResponse::InvalidRequestData { message })?;
+
+    common_utils::fp_utils::when(item.payment.payment_id.is_some(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message:
+                "payment_id must not be provided, as idempotency_key is used as the payment id"
+                    .to_string(),
+        })
+    })?;
+
+    common_utils::fp_utils::when(
+        matches!(
+            item.payment.capture_method,
+            Some(enums::CaptureMethod::Scheduled)
+        ),
+        || {
+            Err(errors::ApiErrorResponse::NotImplemented {
+                message: errors::NotImplementedMessage::Default,
+            })
+        },
+    )?;
+
+    common_utils::id_type::PaymentId::try_from(Cow::Owned(item.idempotency_key.clone()))
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "idempotency_key",
+        })
+}
+
+fn set_payments_batch_item_error(
+    outcome: &mut storage::PaymentBatchItem,
+    error: &errors::ApiErrorResponse,
+) {
+    let api_error: api_models::errors::types::ApiErrorResponse = error.switch();
+    let error_response = api_models::errors::types::ErrorResponse::from(&api_error);
+
+    outcome.status = enums::PaymentBatchItemStatus::Failed;
+    outcome.error_code = Some(error_response.code);
+    outcome.error_message = Some(error_response.message);
+}
+
+fn get_payments_batch_response(payment_batch: storage::PaymentBatch) -> PaymentsBatchResponse {
+    let items = payment_batch
+        .items
+        .0
+        .into_iter()
+        .map(|item| PaymentsBatchItemResponse {
+            idempotency_key: item.idempotency_key,
+            status: item.status,
+            payment_id: item.payment_id,
+            payment_status: item.payment_status,
+            error_code: item.error_code,
+            error_message: item.error_message,
+        })
+        .collect::<Vec<_>>();
+    let count_items = |status| items.iter().filter(|item| item.status == status).count();
+
+    PaymentsBatchResponse {
+        batch_id: payment_batch.id,
+        status: payment_batch.status,
+        total_count: payment_batch.total_count,
+        processed_count: count_items(enums::PaymentBatchItemStatus::Processed),
+        failed_count: count_items(enums::PaymentBatchItemStatus::Failed),
+        items,
+        created_at: payment_batch.created_at,
+    }
+}
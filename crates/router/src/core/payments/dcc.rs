@@ -0,0 +1,155 @@
+use std::str::FromStr;
+
+use api_models::payments as payment_types;
+use common_utils::{ext_traits::OptionExt, fp_utils::when};
+use diesel_models::DccDetails;
+use error_stack::report;
+use masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        cards_info,
+        currency::{create_quote, use_fx_quote_for_payment},
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::helpers,
+    },
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+};
+
+/// Identifies the currency of a card from the country of its issuer, which is `None` if the BIN is
+/// unknown or its country has no currency of its own
+async fn get_card_currency(
+    state: &SessionState,
+    card_bin: &str,
+) -> RouterResult<Option<enums::Currency>> {
+    let card_info = cards_info::get_card_info_for_bin(state, card_bin).await?;
+
+    Ok(card_info
+        .and_then(|card_info| card_info.country_code)
+        .and_then(|country_code| {
+            enums::CountryAlpha2::from_str(country_code.trim())
+                .inspect_err(|error| {
+                    logger::warn!(?error, %country_code, "Unknown country code of card BIN");
+                })
+                .ok()
+        })
+        .and_then(enums::CountryAlpha2::get_local_currency))
+}
+
+/// Offers the customer to pay in the currency of their card when it differs from the currency of
+/// the payment. The converted amount is guaranteed by a quote until it expires.
+#[instrument(skip_all)]
+pub async fn get_dcc_offer(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: payment_types::PaymentsDccOfferRequest,
+) -> RouterResponse<payment_types::PaymentsDccOfferResponse> {
+    cards_info::verify_iin_length(&request.card_bin)?;
+
+    let payment_intent = helpers::verify_payment_intent_time_and_client_secret(
+        &state,
+        &merchant_context,
+        Some(request.client_secret.peek().to_owned()),
+    )
+    .await?
+    .get_required_value("payment_intent")?;
+    when(payment_intent.payment_id != request.payment_id, || {
+        Err(report!(errors::ApiErrorResponse::ClientSecretInvalid))
+    })?;
+    helpers::validate_payment_status_against_allowed_statuses(
+        payment_intent.status,
+        &[
+            enums::IntentStatus::RequiresPaymentMethod,
+            enums::IntentStatus::RequiresConfirmation,
+        ],
+        "get a dcc offer for",
+    )?;
+    let payment_currency = payment_intent.currency.get_required_value("currency")?;
+
+    let card_currency = get_card_currency(&state, &request.card_bin)
+        .await?
+        .filter(|card_currency| *card_currency != payment_currency);
+    let dcc_offer = match card_currency {
+        Some(card_currency) => {
+            let fx_quote = create_quote(
+                &state,
+                merchant_context.get_merchant_account().get_id(),
+                payment_intent.amount,
+                payment_currency,
+                card_currency,
+            )
+            .await?;
+            Some(payment_types::DccOffer {
+                quote_id: fx_quote.id,
+                card_currency,
+                converted_amount: fx_quote.converted_amount,
+                rate: fx_quote.rate,
+                expires_at: fx_quote.expires_at,
+            })
+        }
+        None => None,
+    };
+
+    Ok(ApplicationResponse::Json(
+        payment_types::PaymentsDccOfferResponse {
+            payment_id: payment_intent.payment_id,
+            amount: payment_intent.amount,
+            currency: payment_currency,
+            dcc_offer,
+        },
+    ))
+}
+
+/// Validates the choice of the customer on the DCC offer of a payment, and builds the DCC details
+/// to be recorded on its attempt. The quote of an accepted offer is marked as used by the payment,
+/// so that the converted amount charged to the customer is the one they accepted.
+pub async fn get_dcc_details_for_choice(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    payment_intent: &storage::PaymentIntent,
+    dcc_choice: &payment_types::DccChoice,
+) -> RouterResult<DccDetails> {
+    let payment_currency = payment_intent.currency.get_required_value("currency")?;
+
+    let fx_quote = if dcc_choice.accepted {
+        use_fx_quote_for_payment(
+            state,
+            merchant_id,
+            &dcc_choice.quote_id,
+            &payment_intent.payment_id,
+            payment_intent.amount,
+            payment_currency,
+        )
+        .await?
+    } else {
+        state
+            .store
+            .find_fx_quote_by_merchant_id_quote_id(merchant_id, &dcc_choice.quote_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("FX quote {} not found", dcc_choice.quote_id),
+            })?
+    };
+    when(
+        fx_quote.amount != payment_intent.amount || fx_quote.from_currency != payment_currency,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("FX quote {} is not for this payment", dcc_choice.quote_id),
+            }))
+        },
+    )?;
+
+    Ok(DccDetails {
+        quote_id: fx_quote.id,
+        accepted: dcc_choice.accepted,
+        card_currency: fx_quote.to_currency,
+        converted_amount: fx_quote.converted_amount,
+        rate: fx_quote.rate,
+    })
+}
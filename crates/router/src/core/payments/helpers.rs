@@ -41,7 +41,10 @@ use hyperswitch_domain_models::{
     },
     router_data::KlarnaSdkResponse,
 };
-use hyperswitch_interfaces::integrity::{CheckIntegrity, FlowIntegrity, GetIntegrityObject};
+use hyperswitch_interfaces::{
+    api::ConnectorSpecifications,
+    integrity::{CheckIntegrity, FlowIntegrity, GetIntegrityObject},
+};
 use josekit::jwe;
 use masking::{ExposeInterface, PeekInterface, SwitchStrategy};
 use num_traits::{FromPrimitive, ToPrimitive};
@@ -68,7 +71,7 @@ use crate::{
     connector,
     consts::{self, BASE64_ENGINE},
     core::{
-        authentication,
+        authentication, cards_info,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate::helpers::MandateGenericData,
         payment_methods::{
@@ -283,7 +286,6 @@ pub async fn create_or_find_address_for_payment_by_request(
     payment_id: &id_type::PaymentId,
     storage_scheme: storage_enums::MerchantStorageScheme,
 ) -> CustomResult<Option<domain::Address>, errors::ApiErrorResponse> {
-    let key = merchant_key_store.key.get_inner().peek();
     let db = &state.store;
     let key_manager_state = &state.into();
     Ok(match address_id {
@@ -301,39 +303,64 @@ pub async fn create_or_find_address_for_payment_by_request(
         )
         .transpose()
         .to_not_found_response(errors::ApiErrorResponse::AddressNotFound)?,
-        None => match req_address {
-            Some(address) => {
-                // generate a new address here
-                let address = get_domain_address(state, address, merchant_id, key, storage_scheme)
-                    .await
-                    .change_context(errors::ApiErrorResponse::InternalServerError)
-                    .attach_printable("Failed while encrypting address while insert")?;
-
-                let payment_address = domain::PaymentAddress {
-                    address,
-                    payment_id: payment_id.clone(),
-                    customer_id: customer_id.cloned(),
-                };
-
-                Some(
-                    db.insert_address_for_payments(
-                        key_manager_state,
-                        payment_id,
-                        payment_address,
-                        merchant_key_store,
-                        storage_scheme,
-                    )
-                    .await
-                    .map(|payment_address| payment_address.address)
-                    .change_context(errors::ApiErrorResponse::InternalServerError)
-                    .attach_printable("Failed while inserting new address")?,
+        None => match get_payment_address_by_request(
+            state,
+            req_address,
+            merchant_id,
+            customer_id,
+            merchant_key_store,
+            payment_id,
+            storage_scheme,
+        )
+        .await?
+        {
+            Some(payment_address) => Some(
+                db.insert_address_for_payments(
+                    key_manager_state,
+                    payment_id,
+                    payment_address,
+                    merchant_key_store,
+                    storage_scheme,
                 )
-            }
+                .await
+                .map(|payment_address| payment_address.address)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while inserting new address")?,
+            ),
             None => None,
         },
     })
 }
 
+/// Constructs a new address of the payment from the address in the request, without inserting it.
+/// Used when the address is inserted along with the payment.
+pub async fn get_payment_address_by_request(
+    state: &SessionState,
+    req_address: Option<&api::Address>,
+    merchant_id: &id_type::MerchantId,
+    customer_id: Option<&id_type::CustomerId>,
+    merchant_key_store: &domain::MerchantKeyStore,
+    payment_id: &id_type::PaymentId,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> CustomResult<Option<domain::PaymentAddress>, errors::ApiErrorResponse> {
+    let key = merchant_key_store.key.get_inner().peek();
+    req_address
+        .async_map(|address| async move {
+            let address = get_domain_address(state, address, merchant_id, key, storage_scheme)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while encrypting address while insert")?;
+
+            Ok(domain::PaymentAddress {
+                address,
+                payment_id: payment_id.clone(),
+                customer_id: customer_id.cloned(),
+            })
+        })
+        .await
+        .transpose()
+}
+
 pub async fn get_domain_address(
     session_state: &SessionState,
     address: &api_models::payments::Address,
@@ -1867,6 +1894,7 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, D>(
                         updated_by: None,
                         version: common_types::consts::API_VERSION,
                         tax_registration_id: encryptable_customer.tax_registration_id,
+                        merged_into_customer_id: None,
                     };
                     metrics::CUSTOMER_CREATED.add(1, &[]);
                     db.insert_customer(new_customer, key_manager_state, key_store, storage_scheme)
@@ -2976,6 +3004,72 @@ pub(crate) fn validate_amount_to_capture(
     )
 }
 
+#[cfg(feature = "v1")]
+/// Validates the amount to capture of a payment with overcapture enabled against the overcapture
+/// limit of the connector which authorized it
+pub(crate) fn validate_amount_to_overcapture(
+    connector: Option<&String>,
+    amount_capturable: MinorUnit,
+    amount_to_capture: Option<MinorUnit>,
+) -> RouterResult<()> {
+    let overcapture_limit_percentage = connector
+        .and_then(|connector| api_enums::Connector::from_str(connector).ok())
+        .and_then(|connector| connector.get_overcapture_limit_percentage());
+
+    match overcapture_limit_percentage {
+        Some(overcapture_limit_percentage) => {
+            let max_amount_to_capture = MinorUnit::new(
+                amount_capturable.get_amount_as_i64()
+                    * (100 + i64::from(overcapture_limit_percentage))
+                    / 100,
+            );
+            utils::when(
+                amount_to_capture.is_some_and(|amount| amount > max_amount_to_capture),
+                || {
+                    Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!(
+                            "amount_to_capture exceeds the maximum overcapture amount of {max_amount_to_capture}"
+                        )
+                    }))
+                },
+            )
+        }
+        None => validate_amount_to_capture(
+            amount_capturable.get_amount_as_i64(),
+            amount_to_capture.map(|amount| amount.get_amount_as_i64()),
+        ),
+    }
+}
+
+#[cfg(feature = "v1")]
+/// Validates a tip added to the capture of a payment after its authorization
+pub(crate) fn validate_tip_adjustment(
+    tip_amount: MinorUnit,
+    capture_method: api_enums::CaptureMethod,
+    is_overcapture_enabled: bool,
+) -> RouterResult<()> {
+    utils::when(tip_amount <= MinorUnit::new(0), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "tip_amount must be greater than 0".to_string()
+        }))
+    })?;
+    utils::when(
+        capture_method == api_enums::CaptureMethod::ManualMultiple,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "tip adjustment is not supported for payments with multiple captures"
+                    .to_string()
+            }))
+        },
+    )?;
+    utils::when(!is_overcapture_enabled, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "tip adjustment requires overcapture to be enabled for the payment"
+                .to_string()
+        }))
+    })
+}
+
 #[cfg(feature = "v1")]
 #[instrument(skip_all)]
 pub(crate) fn validate_payment_method_fields_present(
@@ -3128,6 +3222,23 @@ pub(super) fn validate_payment_list_request(
     )?;
     Ok(())
 }
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub(super) fn validate_payment_list_cursor(
+    req: &api::PaymentListFilterConstraints,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    utils::when(
+        req.cursor.is_some() && req.order != api_models::payments::Order::default(),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "cursor can only be used when sorting on created in descending order"
+                    .to_string(),
+            })
+        },
+    )?;
+    Ok(())
+}
+
 #[cfg(feature = "olap")]
 pub(super) fn validate_payment_list_request_for_joins(
     limit: u32,
@@ -3918,6 +4029,8 @@ mod tests {
             order_date: None,
             shipping_amount_tax: None,
             duty_amount: None,
+            purchase_order_number: None,
+            mit_category: None,
             enable_partial_authorization: None,
             enable_overcapture: None,
         };
@@ -4003,6 +4116,8 @@ mod tests {
             order_date: None,
             shipping_amount_tax: None,
             duty_amount: None,
+            purchase_order_number: None,
+            mit_category: None,
             enable_partial_authorization: None,
             enable_overcapture: None,
         };
@@ -4086,12 +4201,64 @@ mod tests {
             order_date: None,
             shipping_amount_tax: None,
             duty_amount: None,
+            purchase_order_number: None,
+            mit_category: None,
             enable_partial_authorization: None,
             enable_overcapture: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_err())
     }
+
+    #[test]
+    fn test_validate_split_payments_for_connector() {
+        let split_payments = common_types::payments::SplitPaymentsRequest::StripeSplitPayment(
+            common_types::payments::StripeSplitPaymentRequest {
+                charge_type: api_enums::PaymentChargeType::Stripe(
+                    api_enums::StripeChargeType::Destination,
+                ),
+                application_fees: Some(MinorUnit::new(100)),
+                transfer_account_id: "acct_123".to_string(),
+            },
+        );
+
+        assert!(validate_split_payments_for_connector(
+            api_enums::Connector::Stripe,
+            Some(&split_payments)
+        )
+        .is_ok());
+        assert!(validate_split_payments_for_connector(
+            api_enums::Connector::Adyen,
+            Some(&split_payments)
+        )
+        .is_err());
+        assert!(validate_split_payments_for_connector(api_enums::Connector::Adyen, None).is_ok());
+    }
+
+    #[test]
+    fn test_validate_amount_to_overcapture() {
+        let stripe = "stripe".to_string();
+        let checkout = "checkout".to_string();
+
+        assert!(validate_amount_to_overcapture(
+            Some(&stripe),
+            MinorUnit::new(1000),
+            Some(MinorUnit::new(1200))
+        )
+        .is_ok());
+        assert!(validate_amount_to_overcapture(
+            Some(&stripe),
+            MinorUnit::new(1000),
+            Some(MinorUnit::new(1201))
+        )
+        .is_err());
+        assert!(validate_amount_to_overcapture(
+            Some(&checkout),
+            MinorUnit::new(1000),
+            Some(MinorUnit::new(1001))
+        )
+        .is_err());
+    }
 }
 
 // This function will be removed after moving this functionality to server_wrap and using cache instead of config
@@ -4416,6 +4583,7 @@ pub fn router_data_type_conversion<F1, F2, Req1, Req2, Res1, Res2>(
         quote_id: None,
         test_mode: router_data.test_mode,
         connector_api_version: router_data.connector_api_version,
+        merchant_connector_id: router_data.merchant_connector_id,
         connector_http_status_code: router_data.connector_http_status_code,
         external_latency: router_data.external_latency,
         apple_pay_flow: router_data.apple_pay_flow,
@@ -4443,17 +4611,20 @@ pub fn get_attempt_type(
     payment_intent: &PaymentIntent,
     payment_attempt: &PaymentAttempt,
     is_manual_retry_enabled: Option<bool>,
+    is_scheduled_retry: bool,
     action: &str,
 ) -> RouterResult<AttemptType> {
     match payment_intent.status {
         enums::IntentStatus::Failed => {
-            if matches!(is_manual_retry_enabled, Some(true)) {
+            // Soft decline retries are scheduled by the profile itself, within their own limits
+            if matches!(is_manual_retry_enabled, Some(true)) || is_scheduled_retry {
                 // if it is false, don't go ahead with manual retry
                 fp_utils::when(
-                    !validate_manual_retry_cutoff(
-                        payment_intent.created_at,
-                        payment_intent.session_expiry,
-                    ),
+                    !is_scheduled_retry
+                        && !validate_manual_retry_cutoff(
+                            payment_intent.created_at,
+                            payment_intent.session_expiry,
+                        ),
                     || {
                         Err(report!(errors::ApiErrorResponse::PreconditionFailed {
                             message:
@@ -4492,7 +4663,8 @@ pub fn get_attempt_type(
                     | enums::AttemptStatus::DeviceDataCollectionPending
                     | enums::AttemptStatus::IntegrityFailure
                     | enums::AttemptStatus::Expired
-                    | enums::AttemptStatus::PartiallyAuthorized => {
+                    | enums::AttemptStatus::PartiallyAuthorized
+                    | enums::AttemptStatus::IncrementalAuthorizationPending => {
                         metrics::MANUAL_RETRY_VALIDATION_FAILED.add(
                             1,
                             router_env::metric_attributes!((
@@ -4551,7 +4723,8 @@ pub fn get_attempt_type(
         | enums::IntentStatus::Succeeded
         | enums::IntentStatus::Conflicted
         | enums::IntentStatus::Expired
-        | enums::IntentStatus::PartiallyAuthorizedAndRequiresCapture => {
+        | enums::IntentStatus::PartiallyAuthorizedAndRequiresCapture
+        | enums::IntentStatus::IncrementalAuthorizationPending => {
             Err(report!(errors::ApiErrorResponse::PreconditionFailed {
                 message: format!(
                     "You cannot {action} this payment because it has status {}",
@@ -4562,6 +4735,7 @@ pub fn get_attempt_type(
 
         enums::IntentStatus::RequiresCustomerAction
         | enums::IntentStatus::RequiresMerchantAction
+        | enums::IntentStatus::RequiresMerchantReview
         | enums::IntentStatus::RequiresPaymentMethod
         | enums::IntentStatus::RequiresConfirmation => Ok(AttemptType::SameOld),
     }
@@ -4693,6 +4867,8 @@ impl AttemptType {
             connector_request_reference_id: None,
             network_transaction_id: None,
             network_details: None,
+            dcc_details: None,
+            device_fingerprint: None,
         }
     }
 
@@ -4824,7 +5000,8 @@ pub fn is_manual_retry_allowed(
             | enums::AttemptStatus::DeviceDataCollectionPending
             | enums::AttemptStatus::IntegrityFailure
             | enums::AttemptStatus::Expired
-            | enums::AttemptStatus::PartiallyAuthorized => {
+            | enums::AttemptStatus::PartiallyAuthorized
+            | enums::AttemptStatus::IncrementalAuthorizationPending => {
                 logger::error!("Payment Attempt should not be in this state because Attempt to Intent status mapping doesn't allow it");
                 None
             }
@@ -4846,10 +5023,12 @@ pub fn is_manual_retry_allowed(
         | enums::IntentStatus::Succeeded
         | enums::IntentStatus::Conflicted
         | enums::IntentStatus::Expired
-        | enums::IntentStatus::PartiallyAuthorizedAndRequiresCapture => Some(false),
+        | enums::IntentStatus::PartiallyAuthorizedAndRequiresCapture
+        | enums::IntentStatus::IncrementalAuthorizationPending => Some(false),
 
         enums::IntentStatus::RequiresCustomerAction
         | enums::IntentStatus::RequiresMerchantAction
+        | enums::IntentStatus::RequiresMerchantReview
         | enums::IntentStatus::RequiresPaymentMethod
         | enums::IntentStatus::RequiresConfirmation => None,
     };
@@ -4883,17 +5062,56 @@ mod test {
             super::get_payment_id_from_client_secret(client_secret3).unwrap()
         );
     }
+
+    #[test]
+    fn test_standalone_authentication_id_for_confirm() {
+        use std::borrow::Cow;
+
+        use common_utils::id_type::AuthenticationId;
+
+        let created_with = AuthenticationId::try_from(Cow::Borrowed("authn_created")).unwrap();
+        let confirmed_with = AuthenticationId::try_from(Cow::Borrowed("authn_confirmed")).unwrap();
+
+        // Payment created with an authentication, confirmed without one
+        assert_eq!(
+            super::get_standalone_authentication_id_for_confirm(None, Some(&created_with), None),
+            Some(&created_with)
+        );
+        // The authentication in the confirm request takes precedence
+        assert_eq!(
+            super::get_standalone_authentication_id_for_confirm(
+                Some(&confirmed_with),
+                Some(&created_with),
+                None
+            ),
+            Some(&confirmed_with)
+        );
+        // The authentication of the payment's own 3DS flow is not picked up
+        assert_eq!(
+            super::get_standalone_authentication_id_for_confirm(
+                None,
+                Some(&created_with),
+                Some(true)
+            ),
+            None
+        );
+        assert_eq!(
+            super::get_standalone_authentication_id_for_confirm(None, None, None),
+            None
+        );
+    }
 }
 
 #[instrument(skip_all)]
 pub async fn get_additional_payment_data(
     pm_data: &domain::PaymentMethodData,
-    db: &dyn StorageInterface,
+    state: &SessionState,
     profile_id: &id_type::ProfileId,
 ) -> Result<
     Option<api_models::payments::AdditionalPaymentData>,
     error_stack::Report<errors::ApiErrorResponse>,
 > {
+    let db = state.store.as_ref();
     match pm_data {
         domain::PaymentMethodData::Card(card_data) => {
             //todo!
@@ -4981,7 +5199,7 @@ pub async fn get_additional_payment_data(
                 let card_info = card_isin
                     .clone()
                     .async_and_then(|card_isin| async move {
-                        db.get_card_info(&card_isin)
+                        cards_info::get_card_info_for_bin(state, &card_isin)
                             .await
                             .map_err(|error| services::logger::warn!(card_info_error=?error))
                             .ok()
@@ -5282,7 +5500,7 @@ pub async fn get_additional_payment_data(
                 let card_info = card_isin
                     .clone()
                     .async_and_then(|card_isin| async move {
-                        db.get_card_info(&card_isin)
+                        cards_info::get_card_info_for_bin(state, &card_isin)
                             .await
                             .map_err(|error| services::logger::warn!(card_info_error=?error))
                             .ok()
@@ -6478,8 +6696,23 @@ pub struct JwsBody {
     pub secured_payload: masking::Secret<String>,
 }
 
+/// Maps the funding type of a card, as returned by the BIN lookup, to the corresponding payment
+/// method type
+pub fn get_payment_method_type_from_card_type(
+    card_type: &str,
+) -> Option<common_enums::PaymentMethodType> {
+    if card_type.eq_ignore_ascii_case("credit") {
+        Some(common_enums::PaymentMethodType::Credit)
+    } else if card_type.eq_ignore_ascii_case("debit") {
+        Some(common_enums::PaymentMethodType::Debit)
+    } else {
+        None
+    }
+}
+
 pub fn get_key_params_for_surcharge_details(
     payment_method_data: &domain::PaymentMethodData,
+    card_type_from_bin_lookup: Option<&str>,
 ) -> Option<(
     common_enums::PaymentMethod,
     common_enums::PaymentMethodType,
@@ -6487,11 +6720,14 @@ pub fn get_key_params_for_surcharge_details(
 )> {
     match payment_method_data {
         domain::PaymentMethodData::Card(card) => {
-            // surcharge generated will always be same for credit as well as debit
-            // since surcharge conditions cannot be defined on card_type
+            // cards whose funding type is unknown are surcharged as credit cards
+            let payment_method_type = card_type_from_bin_lookup
+                .or(card.card_type.as_deref())
+                .and_then(get_payment_method_type_from_card_type)
+                .unwrap_or(common_enums::PaymentMethodType::Credit);
             Some((
                 common_enums::PaymentMethod::Card,
-                common_enums::PaymentMethodType::Credit,
+                payment_method_type,
                 card.card_network.clone(),
             ))
         }
@@ -6697,6 +6933,16 @@ pub fn validate_session_expiry(session_expiry: u32) -> Result<(), errors::ApiErr
     }
 }
 
+pub fn validate_intent_expiry(intent_expiry: u32) -> Result<(), errors::ApiErrorResponse> {
+    if !(consts::MIN_SESSION_EXPIRY..=consts::MAX_SESSION_EXPIRY).contains(&intent_expiry) {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "intent_expiry should be between 60(1 min) to 7890000(3 months).".to_string(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
 pub fn get_recipient_id_for_open_banking(
     merchant_data: &AdditionalMerchantData,
 ) -> Result<Option<String>, errors::ApiErrorResponse> {
@@ -7060,6 +7306,135 @@ pub async fn decide_action_for_unified_authentication_service<F: Clone>(
     })
 }
 
+/// Fetches a standalone external 3DS authentication referenced in a payment request, along with
+/// its authentication value, after validating that it can be used to authorize the payment.
+#[cfg(feature = "v1")]
+pub async fn get_standalone_authentication_store(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    authentication_id: &id_type::AuthenticationId,
+    payment_intent: &PaymentIntent,
+) -> RouterResult<
+    hyperswitch_domain_models::router_request_types::authentication::AuthenticationStore,
+> {
+    let authentication = state
+        .store
+        .find_authentication_by_merchant_id_authentication_id(
+            merchant_context.get_merchant_account().get_id(),
+            authentication_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::AuthenticationNotFound {
+            id: authentication_id.get_string_repr().to_owned(),
+        })?;
+
+    if payment_intent.profile_id.as_ref() != Some(&authentication.profile_id) {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "authentication does not belong to the profile of the payment".to_string(),
+        })?
+    }
+
+    if authentication
+        .payment_id
+        .as_ref()
+        .is_some_and(|payment_id| payment_id != &payment_intent.payment_id)
+    {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "authentication is already associated with a different payment".to_string(),
+        })?
+    }
+
+    if authentication.authentication_status != common_enums::AuthenticationStatus::Success {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "authentication has not completed successfully".to_string(),
+        })?
+    }
+
+    if authentication.authentication_lifecycle_status
+        != common_enums::AuthenticationLifecycleStatus::Unused
+    {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "authentication has already been used or has expired".to_string(),
+        })?
+    }
+
+    if authentication
+        .amount
+        .is_some_and(|amount| amount != payment_intent.amount)
+        || authentication
+            .currency
+            .zip(payment_intent.currency)
+            .is_some_and(|(authentication_currency, payment_currency)| {
+                authentication_currency != payment_currency
+            })
+    {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "amount or currency of the authentication does not match the payment"
+                .to_string(),
+        })?
+    }
+
+    let tokenised_data = vault::get_tokenized_data(
+        state,
+        authentication_id.get_string_repr(),
+        false,
+        merchant_context.get_merchant_key_store().key.get_inner(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::PreconditionFailed {
+        message: "authentication value is no longer available for the authentication".to_string(),
+    })?;
+
+    Ok(
+        hyperswitch_domain_models::router_request_types::authentication::AuthenticationStore {
+            cavv: Some(masking::Secret::new(tokenised_data.value1)),
+            authentication,
+        },
+    )
+}
+
+/// Returns the standalone external 3DS authentication to be used while confirming a payment. The
+/// authentication in the confirm request takes precedence over the one referenced when the payment
+/// was created, which is only used as long as the payment has not gone through an authentication
+/// of its own.
+pub fn get_standalone_authentication_id_for_confirm<'a>(
+    request_authentication_id: Option<&'a id_type::AuthenticationId>,
+    payment_attempt_authentication_id: Option<&'a id_type::AuthenticationId>,
+    external_three_ds_authentication_attempted: Option<bool>,
+) -> Option<&'a id_type::AuthenticationId> {
+    request_authentication_id.or(external_three_ds_authentication_attempted
+        .is_none()
+        .then_some(payment_attempt_authentication_id)
+        .flatten())
+}
+
+/// Associates a standalone external 3DS authentication with the payment it is used for, so that it
+/// can't be used for any other payment.
+#[cfg(feature = "v1")]
+pub async fn link_standalone_authentication_to_payment(
+    state: &SessionState,
+    authentication_store: &mut hyperswitch_domain_models::router_request_types::authentication::AuthenticationStore,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<()> {
+    if authentication_store.authentication.payment_id.as_ref() == Some(payment_id) {
+        return Ok(());
+    }
+
+    authentication_store.authentication = state
+        .store
+        .update_authentication_by_merchant_id_authentication_id(
+            authentication_store.authentication.clone(),
+            storage::AuthenticationUpdate::PaymentIdUpdate {
+                payment_id: payment_id.to_owned(),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to associate the authentication with the payment")?;
+
+    Ok(())
+}
+
 pub enum PaymentExternalAuthenticationFlow {
     PreAuthenticationFlow {
         acquirer_details: Option<authentication::types::AcquirerDetails>,
@@ -7557,6 +7932,51 @@ pub fn validate_platform_request_for_marketplace(
     Ok(())
 }
 
+/// Ensures that the split instructions of a payment are meant for the connector it is routed to,
+/// since connectors silently drop split instructions of other platforms
+pub fn validate_split_payments_for_connector(
+    connector: api_enums::Connector,
+    split_payments: Option<&common_types::payments::SplitPaymentsRequest>,
+) -> Result<(), errors::ApiErrorResponse> {
+    let (split_type, supported_connector) = match split_payments {
+        Some(common_types::payments::SplitPaymentsRequest::StripeSplitPayment(_)) => {
+            ("stripe_split_payment", api_enums::Connector::Stripe)
+        }
+        Some(common_types::payments::SplitPaymentsRequest::AdyenSplitPayment(_)) => {
+            ("adyen_split_payment", api_enums::Connector::Adyen)
+        }
+        Some(common_types::payments::SplitPaymentsRequest::XenditSplitPayment(_)) => {
+            ("xendit_split_payment", api_enums::Connector::Xendit)
+        }
+        None => return Ok(()),
+    };
+
+    utils::when(connector != supported_connector, || {
+        Err(errors::ApiErrorResponse::NotSupported {
+            message: format!("split_payments.{split_type} for connector {connector}"),
+        })
+    })
+}
+
+/// Ensures that a payment in which the customer accepted dynamic currency conversion is routed to
+/// a connector which supports it, since the customer would otherwise not be charged the amount they
+/// accepted in the currency of their card
+pub fn validate_dcc_for_connector(
+    connector: &api::ConnectorData,
+    dcc_details: Option<&diesel_models::DccDetails>,
+) -> Result<(), errors::ApiErrorResponse> {
+    let is_dcc_accepted = dcc_details.is_some_and(|dcc_details| dcc_details.accepted);
+
+    utils::when(
+        is_dcc_accepted && !connector.connector.is_dcc_supported(),
+        || {
+            Err(errors::ApiErrorResponse::NotSupported {
+                message: format!("dcc for connector {}", connector.connector_name),
+            })
+        },
+    )
+}
+
 pub async fn is_merchant_eligible_authentication_service(
     merchant_id: &id_type::MerchantId,
     state: &SessionState,
@@ -419,6 +419,7 @@ pub trait Domain<F: Clone, R, D>: Send + Sync {
         _state: &SessionState,
         _payment_data: &mut D,
         _business_profile: &domain::Profile,
+        _merchant_context: &domain::MerchantContext,
     ) -> CustomResult<(), errors::ApiErrorResponse> {
         Ok(())
     }
@@ -10,6 +10,7 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
+        payment_review,
         payments::{helpers, operations, PaymentData},
     },
     events::audit_events::{AuditEvent, AuditEventType},
@@ -271,6 +272,13 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsCaptureReque
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+        payment_review::record_payment_decision_on_pending_review(
+            state,
+            &payment_data.payment_intent.merchant_id,
+            &payment_data.payment_intent.payment_id,
+            storage_enums::PaymentReviewStatus::Approved,
+        )
+        .await?;
         req_state
             .event_context
             .event(AuditEvent::new(AuditEventType::PaymentApprove))
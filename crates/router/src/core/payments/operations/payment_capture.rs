@@ -84,26 +84,48 @@ impl<F: Send + Clone + Sync> GetTracker<F, payments::PaymentData<F>, api::Paymen
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
-        payment_attempt
-            .amount_to_capture
-            .update_value(request.amount_to_capture);
-
         let capture_method = payment_attempt
             .capture_method
             .get_required_value("capture_method")?;
 
         helpers::validate_status_with_capture_method(payment_intent.status, capture_method)?;
 
-        if !*payment_attempt
+        let is_overcapture_enabled = *payment_attempt
             .is_overcapture_enabled
             .unwrap_or_default()
-            .deref()
-        {
+            .deref();
+
+        let amount_to_capture = match request.tip_amount {
+            Some(tip_amount) => {
+                helpers::validate_tip_adjustment(
+                    tip_amount,
+                    capture_method,
+                    is_overcapture_enabled,
+                )?;
+                Some(
+                    request
+                        .amount_to_capture
+                        .unwrap_or(payment_attempt.amount_capturable)
+                        + tip_amount,
+                )
+            }
+            None => request.amount_to_capture,
+        };
+
+        payment_attempt
+            .amount_to_capture
+            .update_value(amount_to_capture);
+
+        if is_overcapture_enabled {
+            helpers::validate_amount_to_overcapture(
+                payment_attempt.connector.as_ref(),
+                payment_attempt.amount_capturable,
+                amount_to_capture,
+            )?;
+        } else {
             helpers::validate_amount_to_capture(
                 payment_attempt.amount_capturable.get_amount_as_i64(),
-                request
-                    .amount_to_capture
-                    .map(|capture_amount| capture_amount.get_amount_as_i64()),
+                amount_to_capture.map(|capture_amount| capture_amount.get_amount_as_i64()),
             )?;
         }
 
@@ -41,8 +41,10 @@ impl ValidateStatusForOperation for PaymentsCapture {
             | common_enums::IntentStatus::Cancelled
             | common_enums::IntentStatus::CancelledPostCapture
             | common_enums::IntentStatus::Processing
+            | common_enums::IntentStatus::IncrementalAuthorizationPending
             | common_enums::IntentStatus::RequiresCustomerAction
             | common_enums::IntentStatus::RequiresMerchantAction
+            | common_enums::IntentStatus::RequiresMerchantReview
             | common_enums::IntentStatus::RequiresPaymentMethod
             | common_enums::IntentStatus::PartiallyCaptured
             | common_enums::IntentStatus::RequiresConfirmation
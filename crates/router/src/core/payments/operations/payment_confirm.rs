@@ -35,8 +35,8 @@ use crate::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate::helpers as m_helpers,
         payments::{
-            self, helpers, operations, populate_surcharge_details, CustomerDetails, PaymentAddress,
-            PaymentData,
+            self, dcc, helpers, operations, populate_surcharge_details, CustomerDetails,
+            PaymentAddress, PaymentData,
         },
         three_ds_decision_rule,
         unified_authentication_service::{
@@ -130,6 +130,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
                     storage_enums::IntentStatus::Processing,
                     storage_enums::IntentStatus::RequiresCapture,
                     storage_enums::IntentStatus::RequiresMerchantAction,
+                    storage_enums::IntentStatus::RequiresMerchantReview,
                 ],
                 "confirm",
             )?;
@@ -142,6 +143,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
                     storage_enums::IntentStatus::Processing,
                     storage_enums::IntentStatus::RequiresCapture,
                     storage_enums::IntentStatus::RequiresMerchantAction,
+                    storage_enums::IntentStatus::RequiresMerchantReview,
                     storage_enums::IntentStatus::RequiresCustomerAction,
                 ],
                 "confirm",
@@ -287,6 +289,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             match payment_intent.status {
                 api_models::enums::IntentStatus::RequiresCustomerAction
                 | api_models::enums::IntentStatus::RequiresMerchantAction
+                | api_models::enums::IntentStatus::RequiresMerchantReview
                 | api_models::enums::IntentStatus::RequiresPaymentMethod
                 | api_models::enums::IntentStatus::RequiresConfirmation => {
                     // Normal payment
@@ -327,6 +330,8 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
                         &payment_intent,
                         &payment_attempt,
                         business_profile.is_manual_retry_enabled,
+                        header_payload.payment_confirm_source
+                            == Some(common_enums::PaymentSource::ScheduledRetry),
                         "confirm",
                     )?;
 
@@ -414,6 +419,19 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             .map(masking::Secret::new)
             .or(payment_attempt.customer_acceptance);
 
+        if let Some(dcc_choice) = request.dcc.as_ref() {
+            payment_attempt.dcc_details = Some(
+                dcc::get_dcc_details_for_choice(state, merchant_id, &payment_intent, dcc_choice)
+                    .await?,
+            );
+        }
+
+        payment_attempt.device_fingerprint = request
+            .device_fingerprint
+            .clone()
+            .map(ForeignFrom::foreign_from)
+            .or(payment_attempt.device_fingerprint);
+
         currency = payment_attempt.currency.get_required_value("currency")?;
         amount = payment_attempt.get_total_amount().into();
 
@@ -480,7 +498,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             .as_ref()
             .and_then(|pmd| pmd.payment_method_data.clone());
 
-        let store = state.clone().store;
+        let additional_pm_data_state = state.clone();
         let profile_id = payment_intent
             .profile_id
             .clone()
@@ -494,7 +512,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
                     .async_map(|payment_method_data| async move {
                         helpers::get_additional_payment_data(
                             &payment_method_data.into(),
-                            store.as_ref(),
+                            &additional_pm_data_state,
                             &profile_id,
                         )
                         .await
@@ -783,6 +801,30 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             )),
         );
 
+        let mut authentication = helpers::get_standalone_authentication_id_for_confirm(
+            request.authentication_id.as_ref(),
+            payment_attempt.authentication_id.as_ref(),
+            payment_attempt.external_three_ds_authentication_attempted,
+        )
+        .async_map(|authentication_id| {
+            helpers::get_standalone_authentication_store(
+                state,
+                merchant_context,
+                authentication_id,
+                &payment_intent,
+            )
+        })
+        .await
+        .transpose()?;
+        if let Some(authentication_store) = authentication.as_mut() {
+            helpers::link_standalone_authentication_to_payment(
+                state,
+                authentication_store,
+                &payment_intent.payment_id,
+            )
+            .await?;
+        }
+
         let payment_data = PaymentData {
             flow: PhantomData,
             payment_intent,
@@ -820,7 +862,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             payment_link_data: None,
             incremental_authorization_details: None,
             authorizations: vec![],
-            authentication: None,
+            authentication,
             recurring_details,
             poll_config: None,
             tax_data: None,
@@ -1120,97 +1162,139 @@ impl<F: Clone + Send + Sync> Domain<F, api::PaymentsRequest, PaymentData<F>> for
         state: &SessionState,
         payment_data: &mut PaymentData<F>,
         business_profile: &domain::Profile,
+        merchant_context: &domain::MerchantContext,
     ) -> CustomResult<(), errors::ApiErrorResponse> {
         // If the business profile has a three_ds_decision_rule_algorithm, we will use it to determine the 3DS strategy (authentication_type, exemption_type and force_three_ds_challenge)
-        if let Some(three_ds_decision_rule) =
-            business_profile.three_ds_decision_rule_algorithm.clone()
-        {
-            // Parse the three_ds_decision_rule to get the algorithm_id
-            let algorithm_id = three_ds_decision_rule
-                .parse_value::<api::routing::RoutingAlgorithmRef>("RoutingAlgorithmRef")
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Could not decode profile routing algorithm ref")?
-                .algorithm_id
-                .ok_or(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("No algorithm_id found in three_ds_decision_rule_algorithm")?;
-            // get additional card info from payment data
-            let additional_card_info = payment_data
-                .payment_attempt
-                .payment_method_data
-                .as_ref()
-                .map(|payment_method_data| {
-                    payment_method_data
-                        .clone()
-                        .parse_value::<api_models::payments::AdditionalPaymentData>(
-                            "additional_payment_method_data",
-                        )
+        let profile_algorithm_id = business_profile
+            .three_ds_decision_rule_algorithm
+            .clone()
+            .map(|three_ds_decision_rule| {
+                // Parse the three_ds_decision_rule to get the algorithm_id
+                three_ds_decision_rule
+                    .parse_value::<api::routing::RoutingAlgorithmRef>("RoutingAlgorithmRef")
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Could not decode profile routing algorithm ref")?
+                    .algorithm_id
+                    .ok_or(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("No algorithm_id found in three_ds_decision_rule_algorithm")
+            })
+            .transpose()?;
+        // Otherwise fall back to the 3DS decision manager config of the merchant, if any
+        let merchant_algorithm_ref = match profile_algorithm_id {
+            Some(_) => None,
+            None => merchant_context
+                .get_merchant_account()
+                .routing_algorithm
+                .clone()
+                .map(|routing_algorithm| {
+                    routing_algorithm
+                        .parse_value::<api::routing::RoutingAlgorithmRef>("RoutingAlgorithmRef")
                 })
                 .transpose()
                 .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("unable to parse value into additional_payment_method_data")?
-                .and_then(|additional_payment_method_data| {
-                    additional_payment_method_data.get_additional_card_info()
-                });
-            // get acquirer details from business profile based on card network
-            let acquirer_config = additional_card_info.as_ref().and_then(|card_info| {
-                card_info
-                    .card_network
+                .attach_printable("Could not decode merchant routing algorithm ref")?
+                .filter(|algorithm_ref| algorithm_ref.three_ds_decision_config_algo_id.is_some()),
+        };
+        if profile_algorithm_id.is_none() && merchant_algorithm_ref.is_none() {
+            return Ok(());
+        }
+        // get additional card info from payment data
+        let additional_card_info = payment_data
+            .payment_attempt
+            .payment_method_data
+            .as_ref()
+            .map(|payment_method_data| {
+                payment_method_data
                     .clone()
-                    .and_then(|network| business_profile.get_acquirer_details_from_network(network))
+                    .parse_value::<api_models::payments::AdditionalPaymentData>(
+                        "additional_payment_method_data",
+                    )
+            })
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("unable to parse value into additional_payment_method_data")?
+            .and_then(|additional_payment_method_data| {
+                additional_payment_method_data.get_additional_card_info()
             });
-            let country = business_profile
-                .merchant_country_code
+        // get acquirer details from business profile based on card network
+        let acquirer_config = additional_card_info.as_ref().and_then(|card_info| {
+            card_info
+                .card_network
+                .clone()
+                .and_then(|network| business_profile.get_acquirer_details_from_network(network))
+        });
+        let country = business_profile
+            .merchant_country_code
+            .as_ref()
+            .map(|country_code| country_code.validate_and_get_country_from_merchant_country_code())
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error while parsing country from merchant country code")?;
+        let payment = api_models::three_ds_decision_rule::PaymentData {
+            amount: payment_data.payment_intent.amount,
+            currency: payment_data
+                .payment_intent
+                .currency
+                .ok_or(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("currency is not set in payment intent")?,
+        };
+        let payment_method = Some(api_models::three_ds_decision_rule::PaymentMethodMetaData {
+            card_network: additional_card_info
                 .as_ref()
-                .map(|country_code| {
-                    country_code.validate_and_get_country_from_merchant_country_code()
-                })
+                .and_then(|info| info.card_network.clone()),
+        });
+        let issuer = Some(api_models::three_ds_decision_rule::IssuerData {
+            name: additional_card_info
+                .as_ref()
+                .and_then(|info| info.card_issuer.clone()),
+            country: additional_card_info
+                .as_ref()
+                .map(|info| info.card_issuing_country.clone().parse_enum("Country"))
                 .transpose()
                 .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Error while parsing country from merchant country code")?;
+                .attach_printable("Error while getting country enum from issuer country")?,
+        });
+        let acquirer = acquirer_config.as_ref().map(|acquirer| {
+            api_models::three_ds_decision_rule::AcquirerData {
+                country,
+                fraud_rate: Some(acquirer.acquirer_fraud_rate),
+            }
+        });
+        let decision = match (profile_algorithm_id, merchant_algorithm_ref) {
             // get three_ds_decision_rule_output using algorithm_id and payment data
-            let decision = three_ds_decision_rule::get_three_ds_decision_rule_output(
-                state,
-                &business_profile.merchant_id,
-                api_models::three_ds_decision_rule::ThreeDsDecisionRuleExecuteRequest {
-                    routing_id: algorithm_id,
-                    payment: api_models::three_ds_decision_rule::PaymentData {
-                        amount: payment_data.payment_intent.amount,
-                        currency: payment_data
-                            .payment_intent
-                            .currency
-                            .ok_or(errors::ApiErrorResponse::InternalServerError)
-                            .attach_printable("currency is not set in payment intent")?,
+            (Some(routing_id), _) => Some(
+                three_ds_decision_rule::get_three_ds_decision_rule_output(
+                    state,
+                    &business_profile.merchant_id,
+                    api_models::three_ds_decision_rule::ThreeDsDecisionRuleExecuteRequest {
+                        routing_id,
+                        payment,
+                        payment_method,
+                        issuer,
+                        customer_device: None,
+                        acquirer,
                     },
-                    payment_method: Some(
-                        api_models::three_ds_decision_rule::PaymentMethodMetaData {
-                            card_network: additional_card_info
-                                .as_ref()
-                                .and_then(|info| info.card_network.clone()),
-                        },
+                )
+                .await?,
+            ),
+            (None, Some(algorithm_ref)) => {
+                three_ds_decision_rule::get_three_ds_decision_manager_output(
+                    state,
+                    &business_profile.merchant_id,
+                    &algorithm_ref,
+                    three_ds_decision_rule::utils::make_dsl_input(
+                        payment,
+                        payment_method,
+                        None,
+                        issuer,
+                        acquirer,
                     ),
-                    issuer: Some(api_models::three_ds_decision_rule::IssuerData {
-                        name: additional_card_info
-                            .as_ref()
-                            .and_then(|info| info.card_issuer.clone()),
-                        country: additional_card_info
-                            .as_ref()
-                            .map(|info| info.card_issuing_country.clone().parse_enum("Country"))
-                            .transpose()
-                            .change_context(errors::ApiErrorResponse::InternalServerError)
-                            .attach_printable(
-                                "Error while getting country enum from issuer country",
-                            )?,
-                    }),
-                    customer_device: None,
-                    acquirer: acquirer_config.as_ref().map(|acquirer| {
-                        api_models::three_ds_decision_rule::AcquirerData {
-                            country,
-                            fraud_rate: Some(acquirer.acquirer_fraud_rate),
-                        }
-                    }),
-                },
-            )
-            .await?;
+                )
+                .await?
+            }
+            (None, None) => None,
+        };
+        if let Some(decision) = decision {
             logger::info!("Three DS Decision Rule Output: {:?}", decision);
             // We should update authentication_type from the Three DS Decision if it is not already set
             if payment_data.payment_attempt.authentication_type.is_none() {
@@ -1760,8 +1844,7 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
             .payment_method_data
             .as_ref()
             .async_map(|payment_method_data| async {
-                helpers::get_additional_payment_data(payment_method_data, &*state.store, profile_id)
-                    .await
+                helpers::get_additional_payment_data(payment_method_data, state, profile_id).await
             })
             .await
             .transpose()?
@@ -1924,6 +2007,8 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
                             .payment_attempt
                             .network_transaction_id
                             .clone(),
+                        dcc_details: payment_data.payment_attempt.dcc_details,
+                        device_fingerprint: payment_data.payment_attempt.device_fingerprint,
                     },
                     storage_scheme,
                 )
@@ -2022,6 +2107,11 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
                         order_date: payment_data.payment_intent.order_date,
                         shipping_amount_tax: payment_data.payment_intent.shipping_amount_tax,
                         duty_amount: payment_data.payment_intent.duty_amount,
+                        purchase_order_number: payment_data
+                            .payment_intent
+                            .purchase_order_number
+                            .clone(),
+                        mit_category: payment_data.payment_intent.mit_category,
                         enable_partial_authorization: payment_data
                             .payment_intent
                             .enable_partial_authorization,
@@ -35,6 +35,7 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     consts,
     core::{
+        currency::use_fx_quote_for_payment,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate::helpers as m_helpers,
         payment_link,
@@ -44,7 +45,7 @@ use crate::{
     },
     db::StorageInterface,
     events::audit_events::{AuditEvent, AuditEventType},
-    routes::{app::ReqState, SessionState},
+    routes::{app::ReqState, metrics, SessionState},
     services,
     types::{
         self,
@@ -188,45 +189,39 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
 
         let customer_details = helpers::get_customer_details_from_request(request);
 
-        let shipping_address = helpers::create_or_find_address_for_payment_by_request(
-            state,
-            request.shipping.as_ref(),
-            None,
-            merchant_id,
-            customer_details.customer_id.as_ref(),
-            merchant_context.get_merchant_key_store(),
-            &payment_id,
-            merchant_context.get_merchant_account().storage_scheme,
-        )
-        .await?;
-
-        let billing_address = helpers::create_or_find_address_for_payment_by_request(
-            state,
-            request.billing.as_ref(),
-            None,
-            merchant_id,
-            customer_details.customer_id.as_ref(),
-            merchant_context.get_merchant_key_store(),
-            &payment_id,
-            merchant_context.get_merchant_account().storage_scheme,
-        )
-        .await?;
-
-        let payment_method_billing_address =
-            helpers::create_or_find_address_for_payment_by_request(
+        // The addresses are inserted along with the payment, once it is validated
+        let (shipping_address, billing_address, payment_method_billing_address) = tokio::try_join!(
+            helpers::get_payment_address_by_request(
+                state,
+                request.shipping.as_ref(),
+                merchant_id,
+                customer_details.customer_id.as_ref(),
+                merchant_context.get_merchant_key_store(),
+                &payment_id,
+                storage_scheme,
+            ),
+            helpers::get_payment_address_by_request(
+                state,
+                request.billing.as_ref(),
+                merchant_id,
+                customer_details.customer_id.as_ref(),
+                merchant_context.get_merchant_key_store(),
+                &payment_id,
+                storage_scheme,
+            ),
+            helpers::get_payment_address_by_request(
                 state,
                 request
                     .payment_method_data
                     .as_ref()
                     .and_then(|pmd| pmd.billing.as_ref()),
-                None,
                 merchant_id,
                 customer_details.customer_id.as_ref(),
                 merchant_context.get_merchant_key_store(),
                 &payment_id,
-                merchant_context.get_merchant_account().storage_scheme,
-            )
-            .await?;
+                storage_scheme,
+            ),
+        )?;
 
         let browser_info = request
             .browser_info
@@ -302,11 +297,11 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             request,
             shipping_address
                 .as_ref()
-                .map(|address| address.address_id.clone()),
+                .map(|payment_address| payment_address.address.address_id.clone()),
             payment_link_data.clone(),
             billing_address
                 .as_ref()
-                .map(|address| address.address_id.clone()),
+                .map(|payment_address| payment_address.address.address_id.clone()),
             attempt_id,
             profile_id.clone(),
             session_expiry,
@@ -327,7 +322,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             state,
             payment_method_billing_address
                 .as_ref()
-                .map(|address| address.address_id.clone()),
+                .map(|payment_address| payment_address.address.address_id.clone()),
             &payment_method_info,
             merchant_context.get_merchant_key_store(),
             profile_id,
@@ -336,40 +331,75 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
         )
         .await?;
 
-        let payment_intent = db
-            .insert_payment_intent(
-                key_manager_state,
-                payment_intent_new,
-                merchant_context.get_merchant_key_store(),
-                storage_scheme,
+        // The quote is used before the payment is created so that a payment is never created with
+        // a rate that is not guaranteed
+        if let Some(fx_quote_id) = &request.fx_quote_id {
+            use_fx_quote_for_payment(
+                state,
+                merchant_id,
+                fx_quote_id,
+                &payment_id,
+                MinorUnit::from(amount),
+                currency,
             )
+            .await?;
+        }
+
+        let mut authentication = request
+            .authentication_id
+            .as_ref()
+            .async_map(|authentication_id| {
+                helpers::get_standalone_authentication_store(
+                    state,
+                    merchant_context,
+                    authentication_id,
+                    &payment_intent_new,
+                )
+            })
             .await
-            .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
-                payment_id: payment_id.clone(),
-            })?;
+            .transpose()?;
+
+        let payment_attempt_new = match &authentication {
+            Some(authentication_store) => storage::PaymentAttemptNew {
+                authentication_connector: authentication_store
+                    .authentication
+                    .authentication_connector
+                    .clone(),
+                authentication_id: Some(
+                    authentication_store
+                        .authentication
+                        .authentication_id
+                        .clone(),
+                ),
+                ..payment_attempt_new
+            },
+            None => payment_attempt_new,
+        };
 
         if let Some(order_details) = &request.order_details {
             helpers::validate_order_details_amount(
                 order_details.to_owned(),
-                payment_intent.amount,
+                payment_intent_new.amount,
                 false,
             )?;
         }
 
-        #[cfg(feature = "v1")]
-        let mut payment_attempt = db
-            .insert_payment_attempt(payment_attempt_new, storage_scheme)
-            .await
-            .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
-                payment_id: payment_id.clone(),
-            })?;
-
-        #[cfg(feature = "v2")]
-        let payment_attempt = db
-            .insert_payment_attempt(
+        let payment_addresses = [
+            &shipping_address,
+            &billing_address,
+            &payment_method_billing_address,
+        ]
+        .into_iter()
+        .flatten()
+        .cloned()
+        .collect();
+        let (payment_intent, mut payment_attempt) = db
+            .insert_payment_with_addresses(
                 key_manager_state,
-                merchant_key_store,
+                payment_intent_new,
                 payment_attempt_new,
+                payment_addresses,
+                merchant_context.get_merchant_key_store(),
                 storage_scheme,
             )
             .await
@@ -377,6 +407,29 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
                 payment_id: payment_id.clone(),
             })?;
 
+        if let Some(authentication_store) = authentication.as_mut() {
+            helpers::link_standalone_authentication_to_payment(
+                state,
+                authentication_store,
+                &payment_intent.payment_id,
+            )
+            .await?;
+        }
+
+        if let Some(intent_expiry) = business_profile.intent_expiry {
+            let expire_at = payment_intent
+                .created_at
+                .saturating_add(time::Duration::seconds(intent_expiry));
+            payments::add_payment_intent_expiry_task(db, &payment_intent, expire_at)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to add payment intent expiry task to process tracker")?;
+            metrics::TASKS_ADDED_COUNT.add(
+                1,
+                router_env::metric_attributes!(("flow", "PaymentIntentExpiry")),
+            );
+        }
+
         let mandate_details_present = payment_attempt.mandate_details.is_some();
 
         helpers::validate_mandate_data_and_future_usage(
@@ -564,9 +617,15 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             ));
 
         let address = PaymentAddress::new(
-            shipping_address.as_ref().map(From::from),
-            billing_address.as_ref().map(From::from),
-            payment_method_billing_address.as_ref().map(From::from),
+            shipping_address
+                .as_ref()
+                .map(|payment_address| From::from(&payment_address.address)),
+            billing_address
+                .as_ref()
+                .map(|payment_address| From::from(&payment_address.address)),
+            payment_method_billing_address
+                .as_ref()
+                .map(|payment_address| From::from(&payment_address.address)),
             business_profile.use_billing_as_payment_method_billing,
         );
 
@@ -619,7 +678,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             payment_link_data,
             incremental_authorization_details: None,
             authorizations: vec![],
-            authentication: None,
+            authentication,
             recurring_details,
             poll_config: None,
             tax_data: None,
@@ -983,6 +1042,14 @@ impl<F: Send + Clone + Sync> ValidateRequest<F, api::PaymentsRequest, PaymentDat
             }
         };
 
+        // DCC offers are made for existing payments, hence the choice of the customer can only be
+        // passed on confirming the payment
+        utils::when(request.dcc.is_some(), || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "dcc can only be passed on confirming a payment".to_string(),
+            })
+        })?;
+
         let payment_id = request.payment_id.clone().ok_or(error_stack::report!(
             errors::ApiErrorResponse::PaymentNotFound
         ))?;
@@ -1155,7 +1222,7 @@ impl PaymentCreate {
             .async_map(|payment_method_data| async {
                 helpers::get_additional_payment_data(
                     &payment_method_data.into(),
-                    &*state.store,
+                    state,
                     &profile_id,
                 )
                 .await
@@ -1395,6 +1462,11 @@ impl PaymentCreate {
                 connector_request_reference_id: None,
                 network_transaction_id:None,
                 network_details:None,
+                dcc_details: None,
+                device_fingerprint: request
+                    .device_fingerprint
+                    .clone()
+                    .map(ForeignFrom::foreign_from),
             },
             additional_pm_data,
 
@@ -1635,6 +1707,8 @@ impl PaymentCreate {
             order_date: request.order_date,
             discount_amount: request.discount_amount,
             duty_amount: request.duty_amount,
+            purchase_order_number: request.purchase_order_number.clone(),
+            mit_category: request.mit_category,
             tax_status: request.tax_status,
             shipping_amount_tax: request.shipping_amount_tax,
             enable_partial_authorization: request.enable_partial_authorization,
@@ -35,8 +35,10 @@ impl ValidateStatusForOperation for PaymentGet {
         match intent_status {
             common_enums::IntentStatus::RequiresCapture
             | common_enums::IntentStatus::PartiallyAuthorizedAndRequiresCapture
+            | common_enums::IntentStatus::IncrementalAuthorizationPending
             | common_enums::IntentStatus::RequiresCustomerAction
             | common_enums::IntentStatus::RequiresMerchantAction
+            | common_enums::IntentStatus::RequiresMerchantReview
             | common_enums::IntentStatus::Processing
             | common_enums::IntentStatus::Succeeded
             | common_enums::IntentStatus::Failed
@@ -56,8 +58,10 @@ impl ValidateStatusForOperation for PaymentGet {
                     states: [
                         common_enums::IntentStatus::RequiresCapture,
                         common_enums::IntentStatus::PartiallyAuthorizedAndRequiresCapture,
+                        common_enums::IntentStatus::IncrementalAuthorizationPending,
                         common_enums::IntentStatus::RequiresCustomerAction,
                         common_enums::IntentStatus::RequiresMerchantAction,
+                        common_enums::IntentStatus::RequiresMerchantReview,
                         common_enums::IntentStatus::Processing,
                         common_enums::IntentStatus::Succeeded,
                         common_enums::IntentStatus::Failed,
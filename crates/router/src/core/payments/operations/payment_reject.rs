@@ -10,6 +10,7 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
+        payment_review,
         payments::{helpers, operations, PaymentAddress, PaymentData},
     },
     events::audit_events::{AuditEvent, AuditEventType},
@@ -278,6 +279,13 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, PaymentsCancelRequest> fo
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+        payment_review::record_payment_decision_on_pending_review(
+            state,
+            &payment_data.payment_intent.merchant_id,
+            &payment_data.payment_intent.payment_id,
+            enums::PaymentReviewStatus::Declined,
+        )
+        .await?;
         let error_code = payment_data.payment_attempt.error_code.clone();
         let error_message = payment_data.payment_attempt.error_message.clone();
         req_state
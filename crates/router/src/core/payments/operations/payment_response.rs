@@ -18,13 +18,15 @@ use hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt;
 use hyperswitch_domain_models::payments::{
     PaymentConfirmData, PaymentIntentData, PaymentStatusData,
 };
+#[cfg(feature = "v1")]
+use hyperswitch_domain_models::router_data::AccountUpdaterCardDetails;
 use router_derive;
 use router_env::{instrument, logger, tracing};
 use storage_impl::DataModelExt;
 use tracing_futures::Instrument;
 
 use super::{Operation, OperationSessionSetters, PostUpdateTracker};
-#[cfg(all(feature = "v1", feature = "dynamic_routing"))]
+#[cfg(feature = "v1")]
 use crate::core::routing::helpers as routing_helpers;
 #[cfg(feature = "v2")]
 use crate::utils::OptionExt;
@@ -102,6 +104,14 @@ impl<F: Send + Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsAuthor
         payment_data.payment_attempt.setup_future_usage_applied =
             router_data.request.setup_future_usage;
 
+        let authorization_latency = router_data.external_latency;
+        let is_connector_failure = is_connector_health_failure(&router_data.response);
+        let account_updater_card_details = router_data
+            .connector_response
+            .as_ref()
+            .and_then(|connector_response| connector_response.get_account_updater_card_details())
+            .cloned();
+
         payment_data = Box::pin(payment_response_update_tracker(
             db,
             payment_data,
@@ -116,6 +126,16 @@ impl<F: Send + Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsAuthor
         ))
         .await?;
 
+        record_authorization_latency(db, &payment_data, authorization_latency);
+        record_connector_health(db, &payment_data, is_connector_failure);
+        record_payment_method_usage(
+            db,
+            &payment_data,
+            key_store,
+            storage_scheme,
+            account_updater_card_details,
+        );
+
         Ok(payment_data)
     }
 
@@ -407,40 +427,61 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsIncrementalAu
                 report!(errors::ApiErrorResponse::InternalServerError)
                     .attach_printable("missing incremental_authorization_details in payment_data")
             })?;
+        // Restores the status the payment had before the incremental authorization
+        let revert_status_updates = || {
+            (
+                Some(storage::PaymentAttemptUpdate::StatusUpdate {
+                    status: enums::AttemptStatus::Authorized,
+                    updated_by: storage_scheme.to_string(),
+                }),
+                Some(storage::PaymentIntentUpdate::PGStatusUpdate {
+                    status: enums::IntentStatus::RequiresCapture,
+                    incremental_authorization_allowed: None,
+                    updated_by: storage_scheme.to_string(),
+                    feature_metadata: None,
+                }),
+            )
+        };
         // Update payment_intent and payment_attempt 'amount' if incremental_authorization is successful
         let (option_payment_attempt_update, option_payment_intent_update) = match router_data
             .response
             .clone()
         {
-            Err(_) => (None, None),
+            Err(_) => revert_status_updates(),
             Ok(types::PaymentsResponseData::IncrementalAuthorizationResponse {
                 status, ..
-            }) => {
-                if status == AuthorizationStatus::Success {
+            }) => match status {
+                AuthorizationStatus::Success => {
+                    let mut net_amount = payment_data.payment_attempt.net_amount.clone();
+                    if let Some(surcharge_details) = payment_data.surcharge_details.clone() {
+                        net_amount.set_surcharge_details(Some(surcharge_details));
+                    }
+                    // Internally, `NetAmount` is computed as (order_amount + additional_amount), so we subtract here to avoid double-counting.
+                    net_amount.set_order_amount(
+                        incremental_authorization_details.total_amount
+                            - net_amount.get_additional_amount(),
+                    );
+                    let amount = net_amount.get_order_amount();
                     (
                         Some(
                             storage::PaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
-                                net_amount: hyperswitch_domain_models::payments::payment_attempt::NetAmount::new(
-                                    // Internally, `NetAmount` is computed as (order_amount + additional_amount), so we subtract here to avoid double-counting.
-                                    incremental_authorization_details.total_amount - payment_data.payment_attempt.net_amount.get_additional_amount(),
-                                    None,
-                                    None,
-                                    None,
-                                    None,
-                                ),
+                                net_amount,
                                 amount_capturable: incremental_authorization_details.total_amount,
+                                status: enums::AttemptStatus::Authorized,
                             },
                         ),
                         Some(
                             storage::PaymentIntentUpdate::IncrementalAuthorizationAmountUpdate {
-                                amount: incremental_authorization_details.total_amount - payment_data.payment_attempt.net_amount.get_additional_amount(),
+                                amount,
+                                status: enums::IntentStatus::RequiresCapture,
                             },
                         ),
                     )
-                } else {
-                    (None, None)
                 }
-            }
+                // The payment stays pending until the outcome is known through a sync
+                AuthorizationStatus::Processing | AuthorizationStatus::Unresolved => (None, None),
+                AuthorizationStatus::Failure => revert_status_updates(),
+            },
             _ => Err(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("unexpected response in incremental_authorization flow")?,
         };
@@ -2329,6 +2370,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
 
     payment_data.payment_intent = payment_intent;
     payment_data.payment_attempt = payment_attempt;
+    record_routing_experiment_payment(state, &payment_data);
     router_data.payment_method_status.and_then(|status| {
         payment_data
             .payment_method_info
@@ -2381,6 +2423,149 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     }
 }
 
+/// Records the connector's authorization latency in the background, to be used by latency based
+/// routing
+#[cfg(feature = "v1")]
+fn record_authorization_latency<F: Clone>(
+    state: &SessionState,
+    payment_data: &PaymentData<F>,
+    external_latency: Option<u128>,
+) {
+    let (Some(profile_id), Some(connector), Some(latency_ms)) = (
+        payment_data.payment_intent.profile_id.clone(),
+        payment_data.payment_attempt.connector.clone(),
+        external_latency.and_then(|latency| u64::try_from(latency).ok()),
+    ) else {
+        return;
+    };
+    let merchant_connector_id = payment_data.payment_attempt.merchant_connector_id.clone();
+    let state = state.clone();
+
+    tokio::spawn(
+        async move {
+            routing_helpers::record_connector_latency(
+                &state,
+                &profile_id,
+                &connector,
+                merchant_connector_id.as_ref(),
+                latency_ms,
+            )
+            .await
+            .map_err(|e| logger::error!(connector_latency_record_error=?e))
+            .ok();
+        }
+        .in_current_span(),
+    );
+}
+
+/// Server errors and timeouts of the connector count towards its health breaker, declines do not
+#[cfg(feature = "v1")]
+fn is_connector_health_failure<T>(response: &Result<T, types::ErrorResponse>) -> bool {
+    response
+        .as_ref()
+        .is_err_and(|error_response| error_response.status_code >= 500)
+}
+
+/// Records the outcome of the authorization for the connector health breaker in the background
+#[cfg(feature = "v1")]
+fn record_connector_health<F: Clone>(
+    state: &SessionState,
+    payment_data: &PaymentData<F>,
+    is_connector_failure: bool,
+) {
+    let Some(merchant_connector_id) = payment_data.payment_attempt.merchant_connector_id.clone()
+    else {
+        return;
+    };
+    let profile_id = payment_data.payment_attempt.profile_id.clone();
+    let state = state.clone();
+
+    tokio::spawn(
+        async move {
+            routing_helpers::record_connector_health_outcome(
+                &state,
+                &profile_id,
+                &merchant_connector_id,
+                is_connector_failure,
+            )
+            .await
+            .map_err(|e| logger::error!(connector_health_record_error=?e))
+            .ok();
+        }
+        .in_current_span(),
+    );
+}
+
+/// Records the outcome of the payment on the health of the saved payment method it was made with
+/// in the background
+#[cfg(feature = "v1")]
+fn record_payment_method_usage<F: Clone>(
+    state: &SessionState,
+    payment_data: &PaymentData<F>,
+    key_store: &domain::MerchantKeyStore,
+    storage_scheme: enums::MerchantStorageScheme,
+    account_updater_card_details: Option<AccountUpdaterCardDetails>,
+) {
+    let payment_attempt = &payment_data.payment_attempt;
+    let is_success = match payment_attempt.status {
+        enums::AttemptStatus::Charged
+        | enums::AttemptStatus::Authorized
+        | enums::AttemptStatus::PartialCharged
+        | enums::AttemptStatus::PartialChargedAndChargeable => true,
+        enums::AttemptStatus::Failure | enums::AttemptStatus::AuthorizationFailed => false,
+        _ => return,
+    };
+    let Some(payment_method_id) = payment_attempt.payment_method_id.clone() else {
+        return;
+    };
+    let state = state.clone();
+    let key_store = key_store.clone();
+
+    tokio::spawn(
+        async move {
+            payment_methods::health::record_payment_method_usage(
+                &state,
+                &key_store,
+                storage_scheme,
+                &payment_method_id,
+                is_success,
+                account_updater_card_details,
+            )
+            .await
+            .map_err(|e| logger::error!(payment_method_usage_record_error=?e))
+            .ok();
+        }
+        .in_current_span(),
+    );
+}
+
+/// Records the final status of the payment attempt for the routing experiment it was routed with
+/// in the background
+#[cfg(feature = "v1")]
+fn record_routing_experiment_payment<F: Clone>(
+    state: &SessionState,
+    payment_data: &PaymentData<F>,
+) {
+    let payment_attempt = &payment_data.payment_attempt;
+    let is_final_status = payment_attempt.status.is_terminal_status()
+        || payment_attempt.status == enums::AttemptStatus::Authorized;
+    if !is_final_status {
+        return;
+    }
+    let payment_attempt = payment_attempt.clone();
+    let state = state.clone();
+
+    tokio::spawn(
+        async move {
+            routing_helpers::record_routing_experiment_payment(&state, &payment_attempt)
+                .await
+                .map_err(|e| logger::error!(routing_experiment_record_error=?e))
+                .ok();
+        }
+        .in_current_span(),
+    );
+}
+
 #[cfg(feature = "v2")]
 async fn update_payment_method_status_and_ntid<F: Clone>(
     state: &SessionState,
@@ -2673,6 +2858,7 @@ impl<F: Clone> PostUpdateTracker<F, PaymentConfirmData<F>, types::PaymentsAuthor
                 | common_enums::AttemptStatus::AuthenticationSuccessful
                 | common_enums::AttemptStatus::Authorized
                 | common_enums::AttemptStatus::PartiallyAuthorized
+                | common_enums::AttemptStatus::IncrementalAuthorizationPending
                 | common_enums::AttemptStatus::Charged
                 | common_enums::AttemptStatus::Authorizing
                 | common_enums::AttemptStatus::CodInitiated
@@ -189,6 +189,14 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             .amount
             .unwrap_or_else(|| payment_attempt.net_amount.get_order_amount().into());
 
+        // The split instructions must keep adding up to the amount when it is updated
+        if request.amount.is_some() && payment_intent.split_payments.is_some() {
+            helpers::validate_platform_request_for_marketplace(
+                amount,
+                payment_intent.split_payments.clone(),
+            )?;
+        }
+
         if request.confirm.unwrap_or(false) {
             helpers::validate_customer_id_mandatory_cases(
                 request.setup_future_usage.is_some(),
@@ -787,8 +795,7 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
             .payment_method_data
             .as_ref()
             .async_map(|payment_method_data| async {
-                helpers::get_additional_payment_data(payment_method_data, &*state.store, profile_id)
-                    .await
+                helpers::get_additional_payment_data(payment_method_data, state, profile_id).await
             })
             .await
             .transpose()?
@@ -972,6 +979,11 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
                     order_date: payment_data.payment_intent.order_date,
                     shipping_amount_tax: payment_data.payment_intent.shipping_amount_tax,
                     duty_amount: payment_data.payment_intent.duty_amount,
+                    purchase_order_number: payment_data
+                        .payment_intent
+                        .purchase_order_number
+                        .clone(),
+                    mit_category: payment_data.payment_intent.mit_category,
                     enable_partial_authorization: payment_data
                         .payment_intent
                         .enable_partial_authorization,
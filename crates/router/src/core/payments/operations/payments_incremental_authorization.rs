@@ -2,18 +2,20 @@ use std::marker::PhantomData;
 
 use api_models::{enums::FrmSuggestion, payments::PaymentsIncrementalAuthorizationRequest};
 use async_trait::async_trait;
-use common_utils::errors::CustomResult;
+use common_utils::{errors::CustomResult, types::MinorUnit};
 use diesel_models::authorization::AuthorizationNew;
 use error_stack::{report, ResultExt};
+use hyperswitch_interfaces::api::ConnectorSpecifications;
 use router_env::{instrument, tracing};
 
 use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
+        payment_methods::surcharge_decision_configs,
         payments::{
-            self, helpers, operations, CustomerDetails, IncrementalAuthorizationDetails,
-            PaymentAddress,
+            self, helpers, operations, types as payment_types, CustomerDetails,
+            IncrementalAuthorizationDetails, PaymentAddress,
         },
     },
     routes::{app::ReqState, SessionState},
@@ -99,6 +101,25 @@ impl<F: Send + Clone + Sync>
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
+        let connector_name = payment_attempt
+            .connector
+            .as_ref()
+            .get_required_value("connector")?;
+        let connector = api::ConnectorData::get_connector_by_name(
+            &state.conf.connectors,
+            connector_name,
+            api::GetToken::Connector,
+            payment_attempt.merchant_connector_id.clone(),
+        )
+        .attach_printable("Invalid connector name received in payment attempt")?;
+        if !connector.connector.is_incremental_authorization_supported() {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Incremental authorization is not supported by the connector {connector_name}"
+                ),
+            })?
+        }
+
         // Incremental authorization should be performed on an amount greater than the original authorized amount (in this case, greater than the net_amount which is sent for authorization)
         // request.amount is the total amount that should be authorized in incremental authorization which should be greater than the original authorized amount
         if payment_attempt.get_total_amount() >= request.amount {
@@ -129,6 +150,27 @@ impl<F: Send + Clone + Sync>
                 id: profile_id.get_string_repr().to_owned(),
             })?;
 
+        let surcharge_details = get_surcharge_details_for_incremental_authorization(
+            state,
+            merchant_context,
+            &business_profile,
+            &payment_intent,
+            &payment_attempt,
+            request.amount,
+        )
+        .await?;
+        let total_amount = surcharge_details
+            .as_ref()
+            .map(|surcharge_details| {
+                request.amount
+                    - payment_attempt
+                        .net_amount
+                        .get_total_surcharge_amount()
+                        .unwrap_or_default()
+                    + surcharge_details.get_total_surcharge_amount()
+            })
+            .unwrap_or(request.amount);
+
         let payment_data = payments::PaymentData {
             flow: PhantomData,
             payment_intent,
@@ -161,12 +203,12 @@ impl<F: Send + Clone + Sync>
             ephemeral_key: None,
             multiple_capture_data: None,
             redirect_response: None,
-            surcharge_details: None,
+            surcharge_details,
             frm_message: None,
             payment_link_data: None,
             incremental_authorization_details: Some(IncrementalAuthorizationDetails {
-                additional_amount: request.amount - amount,
-                total_amount: request.amount,
+                additional_amount: total_amount - amount,
+                total_amount,
                 reason: request.reason.clone(),
                 authorization_id: None,
             }),
@@ -260,6 +302,20 @@ impl<F: Clone + Sync>
                 ),
             })
             .attach_printable("failed while inserting new authorization")?;
+        // The payment can't be captured until the connector responds to the authorization
+        payment_data.payment_attempt = state
+            .store
+            .update_payment_attempt_with_attempt_id(
+                payment_data.payment_attempt.clone(),
+                storage::PaymentAttemptUpdate::StatusUpdate {
+                    status: enums::AttemptStatus::IncrementalAuthorizationPending,
+                    updated_by: storage_scheme.to_string(),
+                },
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+            .attach_printable("Failed to update status in Payment Attempt")?;
         // Update authorization_count in payment_intent
         payment_data.payment_intent = state
             .store
@@ -268,6 +324,7 @@ impl<F: Clone + Sync>
                 payment_data.payment_intent.clone(),
                 storage::PaymentIntentUpdate::AuthorizationCountUpdate {
                     authorization_count: new_authorization_count,
+                    status: enums::IntentStatus::IncrementalAuthorizationPending,
                 },
                 key_store,
                 storage_scheme,
@@ -381,3 +438,52 @@ impl<F: Clone + Send + Sync>
         Ok(false)
     }
 }
+
+/// Recalculates the surcharge for the amount being authorized, when the surcharge of the payment was
+/// calculated through the surcharge rules. `amount` is the amount to be authorized including the
+/// current surcharge. The current surcharge is retained when the rules don't apply to the new
+/// amount, or when the surcharge was sent by the merchant.
+async fn get_surcharge_details_for_incremental_authorization(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    business_profile: &domain::Profile,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    amount: MinorUnit,
+) -> RouterResult<Option<payment_types::SurchargeDetails>> {
+    let Some(total_surcharge_amount) = payment_attempt.net_amount.get_total_surcharge_amount()
+    else {
+        return Ok(None);
+    };
+    if payment_intent.surcharge_applicable != Some(true) {
+        return Ok(None);
+    }
+
+    let surcharge_config = surcharge_decision_configs::get_surcharge_config_ref(
+        merchant_context.get_merchant_account(),
+        Some(business_profile),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Could not resolve the surcharge decision config")?;
+    let billing_address = helpers::get_address_by_id(
+        state,
+        payment_intent.billing_address_id.clone(),
+        merchant_context.get_merchant_key_store(),
+        &payment_intent.payment_id,
+        merchant_context.get_merchant_account().get_id(),
+        merchant_context.get_merchant_account().storage_scheme,
+    )
+    .await?;
+
+    surcharge_decision_configs::perform_surcharge_decision_management_for_amount_update(
+        state,
+        surcharge_config,
+        payment_attempt,
+        payment_intent,
+        billing_address.as_ref().map(Into::into),
+        amount - total_surcharge_amount,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("error performing surcharge decision operation")
+}
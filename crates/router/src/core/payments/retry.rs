@@ -61,6 +61,7 @@ where
     dyn api::Connector: services::api::ConnectorIntegration<F, FData, types::PaymentsResponseData>,
 {
     let mut retries = None;
+    let mut retry_policy_tracker = RetryPolicyTracker::default();
 
     metrics::AUTO_RETRY_ELIGIBLE_REQUEST_COUNT.add(1, &[]);
 
@@ -124,17 +125,29 @@ where
                 None => get_gsm(state, &router_data).await?,
             };
 
-            match get_gsm_decision(gsm) {
+            // Retry policy configured on the profile takes precedence over the GSM decision
+            let retry_policy_rule = get_retry_policy_rule(business_profile, gsm.as_ref());
+            let decision = match retry_policy_rule.as_ref() {
+                Some(rule) => rule.get_decision(),
+                None => get_gsm_decision(gsm),
+            };
+
+            match decision {
                 storage_enums::GsmDecision::Retry => {
-                    retries = get_retries(
-                        state,
-                        retries,
-                        merchant_context.get_merchant_account().get_id(),
-                        business_profile,
-                    )
-                    .await;
+                    retries = match retry_policy_rule.as_ref() {
+                        Some(rule) => Some(retry_policy_tracker.get_remaining_attempts(rule)),
+                        None => {
+                            get_retries(
+                                state,
+                                retries,
+                                merchant_context.get_merchant_account().get_id(),
+                                business_profile,
+                            )
+                            .await
+                        }
+                    };
 
-                    if retries.is_none() || retries == Some(0) {
+                    if !retries.is_some_and(|retries| retries > 0) {
                         metrics::AUTO_RETRY_EXHAUSTED_COUNT.add(1, &[]);
                         logger::info!("retries exhausted for auto_retry payment");
                         break;
@@ -200,6 +213,15 @@ where
                         (connector_routing_data.connector_data, routing_decision)
                     };
 
+                    if let Some(delay) = retry_policy_rule
+                        .as_ref()
+                        .map(|rule| retry_policy_tracker.take_delay(rule))
+                        .filter(|delay| !delay.is_zero())
+                    {
+                        logger::info!(?delay, "waiting before retry as per profile retry policy");
+                        tokio::time::sleep(delay).await;
+                    }
+
                     router_data = do_retry(
                         &state.clone(),
                         req_state.clone(),
@@ -221,6 +243,9 @@ where
                     .await?;
 
                     retries = retries.map(|i| i - 1);
+                    if let Some(rule) = retry_policy_rule.as_ref() {
+                        retry_policy_tracker.record_attempt(rule);
+                    }
                 }
                 storage_enums::GsmDecision::DoDefault => break,
             }
@@ -230,6 +255,45 @@ where
     Ok(router_data)
 }
 
+/// Tracks the retries made for a payment as per the retry policy of the profile. Attempts are
+/// counted per decline category, and the delays waited for before the retries share a budget,
+/// since the retries are made while the request of the merchant is being served.
+#[cfg(feature = "v1")]
+#[derive(Debug, Default)]
+struct RetryPolicyTracker {
+    attempts: std::collections::BTreeMap<common_enums::ErrorCategory, u8>,
+    total_delay: std::time::Duration,
+}
+
+#[cfg(feature = "v1")]
+impl RetryPolicyTracker {
+    fn get_remaining_attempts(&self, rule: &common_types::domain::RetryPolicyRule) -> i32 {
+        let attempts = self
+            .attempts
+            .get(&rule.error_category)
+            .copied()
+            .unwrap_or_default();
+        i32::from(rule.max_attempts) - i32::from(attempts)
+    }
+
+    fn record_attempt(&mut self, rule: &common_types::domain::RetryPolicyRule) {
+        let attempts = self.attempts.entry(rule.error_category).or_default();
+        *attempts = attempts.saturating_add(1);
+    }
+
+    /// Delay to wait for before the next retry, limited by the delay budget left for the payment
+    fn take_delay(&mut self, rule: &common_types::domain::RetryPolicyRule) -> std::time::Duration {
+        let budget = std::time::Duration::from_millis(u64::from(
+            common_types::consts::MAX_RETRY_POLICY_TOTAL_DELAY_IN_MILLIS,
+        ));
+        let delay = rule
+            .get_delay()
+            .min(budget.saturating_sub(self.total_delay));
+        self.total_delay += delay;
+        delay
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn is_step_up_enabled_for_merchant_connector(
     state: &app::SessionState,
@@ -324,6 +388,20 @@ pub fn get_gsm_decision(
     option_gsm_decision.unwrap_or_default()
 }
 
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub fn get_retry_policy_rule(
+    business_profile: &domain::Profile,
+    option_gsm: Option<&hyperswitch_domain_models::gsm::GatewayStatusMap>,
+) -> Option<common_types::domain::RetryPolicyRule> {
+    let error_category = option_gsm.and_then(|gsm| gsm.error_category)?;
+    business_profile
+        .retry_policy
+        .as_ref()
+        .and_then(|retry_policy| retry_policy.get_rule(error_category))
+        .cloned()
+}
+
 #[inline]
 fn get_flow_name<F>() -> RouterResult<String> {
     Ok(std::any::type_name::<F>()
@@ -748,6 +826,8 @@ pub fn make_new_payment_attempt(
         connector_request_reference_id: Default::default(),
         network_transaction_id: old_payment_attempt.network_transaction_id,
         network_details: Default::default(),
+        dcc_details: old_payment_attempt.dcc_details,
+        device_fingerprint: old_payment_attempt.device_fingerprint,
     }
 }
 
@@ -789,7 +869,8 @@ pub async fn config_should_call_gsm(
 ) -> bool {
     let merchant_config_gsm = get_merchant_config_for_gsm(db, merchant_id).await;
     let profile_config_gsm = profile.is_auto_retries_enabled;
-    merchant_config_gsm || profile_config_gsm
+    let profile_retry_policy = profile.retry_policy.is_some();
+    merchant_config_gsm || profile_config_gsm || profile_retry_policy
 }
 
 pub trait GsmValidation<F: Send + Clone + Sync, FData: Send + Sync, Resp> {
@@ -831,7 +912,8 @@ impl<F: Send + Clone + Sync, FData: Send + Sync>
                 | storage_enums::AttemptStatus::DeviceDataCollectionPending
                 | storage_enums::AttemptStatus::IntegrityFailure
                 | storage_enums::AttemptStatus::Expired
-                | storage_enums::AttemptStatus::PartiallyAuthorized => false,
+                | storage_enums::AttemptStatus::PartiallyAuthorized
+                | storage_enums::AttemptStatus::IncrementalAuthorizationPending => false,
 
                 storage_enums::AttemptStatus::AuthenticationFailed
                 | storage_enums::AttemptStatus::AuthorizationFailed
@@ -840,3 +922,61 @@ impl<F: Send + Clone + Sync, FData: Send + Sync>
         }
     }
 }
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn get_rule(
+        error_category: common_enums::ErrorCategory,
+        max_attempts: u8,
+        delay_in_millis: u32,
+    ) -> common_types::domain::RetryPolicyRule {
+        common_types::domain::RetryPolicyRule {
+            error_category,
+            retry: true,
+            max_attempts,
+            delay_in_millis,
+        }
+    }
+
+    #[test]
+    fn test_retry_policy_attempts_are_counted_per_category() {
+        let mut tracker = RetryPolicyTracker::default();
+        let soft_decline_rule = get_rule(common_enums::ErrorCategory::SoftDecline, 2, 0);
+        let processor_rule = get_rule(common_enums::ErrorCategory::ProcessorDowntime, 1, 0);
+
+        assert_eq!(tracker.get_remaining_attempts(&soft_decline_rule), 2);
+        tracker.record_attempt(&soft_decline_rule);
+        tracker.record_attempt(&soft_decline_rule);
+        assert_eq!(tracker.get_remaining_attempts(&soft_decline_rule), 0);
+
+        // Attempts of another category do not use up the attempts of the processor rule
+        assert_eq!(tracker.get_remaining_attempts(&processor_rule), 1);
+        tracker.record_attempt(&processor_rule);
+        assert_eq!(tracker.get_remaining_attempts(&processor_rule), 0);
+
+        // Attempts beyond the limit never turn into fresh attempts
+        tracker.record_attempt(&processor_rule);
+        assert!(tracker.get_remaining_attempts(&processor_rule) < 0);
+    }
+
+    #[test]
+    fn test_retry_policy_delays_are_limited_by_budget() {
+        let mut tracker = RetryPolicyTracker::default();
+        let rule = get_rule(
+            common_enums::ErrorCategory::SoftDecline,
+            5,
+            common_types::consts::MAX_RETRY_POLICY_DELAY_IN_MILLIS,
+        );
+        let budget = std::time::Duration::from_millis(u64::from(
+            common_types::consts::MAX_RETRY_POLICY_TOTAL_DELAY_IN_MILLIS,
+        ));
+
+        let total_delay = (0..5).map(|_| tracker.take_delay(&rule)).sum::<Duration>();
+        assert_eq!(total_delay, budget);
+        assert!(tracker.take_delay(&rule).is_zero());
+    }
+}
@@ -1,10 +1,11 @@
 mod transformers;
 pub mod utils;
-#[cfg(all(feature = "v1", feature = "dynamic_routing"))]
-use std::collections::hash_map;
-#[cfg(all(feature = "v1", feature = "dynamic_routing"))]
-use std::hash::{Hash, Hasher};
-use std::{collections::HashMap, str::FromStr, sync::Arc};
+use std::{
+    collections::{hash_map, HashMap},
+    hash::{Hash, Hasher},
+    str::FromStr,
+    sync::Arc,
+};
 
 #[cfg(feature = "v1")]
 use api_models::open_router::{self as or_types, DecidedGateway, OpenRouterDecideGatewayRequest};
@@ -42,12 +43,12 @@ use kgraph_utils::{
 };
 use masking::{PeekInterface, Secret};
 use rand::distributions::{self, Distribution};
-#[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use rand::SeedableRng;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use router_env::{instrument, tracing};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use rustc_hash::FxHashMap;
-use storage_impl::redis::cache::{CacheKey, CGRAPH_CACHE, ROUTING_CACHE};
+use storage_impl::redis::cache::{CacheKey, CGRAPH_CACHE, GEO_IP_CACHE, ROUTING_CACHE};
 
 #[cfg(feature = "v2")]
 use crate::core::admin;
@@ -72,7 +73,7 @@ use crate::{
         domain, storage as oss_storage,
         transformers::{ForeignFrom, ForeignInto, ForeignTryFrom},
     },
-    utils::{OptionExt, ValueExt},
+    utils::{currency as currency_utils, OptionExt, ValueExt},
     SessionState,
 };
 
@@ -80,7 +81,9 @@ pub enum CachedAlgorithm {
     Single(Box<routing_types::RoutableConnectorChoice>),
     Priority(Vec<routing_types::RoutableConnectorChoice>),
     VolumeSplit(Vec<routing_types::ConnectorVolumeSplit>),
+    StickyVolumeSplit(Vec<routing_types::ConnectorVolumeSplit>),
     Advanced(backend::VirInterpreterBackend<ConnectorSelection>),
+    Cost(Vec<routing_types::RoutableConnectorChoice>),
 }
 
 #[cfg(feature = "v1")]
@@ -111,6 +114,8 @@ pub struct SessionRoutingPmTypeInput<'a> {
     backend_input: dsl_inputs::BackendInput,
     allowed_connectors: FxHashMap<String, api::GetToken>,
     profile_id: &'a common_utils::id_type::ProfileId,
+    /// Key on which sticky volume splits are performed, the customer id of the payment
+    sticky_routing_key: Option<&'a str>,
 }
 
 #[cfg(feature = "v2")]
@@ -119,6 +124,8 @@ pub struct SessionRoutingPmTypeInput<'a> {
     backend_input: dsl_inputs::BackendInput,
     allowed_connectors: FxHashMap<String, api::GetToken>,
     profile_id: &'a common_utils::id_type::ProfileId,
+    /// Key on which sticky volume splits are performed, the customer id of the payment
+    sticky_routing_key: Option<&'a str>,
 }
 
 type RoutingResult<O> = oss_errors::CustomResult<O, errors::RoutingError>;
@@ -178,6 +185,8 @@ pub fn make_dsl_input_for_payouts(
             .and_then(|ba| ba.address.as_ref())
             .and_then(|addr| addr.country)
             .map(api_enums::Country::from_alpha2),
+        ip_country: None,
+        normalized_amount: None,
         business_label: payout_data.payout_attempt.business_label.clone(),
         setup_future_usage: None,
     };
@@ -305,6 +314,8 @@ pub fn make_dsl_input(
             .and_then(|billing_address| billing_address.address.as_ref())
             .and_then(|address_details| address_details.country)
             .map(api_enums::Country::from_alpha2),
+        ip_country: None,
+        normalized_amount: None,
         business_label: None,
         setup_future_usage: Some(payments_dsl_input.payment_intent.setup_future_usage),
     };
@@ -422,6 +433,8 @@ pub fn make_dsl_input(
             .and_then(|bic| bic.address.as_ref())
             .and_then(|add| add.country)
             .map(api_enums::Country::from_alpha2),
+        ip_country: None,
+        normalized_amount: None,
         business_label: payments_dsl_input.payment_intent.business_label.clone(),
         setup_future_usage: payments_dsl_input.payment_intent.setup_future_usage,
     };
@@ -444,6 +457,226 @@ pub fn make_dsl_input(
     })
 }
 
+/// Resolves the country of the customer's IP address through the configured GeoIP provider.
+/// Resolved countries are cached in memory, so that the provider is called once per IP address.
+/// Lookup failures are logged and treated as an unknown country so that routing can proceed.
+#[cfg(feature = "v1")]
+pub async fn resolve_customer_ip_country(
+    state: &SessionState,
+    payment_attempt: &oss_storage::PaymentAttempt,
+) -> Option<api_enums::Country> {
+    let ip_address = payment_attempt
+        .browser_info
+        .clone()
+        .map(|browser_info| {
+            browser_info
+                .parse_value::<api_models::payments::BrowserInformation>("BrowserInformation")
+        })
+        .transpose()
+        .inspect_err(|err| logger::warn!(?err, "Unable to parse browser_info of payment_attempt"))
+        .ok()
+        .flatten()
+        .and_then(|browser_info| browser_info.ip_address)?;
+
+    // The country of an IP address doesn't depend on the tenant
+    let cache_key = CacheKey {
+        key: ip_address.to_string(),
+        prefix: String::new(),
+    };
+    if let Some(country) = GEO_IP_CACHE
+        .get_val::<Option<api_enums::Country>>(cache_key.clone())
+        .await
+    {
+        return country;
+    }
+
+    let country = state
+        .geo_ip_client
+        .get_country(&state.conf.proxy, ip_address)
+        .await
+        .inspect_err(|err| logger::warn!(?err, "Failed to resolve the country of customer IP"))
+        .ok()?
+        .map(api_enums::Country::from_alpha2);
+
+    GEO_IP_CACHE.push(cache_key, country).await;
+
+    country
+}
+
+/// Checks whether the routing program has rules on the country of the customer's IP address,
+/// which is only resolved when it does
+#[cfg(feature = "v1")]
+fn is_ip_country_referenced(cached_algorithm: &CachedAlgorithm) -> bool {
+    match cached_algorithm {
+        CachedAlgorithm::Advanced(interpreter) => {
+            interpreter.references_key(&euclid::types::EuclidKey::IpCountry)
+                || interpreter.references_key(&euclid::types::EuclidKey::BillingIpCountryMatch)
+        }
+        CachedAlgorithm::Single(_)
+        | CachedAlgorithm::Priority(_)
+        | CachedAlgorithm::VolumeSplit(_)
+        | CachedAlgorithm::StickyVolumeSplit(_)
+        | CachedAlgorithm::Cost(_) => false,
+    }
+}
+
+/// Converts the amount of a transaction to the reference currency configured for routing, so
+/// that amount based routing rules need not be duplicated for every accepted currency.
+/// Conversion failures are logged and treated as an unknown amount so that routing can proceed.
+pub async fn get_normalized_routing_amount(
+    state: &SessionState,
+    amount: common_utils::types::MinorUnit,
+    currency: api_enums::Currency,
+) -> Option<common_utils::types::MinorUnit> {
+    let normalization_config = &state.conf.routing_amount_normalization;
+    if !normalization_config.enabled {
+        return None;
+    }
+
+    let reference_currency = normalization_config.reference_currency;
+    if currency == reference_currency {
+        return Some(amount);
+    }
+
+    let forex_api = state.conf.forex_api.get_inner();
+    let rates = currency_utils::get_forex_rates(state, forex_api.data_expiration_delay_in_seconds)
+        .await
+        .inspect_err(|err| logger::warn!(?err, "Failed to fetch forex rates for routing"))
+        .ok()?;
+
+    // The converted amount is expressed in the base unit of the reference currency
+    let converted_amount = currency_conversion::conversion::convert(
+        &rates.data,
+        currency,
+        reference_currency,
+        amount.get_amount_as_i64(),
+    )
+    .inspect_err(|err| {
+        logger::warn!(
+            ?err,
+            ?currency,
+            ?reference_currency,
+            "Failed to convert the amount to the routing reference currency"
+        )
+    })
+    .ok()?;
+
+    let minor_unit_factor = Decimal::from(10_i64.pow(u32::from(
+        reference_currency.number_of_digits_after_decimal_point(),
+    )));
+    (converted_amount * minor_unit_factor)
+        .round()
+        .to_i64()
+        .map(common_utils::types::MinorUnit::new)
+}
+
+#[cfg(feature = "v1")]
+pub fn make_dsl_input_for_simulation(
+    payment_data: &api_models::routing::RoutingSimulatePaymentData,
+) -> dsl_inputs::BackendInput {
+    let payment_input = dsl_inputs::PaymentInput {
+        amount: payment_data.amount,
+        card_bin: payment_data.card_bin.clone(),
+        currency: payment_data.currency,
+        authentication_type: payment_data.authentication_type,
+        capture_method: payment_data.capture_method.and_then(|cm| cm.foreign_into()),
+        business_country: payment_data
+            .business_country
+            .map(api_enums::Country::from_alpha2),
+        billing_country: payment_data
+            .billing_country
+            .map(api_enums::Country::from_alpha2),
+        ip_country: payment_data.ip_country.map(api_enums::Country::from_alpha2),
+        normalized_amount: None,
+        business_label: payment_data.business_label.clone(),
+        setup_future_usage: payment_data.setup_future_usage,
+    };
+
+    let payment_method_input = dsl_inputs::PaymentMethodInput {
+        payment_method: payment_data.payment_method,
+        payment_method_type: payment_data.payment_method_type,
+        card_network: payment_data.card_network.clone(),
+    };
+
+    let mandate_data = dsl_inputs::MandateData {
+        mandate_acceptance_type: None,
+        mandate_type: None,
+        payment_type: Some(euclid_enums::PaymentType::NonMandate),
+    };
+
+    dsl_inputs::BackendInput {
+        metadata: payment_data
+            .metadata
+            .clone()
+            .map(|metadata| metadata.into_iter().collect()),
+        payment: payment_input,
+        payment_method: payment_method_input,
+        mandate: mandate_data,
+        acquirer_data: None,
+        customer_device_data: None,
+        issuer_data: None,
+    }
+}
+
+/// Evaluates a static routing algorithm against the given backend input without touching the
+/// routing cache or the decision engine, returning the resulting connector ordering. Volume splits
+/// are not rolled, every connector of the split is returned with its configured share instead, so
+/// that the same input always produces the same output.
+pub async fn perform_static_routing_simulation(
+    state: &SessionState,
+    algorithm: routing_types::StaticRoutingAlgorithm,
+    backend_input: dsl_inputs::BackendInput,
+) -> RoutingResult<(
+    Vec<routing_types::RoutableConnectorChoice>,
+    Option<Vec<routing_types::ConnectorVolumeSplit>>,
+)> {
+    match algorithm {
+        routing_types::StaticRoutingAlgorithm::Single(conn) => Ok((vec![*conn], None)),
+        routing_types::StaticRoutingAlgorithm::Priority(plist) => Ok((plist, None)),
+        routing_types::StaticRoutingAlgorithm::VolumeSplit(splits)
+        | routing_types::StaticRoutingAlgorithm::StickyVolumeSplit(splits) => {
+            Ok(get_volume_split_candidates(splits))
+        }
+        routing_types::StaticRoutingAlgorithm::Advanced(program) => {
+            let interpreter = backend::VirInterpreterBackend::with_program(program)
+                .change_context(errors::RoutingError::DslBackendInitError)
+                .attach_printable("Error initializing DSL interpreter backend")?;
+
+            match execute_dsl_v1(backend_input, &interpreter)? {
+                routing_types::StaticRoutingAlgorithm::Priority(plist) => Ok((plist, None)),
+                routing_types::StaticRoutingAlgorithm::VolumeSplit(splits) => {
+                    Ok(get_volume_split_candidates(splits))
+                }
+                _ => Err(errors::RoutingError::DslIncorrectSelectionAlgorithm).attach_printable(
+                    "Unsupported algorithm received as a result of static routing",
+                ),
+            }
+        }
+        routing_types::StaticRoutingAlgorithm::ThreeDsDecisionRule(_) => {
+            Err(errors::RoutingError::InvalidRoutingAlgorithmStructure)
+                .attach_printable("Unsupported algorithm received for simulation")
+        }
+        routing_types::StaticRoutingAlgorithm::Cost(plist) => Ok((
+            perform_cost_based_routing(state, &backend_input, plist).await?,
+            None,
+        )),
+    }
+}
+
+/// Lists every connector of a volume split, ordered from the largest share of the volume, along
+/// with the configured splits
+fn get_volume_split_candidates(
+    mut splits: Vec<routing_types::ConnectorVolumeSplit>,
+) -> (
+    Vec<routing_types::RoutableConnectorChoice>,
+    Option<Vec<routing_types::ConnectorVolumeSplit>>,
+) {
+    splits.sort_by(|split_a, split_b| split_b.split.cmp(&split_a.split));
+    let connectors = splits.iter().map(|split| split.connector.clone()).collect();
+
+    (connectors, Some(splits))
+}
+
 pub async fn perform_static_routing_v1(
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
@@ -457,13 +690,8 @@ pub async fn perform_static_routing_v1(
     logger::debug!("euclid_routing: performing routing for connector selection");
     let get_merchant_fallback_config = || async {
         #[cfg(feature = "v1")]
-        return routing::helpers::get_merchant_default_config(
-            &*state.clone().store,
-            business_profile.get_id().get_string_repr(),
-            &api_enums::TransactionType::from(transaction_data),
-        )
-        .await
-        .change_context(errors::RoutingError::FallbackConfigFetchFailed);
+        return get_default_fallback_config(state, business_profile.get_id(), transaction_data)
+            .await;
         #[cfg(feature = "v2")]
         return admin::ProfileWrapper::new(business_profile.clone())
             .get_default_fallback_list_of_connector_under_profile()
@@ -485,11 +713,30 @@ pub async fn perform_static_routing_v1(
     )
     .await?;
 
-    let backend_input = match transaction_data {
+    let mut backend_input = match transaction_data {
         routing::TransactionData::Payment(payment_data) => make_dsl_input(payment_data)?,
         #[cfg(feature = "payouts")]
         routing::TransactionData::Payout(payout_data) => make_dsl_input_for_payouts(payout_data)?,
     };
+    backend_input.payment.normalized_amount = get_normalized_routing_amount(
+        state,
+        backend_input.payment.amount,
+        backend_input.payment.currency,
+    )
+    .await;
+
+    #[cfg(feature = "v1")]
+    match transaction_data {
+        routing::TransactionData::Payment(payment_data)
+            if is_ip_country_referenced(cached_algorithm.as_ref()) =>
+        {
+            backend_input.payment.ip_country =
+                resolve_customer_ip_country(state, payment_data.payment_attempt).await;
+        }
+        routing::TransactionData::Payment(_) => {}
+        #[cfg(feature = "payouts")]
+        routing::TransactionData::Payout(_) => {}
+    }
 
     let payment_id = match transaction_data {
         routing::TransactionData::Payment(payment_data) => payment_data
@@ -533,14 +780,26 @@ pub async fn perform_static_routing_v1(
         ),
         CachedAlgorithm::Priority(plist) => (plist.clone(), None),
         CachedAlgorithm::VolumeSplit(splits) => (
-            perform_volume_split(splits.to_vec())
+            perform_volume_split(splits.to_vec(), None)
                 .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
             Some(common_enums::RoutingApproach::VolumeBasedRouting),
         ),
+        CachedAlgorithm::StickyVolumeSplit(splits) => (
+            perform_volume_split(
+                splits.to_vec(),
+                get_sticky_routing_key(transaction_data).as_deref(),
+            )
+            .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
+            Some(common_enums::RoutingApproach::VolumeBasedRouting),
+        ),
         CachedAlgorithm::Advanced(interpreter) => (
             execute_dsl_and_get_connector_v1(backend_input, interpreter)?,
             Some(common_enums::RoutingApproach::RuleBasedRouting),
         ),
+        CachedAlgorithm::Cost(plist) => (
+            perform_cost_based_routing(state, &backend_input, plist.clone()).await?,
+            None,
+        ),
     };
 
     // Results are logged for diff(between legacy and decision_engine's euclid) and have parameters as:
@@ -625,7 +884,7 @@ pub fn perform_straight_through_routing(
         routing_types::StraightThroughAlgorithm::Priority(conns) => (conns.clone(), true),
 
         routing_types::StraightThroughAlgorithm::VolumeSplit(splits) => (
-            perform_volume_split(splits.to_vec())
+            perform_volume_split(splits.to_vec(), None)
                 .change_context(errors::RoutingError::ConnectorSelectionFailed)
                 .attach_printable(
                     "Volume Split connector selection error in straight through routing",
@@ -649,20 +908,29 @@ pub fn perform_routing_for_single_straight_through_algorithm(
     })
 }
 
-fn execute_dsl_and_get_connector_v1(
+fn execute_dsl_v1(
     backend_input: dsl_inputs::BackendInput,
     interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
-) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
-    let routing_output: routing_types::StaticRoutingAlgorithm = interpreter
+) -> RoutingResult<routing_types::StaticRoutingAlgorithm> {
+    interpreter
         .execute(backend_input)
         .map(|out| out.connector_selection.foreign_into())
-        .change_context(errors::RoutingError::DslExecutionError)?;
+        .change_context(errors::RoutingError::DslExecutionError)
+}
+
+fn execute_dsl_and_get_connector_v1(
+    backend_input: dsl_inputs::BackendInput,
+    interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
+) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+    let routing_output = execute_dsl_v1(backend_input, interpreter)?;
 
     Ok(match routing_output {
         routing_types::StaticRoutingAlgorithm::Priority(plist) => plist,
 
-        routing_types::StaticRoutingAlgorithm::VolumeSplit(splits) => perform_volume_split(splits)
-            .change_context(errors::RoutingError::DslFinalConnectorSelectionFailed)?,
+        routing_types::StaticRoutingAlgorithm::VolumeSplit(splits) => {
+            perform_volume_split(splits, None)
+                .change_context(errors::RoutingError::DslFinalConnectorSelectionFailed)?
+        }
 
         _ => Err(errors::RoutingError::DslIncorrectSelectionAlgorithm)
             .attach_printable("Unsupported algorithm received as a result of static routing")?,
@@ -694,6 +962,9 @@ pub async fn refresh_routing_cache_v1(
         routing_types::StaticRoutingAlgorithm::VolumeSplit(splits) => {
             CachedAlgorithm::VolumeSplit(splits)
         }
+        routing_types::StaticRoutingAlgorithm::StickyVolumeSplit(splits) => {
+            CachedAlgorithm::StickyVolumeSplit(splits)
+        }
         routing_types::StaticRoutingAlgorithm::Advanced(program) => {
             let interpreter = backend::VirInterpreterBackend::with_program(program)
                 .change_context(errors::RoutingError::DslBackendInitError)
@@ -705,6 +976,7 @@ pub async fn refresh_routing_cache_v1(
             Err(errors::RoutingError::InvalidRoutingAlgorithmStructure)
                 .attach_printable("Unsupported algorithm received")?
         }
+        routing_types::StaticRoutingAlgorithm::Cost(plist) => CachedAlgorithm::Cost(plist),
     };
 
     let arc_cached_algorithm = Arc::new(cached_algorithm);
@@ -722,6 +994,174 @@ pub async fn refresh_routing_cache_v1(
     Ok(arc_cached_algorithm)
 }
 
+/// Provides the running routing experiment of the profile, along with the arm that the payment
+/// is assigned to, if any
+#[cfg(feature = "v1")]
+pub async fn get_routing_experiment_for_payment(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    payment_id: &common_utils::id_type::PaymentId,
+) -> RoutingResult<
+    Option<(
+        routing_types::RoutingExperiment,
+        common_enums::RoutingExperimentArm,
+    )>,
+> {
+    let experiment =
+        routing::helpers::get_routing_experiment(&*state.store, business_profile.get_id())
+            .await
+            .change_context(errors::RoutingError::RoutingExperimentFetchFailed)?
+            .filter(|experiment| experiment.is_running());
+
+    Ok(experiment.map(|experiment| {
+        let arm = routing::helpers::get_routing_experiment_arm(&experiment, payment_id);
+        logger::debug!(routing_experiment_id = %experiment.experiment_id, routing_experiment_arm = %arm);
+        (experiment, arm)
+    }))
+}
+
+/// Orders the connectors by the fee charged by each of them for the payment, as per the fee
+/// schedules of their merchant connector accounts. Connectors without an applicable fee rule are
+/// retained after the priced ones, in their original order.
+pub async fn perform_cost_based_routing(
+    state: &SessionState,
+    backend_input: &dsl_inputs::BackendInput,
+    connectors: Vec<routing_types::RoutableConnectorChoice>,
+) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+    let billing_country = backend_input
+        .payment
+        .billing_country
+        .map(|country| country.to_alpha2());
+
+    let mut priced_connectors = Vec::with_capacity(connectors.len());
+    let mut unpriced_connectors = Vec::new();
+
+    for connector in connectors {
+        let fee = match connector.merchant_connector_id.as_ref() {
+            Some(merchant_connector_id) => {
+                routing::helpers::find_connector_fee_schedule(&*state.store, merchant_connector_id)
+                    .await
+                    .change_context(errors::RoutingError::ConnectorFeeScheduleFetchFailed)?
+                    .and_then(|fee_schedule| {
+                        fee_schedule.calculate_fee(
+                            backend_input.payment.amount,
+                            backend_input.payment.currency,
+                            backend_input.payment_method.card_network.as_ref(),
+                            billing_country,
+                        )
+                    })
+            }
+            None => None,
+        };
+
+        match fee {
+            Some(fee) => priced_connectors.push((fee, connector)),
+            None => unpriced_connectors.push(connector),
+        }
+    }
+
+    priced_connectors.sort_by_key(|(fee, _)| fee.get_amount_as_i64());
+    logger::debug!(cost_based_routing_connector_fees = ?priced_connectors);
+
+    Ok(priced_connectors
+        .into_iter()
+        .map(|(_, connector)| connector)
+        .chain(unpriced_connectors)
+        .collect())
+}
+
+/// Moves connectors whose recent p95 authorization latency exceeds the threshold configured for
+/// the profile to the end of the routing output, preserving the relative order otherwise
+#[cfg(feature = "v1")]
+pub async fn perform_latency_based_routing(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    connectors: Vec<routing_types::RoutableConnectorChoice>,
+) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+    let latency_routing_config = routing::helpers::get_latency_based_routing_config(
+        &*state.store,
+        business_profile.get_id(),
+    )
+    .await
+    .change_context(errors::RoutingError::LatencyBasedRoutingConfigError)?;
+    if !latency_routing_config.enabled {
+        return Ok(connectors);
+    }
+
+    let latency_stats = routing::helpers::get_connectors_latency_stats(
+        state,
+        business_profile.get_id(),
+        &latency_routing_config,
+        &connectors,
+    )
+    .await
+    .change_context(errors::RoutingError::ConnectorLatencyFetchFailed)?;
+
+    let mut preferred_connectors = Vec::with_capacity(connectors.len());
+    let mut deprioritized_connectors = Vec::new();
+
+    for latency_stats in latency_stats {
+        if latency_stats.is_deprioritized {
+            logger::debug!(
+                latency_based_routing_deprioritized_connector = %latency_stats.connector,
+                p95_latency_ms = ?latency_stats.p95_latency_ms,
+            );
+            deprioritized_connectors.push(latency_stats.connector);
+        } else {
+            preferred_connectors.push(latency_stats.connector);
+        }
+    }
+
+    preferred_connectors.extend(deprioritized_connectors);
+    Ok(preferred_connectors)
+}
+
+/// Removes connectors whose health breaker is open from the routing output, so that the payment
+/// falls back to the next eligible connector. The output is left untouched if every connector is
+/// unhealthy, as failing the payment outright would be no better than trying them.
+#[cfg(feature = "v1")]
+pub async fn perform_connector_health_filtering(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    connectors: Vec<routing_types::RoutableConnectorChoice>,
+) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+    if !state.conf.connector_health.enabled {
+        return Ok(connectors);
+    }
+
+    let merchant_connector_ids = connectors
+        .iter()
+        .filter_map(|connector| connector.merchant_connector_id.as_ref())
+        .collect::<Vec<_>>();
+    let mut skipped_connectors = routing::helpers::get_connectors_skipped_by_health_breaker(
+        state,
+        business_profile.get_id(),
+        &merchant_connector_ids,
+    )
+    .await
+    .change_context(errors::RoutingError::ConnectorHealthFetchFailed)?
+    .into_iter();
+
+    let mut healthy_connectors = Vec::with_capacity(connectors.len());
+    for connector in connectors.iter() {
+        let is_open = connector.merchant_connector_id.is_some()
+            && skipped_connectors.next().unwrap_or_default();
+
+        if is_open {
+            logger::debug!(connector_health_skipped_connector = %connector);
+        } else {
+            healthy_connectors.push(connector.clone());
+        }
+    }
+
+    if healthy_connectors.is_empty() {
+        logger::warn!("connector_health: breakers of all eligible connectors are open");
+        return Ok(connectors);
+    }
+
+    Ok(healthy_connectors)
+}
+
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 pub fn perform_dynamic_routing_volume_split(
     splits: Vec<api_models::routing::RoutingVolumeSplit>,
@@ -752,16 +1192,47 @@ pub fn perform_dynamic_routing_volume_split(
     Ok(routing_choice)
 }
 
+/// Sticky volume splits are keyed on the customer of the transaction. Transactions without a
+/// customer are split like regular volume splits.
+fn get_sticky_routing_key(transaction_data: &routing::TransactionData<'_>) -> Option<String> {
+    match transaction_data {
+        routing::TransactionData::Payment(payment_data) => payment_data
+            .payment_intent
+            .customer_id
+            .as_ref()
+            .map(|customer_id| customer_id.get_string_repr().to_owned()),
+        #[cfg(feature = "payouts")]
+        routing::TransactionData::Payout(payout_data) => payout_data
+            .payouts
+            .customer_id
+            .as_ref()
+            .map(|customer_id| customer_id.get_string_repr().to_owned()),
+    }
+}
+
+/// Picks a connector as per the volume split weights and moves it to the front of the output.
+/// When a seed is provided, the pick is derived from it, so that the same seed always lands on the
+/// same connector for a given set of splits.
 pub fn perform_volume_split(
     mut splits: Vec<routing_types::ConnectorVolumeSplit>,
+    rng_seed: Option<&str>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
     let weights: Vec<u8> = splits.iter().map(|sp| sp.split).collect();
     let weighted_index = distributions::WeightedIndex::new(weights)
         .change_context(errors::RoutingError::VolumeSplitFailed)
         .attach_printable("Error creating weighted distribution for volume split")?;
 
-    let mut rng = rand::thread_rng();
-    let idx = weighted_index.sample(&mut rng);
+    let idx = if let Some(seed) = rng_seed {
+        let mut hasher = hash_map::DefaultHasher::new();
+        seed.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(hash);
+        weighted_index.sample(&mut rng)
+    } else {
+        let mut rng = rand::thread_rng();
+        weighted_index.sample(&mut rng)
+    };
 
     splits
         .get(idx)
@@ -996,6 +1467,44 @@ pub async fn perform_eligibility_analysis(
     .await
 }
 
+/// Provides the default fallback connectors of the profile for the transaction. Payments use the
+/// fallback ordering configured for their payment method type, if the profile has one.
+#[cfg(feature = "v1")]
+async fn get_default_fallback_config(
+    state: &SessionState,
+    profile_id: &common_utils::id_type::ProfileId,
+    transaction_data: &routing::TransactionData<'_>,
+) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+    if let routing::TransactionData::Payment(payment_data) = transaction_data {
+        if let Some(payment_method_type) = payment_data.payment_attempt.payment_method_type {
+            let payment_method_fallback_config =
+                routing::helpers::get_payment_method_default_config(
+                    &*state.store,
+                    profile_id,
+                    payment_method_type,
+                )
+                .await
+                .change_context(errors::RoutingError::FallbackConfigFetchFailed)?;
+
+            if let Some(fallback_config) = payment_method_fallback_config {
+                logger::debug!(
+                    %payment_method_type,
+                    "euclid_routing: using payment method type fallback"
+                );
+                return Ok(fallback_config);
+            }
+        }
+    }
+
+    routing::helpers::get_merchant_default_config(
+        &*state.store,
+        profile_id.get_string_repr(),
+        &api_enums::TransactionType::from(transaction_data),
+    )
+    .await
+    .change_context(errors::RoutingError::FallbackConfigFetchFailed)
+}
+
 pub async fn perform_fallback_routing(
     state: &SessionState,
     key_store: &domain::MerchantKeyStore,
@@ -1004,25 +1513,21 @@ pub async fn perform_fallback_routing(
     business_profile: &domain::Profile,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
     #[cfg(feature = "v1")]
-    let fallback_config = routing::helpers::get_merchant_default_config(
-        &*state.store,
+    let fallback_config = get_default_fallback_config(
+        state,
         match transaction_data {
             routing::TransactionData::Payment(payment_data) => payment_data
                 .payment_intent
                 .profile_id
                 .as_ref()
                 .get_required_value("profile_id")
-                .change_context(errors::RoutingError::ProfileIdMissing)?
-                .get_string_repr(),
+                .change_context(errors::RoutingError::ProfileIdMissing)?,
             #[cfg(feature = "payouts")]
-            routing::TransactionData::Payout(payout_data) => {
-                payout_data.payout_attempt.profile_id.get_string_repr()
-            }
+            routing::TransactionData::Payout(payout_data) => &payout_data.payout_attempt.profile_id,
         },
-        &api_enums::TransactionType::from(transaction_data),
+        transaction_data,
     )
-    .await
-    .change_context(errors::RoutingError::FallbackConfigFetchFailed)?;
+    .await?;
     #[cfg(feature = "v2")]
     let fallback_config = admin::ProfileWrapper::new(business_profile.clone())
         .get_default_fallback_list_of_connector_under_profile()
@@ -1132,6 +1637,8 @@ pub async fn perform_session_flow_routing<'a>(
         billing_country: session_input
             .country
             .map(storage_enums::Country::from_alpha2),
+        ip_country: None,
+        normalized_amount: None,
         // business_label not available in payment_intent anymore
         business_label: None,
         setup_future_usage: Some(session_input.payment_intent.setup_future_usage),
@@ -1185,6 +1692,11 @@ pub async fn perform_session_flow_routing<'a>(
             backend_input: backend_input.clone(),
             allowed_connectors,
             profile_id: &profile_id,
+            sticky_routing_key: session_input
+                .payment_intent
+                .customer_id
+                .as_ref()
+                .map(|customer_id| customer_id.get_string_repr()),
         };
 
         let routable_connector_choice_option = perform_session_routing_for_pm_type(
@@ -1282,6 +1794,8 @@ pub async fn perform_session_flow_routing(
         billing_country: session_input
             .country
             .map(storage_enums::Country::from_alpha2),
+        ip_country: None,
+        normalized_amount: None,
         business_label: session_input.payment_intent.business_label.clone(),
         setup_future_usage: session_input.payment_intent.setup_future_usage,
     };
@@ -1338,6 +1852,11 @@ pub async fn perform_session_flow_routing(
             backend_input: backend_input.clone(),
             allowed_connectors,
             profile_id: &profile_id,
+            sticky_routing_key: session_input
+                .payment_intent
+                .customer_id
+                .as_ref()
+                .map(|customer_id| customer_id.get_string_repr()),
         };
 
         let (routable_connector_choice_option, routing_approach) =
@@ -1411,7 +1930,12 @@ async fn perform_session_routing_for_pm_type(
             ),
             CachedAlgorithm::Priority(plist) => (plist.clone(), None),
             CachedAlgorithm::VolumeSplit(splits) => (
-                perform_volume_split(splits.to_vec())
+                perform_volume_split(splits.to_vec(), None)
+                    .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
+                Some(common_enums::RoutingApproach::VolumeBasedRouting),
+            ),
+            CachedAlgorithm::StickyVolumeSplit(splits) => (
+                perform_volume_split(splits.to_vec(), session_pm_input.sticky_routing_key)
                     .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
                 Some(common_enums::RoutingApproach::VolumeBasedRouting),
             ),
@@ -1422,6 +1946,15 @@ async fn perform_session_routing_for_pm_type(
                 )?,
                 Some(common_enums::RoutingApproach::RuleBasedRouting),
             ),
+            CachedAlgorithm::Cost(plist) => (
+                perform_cost_based_routing(
+                    session_pm_input.state,
+                    &session_pm_input.backend_input,
+                    plist.clone(),
+                )
+                .await?,
+                None,
+            ),
         }
     } else {
         (
@@ -1500,12 +2033,20 @@ async fn get_chosen_connectors<'a>(
         match cached_algorithm.as_ref() {
             CachedAlgorithm::Single(conn) => vec![(**conn).clone()],
             CachedAlgorithm::Priority(plist) => plist.clone(),
-            CachedAlgorithm::VolumeSplit(splits) => perform_volume_split(splits.to_vec())
+            CachedAlgorithm::VolumeSplit(splits) => perform_volume_split(splits.to_vec(), None)
                 .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
+            CachedAlgorithm::StickyVolumeSplit(splits) => {
+                perform_volume_split(splits.to_vec(), session_pm_input.sticky_routing_key)
+                    .change_context(errors::RoutingError::ConnectorSelectionFailed)?
+            }
             CachedAlgorithm::Advanced(interpreter) => execute_dsl_and_get_connector_v1(
                 session_pm_input.backend_input.clone(),
                 interpreter,
             )?,
+            CachedAlgorithm::Cost(plist) => {
+                perform_cost_based_routing(state, &session_pm_input.backend_input, plist.clone())
+                    .await?
+            }
         }
     } else {
         profile_wrapper
@@ -1607,6 +2148,8 @@ pub fn make_dsl_input_for_surcharge(
             .and_then(|bic| bic.address)
             .and_then(|add| add.country)
             .map(api_enums::Country::from_alpha2),
+        ip_country: None,
+        normalized_amount: None,
         business_label: payment_intent.business_label.clone(),
         setup_future_usage: payment_intent.setup_future_usage,
     };
@@ -2692,3 +3235,86 @@ where
         Ok(routable_connectors)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::as_conversions)]
+    use super::*;
+
+    fn get_splits() -> Vec<routing_types::ConnectorVolumeSplit> {
+        [
+            (api_enums::RoutableConnectors::Stripe, 70),
+            (api_enums::RoutableConnectors::Adyen, 30),
+        ]
+        .into_iter()
+        .map(|(connector, split)| routing_types::ConnectorVolumeSplit {
+            connector: routing_types::RoutableConnectorChoice {
+                choice_kind: routing_types::RoutableChoiceKind::FullStruct,
+                connector,
+                merchant_connector_id: None,
+            },
+            split,
+        })
+        .collect()
+    }
+
+    fn get_first_connector(rng_seed: Option<&str>) -> api_enums::RoutableConnectors {
+        perform_volume_split(get_splits(), rng_seed)
+            .unwrap()
+            .first()
+            .unwrap()
+            .connector
+    }
+
+    #[test]
+    fn test_sticky_volume_split_is_stable_per_customer() {
+        for customer_id in ["cus_1", "cus_2", "cus_abcdef", "cus_zyxw"] {
+            let first_choice = get_first_connector(Some(customer_id));
+            for _ in 0..20 {
+                assert_eq!(get_first_connector(Some(customer_id)), first_choice);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sticky_volume_split_honours_weights() {
+        let total = 10_000;
+        let stripe_count = (0..total)
+            .filter(|i| {
+                get_first_connector(Some(&format!("cus_{i}")))
+                    == api_enums::RoutableConnectors::Stripe
+            })
+            .count();
+
+        // 70% of customers are expected on stripe, allow a margin for the hash distribution
+        let stripe_share = stripe_count as f64 / f64::from(total);
+        assert!((0.66..=0.74).contains(&stripe_share), "{stripe_share}");
+    }
+
+    #[test]
+    fn test_volume_split_keeps_every_connector_as_fallback() {
+        let connectors = perform_volume_split(get_splits(), Some("cus_1")).unwrap();
+        assert_eq!(connectors.len(), 2);
+    }
+
+    #[test]
+    fn test_volume_split_candidates_are_deterministic() {
+        let mut splits = get_splits();
+        splits.reverse();
+
+        for _ in 0..20 {
+            let (connectors, volume_splits) = get_volume_split_candidates(splits.clone());
+            assert_eq!(
+                connectors
+                    .iter()
+                    .map(|connector| connector.connector)
+                    .collect::<Vec<_>>(),
+                vec![
+                    api_enums::RoutableConnectors::Stripe,
+                    api_enums::RoutableConnectors::Adyen
+                ]
+            );
+            assert_eq!(volume_splits, Some(get_splits()));
+        }
+    }
+}
@@ -778,6 +778,7 @@ pub fn convert_backend_input_to_routing_eval(
     fallback_output: Vec<DeRoutableConnectorChoice>,
 ) -> RoutingResult<RoutingEvaluateRequest> {
     let mut params: HashMap<String, Option<ValueType>> = HashMap::new();
+    let billing_ip_country_match = input.payment.get_billing_ip_country_match();
 
     // Payment
     params.insert(
@@ -795,6 +796,17 @@ pub fn convert_backend_input_to_routing_eval(
         "currency".to_string(),
         Some(ValueType::EnumVariant(input.payment.currency.to_string())),
     );
+    if let Some(normalized_amount) = input.payment.normalized_amount {
+        params.insert(
+            "normalized_amount".to_string(),
+            Some(ValueType::Number(
+                normalized_amount
+                    .get_amount_as_i64()
+                    .try_into()
+                    .unwrap_or_default(),
+            )),
+        );
+    }
 
     if let Some(auth_type) = input.payment.authentication_type {
         params.insert(
@@ -823,6 +835,18 @@ pub fn convert_backend_input_to_routing_eval(
             Some(ValueType::EnumVariant(country.to_string())),
         );
     }
+    if let Some(country) = input.payment.ip_country {
+        params.insert(
+            "ip_country".to_string(),
+            Some(ValueType::EnumVariant(country.to_string())),
+        );
+    }
+    if let Some(country_match) = billing_ip_country_match {
+        params.insert(
+            "billing_ip_country_match".to_string(),
+            Some(ValueType::EnumVariant(country_match.to_string())),
+        );
+    }
     if let Some(label) = input.payment.business_label {
         params.insert(
             "business_label".to_string(),
@@ -0,0 +1,118 @@
+use api_models::analytics::search::{PaymentSearchRequest, PaymentSearchResponse};
+use base64::Engine;
+use common_utils::{id_type, types::authentication::AuthInfo};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    analytics::{opensearch::OpenSearchError, search},
+    consts,
+    core::errors::{self, RouterResponse},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::domain,
+};
+
+/// Searches the payments of the merchant, or of the profile if the caller is restricted to one,
+/// in the search index. Pages are fetched with the cursor of the previous page, which stays
+/// stable while new payments are indexed.
+#[instrument(skip_all)]
+pub async fn search_payments(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    request: PaymentSearchRequest,
+) -> RouterResponse<PaymentSearchResponse> {
+    let opensearch_client =
+        state
+            .opensearch_client
+            .as_ref()
+            .ok_or(errors::ApiErrorResponse::NotSupported {
+                message: "Payments search is not enabled".to_string(),
+            })?;
+
+    let merchant_account = merchant_context.get_merchant_account();
+    let search_params = vec![match profile_id {
+        Some(profile_id) => AuthInfo::ProfileLevel {
+            org_id: merchant_account.get_org_id().clone(),
+            merchant_id: merchant_account.get_id().clone(),
+            profile_ids: vec![profile_id],
+        },
+        None => AuthInfo::MerchantLevel {
+            org_id: merchant_account.get_org_id().clone(),
+            merchant_ids: vec![merchant_account.get_id().clone()],
+        },
+    }];
+
+    let limit = request
+        .limit
+        .unwrap_or(consts::PAYMENTS_SEARCH_DEFAULT_LIMIT)
+        .min(common_utils::consts::PAYMENTS_LIST_MAX_LIMIT_V1);
+    let search_after = request.cursor.as_deref().map(decode_cursor).transpose()?;
+
+    let results = search::payment_search_results(
+        opensearch_client,
+        request,
+        i64::from(limit),
+        search_after,
+        search_params,
+    )
+    .await
+    .map_err(|error| match error.current_context() {
+        OpenSearchError::BadRequestError(message) => {
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: message.clone(),
+            })
+        }
+        _ => error.change_context(errors::ApiErrorResponse::InternalServerError),
+    })
+    .attach_printable("Failed to search the payments")?;
+
+    let next_cursor = results
+        .next_search_after
+        .map(|search_after| encode_cursor(&search_after))
+        .transpose()?;
+
+    Ok(ApplicationResponse::Json(PaymentSearchResponse {
+        count: results.count,
+        data: results.hits,
+        next_cursor,
+    }))
+}
+
+/// The cursor is the sort values of the last payment of a page, which the next page starts after
+fn encode_cursor(search_after: &[serde_json::Value]) -> errors::RouterResult<String> {
+    serde_json::to_vec(search_after)
+        .map(|cursor| common_utils::consts::BASE64_ENGINE_URL_SAFE.encode(cursor))
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the payments search cursor")
+}
+
+fn decode_cursor(cursor: &str) -> errors::RouterResult<Vec<serde_json::Value>> {
+    common_utils::consts::BASE64_ENGINE_URL_SAFE
+        .decode(cursor)
+        .ok()
+        .and_then(|cursor| serde_json::from_slice(&cursor).ok())
+        .ok_or(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Invalid cursor".to_string(),
+        }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_round_trip() {
+        let search_after = vec![
+            serde_json::json!(1_700_000_000_000_i64),
+            serde_json::json!("pay_abc"),
+        ];
+        let cursor = encode_cursor(&search_after).expect("Failed to encode the cursor");
+        assert_eq!(
+            decode_cursor(&cursor).expect("Failed to decode the cursor"),
+            search_after
+        );
+        assert!(decode_cursor("not a cursor").is_err());
+    }
+}
@@ -0,0 +1,284 @@
+use api_models::mandates::RecurringDetails;
+use common_types::domain::SoftDeclineRetryConfig;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use scheduler::utils as pt_utils;
+
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        payments::helpers,
+    },
+    routes::{metrics, SessionState},
+    types::{
+        domain,
+        storage::{self, enums, payment_attempt::SoftDeclineRetryTrackingData},
+    },
+};
+
+const SOFT_DECLINE_RETRY_TASK: &str = "SOFT_DECLINE_RETRY";
+
+/// Network advice codes asking not to reattempt the transaction: Mastercard merchant advice codes
+/// `03` (do not try again) and `21` (payment cancellation), and Visa stop payment orders and
+/// revocations of authorization `R0`, `R1` and `R3`
+const DO_NOT_RETRY_NETWORK_ADVICE_CODES: &[&str] = &["03", "21", "R0", "R1", "R3"];
+
+/// Number of authorization attempts of a transaction, including the original one, allowed by a
+/// card network within a rolling window
+#[derive(Debug, PartialEq)]
+struct NetworkRetryLimit {
+    max_attempts: usize,
+    window: time::Duration,
+}
+
+fn get_network_retry_limit(card_network: Option<&enums::CardNetwork>) -> NetworkRetryLimit {
+    match card_network {
+        // Mastercard treats more than 10 authorization attempts of a card within 24 hours as
+        // excessive
+        Some(enums::CardNetwork::Mastercard) => NetworkRetryLimit {
+            max_attempts: 10,
+            window: time::Duration::days(1),
+        },
+        // Visa allows 15 reattempts of a declined transaction within 30 days, which is applied to
+        // the other card networks as well
+        _ => NetworkRetryLimit {
+            max_attempts: 16,
+            window: time::Duration::days(30),
+        },
+    }
+}
+
+/// Computes the time of the next retry of a payment declined at the given times, or `None` if the
+/// retry intervals are exhausted or another retry would exceed the limits of the card network
+fn get_next_retry_time(
+    config: &SoftDeclineRetryConfig,
+    card_network: Option<&enums::CardNetwork>,
+    declined_at: &[time::PrimitiveDateTime],
+) -> Option<time::PrimitiveDateTime> {
+    let retry_count = declined_at.len().checked_sub(1)?;
+    let retry_interval = config.get_retry_interval_in_seconds(retry_count)?;
+    let next_retry_at = declined_at
+        .last()?
+        .saturating_add(time::Duration::seconds(i64::from(retry_interval)));
+
+    let limit = get_network_retry_limit(card_network);
+    let window_start = next_retry_at.saturating_sub(limit.window);
+    let attempts_within_window = declined_at
+        .iter()
+        .filter(|declined_at| **declined_at > window_start)
+        .count();
+
+    (attempts_within_window < limit.max_attempts).then_some(next_retry_at)
+}
+
+/// Recurring details with which a payment can be retried without the customer being present,
+/// which are only available for merchant initiated payments made with a saved payment method
+pub fn get_recurring_details(
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) -> Option<RecurringDetails> {
+    if payment_intent.off_session != Some(true) {
+        return None;
+    }
+    match (
+        &payment_attempt.mandate_id,
+        &payment_attempt.payment_method_id,
+    ) {
+        (Some(mandate_id), _) => Some(RecurringDetails::MandateId(mandate_id.clone())),
+        (None, Some(payment_method_id)) => {
+            Some(RecurringDetails::PaymentMethodId(payment_method_id.clone()))
+        }
+        (None, None) => None,
+    }
+}
+
+/// Classifies the decline of a payment attempt with the gateway status mapping of its error
+/// code, and indicates if it has to be retried as per the config. Declines which are not mapped
+/// are not retried.
+pub async fn is_retriable_decline(
+    state: &SessionState,
+    config: &SoftDeclineRetryConfig,
+    payment_attempt: &storage::PaymentAttempt,
+) -> bool {
+    let network_advice_code = payment_attempt
+        .network_details
+        .as_ref()
+        .and_then(|network_details| network_details.network_advice_code.as_deref());
+    if network_advice_code.is_some_and(|code| DO_NOT_RETRY_NETWORK_ADVICE_CODES.contains(&code)) {
+        return false;
+    }
+    let Some(connector) = payment_attempt.connector.clone() else {
+        return false;
+    };
+
+    helpers::get_gsm_record(
+        state,
+        payment_attempt.error_code.clone(),
+        payment_attempt.error_message.clone(),
+        connector,
+        "Authorize".to_string(),
+    )
+    .await
+    .and_then(|gsm| gsm.error_category)
+    .is_some_and(|error_category| config.is_retriable(error_category))
+}
+
+/// Computes the time of the next retry of a payment whose active attempt was declined, if it can
+/// be retried as per the soft decline retry config of its profile and the card network limits
+pub async fn get_next_retry_time_for_decline(
+    state: &SessionState,
+    config: &SoftDeclineRetryConfig,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    declined_at: &[time::PrimitiveDateTime],
+) -> Option<time::PrimitiveDateTime> {
+    if payment_intent.status != enums::IntentStatus::Failed
+        || get_recurring_details(payment_intent, payment_attempt).is_none()
+        || !is_retriable_decline(state, config, payment_attempt).await
+    {
+        return None;
+    }
+    get_next_retry_time(
+        config,
+        payment_attempt.extract_card_network().as_ref(),
+        declined_at,
+    )
+}
+
+/// Schedules a retry of a merchant initiated payment which failed with a retriable decline, as
+/// per the soft decline retry config of its profile. Further retries are scheduled by the retry
+/// task itself.
+#[instrument(skip_all)]
+pub async fn schedule_retry_if_required(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<()> {
+    let Some(config) = business_profile.soft_decline_retry_config.as_ref() else {
+        return Ok(());
+    };
+
+    let declined_at = vec![common_utils::date_time::now()];
+    let Some(schedule_time) = get_next_retry_time_for_decline(
+        state,
+        config,
+        payment_intent,
+        payment_attempt,
+        &declined_at,
+    )
+    .await
+    else {
+        return Ok(());
+    };
+
+    let tracking_data = SoftDeclineRetryTrackingData {
+        merchant_id: payment_intent.merchant_id.clone(),
+        profile_id: business_profile.get_id().clone(),
+        payment_id: payment_intent.payment_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        declined_at,
+    };
+    let runner = storage::ProcessTrackerRunner::SoftDeclineRetryWorkflow;
+    let tag = ["PAYMENT", "SOFT_DECLINE_RETRY"];
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        SOFT_DECLINE_RETRY_TASK,
+        payment_intent.payment_id.get_string_repr(),
+        &payment_intent.merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        SOFT_DECLINE_RETRY_TASK,
+        runner,
+        tag,
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct soft decline retry process tracker task")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add soft decline retry task to process tracker")?;
+    metrics::TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "SoftDeclineRetry")),
+    );
+    logger::info!(
+        payment_id = ?payment_intent.payment_id,
+        %schedule_time,
+        "Scheduled retry of the soft declined payment"
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_config(retry_intervals_in_seconds: Vec<u32>) -> SoftDeclineRetryConfig {
+        SoftDeclineRetryConfig {
+            error_categories: vec![enums::ErrorCategory::SoftDecline],
+            retry_intervals_in_seconds,
+        }
+    }
+
+    #[test]
+    fn test_get_next_retry_time() {
+        let declined_at = time::macros::datetime!(2025-01-01 00:00);
+        let config = get_config(vec![3600, 86400]);
+
+        assert_eq!(
+            get_next_retry_time(&config, None, &[declined_at]),
+            Some(time::macros::datetime!(2025-01-01 01:00))
+        );
+        assert_eq!(
+            get_next_retry_time(
+                &config,
+                None,
+                &[declined_at, time::macros::datetime!(2025-01-01 01:00)]
+            ),
+            Some(time::macros::datetime!(2025-01-02 01:00))
+        );
+
+        // Retry intervals are exhausted
+        assert_eq!(
+            get_next_retry_time(
+                &config,
+                None,
+                &[
+                    declined_at,
+                    time::macros::datetime!(2025-01-01 01:00),
+                    time::macros::datetime!(2025-01-02 01:00)
+                ]
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_get_next_retry_time_within_network_limits() {
+        let config = get_config(vec![3600; 15]);
+        let declined_at = (0..10)
+            .map(|hour| time::macros::datetime!(2025-01-01 00:00) + time::Duration::hours(hour))
+            .collect::<Vec<_>>();
+
+        // Mastercard allows 10 attempts within 24 hours
+        assert_eq!(
+            get_next_retry_time(&config, Some(&enums::CardNetwork::Mastercard), &declined_at),
+            None
+        );
+        assert_eq!(
+            get_next_retry_time(&config, Some(&enums::CardNetwork::Visa), &declined_at),
+            Some(time::macros::datetime!(2025-01-01 10:00))
+        );
+    }
+}
@@ -1,8 +1,8 @@
 use std::{fmt::Debug, marker::PhantomData, str::FromStr};
 
 use api_models::payments::{
-    Address, ConnectorMandateReferenceId, CustomerDetails, CustomerDetailsResponse, FrmMessage,
-    MandateIds, NetworkDetails, RequestSurchargeDetails,
+    Address, ConnectorMandateReferenceId, CustomerDetails, CustomerDetailsResponse, DccDetails,
+    DeviceFingerprint, FrmMessage, MandateIds, NetworkDetails, RequestSurchargeDetails,
 };
 use common_enums::{Currency, RequestIncrementalAuthorization};
 use common_utils::{
@@ -17,6 +17,7 @@ use diesel_models::{
     ephemeral_key,
     payment_attempt::{
         ConnectorMandateReferenceId as DieselConnectorMandateReferenceId,
+        DccDetails as DieselDccDetails, DeviceFingerprint as DieselDeviceFingerprint,
         NetworkDetails as DieselNetworkDetails,
     },
 };
@@ -165,6 +166,7 @@ where
         test_mode,
         payment_method_balance: None,
         connector_api_version: None,
+        merchant_connector_id: None,
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow: None,
@@ -249,6 +251,7 @@ pub async fn construct_external_vault_proxy_router_data_v2<'a>(
         preprocessing_id: payment_data.payment_attempt.preprocessing_step_id.clone(),
         payment_method_balance: None,
         connector_api_version: None,
+        merchant_connector_id: None,
         connector_request_reference_id,
         test_mode: Some(true),
         connector_http_status_code: None,
@@ -431,6 +434,8 @@ pub async fn construct_payment_router_data_for_authorize<'a>(
         payment_channel: None,
         enable_partial_authorization: None,
         enable_overcapture: None,
+        mit_category: None,
+        dcc_data: None,
     };
     let connector_mandate_request_reference_id = payment_data
         .payment_attempt
@@ -495,6 +500,7 @@ pub async fn construct_payment_router_data_for_authorize<'a>(
         test_mode: Some(true),
         payment_method_balance: None,
         connector_api_version: None,
+        merchant_connector_id: None,
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow: None,
@@ -838,6 +844,7 @@ pub async fn construct_payment_router_data_for_capture<'a>(
         test_mode: Some(true),
         payment_method_balance: None,
         connector_api_version: None,
+        merchant_connector_id: None,
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow: None,
@@ -969,6 +976,7 @@ pub async fn construct_router_data_for_psync<'a>(
         test_mode: Some(true),
         payment_method_balance: None,
         connector_api_version: None,
+        merchant_connector_id: None,
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow: None,
@@ -1164,6 +1172,7 @@ pub async fn construct_payment_router_data_for_sdk_session<'a>(
         test_mode: Some(true),
         payment_method_balance: None,
         connector_api_version: None,
+        merchant_connector_id: None,
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow: None,
@@ -1389,6 +1398,7 @@ pub async fn construct_payment_router_data_for_setup_mandate<'a>(
         test_mode: Some(true),
         payment_method_balance: None,
         connector_api_version: None,
+        merchant_connector_id: None,
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow: None,
@@ -1578,6 +1588,7 @@ where
                 .payment_intent
                 .merchant_order_reference_id
                 .clone(),
+            purchase_order_number: payment_data.payment_intent.purchase_order_number.clone(),
             customer_id: payment_data.payment_intent.customer_id.clone(),
             shipping_origin_zip: shipping_address
                 .and_then(|addr| addr.address.as_ref())
@@ -1657,6 +1668,7 @@ where
         test_mode,
         payment_method_balance: None,
         connector_api_version,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow,
@@ -1853,6 +1865,7 @@ pub async fn construct_payment_router_data_for_update_metadata<'a>(
         test_mode,
         payment_method_balance: None,
         connector_api_version,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow,
@@ -3426,6 +3439,10 @@ where
             network_details: payment_attempt
                 .network_details
                 .map(NetworkDetails::foreign_from),
+            dcc_details: payment_attempt.dcc_details.map(DccDetails::foreign_from),
+            device_fingerprint: payment_attempt
+                .device_fingerprint
+                .map(DeviceFingerprint::foreign_from),
         };
 
         services::ApplicationResponse::JsonWithHeaders((payments_response, headers))
@@ -3726,6 +3743,8 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
             enable_overcapture: pi.enable_overcapture,
             is_overcapture_enabled: pa.is_overcapture_enabled,
             network_details: pa.network_details.map(NetworkDetails::foreign_from),
+            dcc_details: pa.dcc_details.map(DccDetails::foreign_from),
+            device_fingerprint: pa.device_fingerprint.map(DeviceFingerprint::foreign_from),
         }
     }
 }
@@ -4058,6 +4077,8 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             payment_channel: None,
             enable_partial_authorization: None,
             enable_overcapture: None,
+            mit_category: None,
+            dcc_data: None,
         })
     }
 }
@@ -4232,6 +4253,17 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             payment_data.mandate_id.as_ref(),
             payment_data.payment_intent.off_session,
         );
+        let dcc_data = payment_data
+            .payment_attempt
+            .dcc_details
+            .as_ref()
+            .filter(|dcc_details| dcc_details.accepted)
+            .map(|dcc_details| types::DccData {
+                quote_id: dcc_details.quote_id.clone(),
+                card_currency: dcc_details.card_currency,
+                converted_amount: dcc_details.converted_amount,
+                rate: dcc_details.rate.clone(),
+            });
 
         Ok(Self {
             payment_method_data: (payment_method_data.get_required_value("payment_method_data")?),
@@ -4292,6 +4324,8 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             payment_channel: payment_data.payment_intent.payment_channel,
             enable_partial_authorization: payment_data.payment_intent.enable_partial_authorization,
             enable_overcapture: payment_data.payment_intent.enable_overcapture,
+            mit_category: payment_data.payment_intent.mit_category,
+            dcc_data,
         })
     }
 }
@@ -5990,6 +6024,36 @@ impl ForeignFrom<NetworkDetails> for DieselNetworkDetails {
     }
 }
 
+impl ForeignFrom<DieselDccDetails> for DccDetails {
+    fn foreign_from(value: DieselDccDetails) -> Self {
+        Self {
+            quote_id: value.quote_id,
+            accepted: value.accepted,
+            card_currency: value.card_currency,
+            converted_amount: value.converted_amount,
+            rate: value.rate,
+        }
+    }
+}
+
+impl ForeignFrom<DieselDeviceFingerprint> for DeviceFingerprint {
+    fn foreign_from(value: DieselDeviceFingerprint) -> Self {
+        Self {
+            fingerprint_id: value.fingerprint_id,
+            session_id: value.session_id,
+        }
+    }
+}
+
+impl ForeignFrom<DeviceFingerprint> for DieselDeviceFingerprint {
+    fn foreign_from(value: DeviceFingerprint) -> Self {
+        Self {
+            fingerprint_id: value.fingerprint_id,
+            session_id: value.session_id,
+        }
+    }
+}
+
 #[cfg(feature = "v2")]
 impl ForeignFrom<diesel_models::ConnectorTokenDetails>
     for Option<api_models::payments::ConnectorTokenDetails>
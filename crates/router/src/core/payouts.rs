@@ -1,4 +1,8 @@
 pub mod access_token;
+#[cfg(feature = "v1")]
+pub mod batch;
+#[cfg(feature = "v1")]
+pub mod beneficiary;
 pub mod helpers;
 #[cfg(feature = "payout_retry")]
 pub mod retry;
@@ -311,6 +315,10 @@ pub async fn payouts_create_core(
     merchant_context: domain::MerchantContext,
     req: payouts::PayoutCreateRequest,
 ) -> RouterResponse<payouts::PayoutCreateResponse> {
+    #[cfg(feature = "v1")]
+    let req = beneficiary::populate_payout_request_from_beneficiary(&state, &merchant_context, req)
+        .await?;
+
     // Validate create request
     let (payout_id, payout_method_data, profile_id, customer, payment_method) =
         validator::validate_create_request(&state, &merchant_context, &req).await?;
@@ -435,6 +443,9 @@ pub async fn payouts_update_core(
     merchant_context: domain::MerchantContext,
     req: payouts::PayoutCreateRequest,
 ) -> RouterResponse<payouts::PayoutCreateResponse> {
+    #[cfg(feature = "v1")]
+    let req = beneficiary::populate_payout_request_from_beneficiary(&state, &merchant_context, req)
+        .await?;
     let payout_id = req.payout_id.clone().get_required_value("payout_id")?;
     let mut payout_data = Box::pin(make_payout_data(
         &state,
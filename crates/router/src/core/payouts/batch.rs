@@ -0,0 +1,466 @@
+use std::collections::HashSet;
+
+use api_models::payouts::{
+    PayoutActionRequest, PayoutBatchCreateRequest, PayoutBatchItemResponse, PayoutBatchResponse,
+    PayoutCreateRequest,
+};
+use common_utils::{errors::ErrorSwitch, id_type};
+use diesel_models::process_tracker::business_status;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use scheduler::utils as pt_utils;
+use time::{Duration, PrimitiveDateTime};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{domain, storage, storage::enums},
+};
+
+const PAYOUT_BATCH_EXECUTE_TASK: &str = "EXECUTE_PAYOUT_BATCH";
+
+/// Validates a batch of payouts and schedules its execution at the requested time, or at the next
+/// batching cutoff when no time is requested
+#[instrument(skip_all)]
+pub async fn create_payout_batch(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    request: PayoutBatchCreateRequest,
+) -> RouterResponse<PayoutBatchResponse> {
+    let item_count = request.payout_ids.len();
+    common_utils::fp_utils::when(
+        item_count == 0 || item_count > consts::MAX_PAYOUTS_BATCH_SIZE,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "a batch must contain between 1 and {} payouts",
+                    consts::MAX_PAYOUTS_BATCH_SIZE
+                ),
+            })
+        },
+    )?;
+
+    let now = common_utils::date_time::now();
+    let scheduled_at = match request.scheduled_at {
+        Some(scheduled_at) => {
+            common_utils::fp_utils::when(scheduled_at <= now, || {
+                Err(errors::ApiErrorResponse::InvalidDataFormat {
+                    field_name: "scheduled_at".to_string(),
+                    expected_format: "time in the future".to_string(),
+                })
+            })?;
+            scheduled_at
+        }
+        None => get_next_batch_cutoff(&state.conf.payouts.batch_cutoff_hours, now)
+            .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "scheduled_at",
+            })
+            .attach_printable("No payout batch cutoff is configured")?,
+    };
+
+    let mut payout_ids = HashSet::with_capacity(item_count);
+    for payout_id in &request.payout_ids {
+        common_utils::fp_utils::when(!payout_ids.insert(payout_id), || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "payout_id {} is repeated in the batch",
+                    payout_id.get_string_repr()
+                ),
+            })
+        })?;
+
+        let payout = state
+            .store
+            .find_payout_by_merchant_id_payout_id(
+                merchant_context.get_merchant_account().get_id(),
+                payout_id,
+                merchant_context.get_merchant_account().storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
+        core_utils::validate_profile_id_from_auth_layer(profile_id.clone(), &payout)?;
+
+        common_utils::fp_utils::when(
+            !matches!(
+                payout.status,
+                enums::PayoutStatus::RequiresConfirmation
+                    | enums::PayoutStatus::RequiresFulfillment
+            ),
+            || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "Payout {} cannot be batched for status {}",
+                        payout_id.get_string_repr(),
+                        payout.status
+                    ),
+                })
+            },
+        )?;
+    }
+
+    let payout_batch = storage::PayoutBatchNew {
+        id: common_utils::generate_id_with_default_len("payout_batch"),
+        merchant_id: merchant_context.get_merchant_account().get_id().clone(),
+        profile_id,
+        status: enums::PayoutBatchStatus::Scheduled,
+        total_count: i32::try_from(item_count)
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        items: storage::PayoutBatchItems(
+            request
+                .payout_ids
+                .into_iter()
+                .map(storage::PayoutBatchItem::pending)
+                .collect(),
+        ),
+        scheduled_at,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let db = &*state.store;
+    let payout_batch = db
+        .insert_payout_batch(payout_batch)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert payout batch")?;
+
+    add_payout_batch_execute_task(db, &payout_batch)
+        .await
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while pushing payout batch execute task to scheduler, batch_id: {}",
+                payout_batch.id
+            )
+        })?;
+
+    Ok(ApplicationResponse::Json(get_payout_batch_response(
+        payout_batch,
+    )))
+}
+
+pub async fn retrieve_payout_batch(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    batch_id: String,
+) -> RouterResponse<PayoutBatchResponse> {
+    let payout_batch = find_payout_batch(&state, &merchant_context, &batch_id).await?;
+
+    Ok(ApplicationResponse::Json(get_payout_batch_response(
+        payout_batch,
+    )))
+}
+
+/// Cancels a batch of payouts which has not reached its cutoff yet, leaving its payouts as they
+/// were before they were batched
+#[instrument(skip_all)]
+pub async fn cancel_payout_batch(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    batch_id: String,
+) -> RouterResponse<PayoutBatchResponse> {
+    let db = &*state.store;
+    let payout_batch = find_payout_batch(&state, &merchant_context, &batch_id).await?;
+
+    common_utils::fp_utils::when(
+        payout_batch.status != enums::PayoutBatchStatus::Scheduled,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Only a scheduled payout batch can be cancelled, batch is in {} state",
+                    payout_batch.status
+                ),
+            }))
+        },
+    )?;
+
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::PayoutBatchWorkflow,
+        PAYOUT_BATCH_EXECUTE_TASK,
+        &payout_batch.id,
+        &payout_batch.merchant_id,
+    );
+    let execute_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the process id")?;
+
+    let payout_batch = db
+        .update_payout_batch_by_merchant_id_batch_id(
+            &payout_batch.merchant_id,
+            &payout_batch.id,
+            storage::PayoutBatchUpdate::new(
+                enums::PayoutBatchStatus::Cancelled,
+                payout_batch.items.0,
+            ),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Unable to cancel payout batch with batch_id: {batch_id}")
+        })?;
+
+    // The execute task finishes on finding the batch cancelled, this only avoids running it
+    if let Some(execute_process) = execute_process {
+        db.as_scheduler()
+            .finish_process_with_business_status(execute_process, business_status::REVOKED)
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to revoke the payout batch execute task")
+            })
+            .ok();
+    }
+
+    Ok(ApplicationResponse::Json(get_payout_batch_response(
+        payout_batch,
+    )))
+}
+
+/// Executes the pending payouts of a batch one after another, recording the outcome of every
+/// payout on the batch as it completes. Payouts awaiting confirmation are confirmed, and payouts
+/// awaiting fulfillment are fulfilled.
+#[instrument(skip_all)]
+pub async fn execute_payout_batch(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payout_batch: storage::PayoutBatch,
+) -> RouterResult<storage::PayoutBatch> {
+    if !matches!(
+        payout_batch.status,
+        enums::PayoutBatchStatus::Scheduled | enums::PayoutBatchStatus::Processing
+    ) {
+        logger::info!(batch_id = %payout_batch.id, status = %payout_batch.status, "Skipping execution of payout batch");
+        return Ok(payout_batch);
+    }
+
+    let db = &*state.store;
+    let mut outcomes = payout_batch.items.0;
+    let mut payout_batch_update =
+        storage::PayoutBatchUpdate::new(enums::PayoutBatchStatus::Processing, outcomes.clone());
+
+    for index in 0..outcomes.len() {
+        let Some(item) = outcomes
+            .get(index)
+            .filter(|item| item.status == enums::PayoutBatchItemStatus::Pending)
+        else {
+            continue;
+        };
+        let outcome = Box::pin(execute_payout_batch_item(
+            state,
+            merchant_context,
+            item.payout_id.clone(),
+        ))
+        .await;
+        if let Some(item) = outcomes.get_mut(index) {
+            *item = outcome;
+        }
+
+        payout_batch_update =
+            storage::PayoutBatchUpdate::new(enums::PayoutBatchStatus::Processing, outcomes.clone());
+        db.update_payout_batch_by_merchant_id_batch_id(
+            &payout_batch.merchant_id,
+            &payout_batch.id,
+            payout_batch_update.clone(),
+        )
+        .await
+        .map_err(|error| {
+            logger::error!(?error, batch_id = %payout_batch.id, "Failed to update payout batch")
+        })
+        .ok();
+    }
+
+    payout_batch_update.status = enums::PayoutBatchStatus::Completed;
+    db.update_payout_batch_by_merchant_id_batch_id(
+        &payout_batch.merchant_id,
+        &payout_batch.id,
+        payout_batch_update,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable_lazy(|| {
+        format!(
+            "Unable to complete payout batch with batch_id: {}",
+            payout_batch.id
+        )
+    })
+}
+
+async fn execute_payout_batch_item(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payout_id: id_type::PayoutId,
+) -> storage::PayoutBatchItem {
+    let mut outcome = storage::PayoutBatchItem::pending(payout_id.clone());
+
+    let response = match state
+        .store
+        .find_payout_by_merchant_id_payout_id(
+            merchant_context.get_merchant_account().get_id(),
+            &payout_id,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)
+    {
+        Ok(payout) => match payout.status {
+            enums::PayoutStatus::RequiresConfirmation => {
+                Box::pin(super::payouts_confirm_core(
+                    state.clone(),
+                    merchant_context.clone(),
+                    PayoutCreateRequest {
+                        payout_id: Some(payout_id),
+                        confirm: Some(true),
+                        ..Default::default()
+                    },
+                ))
+                .await
+            }
+            enums::PayoutStatus::RequiresFulfillment => {
+                Box::pin(super::payouts_fulfill_core(
+                    state.clone(),
+                    merchant_context.clone(),
+                    PayoutActionRequest { payout_id },
+                ))
+                .await
+            }
+            status => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "Payout {} cannot be executed for status {status}",
+                    payout.payout_id.get_string_repr()
+                ),
+            })),
+        },
+        Err(error) => Err(error),
+    }
+    .and_then(|response| {
+        response
+            .get_json_body()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+    });
+
+    match response {
+        Ok(payout_response) => {
+            outcome.status = enums::PayoutBatchItemStatus::Processed;
+            outcome.payout_status = Some(payout_response.status);
+            outcome.connector = payout_response.connector;
+            outcome.error_code = payout_response.error_code;
+            outcome.error_message = payout_response.error_message;
+        }
+        Err(error) => {
+            logger::info!(?error, payout_id = ?outcome.payout_id, "Failed to execute payout of batch");
+            let api_error: api_models::errors::types::ApiErrorResponse =
+                error.current_context().switch();
+            let error_response = api_models::errors::types::ErrorResponse::from(&api_error);
+            outcome.status = enums::PayoutBatchItemStatus::Failed;
+            outcome.error_code = Some(error_response.code);
+            outcome.error_message = Some(error_response.message);
+        }
+    }
+
+    outcome
+}
+
+async fn find_payout_batch(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    batch_id: &str,
+) -> RouterResult<storage::PayoutBatch> {
+    state
+        .store
+        .find_payout_batch_by_merchant_id_batch_id(
+            merchant_context.get_merchant_account().get_id(),
+            batch_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Payout batch not found".to_string(),
+        })
+}
+
+async fn add_payout_batch_execute_task(
+    db: &dyn StorageInterface,
+    payout_batch: &storage::PayoutBatch,
+) -> RouterResult<storage::ProcessTracker> {
+    let runner = storage::ProcessTrackerRunner::PayoutBatchWorkflow;
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        PAYOUT_BATCH_EXECUTE_TASK,
+        &payout_batch.id,
+        &payout_batch.merchant_id,
+    );
+    let tracking_data = storage::PayoutBatchTrackingData {
+        merchant_id: payout_batch.merchant_id.clone(),
+        batch_id: payout_batch.id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        PAYOUT_BATCH_EXECUTE_TASK,
+        runner,
+        ["PAYOUT", "BATCH"],
+        tracking_data,
+        None,
+        payout_batch.scheduled_at,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct payout batch execute task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert payout batch execute task")
+}
+
+/// Returns the earliest configured cutoff hour of the day which is after the specified time
+fn get_next_batch_cutoff(
+    cutoff_hours: &HashSet<u8>,
+    now: PrimitiveDateTime,
+) -> Option<PrimitiveDateTime> {
+    let start_of_day = now.date().midnight();
+    cutoff_hours
+        .iter()
+        .map(|hour| {
+            let cutoff = start_of_day + Duration::hours(i64::from(*hour));
+            if cutoff > now {
+                cutoff
+            } else {
+                cutoff + Duration::days(1)
+            }
+        })
+        .min()
+}
+
+fn get_payout_batch_response(payout_batch: storage::PayoutBatch) -> PayoutBatchResponse {
+    let items = payout_batch
+        .items
+        .0
+        .into_iter()
+        .map(|item| PayoutBatchItemResponse {
+            payout_id: item.payout_id,
+            status: item.status,
+            payout_status: item.payout_status,
+            connector: item.connector,
+            error_code: item.error_code,
+            error_message: item.error_message,
+        })
+        .collect::<Vec<_>>();
+    let count_items = |status| items.iter().filter(|item| item.status == status).count();
+
+    PayoutBatchResponse {
+        batch_id: payout_batch.id,
+        status: payout_batch.status,
+        total_count: payout_batch.total_count,
+        processed_count: count_items(enums::PayoutBatchItemStatus::Processed),
+        failed_count: count_items(enums::PayoutBatchItemStatus::Failed),
+        items,
+        scheduled_at: payout_batch.scheduled_at,
+        created_at: payout_batch.created_at,
+    }
+}
@@ -0,0 +1,367 @@
+use api_models::payouts::{
+    BeneficiaryCreateRequest, BeneficiaryDeleteResponse, BeneficiaryListConstraints,
+    BeneficiaryResponse, BeneficiaryUpdateRequest, PayoutCreateRequest, PayoutMethodData,
+    PayoutMethodDataResponse,
+};
+use common_utils::{
+    consts::PAYOUTS_LIST_MAX_LIMIT_GET,
+    encryption::Encryption,
+    ext_traits::{Encode, StringExt},
+    fp_utils, payout_method_utils, type_name,
+    types::keymanager::Identifier,
+};
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{domain, storage, storage::enums},
+};
+
+#[instrument(skip_all)]
+pub async fn create_beneficiary(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: BeneficiaryCreateRequest,
+) -> RouterResponse<BeneficiaryResponse> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let payout_type = get_beneficiary_payout_type(&request.payout_method_data)?;
+
+    if let Some(customer_id) = &request.customer_id {
+        state
+            .store
+            .find_customer_by_customer_id_merchant_id(
+                &(&state).into(),
+                customer_id,
+                merchant_id,
+                merchant_context.get_merchant_key_store(),
+                merchant_context.get_merchant_account().storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+    }
+
+    let payout_method_data =
+        encrypt_payout_method_data(&state, &merchant_context, &request.payout_method_data).await?;
+    let now = common_utils::date_time::now();
+    let beneficiary = state
+        .store
+        .insert_payout_beneficiary(storage::PayoutBeneficiaryNew {
+            id: common_utils::generate_id(consts::ID_LENGTH, "ben"),
+            merchant_id: merchant_id.clone(),
+            customer_id: request.customer_id,
+            payout_type,
+            payout_method_data,
+            additional_payout_method_data: get_additional_payout_method_data(
+                request.payout_method_data,
+            ),
+            kyc_status: request.kyc_status.unwrap_or_default(),
+            metadata: request.metadata,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the payout beneficiary")?;
+
+    Ok(ApplicationResponse::Json(to_beneficiary_response(
+        beneficiary,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_beneficiary(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    beneficiary_id: String,
+) -> RouterResponse<BeneficiaryResponse> {
+    let beneficiary = find_beneficiary(&state, &merchant_context, &beneficiary_id).await?;
+    Ok(ApplicationResponse::Json(to_beneficiary_response(
+        beneficiary,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn update_beneficiary(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    beneficiary_id: String,
+    request: BeneficiaryUpdateRequest,
+) -> RouterResponse<BeneficiaryResponse> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    find_beneficiary(&state, &merchant_context, &beneficiary_id).await?;
+
+    let (payout_type, payout_method_data, additional_payout_method_data) = match request
+        .payout_method_data
+    {
+        Some(payout_method_data) => (
+            Some(get_beneficiary_payout_type(&payout_method_data)?),
+            Some(encrypt_payout_method_data(&state, &merchant_context, &payout_method_data).await?),
+            get_additional_payout_method_data(payout_method_data),
+        ),
+        None => (None, None, None),
+    };
+
+    let beneficiary = state
+        .store
+        .update_payout_beneficiary_by_merchant_id_beneficiary_id(
+            merchant_id,
+            &beneficiary_id,
+            storage::PayoutBeneficiaryUpdate {
+                payout_type,
+                payout_method_data,
+                additional_payout_method_data,
+                kyc_status: request.kyc_status,
+                metadata: request.metadata,
+                modified_at: common_utils::date_time::now(),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the payout beneficiary")?;
+
+    Ok(ApplicationResponse::Json(to_beneficiary_response(
+        beneficiary,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_beneficiary(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    beneficiary_id: String,
+) -> RouterResponse<BeneficiaryDeleteResponse> {
+    let beneficiary = state
+        .store
+        .delete_payout_beneficiary_by_merchant_id_beneficiary_id(
+            merchant_context.get_merchant_account().get_id(),
+            &beneficiary_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Beneficiary does not exist in our records".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(BeneficiaryDeleteResponse {
+        beneficiary_id: beneficiary.id,
+        deleted: true,
+    }))
+}
+
+#[instrument(skip_all)]
+pub async fn list_beneficiaries(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    constraints: BeneficiaryListConstraints,
+) -> RouterResponse<Vec<BeneficiaryResponse>> {
+    fp_utils::when(
+        constraints.limit > PAYOUTS_LIST_MAX_LIMIT_GET || constraints.limit < 1,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("limit should be in between 1 and {PAYOUTS_LIST_MAX_LIMIT_GET}"),
+            })
+        },
+    )?;
+
+    let beneficiaries = state
+        .store
+        .list_payout_beneficiaries_by_merchant_id(
+            merchant_context.get_merchant_account().get_id(),
+            constraints.customer_id.as_ref(),
+            i64::from(constraints.limit),
+            constraints.offset.map(i64::from),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the payout beneficiaries")?;
+
+    Ok(ApplicationResponse::Json(
+        beneficiaries
+            .into_iter()
+            .map(to_beneficiary_response)
+            .collect(),
+    ))
+}
+
+/// Fills the payout method data, customer and payout type of a payout request from the
+/// beneficiary referenced by it, so that the rest of the payout flow is unaware of beneficiaries
+#[instrument(skip_all)]
+pub async fn populate_payout_request_from_beneficiary(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    mut request: PayoutCreateRequest,
+) -> RouterResult<PayoutCreateRequest> {
+    let Some(beneficiary_id) = request.beneficiary_id.clone() else {
+        return Ok(request);
+    };
+
+    fp_utils::when(
+        request.payout_method_data.is_some()
+            || request.payout_token.is_some()
+            || request.payout_method_id.is_some(),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "beneficiary_id can't be passed along with payout_method_data, payout_token or payout_method_id".to_string(),
+            })
+        },
+    )?;
+
+    let beneficiary = find_beneficiary(state, merchant_context, &beneficiary_id).await?;
+    fp_utils::when(
+        beneficiary.kyc_status == enums::BeneficiaryKycStatus::Rejected,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Payouts can't be made to the beneficiary {beneficiary_id} as its KYC verification was rejected"
+                ),
+            })
+        },
+    )?;
+
+    if let Some(beneficiary_customer_id) = &beneficiary.customer_id {
+        match request.get_customer_id() {
+            Some(customer_id) if customer_id != beneficiary_customer_id => {
+                return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "customer_id does not match the customer of the beneficiary"
+                        .to_string(),
+                }));
+            }
+            Some(_) => (),
+            None => request.customer_id = Some(beneficiary_customer_id.clone()),
+        }
+    }
+
+    if let Some(payout_type) = request.payout_type {
+        fp_utils::when(payout_type != beneficiary.payout_type, || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "payout_type must be {} for the beneficiary",
+                    beneficiary.payout_type
+                ),
+            })
+        })?;
+    }
+    request.payout_type = Some(beneficiary.payout_type);
+    request.payout_method_data = Some(
+        decrypt_payout_method_data(state, merchant_context, &beneficiary.payout_method_data)
+            .await?,
+    );
+
+    Ok(request)
+}
+
+async fn find_beneficiary(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    beneficiary_id: &str,
+) -> RouterResult<storage::PayoutBeneficiary> {
+    state
+        .store
+        .find_payout_beneficiary_by_merchant_id_beneficiary_id(
+            merchant_context.get_merchant_account().get_id(),
+            beneficiary_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Beneficiary does not exist in our records".to_string(),
+        })
+}
+
+/// Only bank accounts and wallets can be stored for a beneficiary, card details are expected to
+/// be stored in the locker as payment methods
+fn get_beneficiary_payout_type(
+    payout_method_data: &PayoutMethodData,
+) -> RouterResult<enums::PayoutType> {
+    match payout_method_data {
+        PayoutMethodData::Bank(_) => Ok(enums::PayoutType::Bank),
+        PayoutMethodData::Wallet(_) => Ok(enums::PayoutType::Wallet),
+        PayoutMethodData::Card(_) => Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: "Card details can't be stored for a beneficiary".to_string(),
+        })),
+    }
+}
+
+fn get_additional_payout_method_data(
+    payout_method_data: PayoutMethodData,
+) -> Option<payout_method_utils::AdditionalPayoutMethodData> {
+    match payout_method_data {
+        PayoutMethodData::Bank(bank) => Some(
+            payout_method_utils::AdditionalPayoutMethodData::Bank(Box::new(bank.into())),
+        ),
+        PayoutMethodData::Wallet(wallet) => Some(
+            payout_method_utils::AdditionalPayoutMethodData::Wallet(Box::new(wallet.into())),
+        ),
+        PayoutMethodData::Card(_) => None,
+    }
+}
+
+async fn encrypt_payout_method_data(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payout_method_data: &PayoutMethodData,
+) -> RouterResult<Encryption> {
+    let key_store = merchant_context.get_merchant_key_store();
+    let serialized_payout_method_data = payout_method_data
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the payout method data")?;
+
+    domain::types::crypto_operation::<String, masking::WithType>(
+        &state.into(),
+        type_name!(storage::PayoutBeneficiary),
+        domain::types::CryptoOperation::Encrypt(serialized_payout_method_data.into()),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .map(Encryption::from)
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt the payout method data")
+}
+
+async fn decrypt_payout_method_data(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payout_method_data: &Encryption,
+) -> RouterResult<PayoutMethodData> {
+    let key_store = merchant_context.get_merchant_key_store();
+    let decrypted_payout_method_data =
+        domain::types::crypto_operation::<String, masking::WithType>(
+            &state.into(),
+            type_name!(storage::PayoutBeneficiary),
+            domain::types::CryptoOperation::Decrypt(payout_method_data.clone()),
+            Identifier::Merchant(key_store.merchant_id.clone()),
+            key_store.key.peek(),
+        )
+        .await
+        .and_then(|val| val.try_into_operation())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to decrypt the payout method data")?;
+
+    decrypted_payout_method_data
+        .get_inner()
+        .peek()
+        .parse_struct("PayoutMethodData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the payout method data of the beneficiary")
+}
+
+fn to_beneficiary_response(beneficiary: storage::PayoutBeneficiary) -> BeneficiaryResponse {
+    BeneficiaryResponse {
+        beneficiary_id: beneficiary.id,
+        customer_id: beneficiary.customer_id,
+        payout_type: beneficiary.payout_type,
+        payout_method_data: beneficiary
+            .additional_payout_method_data
+            .map(PayoutMethodDataResponse::from),
+        kyc_status: beneficiary.kyc_status,
+        metadata: beneficiary.metadata,
+        created_at: beneficiary.created_at,
+        modified_at: beneficiary.modified_at,
+    }
+}
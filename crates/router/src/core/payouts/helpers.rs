@@ -871,6 +871,7 @@ pub(super) async fn get_or_create_customer_details(
                     updated_by: None,
                     version: common_types::consts::API_VERSION,
                     tax_registration_id: encryptable_customer.tax_registration_id,
+                    merged_into_customer_id: None,
                 };
 
                 Ok(Some(
@@ -0,0 +1,990 @@
+use std::{collections::HashSet, str::FromStr};
+
+use actix_multipart::form::{bytes::Bytes, text::Text, MultipartForm};
+use api_models::recon as recon_api;
+use common_utils::{
+    fp_utils::when,
+    id_type,
+    types::{ConnectorTransactionIdTrait, MinorUnit},
+};
+use error_stack::{report, ResultExt};
+use router_env::{
+    instrument, logger,
+    tracing::{self, Instrument},
+};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use time::{
+    format_description::well_known::{Iso8601, Rfc3339},
+    PrimitiveDateTime,
+};
+
+use crate::{
+    configs::settings::{SettlementAmountUnit, SettlementReportFormat},
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services::{self, ApplicationResponse},
+    types::{
+        api::{self, ConnectorCommon},
+        domain,
+        storage::{self, enums},
+        ConnectorAuthType,
+    },
+};
+
+/// Statuses of the payment attempts which are expected to be settled by the connector
+const SETTLED_ATTEMPT_STATUSES: [enums::AttemptStatus; 3] = [
+    enums::AttemptStatus::Charged,
+    enums::AttemptStatus::PartialCharged,
+    enums::AttemptStatus::PartialChargedAndChargeable,
+];
+
+#[derive(Debug, MultipartForm)]
+pub struct SettlementReportUploadForm {
+    #[multipart(limit = "50MB")]
+    pub file: Bytes,
+    pub connector: Text<String>,
+    pub merchant_connector_id: Option<Text<id_type::MerchantConnectorAccountId>>,
+    pub transactions_from: Option<Text<String>>,
+    pub transactions_to: Option<Text<String>>,
+}
+
+/// Transaction of a settlement report which is reconciled
+#[derive(Debug, Clone, PartialEq)]
+struct SettlementReportRow {
+    transaction_type: enums::ReconTransactionType,
+    connector_transaction_id: String,
+    amount: MinorUnit,
+    currency: enums::Currency,
+    fee: Option<MinorUnit>,
+    settled_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Default)]
+struct ReconCounts {
+    total: i32,
+    matched: i32,
+    mismatched: i32,
+    missing: i32,
+}
+
+impl ReconCounts {
+    fn add(&mut self, recon_status: enums::SettlementReconStatus) {
+        self.total += 1;
+        match recon_status {
+            enums::SettlementReconStatus::Matched => self.matched += 1,
+            enums::SettlementReconStatus::AmountMismatch
+            | enums::SettlementReconStatus::CurrencyMismatch
+            | enums::SettlementReconStatus::StatusMismatch => self.mismatched += 1,
+            enums::SettlementReconStatus::MissingInHyperswitch
+            | enums::SettlementReconStatus::MissingInSettlement => self.missing += 1,
+        }
+    }
+}
+
+/// Splits an uploaded settlement report into its details and the report file
+pub fn get_settlement_report_upload_request(
+    form: SettlementReportUploadForm,
+) -> RouterResult<(recon_api::SettlementReportUploadRequest, bytes::Bytes)> {
+    let parse_period_bound = |value: Option<Text<String>>, field_name: &str| {
+        value
+            .map(|value| {
+                parse_datetime(&value).ok_or(errors::ApiErrorResponse::InvalidDataFormat {
+                    field_name: field_name.to_string(),
+                    expected_format: "YYYY-MM-DDTHH:MM:SS".to_string(),
+                })
+            })
+            .transpose()
+    };
+    let request = recon_api::SettlementReportUploadRequest {
+        connector: form.connector.into_inner(),
+        merchant_connector_id: form.merchant_connector_id.map(Text::into_inner),
+        file_name: form.file.file_name,
+        transactions_from: parse_period_bound(form.transactions_from, "transactions_from")?,
+        transactions_to: parse_period_bound(form.transactions_to, "transactions_to")?,
+    };
+
+    Ok((request, form.file.data))
+}
+
+/// Reconciles a settlement report uploaded by the merchant. The transactions of the report are
+/// matched in the background, and the outcome is recorded on the report as it completes.
+#[instrument(skip_all)]
+pub async fn upload_settlement_report(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: recon_api::SettlementReportUploadRequest,
+    file: bytes::Bytes,
+) -> RouterResponse<recon_api::SettlementReportResponse> {
+    api_models::enums::Connector::from_str(&request.connector).change_context(
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("{} is not a valid connector", request.connector),
+        },
+    )?;
+    if let Some(merchant_connector_id) = &request.merchant_connector_id {
+        let merchant_connector_account =
+            find_merchant_connector_account(&state, &merchant_context, merchant_connector_id)
+                .await?;
+        when(
+            merchant_connector_account.connector_name != request.connector,
+            || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "merchant connector account {} is not a {} account",
+                        merchant_connector_id.get_string_repr(),
+                        request.connector
+                    ),
+                })
+            },
+        )?;
+    }
+
+    let settings = &state.conf.settlement_recon;
+    let rows = parse_settlement_report(
+        &settings.get_report_format(&request.connector),
+        &file,
+        settings.max_report_rows,
+    )?;
+
+    create_settlement_report(
+        state,
+        merchant_context,
+        SettlementReportDetails {
+            connector: request.connector,
+            merchant_connector_id: request.merchant_connector_id,
+            source: enums::SettlementReportSource::FileUpload,
+            file_name: request.file_name,
+            transactions_from: request.transactions_from,
+            transactions_to: request.transactions_to,
+        },
+        rows,
+    )
+    .await
+}
+
+/// Downloads the settlement report of a day from the connector of a merchant connector account
+/// and reconciles it. Reports are pulled from the HTTPS URL configured for the connector, as
+/// connectors don't share a settlement report API. Pulling reports delivered over SFTP is not
+/// supported, such reports have to be uploaded.
+#[instrument(skip_all)]
+pub async fn pull_settlement_report(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: recon_api::SettlementReportPullRequest,
+) -> RouterResponse<recon_api::SettlementReportResponse> {
+    let merchant_connector_account =
+        find_merchant_connector_account(&state, &merchant_context, &request.merchant_connector_id)
+            .await?;
+    let connector = merchant_connector_account.connector_name.clone();
+    let settings = &state.conf.settlement_recon;
+    let format = settings.get_report_format(&connector);
+    let report_url = format.report_url.as_ref().ok_or_else(|| {
+        report!(errors::ApiErrorResponse::NotSupported {
+            message: format!("Pulling settlement reports of {connector}"),
+        })
+    })?;
+
+    let auth_type: ConnectorAuthType = merchant_connector_account
+        .get_connector_account_details()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the connector account details")?;
+    // The report is requested with the same authentication headers as the payment requests of
+    // the connector, since connectors differ in how the credentials are sent
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &connector,
+        api::GetToken::Connector,
+        Some(merchant_connector_account.get_id()),
+    )?;
+    let auth_headers = connector_data
+        .connector
+        .get_auth_header(&auth_type)
+        .change_context(errors::ApiErrorResponse::PreconditionFailed {
+            message: "merchant connector account has no credentials to pull settlement reports \
+                      with"
+                .to_string(),
+        })?;
+
+    let url = report_url.replace("{report_date}", &request.report_date.to_string());
+    let mut connector_request = services::Request::new(services::Method::Get, &url);
+    for (header_name, header_value) in auth_headers {
+        connector_request.add_header(&header_name, header_value);
+    }
+    let response =
+        services::call_connector_api(&state, connector_request, "pull_settlement_report")
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to download the settlement report")?
+            .map_err(|error_response| {
+                report!(errors::ApiErrorResponse::InternalServerError).attach_printable(format!(
+                    "Connector responded to the settlement report download with status code {}",
+                    error_response.status_code
+                ))
+            })?;
+
+    let rows = parse_settlement_report(&format, &response.response, settings.max_report_rows)?;
+
+    create_settlement_report(
+        state,
+        merchant_context,
+        SettlementReportDetails {
+            file_name: url.rsplit('/').next().map(ToOwned::to_owned),
+            connector,
+            merchant_connector_id: Some(request.merchant_connector_id),
+            source: enums::SettlementReportSource::ApiPull,
+            transactions_from: request.transactions_from,
+            transactions_to: request.transactions_to,
+        },
+        rows,
+    )
+    .await
+}
+
+pub async fn retrieve_settlement_report(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    report_id: String,
+) -> RouterResponse<recon_api::SettlementReportResponse> {
+    let settlement_report = state
+        .store
+        .find_settlement_report_by_merchant_id_report_id(
+            merchant_context.get_merchant_account().get_id(),
+            &report_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Settlement report not found".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(get_settlement_report_response(
+        settlement_report,
+    )))
+}
+
+/// Lists the transactions which did not match, optionally of a single report or status
+pub async fn list_recon_exceptions(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: recon_api::ReconExceptionListRequest,
+) -> RouterResponse<recon_api::ReconExceptionListResponse> {
+    when(
+        request.recon_status == Some(enums::SettlementReconStatus::Matched),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "matched transactions are not exceptions".to_string(),
+            })
+        },
+    )?;
+    let limit = request
+        .limit
+        .map_or(consts::RECON_EXCEPTIONS_DEFAULT_LIMIT, i64::from)
+        .min(consts::RECON_EXCEPTIONS_MAX_LIMIT);
+
+    let recon_records = state
+        .store
+        .filter_recon_exceptions_by_merchant_id(
+            merchant_context.get_merchant_account().get_id(),
+            request.report_id,
+            request.recon_status,
+            limit,
+            request.offset.map(i64::from).unwrap_or_default(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list recon exceptions")?;
+
+    let data = recon_records
+        .into_iter()
+        .map(get_recon_record_response)
+        .collect::<Vec<_>>();
+    Ok(ApplicationResponse::Json(
+        recon_api::ReconExceptionListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+/// Retrieves the outcomes of reconciling a payment and its refunds
+pub async fn retrieve_payment_recon_status(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    payment_id: id_type::PaymentId,
+) -> RouterResponse<recon_api::PaymentReconStatusResponse> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &payment_id,
+            merchant_id,
+            merchant_context.get_merchant_key_store(),
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let recon_records = state
+        .store
+        .find_recon_records_by_merchant_id_payment_id(merchant_id, &payment_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the recon records of the payment")?;
+
+    // Records are sorted with the most recent first
+    let recon_status = recon_records
+        .iter()
+        .find(|record| record.transaction_type == enums::ReconTransactionType::Payment)
+        .map(|record| record.recon_status);
+    Ok(ApplicationResponse::Json(
+        recon_api::PaymentReconStatusResponse {
+            payment_id,
+            recon_status,
+            records: recon_records
+                .into_iter()
+                .map(get_recon_record_response)
+                .collect(),
+        },
+    ))
+}
+
+struct SettlementReportDetails {
+    connector: String,
+    merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+    source: enums::SettlementReportSource,
+    file_name: Option<String>,
+    transactions_from: Option<PrimitiveDateTime>,
+    transactions_to: Option<PrimitiveDateTime>,
+}
+
+async fn create_settlement_report(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    details: SettlementReportDetails,
+    rows: Vec<SettlementReportRow>,
+) -> RouterResponse<recon_api::SettlementReportResponse> {
+    match (details.transactions_from, details.transactions_to) {
+        (Some(transactions_from), Some(transactions_to)) if transactions_from > transactions_to => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "transactions_from must not be later than transactions_to".to_string(),
+            })
+        }
+        (Some(_), None) | (None, Some(_)) => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "transactions_from and transactions_to must be provided together".to_string(),
+        }),
+        _ => Ok(()),
+    }?;
+
+    let now = common_utils::date_time::now();
+    let settlement_report = storage::SettlementReportNew {
+        id: common_utils::generate_id_with_default_len("settlement"),
+        merchant_id: merchant_context.get_merchant_account().get_id().clone(),
+        connector: details.connector,
+        merchant_connector_id: details.merchant_connector_id,
+        source: details.source,
+        file_name: details.file_name,
+        status: enums::SettlementReportStatus::Processing,
+        transactions_from: details.transactions_from,
+        transactions_to: details.transactions_to,
+        total_count: 0,
+        matched_count: 0,
+        mismatched_count: 0,
+        missing_count: 0,
+        error_message: None,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let settlement_report = state
+        .store
+        .insert_settlement_report(settlement_report)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert settlement report")?;
+
+    let response = get_settlement_report_response(settlement_report.clone());
+
+    tokio::spawn(
+        process_settlement_report(state, merchant_context, settlement_report, rows)
+            .in_current_span(),
+    );
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+async fn process_settlement_report(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    settlement_report: storage::SettlementReport,
+    rows: Vec<SettlementReportRow>,
+) {
+    let settlement_report_update =
+        reconcile_settlement_report(&state, &merchant_context, &settlement_report, rows)
+            .await
+            .unwrap_or_else(|error| {
+                logger::error!(?error, "Failed to reconcile the settlement report");
+                storage::SettlementReportUpdate::failed(error.current_context().to_string())
+            });
+
+    if let Err(error) = state
+        .store
+        .update_settlement_report_by_merchant_id_report_id(
+            &settlement_report.merchant_id,
+            &settlement_report.id,
+            settlement_report_update,
+        )
+        .await
+    {
+        logger::error!(?error, "Failed to update the settlement report");
+    }
+}
+
+/// Matches every transaction of the report with the transaction recorded by Hyperswitch, and
+/// flags the successful payments of the period of the report which it does not settle
+async fn reconcile_settlement_report(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    settlement_report: &storage::SettlementReport,
+    rows: Vec<SettlementReportRow>,
+) -> RouterResult<storage::SettlementReportUpdate> {
+    let mut counts = ReconCounts::default();
+    let mut settled_attempt_ids = HashSet::new();
+
+    for row in rows {
+        let recon_record =
+            get_recon_record(state, merchant_context, settlement_report, row).await?;
+        if recon_record.transaction_type == enums::ReconTransactionType::Payment {
+            settled_attempt_ids.extend(recon_record.attempt_id.clone());
+        }
+        counts.add(recon_record.recon_status);
+        insert_recon_record(state, recon_record).await?;
+    }
+
+    if let (Some(transactions_from), Some(transactions_to)) = (
+        settlement_report.transactions_from,
+        settlement_report.transactions_to,
+    ) {
+        // The attempts of the period are read in batches, as a period can have more attempts than
+        // can be held in memory at once
+        let batch_size = consts::SETTLEMENT_RECON_ATTEMPTS_BATCH_SIZE;
+        let mut offset = 0;
+        loop {
+            let payment_attempts = state
+                .store
+                .find_payment_attempts_for_recon(
+                    &settlement_report.merchant_id,
+                    &settlement_report.connector,
+                    SETTLED_ATTEMPT_STATUSES.to_vec(),
+                    transactions_from,
+                    transactions_to,
+                    batch_size,
+                    offset,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find the payment attempts of the settlement period")?;
+            let is_last_batch =
+                i64::try_from(payment_attempts.len()).is_ok_and(|count| count < batch_size);
+            offset += batch_size;
+
+            let unsettled_attempts = payment_attempts.into_iter().filter(|payment_attempt| {
+                !settled_attempt_ids.contains(&payment_attempt.attempt_id)
+                    && settlement_report.merchant_connector_id.as_ref().is_none_or(
+                        |merchant_connector_id| {
+                            payment_attempt.merchant_connector_id.as_ref()
+                                == Some(merchant_connector_id)
+                        },
+                    )
+            });
+            for payment_attempt in unsettled_attempts {
+                let recon_record = storage::ReconRecordNew {
+                    id: common_utils::generate_id_with_default_len("recon"),
+                    report_id: settlement_report.id.clone(),
+                    merchant_id: settlement_report.merchant_id.clone(),
+                    connector: settlement_report.connector.clone(),
+                    transaction_type: enums::ReconTransactionType::Payment,
+                    connector_transaction_id: payment_attempt
+                        .get_optional_connector_transaction_id()
+                        .cloned(),
+                    payment_id: Some(payment_attempt.payment_id),
+                    attempt_id: Some(payment_attempt.attempt_id),
+                    refund_id: None,
+                    recon_status: enums::SettlementReconStatus::MissingInSettlement,
+                    settled_amount: None,
+                    settled_currency: None,
+                    expected_amount: Some(
+                        payment_attempt
+                            .amount_to_capture
+                            .or(payment_attempt.net_amount)
+                            .unwrap_or(payment_attempt.amount),
+                    ),
+                    expected_currency: payment_attempt.currency,
+                    fee: None,
+                    settled_at: None,
+                    created_at: common_utils::date_time::now(),
+                };
+                counts.add(recon_record.recon_status);
+                insert_recon_record(state, recon_record).await?;
+            }
+
+            if is_last_batch {
+                break;
+            }
+        }
+    }
+
+    Ok(storage::SettlementReportUpdate {
+        status: enums::SettlementReportStatus::Completed,
+        total_count: Some(counts.total),
+        matched_count: Some(counts.matched),
+        mismatched_count: Some(counts.mismatched),
+        missing_count: Some(counts.missing),
+        error_message: None,
+        modified_at: common_utils::date_time::now(),
+    })
+}
+
+/// Matches a settled transaction with the payment attempt or refund of the connector transaction
+async fn get_recon_record(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    settlement_report: &storage::SettlementReport,
+    row: SettlementReportRow,
+) -> RouterResult<storage::ReconRecordNew> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+
+    let expected = match row.transaction_type {
+        enums::ReconTransactionType::Payment => {
+            let payment_attempt = state
+                .store
+                .find_payment_attempt_by_merchant_id_connector_txn_id(
+                    merchant_id,
+                    &row.connector_transaction_id,
+                    storage_scheme,
+                )
+                .await;
+            match payment_attempt {
+                Ok(payment_attempt)
+                    if payment_attempt.connector.as_deref()
+                        == Some(settlement_report.connector.as_str()) =>
+                {
+                    Some(ExpectedTransaction {
+                        payment_id: payment_attempt.payment_id,
+                        attempt_id: payment_attempt.attempt_id,
+                        refund_id: None,
+                        amount: payment_attempt
+                            .amount_to_capture
+                            .unwrap_or_else(|| payment_attempt.net_amount.get_total_amount()),
+                        currency: payment_attempt.currency,
+                        is_successful: SETTLED_ATTEMPT_STATUSES.contains(&payment_attempt.status),
+                    })
+                }
+                Ok(_) => None,
+                Err(error) if error.current_context().is_db_not_found() => None,
+                Err(error) => Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to find the payment attempt of the settlement")?,
+            }
+        }
+        enums::ReconTransactionType::Refund => {
+            let refund = state
+                .store
+                .find_refund_by_merchant_id_connector_refund_id_connector(
+                    merchant_id,
+                    &row.connector_transaction_id,
+                    &settlement_report.connector,
+                    storage_scheme,
+                )
+                .await;
+            match refund {
+                Ok(refund) => Some(ExpectedTransaction {
+                    payment_id: refund.payment_id,
+                    attempt_id: refund.attempt_id,
+                    refund_id: Some(refund.refund_id),
+                    amount: refund.refund_amount,
+                    currency: Some(refund.currency),
+                    is_successful: refund.refund_status == enums::RefundStatus::Success,
+                }),
+                Err(error) if error.current_context().is_db_not_found() => None,
+                Err(error) => Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to find the refund of the settlement")?,
+            }
+        }
+    };
+
+    let recon_status = get_recon_status(&row, expected.as_ref());
+    Ok(storage::ReconRecordNew {
+        id: common_utils::generate_id_with_default_len("recon"),
+        report_id: settlement_report.id.clone(),
+        merchant_id: settlement_report.merchant_id.clone(),
+        connector: settlement_report.connector.clone(),
+        transaction_type: row.transaction_type,
+        connector_transaction_id: Some(row.connector_transaction_id),
+        payment_id: expected
+            .as_ref()
+            .map(|expected| expected.payment_id.clone()),
+        attempt_id: expected
+            .as_ref()
+            .map(|expected| expected.attempt_id.clone()),
+        refund_id: expected
+            .as_ref()
+            .and_then(|expected| expected.refund_id.clone()),
+        recon_status,
+        settled_amount: Some(row.amount),
+        settled_currency: Some(row.currency),
+        expected_amount: expected.as_ref().map(|expected| expected.amount),
+        expected_currency: expected.and_then(|expected| expected.currency),
+        fee: row.fee,
+        settled_at: row.settled_at,
+        created_at: common_utils::date_time::now(),
+    })
+}
+
+/// Transaction recorded by Hyperswitch which a settled transaction is expected to match
+#[derive(Debug)]
+struct ExpectedTransaction {
+    payment_id: id_type::PaymentId,
+    attempt_id: String,
+    refund_id: Option<String>,
+    amount: MinorUnit,
+    currency: Option<enums::Currency>,
+    is_successful: bool,
+}
+
+fn get_recon_status(
+    row: &SettlementReportRow,
+    expected: Option<&ExpectedTransaction>,
+) -> enums::SettlementReconStatus {
+    match expected {
+        None => enums::SettlementReconStatus::MissingInHyperswitch,
+        Some(expected) if !expected.is_successful => enums::SettlementReconStatus::StatusMismatch,
+        Some(expected)
+            if expected
+                .currency
+                .is_some_and(|currency| currency != row.currency) =>
+        {
+            enums::SettlementReconStatus::CurrencyMismatch
+        }
+        Some(expected) if expected.amount != row.amount => {
+            enums::SettlementReconStatus::AmountMismatch
+        }
+        Some(_) => enums::SettlementReconStatus::Matched,
+    }
+}
+
+async fn insert_recon_record(
+    state: &SessionState,
+    recon_record: storage::ReconRecordNew,
+) -> RouterResult<()> {
+    state
+        .store
+        .insert_recon_record(recon_record)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert recon record")?;
+    Ok(())
+}
+
+async fn find_merchant_connector_account(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<domain::MerchantConnectorAccount> {
+    state
+        .store
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &state.into(),
+            merchant_context.get_merchant_account().get_id(),
+            merchant_connector_id,
+            merchant_context.get_merchant_key_store(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.get_string_repr().to_string(),
+        })
+}
+
+/// Reads the transactions of a settlement report in the format of the connector. Rows of
+/// transaction types which are not reconciled are skipped.
+fn parse_settlement_report(
+    format: &SettlementReportFormat,
+    data: &[u8],
+    max_rows: usize,
+) -> RouterResult<Vec<SettlementReportRow>> {
+    let mut csv_reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(data);
+    let headers = csv_reader
+        .headers()
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: "settlement report must be a CSV file with a header row".to_string(),
+        })?
+        .clone();
+    let find_column = |column: &str| headers.iter().position(|header| header == column);
+    let required_column = |column: &str| {
+        find_column(column).ok_or_else(|| {
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("settlement report has no {column} column"),
+            })
+        })
+    };
+    let connector_transaction_id_index = required_column(&format.connector_transaction_id_column)?;
+    let transaction_type_index = required_column(&format.transaction_type_column)?;
+    let amount_index = required_column(&format.amount_column)?;
+    let currency_index = required_column(&format.currency_column)?;
+    let fee_index = format.fee_column.as_deref().and_then(find_column);
+    let settled_at_index = format.settled_at_column.as_deref().and_then(find_column);
+
+    let mut rows = Vec::new();
+    for (row_number, record) in csv_reader.records().enumerate() {
+        let row_number = row_number + 1;
+        let record = record.change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("invalid row {row_number} in the settlement report"),
+        })?;
+        let field = |index: usize| record.get(index).unwrap_or_default();
+        let optional_field =
+            |index: Option<usize>| index.map(field).filter(|value| !value.is_empty());
+        let invalid_field = |column: &str| errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("invalid {column} on row {row_number} of the settlement report"),
+        };
+
+        let transaction_type = field(transaction_type_index);
+        let transaction_type = if format.payment_types.contains(transaction_type) {
+            enums::ReconTransactionType::Payment
+        } else if format.refund_types.contains(transaction_type) {
+            enums::ReconTransactionType::Refund
+        } else {
+            continue;
+        };
+        when(rows.len() >= max_rows, || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("settlement report must have at most {max_rows} transactions"),
+            })
+        })?;
+
+        let connector_transaction_id = optional_field(Some(connector_transaction_id_index))
+            .ok_or_else(|| invalid_field(&format.connector_transaction_id_column))?;
+        let currency = enums::Currency::from_str(&field(currency_index).to_uppercase())
+            .map_err(|_| invalid_field(&format.currency_column))?;
+        let amount = parse_amount(field(amount_index), currency, format.amount_unit)
+            .ok_or_else(|| invalid_field(&format.amount_column))?;
+        let fee = optional_field(fee_index)
+            .map(|fee| {
+                parse_amount(fee, currency, format.amount_unit)
+                    .ok_or_else(|| invalid_field(format.fee_column.as_deref().unwrap_or_default()))
+            })
+            .transpose()?;
+        let settled_at = optional_field(settled_at_index)
+            .map(|settled_at| {
+                parse_datetime(settled_at).ok_or_else(|| {
+                    invalid_field(format.settled_at_column.as_deref().unwrap_or_default())
+                })
+            })
+            .transpose()?;
+
+        rows.push(SettlementReportRow {
+            transaction_type,
+            connector_transaction_id: connector_transaction_id.to_string(),
+            amount,
+            currency,
+            fee,
+            settled_at,
+        });
+    }
+
+    Ok(rows)
+}
+
+/// Parses an amount of a settlement report into minor units. Refunds and fees are reported as
+/// negative amounts by some connectors, so the sign of the amount is ignored.
+fn parse_amount(
+    value: &str,
+    currency: enums::Currency,
+    amount_unit: SettlementAmountUnit,
+) -> Option<MinorUnit> {
+    let amount = Decimal::from_str(value).ok()?.abs();
+    let amount = match amount_unit {
+        SettlementAmountUnit::Major => {
+            amount
+                * Decimal::from(
+                    10_i64.pow(u32::from(currency.number_of_digits_after_decimal_point())),
+                )
+        }
+        SettlementAmountUnit::Minor => amount,
+    };
+
+    amount
+        .fract()
+        .is_zero()
+        .then(|| amount.to_i64())
+        .flatten()
+        .map(MinorUnit::new)
+}
+
+/// Parses an ISO 8601 date or date and time, with a space or `T` separating the date and time.
+/// Times with an offset are converted to UTC.
+fn parse_datetime(value: &str) -> Option<PrimitiveDateTime> {
+    let value = value.replacen(' ', "T", 1);
+    time::OffsetDateTime::parse(&value, &Rfc3339)
+        .map(|datetime| {
+            let datetime = datetime.to_offset(time::UtcOffset::UTC);
+            PrimitiveDateTime::new(datetime.date(), datetime.time())
+        })
+        .or_else(|_| PrimitiveDateTime::parse(&value, &Iso8601::DEFAULT))
+        .or_else(|_| time::Date::parse(&value, &Iso8601::DEFAULT).map(time::Date::midnight))
+        .ok()
+}
+
+fn get_settlement_report_response(
+    settlement_report: storage::SettlementReport,
+) -> recon_api::SettlementReportResponse {
+    recon_api::SettlementReportResponse {
+        report_id: settlement_report.id,
+        connector: settlement_report.connector,
+        merchant_connector_id: settlement_report.merchant_connector_id,
+        source: settlement_report.source,
+        file_name: settlement_report.file_name,
+        status: settlement_report.status,
+        transactions_from: settlement_report.transactions_from,
+        transactions_to: settlement_report.transactions_to,
+        total_count: settlement_report.total_count,
+        matched_count: settlement_report.matched_count,
+        mismatched_count: settlement_report.mismatched_count,
+        missing_count: settlement_report.missing_count,
+        error_message: settlement_report.error_message,
+        created_at: settlement_report.created_at,
+        modified_at: settlement_report.modified_at,
+    }
+}
+
+fn get_recon_record_response(recon_record: storage::ReconRecord) -> recon_api::ReconRecordResponse {
+    recon_api::ReconRecordResponse {
+        record_id: recon_record.id,
+        report_id: recon_record.report_id,
+        connector: recon_record.connector,
+        transaction_type: recon_record.transaction_type,
+        connector_transaction_id: recon_record.connector_transaction_id,
+        payment_id: recon_record.payment_id,
+        attempt_id: recon_record.attempt_id,
+        refund_id: recon_record.refund_id,
+        recon_status: recon_record.recon_status,
+        settled_amount: recon_record.settled_amount,
+        settled_currency: recon_record.settled_currency,
+        expected_amount: recon_record.expected_amount,
+        expected_currency: recon_record.expected_currency,
+        fee: recon_record.fee,
+        settled_at: recon_record.settled_at,
+        created_at: recon_record.created_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_expected_transaction(amount: i64, is_successful: bool) -> ExpectedTransaction {
+        ExpectedTransaction {
+            payment_id: id_type::PaymentId::try_from(std::borrow::Cow::Borrowed("pay_123"))
+                .unwrap(),
+            attempt_id: "pay_123_1".to_string(),
+            refund_id: None,
+            amount: MinorUnit::new(amount),
+            currency: Some(enums::Currency::USD),
+            is_successful,
+        }
+    }
+
+    #[test]
+    fn test_parse_settlement_report() {
+        let format = SettlementReportFormat {
+            connector_transaction_id_column: "Psp Reference".to_string(),
+            transaction_type_column: "Record Type".to_string(),
+            amount_column: "Main Amount".to_string(),
+            currency_column: "Main Currency".to_string(),
+            fee_column: Some("Commission".to_string()),
+            settled_at_column: Some("Booking Date".to_string()),
+            payment_types: HashSet::from(["Settled".to_string()]),
+            refund_types: HashSet::from(["Refunded".to_string()]),
+            amount_unit: SettlementAmountUnit::Major,
+            report_url: None,
+        };
+        let report =
+            "Psp Reference,Record Type,Main Amount,Main Currency,Commission,Booking Date\n\
+                      8815,Settled,10.50,USD,0.30,2025-01-02 10:00:00\n\
+                      8816,Fee,1.00,USD,,2025-01-02 10:00:00\n\
+                      8817,Refunded,-5.00,eur,,2025-01-03\n";
+
+        let rows = parse_settlement_report(&format, report.as_bytes(), 10).unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                SettlementReportRow {
+                    transaction_type: enums::ReconTransactionType::Payment,
+                    connector_transaction_id: "8815".to_string(),
+                    amount: MinorUnit::new(1050),
+                    currency: enums::Currency::USD,
+                    fee: Some(MinorUnit::new(30)),
+                    settled_at: Some(time::macros::datetime!(2025-01-02 10:00)),
+                },
+                SettlementReportRow {
+                    transaction_type: enums::ReconTransactionType::Refund,
+                    connector_transaction_id: "8817".to_string(),
+                    amount: MinorUnit::new(500),
+                    currency: enums::Currency::EUR,
+                    fee: None,
+                    settled_at: Some(time::macros::datetime!(2025-01-03 00:00)),
+                },
+            ]
+        );
+
+        // The report has more transactions than allowed
+        assert!(parse_settlement_report(&format, report.as_bytes(), 1).is_err());
+        // The report is missing a required column
+        assert!(
+            parse_settlement_report(&SettlementReportFormat::default(), report.as_bytes(), 10)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_get_recon_status() {
+        let row = SettlementReportRow {
+            transaction_type: enums::ReconTransactionType::Payment,
+            connector_transaction_id: "8815".to_string(),
+            amount: MinorUnit::new(1050),
+            currency: enums::Currency::USD,
+            fee: None,
+            settled_at: None,
+        };
+
+        assert_eq!(
+            get_recon_status(&row, Some(&get_expected_transaction(1050, true))),
+            enums::SettlementReconStatus::Matched
+        );
+        assert_eq!(
+            get_recon_status(&row, Some(&get_expected_transaction(1000, true))),
+            enums::SettlementReconStatus::AmountMismatch
+        );
+        assert_eq!(
+            get_recon_status(&row, Some(&get_expected_transaction(1050, false))),
+            enums::SettlementReconStatus::StatusMismatch
+        );
+        assert_eq!(
+            get_recon_status(
+                &SettlementReportRow {
+                    currency: enums::Currency::EUR,
+                    ..row.clone()
+                },
+                Some(&get_expected_transaction(1050, true))
+            ),
+            enums::SettlementReconStatus::CurrencyMismatch
+        );
+        assert_eq!(
+            get_recon_status(&row, None),
+            enums::SettlementReconStatus::MissingInHyperswitch
+        );
+    }
+}
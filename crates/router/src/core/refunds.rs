@@ -20,6 +20,8 @@ use scheduler::{
 #[cfg(feature = "olap")]
 use strum::IntoEnumIterator;
 
+#[cfg(feature = "olap")]
+use crate::core::list_views;
 use crate::{
     consts,
     core::{
@@ -52,6 +54,7 @@ pub async fn refund_create_core(
     merchant_context: domain::MerchantContext,
     _profile_id: Option<common_utils::id_type::ProfileId>,
     req: refunds::RefundRequest,
+    created_by: Option<common_utils::types::CreatedBy>,
 ) -> RouterResponse<refunds::RefundResponse> {
     let db = &*state.store;
     let (merchant_id, payment_intent, payment_attempt, amount);
@@ -128,6 +131,7 @@ pub async fn refund_create_core(
         amount,
         req,
         creds_identifier,
+        created_by,
     ))
     .await
     .map(services::ApplicationResponse::Json)
@@ -804,6 +808,247 @@ pub async fn refund_update_core(
     Ok(services::ApplicationResponse::Json(response.foreign_into()))
 }
 
+// ********************************************** REFUND CANCEL **********************************************
+
+#[instrument(skip_all)]
+pub async fn refund_cancel_core(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: refunds::RefundCancelRequest,
+) -> RouterResponse<refunds::RefundResponse> {
+    let db = state.store.as_ref();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+    let refund = db
+        .find_refund_by_merchant_id_refund_id(
+            merchant_context.get_merchant_account().get_id(),
+            &req.refund_id,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+
+    utils::when(
+        refund.refund_status != enums::RefundStatus::Scheduled || refund.sent_to_gateway,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Only a scheduled refund can be cancelled, refund is in {} state",
+                    refund.refund_status
+                ),
+            }))
+        },
+    )?;
+
+    let task_id = format!(
+        "{}_EXECUTE_REFUND_{}",
+        storage::ProcessTrackerRunner::RefundWorkflowRouter,
+        refund.internal_reference_id
+    );
+    let refund_process = db
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the process id")?;
+
+    let response = db
+        .update_refund(
+            refund,
+            diesel_refund::RefundUpdate::ManualUpdate {
+                refund_status: Some(enums::RefundStatus::Cancelled),
+                refund_error_message: None,
+                refund_error_code: None,
+                updated_by: storage_scheme.to_string(),
+            },
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Unable to cancel refund with refund_id: {}", req.refund_id)
+        })?;
+
+    // The execute task finishes on finding the refund cancelled, this only avoids running it
+    if let Some(refund_process) = refund_process {
+        db.as_scheduler()
+            .finish_process_with_business_status(refund_process, business_status::REVOKED)
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to revoke the refund execute task"))
+            .ok();
+    }
+
+    Ok(services::ApplicationResponse::Json(response.foreign_into()))
+}
+
+// ********************************************** REFUND APPROVAL **********************************************
+
+#[instrument(skip_all)]
+pub async fn refund_approve_core(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    req: refunds::RefundApproveRequest,
+    reviewed_by: Option<common_utils::types::CreatedBy>,
+) -> RouterResponse<refunds::RefundResponse> {
+    let db = &*state.store;
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let (refund, reviewed_by) = get_refund_for_review(
+        &state,
+        &merchant_context,
+        profile_id,
+        &req.refund_id,
+        reviewed_by,
+    )
+    .await?;
+
+    let refund = db
+        .update_refund(
+            refund,
+            diesel_refund::RefundUpdate::ReviewUpdate {
+                refund_status: enums::RefundStatus::Pending,
+                refund_error_message: None,
+                reviewed_by,
+                updated_by: storage_scheme.to_string(),
+            },
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Unable to approve refund with refund_id: {}", req.refund_id)
+        })?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_connector_transaction_id_payment_id_merchant_id(
+            &refund.connector_transaction_id,
+            &refund.payment_id,
+            merchant_id,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &payment_attempt.payment_id,
+            merchant_id,
+            merchant_context.get_merchant_key_store(),
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let split_refunds = core_utils::get_split_refunds(SplitRefundInput {
+        split_payment_request: payment_intent.split_payments.clone(),
+        payment_charges: payment_attempt.charges.clone(),
+        charge_id: payment_attempt.charge_id.clone(),
+        refund_request: refund.split_refunds.clone(),
+    })?;
+
+    let refund_type = refund.refund_type.foreign_into();
+    let response = Box::pin(schedule_refund_execution(
+        &state,
+        refund,
+        refund_type,
+        None,
+        &merchant_context,
+        &payment_attempt,
+        &payment_intent,
+        None,
+        split_refunds,
+    ))
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(response.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn refund_reject_core(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    req: refunds::RefundRejectRequest,
+    reviewed_by: Option<common_utils::types::CreatedBy>,
+) -> RouterResponse<refunds::RefundResponse> {
+    let db = &*state.store;
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+    let (refund, reviewed_by) = get_refund_for_review(
+        &state,
+        &merchant_context,
+        profile_id,
+        &req.refund_id,
+        reviewed_by,
+    )
+    .await?;
+
+    let response = db
+        .update_refund(
+            refund,
+            diesel_refund::RefundUpdate::ReviewUpdate {
+                refund_status: enums::RefundStatus::Cancelled,
+                refund_error_message: Some(req.reason),
+                reviewed_by,
+                updated_by: storage_scheme.to_string(),
+            },
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Unable to reject refund with refund_id: {}", req.refund_id)
+        })?;
+
+    Ok(services::ApplicationResponse::Json(response.foreign_into()))
+}
+
+/// Fetches a refund pending approval, and checks that it is not reviewed by the user who created
+/// it. Returns the refund along with the reviewer to be recorded on it.
+async fn get_refund_for_review(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    refund_id: &str,
+    reviewed_by: Option<common_utils::types::CreatedBy>,
+) -> RouterResult<(diesel_refund::Refund, String)> {
+    let refund = state
+        .store
+        .find_refund_by_merchant_id_refund_id(
+            merchant_context.get_merchant_account().get_id(),
+            refund_id,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &refund)?;
+
+    utils::when(
+        refund.refund_status != enums::RefundStatus::PendingApproval,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Only a refund pending approval can be approved or rejected, refund is in {} state",
+                    refund.refund_status
+                ),
+            }))
+        },
+    )?;
+
+    let reviewed_by = reviewed_by
+        .map(|reviewed_by| reviewed_by.to_string())
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get the user reviewing the refund")?;
+
+    utils::when(refund.created_by.as_ref() == Some(&reviewed_by), || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "A refund cannot be approved or rejected by the user who created it"
+                .to_string(),
+        }))
+    })?;
+
+    Ok((refund, reviewed_by))
+}
+
 // ********************************************** VALIDATIONS **********************************************
 
 #[instrument(skip_all)]
@@ -816,6 +1061,7 @@ pub async fn validate_and_create_refund(
     refund_amount: MinorUnit,
     req: refunds::RefundRequest,
     creds_identifier: Option<String>,
+    created_by: Option<common_utils::types::CreatedBy>,
 ) -> RouterResult<refunds::RefundResponse> {
     let db = &*state.store;
     let split_refunds = core_utils::get_split_refunds(SplitRefundInput {
@@ -825,8 +1071,54 @@ pub async fn validate_and_create_refund(
         refund_request: req.split_refunds.clone(),
     })?;
 
+    let execute_at = req.execute_at;
+    if let Some(execute_at) = execute_at {
+        utils::when(
+            req.refund_type == Some(refunds::RefundType::Instant),
+            || {
+                Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "`execute_at` cannot be passed for an instant refund".to_string(),
+                }))
+            },
+        )?;
+        utils::when(execute_at <= common_utils::date_time::now(), || {
+            Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "execute_at".to_string(),
+                expected_format: "time in the future".to_string(),
+            }))
+        })?;
+    }
+
+    let refund_approval_threshold = match payment_intent.profile_id.as_ref() {
+        Some(profile_id) => {
+            db.find_business_profile_by_profile_id(
+                &state.into(),
+                merchant_context.get_merchant_key_store(),
+                profile_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                id: profile_id.get_string_repr().to_owned(),
+            })?
+            .refund_approval_threshold
+        }
+        None => None,
+    };
+    let requires_approval =
+        refund_approval_threshold.is_some_and(|threshold| refund_amount > threshold);
+    utils::when(requires_approval && execute_at.is_some(), || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "`execute_at` cannot be passed for a refund which requires approval"
+                .to_string(),
+        }))
+    })?;
+
     // Only for initial dev and testing
-    let refund_type = req.refund_type.unwrap_or_default();
+    let refund_type = if execute_at.is_some() {
+        refunds::RefundType::Scheduled
+    } else {
+        req.refund_type.unwrap_or_default()
+    };
 
     // If Refund Id not passed in request Generate one.
 
@@ -903,13 +1195,19 @@ pub async fn validate_and_create_refund(
         merchant_id: merchant_context.get_merchant_account().get_id().clone(),
         connector_transaction_id,
         connector,
-        refund_type: req.refund_type.unwrap_or_default().foreign_into(),
+        refund_type: refund_type.foreign_into(),
         total_amount: payment_attempt.get_total_amount(),
         refund_amount,
         currency,
         created_at: common_utils::date_time::now(),
         modified_at: common_utils::date_time::now(),
-        refund_status: enums::RefundStatus::Pending,
+        refund_status: if requires_approval {
+            enums::RefundStatus::PendingApproval
+        } else if execute_at.is_some() {
+            enums::RefundStatus::Scheduled
+        } else {
+            enums::RefundStatus::Pending
+        },
         metadata: req.metadata,
         description: req.reason.clone(),
         attempt_id: payment_attempt.attempt_id.clone(),
@@ -928,6 +1226,7 @@ pub async fn validate_and_create_refund(
             .clone(),
         processor_transaction_data,
         processor_refund_data: None,
+        created_by: created_by.map(|created_by| created_by.to_string()),
     };
 
     let refund = match db
@@ -942,6 +1241,7 @@ pub async fn validate_and_create_refund(
                 state,
                 refund.clone(),
                 refund_type,
+                execute_at,
                 merchant_context,
                 payment_attempt,
                 payment_intent,
@@ -995,6 +1295,18 @@ pub async fn refund_list(
     profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
     req: api_models::refunds::RefundListRequest,
 ) -> RouterResponse<api_models::refunds::RefundListResponse> {
+    let req = match req.view_id.clone() {
+        Some(view_id) => {
+            list_views::apply_refunds_list_view(
+                &state,
+                merchant_context.get_merchant_account().get_id(),
+                &view_id,
+                req,
+            )
+            .await?
+        }
+        None => req,
+    };
     let db = state.store;
     let limit = validator::validate_refund_list(req.limit)?;
     let offset = req.offset.unwrap_or_default();
@@ -1009,6 +1321,9 @@ pub async fn refund_list(
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+    let next_cursor = core_utils::get_next_list_cursor(&refund_list, limit, |refund| {
+        common_utils::types::ListCursor::new(refund.modified_at, refund.refund_id.clone())
+    });
 
     let data: Vec<refunds::RefundResponse> = refund_list
         .into_iter()
@@ -1029,6 +1344,7 @@ pub async fn refund_list(
             count: data.len(),
             total_count,
             data,
+            next_cursor,
         },
     ))
 }
@@ -1291,6 +1607,7 @@ pub async fn schedule_refund_execution(
     state: &SessionState,
     refund: diesel_refund::Refund,
     refund_type: api_models::refunds::RefundType,
+    execute_at: Option<time::PrimitiveDateTime>,
     merchant_context: &domain::MerchantContext,
     payment_attempt: &storage::PaymentAttempt,
     payment_intent: &storage::PaymentIntent,
@@ -1316,7 +1633,7 @@ pub async fn schedule_refund_execution(
                     // Execute the refund task based on refund_type
                     match refund_type {
                         api_models::refunds::RefundType::Scheduled => {
-                            add_refund_execute_task(db, &refund, runner)
+                            add_refund_execute_task(db, &refund, runner, common_utils::date_time::now())
                                 .await
                                 .change_context(errors::ApiErrorResponse::InternalServerError)
                                 .attach_printable_lazy(|| format!("Failed while pushing refund execute task to scheduler, refund_id: {}", refund.refund_id))?;
@@ -1371,6 +1688,22 @@ pub async fn schedule_refund_execution(
                 }
             }
         }
+        enums::RefundStatus::Scheduled => {
+            // The refund is sent to the connector by the execute task at the requested time
+            let execute_at = execute_at.get_required_value("execute_at")?;
+            add_refund_execute_task(db, &refund, runner, execute_at)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Failed while pushing scheduled refund execute task to scheduler, refund_id: {}",
+                        refund.refund_id
+                    )
+                })?;
+            Ok(refund)
+        }
+        // The refund is sent to the connector once it is approved
+        enums::RefundStatus::PendingApproval => Ok(refund),
         //  [#255]: This is not allowed to be otherwise or all
         _ => Ok(refund),
     }?;
@@ -1544,7 +1877,7 @@ pub async fn trigger_refund_execute_workflow(
         .await
         .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
     match (&refund.sent_to_gateway, &refund.refund_status) {
-        (false, enums::RefundStatus::Pending) => {
+        (false, enums::RefundStatus::Pending) | (false, enums::RefundStatus::Scheduled) => {
             let merchant_account = db
                 .find_merchant_account_by_merchant_id(
                     key_manager_state,
@@ -1683,11 +2016,11 @@ pub async fn add_refund_execute_task(
     db: &dyn db::StorageInterface,
     refund: &diesel_refund::Refund,
     runner: storage::ProcessTrackerRunner,
+    schedule_time: time::PrimitiveDateTime,
 ) -> RouterResult<storage::ProcessTracker> {
     let task = "EXECUTE_REFUND";
     let process_tracker_id = format!("{runner}_{task}_{}", refund.internal_reference_id);
     let tag = ["REFUND"];
-    let schedule_time = common_utils::date_time::now();
     let refund_workflow_tracking_data = refund_to_refund_core_workflow_model(refund);
     let process_tracker_entry = storage::ProcessTrackerNew::new(
         process_tracker_id,
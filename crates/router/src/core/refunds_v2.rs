@@ -1055,6 +1055,7 @@ pub async fn refund_list(
             count: data.len(),
             total_count,
             data,
+            next_cursor: None,
         },
     ))
 }
@@ -130,6 +130,7 @@ pub async fn construct_relay_refund_router_data<F>(
         test_mode: connector_account.get_connector_test_mode(),
         payment_method_balance: None,
         connector_api_version,
+        merchant_connector_id: Some(connector_account.get_id()),
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow: None,
@@ -0,0 +1,853 @@
+pub mod table;
+
+use api_models::{
+    analytics::{AnalyticsRequest, GetPaymentMetricRequest, GetRefundMetricRequest},
+    reports as reports_api,
+};
+use common_utils::{
+    date_time,
+    ext_traits::Encode,
+    pii,
+    types::{authentication::AuthInfo, TimeRange},
+};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use scheduler::utils as pt_utils;
+
+use self::table::ReportTable;
+use crate::{
+    analytics_validator, consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    routes::{metrics, SessionState},
+    services::ApplicationResponse,
+    types::{
+        domain,
+        storage::{self, enums, report::ReportGenerationTrackingData},
+    },
+};
+#[cfg(feature = "email")]
+use crate::{services::email::types as email_types, utils::user as user_utils};
+
+pub const REPORT_GENERATION_TASK: &str = "REPORT_GENERATION";
+
+const XLSX_CONTENT_TYPE: &str = "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet";
+
+/// The metrics request of the domain of a report
+enum ReportMetricRequest {
+    Payments(GetPaymentMetricRequest),
+    Refunds(GetRefundMetricRequest),
+}
+
+/// The outcome of generating and delivering a report, which is recorded as the report run
+struct ReportRunOutcome {
+    row_count: Option<i64>,
+    file_key: Option<String>,
+    error_message: Option<String>,
+}
+
+/// Creates a report template and schedules its generation on the frequency of the template
+#[instrument(skip_all)]
+pub async fn create_report_template(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: reports_api::ReportTemplateCreateRequest,
+    created_by: Option<common_utils::types::CreatedBy>,
+) -> RouterResponse<reports_api::ReportTemplateResponse> {
+    let db = &*state.store;
+    let created_by = created_by
+        .map(|created_by| created_by.to_string())
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get the user creating the report")?;
+    validate_report_name(&request.name)?;
+    let filters = request
+        .filters
+        .unwrap_or_else(|| serde_json::Value::Object(Default::default()));
+    let recipients = request.recipients.unwrap_or_default();
+    get_report_metric_request(
+        request.domain,
+        &request.metrics,
+        &request.dimensions,
+        &filters,
+        get_last_report_period(request.frequency, date_time::now()),
+    )?;
+    validate_report_delivery(request.delivery_method, &recipients)?;
+
+    let now = date_time::now();
+    let template = db
+        .insert_report_template(storage::ReportTemplateNew {
+            id: common_utils::generate_id_with_default_len("report"),
+            merchant_id: merchant_context.get_merchant_account().get_id().clone(),
+            created_by,
+            name: request.name,
+            domain: request.domain,
+            metrics: request
+                .metrics
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
+            dimensions: request
+                .dimensions
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
+            filters,
+            format: request.format,
+            frequency: request.frequency,
+            delivery_method: request.delivery_method,
+            recipients: recipients
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
+            is_active: true,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the report template")?;
+
+    add_report_generation_task(db, &template).await?;
+
+    Ok(ApplicationResponse::Json(get_template_response(template)))
+}
+
+#[instrument(skip_all)]
+pub async fn list_report_templates(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+) -> RouterResponse<reports_api::ReportTemplateListResponse> {
+    let templates = state
+        .store
+        .list_report_templates_by_merchant_id(merchant_context.get_merchant_account().get_id())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the report templates")?;
+
+    let data = templates
+        .into_iter()
+        .map(get_template_response)
+        .collect::<Vec<_>>();
+
+    Ok(ApplicationResponse::Json(
+        reports_api::ReportTemplateListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_report_template(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    template_id: String,
+) -> RouterResponse<reports_api::ReportTemplateResponse> {
+    let template = find_report_template(&state, &merchant_context, &template_id).await?;
+
+    Ok(ApplicationResponse::Json(get_template_response(template)))
+}
+
+/// Updates a report template. The metrics, dimensions and filters are validated together with the
+/// existing fields of the template which are not updated.
+#[instrument(skip_all)]
+pub async fn update_report_template(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    template_id: String,
+    request: reports_api::ReportTemplateUpdateRequest,
+) -> RouterResponse<reports_api::ReportTemplateResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let template = find_report_template(&state, &merchant_context, &template_id).await?;
+
+    if let Some(name) = &request.name {
+        validate_report_name(name)?;
+    }
+    let metrics = request
+        .metrics
+        .clone()
+        .unwrap_or_else(|| get_string_list(&template.metrics));
+    let dimensions = request
+        .dimensions
+        .clone()
+        .unwrap_or_else(|| get_string_list(&template.dimensions));
+    let filters = request
+        .filters
+        .clone()
+        .unwrap_or_else(|| template.filters.clone());
+    let frequency = request.frequency.unwrap_or(template.frequency);
+    get_report_metric_request(
+        template.domain,
+        &metrics,
+        &dimensions,
+        &filters,
+        get_last_report_period(frequency, date_time::now()),
+    )?;
+    let recipients = request
+        .recipients
+        .clone()
+        .unwrap_or_else(|| get_recipients(&template.recipients));
+    validate_report_delivery(
+        request.delivery_method.unwrap_or(template.delivery_method),
+        &recipients,
+    )?;
+
+    let template = db
+        .update_report_template_by_merchant_id_template_id(
+            merchant_id,
+            &template_id,
+            storage::ReportTemplateUpdate {
+                name: request.name,
+                metrics: request
+                    .metrics
+                    .map(|metrics| metrics.encode_to_value())
+                    .transpose()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)?,
+                dimensions: request
+                    .dimensions
+                    .map(|dimensions| dimensions.encode_to_value())
+                    .transpose()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)?,
+                filters: request.filters,
+                format: request.format,
+                frequency: request.frequency,
+                delivery_method: request.delivery_method,
+                recipients: request
+                    .recipients
+                    .map(|recipients| recipients.encode_to_value())
+                    .transpose()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)?,
+                is_active: request.is_active,
+                modified_at: date_time::now(),
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Report template does not exist in our records".to_string(),
+        })
+        .attach_printable("Failed to update the report template")?;
+
+    Ok(ApplicationResponse::Json(get_template_response(template)))
+}
+
+/// Deletes a report template. The scheduled generation of the template finishes the next time it
+/// runs, and the runs of the template are kept in the run history.
+#[instrument(skip_all)]
+pub async fn delete_report_template(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    template_id: String,
+) -> RouterResponse<reports_api::ReportTemplateDeleteResponse> {
+    let deleted = state
+        .store
+        .delete_report_template_by_merchant_id_template_id(
+            merchant_context.get_merchant_account().get_id(),
+            &template_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Report template does not exist in our records".to_string(),
+        })
+        .attach_printable("Failed to delete the report template")?;
+
+    Ok(ApplicationResponse::Json(
+        reports_api::ReportTemplateDeleteResponse {
+            template_id,
+            deleted,
+        },
+    ))
+}
+
+/// Generates and delivers a report on demand, for the given period or the last complete period of
+/// the frequency of the report
+#[instrument(skip_all)]
+pub async fn run_report(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    template_id: String,
+    request: reports_api::ReportRunRequest,
+    triggered_by: Option<common_utils::types::CreatedBy>,
+) -> RouterResponse<reports_api::ReportRunResponse> {
+    let triggered_by = triggered_by
+        .map(|triggered_by| triggered_by.to_string())
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get the user running the report")?;
+    let template = find_report_template(&state, &merchant_context, &template_id).await?;
+
+    let now = date_time::now();
+    let time_range = match request.time_range {
+        Some(time_range) => {
+            let end_time = time_range.end_time.unwrap_or(now);
+            if time_range.start_time >= end_time {
+                return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "start_time of the time_range must be before its end_time".to_string(),
+                }));
+            }
+            TimeRange {
+                start_time: time_range.start_time,
+                end_time: Some(end_time),
+            }
+        }
+        None => get_last_report_period(template.frequency, now),
+    };
+
+    let report_run = generate_report(
+        &state,
+        merchant_context.get_merchant_account(),
+        &template,
+        time_range,
+        triggered_by,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(get_run_response(report_run)))
+}
+
+#[instrument(skip_all)]
+pub async fn list_report_runs(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    query: reports_api::ReportRunListQuery,
+) -> RouterResponse<reports_api::ReportRunListResponse> {
+    let limit = query
+        .limit
+        .map(i64::from)
+        .unwrap_or(consts::REPORT_RUNS_DEFAULT_LIMIT)
+        .min(consts::REPORT_RUNS_MAX_LIMIT);
+    let offset = query.offset.map(i64::from).unwrap_or_default();
+
+    let report_runs = state
+        .store
+        .list_report_runs_by_merchant_id(
+            merchant_context.get_merchant_account().get_id(),
+            query.template_id,
+            limit,
+            offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the report runs")?;
+
+    let data = report_runs
+        .into_iter()
+        .map(get_run_response)
+        .collect::<Vec<_>>();
+
+    Ok(ApplicationResponse::Json(
+        reports_api::ReportRunListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+/// Downloads the file of a report run, which is stored for every report which was generated
+#[instrument(skip_all)]
+pub async fn download_report_run_file(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    run_id: String,
+) -> RouterResponse<()> {
+    let report_run = state
+        .store
+        .find_report_run_by_merchant_id_run_id(
+            merchant_context.get_merchant_account().get_id(),
+            &run_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Report run does not exist in our records".to_string(),
+        })?;
+    let file_key = report_run
+        .file_key
+        .ok_or(errors::ApiErrorResponse::FileNotAvailable)
+        .attach_printable("The report of the run was not generated")?;
+
+    let file_data = state
+        .file_storage_client
+        .retrieve_file(&file_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve the report file")?;
+    let content_type = match report_run.format {
+        enums::ReportFormat::Csv => mime::TEXT_CSV,
+        enums::ReportFormat::Xlsx => XLSX_CONTENT_TYPE
+            .parse::<mime::Mime>()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse file content type")?,
+    };
+
+    Ok(ApplicationResponse::FileData((file_data, content_type)))
+}
+
+/// Generates the report of the template for the period, stores the report file and delivers it,
+/// and records the run. A failure to generate or deliver the report is recorded as a failed run
+/// rather than returned, so that it shows up in the run history.
+#[instrument(skip_all)]
+pub async fn generate_report(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    template: &storage::ReportTemplate,
+    time_range: TimeRange,
+    triggered_by: String,
+) -> RouterResult<storage::ReportRun> {
+    let run_id = common_utils::generate_time_ordered_id("report_run");
+    let start_time = time_range.start_time;
+    let end_time = time_range.end_time.unwrap_or_else(date_time::now);
+
+    let outcome =
+        build_and_deliver_report(state, merchant_account, template, &run_id, time_range).await;
+    let status = match outcome.error_message {
+        Some(_) => enums::ReportRunStatus::Failed,
+        None => enums::ReportRunStatus::Succeeded,
+    };
+
+    let report_run = state
+        .store
+        .insert_report_run(storage::ReportRunNew {
+            id: run_id,
+            merchant_id: template.merchant_id.clone(),
+            template_id: template.id.clone(),
+            status,
+            triggered_by,
+            start_time,
+            end_time,
+            format: template.format,
+            row_count: outcome.row_count,
+            file_key: outcome.file_key,
+            error_message: outcome.error_message,
+            created_at: date_time::now(),
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record the report run")?;
+
+    logger::info!(
+        report_run_id = %report_run.id,
+        template_id = %report_run.template_id,
+        status = %report_run.status,
+        row_count = ?report_run.row_count,
+        "Generated the report of the template"
+    );
+
+    Ok(report_run)
+}
+
+async fn build_and_deliver_report(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    template: &storage::ReportTemplate,
+    run_id: &str,
+    time_range: TimeRange,
+) -> ReportRunOutcome {
+    let table = match get_report_table(state, merchant_account, template, time_range).await {
+        Ok(table) => table,
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch the metrics of the report");
+            return ReportRunOutcome {
+                row_count: None,
+                file_key: None,
+                error_message: Some("Failed to fetch the metrics of the report".to_string()),
+            };
+        }
+    };
+    let row_count = Some(i64::try_from(table.rows.len()).unwrap_or(i64::MAX));
+
+    let (file_data, extension) = match template.format {
+        enums::ReportFormat::Csv => (table.to_csv(), "csv"),
+        enums::ReportFormat::Xlsx => (table.to_xlsx(), "xlsx"),
+    };
+    let file_key = format!(
+        "reports/{}/{run_id}.{extension}",
+        template.merchant_id.get_string_repr()
+    );
+    let stored_file = match file_data {
+        Ok(file_data) => state
+            .file_storage_client
+            .upload_file(&file_key, file_data)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError),
+        Err(error) => Err(error),
+    };
+    if let Err(error) = stored_file {
+        logger::error!(?error, "Failed to store the report file");
+        return ReportRunOutcome {
+            row_count,
+            file_key: None,
+            error_message: Some("Failed to store the report file".to_string()),
+        };
+    }
+
+    let error_message = match template.delivery_method {
+        enums::ReportDeliveryMethod::Email => {
+            let recipients = get_recipients(&template.recipients);
+            let failed_recipients =
+                send_report_emails(state, template, run_id, &time_range, &table, &recipients).await;
+            (failed_recipients > 0).then(|| {
+                format!(
+                    "Failed to send the report to {failed_recipients} of the {} recipients",
+                    recipients.len()
+                )
+            })
+        }
+        enums::ReportDeliveryMethod::FileStorage => None,
+    };
+
+    ReportRunOutcome {
+        row_count,
+        file_key: Some(file_key),
+        error_message,
+    }
+}
+
+async fn get_report_table(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    template: &storage::ReportTemplate,
+    time_range: TimeRange,
+) -> RouterResult<ReportTable> {
+    let dimensions = get_string_list(&template.dimensions);
+    let request = get_report_metric_request(
+        template.domain,
+        &get_string_list(&template.metrics),
+        &dimensions,
+        &template.filters,
+        time_range,
+    )?;
+    let auth = AuthInfo::MerchantLevel {
+        org_id: merchant_account.get_org_id().clone(),
+        merchant_ids: vec![merchant_account.get_id().clone()],
+    };
+
+    let buckets = match request {
+        ReportMetricRequest::Payments(request) => {
+            let ex_rates = analytics_validator::request_validator(
+                AnalyticsRequest {
+                    payment_attempt: Some(request.clone()),
+                    ..Default::default()
+                },
+                state,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            analytics::payments::get_metrics(&state.pool, &ex_rates, &auth, request)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)?
+                .query_data
+                .encode_to_value()
+        }
+        ReportMetricRequest::Refunds(request) => {
+            let ex_rates = analytics_validator::request_validator(
+                AnalyticsRequest {
+                    refund: Some(request.clone()),
+                    ..Default::default()
+                },
+                state,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            analytics::refunds::get_metrics(&state.pool, &ex_rates, &auth, request)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)?
+                .query_data
+                .encode_to_value()
+        }
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to serialize the metric buckets of the report")?;
+
+    Ok(ReportTable::from_buckets(
+        &dimensions,
+        get_json_list(buckets),
+    ))
+}
+
+/// Sends the summary of the report to each recipient, and returns the number of recipients the
+/// report could not be sent to. The email client does not support attachments, hence the email
+/// carries the first [`consts::REPORT_EMAIL_MAX_ROWS`] rows of the report and the full report is
+/// downloaded from the run.
+#[cfg(feature = "email")]
+async fn send_report_emails(
+    state: &SessionState,
+    template: &storage::ReportTemplate,
+    run_id: &str,
+    time_range: &TimeRange,
+    table: &ReportTable,
+    recipients: &[pii::Email],
+) -> usize {
+    let report_table = table.to_html(consts::REPORT_EMAIL_MAX_ROWS);
+    let mut failed_recipients = 0;
+
+    for recipient in recipients {
+        let recipient_email = match domain::UserEmail::from_pii_email(recipient.clone()) {
+            Ok(recipient_email) => recipient_email,
+            Err(error) => {
+                logger::error!(?error, "Failed to convert the recipient of the report");
+                failed_recipients += 1;
+                continue;
+            }
+        };
+        let email_contents = email_types::ScheduledReport {
+            recipient_email,
+            report_name: template.name.clone(),
+            run_id: run_id.to_string(),
+            format: template.format.to_string(),
+            start_time: time_range.start_time.to_string(),
+            end_time: time_range
+                .end_time
+                .map(|end_time| end_time.to_string())
+                .unwrap_or_default(),
+            row_count: table.rows.len(),
+            report_table: report_table.clone(),
+        };
+
+        if let Err(error) = state
+            .email_client
+            .clone()
+            .compose_and_send_email(
+                user_utils::get_base_url(state),
+                Box::new(email_contents),
+                state.conf.proxy.https_url.as_ref(),
+            )
+            .await
+        {
+            logger::error!(?error, "Failed to send the report email");
+            failed_recipients += 1;
+        }
+    }
+
+    failed_recipients
+}
+
+#[cfg(not(feature = "email"))]
+async fn send_report_emails(
+    _state: &SessionState,
+    _template: &storage::ReportTemplate,
+    _run_id: &str,
+    _time_range: &TimeRange,
+    _table: &ReportTable,
+    recipients: &[pii::Email],
+) -> usize {
+    logger::error!("Email delivery of reports is not enabled");
+    recipients.len()
+}
+
+/// Builds the metrics request of the domain of the report, which validates the metrics,
+/// dimensions and filters of the report against the metrics endpoint of the domain
+fn get_report_metric_request(
+    domain: enums::ReportDomain,
+    metrics: &[String],
+    dimensions: &[String],
+    filters: &serde_json::Value,
+    time_range: TimeRange,
+) -> RouterResult<ReportMetricRequest> {
+    if metrics.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "metrics must contain at least one metric".to_string(),
+        }));
+    }
+    let metrics = serde_json::Value::from(metrics.to_vec());
+    let dimensions = serde_json::Value::from(dimensions.to_vec());
+
+    Ok(match domain {
+        enums::ReportDomain::Payments => ReportMetricRequest::Payments(GetPaymentMetricRequest {
+            time_series: None,
+            time_range,
+            group_by_names: parse_report_field(dimensions, "dimensions", domain)?,
+            filters: parse_report_field(filters.clone(), "filters", domain)?,
+            metrics: parse_report_field(metrics, "metrics", domain)?,
+            distribution: None,
+            delta: false,
+        }),
+        enums::ReportDomain::Refunds => ReportMetricRequest::Refunds(GetRefundMetricRequest {
+            time_series: None,
+            time_range,
+            group_by_names: parse_report_field(dimensions, "dimensions", domain)?,
+            filters: parse_report_field(filters.clone(), "filters", domain)?,
+            metrics: parse_report_field(metrics, "metrics", domain)?,
+            distribution: None,
+            delta: false,
+        }),
+    })
+}
+
+fn parse_report_field<T: serde::de::DeserializeOwned>(
+    value: serde_json::Value,
+    field_name: &str,
+    domain: enums::ReportDomain,
+) -> RouterResult<T> {
+    serde_json::from_value(value).map_err(|error| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("{field_name} are not valid for {domain} reports: {error}"),
+        })
+    })
+}
+
+fn validate_report_name(name: &str) -> RouterResult<()> {
+    if name.trim().is_empty() || name.len() > 255 {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "name must be between 1 and 255 characters".to_string(),
+        }));
+    }
+    Ok(())
+}
+
+fn validate_report_delivery(
+    delivery_method: enums::ReportDeliveryMethod,
+    recipients: &[pii::Email],
+) -> RouterResult<()> {
+    match delivery_method {
+        enums::ReportDeliveryMethod::Email if cfg!(not(feature = "email")) => {
+            Err(report!(errors::ApiErrorResponse::NotSupported {
+                message: "Email delivery of reports is not enabled".to_string(),
+            }))
+        }
+        enums::ReportDeliveryMethod::Email if recipients.is_empty() => {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "recipients are required when the report is delivered by email"
+                    .to_string(),
+            }))
+        }
+        enums::ReportDeliveryMethod::Email | enums::ReportDeliveryMethod::FileStorage => Ok(()),
+    }
+}
+
+async fn find_report_template(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    template_id: &str,
+) -> RouterResult<storage::ReportTemplate> {
+    state
+        .store
+        .find_report_template_by_merchant_id_template_id(
+            merchant_context.get_merchant_account().get_id(),
+            template_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Report template does not exist in our records".to_string(),
+        })
+}
+
+fn get_report_period(frequency: enums::ReportFrequency) -> time::Duration {
+    match frequency {
+        enums::ReportFrequency::Daily => time::Duration::days(1),
+        enums::ReportFrequency::Weekly => time::Duration::weeks(1),
+    }
+}
+
+/// The last complete period of the frequency, which ends at the last midnight in UTC
+pub fn get_last_report_period(
+    frequency: enums::ReportFrequency,
+    now: time::PrimitiveDateTime,
+) -> TimeRange {
+    let end_time = now.date().midnight();
+    TimeRange {
+        start_time: end_time - get_report_period(frequency),
+        end_time: Some(end_time),
+    }
+}
+
+/// The end of the current period of the frequency, when the report of the period is generated
+pub fn get_next_report_schedule_time(
+    frequency: enums::ReportFrequency,
+    now: time::PrimitiveDateTime,
+) -> time::PrimitiveDateTime {
+    now.date().midnight() + get_report_period(frequency)
+}
+
+/// Schedules the recurring generation of the report. A template which is updated keeps its task,
+/// which picks up the latest template when it runs.
+async fn add_report_generation_task(
+    db: &dyn StorageInterface,
+    template: &storage::ReportTemplate,
+) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::ReportGenerationWorkflow;
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        REPORT_GENERATION_TASK,
+        &template.id,
+        &template.merchant_id,
+    );
+    let tracking_data = ReportGenerationTrackingData {
+        merchant_id: template.merchant_id.clone(),
+        template_id: template.id.clone(),
+    };
+    let schedule_time = get_next_report_schedule_time(template.frequency, date_time::now());
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        REPORT_GENERATION_TASK,
+        runner,
+        ["REPORT"],
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct report generation process tracker task")?;
+
+    match db.insert_process(process_tracker_entry).await {
+        Ok(_) => {
+            metrics::TASKS_ADDED_COUNT.add(
+                1,
+                router_env::metric_attributes!(("flow", "ReportGeneration")),
+            );
+            Ok(())
+        }
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to add report generation task to process tracker"),
+    }
+}
+
+fn get_string_list(value: &serde_json::Value) -> Vec<String> {
+    serde_json::from_value(value.clone()).unwrap_or_default()
+}
+
+fn get_recipients(value: &serde_json::Value) -> Vec<pii::Email> {
+    serde_json::from_value(value.clone()).unwrap_or_default()
+}
+
+fn get_json_list(value: serde_json::Value) -> Vec<serde_json::Value> {
+    match value {
+        serde_json::Value::Array(values) => values,
+        _ => Vec::new(),
+    }
+}
+
+fn get_template_response(template: storage::ReportTemplate) -> reports_api::ReportTemplateResponse {
+    reports_api::ReportTemplateResponse {
+        metrics: get_string_list(&template.metrics),
+        dimensions: get_string_list(&template.dimensions),
+        recipients: get_recipients(&template.recipients),
+        template_id: template.id,
+        merchant_id: template.merchant_id,
+        name: template.name,
+        domain: template.domain,
+        filters: template.filters,
+        format: template.format,
+        frequency: template.frequency,
+        delivery_method: template.delivery_method,
+        is_active: template.is_active,
+        created_by: template.created_by,
+        created_at: template.created_at,
+        modified_at: template.modified_at,
+    }
+}
+
+fn get_run_response(report_run: storage::ReportRun) -> reports_api::ReportRunResponse {
+    reports_api::ReportRunResponse {
+        run_id: report_run.id,
+        template_id: report_run.template_id,
+        merchant_id: report_run.merchant_id,
+        status: report_run.status,
+        triggered_by: report_run.triggered_by,
+        start_time: report_run.start_time,
+        end_time: report_run.end_time,
+        format: report_run.format,
+        row_count: report_run.row_count,
+        is_file_available: report_run.file_key.is_some(),
+        error_message: report_run.error_message,
+        created_at: report_run.created_at,
+    }
+}
@@ -0,0 +1,357 @@
+use std::collections::BTreeSet;
+
+use common_utils::errors::CustomResult;
+use error_stack::ResultExt;
+
+use crate::core::errors;
+
+/// The rows of a report, built from the metric buckets returned by analytics. Each bucket is a
+/// row, and the dimensions of the report come first among the columns.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReportTable {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<serde_json::Value>>,
+}
+
+impl ReportTable {
+    /// Builds the table from the buckets serialized as JSON objects. The columns which are not
+    /// set in any bucket are left out.
+    pub fn from_buckets(dimensions: &[String], buckets: Vec<serde_json::Value>) -> Self {
+        let buckets = buckets
+            .into_iter()
+            .filter_map(|bucket| match bucket {
+                serde_json::Value::Object(bucket) => Some(bucket),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let set_columns = buckets
+            .iter()
+            .flat_map(|bucket| {
+                bucket
+                    .iter()
+                    .filter(|(_, value)| !value.is_null())
+                    .map(|(column, _)| column.clone())
+            })
+            .collect::<BTreeSet<_>>();
+        let dimension_columns = dimensions
+            .iter()
+            .filter(|dimension| set_columns.contains(*dimension))
+            .cloned()
+            .collect::<Vec<_>>();
+        let columns = dimension_columns
+            .iter()
+            .cloned()
+            .chain(
+                set_columns
+                    .into_iter()
+                    .filter(|column| !dimension_columns.contains(column)),
+            )
+            .collect::<Vec<_>>();
+
+        let rows = buckets
+            .into_iter()
+            .map(|mut bucket| {
+                columns
+                    .iter()
+                    .map(|column| match bucket.remove(column) {
+                        Some(value @ serde_json::Value::Array(_))
+                        | Some(value @ serde_json::Value::Object(_)) => {
+                            serde_json::Value::String(value.to_string())
+                        }
+                        Some(value) => value,
+                        None => serde_json::Value::Null,
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self { columns, rows }
+    }
+
+    pub fn to_csv(&self) -> CustomResult<Vec<u8>, errors::ApiErrorResponse> {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        writer
+            .write_record(&self.columns)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to write the columns of the report")?;
+        for row in &self.rows {
+            writer
+                .write_record(row.iter().map(get_cell_text))
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to write a row of the report")?;
+        }
+        writer
+            .into_inner()
+            .map_err(|error| error.into_error())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to write the report")
+    }
+
+    /// Writes the table as the single sheet of an XLSX workbook. Numbers are written as numeric
+    /// cells and everything else as text.
+    pub fn to_xlsx(&self) -> CustomResult<Vec<u8>, errors::ApiErrorResponse> {
+        let mut sheet_data = String::new();
+        let header = self
+            .columns
+            .iter()
+            .map(|column| serde_json::Value::String(column.clone()))
+            .collect::<Vec<_>>();
+        for (row_index, row) in std::iter::once(&header).chain(&self.rows).enumerate() {
+            sheet_data.push_str(&format!(r#"<row r="{}">"#, row_index + 1));
+            for (column_index, cell) in row.iter().enumerate() {
+                let reference = format!("{}{}", get_xlsx_column_name(column_index), row_index + 1);
+                match cell {
+                    serde_json::Value::Null => {}
+                    serde_json::Value::Number(number) => {
+                        sheet_data.push_str(&format!(r#"<c r="{reference}"><v>{number}</v></c>"#))
+                    }
+                    _ => sheet_data.push_str(&format!(
+                        r#"<c r="{reference}" t="inlineStr"><is><t xml:space="preserve">{}</t></is></c>"#,
+                        escape_markup(&get_cell_text(cell))
+                    )),
+                }
+            }
+            sheet_data.push_str("</row>");
+        }
+        let sheet = format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheetData>{sheet_data}</sheetData></worksheet>"#
+        );
+
+        write_stored_zip(&[
+            ("[Content_Types].xml", XLSX_CONTENT_TYPES.as_bytes()),
+            ("_rels/.rels", XLSX_ROOT_RELATIONSHIPS.as_bytes()),
+            ("xl/workbook.xml", XLSX_WORKBOOK.as_bytes()),
+            (
+                "xl/_rels/workbook.xml.rels",
+                XLSX_WORKBOOK_RELATIONSHIPS.as_bytes(),
+            ),
+            ("xl/worksheets/sheet1.xml", sheet.as_bytes()),
+        ])
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to write the report workbook")
+    }
+
+    /// Renders at most `max_rows` rows of the table as an HTML table, for the body of an email
+    pub fn to_html(&self, max_rows: usize) -> String {
+        let header = self
+            .columns
+            .iter()
+            .map(|column| format!("<th>{}</th>", escape_markup(column)))
+            .collect::<String>();
+        let rows = self
+            .rows
+            .iter()
+            .take(max_rows)
+            .map(|row| {
+                let cells = row
+                    .iter()
+                    .map(|cell| format!("<td>{}</td>", escape_markup(&get_cell_text(cell))))
+                    .collect::<String>();
+                format!("<tr>{cells}</tr>")
+            })
+            .collect::<String>();
+        let truncation_note = if self.rows.len() > max_rows {
+            format!(
+                "<p>Showing the first {max_rows} of {} rows.</p>",
+                self.rows.len()
+            )
+        } else {
+            String::new()
+        };
+
+        format!(
+            r#"<table border="1" cellpadding="4" style="border-collapse: collapse; font-size: 0.8rem;"><tr>{header}</tr>{rows}</table>{truncation_note}"#
+        )
+    }
+}
+
+const XLSX_CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/><Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/></Types>"#;
+
+const XLSX_ROOT_RELATIONSHIPS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/></Relationships>"#;
+
+const XLSX_WORKBOOK: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><sheets><sheet name="Report" sheetId="1" r:id="rId1"/></sheets></workbook>"#;
+
+const XLSX_WORKBOOK_RELATIONSHIPS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/></Relationships>"#;
+
+fn get_cell_text(cell: &serde_json::Value) -> String {
+    match cell {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Escapes the characters which are special in both XML and HTML
+fn escape_markup(text: &str) -> String {
+    text.chars().fold(
+        String::with_capacity(text.len()),
+        |mut escaped, character| {
+            match character {
+                '&' => escaped.push_str("&amp;"),
+                '<' => escaped.push_str("&lt;"),
+                '>' => escaped.push_str("&gt;"),
+                '"' => escaped.push_str("&quot;"),
+                '\'' => escaped.push_str("&apos;"),
+                character => escaped.push(character),
+            }
+            escaped
+        },
+    )
+}
+
+/// Gets the name of a zero based column index, `A` to `Z`, then `AA` and so on
+fn get_xlsx_column_name(column_index: usize) -> String {
+    let mut name = Vec::new();
+    let mut remaining = column_index + 1;
+    while remaining > 0 {
+        let letter_index = (remaining - 1) % 26;
+        name.push(char::from(
+            b'A' + u8::try_from(letter_index).unwrap_or_default(),
+        ));
+        remaining = (remaining - 1) / 26;
+    }
+    name.iter().rev().collect()
+}
+
+/// Writes the files into a ZIP archive without compression, which is all an XLSX workbook needs.
+/// Fails when the archive doesn't fit the limits of ZIP without the ZIP64 extensions, 4 GiB and
+/// 65535 entries.
+fn write_stored_zip(files: &[(&str, &[u8])]) -> Result<Vec<u8>, std::num::TryFromIntError> {
+    // The modification date of the entries, 1980-01-01 in the MS-DOS date format
+    const DOS_DATE: u16 = 0x21;
+
+    let mut archive = Vec::new();
+    let mut central_directory = Vec::new();
+    for (name, data) in files {
+        let offset = u32::try_from(archive.len())?;
+        let crc = crc32fast::hash(data);
+        let size = u32::try_from(data.len())?;
+        let name_length = u16::try_from(name.len())?;
+
+        archive.extend_from_slice(&0x04034b50u32.to_le_bytes());
+        archive.extend_from_slice(&20u16.to_le_bytes()); // version needed to extract
+        archive.extend_from_slice(&0u16.to_le_bytes()); // flags
+        archive.extend_from_slice(&0u16.to_le_bytes()); // compression method, stored
+        archive.extend_from_slice(&0u16.to_le_bytes()); // modification time
+        archive.extend_from_slice(&DOS_DATE.to_le_bytes());
+        archive.extend_from_slice(&crc.to_le_bytes());
+        archive.extend_from_slice(&size.to_le_bytes()); // compressed size
+        archive.extend_from_slice(&size.to_le_bytes()); // uncompressed size
+        archive.extend_from_slice(&name_length.to_le_bytes());
+        archive.extend_from_slice(&0u16.to_le_bytes()); // extra field length
+        archive.extend_from_slice(name.as_bytes());
+        archive.extend_from_slice(data);
+
+        central_directory.extend_from_slice(&0x02014b50u32.to_le_bytes());
+        central_directory.extend_from_slice(&20u16.to_le_bytes()); // version made by
+        central_directory.extend_from_slice(&20u16.to_le_bytes()); // version needed to extract
+        central_directory.extend_from_slice(&0u16.to_le_bytes()); // flags
+        central_directory.extend_from_slice(&0u16.to_le_bytes()); // compression method, stored
+        central_directory.extend_from_slice(&0u16.to_le_bytes()); // modification time
+        central_directory.extend_from_slice(&DOS_DATE.to_le_bytes());
+        central_directory.extend_from_slice(&crc.to_le_bytes());
+        central_directory.extend_from_slice(&size.to_le_bytes()); // compressed size
+        central_directory.extend_from_slice(&size.to_le_bytes()); // uncompressed size
+        central_directory.extend_from_slice(&name_length.to_le_bytes());
+        central_directory.extend_from_slice(&0u16.to_le_bytes()); // extra field length
+        central_directory.extend_from_slice(&0u16.to_le_bytes()); // comment length
+        central_directory.extend_from_slice(&0u16.to_le_bytes()); // disk number
+        central_directory.extend_from_slice(&0u16.to_le_bytes()); // internal attributes
+        central_directory.extend_from_slice(&0u32.to_le_bytes()); // external attributes
+        central_directory.extend_from_slice(&offset.to_le_bytes());
+        central_directory.extend_from_slice(name.as_bytes());
+    }
+
+    let central_directory_offset = u32::try_from(archive.len())?;
+    let central_directory_size = u32::try_from(central_directory.len())?;
+    let entry_count = u16::try_from(files.len())?;
+    archive.extend_from_slice(&central_directory);
+    archive.extend_from_slice(&0x06054b50u32.to_le_bytes());
+    archive.extend_from_slice(&0u16.to_le_bytes()); // disk number
+    archive.extend_from_slice(&0u16.to_le_bytes()); // disk with the central directory
+    archive.extend_from_slice(&entry_count.to_le_bytes()); // entries on this disk
+    archive.extend_from_slice(&entry_count.to_le_bytes()); // total entries
+    archive.extend_from_slice(&central_directory_size.to_le_bytes());
+    archive.extend_from_slice(&central_directory_offset.to_le_bytes());
+    archive.extend_from_slice(&0u16.to_le_bytes()); // comment length
+    Ok(archive)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+
+    use super::*;
+
+    fn get_table() -> ReportTable {
+        ReportTable::from_buckets(
+            &["connector".to_string(), "currency".to_string()],
+            vec![
+                serde_json::json!({
+                    "payment_count": 10,
+                    "currency": "EUR",
+                    "connector": "stripe",
+                    "payment_success_rate": null,
+                }),
+                serde_json::json!({
+                    "payment_count": 4,
+                    "currency": "USD",
+                    "connector": "adyen, \"eu\"",
+                    "payment_success_rate": 50.0,
+                }),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_report_table_puts_dimensions_first_and_drops_unset_columns() {
+        let table = get_table();
+
+        assert_eq!(
+            table.columns,
+            vec![
+                "connector",
+                "currency",
+                "payment_count",
+                "payment_success_rate"
+            ]
+        );
+        assert_eq!(table.rows[0][3], serde_json::Value::Null);
+        assert_eq!(
+            String::from_utf8(table.to_csv().unwrap()).unwrap(),
+            "connector,currency,payment_count,payment_success_rate\n\
+             stripe,EUR,10,\n\
+             \"adyen, \"\"eu\"\"\",USD,4,50.0\n"
+        );
+    }
+
+    #[test]
+    fn test_report_table_xlsx_is_a_zip_archive_of_the_workbook() {
+        let xlsx = get_table().to_xlsx().unwrap();
+
+        assert_eq!(&xlsx[..4], &0x04034b50u32.to_le_bytes());
+        let end_of_central_directory = &xlsx[xlsx.len() - 22..];
+        assert_eq!(&end_of_central_directory[..4], &0x06054b50u32.to_le_bytes());
+        assert_eq!(&end_of_central_directory[10..12], &5u16.to_le_bytes());
+        let content = String::from_utf8_lossy(&xlsx);
+        assert!(content.contains(r#"<c r="C2"><v>10</v></c>"#));
+        assert!(content.contains("adyen, &quot;eu&quot;"));
+    }
+
+    #[test]
+    fn test_stored_zip_fails_beyond_the_entry_limit() {
+        let files = vec![("a", [].as_slice()); usize::from(u16::MAX) + 1];
+
+        assert!(write_stored_zip(&files).is_err());
+        assert!(write_stored_zip(&files[1..]).is_ok());
+    }
+
+    #[test]
+    fn test_xlsx_column_name() {
+        assert_eq!(get_xlsx_column_name(0), "A");
+        assert_eq!(get_xlsx_column_name(25), "Z");
+        assert_eq!(get_xlsx_column_name(26), "AA");
+        assert_eq!(get_xlsx_column_name(701), "ZZ");
+        assert_eq!(get_xlsx_column_name(702), "AAA");
+    }
+}
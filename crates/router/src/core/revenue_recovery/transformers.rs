@@ -12,7 +12,8 @@ impl ForeignFrom<AttemptStatus> for RevenueRecoveryPaymentsAttemptStatus {
             AttemptStatus::Authorized
             | AttemptStatus::Charged
             | AttemptStatus::AutoRefunded
-            | AttemptStatus::PartiallyAuthorized => Self::Succeeded,
+            | AttemptStatus::PartiallyAuthorized
+            | AttemptStatus::IncrementalAuthorizationPending => Self::Succeeded,
 
             AttemptStatus::Started
             | AttemptStatus::AuthenticationSuccessful
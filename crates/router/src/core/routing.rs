@@ -36,6 +36,8 @@ use payment_methods::helpers::StorageErrorExt;
 use rustc_hash::FxHashSet;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use storage_impl::redis::cache;
+#[cfg(feature = "v1")]
+use strum::IntoEnumIterator;
 
 #[cfg(feature = "payouts")]
 use super::payouts;
@@ -348,6 +350,7 @@ pub async fn create_routing_algorithm_under_profile(
     authentication_profile_id: Option<common_utils::id_type::ProfileId>,
     request: routing_types::RoutingConfigRequest,
     transaction_type: enums::TransactionType,
+    created_by: Option<common_utils::types::CreatedBy>,
 ) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
     use api_models::routing::StaticRoutingAlgorithm as EuclidAlgorithm;
 
@@ -378,6 +381,14 @@ pub async fn create_routing_algorithm_under_profile(
         })
         .attach_printable("Algorithm of config not given")?;
 
+    if let Some(activate_at) = request.activate_at {
+        utils::when(activate_at <= common_utils::date_time::now(), || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "activate_at must be a time in the future".to_string(),
+            })
+        })?;
+    }
+
     let algorithm_id = common_utils::generate_routing_id_of_default_length();
 
     let profile_id = request
@@ -435,6 +446,18 @@ pub async fn create_routing_algorithm_under_profile(
                     splits.into_iter().map(Into::into).collect();
                 Some(StaticRoutingAlgorithm::VolumeSplit(converted))
             }
+            EuclidAlgorithm::StickyVolumeSplit(_) => {
+                router_env::logger::info!(
+                    "decision_engine_euclid: sticky volume splits are not supported by the decision engine"
+                );
+                None
+            }
+            EuclidAlgorithm::Cost(_) => {
+                router_env::logger::info!(
+                    "decision_engine_euclid: cost algorithms are not supported by the decision engine"
+                );
+                None
+            }
             EuclidAlgorithm::ThreeDsDecisionRule(_) => {
                 router_env::logger::error!(
                     "decision_engine_euclid: ThreeDsDecisionRules are not yet implemented"
@@ -507,10 +530,34 @@ pub async fn create_routing_algorithm_under_profile(
         algorithm_for: transaction_type.to_owned(),
         decision_engine_routing_id,
     };
-    let record = db
-        .insert_routing_algorithm(algo)
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+    let routing_audit_log = helpers::RoutingAuditLogDetails {
+        action: storage_enums::RoutingAuditAction::Create,
+        transaction_type,
+        previous_algorithm_id: None,
+        new_algorithm_id: Some(algo.algorithm_id.clone()),
+        config_diff: helpers::get_routing_config_diff(
+            &serde_json::Value::Null,
+            &algo.algorithm_data,
+        ),
+        created_by: created_by.clone(),
+    };
+    let (record, _) =
+        helpers::insert_routing_algorithm_with_audit_log(db, algo, routing_audit_log).await?;
+
+    if let Some(activate_at) = request.activate_at {
+        helpers::add_routing_activation_task(
+            db,
+            storage::RoutingActivationTrackingData {
+                merchant_id: record.merchant_id.clone(),
+                profile_id: record.profile_id.clone(),
+                algorithm_id: record.algorithm_id.clone(),
+                transaction_type,
+                created_by,
+            },
+            activate_at,
+        )
+        .await?;
+    }
 
     let new_record = record.foreign_into();
 
@@ -597,6 +644,29 @@ pub async fn link_routing_config(
     authentication_profile_id: Option<common_utils::id_type::ProfileId>,
     algorithm_id: common_utils::id_type::RoutingId,
     transaction_type: enums::TransactionType,
+    created_by: Option<common_utils::types::CreatedBy>,
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    Box::pin(activate_routing_algorithm(
+        state,
+        merchant_context,
+        authentication_profile_id,
+        algorithm_id,
+        transaction_type,
+        None,
+        created_by,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+async fn activate_routing_algorithm(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    algorithm_id: common_utils::id_type::RoutingId,
+    transaction_type: enums::TransactionType,
+    rolled_back_from: Option<u32>,
+    created_by: Option<common_utils::types::CreatedBy>,
 ) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
     metrics::ROUTING_LINK_CONFIG.add(1, &[]);
     let db = state.store.as_ref();
@@ -678,6 +748,23 @@ pub async fn link_routing_config(
                 },
             )?;
 
+            let dynamic_routing_type = match routing_algorithm.name.as_str() {
+                helpers::SUCCESS_BASED_DYNAMIC_ROUTING_ALGORITHM => {
+                    Some(routing_types::DynamicRoutingType::SuccessRateBasedRouting)
+                }
+                helpers::ELIMINATION_BASED_DYNAMIC_ROUTING_ALGORITHM => {
+                    Some(routing_types::DynamicRoutingType::EliminationRouting)
+                }
+                helpers::CONTRACT_BASED_DYNAMIC_ROUTING_ALGORITHM => {
+                    Some(routing_types::DynamicRoutingType::ContractBasedRouting)
+                }
+                _ => None,
+            };
+            let previous_algorithm_id = dynamic_routing_type.and_then(|dynamic_routing_type| {
+                dynamic_routing_ref.get_algorithm_id(dynamic_routing_type)
+            });
+            let new_algorithm_id = algorithm_id.clone();
+
             if routing_algorithm.name == helpers::SUCCESS_BASED_DYNAMIC_ROUTING_ALGORITHM {
                 dynamic_routing_ref.update_algorithm_id(
                 algorithm_id,
@@ -818,20 +905,46 @@ pub async fn link_routing_config(
             );
             }
 
-            helpers::update_business_profile_active_dynamic_algorithm_ref(
+            let routing_audit_log = helpers::update_business_profile_active_dynamic_algorithm_ref(
                 db,
                 key_manager_state,
                 merchant_context.get_merchant_key_store(),
                 business_profile.clone(),
                 dynamic_routing_ref,
+                helpers::RoutingAuditLogDetails {
+                    action: storage_enums::RoutingAuditAction::Link,
+                    transaction_type,
+                    previous_algorithm_id,
+                    new_algorithm_id: Some(new_algorithm_id.clone()),
+                    config_diff: None,
+                    created_by,
+                },
+            )
+            .await?;
+
+            helpers::record_routing_version(
+                &state,
+                &business_profile,
+                &new_algorithm_id,
+                rolled_back_from,
+                &routing_algorithm.algorithm_for,
             )
             .await?;
+
+            helpers::trigger_routing_config_webhook(
+                &state,
+                &merchant_context,
+                &business_profile,
+                routing_audit_log,
+            )
+            .await;
         }
         diesel_models::enums::RoutingAlgorithmKind::Single
         | diesel_models::enums::RoutingAlgorithmKind::Priority
         | diesel_models::enums::RoutingAlgorithmKind::Advanced
         | diesel_models::enums::RoutingAlgorithmKind::VolumeSplit
-        | diesel_models::enums::RoutingAlgorithmKind::ThreeDsDecisionRule => {
+        | diesel_models::enums::RoutingAlgorithmKind::ThreeDsDecisionRule
+        | diesel_models::enums::RoutingAlgorithmKind::Cost => {
             let mut routing_ref: routing_types::RoutingAlgorithmRef = business_profile
                 .routing_algorithm
                 .clone()
@@ -860,16 +973,61 @@ pub async fn link_routing_config(
                     })
                 },
             )?;
-            routing_ref.update_algorithm_id(algorithm_id);
-            helpers::update_profile_active_algorithm_ref(
+            let previous_algorithm_id = routing_ref.algorithm_id.clone();
+            let previous_algorithm_data = match previous_algorithm_id.as_ref() {
+                Some(previous_algorithm_id) => db
+                    .find_routing_algorithm_by_profile_id_algorithm_id(
+                        business_profile.get_id(),
+                        previous_algorithm_id,
+                    )
+                    .await
+                    .map(|previous_algorithm| previous_algorithm.algorithm_data)
+                    .map_err(|error| {
+                        router_env::logger::warn!(previous_routing_algorithm_fetch_error=?error)
+                    })
+                    .ok(),
+                None => None,
+            };
+
+            routing_ref.update_algorithm_id(algorithm_id.clone());
+            let routing_audit_log = helpers::update_profile_active_algorithm_ref_with_audit_log(
                 db,
                 key_manager_state,
                 merchant_context.get_merchant_key_store(),
                 business_profile.clone(),
                 routing_ref,
+                helpers::RoutingAuditLogDetails {
+                    action: storage_enums::RoutingAuditAction::Link,
+                    transaction_type,
+                    previous_algorithm_id,
+                    new_algorithm_id: Some(algorithm_id.clone()),
+                    config_diff: helpers::get_routing_config_diff(
+                        previous_algorithm_data
+                            .as_ref()
+                            .unwrap_or(&serde_json::Value::Null),
+                        &routing_algorithm.algorithm_data,
+                    ),
+                    created_by,
+                },
+            )
+            .await?;
+
+            helpers::record_routing_version(
+                &state,
+                &business_profile,
+                &algorithm_id,
+                rolled_back_from,
                 &transaction_type,
             )
             .await?;
+
+            helpers::trigger_routing_config_webhook(
+                &state,
+                &merchant_context,
+                &business_profile,
+                routing_audit_log,
+            )
+            .await;
         }
     };
     if let Some(euclid_routing_id) = routing_algorithm.decision_engine_routing_id.clone() {
@@ -902,92 +1060,111 @@ pub async fn link_routing_config(
     ))
 }
 
-#[cfg(feature = "v2")]
-pub async fn retrieve_routing_algorithm_from_algorithm_id(
+#[cfg(feature = "v1")]
+pub async fn retrieve_routing_version_history(
     state: SessionState,
     merchant_context: domain::MerchantContext,
     authentication_profile_id: Option<common_utils::id_type::ProfileId>,
-    algorithm_id: common_utils::id_type::RoutingId,
-) -> RouterResponse<routing_types::MerchantRoutingAlgorithm> {
-    metrics::ROUTING_RETRIEVE_CONFIG.add(1, &[]);
+    request: routing_types::RoutingVersionHistoryRequest,
+) -> RouterResponse<routing_types::RoutingVersionHistory> {
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
 
-    let routing_algorithm = RoutingAlgorithmUpdate::fetch_routing_algo(
-        merchant_context.get_merchant_account().get_id(),
-        &algorithm_id,
-        db,
-    )
-    .await?;
     let business_profile = core_utils::validate_and_get_business_profile(
         db,
         key_manager_state,
         merchant_context.get_merchant_key_store(),
-        Some(&routing_algorithm.0.profile_id),
+        Some(&request.profile_id),
         merchant_context.get_merchant_account().get_id(),
     )
     .await?
     .get_required_value("Profile")
-    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: request.profile_id.get_string_repr().to_owned(),
+    })?;
 
     core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
 
-    let response = routing_types::MerchantRoutingAlgorithm::foreign_try_from(routing_algorithm.0)
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("unable to parse routing algorithm")?;
+    let history = helpers::get_routing_version_history(
+        db,
+        business_profile.get_id(),
+        &request
+            .transaction_type
+            .unwrap_or(enums::TransactionType::Payment),
+    )
+    .await?;
 
-    metrics::ROUTING_RETRIEVE_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
-    Ok(service_api::ApplicationResponse::Json(response))
+    Ok(service_api::ApplicationResponse::Json(history))
 }
 
 #[cfg(feature = "v1")]
-pub async fn retrieve_routing_algorithm_from_algorithm_id(
+pub async fn rollback_routing_config(
     state: SessionState,
     merchant_context: domain::MerchantContext,
     authentication_profile_id: Option<common_utils::id_type::ProfileId>,
-    algorithm_id: common_utils::id_type::RoutingId,
-) -> RouterResponse<routing_types::MerchantRoutingAlgorithm> {
-    metrics::ROUTING_RETRIEVE_CONFIG.add(1, &[]);
+    request: routing_types::RoutingRollbackRequest,
+    created_by: Option<common_utils::types::CreatedBy>,
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    metrics::ROUTING_ROLLBACK_CONFIG.add(1, &[]);
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
-
-    let routing_algorithm = db
-        .find_routing_algorithm_by_algorithm_id_merchant_id(
-            &algorithm_id,
-            merchant_context.get_merchant_account().get_id(),
-        )
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+    let transaction_type = request
+        .transaction_type
+        .unwrap_or(enums::TransactionType::Payment);
 
     let business_profile = core_utils::validate_and_get_business_profile(
         db,
         key_manager_state,
         merchant_context.get_merchant_key_store(),
-        Some(&routing_algorithm.profile_id),
+        Some(&request.profile_id),
         merchant_context.get_merchant_account().get_id(),
     )
     .await?
     .get_required_value("Profile")
-    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: request.profile_id.get_string_repr().to_owned(),
+    })?;
 
-    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+    core_utils::validate_profile_id_from_auth_layer(
+        authentication_profile_id.clone(),
+        &business_profile,
+    )?;
 
-    let response = routing_types::MerchantRoutingAlgorithm::foreign_try_from(routing_algorithm)
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("unable to parse routing algorithm")?;
+    let history =
+        helpers::get_routing_version_history(db, business_profile.get_id(), &transaction_type)
+            .await?;
 
-    metrics::ROUTING_RETRIEVE_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
-    Ok(service_api::ApplicationResponse::Json(response))
+    let target_version = history.get_version(request.version).ok_or(
+        errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!(
+                "Routing config version {} not found for the profile",
+                request.version
+            ),
+        },
+    )?;
+
+    let response = Box::pin(activate_routing_algorithm(
+        state,
+        merchant_context,
+        authentication_profile_id,
+        target_version.algorithm_id.clone(),
+        transaction_type,
+        Some(target_version.version),
+        created_by,
+    ))
+    .await?;
+
+    metrics::ROUTING_ROLLBACK_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(response)
 }
 
-#[cfg(feature = "v2")]
-pub async fn unlink_routing_config_under_profile(
+#[cfg(feature = "v1")]
+pub async fn list_scheduled_routing_activations(
     state: SessionState,
     merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
     profile_id: common_utils::id_type::ProfileId,
-    transaction_type: &enums::TransactionType,
-) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
-    metrics::ROUTING_UNLINK_CONFIG.add(1, &[]);
+) -> RouterResponse<routing_types::RoutingScheduledActivations> {
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
 
@@ -999,593 +1176,2109 @@ pub async fn unlink_routing_config_under_profile(
         merchant_context.get_merchant_account().get_id(),
     )
     .await?
-    .get_required_value("Profile")?;
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
 
-    let routing_algo_id = match transaction_type {
-        enums::TransactionType::Payment => business_profile.routing_algorithm_id.clone(),
-        #[cfg(feature = "payouts")]
-        enums::TransactionType::Payout => business_profile.payout_routing_algorithm_id.clone(),
-        // TODO: Handle ThreeDsAuthentication Transaction Type for Three DS Decision Rule Algorithm configuration
-        enums::TransactionType::ThreeDsAuthentication => todo!(),
-    };
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
 
-    if let Some(algorithm_id) = routing_algo_id {
-        let record = RoutingAlgorithmUpdate::fetch_routing_algo(
-            merchant_context.get_merchant_account().get_id(),
-            &algorithm_id,
-            db,
-        )
-        .await?;
-        let response = record.0.foreign_into();
-        admin::ProfileWrapper::new(business_profile)
-            .update_profile_and_invalidate_routing_config_for_active_algorithm_id_update(
-                db,
-                key_manager_state,
-                merchant_context.get_merchant_key_store(),
-                algorithm_id,
-                transaction_type,
-            )
-            .await?;
-        metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
-        Ok(service_api::ApplicationResponse::Json(response))
-    } else {
-        Err(errors::ApiErrorResponse::PreconditionFailed {
-            message: "Algorithm is already inactive".to_string(),
-        })?
-    }
+    let scheduled_activations =
+        helpers::get_routing_scheduled_activations(db, business_profile.get_id()).await?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        scheduled_activations,
+    ))
 }
 
 #[cfg(feature = "v1")]
-pub async fn unlink_routing_config(
+pub async fn cancel_scheduled_routing_activation(
     state: SessionState,
     merchant_context: domain::MerchantContext,
-    request: routing_types::RoutingConfigRequest,
     authentication_profile_id: Option<common_utils::id_type::ProfileId>,
-    transaction_type: enums::TransactionType,
-) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
-    metrics::ROUTING_UNLINK_CONFIG.add(1, &[]);
-
+    request: routing_types::RoutingScheduledActivationRequest,
+) -> RouterResponse<routing_types::RoutingScheduledActivation> {
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
 
-    let profile_id = request
-        .profile_id
-        .get_required_value("profile_id")
-        .change_context(errors::ApiErrorResponse::MissingRequiredField {
-            field_name: "profile_id",
-        })
-        .attach_printable("Profile_id not provided")?;
-
     let business_profile = core_utils::validate_and_get_business_profile(
         db,
         key_manager_state,
         merchant_context.get_merchant_key_store(),
-        Some(&profile_id),
+        Some(&request.profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: request.profile_id.get_string_repr().to_owned(),
+    })?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let scheduled_activations =
+        helpers::get_routing_scheduled_activations(db, business_profile.get_id()).await?;
+    let activation = scheduled_activations
+        .activations
+        .iter()
+        .find(|activation| activation.algorithm_id == request.algorithm_id)
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Scheduled activation not found for the routing algorithm".to_string(),
+        })?;
+
+    utils::when(!activation.is_scheduled(), || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Only a scheduled routing activation can be cancelled".to_string(),
+        })
+    })?;
+
+    let process_tracker_id = helpers::get_routing_activation_process_tracker_id(
         merchant_context.get_merchant_account().get_id(),
+        &request.algorithm_id,
+    );
+    let activation_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the process id")?;
+
+    let activation = helpers::update_routing_scheduled_activation_status(
+        db,
+        business_profile.get_id(),
+        &request.algorithm_id,
+        routing_types::RoutingActivationStatus::Cancelled,
+        None,
     )
     .await?;
 
-    match business_profile {
-        Some(business_profile) => {
+    // The activation task finishes on finding the activation cancelled, this only avoids running it
+    if let Some(activation_process) = activation_process {
+        db.as_scheduler()
+            .finish_process_with_business_status(
+                activation_process,
+                diesel_models::process_tracker::business_status::REVOKED,
+            )
+            .await
+            .map_err(|error| {
+                router_env::logger::error!(?error, "Failed to revoke the routing activation task")
+            })
+            .ok();
+    }
+
+    Ok(service_api::ApplicationResponse::Json(activation))
+}
+
+#[cfg(feature = "v1")]
+pub async fn retrieve_routing_audit_log(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    query: routing_types::RoutingAuditLogQuery,
+) -> RouterResponse<routing_types::RoutingAuditLogResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let profile_id = match query.profile_id.or(authentication_profile_id.clone()) {
+        Some(profile_id) => {
+            let business_profile = core_utils::validate_and_get_business_profile(
+                db,
+                key_manager_state,
+                merchant_context.get_merchant_key_store(),
+                Some(&profile_id),
+                merchant_context.get_merchant_account().get_id(),
+            )
+            .await?
+            .get_required_value("Profile")
+            .change_context(errors::ApiErrorResponse::ProfileNotFound {
+                id: profile_id.get_string_repr().to_owned(),
+            })?;
+
             core_utils::validate_profile_id_from_auth_layer(
                 authentication_profile_id,
                 &business_profile,
             )?;
-            let routing_algo_ref: routing_types::RoutingAlgorithmRef = match transaction_type {
-                enums::TransactionType::Payment => business_profile.routing_algorithm.clone(),
-                #[cfg(feature = "payouts")]
-                enums::TransactionType::Payout => business_profile.payout_routing_algorithm.clone(),
-                enums::TransactionType::ThreeDsAuthentication => {
-                    business_profile.three_ds_decision_rule_algorithm.clone()
-                }
-            }
-            .map(|val| val.parse_value("RoutingAlgorithmRef"))
-            .transpose()
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("unable to deserialize routing algorithm ref from merchant account")?
-            .unwrap_or_default();
 
-            let timestamp = common_utils::date_time::now_unix_timestamp();
+            Some(business_profile.get_id().to_owned())
+        }
+        None => None,
+    };
 
-            match routing_algo_ref.algorithm_id {
-                Some(algorithm_id) => {
-                    let routing_algorithm: routing_types::RoutingAlgorithmRef =
-                        routing_types::RoutingAlgorithmRef {
-                            algorithm_id: None,
-                            timestamp,
-                            config_algo_id: routing_algo_ref.config_algo_id.clone(),
-                            surcharge_config_algo_id: routing_algo_ref.surcharge_config_algo_id,
-                        };
+    let limit = query
+        .limit
+        .map_or(crate::consts::ROUTING_AUDIT_LOG_DEFAULT_LIMIT, i64::from)
+        .min(crate::consts::ROUTING_AUDIT_LOG_MAX_LIMIT);
 
-                    let record = db
-                        .find_routing_algorithm_by_profile_id_algorithm_id(
-                            &profile_id,
-                            &algorithm_id,
-                        )
-                        .await
-                        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
-                    let response = record.foreign_into();
-                    helpers::update_profile_active_algorithm_ref(
-                        db,
-                        key_manager_state,
-                        merchant_context.get_merchant_key_store(),
-                        business_profile,
-                        routing_algorithm,
-                        &transaction_type,
-                    )
-                    .await?;
+    let records = db
+        .list_routing_audit_log_by_merchant_id_constraints(
+            merchant_context.get_merchant_account().get_id(),
+            profile_id,
+            query.start_time,
+            query.end_time,
+            limit,
+            query.offset.map(i64::from).unwrap_or_default(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch routing audit log")?;
 
-                    metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
-                    Ok(service_api::ApplicationResponse::Json(response))
-                }
-                None => Err(errors::ApiErrorResponse::PreconditionFailed {
-                    message: "Algorithm is already inactive".to_string(),
-                })?,
-            }
-        }
-        None => Err(errors::ApiErrorResponse::InvalidRequestData {
-            message: "The business_profile is not present".to_string(),
-        }
-        .into()),
-    }
+    let data = records
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect::<Vec<routing_types::RoutingAuditLogRecord>>();
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingAuditLogResponse {
+            count: data.len(),
+            data,
+        },
+    ))
 }
 
-#[cfg(feature = "v2")]
-pub async fn update_default_fallback_routing(
+#[cfg(feature = "v1")]
+pub async fn retrieve_connector_health(
     state: SessionState,
     merchant_context: domain::MerchantContext,
-    profile_id: common_utils::id_type::ProfileId,
-    updated_list_of_connectors: Vec<routing_types::RoutableConnectorChoice>,
-) -> RouterResponse<Vec<routing_types::RoutableConnectorChoice>> {
-    metrics::ROUTING_UPDATE_CONFIG.add(1, &[]);
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    query: routing_types::ConnectorHealthQuery,
+) -> RouterResponse<routing_types::ConnectorHealthResponse> {
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
-    let profile = core_utils::validate_and_get_business_profile(
-        db,
-        key_manager_state,
-        merchant_context.get_merchant_key_store(),
-        Some(&profile_id),
-        merchant_context.get_merchant_account().get_id(),
-    )
-    .await?
-    .get_required_value("Profile")?;
-    let profile_wrapper = admin::ProfileWrapper::new(profile);
-    let default_list_of_connectors =
-        profile_wrapper.get_default_fallback_list_of_connector_under_profile()?;
 
-    utils::when(
-        default_list_of_connectors.len() != updated_list_of_connectors.len(),
-        || {
-            Err(errors::ApiErrorResponse::PreconditionFailed {
-                message: "current config and updated config have different lengths".to_string(),
-            })
-        },
-    )?;
+    let profile_id = match query.profile_id.or(authentication_profile_id.clone()) {
+        Some(profile_id) => {
+            let business_profile = core_utils::validate_and_get_business_profile(
+                db,
+                key_manager_state,
+                merchant_context.get_merchant_key_store(),
+                Some(&profile_id),
+                merchant_context.get_merchant_account().get_id(),
+            )
+            .await?
+            .get_required_value("Profile")
+            .change_context(errors::ApiErrorResponse::ProfileNotFound {
+                id: profile_id.get_string_repr().to_owned(),
+            })?;
 
-    let existing_set_of_default_connectors: FxHashSet<String> = FxHashSet::from_iter(
-        default_list_of_connectors
-            .iter()
-            .map(|conn_choice| conn_choice.to_string()),
-    );
-    let updated_set_of_default_connectors: FxHashSet<String> = FxHashSet::from_iter(
-        updated_list_of_connectors
-            .iter()
-            .map(|conn_choice| conn_choice.to_string()),
-    );
+            core_utils::validate_profile_id_from_auth_layer(
+                authentication_profile_id,
+                &business_profile,
+            )?;
 
-    let symmetric_diff_between_existing_and_updated_connectors: Vec<String> =
-        existing_set_of_default_connectors
-            .symmetric_difference(&updated_set_of_default_connectors)
-            .cloned()
-            .collect();
+            Some(business_profile.get_id().to_owned())
+        }
+        None => None,
+    };
 
-    utils::when(
-        !symmetric_diff_between_existing_and_updated_connectors.is_empty(),
-        || {
-            Err(errors::ApiErrorResponse::InvalidRequestData {
-                message: format!(
-                    "connector mismatch between old and new configs ({})",
-                    symmetric_diff_between_existing_and_updated_connectors.join(", ")
-                ),
-            })
-        },
-    )?;
-    profile_wrapper
-        .update_default_fallback_routing_of_connectors_under_profile(
-            db,
-            &updated_list_of_connectors,
+    let merchant_connector_accounts = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
             key_manager_state,
+            merchant_context.get_merchant_account().get_id(),
+            false,
             merchant_context.get_merchant_key_store(),
         )
-        .await?;
+        .await
+        .change_context(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_context
+                .get_merchant_account()
+                .get_id()
+                .get_string_repr()
+                .to_owned(),
+        })?
+        .into_iter()
+        .filter(|mca| {
+            mca.connector_type == enums::ConnectorType::PaymentProcessor
+                && profile_id
+                    .as_ref()
+                    .is_none_or(|profile_id| mca.profile_id == *profile_id)
+        })
+        .collect::<Vec<_>>();
+
+    let mut connectors = Vec::with_capacity(merchant_connector_accounts.len());
+    for merchant_connector_account in merchant_connector_accounts.iter() {
+        connectors
+            .push(helpers::get_connector_health_status(&state, merchant_connector_account).await?);
+    }
 
-    metrics::ROUTING_UPDATE_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
     Ok(service_api::ApplicationResponse::Json(
-        updated_list_of_connectors,
+        routing_types::ConnectorHealthResponse {
+            enabled: state.conf.connector_health.enabled,
+            connectors,
+        },
     ))
 }
 
-#[cfg(feature = "v1")]
-pub async fn update_default_routing_config(
+#[cfg(feature = "v2")]
+pub async fn retrieve_routing_algorithm_from_algorithm_id(
     state: SessionState,
     merchant_context: domain::MerchantContext,
-    updated_config: Vec<routing_types::RoutableConnectorChoice>,
-    transaction_type: &enums::TransactionType,
-) -> RouterResponse<Vec<routing_types::RoutableConnectorChoice>> {
-    metrics::ROUTING_UPDATE_CONFIG.add(1, &[]);
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    algorithm_id: common_utils::id_type::RoutingId,
+) -> RouterResponse<routing_types::MerchantRoutingAlgorithm> {
+    metrics::ROUTING_RETRIEVE_CONFIG.add(1, &[]);
     let db = state.store.as_ref();
-    let default_config = helpers::get_merchant_default_config(
+    let key_manager_state = &(&state).into();
+
+    let routing_algorithm = RoutingAlgorithmUpdate::fetch_routing_algo(
+        merchant_context.get_merchant_account().get_id(),
+        &algorithm_id,
         db,
-        merchant_context
-            .get_merchant_account()
-            .get_id()
-            .get_string_repr(),
-        transaction_type,
     )
     .await?;
-
-    utils::when(default_config.len() != updated_config.len(), || {
-        Err(errors::ApiErrorResponse::PreconditionFailed {
-            message: "current config and updated config have different lengths".to_string(),
-        })
-    })?;
-
-    let existing_set: FxHashSet<String> =
-        FxHashSet::from_iter(default_config.iter().map(|c| c.to_string()));
-    let updated_set: FxHashSet<String> =
-        FxHashSet::from_iter(updated_config.iter().map(|c| c.to_string()));
-
-    let symmetric_diff: Vec<String> = existing_set
-        .symmetric_difference(&updated_set)
-        .cloned()
-        .collect();
-
-    utils::when(!symmetric_diff.is_empty(), || {
-        Err(errors::ApiErrorResponse::InvalidRequestData {
-            message: format!(
-                "connector mismatch between old and new configs ({})",
-                symmetric_diff.join(", ")
-            ),
-        })
-    })?;
-
-    helpers::update_merchant_default_config(
+    let business_profile = core_utils::validate_and_get_business_profile(
         db,
-        merchant_context
-            .get_merchant_account()
-            .get_id()
-            .get_string_repr(),
-        updated_config.clone(),
-        transaction_type,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&routing_algorithm.0.profile_id),
+        merchant_context.get_merchant_account().get_id(),
     )
-    .await?;
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
-    metrics::ROUTING_UPDATE_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
-    Ok(service_api::ApplicationResponse::Json(updated_config))
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let response = routing_types::MerchantRoutingAlgorithm::foreign_try_from(routing_algorithm.0)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to parse routing algorithm")?;
+
+    metrics::ROUTING_RETRIEVE_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(response))
 }
 
-#[cfg(feature = "v2")]
-pub async fn retrieve_default_fallback_algorithm_for_profile(
+#[cfg(feature = "v1")]
+pub async fn retrieve_routing_algorithm_from_algorithm_id(
     state: SessionState,
     merchant_context: domain::MerchantContext,
-    profile_id: common_utils::id_type::ProfileId,
-) -> RouterResponse<Vec<routing_types::RoutableConnectorChoice>> {
-    metrics::ROUTING_RETRIEVE_DEFAULT_CONFIG.add(1, &[]);
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    algorithm_id: common_utils::id_type::RoutingId,
+) -> RouterResponse<routing_types::MerchantRoutingAlgorithm> {
+    metrics::ROUTING_RETRIEVE_CONFIG.add(1, &[]);
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
-    let profile = core_utils::validate_and_get_business_profile(
+
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(
+            &algorithm_id,
+            merchant_context.get_merchant_account().get_id(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
         db,
         key_manager_state,
         merchant_context.get_merchant_key_store(),
-        Some(&profile_id),
+        Some(&routing_algorithm.profile_id),
         merchant_context.get_merchant_account().get_id(),
     )
     .await?
-    .get_required_value("Profile")?;
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
-    let connectors_choice = admin::ProfileWrapper::new(profile)
-        .get_default_fallback_list_of_connector_under_profile()?;
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
 
-    metrics::ROUTING_RETRIEVE_DEFAULT_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
-    Ok(service_api::ApplicationResponse::Json(connectors_choice))
+    let response = routing_types::MerchantRoutingAlgorithm::foreign_try_from(routing_algorithm)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to parse routing algorithm")?;
+
+    metrics::ROUTING_RETRIEVE_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(response))
 }
 
 #[cfg(feature = "v1")]
-pub async fn retrieve_default_routing_config(
+pub async fn diff_routing_algorithms(
     state: SessionState,
-    profile_id: Option<common_utils::id_type::ProfileId>,
     merchant_context: domain::MerchantContext,
-    transaction_type: &enums::TransactionType,
-) -> RouterResponse<Vec<routing_types::RoutableConnectorChoice>> {
-    metrics::ROUTING_RETRIEVE_DEFAULT_CONFIG.add(1, &[]);
-    let db = state.store.as_ref();
-    let id = profile_id
-        .map(|profile_id| profile_id.get_string_repr().to_owned())
-        .unwrap_or_else(|| {
-            merchant_context
-                .get_merchant_account()
-                .get_id()
-                .get_string_repr()
-                .to_string()
-        });
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    request: routing_types::RoutingAlgorithmDiffRequest,
+) -> RouterResponse<routing_types::RoutingAlgorithmDiffResponse> {
+    let algorithm = get_static_routing_algorithm_for_diff(
+        &state,
+        &merchant_context,
+        authentication_profile_id.clone(),
+        &request.algorithm_id,
+    )
+    .await?;
+    let other_algorithm = get_static_routing_algorithm_for_diff(
+        &state,
+        &merchant_context,
+        authentication_profile_id,
+        &request.other_algorithm_id,
+    )
+    .await?;
 
-    helpers::get_merchant_default_config(db, &id, transaction_type)
-        .await
-        .map(|conn_choice| {
-            metrics::ROUTING_RETRIEVE_DEFAULT_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
-            service_api::ApplicationResponse::Json(conn_choice)
-        })
+    let response = helpers::get_routing_algorithm_diff(
+        request.algorithm_id,
+        &algorithm,
+        request.other_algorithm_id,
+        &other_algorithm,
+    )?;
+
+    Ok(service_api::ApplicationResponse::Json(response))
 }
 
-#[cfg(feature = "v2")]
-pub async fn retrieve_routing_config_under_profile(
-    state: SessionState,
-    merchant_context: domain::MerchantContext,
-    query_params: RoutingRetrieveQuery,
-    profile_id: common_utils::id_type::ProfileId,
-    transaction_type: &enums::TransactionType,
-) -> RouterResponse<routing_types::LinkedRoutingConfigRetrieveResponse> {
-    metrics::ROUTING_RETRIEVE_LINK_CONFIG.add(1, &[]);
+#[cfg(feature = "v1")]
+async fn get_static_routing_algorithm_for_diff(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    algorithm_id: &common_utils::id_type::RoutingId,
+) -> RouterResult<routing_types::StaticRoutingAlgorithm> {
     let db = state.store.as_ref();
-    let key_manager_state = &(&state).into();
+    let key_manager_state = &state.into();
+
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(
+            algorithm_id,
+            merchant_context.get_merchant_account().get_id(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
     let business_profile = core_utils::validate_and_get_business_profile(
         db,
         key_manager_state,
         merchant_context.get_merchant_key_store(),
-        Some(&profile_id),
+        Some(&routing_algorithm.profile_id),
         merchant_context.get_merchant_account().get_id(),
     )
     .await?
-    .get_required_value("Profile")?;
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
-    let record = db
-        .list_routing_algorithm_metadata_by_profile_id(
-            business_profile.get_id(),
-            i64::from(query_params.limit.unwrap_or_default()),
-            i64::from(query_params.offset.unwrap_or_default()),
-        )
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
 
-    let active_algorithms = record
-        .into_iter()
-        .filter(|routing_rec| &routing_rec.algorithm_for == transaction_type)
-        .map(|routing_algo| routing_algo.foreign_into())
-        .collect::<Vec<_>>();
+    utils::when(
+        routing_algorithm.kind == storage_enums::RoutingAlgorithmKind::Dynamic,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Routing algorithm {} is a dynamic routing algorithm, only static routing algorithms can be compared",
+                    algorithm_id.get_string_repr()
+                ),
+            })
+        },
+    )?;
 
-    metrics::ROUTING_RETRIEVE_LINK_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
-    Ok(service_api::ApplicationResponse::Json(
-        routing_types::LinkedRoutingConfigRetrieveResponse::ProfileBased(active_algorithms),
-    ))
+    routing_algorithm
+        .algorithm_data
+        .parse_value::<routing_types::StaticRoutingAlgorithm>("RoutingAlgorithm")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to parse routing algorithm")
 }
 
 #[cfg(feature = "v1")]
-pub async fn retrieve_linked_routing_config(
+pub async fn simulate_routing_algorithm(
     state: SessionState,
     merchant_context: domain::MerchantContext,
     authentication_profile_id: Option<common_utils::id_type::ProfileId>,
-    query_params: routing_types::RoutingRetrieveLinkQuery,
-    transaction_type: enums::TransactionType,
-) -> RouterResponse<routing_types::LinkedRoutingConfigRetrieveResponse> {
-    metrics::ROUTING_RETRIEVE_LINK_CONFIG.add(1, &[]);
-
+    request: routing_types::RoutingSimulateRequest,
+) -> RouterResponse<routing_types::RoutingSimulateResponse> {
+    metrics::ROUTING_SIMULATE_CONFIG.add(1, &[]);
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
-    let merchant_key_store = merchant_context.get_merchant_key_store();
-    let merchant_id = merchant_context.get_merchant_account().get_id();
 
-    // Get business profiles
-    let business_profiles = if let Some(profile_id) = query_params.profile_id {
-        core_utils::validate_and_get_business_profile(
-            db,
-            key_manager_state,
-            merchant_key_store,
-            Some(&profile_id),
-            merchant_id,
-        )
-        .await?
-        .map(|profile| vec![profile])
-        .get_required_value("Profile")
-        .change_context(errors::ApiErrorResponse::ProfileNotFound {
-            id: profile_id.get_string_repr().to_owned(),
-        })?
-    } else {
-        let business_profile = db
-            .list_profile_by_merchant_id(key_manager_state, merchant_key_store, merchant_id)
-            .await
-            .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
-        core_utils::filter_objects_based_on_profile_id_list(
-            authentication_profile_id.map(|profile_id| vec![profile_id]),
-            business_profile,
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(
+            &request.algorithm_id,
+            merchant_context.get_merchant_account().get_id(),
         )
-    };
-
-    let mut active_algorithms = Vec::new();
-
-    for business_profile in business_profiles {
-        let profile_id = business_profile.get_id();
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
-        // Handle static routing algorithm
-        let routing_ref: routing_types::RoutingAlgorithmRef = match transaction_type {
-            enums::TransactionType::Payment => &business_profile.routing_algorithm,
-            #[cfg(feature = "payouts")]
-            enums::TransactionType::Payout => &business_profile.payout_routing_algorithm,
-            enums::TransactionType::ThreeDsAuthentication => {
-                &business_profile.three_ds_decision_rule_algorithm
-            }
-        }
-        .clone()
-        .map(|val| val.parse_value("RoutingAlgorithmRef"))
-        .transpose()
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("unable to deserialize routing algorithm ref from merchant account")?
-        .unwrap_or_default();
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&routing_algorithm.profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
-        if let Some(algorithm_id) = routing_ref.algorithm_id {
-            let record = db
-                .find_routing_algorithm_metadata_by_algorithm_id_profile_id(
-                    &algorithm_id,
-                    profile_id,
-                )
-                .await
-                .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
-            let hs_records: Vec<routing_types::RoutingDictionaryRecord> =
-                vec![record.foreign_into()];
-            let de_records = retrieve_decision_engine_active_rules(
-                &state,
-                &transaction_type,
-                profile_id.clone(),
-                hs_records.clone(),
-            )
-            .await;
-            compare_and_log_result(
-                de_records.clone(),
-                hs_records.clone(),
-                "list_active_routing".to_string(),
-            );
-            active_algorithms.append(
-                &mut select_routing_result(&state, &business_profile, hs_records, de_records).await,
-            );
-        }
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
 
-        // Handle dynamic routing algorithms
-        let dynamic_routing_ref: routing_types::DynamicRoutingAlgorithmRef = business_profile
-            .dynamic_routing_algorithm
-            .clone()
-            .map(|val| val.parse_value("DynamicRoutingAlgorithmRef"))
-            .transpose()
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable(
-                "unable to deserialize dynamic routing algorithm ref from business profile",
-            )?
-            .unwrap_or_default();
+    utils::when(
+        routing_algorithm.kind == diesel_models::enums::RoutingAlgorithmKind::Dynamic,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Simulation is only supported for static routing algorithms".to_string(),
+            })
+        },
+    )?;
 
-        // Collect all dynamic algorithm IDs
-        let mut dynamic_algorithm_ids = Vec::new();
+    let algorithm: routing_types::StaticRoutingAlgorithm = routing_algorithm
+        .algorithm_data
+        .parse_value("RoutingAlgorithm")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to deserialize routing algorithm")?;
 
-        if let Some(sba) = &dynamic_routing_ref.success_based_algorithm {
-            if let Some(id) = &sba.algorithm_id_with_timestamp.algorithm_id {
-                dynamic_algorithm_ids.push(id.clone());
-            }
-        }
-        if let Some(era) = &dynamic_routing_ref.elimination_routing_algorithm {
-            if let Some(id) = &era.algorithm_id_with_timestamp.algorithm_id {
-                dynamic_algorithm_ids.push(id.clone());
-            }
-        }
-        if let Some(cbr) = &dynamic_routing_ref.contract_based_routing {
-            if let Some(id) = &cbr.algorithm_id_with_timestamp.algorithm_id {
-                dynamic_algorithm_ids.push(id.clone());
-            }
-        }
+    let mut backend_input = payments_routing::make_dsl_input_for_simulation(&request.payment);
+    backend_input.payment.normalized_amount = payments_routing::get_normalized_routing_amount(
+        &state,
+        backend_input.payment.amount,
+        backend_input.payment.currency,
+    )
+    .await;
 
-        // Fetch all dynamic algorithms
-        for algorithm_id in dynamic_algorithm_ids {
-            let record = db
-                .find_routing_algorithm_metadata_by_algorithm_id_profile_id(
-                    &algorithm_id,
-                    profile_id,
-                )
-                .await
-                .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
-            if record.algorithm_for == transaction_type {
-                active_algorithms.push(record.foreign_into());
-            }
-        }
-    }
+    let (connectors, volume_splits) =
+        payments_routing::perform_static_routing_simulation(&state, algorithm, backend_input)
+            .await
+            .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Unable to simulate the routing algorithm for the given payment"
+                    .to_string(),
+            })?;
 
-    metrics::ROUTING_RETRIEVE_LINK_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+    metrics::ROUTING_SIMULATE_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
     Ok(service_api::ApplicationResponse::Json(
-        routing_types::LinkedRoutingConfigRetrieveResponse::ProfileBased(active_algorithms),
+        routing_types::RoutingSimulateResponse {
+            algorithm_id: routing_algorithm.algorithm_id,
+            kind: routing_algorithm.kind.foreign_into(),
+            connectors,
+            volume_splits,
+        },
     ))
 }
 
-pub async fn retrieve_decision_engine_active_rules(
-    state: &SessionState,
-    transaction_type: &enums::TransactionType,
-    profile_id: common_utils::id_type::ProfileId,
-    hs_records: Vec<routing_types::RoutingDictionaryRecord>,
-) -> Vec<routing_types::RoutingDictionaryRecord> {
-    let mut de_records =
-        list_de_euclid_active_routing_algorithm(state, profile_id.get_string_repr().to_owned())
-            .await
-            .map_err(|e| {
-                router_env::logger::error!(?e, "Failed to list DE Euclid active routing algorithm");
-            })
-            .ok() // Avoid throwing error if Decision Engine is not available or other errors thrown
-            .unwrap_or_default();
-    // Use Hs records to list the dynamic algorithms as DE is not supporting dynamic algorithms in HS standard
-    let mut dynamic_algos = hs_records
-        .into_iter()
-        .filter(|record| record.kind == routing_types::RoutingAlgorithmKind::Dynamic)
-        .collect::<Vec<_>>();
-    de_records.append(&mut dynamic_algos);
-    de_records
-        .into_iter()
-        .filter(|r| r.algorithm_for == Some(*transaction_type))
-        .collect::<Vec<_>>()
-}
-// List all the default fallback algorithms under all the profile under a merchant
-pub async fn retrieve_default_routing_config_for_profiles(
+#[cfg(feature = "v2")]
+pub async fn unlink_routing_config_under_profile(
     state: SessionState,
     merchant_context: domain::MerchantContext,
+    profile_id: common_utils::id_type::ProfileId,
     transaction_type: &enums::TransactionType,
-) -> RouterResponse<Vec<routing_types::ProfileDefaultRoutingConfig>> {
-    metrics::ROUTING_RETRIEVE_CONFIG_FOR_PROFILE.add(1, &[]);
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    metrics::ROUTING_UNLINK_CONFIG.add(1, &[]);
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
 
-    let all_profiles = db
-        .list_profile_by_merchant_id(
-            key_manager_state,
-            merchant_context.get_merchant_key_store(),
-            merchant_context.get_merchant_account().get_id(),
-        )
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)
-        .attach_printable("error retrieving all business profiles for merchant")?;
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")?;
+
+    let routing_algo_id = match transaction_type {
+        enums::TransactionType::Payment => business_profile.routing_algorithm_id.clone(),
+        #[cfg(feature = "payouts")]
+        enums::TransactionType::Payout => business_profile.payout_routing_algorithm_id.clone(),
+        // TODO: Handle ThreeDsAuthentication Transaction Type for Three DS Decision Rule Algorithm configuration
+        enums::TransactionType::ThreeDsAuthentication => todo!(),
+    };
+
+    if let Some(algorithm_id) = routing_algo_id {
+        let record = RoutingAlgorithmUpdate::fetch_routing_algo(
+            merchant_context.get_merchant_account().get_id(),
+            &algorithm_id,
+            db,
+        )
+        .await?;
+        let response = record.0.foreign_into();
+        admin::ProfileWrapper::new(business_profile)
+            .update_profile_and_invalidate_routing_config_for_active_algorithm_id_update(
+                db,
+                key_manager_state,
+                merchant_context.get_merchant_key_store(),
+                algorithm_id,
+                transaction_type,
+            )
+            .await?;
+        metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+        Ok(service_api::ApplicationResponse::Json(response))
+    } else {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Algorithm is already inactive".to_string(),
+        })?
+    }
+}
+
+#[cfg(feature = "v1")]
+pub async fn unlink_routing_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: routing_types::RoutingConfigRequest,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    transaction_type: enums::TransactionType,
+    created_by: Option<common_utils::types::CreatedBy>,
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    metrics::ROUTING_UNLINK_CONFIG.add(1, &[]);
+
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let profile_id = request
+        .profile_id
+        .get_required_value("profile_id")
+        .change_context(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "profile_id",
+        })
+        .attach_printable("Profile_id not provided")?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?;
+
+    match business_profile {
+        Some(business_profile) => {
+            core_utils::validate_profile_id_from_auth_layer(
+                authentication_profile_id,
+                &business_profile,
+            )?;
+            let routing_algo_ref: routing_types::RoutingAlgorithmRef = match transaction_type {
+                enums::TransactionType::Payment => business_profile.routing_algorithm.clone(),
+                #[cfg(feature = "payouts")]
+                enums::TransactionType::Payout => business_profile.payout_routing_algorithm.clone(),
+                enums::TransactionType::ThreeDsAuthentication => {
+                    business_profile.three_ds_decision_rule_algorithm.clone()
+                }
+            }
+            .map(|val| val.parse_value("RoutingAlgorithmRef"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("unable to deserialize routing algorithm ref from merchant account")?
+            .unwrap_or_default();
+
+            let timestamp = common_utils::date_time::now_unix_timestamp();
+
+            match routing_algo_ref.algorithm_id {
+                Some(algorithm_id) => {
+                    let routing_algorithm: routing_types::RoutingAlgorithmRef =
+                        routing_types::RoutingAlgorithmRef {
+                            algorithm_id: None,
+                            timestamp,
+                            config_algo_id: routing_algo_ref.config_algo_id.clone(),
+                            surcharge_config_algo_id: routing_algo_ref.surcharge_config_algo_id,
+                            three_ds_decision_config_algo_id: routing_algo_ref
+                                .three_ds_decision_config_algo_id,
+                        };
+
+                    let record = db
+                        .find_routing_algorithm_by_profile_id_algorithm_id(
+                            &profile_id,
+                            &algorithm_id,
+                        )
+                        .await
+                        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+                    let config_diff = helpers::get_routing_config_diff(
+                        &record.algorithm_data,
+                        &serde_json::Value::Null,
+                    );
+                    let response = record.foreign_into();
+                    let routing_audit_log =
+                        helpers::update_profile_active_algorithm_ref_with_audit_log(
+                            db,
+                            key_manager_state,
+                            merchant_context.get_merchant_key_store(),
+                            business_profile.clone(),
+                            routing_algorithm,
+                            helpers::RoutingAuditLogDetails {
+                                action: storage_enums::RoutingAuditAction::Unlink,
+                                transaction_type,
+                                previous_algorithm_id: Some(algorithm_id),
+                                new_algorithm_id: None,
+                                config_diff,
+                                created_by,
+                            },
+                        )
+                        .await?;
+                    helpers::trigger_routing_config_webhook(
+                        &state,
+                        &merchant_context,
+                        &business_profile,
+                        routing_audit_log,
+                    )
+                    .await;
+
+                    metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+                    Ok(service_api::ApplicationResponse::Json(response))
+                }
+                None => Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Algorithm is already inactive".to_string(),
+                })?,
+            }
+        }
+        None => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The business_profile is not present".to_string(),
+        }
+        .into()),
+    }
+}
+
+#[cfg(feature = "v2")]
+pub async fn update_default_fallback_routing(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: common_utils::id_type::ProfileId,
+    updated_list_of_connectors: Vec<routing_types::RoutableConnectorChoice>,
+) -> RouterResponse<Vec<routing_types::RoutableConnectorChoice>> {
+    metrics::ROUTING_UPDATE_CONFIG.add(1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")?;
+    let profile_wrapper = admin::ProfileWrapper::new(profile);
+    let default_list_of_connectors =
+        profile_wrapper.get_default_fallback_list_of_connector_under_profile()?;
+
+    utils::when(
+        default_list_of_connectors.len() != updated_list_of_connectors.len(),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "current config and updated config have different lengths".to_string(),
+            })
+        },
+    )?;
+
+    let existing_set_of_default_connectors: FxHashSet<String> = FxHashSet::from_iter(
+        default_list_of_connectors
+            .iter()
+            .map(|conn_choice| conn_choice.to_string()),
+    );
+    let updated_set_of_default_connectors: FxHashSet<String> = FxHashSet::from_iter(
+        updated_list_of_connectors
+            .iter()
+            .map(|conn_choice| conn_choice.to_string()),
+    );
+
+    let symmetric_diff_between_existing_and_updated_connectors: Vec<String> =
+        existing_set_of_default_connectors
+            .symmetric_difference(&updated_set_of_default_connectors)
+            .cloned()
+            .collect();
+
+    utils::when(
+        !symmetric_diff_between_existing_and_updated_connectors.is_empty(),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "connector mismatch between old and new configs ({})",
+                    symmetric_diff_between_existing_and_updated_connectors.join(", ")
+                ),
+            })
+        },
+    )?;
+    profile_wrapper
+        .update_default_fallback_routing_of_connectors_under_profile(
+            db,
+            &updated_list_of_connectors,
+            key_manager_state,
+            merchant_context.get_merchant_key_store(),
+        )
+        .await?;
+
+    metrics::ROUTING_UPDATE_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        updated_list_of_connectors,
+    ))
+}
+
+#[cfg(feature = "v1")]
+pub async fn update_default_routing_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    updated_config: Vec<routing_types::RoutableConnectorChoice>,
+    transaction_type: &enums::TransactionType,
+) -> RouterResponse<Vec<routing_types::RoutableConnectorChoice>> {
+    metrics::ROUTING_UPDATE_CONFIG.add(1, &[]);
+    let db = state.store.as_ref();
+    let default_config = helpers::get_merchant_default_config(
+        db,
+        merchant_context
+            .get_merchant_account()
+            .get_id()
+            .get_string_repr(),
+        transaction_type,
+    )
+    .await?;
+
+    utils::when(default_config.len() != updated_config.len(), || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "current config and updated config have different lengths".to_string(),
+        })
+    })?;
+
+    let existing_set: FxHashSet<String> =
+        FxHashSet::from_iter(default_config.iter().map(|c| c.to_string()));
+    let updated_set: FxHashSet<String> =
+        FxHashSet::from_iter(updated_config.iter().map(|c| c.to_string()));
+
+    let symmetric_diff: Vec<String> = existing_set
+        .symmetric_difference(&updated_set)
+        .cloned()
+        .collect();
+
+    utils::when(!symmetric_diff.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "connector mismatch between old and new configs ({})",
+                symmetric_diff.join(", ")
+            ),
+        })
+    })?;
+
+    helpers::update_merchant_default_config(
+        db,
+        merchant_context
+            .get_merchant_account()
+            .get_id()
+            .get_string_repr(),
+        updated_config.clone(),
+        transaction_type,
+    )
+    .await?;
+
+    metrics::ROUTING_UPDATE_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(updated_config))
+}
+
+#[cfg(feature = "v2")]
+pub async fn retrieve_default_fallback_algorithm_for_profile(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: common_utils::id_type::ProfileId,
+) -> RouterResponse<Vec<routing_types::RoutableConnectorChoice>> {
+    metrics::ROUTING_RETRIEVE_DEFAULT_CONFIG.add(1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")?;
+
+    let connectors_choice = admin::ProfileWrapper::new(profile)
+        .get_default_fallback_list_of_connector_under_profile()?;
+
+    metrics::ROUTING_RETRIEVE_DEFAULT_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(connectors_choice))
+}
+
+#[cfg(feature = "v1")]
+pub async fn retrieve_default_routing_config(
+    state: SessionState,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    merchant_context: domain::MerchantContext,
+    transaction_type: &enums::TransactionType,
+) -> RouterResponse<Vec<routing_types::RoutableConnectorChoice>> {
+    metrics::ROUTING_RETRIEVE_DEFAULT_CONFIG.add(1, &[]);
+    let db = state.store.as_ref();
+    let id = profile_id
+        .map(|profile_id| profile_id.get_string_repr().to_owned())
+        .unwrap_or_else(|| {
+            merchant_context
+                .get_merchant_account()
+                .get_id()
+                .get_string_repr()
+                .to_string()
+        });
+
+    helpers::get_merchant_default_config(db, &id, transaction_type)
+        .await
+        .map(|conn_choice| {
+            metrics::ROUTING_RETRIEVE_DEFAULT_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+            service_api::ApplicationResponse::Json(conn_choice)
+        })
+}
+
+#[cfg(feature = "v2")]
+pub async fn retrieve_routing_config_under_profile(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    query_params: RoutingRetrieveQuery,
+    profile_id: common_utils::id_type::ProfileId,
+    transaction_type: &enums::TransactionType,
+) -> RouterResponse<routing_types::LinkedRoutingConfigRetrieveResponse> {
+    metrics::ROUTING_RETRIEVE_LINK_CONFIG.add(1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")?;
+
+    let record = db
+        .list_routing_algorithm_metadata_by_profile_id(
+            business_profile.get_id(),
+            i64::from(query_params.limit.unwrap_or_default()),
+            i64::from(query_params.offset.unwrap_or_default()),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    let active_algorithms = record
+        .into_iter()
+        .filter(|routing_rec| &routing_rec.algorithm_for == transaction_type)
+        .map(|routing_algo| routing_algo.foreign_into())
+        .collect::<Vec<_>>();
+
+    metrics::ROUTING_RETRIEVE_LINK_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::LinkedRoutingConfigRetrieveResponse::ProfileBased(active_algorithms),
+    ))
+}
+
+#[cfg(feature = "v1")]
+pub async fn retrieve_linked_routing_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    query_params: routing_types::RoutingRetrieveLinkQuery,
+    transaction_type: enums::TransactionType,
+) -> RouterResponse<routing_types::LinkedRoutingConfigRetrieveResponse> {
+    metrics::ROUTING_RETRIEVE_LINK_CONFIG.add(1, &[]);
+
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let merchant_key_store = merchant_context.get_merchant_key_store();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    // Get business profiles
+    let business_profiles = if let Some(profile_id) = query_params.profile_id {
+        core_utils::validate_and_get_business_profile(
+            db,
+            key_manager_state,
+            merchant_key_store,
+            Some(&profile_id),
+            merchant_id,
+        )
+        .await?
+        .map(|profile| vec![profile])
+        .get_required_value("Profile")
+        .change_context(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?
+    } else {
+        let business_profile = db
+            .list_profile_by_merchant_id(key_manager_state, merchant_key_store, merchant_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+        core_utils::filter_objects_based_on_profile_id_list(
+            authentication_profile_id.map(|profile_id| vec![profile_id]),
+            business_profile,
+        )
+    };
+
+    let mut active_algorithms = Vec::new();
+
+    for business_profile in business_profiles {
+        let profile_id = business_profile.get_id();
+
+        // Handle static routing algorithm
+        let routing_ref: routing_types::RoutingAlgorithmRef = match transaction_type {
+            enums::TransactionType::Payment => &business_profile.routing_algorithm,
+            #[cfg(feature = "payouts")]
+            enums::TransactionType::Payout => &business_profile.payout_routing_algorithm,
+            enums::TransactionType::ThreeDsAuthentication => {
+                &business_profile.three_ds_decision_rule_algorithm
+            }
+        }
+        .clone()
+        .map(|val| val.parse_value("RoutingAlgorithmRef"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to deserialize routing algorithm ref from merchant account")?
+        .unwrap_or_default();
+
+        if let Some(algorithm_id) = routing_ref.algorithm_id {
+            let record = db
+                .find_routing_algorithm_metadata_by_algorithm_id_profile_id(
+                    &algorithm_id,
+                    profile_id,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+            let hs_records: Vec<routing_types::RoutingDictionaryRecord> =
+                vec![record.foreign_into()];
+            let de_records = retrieve_decision_engine_active_rules(
+                &state,
+                &transaction_type,
+                profile_id.clone(),
+                hs_records.clone(),
+            )
+            .await;
+            compare_and_log_result(
+                de_records.clone(),
+                hs_records.clone(),
+                "list_active_routing".to_string(),
+            );
+            active_algorithms.append(
+                &mut select_routing_result(&state, &business_profile, hs_records, de_records).await,
+            );
+        }
+
+        // Handle dynamic routing algorithms
+        let dynamic_routing_ref: routing_types::DynamicRoutingAlgorithmRef = business_profile
+            .dynamic_routing_algorithm
+            .clone()
+            .map(|val| val.parse_value("DynamicRoutingAlgorithmRef"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "unable to deserialize dynamic routing algorithm ref from business profile",
+            )?
+            .unwrap_or_default();
+
+        // Collect all dynamic algorithm IDs
+        let mut dynamic_algorithm_ids = Vec::new();
+
+        if let Some(sba) = &dynamic_routing_ref.success_based_algorithm {
+            if let Some(id) = &sba.algorithm_id_with_timestamp.algorithm_id {
+                dynamic_algorithm_ids.push(id.clone());
+            }
+        }
+        if let Some(era) = &dynamic_routing_ref.elimination_routing_algorithm {
+            if let Some(id) = &era.algorithm_id_with_timestamp.algorithm_id {
+                dynamic_algorithm_ids.push(id.clone());
+            }
+        }
+        if let Some(cbr) = &dynamic_routing_ref.contract_based_routing {
+            if let Some(id) = &cbr.algorithm_id_with_timestamp.algorithm_id {
+                dynamic_algorithm_ids.push(id.clone());
+            }
+        }
+
+        // Fetch all dynamic algorithms
+        for algorithm_id in dynamic_algorithm_ids {
+            let record = db
+                .find_routing_algorithm_metadata_by_algorithm_id_profile_id(
+                    &algorithm_id,
+                    profile_id,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+            if record.algorithm_for == transaction_type {
+                active_algorithms.push(record.foreign_into());
+            }
+        }
+    }
+
+    metrics::ROUTING_RETRIEVE_LINK_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::LinkedRoutingConfigRetrieveResponse::ProfileBased(active_algorithms),
+    ))
+}
+
+pub async fn retrieve_decision_engine_active_rules(
+    state: &SessionState,
+    transaction_type: &enums::TransactionType,
+    profile_id: common_utils::id_type::ProfileId,
+    hs_records: Vec<routing_types::RoutingDictionaryRecord>,
+) -> Vec<routing_types::RoutingDictionaryRecord> {
+    let mut de_records =
+        list_de_euclid_active_routing_algorithm(state, profile_id.get_string_repr().to_owned())
+            .await
+            .map_err(|e| {
+                router_env::logger::error!(?e, "Failed to list DE Euclid active routing algorithm");
+            })
+            .ok() // Avoid throwing error if Decision Engine is not available or other errors thrown
+            .unwrap_or_default();
+    // Use Hs records to list the dynamic algorithms as DE is not supporting dynamic algorithms in HS standard
+    let mut dynamic_algos = hs_records
+        .into_iter()
+        .filter(|record| record.kind == routing_types::RoutingAlgorithmKind::Dynamic)
+        .collect::<Vec<_>>();
+    de_records.append(&mut dynamic_algos);
+    de_records
+        .into_iter()
+        .filter(|r| r.algorithm_for == Some(*transaction_type))
+        .collect::<Vec<_>>()
+}
+// List all the default fallback algorithms under all the profile under a merchant
+pub async fn retrieve_default_routing_config_for_profiles(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    transaction_type: &enums::TransactionType,
+) -> RouterResponse<Vec<routing_types::ProfileDefaultRoutingConfig>> {
+    metrics::ROUTING_RETRIEVE_CONFIG_FOR_PROFILE.add(1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let all_profiles = db
+        .list_profile_by_merchant_id(
+            key_manager_state,
+            merchant_context.get_merchant_key_store(),
+            merchant_context.get_merchant_account().get_id(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)
+        .attach_printable("error retrieving all business profiles for merchant")?;
+
+    let retrieve_config_futures = all_profiles
+        .iter()
+        .map(|prof| {
+            helpers::get_merchant_default_config(
+                db,
+                prof.get_id().get_string_repr(),
+                transaction_type,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let configs = futures::future::join_all(retrieve_config_futures)
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let default_configs = configs
+        .into_iter()
+        .zip(all_profiles.iter().map(|prof| prof.get_id().to_owned()))
+        .map(
+            |(config, profile_id)| routing_types::ProfileDefaultRoutingConfig {
+                profile_id,
+                connectors: config,
+            },
+        )
+        .collect::<Vec<_>>();
+
+    metrics::ROUTING_RETRIEVE_CONFIG_FOR_PROFILE_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(default_configs))
+}
+
+pub async fn update_default_routing_config_for_profile(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    updated_config: Vec<routing_types::RoutableConnectorChoice>,
+    profile_id: common_utils::id_type::ProfileId,
+    transaction_type: &enums::TransactionType,
+    created_by: Option<common_utils::types::CreatedBy>,
+) -> RouterResponse<routing_types::ProfileDefaultRoutingConfig> {
+    metrics::ROUTING_UPDATE_CONFIG_FOR_PROFILE.add(1, &[]);
+
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+    let default_config = helpers::get_merchant_default_config(
+        db,
+        business_profile.get_id().get_string_repr(),
+        transaction_type,
+    )
+    .await?;
+
+    utils::when(default_config.len() != updated_config.len(), || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "current config and updated config have different lengths".to_string(),
+        })
+    })?;
+
+    let existing_set = FxHashSet::from_iter(
+        default_config
+            .iter()
+            .map(|c| (c.connector.to_string(), c.merchant_connector_id.as_ref())),
+    );
+
+    let updated_set = FxHashSet::from_iter(
+        updated_config
+            .iter()
+            .map(|c| (c.connector.to_string(), c.merchant_connector_id.as_ref())),
+    );
+
+    let symmetric_diff = existing_set
+        .symmetric_difference(&updated_set)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    utils::when(!symmetric_diff.is_empty(), || {
+        let error_str = symmetric_diff
+            .into_iter()
+            .map(|(connector, ident)| format!("'{connector}:{ident:?}'"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("connector mismatch between old and new configs ({error_str})"),
+        })
+    })?;
+
+    let routing_audit_log = helpers::update_profile_default_config(
+        db,
+        &business_profile,
+        updated_config.clone(),
+        helpers::RoutingAuditLogDetails {
+            action: storage_enums::RoutingAuditAction::Update,
+            transaction_type: *transaction_type,
+            previous_algorithm_id: None,
+            new_algorithm_id: None,
+            config_diff: helpers::get_routing_config_diff(
+                &serde_json::json!(default_config),
+                &serde_json::json!(updated_config),
+            ),
+            created_by,
+        },
+    )
+    .await?;
+    helpers::trigger_routing_config_webhook(
+        &state,
+        &merchant_context,
+        &business_profile,
+        routing_audit_log,
+    )
+    .await;
+
+    metrics::ROUTING_UPDATE_CONFIG_FOR_PROFILE_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::ProfileDefaultRoutingConfig {
+            profile_id: business_profile.get_id().to_owned(),
+            connectors: updated_config,
+        },
+    ))
+}
+
+#[cfg(feature = "v1")]
+pub async fn update_payment_method_default_routing_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    request: routing_types::PaymentMethodDefaultRoutingConfig,
+    created_by: Option<common_utils::types::CreatedBy>,
+) -> RouterResponse<routing_types::PaymentMethodDefaultRoutingConfig> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&request.profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: request.profile_id.get_string_repr().to_owned(),
+    })?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    utils::when(request.connectors.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "connectors cannot be empty".to_string(),
+        })
+    })?;
+
+    let default_config = helpers::get_merchant_default_config(
+        db,
+        business_profile.get_id().get_string_repr(),
+        &enums::TransactionType::Payment,
+    )
+    .await?;
+
+    let existing_set = FxHashSet::from_iter(
+        default_config
+            .iter()
+            .map(|c| (c.connector.to_string(), c.merchant_connector_id.as_ref())),
+    );
+
+    let mut updated_set = FxHashSet::default();
+    for connector in &request.connectors {
+        let ident = (
+            connector.connector.to_string(),
+            connector.merchant_connector_id.as_ref(),
+        );
+
+        utils::when(!existing_set.contains(&ident), || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "connector '{}:{:?}' is not present in the default config of the profile",
+                    ident.0, ident.1
+                ),
+            })
+        })?;
+
+        utils::when(!updated_set.insert(ident.clone()), || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("connector '{}:{:?}' is repeated", ident.0, ident.1),
+            })
+        })?;
+    }
+
+    let previous_config = helpers::get_payment_method_default_config(
+        db,
+        business_profile.get_id(),
+        request.payment_method_type,
+    )
+    .await?;
+
+    let routing_audit_log = helpers::upsert_payment_method_default_config(
+        db,
+        &business_profile,
+        request.payment_method_type,
+        request.connectors.clone(),
+        helpers::RoutingAuditLogDetails {
+            action: storage_enums::RoutingAuditAction::Update,
+            transaction_type: enums::TransactionType::Payment,
+            previous_algorithm_id: None,
+            new_algorithm_id: None,
+            config_diff: helpers::get_routing_config_diff(
+                &serde_json::json!(previous_config),
+                &serde_json::json!(request.connectors),
+            ),
+            created_by,
+        },
+    )
+    .await?;
+    helpers::trigger_routing_config_webhook(
+        &state,
+        &merchant_context,
+        &business_profile,
+        routing_audit_log,
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::PaymentMethodDefaultRoutingConfig {
+            profile_id: business_profile.get_id().to_owned(),
+            payment_method_type: request.payment_method_type,
+            connectors: request.connectors,
+        },
+    ))
+}
+
+#[cfg(feature = "v1")]
+pub async fn retrieve_payment_method_default_routing_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    query: routing_types::PaymentMethodDefaultRoutingConfigQuery,
+) -> RouterResponse<routing_types::PaymentMethodDefaultRoutingConfig> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&query.profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: query.profile_id.get_string_repr().to_owned(),
+    })?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let connectors = helpers::get_payment_method_default_config(
+        db,
+        business_profile.get_id(),
+        query.payment_method_type,
+    )
+    .await?
+    .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+        message: format!(
+            "No default config found for the payment method type {}",
+            query.payment_method_type
+        ),
+    })?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::PaymentMethodDefaultRoutingConfig {
+            profile_id: business_profile.get_id().to_owned(),
+            payment_method_type: query.payment_method_type,
+            connectors,
+        },
+    ))
+}
+
+#[cfg(feature = "v1")]
+pub async fn delete_payment_method_default_routing_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    query: routing_types::PaymentMethodDefaultRoutingConfigQuery,
+    created_by: Option<common_utils::types::CreatedBy>,
+) -> RouterResponse<routing_types::PaymentMethodDefaultRoutingConfig> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&query.profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: query.profile_id.get_string_repr().to_owned(),
+    })?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let connectors = helpers::get_payment_method_default_config(
+        db,
+        business_profile.get_id(),
+        query.payment_method_type,
+    )
+    .await?
+    .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+        message: format!(
+            "No default config found for the payment method type {}",
+            query.payment_method_type
+        ),
+    })?;
+
+    let routing_audit_log = helpers::delete_payment_method_default_config(
+        db,
+        &business_profile,
+        query.payment_method_type,
+        helpers::RoutingAuditLogDetails {
+            action: storage_enums::RoutingAuditAction::Update,
+            transaction_type: enums::TransactionType::Payment,
+            previous_algorithm_id: None,
+            new_algorithm_id: None,
+            config_diff: helpers::get_routing_config_diff(
+                &serde_json::json!(connectors),
+                &serde_json::Value::Null,
+            ),
+            created_by,
+        },
+    )
+    .await?;
+    helpers::trigger_routing_config_webhook(
+        &state,
+        &merchant_context,
+        &business_profile,
+        routing_audit_log,
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::PaymentMethodDefaultRoutingConfig {
+            profile_id: business_profile.get_id().to_owned(),
+            payment_method_type: query.payment_method_type,
+            connectors,
+        },
+    ))
+}
+
+// Toggle the specific routing type as well as add the default configs in RoutingAlgorithm table
+// and update the same in business profile table.
+
+#[cfg(all(feature = "v1", feature = "dynamic_routing"))]
+pub async fn toggle_specific_dynamic_routing(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    feature_to_enable: routing::DynamicRoutingFeatures,
+    profile_id: common_utils::id_type::ProfileId,
+    dynamic_routing_type: routing::DynamicRoutingType,
+    created_by: Option<common_utils::types::CreatedBy>,
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    metrics::ROUTING_CREATE_REQUEST_RECEIVED.add(
+        1,
+        router_env::metric_attributes!(
+            ("profile_id", profile_id.clone()),
+            ("algorithm_type", dynamic_routing_type.to_string())
+        ),
+    );
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile: domain::Profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    let dynamic_routing_algo_ref: routing_types::DynamicRoutingAlgorithmRef = business_profile
+        .dynamic_routing_algorithm
+        .clone()
+        .map(|val| val.parse_value("DynamicRoutingAlgorithmRef"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable(
+            "unable to deserialize dynamic routing algorithm ref from business profile",
+        )?
+        .unwrap_or_default();
+
+    match feature_to_enable {
+        routing::DynamicRoutingFeatures::Metrics
+        | routing::DynamicRoutingFeatures::DynamicConnectorSelection => {
+            // occurs when algorithm is already present in the db
+            // 1. If present with same feature then return response as already enabled
+            // 2. Else update the feature and persist the same on db
+            // 3. If not present in db then create a new default entry
+            Box::pin(helpers::enable_dynamic_routing_algorithm(
+                &state,
+                &merchant_context,
+                business_profile,
+                feature_to_enable,
+                dynamic_routing_algo_ref,
+                dynamic_routing_type,
+                None,
+                created_by,
+            ))
+            .await
+        }
+        routing::DynamicRoutingFeatures::None => {
+            // disable specific dynamic routing for the requested profile
+            helpers::disable_dynamic_routing_algorithm(
+                &state,
+                &merchant_context,
+                business_profile,
+                dynamic_routing_algo_ref,
+                dynamic_routing_type,
+                created_by,
+            )
+            .await
+        }
+    }
+}
+
+#[cfg(all(feature = "v1", feature = "dynamic_routing"))]
+pub async fn create_specific_dynamic_routing(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    feature_to_enable: routing::DynamicRoutingFeatures,
+    profile_id: common_utils::id_type::ProfileId,
+    dynamic_routing_type: routing::DynamicRoutingType,
+    payload: routing_types::DynamicRoutingPayload,
+    created_by: Option<common_utils::types::CreatedBy>,
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    metrics::ROUTING_CREATE_REQUEST_RECEIVED.add(
+        1,
+        router_env::metric_attributes!(
+            ("profile_id", profile_id.clone()),
+            ("algorithm_type", dynamic_routing_type.to_string())
+        ),
+    );
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile: domain::Profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    let dynamic_routing_algo_ref: routing_types::DynamicRoutingAlgorithmRef = business_profile
+        .dynamic_routing_algorithm
+        .clone()
+        .map(|val| val.parse_value("DynamicRoutingAlgorithmRef"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable(
+            "unable to deserialize dynamic routing algorithm ref from business profile",
+        )?
+        .unwrap_or_default();
+
+    match feature_to_enable {
+        routing::DynamicRoutingFeatures::Metrics
+        | routing::DynamicRoutingFeatures::DynamicConnectorSelection => {
+            Box::pin(helpers::enable_dynamic_routing_algorithm(
+                &state,
+                &merchant_context,
+                business_profile,
+                feature_to_enable,
+                dynamic_routing_algo_ref,
+                dynamic_routing_type,
+                Some(payload),
+                created_by,
+            ))
+            .await
+        }
+        routing::DynamicRoutingFeatures::None => {
+            // disable specific dynamic routing for the requested profile
+            helpers::disable_dynamic_routing_algorithm(
+                &state,
+                &merchant_context,
+                business_profile,
+                dynamic_routing_algo_ref,
+                dynamic_routing_type,
+                created_by,
+            )
+            .await
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+pub async fn configure_dynamic_routing_volume_split(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: common_utils::id_type::ProfileId,
+    routing_info: routing::RoutingVolumeSplit,
+    created_by: Option<common_utils::types::CreatedBy>,
+) -> RouterResponse<routing::RoutingVolumeSplit> {
+    metrics::ROUTING_CREATE_REQUEST_RECEIVED.add(
+        1,
+        router_env::metric_attributes!(("profile_id", profile_id.clone())),
+    );
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    utils::when(
+        routing_info.split > crate::consts::DYNAMIC_ROUTING_MAX_VOLUME,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Dynamic routing volume split should be less than 100".to_string(),
+            })
+        },
+    )?;
+
+    let business_profile: domain::Profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    let mut dynamic_routing_algo_ref: routing_types::DynamicRoutingAlgorithmRef = business_profile
+        .dynamic_routing_algorithm
+        .clone()
+        .map(|val| val.parse_value("DynamicRoutingAlgorithmRef"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable(
+            "unable to deserialize dynamic routing algorithm ref from business profile",
+        )?
+        .unwrap_or_default();
+
+    dynamic_routing_algo_ref.update_volume_split(Some(routing_info.split));
+
+    let routing_audit_log = helpers::update_business_profile_active_dynamic_algorithm_ref(
+        db,
+        &((&state).into()),
+        merchant_context.get_merchant_key_store(),
+        business_profile.clone(),
+        dynamic_routing_algo_ref.clone(),
+        helpers::RoutingAuditLogDetails {
+            action: storage_enums::RoutingAuditAction::VolumeSplitUpdate,
+            transaction_type: enums::TransactionType::Payment,
+            previous_algorithm_id: None,
+            new_algorithm_id: None,
+            config_diff: None,
+            created_by,
+        },
+    )
+    .await?;
+    helpers::trigger_routing_config_webhook(
+        &state,
+        &merchant_context,
+        &business_profile,
+        routing_audit_log,
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(routing_info))
+}
+
+#[cfg(feature = "v1")]
+pub async fn configure_latency_based_routing(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    request: routing_types::LatencyBasedRoutingConfigWrapper,
+    created_by: Option<common_utils::types::CreatedBy>,
+) -> RouterResponse<routing_types::LatencyBasedRoutingResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    utils::when(request.config.latency_threshold_ms == 0, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "latency_threshold_ms should be greater than 0".to_string(),
+        })
+    })?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&request.profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: request.profile_id.get_string_repr().to_owned(),
+    })?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let previous_config =
+        helpers::get_latency_based_routing_config(db, business_profile.get_id()).await?;
+    let routing_audit_log = helpers::upsert_latency_based_routing_config(
+        db,
+        &business_profile,
+        &request.config,
+        helpers::RoutingAuditLogDetails {
+            action: storage_enums::RoutingAuditAction::LatencyRoutingUpdate,
+            transaction_type: enums::TransactionType::Payment,
+            previous_algorithm_id: None,
+            new_algorithm_id: None,
+            config_diff: helpers::get_routing_config_diff(
+                &serde_json::json!(previous_config),
+                &serde_json::json!(request.config),
+            ),
+            created_by,
+        },
+    )
+    .await?;
+    helpers::trigger_routing_config_webhook(
+        &state,
+        &merchant_context,
+        &business_profile,
+        routing_audit_log,
+    )
+    .await;
+
+    let response =
+        build_latency_based_routing_response(&state, &business_profile, request.config).await?;
+
+    Ok(service_api::ApplicationResponse::Json(response))
+}
+
+#[cfg(feature = "v1")]
+pub async fn retrieve_latency_based_routing(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    profile_id: common_utils::id_type::ProfileId,
+) -> RouterResponse<routing_types::LatencyBasedRoutingResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let config = helpers::get_latency_based_routing_config(db, business_profile.get_id()).await?;
+
+    let response = build_latency_based_routing_response(&state, &business_profile, config).await?;
+
+    Ok(service_api::ApplicationResponse::Json(response))
+}
+
+/// Attaches the recent latency of every connector in the profile's fallback list to the config
+#[cfg(feature = "v1")]
+async fn build_latency_based_routing_response(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    config: routing_types::LatencyBasedRoutingConfig,
+) -> RouterResult<routing_types::LatencyBasedRoutingResponse> {
+    let connectors = helpers::get_merchant_default_config(
+        state.store.as_ref(),
+        business_profile.get_id().get_string_repr(),
+        &enums::TransactionType::Payment,
+    )
+    .await?;
+
+    let connector_latencies = helpers::get_connectors_latency_stats(
+        state,
+        business_profile.get_id(),
+        &config,
+        &connectors,
+    )
+    .await?;
+
+    Ok(routing_types::LatencyBasedRoutingResponse {
+        profile_id: business_profile.get_id().to_owned(),
+        config,
+        connector_latencies,
+    })
+}
+
+/// Validates that the profile and the merchant connector account belong to the merchant, and
+/// that the merchant connector account is configured under the profile
+#[cfg(feature = "v1")]
+async fn validate_profile_and_connector_for_fee_schedule(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    profile_id: &common_utils::id_type::ProfileId,
+    merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+) -> RouterResult<domain::Profile> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let merchant_connector_account = db
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            key_manager_state,
+            merchant_context.get_merchant_account().get_id(),
+            merchant_connector_id,
+            merchant_context.get_merchant_key_store(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.get_string_repr().to_owned(),
+        })?;
+
+    utils::when(&merchant_connector_account.profile_id != profile_id, || {
+        Err(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.get_string_repr().to_owned(),
+        })
+    })?;
+
+    Ok(business_profile)
+}
+
+#[cfg(feature = "v1")]
+pub async fn upsert_connector_fee_schedule(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    request: routing_types::ConnectorFeeScheduleWrapper,
+    created_by: Option<common_utils::types::CreatedBy>,
+) -> RouterResponse<routing_types::ConnectorFeeSchedule> {
+    metrics::ROUTING_UPSERT_FEE_SCHEDULE.add(1, &[]);
+
+    utils::when(request.rules.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "At least one fee rule should be provided".to_string(),
+        })
+    })?;
+
+    utils::when(
+        request.rules.iter().any(|rule| {
+            [rule.interchange, rule.scheme, rule.markup]
+                .iter()
+                .any(|component| {
+                    component.percentage_bps > 10_000
+                        || component.fixed_amount.get_amount_as_i64() < 0
+                })
+        }),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Fee percentages should be within 10000 basis points and fixed fees \
+                    should not be negative"
+                    .to_string(),
+            })
+        },
+    )?;
+
+    let business_profile = validate_profile_and_connector_for_fee_schedule(
+        &state,
+        &merchant_context,
+        authentication_profile_id,
+        &request.profile_id,
+        &request.merchant_connector_id,
+    )
+    .await?;
+
+    let (fee_schedule, routing_audit_log) = helpers::upsert_connector_fee_schedule(
+        state.store.as_ref(),
+        &business_profile,
+        &request.merchant_connector_id,
+        request.rules,
+        created_by,
+    )
+    .await?;
+    helpers::trigger_routing_config_webhook(
+        &state,
+        &merchant_context,
+        &business_profile,
+        routing_audit_log,
+    )
+    .await;
+
+    metrics::ROUTING_UPSERT_FEE_SCHEDULE_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(fee_schedule))
+}
+
+#[cfg(feature = "v1")]
+pub async fn retrieve_connector_fee_schedule(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    request: routing_types::ConnectorFeeScheduleQuery,
+) -> RouterResponse<routing_types::ConnectorFeeSchedule> {
+    validate_profile_and_connector_for_fee_schedule(
+        &state,
+        &merchant_context,
+        authentication_profile_id,
+        &request.profile_id,
+        &request.merchant_connector_id,
+    )
+    .await?;
+
+    let fee_schedule =
+        helpers::find_connector_fee_schedule(state.store.as_ref(), &request.merchant_connector_id)
+            .await?
+            .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+                message: "Fee schedule not found for the merchant connector account".to_string(),
+            })?;
+
+    Ok(service_api::ApplicationResponse::Json(fee_schedule))
+}
+
+#[cfg(feature = "v1")]
+pub async fn delete_connector_fee_schedule(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    request: routing_types::ConnectorFeeScheduleQuery,
+    created_by: Option<common_utils::types::CreatedBy>,
+) -> RouterResponse<routing_types::ConnectorFeeSchedule> {
+    metrics::ROUTING_DELETE_FEE_SCHEDULE.add(1, &[]);
+
+    let business_profile = validate_profile_and_connector_for_fee_schedule(
+        &state,
+        &merchant_context,
+        authentication_profile_id,
+        &request.profile_id,
+        &request.merchant_connector_id,
+    )
+    .await?;
+
+    let db = state.store.as_ref();
+    let fee_schedule = helpers::find_connector_fee_schedule(db, &request.merchant_connector_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Fee schedule not found for the merchant connector account".to_string(),
+        })?;
+
+    let routing_audit_log =
+        helpers::delete_connector_fee_schedule(db, &business_profile, &fee_schedule, created_by)
+            .await?;
+    helpers::trigger_routing_config_webhook(
+        &state,
+        &merchant_context,
+        &business_profile,
+        routing_audit_log,
+    )
+    .await;
+
+    metrics::ROUTING_DELETE_FEE_SCHEDULE_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(fee_schedule))
+}
+
+#[cfg(feature = "v1")]
+async fn validate_routing_experiment_algorithm(
+    db: &dyn StorageInterface,
+    profile_id: &common_utils::id_type::ProfileId,
+    algorithm_id: &common_utils::id_type::RoutingId,
+) -> RouterResult<()> {
+    let routing_algorithm = db
+        .find_routing_algorithm_by_profile_id_algorithm_id(profile_id, algorithm_id)
+        .await
+        .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    utils::when(
+        routing_algorithm.algorithm_for != enums::TransactionType::Payment
+            || !matches!(
+                routing_algorithm.kind,
+                storage_enums::RoutingAlgorithmKind::Single
+                    | storage_enums::RoutingAlgorithmKind::Priority
+                    | storage_enums::RoutingAlgorithmKind::VolumeSplit
+                    | storage_enums::RoutingAlgorithmKind::Advanced
+                    | storage_enums::RoutingAlgorithmKind::Cost
+            ),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "routing algorithm {} is not a static payment routing algorithm",
+                    algorithm_id.get_string_repr()
+                ),
+            })
+        },
+    )
+}
+
+#[cfg(feature = "v1")]
+pub async fn start_routing_experiment(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    request: routing_types::RoutingExperimentRequestWrapper,
+    created_by: Option<common_utils::types::CreatedBy>,
+) -> RouterResponse<routing_types::RoutingExperiment> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&request.profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: request.profile_id.get_string_repr().to_owned(),
+    })?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let experiment_request = request.request;
+
+    utils::when(
+        experiment_request.treatment_percentage == 0
+            || experiment_request.treatment_percentage >= 100,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "treatment_percentage must be between 1 and 99".to_string(),
+            })
+        },
+    )?;
+
+    utils::when(
+        experiment_request.control_algorithm_id == experiment_request.treatment_algorithm_id,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "control and treatment routing algorithms must be different".to_string(),
+            })
+        },
+    )?;
+
+    validate_routing_experiment_algorithm(
+        db,
+        business_profile.get_id(),
+        &experiment_request.control_algorithm_id,
+    )
+    .await?;
+    validate_routing_experiment_algorithm(
+        db,
+        business_profile.get_id(),
+        &experiment_request.treatment_algorithm_id,
+    )
+    .await?;
 
-    let retrieve_config_futures = all_profiles
-        .iter()
-        .map(|prof| {
-            helpers::get_merchant_default_config(
-                db,
-                prof.get_id().get_string_repr(),
-                transaction_type,
-            )
+    let previous_experiment =
+        helpers::get_routing_experiment(db, business_profile.get_id()).await?;
+    let is_experiment_running = previous_experiment
+        .as_ref()
+        .is_some_and(|experiment| experiment.is_running());
+
+    utils::when(is_experiment_running, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "A routing experiment is already running for the profile".to_string(),
         })
-        .collect::<Vec<_>>();
+    })?;
 
-    let configs = futures::future::join_all(retrieve_config_futures)
-        .await
-        .into_iter()
-        .collect::<Result<Vec<_>, _>>()?;
+    let experiment = routing_types::RoutingExperiment {
+        experiment_id: common_utils::generate_id(crate::consts::ID_LENGTH, "routing_exp"),
+        profile_id: business_profile.get_id().to_owned(),
+        name: experiment_request.name,
+        control_algorithm_id: experiment_request.control_algorithm_id,
+        treatment_algorithm_id: experiment_request.treatment_algorithm_id,
+        treatment_percentage: experiment_request.treatment_percentage,
+        status: routing_types::RoutingExperimentStatus::Running,
+        started_at: common_utils::date_time::now(),
+        stopped_at: None,
+    };
 
-    let default_configs = configs
-        .into_iter()
-        .zip(all_profiles.iter().map(|prof| prof.get_id().to_owned()))
-        .map(
-            |(config, profile_id)| routing_types::ProfileDefaultRoutingConfig {
-                profile_id,
-                connectors: config,
-            },
-        )
-        .collect::<Vec<_>>();
+    let routing_audit_log = helpers::upsert_routing_experiment(
+        db,
+        &business_profile,
+        &experiment,
+        helpers::RoutingAuditLogDetails {
+            action: storage_enums::RoutingAuditAction::ExperimentUpdate,
+            transaction_type: enums::TransactionType::Payment,
+            previous_algorithm_id: None,
+            new_algorithm_id: None,
+            config_diff: helpers::get_routing_config_diff(
+                &serde_json::json!(previous_experiment),
+                &serde_json::json!(experiment),
+            ),
+            created_by,
+        },
+    )
+    .await?;
+    helpers::trigger_routing_config_webhook(
+        &state,
+        &merchant_context,
+        &business_profile,
+        routing_audit_log,
+    )
+    .await;
 
-    metrics::ROUTING_RETRIEVE_CONFIG_FOR_PROFILE_SUCCESS_RESPONSE.add(1, &[]);
-    Ok(service_api::ApplicationResponse::Json(default_configs))
+    Ok(service_api::ApplicationResponse::Json(experiment))
 }
 
-pub async fn update_default_routing_config_for_profile(
+#[cfg(feature = "v1")]
+pub async fn stop_routing_experiment(
     state: SessionState,
     merchant_context: domain::MerchantContext,
-    updated_config: Vec<routing_types::RoutableConnectorChoice>,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
     profile_id: common_utils::id_type::ProfileId,
-    transaction_type: &enums::TransactionType,
-) -> RouterResponse<routing_types::ProfileDefaultRoutingConfig> {
-    metrics::ROUTING_UPDATE_CONFIG_FOR_PROFILE.add(1, &[]);
-
+    created_by: Option<common_utils::types::CreatedBy>,
+) -> RouterResponse<routing_types::RoutingExperiment> {
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
 
@@ -1601,87 +3294,149 @@ pub async fn update_default_routing_config_for_profile(
     .change_context(errors::ApiErrorResponse::ProfileNotFound {
         id: profile_id.get_string_repr().to_owned(),
     })?;
-    let default_config = helpers::get_merchant_default_config(
-        db,
-        business_profile.get_id().get_string_repr(),
-        transaction_type,
-    )
-    .await?;
 
-    utils::when(default_config.len() != updated_config.len(), || {
-        Err(errors::ApiErrorResponse::PreconditionFailed {
-            message: "current config and updated config have different lengths".to_string(),
-        })
-    })?;
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
 
-    let existing_set = FxHashSet::from_iter(
-        default_config
-            .iter()
-            .map(|c| (c.connector.to_string(), c.merchant_connector_id.as_ref())),
-    );
+    let mut experiment = helpers::get_routing_experiment(db, business_profile.get_id())
+        .await?
+        .filter(|experiment| experiment.is_running())
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "No routing experiment is running for the profile".to_string(),
+        })?;
 
-    let updated_set = FxHashSet::from_iter(
-        updated_config
-            .iter()
-            .map(|c| (c.connector.to_string(), c.merchant_connector_id.as_ref())),
-    );
+    let previous_experiment = serde_json::json!(experiment);
+    experiment.status = routing_types::RoutingExperimentStatus::Stopped;
+    experiment.stopped_at = Some(common_utils::date_time::now());
 
-    let symmetric_diff = existing_set
-        .symmetric_difference(&updated_set)
-        .cloned()
-        .collect::<Vec<_>>();
+    let routing_audit_log = helpers::upsert_routing_experiment(
+        db,
+        &business_profile,
+        &experiment,
+        helpers::RoutingAuditLogDetails {
+            action: storage_enums::RoutingAuditAction::ExperimentUpdate,
+            transaction_type: enums::TransactionType::Payment,
+            previous_algorithm_id: None,
+            new_algorithm_id: None,
+            config_diff: helpers::get_routing_config_diff(
+                &previous_experiment,
+                &serde_json::json!(experiment),
+            ),
+            created_by,
+        },
+    )
+    .await?;
+    helpers::trigger_routing_config_webhook(
+        &state,
+        &merchant_context,
+        &business_profile,
+        routing_audit_log,
+    )
+    .await;
 
-    utils::when(!symmetric_diff.is_empty(), || {
-        let error_str = symmetric_diff
-            .into_iter()
-            .map(|(connector, ident)| format!("'{connector}:{ident:?}'"))
-            .collect::<Vec<_>>()
-            .join(", ");
+    Ok(service_api::ApplicationResponse::Json(experiment))
+}
 
-        Err(errors::ApiErrorResponse::InvalidRequestData {
-            message: format!("connector mismatch between old and new configs ({error_str})"),
-        })
-    })?;
+#[cfg(feature = "v1")]
+pub async fn retrieve_routing_experiment_results(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    profile_id: common_utils::id_type::ProfileId,
+) -> RouterResponse<routing_types::RoutingExperimentResults> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
 
-    helpers::update_merchant_default_config(
+    let business_profile = core_utils::validate_and_get_business_profile(
         db,
-        business_profile.get_id().get_string_repr(),
-        updated_config.clone(),
-        transaction_type,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&profile_id),
+        merchant_context.get_merchant_account().get_id(),
     )
-    .await?;
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let experiment = helpers::get_routing_experiment(db, business_profile.get_id())
+        .await?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "No routing experiment found for the profile".to_string(),
+        })?;
+
+    let status_counts = db
+        .get_routing_experiment_payment_status_count(&experiment.experiment_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch routing experiment payments")?;
+
+    let arms = [
+        storage_enums::RoutingExperimentArm::Control,
+        storage_enums::RoutingExperimentArm::Treatment,
+    ]
+    .into_iter()
+    .map(|arm| {
+        let (total_payments, successful_payments) = status_counts
+            .iter()
+            .filter(|(payment_arm, _, _)| *payment_arm == arm)
+            .fold((0, 0), |(total, successful), (_, status, count)| {
+                let is_successful = matches!(
+                    status,
+                    storage_enums::AttemptStatus::Authorized
+                        | storage_enums::AttemptStatus::Charged
+                        | storage_enums::AttemptStatus::PartialCharged
+                );
+                (
+                    total + count,
+                    successful + if is_successful { *count } else { 0 },
+                )
+            });
+
+        #[allow(clippy::as_conversions)]
+        let authorization_rate = (total_payments > 0)
+            .then(|| successful_payments as f64 * 100.0 / total_payments as f64);
+
+        routing_types::RoutingExperimentArmResult {
+            arm,
+            algorithm_id: experiment.get_algorithm_id(arm).to_owned(),
+            total_payments,
+            successful_payments,
+            authorization_rate,
+        }
+    })
+    .collect();
 
-    metrics::ROUTING_UPDATE_CONFIG_FOR_PROFILE_SUCCESS_RESPONSE.add(1, &[]);
     Ok(service_api::ApplicationResponse::Json(
-        routing_types::ProfileDefaultRoutingConfig {
-            profile_id: business_profile.get_id().to_owned(),
-            connectors: updated_config,
-        },
+        routing_types::RoutingExperimentResults { experiment, arms },
     ))
 }
 
-// Toggle the specific routing type as well as add the default configs in RoutingAlgorithm table
-// and update the same in business profile table.
+#[cfg(feature = "v1")]
+fn get_active_routing_algorithm_id(
+    routing_algorithm: Option<serde_json::Value>,
+) -> RouterResult<Option<common_utils::id_type::RoutingId>> {
+    Ok(routing_algorithm
+        .map(|val| val.parse_value::<routing_types::RoutingAlgorithmRef>("RoutingAlgorithmRef"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to deserialize routing algorithm ref from business profile")?
+        .and_then(|routing_ref| routing_ref.algorithm_id))
+}
 
-#[cfg(all(feature = "v1", feature = "dynamic_routing"))]
-pub async fn toggle_specific_dynamic_routing(
+#[cfg(feature = "v1")]
+pub async fn export_routing_config(
     state: SessionState,
     merchant_context: domain::MerchantContext,
-    feature_to_enable: routing::DynamicRoutingFeatures,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
     profile_id: common_utils::id_type::ProfileId,
-    dynamic_routing_type: routing::DynamicRoutingType,
-) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
-    metrics::ROUTING_CREATE_REQUEST_RECEIVED.add(
-        1,
-        router_env::metric_attributes!(
-            ("profile_id", profile_id.clone()),
-            ("algorithm_type", dynamic_routing_type.to_string())
-        ),
-    );
+) -> RouterResponse<routing_types::RoutingConfigExport> {
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
 
-    let business_profile: domain::Profile = core_utils::validate_and_get_business_profile(
+    let business_profile = core_utils::validate_and_get_business_profile(
         db,
         key_manager_state,
         merchant_context.get_merchant_key_store(),
@@ -1694,69 +3449,122 @@ pub async fn toggle_specific_dynamic_routing(
         id: profile_id.get_string_repr().to_owned(),
     })?;
 
-    let dynamic_routing_algo_ref: routing_types::DynamicRoutingAlgorithmRef = business_profile
-        .dynamic_routing_algorithm
-        .clone()
-        .map(|val| val.parse_value("DynamicRoutingAlgorithmRef"))
-        .transpose()
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable(
-            "unable to deserialize dynamic routing algorithm ref from business profile",
-        )?
-        .unwrap_or_default();
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
 
-    match feature_to_enable {
-        routing::DynamicRoutingFeatures::Metrics
-        | routing::DynamicRoutingFeatures::DynamicConnectorSelection => {
-            // occurs when algorithm is already present in the db
-            // 1. If present with same feature then return response as already enabled
-            // 2. Else update the feature and persist the same on db
-            // 3. If not present in db then create a new default entry
-            Box::pin(helpers::enable_dynamic_routing_algorithm(
-                &state,
-                merchant_context.get_merchant_key_store().clone(),
-                business_profile,
-                feature_to_enable,
-                dynamic_routing_algo_ref,
-                dynamic_routing_type,
-                None,
-            ))
-            .await
-        }
-        routing::DynamicRoutingFeatures::None => {
-            // disable specific dynamic routing for the requested profile
-            helpers::disable_dynamic_routing_algorithm(
-                &state,
-                merchant_context.get_merchant_key_store().clone(),
-                business_profile,
-                dynamic_routing_algo_ref,
-                dynamic_routing_type,
+    let algorithms_metadata = db
+        .list_routing_algorithm_metadata_by_profile_id(
+            business_profile.get_id(),
+            crate::consts::ROUTING_CONFIG_EXPORT_MAX_ALGORITHMS,
+            0,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the routing algorithms of the profile")?;
+
+    let mut algorithms = Vec::with_capacity(algorithms_metadata.len());
+    for metadata in algorithms_metadata.into_iter().filter(|metadata| {
+        !matches!(
+            metadata.kind,
+            storage_enums::RoutingAlgorithmKind::Dynamic
+                | storage_enums::RoutingAlgorithmKind::ThreeDsDecisionRule
+        )
+    }) {
+        let routing_algorithm = db
+            .find_routing_algorithm_by_profile_id_algorithm_id(
+                business_profile.get_id(),
+                &metadata.algorithm_id,
             )
             .await
+            .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+        let algorithm = routing_algorithm
+            .algorithm_data
+            .parse_value::<routing_types::StaticRoutingAlgorithm>("RoutingAlgorithm")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("unable to parse routing algorithm")?;
+
+        algorithms.push(routing_types::RoutingAlgorithmExport {
+            algorithm_id: routing_algorithm.algorithm_id,
+            name: routing_algorithm.name,
+            description: routing_algorithm.description.unwrap_or_default(),
+            algorithm,
+            transaction_type: routing_algorithm.algorithm_for,
+        });
+    }
+
+    // Dynamic routing algorithms are not exported, so they cannot be referenced as active
+    let is_exported = |algorithm_id: &common_utils::id_type::RoutingId| {
+        algorithms
+            .iter()
+            .any(|algorithm| algorithm.algorithm_id == *algorithm_id)
+    };
+    let active_payment_algorithm_id =
+        get_active_routing_algorithm_id(business_profile.routing_algorithm.clone())?
+            .filter(is_exported);
+    let active_payout_algorithm_id =
+        get_active_routing_algorithm_id(business_profile.payout_routing_algorithm.clone())?
+            .filter(is_exported);
+
+    let default_fallback_connectors = helpers::get_merchant_default_config(
+        db,
+        business_profile.get_id().get_string_repr(),
+        &enums::TransactionType::Payment,
+    )
+    .await?;
+
+    let mut payment_method_default_fallback_connectors = Vec::new();
+    for payment_method_type in enums::PaymentMethodType::iter() {
+        if let Some(connectors) = helpers::get_payment_method_default_config(
+            db,
+            business_profile.get_id(),
+            payment_method_type,
+        )
+        .await?
+        {
+            payment_method_default_fallback_connectors.push(
+                routing_types::PaymentMethodDefaultRoutingConfig {
+                    profile_id: business_profile.get_id().to_owned(),
+                    payment_method_type,
+                    connectors,
+                },
+            );
         }
     }
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingConfigExport {
+            version: routing_types::ROUTING_CONFIG_EXPORT_VERSION,
+            profile_id: business_profile.get_id().to_owned(),
+            exported_at: common_utils::date_time::now(),
+            algorithms,
+            active_payment_algorithm_id,
+            active_payout_algorithm_id,
+            default_fallback_connectors,
+            payment_method_default_fallback_connectors,
+        },
+    ))
 }
 
-#[cfg(all(feature = "v1", feature = "dynamic_routing"))]
-pub async fn create_specific_dynamic_routing(
+#[cfg(feature = "v1")]
+pub async fn import_routing_config(
     state: SessionState,
     merchant_context: domain::MerchantContext,
-    feature_to_enable: routing::DynamicRoutingFeatures,
-    profile_id: common_utils::id_type::ProfileId,
-    dynamic_routing_type: routing::DynamicRoutingType,
-    payload: routing_types::DynamicRoutingPayload,
-) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
-    metrics::ROUTING_CREATE_REQUEST_RECEIVED.add(
-        1,
-        router_env::metric_attributes!(
-            ("profile_id", profile_id.clone()),
-            ("algorithm_type", dynamic_routing_type.to_string())
-        ),
-    );
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    request: routing_types::RoutingConfigImportRequestWrapper,
+    created_by: Option<common_utils::types::CreatedBy>,
+) -> RouterResponse<routing_types::RoutingConfigImportResponse> {
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
+    let routing_types::RoutingConfigImportRequestWrapper {
+        profile_id,
+        request:
+            routing_types::RoutingConfigImportRequest {
+                config,
+                merchant_connector_id_mapping,
+            },
+    } = request;
 
-    let business_profile: domain::Profile = core_utils::validate_and_get_business_profile(
+    let business_profile = core_utils::validate_and_get_business_profile(
         db,
         key_manager_state,
         merchant_context.get_merchant_key_store(),
@@ -1769,104 +3577,255 @@ pub async fn create_specific_dynamic_routing(
         id: profile_id.get_string_repr().to_owned(),
     })?;
 
-    let dynamic_routing_algo_ref: routing_types::DynamicRoutingAlgorithmRef = business_profile
-        .dynamic_routing_algorithm
-        .clone()
-        .map(|val| val.parse_value("DynamicRoutingAlgorithmRef"))
-        .transpose()
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable(
-            "unable to deserialize dynamic routing algorithm ref from business profile",
-        )?
-        .unwrap_or_default();
-
-    match feature_to_enable {
-        routing::DynamicRoutingFeatures::Metrics
-        | routing::DynamicRoutingFeatures::DynamicConnectorSelection => {
-            Box::pin(helpers::enable_dynamic_routing_algorithm(
-                &state,
-                merchant_context.get_merchant_key_store().clone(),
-                business_profile,
-                feature_to_enable,
-                dynamic_routing_algo_ref,
-                dynamic_routing_type,
-                Some(payload),
-            ))
-            .await
-        }
-        routing::DynamicRoutingFeatures::None => {
-            // disable specific dynamic routing for the requested profile
-            helpers::disable_dynamic_routing_algorithm(
-                &state,
-                merchant_context.get_merchant_key_store().clone(),
-                business_profile,
-                dynamic_routing_algo_ref,
-                dynamic_routing_type,
-            )
-            .await
-        }
-    }
-}
-
-#[cfg(feature = "v1")]
-pub async fn configure_dynamic_routing_volume_split(
-    state: SessionState,
-    merchant_context: domain::MerchantContext,
-    profile_id: common_utils::id_type::ProfileId,
-    routing_info: routing::RoutingVolumeSplit,
-) -> RouterResponse<routing::RoutingVolumeSplit> {
-    metrics::ROUTING_CREATE_REQUEST_RECEIVED.add(
-        1,
-        router_env::metric_attributes!(("profile_id", profile_id.clone())),
-    );
-    let db = state.store.as_ref();
-    let key_manager_state = &(&state).into();
+    core_utils::validate_profile_id_from_auth_layer(
+        authentication_profile_id.clone(),
+        &business_profile,
+    )?;
 
     utils::when(
-        routing_info.split > crate::consts::DYNAMIC_ROUTING_MAX_VOLUME,
+        config.version != routing_types::ROUTING_CONFIG_EXPORT_VERSION,
         || {
             Err(errors::ApiErrorResponse::InvalidRequestData {
-                message: "Dynamic routing volume split should be less than 100".to_string(),
+                message: format!(
+                    "unsupported routing config export version '{}'",
+                    config.version
+                ),
             })
         },
     )?;
 
-    let business_profile: domain::Profile = core_utils::validate_and_get_business_profile(
-        db,
-        key_manager_state,
-        merchant_context.get_merchant_key_store(),
-        Some(&profile_id),
-        merchant_context.get_merchant_account().get_id(),
-    )
-    .await?
-    .get_required_value("Profile")
-    .change_context(errors::ApiErrorResponse::ProfileNotFound {
-        id: profile_id.get_string_repr().to_owned(),
+    let validate_active_algorithm =
+        |algorithm_id: Option<&common_utils::id_type::RoutingId>,
+         transaction_type: enums::TransactionType| {
+            algorithm_id.map_or(Ok(()), |algorithm_id| {
+                utils::when(
+                    !config.algorithms.iter().any(|algorithm| {
+                        algorithm.algorithm_id == *algorithm_id
+                            && algorithm.transaction_type == transaction_type
+                    }),
+                    || {
+                        Err(errors::ApiErrorResponse::InvalidRequestData {
+                            message: format!(
+                                "active {transaction_type} routing algorithm '{}' is not one of the exported algorithms",
+                                algorithm_id.get_string_repr()
+                            ),
+                        })
+                    },
+                )
+            })
+        };
+    validate_active_algorithm(
+        config.active_payment_algorithm_id.as_ref(),
+        enums::TransactionType::Payment,
+    )?;
+    #[cfg(feature = "payouts")]
+    validate_active_algorithm(
+        config.active_payout_algorithm_id.as_ref(),
+        enums::TransactionType::Payout,
+    )?;
+    #[cfg(not(feature = "payouts"))]
+    utils::when(config.active_payout_algorithm_id.is_some(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "payout routing algorithms cannot be imported".to_string(),
+        })
     })?;
 
-    let mut dynamic_routing_algo_ref: routing_types::DynamicRoutingAlgorithmRef = business_profile
-        .dynamic_routing_algorithm
-        .clone()
-        .map(|val| val.parse_value("DynamicRoutingAlgorithmRef"))
-        .transpose()
+    let profile_mcas = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            key_manager_state,
+            merchant_context.get_merchant_account().get_id(),
+            true,
+            merchant_context.get_merchant_key_store(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_context
+                .get_merchant_account()
+                .get_id()
+                .get_string_repr()
+                .to_owned(),
+        })?
+        .into_iter()
+        .filter(|mca| mca.profile_id == *business_profile.get_id())
+        .collect::<Vec<_>>();
+    let mca_id_mapping = merchant_connector_id_mapping.as_ref();
+
+    // Remap and validate everything before anything is written, so that an invalid document is
+    // rejected without any changes to the profile. The writes below are not atomic: the algorithms
+    // are created one at a time, and some are also registered with the decision engine. If a later
+    // write fails, the algorithms created before it are left under the profile without being
+    // activated.
+    let mut algorithms = config.algorithms;
+    for algorithm in algorithms.iter_mut() {
+        helpers::remap_connectors_in_routing_algorithm(
+            &mut algorithm.algorithm,
+            &profile_mcas,
+            mca_id_mapping,
+        )?;
+        if algorithm
+            .algorithm
+            .should_validate_connectors_in_routing_config()
+        {
+            helpers::validate_connectors_in_routing_config(
+                &state,
+                merchant_context.get_merchant_key_store(),
+                merchant_context.get_merchant_account().get_id(),
+                business_profile.get_id(),
+                &algorithm.algorithm,
+            )
+            .await?;
+        }
+    }
+
+    let mut default_fallback_connectors = config.default_fallback_connectors;
+    for choice in default_fallback_connectors.iter_mut() {
+        helpers::remap_routable_connector_choice(choice, &profile_mcas, mca_id_mapping)?;
+    }
+
+    let mut payment_method_default_fallback_connectors =
+        config.payment_method_default_fallback_connectors;
+    for payment_method_config in payment_method_default_fallback_connectors.iter_mut() {
+        for choice in payment_method_config.connectors.iter_mut() {
+            helpers::remap_routable_connector_choice(choice, &profile_mcas, mca_id_mapping)?;
+        }
+    }
+
+    let mut imported_algorithms = Vec::with_capacity(algorithms.len());
+    for algorithm in algorithms {
+        let record = Box::pin(create_routing_algorithm_under_profile(
+            state.clone(),
+            merchant_context.clone(),
+            authentication_profile_id.clone(),
+            routing_types::RoutingConfigRequest {
+                name: Some(algorithm.name),
+                description: Some(algorithm.description),
+                algorithm: Some(algorithm.algorithm),
+                profile_id: Some(business_profile.get_id().to_owned()),
+                transaction_type: Some(algorithm.transaction_type),
+                activate_at: None,
+            },
+            algorithm.transaction_type,
+            created_by.clone(),
+        ))
+        .await?
+        .get_json_body()
         .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable(
-            "unable to deserialize dynamic routing algorithm ref from business profile",
-        )?
-        .unwrap_or_default();
+        .attach_printable("Failed to get the imported routing algorithm")?;
 
-    dynamic_routing_algo_ref.update_volume_split(Some(routing_info.split));
+        imported_algorithms.push(routing_types::ImportedRoutingAlgorithm {
+            source_algorithm_id: algorithm.algorithm_id,
+            algorithm: record,
+        });
+    }
 
-    helpers::update_business_profile_active_dynamic_algorithm_ref(
-        db,
-        &((&state).into()),
-        merchant_context.get_merchant_key_store(),
-        business_profile.clone(),
-        dynamic_routing_algo_ref.clone(),
-    )
-    .await?;
+    let get_imported_algorithm_id =
+        |source_algorithm_id: Option<common_utils::id_type::RoutingId>| {
+            source_algorithm_id.and_then(|source_algorithm_id| {
+                imported_algorithms
+                    .iter()
+                    .find(|imported| imported.source_algorithm_id == source_algorithm_id)
+                    .map(|imported| imported.algorithm.id.clone())
+            })
+        };
+    let active_payment_algorithm_id = get_imported_algorithm_id(config.active_payment_algorithm_id);
+    let active_payout_algorithm_id = get_imported_algorithm_id(config.active_payout_algorithm_id);
+
+    if let Some(algorithm_id) = active_payment_algorithm_id.clone() {
+        Box::pin(link_routing_config(
+            state.clone(),
+            merchant_context.clone(),
+            authentication_profile_id.clone(),
+            algorithm_id,
+            enums::TransactionType::Payment,
+            created_by.clone(),
+        ))
+        .await?;
+    }
 
-    Ok(service_api::ApplicationResponse::Json(routing_info))
+    #[cfg(feature = "payouts")]
+    if let Some(algorithm_id) = active_payout_algorithm_id.clone() {
+        Box::pin(link_routing_config(
+            state.clone(),
+            merchant_context.clone(),
+            authentication_profile_id.clone(),
+            algorithm_id,
+            enums::TransactionType::Payout,
+            created_by.clone(),
+        ))
+        .await?;
+    }
+
+    // The default fallback connectors of a profile always hold all of its connectors, so only the
+    // order of the imported connectors is applied, followed by the connectors missing from it
+    if !default_fallback_connectors.is_empty() {
+        let current_default_config = helpers::get_merchant_default_config(
+            db,
+            business_profile.get_id().get_string_repr(),
+            &enums::TransactionType::Payment,
+        )
+        .await?;
+
+        let is_same_choice =
+            |a: &routing_types::RoutableConnectorChoice,
+             b: &routing_types::RoutableConnectorChoice| {
+                a.connector == b.connector && a.merchant_connector_id == b.merchant_connector_id
+            };
+
+        let mut updated_default_config = Vec::with_capacity(current_default_config.len());
+        for choice in default_fallback_connectors
+            .into_iter()
+            .chain(current_default_config.iter().cloned())
+        {
+            if current_default_config
+                .iter()
+                .any(|current| is_same_choice(current, &choice))
+                && !updated_default_config
+                    .iter()
+                    .any(|updated| is_same_choice(updated, &choice))
+            {
+                updated_default_config.push(choice);
+            }
+        }
+
+        let is_reordered = current_default_config
+            .iter()
+            .zip(updated_default_config.iter())
+            .any(|(current, updated)| !is_same_choice(current, updated));
+        if is_reordered {
+            update_default_routing_config_for_profile(
+                state.clone(),
+                merchant_context.clone(),
+                updated_default_config,
+                business_profile.get_id().to_owned(),
+                &enums::TransactionType::Payment,
+                created_by.clone(),
+            )
+            .await?;
+        }
+    }
+
+    for payment_method_config in payment_method_default_fallback_connectors {
+        update_payment_method_default_routing_config(
+            state.clone(),
+            merchant_context.clone(),
+            authentication_profile_id.clone(),
+            routing_types::PaymentMethodDefaultRoutingConfig {
+                profile_id: business_profile.get_id().to_owned(),
+                ..payment_method_config
+            },
+            created_by.clone(),
+        )
+        .await?;
+    }
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingConfigImportResponse {
+            profile_id: business_profile.get_id().to_owned(),
+            algorithms: imported_algorithms,
+            active_payment_algorithm_id,
+            active_payout_algorithm_id,
+        },
+    ))
 }
 
 #[cfg(feature = "v1")]
@@ -1915,8 +3874,10 @@ pub async fn retrieve_dynamic_routing_volume_split(
 pub async fn success_based_routing_update_configs(
     state: SessionState,
     request: routing_types::SuccessBasedRoutingConfig,
+    merchant_context: domain::MerchantContext,
     algorithm_id: common_utils::id_type::RoutingId,
     profile_id: common_utils::id_type::ProfileId,
+    created_by: Option<common_utils::types::CreatedBy>,
 ) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
     metrics::ROUTING_UPDATE_CONFIG_FOR_PROFILE.add(
         1,
@@ -1929,6 +3890,20 @@ pub async fn success_based_routing_update_configs(
         ),
     );
     let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
 
     let dynamic_routing_algo_to_update = db
         .find_routing_algorithm_by_profile_id_algorithm_id(&profile_id, &algorithm_id)
@@ -1958,11 +3933,26 @@ pub async fn success_based_routing_update_configs(
         algorithm_for: dynamic_routing_algo_to_update.algorithm_for,
         decision_engine_routing_id: None,
     };
-    let record = db
-        .insert_routing_algorithm(algo)
-        .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Unable to insert record in routing algorithm table")?;
+    let routing_audit_log = helpers::RoutingAuditLogDetails {
+        action: storage_enums::RoutingAuditAction::DynamicRoutingUpdate,
+        transaction_type: algo.algorithm_for,
+        previous_algorithm_id: Some(algorithm_id.clone()),
+        new_algorithm_id: Some(algo.algorithm_id.clone()),
+        config_diff: helpers::get_routing_config_diff(
+            &dynamic_routing_algo_to_update.algorithm_data,
+            &algo.algorithm_data,
+        ),
+        created_by,
+    };
+    let (record, routing_audit_log) =
+        helpers::insert_routing_algorithm_with_audit_log(db, algo, routing_audit_log).await?;
+    helpers::trigger_routing_config_webhook(
+        &state,
+        &merchant_context,
+        &business_profile,
+        routing_audit_log,
+    )
+    .await;
 
     // redact cache for success based routing configs
     let cache_key = format!(
@@ -2014,8 +4004,10 @@ pub async fn success_based_routing_update_configs(
 pub async fn elimination_routing_update_configs(
     state: SessionState,
     request: routing_types::EliminationRoutingConfig,
+    merchant_context: domain::MerchantContext,
     algorithm_id: common_utils::id_type::RoutingId,
     profile_id: common_utils::id_type::ProfileId,
+    created_by: Option<common_utils::types::CreatedBy>,
 ) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
     metrics::ROUTING_UPDATE_CONFIG_FOR_PROFILE.add(
         1,
@@ -2029,6 +4021,20 @@ pub async fn elimination_routing_update_configs(
     );
 
     let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
 
     let dynamic_routing_algo_to_update = db
         .find_routing_algorithm_by_profile_id_algorithm_id(&profile_id, &algorithm_id)
@@ -2060,12 +4066,27 @@ pub async fn elimination_routing_update_configs(
         algorithm_for: dynamic_routing_algo_to_update.algorithm_for,
         decision_engine_routing_id: None,
     };
+    let routing_audit_log = helpers::RoutingAuditLogDetails {
+        action: storage_enums::RoutingAuditAction::DynamicRoutingUpdate,
+        transaction_type: algo.algorithm_for,
+        previous_algorithm_id: Some(algorithm_id.clone()),
+        new_algorithm_id: Some(algo.algorithm_id.clone()),
+        config_diff: helpers::get_routing_config_diff(
+            &dynamic_routing_algo_to_update.algorithm_data,
+            &algo.algorithm_data,
+        ),
+        created_by,
+    };
 
-    let record = db
-        .insert_routing_algorithm(algo)
-        .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Unable to insert record in routing algorithm table")?;
+    let (record, routing_audit_log) =
+        helpers::insert_routing_algorithm_with_audit_log(db, algo, routing_audit_log).await?;
+    helpers::trigger_routing_config_webhook(
+        &state,
+        &merchant_context,
+        &business_profile,
+        routing_audit_log,
+    )
+    .await;
 
     // redact cache for elimination routing configs
     let cache_key = format!(
@@ -2121,6 +4142,7 @@ pub async fn contract_based_dynamic_routing_setup(
     profile_id: common_utils::id_type::ProfileId,
     feature_to_enable: routing_types::DynamicRoutingFeatures,
     config: Option<routing_types::ContractBasedRoutingConfig>,
+    created_by: Option<common_utils::types::CreatedBy>,
 ) -> RouterResult<service_api::ApplicationResponse<routing_types::RoutingDictionaryRecord>> {
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
@@ -2179,10 +4201,11 @@ pub async fn contract_based_dynamic_routing_setup(
             .attach_printable("Failed to get dynamic_routing_algo_ref")?;
         return helpers::disable_dynamic_routing_algorithm(
             &state,
-            merchant_context.get_merchant_key_store().clone(),
+            &merchant_context,
             business_profile,
             algorithm,
             routing_types::DynamicRoutingType::ContractBasedRouting,
+            created_by,
         )
         .await;
     }
@@ -2194,6 +4217,9 @@ pub async fn contract_based_dynamic_routing_setup(
     let merchant_id = business_profile.merchant_id.clone();
     let algorithm_id = common_utils::generate_routing_id_of_default_length();
     let timestamp = common_utils::date_time::now();
+    let previous_algorithm_id = dynamic_routing_algo_ref.as_ref().and_then(|algo| {
+        algo.get_algorithm_id(routing_types::DynamicRoutingType::ContractBasedRouting)
+    });
 
     let algo = RoutingAlgorithm {
         algorithm_id: algorithm_id.clone(),
@@ -2213,7 +4239,7 @@ pub async fn contract_based_dynamic_routing_setup(
     // 2. if dynamic_routing_algo_ref is not present, create a new dynamic_routing_algo_ref with contract algo set up
     let final_algorithm = if let Some(mut algo) = dynamic_routing_algo_ref {
         algo.update_algorithm_id(
-            algorithm_id,
+            algorithm_id.clone(),
             feature_to_enable,
             routing_types::DynamicRoutingType::ContractBasedRouting,
         );
@@ -2295,15 +4321,39 @@ pub async fn contract_based_dynamic_routing_setup(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Unable to insert record in routing algorithm table")?;
 
-    helpers::update_business_profile_active_dynamic_algorithm_ref(
+    let routing_audit_log = helpers::update_business_profile_active_dynamic_algorithm_ref(
         db,
         key_manager_state,
         merchant_context.get_merchant_key_store(),
-        business_profile,
+        business_profile.clone(),
         final_algorithm,
+        helpers::RoutingAuditLogDetails {
+            action: storage_enums::RoutingAuditAction::DynamicRoutingUpdate,
+            transaction_type: enums::TransactionType::Payment,
+            previous_algorithm_id,
+            new_algorithm_id: Some(algorithm_id.clone()),
+            config_diff: None,
+            created_by,
+        },
     )
     .await?;
 
+    helpers::record_routing_version(
+        &state,
+        &business_profile,
+        &algorithm_id,
+        None,
+        &enums::TransactionType::Payment,
+    )
+    .await?;
+    helpers::trigger_routing_config_webhook(
+        &state,
+        &merchant_context,
+        &business_profile,
+        routing_audit_log,
+    )
+    .await;
+
     let new_record = record.foreign_into();
 
     metrics::ROUTING_CREATE_SUCCESS_RESPONSE.add(
@@ -2320,6 +4370,7 @@ pub async fn contract_based_routing_update_configs(
     merchant_context: domain::MerchantContext,
     algorithm_id: common_utils::id_type::RoutingId,
     profile_id: common_utils::id_type::ProfileId,
+    created_by: Option<common_utils::types::CreatedBy>,
 ) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
     metrics::ROUTING_UPDATE_CONFIG_FOR_PROFILE.add(
         1,
@@ -2334,6 +4385,19 @@ pub async fn contract_based_routing_update_configs(
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
 
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
     let dynamic_routing_algo_to_update = db
         .find_routing_algorithm_by_profile_id_algorithm_id(&profile_id, &algorithm_id)
         .await
@@ -2399,11 +4463,26 @@ pub async fn contract_based_routing_update_configs(
         algorithm_for: dynamic_routing_algo_to_update.algorithm_for,
         decision_engine_routing_id: None,
     };
-    let record = db
-        .insert_routing_algorithm(algo)
-        .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Unable to insert record in routing algorithm table")?;
+    let routing_audit_log = helpers::RoutingAuditLogDetails {
+        action: storage_enums::RoutingAuditAction::DynamicRoutingUpdate,
+        transaction_type: algo.algorithm_for,
+        previous_algorithm_id: Some(algorithm_id.clone()),
+        new_algorithm_id: Some(algo.algorithm_id.clone()),
+        config_diff: helpers::get_routing_config_diff(
+            &dynamic_routing_algo_to_update.algorithm_data,
+            &algo.algorithm_data,
+        ),
+        created_by,
+    };
+    let (record, routing_audit_log) =
+        helpers::insert_routing_algorithm_with_audit_log(db, algo, routing_audit_log).await?;
+    helpers::trigger_routing_config_webhook(
+        &state,
+        &merchant_context,
+        &business_profile,
+        routing_audit_log,
+    )
+    .await;
 
     // redact cache for contract based routing configs
     let cache_key = format!(
@@ -2691,6 +4770,28 @@ pub async fn migrate_rules_for_profile(
             Ok(EuclidAlgorithm::VolumeSplit(splits)) => Some(StaticRoutingAlgorithm::VolumeSplit(
                 splits.into_iter().map(Into::into).collect(),
             )),
+            Ok(EuclidAlgorithm::StickyVolumeSplit(_)) => {
+                router_env::logger::info!(
+                    ?algorithm_id,
+                    "Skipping sticky volume split migration (not supported by decision engine)"
+                );
+                push_error(
+                    algorithm_id.clone(),
+                    "Sticky volume split migration not supported".into(),
+                );
+                None
+            }
+            Ok(EuclidAlgorithm::Cost(_)) => {
+                router_env::logger::info!(
+                    ?algorithm_id,
+                    "Skipping cost algorithm migration (not supported by decision engine)"
+                );
+                push_error(
+                    algorithm_id.clone(),
+                    "Cost algorithm migration not supported".into(),
+                );
+                None
+            }
             Ok(EuclidAlgorithm::ThreeDsDecisionRule(_)) => {
                 router_env::logger::info!(
                     ?algorithm_id,
@@ -54,17 +54,22 @@ use crate::{
 };
 #[cfg(feature = "v1")]
 use crate::{
-    core::payments::{
-        routing::utils::{self as routing_utils, DecisionEngineApiHandler},
-        OperationSessionGetters, OperationSessionSetters,
+    core::{
+        api_locking,
+        payments::{
+            routing::utils::{self as routing_utils, DecisionEngineApiHandler},
+            OperationSessionGetters, OperationSessionSetters,
+        },
+        webhooks as webhooks_core,
     },
+    routes::lock_utils,
     services,
+    types::transformers::ForeignInto,
 };
 #[cfg(all(feature = "dynamic_routing", feature = "v1"))]
 use crate::{
     core::{metrics as core_metrics, routing},
     routes::app::SessionStateInfo,
-    types::transformers::ForeignInto,
 };
 pub const SUCCESS_BASED_DYNAMIC_ROUTING_ALGORITHM: &str =
     "Success rate based dynamic routing algorithm";
@@ -152,6 +157,102 @@ pub async fn update_merchant_default_config(
     Ok(())
 }
 
+/// Updates the default fallback connectors of a profile along with the audit log entry of the
+/// change
+pub async fn update_profile_default_config(
+    db: &dyn StorageInterface,
+    business_profile: &domain::Profile,
+    connectors: Vec<routing_types::RoutableConnectorChoice>,
+    routing_audit_log: RoutingAuditLogDetails,
+) -> RouterResult<storage::RoutingAuditLog> {
+    let key = get_default_config_key(
+        business_profile.get_id().get_string_repr(),
+        &routing_audit_log.transaction_type,
+    );
+    let config_str = connectors
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize profile default routing config during update")?;
+
+    db.upsert_config_with_routing_audit_log(
+        key,
+        Some(config_str),
+        routing_audit_log.construct_new(&business_profile.merchant_id, business_profile.get_id()),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Error updating the default routing config of the profile in DB")
+}
+
+/// Provides the default fallback connectors of a profile for the specified payment method type,
+/// if the profile has a separate fallback ordering configured for it. Payment method types without
+/// one are cached as such, as the config is looked up whenever a payment falls back.
+#[cfg(feature = "v1")]
+pub async fn get_payment_method_default_config(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+    payment_method_type: storage::enums::PaymentMethodType,
+) -> RouterResult<Option<Vec<routing_types::RoutableConnectorChoice>>> {
+    let key =
+        get_payment_method_default_config_key(profile_id.get_string_repr(), payment_method_type);
+    db.find_config_by_key_unwrap_or(&key, Some(serde_json::Value::Null.to_string()))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error fetching payment method default config for profile")?
+        .config
+        .parse_struct("Vec<RoutableConnectorChoice>")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Payment method default config has invalid structure")
+}
+
+#[cfg(feature = "v1")]
+pub async fn upsert_payment_method_default_config(
+    db: &dyn StorageInterface,
+    business_profile: &domain::Profile,
+    payment_method_type: storage::enums::PaymentMethodType,
+    connectors: Vec<routing_types::RoutableConnectorChoice>,
+    routing_audit_log: RoutingAuditLogDetails,
+) -> RouterResult<storage::RoutingAuditLog> {
+    let key = get_payment_method_default_config_key(
+        business_profile.get_id().get_string_repr(),
+        payment_method_type,
+    );
+    let config_str = connectors
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize payment method default config")?;
+
+    db.upsert_config_with_routing_audit_log(
+        key,
+        Some(config_str),
+        routing_audit_log.construct_new(&business_profile.merchant_id, business_profile.get_id()),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Error updating payment method default config in DB")
+}
+
+#[cfg(feature = "v1")]
+pub async fn delete_payment_method_default_config(
+    db: &dyn StorageInterface,
+    business_profile: &domain::Profile,
+    payment_method_type: storage::enums::PaymentMethodType,
+    routing_audit_log: RoutingAuditLogDetails,
+) -> RouterResult<storage::RoutingAuditLog> {
+    let key = get_payment_method_default_config_key(
+        business_profile.get_id().get_string_repr(),
+        payment_method_type,
+    );
+    db.upsert_config_with_routing_audit_log(
+        key,
+        None,
+        routing_audit_log.construct_new(&business_profile.merchant_id, business_profile.get_id()),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Error deleting payment method default config from DB")
+}
+
 pub async fn update_merchant_routing_dictionary(
     db: &dyn StorageInterface,
     merchant_id: &str,
@@ -175,6 +276,1414 @@ pub async fn update_merchant_routing_dictionary(
     Ok(())
 }
 
+/// Maximum number of activations retained in the routing version history of a profile
+#[cfg(feature = "v1")]
+const MAX_ROUTING_VERSION_HISTORY_LENGTH: usize = 50;
+
+#[cfg(feature = "v1")]
+async fn find_routing_version_history(
+    db: &dyn StorageInterface,
+    key: &str,
+) -> RouterResult<Option<routing_types::RoutingVersionHistory>> {
+    match db.find_config_by_key(key).await {
+        Ok(config) => config
+            .config
+            .parse_struct("RoutingVersionHistory")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Routing version history has invalid structure")
+            .map(Some),
+        Err(e) if e.current_context().is_db_not_found() => Ok(None),
+        Err(e) => Err(e)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error fetching routing version history for profile"),
+    }
+}
+
+/// Provides the activation history of the routing algorithms under a profile, oldest first
+#[cfg(feature = "v1")]
+pub async fn get_routing_version_history(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+    transaction_type: &storage::enums::TransactionType,
+) -> RouterResult<routing_types::RoutingVersionHistory> {
+    let key = get_routing_version_history_key(profile_id.get_string_repr(), transaction_type);
+    Ok(find_routing_version_history(db, &key)
+        .await?
+        .unwrap_or_else(|| {
+            routing_types::RoutingVersionHistory::new(profile_id.clone(), *transaction_type)
+        }))
+}
+
+/// Appends a new version to the routing version history of a profile whenever an algorithm
+/// gets activated, so that it can be rolled back to later. The history is read, modified and
+/// written back as a whole, so concurrent activations of a profile are serialized with a lock
+/// on the history.
+#[cfg(feature = "v1")]
+pub async fn record_routing_version(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    algorithm_id: &id_type::RoutingId,
+    rolled_back_from: Option<u32>,
+    transaction_type: &storage::enums::TransactionType,
+) -> RouterResult<routing_types::RoutingConfigVersion> {
+    let key = get_routing_version_history_key(
+        business_profile.get_id().get_string_repr(),
+        transaction_type,
+    );
+    let lock_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: key.clone(),
+            api_identifier: lock_utils::ApiIdentifier::Routing,
+            override_lock_retries: None,
+        },
+    };
+
+    lock_action
+        .clone()
+        .perform_locking_action(state, business_profile.merchant_id.clone())
+        .await?;
+
+    let version = append_routing_version(
+        state.store.as_ref(),
+        business_profile.get_id(),
+        key,
+        algorithm_id,
+        rolled_back_from,
+        transaction_type,
+    )
+    .await;
+
+    lock_action
+        .free_lock_action(state, business_profile.merchant_id.clone())
+        .await?;
+
+    version
+}
+
+#[cfg(feature = "v1")]
+async fn append_routing_version(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+    key: String,
+    algorithm_id: &id_type::RoutingId,
+    rolled_back_from: Option<u32>,
+    transaction_type: &storage::enums::TransactionType,
+) -> RouterResult<routing_types::RoutingConfigVersion> {
+    let existing_history = find_routing_version_history(db, &key).await?;
+    let is_new_history = existing_history.is_none();
+    let mut history = existing_history.unwrap_or_else(|| {
+        routing_types::RoutingVersionHistory::new(profile_id.clone(), *transaction_type)
+    });
+
+    let version = routing_types::RoutingConfigVersion {
+        version: history
+            .get_latest_version()
+            .map_or(1, |latest| latest.version.saturating_add(1)),
+        algorithm_id: algorithm_id.clone(),
+        activated_at: common_utils::date_time::now_unix_timestamp(),
+        rolled_back_from,
+    };
+    history.versions.push(version.clone());
+
+    if history.versions.len() > MAX_ROUTING_VERSION_HISTORY_LENGTH {
+        let overflow = history.versions.len() - MAX_ROUTING_VERSION_HISTORY_LENGTH;
+        history.versions.drain(..overflow);
+    }
+
+    let history_str = history
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize routing version history")?;
+
+    if is_new_history {
+        db.insert_config(configs::ConfigNew {
+            key,
+            config: history_str,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error inserting routing version history into DB")?;
+    } else {
+        db.update_config_by_key(
+            &key,
+            configs::ConfigUpdate::Update {
+                config: Some(history_str),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating routing version history in DB")?;
+    }
+
+    Ok(version)
+}
+
+/// Number of recent authorization latencies retained per connector for latency based routing
+#[cfg(feature = "v1")]
+const CONNECTOR_LATENCY_WINDOW_SIZE: usize = 100;
+
+/// Expiry (in seconds) of the recorded latencies, so that connectors which stop receiving
+/// traffic are not deprioritized forever
+#[cfg(feature = "v1")]
+const CONNECTOR_LATENCY_SAMPLES_TTL: i64 = 60 * 60;
+
+/// Percentile of the recorded latencies which is compared against the configured threshold
+#[cfg(feature = "v1")]
+const CONNECTOR_LATENCY_PERCENTILE: usize = 95;
+
+/// Fields of the connector health stats hash
+#[cfg(feature = "v1")]
+const CONNECTOR_HEALTH_REQUEST_COUNT_FIELD: &str = "request_count";
+#[cfg(feature = "v1")]
+const CONNECTOR_HEALTH_FAILURE_COUNT_FIELD: &str = "failure_count";
+
+/// Fields of the connector health breaker hash, holding unix timestamps
+#[cfg(feature = "v1")]
+const CONNECTOR_HEALTH_OPENED_AT_FIELD: &str = "opened_at";
+#[cfg(feature = "v1")]
+const CONNECTOR_HEALTH_OPEN_UNTIL_FIELD: &str = "open_until";
+
+/// Breaker transitions returned by `RECORD_CONNECTOR_HEALTH_OUTCOME_SCRIPT`
+#[cfg(feature = "v1")]
+const CONNECTOR_HEALTH_BREAKER_CLOSED: u64 = 1;
+#[cfg(feature = "v1")]
+const CONNECTOR_HEALTH_BREAKER_OPENED: u64 = 2;
+
+/// Returns 1 for each connector which has to be skipped. Once the breaker of a connector is half
+/// open, the trial key is set by the first request, which is let through as the trial request.
+#[cfg(feature = "v1")]
+const GET_CONNECTORS_SKIPPED_BY_HEALTH_SCRIPT: &str = r#"
+    local now = tonumber(ARGV[1])
+    local is_skipped = {}
+    for i = 1, #KEYS, 2 do
+        local open_until = tonumber(redis.call("HGET", KEYS[i], "open_until"))
+        if not open_until then
+            is_skipped[#is_skipped + 1] = 0
+        elseif now < open_until then
+            is_skipped[#is_skipped + 1] = 1
+        elseif redis.call("SET", KEYS[i + 1], ARGV[1], "NX", "EX", ARGV[2]) then
+            is_skipped[#is_skipped + 1] = 0
+        else
+            is_skipped[#is_skipped + 1] = 1
+        end
+    end
+    return is_skipped
+"#;
+
+/// Counts the outcome within the current window of a closed breaker and opens the breaker once
+/// the failure rate reaches the threshold. The outcome of a request made while the breaker is
+/// half open closes it or opens it again.
+#[cfg(feature = "v1")]
+const RECORD_CONNECTOR_HEALTH_OUTCOME_SCRIPT: &str = r#"
+    local now = tonumber(ARGV[1])
+    local is_failure = tonumber(ARGV[2])
+    local open_until = tonumber(redis.call("HGET", KEYS[2], "open_until"))
+    if open_until then
+        -- Requests which were in flight when the breaker opened are not counted
+        if now < open_until then
+            return 0
+        end
+        if is_failure == 0 then
+            redis.call("DEL", KEYS[1], KEYS[2], KEYS[3])
+            return 1
+        end
+    else
+        local request_count = redis.call("HINCRBY", KEYS[1], "request_count", 1)
+        local failure_count = redis.call("HINCRBY", KEYS[1], "failure_count", is_failure)
+        -- The window starts with the first request recorded after the previous one expired
+        if request_count == 1 then
+            redis.call("EXPIRE", KEYS[1], ARGV[3])
+        end
+        if request_count < tonumber(ARGV[4])
+            or failure_count * 100 < tonumber(ARGV[5]) * request_count then
+            return 0
+        end
+    end
+
+    redis.call("DEL", KEYS[1], KEYS[3])
+    redis.call("HSET", KEYS[2], "opened_at", now, "open_until", now + tonumber(ARGV[6]))
+    redis.call("EXPIRE", KEYS[2], tonumber(ARGV[6]) + tonumber(ARGV[3]))
+    return 2
+"#;
+
+/// Appends a latency to the samples of a connector, trims them to the window size and refreshes
+/// their expiry in a single step, so that concurrent writers can't grow the window
+#[cfg(feature = "v1")]
+const RECORD_CONNECTOR_LATENCY_SCRIPT: &str = r#"
+    redis.call("RPUSH", KEYS[1], ARGV[1])
+    redis.call("LTRIM", KEYS[1], -tonumber(ARGV[2]), -1)
+    redis.call("EXPIRE", KEYS[1], ARGV[3])
+    return 1
+"#;
+
+/// Reads the samples of all the given connectors in a single round trip
+#[cfg(feature = "v1")]
+const GET_CONNECTOR_LATENCIES_SCRIPT: &str = r#"
+    local samples = {}
+    for i, key in ipairs(KEYS) do
+        samples[i] = redis.call("LRANGE", key, 0, -1)
+    end
+    return samples
+"#;
+
+/// Profiles without a config are cached with the default, disabled config, so that routing
+/// doesn't query the database for every payment of such profiles
+#[cfg(feature = "v1")]
+pub async fn get_latency_based_routing_config(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<routing_types::LatencyBasedRoutingConfig> {
+    let key = get_latency_routing_config_key(profile_id.get_string_repr());
+    let default_config = routing_types::LatencyBasedRoutingConfig::default()
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize latency based routing config")?;
+
+    db.find_config_by_key_unwrap_or(&key, Some(default_config))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error fetching latency based routing config for profile")?
+        .config
+        .parse_struct("LatencyBasedRoutingConfig")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Latency based routing config has invalid structure")
+}
+
+#[cfg(feature = "v1")]
+pub async fn upsert_latency_based_routing_config(
+    db: &dyn StorageInterface,
+    business_profile: &domain::Profile,
+    config: &routing_types::LatencyBasedRoutingConfig,
+    routing_audit_log: RoutingAuditLogDetails,
+) -> RouterResult<storage::RoutingAuditLog> {
+    let key = get_latency_routing_config_key(business_profile.get_id().get_string_repr());
+    let config_str = config
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize latency based routing config")?;
+
+    db.upsert_config_with_routing_audit_log(
+        key,
+        Some(config_str),
+        routing_audit_log.construct_new(&business_profile.merchant_id, business_profile.get_id()),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Error updating latency based routing config in DB")
+}
+
+/// Records the authorization latency of a connector, retaining only the most recent
+/// `CONNECTOR_LATENCY_WINDOW_SIZE` samples
+#[cfg(feature = "v1")]
+pub async fn record_connector_latency(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+    connector: &str,
+    merchant_connector_id: Option<&id_type::MerchantConnectorAccountId>,
+    latency_ms: u64,
+) -> RouterResult<()> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let key = get_connector_latency_key(
+        profile_id.get_string_repr(),
+        connector,
+        merchant_connector_id,
+    );
+    let values = vec![
+        latency_ms.to_string(),
+        CONNECTOR_LATENCY_WINDOW_SIZE.to_string(),
+        CONNECTOR_LATENCY_SAMPLES_TTL.to_string(),
+    ];
+
+    redis_conn
+        .evaluate_redis_script::<_, u64>(
+            RECORD_CONNECTOR_LATENCY_SCRIPT,
+            vec![redis_conn.add_prefix(&key)],
+            values,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record connector latency")?;
+
+    Ok(())
+}
+
+/// Fetches the recent latency of the given connectors, in the same order
+#[cfg(feature = "v1")]
+pub async fn get_connectors_latency_stats(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+    config: &routing_types::LatencyBasedRoutingConfig,
+    connectors: &[routing_types::RoutableConnectorChoice],
+) -> RouterResult<Vec<routing_types::ConnectorLatencyStats>> {
+    if connectors.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let keys = connectors
+        .iter()
+        .map(|connector| {
+            redis_conn.add_prefix(&get_connector_latency_key(
+                profile_id.get_string_repr(),
+                &connector.connector.to_string(),
+                connector.merchant_connector_id.as_ref(),
+            ))
+        })
+        .collect::<Vec<_>>();
+
+    let connectors_samples = redis_conn
+        .evaluate_redis_script::<_, Vec<Vec<String>>>(
+            GET_CONNECTOR_LATENCIES_SCRIPT,
+            keys,
+            Vec::<String>::new(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch connector latency samples")?;
+
+    Ok(connectors
+        .iter()
+        .zip(connectors_samples)
+        .map(|(connector, samples)| {
+            let samples = samples
+                .into_iter()
+                .filter_map(|sample| sample.parse::<u64>().ok())
+                .collect::<Vec<_>>();
+            let mut latency_stats = routing_types::ConnectorLatencyStats {
+                connector: connector.clone(),
+                sample_count: samples.len(),
+                p95_latency_ms: calculate_latency_percentile(samples, CONNECTOR_LATENCY_PERCENTILE),
+                is_deprioritized: false,
+            };
+            latency_stats.is_deprioritized =
+                config.enabled && config.is_latency_above_threshold(&latency_stats);
+            latency_stats
+        })
+        .collect())
+}
+
+/// Computes the nearest-rank percentile of the given latency samples
+pub fn calculate_latency_percentile(mut samples: Vec<u64>, percentile: usize) -> Option<u64> {
+    samples.sort_unstable();
+    let rank = percentile.saturating_mul(samples.len()).div_ceil(100);
+    samples.get(rank.saturating_sub(1)).copied()
+}
+
+/// Breaker of a connector, persisted while the breaker is open or half open
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone)]
+pub struct ConnectorHealthBreaker {
+    pub opened_at: time::PrimitiveDateTime,
+    pub open_until: time::PrimitiveDateTime,
+}
+
+#[cfg(feature = "v1")]
+impl ConnectorHealthBreaker {
+    pub fn get_state(&self) -> routing_types::ConnectorCircuitBreakerState {
+        if common_utils::date_time::now() < self.open_until {
+            routing_types::ConnectorCircuitBreakerState::Open
+        } else {
+            routing_types::ConnectorCircuitBreakerState::HalfOpen
+        }
+    }
+
+    fn from_hash_fields(
+        fields: &std::collections::HashMap<String, String>,
+    ) -> RouterResult<Option<Self>> {
+        let get_timestamp = |field: &str| {
+            fields
+                .get(field)
+                .map(|timestamp| {
+                    timestamp
+                        .parse::<i64>()
+                        .ok()
+                        .and_then(|timestamp| {
+                            time::OffsetDateTime::from_unix_timestamp(timestamp).ok()
+                        })
+                        .map(common_utils::date_time::convert_to_pdt)
+                        .ok_or(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Invalid timestamp in connector health breaker")
+                })
+                .transpose()
+        };
+
+        Ok(
+            match (
+                get_timestamp(CONNECTOR_HEALTH_OPENED_AT_FIELD)?,
+                get_timestamp(CONNECTOR_HEALTH_OPEN_UNTIL_FIELD)?,
+            ) {
+                (Some(opened_at), Some(open_until)) => Some(Self {
+                    opened_at,
+                    open_until,
+                }),
+                _ => None,
+            },
+        )
+    }
+}
+
+#[cfg(feature = "v1")]
+pub async fn get_connector_health_breaker(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<Option<ConnectorHealthBreaker>> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let key = get_connector_health_breaker_key(
+        profile_id.get_string_repr(),
+        merchant_connector_id.get_string_repr(),
+    );
+
+    let fields = redis_conn
+        .get_hash_fields::<std::collections::HashMap<String, String>>(&key.as_str().into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch connector health breaker")?;
+
+    ConnectorHealthBreaker::from_hash_fields(&fields)
+}
+
+/// Finds which of the given connectors have to be skipped, in the same order, with a single
+/// script. A connector is skipped while its breaker is open. Once half open, a single trial
+/// request is let through until its outcome is recorded or the trial times out.
+#[cfg(feature = "v1")]
+pub async fn get_connectors_skipped_by_health_breaker(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+    merchant_connector_ids: &[&id_type::MerchantConnectorAccountId],
+) -> RouterResult<Vec<bool>> {
+    if merchant_connector_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let keys = merchant_connector_ids
+        .iter()
+        .flat_map(|merchant_connector_id| {
+            [
+                get_connector_health_breaker_key(
+                    profile_id.get_string_repr(),
+                    merchant_connector_id.get_string_repr(),
+                ),
+                get_connector_health_trial_key(
+                    profile_id.get_string_repr(),
+                    merchant_connector_id.get_string_repr(),
+                ),
+            ]
+        })
+        .map(|key| redis_conn.add_prefix(&key))
+        .collect::<Vec<_>>();
+    let values = vec![
+        common_utils::date_time::now_unix_timestamp().to_string(),
+        state
+            .conf
+            .connector_health
+            .half_open_trial_timeout_secs
+            .to_string(),
+    ];
+
+    Ok(redis_conn
+        .evaluate_redis_script::<_, Vec<u64>>(GET_CONNECTORS_SKIPPED_BY_HEALTH_SCRIPT, keys, values)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch connector health breakers")?
+        .into_iter()
+        .map(|is_skipped| is_skipped == 1)
+        .collect())
+}
+
+/// Records the outcome of a request to a connector and opens its breaker once the failure rate
+/// within the current window reaches the configured threshold. While half open, the outcome of
+/// the trial request decides whether the breaker closes or opens again. The transition is made
+/// by a single script, so that concurrent outcomes can't interleave.
+#[cfg(feature = "v1")]
+pub async fn record_connector_health_outcome(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    is_failure: bool,
+) -> RouterResult<()> {
+    let settings = &state.conf.connector_health;
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let keys = [
+        get_connector_health_stats_key(
+            profile_id.get_string_repr(),
+            merchant_connector_id.get_string_repr(),
+        ),
+        get_connector_health_breaker_key(
+            profile_id.get_string_repr(),
+            merchant_connector_id.get_string_repr(),
+        ),
+        get_connector_health_trial_key(
+            profile_id.get_string_repr(),
+            merchant_connector_id.get_string_repr(),
+        ),
+    ]
+    .iter()
+    .map(|key| redis_conn.add_prefix(key))
+    .collect::<Vec<_>>();
+    let values = vec![
+        common_utils::date_time::now_unix_timestamp().to_string(),
+        u8::from(is_failure).to_string(),
+        settings.window_secs.to_string(),
+        settings.minimum_request_count.to_string(),
+        settings.failure_rate_threshold.to_string(),
+        settings.open_duration_secs.to_string(),
+    ];
+
+    let transition = redis_conn
+        .evaluate_redis_script::<_, u64>(RECORD_CONNECTOR_HEALTH_OUTCOME_SCRIPT, keys, values)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record connector health outcome")?;
+
+    match transition {
+        CONNECTOR_HEALTH_BREAKER_CLOSED => logger::info!(
+            merchant_connector_id = merchant_connector_id.get_string_repr(),
+            "Closed connector health breaker"
+        ),
+        CONNECTOR_HEALTH_BREAKER_OPENED => logger::warn!(
+            merchant_connector_id = merchant_connector_id.get_string_repr(),
+            "Opened connector health breaker"
+        ),
+        _ => (),
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "v1")]
+pub async fn get_connector_health_status(
+    state: &SessionState,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+) -> RouterResult<routing_types::ConnectorHealthStatus> {
+    let merchant_connector_id = merchant_connector_account.get_id();
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let stats_key = get_connector_health_stats_key(
+        merchant_connector_account.profile_id.get_string_repr(),
+        merchant_connector_id.get_string_repr(),
+    );
+
+    let counts = redis_conn
+        .get_hash_fields::<std::collections::HashMap<String, String>>(&stats_key.as_str().into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch connector health stats")?;
+    let get_count = |field: &str| {
+        counts
+            .get(field)
+            .and_then(|count| count.parse::<u64>().ok())
+            .unwrap_or_default()
+    };
+
+    let breaker = get_connector_health_breaker(
+        state,
+        &merchant_connector_account.profile_id,
+        &merchant_connector_id,
+    )
+    .await?;
+
+    Ok(routing_types::ConnectorHealthStatus {
+        connector: merchant_connector_account
+            .connector_name
+            .parse::<api_models::enums::RoutableConnectors>()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Invalid connector name in merchant connector account")?,
+        profile_id: merchant_connector_account.profile_id.clone(),
+        state: breaker.as_ref().map_or(
+            routing_types::ConnectorCircuitBreakerState::Closed,
+            ConnectorHealthBreaker::get_state,
+        ),
+        request_count: get_count(CONNECTOR_HEALTH_REQUEST_COUNT_FIELD),
+        failure_count: get_count(CONNECTOR_HEALTH_FAILURE_COUNT_FIELD),
+        opened_at: breaker.as_ref().map(|breaker| breaker.opened_at),
+        open_until: breaker.map(|breaker| breaker.open_until),
+        merchant_connector_id,
+    })
+}
+
+/// Merchant connector accounts without a fee schedule are cached as such, so that cost routing
+/// doesn't query the database for them on every payment
+pub async fn find_connector_fee_schedule(
+    db: &dyn StorageInterface,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<Option<routing_types::ConnectorFeeSchedule>> {
+    let key = get_connector_fee_schedule_key(merchant_connector_id.get_string_repr());
+    db.find_config_by_key_unwrap_or(&key, Some(serde_json::Value::Null.to_string()))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error fetching connector fee schedule")?
+        .config
+        .parse_struct("ConnectorFeeSchedule")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Connector fee schedule has invalid structure")
+}
+
+/// Creates the fee schedule of a merchant connector account, or replaces the rules of the
+/// existing one
+#[cfg(feature = "v1")]
+pub async fn upsert_connector_fee_schedule(
+    db: &dyn StorageInterface,
+    business_profile: &domain::Profile,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    rules: Vec<routing_types::ConnectorFeeRule>,
+    created_by: Option<common_utils::types::CreatedBy>,
+) -> RouterResult<(
+    routing_types::ConnectorFeeSchedule,
+    storage::RoutingAuditLog,
+)> {
+    let key = get_connector_fee_schedule_key(merchant_connector_id.get_string_repr());
+    let timestamp = common_utils::date_time::now_unix_timestamp();
+    let existing_fee_schedule = find_connector_fee_schedule(db, merchant_connector_id).await?;
+
+    let fee_schedule = routing_types::ConnectorFeeSchedule {
+        profile_id: business_profile.get_id().to_owned(),
+        merchant_connector_id: merchant_connector_id.to_owned(),
+        rules,
+        created_at: existing_fee_schedule
+            .as_ref()
+            .map(|fee_schedule| fee_schedule.created_at)
+            .unwrap_or(timestamp),
+        modified_at: timestamp,
+    };
+    let fee_schedule_str = fee_schedule
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize connector fee schedule")?;
+
+    let routing_audit_log = RoutingAuditLogDetails {
+        action: storage::enums::RoutingAuditAction::FeeScheduleUpdate,
+        transaction_type: storage::enums::TransactionType::Payment,
+        previous_algorithm_id: None,
+        new_algorithm_id: None,
+        config_diff: get_routing_config_diff(
+            &serde_json::json!(existing_fee_schedule),
+            &serde_json::json!(fee_schedule),
+        ),
+        created_by,
+    };
+    let routing_audit_log = db
+        .upsert_config_with_routing_audit_log(
+            key,
+            Some(fee_schedule_str),
+            routing_audit_log
+                .construct_new(&business_profile.merchant_id, business_profile.get_id()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating connector fee schedule in DB")?;
+
+    Ok((fee_schedule, routing_audit_log))
+}
+
+#[cfg(feature = "v1")]
+pub async fn delete_connector_fee_schedule(
+    db: &dyn StorageInterface,
+    business_profile: &domain::Profile,
+    fee_schedule: &routing_types::ConnectorFeeSchedule,
+    created_by: Option<common_utils::types::CreatedBy>,
+) -> RouterResult<storage::RoutingAuditLog> {
+    let key = get_connector_fee_schedule_key(fee_schedule.merchant_connector_id.get_string_repr());
+    let routing_audit_log = RoutingAuditLogDetails {
+        action: storage::enums::RoutingAuditAction::FeeScheduleUpdate,
+        transaction_type: storage::enums::TransactionType::Payment,
+        previous_algorithm_id: None,
+        new_algorithm_id: None,
+        config_diff: get_routing_config_diff(
+            &serde_json::json!(fee_schedule),
+            &serde_json::Value::Null,
+        ),
+        created_by,
+    };
+
+    db.upsert_config_with_routing_audit_log(
+        key,
+        None,
+        routing_audit_log.construct_new(&business_profile.merchant_id, business_profile.get_id()),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Error deleting connector fee schedule from DB")
+}
+
+/// Provides the routing experiment of a profile. Profiles without an experiment are cached as
+/// such, as the experiment is looked up for every payment.
+#[cfg(feature = "v1")]
+pub async fn get_routing_experiment(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Option<routing_types::RoutingExperiment>> {
+    let key = get_routing_experiment_key(profile_id.get_string_repr());
+    db.find_config_by_key_unwrap_or(&key, Some(serde_json::Value::Null.to_string()))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error fetching routing experiment for profile")?
+        .config
+        .parse_struct("RoutingExperiment")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Routing experiment has invalid structure")
+}
+
+/// Stores the routing experiment of a profile, replacing the previous experiment if any
+#[cfg(feature = "v1")]
+pub async fn upsert_routing_experiment(
+    db: &dyn StorageInterface,
+    business_profile: &domain::Profile,
+    experiment: &routing_types::RoutingExperiment,
+    routing_audit_log: RoutingAuditLogDetails,
+) -> RouterResult<storage::RoutingAuditLog> {
+    let key = get_routing_experiment_key(business_profile.get_id().get_string_repr());
+    let config_str = experiment
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize routing experiment")?;
+
+    db.upsert_config_with_routing_audit_log(
+        key,
+        Some(config_str),
+        routing_audit_log.construct_new(&business_profile.merchant_id, business_profile.get_id()),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Error updating routing experiment in DB")
+}
+
+/// Assigns a payment to an arm of the routing experiment. The assignment only depends on the
+/// payment id, so that all attempts of a payment are routed with the same arm.
+#[cfg(feature = "v1")]
+pub fn get_routing_experiment_arm(
+    experiment: &routing_types::RoutingExperiment,
+    payment_id: &id_type::PaymentId,
+) -> storage::enums::RoutingExperimentArm {
+    let bucket = crc32fast::hash(payment_id.get_string_repr().as_bytes()) % 100;
+    if bucket < u32::from(experiment.treatment_percentage) {
+        storage::enums::RoutingExperimentArm::Treatment
+    } else {
+        storage::enums::RoutingExperimentArm::Control
+    }
+}
+
+/// Arm of a routing experiment which a payment attempt was routed with
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone)]
+pub struct RoutingExperimentAssignment {
+    pub experiment_id: String,
+    pub arm: storage::enums::RoutingExperimentArm,
+    pub algorithm_id: id_type::RoutingId,
+    /// The merchant connector accounts chosen by the algorithm of the arm
+    pub merchant_connector_ids: Vec<id_type::MerchantConnectorAccountId>,
+}
+
+#[cfg(feature = "v1")]
+impl RoutingExperimentAssignment {
+    pub fn new(
+        experiment: &routing_types::RoutingExperiment,
+        arm: storage::enums::RoutingExperimentArm,
+        connectors: &[routing_types::RoutableConnectorChoice],
+    ) -> Self {
+        Self {
+            experiment_id: experiment.experiment_id.clone(),
+            arm,
+            algorithm_id: experiment.get_algorithm_id(arm).to_owned(),
+            merchant_connector_ids: connectors
+                .iter()
+                .filter_map(|connector| connector.merchant_connector_id.clone())
+                .collect(),
+        }
+    }
+
+    /// Whether the attempt was made with a connector chosen by the algorithm of the arm, and not
+    /// one picked by the fallback or the mandate of the payment
+    pub fn is_chosen_connector(
+        &self,
+        merchant_connector_id: Option<&id_type::MerchantConnectorAccountId>,
+    ) -> bool {
+        merchant_connector_id.is_some_and(|merchant_connector_id| {
+            self.merchant_connector_ids.contains(merchant_connector_id)
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl From<storage::RoutingExperimentPayment> for RoutingExperimentAssignment {
+    fn from(routing_experiment_payment: storage::RoutingExperimentPayment) -> Self {
+        Self {
+            experiment_id: routing_experiment_payment.experiment_id,
+            arm: routing_experiment_payment.arm,
+            algorithm_id: routing_experiment_payment.algorithm_id,
+            merchant_connector_ids: routing_experiment_payment
+                .merchant_connector_ids
+                .into_iter()
+                .flatten()
+                .collect(),
+        }
+    }
+}
+
+/// Tags the payment attempt with the arm of the routing experiment it was routed with. The
+/// status of the attempt is recorded once it reaches a final status.
+#[cfg(feature = "v1")]
+pub async fn store_routing_experiment_assignment(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+    payment_attempt: &storage::PaymentAttempt,
+    assignment: RoutingExperimentAssignment,
+) -> RouterResult<()> {
+    let now = common_utils::date_time::now();
+    let routing_experiment_payment = storage::RoutingExperimentPaymentNew {
+        experiment_id: assignment.experiment_id,
+        attempt_id: payment_attempt.attempt_id.clone(),
+        merchant_id: payment_attempt.merchant_id.clone(),
+        profile_id: profile_id.to_owned(),
+        payment_id: payment_attempt.payment_id.clone(),
+        arm: assignment.arm,
+        algorithm_id: assignment.algorithm_id,
+        merchant_connector_ids: assignment
+            .merchant_connector_ids
+            .into_iter()
+            .map(Some)
+            .collect(),
+        connector: None,
+        attempt_status: None,
+        created_at: now,
+        modified_at: now,
+    };
+
+    match state
+        .store
+        .insert_routing_experiment_payment(routing_experiment_payment)
+        .await
+    {
+        Ok(_) => Ok(()),
+        // The attempt has already been routed with the experiment
+        Err(e) if e.current_context().is_db_unique_violation() => Ok(()),
+        Err(e) => Err(e)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to store routing experiment assignment"),
+    }
+}
+
+/// Records the final status of a payment attempt routed with an arm of the routing experiment.
+/// The attempt is dropped from the experiment if its connector was not chosen by the algorithm of
+/// the arm.
+#[cfg(feature = "v1")]
+pub async fn record_routing_experiment_payment(
+    state: &SessionState,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<()> {
+    let Some(routing_experiment_payment) = state
+        .store
+        .find_pending_routing_experiment_payment(
+            &payment_attempt.merchant_id,
+            &payment_attempt.attempt_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch routing experiment payment")?
+    else {
+        return Ok(());
+    };
+    let assignment = RoutingExperimentAssignment::from(routing_experiment_payment);
+
+    if !assignment.is_chosen_connector(payment_attempt.merchant_connector_id.as_ref()) {
+        logger::debug!(
+            routing_experiment_id = %assignment.experiment_id,
+            "Connector of the attempt was not chosen by the routing experiment"
+        );
+        state
+            .store
+            .delete_routing_experiment_payment(
+                &assignment.experiment_id,
+                &payment_attempt.attempt_id,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to delete routing experiment payment")?;
+        return Ok(());
+    }
+
+    match state
+        .store
+        .update_pending_routing_experiment_payment(
+            &assignment.experiment_id,
+            &payment_attempt.attempt_id,
+            storage::RoutingExperimentPaymentUpdate {
+                connector: payment_attempt.connector.clone(),
+                attempt_status: Some(payment_attempt.status),
+                modified_at: common_utils::date_time::now(),
+            },
+        )
+        .await
+    {
+        Ok(_) => Ok(()),
+        // The attempt has already been recorded with its first final status
+        Err(e) if e.current_context().is_db_not_found() => Ok(()),
+        Err(e) => Err(e)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to record routing experiment payment"),
+    }
+}
+
+/// A change to the routing configuration of a profile, which is recorded in the routing audit log
+/// of the profile in the same transaction as the change itself
+#[derive(Clone, Debug)]
+pub struct RoutingAuditLogDetails {
+    pub action: storage::enums::RoutingAuditAction,
+    pub transaction_type: storage::enums::TransactionType,
+    pub previous_algorithm_id: Option<id_type::RoutingId>,
+    pub new_algorithm_id: Option<id_type::RoutingId>,
+    pub config_diff: Option<serde_json::Value>,
+    pub created_by: Option<common_utils::types::CreatedBy>,
+}
+
+impl RoutingAuditLogDetails {
+    pub fn construct_new(
+        self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+    ) -> storage::RoutingAuditLogNew {
+        storage::RoutingAuditLogNew {
+            id: common_utils::generate_id(crate::consts::ID_LENGTH, "routing_audit"),
+            merchant_id: merchant_id.to_owned(),
+            profile_id: profile_id.to_owned(),
+            action: self.action,
+            transaction_type: self.transaction_type,
+            previous_algorithm_id: self.previous_algorithm_id,
+            new_algorithm_id: self.new_algorithm_id,
+            config_diff: self.config_diff,
+            created_by: self.created_by.map(|created_by| created_by.to_string()),
+            created_at: common_utils::date_time::now(),
+        }
+    }
+}
+
+/// Inserts a routing algorithm under a profile along with the audit log entry of its creation
+pub async fn insert_routing_algorithm_with_audit_log(
+    db: &dyn StorageInterface,
+    routing_algorithm: storage::RoutingAlgorithm,
+    routing_audit_log: RoutingAuditLogDetails,
+) -> RouterResult<(storage::RoutingAlgorithm, storage::RoutingAuditLog)> {
+    let routing_audit_log = routing_audit_log.construct_new(
+        &routing_algorithm.merchant_id,
+        &routing_algorithm.profile_id,
+    );
+    db.insert_routing_algorithm_with_audit_log(routing_algorithm, routing_audit_log)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert routing algorithm along with its audit log")
+}
+
+/// Notifies the merchant of a change to the routing configuration of a profile by an outgoing
+/// webhook, identified by the routing audit log record of the change
+#[cfg(feature = "v1")]
+pub async fn trigger_routing_config_webhook(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    business_profile: &domain::Profile,
+    routing_audit_log: storage::RoutingAuditLog,
+) {
+    let event_type = match routing_audit_log.action {
+        storage::enums::RoutingAuditAction::Link => storage::enums::EventType::RoutingConfigLinked,
+        storage::enums::RoutingAuditAction::Unlink => {
+            storage::enums::EventType::RoutingConfigUnlinked
+        }
+        storage::enums::RoutingAuditAction::Update => {
+            storage::enums::EventType::RoutingDefaultUpdated
+        }
+        storage::enums::RoutingAuditAction::DynamicRoutingUpdate => {
+            storage::enums::EventType::RoutingDynamicUpdated
+        }
+        storage::enums::RoutingAuditAction::VolumeSplitUpdate => {
+            storage::enums::EventType::RoutingVolumeSplitUpdated
+        }
+        storage::enums::RoutingAuditAction::ExperimentUpdate => {
+            storage::enums::EventType::RoutingExperimentUpdated
+        }
+        storage::enums::RoutingAuditAction::LatencyRoutingUpdate => {
+            storage::enums::EventType::RoutingLatencyConfigUpdated
+        }
+        storage::enums::RoutingAuditAction::FeeScheduleUpdate => {
+            storage::enums::EventType::RoutingFeeScheduleUpdated
+        }
+        // Creating a routing algorithm does not affect the routing of a profile until it is linked
+        storage::enums::RoutingAuditAction::Create => return,
+    };
+    let primary_object_id = routing_audit_log.id.clone();
+    let primary_object_created_at = routing_audit_log.created_at;
+
+    // The routing config has already been updated at this point, so failing to notify the
+    // merchant must not fail the request
+    Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_context.clone(),
+        business_profile.clone(),
+        event_type,
+        storage::enums::EventClass::Routing,
+        primary_object_id,
+        storage::enums::EventObjectType::RoutingDetails,
+        api_models::webhooks::OutgoingWebhookContent::RoutingDetails(Box::new(
+            routing_audit_log.foreign_into(),
+        )),
+        Some(primary_object_created_at),
+    ))
+    .await
+    .unwrap_or_else(
+        |error| logger::error!(?error, %event_type, "Failed to trigger routing config webhook"),
+    );
+}
+
+/// Name of the process tracker task which activates a routing algorithm at its scheduled time
+#[cfg(feature = "v1")]
+const ROUTING_ACTIVATION_TASK: &str = "ROUTING_ACTIVATION";
+
+/// Maximum number of scheduled activations retained for a profile
+#[cfg(feature = "v1")]
+const MAX_ROUTING_SCHEDULED_ACTIVATIONS_LENGTH: usize = 50;
+
+/// Provides the id of the process tracker task which activates a routing algorithm
+#[cfg(feature = "v1")]
+pub fn get_routing_activation_process_tracker_id(
+    merchant_id: &id_type::MerchantId,
+    algorithm_id: &id_type::RoutingId,
+) -> String {
+    scheduler::utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::RoutingActivationWorkflow,
+        ROUTING_ACTIVATION_TASK,
+        algorithm_id.get_string_repr(),
+        merchant_id,
+    )
+}
+
+/// Schedules the activation of a routing algorithm for a profile at the specified time
+#[cfg(feature = "v1")]
+pub async fn add_routing_activation_task(
+    db: &dyn StorageInterface,
+    tracking_data: storage::RoutingActivationTrackingData,
+    activate_at: time::PrimitiveDateTime,
+) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::RoutingActivationWorkflow;
+    let tag = ["ROUTING", "ACTIVATION"];
+    let process_tracker_id = get_routing_activation_process_tracker_id(
+        &tracking_data.merchant_id,
+        &tracking_data.algorithm_id,
+    );
+    let scheduled_activation = routing_types::RoutingScheduledActivation {
+        algorithm_id: tracking_data.algorithm_id.clone(),
+        transaction_type: tracking_data.transaction_type,
+        activate_at,
+        status: routing_types::RoutingActivationStatus::Scheduled,
+        failure_reason: None,
+    };
+    let profile_id = tracking_data.profile_id.clone();
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        ROUTING_ACTIVATION_TASK,
+        runner,
+        tag,
+        tracking_data,
+        None,
+        activate_at,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct routing activation process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert routing activation task to process tracker")?;
+    crate::routes::metrics::TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "RoutingActivation")),
+    );
+
+    let key = get_routing_scheduled_activations_key(profile_id.get_string_repr());
+    let existing_activations = find_routing_scheduled_activations(db, &key).await?;
+    let is_new = existing_activations.is_none();
+    let mut scheduled_activations = existing_activations
+        .unwrap_or_else(|| routing_types::RoutingScheduledActivations::new(profile_id));
+    scheduled_activations.activations.push(scheduled_activation);
+
+    // Only the activations which are no longer pending are evicted
+    let mut overflow = scheduled_activations
+        .activations
+        .len()
+        .saturating_sub(MAX_ROUTING_SCHEDULED_ACTIVATIONS_LENGTH);
+    scheduled_activations.activations.retain(|activation| {
+        if overflow > 0 && !activation.is_scheduled() {
+            overflow -= 1;
+            false
+        } else {
+            true
+        }
+    });
+
+    store_routing_scheduled_activations(db, key, &scheduled_activations, is_new).await
+}
+
+#[cfg(feature = "v1")]
+async fn find_routing_scheduled_activations(
+    db: &dyn StorageInterface,
+    key: &str,
+) -> RouterResult<Option<routing_types::RoutingScheduledActivations>> {
+    match db.find_config_by_key(key).await {
+        Ok(config) => config
+            .config
+            .parse_struct("RoutingScheduledActivations")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Routing scheduled activations have invalid structure")
+            .map(Some),
+        Err(e) if e.current_context().is_db_not_found() => Ok(None),
+        Err(e) => Err(e)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error fetching routing scheduled activations for profile"),
+    }
+}
+
+#[cfg(feature = "v1")]
+async fn store_routing_scheduled_activations(
+    db: &dyn StorageInterface,
+    key: String,
+    scheduled_activations: &routing_types::RoutingScheduledActivations,
+    is_new: bool,
+) -> RouterResult<()> {
+    let config_str = scheduled_activations
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize routing scheduled activations")?;
+
+    if is_new {
+        db.insert_config(configs::ConfigNew {
+            key,
+            config: config_str,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error inserting routing scheduled activations into DB")?;
+    } else {
+        db.update_config_by_key(
+            &key,
+            configs::ConfigUpdate::Update {
+                config: Some(config_str),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating routing scheduled activations in DB")?;
+    }
+
+    Ok(())
+}
+
+/// Provides the scheduled activations of the routing algorithms under a profile, oldest first
+#[cfg(feature = "v1")]
+pub async fn get_routing_scheduled_activations(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<routing_types::RoutingScheduledActivations> {
+    let key = get_routing_scheduled_activations_key(profile_id.get_string_repr());
+    Ok(find_routing_scheduled_activations(db, &key)
+        .await?
+        .unwrap_or_else(|| routing_types::RoutingScheduledActivations::new(profile_id.clone())))
+}
+
+/// Records the outcome of a scheduled activation of a routing algorithm
+#[cfg(feature = "v1")]
+pub async fn update_routing_scheduled_activation_status(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+    algorithm_id: &id_type::RoutingId,
+    status: routing_types::RoutingActivationStatus,
+    failure_reason: Option<String>,
+) -> RouterResult<routing_types::RoutingScheduledActivation> {
+    let key = get_routing_scheduled_activations_key(profile_id.get_string_repr());
+    let mut scheduled_activations = find_routing_scheduled_activations(db, &key).await?.ok_or(
+        errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Scheduled activation not found for the routing algorithm".to_string(),
+        },
+    )?;
+    let activation = scheduled_activations
+        .get_activation_mut(algorithm_id)
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Scheduled activation not found for the routing algorithm".to_string(),
+        })?;
+    activation.status = status;
+    activation.failure_reason = failure_reason;
+    let activation = activation.clone();
+
+    store_routing_scheduled_activations(db, key, &scheduled_activations, false).await?;
+
+    Ok(activation)
+}
+
+/// Computes the fields which differ between two routing configs, keyed by their JSON path
+pub fn get_routing_config_diff(
+    old_config: &serde_json::Value,
+    new_config: &serde_json::Value,
+) -> Option<serde_json::Value> {
+    let mut diff = serde_json::Map::new();
+    collect_routing_config_diff("$", old_config, new_config, &mut diff);
+    (!diff.is_empty()).then_some(serde_json::Value::Object(diff))
+}
+
+fn collect_routing_config_diff(
+    path: &str,
+    old_config: &serde_json::Value,
+    new_config: &serde_json::Value,
+    diff: &mut serde_json::Map<String, serde_json::Value>,
+) {
+    match (old_config, new_config) {
+        (serde_json::Value::Object(old_fields), serde_json::Value::Object(new_fields)) => {
+            let keys = old_fields
+                .keys()
+                .chain(new_fields.keys())
+                .collect::<std::collections::BTreeSet<_>>();
+            for key in keys {
+                collect_routing_config_diff(
+                    &format!("{path}.{key}"),
+                    old_fields.get(key).unwrap_or(&serde_json::Value::Null),
+                    new_fields.get(key).unwrap_or(&serde_json::Value::Null),
+                    diff,
+                );
+            }
+        }
+        (serde_json::Value::Array(old_items), serde_json::Value::Array(new_items)) => {
+            for index in 0..old_items.len().max(new_items.len()) {
+                collect_routing_config_diff(
+                    &format!("{path}[{index}]"),
+                    old_items.get(index).unwrap_or(&serde_json::Value::Null),
+                    new_items.get(index).unwrap_or(&serde_json::Value::Null),
+                    diff,
+                );
+            }
+        }
+        _ if old_config != new_config => {
+            diff.insert(
+                path.to_owned(),
+                serde_json::json!({ "old": old_config, "new": new_config }),
+            );
+        }
+        _ => {}
+    }
+}
+
+/// Name under which the default connector selection of a routing algorithm is diffed
+pub const ROUTING_DEFAULT_SELECTION_RULE_NAME: &str = "default_selection";
+
+/// Computes the rules added, removed and changed from one static routing algorithm to another.
+/// Rules of advanced algorithms are matched by their name, while the other algorithms are
+/// compared as a whole under the default selection.
+pub fn get_routing_algorithm_diff(
+    algorithm_id: id_type::RoutingId,
+    algorithm: &routing_types::StaticRoutingAlgorithm,
+    other_algorithm_id: id_type::RoutingId,
+    other_algorithm: &routing_types::StaticRoutingAlgorithm,
+) -> RouterResult<routing_types::RoutingAlgorithmDiffResponse> {
+    let rules = get_routing_algorithm_rules(algorithm)?;
+    let mut other_rules = get_routing_algorithm_rules(other_algorithm)?;
+
+    let mut removed = Vec::new();
+    let mut changed = Vec::new();
+    for (name, rule) in rules {
+        match other_rules.remove(&name) {
+            Some(other_rule) => {
+                if let Some(changes) = get_routing_config_diff(&rule, &other_rule) {
+                    changed.push(routing_types::RoutingRuleChange { name, changes });
+                }
+            }
+            None => removed.push(routing_types::RoutingRuleDiff { name, rule }),
+        }
+    }
+    let added = other_rules
+        .into_iter()
+        .map(|(name, rule)| routing_types::RoutingRuleDiff { name, rule })
+        .collect();
+
+    Ok(routing_types::RoutingAlgorithmDiffResponse {
+        algorithm_id,
+        other_algorithm_id,
+        algorithm_kind: algorithm.get_kind(),
+        other_algorithm_kind: other_algorithm.get_kind(),
+        added,
+        removed,
+        changed,
+    })
+}
+
+fn get_routing_algorithm_rules(
+    algorithm: &routing_types::StaticRoutingAlgorithm,
+) -> RouterResult<std::collections::BTreeMap<String, serde_json::Value>> {
+    match algorithm {
+        routing_types::StaticRoutingAlgorithm::Single(_)
+        | routing_types::StaticRoutingAlgorithm::Priority(_)
+        | routing_types::StaticRoutingAlgorithm::VolumeSplit(_)
+        | routing_types::StaticRoutingAlgorithm::StickyVolumeSplit(_)
+        | routing_types::StaticRoutingAlgorithm::Cost(_) => {
+            let algorithm = algorithm
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize routing algorithm")?;
+            Ok(std::collections::BTreeMap::from([(
+                ROUTING_DEFAULT_SELECTION_RULE_NAME.to_string(),
+                algorithm,
+            )]))
+        }
+        routing_types::StaticRoutingAlgorithm::Advanced(program) => {
+            get_routing_program_rules(program)
+        }
+        routing_types::StaticRoutingAlgorithm::ThreeDsDecisionRule(program) => {
+            get_routing_program_rules(program)
+        }
+    }
+}
+
+fn get_routing_program_rules<O: serde::Serialize>(
+    program: &euclid::frontend::ast::Program<O>,
+) -> RouterResult<std::collections::BTreeMap<String, serde_json::Value>> {
+    let mut rules = std::collections::BTreeMap::new();
+    rules.insert(
+        ROUTING_DEFAULT_SELECTION_RULE_NAME.to_string(),
+        program
+            .default_selection
+            .encode_to_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize routing default selection")?,
+    );
+    for rule in &program.rules {
+        rules.insert(
+            rule.name.clone(),
+            rule.encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize routing rule")?,
+        );
+    }
+    Ok(rules)
+}
+
 /// This will help make one of all configured algorithms to be in active state for a particular
 /// merchant
 #[cfg(feature = "v1")]
@@ -210,6 +1719,7 @@ pub async fn update_merchant_active_algorithm_ref(
         default_profile: None,
         payment_link_config: None,
         pm_collect_link_config: None,
+        allowed_ip_ranges: None,
     };
 
     let db = &*state.store;
@@ -232,23 +1742,15 @@ pub async fn update_merchant_active_algorithm_ref(
 }
 
 #[cfg(feature = "v1")]
-pub async fn update_profile_active_algorithm_ref(
-    db: &dyn StorageInterface,
-    key_manager_state: &KeyManagerState,
-    merchant_key_store: &domain::MerchantKeyStore,
-    current_business_profile: domain::Profile,
+fn get_routing_algorithm_ref_update(
     algorithm_id: routing_types::RoutingAlgorithmRef,
     transaction_type: &storage::enums::TransactionType,
-) -> RouterResult<()> {
+) -> RouterResult<domain::ProfileUpdate> {
     let ref_val = algorithm_id
         .encode_to_value()
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to convert routing ref to value")?;
 
-    let merchant_id = current_business_profile.merchant_id.clone();
-
-    let profile_id = current_business_profile.get_id().to_owned();
-
     let (routing_algorithm, payout_routing_algorithm, three_ds_decision_rule_algorithm) =
         match transaction_type {
             storage::enums::TransactionType::Payment => (Some(ref_val), None, None),
@@ -257,23 +1759,21 @@ pub async fn update_profile_active_algorithm_ref(
             storage::enums::TransactionType::ThreeDsAuthentication => (None, None, Some(ref_val)),
         };
 
-    let business_profile_update = domain::ProfileUpdate::RoutingAlgorithmUpdate {
+    Ok(domain::ProfileUpdate::RoutingAlgorithmUpdate {
         routing_algorithm,
         payout_routing_algorithm,
         three_ds_decision_rule_algorithm,
-    };
-
-    db.update_profile_by_profile_id(
-        key_manager_state,
-        merchant_key_store,
-        current_business_profile,
-        business_profile_update,
-    )
-    .await
-    .change_context(errors::ApiErrorResponse::InternalServerError)
-    .attach_printable("Failed to update routing algorithm ref in business profile")?;
+    })
+}
 
-    // Invalidate the routing cache for Payments and Payouts transaction types
+/// Invalidates the routing cache of a profile for Payments and Payouts transaction types
+#[cfg(feature = "v1")]
+async fn invalidate_profile_routing_cache(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+    transaction_type: &storage::enums::TransactionType,
+) -> RouterResult<()> {
     if !transaction_type.is_three_ds_authentication() {
         let routing_cache_key = cache::CacheKind::Routing(
             format!(
@@ -293,20 +1793,18 @@ pub async fn update_profile_active_algorithm_ref(
 }
 
 #[cfg(feature = "v1")]
-pub async fn update_business_profile_active_dynamic_algorithm_ref(
+pub async fn update_profile_active_algorithm_ref(
     db: &dyn StorageInterface,
     key_manager_state: &KeyManagerState,
     merchant_key_store: &domain::MerchantKeyStore,
     current_business_profile: domain::Profile,
-    dynamic_routing_algorithm_ref: routing_types::DynamicRoutingAlgorithmRef,
+    algorithm_id: routing_types::RoutingAlgorithmRef,
+    transaction_type: &storage::enums::TransactionType,
 ) -> RouterResult<()> {
-    let ref_val = dynamic_routing_algorithm_ref
-        .encode_to_value()
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to convert dynamic routing ref to value")?;
-    let business_profile_update = domain::ProfileUpdate::DynamicRoutingAlgorithmUpdate {
-        dynamic_routing_algorithm: Some(ref_val),
-    };
+    let merchant_id = current_business_profile.merchant_id.clone();
+    let profile_id = current_business_profile.get_id().to_owned();
+    let business_profile_update = get_routing_algorithm_ref_update(algorithm_id, transaction_type)?;
+
     db.update_profile_by_profile_id(
         key_manager_state,
         merchant_key_store,
@@ -315,8 +1813,92 @@ pub async fn update_business_profile_active_dynamic_algorithm_ref(
     )
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)
-    .attach_printable("Failed to update dynamic routing algorithm ref in business profile")?;
-    Ok(())
+    .attach_printable("Failed to update routing algorithm ref in business profile")?;
+
+    invalidate_profile_routing_cache(db, &merchant_id, &profile_id, transaction_type).await
+}
+
+/// Activates or deactivates a routing algorithm of a profile, along with the audit log entry of
+/// the change
+#[cfg(feature = "v1")]
+pub async fn update_profile_active_algorithm_ref_with_audit_log(
+    db: &dyn StorageInterface,
+    key_manager_state: &KeyManagerState,
+    merchant_key_store: &domain::MerchantKeyStore,
+    current_business_profile: domain::Profile,
+    algorithm_id: routing_types::RoutingAlgorithmRef,
+    routing_audit_log: RoutingAuditLogDetails,
+) -> RouterResult<storage::RoutingAuditLog> {
+    let transaction_type = routing_audit_log.transaction_type;
+    let merchant_id = current_business_profile.merchant_id.clone();
+    let profile_id = current_business_profile.get_id().to_owned();
+    let business_profile_update =
+        get_routing_algorithm_ref_update(algorithm_id, &transaction_type)?;
+    let routing_audit_log = routing_audit_log.construct_new(
+        &current_business_profile.merchant_id,
+        current_business_profile.get_id(),
+    );
+
+    let (_, routing_audit_log) = db
+        .update_profile_with_routing_audit_log(
+            key_manager_state,
+            merchant_key_store,
+            current_business_profile,
+            business_profile_update,
+            routing_audit_log,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update routing algorithm ref in business profile")?;
+
+    invalidate_profile_routing_cache(db, &merchant_id, &profile_id, &transaction_type).await?;
+    Ok(routing_audit_log)
+}
+
+/// Updates the dynamic routing algorithm ref of a profile, along with the audit log entry of the
+/// change. The difference between the previous and the updated ref is recorded as the config
+/// diff of the entry, unless a config diff is given.
+#[cfg(feature = "v1")]
+pub async fn update_business_profile_active_dynamic_algorithm_ref(
+    db: &dyn StorageInterface,
+    key_manager_state: &KeyManagerState,
+    merchant_key_store: &domain::MerchantKeyStore,
+    current_business_profile: domain::Profile,
+    dynamic_routing_algorithm_ref: routing_types::DynamicRoutingAlgorithmRef,
+    mut routing_audit_log: RoutingAuditLogDetails,
+) -> RouterResult<storage::RoutingAuditLog> {
+    let ref_val = dynamic_routing_algorithm_ref
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to convert dynamic routing ref to value")?;
+    if routing_audit_log.config_diff.is_none() {
+        routing_audit_log.config_diff = get_routing_config_diff(
+            current_business_profile
+                .dynamic_routing_algorithm
+                .as_ref()
+                .unwrap_or(&serde_json::Value::Null),
+            &ref_val,
+        );
+    }
+    let routing_audit_log = routing_audit_log.construct_new(
+        &current_business_profile.merchant_id,
+        current_business_profile.get_id(),
+    );
+    let business_profile_update = domain::ProfileUpdate::DynamicRoutingAlgorithmUpdate {
+        dynamic_routing_algorithm: Some(ref_val),
+    };
+    let (_, routing_audit_log) = db
+        .update_profile_with_routing_audit_log(
+            key_manager_state,
+            merchant_key_store,
+            current_business_profile,
+            business_profile_update,
+            routing_audit_log,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update dynamic routing algorithm ref in business profile")?;
+    Ok(routing_audit_log)
 }
 
 #[cfg(feature = "v2")]
@@ -417,13 +1999,15 @@ impl RoutingAlgorithmHelpers<'_> {
                 self.connector_choice(choice)?;
             }
 
-            routing_types::StaticRoutingAlgorithm::Priority(list) => {
+            routing_types::StaticRoutingAlgorithm::Priority(list)
+            | routing_types::StaticRoutingAlgorithm::Cost(list) => {
                 for choice in list {
                     self.connector_choice(choice)?;
                 }
             }
 
-            routing_types::StaticRoutingAlgorithm::VolumeSplit(splits) => {
+            routing_types::StaticRoutingAlgorithm::VolumeSplit(splits)
+            | routing_types::StaticRoutingAlgorithm::StickyVolumeSplit(splits) => {
                 for split in splits {
                     self.connector_choice(&split.connector)?;
                 }
@@ -531,13 +2115,15 @@ pub async fn validate_connectors_in_routing_config(
             connector_choice(choice)?;
         }
 
-        routing_types::StaticRoutingAlgorithm::Priority(list) => {
+        routing_types::StaticRoutingAlgorithm::Priority(list)
+        | routing_types::StaticRoutingAlgorithm::Cost(list) => {
             for choice in list {
                 connector_choice(choice)?;
             }
         }
 
-        routing_types::StaticRoutingAlgorithm::VolumeSplit(splits) => {
+        routing_types::StaticRoutingAlgorithm::VolumeSplit(splits)
+        | routing_types::StaticRoutingAlgorithm::StickyVolumeSplit(splits) => {
             for split in splits {
                 connector_choice(&split.connector)?;
             }
@@ -579,12 +2165,244 @@ pub async fn validate_connectors_in_routing_config(
     Ok(())
 }
 
+/// Replaces a merchant connector account of a routing config exported from another profile with
+/// the merchant connector account of the importing profile, either explicitly mapped to it or
+/// being the only account of the same connector in the importing profile
+#[cfg(feature = "v1")]
+pub fn remap_routable_connector_choice(
+    choice: &mut routing_types::RoutableConnectorChoice,
+    profile_mcas: &[domain::MerchantConnectorAccount],
+    mca_id_mapping: Option<
+        &std::collections::HashMap<
+            id_type::MerchantConnectorAccountId,
+            id_type::MerchantConnectorAccountId,
+        >,
+    >,
+) -> RouterResult<()> {
+    let Some(source_mca_id) = choice.merchant_connector_id.as_ref() else {
+        return Ok(());
+    };
+    let connector_name = choice.connector.to_string();
+
+    let remapped_mca_id = match mca_id_mapping.and_then(|mapping| mapping.get(source_mca_id)) {
+        Some(mapped_mca_id) => profile_mcas
+            .iter()
+            .find(|mca| mca.get_id() == *mapped_mca_id && mca.connector_name == connector_name)
+            .map(|mca| mca.get_id())
+            .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "merchant connector account '{}' mapped for '{}' is not a '{}' account of the profile",
+                    mapped_mca_id.get_string_repr(),
+                    source_mca_id.get_string_repr(),
+                    connector_name,
+                ),
+            })?,
+        None if profile_mcas
+            .iter()
+            .any(|mca| mca.get_id() == *source_mca_id && mca.connector_name == connector_name) =>
+        {
+            source_mca_id.clone()
+        }
+        None => {
+            let mut connector_mcas = profile_mcas
+                .iter()
+                .filter(|mca| mca.connector_name == connector_name);
+
+            match (connector_mcas.next(), connector_mcas.next()) {
+                (Some(mca), None) => mca.get_id(),
+                (None, _) => Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "no '{}' merchant connector account found in the profile to replace '{}'",
+                        connector_name,
+                        source_mca_id.get_string_repr(),
+                    ),
+                })?,
+                (Some(_), Some(_)) => Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "multiple '{}' merchant connector accounts found in the profile to replace '{}', provide a mapping for it",
+                        connector_name,
+                        source_mca_id.get_string_repr(),
+                    ),
+                })?,
+            }
+        }
+    };
+
+    choice.merchant_connector_id = Some(remapped_mca_id);
+
+    Ok(())
+}
+
+/// Replaces the merchant connector accounts of all the connectors of a routing algorithm exported
+/// from another profile, see [`remap_routable_connector_choice`]
+#[cfg(feature = "v1")]
+pub fn remap_connectors_in_routing_algorithm(
+    routing_algorithm: &mut routing_types::StaticRoutingAlgorithm,
+    profile_mcas: &[domain::MerchantConnectorAccount],
+    mca_id_mapping: Option<
+        &std::collections::HashMap<
+            id_type::MerchantConnectorAccountId,
+            id_type::MerchantConnectorAccountId,
+        >,
+    >,
+) -> RouterResult<()> {
+    let remap_connector_selection =
+        |selection: &mut routing_types::ConnectorSelection| -> RouterResult<()> {
+            match selection {
+                routing_types::ConnectorSelection::VolumeSplit(splits) => {
+                    for split in splits {
+                        remap_routable_connector_choice(
+                            &mut split.connector,
+                            profile_mcas,
+                            mca_id_mapping,
+                        )?;
+                    }
+                }
+
+                routing_types::ConnectorSelection::Priority(list) => {
+                    for choice in list {
+                        remap_routable_connector_choice(choice, profile_mcas, mca_id_mapping)?;
+                    }
+                }
+            }
+
+            Ok(())
+        };
+
+    match routing_algorithm {
+        routing_types::StaticRoutingAlgorithm::Single(choice) => {
+            remap_routable_connector_choice(choice, profile_mcas, mca_id_mapping)?;
+        }
+
+        routing_types::StaticRoutingAlgorithm::Priority(list)
+        | routing_types::StaticRoutingAlgorithm::Cost(list) => {
+            for choice in list {
+                remap_routable_connector_choice(choice, profile_mcas, mca_id_mapping)?;
+            }
+        }
+
+        routing_types::StaticRoutingAlgorithm::VolumeSplit(splits)
+        | routing_types::StaticRoutingAlgorithm::StickyVolumeSplit(splits) => {
+            for split in splits {
+                remap_routable_connector_choice(
+                    &mut split.connector,
+                    profile_mcas,
+                    mca_id_mapping,
+                )?;
+            }
+        }
+
+        routing_types::StaticRoutingAlgorithm::Advanced(program) => {
+            remap_connector_selection(&mut program.default_selection)?;
+
+            for rule in &mut program.rules {
+                remap_connector_selection(&mut rule.connector_selection)?;
+            }
+        }
+
+        routing_types::StaticRoutingAlgorithm::ThreeDsDecisionRule(_) => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "three_ds decision rules cannot be imported as routing configs"
+                    .to_string(),
+            })?
+        }
+    }
+
+    Ok(())
+}
+
 /// Provides the identifier for the specific merchant's routing_dictionary_key
 #[inline(always)]
 pub fn get_routing_dictionary_key(merchant_id: &str) -> String {
     format!("routing_dict_{merchant_id}")
 }
 
+/// Provides the identifier for the activation history of routing algorithms under a profile
+#[inline(always)]
+pub fn get_routing_version_history_key(
+    profile_id: &str,
+    transaction_type: &storage::enums::TransactionType,
+) -> String {
+    match transaction_type {
+        storage::enums::TransactionType::Payment => format!("routing_versions_{profile_id}"),
+        #[cfg(feature = "payouts")]
+        storage::enums::TransactionType::Payout => format!("routing_versions_po_{profile_id}"),
+        storage::enums::TransactionType::ThreeDsAuthentication => {
+            format!("three_ds_authentication_versions_{profile_id}")
+        }
+    }
+}
+
+/// Provides the identifier for the latency based routing config of a profile
+#[inline(always)]
+pub fn get_latency_routing_config_key(profile_id: &str) -> String {
+    format!("latency_routing_{profile_id}")
+}
+
+/// Provides the identifier for the recorded authorization latencies of a connector under a profile.
+/// The keys of a profile share a hash tag, so that they are in the same slot when redis runs in
+/// cluster mode, which is required for a script to read them together.
+#[inline(always)]
+pub fn get_connector_latency_key(
+    profile_id: &str,
+    connector: &str,
+    merchant_connector_id: Option<&id_type::MerchantConnectorAccountId>,
+) -> String {
+    match merchant_connector_id {
+        Some(mca_id) => format!(
+            "connector_latency_{{{profile_id}}}_{}",
+            mca_id.get_string_repr()
+        ),
+        None => format!("connector_latency_{{{profile_id}}}_{connector}"),
+    }
+}
+
+/// Provides the identifier for the request counts of the current health window of a connector.
+/// The health keys of a profile share a hash tag, so that a script can read them together.
+#[inline(always)]
+pub fn get_connector_health_stats_key(profile_id: &str, merchant_connector_id: &str) -> String {
+    format!("connector_health_stats_{{{profile_id}}}_{merchant_connector_id}")
+}
+
+/// Provides the identifier for the health breaker of a connector
+#[inline(always)]
+pub fn get_connector_health_breaker_key(profile_id: &str, merchant_connector_id: &str) -> String {
+    format!("connector_health_breaker_{{{profile_id}}}_{merchant_connector_id}")
+}
+
+/// Provides the identifier for the trial request of a connector whose health breaker is half open
+#[inline(always)]
+pub fn get_connector_health_trial_key(profile_id: &str, merchant_connector_id: &str) -> String {
+    format!("connector_health_trial_{{{profile_id}}}_{merchant_connector_id}")
+}
+
+/// Provides the identifier for the fee schedule of a merchant connector account
+#[inline(always)]
+pub fn get_connector_fee_schedule_key(merchant_connector_id: &str) -> String {
+    format!("connector_fee_schedule_{merchant_connector_id}")
+}
+
+/// Provides the identifier for the scheduled activations of routing algorithms under a profile
+#[inline(always)]
+pub fn get_routing_scheduled_activations_key(profile_id: &str) -> String {
+    format!("routing_scheduled_activations_{profile_id}")
+}
+
+/// Provides the identifier for the routing experiment of a profile
+#[inline(always)]
+pub fn get_routing_experiment_key(profile_id: &str) -> String {
+    format!("routing_experiment_{profile_id}")
+}
+
+/// Provides the identifier for the default fallback config of a profile for a payment method type
+#[inline(always)]
+pub fn get_payment_method_default_config_key(
+    profile_id: &str,
+    payment_method_type: storage::enums::PaymentMethodType,
+) -> String {
+    format!("routing_default_{profile_id}_pmt_{payment_method_type}")
+}
+
 /// Provides the identifier for the specific merchant's default_config
 #[inline(always)]
 pub fn get_default_config_key(
@@ -1692,7 +3510,10 @@ fn get_desired_payment_status_for_dynamic_routing_metrics(
         | common_enums::AttemptStatus::Authorized
         | common_enums::AttemptStatus::PartialCharged
         | common_enums::AttemptStatus::PartialChargedAndChargeable
-        | common_enums::AttemptStatus::PartiallyAuthorized => common_enums::AttemptStatus::Charged,
+        | common_enums::AttemptStatus::PartiallyAuthorized
+        | common_enums::AttemptStatus::IncrementalAuthorizationPending => {
+            common_enums::AttemptStatus::Charged
+        }
         common_enums::AttemptStatus::Failure
         | common_enums::AttemptStatus::AuthorizationFailed
         | common_enums::AttemptStatus::AuthenticationFailed
@@ -1729,7 +3550,8 @@ impl ForeignFrom<common_enums::AttemptStatus> for open_router::TxnStatus {
             common_enums::AttemptStatus::AuthenticationPending => Self::PendingVbv,
             common_enums::AttemptStatus::AuthenticationSuccessful => Self::VBVSuccessful,
             common_enums::AttemptStatus::Authorized
-            | common_enums::AttemptStatus::PartiallyAuthorized => Self::Authorized,
+            | common_enums::AttemptStatus::PartiallyAuthorized
+            | common_enums::AttemptStatus::IncrementalAuthorizationPending => Self::Authorized,
             common_enums::AttemptStatus::AuthorizationFailed => Self::AuthorizationFailed,
             common_enums::AttemptStatus::Charged => Self::Charged,
             common_enums::AttemptStatus::Authorizing => Self::Authorizing,
@@ -1790,10 +3612,11 @@ fn get_dynamic_routing_based_metrics_outcome_for_payment(
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 pub async fn disable_dynamic_routing_algorithm(
     state: &SessionState,
-    key_store: domain::MerchantKeyStore,
+    merchant_context: &domain::MerchantContext,
     business_profile: domain::Profile,
     dynamic_routing_algo_ref: routing_types::DynamicRoutingAlgorithmRef,
     dynamic_routing_type: routing_types::DynamicRoutingType,
+    created_by: Option<common_utils::types::CreatedBy>,
 ) -> RouterResult<ApplicationResponse<routing_types::RoutingDictionaryRecord>> {
     let db = state.store.as_ref();
     let key_manager_state = &state.into();
@@ -1954,14 +3777,29 @@ pub async fn disable_dynamic_routing_algorithm(
         .await
         .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
     let response = record.foreign_into();
-    update_business_profile_active_dynamic_algorithm_ref(
+    let routing_audit_log = update_business_profile_active_dynamic_algorithm_ref(
         db,
         key_manager_state,
-        &key_store,
-        business_profile,
+        merchant_context.get_merchant_key_store(),
+        business_profile.clone(),
         dynamic_routing_algorithm,
+        RoutingAuditLogDetails {
+            action: storage::enums::RoutingAuditAction::DynamicRoutingUpdate,
+            transaction_type: storage::enums::TransactionType::Payment,
+            previous_algorithm_id: Some(algorithm_id),
+            new_algorithm_id: None,
+            config_diff: None,
+            created_by,
+        },
     )
     .await?;
+    trigger_routing_config_webhook(
+        state,
+        merchant_context,
+        &business_profile,
+        routing_audit_log,
+    )
+    .await;
 
     core_metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(
         1,
@@ -1974,12 +3812,13 @@ pub async fn disable_dynamic_routing_algorithm(
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 pub async fn enable_dynamic_routing_algorithm(
     state: &SessionState,
-    key_store: domain::MerchantKeyStore,
+    merchant_context: &domain::MerchantContext,
     business_profile: domain::Profile,
     feature_to_enable: routing_types::DynamicRoutingFeatures,
     dynamic_routing_algo_ref: routing_types::DynamicRoutingAlgorithmRef,
     dynamic_routing_type: routing_types::DynamicRoutingType,
     payload: Option<routing_types::DynamicRoutingPayload>,
+    created_by: Option<common_utils::types::CreatedBy>,
 ) -> RouterResult<ApplicationResponse<routing_types::RoutingDictionaryRecord>> {
     let mut dynamic_routing = dynamic_routing_algo_ref.clone();
     match dynamic_routing_type {
@@ -1989,26 +3828,28 @@ pub async fn enable_dynamic_routing_algorithm(
 
             enable_specific_routing_algorithm(
                 state,
-                key_store,
+                merchant_context,
                 business_profile,
                 feature_to_enable,
                 dynamic_routing.clone(),
                 dynamic_routing_type,
                 dynamic_routing.success_based_algorithm,
                 payload,
+                created_by,
             )
             .await
         }
         routing_types::DynamicRoutingType::EliminationRouting => {
             enable_specific_routing_algorithm(
                 state,
-                key_store,
+                merchant_context,
                 business_profile,
                 feature_to_enable,
                 dynamic_routing.clone(),
                 dynamic_routing_type,
                 dynamic_routing.elimination_routing_algorithm,
                 payload,
+                created_by,
             )
             .await
         }
@@ -2025,13 +3866,14 @@ pub async fn enable_dynamic_routing_algorithm(
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 pub async fn enable_specific_routing_algorithm<A>(
     state: &SessionState,
-    key_store: domain::MerchantKeyStore,
+    merchant_context: &domain::MerchantContext,
     business_profile: domain::Profile,
     feature_to_enable: routing_types::DynamicRoutingFeatures,
     mut dynamic_routing_algo_ref: routing_types::DynamicRoutingAlgorithmRef,
     dynamic_routing_type: routing_types::DynamicRoutingType,
     algo_type: Option<A>,
     payload: Option<routing_types::DynamicRoutingPayload>,
+    created_by: Option<common_utils::types::CreatedBy>,
 ) -> RouterResult<ApplicationResponse<routing_types::RoutingDictionaryRecord>>
 where
     A: routing_types::DynamicRoutingAlgoAccessor + Clone + Debug,
@@ -2040,12 +3882,13 @@ where
     if let Some(payload) = payload {
         return create_specific_dynamic_routing_setup(
             state,
-            key_store,
+            merchant_context,
             business_profile,
             feature_to_enable,
             dynamic_routing_algo_ref,
             dynamic_routing_type,
             payload,
+            created_by,
         )
         .await;
     }
@@ -2053,11 +3896,12 @@ where
     let Some(mut algo_type) = algo_type else {
         return default_specific_dynamic_routing_setup(
             state,
-            key_store,
+            merchant_context,
             business_profile,
             feature_to_enable,
             dynamic_routing_algo_ref,
             dynamic_routing_type,
+            created_by,
         )
         .await;
     };
@@ -2070,11 +3914,12 @@ where
     else {
         return default_specific_dynamic_routing_setup(
             state,
-            key_store,
+            merchant_context,
             business_profile,
             feature_to_enable,
             dynamic_routing_algo_ref,
             dynamic_routing_type,
+            created_by,
         )
         .await;
     };
@@ -2093,14 +3938,29 @@ where
     };
     *algo_type_enabled_features = feature_to_enable;
     dynamic_routing_algo_ref.update_enabled_features(dynamic_routing_type, feature_to_enable);
-    update_business_profile_active_dynamic_algorithm_ref(
+    let routing_audit_log = update_business_profile_active_dynamic_algorithm_ref(
         db,
         &state.into(),
-        &key_store,
-        business_profile,
+        merchant_context.get_merchant_key_store(),
+        business_profile.clone(),
         dynamic_routing_algo_ref.clone(),
+        RoutingAuditLogDetails {
+            action: storage::enums::RoutingAuditAction::DynamicRoutingUpdate,
+            transaction_type: storage::enums::TransactionType::Payment,
+            previous_algorithm_id: Some(algo_type_algorithm_id.clone()),
+            new_algorithm_id: Some(algo_type_algorithm_id.clone()),
+            config_diff: None,
+            created_by,
+        },
     )
     .await?;
+    trigger_routing_config_webhook(
+        state,
+        merchant_context,
+        &business_profile,
+        routing_audit_log,
+    )
+    .await;
 
     let routing_algorithm = db
         .find_routing_algorithm_by_profile_id_algorithm_id(&profile_id, &algo_type_algorithm_id)
@@ -2118,11 +3978,12 @@ where
 #[instrument(skip_all)]
 pub async fn default_specific_dynamic_routing_setup(
     state: &SessionState,
-    key_store: domain::MerchantKeyStore,
+    merchant_context: &domain::MerchantContext,
     business_profile: domain::Profile,
     feature_to_enable: routing_types::DynamicRoutingFeatures,
     mut dynamic_routing_algo_ref: routing_types::DynamicRoutingAlgorithmRef,
     dynamic_routing_type: routing_types::DynamicRoutingType,
+    created_by: Option<common_utils::types::CreatedBy>,
 ) -> RouterResult<ApplicationResponse<routing_types::RoutingDictionaryRecord>> {
     let db = state.store.as_ref();
     let key_manager_state = &state.into();
@@ -2130,6 +3991,7 @@ pub async fn default_specific_dynamic_routing_setup(
     let merchant_id = business_profile.merchant_id.clone();
     let algorithm_id = common_utils::generate_routing_id_of_default_length();
     let timestamp = common_utils::date_time::now();
+    let previous_algorithm_id = dynamic_routing_algo_ref.get_algorithm_id(dynamic_routing_type);
 
     let algo = match dynamic_routing_type {
         routing_types::DynamicRoutingType::SuccessRateBasedRouting => {
@@ -2207,19 +4069,43 @@ pub async fn default_specific_dynamic_routing_setup(
         .attach_printable("Unable to insert record in routing algorithm table")?;
 
     dynamic_routing_algo_ref.update_algorithm_id(
-        algorithm_id,
+        algorithm_id.clone(),
         feature_to_enable,
         dynamic_routing_type,
     );
-    update_business_profile_active_dynamic_algorithm_ref(
+    let routing_audit_log = update_business_profile_active_dynamic_algorithm_ref(
         db,
         key_manager_state,
-        &key_store,
-        business_profile,
+        merchant_context.get_merchant_key_store(),
+        business_profile.clone(),
         dynamic_routing_algo_ref,
+        RoutingAuditLogDetails {
+            action: storage::enums::RoutingAuditAction::DynamicRoutingUpdate,
+            transaction_type: storage::enums::TransactionType::Payment,
+            previous_algorithm_id,
+            new_algorithm_id: Some(algorithm_id.clone()),
+            config_diff: None,
+            created_by,
+        },
     )
     .await?;
 
+    record_routing_version(
+        state,
+        &business_profile,
+        &algorithm_id,
+        None,
+        &storage::enums::TransactionType::Payment,
+    )
+    .await?;
+    trigger_routing_config_webhook(
+        state,
+        merchant_context,
+        &business_profile,
+        routing_audit_log,
+    )
+    .await;
+
     let new_record = record.foreign_into();
 
     core_metrics::ROUTING_CREATE_SUCCESS_RESPONSE.add(
@@ -2233,12 +4119,13 @@ pub async fn default_specific_dynamic_routing_setup(
 #[instrument(skip_all)]
 pub async fn create_specific_dynamic_routing_setup(
     state: &SessionState,
-    key_store: domain::MerchantKeyStore,
+    merchant_context: &domain::MerchantContext,
     business_profile: domain::Profile,
     feature_to_enable: routing_types::DynamicRoutingFeatures,
     mut dynamic_routing_algo_ref: routing_types::DynamicRoutingAlgorithmRef,
     dynamic_routing_type: routing_types::DynamicRoutingType,
     payload: routing_types::DynamicRoutingPayload,
+    created_by: Option<common_utils::types::CreatedBy>,
 ) -> RouterResult<ApplicationResponse<routing_types::RoutingDictionaryRecord>> {
     let db = state.store.as_ref();
     let key_manager_state = &state.into();
@@ -2246,6 +4133,7 @@ pub async fn create_specific_dynamic_routing_setup(
     let merchant_id = business_profile.merchant_id.clone();
     let algorithm_id = common_utils::generate_routing_id_of_default_length();
     let timestamp = common_utils::date_time::now();
+    let previous_algorithm_id = dynamic_routing_algo_ref.get_algorithm_id(dynamic_routing_type);
 
     let algo = match dynamic_routing_type {
         routing_types::DynamicRoutingType::SuccessRateBasedRouting => {
@@ -2314,15 +4202,39 @@ pub async fn create_specific_dynamic_routing_setup(
         .attach_printable("Unable to insert record in routing algorithm table")?;
 
     dynamic_routing_algo_ref.update_feature(feature_to_enable, dynamic_routing_type);
-    update_business_profile_active_dynamic_algorithm_ref(
+    let routing_audit_log = update_business_profile_active_dynamic_algorithm_ref(
         db,
         key_manager_state,
-        &key_store,
-        business_profile,
+        merchant_context.get_merchant_key_store(),
+        business_profile.clone(),
         dynamic_routing_algo_ref,
+        RoutingAuditLogDetails {
+            action: storage::enums::RoutingAuditAction::DynamicRoutingUpdate,
+            transaction_type: storage::enums::TransactionType::Payment,
+            previous_algorithm_id,
+            new_algorithm_id: Some(algorithm_id.clone()),
+            config_diff: None,
+            created_by,
+        },
     )
     .await?;
 
+    record_routing_version(
+        state,
+        &business_profile,
+        &algorithm_id,
+        None,
+        &storage::enums::TransactionType::Payment,
+    )
+    .await?;
+    trigger_routing_config_webhook(
+        state,
+        merchant_context,
+        &business_profile,
+        routing_audit_log,
+    )
+    .await;
+
     let new_record = record.foreign_into();
 
     core_metrics::ROUTING_CREATE_SUCCESS_RESPONSE.add(
@@ -2731,3 +4643,238 @@ pub async fn delete_decision_engine_merchant(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use super::*;
+
+    #[test]
+    fn test_calculate_latency_percentile() {
+        assert_eq!(calculate_latency_percentile(Vec::new(), 95), None);
+        assert_eq!(calculate_latency_percentile(vec![120], 95), Some(120));
+
+        let samples = (1..=100).rev().collect::<Vec<u64>>();
+        assert_eq!(calculate_latency_percentile(samples.clone(), 95), Some(95));
+        assert_eq!(calculate_latency_percentile(samples, 50), Some(50));
+    }
+
+    #[test]
+    fn test_connector_latency_keys_share_hash_tag() {
+        let mca_id = id_type::MerchantConnectorAccountId::wrap("mca_1".to_string())
+            .expect("invalid merchant connector account id");
+
+        assert_eq!(
+            get_connector_latency_key("pro_1", "stripe", None),
+            "connector_latency_{pro_1}_stripe"
+        );
+        assert_eq!(
+            get_connector_latency_key("pro_1", "stripe", Some(&mca_id)),
+            "connector_latency_{pro_1}_mca_1"
+        );
+    }
+
+    #[test]
+    fn test_payment_method_default_config_key() {
+        assert_eq!(
+            get_payment_method_default_config_key(
+                "pro_1",
+                storage::enums::PaymentMethodType::Credit
+            ),
+            "routing_default_pro_1_pmt_credit"
+        );
+        assert_ne!(
+            get_payment_method_default_config_key(
+                "pro_1",
+                storage::enums::PaymentMethodType::Debit
+            ),
+            get_payment_method_default_config_key(
+                "pro_1",
+                storage::enums::PaymentMethodType::Credit
+            )
+        );
+    }
+
+    #[test]
+    fn test_get_routing_config_diff() {
+        let old_config = serde_json::json!({
+            "type": "priority",
+            "data": [{ "connector": "stripe" }, { "connector": "adyen" }]
+        });
+        let new_config = serde_json::json!({
+            "type": "priority",
+            "data": [{ "connector": "adyen" }]
+        });
+
+        assert_eq!(get_routing_config_diff(&old_config, &old_config), None);
+        assert_eq!(
+            get_routing_config_diff(&old_config, &new_config),
+            Some(serde_json::json!({
+                "$.data[0].connector": { "old": "stripe", "new": "adyen" },
+                "$.data[1]": { "old": { "connector": "adyen" }, "new": null }
+            }))
+        );
+    }
+
+    #[test]
+    fn test_get_routing_algorithm_diff() {
+        let advanced_algorithm = |rules: serde_json::Value| {
+            serde_json::from_value::<routing_types::StaticRoutingAlgorithm>(serde_json::json!({
+                "type": "advanced",
+                "data": {
+                    "defaultSelection": { "type": "priority", "data": ["stripe"] },
+                    "rules": rules,
+                    "metadata": {}
+                }
+            }))
+            .expect("Failed to parse routing algorithm")
+        };
+        let rule = |name: &str, connector: &str| {
+            serde_json::json!({
+                "name": name,
+                "connectorSelection": { "type": "priority", "data": [connector] },
+                "statements": []
+            })
+        };
+        let algorithm = advanced_algorithm(serde_json::json!([
+            rule("card_rule", "adyen"),
+            rule("wallet_rule", "checkout"),
+        ]));
+        let other_algorithm = advanced_algorithm(serde_json::json!([
+            rule("card_rule", "checkout"),
+            rule("bank_rule", "adyen"),
+        ]));
+        let algorithm_id = common_utils::generate_routing_id_of_default_length();
+        let other_algorithm_id = common_utils::generate_routing_id_of_default_length();
+
+        let diff = get_routing_algorithm_diff(
+            algorithm_id.clone(),
+            &algorithm,
+            other_algorithm_id.clone(),
+            &other_algorithm,
+        )
+        .expect("Failed to compute routing algorithm diff");
+        assert_eq!(
+            diff.added
+                .iter()
+                .map(|rule| rule.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["bank_rule"]
+        );
+        assert_eq!(
+            diff.removed
+                .iter()
+                .map(|rule| rule.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["wallet_rule"]
+        );
+        assert_eq!(
+            diff.changed
+                .iter()
+                .map(|rule| rule.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["card_rule"]
+        );
+
+        let diff =
+            get_routing_algorithm_diff(algorithm_id.clone(), &algorithm, algorithm_id, &algorithm)
+                .expect("Failed to compute routing algorithm diff");
+        assert!(diff.added.is_empty() && diff.removed.is_empty() && diff.changed.is_empty());
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_get_routing_experiment_arm() {
+        let mut experiment = routing_types::RoutingExperiment {
+            experiment_id: "routing_exp_test".to_string(),
+            profile_id: <id_type::ProfileId as id_type::GenerateId>::generate(),
+            name: "test".to_string(),
+            control_algorithm_id: common_utils::generate_routing_id_of_default_length(),
+            treatment_algorithm_id: common_utils::generate_routing_id_of_default_length(),
+            treatment_percentage: 0,
+            status: routing_types::RoutingExperimentStatus::Running,
+            started_at: common_utils::date_time::now(),
+            stopped_at: None,
+        };
+        let payment_ids = (0..100)
+            .map(|index| {
+                id_type::PaymentId::try_from(std::borrow::Cow::Owned(format!("pay_{index}")))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .expect("invalid payment id");
+        let count_treatment = |experiment: &routing_types::RoutingExperiment| {
+            payment_ids
+                .iter()
+                .filter(|payment_id| {
+                    get_routing_experiment_arm(experiment, payment_id)
+                        == storage::enums::RoutingExperimentArm::Treatment
+                })
+                .count()
+        };
+
+        assert_eq!(count_treatment(&experiment), 0);
+
+        experiment.treatment_percentage = 100;
+        assert_eq!(count_treatment(&experiment), 100);
+
+        experiment.treatment_percentage = 50;
+        let treatment_count = count_treatment(&experiment);
+        assert!(treatment_count > 0 && treatment_count < 100);
+        assert_eq!(count_treatment(&experiment), treatment_count);
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_connector_health_breaker_state() {
+        let now = common_utils::date_time::now();
+        let mut breaker = ConnectorHealthBreaker {
+            opened_at: now,
+            open_until: now + time::Duration::seconds(30),
+        };
+        assert_eq!(
+            breaker.get_state(),
+            routing_types::ConnectorCircuitBreakerState::Open
+        );
+
+        breaker.open_until = now - time::Duration::seconds(1);
+        assert_eq!(
+            breaker.get_state(),
+            routing_types::ConnectorCircuitBreakerState::HalfOpen
+        );
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_routing_experiment_assignment_is_chosen_connector() {
+        let experiment = routing_types::RoutingExperiment {
+            experiment_id: "routing_exp_test".to_string(),
+            profile_id: <id_type::ProfileId as id_type::GenerateId>::generate(),
+            name: "test".to_string(),
+            control_algorithm_id: common_utils::generate_routing_id_of_default_length(),
+            treatment_algorithm_id: common_utils::generate_routing_id_of_default_length(),
+            treatment_percentage: 50,
+            status: routing_types::RoutingExperimentStatus::Running,
+            started_at: common_utils::date_time::now(),
+            stopped_at: None,
+        };
+        let merchant_connector_id = |id: &'static str| {
+            id_type::MerchantConnectorAccountId::wrap(id.to_string())
+                .expect("invalid merchant connector id")
+        };
+        let connectors = vec![routing_types::RoutableConnectorChoice {
+            choice_kind: routing_types::RoutableChoiceKind::FullStruct,
+            connector: common_enums::RoutableConnectors::Stripe,
+            merchant_connector_id: Some(merchant_connector_id("mca_stripe")),
+        }];
+
+        let assignment = RoutingExperimentAssignment::new(
+            &experiment,
+            storage::enums::RoutingExperimentArm::Treatment,
+            &connectors,
+        );
+        assert_eq!(assignment.algorithm_id, experiment.treatment_algorithm_id);
+        assert!(assignment.is_chosen_connector(Some(&merchant_connector_id("mca_stripe"))));
+        assert!(!assignment.is_chosen_connector(Some(&merchant_connector_id("mca_adyen"))));
+        assert!(!assignment.is_chosen_connector(None));
+    }
+}
@@ -1,6 +1,6 @@
 use api_models::routing::{
     DynamicRoutingAlgorithm, MerchantRoutingAlgorithm, RoutingAlgorithmKind,
-    RoutingAlgorithmWrapper, RoutingDictionaryRecord,
+    RoutingAlgorithmWrapper, RoutingAuditLogRecord, RoutingDictionaryRecord,
 };
 #[cfg(feature = "v1")]
 use api_models::{
@@ -11,6 +11,7 @@ use common_utils::ext_traits::ValueExt;
 use diesel_models::{
     enums as storage_enums,
     routing_algorithm::{RoutingAlgorithm, RoutingProfileMetadata},
+    routing_audit_log::RoutingAuditLog,
 };
 #[cfg(feature = "v1")]
 use hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt;
@@ -21,6 +22,22 @@ use crate::{
     types::transformers::{ForeignFrom, ForeignInto, ForeignTryFrom},
 };
 
+impl ForeignFrom<RoutingAuditLog> for RoutingAuditLogRecord {
+    fn foreign_from(value: RoutingAuditLog) -> Self {
+        Self {
+            id: value.id,
+            profile_id: value.profile_id,
+            action: value.action,
+            transaction_type: value.transaction_type,
+            previous_algorithm_id: value.previous_algorithm_id,
+            new_algorithm_id: value.new_algorithm_id,
+            config_diff: value.config_diff,
+            created_by: value.created_by,
+            created_at: value.created_at,
+        }
+    }
+}
+
 impl ForeignFrom<RoutingProfileMetadata> for RoutingDictionaryRecord {
     fn foreign_from(value: RoutingProfileMetadata) -> Self {
         Self {
@@ -92,6 +109,7 @@ impl ForeignFrom<storage_enums::RoutingAlgorithmKind> for RoutingAlgorithmKind {
             storage_enums::RoutingAlgorithmKind::Advanced => Self::Advanced,
             storage_enums::RoutingAlgorithmKind::Dynamic => Self::Dynamic,
             storage_enums::RoutingAlgorithmKind::ThreeDsDecisionRule => Self::ThreeDsDecisionRule,
+            storage_enums::RoutingAlgorithmKind::Cost => Self::Cost,
         }
     }
 }
@@ -105,6 +123,7 @@ impl ForeignFrom<RoutingAlgorithmKind> for storage_enums::RoutingAlgorithmKind {
             RoutingAlgorithmKind::Advanced => Self::Advanced,
             RoutingAlgorithmKind::Dynamic => Self::Dynamic,
             RoutingAlgorithmKind::ThreeDsDecisionRule => Self::ThreeDsDecisionRule,
+            RoutingAlgorithmKind::Cost => Self::Cost,
         }
     }
 }
@@ -0,0 +1,116 @@
+use common_utils::{crypto, date_time, fp_utils, type_name, types::keymanager::Identifier};
+use error_stack::{report, ResultExt};
+use masking::{PeekInterface, StrongSecret};
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, StorageErrorExt},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{api, domain, storage},
+};
+
+#[instrument(skip_all)]
+pub async fn create_signing_key(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+) -> RouterResponse<api::CreateSigningKeyResponse> {
+    let store = state.store.as_ref();
+    let merchant_id = key_store.merchant_id.clone();
+
+    let signing_keys = store
+        .list_merchant_signing_keys_by_merchant_id(&merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list merchant signing keys")?;
+    fp_utils::when(
+        signing_keys.len() >= consts::MAX_SIGNING_KEYS_PER_MERCHANT,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "A merchant can have at most {} signing keys, revoke one of the existing signing keys to create a new one",
+                    consts::MAX_SIGNING_KEYS_PER_MERCHANT
+                ),
+            }))
+        },
+    )?;
+
+    let signing_secret = StrongSecret::new(
+        crypto::generate_cryptographically_secure_random_string(consts::SIGNING_SECRET_LENGTH),
+    );
+    let encrypted_signing_secret = domain::types::crypto_operation::<String, masking::WithType>(
+        &(&state).into(),
+        type_name!(storage::MerchantSigningKey),
+        domain::types::CryptoOperation::Encrypt(signing_secret.peek().clone().into()),
+        Identifier::Merchant(merchant_id.clone()),
+        key_store.key.peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt signing secret")?;
+
+    let signing_key = store
+        .insert_merchant_signing_key(storage::MerchantSigningKeyNew {
+            key_id: common_utils::generate_id(consts::ID_LENGTH, "sigk"),
+            merchant_id,
+            signing_secret: encrypted_signing_secret.into(),
+            created_at: date_time::now(),
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert new signing key")?;
+
+    Ok(ApplicationResponse::Json(api::CreateSigningKeyResponse {
+        key_id: signing_key.key_id,
+        merchant_id: signing_key.merchant_id,
+        signing_secret,
+        created: signing_key.created_at,
+    }))
+}
+
+#[instrument(skip_all)]
+pub async fn list_signing_keys(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+) -> RouterResponse<Vec<api::SigningKeyResponse>> {
+    let signing_keys = state
+        .store
+        .list_merchant_signing_keys_by_merchant_id(&merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list merchant signing keys")?;
+
+    Ok(ApplicationResponse::Json(
+        signing_keys
+            .into_iter()
+            .map(|signing_key| api::SigningKeyResponse {
+                key_id: signing_key.key_id,
+                merchant_id: signing_key.merchant_id,
+                created: signing_key.created_at,
+            })
+            .collect(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn revoke_signing_key(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    key_id: String,
+) -> RouterResponse<api::RevokeSigningKeyResponse> {
+    let signing_key = state
+        .store
+        .delete_merchant_signing_key_by_merchant_id_key_id(&merchant_id, &key_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Signing key does not exist in our records".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(api::RevokeSigningKeyResponse {
+        merchant_id: signing_key.merchant_id,
+        key_id: signing_key.key_id,
+        revoked: true,
+    }))
+}
@@ -3,14 +3,30 @@ use std::str::FromStr;
 use api_models::subscription::{
     self as subscription_types, CreateSubscriptionResponse, SubscriptionStatus,
 };
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::OptionExt;
 use common_utils::id_type::GenerateId;
 use diesel_models::subscription::SubscriptionNew;
 use error_stack::ResultExt;
 use hyperswitch_domain_models::{api::ApplicationResponse, merchant_context::MerchantContext};
 use masking::Secret;
+#[cfg(feature = "v1")]
+use router_env::logger;
+use time::PrimitiveDateTime;
 
-use super::errors::{self, RouterResponse};
-use crate::routes::SessionState;
+use super::errors::{self, RouterResponse, RouterResult, StorageErrorExt};
+#[cfg(feature = "v1")]
+use crate::{
+    core::{payments, webhooks as webhooks_core},
+    routes::app::ReqState,
+    services,
+    types::{api, domain},
+};
+use crate::{
+    db::StorageInterface,
+    routes::SessionState,
+    types::{storage, transformers::ForeignFrom},
+};
 
 pub async fn create_subscription(
     state: SessionState,
@@ -27,6 +43,23 @@ pub async fn create_subscription(
         },
     )?;
 
+    if let Some(plan_id) = &request.plan_id {
+        let plan = db
+            .find_subscription_plan_by_merchant_id_plan_id(
+                merchant_context.get_merchant_account().get_id(),
+                plan_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+                message: "Subscription plan not found".to_string(),
+            })?;
+        common_utils::fp_utils::when(plan.profile_id != profile_id, || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "subscription plan does not belong to the profile".to_string(),
+            })
+        })?;
+    }
+
     let mut subscription = SubscriptionNew::new(
         id,
         SubscriptionStatus::Created.to_string(),
@@ -40,6 +73,7 @@ pub async fn create_subscription(
         None,
         profile_id,
         request.merchant_reference_id,
+        request.plan_id.clone(),
     );
 
     subscription.generate_and_set_client_secret();
@@ -54,7 +88,7 @@ pub async fn create_subscription(
         subscription_response.merchant_reference_id,
         SubscriptionStatus::from_str(&subscription_response.status)
             .unwrap_or(SubscriptionStatus::Created),
-        None,
+        subscription_response.plan_id,
         subscription_response.profile_id,
         subscription_response.merchant_id,
         subscription_response.client_secret.map(Secret::new),
@@ -63,3 +97,423 @@ pub async fn create_subscription(
 
     Ok(ApplicationResponse::Json(response))
 }
+
+pub async fn create_subscription_plan(
+    state: SessionState,
+    merchant_context: MerchantContext,
+    profile_id: String,
+    request: subscription_types::CreateSubscriptionPlanRequest,
+) -> RouterResponse<subscription_types::SubscriptionPlanResponse> {
+    let db = &*state.store;
+    let profile_id = common_utils::id_type::ProfileId::from_str(&profile_id).change_context(
+        errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "X-Profile-Id",
+        },
+    )?;
+    common_utils::fp_utils::when(request.amount.get_amount_as_i64() <= 0, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount must be greater than zero for a subscription plan".to_string(),
+        })
+    })?;
+    common_utils::fp_utils::when(request.billing_interval_count == Some(0), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "billing_interval_count must be greater than zero".to_string(),
+        })
+    })?;
+
+    let now = common_utils::date_time::now();
+    let plan = storage::SubscriptionPlanNew {
+        id: common_utils::generate_id_with_default_len("plan"),
+        merchant_id: merchant_context.get_merchant_account().get_id().clone(),
+        profile_id,
+        name: request.name,
+        amount: request.amount,
+        currency: request.currency,
+        billing_interval: request.billing_interval,
+        billing_interval_count: request.billing_interval_count.map_or(1, i32::from),
+        trial_period_days: request.trial_period_days.map(i32::from),
+        max_payment_retries: request.max_payment_retries.map_or(3, i32::from),
+        payment_retry_interval_hours: request.payment_retry_interval_hours.map_or(24, i32::from),
+        created_at: now,
+        modified_at: now,
+    };
+
+    let plan = db
+        .insert_subscription_plan(plan)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("subscriptions: unable to insert subscription plan to database")?;
+
+    Ok(ApplicationResponse::Json(
+        subscription_types::SubscriptionPlanResponse::foreign_from(plan),
+    ))
+}
+
+pub async fn retrieve_subscription_plan(
+    state: SessionState,
+    merchant_context: MerchantContext,
+    plan_id: String,
+) -> RouterResponse<subscription_types::SubscriptionPlanResponse> {
+    let plan = find_subscription_plan(
+        &*state.store,
+        merchant_context.get_merchant_account().get_id(),
+        &plan_id,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(
+        subscription_types::SubscriptionPlanResponse::foreign_from(plan),
+    ))
+}
+
+pub async fn retrieve_subscription(
+    state: SessionState,
+    merchant_context: MerchantContext,
+    subscription_id: String,
+) -> RouterResponse<subscription_types::SubscriptionResponse> {
+    let subscription = state
+        .store
+        .find_by_merchant_id_subscription_id(
+            merchant_context.get_merchant_account().get_id(),
+            subscription_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Subscription not found".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        subscription_types::SubscriptionResponse::foreign_from(subscription),
+    ))
+}
+
+#[cfg(feature = "v2")]
+pub async fn attach_subscription_payment_method(
+    _state: SessionState,
+    _merchant_context: MerchantContext,
+    _subscription_id: String,
+    _request: subscription_types::AttachSubscriptionPaymentMethodRequest,
+) -> RouterResponse<subscription_types::SubscriptionResponse> {
+    todo!()
+}
+
+/// Attaches the saved payment method or mandate a subscription is billed with. Attaching it to
+/// a newly created subscription starts its trial, if any, and schedules its first billing cycle.
+#[cfg(feature = "v1")]
+pub async fn attach_subscription_payment_method(
+    state: SessionState,
+    merchant_context: MerchantContext,
+    subscription_id: String,
+    request: subscription_types::AttachSubscriptionPaymentMethodRequest,
+) -> RouterResponse<subscription_types::SubscriptionResponse> {
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+
+    let subscription = db
+        .find_by_merchant_id_subscription_id(merchant_id, subscription_id.clone())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Subscription not found".to_string(),
+        })?;
+    let status =
+        SubscriptionStatus::from_str(&subscription.status).unwrap_or(SubscriptionStatus::Created);
+    common_utils::fp_utils::when(status == SubscriptionStatus::InActive, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "payment method cannot be attached to an inactive subscription".to_string(),
+        })
+    })?;
+    let plan_id = subscription
+        .plan_id
+        .clone()
+        .get_required_value("plan_id")
+        .change_context(errors::ApiErrorResponse::PreconditionFailed {
+            message: "subscription is not associated with a plan".to_string(),
+        })?;
+
+    let (payment_method_id, mandate_id) = match (request.payment_method_id, request.mandate_id) {
+        (Some(payment_method_id), None) => {
+            let payment_method = db
+                .find_payment_method(
+                    key_manager_state,
+                    merchant_context.get_merchant_key_store(),
+                    &payment_method_id,
+                    storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+            common_utils::fp_utils::when(
+                payment_method.customer_id != subscription.customer_id
+                    || payment_method.status != storage::enums::PaymentMethodStatus::Active,
+                || {
+                    Err(errors::ApiErrorResponse::PreconditionFailed {
+                        message: "payment method is not an active payment method of the customer"
+                            .to_string(),
+                    })
+                },
+            )?;
+            (Some(payment_method_id), None)
+        }
+        (None, Some(mandate_id)) => {
+            let mandate = db
+                .find_mandate_by_merchant_id_mandate_id(merchant_id, &mandate_id, storage_scheme)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+            common_utils::fp_utils::when(
+                mandate.customer_id != subscription.customer_id
+                    || mandate.mandate_status != storage::enums::MandateStatus::Active,
+                || {
+                    Err(errors::ApiErrorResponse::PreconditionFailed {
+                        message: "mandate is not an active mandate of the customer".to_string(),
+                    })
+                },
+            )?;
+            (Some(mandate.payment_method_id), Some(mandate_id))
+        }
+        _ => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "exactly one of payment_method_id and mandate_id must be provided".to_string(),
+        })?,
+    };
+
+    let mut subscription_update = storage::SubscriptionUpdate::new(payment_method_id, None);
+    subscription_update.mandate_id = mandate_id;
+
+    // Subscriptions which are already being billed only switch the payment method used for the
+    // upcoming billing cycles
+    let billing_starts_at = if status == SubscriptionStatus::Created {
+        let plan = find_subscription_plan(db, merchant_id, &plan_id).await?;
+        let now = common_utils::date_time::now();
+        let (status, billing_starts_at) = match plan.trial_period_days {
+            Some(trial_period_days) if trial_period_days > 0 => (
+                SubscriptionStatus::Trial,
+                now.saturating_add(time::Duration::days(trial_period_days.into())),
+            ),
+            _ => (SubscriptionStatus::Pending, now),
+        };
+        subscription_update.status = Some(status.to_string());
+        subscription_update.trial_end =
+            (status == SubscriptionStatus::Trial).then_some(billing_starts_at);
+        subscription_update.current_period_start = Some(now);
+        subscription_update.current_period_end = Some(billing_starts_at);
+        Some(billing_starts_at)
+    } else {
+        None
+    };
+
+    let subscription = db
+        .update_subscription_entry(merchant_id, subscription_id, subscription_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("subscriptions: unable to update subscription entry in database")?;
+
+    if let Some(billing_starts_at) = billing_starts_at {
+        add_subscription_billing_task(
+            db,
+            storage::SubscriptionBillingTrackingData {
+                subscription_id: subscription.id.clone(),
+                merchant_id: subscription.merchant_id.clone(),
+                profile_id: subscription.profile_id.clone(),
+            },
+            billing_starts_at,
+        )
+        .await?;
+    }
+
+    Ok(ApplicationResponse::Json(
+        subscription_types::SubscriptionResponse::foreign_from(subscription),
+    ))
+}
+
+pub async fn find_subscription_plan(
+    db: &dyn StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+    plan_id: &str,
+) -> RouterResult<storage::SubscriptionPlan> {
+    db.find_subscription_plan_by_merchant_id_plan_id(merchant_id, plan_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Subscription plan not found".to_string(),
+        })
+}
+
+/// Computes the end of a billing period of `count` intervals starting at `from`. Monthly and
+/// yearly periods ending on a day missing from the target month are clamped to its last day.
+pub fn add_billing_interval(
+    from: PrimitiveDateTime,
+    interval: storage::enums::SubscriptionBillingInterval,
+    count: i32,
+) -> PrimitiveDateTime {
+    match interval {
+        storage::enums::SubscriptionBillingInterval::Day => {
+            from.saturating_add(time::Duration::days(count.into()))
+        }
+        storage::enums::SubscriptionBillingInterval::Week => {
+            from.saturating_add(time::Duration::weeks(count.into()))
+        }
+        storage::enums::SubscriptionBillingInterval::Month => add_months(from, count),
+        storage::enums::SubscriptionBillingInterval::Year => {
+            add_months(from, count.saturating_mul(12))
+        }
+    }
+}
+
+fn add_months(from: PrimitiveDateTime, months: i32) -> PrimitiveDateTime {
+    let total_months = from
+        .year()
+        .saturating_mul(12)
+        .saturating_add(i32::from(u8::from(from.month())) - 1)
+        .saturating_add(months);
+    let month = u8::try_from(total_months.rem_euclid(12) + 1)
+        .ok()
+        .and_then(|month| time::Month::try_from(month).ok())
+        .unwrap_or(from.month());
+    let year = total_months.div_euclid(12);
+    let day = from.day().min(time::util::days_in_year_month(year, month));
+
+    time::Date::from_calendar_date(year, month, day)
+        .map(|date| PrimitiveDateTime::new(date, from.time()))
+        .unwrap_or(PrimitiveDateTime::MAX)
+}
+
+/// Schedules the billing of a subscription at the specified time. The same task is rescheduled
+/// for every subsequent billing cycle and payment retry.
+pub async fn add_subscription_billing_task(
+    db: &dyn StorageInterface,
+    tracking_data: storage::SubscriptionBillingTrackingData,
+    schedule_time: PrimitiveDateTime,
+) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::SubscriptionBillingWorkflow;
+    let task = "SUBSCRIPTION_BILLING";
+    let tag = ["SUBSCRIPTION", "BILLING"];
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        task,
+        tracking_data.subscription_id.get_string_repr(),
+        &tracking_data.merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct subscription billing process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert subscription billing task to process tracker")?;
+    crate::routes::metrics::TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "SubscriptionBilling")),
+    );
+
+    Ok(())
+}
+
+/// Creates and confirms an off-session payment for the current billing cycle of a subscription,
+/// using the mandate or saved payment method attached to it
+#[cfg(feature = "v1")]
+pub async fn create_billing_cycle_payment(
+    state: &SessionState,
+    req_state: ReqState,
+    merchant_context: &MerchantContext,
+    subscription: &storage::Subscription,
+    plan: &storage::SubscriptionPlan,
+    payment_id: common_utils::id_type::PaymentId,
+) -> RouterResult<api_models::payments::PaymentsResponse> {
+    let recurring_details = match (&subscription.mandate_id, &subscription.payment_method_id) {
+        (Some(mandate_id), _) => {
+            api_models::mandates::RecurringDetails::MandateId(mandate_id.clone())
+        }
+        (None, Some(payment_method_id)) => {
+            api_models::mandates::RecurringDetails::PaymentMethodId(payment_method_id.clone())
+        }
+        (None, None) => Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "subscription does not have a payment method attached".to_string(),
+        })?,
+    };
+
+    let payments_request = api_models::payments::PaymentsRequest {
+        amount: Some(plan.amount.into()),
+        currency: Some(plan.currency),
+        payment_id: Some(api_models::payments::PaymentIdType::PaymentIntentId(
+            payment_id,
+        )),
+        customer_id: Some(subscription.customer_id.clone()),
+        profile_id: Some(subscription.profile_id.clone()),
+        description: Some(format!("Subscription billing for plan {}", plan.name)),
+        recurring_details: Some(recurring_details),
+        off_session: Some(true),
+        confirm: Some(true),
+        ..Default::default()
+    };
+
+    Box::pin(payments::payments_core::<
+        api::Authorize,
+        api_models::payments::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api::Authorize>,
+    >(
+        state.clone(),
+        req_state,
+        merchant_context.clone(),
+        Some(subscription.profile_id.clone()),
+        payments::PaymentCreate,
+        payments_request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+    ))
+    .await?
+    .get_json_body()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Unexpected response from payments core")
+}
+
+/// Notifies the merchant of the outcome of a billing cycle of a subscription
+#[cfg(feature = "v1")]
+pub async fn trigger_subscription_webhook(
+    state: &SessionState,
+    merchant_context: &MerchantContext,
+    business_profile: &domain::Profile,
+    subscription: &storage::Subscription,
+    event_type: storage::enums::EventType,
+) {
+    // Each billing cycle payment attempt is a separate event, while deactivation happens once
+    let primary_object_id = match (event_type, &subscription.last_payment_id) {
+        (storage::enums::EventType::SubscriptionInactive, _) | (_, None) => {
+            subscription.id.get_string_repr().to_string()
+        }
+        (_, Some(payment_id)) => payment_id.get_string_repr().to_string(),
+    };
+
+    // The subscription has already been updated at this point, so failing to notify the
+    // merchant must not fail the billing cycle
+    Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_context.clone(),
+        business_profile.clone(),
+        event_type,
+        storage::enums::EventClass::Subscriptions,
+        primary_object_id,
+        storage::enums::EventObjectType::SubscriptionDetails,
+        api_models::webhooks::OutgoingWebhookContent::SubscriptionDetails(Box::new(
+            subscription_types::SubscriptionResponse::foreign_from(subscription.clone()),
+        )),
+        Some(subscription.modified_at),
+    ))
+    .await
+    .unwrap_or_else(
+        |error| logger::error!(?error, %event_type, "Failed to trigger subscription webhook"),
+    );
+}
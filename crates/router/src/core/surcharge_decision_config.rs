@@ -1,11 +1,15 @@
 use api_models::surcharge_decision_configs::{
     SurchargeDecisionConfigReq, SurchargeDecisionManagerRecord, SurchargeDecisionManagerResponse,
+    SurchargePreviewRequest, SurchargePreviewResponse,
 };
 use common_utils::ext_traits::StringExt;
 use error_stack::ResultExt;
 
 use crate::{
-    core::errors::{self, RouterResponse},
+    core::{
+        cards_info,
+        errors::{self, RouterResponse},
+    },
     routes::SessionState,
     services::api as service_api,
     types::domain,
@@ -17,25 +21,11 @@ pub async fn upsert_surcharge_decision_config(
     merchant_context: domain::MerchantContext,
     request: SurchargeDecisionConfigReq,
 ) -> RouterResponse<SurchargeDecisionManagerRecord> {
-    use common_utils::ext_traits::{Encode, OptionExt, ValueExt};
-    use diesel_models::configs;
+    use common_utils::ext_traits::ValueExt;
     use storage_impl::redis::cache;
 
     use super::routing::helpers::update_merchant_active_algorithm_ref;
 
-    let db = state.store.as_ref();
-    let name = request.name;
-
-    let program = request
-        .algorithm
-        .get_required_value("algorithm")
-        .change_context(errors::ApiErrorResponse::MissingRequiredField {
-            field_name: "algorithm",
-        })
-        .attach_printable("Program for config not given")?;
-    let merchant_surcharge_configs = request.merchant_surcharge_configs;
-
-    let timestamp = common_utils::date_time::now_unix_timestamp();
     let mut algo_id: api_models::routing::RoutingAlgorithmRef = merchant_context
         .get_merchant_account()
         .routing_algorithm
@@ -50,17 +40,58 @@ pub async fn upsert_surcharge_decision_config(
         .get_merchant_account()
         .get_id()
         .get_payment_method_surcharge_routing_id();
-    let read_config_key = db.find_config_by_key(&key).await;
+    let record = upsert_surcharge_decision_record(&state, &key, request).await?;
+
+    let surcharge_cache_key = merchant_context
+        .get_merchant_account()
+        .get_id()
+        .get_surcharge_dsk_key();
+    algo_id.update_surcharge_config_id(key);
+    let config_key = cache::CacheKind::Surcharge(surcharge_cache_key.into());
+    update_merchant_active_algorithm_ref(
+        &state,
+        merchant_context.get_merchant_key_store(),
+        config_key,
+        algo_id,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update routing algorithm ref")?;
+
+    Ok(service_api::ApplicationResponse::Json(record))
+}
+
+/// Validates the surcharge rules in the request and stores them under the given config key,
+/// updating the existing record if one is present
+#[cfg(feature = "v1")]
+async fn upsert_surcharge_decision_record(
+    state: &SessionState,
+    key: &str,
+    request: SurchargeDecisionConfigReq,
+) -> errors::RouterResult<SurchargeDecisionManagerRecord> {
+    use common_utils::ext_traits::{Encode, OptionExt};
+    use diesel_models::configs;
+
+    let db = state.store.as_ref();
+    let name = request.name;
+
+    let program = request
+        .algorithm
+        .get_required_value("algorithm")
+        .change_context(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "algorithm",
+        })
+        .attach_printable("Program for config not given")?;
+    let merchant_surcharge_configs = request.merchant_surcharge_configs;
+
+    let timestamp = common_utils::date_time::now_unix_timestamp();
+    let read_config_key = db.find_config_by_key(key).await;
 
     euclid::frontend::ast::lowering::lower_program(program.clone())
         .change_context(errors::ApiErrorResponse::InvalidRequestData {
             message: "Invalid Request Data".to_string(),
         })
         .attach_printable("The Request has an Invalid Comparison")?;
-    let surcharge_cache_key = merchant_context
-        .get_merchant_account()
-        .get_id()
-        .get_surcharge_dsk_key();
     match read_config_key {
         Ok(config) => {
             let previous_record: SurchargeDecisionManagerRecord = config
@@ -86,24 +117,12 @@ pub async fn upsert_surcharge_decision_config(
                 config: Some(serialize_updated_str),
             };
 
-            db.update_config_by_key(&key, updated_config)
+            db.update_config_by_key(key, updated_config)
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error serializing the config")?;
 
-            algo_id.update_surcharge_config_id(key.clone());
-            let config_key = cache::CacheKind::Surcharge(surcharge_cache_key.into());
-            update_merchant_active_algorithm_ref(
-                &state,
-                merchant_context.get_merchant_key_store(),
-                config_key,
-                algo_id,
-            )
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed to update routing algorithm ref")?;
-
-            Ok(service_api::ApplicationResponse::Json(new_algo))
+            Ok(new_algo)
         }
         Err(e) if e.current_context().is_db_not_found() => {
             let new_rec = SurchargeDecisionManagerRecord {
@@ -124,7 +143,7 @@ pub async fn upsert_surcharge_decision_config(
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error serializing the config")?;
             let new_config = configs::ConfigNew {
-                key: key.clone(),
+                key: key.to_owned(),
                 config: serialized_str,
             };
 
@@ -133,19 +152,7 @@ pub async fn upsert_surcharge_decision_config(
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error fetching the config")?;
 
-            algo_id.update_surcharge_config_id(key.clone());
-            let config_key = cache::CacheKind::Surcharge(surcharge_cache_key.into());
-            update_merchant_active_algorithm_ref(
-                &state,
-                merchant_context.get_merchant_key_store(),
-                config_key,
-                algo_id,
-            )
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed to update routing algorithm ref")?;
-
-            Ok(service_api::ApplicationResponse::Json(new_rec))
+            Ok(new_rec)
         }
         Err(e) => Err(e)
             .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -238,3 +245,253 @@ pub async fn retrieve_surcharge_decision_config(
         .attach_printable("The Surcharge Decision Config Record was not found")?;
     Ok(service_api::ApplicationResponse::Json(record))
 }
+
+#[cfg(feature = "v1")]
+pub async fn upsert_surcharge_decision_config_for_profile(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: common_utils::id_type::ProfileId,
+    request: SurchargeDecisionConfigReq,
+) -> RouterResponse<SurchargeDecisionManagerRecord> {
+    let business_profile = get_business_profile(&state, &merchant_context, &profile_id).await?;
+    let key = profile_id.get_payment_method_surcharge_routing_id();
+    let record = upsert_surcharge_decision_record(&state, &key, request).await?;
+    update_profile_surcharge_config_ref(&state, &merchant_context, business_profile, Some(key))
+        .await?;
+
+    Ok(service_api::ApplicationResponse::Json(record))
+}
+
+#[cfg(feature = "v1")]
+pub async fn delete_surcharge_decision_config_for_profile(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: common_utils::id_type::ProfileId,
+) -> RouterResponse<()> {
+    let business_profile = get_business_profile(&state, &merchant_context, &profile_id).await?;
+    update_profile_surcharge_config_ref(&state, &merchant_context, business_profile, None).await?;
+
+    state
+        .store
+        .delete_config_by_key(&profile_id.get_payment_method_surcharge_routing_id())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to delete routing config from DB")?;
+    Ok(service_api::ApplicationResponse::StatusOk)
+}
+
+#[cfg(feature = "v1")]
+pub async fn retrieve_surcharge_decision_config_for_profile(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: common_utils::id_type::ProfileId,
+) -> RouterResponse<SurchargeDecisionManagerResponse> {
+    get_business_profile(&state, &merchant_context, &profile_id).await?;
+    let algo_config = state
+        .store
+        .find_config_by_key(&profile_id.get_payment_method_surcharge_routing_id())
+        .await
+        .change_context(errors::ApiErrorResponse::ResourceIdNotFound)
+        .attach_printable("The surcharge conditional config was not found in the DB")?;
+    let record: SurchargeDecisionManagerRecord = algo_config
+        .config
+        .parse_struct("SurchargeDecisionConfigsRecord")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("The Surcharge Decision Config Record was not found")?;
+    Ok(service_api::ApplicationResponse::Json(record))
+}
+
+#[cfg(feature = "v1")]
+async fn get_business_profile(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    profile_id: &common_utils::id_type::ProfileId,
+) -> errors::RouterResult<domain::Profile> {
+    use common_utils::ext_traits::OptionExt;
+
+    super::utils::validate_and_get_business_profile(
+        state.store.as_ref(),
+        &state.into(),
+        merchant_context.get_merchant_key_store(),
+        Some(profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })
+}
+
+/// Sets the surcharge config of the profile in its routing algorithm ref and invalidates the
+/// cached surcharge rules of the profile
+#[cfg(feature = "v1")]
+async fn update_profile_surcharge_config_ref(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    business_profile: domain::Profile,
+    surcharge_config_algo_id: Option<String>,
+) -> errors::RouterResult<()> {
+    use common_utils::ext_traits::ValueExt;
+    use storage_impl::redis::cache;
+
+    use super::routing::helpers::update_profile_active_algorithm_ref;
+
+    let db = state.store.as_ref();
+    let mut algo_id: api_models::routing::RoutingAlgorithmRef = business_profile
+        .routing_algorithm
+        .clone()
+        .map(|val| val.parse_value("routing algorithm"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not decode the routing algorithm")?
+        .unwrap_or_default();
+    match surcharge_config_algo_id {
+        Some(surcharge_config_algo_id) => {
+            algo_id.update_surcharge_config_id(surcharge_config_algo_id)
+        }
+        None => algo_id.surcharge_config_algo_id = None,
+    }
+    let surcharge_cache_key = business_profile.get_id().get_surcharge_dsl_key();
+
+    update_profile_active_algorithm_ref(
+        db,
+        &state.into(),
+        merchant_context.get_merchant_key_store(),
+        business_profile,
+        algo_id,
+        &common_enums::TransactionType::Payment,
+    )
+    .await?;
+
+    cache::redact_from_redis_and_publish(
+        db.get_cache_store().as_ref(),
+        [cache::CacheKind::Surcharge(surcharge_cache_key.into())],
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to invalidate the surcharge config cache")?;
+
+    Ok(())
+}
+
+#[cfg(feature = "v1")]
+pub async fn preview_surcharge(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: SurchargePreviewRequest,
+) -> RouterResponse<SurchargePreviewResponse> {
+    use euclid::backend::inputs as dsl_inputs;
+
+    use super::payment_methods::surcharge_decision_configs;
+
+    let business_profile = match request.profile_id.as_ref() {
+        Some(profile_id) => {
+            Some(get_business_profile(&state, &merchant_context, profile_id).await?)
+        }
+        None => None,
+    };
+    let surcharge_config = surcharge_decision_configs::get_surcharge_config_ref(
+        merchant_context.get_merchant_account(),
+        business_profile.as_ref(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Could not resolve the surcharge decision config")?;
+
+    let card_info = match request.card_bin.as_ref() {
+        Some(card_bin) => cards_info::get_card_info_for_bin(&state, card_bin)
+            .await
+            .map_err(|error| router_env::logger::warn!(card_info_error=?error))
+            .ok()
+            .flatten(),
+        None => None,
+    };
+    let card_network = request.card_network.or(card_info
+        .as_ref()
+        .and_then(|card_info| card_info.card_network.clone()));
+    let payment_method_type = request.payment_method_type.or(card_info
+        .and_then(|card_info| card_info.card_type)
+        .as_deref()
+        .and_then(super::payments::helpers::get_payment_method_type_from_card_type));
+
+    // The surcharge is evaluated and applied on the same amount as in the payment flow, which is
+    // the total amount of the payment before the surcharge is added to it
+    let total_amount = hyperswitch_domain_models::payments::payment_attempt::NetAmount::new(
+        request.amount,
+        request.shipping_cost,
+        request.order_tax_amount,
+        None,
+        None,
+    )
+    .get_total_amount();
+
+    let backend_input = dsl_inputs::BackendInput {
+        metadata: None,
+        payment: dsl_inputs::PaymentInput {
+            amount: total_amount,
+            currency: request.currency,
+            authentication_type: None,
+            card_bin: request.card_bin,
+            capture_method: None,
+            business_country: None,
+            billing_country: request.billing_country,
+            ip_country: None,
+            normalized_amount: None,
+            business_label: None,
+            setup_future_usage: None,
+        },
+        payment_method: dsl_inputs::PaymentMethodInput {
+            payment_method: Some(request.payment_method),
+            payment_method_type,
+            card_network,
+        },
+        mandate: dsl_inputs::MandateData {
+            mandate_acceptance_type: None,
+            mandate_type: None,
+            payment_type: None,
+        },
+        acquirer_data: None,
+        customer_device_data: None,
+        issuer_data: None,
+    };
+
+    let surcharge_details =
+        surcharge_decision_configs::perform_surcharge_decision_management_for_preview(
+            &state,
+            surcharge_config,
+            backend_input,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error evaluating the surcharge decision config")?;
+
+    let (surcharge_amount, tax_on_surcharge_amount) = surcharge_details
+        .as_ref()
+        .map(|surcharge_details| {
+            surcharge_decision_configs::calculate_surcharge_amounts(surcharge_details, total_amount)
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error calculating the surcharge amount")?
+        .unwrap_or_default();
+
+    Ok(service_api::ApplicationResponse::Json(
+        SurchargePreviewResponse {
+            amount: request.amount,
+            currency: request.currency,
+            surcharge_details,
+            surcharge_amount,
+            tax_on_surcharge_amount,
+            net_amount: total_amount + surcharge_amount + tax_on_surcharge_amount,
+        },
+    ))
+}
+
+#[cfg(feature = "v2")]
+pub async fn preview_surcharge(
+    _state: SessionState,
+    _merchant_context: domain::MerchantContext,
+    _request: SurchargePreviewRequest,
+) -> RouterResponse<SurchargePreviewResponse> {
+    todo!()
+}
@@ -1,7 +1,12 @@
 pub mod utils;
 
-use common_types::three_ds_decision_rule_engine::ThreeDSDecisionRule;
-use common_utils::ext_traits::ValueExt;
+use api_models::three_ds_decision_rule::{
+    ThreeDsDecisionManagerConfigRequest, ThreeDsDecisionManagerRecord,
+    ThreeDsDecisionManagerResponse,
+};
+use common_types::three_ds_decision_rule_engine::{ThreeDSDecision, ThreeDSDecisionRule};
+use common_utils::ext_traits::{Encode, OptionExt, StringExt, ValueExt};
+use diesel_models::configs;
 use error_stack::ResultExt;
 use euclid::{
     backend::{self, inputs as dsl_inputs, EuclidBackend},
@@ -9,11 +14,13 @@ use euclid::{
 };
 use hyperswitch_domain_models::merchant_context::MerchantContext;
 use router_env::{instrument, tracing};
+use storage_impl::redis::cache::{self, DECISION_MANAGER_CACHE};
 
 use crate::{
     core::{
         errors,
         errors::{RouterResponse, StorageErrorExt},
+        routing::helpers::update_merchant_active_algorithm_ref,
     },
     services,
     types::transformers::ForeignFrom,
@@ -29,7 +36,7 @@ pub async fn execute_three_ds_decision_rule(
     let decision = get_three_ds_decision_rule_output(
         &state,
         merchant_context.get_merchant_account().get_id(),
-        request.clone(),
+        request,
     )
     .await?;
     // Construct response
@@ -60,22 +67,254 @@ pub async fn get_three_ds_decision_rule_output(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Error parsing program from three_ds_decision rule algorithm")?;
     // Construct backend input from request
-    let backend_input = dsl_inputs::BackendInput::foreign_from(request.clone());
+    let backend_input = dsl_inputs::BackendInput::foreign_from(request);
     // Initialize interpreter with the rule program
     let interpreter = backend::VirInterpreterBackend::with_program(program)
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Error initializing DSL interpreter backend")?;
+    execute_three_ds_decision_rule_program(&interpreter, backend_input)
+}
+
+/// Executes the 3DS decision manager config of the merchant, returning `None` if the merchant
+/// has not configured one
+#[instrument(skip_all)]
+pub async fn get_three_ds_decision_manager_output(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    algorithm_ref: &api_models::routing::RoutingAlgorithmRef,
+    backend_input: dsl_inputs::BackendInput,
+) -> errors::RouterResult<Option<ThreeDSDecision>> {
+    let Some(config_key) = algorithm_ref.three_ds_decision_config_algo_id.as_ref() else {
+        return Ok(None);
+    };
+    let db = state.store.as_ref();
+
+    let find_key_from_db = || async {
+        let config = db.find_config_by_key(config_key).await?;
+
+        let record: ThreeDsDecisionManagerRecord = config
+            .config
+            .parse_struct("ThreeDsDecisionManagerRecord")
+            .change_context(errors::StorageError::DeserializationFailed)
+            .attach_printable("Error parsing 3DS decision manager config from configs")?;
+
+        backend::VirInterpreterBackend::with_program(record.algorithm)
+            .change_context(errors::StorageError::ValueNotFound("Program".to_string()))
+            .attach_printable("Error initializing DSL interpreter backend")
+    };
+
+    let interpreter = cache::get_or_populate_in_memory(
+        db.get_cache_store().as_ref(),
+        &merchant_id.get_three_ds_decision_dsl_key(),
+        find_key_from_db,
+        &DECISION_MANAGER_CACHE,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Error fetching 3DS decision manager config")?;
+
+    execute_three_ds_decision_rule_program(&interpreter, backend_input).map(Some)
+}
+
+fn execute_three_ds_decision_rule_program(
+    interpreter: &backend::VirInterpreterBackend<ThreeDSDecisionRule>,
+    backend_input: dsl_inputs::BackendInput,
+) -> errors::RouterResult<ThreeDSDecision> {
+    let sca_applicability = backend_input.get_sca_applicability();
     // Execute the rule
     let result = interpreter
         .execute(backend_input)
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Error executing 3DS decision rule")?;
     // Apply PSD2 validations to the decision
-    let final_decision =
-        utils::apply_psd2_validations_during_execute(result.get_output().get_decision(), &request);
+    let final_decision = utils::apply_psd2_validations_during_execute(
+        result.get_output().get_decision(),
+        sca_applicability,
+    );
     Ok(final_decision)
 }
 
+#[cfg(feature = "v1")]
+pub async fn upsert_three_ds_decision_manager_config(
+    state: SessionState,
+    merchant_context: MerchantContext,
+    request: ThreeDsDecisionManagerConfigRequest,
+) -> RouterResponse<ThreeDsDecisionManagerRecord> {
+    let db = state.store.as_ref();
+    let name = request.name;
+
+    let program = request
+        .algorithm
+        .get_required_value("algorithm")
+        .change_context(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "algorithm",
+        })
+        .attach_printable("Program for config not given")?;
+
+    ast::lowering::lower_program(program.clone())
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Invalid Request Data".to_string(),
+        })
+        .attach_printable("The Request has an Invalid Comparison")?;
+
+    let timestamp = common_utils::date_time::now_unix_timestamp();
+    let mut algorithm_ref: api_models::routing::RoutingAlgorithmRef = merchant_context
+        .get_merchant_account()
+        .routing_algorithm
+        .clone()
+        .map(|val| val.parse_value("routing algorithm"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not decode the routing algorithm")?
+        .unwrap_or_default();
+
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let key = merchant_id.get_three_ds_decision_routing_id();
+
+    let record = match db.find_config_by_key(&key).await {
+        Ok(config) => {
+            let previous_record: ThreeDsDecisionManagerRecord = config
+                .config
+                .parse_struct("ThreeDsDecisionManagerRecord")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to parse the existing 3DS decision manager config")?;
+
+            let record = ThreeDsDecisionManagerRecord {
+                name: name.unwrap_or(previous_record.name),
+                algorithm: program,
+                created_at: previous_record.created_at,
+                modified_at: timestamp,
+            };
+
+            let updated_config = configs::ConfigUpdate::Update {
+                config: Some(
+                    record
+                        .encode_to_string_of_json()
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Unable to serialize config to string")?,
+                ),
+            };
+
+            db.update_config_by_key(&key, updated_config)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update the 3DS decision manager config")?;
+
+            record
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            let record = ThreeDsDecisionManagerRecord {
+                name: name
+                    .get_required_value("name")
+                    .change_context(errors::ApiErrorResponse::MissingRequiredField {
+                        field_name: "name",
+                    })
+                    .attach_printable("name of the config not found")?,
+                algorithm: program,
+                created_at: timestamp,
+                modified_at: timestamp,
+            };
+
+            let new_config = configs::ConfigNew {
+                key: key.clone(),
+                config: record
+                    .encode_to_string_of_json()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Unable to serialize config to string")?,
+            };
+
+            db.insert_config(new_config)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert the 3DS decision manager config")?;
+
+            record
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error fetching the 3DS decision manager config")?,
+    };
+
+    algorithm_ref.update_three_ds_decision_config_id(key);
+    let cache_key =
+        cache::CacheKind::DecisionManager(merchant_id.get_three_ds_decision_dsl_key().into());
+    update_merchant_active_algorithm_ref(
+        &state,
+        merchant_context.get_merchant_key_store(),
+        cache_key,
+        algorithm_ref,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update routing algorithm ref")?;
+
+    Ok(services::ApplicationResponse::Json(record))
+}
+
+#[cfg(feature = "v1")]
+pub async fn delete_three_ds_decision_manager_config(
+    state: SessionState,
+    merchant_context: MerchantContext,
+) -> RouterResponse<()> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let key = merchant_id.get_three_ds_decision_routing_id();
+
+    let mut algorithm_ref: api_models::routing::RoutingAlgorithmRef = merchant_context
+        .get_merchant_account()
+        .routing_algorithm
+        .clone()
+        .map(|value| value.parse_value("routing algorithm"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not decode the routing algorithm")?
+        .unwrap_or_default();
+    algorithm_ref.three_ds_decision_config_algo_id = None;
+
+    let cache_key =
+        cache::CacheKind::DecisionManager(merchant_id.get_three_ds_decision_dsl_key().into());
+    update_merchant_active_algorithm_ref(
+        &state,
+        merchant_context.get_merchant_key_store(),
+        cache_key,
+        algorithm_ref,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update deleted algorithm ref")?;
+
+    db.delete_config_by_key(&key)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)
+        .attach_printable("Failed to delete the 3DS decision manager config")?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+pub async fn retrieve_three_ds_decision_manager_config(
+    state: SessionState,
+    merchant_context: MerchantContext,
+) -> RouterResponse<ThreeDsDecisionManagerResponse> {
+    let db = state.store.as_ref();
+    let key = merchant_context
+        .get_merchant_account()
+        .get_id()
+        .get_three_ds_decision_routing_id();
+
+    let config = db
+        .find_config_by_key(&key)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)
+        .attach_printable("The 3DS decision manager config was not found in the DB")?;
+    let record: ThreeDsDecisionManagerRecord = config
+        .config
+        .parse_struct("ThreeDsDecisionManagerRecord")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to parse the 3DS decision manager config")?;
+
+    Ok(services::ApplicationResponse::Json(record))
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Algorithm {
     data: serde_json::Value,
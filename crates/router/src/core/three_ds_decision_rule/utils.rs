@@ -1,27 +1,15 @@
 use api_models::three_ds_decision_rule as api_threedsecure;
 use common_types::three_ds_decision_rule_engine::ThreeDSDecision;
-use euclid::backend::inputs as dsl_inputs;
+use euclid::{backend::inputs as dsl_inputs, frontend::dir::enums::ScaApplicability};
 
-use crate::{consts::PSD2_COUNTRIES, types::transformers::ForeignFrom};
+use crate::types::transformers::ForeignFrom;
 
 // function to apply PSD2 validations to the decision
 pub fn apply_psd2_validations_during_execute(
     decision: ThreeDSDecision,
-    request: &api_models::three_ds_decision_rule::ThreeDsDecisionRuleExecuteRequest,
+    sca_applicability: Option<ScaApplicability>,
 ) -> ThreeDSDecision {
-    let issuer_in_psd2 = request
-        .issuer
-        .as_ref()
-        .and_then(|issuer| issuer.country)
-        .map(|country| PSD2_COUNTRIES.contains(&country))
-        .unwrap_or(false);
-    let acquirer_in_psd2 = request
-        .acquirer
-        .as_ref()
-        .and_then(|acquirer| acquirer.country)
-        .map(|country| PSD2_COUNTRIES.contains(&country))
-        .unwrap_or(false);
-    if issuer_in_psd2 && acquirer_in_psd2 {
+    if sca_applicability == Some(ScaApplicability::Applicable) {
         // If both issuer and acquirer are in PSD2 region
         match decision {
             // If the decision is to enforce no 3DS, override it to enforce 3DS
@@ -47,6 +35,8 @@ impl ForeignFrom<api_threedsecure::PaymentData> for dsl_inputs::PaymentInput {
             capture_method: None,
             business_country: None,
             billing_country: None,
+            ip_country: None,
+            normalized_amount: None,
             business_label: None,
             setup_future_usage: None,
             card_bin: None,
@@ -98,18 +88,34 @@ impl ForeignFrom<api_threedsecure::AcquirerData> for dsl_inputs::AcquirerDataInp
 
 impl ForeignFrom<api_threedsecure::ThreeDsDecisionRuleExecuteRequest> for dsl_inputs::BackendInput {
     fn foreign_from(request: api_threedsecure::ThreeDsDecisionRuleExecuteRequest) -> Self {
-        Self {
-            metadata: None,
-            payment: dsl_inputs::PaymentInput::foreign_from(request.payment),
-            payment_method: dsl_inputs::PaymentMethodInput::foreign_from(request.payment_method),
-            mandate: dsl_inputs::MandateData {
-                mandate_acceptance_type: None,
-                mandate_type: None,
-                payment_type: None,
-            },
-            acquirer_data: request.acquirer.map(ForeignFrom::foreign_from),
-            customer_device_data: request.customer_device.map(ForeignFrom::foreign_from),
-            issuer_data: request.issuer.map(ForeignFrom::foreign_from),
-        }
+        make_dsl_input(
+            request.payment,
+            request.payment_method,
+            request.customer_device,
+            request.issuer,
+            request.acquirer,
+        )
+    }
+}
+
+pub fn make_dsl_input(
+    payment: api_threedsecure::PaymentData,
+    payment_method: Option<api_threedsecure::PaymentMethodMetaData>,
+    customer_device: Option<api_threedsecure::CustomerDeviceData>,
+    issuer: Option<api_threedsecure::IssuerData>,
+    acquirer: Option<api_threedsecure::AcquirerData>,
+) -> dsl_inputs::BackendInput {
+    dsl_inputs::BackendInput {
+        metadata: None,
+        payment: dsl_inputs::PaymentInput::foreign_from(payment),
+        payment_method: dsl_inputs::PaymentMethodInput::foreign_from(payment_method),
+        mandate: dsl_inputs::MandateData {
+            mandate_acceptance_type: None,
+            mandate_type: None,
+            payment_type: None,
+        },
+        acquirer_data: acquirer.map(ForeignFrom::foreign_from),
+        customer_device_data: customer_device.map(ForeignFrom::foreign_from),
+        issuer_data: issuer.map(ForeignFrom::foreign_from),
     }
 }
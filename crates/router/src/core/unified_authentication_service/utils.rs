@@ -103,6 +103,7 @@ pub fn construct_uas_router_data<F: Clone, Req, Res>(
         preprocessing_id: None,
         payment_method_balance: None,
         connector_api_version: None,
+        merchant_connector_id: None,
         request: request_data,
         response: Err(ErrorResponse::default()),
         connector_request_reference_id:
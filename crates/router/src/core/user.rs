@@ -46,15 +46,19 @@ use crate::{
     types::{domain, transformers::ForeignInto},
     utils::{
         self,
-        user::{theme as theme_utils, two_factor_auth as tfa_utils},
+        user::{passkey as passkey_utils, theme as theme_utils, two_factor_auth as tfa_utils},
     },
 };
 #[cfg(feature = "email")]
 use crate::{services::email::types as email_types, utils::user as user_utils};
 
 pub mod dashboard_metadata;
+pub mod passkey;
 #[cfg(feature = "dummy_connector")]
 pub mod sample_data;
+#[cfg(feature = "v1")]
+pub mod scim;
+pub mod session;
 pub mod theme;
 
 #[cfg(feature = "email")]
@@ -463,6 +467,9 @@ pub async fn change_password(
     let _ = auth::blacklist::insert_user_in_blacklist(&state, user.get_user_id())
         .await
         .map_err(|error| logger::error!(?error));
+    let _ = auth::user_sessions::revoke_all_user_sessions(&state, user.get_user_id(), None)
+        .await
+        .map_err(|error| logger::error!(?error));
 
     #[cfg(not(feature = "email"))]
     {
@@ -570,6 +577,9 @@ pub async fn rotate_password(
     let _ = auth::blacklist::insert_user_in_blacklist(&state, &user.user_id)
         .await
         .map_err(|error| logger::error!(?error));
+    let _ = auth::user_sessions::revoke_all_user_sessions(&state, &user.user_id, None)
+        .await
+        .map_err(|error| logger::error!(?error));
 
     auth::cookies::remove_cookie_response()
 }
@@ -629,6 +639,9 @@ pub async fn reset_password_token_only_flow(
     let _ = auth::blacklist::insert_user_in_blacklist(&state, &user.user_id)
         .await
         .map_err(|error| logger::error!(?error));
+    let _ = auth::user_sessions::revoke_all_user_sessions(&state, &user.user_id, None)
+        .await
+        .map_err(|error| logger::error!(?error));
 
     auth::cookies::remove_cookie_response()
 }
@@ -1112,6 +1125,7 @@ async fn handle_new_user_invitation(
             role_id: request.role_id.clone(),
             profile_id: user_from_token.profile_id.clone(),
             tenant_id: user_from_token.tenant_id.clone(),
+            session_id: None,
         };
 
         let set_metadata_request = SetMetaDataRequest::IsChangePasswordRequired;
@@ -1532,6 +1546,7 @@ pub async fn create_platform_account(
                 organization_details: None,
                 metadata: None,
                 platform_merchant_id: Some(merchant_account.get_id().to_owned()),
+                is_passkey_required: None,
             },
         )
         .await
@@ -2381,7 +2396,14 @@ pub async fn terminate_two_factor_auth(
         .change_context(UserErrors::InternalServerError)?
         .into();
 
-    if state.conf.user.force_two_factor_auth || !skip_two_factor_auth {
+    let is_passkey_verified =
+        passkey_utils::check_passkey_in_redis(&state, &user_token.user_id).await?;
+
+    // Passkeys are a complete second factor on their own, so TOTP setup is not enforced for them
+    if !is_passkey_verified
+        && (!skip_two_factor_auth
+            || tfa_utils::is_two_factor_auth_required_for_user(&state, &user_token.user_id).await?)
+    {
         if !tfa_utils::check_totp_in_redis(&state, &user_token.user_id).await?
             && !tfa_utils::check_recovery_code_in_redis(&state, &user_token.user_id).await?
         {
@@ -2437,6 +2459,7 @@ pub async fn check_two_factor_auth_status(
             totp: tfa_utils::check_totp_in_redis(&state, &user_token.user_id).await?,
             recovery_code: tfa_utils::check_recovery_code_in_redis(&state, &user_token.user_id)
                 .await?,
+            passkey: passkey_utils::check_passkey_in_redis(&state, &user_token.user_id).await?,
         },
     ))
 }
@@ -2452,11 +2475,25 @@ pub async fn check_two_factor_auth_status_with_attempts(
         .change_context(UserErrors::InternalServerError)?
         .into();
 
-    let is_skippable = state.conf.user.force_two_factor_auth.not();
+    let is_skippable = tfa_utils::is_two_factor_auth_required_for_user(&state, &user_token.user_id)
+        .await?
+        .not();
+    let passkey = if passkey_utils::get_passkeys_for_user(&state, &user_token.user_id)
+        .await?
+        .is_empty()
+    {
+        None
+    } else {
+        Some(user_api::PasskeyAuthStatus {
+            is_completed: passkey_utils::check_passkey_in_redis(&state, &user_token.user_id)
+                .await?,
+        })
+    };
     if user_from_db.get_totp_status() == TotpStatus::NotSet {
         return Ok(ApplicationResponse::Json(user_api::TwoFactorStatus {
             status: None,
             is_skippable,
+            passkey,
         }));
     };
 
@@ -2476,6 +2513,7 @@ pub async fn check_two_factor_auth_status_with_attempts(
             recovery_code,
         }),
         is_skippable,
+        passkey,
     }))
 }
 
@@ -2494,6 +2532,18 @@ pub async fn create_user_authentication_method(
     )
     .change_context(UserErrors::InternalServerError)
     .attach_printable("Failed to decode DEK")?;
+
+    if let Some(jit_provisioning) = utils::user::get_oidc_jit_provisioning_config(&req.auth_method)
+    {
+        utils::user::validate_oidc_jit_provisioning_config(
+            &state,
+            req.owner_type,
+            &req.owner_id,
+            jit_provisioning,
+        )
+        .await?;
+    }
+
     let id = uuid::Uuid::new_v4().to_string();
     let (private_config, public_config) = utils::user::construct_public_and_private_db_configs(
         &state,
@@ -2602,6 +2652,26 @@ pub async fn update_user_authentication_method(
             id,
             auth_config: auth_method,
         } => {
+            if let Some(jit_provisioning) =
+                utils::user::get_oidc_jit_provisioning_config(&auth_method)
+            {
+                let user_authentication_method = state
+                    .store
+                    .get_user_authentication_method_by_id(&id)
+                    .await
+                    .to_not_found_response(UserErrors::InvalidAuthMethodOperationWithMessage(
+                        "Auth method not found".to_string(),
+                    ))?;
+
+                utils::user::validate_oidc_jit_provisioning_config(
+                    &state,
+                    user_authentication_method.owner_type,
+                    &user_authentication_method.owner_id,
+                    jit_provisioning,
+                )
+                .await?;
+            }
+
             let (private_config, public_config) =
                 utils::user::construct_public_and_private_db_configs(
                     &state,
@@ -2823,13 +2893,27 @@ pub async fn sso_sign(
     )
     .await?;
 
-    // TODO: Use config to handle not found error
-    let user_from_db: domain::UserFromStorage = state
-        .global_store
-        .find_user_by_email(&email)
-        .await
-        .map(Into::into)
-        .to_not_found_response(UserErrors::UserNotFound)?;
+    let user_from_db: domain::UserFromStorage =
+        match state.global_store.find_user_by_email(&email).await {
+            Ok(user) => user.into(),
+            Err(error) if error.current_context().is_db_not_found() => {
+                let jit_provisioning = open_id_public_config
+                    .jit_provisioning
+                    .filter(|_| user_authentication_method.allow_signup)
+                    .ok_or_else(|| error.change_context(UserErrors::UserNotFound))?;
+
+                provision_sso_user(
+                    &state,
+                    email,
+                    user_authentication_method.owner_type,
+                    &user_authentication_method.owner_id,
+                    &user_authentication_method.email_domain,
+                    jit_provisioning,
+                )
+                .await?
+            }
+            Err(error) => return Err(error.change_context(UserErrors::InternalServerError)),
+        };
 
     if !user_from_db.is_verified() {
         state
@@ -2859,6 +2943,54 @@ pub async fn sso_sign(
     auth::cookies::set_cookie_response(response, token)
 }
 
+/// Provisions a user signing in through SSO for the first time, adding them to the merchant
+/// account configured for the authentication method of their email domain.
+async fn provision_sso_user(
+    state: &SessionState,
+    email: domain::UserEmail,
+    owner_type: common_enums::Owner,
+    owner_id: &str,
+    email_domain: &str,
+    jit_provisioning: user_api::OpenIdConnectJitProvisioningConfig,
+) -> UserResult<domain::UserFromStorage> {
+    if email.extract_domain()? != email_domain {
+        return Err(report!(UserErrors::UserNotFound))
+            .attach_printable("Email domain does not match the auth method email domain");
+    }
+
+    let org_id = utils::user::validate_oidc_jit_provisioning_config(
+        state,
+        owner_type,
+        owner_id,
+        &jit_provisioning,
+    )
+    .await?;
+
+    let new_user = domain::NewUser::try_from((
+        email,
+        None,
+        domain::MerchantAccountIdentifier {
+            merchant_id: jit_provisioning.merchant_id.clone(),
+            org_id: org_id.clone(),
+        },
+    ))?;
+    let user_from_db = new_user
+        .insert_user_in_db(state.global_store.as_ref())
+        .await?;
+
+    new_user
+        .get_no_level_user_role(jit_provisioning.role_id, UserStatus::Active)
+        .add_entity(domain::MerchantLevel {
+            tenant_id: state.tenant.tenant_id.clone(),
+            org_id,
+            merchant_id: jit_provisioning.merchant_id,
+        })
+        .insert_in_v2(state)
+        .await?;
+
+    Ok(user_from_db)
+}
+
 pub async fn terminate_auth_select(
     state: SessionState,
     user_token: auth::UserFromSinglePurposeToken,
@@ -3283,6 +3415,7 @@ pub async fn switch_org_for_user(
         role_id.clone(),
         profile_id.clone(),
         user_from_token.tenant_id.clone(),
+        user_from_token.session_id.clone(),
     )
     .await?;
 
@@ -3497,6 +3630,7 @@ pub async fn switch_merchant_for_user_in_org(
         role_id.clone(),
         profile_id,
         user_from_token.tenant_id.clone(),
+        user_from_token.session_id.clone(),
     )
     .await?;
 
@@ -3632,6 +3766,7 @@ pub async fn switch_profile_for_user_in_org_and_merchant(
         role_id.clone(),
         profile_id,
         user_from_token.tenant_id.clone(),
+        user_from_token.session_id.clone(),
     )
     .await?;
 
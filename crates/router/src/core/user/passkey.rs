@@ -0,0 +1,349 @@
+use api_models::user as user_api;
+use common_enums::UserAuthType;
+use common_utils::{ext_traits::ValueExt, fp_utils};
+use diesel_models::user_passkey::{UserPasskey, UserPasskeyNew, UserPasskeyUpdate};
+use error_stack::{report, ResultExt};
+use masking::ExposeInterface;
+use router_env::logger;
+use webauthn_rs::prelude::PasskeyAuthentication;
+
+use crate::{
+    consts,
+    core::errors::{StorageErrorExt, UserErrors, UserResponse, UserResult},
+    routes::SessionState,
+    services::{authentication as auth, ApplicationResponse},
+    types::domain,
+    utils::{
+        self,
+        user::{passkey as passkey_utils, two_factor_auth as tfa_utils},
+    },
+};
+
+pub async fn begin_passkey_registration(
+    state: SessionState,
+    user_token: auth::UserIdFromAuth,
+) -> UserResponse<user_api::PasskeyChallengeResponse> {
+    let user_from_db: domain::UserFromStorage = state
+        .global_store
+        .find_user_by_id(&user_token.user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into();
+
+    let user_passkeys = passkey_utils::get_passkeys_for_user(&state, &user_token.user_id).await?;
+    if user_passkeys.len() >= consts::user::MAX_PASSKEYS_PER_USER {
+        return Err(UserErrors::MaxPasskeysReached.into());
+    }
+
+    // Users who already have a second factor have to use it before adding another one
+    if (user_from_db.get_totp_status() == diesel_models::enums::TotpStatus::Set
+        || !user_passkeys.is_empty())
+        && !is_two_factor_auth_completed(&state, &user_token.user_id).await?
+    {
+        return Err(UserErrors::TwoFactorAuthRequired.into());
+    }
+
+    let exclude_credentials = user_passkeys
+        .iter()
+        .map(|user_passkey| {
+            passkey_utils::parse_passkey(user_passkey).map(|passkey| passkey.cred_id().clone())
+        })
+        .collect::<UserResult<Vec<_>>>()?;
+
+    let email = user_from_db.get_email().expose().expose();
+    let (options, registration) = passkey_utils::get_webauthn(&state)?
+        .start_passkey_registration(
+            passkey_utils::get_webauthn_user_handle(user_from_db.get_user_id())?,
+            &email,
+            &user_from_db.get_name().expose(),
+            Some(exclude_credentials),
+        )
+        .change_context(UserErrors::InternalServerError)?;
+
+    passkey_utils::insert_passkey_registration_in_redis(&state, &user_token.user_id, &registration)
+        .await?;
+
+    Ok(ApplicationResponse::Json(
+        user_api::PasskeyChallengeResponse {
+            options: serde_json::to_value(options)
+                .change_context(UserErrors::InternalServerError)?,
+        },
+    ))
+}
+
+pub async fn finish_passkey_registration(
+    state: SessionState,
+    user_token: auth::UserIdFromAuth,
+    req: user_api::FinishPasskeyRegistrationRequest,
+) -> UserResponse<user_api::PasskeyResponse> {
+    let name = validate_passkey_name(req.name)?;
+    let registration =
+        passkey_utils::get_passkey_registration_from_redis(&state, &user_token.user_id).await?;
+    let credential = req
+        .credential
+        .parse_value("RegisterPublicKeyCredential")
+        .change_context(UserErrors::InvalidPasskey)?;
+
+    let passkey = passkey_utils::get_webauthn(&state)?
+        .finish_passkey_registration(&credential, &registration)
+        .change_context(UserErrors::InvalidPasskey)?;
+
+    let user_passkey = state
+        .global_store
+        .insert_user_passkey(UserPasskeyNew {
+            id: common_utils::generate_id(consts::ID_LENGTH, "passkey"),
+            user_id: user_token.user_id.clone(),
+            name,
+            passkey: serde_json::to_value(passkey)
+                .change_context(UserErrors::InternalServerError)?,
+            created_at: common_utils::date_time::now(),
+            last_used_at: None,
+        })
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    // This is not the main task of this API, so we don't throw error if this fails.
+    // Registering a passkey proves the possession of it in the same way as verifying it.
+    let _ = passkey_utils::insert_passkey_in_redis(&state, &user_token.user_id)
+        .await
+        .inspect_err(|error| logger::error!(?error));
+
+    Ok(ApplicationResponse::Json(get_passkey_response(
+        user_passkey,
+    )))
+}
+
+pub async fn list_passkeys(
+    state: SessionState,
+    user_token: auth::UserFromToken,
+) -> UserResponse<Vec<user_api::PasskeyResponse>> {
+    let user_passkeys = passkey_utils::get_passkeys_for_user(&state, &user_token.user_id).await?;
+
+    Ok(ApplicationResponse::Json(
+        user_passkeys
+            .into_iter()
+            .map(get_passkey_response)
+            .collect(),
+    ))
+}
+
+pub async fn update_passkey(
+    state: SessionState,
+    user_token: auth::UserFromToken,
+    passkey_id: String,
+    req: user_api::UpdatePasskeyRequest,
+) -> UserResponse<user_api::PasskeyResponse> {
+    let name = validate_passkey_name(req.name)?;
+    let user_passkey = state
+        .global_store
+        .update_user_passkey_by_user_id_passkey_id(
+            &user_token.user_id,
+            &passkey_id,
+            UserPasskeyUpdate::Rename { name },
+        )
+        .await
+        .to_not_found_response(UserErrors::PasskeyNotFound)?;
+
+    Ok(ApplicationResponse::Json(get_passkey_response(
+        user_passkey,
+    )))
+}
+
+pub async fn delete_passkey(
+    state: SessionState,
+    user_token: auth::UserFromToken,
+    passkey_id: String,
+) -> UserResponse<()> {
+    if !is_two_factor_auth_completed(&state, &user_token.user_id).await? {
+        return Err(UserErrors::TwoFactorAuthRequired.into());
+    }
+
+    state
+        .global_store
+        .delete_user_passkey_by_user_id_passkey_id(&user_token.user_id, &passkey_id)
+        .await
+        .to_not_found_response(UserErrors::PasskeyNotFound)?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn begin_passkey_verification(
+    state: SessionState,
+    user_token: auth::UserIdFromAuth,
+) -> UserResponse<user_api::PasskeyChallengeResponse> {
+    begin_passkey_authentication(&state, &user_token.user_id)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+pub async fn verify_passkey(
+    state: SessionState,
+    user_token: auth::UserIdFromAuth,
+    req: user_api::VerifyPasskeyRequest,
+) -> UserResponse<()> {
+    let authentication =
+        passkey_utils::get_passkey_authentication_from_redis(&state, &user_token.user_id).await?;
+    passkey_utils::verify_passkey_authentication(
+        &state,
+        &user_token.user_id,
+        &authentication,
+        req.credential,
+    )
+    .await?;
+    passkey_utils::insert_passkey_in_redis(&state, &user_token.user_id).await?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn begin_passkey_signin(
+    state: SessionState,
+    req: user_api::BeginPasskeySignInRequest,
+) -> UserResponse<user_api::PasskeySignInChallengeResponse> {
+    let user_from_db = get_user_for_passkey_signin(&state, req.email).await?;
+    let user_id = user_from_db.get_user_id().to_string();
+
+    let (options, authentication) = start_passkey_authentication(&state, &user_id)
+        .await
+        .map_err(|error| match error.current_context() {
+            // Not revealing whether the user exists or has passkeys
+            UserErrors::PasskeyNotSetup => error.change_context(UserErrors::InvalidCredentials),
+            _ => error,
+        })?;
+
+    let ceremony_id = uuid::Uuid::new_v4().to_string();
+    passkey_utils::insert_passkey_signin_ceremony_in_redis(
+        &state,
+        &ceremony_id,
+        &passkey_utils::PasskeySignInCeremony {
+            user_id,
+            authentication,
+        },
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(
+        user_api::PasskeySignInChallengeResponse {
+            ceremony_id,
+            options,
+        },
+    ))
+}
+
+pub async fn passkey_signin(
+    state: SessionState,
+    req: user_api::PasskeySignInRequest,
+) -> UserResponse<user_api::TokenResponse> {
+    let user_from_db = get_user_for_passkey_signin(&state, req.email).await?;
+    let ceremony =
+        passkey_utils::get_passkey_signin_ceremony_from_redis(&state, &req.ceremony_id).await?;
+    fp_utils::when(ceremony.user_id != user_from_db.get_user_id(), || {
+        Err(report!(UserErrors::InvalidCredentials))
+            .attach_printable("Passkey sign in ceremony was started for a different user")
+    })?;
+
+    passkey_utils::verify_passkey_authentication(
+        &state,
+        user_from_db.get_user_id(),
+        &ceremony.authentication,
+        req.credential,
+    )
+    .await
+    .map_err(|error| {
+        logger::error!(?error);
+        report!(UserErrors::InvalidCredentials)
+    })?;
+    passkey_utils::insert_passkey_in_redis(&state, user_from_db.get_user_id()).await?;
+
+    let next_flow =
+        domain::NextFlow::from_origin(domain::Origin::SignInWithPasskey, user_from_db, &state)
+            .await?;
+    let token = next_flow.get_token(&state).await?;
+
+    let response = user_api::TokenResponse {
+        token: token.clone(),
+        token_type: next_flow.get_flow().into(),
+    };
+    auth::cookies::set_cookie_response(response, token)
+}
+
+async fn begin_passkey_authentication(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<user_api::PasskeyChallengeResponse> {
+    let (options, authentication) = start_passkey_authentication(state, user_id).await?;
+    passkey_utils::insert_passkey_authentication_in_redis(state, user_id, &authentication).await?;
+
+    Ok(user_api::PasskeyChallengeResponse { options })
+}
+
+async fn start_passkey_authentication(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<(serde_json::Value, PasskeyAuthentication)> {
+    let passkeys = passkey_utils::get_passkeys_for_user(state, user_id)
+        .await?
+        .iter()
+        .map(passkey_utils::parse_passkey)
+        .collect::<UserResult<Vec<_>>>()?;
+    if passkeys.is_empty() {
+        return Err(UserErrors::PasskeyNotSetup.into());
+    }
+
+    let (options, authentication) = passkey_utils::get_webauthn(state)?
+        .start_passkey_authentication(&passkeys)
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok((
+        serde_json::to_value(options).change_context(UserErrors::InternalServerError)?,
+        authentication,
+    ))
+}
+
+/// Passkeys replace the password, so they are allowed wherever password sign in is allowed
+async fn get_user_for_passkey_signin(
+    state: &SessionState,
+    email: common_utils::pii::Email,
+) -> UserResult<domain::UserFromStorage> {
+    let user_email = domain::UserEmail::from_pii_email(email)?;
+    utils::user::validate_email_domain_auth_type_using_db(
+        state,
+        &user_email,
+        UserAuthType::Password,
+    )
+    .await?;
+
+    state
+        .global_store
+        .find_user_by_email(&user_email)
+        .await
+        .to_not_found_response(UserErrors::InvalidCredentials)
+        .map(Into::into)
+}
+
+async fn is_two_factor_auth_completed(state: &SessionState, user_id: &str) -> UserResult<bool> {
+    Ok(tfa_utils::check_totp_in_redis(state, user_id).await?
+        || tfa_utils::check_recovery_code_in_redis(state, user_id).await?
+        || passkey_utils::check_passkey_in_redis(state, user_id).await?)
+}
+
+fn validate_passkey_name(name: String) -> UserResult<String> {
+    let name = name.trim().to_string();
+    fp_utils::when(
+        name.is_empty() || name.len() > consts::user::MAX_PASSKEY_NAME_LENGTH,
+        || {
+            Err(report!(UserErrors::NameParsingError))
+                .attach_printable("Passkey name is empty or too long")
+        },
+    )?;
+
+    Ok(name)
+}
+
+fn get_passkey_response(user_passkey: UserPasskey) -> user_api::PasskeyResponse {
+    user_api::PasskeyResponse {
+        id: user_passkey.id,
+        name: user_passkey.name,
+        created_at: user_passkey.created_at,
+        last_used_at: user_passkey.last_used_at,
+    }
+}
@@ -0,0 +1,759 @@
+use std::collections::{HashMap, HashSet};
+
+use api_models::user::scim as scim_api;
+use common_enums::EntityType;
+use diesel_models::{
+    enums::{UserRoleVersion, UserStatus},
+    role::ListRolesByEntityPayload,
+    user_role::{UserRole, UserRoleUpdate},
+};
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, PeekInterface, Secret};
+
+use crate::{
+    consts,
+    core::errors::{UserErrors, UserResponse, UserResult},
+    db::user_role::ListUserRolesByOrgIdPayload,
+    routes::SessionState,
+    services::{
+        authentication as auth,
+        authorization::roles::{self, predefined_roles::PREDEFINED_ROLES},
+        ApplicationResponse,
+    },
+    types::domain,
+    utils,
+};
+
+/// The role given to users provisioned without being added to a group
+const DEFAULT_ROLE_ID: &str = consts::user_role::ROLE_ID_MERCHANT_VIEW_ONLY;
+const MAX_PAGE_SIZE: usize = 100;
+
+/// A user provisioned in the merchant account, along with their merchant level user role
+struct ScimUser {
+    user: domain::UserFromStorage,
+    user_role: UserRole,
+}
+
+pub async fn list_users(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    query: scim_api::ScimListQuery,
+) -> UserResponse<scim_api::ScimListResponse<scim_api::ScimUserResponse>> {
+    let user_name_filter = query
+        .filter
+        .as_deref()
+        .map(|filter| parse_equality_filter(filter, "userName"))
+        .transpose()?;
+
+    let mut users = get_scim_users(&state, &merchant_account, None).await?;
+    if let Some(user_name) = user_name_filter {
+        users.retain(|scim_user| {
+            scim_user
+                .user
+                .get_email()
+                .peek()
+                .eq_ignore_ascii_case(&user_name)
+        });
+    }
+
+    let role_names = get_role_names(&state, &merchant_account).await?;
+    let resources = users
+        .into_iter()
+        .map(|scim_user| get_scim_user_response(scim_user, &role_names))
+        .collect();
+
+    Ok(ApplicationResponse::Json(paginate(resources, &query)))
+}
+
+pub async fn create_user(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    request: scim_api::ScimUserRequest,
+) -> UserResponse<scim_api::ScimUserResponse> {
+    let email = domain::UserEmail::from_pii_email(request.user_name.clone())?;
+    let status = get_user_status(request.active);
+
+    let user = match state.global_store.find_user_by_email(&email).await {
+        Ok(user) => domain::UserFromStorage::from(user),
+        Err(error) if error.current_context().is_db_not_found() => {
+            let new_user = domain::NewUser::try_from((
+                email,
+                get_user_name(&request)?,
+                domain::MerchantAccountIdentifier {
+                    merchant_id: merchant_account.get_id().clone(),
+                    org_id: merchant_account.get_org_id().clone(),
+                },
+            ))?;
+            new_user
+                .insert_user_in_db(state.global_store.as_ref())
+                .await?
+        }
+        Err(error) => return Err(error.change_context(UserErrors::InternalServerError)),
+    };
+
+    let existing_user_roles =
+        list_user_roles_in_org(&state, &merchant_account, Some(user.get_user_id())).await?;
+    if !existing_user_roles.is_empty() {
+        return Err(report!(UserErrors::ScimResourceAlreadyExists))
+            .attach_printable("User already has a role in the organization of the merchant");
+    }
+
+    let now = common_utils::date_time::now();
+    let user_role = domain::NewUserRole {
+        user_id: user.get_user_id().to_string(),
+        role_id: DEFAULT_ROLE_ID.to_string(),
+        status,
+        created_by: user.get_user_id().to_string(),
+        last_modified_by: user.get_user_id().to_string(),
+        created_at: now,
+        last_modified: now,
+        entity: domain::NoLevel,
+    }
+    .add_entity(domain::MerchantLevel {
+        tenant_id: state.tenant.tenant_id.clone(),
+        org_id: merchant_account.get_org_id().clone(),
+        merchant_id: merchant_account.get_id().clone(),
+    })
+    .insert_in_v2(&state)
+    .await?;
+
+    let role_names = get_role_names(&state, &merchant_account).await?;
+    Ok(ApplicationResponse::Json(get_scim_user_response(
+        ScimUser { user, user_role },
+        &role_names,
+    )))
+}
+
+pub async fn retrieve_user(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    user_id: String,
+) -> UserResponse<scim_api::ScimUserResponse> {
+    let scim_user = get_scim_user(&state, &merchant_account, &user_id).await?;
+    let role_names = get_role_names(&state, &merchant_account).await?;
+
+    Ok(ApplicationResponse::Json(get_scim_user_response(
+        scim_user,
+        &role_names,
+    )))
+}
+
+pub async fn replace_user(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    user_id: String,
+    request: scim_api::ScimUserRequest,
+) -> UserResponse<scim_api::ScimUserResponse> {
+    let scim_user = get_scim_user(&state, &merchant_account, &user_id).await?;
+    let email = domain::UserEmail::from_pii_email(request.user_name.clone())?;
+    if email.get_secret().peek() != scim_user.user.get_email().peek() {
+        return Err(report!(UserErrors::InvalidScimRequest(
+            "userName cannot be changed".to_string()
+        )));
+    }
+
+    set_user_active(
+        &state,
+        &merchant_account,
+        scim_user,
+        request.active.unwrap_or(true),
+    )
+    .await?;
+    retrieve_user(state, merchant_account, user_id).await
+}
+
+pub async fn patch_user(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    user_id: String,
+    request: scim_api::ScimPatchRequest,
+) -> UserResponse<scim_api::ScimUserResponse> {
+    let mut scim_user = get_scim_user(&state, &merchant_account, &user_id).await?;
+
+    for operation in request.operations {
+        let active = match (operation.op, operation.path.as_deref(), operation.value) {
+            (scim_api::ScimPatchOp::Replace, Some("active"), Some(value)) => value.as_bool(),
+            // Some identity providers send the attributes being replaced as an object
+            (scim_api::ScimPatchOp::Replace, None, Some(value)) => {
+                value.get("active").and_then(|active| active.as_bool())
+            }
+            _ => None,
+        }
+        .ok_or_else(|| {
+            report!(UserErrors::InvalidScimRequest(
+                "only replacing the active attribute of a user is supported".to_string()
+            ))
+        })?;
+
+        scim_user = set_user_active(&state, &merchant_account, scim_user, active).await?;
+    }
+
+    let role_names = get_role_names(&state, &merchant_account).await?;
+    Ok(ApplicationResponse::Json(get_scim_user_response(
+        scim_user,
+        &role_names,
+    )))
+}
+
+/// Deprovisions the user by deactivating their user role, the user itself is not deleted as they
+/// may have roles in other organizations.
+pub async fn delete_user(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    user_id: String,
+) -> UserResponse<()> {
+    let scim_user = get_scim_user(&state, &merchant_account, &user_id).await?;
+    set_user_active(&state, &merchant_account, scim_user, false).await?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn list_groups(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    query: scim_api::ScimListQuery,
+) -> UserResponse<scim_api::ScimListResponse<scim_api::ScimGroupResponse>> {
+    let display_name_filter = query
+        .filter
+        .as_deref()
+        .map(|filter| parse_equality_filter(filter, "displayName"))
+        .transpose()?;
+
+    let mut groups = get_groups(&state, &merchant_account).await?;
+    if let Some(display_name) = display_name_filter {
+        groups.retain(|role_info| role_info.get_role_name() == display_name);
+    }
+
+    let users = get_scim_users(&state, &merchant_account, None).await?;
+    let resources = groups
+        .iter()
+        .map(|role_info| get_scim_group_response(role_info, &users))
+        .collect();
+
+    Ok(ApplicationResponse::Json(paginate(resources, &query)))
+}
+
+pub async fn retrieve_group(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    group_id: String,
+) -> UserResponse<scim_api::ScimGroupResponse> {
+    let role_info = get_group(&state, &merchant_account, &group_id).await?;
+    let users = get_scim_users(&state, &merchant_account, None).await?;
+
+    Ok(ApplicationResponse::Json(get_scim_group_response(
+        &role_info, &users,
+    )))
+}
+
+/// Adds and removes the members of a group, which changes the role of the users in the merchant
+/// account. Users removed from the group fall back to the default role.
+pub async fn patch_group(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    group_id: String,
+    request: scim_api::ScimPatchRequest,
+) -> UserResponse<scim_api::ScimGroupResponse> {
+    let role_info = get_group(&state, &merchant_account, &group_id).await?;
+
+    for operation in request.operations {
+        let path = operation.path.as_deref().unwrap_or("members");
+        if path == "displayName" {
+            return Err(report!(UserErrors::InvalidScimRequest(
+                "groups cannot be renamed".to_string()
+            )));
+        }
+
+        let mut member_ids = operation
+            .value
+            .as_ref()
+            .map(get_member_ids)
+            .transpose()?
+            .unwrap_or_default();
+        if let Some(member_id) = parse_member_path(path)? {
+            member_ids.push(member_id);
+        }
+
+        let users = get_scim_users(&state, &merchant_account, None).await?;
+        let current_member_ids = users
+            .iter()
+            .filter(|scim_user| scim_user.user_role.role_id == group_id)
+            .map(|scim_user| scim_user.user.get_user_id().to_string())
+            .collect::<HashSet<_>>();
+
+        let (members_to_add, members_to_remove) = match operation.op {
+            scim_api::ScimPatchOp::Add => (member_ids, Vec::new()),
+            scim_api::ScimPatchOp::Remove => (Vec::new(), member_ids),
+            scim_api::ScimPatchOp::Replace => {
+                let members_to_remove = current_member_ids
+                    .iter()
+                    .filter(|member_id| !member_ids.contains(member_id))
+                    .cloned()
+                    .collect();
+                (member_ids, members_to_remove)
+            }
+        };
+
+        for member_id in members_to_add {
+            if !current_member_ids.contains(&member_id) {
+                let scim_user = find_scim_user(&users, &member_id)?;
+                update_user_role_id(&state, &merchant_account, scim_user, &group_id).await?;
+            }
+        }
+        for member_id in members_to_remove {
+            if current_member_ids.contains(&member_id) {
+                let scim_user = find_scim_user(&users, &member_id)?;
+                update_user_role_id(&state, &merchant_account, scim_user, DEFAULT_ROLE_ID).await?;
+            }
+        }
+    }
+
+    let users = get_scim_users(&state, &merchant_account, None).await?;
+    Ok(ApplicationResponse::Json(get_scim_group_response(
+        &role_info, &users,
+    )))
+}
+
+async fn list_user_roles_in_org(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    user_id: Option<&str>,
+) -> UserResult<Vec<UserRole>> {
+    let user_id = user_id.map(ToString::to_string);
+
+    state
+        .global_store
+        .list_user_roles_by_org_id(ListUserRolesByOrgIdPayload {
+            user_id: user_id.as_ref(),
+            tenant_id: &state.tenant.tenant_id,
+            org_id: merchant_account.get_org_id(),
+            merchant_id: None,
+            profile_id: None,
+            version: None,
+            limit: None,
+        })
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+/// Lists the users having a merchant level user role in the merchant account, the v2 user role
+/// being preferred when a user has both versions.
+async fn get_scim_users(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    user_id: Option<&str>,
+) -> UserResult<Vec<ScimUser>> {
+    let merchant_id = merchant_account.get_id().get_string_repr();
+    let mut user_roles = HashMap::new();
+
+    for user_role in list_user_roles_in_org(state, merchant_account, user_id).await? {
+        let is_merchant_level =
+            user_role
+                .get_entity_id_and_type()
+                .is_some_and(|(entity_id, entity_type)| {
+                    entity_type == EntityType::Merchant && entity_id == merchant_id
+                });
+        if !is_merchant_level {
+            continue;
+        }
+
+        let is_preferred = user_roles
+            .get(&user_role.user_id)
+            .is_none_or(|existing: &UserRole| existing.version == UserRoleVersion::V1);
+        if is_preferred {
+            user_roles.insert(user_role.user_id.clone(), user_role);
+        }
+    }
+
+    if user_roles.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut users = state
+        .global_store
+        .find_users_by_user_ids(user_roles.keys().cloned().collect())
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into_iter()
+        .filter_map(|user| {
+            user_roles.remove(&user.user_id).map(|user_role| ScimUser {
+                user: domain::UserFromStorage::from(user),
+                user_role,
+            })
+        })
+        .collect::<Vec<_>>();
+    users.sort_by(|a, b| a.user_role.created_at.cmp(&b.user_role.created_at));
+
+    Ok(users)
+}
+
+async fn get_scim_user(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    user_id: &str,
+) -> UserResult<ScimUser> {
+    get_scim_users(state, merchant_account, Some(user_id))
+        .await?
+        .pop()
+        .ok_or_else(|| report!(UserErrors::ScimResourceNotFound))
+        .attach_printable_lazy(|| format!("User {user_id} is not provisioned in the merchant"))
+}
+
+fn find_scim_user<'a>(users: &'a [ScimUser], user_id: &str) -> UserResult<&'a ScimUser> {
+    users
+        .iter()
+        .find(|scim_user| scim_user.user.get_user_id() == user_id)
+        .ok_or_else(|| {
+            report!(UserErrors::InvalidScimRequest(format!(
+                "user {user_id} is not provisioned in the merchant"
+            )))
+        })
+}
+
+async fn set_user_active(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    mut scim_user: ScimUser,
+    active: bool,
+) -> UserResult<ScimUser> {
+    let status = get_user_status(Some(active));
+    if scim_user.user_role.status == status {
+        return Ok(scim_user);
+    }
+    // Invited users are activated by accepting their invitation
+    if active && scim_user.user_role.status == UserStatus::InvitationSent {
+        return Ok(scim_user);
+    }
+
+    let user_id = scim_user.user.get_user_id().to_string();
+    scim_user.user_role = update_user_role(
+        state,
+        merchant_account,
+        &user_id,
+        UserRoleUpdate::UpdateStatus {
+            status,
+            modified_by: user_id.clone(),
+        },
+    )
+    .await?;
+
+    if !active {
+        auth::blacklist::insert_user_in_blacklist(state, &user_id).await?;
+    }
+
+    Ok(scim_user)
+}
+
+async fn update_user_role_id(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    scim_user: &ScimUser,
+    role_id: &str,
+) -> UserResult<()> {
+    let user_id = scim_user.user.get_user_id();
+    update_user_role(
+        state,
+        merchant_account,
+        user_id,
+        UserRoleUpdate::UpdateRole {
+            role_id: role_id.to_string(),
+            modified_by: user_id.to_string(),
+        },
+    )
+    .await?;
+
+    auth::blacklist::insert_user_in_blacklist(state, user_id).await
+}
+
+async fn update_user_role(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    user_id: &str,
+    update: UserRoleUpdate,
+) -> UserResult<UserRole> {
+    let (update_v1_result, update_v2_result) = utils::user_role::update_v1_and_v2_user_roles_in_db(
+        state,
+        user_id,
+        &state.tenant.tenant_id,
+        merchant_account.get_org_id(),
+        Some(merchant_account.get_id()),
+        None,
+        update,
+    )
+    .await;
+
+    if update_v1_result
+        .as_ref()
+        .is_err_and(|err| !err.current_context().is_db_not_found())
+        || update_v2_result
+            .as_ref()
+            .is_err_and(|err| !err.current_context().is_db_not_found())
+    {
+        return Err(report!(UserErrors::InternalServerError));
+    }
+
+    update_v2_result
+        .or(update_v1_result)
+        .change_context(UserErrors::ScimResourceNotFound)
+}
+
+/// Lists the roles which can be assigned to users of the merchant account
+async fn get_groups(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+) -> UserResult<Vec<roles::RoleInfo>> {
+    let custom_roles = state
+        .global_store
+        .generic_list_roles_by_entity_type(
+            ListRolesByEntityPayload::Merchant(merchant_account.get_id().clone()),
+            false,
+            state.tenant.tenant_id.clone(),
+            merchant_account.get_org_id().clone(),
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to get roles")?;
+
+    let mut groups = PREDEFINED_ROLES
+        .values()
+        .cloned()
+        .chain(custom_roles.into_iter().map(roles::RoleInfo::from))
+        .filter(|role_info| {
+            role_info.get_entity_type() == EntityType::Merchant
+                && role_info.is_invitable()
+                && !role_info.is_internal()
+        })
+        .collect::<Vec<_>>();
+    groups.sort_by(|a, b| a.get_role_id().cmp(b.get_role_id()));
+
+    Ok(groups)
+}
+
+async fn get_group(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    group_id: &str,
+) -> UserResult<roles::RoleInfo> {
+    get_groups(state, merchant_account)
+        .await?
+        .into_iter()
+        .find(|role_info| role_info.get_role_id() == group_id)
+        .ok_or_else(|| report!(UserErrors::ScimResourceNotFound))
+        .attach_printable_lazy(|| format!("Role {group_id} cannot be assigned in the merchant"))
+}
+
+async fn get_role_names(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+) -> UserResult<HashMap<String, String>> {
+    Ok(get_groups(state, merchant_account)
+        .await?
+        .into_iter()
+        .map(|role_info| {
+            (
+                role_info.get_role_id().to_string(),
+                role_info.get_role_name().to_string(),
+            )
+        })
+        .collect())
+}
+
+fn get_user_status(active: Option<bool>) -> UserStatus {
+    if active.unwrap_or(true) {
+        UserStatus::Active
+    } else {
+        UserStatus::Deactivated
+    }
+}
+
+fn get_user_name(request: &scim_api::ScimUserRequest) -> UserResult<Option<domain::UserName>> {
+    let name = request.display_name.clone().or_else(|| {
+        request.name.clone().and_then(|name| {
+            name.formatted.or_else(|| {
+                let name_parts = [name.given_name, name.family_name]
+                    .into_iter()
+                    .flatten()
+                    .map(|name_part| name_part.expose())
+                    .collect::<Vec<_>>();
+                (!name_parts.is_empty()).then(|| Secret::new(name_parts.join(" ")))
+            })
+        })
+    });
+
+    name.map(domain::UserName::new).transpose()
+}
+
+fn get_scim_user_response(
+    scim_user: ScimUser,
+    role_names: &HashMap<String, String>,
+) -> scim_api::ScimUserResponse {
+    let ScimUser { user, user_role } = scim_user;
+    let email = user.get_email();
+    let groups = role_names
+        .get(&user_role.role_id)
+        .map(|role_name| scim_api::ScimGroupReference {
+            value: user_role.role_id.clone(),
+            display: role_name.clone(),
+        })
+        .into_iter()
+        .collect();
+
+    scim_api::ScimUserResponse {
+        schemas: vec![scim_api::SCIM_USER_SCHEMA],
+        id: user.get_user_id().to_string(),
+        user_name: email.clone(),
+        display_name: user.get_name(),
+        emails: vec![scim_api::ScimEmail {
+            value: email,
+            primary: true,
+        }],
+        active: user_role.status == UserStatus::Active,
+        groups,
+        meta: scim_api::ScimMeta {
+            resource_type: scim_api::ScimResourceType::User,
+            created: Some(user_role.created_at),
+            last_modified: Some(user_role.last_modified),
+        },
+    }
+}
+
+fn get_scim_group_response(
+    role_info: &roles::RoleInfo,
+    users: &[ScimUser],
+) -> scim_api::ScimGroupResponse {
+    let members = users
+        .iter()
+        .filter(|scim_user| scim_user.user_role.role_id == role_info.get_role_id())
+        .map(|scim_user| scim_api::ScimGroupMember {
+            value: scim_user.user.get_user_id().to_string(),
+            display: Some(scim_user.user.get_email()),
+        })
+        .collect();
+
+    scim_api::ScimGroupResponse {
+        schemas: vec![scim_api::SCIM_GROUP_SCHEMA],
+        id: role_info.get_role_id().to_string(),
+        display_name: role_info.get_role_name().to_string(),
+        members,
+        meta: scim_api::ScimMeta {
+            resource_type: scim_api::ScimResourceType::Group,
+            created: None,
+            last_modified: None,
+        },
+    }
+}
+
+fn paginate<T>(
+    resources: Vec<T>,
+    query: &scim_api::ScimListQuery,
+) -> scim_api::ScimListResponse<T> {
+    let total_results = resources.len();
+    let start_index = query.start_index.unwrap_or(1).max(1);
+    let count = query.count.unwrap_or(MAX_PAGE_SIZE).min(MAX_PAGE_SIZE);
+    let resources = resources
+        .into_iter()
+        .skip(start_index - 1)
+        .take(count)
+        .collect::<Vec<_>>();
+
+    scim_api::ScimListResponse {
+        schemas: vec![scim_api::SCIM_LIST_RESPONSE_SCHEMA],
+        total_results,
+        start_index,
+        items_per_page: resources.len(),
+        resources,
+    }
+}
+
+/// Parses a filter of the form `attribute eq "value"`, the only kind sent by identity providers
+/// when looking up existing resources.
+fn parse_equality_filter(filter: &str, attribute: &str) -> UserResult<String> {
+    let mut parts = filter.trim().splitn(3, char::is_whitespace);
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(filter_attribute), Some(operator), Some(value))
+            if filter_attribute.eq_ignore_ascii_case(attribute)
+                && operator.eq_ignore_ascii_case("eq") =>
+        {
+            value
+                .trim()
+                .strip_prefix('"')
+                .and_then(|value| value.strip_suffix('"'))
+                .map(ToString::to_string)
+                .ok_or_else(|| {
+                    report!(UserErrors::InvalidScimRequest(format!(
+                        "unsupported filter: {filter}"
+                    )))
+                })
+        }
+        _ => Err(report!(UserErrors::InvalidScimRequest(format!(
+            "unsupported filter: {filter}"
+        )))),
+    }
+}
+
+/// Parses the id of the member from a path of the form `members[value eq "id"]`
+fn parse_member_path(path: &str) -> UserResult<Option<String>> {
+    if path == "members" {
+        return Ok(None);
+    }
+
+    path.strip_prefix("members[")
+        .and_then(|filter| filter.strip_suffix(']'))
+        .map(|filter| parse_equality_filter(filter, "value"))
+        .transpose()?
+        .map(Some)
+        .ok_or_else(|| {
+            report!(UserErrors::InvalidScimRequest(format!(
+                "unsupported path: {path}"
+            )))
+        })
+}
+
+fn get_member_ids(value: &serde_json::Value) -> UserResult<Vec<String>> {
+    let members = match value {
+        serde_json::Value::Array(members) => members.as_slice(),
+        member => std::slice::from_ref(member),
+    };
+
+    members
+        .iter()
+        .map(|member| {
+            member
+                .get("value")
+                .and_then(|value| value.as_str())
+                .map(ToString::to_string)
+                .ok_or_else(|| {
+                    report!(UserErrors::InvalidScimRequest(
+                        "members must have a value".to_string()
+                    ))
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_parse_equality_filter() {
+        assert_eq!(
+            parse_equality_filter(r#"userName eq "user@example.com""#, "userName").unwrap(),
+            "user@example.com"
+        );
+        assert!(parse_equality_filter(r#"userName co "user""#, "userName").is_err());
+        assert!(parse_equality_filter(r#"displayName eq "admin""#, "userName").is_err());
+    }
+
+    #[test]
+    fn test_parse_member_path() {
+        assert_eq!(parse_member_path("members").unwrap(), None);
+        assert_eq!(
+            parse_member_path(r#"members[value eq "user_id"]"#).unwrap(),
+            Some("user_id".to_string())
+        );
+        assert!(parse_member_path("members.value").is_err());
+    }
+}
@@ -0,0 +1,54 @@
+use api_models::user as user_api;
+
+use crate::{
+    core::errors::UserResponse,
+    routes::SessionState,
+    services::{authentication as auth, ApplicationResponse},
+};
+
+pub async fn list_user_sessions(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+) -> UserResponse<Vec<user_api::UserSessionResponse>> {
+    let user_sessions =
+        auth::user_sessions::list_user_sessions(&state, &user_from_token.user_id).await?;
+
+    Ok(ApplicationResponse::Json(
+        user_sessions
+            .into_iter()
+            .map(|user_session| user_api::UserSessionResponse {
+                is_current: user_from_token.session_id.as_ref() == Some(&user_session.session_id),
+                session_id: user_session.session_id,
+                ip_address: user_session.ip_address,
+                user_agent: user_session.user_agent,
+                issued_at: user_session.issued_at,
+                expires_at: user_session.expires_at,
+            })
+            .collect(),
+    ))
+}
+
+pub async fn revoke_user_session(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+    session_id: String,
+) -> UserResponse<()> {
+    auth::user_sessions::revoke_user_session(&state, &user_from_token.user_id, &session_id).await?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+/// Signs the user out of every other device, the session making the request stays active
+pub async fn revoke_all_user_sessions(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+) -> UserResponse<()> {
+    auth::user_sessions::revoke_all_user_sessions(
+        &state,
+        &user_from_token.user_id,
+        user_from_token.session_id.as_deref(),
+    )
+    .await?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
@@ -3,7 +3,7 @@ use std::{cmp, collections::HashSet};
 use api_models::user_role::role as role_api;
 use common_enums::{EntityType, ParentGroup, PermissionGroup};
 use common_utils::generate_id_with_default_len;
-use diesel_models::role::{ListRolesByEntityPayload, RoleNew, RoleUpdate};
+use diesel_models::role::{ListRolesByEntityPayload, RoleNew, RolePermissions, RoleUpdate};
 use error_stack::{report, ResultExt};
 
 use crate::{
@@ -45,12 +45,7 @@ pub async fn get_groups_and_resources_for_role_from_token(
         .get_permission_groups()
         .into_iter()
         .collect::<Vec<_>>();
-    let resources = groups
-        .iter()
-        .flat_map(|group| group.resources())
-        .collect::<HashSet<_>>()
-        .into_iter()
-        .collect();
+    let resources = role_info.get_resources_set().into_iter().collect();
 
     Ok(ApplicationResponse::Json(role_api::GroupsAndResources {
         groups,
@@ -97,7 +92,7 @@ pub async fn create_role(
 
     let role_name = RoleName::new(req.role_name)?;
 
-    utils::user_role::validate_role_groups(&req.groups)?;
+    utils::user_role::validate_role_groups_and_permissions(&req.groups, &req.permissions)?;
     utils::user_role::validate_role_name(
         &state,
         &role_name,
@@ -142,12 +137,14 @@ pub async fn create_role(
             last_modified_at: now,
             profile_id,
             tenant_id: user_from_token.tenant_id.unwrap_or(state.tenant.tenant_id),
+            permissions: (!req.permissions.is_empty()).then(|| RolePermissions(req.permissions)),
         })
         .await
         .to_duplicate_response(UserErrors::RoleNameAlreadyExists)?;
 
     Ok(ApplicationResponse::Json(
         role_api::RoleInfoWithGroupsResponse {
+            permissions: role.get_permissions(),
             groups: role.groups,
             role_id: role.role_id,
             role_name: role.role_name,
@@ -244,6 +241,7 @@ pub async fn create_role_v2(
             last_modified_at: now,
             profile_id,
             tenant_id: user_from_token.tenant_id.unwrap_or(state.tenant.tenant_id),
+            permissions: None,
         })
         .await
         .to_duplicate_response(UserErrors::RoleNameAlreadyExists)?;
@@ -258,6 +256,7 @@ pub async fn create_role_v2(
             role_scope: role.scope,
             entity_type: role.entity_type,
             parent_groups: response_parent_groups,
+            permissions: role.get_permissions(),
         },
     ))
 }
@@ -286,6 +285,7 @@ pub async fn get_role_with_groups(
     Ok(ApplicationResponse::Json(
         role_api::RoleInfoWithGroupsResponse {
             groups: role_info.get_permission_groups().to_vec(),
+            permissions: role_info.get_permissions().to_vec(),
             role_id: role.role_id,
             role_name: role_info.get_role_name().to_string(),
             role_scope: role_info.get_scope(),
@@ -343,6 +343,7 @@ pub async fn get_parent_info_for_role(
     Ok(ApplicationResponse::Json(role_api::RoleInfoWithParents {
         role_id: role.role_id,
         parent_groups,
+        permissions: role_info.get_permissions().to_vec(),
         role_name: role_info.get_role_name().to_string(),
         role_scope: role_info.get_scope(),
     }))
@@ -402,8 +403,16 @@ pub async fn update_role(
         .await?;
     }
 
-    if let Some(ref groups) = req.groups {
-        utils::user_role::validate_role_groups(groups)?;
+    if req.groups.is_some() || req.permissions.is_some() {
+        let groups = req
+            .groups
+            .clone()
+            .unwrap_or_else(|| role_info.get_permission_groups());
+        let permissions = req
+            .permissions
+            .clone()
+            .unwrap_or_else(|| role_info.get_permissions().to_vec());
+        utils::user_role::validate_role_groups_and_permissions(&groups, &permissions)?;
     }
 
     let updated_role = state
@@ -412,6 +421,7 @@ pub async fn update_role(
             role_id,
             RoleUpdate::UpdateDetails {
                 groups: req.groups,
+                permissions: req.permissions,
                 role_name: role_name.map(RoleName::get_role_name),
                 last_modified_at: common_utils::date_time::now(),
                 last_modified_by: user_from_token.user_id,
@@ -424,6 +434,7 @@ pub async fn update_role(
 
     Ok(ApplicationResponse::Json(
         role_api::RoleInfoWithGroupsResponse {
+            permissions: updated_role.get_permissions(),
             groups: updated_role.groups,
             role_id: updated_role.role_id,
             role_name: updated_role.role_name,
@@ -523,6 +534,7 @@ pub async fn list_roles_with_info(
                         role_name: role_info.get_role_name().to_string(),
                         entity_type: role_info.get_entity_type(),
                         parent_groups,
+                        permissions: role_info.get_permissions().to_vec(),
                         role_scope: role_info.get_scope(),
                     }
                 })
@@ -547,6 +559,7 @@ pub async fn list_roles_with_info(
                     role_id: role_info.get_role_id().to_string(),
                     role_name: role_info.get_role_name().to_string(),
                     groups: role_info.get_permission_groups().to_vec(),
+                    permissions: role_info.get_permissions().to_vec(),
                     entity_type: role_info.get_entity_type(),
                     scope: role_info.get_scope(),
                 })
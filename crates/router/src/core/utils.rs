@@ -201,6 +201,7 @@ pub async fn construct_payout_router_data<'a, F>(
         test_mode,
         payment_method_balance: None,
         connector_api_version: None,
+        merchant_connector_id: None,
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow: None,
@@ -375,6 +376,7 @@ pub async fn construct_refund_router_data<'a, F>(
         test_mode: None,
         payment_method_balance: None,
         connector_api_version,
+        merchant_connector_id: None,
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow: None,
@@ -581,6 +583,7 @@ pub async fn construct_refund_router_data<'a, F>(
         test_mode,
         payment_method_balance: None,
         connector_api_version,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow: None,
@@ -632,6 +635,34 @@ pub fn validate_id(id: String, key: &str) -> Result<String, errors::ApiErrorResp
     }
 }
 
+/// Builds the cursor for the page following `items`. A cursor is only handed out when the page is
+/// full, since a short page means there is nothing left to fetch.
+pub fn get_next_list_cursor<T>(
+    items: &[T],
+    limit: i64,
+    to_cursor: impl FnOnce(&T) -> common_utils::types::ListCursor,
+) -> Option<common_utils::types::ListCursor> {
+    let is_page_full = i64::try_from(items.len()).is_ok_and(|count| count >= limit);
+    is_page_full.then(|| items.last().map(to_cursor)).flatten()
+}
+
+/// Response headers carrying the next page cursor, for list endpoints whose body is a bare array
+pub fn get_next_list_cursor_headers(
+    next_cursor: Option<common_utils::types::ListCursor>,
+) -> CustomResult<Vec<(String, masking::Maskable<String>)>, common_utils::errors::ParsingError> {
+    next_cursor
+        .map(|cursor| {
+            cursor.encode().map(|encoded_cursor| {
+                (
+                    crate::headers::X_NEXT_CURSOR.to_string(),
+                    masking::Maskable::new_normal(encoded_cursor),
+                )
+            })
+        })
+        .transpose()
+        .map(|header| header.into_iter().collect())
+}
+
 #[cfg(feature = "v1")]
 pub fn get_split_refunds(
     split_refund_input: refunds_transformers::SplitRefundInput,
@@ -1024,6 +1055,7 @@ pub async fn construct_accept_dispute_router_data<'a>(
         test_mode,
         payment_method_balance: None,
         connector_api_version: None,
+        merchant_connector_id: None,
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow: None,
@@ -1127,6 +1159,7 @@ pub async fn construct_submit_evidence_router_data<'a>(
         quote_id: None,
         test_mode,
         connector_api_version: None,
+        merchant_connector_id: None,
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow: None,
@@ -1239,6 +1272,7 @@ pub async fn construct_upload_file_router_data<'a>(
         quote_id: None,
         test_mode,
         connector_api_version: None,
+        merchant_connector_id: None,
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow: None,
@@ -1301,6 +1335,7 @@ pub async fn construct_dispute_list_router_data<'a>(
         preprocessing_id: None,
         payment_method_balance: None,
         connector_api_version: None,
+        merchant_connector_id: None,
         request: req,
         response: Err(ErrorResponse::default()),
         //TODO
@@ -1417,6 +1452,7 @@ pub async fn construct_dispute_sync_router_data<'a>(
         quote_id: None,
         test_mode,
         connector_api_version: None,
+        merchant_connector_id: None,
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow: None,
@@ -1523,6 +1559,7 @@ pub async fn construct_payments_dynamic_tax_calculation_router_data<F: Clone>(
         preprocessing_id: None,
         payment_method_balance: None,
         connector_api_version: None,
+        merchant_connector_id: None,
         request: types::PaymentsTaxCalculationData {
             amount: payment_intent.amount,
             shipping_cost: payment_intent.shipping_cost,
@@ -1651,6 +1688,7 @@ pub async fn construct_defend_dispute_router_data<'a>(
         quote_id: None,
         test_mode,
         connector_api_version: None,
+        merchant_connector_id: None,
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow: None,
@@ -1751,6 +1789,7 @@ pub async fn construct_retrieve_file_router_data<'a>(
         quote_id: None,
         test_mode,
         connector_api_version: None,
+        merchant_connector_id: None,
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow: None,
@@ -2252,6 +2291,12 @@ impl GetProfileId for diesel_models::Refund {
     }
 }
 
+impl GetProfileId for diesel_models::payment_review::PaymentReview {
+    fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
+        Some(&self.profile_id)
+    }
+}
+
 #[cfg(feature = "v1")]
 impl GetProfileId for api_models::routing::RoutingConfigRequest {
     fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
@@ -54,6 +54,7 @@ pub fn validate_refund_amount(
         .filter_map(|refund| {
             if refund.refund_status != enums::RefundStatus::Failure
                 && refund.refund_status != enums::RefundStatus::TransactionFailure
+                && refund.refund_status != enums::RefundStatus::Cancelled
             {
                 Some(refund.refund_amount.get_amount_as_i64())
             } else {
@@ -1,4 +1,6 @@
 #[cfg(feature = "v1")]
+mod event_sink;
+#[cfg(feature = "v1")]
 pub mod incoming;
 #[cfg(feature = "v2")]
 mod incoming_v2;
@@ -10,6 +12,8 @@ mod outgoing;
 mod outgoing_v2;
 #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
 pub mod recovery_incoming;
+#[cfg(feature = "v1")]
+mod source_verification;
 pub mod types;
 pub mod utils;
 #[cfg(feature = "olap")]
@@ -29,3 +33,4 @@ pub(crate) use self::{
 };
 
 const MERCHANT_ID: &str = "merchant_id";
+const PROFILE_ID: &str = "profile_id";
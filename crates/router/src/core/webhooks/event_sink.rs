@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+
+use api_models::webhooks::{EventSinkPayload, EventSinkPayloadV1};
+use common_utils::ext_traits::Encode;
+use error_stack::ResultExt;
+use redis_interface::SetnxReply;
+use router_env::{instrument, tracing};
+
+use super::{utils, MERCHANT_ID};
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        metrics,
+    },
+    logger,
+    routes::SessionState,
+    services::event_sink::EventSinkMessage,
+    types::{api, domain, storage::enums},
+};
+
+const EVENT_SINK_PUBLISHED_KEY_PREFIX: &str = "event_sink_published";
+
+/// Duration for which an event published to an event sink is remembered, so that it is not
+/// published again when the outgoing webhook for the same event is triggered more than once
+const EVENT_SINK_PUBLISHED_KEY_EXPIRY_SECONDS: i64 = 24 * 60 * 60;
+
+/// Publishes the event to the event sink configured on the profile, if the sink is enabled for the
+/// type of the event. Failures are only logged and recorded, since event sinks are delivered on a
+/// best-effort basis alongside outgoing webhooks.
+#[instrument(skip_all)]
+pub(super) async fn publish_event_to_sink(
+    state: SessionState,
+    business_profile: domain::Profile,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    primary_object_id: String,
+    content: api::OutgoingWebhookContent,
+) {
+    let Some(event_sink_config) = business_profile
+        .event_sink_config
+        .as_ref()
+        .filter(|event_sink_config| event_sink_config.is_event_enabled(event_type))
+    else {
+        return;
+    };
+    let sink = event_sink_config.destination.get_name();
+    let merchant_id = business_profile.merchant_id.clone();
+
+    let idempotency_key = utils::get_idempotent_event_id(
+        &primary_object_id,
+        event_type,
+        enums::WebhookDeliveryAttempt::InitialAttempt,
+    );
+    match is_event_published(&state, &merchant_id, &idempotency_key).await {
+        Ok(true) => {
+            logger::debug!(%idempotency_key, "Event already published to event sink");
+            return;
+        }
+        Ok(false) => {}
+        // Publishing the event more than once is preferred over not publishing it
+        Err(error) => logger::warn!(
+            ?error,
+            "Failed to check if the event was already published to event sink"
+        ),
+    }
+
+    let payload = match event_sink_config.schema_version {
+        enums::EventSinkSchemaVersion::V1 => EventSinkPayload::V1(EventSinkPayloadV1 {
+            profile_id: business_profile.get_id().to_owned(),
+            event_class,
+            idempotency_key: idempotency_key.clone(),
+            webhook: api::OutgoingWebhook {
+                merchant_id: merchant_id.clone(),
+                event_id: utils::generate_event_id(),
+                event_type,
+                content,
+                timestamp: common_utils::date_time::now(),
+            },
+        }),
+    };
+    let publish_result = async {
+        let message = EventSinkMessage {
+            key: primary_object_id,
+            deduplication_id: idempotency_key,
+            payload: payload
+                .encode_to_string_of_json()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to encode event sink payload")?,
+            attributes: HashMap::from([
+                (
+                    "schema_version".to_string(),
+                    event_sink_config.schema_version.to_string(),
+                ),
+                ("event_type".to_string(), event_type.to_string()),
+                (
+                    "merchant_id".to_string(),
+                    merchant_id.get_string_repr().to_owned(),
+                ),
+                (
+                    "profile_id".to_string(),
+                    business_profile.get_id().get_string_repr().to_owned(),
+                ),
+            ]),
+        };
+        state
+            .event_sink_client
+            .publish(
+                business_profile.get_id(),
+                &event_sink_config.destination,
+                message,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+    }
+    .await;
+
+    match publish_result {
+        Ok(()) => metrics::WEBHOOK_EVENT_SINK_PUBLISHED_COUNT.add(
+            1,
+            router_env::metric_attributes!((MERCHANT_ID, merchant_id), ("sink", sink)),
+        ),
+        Err(error) => {
+            logger::error!(?error, sink, "Failed to publish event to event sink");
+            metrics::WEBHOOK_EVENT_SINK_PUBLISH_FAILURE_COUNT.add(
+                1,
+                router_env::metric_attributes!((MERCHANT_ID, merchant_id), ("sink", sink)),
+            );
+        }
+    }
+}
+
+/// Marks the event as published, returning `true` if it was already marked
+async fn is_event_published(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    idempotency_key: &str,
+) -> RouterResult<bool> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let key = format!(
+        "{EVENT_SINK_PUBLISHED_KEY_PREFIX}_{}_{idempotency_key}",
+        merchant_id.get_string_repr()
+    );
+
+    let reply = redis_conn
+        .set_key_if_not_exists_with_expiry(
+            &key.as_str().into(),
+            "",
+            Some(EVENT_SINK_PUBLISHED_KEY_EXPIRY_SECONDS),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to mark event as published")?;
+
+    Ok(reply == SetnxReply::KeyNotSet)
+}
@@ -22,11 +22,11 @@ use hyperswitch_interfaces::webhooks::{IncomingWebhookFlowError, IncomingWebhook
 use masking::{ExposeInterface, PeekInterface};
 use router_env::{instrument, tracing, tracing_actix_web::RequestId};
 
-use super::{types, utils, MERCHANT_ID};
+use super::{source_verification, types, utils, MERCHANT_ID};
 use crate::{
     consts,
     core::{
-        api_locking,
+        api_locking, dispute_decision_config,
         errors::{self, ConnectorErrorExt, CustomResult, RouterResponse, StorageErrorExt},
         metrics, payment_methods,
         payments::{self, tokenization},
@@ -224,6 +224,15 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
         fetch_optional_mca_and_connector(&state, &merchant_context, connector_name_or_mca_id)
             .await?;
 
+    // Reject webhooks received from outside the IP ranges of the connector before processing them
+    source_verification::verify_webhook_source_ip_address(
+        &state,
+        req.headers(),
+        merchant_context.get_merchant_account().get_id(),
+        &connector_name,
+    )
+    .await?;
+
     // Determine webhook processing path (UCS vs non-UCS) and handle event type extraction
     let webhook_processing_result =
         if unified_connector_service::should_call_unified_connector_service_for_webhooks(
@@ -588,9 +597,16 @@ async fn process_webhook_business_logic(
                 merchant_context.get_merchant_account().get_id().clone()
             )),
         );
-    } else if connector.is_webhook_source_verification_mandatory() {
+    } else if connector.is_webhook_source_verification_mandatory()
+        || source_verification::is_signature_verification_required(state, connector_name)
+    {
         // if webhook consumption is mandatory for connector, fail webhook
         // so that merchant can retrigger it after updating merchant_secret
+        source_verification::record_webhook_source_rejection(
+            merchant_context.get_merchant_account().get_id(),
+            connector_name,
+            source_verification::WebhookSourceRejectionReason::SignatureVerificationFailed,
+        );
         return Err(errors::ApiErrorResponse::WebhookAuthenticationFailed.into());
     }
 
@@ -1536,6 +1552,7 @@ pub async fn get_or_update_dispute_object(
                 )?,
                 organization_id: organization_id.clone(),
                 dispute_currency: Some(dispute_details.currency),
+                automated_action: None,
             };
             state
                 .store
@@ -2003,9 +2020,9 @@ async fn disputes_incoming_webhook_flow(
         let event_type: enums::EventType = dispute_object.dispute_status.into();
 
         Box::pin(super::create_event_and_trigger_outgoing_webhook(
-            state,
-            merchant_context,
-            business_profile,
+            state.clone(),
+            merchant_context.clone(),
+            business_profile.clone(),
             event_type,
             enums::EventClass::Disputes,
             dispute_object.dispute_id.clone(),
@@ -2015,6 +2032,21 @@ async fn disputes_incoming_webhook_flow(
         ))
         .await?;
         metrics::INCOMING_DISPUTE_WEBHOOK_MERCHANT_NOTIFIED_METRIC.add(1, &[]);
+
+        // Failing to respond to the dispute automatically leaves it to be handled by the merchant
+        let dispute_object = Box::pin(
+            dispute_decision_config::perform_dispute_decision_management(
+                &state,
+                merchant_context,
+                &business_profile,
+                dispute_object.clone(),
+            ),
+        )
+        .await
+        .map_err(|error| logger::warn!(dispute_decision_error=?error))
+        .ok()
+        .flatten()
+        .unwrap_or(dispute_object);
         Ok(WebhookResponseTracker::Dispute {
             dispute_id: dispute_object.dispute_id,
             payment_id: dispute_object.payment_id,
@@ -57,11 +57,73 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     primary_object_type: enums::EventObjectType,
     content: api::OutgoingWebhookContent,
     primary_object_created_at: Option<time::PrimitiveDateTime>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let webhook_endpoint_ids = business_profile
+        .get_webhook_endpoints_subscribed_to(event_type)
+        .into_iter()
+        .map(|webhook_endpoint| webhook_endpoint.endpoint_id.clone())
+        .collect::<Vec<_>>();
+
+    if state.conf.webhooks.outgoing_enabled && business_profile.event_sink_config.is_some() {
+        tokio::spawn(
+            super::event_sink::publish_event_to_sink(
+                state.clone(),
+                business_profile.clone(),
+                event_type,
+                event_class,
+                primary_object_id.clone(),
+                content.clone(),
+            )
+            .in_current_span(),
+        );
+    }
+
+    // The primary webhook URL of the profile receives all events, while the additional webhook
+    // endpoints only receive the events they are subscribed to
+    for webhook_endpoint_id in
+        std::iter::once(None).chain(webhook_endpoint_ids.into_iter().map(Some))
+    {
+        Box::pin(create_event_and_trigger_outgoing_webhook_to_endpoint(
+            state.clone(),
+            merchant_context.clone(),
+            business_profile.clone(),
+            event_type,
+            event_class,
+            primary_object_id.clone(),
+            primary_object_type,
+            content.clone(),
+            primary_object_created_at,
+            webhook_endpoint_id,
+        ))
+        .await?;
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+async fn create_event_and_trigger_outgoing_webhook_to_endpoint(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    business_profile: domain::Profile,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    primary_object_id: String,
+    primary_object_type: enums::EventObjectType,
+    content: api::OutgoingWebhookContent,
+    primary_object_created_at: Option<time::PrimitiveDateTime>,
+    webhook_endpoint_id: Option<String>,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
     let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
     let idempotent_event_id =
         utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt);
-    let webhook_url_result = get_webhook_url_from_business_profile(&business_profile);
+    let idempotent_event_id = match &webhook_endpoint_id {
+        Some(webhook_endpoint_id) => format!("{idempotent_event_id}_{webhook_endpoint_id}"),
+        None => idempotent_event_id,
+    };
+    let webhook_url_result =
+        get_webhook_url_from_business_profile(&business_profile, webhook_endpoint_id.as_deref());
 
     if !state.conf.webhooks.outgoing_enabled
         || webhook_url_result.is_err()
@@ -70,6 +132,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         logger::debug!(
             business_profile_id=?business_profile.get_id(),
             %idempotent_event_id,
+            ?webhook_endpoint_id,
             "Outgoing webhooks are disabled in application configuration, or merchant webhook URL \
              could not be obtained; skipping outgoing webhooks for event"
         );
@@ -88,10 +151,14 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         timestamp: now,
     };
 
-    let request_content =
-        get_outgoing_webhook_request(&merchant_context, outgoing_webhook, &business_profile)
-            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-            .attach_printable("Failed to construct outgoing webhook request content")?;
+    let request_content = get_outgoing_webhook_request(
+        &merchant_context,
+        outgoing_webhook,
+        &business_profile,
+        webhook_endpoint_id.as_deref(),
+    )
+    .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+    .attach_printable("Failed to construct outgoing webhook request content")?;
 
     let event_metadata = storage::EventMetadata::foreign_from(&content);
     let key_manager_state = &(&state).into();
@@ -140,6 +207,9 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         delivery_attempt: Some(delivery_attempt),
         metadata: Some(event_metadata),
         is_overall_delivery_successful: Some(false),
+        dead_lettered_at: None,
+        webhook_endpoint_id,
+        delivery_latency_in_ms: None,
     };
 
     let lock_value = utils::perform_redis_lock(
@@ -293,7 +363,10 @@ async fn trigger_webhook_to_merchant(
     process_tracker: Option<storage::ProcessTracker>,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
     let webhook_url = match (
-        get_webhook_url_from_business_profile(&business_profile),
+        get_webhook_url_from_business_profile(
+            &business_profile,
+            event.webhook_endpoint_id.as_deref(),
+        ),
         process_tracker.clone(),
     ) {
         (Ok(webhook_url), _) => Ok(webhook_url),
@@ -317,6 +390,39 @@ async fn trigger_webhook_to_merchant(
         (Err(error), None) => Err(error),
     }?;
 
+    // Authentication configured on the webhook endpoint is applied when sending the request
+    // rather than persisted along with the request content, so that rotated credentials are
+    // used for retries as well
+    let webhook_endpoint = event
+        .webhook_endpoint_id
+        .as_deref()
+        .and_then(|webhook_endpoint_id| business_profile.get_webhook_endpoint(webhook_endpoint_id));
+    let webhook_endpoint_secrets = match webhook_endpoint {
+        Some(webhook_endpoint) => business_profile
+            .get_webhook_endpoint_secrets()
+            .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
+            .attach_printable("Failed to deserialize webhook endpoint secrets")?
+            .remove(&webhook_endpoint.endpoint_id)
+            .unwrap_or_default(),
+        None => diesel_models::business_profile::WebhookEndpointSecrets::default(),
+    };
+    let endpoint_headers = webhook_endpoint_secrets
+        .custom_headers
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|(name, _)| !is_reserved_webhook_header(name))
+        .map(|(name, value)| (name, value.expose().into_masked()))
+        .collect();
+    let client_certificate_key = webhook_endpoint_secrets.client_certificate_key;
+    let (client_certificate, ca_certificate) = webhook_endpoint
+        .map(|webhook_endpoint| {
+            (
+                webhook_endpoint.client_certificate.clone(),
+                webhook_endpoint.ca_certificate.clone(),
+            )
+        })
+        .unwrap_or_default();
+
     let event_id = event.event_id;
 
     let headers = request_content
@@ -329,20 +435,36 @@ async fn trigger_webhook_to_merchant(
         .url(&webhook_url)
         .attach_default_headers()
         .headers(headers)
+        .headers(endpoint_headers)
+        .add_certificate(client_certificate)
+        .add_certificate_key(client_certificate_key)
+        .add_ca_certificate_pem(ca_certificate)
         .set_body(RequestContent::RawBytes(
             request_content.body.expose().into_bytes(),
         ))
         .build();
 
+    let delivery_started_at = std::time::Instant::now();
     let response = state
         .api_client
         .send_request(&state, request, None, false)
         .await;
+    let delivery_latency = delivery_started_at.elapsed();
+    let delivery_latency_in_ms = i32::try_from(delivery_latency.as_millis()).ok();
 
     metrics::WEBHOOK_OUTGOING_COUNT.add(
         1,
         router_env::metric_attributes!((MERCHANT_ID, business_profile.merchant_id.clone())),
     );
+    utils::record_webhook_outgoing_delivery(
+        &business_profile,
+        delivery_attempt,
+        response
+            .as_ref()
+            .ok()
+            .map(|response| response.status().as_u16()),
+        delivery_latency,
+    );
     logger::debug!(outgoing_webhook_response=?response);
 
     match delivery_attempt {
@@ -351,7 +473,7 @@ async fn trigger_webhook_to_merchant(
                 api_client_error_handler(
                     state.clone(),
                     merchant_key_store.clone(),
-                    &business_profile.merchant_id,
+                    &business_profile,
                     &event_id,
                     client_error,
                     delivery_attempt,
@@ -367,6 +489,7 @@ async fn trigger_webhook_to_merchant(
                     &business_profile.merchant_id,
                     &event_id,
                     response,
+                    delivery_latency_in_ms,
                 )
                 .await?;
 
@@ -389,7 +512,8 @@ async fn trigger_webhook_to_merchant(
                 } else {
                     error_response_handler(
                         state.clone(),
-                        &business_profile.merchant_id,
+                        merchant_key_store,
+                        &business_profile,
                         delivery_attempt,
                         status_code.as_u16(),
                         "Ignoring error when sending webhook to merchant",
@@ -409,7 +533,7 @@ async fn trigger_webhook_to_merchant(
                     api_client_error_handler(
                         state.clone(),
                         merchant_key_store.clone(),
-                        &business_profile.merchant_id,
+                        &business_profile,
                         &event_id,
                         client_error,
                         delivery_attempt,
@@ -425,6 +549,7 @@ async fn trigger_webhook_to_merchant(
                         &business_profile.merchant_id,
                         &event_id,
                         response,
+                        delivery_latency_in_ms,
                     )
                     .await?;
 
@@ -447,7 +572,8 @@ async fn trigger_webhook_to_merchant(
                     } else {
                         error_response_handler(
                             state.clone(),
-                            &business_profile.merchant_id,
+                            merchant_key_store,
+                            &business_profile,
                             delivery_attempt,
                             status_code.as_u16(),
                             "An error occurred when sending webhook to merchant",
@@ -463,7 +589,7 @@ async fn trigger_webhook_to_merchant(
                 api_client_error_handler(
                     state.clone(),
                     merchant_key_store.clone(),
-                    &business_profile.merchant_id,
+                    &business_profile,
                     &event_id,
                     client_error,
                     delivery_attempt,
@@ -479,6 +605,7 @@ async fn trigger_webhook_to_merchant(
                     &business_profile.merchant_id,
                     &event_id,
                     response,
+                    delivery_latency_in_ms,
                 )
                 .await?;
 
@@ -487,7 +614,8 @@ async fn trigger_webhook_to_merchant(
                 } else {
                     error_response_handler(
                         state,
-                        &business_profile.merchant_id,
+                        merchant_key_store,
+                        &business_profile,
                         delivery_attempt,
                         status_code.as_u16(),
                         "Ignoring error when sending webhook to merchant",
@@ -584,8 +712,9 @@ pub(crate) async fn add_outgoing_webhook_retry_task_to_process_tracker(
 ) -> CustomResult<storage::ProcessTracker, errors::StorageError> {
     let schedule_time = outgoing_webhook_retry::get_webhook_delivery_retry_schedule_time(
         db,
-        &business_profile.merchant_id,
+        business_profile,
         0,
+        common_utils::date_time::now(),
     )
     .await
     .ok_or(errors::StorageError::ValueNotFound(
@@ -639,6 +768,7 @@ pub(crate) async fn add_outgoing_webhook_retry_task_to_process_tracker(
 
 fn get_webhook_url_from_business_profile(
     business_profile: &domain::Profile,
+    webhook_endpoint_id: Option<&str>,
 ) -> CustomResult<String, errors::WebhooksFlowError> {
     let webhook_details = business_profile
         .webhook_details
@@ -646,22 +776,51 @@ fn get_webhook_url_from_business_profile(
         .get_required_value("webhook_details")
         .change_context(errors::WebhooksFlowError::MerchantWebhookDetailsNotFound)?;
 
-    webhook_details
-        .webhook_url
-        .get_required_value("webhook_url")
+    match webhook_endpoint_id {
+        Some(webhook_endpoint_id) => {
+            get_webhook_endpoint_from_business_profile(business_profile, webhook_endpoint_id)
+                .map(|webhook_endpoint| webhook_endpoint.url.clone())
+        }
+        None => webhook_details
+            .webhook_url
+            .get_required_value("webhook_url")
+            .change_context(errors::WebhooksFlowError::MerchantWebhookUrlNotConfigured),
+    }
+    .map(ExposeInterface::expose)
+}
+
+/// Custom headers overriding the headers set by the webhook delivery are skipped, in case they
+/// were configured before such headers were rejected
+fn is_reserved_webhook_header(header_name: &str) -> bool {
+    api_models::consts::RESERVED_OUTGOING_WEBHOOK_HEADERS
+        .iter()
+        .any(|reserved_header| reserved_header.eq_ignore_ascii_case(header_name))
+}
+
+fn get_webhook_endpoint_from_business_profile<'a>(
+    business_profile: &'a domain::Profile,
+    webhook_endpoint_id: &str,
+) -> CustomResult<&'a diesel_models::business_profile::WebhookEndpoint, errors::WebhooksFlowError> {
+    business_profile
+        .get_webhook_endpoint(webhook_endpoint_id)
+        .get_required_value("webhook_endpoint")
         .change_context(errors::WebhooksFlowError::MerchantWebhookUrlNotConfigured)
-        .map(ExposeInterface::expose)
+        .attach_printable_lazy(|| {
+            format!("Webhook endpoint `{webhook_endpoint_id}` is not configured in the profile")
+        })
 }
 
 pub(crate) fn get_outgoing_webhook_request(
     merchant_context: &domain::MerchantContext,
     outgoing_webhook: api::OutgoingWebhook,
     business_profile: &domain::Profile,
+    webhook_endpoint_id: Option<&str>,
 ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
     #[inline]
     fn get_outgoing_webhook_request_inner<WebhookType: types::OutgoingWebhookType>(
         outgoing_webhook: api::OutgoingWebhook,
         business_profile: &domain::Profile,
+        webhook_endpoint_id: Option<&str>,
     ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
         let mut headers = vec![
             (
@@ -675,7 +834,16 @@ pub(crate) fn get_outgoing_webhook_request(
         ];
 
         let transformed_outgoing_webhook = WebhookType::from(outgoing_webhook);
-        let payment_response_hash_key = business_profile.payment_response_hash_key.clone();
+        // Webhooks delivered to an additional webhook endpoint are signed using its own secret
+        let payment_response_hash_key = match webhook_endpoint_id {
+            Some(webhook_endpoint_id) => Some(
+                get_webhook_endpoint_from_business_profile(business_profile, webhook_endpoint_id)?
+                    .secret
+                    .clone()
+                    .expose(),
+            ),
+            None => business_profile.payment_response_hash_key.clone(),
+        };
         let custom_headers = business_profile
             .outgoing_webhook_custom_http_headers
             .clone()
@@ -691,6 +859,7 @@ pub(crate) fn get_outgoing_webhook_request(
         if let Some(ref map) = custom_headers {
             headers.extend(
                 map.iter()
+                    .filter(|(key, _)| !is_reserved_webhook_header(key))
                     .map(|(key, value)| (key.clone(), value.clone().into_masked())),
             );
         };
@@ -715,12 +884,17 @@ pub(crate) fn get_outgoing_webhook_request(
         .get_compatible_connector()
     {
         #[cfg(feature = "stripe")]
-        Some(api_models::enums::Connector::Stripe) => get_outgoing_webhook_request_inner::<
-            stripe_webhooks::StripeOutgoingWebhook,
-        >(outgoing_webhook, business_profile),
+        Some(api_models::enums::Connector::Stripe) => {
+            get_outgoing_webhook_request_inner::<stripe_webhooks::StripeOutgoingWebhook>(
+                outgoing_webhook,
+                business_profile,
+                webhook_endpoint_id,
+            )
+        }
         _ => get_outgoing_webhook_request_inner::<webhooks::OutgoingWebhook>(
             outgoing_webhook,
             business_profile,
+            webhook_endpoint_id,
         ),
     }
 }
@@ -769,6 +943,7 @@ async fn update_event_if_client_error(
             .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)
             .attach_printable("Failed to encrypt outgoing webhook response content")?,
         ),
+        delivery_latency_in_ms: None,
     };
 
     state
@@ -787,7 +962,7 @@ async fn update_event_if_client_error(
 async fn api_client_error_handler(
     state: SessionState,
     merchant_key_store: domain::MerchantKeyStore,
-    merchant_id: &common_utils::id_type::MerchantId,
+    business_profile: &domain::Profile,
     event_id: &str,
     client_error: error_stack::Report<errors::ApiClientError>,
     delivery_attempt: enums::WebhookDeliveryAttempt,
@@ -797,8 +972,8 @@ async fn api_client_error_handler(
     // much of diagnostic information to be exposed to the merchant.
     update_event_if_client_error(
         state.clone(),
-        merchant_key_store,
-        merchant_id,
+        merchant_key_store.clone(),
+        &business_profile.merchant_id,
         event_id,
         "Unable to send request to merchant server".to_string(),
     )
@@ -814,8 +989,9 @@ async fn api_client_error_handler(
     if let ScheduleWebhookRetry::WithProcessTracker(process_tracker) = schedule_webhook_retry {
         // Schedule a retry attempt for webhook delivery
         outgoing_webhook_retry::retry_webhook_delivery_task(
-            &*state.store,
-            merchant_id,
+            &state,
+            business_profile,
+            &merchant_key_store,
             *process_tracker,
         )
         .await
@@ -831,6 +1007,7 @@ async fn update_event_in_storage(
     merchant_id: &common_utils::id_type::MerchantId,
     event_id: &str,
     response: reqwest::Response,
+    delivery_latency_in_ms: Option<i32>,
 ) -> CustomResult<domain::Event, errors::WebhooksFlowError> {
     let status_code = response.status();
     let is_webhook_notified = status_code.is_success();
@@ -891,6 +1068,7 @@ async fn update_event_in_storage(
             .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)
             .attach_printable("Failed to encrypt outgoing webhook response content")?,
         ),
+        delivery_latency_in_ms,
     };
     state
         .store
@@ -973,7 +1151,8 @@ async fn success_response_handler(
 
 async fn error_response_handler(
     state: SessionState,
-    merchant_id: &common_utils::id_type::MerchantId,
+    merchant_key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
     delivery_attempt: enums::WebhookDeliveryAttempt,
     status_code: u16,
     log_message: &'static str,
@@ -981,7 +1160,7 @@ async fn error_response_handler(
 ) -> CustomResult<(), errors::WebhooksFlowError> {
     metrics::WEBHOOK_OUTGOING_NOT_RECEIVED_COUNT.add(
         1,
-        router_env::metric_attributes!((MERCHANT_ID, merchant_id.clone())),
+        router_env::metric_attributes!((MERCHANT_ID, business_profile.merchant_id.clone())),
     );
 
     let error = report!(errors::WebhooksFlowError::NotReceivedByMerchant);
@@ -990,8 +1169,9 @@ async fn error_response_handler(
     if let ScheduleWebhookRetry::WithProcessTracker(process_tracker) = schedule_webhook_retry {
         // Schedule a retry attempt for webhook delivery
         outgoing_webhook_retry::retry_webhook_delivery_task(
-            &*state.store,
-            merchant_id,
+            &state,
+            business_profile,
+            merchant_key_store,
             *process_tracker,
         )
         .await
@@ -1024,6 +1204,16 @@ impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
             webhooks::OutgoingWebhookContent::PayoutDetails(payout_response) => Self::Payout {
                 payout_id: payout_response.payout_id.clone(),
             },
+            webhooks::OutgoingWebhookContent::RoutingDetails(routing_response) => Self::Routing {
+                profile_id: routing_response.profile_id.clone(),
+                routing_audit_log_id: routing_response.id.clone(),
+            },
+            webhooks::OutgoingWebhookContent::SubscriptionDetails(subscription_response) => {
+                Self::Subscription {
+                    subscription_id: subscription_response.id.clone(),
+                    payment_id: subscription_response.last_payment_id.clone(),
+                }
+            }
         }
     }
 }
@@ -1068,5 +1258,21 @@ fn get_outgoing_webhook_event_content_from_event_metadata(
             mandate_id,
             content: serde_json::Value::Null,
         },
+        diesel_models::EventMetadata::Routing {
+            profile_id,
+            routing_audit_log_id,
+        } => OutgoingWebhookEventContent::Routing {
+            profile_id,
+            routing_audit_log_id,
+            content: serde_json::Value::Null,
+        },
+        diesel_models::EventMetadata::Subscription {
+            subscription_id,
+            payment_id,
+        } => OutgoingWebhookEventContent::Subscription {
+            subscription_id,
+            payment_id,
+            content: serde_json::Value::Null,
+        },
     })
 }
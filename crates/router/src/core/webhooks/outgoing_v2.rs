@@ -115,6 +115,9 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         delivery_attempt: Some(delivery_attempt),
         metadata: Some(event_metadata),
         is_overall_delivery_successful: Some(false),
+        dead_lettered_at: None,
+        webhook_endpoint_id: None,
+        delivery_latency_in_ms: None,
     };
 
     let event_insert_result = state
@@ -209,12 +212,22 @@ async fn trigger_webhook_to_merchant(
         .get_webhook_url_from_profile()
         .change_context(errors::WebhooksFlowError::MerchantWebhookUrlNotConfigured)?;
 
+    let delivery_started_at = std::time::Instant::now();
     let response = build_and_send_request(&state, request_content, webhook_url).await;
 
     metrics::WEBHOOK_OUTGOING_COUNT.add(
         1,
         router_env::metric_attributes!((MERCHANT_ID, business_profile.merchant_id.clone())),
     );
+    utils::record_webhook_outgoing_delivery(
+        &business_profile,
+        delivery_attempt,
+        response
+            .as_ref()
+            .ok()
+            .map(|response| response.status().as_u16()),
+        delivery_started_at.elapsed(),
+    );
     logger::debug!(outgoing_webhook_response=?response);
 
     match response {
@@ -483,6 +496,7 @@ async fn update_event_in_storage(
             .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)
             .attach_printable("Failed to encrypt outgoing webhook response content")?,
         ),
+        delivery_latency_in_ms: None,
     };
     state
         .store
@@ -616,6 +630,16 @@ impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
             webhooks::OutgoingWebhookContent::PayoutDetails(payout_response) => Self::Payout {
                 payout_id: payout_response.payout_id.clone(),
             },
+            webhooks::OutgoingWebhookContent::RoutingDetails(routing_response) => Self::Routing {
+                profile_id: routing_response.profile_id.clone(),
+                routing_audit_log_id: routing_response.id.clone(),
+            },
+            webhooks::OutgoingWebhookContent::SubscriptionDetails(subscription_response) => {
+                Self::Subscription {
+                    subscription_id: subscription_response.id.clone(),
+                    payment_id: subscription_response.last_payment_id.clone(),
+                }
+            }
         }
     }
 }
@@ -657,6 +681,22 @@ impl ForeignFrom<storage::EventMetadata> for outgoing_webhook_logs::OutgoingWebh
                 mandate_id,
                 content: serde_json::Value::Null,
             },
+            diesel_models::EventMetadata::Routing {
+                profile_id,
+                routing_audit_log_id,
+            } => Self::Routing {
+                profile_id,
+                routing_audit_log_id,
+                content: serde_json::Value::Null,
+            },
+            diesel_models::EventMetadata::Subscription {
+                subscription_id,
+                payment_id,
+            } => Self::Subscription {
+                subscription_id,
+                payment_id,
+                content: serde_json::Value::Null,
+            },
         }
     }
 }
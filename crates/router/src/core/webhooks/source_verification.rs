@@ -0,0 +1,232 @@
+use actix_web::http::header::HeaderMap;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use super::MERCHANT_ID;
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        metrics,
+    },
+    logger,
+    routes::SessionState,
+    services::{self, authentication::ip_allowlist},
+};
+
+const PUBLISHED_IP_RANGES_KEY_PREFIX: &str = "incoming_webhook_source_ip_ranges";
+
+/// Reason for which an incoming webhook was rejected before being processed.
+#[derive(Clone, Copy, Debug, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub(super) enum WebhookSourceRejectionReason {
+    IpAddressNotAllowed,
+    SignatureVerificationFailed,
+}
+
+/// Checks the IP address from which an incoming webhook was received against the IP address
+/// ranges configured for the connector and the ones it publishes. Connectors without any known IP
+/// address range are not restricted.
+#[instrument(skip_all)]
+pub(super) async fn verify_webhook_source_ip_address(
+    state: &SessionState,
+    request_headers: &HeaderMap,
+    merchant_id: &common_utils::id_type::MerchantId,
+    connector_name: &str,
+) -> RouterResult<()> {
+    let settings = &state.conf.webhooks.incoming_source_verification;
+    let Some(connector_settings) = settings
+        .connectors
+        .get(connector_name)
+        .filter(|_| settings.enabled)
+    else {
+        return Ok(());
+    };
+
+    let mut allowed_ip_ranges = connector_settings.allowed_ip_ranges.clone();
+    if let Some(published_ip_ranges_url) = &connector_settings.published_ip_ranges_url {
+        // If the published IP ranges cannot be obtained, only the configured ones are checked
+        match get_published_ip_ranges(state, connector_name, published_ip_ranges_url).await {
+            Ok(published_ip_ranges) => allowed_ip_ranges.extend(published_ip_ranges),
+            Err(error) => logger::error!(
+                ?error,
+                connector_name,
+                "Failed to obtain the IP ranges published by the connector"
+            ),
+        }
+    }
+
+    if allowed_ip_ranges.is_empty() {
+        return Ok(());
+    }
+
+    let client_ip_address =
+        ip_allowlist::get_client_ip_address(request_headers, settings.trusted_proxy_count);
+    if client_ip_address.is_some_and(|ip_address| {
+        ip_allowlist::is_ip_address_allowed(ip_address, &allowed_ip_ranges)
+    }) {
+        return Ok(());
+    }
+
+    record_webhook_source_rejection(
+        merchant_id,
+        connector_name,
+        WebhookSourceRejectionReason::IpAddressNotAllowed,
+    );
+    Err(report!(
+        errors::ApiErrorResponse::WebhookAuthenticationFailed
+    ))
+    .attach_printable_lazy(|| {
+        format!(
+            "Incoming webhook of connector {connector_name} received from IP address \
+             {client_ip_address:?} outside of its allowed IP ranges"
+        )
+    })
+}
+
+/// Checks if incoming webhooks of the connector failing signature verification must be rejected.
+pub(super) fn is_signature_verification_required(
+    state: &SessionState,
+    connector_name: &str,
+) -> bool {
+    let settings = &state.conf.webhooks.incoming_source_verification;
+    settings.enabled
+        && settings
+            .connectors
+            .get(connector_name)
+            .is_some_and(|connector_settings| connector_settings.require_signature_verification)
+}
+
+pub(super) fn record_webhook_source_rejection(
+    merchant_id: &common_utils::id_type::MerchantId,
+    connector_name: &str,
+    reason: WebhookSourceRejectionReason,
+) {
+    logger::warn!(connector_name, %reason, "Rejecting incoming webhook");
+    metrics::WEBHOOK_INCOMING_SOURCE_REJECTED_COUNT.add(
+        1,
+        router_env::metric_attributes!(
+            (MERCHANT_ID, merchant_id.clone()),
+            ("connector", connector_name.to_owned()),
+            ("reason", reason.to_string()),
+        ),
+    );
+}
+
+/// Obtains the IP ranges published by the connector, which are cached in redis and fetched again
+/// once the refresh interval has elapsed.
+async fn get_published_ip_ranges(
+    state: &SessionState,
+    connector_name: &str,
+    published_ip_ranges_url: &str,
+) -> RouterResult<Vec<String>> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let key = format!("{PUBLISHED_IP_RANGES_KEY_PREFIX}_{connector_name}");
+
+    match redis_conn
+        .get_and_deserialize_key::<Vec<String>>(&key.as_str().into(), "Vec<String>")
+        .await
+    {
+        Ok(published_ip_ranges) => return Ok(published_ip_ranges),
+        Err(error) if error.current_context() == &redis_interface::errors::RedisError::NotFound => {
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch cached published IP ranges")
+        }
+    }
+
+    let request = services::Request::new(services::Method::Get, published_ip_ranges_url);
+    let response = services::call_connector_api(state, request, "webhook_source_ip_ranges")
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the IP ranges published by the connector")?
+        .map_err(|error_response| {
+            report!(errors::ApiErrorResponse::InternalServerError).attach_printable(format!(
+                "Published IP ranges endpoint responded with status code {}",
+                error_response.status_code
+            ))
+        })?;
+    let published_ip_ranges = parse_published_ip_ranges(&response.response);
+    logger::info!(
+        connector_name,
+        published_ip_range_count = published_ip_ranges.len(),
+        "Refreshed the IP ranges published by the connector"
+    );
+
+    redis_conn
+        .serialize_and_set_key_with_expiry(
+            &key.as_str().into(),
+            &published_ip_ranges,
+            state
+                .conf
+                .webhooks
+                .incoming_source_verification
+                .published_ip_ranges_refresh_interval_secs,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to cache published IP ranges")?;
+
+    Ok(published_ip_ranges)
+}
+
+/// Extracts the IP ranges from the published document, which is either a JSON document in which
+/// the IP ranges are strings at any depth, or plain text listing the IP ranges separated by
+/// whitespace. Entries which are not IP ranges are ignored.
+fn parse_published_ip_ranges(body: &[u8]) -> Vec<String> {
+    fn collect_strings(value: serde_json::Value, strings: &mut Vec<String>) {
+        match value {
+            serde_json::Value::String(string) => strings.push(string),
+            serde_json::Value::Array(values) => values
+                .into_iter()
+                .for_each(|value| collect_strings(value, strings)),
+            serde_json::Value::Object(map) => map
+                .into_iter()
+                .for_each(|(_, value)| collect_strings(value, strings)),
+            serde_json::Value::Null | serde_json::Value::Bool(_) | serde_json::Value::Number(_) => {
+            }
+        }
+    }
+
+    let entries = match serde_json::from_slice::<serde_json::Value>(body) {
+        Ok(value) => {
+            let mut strings = Vec::new();
+            collect_strings(value, &mut strings);
+            strings
+        }
+        Err(_) => String::from_utf8_lossy(body)
+            .split_whitespace()
+            .map(ToOwned::to_owned)
+            .collect(),
+    };
+
+    entries
+        .into_iter()
+        .filter(|entry| ip_allowlist::parse_ip_range(entry).is_some())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_published_ip_ranges() {
+        let json_document = br#"{"WEBHOOKS": ["3.18.12.63", "13.235.14.237/32"], "updated": 1}"#;
+        assert_eq!(
+            parse_published_ip_ranges(json_document),
+            vec!["3.18.12.63".to_string(), "13.235.14.237/32".to_string()]
+        );
+
+        let text_document = b"203.0.113.0/24\n2001:db8::/32\nnot-an-ip-range\n";
+        assert_eq!(
+            parse_published_ip_ranges(text_document),
+            vec!["203.0.113.0/24".to_string(), "2001:db8::/32".to_string()]
+        );
+    }
+}
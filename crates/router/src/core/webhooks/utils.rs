@@ -5,7 +5,7 @@ use error_stack::{Report, ResultExt};
 use redis_interface as redis;
 use router_env::tracing;
 
-use super::MERCHANT_ID;
+use super::{MERCHANT_ID, PROFILE_ID};
 use crate::{
     core::{
         errors::{self},
@@ -120,6 +120,7 @@ pub async fn construct_webhook_router_data(
         payment_method_balance: None,
         payment_method_status: None,
         connector_api_version: None,
+        merchant_connector_id: None,
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow: None,
@@ -181,6 +182,43 @@ pub fn increment_webhook_outgoing_not_received_count(
     );
 }
 
+/// Records the latency and the outcome of an outgoing webhook delivery attempt. The status code is
+/// `None` if the request could not be sent to the merchant.
+pub fn record_webhook_outgoing_delivery(
+    business_profile: &domain::Profile,
+    delivery_attempt: common_enums::WebhookDeliveryAttempt,
+    status_code: Option<u16>,
+    delivery_latency: std::time::Duration,
+) {
+    let outcome = match status_code {
+        Some(status_code) if (200..300).contains(&status_code) => "delivered",
+        Some(_) => "rejected",
+        None => "failed",
+    };
+
+    metrics::WEBHOOK_OUTGOING_DELIVERY_TIME.record(
+        delivery_latency.as_secs_f64(),
+        router_env::metric_attributes!(
+            (
+                MERCHANT_ID,
+                router_env::metrics::bounded_attribute_value(
+                    MERCHANT_ID,
+                    business_profile.merchant_id.get_string_repr(),
+                )
+            ),
+            (
+                PROFILE_ID,
+                router_env::metrics::bounded_attribute_value(
+                    PROFILE_ID,
+                    business_profile.get_id().get_string_repr(),
+                )
+            ),
+            ("delivery_attempt", delivery_attempt.to_string()),
+            ("outcome", outcome),
+        ),
+    );
+}
+
 pub fn is_outgoing_webhook_disabled(
     state: &SessionState,
     webhook_url_result: &Result<String, Report<errors::WebhooksFlowError>>,
@@ -15,6 +15,7 @@ use crate::{
 
 const INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT: i64 = 100;
 const INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS: i64 = 90;
+const DELIVERY_HEALTH_DEFAULT_WINDOW_DAYS: i64 = 1;
 
 #[derive(Debug)]
 enum MerchantAccountOrProfile {
@@ -308,6 +309,9 @@ pub async fn retry_delivery_attempt(
         delivery_attempt: Some(delivery_attempt),
         metadata: event_to_retry.metadata,
         is_overall_delivery_successful: Some(false),
+        dead_lettered_at: None,
+        webhook_endpoint_id: event_to_retry.webhook_endpoint_id,
+        delivery_latency_in_ms: None,
     };
 
     let event = store
@@ -354,6 +358,68 @@ pub async fn retry_delivery_attempt(
     ))
 }
 
+#[instrument(skip(state))]
+pub async fn get_delivery_health(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    profile_id: common_utils::id_type::ProfileId,
+    constraints: api::webhook_events::WebhookDeliveryHealthConstraints,
+) -> RouterResponse<api::webhook_events::WebhookDeliveryHealthResponse> {
+    let now = common_utils::date_time::now();
+    let health_begin_time =
+        (now.date() - time::Duration::days(INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS)).midnight();
+
+    let created_before = constraints.created_before.unwrap_or(now);
+    let created_after = constraints
+        .created_after
+        .unwrap_or(created_before - time::Duration::days(DELIVERY_HEALTH_DEFAULT_WINDOW_DAYS));
+
+    fp_utils::when(created_after > created_before, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The `created_after` timestamp must be an earlier timestamp compared to the `created_before` timestamp".to_string(),
+        })
+    })?;
+    fp_utils::when(created_after < health_begin_time, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`created_after` must be a timestamp within the past {INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS} days."
+            ),
+        })
+    })?;
+
+    let delivery_health = state
+        .store
+        .get_event_delivery_health_by_profile_id(
+            &merchant_id,
+            &profile_id,
+            created_after,
+            created_before,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to compute delivery health of events")?;
+
+    #[allow(clippy::as_conversions)]
+    let delivery_success_rate = (delivery_health.total_events > 0).then(|| {
+        delivery_health.delivered_events as f64 * 100.0 / delivery_health.total_events as f64
+    });
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::WebhookDeliveryHealthResponse {
+            merchant_id,
+            profile_id,
+            created_after,
+            created_before,
+            total_count: delivery_health.total_events,
+            delivered_count: delivery_health.delivered_events,
+            delivery_success_rate,
+            p95_delivery_latency_in_ms: delivery_health.p95_delivery_latency_in_ms,
+            pending_retry_count: delivery_health.pending_retry_events,
+            dead_lettered_count: delivery_health.dead_lettered_events,
+        },
+    ))
+}
+
 async fn get_account_and_key_store(
     state: SessionState,
     merchant_id: common_utils::id_type::MerchantId,
@@ -1,5 +1,8 @@
 pub mod address;
+pub mod admin_audit_event;
 pub mod api_keys;
+pub mod archival;
+pub mod auth_rate_alert;
 pub mod authentication;
 pub mod authorization;
 pub mod blocklist;
@@ -9,37 +12,53 @@ pub mod business_profile;
 pub mod callback_mapper;
 pub mod capture;
 pub mod configs;
+pub mod connector_audit_log;
 pub mod customers;
 pub mod dashboard_metadata;
+pub mod data_retention;
 pub mod dispute;
 pub mod dynamic_routing_stats;
 pub mod ephemeral_key;
 pub mod events;
 pub mod file;
 pub mod fraud_check;
+pub mod fx_quote;
 pub mod generic_link;
 pub mod gsm;
 pub mod health_check;
 pub mod hyperswitch_ai_interaction;
 pub mod kafka_store;
+pub mod list_view;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod merchant_key_store;
+pub mod merchant_signing_key;
 pub mod organization;
+pub mod payment_batch;
+pub mod payment_create;
 pub mod payment_link;
+pub mod payment_method_health;
 pub mod payment_method_session;
+pub mod payment_review;
+pub mod payout_batch;
+pub mod payout_beneficiary;
 pub mod refund;
 pub mod relay;
+pub mod report;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod routing_audit_log;
+pub mod routing_experiment_payment;
+pub mod settlement_recon;
 pub mod subscription;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
+pub mod user_passkey;
 pub mod user_role;
 
 use ::payment_methods::state::PaymentMethodsStorageInterface;
@@ -64,7 +83,8 @@ use masking::PeekInterface;
 use redis_interface::errors::RedisError;
 use router_env::logger;
 use storage_impl::{
-    errors::StorageError, redis::kv_store::RedisConnInterface, tokenization, MockDb,
+    database::store::DynamicPgPool, errors::StorageError, redis::kv_store::RedisConnInterface,
+    tokenization, MockDb,
 };
 
 pub use self::kafka_store::KafkaStore;
@@ -96,18 +116,26 @@ pub trait StorageInterface:
     + Sync
     + dyn_clone::DynClone
     + address::AddressInterface
+    + admin_audit_event::AdminAuditEventInterface
     + api_keys::ApiKeyInterface
+    + archival::ArchivalInterface
     + blocklist_lookup::BlocklistLookupInterface
     + configs::ConfigInterface
+    + connector_audit_log::ConnectorAuditLogInterface
     + capture::CaptureInterface
     + customers::CustomerInterface<Error = StorageError>
     + dashboard_metadata::DashboardMetadataInterface
+    + data_retention::DataRetentionInterface
+    + auth_rate_alert::AuthRateAlertInterface
+    + report::ReportInterface
     + dispute::DisputeInterface
     + ephemeral_key::EphemeralKeyInterface
     + ephemeral_key::ClientSecretInterface
     + events::EventInterface
     + file::FileMetadataInterface
     + FraudCheckInterface
+    + fx_quote::FxQuoteInterface
+    + list_view::ListViewInterface
     + locker_mock_up::LockerMockUpInterface
     + mandate::MandateInterface
     + merchant_account::MerchantAccountInterface
@@ -126,12 +154,22 @@ pub trait StorageInterface:
     + reverse_lookup::ReverseLookupInterface
     + CardsInfoInterface<Error = StorageError>
     + merchant_key_store::MerchantKeyStoreInterface
+    + merchant_signing_key::MerchantSigningKeyInterface
     + MasterKeyInterface
+    + payment_batch::PaymentBatchInterface
+    + payment_create::PaymentCreateInterface
     + payment_link::PaymentLinkInterface
+    + payment_method_health::PaymentMethodHealthInterface
+    + payment_review::PaymentReviewInterface
+    + payout_batch::PayoutBatchInterface
+    + payout_beneficiary::PayoutBeneficiaryInterface
     + RedisConnInterface
     + RequestIdStore
     + business_profile::ProfileInterface
     + routing_algorithm::RoutingAlgorithmInterface
+    + routing_audit_log::RoutingAuditLogInterface
+    + routing_experiment_payment::RoutingExperimentPaymentInterface
+    + settlement_recon::SettlementReconInterface
     + gsm::GsmInterface
     + unified_translations::UnifiedTranslationsInterface
     + authorization::AuthorizationInterface
@@ -152,6 +190,8 @@ pub trait StorageInterface:
     fn get_scheduler_db(&self) -> Box<dyn scheduler::SchedulerInterface>;
     fn get_payment_methods_store(&self) -> Box<dyn PaymentMethodsStorageInterface>;
     fn get_cache_store(&self) -> Box<(dyn RedisConnInterface + Send + Sync + 'static)>;
+    /// Returns the database connection pools of the store
+    fn get_database_pools(&self) -> Vec<DynamicPgPool>;
 }
 
 #[async_trait::async_trait]
@@ -162,6 +202,7 @@ pub trait GlobalStorageInterface:
     + user::UserInterface
     + user_role::UserRoleInterface
     + user_key_store::UserKeyStoreInterface
+    + user_passkey::UserPasskeyInterface
     + role::RoleInterface
     + RedisConnInterface
     + 'static
@@ -227,6 +268,10 @@ impl StorageInterface for Store {
     fn get_cache_store(&self) -> Box<(dyn RedisConnInterface + Send + Sync + 'static)> {
         Box::new(self.clone())
     }
+
+    fn get_database_pools(&self) -> Vec<DynamicPgPool> {
+        storage_impl::DatabaseStore::get_pools(self)
+    }
 }
 
 #[async_trait::async_trait]
@@ -250,6 +295,10 @@ impl StorageInterface for MockDb {
     fn get_cache_store(&self) -> Box<(dyn RedisConnInterface + Send + Sync + 'static)> {
         Box::new(self.clone())
     }
+
+    fn get_database_pools(&self) -> Vec<DynamicPgPool> {
+        Vec::new()
+    }
 }
 
 #[async_trait::async_trait]
@@ -0,0 +1,94 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait AdminAuditEventInterface {
+    async fn insert_admin_audit_event(
+        &self,
+        admin_audit_event: storage::AdminAuditEventNew,
+    ) -> CustomResult<storage::AdminAuditEvent, errors::StorageError>;
+
+    async fn list_admin_audit_events_by_entity_constraints(
+        &self,
+        entity: storage::AdminAuditEventEntity,
+        constraints: storage::AdminAuditEventListConstraints,
+    ) -> CustomResult<Vec<storage::AdminAuditEvent>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl AdminAuditEventInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_admin_audit_event(
+        &self,
+        admin_audit_event: storage::AdminAuditEventNew,
+    ) -> CustomResult<storage::AdminAuditEvent, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        admin_audit_event
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_admin_audit_events_by_entity_constraints(
+        &self,
+        entity: storage::AdminAuditEventEntity,
+        constraints: storage::AdminAuditEventListConstraints,
+    ) -> CustomResult<Vec<storage::AdminAuditEvent>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AdminAuditEvent::list_by_entity_constraints(&conn, entity, constraints)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl AdminAuditEventInterface for MockDb {
+    #[instrument(skip_all)]
+    async fn insert_admin_audit_event(
+        &self,
+        _admin_audit_event: storage::AdminAuditEventNew,
+    ) -> CustomResult<storage::AdminAuditEvent, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_admin_audit_events_by_entity_constraints(
+        &self,
+        _entity: storage::AdminAuditEventEntity,
+        _constraints: storage::AdminAuditEventListConstraints,
+    ) -> CustomResult<Vec<storage::AdminAuditEvent>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl AdminAuditEventInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_admin_audit_event(
+        &self,
+        admin_audit_event: storage::AdminAuditEventNew,
+    ) -> CustomResult<storage::AdminAuditEvent, errors::StorageError> {
+        self.diesel_store
+            .insert_admin_audit_event(admin_audit_event)
+            .await
+    }
+
+    async fn list_admin_audit_events_by_entity_constraints(
+        &self,
+        entity: storage::AdminAuditEventEntity,
+        constraints: storage::AdminAuditEventListConstraints,
+    ) -> CustomResult<Vec<storage::AdminAuditEvent>, errors::StorageError> {
+        self.diesel_store
+            .list_admin_audit_events_by_entity_constraints(entity, constraints)
+            .await
+    }
+}
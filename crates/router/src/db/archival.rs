@@ -0,0 +1,64 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ArchivalInterface {
+    async fn archive_rows_created_before(
+        &self,
+        table: storage::ArchivalTable,
+        created_before: time::PrimitiveDateTime,
+        batch_size: i32,
+    ) -> CustomResult<i64, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ArchivalInterface for Store {
+    #[instrument(skip_all)]
+    async fn archive_rows_created_before(
+        &self,
+        table: storage::ArchivalTable,
+        created_before: time::PrimitiveDateTime,
+        batch_size: i32,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        table
+            .archive_rows_created_before(&conn, created_before, batch_size)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ArchivalInterface for MockDb {
+    async fn archive_rows_created_before(
+        &self,
+        _table: storage::ArchivalTable,
+        _created_before: time::PrimitiveDateTime,
+        _batch_size: i32,
+    ) -> CustomResult<i64, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl ArchivalInterface for KafkaStore {
+    async fn archive_rows_created_before(
+        &self,
+        table: storage::ArchivalTable,
+        created_before: time::PrimitiveDateTime,
+        batch_size: i32,
+    ) -> CustomResult<i64, errors::StorageError> {
+        self.diesel_store
+            .archive_rows_created_before(table, created_before, batch_size)
+            .await
+    }
+}
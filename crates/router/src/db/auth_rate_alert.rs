@@ -0,0 +1,282 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait AuthRateAlertInterface {
+    async fn insert_auth_rate_alert_config(
+        &self,
+        auth_rate_alert_config: storage::AuthRateAlertConfigNew,
+    ) -> CustomResult<storage::AuthRateAlertConfig, errors::StorageError>;
+
+    async fn find_optional_auth_rate_alert_config(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Option<storage::AuthRateAlertConfig>, errors::StorageError>;
+
+    async fn update_auth_rate_alert_config(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        auth_rate_alert_config_update: storage::AuthRateAlertConfigUpdate,
+    ) -> CustomResult<storage::AuthRateAlertConfig, errors::StorageError>;
+
+    async fn insert_auth_rate_alert(
+        &self,
+        auth_rate_alert: storage::AuthRateAlertNew,
+    ) -> CustomResult<storage::AuthRateAlert, errors::StorageError>;
+
+    async fn find_active_auth_rate_alerts(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::AuthRateAlert>, errors::StorageError>;
+
+    async fn list_auth_rate_alerts(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        status: Option<common_enums::AuthRateAlertStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::AuthRateAlert>, errors::StorageError>;
+
+    async fn update_auth_rate_alert(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        alert_id: &str,
+        auth_rate_alert_update: storage::AuthRateAlertUpdate,
+    ) -> CustomResult<storage::AuthRateAlert, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl AuthRateAlertInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_auth_rate_alert_config(
+        &self,
+        auth_rate_alert_config: storage::AuthRateAlertConfigNew,
+    ) -> CustomResult<storage::AuthRateAlertConfig, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        auth_rate_alert_config
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_optional_auth_rate_alert_config(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Option<storage::AuthRateAlertConfig>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AuthRateAlertConfig::find_optional_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_auth_rate_alert_config(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        auth_rate_alert_config_update: storage::AuthRateAlertConfigUpdate,
+    ) -> CustomResult<storage::AuthRateAlertConfig, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::AuthRateAlertConfig::update_by_merchant_id(
+            &conn,
+            merchant_id,
+            auth_rate_alert_config_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_auth_rate_alert(
+        &self,
+        auth_rate_alert: storage::AuthRateAlertNew,
+    ) -> CustomResult<storage::AuthRateAlert, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        auth_rate_alert
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_active_auth_rate_alerts(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::AuthRateAlert>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AuthRateAlert::find_active_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_auth_rate_alerts(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        status: Option<common_enums::AuthRateAlertStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::AuthRateAlert>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AuthRateAlert::list_by_merchant_id(&conn, merchant_id, status, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_auth_rate_alert(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        alert_id: &str,
+        auth_rate_alert_update: storage::AuthRateAlertUpdate,
+    ) -> CustomResult<storage::AuthRateAlert, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::AuthRateAlert::update_by_merchant_id_alert_id(
+            &conn,
+            merchant_id,
+            alert_id,
+            auth_rate_alert_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl AuthRateAlertInterface for MockDb {
+    async fn insert_auth_rate_alert_config(
+        &self,
+        _auth_rate_alert_config: storage::AuthRateAlertConfigNew,
+    ) -> CustomResult<storage::AuthRateAlertConfig, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_optional_auth_rate_alert_config(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Option<storage::AuthRateAlertConfig>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_auth_rate_alert_config(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _auth_rate_alert_config_update: storage::AuthRateAlertConfigUpdate,
+    ) -> CustomResult<storage::AuthRateAlertConfig, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_auth_rate_alert(
+        &self,
+        _auth_rate_alert: storage::AuthRateAlertNew,
+    ) -> CustomResult<storage::AuthRateAlert, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_active_auth_rate_alerts(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::AuthRateAlert>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_auth_rate_alerts(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _status: Option<common_enums::AuthRateAlertStatus>,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::AuthRateAlert>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_auth_rate_alert(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _alert_id: &str,
+        _auth_rate_alert_update: storage::AuthRateAlertUpdate,
+    ) -> CustomResult<storage::AuthRateAlert, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl AuthRateAlertInterface for KafkaStore {
+    async fn insert_auth_rate_alert_config(
+        &self,
+        auth_rate_alert_config: storage::AuthRateAlertConfigNew,
+    ) -> CustomResult<storage::AuthRateAlertConfig, errors::StorageError> {
+        self.diesel_store
+            .insert_auth_rate_alert_config(auth_rate_alert_config)
+            .await
+    }
+
+    async fn find_optional_auth_rate_alert_config(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Option<storage::AuthRateAlertConfig>, errors::StorageError> {
+        self.diesel_store
+            .find_optional_auth_rate_alert_config(merchant_id)
+            .await
+    }
+
+    async fn update_auth_rate_alert_config(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        auth_rate_alert_config_update: storage::AuthRateAlertConfigUpdate,
+    ) -> CustomResult<storage::AuthRateAlertConfig, errors::StorageError> {
+        self.diesel_store
+            .update_auth_rate_alert_config(merchant_id, auth_rate_alert_config_update)
+            .await
+    }
+
+    async fn insert_auth_rate_alert(
+        &self,
+        auth_rate_alert: storage::AuthRateAlertNew,
+    ) -> CustomResult<storage::AuthRateAlert, errors::StorageError> {
+        self.diesel_store
+            .insert_auth_rate_alert(auth_rate_alert)
+            .await
+    }
+
+    async fn find_active_auth_rate_alerts(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::AuthRateAlert>, errors::StorageError> {
+        self.diesel_store
+            .find_active_auth_rate_alerts(merchant_id)
+            .await
+    }
+
+    async fn list_auth_rate_alerts(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        status: Option<common_enums::AuthRateAlertStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::AuthRateAlert>, errors::StorageError> {
+        self.diesel_store
+            .list_auth_rate_alerts(merchant_id, status, limit, offset)
+            .await
+    }
+
+    async fn update_auth_rate_alert(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        alert_id: &str,
+        auth_rate_alert_update: storage::AuthRateAlertUpdate,
+    ) -> CustomResult<storage::AuthRateAlert, errors::StorageError> {
+        self.diesel_store
+            .update_auth_rate_alert(merchant_id, alert_id, auth_rate_alert_update)
+            .await
+    }
+}
@@ -41,6 +41,12 @@ pub trait BlocklistInterface {
         limit: i64,
         offset: i64,
     ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError>;
+
+    async fn list_all_blocklist_entries_by_merchant_id_data_kind(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        data_kind: common_enums::BlocklistDataKind,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -100,6 +106,18 @@ impl BlocklistInterface for Store {
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    #[instrument(skip_all)]
+    async fn list_all_blocklist_entries_by_merchant_id_data_kind(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        data_kind: common_enums::BlocklistDataKind,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Blocklist::list_all_by_merchant_id_data_kind(&conn, merchant_id, data_kind)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     #[instrument(skip_all)]
     async fn delete_blocklist_entry_by_merchant_id_fingerprint_id(
         &self,
@@ -148,6 +166,14 @@ impl BlocklistInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn list_all_blocklist_entries_by_merchant_id_data_kind(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _data_kind: common_enums::BlocklistDataKind,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn delete_blocklist_entry_by_merchant_id_fingerprint_id(
         &self,
         _merchant_id: &common_utils::id_type::MerchantId,
@@ -202,6 +228,17 @@ impl BlocklistInterface for KafkaStore {
             .await
     }
 
+    #[instrument(skip_all)]
+    async fn list_all_blocklist_entries_by_merchant_id_data_kind(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        data_kind: common_enums::BlocklistDataKind,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        self.diesel_store
+            .list_all_blocklist_entries_by_merchant_id_data_kind(merchant_id, data_kind)
+            .await
+    }
+
     #[instrument(skip_all)]
     async fn list_blocklist_entries_by_merchant_id(
         &self,
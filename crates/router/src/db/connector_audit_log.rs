@@ -0,0 +1,107 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ConnectorAuditLogInterface {
+    async fn insert_connector_audit_log(
+        &self,
+        connector_audit_log: storage::ConnectorAuditLogNew,
+    ) -> CustomResult<storage::ConnectorAuditLog, errors::StorageError>;
+
+    async fn list_connector_audit_logs_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &str,
+        attempt_id: Option<&str>,
+    ) -> CustomResult<Vec<storage::ConnectorAuditLog>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ConnectorAuditLogInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_connector_audit_log(
+        &self,
+        connector_audit_log: storage::ConnectorAuditLogNew,
+    ) -> CustomResult<storage::ConnectorAuditLog, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        connector_audit_log
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_connector_audit_logs_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &str,
+        attempt_id: Option<&str>,
+    ) -> CustomResult<Vec<storage::ConnectorAuditLog>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ConnectorAuditLog::list_by_merchant_id_payment_id(
+            &conn,
+            merchant_id,
+            payment_id,
+            attempt_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectorAuditLogInterface for MockDb {
+    #[instrument(skip_all)]
+    async fn insert_connector_audit_log(
+        &self,
+        _connector_audit_log: storage::ConnectorAuditLogNew,
+    ) -> CustomResult<storage::ConnectorAuditLog, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_connector_audit_logs_by_merchant_id_payment_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_id: &str,
+        _attempt_id: Option<&str>,
+    ) -> CustomResult<Vec<storage::ConnectorAuditLog>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectorAuditLogInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_connector_audit_log(
+        &self,
+        connector_audit_log: storage::ConnectorAuditLogNew,
+    ) -> CustomResult<storage::ConnectorAuditLog, errors::StorageError> {
+        self.diesel_store
+            .insert_connector_audit_log(connector_audit_log)
+            .await
+    }
+
+    async fn list_connector_audit_logs_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &str,
+        attempt_id: Option<&str>,
+    ) -> CustomResult<Vec<storage::ConnectorAuditLog>, errors::StorageError> {
+        self.diesel_store
+            .list_connector_audit_logs_by_merchant_id_payment_id(
+                merchant_id,
+                payment_id,
+                attempt_id,
+            )
+            .await
+    }
+}
@@ -0,0 +1,346 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait DataRetentionInterface {
+    async fn insert_data_retention_policy(
+        &self,
+        data_retention_policy: storage::DataRetentionPolicyNew,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError>;
+
+    async fn find_optional_data_retention_policy(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Option<storage::DataRetentionPolicy>, errors::StorageError>;
+
+    async fn update_data_retention_policy(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        data_retention_policy_update: storage::DataRetentionPolicyUpdate,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError>;
+
+    async fn insert_data_retention_purge_run(
+        &self,
+        data_retention_purge_run: storage::DataRetentionPurgeRunNew,
+    ) -> CustomResult<storage::DataRetentionPurgeRun, errors::StorageError>;
+
+    async fn list_data_retention_purge_runs(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::DataRetentionPurgeRun>, errors::StorageError>;
+
+    async fn count_connector_audit_logs_created_before(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<i64, errors::StorageError>;
+
+    async fn delete_connector_audit_logs_created_before(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn list_inactive_customers(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        modified_before: time::PrimitiveDateTime,
+        redacted_description: common_utils::types::Description,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::Customer>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl DataRetentionInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_data_retention_policy(
+        &self,
+        data_retention_policy: storage::DataRetentionPolicyNew,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        data_retention_policy
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_optional_data_retention_policy(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Option<storage::DataRetentionPolicy>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::DataRetentionPolicy::find_optional_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_data_retention_policy(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        data_retention_policy_update: storage::DataRetentionPolicyUpdate,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::DataRetentionPolicy::update_by_merchant_id(
+            &conn,
+            merchant_id,
+            data_retention_policy_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_data_retention_purge_run(
+        &self,
+        data_retention_purge_run: storage::DataRetentionPurgeRunNew,
+    ) -> CustomResult<storage::DataRetentionPurgeRun, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        data_retention_purge_run
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_data_retention_purge_runs(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::DataRetentionPurgeRun>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::DataRetentionPurgeRun::list_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn count_connector_audit_logs_created_before(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ConnectorAuditLog::count_by_merchant_id_created_before(
+            &conn,
+            merchant_id,
+            created_before,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_connector_audit_logs_created_before(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ConnectorAuditLog::delete_by_merchant_id_created_before(
+            &conn,
+            merchant_id,
+            created_before,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn list_inactive_customers(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        modified_before: time::PrimitiveDateTime,
+        redacted_description: common_utils::types::Description,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::Customer>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Customer::list_inactive_by_merchant_id(
+            &conn,
+            merchant_id,
+            modified_before,
+            redacted_description,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl DataRetentionInterface for MockDb {
+    async fn insert_data_retention_policy(
+        &self,
+        _data_retention_policy: storage::DataRetentionPolicyNew,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_optional_data_retention_policy(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Option<storage::DataRetentionPolicy>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_data_retention_policy(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _data_retention_policy_update: storage::DataRetentionPolicyUpdate,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_data_retention_purge_run(
+        &self,
+        _data_retention_purge_run: storage::DataRetentionPurgeRunNew,
+    ) -> CustomResult<storage::DataRetentionPurgeRun, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_data_retention_purge_runs(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::DataRetentionPurgeRun>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn count_connector_audit_logs_created_before(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<i64, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_connector_audit_logs_created_before(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "v1")]
+    async fn list_inactive_customers(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _modified_before: time::PrimitiveDateTime,
+        _redacted_description: common_utils::types::Description,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::Customer>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl DataRetentionInterface for KafkaStore {
+    async fn insert_data_retention_policy(
+        &self,
+        data_retention_policy: storage::DataRetentionPolicyNew,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError> {
+        self.diesel_store
+            .insert_data_retention_policy(data_retention_policy)
+            .await
+    }
+
+    async fn find_optional_data_retention_policy(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Option<storage::DataRetentionPolicy>, errors::StorageError> {
+        self.diesel_store
+            .find_optional_data_retention_policy(merchant_id)
+            .await
+    }
+
+    async fn update_data_retention_policy(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        data_retention_policy_update: storage::DataRetentionPolicyUpdate,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError> {
+        self.diesel_store
+            .update_data_retention_policy(merchant_id, data_retention_policy_update)
+            .await
+    }
+
+    async fn insert_data_retention_purge_run(
+        &self,
+        data_retention_purge_run: storage::DataRetentionPurgeRunNew,
+    ) -> CustomResult<storage::DataRetentionPurgeRun, errors::StorageError> {
+        self.diesel_store
+            .insert_data_retention_purge_run(data_retention_purge_run)
+            .await
+    }
+
+    async fn list_data_retention_purge_runs(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::DataRetentionPurgeRun>, errors::StorageError> {
+        self.diesel_store
+            .list_data_retention_purge_runs(merchant_id, limit, offset)
+            .await
+    }
+
+    async fn count_connector_audit_logs_created_before(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<i64, errors::StorageError> {
+        self.diesel_store
+            .count_connector_audit_logs_created_before(merchant_id, created_before)
+            .await
+    }
+
+    async fn delete_connector_audit_logs_created_before(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .delete_connector_audit_logs_created_before(merchant_id, created_before)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn list_inactive_customers(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        modified_before: time::PrimitiveDateTime,
+        redacted_description: common_utils::types::Description,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::Customer>, errors::StorageError> {
+        self.diesel_store
+            .list_inactive_customers(
+                merchant_id,
+                modified_before,
+                redacted_description,
+                limit,
+                offset,
+            )
+            .await
+    }
+}
@@ -107,7 +107,7 @@ impl DisputeInterface for Store {
         merchant_id: &common_utils::id_type::MerchantId,
         dispute_constraints: &disputes::DisputeListConstraints,
     ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
+        let conn = connection::pg_connection_read_replica(self).await?;
         storage::Dispute::filter_by_constraints(&conn, merchant_id, dispute_constraints)
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
@@ -144,7 +144,7 @@ impl DisputeInterface for Store {
         profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
         time_range: &common_utils::types::TimeRange,
     ) -> CustomResult<Vec<(common_enums::DisputeStatus, i64)>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
+        let conn = connection::pg_connection_read_replica(self).await?;
         storage::Dispute::get_dispute_status_with_count(
             &conn,
             merchant_id,
@@ -200,6 +200,8 @@ impl DisputeInterface for MockDb {
             dispute_amount: dispute.dispute_amount,
             organization_id: dispute.organization_id,
             dispute_currency: dispute.dispute_currency,
+            automated_action: dispute.automated_action,
+            evidence_submitted_at: None,
         };
 
         locked_disputes.push(new_dispute.clone());
@@ -251,11 +253,15 @@ impl DisputeInterface for MockDb {
             .unwrap_or(u32::MAX)
             .try_into()
             .unwrap_or(usize::MAX);
-        let offset_usize = dispute_constraints
-            .offset
-            .unwrap_or(0)
-            .try_into()
-            .unwrap_or(usize::MIN);
+        let offset_usize = if dispute_constraints.cursor.is_some() {
+            0
+        } else {
+            dispute_constraints
+                .offset
+                .unwrap_or(0)
+                .try_into()
+                .unwrap_or(usize::MIN)
+        };
         let filtered_disputes: Vec<storage::Dispute> = locked_disputes
             .iter()
             .filter(|dispute| {
@@ -321,6 +327,10 @@ impl DisputeInterface for MockDb {
                                 .end_time
                                 .is_none_or(|end_time| dispute_time <= end_time)
                     })
+                    && dispute_constraints.cursor.as_ref().is_none_or(|cursor| {
+                        (dispute.modified_at, dispute.dispute_id.as_str())
+                            < (cursor.sort_key, cursor.id.as_str())
+                    })
             })
             .skip(offset_usize)
             .take(limit_usize)
@@ -397,9 +407,23 @@ impl DisputeInterface for MockDb {
                 }
                 dispute_to_update.dispute_status = dispute_status;
             }
+            storage::DisputeUpdate::EvidenceSubmissionUpdate {
+                dispute_status,
+                connector_status,
+                evidence_submitted_at,
+            } => {
+                if let Some(status) = connector_status {
+                    dispute_to_update.connector_status = status;
+                }
+                dispute_to_update.dispute_status = dispute_status;
+                dispute_to_update.evidence_submitted_at = Some(evidence_submitted_at);
+            }
             storage::DisputeUpdate::EvidenceUpdate { evidence } => {
                 dispute_to_update.evidence = evidence;
             }
+            storage::DisputeUpdate::AutomatedActionUpdate { automated_action } => {
+                dispute_to_update.automated_action = Some(automated_action);
+            }
         }
 
         dispute_to_update.modified_at = now;
@@ -507,6 +531,7 @@ mod tests {
                 dispute_amount: MinorUnit::new(1040),
                 organization_id: common_utils::id_type::OrganizationId::default(),
                 dispute_currency: Some(Currency::default()),
+                automated_action: None,
             }
         }
 
@@ -690,6 +715,7 @@ mod tests {
                         dispute_stage: None,
                         reason: None,
                         time_range: None,
+                        cursor: None,
                     },
                 )
                 .await
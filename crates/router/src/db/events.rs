@@ -116,6 +116,14 @@ where
         event_types: HashSet<common_enums::EventType>,
         is_delivered: Option<bool>,
     ) -> CustomResult<i64, errors::StorageError>;
+
+    async fn get_event_delivery_health_by_profile_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::EventDeliveryHealth, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -431,6 +439,26 @@ impl EventInterface for Store {
         .await
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn get_event_delivery_health_by_profile_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::EventDeliveryHealth, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::get_delivery_health_by_profile_id(
+            &conn,
+            merchant_id,
+            profile_id,
+            created_after,
+            created_before,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -786,9 +814,11 @@ impl EventInterface for MockDb {
             domain::EventUpdate::UpdateResponse {
                 is_webhook_notified,
                 response,
+                delivery_latency_in_ms,
             } => {
                 event_to_update.is_webhook_notified = is_webhook_notified;
                 event_to_update.response = response.map(Into::into);
+                event_to_update.delivery_latency_in_ms = delivery_latency_in_ms;
             }
             domain::EventUpdate::OverallDeliveryStatusUpdate {
                 is_overall_delivery_successful,
@@ -796,6 +826,9 @@ impl EventInterface for MockDb {
                 event_to_update.is_overall_delivery_successful =
                     Some(is_overall_delivery_successful)
             }
+            domain::EventUpdate::DeadLetterUpdate { dead_lettered_at } => {
+                event_to_update.dead_lettered_at = Some(dead_lettered_at)
+            }
         }
 
         event_to_update
@@ -838,6 +871,65 @@ impl EventInterface for MockDb {
             .change_context(errors::StorageError::MockDbError)
             .attach_printable("Failed to convert usize to i64")
     }
+
+    async fn get_event_delivery_health_by_profile_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::EventDeliveryHealth, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+
+        let events = locked_events
+            .iter()
+            .filter(|event| {
+                event.merchant_id == Some(merchant_id.to_owned())
+                    && event.business_profile_id == Some(profile_id.to_owned())
+                    && (event.created_at >= created_after)
+                    && (event.created_at <= created_before)
+            })
+            .collect::<Vec<_>>();
+
+        let initial_events = events
+            .iter()
+            .filter(|event| event.initial_attempt_id.as_ref() == Some(&event.event_id))
+            .collect::<Vec<_>>();
+        let count = |predicate: fn(&storage::Event) -> bool| {
+            i64::try_from(
+                initial_events
+                    .iter()
+                    .filter(|event| predicate(event))
+                    .count(),
+            )
+            .change_context(errors::StorageError::MockDbError)
+            .attach_printable("Failed to convert usize to i64")
+        };
+
+        let mut delivery_latencies = events
+            .iter()
+            .filter_map(|event| event.delivery_latency_in_ms)
+            .collect::<Vec<_>>();
+        delivery_latencies.sort_unstable();
+        // Nearest-rank percentile, which approximates the interpolated percentile computed by the
+        // database
+        let p95_delivery_latency_in_ms = delivery_latencies
+            .len()
+            .checked_sub(1)
+            .and_then(|last_index| delivery_latencies.get(last_index * 95 / 100))
+            .map(|latency| f64::from(*latency));
+
+        Ok(storage::EventDeliveryHealth {
+            total_events: count(|_| true)?,
+            delivered_events: count(|event| event.is_overall_delivery_successful == Some(true))?,
+            pending_retry_events: count(|event| {
+                event.is_overall_delivery_successful != Some(true)
+                    && event.dead_lettered_at.is_none()
+            })?,
+            dead_lettered_events: count(|event| event.dead_lettered_at.is_some())?,
+            p95_delivery_latency_in_ms,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -967,6 +1059,9 @@ mod tests {
                         .unwrap(),
                     }),
                     is_overall_delivery_successful: Some(false),
+                    dead_lettered_at: None,
+                    webhook_endpoint_id: None,
+                    delivery_latency_in_ms: None,
                 },
                 &merchant_key_store,
             )
@@ -983,6 +1078,7 @@ mod tests {
                 domain::EventUpdate::UpdateResponse {
                     is_webhook_notified: true,
                     response: None,
+                    delivery_latency_in_ms: None,
                 },
                 &merchant_key_store,
             )
@@ -1084,6 +1180,9 @@ mod tests {
                         .unwrap(),
                     }),
                     is_overall_delivery_successful: Some(false),
+                    dead_lettered_at: None,
+                    webhook_endpoint_id: None,
+                    delivery_latency_in_ms: None,
                 },
                 &merchant_key_store,
             )
@@ -1100,6 +1199,7 @@ mod tests {
                 domain::EventUpdate::UpdateResponse {
                     is_webhook_notified: true,
                     response: None,
+                    delivery_latency_in_ms: None,
                 },
                 &merchant_key_store,
             )
@@ -1182,6 +1282,7 @@ mod tests {
                 payment_statuses_enabled: None,
                 refund_statuses_enabled: None,
                 payout_statuses_enabled: None,
+                webhook_endpoints: None,
             }),
             sub_merchants_enabled: None,
             parent_merchant_id: None,
@@ -1209,6 +1310,7 @@ mod tests {
             merchant_account_type: common_enums::MerchantAccountType::Standard,
             product_type: None,
             version: common_enums::ApiVersion::V1,
+            allowed_ip_ranges: None,
         });
         let merchant_account = state
             .store
@@ -1249,6 +1351,7 @@ mod tests {
                 payment_statuses_enabled: None,
                 refund_statuses_enabled: None,
                 payout_statuses_enabled: None,
+                webhook_endpoints: None,
             }),
             metadata: None,
             routing_algorithm: None,
@@ -1291,6 +1394,14 @@ mod tests {
             dispute_polling_interval: None,
             is_manual_retry_enabled: None,
             always_enable_overcapture: None,
+            retry_policy: None,
+            intent_expiry: None,
+            refund_approval_threshold: None,
+            webhook_retry_policy: None,
+            event_sink_config: None,
+            is_connector_audit_capture_enabled: None,
+            soft_decline_retry_config: None,
+            webhook_endpoint_secrets: None,
         });
 
         let business_profile = state
@@ -1409,6 +1520,8 @@ mod tests {
             is_overcapture_enabled: None,
             enable_overcapture: None,
             network_details: None,
+            dcc_details: None,
+            device_fingerprint: None,
         };
         let content =
             api_webhooks::OutgoingWebhookContent::PaymentDetails(Box::new(expected_response));
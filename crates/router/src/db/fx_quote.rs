@@ -0,0 +1,136 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait FxQuoteInterface {
+    async fn insert_fx_quote(
+        &self,
+        fx_quote: storage::FxQuoteNew,
+    ) -> CustomResult<storage::FxQuote, errors::StorageError>;
+
+    async fn find_fx_quote_by_merchant_id_quote_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        quote_id: &str,
+    ) -> CustomResult<storage::FxQuote, errors::StorageError>;
+
+    /// Marks a quote as used by a payment, failing with `ValueNotFound` if it was already used
+    async fn update_unused_fx_quote_by_merchant_id_quote_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        quote_id: &str,
+        fx_quote_update: storage::FxQuoteUpdate,
+    ) -> CustomResult<storage::FxQuote, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl FxQuoteInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_fx_quote(
+        &self,
+        fx_quote: storage::FxQuoteNew,
+    ) -> CustomResult<storage::FxQuote, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        fx_quote
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_fx_quote_by_merchant_id_quote_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        quote_id: &str,
+    ) -> CustomResult<storage::FxQuote, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::FxQuote::find_by_merchant_id_quote_id(&conn, merchant_id, quote_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_unused_fx_quote_by_merchant_id_quote_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        quote_id: &str,
+        fx_quote_update: storage::FxQuoteUpdate,
+    ) -> CustomResult<storage::FxQuote, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::FxQuote::update_unused_by_merchant_id_quote_id(
+            &conn,
+            merchant_id,
+            quote_id,
+            fx_quote_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl FxQuoteInterface for MockDb {
+    async fn insert_fx_quote(
+        &self,
+        _fx_quote: storage::FxQuoteNew,
+    ) -> CustomResult<storage::FxQuote, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_fx_quote_by_merchant_id_quote_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _quote_id: &str,
+    ) -> CustomResult<storage::FxQuote, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_unused_fx_quote_by_merchant_id_quote_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _quote_id: &str,
+        _fx_quote_update: storage::FxQuoteUpdate,
+    ) -> CustomResult<storage::FxQuote, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl FxQuoteInterface for KafkaStore {
+    async fn insert_fx_quote(
+        &self,
+        fx_quote: storage::FxQuoteNew,
+    ) -> CustomResult<storage::FxQuote, errors::StorageError> {
+        self.diesel_store.insert_fx_quote(fx_quote).await
+    }
+
+    async fn find_fx_quote_by_merchant_id_quote_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        quote_id: &str,
+    ) -> CustomResult<storage::FxQuote, errors::StorageError> {
+        self.diesel_store
+            .find_fx_quote_by_merchant_id_quote_id(merchant_id, quote_id)
+            .await
+    }
+
+    async fn update_unused_fx_quote_by_merchant_id_quote_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        quote_id: &str,
+        fx_quote_update: storage::FxQuoteUpdate,
+    ) -> CustomResult<storage::FxQuote, errors::StorageError> {
+        self.diesel_store
+            .update_unused_fx_quote_by_merchant_id_quote_id(merchant_id, quote_id, fx_quote_update)
+            .await
+    }
+}
@@ -37,7 +37,9 @@ use scheduler::{
     SchedulerInterface,
 };
 use serde::Serialize;
-use storage_impl::{config::TenantConfig, redis::kv_store::RedisConnInterface};
+use storage_impl::{
+    config::TenantConfig, database::store::DynamicPgPool, redis::kv_store::RedisConnInterface,
+};
 use time::PrimitiveDateTime;
 
 use super::{
@@ -48,6 +50,7 @@ use super::{
     user::{sample_data::BatchSampleDataInterface, theme::ThemeInterface, UserInterface},
     user_authentication_method::UserAuthenticationMethodInterface,
     user_key_store::UserKeyStoreInterface,
+    user_passkey::UserPasskeyInterface,
     user_role::{ListUserRolesByOrgIdPayload, ListUserRolesByUserIdPayload, UserRoleInterface},
 };
 #[cfg(feature = "payouts")]
@@ -908,6 +911,23 @@ impl EventInterface for KafkaStore {
             )
             .await
     }
+
+    async fn get_event_delivery_health_by_profile_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        created_after: PrimitiveDateTime,
+        created_before: PrimitiveDateTime,
+    ) -> CustomResult<storage::EventDeliveryHealth, errors::StorageError> {
+        self.diesel_store
+            .get_event_delivery_health_by_profile_id(
+                merchant_id,
+                profile_id,
+                created_after,
+                created_before,
+            )
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -1051,6 +1071,21 @@ impl PaymentLinkInterface for KafkaStore {
             .list_payment_link_by_merchant_id(merchant_id, payment_link_constraints)
             .await
     }
+
+    async fn update_payment_link_by_merchant_id_payment_link_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_link_id: &str,
+        payment_link_update: storage::PaymentLinkUpdate,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        self.diesel_store
+            .update_payment_link_by_merchant_id_payment_link_id(
+                merchant_id,
+                payment_link_id,
+                payment_link_update,
+            )
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -3319,6 +3354,10 @@ impl StorageInterface for KafkaStore {
     fn get_cache_store(&self) -> Box<(dyn RedisConnInterface + Send + Sync + 'static)> {
         Box::new(self.clone())
     }
+
+    fn get_database_pools(&self) -> Vec<DynamicPgPool> {
+        self.diesel_store.get_database_pools()
+    }
 }
 
 impl GlobalStorageInterface for KafkaStore {
@@ -4128,6 +4167,56 @@ impl UserAuthenticationMethodInterface for KafkaStore {
     }
 }
 
+#[async_trait::async_trait]
+impl UserPasskeyInterface for KafkaStore {
+    async fn insert_user_passkey(
+        &self,
+        user_passkey: storage::UserPasskeyNew,
+    ) -> CustomResult<storage::UserPasskey, errors::StorageError> {
+        self.diesel_store.insert_user_passkey(user_passkey).await
+    }
+
+    async fn find_user_passkey_by_user_id_passkey_id(
+        &self,
+        user_id: &str,
+        passkey_id: &str,
+    ) -> CustomResult<storage::UserPasskey, errors::StorageError> {
+        self.diesel_store
+            .find_user_passkey_by_user_id_passkey_id(user_id, passkey_id)
+            .await
+    }
+
+    async fn list_user_passkeys_by_user_id(
+        &self,
+        user_id: &str,
+    ) -> CustomResult<Vec<storage::UserPasskey>, errors::StorageError> {
+        self.diesel_store
+            .list_user_passkeys_by_user_id(user_id)
+            .await
+    }
+
+    async fn update_user_passkey_by_user_id_passkey_id(
+        &self,
+        user_id: &str,
+        passkey_id: &str,
+        user_passkey_update: storage::UserPasskeyUpdate,
+    ) -> CustomResult<storage::UserPasskey, errors::StorageError> {
+        self.diesel_store
+            .update_user_passkey_by_user_id_passkey_id(user_id, passkey_id, user_passkey_update)
+            .await
+    }
+
+    async fn delete_user_passkey_by_user_id_passkey_id(
+        &self,
+        user_id: &str,
+        passkey_id: &str,
+    ) -> CustomResult<storage::UserPasskey, errors::StorageError> {
+        self.diesel_store
+            .delete_user_passkey_by_user_id_passkey_id(user_id, passkey_id)
+            .await
+    }
+}
+
 #[async_trait::async_trait]
 impl HyperswitchAiInteractionInterface for KafkaStore {
     async fn insert_hyperswitch_ai_interaction(
@@ -0,0 +1,211 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait ListViewInterface {
+    async fn insert_list_view(
+        &self,
+        list_view: storage::ListViewNew,
+    ) -> CustomResult<storage::ListView, errors::StorageError>;
+
+    async fn find_list_view_by_merchant_id_view_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        view_id: &str,
+    ) -> CustomResult<storage::ListView, errors::StorageError>;
+
+    async fn list_list_views_visible_to_user(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        user_id: &str,
+        entity: Option<enums::ListViewEntity>,
+    ) -> CustomResult<Vec<storage::ListView>, errors::StorageError>;
+
+    async fn update_list_view_by_merchant_id_view_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        view_id: &str,
+        list_view_update: storage::ListViewUpdate,
+    ) -> CustomResult<storage::ListView, errors::StorageError>;
+
+    async fn delete_list_view_by_merchant_id_view_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        view_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ListViewInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_list_view(
+        &self,
+        list_view: storage::ListViewNew,
+    ) -> CustomResult<storage::ListView, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        list_view
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_list_view_by_merchant_id_view_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        view_id: &str,
+    ) -> CustomResult<storage::ListView, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ListView::find_by_merchant_id_view_id(&conn, merchant_id, view_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_list_views_visible_to_user(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        user_id: &str,
+        entity: Option<enums::ListViewEntity>,
+    ) -> CustomResult<Vec<storage::ListView>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ListView::list_visible_to_user(&conn, merchant_id, user_id, entity)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_list_view_by_merchant_id_view_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        view_id: &str,
+        list_view_update: storage::ListViewUpdate,
+    ) -> CustomResult<storage::ListView, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ListView::update_by_merchant_id_view_id(
+            &conn,
+            merchant_id,
+            view_id,
+            list_view_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_list_view_by_merchant_id_view_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        view_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ListView::delete_by_merchant_id_view_id(&conn, merchant_id, view_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ListViewInterface for MockDb {
+    async fn insert_list_view(
+        &self,
+        _list_view: storage::ListViewNew,
+    ) -> CustomResult<storage::ListView, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_list_view_by_merchant_id_view_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _view_id: &str,
+    ) -> CustomResult<storage::ListView, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_list_views_visible_to_user(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _user_id: &str,
+        _entity: Option<enums::ListViewEntity>,
+    ) -> CustomResult<Vec<storage::ListView>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_list_view_by_merchant_id_view_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _view_id: &str,
+        _list_view_update: storage::ListViewUpdate,
+    ) -> CustomResult<storage::ListView, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_list_view_by_merchant_id_view_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _view_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl ListViewInterface for KafkaStore {
+    async fn insert_list_view(
+        &self,
+        list_view: storage::ListViewNew,
+    ) -> CustomResult<storage::ListView, errors::StorageError> {
+        self.diesel_store.insert_list_view(list_view).await
+    }
+
+    async fn find_list_view_by_merchant_id_view_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        view_id: &str,
+    ) -> CustomResult<storage::ListView, errors::StorageError> {
+        self.diesel_store
+            .find_list_view_by_merchant_id_view_id(merchant_id, view_id)
+            .await
+    }
+
+    async fn list_list_views_visible_to_user(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        user_id: &str,
+        entity: Option<enums::ListViewEntity>,
+    ) -> CustomResult<Vec<storage::ListView>, errors::StorageError> {
+        self.diesel_store
+            .list_list_views_visible_to_user(merchant_id, user_id, entity)
+            .await
+    }
+
+    async fn update_list_view_by_merchant_id_view_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        view_id: &str,
+        list_view_update: storage::ListViewUpdate,
+    ) -> CustomResult<storage::ListView, errors::StorageError> {
+        self.diesel_store
+            .update_list_view_by_merchant_id_view_id(merchant_id, view_id, list_view_update)
+            .await
+    }
+
+    async fn delete_list_view_by_merchant_id_view_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        view_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_list_view_by_merchant_id_view_id(merchant_id, view_id)
+            .await
+    }
+}
@@ -1,9 +1,13 @@
-use common_utils::types::keymanager::KeyManagerState;
+use common_utils::{encryption::Encryption, types::keymanager::KeyManagerState};
 use error_stack::{report, ResultExt};
-use masking::Secret;
+use masking::{PeekInterface, Secret};
 use router_env::{instrument, tracing};
 #[cfg(feature = "accounts_cache")]
-use storage_impl::redis::cache::{self, CacheKind, ACCOUNTS_CACHE};
+use storage_impl::redis::cache::{CacheKind, ACCOUNTS_CACHE};
+use storage_impl::redis::{
+    cache::{self, CacheKey},
+    RedisConnInterface,
+};
 
 use crate::{
     connection,
@@ -96,16 +100,10 @@ impl MerchantKeyStoreInterface for Store {
         };
 
         #[cfg(not(feature = "accounts_cache"))]
-        {
-            fetch_func()
-                .await?
-                .convert(state, key, merchant_id.clone().into())
-                .await
-                .change_context(errors::StorageError::DecryptionError)
-        }
+        let merchant_key_store = fetch_func().await?;
 
         #[cfg(feature = "accounts_cache")]
-        {
+        let merchant_key_store = {
             let key_store_cache_key =
                 format!("merchant_key_store_{}", merchant_id.get_string_repr());
             cache::get_or_populate_in_memory(
@@ -115,10 +113,9 @@ impl MerchantKeyStoreInterface for Store {
                 &ACCOUNTS_CACHE,
             )
             .await?
-            .convert(state, key, merchant_id.clone().into())
-            .await
-            .change_context(errors::StorageError::DecryptionError)
-        }
+        };
+
+        convert_merchant_key_store(self, state, merchant_key_store, key).await
     }
 
     #[instrument(skip_all)]
@@ -145,9 +142,13 @@ impl MerchantKeyStoreInterface for Store {
         {
             let key_store_cache_key =
                 format!("merchant_key_store_{}", merchant_id.get_string_repr());
-            cache::publish_and_redact(
+            let data_key_cache_key = format!("data_key_{}", merchant_id.get_string_repr());
+            cache::publish_and_redact_multiple(
                 self,
-                CacheKind::Accounts(key_store_cache_key.into()),
+                [
+                    CacheKind::Accounts(key_store_cache_key.into()),
+                    CacheKind::DataKey(data_key_cache_key.into()),
+                ],
                 delete_func,
             )
             .await
@@ -208,6 +209,78 @@ impl MerchantKeyStoreInterface for Store {
     }
 }
 
+/// Merchant key store cached along with the version of the key manager key its data key was
+/// encrypted with
+#[derive(Clone)]
+struct CachedMerchantKeyStore {
+    key_version: String,
+    merchant_key_store: domain::MerchantKeyStore,
+}
+
+/// Version of the key manager key the data key was encrypted with. Data keys encrypted by the
+/// application have no version, and are not cached as decrypting them does not call the key
+/// manager.
+fn get_key_manager_key_version(encrypted_key: &Encryption) -> Option<String> {
+    std::str::from_utf8(encrypted_key.get_inner().peek())
+        .ok()?
+        .split_once(':')
+        .map(|(version, _)| version.to_string())
+}
+
+/// Decrypts the merchant key store, reusing the data key cached for the same tenant, merchant
+/// and key version so that the key manager is not called on every request
+async fn convert_merchant_key_store(
+    store: &Store,
+    state: &KeyManagerState,
+    merchant_key_store: diesel_models::merchant_key_store::MerchantKeyStore,
+    key: &Secret<Vec<u8>>,
+) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
+    let merchant_id = merchant_key_store.merchant_id.clone();
+    let cache_entry = cache::DATA_KEY_CACHE
+        .get()
+        .filter(|_| state.enabled)
+        .zip(get_key_manager_key_version(&merchant_key_store.key))
+        .zip(store.get_redis_conn().ok())
+        .map(|((data_key_cache, key_version), redis_conn)| {
+            let cache_key = CacheKey {
+                key: format!("data_key_{}", merchant_id.get_string_repr()),
+                prefix: redis_conn.key_prefix.clone(),
+            };
+            (data_key_cache, key_version, cache_key)
+        });
+
+    let Some((data_key_cache, key_version, cache_key)) = cache_entry else {
+        return merchant_key_store
+            .convert(state, key, merchant_id.into())
+            .await
+            .change_context(errors::StorageError::DecryptionError);
+    };
+
+    if let Some(cached) = data_key_cache
+        .get_val::<CachedMerchantKeyStore>(cache_key.clone())
+        .await
+        .filter(|cached| cached.key_version == key_version)
+    {
+        return Ok(cached.merchant_key_store);
+    }
+
+    let merchant_key_store = merchant_key_store
+        .convert(state, key, merchant_id.into())
+        .await
+        .change_context(errors::StorageError::DecryptionError)?;
+    data_key_cache
+        .push(
+            cache_key,
+            CachedMerchantKeyStore {
+                key_version,
+                merchant_key_store: merchant_key_store.clone(),
+            },
+        )
+        .await;
+
+    Ok(merchant_key_store)
+}
+
 #[async_trait::async_trait]
 impl MerchantKeyStoreInterface for MockDb {
     async fn insert_merchant_key_store(
@@ -443,4 +516,21 @@ mod tests {
             .await;
         assert!(find_merchant_key_with_incorrect_master_key_result.is_err());
     }
+
+    #[test]
+    fn test_key_manager_key_version_is_read_from_encrypted_key() {
+        let encrypted_by_key_manager =
+            common_utils::encryption::Encryption::new(b"v2:c2VjcmV0".to_vec().into());
+        let encrypted_by_application =
+            common_utils::encryption::Encryption::new(vec![0xff, 0x00, 0x3a].into());
+
+        assert_eq!(
+            super::get_key_manager_key_version(&encrypted_by_key_manager),
+            Some("v2".to_string())
+        );
+        assert_eq!(
+            super::get_key_manager_key_version(&encrypted_by_application),
+            None
+        );
+    }
 }
@@ -0,0 +1,156 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait MerchantSigningKeyInterface {
+    async fn insert_merchant_signing_key(
+        &self,
+        signing_key: storage::MerchantSigningKeyNew,
+    ) -> CustomResult<storage::MerchantSigningKey, errors::StorageError>;
+
+    async fn find_merchant_signing_key_by_key_id_optional(
+        &self,
+        key_id: &str,
+    ) -> CustomResult<Option<storage::MerchantSigningKey>, errors::StorageError>;
+
+    async fn list_merchant_signing_keys_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::MerchantSigningKey>, errors::StorageError>;
+
+    async fn delete_merchant_signing_key_by_merchant_id_key_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        key_id: &str,
+    ) -> CustomResult<storage::MerchantSigningKey, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl MerchantSigningKeyInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_merchant_signing_key(
+        &self,
+        signing_key: storage::MerchantSigningKeyNew,
+    ) -> CustomResult<storage::MerchantSigningKey, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        signing_key
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_merchant_signing_key_by_key_id_optional(
+        &self,
+        key_id: &str,
+    ) -> CustomResult<Option<storage::MerchantSigningKey>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::MerchantSigningKey::find_optional_by_key_id(&conn, key_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_merchant_signing_keys_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::MerchantSigningKey>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::MerchantSigningKey::list_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_merchant_signing_key_by_merchant_id_key_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        key_id: &str,
+    ) -> CustomResult<storage::MerchantSigningKey, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::MerchantSigningKey::delete_by_merchant_id_key_id(&conn, merchant_id, key_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl MerchantSigningKeyInterface for MockDb {
+    async fn insert_merchant_signing_key(
+        &self,
+        _signing_key: storage::MerchantSigningKeyNew,
+    ) -> CustomResult<storage::MerchantSigningKey, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_merchant_signing_key_by_key_id_optional(
+        &self,
+        _key_id: &str,
+    ) -> CustomResult<Option<storage::MerchantSigningKey>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_merchant_signing_keys_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::MerchantSigningKey>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_merchant_signing_key_by_merchant_id_key_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _key_id: &str,
+    ) -> CustomResult<storage::MerchantSigningKey, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl MerchantSigningKeyInterface for KafkaStore {
+    async fn insert_merchant_signing_key(
+        &self,
+        signing_key: storage::MerchantSigningKeyNew,
+    ) -> CustomResult<storage::MerchantSigningKey, errors::StorageError> {
+        self.diesel_store
+            .insert_merchant_signing_key(signing_key)
+            .await
+    }
+
+    async fn find_merchant_signing_key_by_key_id_optional(
+        &self,
+        key_id: &str,
+    ) -> CustomResult<Option<storage::MerchantSigningKey>, errors::StorageError> {
+        self.diesel_store
+            .find_merchant_signing_key_by_key_id_optional(key_id)
+            .await
+    }
+
+    async fn list_merchant_signing_keys_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::MerchantSigningKey>, errors::StorageError> {
+        self.diesel_store
+            .list_merchant_signing_keys_by_merchant_id(merchant_id)
+            .await
+    }
+
+    async fn delete_merchant_signing_key_by_merchant_id_key_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        key_id: &str,
+    ) -> CustomResult<storage::MerchantSigningKey, errors::StorageError> {
+        self.diesel_store
+            .delete_merchant_signing_key_by_merchant_id_key_id(merchant_id, key_id)
+            .await
+    }
+}
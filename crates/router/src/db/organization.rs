@@ -119,6 +119,7 @@ impl OrganizationInterface for super::MockDb {
                     organization_details,
                     metadata,
                     platform_merchant_id,
+                    is_passkey_required,
                 } => {
                     organization_name
                         .as_ref()
@@ -126,6 +127,7 @@ impl OrganizationInterface for super::MockDb {
                     organization_details.clone_into(&mut org.organization_details);
                     metadata.clone_into(&mut org.metadata);
                     platform_merchant_id.clone_into(&mut org.platform_merchant_id);
+                    is_passkey_required.clone_into(&mut org.is_passkey_required);
                     org
                 }
             })
@@ -0,0 +1,139 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentBatchInterface {
+    async fn insert_payment_batch(
+        &self,
+        payment_batch: storage::PaymentBatchNew,
+    ) -> CustomResult<storage::PaymentBatch, errors::StorageError>;
+
+    async fn find_payment_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::PaymentBatch, errors::StorageError>;
+
+    async fn update_payment_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        payment_batch_update: storage::PaymentBatchUpdate,
+    ) -> CustomResult<storage::PaymentBatch, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentBatchInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payment_batch(
+        &self,
+        payment_batch: storage::PaymentBatchNew,
+    ) -> CustomResult<storage::PaymentBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payment_batch
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::PaymentBatch, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentBatch::find_by_merchant_id_batch_id(&conn, merchant_id, batch_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payment_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        payment_batch_update: storage::PaymentBatchUpdate,
+    ) -> CustomResult<storage::PaymentBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PaymentBatch::update_by_merchant_id_batch_id(
+            &conn,
+            merchant_id,
+            batch_id,
+            payment_batch_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentBatchInterface for MockDb {
+    async fn insert_payment_batch(
+        &self,
+        _payment_batch: storage::PaymentBatchNew,
+    ) -> CustomResult<storage::PaymentBatch, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payment_batch_by_merchant_id_batch_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _batch_id: &str,
+    ) -> CustomResult<storage::PaymentBatch, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payment_batch_by_merchant_id_batch_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _batch_id: &str,
+        _payment_batch_update: storage::PaymentBatchUpdate,
+    ) -> CustomResult<storage::PaymentBatch, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentBatchInterface for KafkaStore {
+    async fn insert_payment_batch(
+        &self,
+        payment_batch: storage::PaymentBatchNew,
+    ) -> CustomResult<storage::PaymentBatch, errors::StorageError> {
+        self.diesel_store.insert_payment_batch(payment_batch).await
+    }
+
+    async fn find_payment_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::PaymentBatch, errors::StorageError> {
+        self.diesel_store
+            .find_payment_batch_by_merchant_id_batch_id(merchant_id, batch_id)
+            .await
+    }
+
+    async fn update_payment_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        payment_batch_update: storage::PaymentBatchUpdate,
+    ) -> CustomResult<storage::PaymentBatch, errors::StorageError> {
+        self.diesel_store
+            .update_payment_batch_by_merchant_id_batch_id(
+                merchant_id,
+                batch_id,
+                payment_batch_update,
+            )
+            .await
+    }
+}
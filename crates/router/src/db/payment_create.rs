@@ -0,0 +1,231 @@
+#[cfg(feature = "v1")]
+use common_utils::types::keymanager::KeyManagerState;
+#[cfg(feature = "v1")]
+use diesel_models::enums::MerchantStorageScheme;
+#[cfg(feature = "v1")]
+use error_stack::ResultExt;
+#[cfg(feature = "v1")]
+use futures::future::try_join_all;
+#[cfg(feature = "v1")]
+use hyperswitch_domain_models::payments::{
+    payment_attempt::PaymentAttemptInterface, payment_intent::PaymentIntentInterface,
+};
+#[cfg(feature = "v1")]
+use router_env::{instrument, logger, tracing};
+#[cfg(feature = "v1")]
+use storage_impl::DataModelExt;
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::db::kafka_store::KafkaStore;
+#[cfg(feature = "v1")]
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::address::AddressInterface,
+    types::{
+        domain::{self, behaviour::Conversion},
+        storage,
+    },
+};
+
+#[async_trait::async_trait]
+pub trait PaymentCreateInterface {
+    /// Inserts the payment intent and attempt of a new payment along with its addresses. For
+    /// merchants on the `PostgresOnly` storage scheme, the rows are inserted in a single
+    /// transaction on a single connection, with the addresses inserted in a single statement.
+    #[cfg(feature = "v1")]
+    async fn insert_payment_with_addresses(
+        &self,
+        state: &KeyManagerState,
+        payment_intent: storage::PaymentIntent,
+        payment_attempt: storage::PaymentAttemptNew,
+        addresses: Vec<domain::PaymentAddress>,
+        key_store: &domain::MerchantKeyStore,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<(storage::PaymentIntent, storage::PaymentAttempt), errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentCreateInterface for Store {
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn insert_payment_with_addresses(
+        &self,
+        state: &KeyManagerState,
+        payment_intent: storage::PaymentIntent,
+        payment_attempt: storage::PaymentAttemptNew,
+        addresses: Vec<domain::PaymentAddress>,
+        key_store: &domain::MerchantKeyStore,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<(storage::PaymentIntent, storage::PaymentAttempt), errors::StorageError> {
+        // The rows of the merchants on the KV storage scheme are written to redis, and drained to
+        // the database later on
+        if storage_scheme != MerchantStorageScheme::PostgresOnly {
+            return insert_payment_sequentially(
+                self,
+                state,
+                payment_intent,
+                payment_attempt,
+                addresses,
+                key_store,
+                storage_scheme,
+            )
+            .await;
+        }
+
+        let addresses_new = try_join_all(addresses.into_iter().map(|address| async {
+            address
+                .construct_new()
+                .await
+                .change_context(errors::StorageError::EncryptionError)
+        }))
+        .await?;
+        let payment_intent_new = payment_intent
+            .construct_new()
+            .await
+            .change_context(errors::StorageError::EncryptionError)?;
+        let payment_attempt_new = payment_attempt.to_storage_model();
+
+        let conn = connection::pg_connection_write(self).await?;
+        let (payment_intent, payment_attempt) = conn
+            .transaction_async(|conn| async move {
+                if !addresses_new.is_empty() {
+                    storage::AddressNew::batch_insert(&conn, addresses_new)
+                        .await
+                        .map_err(errors::StorageError::from)?;
+                }
+                let payment_intent = payment_intent_new
+                    .insert(&conn)
+                    .await
+                    .map_err(errors::StorageError::from)?;
+                let payment_attempt = payment_attempt_new
+                    .insert(&conn)
+                    .await
+                    .map_err(errors::StorageError::from)?;
+                Ok::<_, errors::StorageError>((payment_intent, payment_attempt))
+            })
+            .await?;
+
+        let payment_intent = storage::PaymentIntent::convert_back(
+            state,
+            payment_intent,
+            key_store.key.get_inner(),
+            key_store.merchant_id.clone().into(),
+        )
+        .await
+        .change_context(errors::StorageError::DecryptionError)?;
+
+        Ok((
+            payment_intent,
+            storage::PaymentAttempt::from_storage_model(payment_attempt),
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentCreateInterface for MockDb {
+    #[cfg(feature = "v1")]
+    async fn insert_payment_with_addresses(
+        &self,
+        state: &KeyManagerState,
+        payment_intent: storage::PaymentIntent,
+        payment_attempt: storage::PaymentAttemptNew,
+        addresses: Vec<domain::PaymentAddress>,
+        key_store: &domain::MerchantKeyStore,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<(storage::PaymentIntent, storage::PaymentAttempt), errors::StorageError> {
+        insert_payment_sequentially(
+            self,
+            state,
+            payment_intent,
+            payment_attempt,
+            addresses,
+            key_store,
+            storage_scheme,
+        )
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentCreateInterface for KafkaStore {
+    #[cfg(feature = "v1")]
+    async fn insert_payment_with_addresses(
+        &self,
+        state: &KeyManagerState,
+        payment_intent: storage::PaymentIntent,
+        payment_attempt: storage::PaymentAttemptNew,
+        addresses: Vec<domain::PaymentAddress>,
+        key_store: &domain::MerchantKeyStore,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<(storage::PaymentIntent, storage::PaymentAttempt), errors::StorageError> {
+        let (payment_intent, payment_attempt) = self
+            .diesel_store
+            .insert_payment_with_addresses(
+                state,
+                payment_intent,
+                payment_attempt,
+                addresses,
+                key_store,
+                storage_scheme,
+            )
+            .await?;
+
+        if let Err(er) = self
+            .kafka_producer
+            .log_payment_intent(
+                &payment_intent,
+                None,
+                self.tenant_id.clone(),
+                state.infra_values.clone(),
+            )
+            .await
+        {
+            logger::error!(message="Failed to add analytics entry for Payment Intent {payment_intent:?}", error_message=?er);
+        };
+
+        if let Err(er) = self
+            .kafka_producer
+            .log_payment_attempt(&payment_attempt, None, self.tenant_id.clone())
+            .await
+        {
+            logger::error!(message="Failed to log analytics event for payment attempt {payment_attempt:?}", error_message=?er)
+        }
+
+        Ok((payment_intent, payment_attempt))
+    }
+}
+
+/// Inserts the addresses, the payment intent and the payment attempt one after the other, through
+/// the storage scheme of each of them
+#[cfg(feature = "v1")]
+async fn insert_payment_sequentially<T>(
+    db: &T,
+    state: &KeyManagerState,
+    payment_intent: storage::PaymentIntent,
+    payment_attempt: storage::PaymentAttemptNew,
+    addresses: Vec<domain::PaymentAddress>,
+    key_store: &domain::MerchantKeyStore,
+    storage_scheme: MerchantStorageScheme,
+) -> CustomResult<(storage::PaymentIntent, storage::PaymentAttempt), errors::StorageError>
+where
+    T: AddressInterface
+        + PaymentIntentInterface<Error = errors::StorageError>
+        + PaymentAttemptInterface<Error = errors::StorageError>
+        + Sync,
+{
+    for address in addresses {
+        let payment_id = address.payment_id.clone();
+        db.insert_address_for_payments(state, &payment_id, address, key_store, storage_scheme)
+            .await?;
+    }
+    let payment_intent = db
+        .insert_payment_intent(state, payment_intent, key_store, storage_scheme)
+        .await?;
+    let payment_attempt = db
+        .insert_payment_attempt(payment_attempt, storage_scheme)
+        .await?;
+
+    Ok((payment_intent, payment_attempt))
+}
@@ -0,0 +1,200 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentMethodHealthInterface {
+    async fn insert_payment_method_health(
+        &self,
+        payment_method_health: storage::PaymentMethodHealthNew,
+    ) -> CustomResult<storage::PaymentMethodHealth, errors::StorageError>;
+
+    async fn find_optional_payment_method_health(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_id: &str,
+    ) -> CustomResult<Option<storage::PaymentMethodHealth>, errors::StorageError>;
+
+    async fn update_payment_method_health(
+        &self,
+        this: storage::PaymentMethodHealth,
+        payment_method_health_update: storage::PaymentMethodHealthUpdate,
+    ) -> CustomResult<storage::PaymentMethodHealth, errors::StorageError>;
+
+    #[allow(clippy::too_many_arguments)]
+    async fn filter_payment_method_health_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: Option<common_utils::id_type::CustomerId>,
+        card_expires_before: Option<time::PrimitiveDateTime>,
+        min_consecutive_failures: Option<i32>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethodHealth>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentMethodHealthInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payment_method_health(
+        &self,
+        payment_method_health: storage::PaymentMethodHealthNew,
+    ) -> CustomResult<storage::PaymentMethodHealth, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payment_method_health
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_optional_payment_method_health(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_id: &str,
+    ) -> CustomResult<Option<storage::PaymentMethodHealth>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentMethodHealth::find_optional_by_merchant_id_payment_method_id(
+            &conn,
+            merchant_id,
+            payment_method_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payment_method_health(
+        &self,
+        this: storage::PaymentMethodHealth,
+        payment_method_health_update: storage::PaymentMethodHealthUpdate,
+    ) -> CustomResult<storage::PaymentMethodHealth, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, payment_method_health_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn filter_payment_method_health_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: Option<common_utils::id_type::CustomerId>,
+        card_expires_before: Option<time::PrimitiveDateTime>,
+        min_consecutive_failures: Option<i32>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethodHealth>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentMethodHealth::filter_by_merchant_id(
+            &conn,
+            merchant_id,
+            customer_id,
+            card_expires_before,
+            min_consecutive_failures,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentMethodHealthInterface for MockDb {
+    async fn insert_payment_method_health(
+        &self,
+        _payment_method_health: storage::PaymentMethodHealthNew,
+    ) -> CustomResult<storage::PaymentMethodHealth, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_optional_payment_method_health(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_method_id: &str,
+    ) -> CustomResult<Option<storage::PaymentMethodHealth>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payment_method_health(
+        &self,
+        _this: storage::PaymentMethodHealth,
+        _payment_method_health_update: storage::PaymentMethodHealthUpdate,
+    ) -> CustomResult<storage::PaymentMethodHealth, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn filter_payment_method_health_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _customer_id: Option<common_utils::id_type::CustomerId>,
+        _card_expires_before: Option<time::PrimitiveDateTime>,
+        _min_consecutive_failures: Option<i32>,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethodHealth>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentMethodHealthInterface for KafkaStore {
+    async fn insert_payment_method_health(
+        &self,
+        payment_method_health: storage::PaymentMethodHealthNew,
+    ) -> CustomResult<storage::PaymentMethodHealth, errors::StorageError> {
+        self.diesel_store
+            .insert_payment_method_health(payment_method_health)
+            .await
+    }
+
+    async fn find_optional_payment_method_health(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_id: &str,
+    ) -> CustomResult<Option<storage::PaymentMethodHealth>, errors::StorageError> {
+        self.diesel_store
+            .find_optional_payment_method_health(merchant_id, payment_method_id)
+            .await
+    }
+
+    async fn update_payment_method_health(
+        &self,
+        this: storage::PaymentMethodHealth,
+        payment_method_health_update: storage::PaymentMethodHealthUpdate,
+    ) -> CustomResult<storage::PaymentMethodHealth, errors::StorageError> {
+        self.diesel_store
+            .update_payment_method_health(this, payment_method_health_update)
+            .await
+    }
+
+    async fn filter_payment_method_health_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: Option<common_utils::id_type::CustomerId>,
+        card_expires_before: Option<time::PrimitiveDateTime>,
+        min_consecutive_failures: Option<i32>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethodHealth>, errors::StorageError> {
+        self.diesel_store
+            .filter_payment_method_health_by_merchant_id(
+                merchant_id,
+                customer_id,
+                card_expires_before,
+                min_consecutive_failures,
+                limit,
+                offset,
+            )
+            .await
+    }
+}
@@ -0,0 +1,221 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait PaymentReviewInterface {
+    async fn insert_payment_review(
+        &self,
+        payment_review: storage::PaymentReviewNew,
+    ) -> CustomResult<storage::PaymentReview, errors::StorageError>;
+
+    async fn find_pending_payment_review_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<storage::PaymentReview, errors::StorageError>;
+
+    async fn update_payment_review_by_merchant_id_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        id: &str,
+        payment_review_update: storage::PaymentReviewUpdate,
+    ) -> CustomResult<storage::PaymentReview, errors::StorageError>;
+
+    #[allow(clippy::too_many_arguments)]
+    async fn filter_payment_reviews_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        status: Option<enums::PaymentReviewStatus>,
+        source: Option<enums::PaymentReviewSource>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::PaymentReview>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentReviewInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payment_review(
+        &self,
+        payment_review: storage::PaymentReviewNew,
+    ) -> CustomResult<storage::PaymentReview, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payment_review
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_pending_payment_review_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<storage::PaymentReview, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentReview::find_pending_by_merchant_id_payment_id(
+            &conn,
+            merchant_id,
+            payment_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payment_review_by_merchant_id_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        id: &str,
+        payment_review_update: storage::PaymentReviewUpdate,
+    ) -> CustomResult<storage::PaymentReview, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PaymentReview::update_by_merchant_id_id(
+            &conn,
+            merchant_id,
+            id,
+            payment_review_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn filter_payment_reviews_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        status: Option<enums::PaymentReviewStatus>,
+        source: Option<enums::PaymentReviewSource>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::PaymentReview>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentReview::filter_by_merchant_id(
+            &conn,
+            merchant_id,
+            profile_id,
+            status,
+            source,
+            created_after,
+            created_before,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentReviewInterface for MockDb {
+    async fn insert_payment_review(
+        &self,
+        _payment_review: storage::PaymentReviewNew,
+    ) -> CustomResult<storage::PaymentReview, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_pending_payment_review_by_merchant_id_payment_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<storage::PaymentReview, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payment_review_by_merchant_id_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _id: &str,
+        _payment_review_update: storage::PaymentReviewUpdate,
+    ) -> CustomResult<storage::PaymentReview, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn filter_payment_reviews_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _profile_id: Option<common_utils::id_type::ProfileId>,
+        _status: Option<enums::PaymentReviewStatus>,
+        _source: Option<enums::PaymentReviewSource>,
+        _created_after: Option<time::PrimitiveDateTime>,
+        _created_before: Option<time::PrimitiveDateTime>,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::PaymentReview>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentReviewInterface for KafkaStore {
+    async fn insert_payment_review(
+        &self,
+        payment_review: storage::PaymentReviewNew,
+    ) -> CustomResult<storage::PaymentReview, errors::StorageError> {
+        self.diesel_store
+            .insert_payment_review(payment_review)
+            .await
+    }
+
+    async fn find_pending_payment_review_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<storage::PaymentReview, errors::StorageError> {
+        self.diesel_store
+            .find_pending_payment_review_by_merchant_id_payment_id(merchant_id, payment_id)
+            .await
+    }
+
+    async fn update_payment_review_by_merchant_id_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        id: &str,
+        payment_review_update: storage::PaymentReviewUpdate,
+    ) -> CustomResult<storage::PaymentReview, errors::StorageError> {
+        self.diesel_store
+            .update_payment_review_by_merchant_id_id(merchant_id, id, payment_review_update)
+            .await
+    }
+
+    async fn filter_payment_reviews_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        status: Option<enums::PaymentReviewStatus>,
+        source: Option<enums::PaymentReviewSource>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::PaymentReview>, errors::StorageError> {
+        self.diesel_store
+            .filter_payment_reviews_by_merchant_id(
+                merchant_id,
+                profile_id,
+                status,
+                source,
+                created_after,
+                created_before,
+                limit,
+                offset,
+            )
+            .await
+    }
+}
@@ -0,0 +1,135 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PayoutBatchInterface {
+    async fn insert_payout_batch(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn update_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payout_batch(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payout_batch
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatch::find_by_merchant_id_batch_id(&conn, merchant_id, batch_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PayoutBatch::update_by_merchant_id_batch_id(
+            &conn,
+            merchant_id,
+            batch_id,
+            payout_batch_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for MockDb {
+    async fn insert_payout_batch(
+        &self,
+        _payout_batch: storage::PayoutBatchNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payout_batch_by_merchant_id_batch_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _batch_id: &str,
+        _payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for KafkaStore {
+    async fn insert_payout_batch(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store.insert_payout_batch(payout_batch).await
+    }
+
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store
+            .find_payout_batch_by_merchant_id_batch_id(merchant_id, batch_id)
+            .await
+    }
+
+    async fn update_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store
+            .update_payout_batch_by_merchant_id_batch_id(merchant_id, batch_id, payout_batch_update)
+            .await
+    }
+}
@@ -0,0 +1,235 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PayoutBeneficiaryInterface {
+    async fn insert_payout_beneficiary(
+        &self,
+        payout_beneficiary: storage::PayoutBeneficiaryNew,
+    ) -> CustomResult<storage::PayoutBeneficiary, errors::StorageError>;
+
+    async fn find_payout_beneficiary_by_merchant_id_beneficiary_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        beneficiary_id: &str,
+    ) -> CustomResult<storage::PayoutBeneficiary, errors::StorageError>;
+
+    async fn list_payout_beneficiaries_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: Option<&common_utils::id_type::CustomerId>,
+        limit: i64,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBeneficiary>, errors::StorageError>;
+
+    async fn update_payout_beneficiary_by_merchant_id_beneficiary_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        beneficiary_id: &str,
+        payout_beneficiary_update: storage::PayoutBeneficiaryUpdate,
+    ) -> CustomResult<storage::PayoutBeneficiary, errors::StorageError>;
+
+    async fn delete_payout_beneficiary_by_merchant_id_beneficiary_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        beneficiary_id: &str,
+    ) -> CustomResult<storage::PayoutBeneficiary, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PayoutBeneficiaryInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payout_beneficiary(
+        &self,
+        payout_beneficiary: storage::PayoutBeneficiaryNew,
+    ) -> CustomResult<storage::PayoutBeneficiary, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payout_beneficiary
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_beneficiary_by_merchant_id_beneficiary_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        beneficiary_id: &str,
+    ) -> CustomResult<storage::PayoutBeneficiary, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBeneficiary::find_by_merchant_id_beneficiary_id(
+            &conn,
+            merchant_id,
+            beneficiary_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_beneficiaries_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: Option<&common_utils::id_type::CustomerId>,
+        limit: i64,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBeneficiary>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBeneficiary::list_by_merchant_id(
+            &conn,
+            merchant_id,
+            customer_id,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_beneficiary_by_merchant_id_beneficiary_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        beneficiary_id: &str,
+        payout_beneficiary_update: storage::PayoutBeneficiaryUpdate,
+    ) -> CustomResult<storage::PayoutBeneficiary, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PayoutBeneficiary::update_by_merchant_id_beneficiary_id(
+            &conn,
+            merchant_id,
+            beneficiary_id,
+            payout_beneficiary_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_payout_beneficiary_by_merchant_id_beneficiary_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        beneficiary_id: &str,
+    ) -> CustomResult<storage::PayoutBeneficiary, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PayoutBeneficiary::delete_by_merchant_id_beneficiary_id(
+            &conn,
+            merchant_id,
+            beneficiary_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutBeneficiaryInterface for MockDb {
+    async fn insert_payout_beneficiary(
+        &self,
+        _payout_beneficiary: storage::PayoutBeneficiaryNew,
+    ) -> CustomResult<storage::PayoutBeneficiary, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payout_beneficiary_by_merchant_id_beneficiary_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _beneficiary_id: &str,
+    ) -> CustomResult<storage::PayoutBeneficiary, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_payout_beneficiaries_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _customer_id: Option<&common_utils::id_type::CustomerId>,
+        _limit: i64,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBeneficiary>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payout_beneficiary_by_merchant_id_beneficiary_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _beneficiary_id: &str,
+        _payout_beneficiary_update: storage::PayoutBeneficiaryUpdate,
+    ) -> CustomResult<storage::PayoutBeneficiary, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_payout_beneficiary_by_merchant_id_beneficiary_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _beneficiary_id: &str,
+    ) -> CustomResult<storage::PayoutBeneficiary, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutBeneficiaryInterface for KafkaStore {
+    async fn insert_payout_beneficiary(
+        &self,
+        payout_beneficiary: storage::PayoutBeneficiaryNew,
+    ) -> CustomResult<storage::PayoutBeneficiary, errors::StorageError> {
+        self.diesel_store
+            .insert_payout_beneficiary(payout_beneficiary)
+            .await
+    }
+
+    async fn find_payout_beneficiary_by_merchant_id_beneficiary_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        beneficiary_id: &str,
+    ) -> CustomResult<storage::PayoutBeneficiary, errors::StorageError> {
+        self.diesel_store
+            .find_payout_beneficiary_by_merchant_id_beneficiary_id(merchant_id, beneficiary_id)
+            .await
+    }
+
+    async fn list_payout_beneficiaries_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: Option<&common_utils::id_type::CustomerId>,
+        limit: i64,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBeneficiary>, errors::StorageError> {
+        self.diesel_store
+            .list_payout_beneficiaries_by_merchant_id(merchant_id, customer_id, limit, offset)
+            .await
+    }
+
+    async fn update_payout_beneficiary_by_merchant_id_beneficiary_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        beneficiary_id: &str,
+        payout_beneficiary_update: storage::PayoutBeneficiaryUpdate,
+    ) -> CustomResult<storage::PayoutBeneficiary, errors::StorageError> {
+        self.diesel_store
+            .update_payout_beneficiary_by_merchant_id_beneficiary_id(
+                merchant_id,
+                beneficiary_id,
+                payout_beneficiary_update,
+            )
+            .await
+    }
+
+    async fn delete_payout_beneficiary_by_merchant_id_beneficiary_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        beneficiary_id: &str,
+    ) -> CustomResult<storage::PayoutBeneficiary, errors::StorageError> {
+        self.diesel_store
+            .delete_payout_beneficiary_by_merchant_id_beneficiary_id(merchant_id, beneficiary_id)
+            .await
+    }
+}
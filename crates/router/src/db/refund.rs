@@ -292,7 +292,7 @@ mod storage {
             limit: i64,
             offset: i64,
         ) -> CustomResult<Vec<diesel_models::refund::Refund>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_replica(self).await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::filter_by_constraints(
                 &conn,
                 merchant_id,
@@ -314,7 +314,7 @@ mod storage {
             limit: i64,
             offset: i64,
         ) -> CustomResult<Vec<diesel_models::refund::Refund>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_replica(self).await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::filter_by_constraints(
                 &conn,
                 merchant_id,
@@ -334,7 +334,7 @@ mod storage {
             refund_details: &api_models::payments::TimeRange,
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<api_models::refunds::RefundListMetaData, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_replica(self).await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::filter_by_meta_constraints(
                 &conn,
                 merchant_id,
@@ -353,7 +353,7 @@ mod storage {
             time_range: &api_models::payments::TimeRange,
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<Vec<(common_enums::RefundStatus, i64)>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_replica(self).await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::get_refund_status_with_count(&conn, merchant_id,profile_id_list, time_range)
             .await
             .map_err(|error|report!(errors::StorageError::from(error)))
@@ -367,7 +367,7 @@ mod storage {
             refund_details: &refunds::RefundListConstraints,
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<i64, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_replica(self).await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::get_refunds_count(
                 &conn,
                 merchant_id,
@@ -385,7 +385,7 @@ mod storage {
             refund_details: refunds::RefundListConstraints,
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<i64, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_replica(self).await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::get_refunds_count(
                 &conn,
                 merchant_id,
@@ -545,6 +545,8 @@ mod storage {
                         processor_transaction_data: new.processor_transaction_data.clone(),
                         issuer_error_code: None,
                         issuer_error_message: None,
+                        created_by: new.created_by.clone(),
+                        reviewed_by: None,
                         // Below fields are deprecated. Please add any new fields above this line.
                         connector_refund_data: None,
                         connector_transaction_data: None,
@@ -984,7 +986,7 @@ mod storage {
             limit: i64,
             offset: i64,
         ) -> CustomResult<Vec<diesel_models::refund::Refund>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_replica(self).await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::filter_by_constraints(
                 &conn,
                 merchant_id,
@@ -1006,7 +1008,7 @@ mod storage {
             limit: i64,
             offset: i64,
         ) -> CustomResult<Vec<diesel_models::refund::Refund>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_replica(self).await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::filter_by_constraints(
                 &conn,
                 merchant_id,
@@ -1026,7 +1028,7 @@ mod storage {
             refund_details: &common_utils::types::TimeRange,
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<api_models::refunds::RefundListMetaData, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_replica(self).await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::filter_by_meta_constraints(&conn, merchant_id, refund_details)
                         .await
                         .map_err(|error|report!(errors::StorageError::from(error)))
@@ -1041,7 +1043,7 @@ mod storage {
             constraints: &common_utils::types::TimeRange,
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<Vec<(common_enums::RefundStatus, i64)>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_replica(self).await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::get_refund_status_with_count(&conn, merchant_id,profile_id_list, constraints)
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
@@ -1055,7 +1057,7 @@ mod storage {
             refund_details: &refunds::RefundListConstraints,
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<i64, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_replica(self).await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::get_refunds_count(
                 &conn,
                 merchant_id,
@@ -1073,7 +1075,7 @@ mod storage {
             refund_details: refunds::RefundListConstraints,
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<i64, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_replica(self).await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::get_refunds_count(
                 &conn,
                 merchant_id,
@@ -1152,6 +1154,8 @@ impl RefundInterface for MockDb {
             processor_transaction_data: new.processor_transaction_data.clone(),
             issuer_error_code: None,
             issuer_error_message: None,
+            created_by: new.created_by,
+            reviewed_by: None,
             // Below fields are deprecated. Please add any new fields above this line.
             connector_refund_data: None,
             connector_transaction_data: None,
@@ -1454,7 +1458,17 @@ impl RefundInterface for MockDb {
             .filter(|refund| {
                 unique_statuses.is_empty() || unique_statuses.contains(&refund.refund_status)
             })
-            .skip(usize::try_from(offset).unwrap_or_default())
+            .filter(|refund| {
+                refund_details.cursor.as_ref().is_none_or(|cursor| {
+                    (refund.modified_at, refund.refund_id.as_str())
+                        < (cursor.sort_key, cursor.id.as_str())
+                })
+            })
+            .skip(if refund_details.cursor.is_some() {
+                0
+            } else {
+                usize::try_from(offset).unwrap_or_default()
+            })
             .take(usize::try_from(limit).unwrap_or(MAX_LIMIT))
             .cloned()
             .collect::<Vec<_>>();
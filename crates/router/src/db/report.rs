@@ -0,0 +1,320 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ReportInterface {
+    async fn insert_report_template(
+        &self,
+        report_template: storage::ReportTemplateNew,
+    ) -> CustomResult<storage::ReportTemplate, errors::StorageError>;
+
+    async fn find_report_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+    ) -> CustomResult<storage::ReportTemplate, errors::StorageError>;
+
+    async fn list_report_templates_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::ReportTemplate>, errors::StorageError>;
+
+    async fn update_report_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+        report_template_update: storage::ReportTemplateUpdate,
+    ) -> CustomResult<storage::ReportTemplate, errors::StorageError>;
+
+    async fn delete_report_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn insert_report_run(
+        &self,
+        report_run: storage::ReportRunNew,
+    ) -> CustomResult<storage::ReportRun, errors::StorageError>;
+
+    async fn find_report_run_by_merchant_id_run_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        run_id: &str,
+    ) -> CustomResult<storage::ReportRun, errors::StorageError>;
+
+    async fn list_report_runs_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ReportRun>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ReportInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_report_template(
+        &self,
+        report_template: storage::ReportTemplateNew,
+    ) -> CustomResult<storage::ReportTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        report_template
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_report_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+    ) -> CustomResult<storage::ReportTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReportTemplate::find_by_merchant_id_template_id(&conn, merchant_id, template_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_report_templates_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::ReportTemplate>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReportTemplate::list_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_report_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+        report_template_update: storage::ReportTemplateUpdate,
+    ) -> CustomResult<storage::ReportTemplate, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ReportTemplate::update_by_merchant_id_template_id(
+            &conn,
+            merchant_id,
+            template_id,
+            report_template_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_report_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ReportTemplate::delete_by_merchant_id_template_id(&conn, merchant_id, template_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_report_run(
+        &self,
+        report_run: storage::ReportRunNew,
+    ) -> CustomResult<storage::ReportRun, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        report_run
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_report_run_by_merchant_id_run_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        run_id: &str,
+    ) -> CustomResult<storage::ReportRun, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReportRun::find_by_merchant_id_run_id(&conn, merchant_id, run_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_report_runs_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ReportRun>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReportRun::list_by_merchant_id(&conn, merchant_id, template_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ReportInterface for MockDb {
+    async fn insert_report_template(
+        &self,
+        _report_template: storage::ReportTemplateNew,
+    ) -> CustomResult<storage::ReportTemplate, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_report_template_by_merchant_id_template_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _template_id: &str,
+    ) -> CustomResult<storage::ReportTemplate, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_report_templates_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::ReportTemplate>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_report_template_by_merchant_id_template_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _template_id: &str,
+        _report_template_update: storage::ReportTemplateUpdate,
+    ) -> CustomResult<storage::ReportTemplate, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_report_template_by_merchant_id_template_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _template_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_report_run(
+        &self,
+        _report_run: storage::ReportRunNew,
+    ) -> CustomResult<storage::ReportRun, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_report_run_by_merchant_id_run_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _run_id: &str,
+    ) -> CustomResult<storage::ReportRun, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_report_runs_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _template_id: Option<String>,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::ReportRun>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl ReportInterface for KafkaStore {
+    async fn insert_report_template(
+        &self,
+        report_template: storage::ReportTemplateNew,
+    ) -> CustomResult<storage::ReportTemplate, errors::StorageError> {
+        self.diesel_store
+            .insert_report_template(report_template)
+            .await
+    }
+
+    async fn find_report_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+    ) -> CustomResult<storage::ReportTemplate, errors::StorageError> {
+        self.diesel_store
+            .find_report_template_by_merchant_id_template_id(merchant_id, template_id)
+            .await
+    }
+
+    async fn list_report_templates_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::ReportTemplate>, errors::StorageError> {
+        self.diesel_store
+            .list_report_templates_by_merchant_id(merchant_id)
+            .await
+    }
+
+    async fn update_report_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+        report_template_update: storage::ReportTemplateUpdate,
+    ) -> CustomResult<storage::ReportTemplate, errors::StorageError> {
+        self.diesel_store
+            .update_report_template_by_merchant_id_template_id(
+                merchant_id,
+                template_id,
+                report_template_update,
+            )
+            .await
+    }
+
+    async fn delete_report_template_by_merchant_id_template_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_report_template_by_merchant_id_template_id(merchant_id, template_id)
+            .await
+    }
+
+    async fn insert_report_run(
+        &self,
+        report_run: storage::ReportRunNew,
+    ) -> CustomResult<storage::ReportRun, errors::StorageError> {
+        self.diesel_store.insert_report_run(report_run).await
+    }
+
+    async fn find_report_run_by_merchant_id_run_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        run_id: &str,
+    ) -> CustomResult<storage::ReportRun, errors::StorageError> {
+        self.diesel_store
+            .find_report_run_by_merchant_id_run_id(merchant_id, run_id)
+            .await
+    }
+
+    async fn list_report_runs_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        template_id: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ReportRun>, errors::StorageError> {
+        self.diesel_store
+            .list_report_runs_by_merchant_id(merchant_id, template_id, limit, offset)
+            .await
+    }
+}
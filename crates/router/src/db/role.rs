@@ -227,6 +227,7 @@ impl RoleInterface for MockDb {
             last_modified_by: role.last_modified_by,
             profile_id: role.profile_id,
             tenant_id: role.tenant_id,
+            permissions: role.permissions,
         };
         roles.push(role.clone());
         Ok(role)
@@ -324,11 +325,15 @@ impl RoleInterface for MockDb {
                 *role = match role_update {
                     storage::RoleUpdate::UpdateDetails {
                         groups,
+                        permissions,
                         role_name,
                         last_modified_at,
                         last_modified_by,
                     } => storage::Role {
                         groups: groups.unwrap_or(role.groups.to_owned()),
+                        permissions: permissions
+                            .map(storage::RolePermissions)
+                            .or(role.permissions.to_owned()),
                         role_name: role_name.unwrap_or(role.role_name.to_owned()),
                         last_modified_by,
                         last_modified_at,
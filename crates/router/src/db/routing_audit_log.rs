@@ -0,0 +1,355 @@
+use async_bb8_diesel::AsyncConnection;
+use common_utils::types::keymanager::KeyManagerState;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+use storage_impl::{
+    redis::cache::{self, CacheKind},
+    MockDb,
+};
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::{kafka_store::KafkaStore, StorageInterface},
+    types::{
+        domain::{
+            self,
+            behaviour::{Conversion, ReverseConversion},
+        },
+        storage,
+    },
+};
+
+#[async_trait::async_trait]
+pub trait RoutingAuditLogInterface {
+    async fn insert_routing_audit_log(
+        &self,
+        routing_audit_log: storage::RoutingAuditLogNew,
+    ) -> CustomResult<storage::RoutingAuditLog, errors::StorageError>;
+
+    /// Inserts a routing algorithm along with the audit log entry of its creation, in a single
+    /// transaction
+    async fn insert_routing_algorithm_with_audit_log(
+        &self,
+        routing_algorithm: storage::RoutingAlgorithm,
+        routing_audit_log: storage::RoutingAuditLogNew,
+    ) -> CustomResult<(storage::RoutingAlgorithm, storage::RoutingAuditLog), errors::StorageError>;
+
+    /// Writes a routing config along with the audit log entry of the change, in a single
+    /// transaction. The config is created if it doesn't exist, and deleted if no config is given.
+    async fn upsert_config_with_routing_audit_log(
+        &self,
+        key: String,
+        config: Option<String>,
+        routing_audit_log: storage::RoutingAuditLogNew,
+    ) -> CustomResult<storage::RoutingAuditLog, errors::StorageError>;
+
+    /// Updates the routing algorithm references of a profile along with the audit log entry of
+    /// the change. The audit log entry is only committed if the profile gets updated.
+    async fn update_profile_with_routing_audit_log(
+        &self,
+        key_manager_state: &KeyManagerState,
+        merchant_key_store: &domain::MerchantKeyStore,
+        current_state: domain::Profile,
+        profile_update: domain::ProfileUpdate,
+        routing_audit_log: storage::RoutingAuditLogNew,
+    ) -> CustomResult<(domain::Profile, storage::RoutingAuditLog), errors::StorageError>;
+
+    #[allow(clippy::too_many_arguments)]
+    async fn list_routing_audit_log_by_merchant_id_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::RoutingAuditLog>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl RoutingAuditLogInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_routing_audit_log(
+        &self,
+        routing_audit_log: storage::RoutingAuditLogNew,
+    ) -> CustomResult<storage::RoutingAuditLog, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        routing_audit_log
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_routing_algorithm_with_audit_log(
+        &self,
+        routing_algorithm: storage::RoutingAlgorithm,
+        routing_audit_log: storage::RoutingAuditLogNew,
+    ) -> CustomResult<(storage::RoutingAlgorithm, storage::RoutingAuditLog), errors::StorageError>
+    {
+        let conn = connection::pg_connection_write(self).await?;
+        conn.transaction_async(|conn| async move {
+            let routing_algorithm = routing_algorithm
+                .insert(&conn)
+                .await
+                .map_err(errors::StorageError::from)?;
+            let routing_audit_log = routing_audit_log
+                .insert(&conn)
+                .await
+                .map_err(errors::StorageError::from)?;
+            Ok::<_, errors::StorageError>((routing_algorithm, routing_audit_log))
+        })
+        .await
+        .map_err(|error| report!(error))
+    }
+
+    #[instrument(skip_all)]
+    async fn upsert_config_with_routing_audit_log(
+        &self,
+        key: String,
+        config: Option<String>,
+        routing_audit_log: storage::RoutingAuditLogNew,
+    ) -> CustomResult<storage::RoutingAuditLog, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        let cache_key = CacheKind::Config(key.clone().into());
+        let routing_audit_log = conn
+            .transaction_async(|conn| async move {
+                match config {
+                    Some(config) => match storage::Config::find_by_key(&conn, &key).await {
+                        Ok(_) => {
+                            storage::Config::update_by_key(
+                                &conn,
+                                &key,
+                                storage::ConfigUpdate::Update {
+                                    config: Some(config),
+                                },
+                            )
+                            .await
+                            .map_err(errors::StorageError::from)?;
+                        }
+                        Err(error) if error.current_context().is_db_not_found() => {
+                            storage::ConfigNew { key, config }
+                                .insert(&conn)
+                                .await
+                                .map_err(errors::StorageError::from)?;
+                        }
+                        Err(error) => Err(errors::StorageError::from(error))?,
+                    },
+                    None => {
+                        storage::Config::delete_by_key(&conn, &key)
+                            .await
+                            .map_err(errors::StorageError::from)?;
+                    }
+                }
+                routing_audit_log
+                    .insert(&conn)
+                    .await
+                    .map_err(errors::StorageError::from)
+            })
+            .await
+            .map_err(|error| report!(error))?;
+
+        cache::redact_from_redis_and_publish(self.get_cache_store().as_ref(), [cache_key]).await?;
+
+        Ok(routing_audit_log)
+    }
+
+    #[instrument(skip_all)]
+    async fn update_profile_with_routing_audit_log(
+        &self,
+        key_manager_state: &KeyManagerState,
+        merchant_key_store: &domain::MerchantKeyStore,
+        current_state: domain::Profile,
+        profile_update: domain::ProfileUpdate,
+        routing_audit_log: storage::RoutingAuditLogNew,
+    ) -> CustomResult<(domain::Profile, storage::RoutingAuditLog), errors::StorageError> {
+        let current_state = Conversion::convert(current_state)
+            .await
+            .change_context(errors::StorageError::EncryptionError)?;
+        let profile_update = storage::ProfileUpdateInternal::from(profile_update);
+        let accounts_conn = connection::pg_accounts_connection_write(self).await?;
+        let conn = connection::pg_connection_write(self).await?;
+
+        // The profile lives in the accounts database, which may be separate from the database of
+        // the audit log. The profile is updated while the transaction inserting the audit log
+        // entry is open, so that the entry is rolled back if the profile update fails.
+        let (business_profile, routing_audit_log) = conn
+            .transaction_async(|conn| async move {
+                let routing_audit_log = routing_audit_log
+                    .insert(&conn)
+                    .await
+                    .map_err(errors::StorageError::from)?;
+                let business_profile = current_state
+                    .update_by_profile_id(&accounts_conn, profile_update)
+                    .await
+                    .map_err(errors::StorageError::from)?;
+                Ok::<_, errors::StorageError>((business_profile, routing_audit_log))
+            })
+            .await
+            .map_err(|error| report!(error))?;
+
+        let business_profile = business_profile
+            .convert(
+                key_manager_state,
+                merchant_key_store.key.get_inner(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)?;
+
+        Ok((business_profile, routing_audit_log))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_routing_audit_log_by_merchant_id_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::RoutingAuditLog>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RoutingAuditLog::list_by_merchant_id_constraints(
+            &conn,
+            merchant_id,
+            profile_id,
+            created_after,
+            created_before,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl RoutingAuditLogInterface for MockDb {
+    #[instrument(skip_all)]
+    async fn insert_routing_audit_log(
+        &self,
+        _routing_audit_log: storage::RoutingAuditLogNew,
+    ) -> CustomResult<storage::RoutingAuditLog, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_routing_algorithm_with_audit_log(
+        &self,
+        _routing_algorithm: storage::RoutingAlgorithm,
+        _routing_audit_log: storage::RoutingAuditLogNew,
+    ) -> CustomResult<(storage::RoutingAlgorithm, storage::RoutingAuditLog), errors::StorageError>
+    {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn upsert_config_with_routing_audit_log(
+        &self,
+        _key: String,
+        _config: Option<String>,
+        _routing_audit_log: storage::RoutingAuditLogNew,
+    ) -> CustomResult<storage::RoutingAuditLog, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_profile_with_routing_audit_log(
+        &self,
+        _key_manager_state: &KeyManagerState,
+        _merchant_key_store: &domain::MerchantKeyStore,
+        _current_state: domain::Profile,
+        _profile_update: domain::ProfileUpdate,
+        _routing_audit_log: storage::RoutingAuditLogNew,
+    ) -> CustomResult<(domain::Profile, storage::RoutingAuditLog), errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_routing_audit_log_by_merchant_id_constraints(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _profile_id: Option<common_utils::id_type::ProfileId>,
+        _created_after: Option<time::PrimitiveDateTime>,
+        _created_before: Option<time::PrimitiveDateTime>,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::RoutingAuditLog>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl RoutingAuditLogInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_routing_audit_log(
+        &self,
+        routing_audit_log: storage::RoutingAuditLogNew,
+    ) -> CustomResult<storage::RoutingAuditLog, errors::StorageError> {
+        self.diesel_store
+            .insert_routing_audit_log(routing_audit_log)
+            .await
+    }
+
+    async fn insert_routing_algorithm_with_audit_log(
+        &self,
+        routing_algorithm: storage::RoutingAlgorithm,
+        routing_audit_log: storage::RoutingAuditLogNew,
+    ) -> CustomResult<(storage::RoutingAlgorithm, storage::RoutingAuditLog), errors::StorageError>
+    {
+        self.diesel_store
+            .insert_routing_algorithm_with_audit_log(routing_algorithm, routing_audit_log)
+            .await
+    }
+
+    async fn upsert_config_with_routing_audit_log(
+        &self,
+        key: String,
+        config: Option<String>,
+        routing_audit_log: storage::RoutingAuditLogNew,
+    ) -> CustomResult<storage::RoutingAuditLog, errors::StorageError> {
+        self.diesel_store
+            .upsert_config_with_routing_audit_log(key, config, routing_audit_log)
+            .await
+    }
+
+    async fn update_profile_with_routing_audit_log(
+        &self,
+        key_manager_state: &KeyManagerState,
+        merchant_key_store: &domain::MerchantKeyStore,
+        current_state: domain::Profile,
+        profile_update: domain::ProfileUpdate,
+        routing_audit_log: storage::RoutingAuditLogNew,
+    ) -> CustomResult<(domain::Profile, storage::RoutingAuditLog), errors::StorageError> {
+        self.diesel_store
+            .update_profile_with_routing_audit_log(
+                key_manager_state,
+                merchant_key_store,
+                current_state,
+                profile_update,
+                routing_audit_log,
+            )
+            .await
+    }
+
+    async fn list_routing_audit_log_by_merchant_id_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::RoutingAuditLog>, errors::StorageError> {
+        self.diesel_store
+            .list_routing_audit_log_by_merchant_id_constraints(
+                merchant_id,
+                profile_id,
+                created_after,
+                created_before,
+                limit,
+                offset,
+            )
+            .await
+    }
+}
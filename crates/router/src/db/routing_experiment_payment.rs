@@ -0,0 +1,231 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait RoutingExperimentPaymentInterface {
+    async fn insert_routing_experiment_payment(
+        &self,
+        routing_experiment_payment: storage::RoutingExperimentPaymentNew,
+    ) -> CustomResult<storage::RoutingExperimentPayment, errors::StorageError>;
+
+    async fn find_pending_routing_experiment_payment(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        attempt_id: &str,
+    ) -> CustomResult<Option<storage::RoutingExperimentPayment>, errors::StorageError>;
+
+    async fn update_pending_routing_experiment_payment(
+        &self,
+        experiment_id: &str,
+        attempt_id: &str,
+        routing_experiment_payment: storage::RoutingExperimentPaymentUpdate,
+    ) -> CustomResult<storage::RoutingExperimentPayment, errors::StorageError>;
+
+    async fn delete_routing_experiment_payment(
+        &self,
+        experiment_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn get_routing_experiment_payment_status_count(
+        &self,
+        experiment_id: &str,
+    ) -> CustomResult<
+        Vec<(enums::RoutingExperimentArm, enums::AttemptStatus, i64)>,
+        errors::StorageError,
+    >;
+}
+
+#[async_trait::async_trait]
+impl RoutingExperimentPaymentInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_routing_experiment_payment(
+        &self,
+        routing_experiment_payment: storage::RoutingExperimentPaymentNew,
+    ) -> CustomResult<storage::RoutingExperimentPayment, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        routing_experiment_payment
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_pending_routing_experiment_payment(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        attempt_id: &str,
+    ) -> CustomResult<Option<storage::RoutingExperimentPayment>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::RoutingExperimentPayment::find_optional_pending_by_merchant_id_attempt_id(
+            &conn,
+            merchant_id,
+            attempt_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_pending_routing_experiment_payment(
+        &self,
+        experiment_id: &str,
+        attempt_id: &str,
+        routing_experiment_payment: storage::RoutingExperimentPaymentUpdate,
+    ) -> CustomResult<storage::RoutingExperimentPayment, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::RoutingExperimentPayment::update_pending_by_experiment_id_attempt_id(
+            &conn,
+            experiment_id,
+            attempt_id,
+            routing_experiment_payment,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_routing_experiment_payment(
+        &self,
+        experiment_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::RoutingExperimentPayment::delete_by_experiment_id_attempt_id(
+            &conn,
+            experiment_id,
+            attempt_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn get_routing_experiment_payment_status_count(
+        &self,
+        experiment_id: &str,
+    ) -> CustomResult<
+        Vec<(enums::RoutingExperimentArm, enums::AttemptStatus, i64)>,
+        errors::StorageError,
+    > {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RoutingExperimentPayment::get_status_count_by_experiment_id(&conn, experiment_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl RoutingExperimentPaymentInterface for MockDb {
+    #[instrument(skip_all)]
+    async fn insert_routing_experiment_payment(
+        &self,
+        _routing_experiment_payment: storage::RoutingExperimentPaymentNew,
+    ) -> CustomResult<storage::RoutingExperimentPayment, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_pending_routing_experiment_payment(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _attempt_id: &str,
+    ) -> CustomResult<Option<storage::RoutingExperimentPayment>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_pending_routing_experiment_payment(
+        &self,
+        _experiment_id: &str,
+        _attempt_id: &str,
+        _routing_experiment_payment: storage::RoutingExperimentPaymentUpdate,
+    ) -> CustomResult<storage::RoutingExperimentPayment, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_routing_experiment_payment(
+        &self,
+        _experiment_id: &str,
+        _attempt_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn get_routing_experiment_payment_status_count(
+        &self,
+        _experiment_id: &str,
+    ) -> CustomResult<
+        Vec<(enums::RoutingExperimentArm, enums::AttemptStatus, i64)>,
+        errors::StorageError,
+    > {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl RoutingExperimentPaymentInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_routing_experiment_payment(
+        &self,
+        routing_experiment_payment: storage::RoutingExperimentPaymentNew,
+    ) -> CustomResult<storage::RoutingExperimentPayment, errors::StorageError> {
+        self.diesel_store
+            .insert_routing_experiment_payment(routing_experiment_payment)
+            .await
+    }
+
+    async fn find_pending_routing_experiment_payment(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        attempt_id: &str,
+    ) -> CustomResult<Option<storage::RoutingExperimentPayment>, errors::StorageError> {
+        self.diesel_store
+            .find_pending_routing_experiment_payment(merchant_id, attempt_id)
+            .await
+    }
+
+    async fn update_pending_routing_experiment_payment(
+        &self,
+        experiment_id: &str,
+        attempt_id: &str,
+        routing_experiment_payment: storage::RoutingExperimentPaymentUpdate,
+    ) -> CustomResult<storage::RoutingExperimentPayment, errors::StorageError> {
+        self.diesel_store
+            .update_pending_routing_experiment_payment(
+                experiment_id,
+                attempt_id,
+                routing_experiment_payment,
+            )
+            .await
+    }
+
+    async fn delete_routing_experiment_payment(
+        &self,
+        experiment_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_routing_experiment_payment(experiment_id, attempt_id)
+            .await
+    }
+
+    async fn get_routing_experiment_payment_status_count(
+        &self,
+        experiment_id: &str,
+    ) -> CustomResult<
+        Vec<(enums::RoutingExperimentArm, enums::AttemptStatus, i64)>,
+        errors::StorageError,
+    > {
+        self.diesel_store
+            .get_routing_experiment_payment_status_count(experiment_id)
+            .await
+    }
+}
@@ -0,0 +1,350 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait SettlementReconInterface {
+    async fn insert_settlement_report(
+        &self,
+        settlement_report: storage::SettlementReportNew,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError>;
+
+    async fn find_settlement_report_by_merchant_id_report_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_id: &str,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError>;
+
+    async fn update_settlement_report_by_merchant_id_report_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_id: &str,
+        settlement_report_update: storage::SettlementReportUpdate,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError>;
+
+    async fn insert_recon_record(
+        &self,
+        recon_record: storage::ReconRecordNew,
+    ) -> CustomResult<storage::ReconRecord, errors::StorageError>;
+
+    async fn find_recon_records_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::ReconRecord>, errors::StorageError>;
+
+    async fn filter_recon_exceptions_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_id: Option<String>,
+        recon_status: Option<enums::SettlementReconStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ReconRecord>, errors::StorageError>;
+
+    /// Lists a page of the attempts of a connector with the given statuses made within a time
+    /// range, which are read from the database as reports are reconciled long after the attempts
+    /// are made
+    #[cfg(feature = "v1")]
+    #[allow(clippy::too_many_arguments)]
+    async fn find_payment_attempts_for_recon(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector: &str,
+        statuses: Vec<enums::AttemptStatus>,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<diesel_models::PaymentAttempt>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl SettlementReconInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_settlement_report(
+        &self,
+        settlement_report: storage::SettlementReportNew,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        settlement_report
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_settlement_report_by_merchant_id_report_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_id: &str,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SettlementReport::find_by_merchant_id_report_id(&conn, merchant_id, report_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_settlement_report_by_merchant_id_report_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_id: &str,
+        settlement_report_update: storage::SettlementReportUpdate,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::SettlementReport::update_by_merchant_id_report_id(
+            &conn,
+            merchant_id,
+            report_id,
+            settlement_report_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_recon_record(
+        &self,
+        recon_record: storage::ReconRecordNew,
+    ) -> CustomResult<storage::ReconRecord, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        recon_record
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_recon_records_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::ReconRecord>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReconRecord::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn filter_recon_exceptions_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_id: Option<String>,
+        recon_status: Option<enums::SettlementReconStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ReconRecord>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReconRecord::filter_exceptions_by_merchant_id(
+            &conn,
+            merchant_id,
+            report_id,
+            recon_status,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_payment_attempts_for_recon(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector: &str,
+        statuses: Vec<enums::AttemptStatus>,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<diesel_models::PaymentAttempt>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        diesel_models::PaymentAttempt::find_by_merchant_id_connector_statuses_created_at_range(
+            &conn,
+            merchant_id,
+            connector,
+            statuses,
+            created_after,
+            created_before,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl SettlementReconInterface for MockDb {
+    async fn insert_settlement_report(
+        &self,
+        _settlement_report: storage::SettlementReportNew,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_settlement_report_by_merchant_id_report_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _report_id: &str,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_settlement_report_by_merchant_id_report_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _report_id: &str,
+        _settlement_report_update: storage::SettlementReportUpdate,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_recon_record(
+        &self,
+        _recon_record: storage::ReconRecordNew,
+    ) -> CustomResult<storage::ReconRecord, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_recon_records_by_merchant_id_payment_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::ReconRecord>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn filter_recon_exceptions_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _report_id: Option<String>,
+        _recon_status: Option<enums::SettlementReconStatus>,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::ReconRecord>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_payment_attempts_for_recon(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _connector: &str,
+        _statuses: Vec<enums::AttemptStatus>,
+        _created_after: time::PrimitiveDateTime,
+        _created_before: time::PrimitiveDateTime,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<diesel_models::PaymentAttempt>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl SettlementReconInterface for KafkaStore {
+    async fn insert_settlement_report(
+        &self,
+        settlement_report: storage::SettlementReportNew,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError> {
+        self.diesel_store
+            .insert_settlement_report(settlement_report)
+            .await
+    }
+
+    async fn find_settlement_report_by_merchant_id_report_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_id: &str,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError> {
+        self.diesel_store
+            .find_settlement_report_by_merchant_id_report_id(merchant_id, report_id)
+            .await
+    }
+
+    async fn update_settlement_report_by_merchant_id_report_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_id: &str,
+        settlement_report_update: storage::SettlementReportUpdate,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError> {
+        self.diesel_store
+            .update_settlement_report_by_merchant_id_report_id(
+                merchant_id,
+                report_id,
+                settlement_report_update,
+            )
+            .await
+    }
+
+    async fn insert_recon_record(
+        &self,
+        recon_record: storage::ReconRecordNew,
+    ) -> CustomResult<storage::ReconRecord, errors::StorageError> {
+        self.diesel_store.insert_recon_record(recon_record).await
+    }
+
+    async fn find_recon_records_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::ReconRecord>, errors::StorageError> {
+        self.diesel_store
+            .find_recon_records_by_merchant_id_payment_id(merchant_id, payment_id)
+            .await
+    }
+
+    async fn filter_recon_exceptions_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_id: Option<String>,
+        recon_status: Option<enums::SettlementReconStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ReconRecord>, errors::StorageError> {
+        self.diesel_store
+            .filter_recon_exceptions_by_merchant_id(
+                merchant_id,
+                report_id,
+                recon_status,
+                limit,
+                offset,
+            )
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_payment_attempts_for_recon(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector: &str,
+        statuses: Vec<enums::AttemptStatus>,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<diesel_models::PaymentAttempt>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_attempts_for_recon(
+                merchant_id,
+                connector,
+                statuses,
+                created_after,
+                created_before,
+                limit,
+                offset,
+            )
+            .await
+    }
+}
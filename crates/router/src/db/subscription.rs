@@ -29,6 +29,17 @@ pub trait SubscriptionInterface {
         subscription_id: String,
         data: storage::SubscriptionUpdate,
     ) -> CustomResult<storage::Subscription, errors::StorageError>;
+
+    async fn insert_subscription_plan(
+        &self,
+        subscription_plan_new: storage::SubscriptionPlanNew,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError>;
+
+    async fn find_subscription_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -73,6 +84,30 @@ impl SubscriptionInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn insert_subscription_plan(
+        &self,
+        subscription_plan_new: storage::SubscriptionPlanNew,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        subscription_plan_new
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionPlan::find_by_merchant_id_plan_id(&conn, merchant_id, plan_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -101,6 +136,21 @@ impl SubscriptionInterface for MockDb {
     ) -> CustomResult<storage::Subscription, errors::StorageError> {
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn insert_subscription_plan(
+        &self,
+        _subscription_plan_new: storage::SubscriptionPlanNew,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_subscription_plan_by_merchant_id_plan_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _plan_id: &str,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
 }
 
 #[async_trait::async_trait]
@@ -137,4 +187,25 @@ impl SubscriptionInterface for KafkaStore {
             .update_subscription_entry(merchant_id, subscription_id, data)
             .await
     }
+
+    #[instrument(skip_all)]
+    async fn insert_subscription_plan(
+        &self,
+        subscription_plan_new: storage::SubscriptionPlanNew,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        self.diesel_store
+            .insert_subscription_plan(subscription_plan_new)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        self.diesel_store
+            .find_subscription_plan_by_merchant_id_plan_id(merchant_id, plan_id)
+            .await
+    }
 }
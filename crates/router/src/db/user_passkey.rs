@@ -0,0 +1,228 @@
+use diesel_models::user_passkey as storage;
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::MockDb;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+};
+
+#[async_trait::async_trait]
+pub trait UserPasskeyInterface {
+    async fn insert_user_passkey(
+        &self,
+        user_passkey: storage::UserPasskeyNew,
+    ) -> CustomResult<storage::UserPasskey, errors::StorageError>;
+
+    async fn find_user_passkey_by_user_id_passkey_id(
+        &self,
+        user_id: &str,
+        passkey_id: &str,
+    ) -> CustomResult<storage::UserPasskey, errors::StorageError>;
+
+    async fn list_user_passkeys_by_user_id(
+        &self,
+        user_id: &str,
+    ) -> CustomResult<Vec<storage::UserPasskey>, errors::StorageError>;
+
+    async fn update_user_passkey_by_user_id_passkey_id(
+        &self,
+        user_id: &str,
+        passkey_id: &str,
+        user_passkey_update: storage::UserPasskeyUpdate,
+    ) -> CustomResult<storage::UserPasskey, errors::StorageError>;
+
+    async fn delete_user_passkey_by_user_id_passkey_id(
+        &self,
+        user_id: &str,
+        passkey_id: &str,
+    ) -> CustomResult<storage::UserPasskey, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl UserPasskeyInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_user_passkey(
+        &self,
+        user_passkey: storage::UserPasskeyNew,
+    ) -> CustomResult<storage::UserPasskey, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        user_passkey
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_user_passkey_by_user_id_passkey_id(
+        &self,
+        user_id: &str,
+        passkey_id: &str,
+    ) -> CustomResult<storage::UserPasskey, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::UserPasskey::find_by_user_id_passkey_id(&conn, user_id, passkey_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_user_passkeys_by_user_id(
+        &self,
+        user_id: &str,
+    ) -> CustomResult<Vec<storage::UserPasskey>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::UserPasskey::list_by_user_id(&conn, user_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_user_passkey_by_user_id_passkey_id(
+        &self,
+        user_id: &str,
+        passkey_id: &str,
+        user_passkey_update: storage::UserPasskeyUpdate,
+    ) -> CustomResult<storage::UserPasskey, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::UserPasskey::update_by_user_id_passkey_id(
+            &conn,
+            user_id,
+            passkey_id,
+            user_passkey_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_user_passkey_by_user_id_passkey_id(
+        &self,
+        user_id: &str,
+        passkey_id: &str,
+    ) -> CustomResult<storage::UserPasskey, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::UserPasskey::delete_by_user_id_passkey_id(&conn, user_id, passkey_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl UserPasskeyInterface for MockDb {
+    async fn insert_user_passkey(
+        &self,
+        user_passkey: storage::UserPasskeyNew,
+    ) -> CustomResult<storage::UserPasskey, errors::StorageError> {
+        let mut user_passkeys = self.user_passkeys.lock().await;
+        if user_passkeys
+            .iter()
+            .any(|passkey_inner| passkey_inner.id == user_passkey.id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "passkey_id",
+                key: None,
+            })?
+        }
+
+        let user_passkey = storage::UserPasskey {
+            id: user_passkey.id,
+            user_id: user_passkey.user_id,
+            name: user_passkey.name,
+            passkey: user_passkey.passkey,
+            created_at: user_passkey.created_at,
+            last_used_at: user_passkey.last_used_at,
+        };
+        user_passkeys.push(user_passkey.clone());
+        Ok(user_passkey)
+    }
+
+    async fn find_user_passkey_by_user_id_passkey_id(
+        &self,
+        user_id: &str,
+        passkey_id: &str,
+    ) -> CustomResult<storage::UserPasskey, errors::StorageError> {
+        self.user_passkeys
+            .lock()
+            .await
+            .iter()
+            .find(|passkey_inner| {
+                passkey_inner.user_id == user_id && passkey_inner.id == passkey_id
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No passkey available for user_id = {user_id} and passkey_id = {passkey_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_user_passkeys_by_user_id(
+        &self,
+        user_id: &str,
+    ) -> CustomResult<Vec<storage::UserPasskey>, errors::StorageError> {
+        Ok(self
+            .user_passkeys
+            .lock()
+            .await
+            .iter()
+            .filter(|passkey_inner| passkey_inner.user_id == user_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn update_user_passkey_by_user_id_passkey_id(
+        &self,
+        user_id: &str,
+        passkey_id: &str,
+        user_passkey_update: storage::UserPasskeyUpdate,
+    ) -> CustomResult<storage::UserPasskey, errors::StorageError> {
+        let mut user_passkeys = self.user_passkeys.lock().await;
+        user_passkeys
+            .iter_mut()
+            .find(|passkey_inner| {
+                passkey_inner.user_id == user_id && passkey_inner.id == passkey_id
+            })
+            .map(|passkey_inner| {
+                *passkey_inner = match user_passkey_update {
+                    storage::UserPasskeyUpdate::Rename { name } => storage::UserPasskey {
+                        name,
+                        ..passkey_inner.to_owned()
+                    },
+                    storage::UserPasskeyUpdate::CredentialUsed { passkey } => {
+                        storage::UserPasskey {
+                            passkey,
+                            last_used_at: Some(common_utils::date_time::now()),
+                            ..passkey_inner.to_owned()
+                        }
+                    }
+                };
+                passkey_inner.to_owned()
+            })
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No passkey available for user_id = {user_id} and passkey_id = {passkey_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn delete_user_passkey_by_user_id_passkey_id(
+        &self,
+        user_id: &str,
+        passkey_id: &str,
+    ) -> CustomResult<storage::UserPasskey, errors::StorageError> {
+        let mut user_passkeys = self.user_passkeys.lock().await;
+        let index = user_passkeys
+            .iter()
+            .position(|passkey_inner| {
+                passkey_inner.user_id == user_id && passkey_inner.id == passkey_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No passkey available for user_id = {user_id} and passkey_id = {passkey_id}"
+            )))?;
+        Ok(user_passkeys.remove(index))
+    }
+}
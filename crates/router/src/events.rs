@@ -84,6 +84,13 @@ impl EventsConfig {
             Self::Logs => Ok(()),
         }
     }
+
+    pub fn get_kafka_settings(&self) -> Option<&KafkaSettings> {
+        match self {
+            Self::Kafka { kafka } => Some(kafka),
+            Self::Logs => None,
+        }
+    }
 }
 
 impl EventsHandler {
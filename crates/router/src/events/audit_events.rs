@@ -4,6 +4,8 @@ use diesel_models::fraud_check::FraudCheck;
 use events::{Event, EventInfo};
 use serde::Serialize;
 use time::PrimitiveDateTime;
+
+use crate::services::authentication::ip_allowlist::IpAllowlistOwner;
 #[derive(Debug, Clone, Serialize)]
 #[serde(tag = "event_type")]
 pub enum AuditEventType {
@@ -39,6 +41,20 @@ pub enum AuditEventType {
         error_code: Option<String>,
         error_message: Option<String>,
     },
+    IpAddressRejected {
+        merchant_id: common_utils::id_type::MerchantId,
+        key_id: common_utils::id_type::ApiKeyId,
+        ip_address: Option<String>,
+        rejected_by: IpAllowlistOwner,
+    },
+    CustomerMerged {
+        merchant_id: common_utils::id_type::MerchantId,
+        customer_id: common_utils::id_type::CustomerId,
+        duplicate_customer_id: common_utils::id_type::CustomerId,
+        payment_methods_moved: usize,
+        mandates_moved: usize,
+        payments_moved: usize,
+    },
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -83,6 +99,8 @@ impl Event for AuditEvent {
             AuditEventType::PaymentStatus => "payment_status",
             AuditEventType::PaymentCompleteAuthorize => "payment_complete_authorize",
             AuditEventType::PaymentReject { .. } => "payment_rejected",
+            AuditEventType::IpAddressRejected { .. } => "ip_address_rejected",
+            AuditEventType::CustomerMerged { .. } => "customer_merged",
         };
         format!(
             "{event_type}-{}",
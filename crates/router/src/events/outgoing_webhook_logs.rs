@@ -72,6 +72,16 @@ pub enum OutgoingWebhookEventContent {
         mandate_id: String,
         content: Value,
     },
+    Routing {
+        profile_id: common_utils::id_type::ProfileId,
+        routing_audit_log_id: String,
+        content: Value,
+    },
+    Subscription {
+        subscription_id: common_utils::id_type::SubscriptionId,
+        payment_id: Option<common_utils::id_type::PaymentId>,
+        content: Value,
+    },
 }
 pub trait OutgoingWebhookEventMetric {
     fn get_outgoing_webhook_event_content(&self) -> Option<OutgoingWebhookEventContent>;
@@ -111,6 +121,20 @@ impl OutgoingWebhookEventMetric for OutgoingWebhookContent {
                 content: masking::masked_serialize(&payout_payload)
                     .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
             }),
+            Self::RoutingDetails(routing_payload) => Some(OutgoingWebhookEventContent::Routing {
+                profile_id: routing_payload.profile_id.clone(),
+                routing_audit_log_id: routing_payload.id.clone(),
+                content: masking::masked_serialize(&routing_payload)
+                    .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+            }),
+            Self::SubscriptionDetails(subscription_payload) => {
+                Some(OutgoingWebhookEventContent::Subscription {
+                    subscription_id: subscription_payload.id.clone(),
+                    payment_id: subscription_payload.last_payment_id.clone(),
+                    content: masking::masked_serialize(&subscription_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
         }
     }
 }
@@ -146,6 +170,20 @@ impl OutgoingWebhookEventMetric for OutgoingWebhookContent {
                 content: masking::masked_serialize(&payout_payload)
                     .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
             }),
+            Self::RoutingDetails(routing_payload) => Some(OutgoingWebhookEventContent::Routing {
+                profile_id: routing_payload.profile_id.clone(),
+                routing_audit_log_id: routing_payload.id.clone(),
+                content: masking::masked_serialize(&routing_payload)
+                    .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+            }),
+            Self::SubscriptionDetails(subscription_payload) => {
+                Some(OutgoingWebhookEventContent::Subscription {
+                    subscription_id: subscription_payload.id.clone(),
+                    payment_id: subscription_payload.last_payment_id.clone(),
+                    content: masking::masked_serialize(&subscription_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
         }
     }
 }
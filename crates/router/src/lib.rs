@@ -92,6 +92,7 @@ pub mod headers {
     pub const X_CLIENT_SECRET: &str = "X-Client-Secret";
     pub const X_CUSTOMER_ID: &str = "X-Customer-Id";
     pub const X_CONNECTED_MERCHANT_ID: &str = "x-connected-merchant-id";
+    pub const X_NEXT_CURSOR: &str = "X-Next-Cursor";
     // Header value for X_CONNECTOR_HTTP_STATUS_CODE differs by version.
     // Constant name is kept the same for consistency across versions.
     #[cfg(feature = "v1")]
@@ -100,6 +101,12 @@ pub mod headers {
     pub const X_CONNECTOR_HTTP_STATUS_CODE: &str = "x-connector-http-status-code";
 
     pub const X_REFERENCE_ID: &str = "X-Reference-Id";
+    pub const X_SIGNATURE: &str = "X-Signature";
+    pub const X_SIGNATURE_KEY_ID: &str = "X-Signature-Key-Id";
+    pub const X_SIGNATURE_NONCE: &str = "X-Signature-Nonce";
+    pub const X_SIGNATURE_TIMESTAMP: &str = "X-Signature-Timestamp";
+    // Set by the server from the request, never accepted from the client
+    pub const X_SIGNATURE_PAYLOAD: &str = "x-signature-payload";
 }
 
 pub mod pii {
@@ -155,7 +162,8 @@ pub fn mk_app(
             .service(routes::Webhooks::server(state.clone()))
             .service(routes::Hypersense::server(state.clone()))
             .service(routes::Relay::server(state.clone()))
-            .service(routes::ThreeDsDecisionRule::server(state.clone()));
+            .service(routes::ThreeDsDecisionRule::server(state.clone()))
+            .service(routes::Surcharge::server(state.clone()));
 
         #[cfg(feature = "oltp")]
         {
@@ -208,9 +216,18 @@ pub fn mk_app(
         #[cfg(feature = "v1")]
         {
             server_app = server_app
+                .service(routes::DecisionManager::server(state.clone()))
                 .service(routes::Files::server(state.clone()))
                 .service(routes::Disputes::server(state.clone()))
                 .service(routes::Blocklist::server(state.clone()))
+                .service(routes::AdminAudit::server(state.clone()))
+                .service(routes::ConnectorAuditLogs::server(state.clone()))
+                .service(routes::DataRetention::server(state.clone()))
+                .service(routes::ListViews::server(state.clone()))
+                .service(routes::Reports::server(state.clone()))
+                .service(routes::AuthRateAlerts::server(state.clone()))
+                .service(routes::PaymentReviews::server(state.clone()))
+                .service(routes::SigningKeys::server(state.clone()))
                 .service(routes::Subscription::server(state.clone()))
                 .service(routes::Gsm::server(state.clone()))
                 .service(routes::ApplePayCertificatesMigration::server(state.clone()))
@@ -218,7 +235,9 @@ pub fn mk_app(
                 .service(routes::ConnectorOnboarding::server(state.clone()))
                 .service(routes::Analytics::server(state.clone()))
                 .service(routes::WebhookEvents::server(state.clone()))
-                .service(routes::FeatureMatrix::server(state.clone()));
+                .service(routes::FeatureMatrix::server(state.clone()))
+                .service(routes::ConnectorCapabilities::server(state.clone()))
+                .service(routes::Scim::server(state.clone()));
         }
 
         #[cfg(feature = "v2")]
@@ -257,8 +276,14 @@ pub fn mk_app(
     }
 
     server_app = server_app.service(routes::Cache::server(state.clone()));
+    server_app = server_app.service(routes::ConnectionPools::server(state.clone()));
+    server_app = server_app.service(routes::Drain::server(state.clone()));
     server_app = server_app.service(routes::Health::server(state.clone()));
 
+    if state.conf.log.telemetry.prometheus_enabled {
+        server_app = server_app.service(routes::Metrics::server(state.clone()));
+    }
+
     server_app
 }
 
@@ -337,6 +362,7 @@ pub async fn start_server(conf: settings::Settings<SecuredSecret>) -> Applicatio
     let server = server_builder.run();
 
     let _task_handle = tokio::spawn(receiver_for_error(rx, server.handle()).in_current_span());
+    services::drain::register_server(server.handle());
     Ok(server)
 }
 
@@ -403,6 +429,7 @@ pub fn get_application_builder(
         // this middleware works only for Http1.1 requests
         .wrap(middleware::Http400RequestDetailsLogger)
         .wrap(middleware::AddAcceptLanguageHeader)
+        .wrap(middleware::AddSignaturePayloadHeader)
         .wrap(middleware::RequestResponseMetrics)
         .wrap(middleware::LogSpanInitializer)
         .wrap(router_env::tracing_actix_web::TracingLogger::default())
@@ -317,6 +317,120 @@ where
     }
 }
 
+/// Middleware for adding the payload of signed requests to the headers, as the body, method and
+/// path of the request are not available to authentication otherwise. The payload is
+/// `{timestamp}.{nonce}.{method}.{path_and_query}.{hex encoded SHA-256 digest of the body}`.
+pub struct AddSignaturePayloadHeader;
+
+impl<S: 'static, B> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest>
+    for AddSignaturePayloadHeader
+where
+    S: actix_web::dev::Service<
+        actix_web::dev::ServiceRequest,
+        Response = actix_web::dev::ServiceResponse<B>,
+        Error = actix_web::Error,
+    >,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Transform = AddSignaturePayloadHeaderMiddleware<S>;
+    type InitError = ();
+    type Future = std::future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        std::future::ready(Ok(AddSignaturePayloadHeaderMiddleware {
+            service: std::rc::Rc::new(service),
+        }))
+    }
+}
+
+pub struct AddSignaturePayloadHeaderMiddleware<S> {
+    service: std::rc::Rc<S>,
+}
+
+impl<S, B> actix_web::dev::Service<actix_web::dev::ServiceRequest>
+    for AddSignaturePayloadHeaderMiddleware<S>
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::Error,
+        > + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = futures::future::LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, mut req: actix_web::dev::ServiceRequest) -> Self::Future {
+        use common_utils::crypto::GenerateDigest;
+
+        let svc = self.service.clone();
+        Box::pin(async move {
+            req.headers_mut().remove(headers::X_SIGNATURE_PAYLOAD);
+            if !req.headers().contains_key(headers::X_SIGNATURE) {
+                return svc.call(req).await;
+            }
+
+            let get_header = |req: &actix_web::dev::ServiceRequest, key| {
+                req.headers()
+                    .get(key)
+                    .and_then(|value| value.to_str().ok())
+                    .unwrap_or_default()
+                    .to_owned()
+            };
+            let timestamp = get_header(&req, headers::X_SIGNATURE_TIMESTAMP);
+            let nonce = get_header(&req, headers::X_SIGNATURE_NONCE);
+
+            let (http_req, payload) = req.into_parts();
+            let result_payload: Vec<Result<bytes::Bytes, actix_web::error::PayloadError>> =
+                payload.collect().await;
+            let bytes = result_payload
+                .into_iter()
+                .collect::<Result<Vec<bytes::Bytes>, actix_web::error::PayloadError>>()?
+                .concat();
+            let body_digest = common_utils::crypto::Sha256
+                .generate_digest(&bytes)
+                .map_err(|error| {
+                    actix_web::error::ErrorInternalServerError(format!(
+                        "Failed to generate digest of the request body: {error:?}"
+                    ))
+                })?;
+            let signature_payload = format!(
+                "{timestamp}.{nonce}.{}.{}.{}",
+                http_req.method(),
+                http_req
+                    .uri()
+                    .path_and_query()
+                    .map(|path_and_query| path_and_query.as_str())
+                    .unwrap_or_else(|| http_req.path()),
+                hex::encode(body_digest)
+            );
+
+            // we are creating h1 payload manually from bytes, currently there's no way to create http2 payload with actix
+            let (_, mut new_payload) = actix_http::h1::Payload::create(true);
+            new_payload.unread_data(bytes.into());
+            let mut new_req =
+                actix_web::dev::ServiceRequest::from_parts(http_req, new_payload.into());
+            // The payload can't be built when the signature headers are not valid header values,
+            // authentication fails in that case as the header is missing
+            if let Ok(signature_payload) = http::HeaderValue::from_str(&signature_payload) {
+                new_req.headers_mut().insert(
+                    http::HeaderName::from_static(headers::X_SIGNATURE_PAYLOAD),
+                    signature_payload,
+                );
+            }
+
+            svc.call(new_req).await
+        })
+    }
+}
+
 /// Middleware for Adding Accept-Language header based on query params
 pub struct AddAcceptLanguageHeader;
 
@@ -1,23 +1,35 @@
 pub mod admin;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod admin_audit;
 pub mod api_keys;
 pub mod app;
 #[cfg(feature = "v1")]
 pub mod apple_pay_certificates_migration;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod auth_rate_alerts;
 pub mod authentication;
 #[cfg(all(feature = "olap", feature = "v1"))]
 pub mod blocklist;
 pub mod cache;
 pub mod cards_info;
 pub mod configs;
+pub mod connection_pools;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod connector_audit_log;
 #[cfg(feature = "olap")]
 pub mod connector_onboarding;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 pub mod currency;
 pub mod customers;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod data_retention;
 pub mod disputes;
+pub mod drain;
 #[cfg(feature = "dummy_connector")]
 pub mod dummy_connector;
 pub mod ephemeral_key;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod event_stream;
 pub mod feature_matrix;
 pub mod files;
 #[cfg(feature = "frm")]
@@ -25,6 +37,8 @@ pub mod fraud_check;
 pub mod gsm;
 pub mod health;
 pub mod hypersense;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod list_views;
 pub mod lock_utils;
 #[cfg(feature = "v1")]
 pub mod locker_migration;
@@ -33,6 +47,8 @@ pub mod metrics;
 #[cfg(feature = "v1")]
 pub mod payment_link;
 pub mod payment_methods;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod payment_review;
 pub mod payments;
 #[cfg(feature = "payouts")]
 pub mod payout_link;
@@ -48,12 +64,17 @@ pub mod profiles;
 #[cfg(feature = "recon")]
 pub mod recon;
 pub mod refunds;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod reports;
 #[cfg(feature = "v2")]
 pub mod revenue_recovery_data_backfill;
 #[cfg(feature = "olap")]
 pub mod routing;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod signing_keys;
 #[cfg(feature = "v1")]
 pub mod subscription;
+pub mod surcharge;
 pub mod three_ds_decision_rule;
 pub mod tokenization;
 #[cfg(feature = "olap")]
@@ -89,16 +110,20 @@ pub use self::app::PaymentMethodSession;
 pub use self::app::Proxy;
 #[cfg(all(feature = "olap", feature = "recon", feature = "v1"))]
 pub use self::app::Recon;
+#[cfg(feature = "olap")]
+pub use self::app::{
+    AdminAudit, AuthRateAlerts, Blocklist, ConnectorAuditLogs, DataRetention, DecisionManager,
+    ListViews, Organization, PaymentReviews, Reports, Routing, SigningKeys, Subscription, Verify,
+    WebhookEvents,
+};
 pub use self::app::{
     ApiKeys, AppState, ApplePayCertificatesMigration, Authentication, Cache, Cards, Chat, Configs,
-    ConnectorOnboarding, Customers, Disputes, EphemeralKey, FeatureMatrix, Files, Forex, Gsm,
-    Health, Hypersense, Mandates, MerchantAccount, MerchantConnectorAccount, PaymentLink,
-    PaymentMethods, Payments, Poll, ProcessTracker, ProcessTrackerDeprecated, Profile,
-    ProfileAcquirer, ProfileNew, Refunds, Relay, RelayWebhooks, SessionState, ThreeDsDecisionRule,
-    User, UserDeprecated, Webhooks,
+    ConnectionPools, ConnectorCapabilities, ConnectorOnboarding, Customers, Disputes, Drain,
+    EphemeralKey, FeatureMatrix, Files, Forex, Gsm, Health, Hypersense, Mandates, MerchantAccount,
+    MerchantConnectorAccount, Metrics, PaymentLink, PaymentMethods, Payments, Poll, ProcessTracker,
+    ProcessTrackerDeprecated, Profile, ProfileAcquirer, ProfileNew, Refunds, Relay, RelayWebhooks,
+    Scim, SessionState, Surcharge, ThreeDsDecisionRule, User, UserDeprecated, Webhooks,
 };
-#[cfg(feature = "olap")]
-pub use self::app::{Blocklist, Organization, Routing, Subscription, Verify, WebhookEvents};
 #[cfg(feature = "payouts")]
 pub use self::app::{PayoutLink, Payouts};
 #[cfg(feature = "v2")]
@@ -1068,6 +1068,99 @@ pub async fn merchant_account_transfer_keys(
     .await
 }
 
+/// Merchant Account - Rotate Key
+///
+/// Rotate the Merchant Encryption key in keymanager and re-encrypt the merchant's data
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantKeyRotate))]
+pub async fn merchant_account_rotate_key(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::MerchantKeyRotate;
+    let merchant_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, req, _| rotate_merchant_key(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Migrate Keys
+///
+/// Schedule the transfer of all the locally encrypted merchant keys to the keymanager
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantKeyMigrate))]
+pub async fn merchant_account_migrate_keys(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Json<api_models::admin::MerchantKeyMigrationRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantKeyMigrate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, _, req, _| schedule_merchant_key_migration(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Retrieve Key Migration
+///
+/// Retrieve the progress of the migration of the merchant keys to the keymanager
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantKeyMigrationRetrieve))]
+pub async fn merchant_account_retrieve_key_migration(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::MerchantKeyMigrationRetrieve;
+    let migration_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        migration_id,
+        |state, _, req, _| retrieve_merchant_key_migration(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Key Manager - Reload Certificates
+///
+/// Reload the mTLS certificates of the key manager from their files and rebuild the client
+#[cfg(feature = "keymanager_mtls")]
+#[instrument(skip_all, fields(flow = ?Flow::KeyManagerCertificatesReload))]
+pub async fn key_manager_certificates_reload(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::KeyManagerCertificatesReload;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| reload_key_manager_certificates(state),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Merchant Account - Platform Account
 ///
 /// Enable platform account
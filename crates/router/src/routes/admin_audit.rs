@@ -0,0 +1,75 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::admin_audit as admin_audit_api;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{admin_audit, api_locking},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+    types::storage,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::AdminAuditEventsList))]
+pub async fn list_admin_audit_events(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<admin_audit_api::AdminAuditEventListQuery>,
+) -> impl Responder {
+    let flow = Flow::AdminAuditEventsList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, query, _| {
+            admin_audit::list_admin_audit_events(
+                state,
+                storage::AdminAuditEventEntity::Merchant(auth.merchant_account.get_id().to_owned()),
+                auth.profile_id,
+                query,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::OrganizationAdminAuditEventsList))]
+pub async fn list_organization_admin_audit_events(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<admin_audit_api::AdminAuditEventListQuery>,
+) -> impl Responder {
+    let flow = Flow::OrganizationAdminAuditEventsList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, query, _| {
+            admin_audit::list_admin_audit_events(
+                state,
+                storage::AdminAuditEventEntity::Organization(
+                    auth.merchant_account.get_org_id().to_owned(),
+                ),
+                None,
+                query,
+            )
+        },
+        &auth::JWTAuth {
+            permission: Permission::OrganizationAccountRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -25,8 +25,9 @@ pub async fn api_key_create(
         state,
         &req,
         payload,
-        |state, auth_data, payload, _| async {
-            api_keys::create_api_key(state, payload, auth_data.key_store).await
+        |state, auth_data, payload, req_state| async move {
+            let caller_scopes = api_keys::get_caller_scopes(req_state.auth_type.as_ref());
+            api_keys::create_api_key(state, payload, auth_data.key_store, caller_scopes).await
         },
         auth::auth_type(
             &auth::PlatformOrgAdminAuthWithMerchantIdFromRoute {
@@ -59,8 +60,12 @@ pub async fn api_key_create(
         state,
         &req,
         payload,
-        |state, auth::AuthenticationDataWithoutProfile { key_store, .. }, payload, _| async {
-            api_keys::create_api_key(state, payload, key_store).await
+        |state,
+         auth::AuthenticationDataWithoutProfile { key_store, .. },
+         payload,
+         req_state| async move {
+            let caller_scopes = api_keys::get_caller_scopes(req_state.auth_type.as_ref());
+            api_keys::create_api_key(state, payload, key_store, caller_scopes).await
         },
         auth::auth_type(
             &auth::AdminApiAuthWithMerchantIdFromHeader,
@@ -225,6 +230,87 @@ pub async fn api_key_update(
     .await
 }
 
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::ApiKeyRotate))]
+pub async fn api_key_rotate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ApiKeyId,
+    )>,
+    json_payload: web::Json<api_types::RotateApiKeyRequest>,
+) -> impl Responder {
+    let flow = Flow::ApiKeyRotate;
+    let (merchant_id, key_id) = path.into_inner();
+    let mut payload = json_payload.into_inner();
+    payload.key_id = key_id;
+    payload.merchant_id.clone_from(&merchant_id);
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, req_state| {
+            let caller_scopes = api_keys::get_caller_scopes(req_state.auth_type.as_ref());
+            api_keys::rotate_api_key(state, payload, caller_scopes)
+        },
+        auth::auth_type(
+            &auth::PlatformOrgAdminAuthWithMerchantIdFromRoute {
+                merchant_id_from_route: merchant_id.clone(),
+                is_admin_auth_allowed: true,
+            },
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantApiKeyWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v2")]
+#[instrument(skip_all, fields(flow = ?Flow::ApiKeyRotate))]
+pub async fn api_key_rotate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    key_id: web::Path<common_utils::id_type::ApiKeyId>,
+    json_payload: web::Json<api_types::RotateApiKeyRequest>,
+) -> impl Responder {
+    let flow = Flow::ApiKeyRotate;
+    let mut payload = json_payload.into_inner();
+    payload.key_id = key_id.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state,
+         auth::AuthenticationDataWithoutProfile {
+             merchant_account, ..
+         },
+         mut payload,
+         req_state| {
+            payload.merchant_id = merchant_account.get_id().to_owned();
+            let caller_scopes = api_keys::get_caller_scopes(req_state.auth_type.as_ref());
+            api_keys::rotate_api_key(state, payload, caller_scopes)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromHeader,
+            &auth::JWTAuthMerchantFromHeader {
+                required_permission: Permission::MerchantApiKeyWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(feature = "v1")]
 #[instrument(skip_all, fields(flow = ?Flow::ApiKeyRevoke))]
 pub async fn api_key_revoke(
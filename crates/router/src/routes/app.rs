@@ -1,4 +1,4 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{borrow::Cow, collections::HashMap, sync::Arc};
 
 use actix_web::{web, Scope};
 #[cfg(all(feature = "olap", feature = "v1"))]
@@ -8,7 +8,7 @@ use api_models::routing::RuleMigrationQuery;
 use common_enums::TransactionType;
 #[cfg(feature = "partial-auth")]
 use common_utils::crypto::Blake3;
-use common_utils::id_type;
+use common_utils::{id_type, keymanager::KeyManagerInterface};
 #[cfg(feature = "email")]
 use external_services::email::{
     no_email::NoEmailClient, ses::AwsSes, smtp::SmtpServer, EmailClientConfigs, EmailService,
@@ -22,6 +22,7 @@ use external_services::{
 use hyperswitch_interfaces::{
     crm::CrmInterface,
     encryption_interface::EncryptionManagementInterface,
+    geo_ip::GeoIpInterface,
     secrets_interface::secret_state::{RawSecret, SecuredSecret},
 };
 use router_env::tracing_actix_web::RequestId;
@@ -30,14 +31,28 @@ use storage_impl::{config::TenantConfig, redis::RedisStore, MockDb};
 use tokio::sync::oneshot;
 
 use self::settings::Tenant;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::admin_audit;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::auth_rate_alerts;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::connector_audit_log;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::currency;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::data_retention;
 #[cfg(feature = "dummy_connector")]
 use super::dummy_connector::*;
 #[cfg(all(any(feature = "v1", feature = "v2"), feature = "oltp"))]
 use super::ephemeral_key::*;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::event_stream;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::list_views;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::payment_methods;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::payment_review;
 #[cfg(feature = "payouts")]
 use super::payout_link::*;
 #[cfg(feature = "payouts")]
@@ -52,8 +67,12 @@ use super::proxy;
 use super::recovery_webhooks::*;
 #[cfg(all(feature = "oltp", feature = "v2"))]
 use super::refunds;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::reports;
 #[cfg(feature = "olap")]
 use super::routing;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::signing_keys;
 #[cfg(all(feature = "oltp", feature = "v2"))]
 use super::tokenization as tokenization_routes;
 #[cfg(all(feature = "olap", any(feature = "v1", feature = "v2")))]
@@ -61,8 +80,8 @@ use super::verification::{apple_pay_merchant_registration, retrieve_apple_pay_ve
 #[cfg(feature = "oltp")]
 use super::webhooks::*;
 use super::{
-    admin, api_keys, cache::*, chat, connector_onboarding, disputes, files, gsm, health::*,
-    profiles, relay, user, user_role,
+    admin, api_keys, cache::*, chat, connection_pools, connector_onboarding, disputes, drain,
+    files, gsm, health::*, profiles, relay, user, user_role,
 };
 #[cfg(feature = "v1")]
 use super::{
@@ -104,12 +123,44 @@ pub use crate::{
 use crate::{
     configs::{secrets_transformers, Settings},
     db::kafka_store::{KafkaStore, TenantID},
-    routes::{hypersense as hypersense_routes, three_ds_decision_rule},
+    routes::{hypersense as hypersense_routes, surcharge, three_ds_decision_rule},
+    services::event_sink::EventSinkClient,
+    types::domain,
 };
 
+/// Details of the client which made the request
+#[derive(Clone, Debug, Default)]
+pub struct ClientDetails {
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+impl ClientDetails {
+    pub fn from_request(request: &actix_web::HttpRequest, trusted_proxy_count: usize) -> Self {
+        let ip_address = crate::services::authentication::ip_allowlist::get_client_ip_address(
+            request.headers(),
+            trusted_proxy_count,
+        )
+        .map(|ip_address| ip_address.to_string())
+        .or_else(|| request.peer_addr().map(|address| address.ip().to_string()));
+        let user_agent = request
+            .headers()
+            .get(actix_web::http::header::USER_AGENT)
+            .and_then(|user_agent| user_agent.to_str().ok())
+            .map(ToOwned::to_owned);
+
+        Self {
+            ip_address,
+            user_agent,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct ReqState {
     pub event_context: events::EventContext<crate::events::EventType, EventsHandler>,
+    /// Populated once the request has been authenticated
+    pub auth_type: Option<crate::services::authentication::AuthenticationType>,
 }
 
 #[derive(Clone)]
@@ -127,6 +178,7 @@ pub struct SessionState {
     pub pool: AnalyticsProvider,
     pub file_storage_client: Arc<dyn FileStorageInterface>,
     pub request_id: Option<RequestId>,
+    pub client_details: ClientDetails,
     pub base_url: String,
     pub tenant: Tenant,
     #[cfg(feature = "olap")]
@@ -135,8 +187,16 @@ pub struct SessionState {
     pub theme_storage_client: Arc<dyn FileStorageInterface>,
     pub locale: String,
     pub crm_client: Arc<dyn CrmInterface>,
+    pub geo_ip_client: Arc<dyn GeoIpInterface>,
+    pub event_sink_client: Arc<EventSinkClient>,
+    /// Profile for which the requests sent to connectors while serving the request are captured
+    /// in the connector audit log
+    pub connector_audit_profile_id: Option<id_type::ProfileId>,
     pub infra_components: Option<serde_json::Value>,
     pub enhancement: Option<HashMap<String, String>>,
+    pub key_manager_backend: Option<Arc<dyn KeyManagerInterface>>,
+    #[cfg(feature = "keymanager_mtls")]
+    pub key_manager_certificates: crate::services::key_manager::KeyManagerCertificates,
 }
 impl scheduler::SchedulerSessionState for SessionState {
     fn get_db(&self) -> Box<dyn SchedulerInterface> {
@@ -147,6 +207,7 @@ impl SessionState {
     pub fn get_req_state(&self) -> ReqState {
         ReqState {
             event_context: events::EventContext::new(self.event_handler.clone()),
+            auth_type: None,
         }
     }
     pub fn get_grpc_headers(&self) -> GrpcHeaders {
@@ -167,6 +228,24 @@ impl SessionState {
     }
 }
 
+impl SessionState {
+    /// Returns the state with which the requests sent to connectors are captured in the connector
+    /// audit log, if the profile has opted in to it
+    pub fn with_connector_audit_capture(
+        &self,
+        business_profile: &domain::Profile,
+    ) -> Cow<'_, Self> {
+        if business_profile.is_connector_audit_capture_enabled == Some(true) {
+            Cow::Owned(Self {
+                connector_audit_profile_id: Some(business_profile.get_id().to_owned()),
+                ..self.clone()
+            })
+        } else {
+            Cow::Borrowed(self)
+        }
+    }
+}
+
 pub trait SessionStateInfo {
     fn conf(&self) -> settings::Settings<RawSecret>;
     fn store(&self) -> Box<dyn StorageInterface>;
@@ -259,8 +338,13 @@ pub struct AppState {
     pub grpc_client: Arc<GrpcClients>,
     pub theme_storage_client: Arc<dyn FileStorageInterface>,
     pub crm_client: Arc<dyn CrmInterface>,
+    pub geo_ip_client: Arc<dyn GeoIpInterface>,
+    pub event_sink_client: Arc<EventSinkClient>,
     pub infra_components: Option<serde_json::Value>,
     pub enhancement: Option<HashMap<String, String>>,
+    pub key_manager_backend: Option<Arc<dyn KeyManagerInterface>>,
+    #[cfg(feature = "keymanager_mtls")]
+    pub key_manager_certificates: crate::services::key_manager::KeyManagerCertificates,
 }
 impl scheduler::SchedulerAppState for AppState {
     fn get_tenants(&self) -> Vec<id_type::TenantId> {
@@ -363,6 +447,32 @@ impl AppState {
             .await
             .expect("Failed to create encryption client");
 
+        #[allow(clippy::expect_used)]
+        let key_manager_backend = conf
+            .key_manager
+            .get_inner()
+            .backend
+            .get_key_manager_backend()
+            .await
+            .expect("Failed to create key manager backend");
+
+        #[cfg(feature = "keymanager_mtls")]
+        #[allow(clippy::expect_used)]
+        let key_manager_certificates =
+            crate::services::key_manager::KeyManagerCertificates::new(conf.key_manager.get_inner())
+                .await
+                .expect("Failed to load key manager certificates");
+        #[cfg(feature = "keymanager_mtls")]
+        key_manager_certificates.spawn_watcher();
+
+        let data_key_cache = conf.key_manager.get_inner().data_key_cache;
+        if data_key_cache.enabled {
+            storage_impl::redis::cache::init_data_key_cache(
+                data_key_cache.ttl_in_secs,
+                data_key_cache.max_entries,
+            );
+        }
+
         Box::pin(async move {
             let testable = storage_impl == StorageImpl::PostgresqlTest;
             #[allow(clippy::expect_used)]
@@ -423,6 +533,8 @@ impl AppState {
             let file_storage_client = conf.file_storage.get_file_storage_client().await;
             let theme_storage_client = conf.theme.storage.get_file_storage_client().await;
             let crm_client = conf.crm.get_crm_client().await;
+            let geo_ip_client = conf.geo_ip.get_geo_ip_client().await;
+            let event_sink_client = Arc::new(EventSinkClient::default());
 
             let grpc_client = conf.grpc_client.get_grpc_client_interface().await;
             let infra_component_values = Self::process_env_mappings(conf.infra_values.clone());
@@ -447,8 +559,13 @@ impl AppState {
                 grpc_client,
                 theme_storage_client,
                 crm_client,
+                geo_ip_client,
+                event_sink_client,
                 infra_components: infra_component_values,
                 enhancement,
+                key_manager_backend,
+                #[cfg(feature = "keymanager_mtls")]
+                key_manager_certificates,
             }
         })
         .await
@@ -533,6 +650,7 @@ impl AppState {
             pool: self.pools.get(tenant).ok_or_else(err)?.clone(),
             file_storage_client: self.file_storage_client.clone(),
             request_id: self.request_id,
+            client_details: ClientDetails::default(),
             base_url: tenant_conf.base_url.clone(),
             tenant: tenant_conf.clone(),
             #[cfg(feature = "email")]
@@ -543,8 +661,14 @@ impl AppState {
             theme_storage_client: self.theme_storage_client.clone(),
             locale: locale.unwrap_or(common_utils::consts::DEFAULT_LOCALE.to_string()),
             crm_client: self.crm_client.clone(),
+            geo_ip_client: self.geo_ip_client.clone(),
+            event_sink_client: self.event_sink_client.clone(),
+            connector_audit_profile_id: None,
             infra_components: self.infra_components.clone(),
             enhancement: self.enhancement.clone(),
+            key_manager_backend: self.key_manager_backend.clone(),
+            #[cfg(feature = "keymanager_mtls")]
+            key_manager_certificates: self.key_manager_certificates.clone(),
         })
     }
 
@@ -591,6 +715,16 @@ impl Health {
     }
 }
 
+pub struct Metrics;
+
+impl Metrics {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("metrics")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::get().to(prometheus_metrics)))
+    }
+}
+
 #[cfg(feature = "dummy_connector")]
 pub struct DummyConnector;
 
@@ -807,6 +941,11 @@ impl Payments {
                     web::resource("/profile/aggregate")
                         .route(web::get().to(payments::get_payments_aggregates_profile)),
                 )
+                .service(web::resource("/search").route(web::post().to(payments::payments_search)))
+                .service(
+                    web::resource("/profile/search")
+                        .route(web::post().to(payments::profile_payments_search)),
+                )
                 .service(
                     web::resource("/v2/profile/filter")
                         .route(web::get().to(payments::get_payment_filters_profile)),
@@ -820,6 +959,17 @@ impl Payments {
         {
             route = route
                 .service(web::resource("").route(web::post().to(payments::payments_create)))
+                .service(
+                    web::resource("/batch").route(web::post().to(payments::payments_batch_create)),
+                )
+                .service(
+                    web::resource("/batch/upload")
+                        .route(web::post().to(payments::payments_batch_upload)),
+                )
+                .service(
+                    web::resource("/batch/{batch_id}")
+                        .route(web::get().to(payments::payments_batch_retrieve)),
+                )
                 .service(
                     web::resource("/session_tokens")
                         .route(web::post().to(payments::payments_connector_session)),
@@ -836,6 +986,9 @@ impl Payments {
                 .service(
                     web::resource("/{payment_id}/post_session_tokens").route(web::post().to(payments::payments_post_session_tokens)),
                 )
+                .service(
+                    web::resource("/{payment_id}/dcc_offer").route(web::post().to(payments::payments_dcc_offer)),
+                )
                 .service(
                     web::resource("/{payment_id}/confirm").route(web::post().to(payments::payments_confirm)),
                 )
@@ -923,6 +1076,30 @@ impl Forex {
             .service(
                 web::resource("/convert_from_minor").route(web::get().to(currency::convert_forex)),
             )
+            .service(
+                web::resource("/conversion")
+                    .route(web::get().to(currency::get_currency_conversion)),
+            )
+            .service(web::resource("/quotes").route(web::post().to(currency::create_fx_quote)))
+            .service(
+                web::resource("/quotes/{quote_id}")
+                    .route(web::get().to(currency::retrieve_fx_quote)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct DecisionManager;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl DecisionManager {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/decision-manager")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/validate")
+                    .route(web::post().to(routing::validate_decision_manager_config)),
+            )
     }
 }
 
@@ -987,6 +1164,67 @@ impl Routing {
                     routing::migrate_routing_rules_for_profile(state, req, query)
                 },
             )))
+            .service(
+                web::resource("/simulate").route(web::post().to(routing::routing_simulate_config)),
+            )
+            .service(
+                web::resource("/audit").route(web::get().to(routing::routing_retrieve_audit_log)),
+            )
+            .service(
+                web::resource("/connector-health")
+                    .route(web::get().to(routing::routing_retrieve_connector_health)),
+            )
+            .service(
+                web::resource("/{algorithm_id}/diff/{other_algorithm_id}")
+                    .route(web::get().to(routing::routing_diff_config)),
+            )
+            .service(
+                web::resource("/experiment/profile/{profile_id}")
+                    .route(web::get().to(routing::retrieve_routing_experiment_results)),
+            )
+            .service(
+                web::resource("/experiment/profile/{profile_id}/start")
+                    .route(web::post().to(routing::start_routing_experiment)),
+            )
+            .service(
+                web::resource("/experiment/profile/{profile_id}/stop")
+                    .route(web::post().to(routing::stop_routing_experiment)),
+            )
+            .service(
+                web::resource("/export/profile/{profile_id}")
+                    .route(web::get().to(routing::export_routing_config)),
+            )
+            .service(
+                web::resource("/import/profile/{profile_id}")
+                    .route(web::post().to(routing::import_routing_config)),
+            )
+            .service(
+                web::resource("/latency/profile/{profile_id}")
+                    .route(web::post().to(routing::upsert_latency_based_routing_config))
+                    .route(web::get().to(routing::retrieve_latency_based_routing_config)),
+            )
+            .service(
+                web::resource("/cost/profile/{profile_id}/fee_schedule/{merchant_connector_id}")
+                    .route(web::post().to(routing::upsert_connector_fee_schedule))
+                    .route(web::get().to(routing::retrieve_connector_fee_schedule))
+                    .route(web::delete().to(routing::delete_connector_fee_schedule)),
+            )
+            .service(
+                web::resource("/{profile_id}/versions")
+                    .route(web::get().to(routing::routing_retrieve_version_history)),
+            )
+            .service(
+                web::resource("/{profile_id}/rollback/{version}")
+                    .route(web::post().to(routing::routing_rollback_config)),
+            )
+            .service(
+                web::resource("/{profile_id}/activations")
+                    .route(web::get().to(routing::routing_list_scheduled_activations)),
+            )
+            .service(
+                web::resource("/{profile_id}/activations/{algorithm_id}/cancel")
+                    .route(web::post().to(routing::routing_cancel_scheduled_activation)),
+            )
             .service(
                 web::resource("/deactivate").route(web::post().to(|state, req, payload| {
                     routing::routing_unlink_config(state, req, payload, None)
@@ -1004,6 +1242,27 @@ impl Routing {
                     .route(web::get().to(routing::retrieve_surcharge_decision_manager_config))
                     .route(web::delete().to(routing::delete_surcharge_decision_manager_config)),
             )
+            .service(
+                web::resource("/decision/surcharge/profile/{profile_id}")
+                    .route(
+                        web::put()
+                            .to(routing::upsert_surcharge_decision_manager_config_for_profile),
+                    )
+                    .route(
+                        web::get()
+                            .to(routing::retrieve_surcharge_decision_manager_config_for_profile),
+                    )
+                    .route(
+                        web::delete()
+                            .to(routing::delete_surcharge_decision_manager_config_for_profile),
+                    ),
+            )
+            .service(
+                web::resource("/decision/three_ds")
+                    .route(web::put().to(routing::upsert_three_ds_decision_manager_config))
+                    .route(web::get().to(routing::retrieve_three_ds_decision_manager_config))
+                    .route(web::delete().to(routing::delete_three_ds_decision_manager_config)),
+            )
             .service(
                 web::resource("/default/profile/{profile_id}").route(web::post().to(
                     |state, req, path, payload| {
@@ -1017,6 +1276,14 @@ impl Routing {
                     },
                 )),
             )
+            .service(
+                web::resource(
+                    "/default/profile/{profile_id}/payment_method_type/{payment_method_type}",
+                )
+                .route(web::post().to(routing::routing_update_payment_method_default_config))
+                .route(web::get().to(routing::routing_retrieve_payment_method_default_config))
+                .route(web::delete().to(routing::routing_delete_payment_method_default_config)),
+            )
             .service(
                 web::resource("/default/profile").route(web::get().to(|state, req| {
                     routing::routing_retrieve_default_config(state, req, &TransactionType::Payment)
@@ -1171,13 +1438,29 @@ pub struct Subscription;
 #[cfg(all(feature = "oltp", feature = "v1"))]
 impl Subscription {
     pub fn server(state: AppState) -> Scope {
-        web::scope("/subscription/create")
+        web::scope("/subscription")
             .app_data(web::Data::new(state.clone()))
-            .service(web::resource("").route(
+            .service(web::resource("/create").route(
                 web::post().to(|state, req, payload| {
                     subscription::create_subscription(state, req, payload)
                 }),
             ))
+            .service(
+                web::resource("/plans")
+                    .route(web::post().to(subscription::create_subscription_plan)),
+            )
+            .service(
+                web::resource("/plans/{plan_id}")
+                    .route(web::get().to(subscription::retrieve_subscription_plan)),
+            )
+            .service(
+                web::resource("/{subscription_id}")
+                    .route(web::get().to(subscription::retrieve_subscription)),
+            )
+            .service(
+                web::resource("/{subscription_id}/attach")
+                    .route(web::post().to(subscription::attach_subscription_payment_method)),
+            )
     }
 }
 
@@ -1227,6 +1510,18 @@ impl Customers {
                     web::resource("/{customer_id}/mandates")
                         .route(web::get().to(customers::get_customer_mandates)),
                 )
+                .service(
+                    web::resource("/{customer_id}/merge")
+                        .route(web::post().to(customers::customers_merge)),
+                )
+                .service(
+                    web::resource("/{customer_id}/redact")
+                        .route(web::post().to(customers::customers_redact)),
+                )
+                .service(
+                    web::resource("/{customer_id}/export")
+                        .route(web::get().to(customers::customers_export)),
+                )
                 .service(web::resource("/list").route(web::get().to(customers::customers_list)))
         }
 
@@ -1284,7 +1579,9 @@ impl Refunds {
                 .service(
                     web::resource("/{id}/manual-update")
                         .route(web::put().to(refunds_manual_update)),
-                );
+                )
+                .service(web::resource("/{id}/approve").route(web::post().to(refunds_approve)))
+                .service(web::resource("/{id}/reject").route(web::post().to(refunds_reject)));
         }
         #[cfg(feature = "oltp")]
         {
@@ -1295,7 +1592,8 @@ impl Refunds {
                     web::resource("/{id}")
                         .route(web::get().to(refunds_retrieve))
                         .route(web::post().to(refunds_update)),
-                );
+                )
+                .service(web::resource("/{id}/cancel").route(web::post().to(refunds_cancel)));
         }
         route
     }
@@ -1362,6 +1660,25 @@ impl Payouts {
                 );
         }
         route = route
+            .service(web::resource("/batch").route(web::post().to(payouts_batch_create)))
+            .service(
+                web::resource("/batch/{batch_id}").route(web::get().to(payouts_batch_retrieve)),
+            )
+            .service(
+                web::resource("/batch/{batch_id}/cancel")
+                    .route(web::post().to(payouts_batch_cancel)),
+            )
+            .service(
+                web::resource("/beneficiaries")
+                    .route(web::post().to(payout_beneficiary_create))
+                    .route(web::get().to(payout_beneficiary_list)),
+            )
+            .service(
+                web::resource("/beneficiaries/{beneficiary_id}")
+                    .route(web::get().to(payout_beneficiary_retrieve))
+                    .route(web::post().to(payout_beneficiary_update))
+                    .route(web::delete().to(payout_beneficiary_delete)),
+            )
             .service(
                 web::resource("/{payout_id}")
                     .route(web::get().to(payouts_retrieve))
@@ -1433,11 +1750,18 @@ impl PaymentMethods {
         #[cfg(feature = "olap")]
         {
             route =
-                route.service(web::resource("/filter").route(
-                    web::get().to(
+                route
+                    .service(web::resource("/filter").route(web::get().to(
                         payment_methods::list_countries_currencies_for_connector_payment_method,
-                    ),
-                ));
+                    )))
+                    .service(
+                        web::resource("/health")
+                            .route(web::get().to(payment_methods::list_payment_method_health)),
+                    )
+                    .service(
+                        web::resource("/{payment_method_id}/health")
+                            .route(web::get().to(payment_methods::retrieve_payment_method_health)),
+                    );
         }
         #[cfg(feature = "oltp")]
         {
@@ -1583,6 +1907,26 @@ impl Recon {
                 web::resource("/verify_token")
                     .route(web::get().to(recon_routes::verify_recon_token)),
             )
+            .service(
+                web::resource("/settlement_reports/upload")
+                    .route(web::post().to(recon_routes::upload_settlement_report)),
+            )
+            .service(
+                web::resource("/settlement_reports/pull")
+                    .route(web::post().to(recon_routes::pull_settlement_report)),
+            )
+            .service(
+                web::resource("/settlement_reports/{report_id}")
+                    .route(web::get().to(recon_routes::retrieve_settlement_report)),
+            )
+            .service(
+                web::resource("/exceptions")
+                    .route(web::get().to(recon_routes::list_recon_exceptions)),
+            )
+            .service(
+                web::resource("/payments/{payment_id}")
+                    .route(web::get().to(recon_routes::retrieve_payment_recon_status)),
+            )
     }
 }
 
@@ -1621,12 +1965,162 @@ impl Blocklist {
                     .route(web::post().to(blocklist::add_entry_to_blocklist))
                     .route(web::delete().to(blocklist::remove_entry_from_blocklist)),
             )
+            .service(
+                web::resource("/bulk")
+                    .route(web::post().to(blocklist::bulk_add_entries_to_blocklist)),
+            )
             .service(
                 web::resource("/toggle").route(web::post().to(blocklist::toggle_blocklist_guard)),
             )
     }
 }
 
+#[cfg(feature = "olap")]
+pub struct AdminAudit;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl AdminAudit {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/audit")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/events").route(web::get().to(admin_audit::list_admin_audit_events)),
+            )
+            .service(
+                web::resource("/events/organization")
+                    .route(web::get().to(admin_audit::list_organization_admin_audit_events)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct PaymentReviews;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl PaymentReviews {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/payment_reviews")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::get().to(payment_review::list_payment_reviews)))
+            .service(
+                web::resource("/{payment_id}/approve")
+                    .route(web::post().to(payment_review::approve_payment_review)),
+            )
+            .service(
+                web::resource("/{payment_id}/decline")
+                    .route(web::post().to(payment_review::decline_payment_review)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct ConnectorAuditLogs;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl ConnectorAuditLogs {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/connector_audit_logs")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/{merchant_id}/payments/{payment_id}")
+                    .route(web::get().to(connector_audit_log::list_connector_audit_logs)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct DataRetention;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl DataRetention {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/data_retention")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/policy")
+                    .route(web::get().to(data_retention::retrieve_data_retention_policy))
+                    .route(web::post().to(data_retention::upsert_data_retention_policy)),
+            )
+            .service(web::resource("/purge").route(web::post().to(data_retention::purge_data)))
+            .service(
+                web::resource("/purge_runs")
+                    .route(web::get().to(data_retention::list_data_retention_purge_runs)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct ListViews;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl ListViews {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/list_views")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::post().to(list_views::create_list_view))
+                    .route(web::get().to(list_views::list_list_views)),
+            )
+            .service(
+                web::resource("/{view_id}")
+                    .route(web::get().to(list_views::retrieve_list_view))
+                    .route(web::post().to(list_views::update_list_view))
+                    .route(web::delete().to(list_views::delete_list_view)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct AuthRateAlerts;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl AuthRateAlerts {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/auth_rate_alerts")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("").route(web::get().to(auth_rate_alerts::list_auth_rate_alerts)),
+            )
+            .service(
+                web::resource("/config")
+                    .route(web::get().to(auth_rate_alerts::retrieve_auth_rate_alert_config))
+                    .route(web::post().to(auth_rate_alerts::upsert_auth_rate_alert_config)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct Reports;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl Reports {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/reports")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/templates")
+                    .route(web::post().to(reports::create_report_template))
+                    .route(web::get().to(reports::list_report_templates)),
+            )
+            .service(
+                web::resource("/templates/{template_id}")
+                    .route(web::get().to(reports::retrieve_report_template))
+                    .route(web::post().to(reports::update_report_template))
+                    .route(web::delete().to(reports::delete_report_template)),
+            )
+            .service(
+                web::resource("/templates/{template_id}/run")
+                    .route(web::post().to(reports::run_report)),
+            )
+            .service(web::resource("/runs").route(web::get().to(reports::list_report_runs)))
+            .service(
+                web::resource("/runs/{run_id}/file")
+                    .route(web::get().to(reports::download_report_run_file)),
+            )
+    }
+}
+
 #[cfg(feature = "olap")]
 pub struct Organization;
 
@@ -1707,6 +2201,18 @@ impl MerchantAccount {
                 web::resource("/transfer")
                     .route(web::post().to(admin::merchant_account_transfer_keys)),
             )
+            .service(
+                web::resource("/keys/migrate")
+                    .route(web::post().to(admin::merchant_account_migrate_keys)),
+            )
+            .service(
+                web::resource("/keys/migrate/{migration_id}")
+                    .route(web::get().to(admin::merchant_account_retrieve_key_migration)),
+            )
+            .service(
+                web::resource("/{id}/keys/rotate")
+                    .route(web::post().to(admin::merchant_account_rotate_key)),
+            )
             .service(
                 web::resource("/kv").route(web::post().to(admin::merchant_account_toggle_all_kv)),
             )
@@ -1722,6 +2228,13 @@ impl MerchantAccount {
                     .route(web::post().to(admin::merchant_account_enable_platform_account)),
             )
         }
+        #[cfg(feature = "keymanager_mtls")]
+        {
+            routes = routes.service(
+                web::resource("/keymanager/certificates/reload")
+                    .route(web::post().to(admin::key_manager_certificates_reload)),
+            );
+        }
         routes.app_data(web::Data::new(state))
     }
 }
@@ -2002,6 +2515,9 @@ impl ApiKeys {
                     .route(web::put().to(api_keys::api_key_update))
                     .route(web::delete().to(api_keys::api_key_revoke)),
             )
+            .service(
+                web::resource("/{key_id}/rotate").route(web::post().to(api_keys::api_key_rotate)),
+            )
     }
 }
 
@@ -2018,6 +2534,26 @@ impl ApiKeys {
                     .route(web::post().to(api_keys::api_key_update))
                     .route(web::delete().to(api_keys::api_key_revoke)),
             )
+            .service(
+                web::resource("/{key_id}/rotate").route(web::post().to(api_keys::api_key_rotate)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct SigningKeys;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl SigningKeys {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/signing_keys/{merchant_id}")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(signing_keys::signing_key_create)))
+            .service(web::resource("/list").route(web::get().to(signing_keys::signing_key_list)))
+            .service(
+                web::resource("/{key_id}")
+                    .route(web::delete().to(signing_keys::signing_key_revoke)),
+            )
     }
 }
 
@@ -2059,6 +2595,12 @@ impl Disputes {
                 web::resource("/evidence/{dispute_id}")
                     .route(web::get().to(disputes::retrieve_dispute_evidence)),
             )
+            .service(
+                web::resource("/decision/profile/{profile_id}")
+                    .route(web::put().to(disputes::upsert_dispute_decision_config_for_profile))
+                    .route(web::get().to(disputes::retrieve_dispute_decision_config_for_profile))
+                    .route(web::delete().to(disputes::delete_dispute_decision_config_for_profile)),
+            )
             .service(
                 web::resource("/{dispute_id}").route(web::get().to(disputes::retrieve_dispute)),
             )
@@ -2109,6 +2651,36 @@ impl Cache {
     }
 }
 
+pub struct Drain;
+
+impl Drain {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/drain")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::get().to(drain::retrieve_drain_status))
+                    .route(web::post().to(drain::start_drain)),
+            )
+    }
+}
+
+pub struct ConnectionPools;
+
+impl ConnectionPools {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/connection_pools")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("").route(web::get().to(connection_pools::retrieve_connection_pools)),
+            )
+            .service(
+                web::resource("/resize")
+                    .route(web::post().to(connection_pools::resize_database_pool)),
+            )
+    }
+}
+
 pub struct PaymentLink;
 
 #[cfg(all(feature = "olap", feature = "v1"))]
@@ -2116,10 +2688,17 @@ impl PaymentLink {
     pub fn server(state: AppState) -> Scope {
         web::scope("/payment_link")
             .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(payment_link::payment_link_create)))
             .service(web::resource("/list").route(web::post().to(payment_link::payments_link_list)))
             .service(
                 web::resource("/{payment_link_id}")
-                    .route(web::get().to(payment_link::payment_link_retrieve)),
+                    .route(web::get().to(payment_link::payment_link_retrieve))
+                    .route(web::post().to(payment_link::payment_link_update))
+                    .route(web::delete().to(payment_link::payment_link_deactivate)),
+            )
+            .service(
+                web::resource("/{payment_link_id}/status")
+                    .route(web::get().to(payment_link::payment_link_status_retrieve)),
             )
             .service(
                 web::resource("{merchant_id}/{payment_id}")
@@ -2306,6 +2885,12 @@ impl Profile {
                 .service(
                     web::resource("/toggle_connector_agnostic_mit")
                         .route(web::post().to(profiles::toggle_connector_agnostic_mit)),
+                )
+                .service(
+                    web::resource("/frm_velocity_rules")
+                        .route(web::put().to(profiles::upsert_frm_velocity_rules))
+                        .route(web::get().to(profiles::retrieve_frm_velocity_rules))
+                        .route(web::delete().to(profiles::delete_frm_velocity_rules)),
                 ),
         );
 
@@ -2380,6 +2965,17 @@ impl Chat {
         route
     }
 }
+pub struct Surcharge;
+
+#[cfg(feature = "oltp")]
+impl Surcharge {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/surcharge")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/preview").route(web::post().to(surcharge::preview_surcharge)))
+    }
+}
+
 pub struct ThreeDsDecisionRule;
 
 #[cfg(feature = "oltp")]
@@ -2637,12 +3233,61 @@ impl User {
                                 .route(web::get().to(user::generate_recovery_codes)),
                         ),
                 )
+                .service(
+                    web::scope("/passkey")
+                        .service(
+                            web::resource("/begin")
+                                .route(web::get().to(user::passkey::begin_passkey_verification)),
+                        )
+                        .service(
+                            web::resource("/verify")
+                                .route(web::post().to(user::passkey::verify_passkey)),
+                        ),
+                )
                 .service(
                     web::resource("/terminate")
                         .route(web::get().to(user::terminate_two_factor_auth)),
                 ),
         );
 
+        route = route.service(
+            web::scope("/passkey")
+                .service(
+                    web::resource("/register/begin")
+                        .route(web::get().to(user::passkey::begin_passkey_registration)),
+                )
+                .service(
+                    web::resource("/register/finish")
+                        .route(web::post().to(user::passkey::finish_passkey_registration)),
+                )
+                .service(
+                    web::resource("/signin/begin")
+                        .route(web::post().to(user::passkey::begin_passkey_signin)),
+                )
+                .service(
+                    web::resource("/signin").route(web::post().to(user::passkey::passkey_signin)),
+                )
+                .service(web::resource("/list").route(web::get().to(user::passkey::list_passkeys)))
+                .service(
+                    web::resource("/{passkey_id}")
+                        .route(web::put().to(user::passkey::update_passkey))
+                        .route(web::delete().to(user::passkey::delete_passkey)),
+                ),
+        );
+
+        route = route.service(
+            web::scope("/sessions")
+                .service(
+                    web::resource("")
+                        .route(web::get().to(user::session::list_user_sessions))
+                        .route(web::delete().to(user::session::revoke_all_user_sessions)),
+                )
+                .service(
+                    web::resource("/{session_id}")
+                        .route(web::delete().to(user::session::revoke_user_session)),
+                ),
+        );
+
         route = route.service(
             web::scope("/auth")
                 .service(
@@ -2851,6 +3496,34 @@ impl User {
     }
 }
 
+pub struct Scim;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl Scim {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/scim/v2")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/Users")
+                    .route(web::get().to(user::scim::list_users))
+                    .route(web::post().to(user::scim::create_user)),
+            )
+            .service(
+                web::resource("/Users/{user_id}")
+                    .route(web::get().to(user::scim::retrieve_user))
+                    .route(web::put().to(user::scim::replace_user))
+                    .route(web::patch().to(user::scim::patch_user))
+                    .route(web::delete().to(user::scim::delete_user)),
+            )
+            .service(web::resource("/Groups").route(web::get().to(user::scim::list_groups)))
+            .service(
+                web::resource("/Groups/{group_id}")
+                    .route(web::get().to(user::scim::retrieve_group))
+                    .route(web::patch().to(user::scim::patch_group)),
+            )
+    }
+}
+
 pub struct ConnectorOnboarding;
 
 #[cfg(all(feature = "olap", feature = "v1"))]
@@ -2881,9 +3554,14 @@ impl WebhookEvents {
     pub fn server(config: AppState) -> Scope {
         web::scope("/events")
             .app_data(web::Data::new(config))
+            .service(web::resource("/stream").route(web::get().to(event_stream::stream_events)))
             .service(web::scope("/profile/list").service(web::resource("").route(
                 web::post().to(webhook_events::list_initial_webhook_delivery_attempts_with_jwtauth),
             )))
+            .service(
+                web::resource("/profile/delivery_health")
+                    .route(web::get().to(webhook_events::get_webhook_delivery_health)),
+            )
             .service(
                 web::scope("/{merchant_id}")
                     .service(web::resource("").route(
@@ -2914,6 +3592,21 @@ impl FeatureMatrix {
     }
 }
 
+#[cfg(feature = "olap")]
+pub struct ConnectorCapabilities;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl ConnectorCapabilities {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/connectors")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/{connector_name}/capabilities")
+                    .route(web::get().to(feature_matrix::fetch_connector_capabilities)),
+            )
+    }
+}
+
 #[cfg(feature = "olap")]
 pub struct ProcessTrackerDeprecated;
 
@@ -0,0 +1,117 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::auth_rate_alerts as auth_rate_alerts_api;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, auth_rate_alerts},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+    types::domain,
+};
+
+/// Authorization Rate Alerts - Retrieve Config
+///
+/// To retrieve the authorization rate alerting of the merchant
+#[instrument(skip_all, fields(flow = ?Flow::AuthRateAlertConfigRetrieve))]
+pub async fn retrieve_auth_rate_alert_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> impl Responder {
+    let flow = Flow::AuthRateAlertConfigRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            auth_rate_alerts::retrieve_auth_rate_alert_config(state, merchant_context)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Authorization Rate Alerts - Set Config
+///
+/// To set the authorization rate alerting of the merchant, which replaces any existing alerting
+#[instrument(skip_all, fields(flow = ?Flow::AuthRateAlertConfigUpsert))]
+pub async fn upsert_auth_rate_alert_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<auth_rate_alerts_api::AuthRateAlertConfigRequest>,
+) -> impl Responder {
+    let flow = Flow::AuthRateAlertConfigUpsert;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            auth_rate_alerts::upsert_auth_rate_alert_config(state, merchant_context, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Authorization Rate Alerts - List
+///
+/// To list the authorization rate alerts of the merchant, the latest alert at the top
+#[instrument(skip_all, fields(flow = ?Flow::AuthRateAlertsList))]
+pub async fn list_auth_rate_alerts(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<auth_rate_alerts_api::AuthRateAlertListQuery>,
+) -> impl Responder {
+    let flow = Flow::AuthRateAlertsList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, query, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            auth_rate_alerts::list_auth_rate_alerts(state, merchant_context, query)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -12,7 +12,7 @@ use crate::{
 #[utoipa::path(
     post,
     path = "/blocklist",
-    request_body = BlocklistRequest,
+    request_body = AddToBlocklistRequest,
     responses(
         (status = 200, description = "Fingerprint Blocked", body = BlocklistResponse),
         (status = 400, description = "Invalid Data")
@@ -53,6 +53,50 @@ pub async fn add_entry_to_blocklist(
     .await
 }
 
+#[utoipa::path(
+    post,
+    path = "/blocklist/bulk",
+    request_body = BulkAddToBlocklistRequest,
+    responses(
+        (status = 200, description = "Entries processed", body = BulkAddToBlocklistResponse),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Blocklist",
+    operation_id = "Bulk add entries to the blocklist",
+    security(("api_key" = []))
+)]
+pub async fn bulk_add_entries_to_blocklist(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_blocklist::BulkAddToBlocklistRequest>,
+) -> HttpResponse {
+    let flow = Flow::BulkAddToBlocklist;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, body, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            blocklist::bulk_add_entries_to_blocklist(state, merchant_context, body)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[utoipa::path(
     delete,
     path = "/blocklist",
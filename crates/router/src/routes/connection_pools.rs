@@ -0,0 +1,48 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::connection_pools as connection_pools_api;
+use router_env::{instrument, tracing, Flow};
+
+use super::AppState;
+use crate::{
+    core::{api_locking, connection_pools},
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::ConnectionPoolsRetrieve))]
+pub async fn retrieve_connection_pools(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> impl Responder {
+    let flow = Flow::ConnectionPoolsRetrieve;
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| connection_pools::retrieve_connection_pools(state),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ConnectionPoolResize))]
+pub async fn resize_database_pool(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<connection_pools_api::DatabasePoolResizeRequest>,
+) -> impl Responder {
+    let flow = Flow::ConnectionPoolResize;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, request, _| connection_pools::resize_database_pool(state, request),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,38 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::connector_audit_log as connector_audit_log_api;
+use common_utils::id_type;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, connector_audit_log},
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorAuditLogsList))]
+pub async fn list_connector_audit_logs(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, id_type::PaymentId)>,
+    query: web::Query<connector_audit_log_api::ConnectorAuditLogListQuery>,
+) -> impl Responder {
+    let flow = Flow::ConnectorAuditLogsList;
+    let (merchant_id, payment_id) = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, _, query, _| {
+            connector_audit_log::list_connector_audit_logs(
+                state,
+                merchant_id.clone(),
+                payment_id.clone(),
+                query,
+            )
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
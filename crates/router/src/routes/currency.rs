@@ -4,7 +4,8 @@ use router_env::Flow;
 use crate::{
     core::{api_locking, currency},
     routes::AppState,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+    types::domain,
 };
 
 #[cfg(feature = "v1")]
@@ -64,3 +65,97 @@ pub async fn convert_forex(
     ))
     .await
 }
+
+#[cfg(feature = "v1")]
+pub async fn get_currency_conversion(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    params: web::Query<api_models::currency::CurrencyConversionRequest>,
+) -> HttpResponse {
+    let flow = Flow::RetrieveForexFlow;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        params.into_inner(),
+        |state, _: auth::AuthenticationData, params, _| {
+            currency::get_currency_conversion(state, params)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::DashboardNoPermissionAuth,
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+pub async fn create_fx_quote(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::currency::FxQuoteCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::FxQuoteCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            currency::create_fx_quote(state, merchant_context, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPaymentWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+pub async fn retrieve_fx_quote(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::FxQuoteRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, quote_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            currency::retrieve_fx_quote(state, merchant_context, quote_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
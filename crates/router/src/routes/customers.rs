@@ -427,3 +427,115 @@ pub async fn get_customer_mandates(
     ))
     .await
 }
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersMerge))]
+pub async fn customers_merge(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::CustomerId>,
+    json_payload: web::Json<customers::CustomerMergeRequest>,
+) -> HttpResponse {
+    let flow = Flow::CustomersMerge;
+    let request_internal = customers::CustomerMergeRequestInternal {
+        customer_id: path.into_inner(),
+        request: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, auth: auth::AuthenticationData, request_internal, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            merge_customers(state, req_state, merchant_context, request_internal)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantCustomerWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersRedact))]
+pub async fn customers_redact(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::CustomerId>,
+) -> HttpResponse {
+    let flow = Flow::CustomersRedact;
+    let customer_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        customer_id,
+        |state, auth: auth::AuthenticationData, customer_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            redact_customer(state, merchant_context, customer_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantCustomerWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersExport))]
+pub async fn customers_export(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::CustomerId>,
+) -> HttpResponse {
+    let flow = Flow::CustomersExport;
+    let customer_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        customer_id,
+        |state, auth: auth::AuthenticationData, customer_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            export_customer_data(state, merchant_context, customer_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantCustomerRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,158 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::data_retention as data_retention_api;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, data_retention},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+    types::domain,
+};
+
+/// Data Retention - Retrieve Policy
+///
+/// To retrieve the data retention policy of the merchant
+#[instrument(skip_all, fields(flow = ?Flow::DataRetentionPolicyRetrieve))]
+pub async fn retrieve_data_retention_policy(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> impl Responder {
+    let flow = Flow::DataRetentionPolicyRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            data_retention::retrieve_data_retention_policy(state, merchant_context)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Data Retention - Set Policy
+///
+/// To set the data retention policy of the merchant, which replaces any existing policy
+#[instrument(skip_all, fields(flow = ?Flow::DataRetentionPolicyUpsert))]
+pub async fn upsert_data_retention_policy(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<data_retention_api::DataRetentionPolicyRequest>,
+) -> impl Responder {
+    let flow = Flow::DataRetentionPolicyUpsert;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            data_retention::upsert_data_retention_policy(state, merchant_context, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Data Retention - Purge
+///
+/// To purge the data which is past the retention policy of the merchant, or to only report it
+/// with a dry run
+#[instrument(skip_all, fields(flow = ?Flow::DataRetentionPurge))]
+pub async fn purge_data(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<data_retention_api::DataRetentionPurgeRequest>,
+) -> impl Responder {
+    let flow = Flow::DataRetentionPurge;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            let triggered_by = req_state
+                .auth_type
+                .as_ref()
+                .and_then(|auth_type| auth_type.get_created_by());
+            data_retention::purge_data(state, merchant_context, req, triggered_by)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Data Retention - List Purge Runs
+///
+/// To list the purges run for the merchant, the latest purge at the top
+#[instrument(skip_all, fields(flow = ?Flow::DataRetentionPurgeRunsList))]
+pub async fn list_data_retention_purge_runs(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<data_retention_api::DataRetentionPurgeRunListQuery>,
+) -> impl Responder {
+    let flow = Flow::DataRetentionPurgeRunsList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, query, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            data_retention::list_data_retention_purge_runs(state, merchant_context, query)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -7,6 +7,8 @@ use crate::{core::api_locking, services::authorization::permissions::Permission}
 pub mod utils;
 
 use super::app::AppState;
+#[cfg(feature = "v1")]
+use crate::core::dispute_decision_config;
 use crate::{
     core::disputes,
     services::{api, authentication as auth},
@@ -553,7 +555,7 @@ pub async fn delete_dispute_evidence(
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            disputes::delete_evidence(state, merchant_context, req)
+            disputes::delete_evidence(state, merchant_context, auth.profile_id, req)
         },
         auth::auth_type(
             &auth::HeaderAuth(auth::ApiKeyAuth {
@@ -570,6 +572,128 @@ pub async fn delete_dispute_evidence(
     .await
 }
 
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::DisputeDecisionManagerUpsertConfig))]
+pub async fn upsert_dispute_decision_config_for_profile(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::ProfileId>,
+    json_payload: web::Json<api_models::dispute_decision_configs::DisputeDecisionConfigReq>,
+) -> HttpResponse {
+    let flow = Flow::DisputeDecisionManagerUpsertConfig;
+    let profile_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (profile_id.clone(), json_payload.into_inner()),
+        |state, auth: auth::AuthenticationData, (profile_id, request), _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            dispute_decision_config::upsert_dispute_decision_config_for_profile(
+                state,
+                merchant_context,
+                profile_id,
+                request,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id,
+                required_permission: Permission::ProfileDisputeWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::DisputeDecisionManagerRetrieveConfig))]
+pub async fn retrieve_dispute_decision_config_for_profile(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::ProfileId>,
+) -> HttpResponse {
+    let flow = Flow::DisputeDecisionManagerRetrieveConfig;
+    let profile_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        profile_id.clone(),
+        |state, auth: auth::AuthenticationData, profile_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            dispute_decision_config::retrieve_dispute_decision_config_for_profile(
+                state,
+                merchant_context,
+                profile_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id,
+                required_permission: Permission::ProfileDisputeRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::DisputeDecisionManagerDeleteConfig))]
+pub async fn delete_dispute_decision_config_for_profile(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::ProfileId>,
+) -> HttpResponse {
+    let flow = Flow::DisputeDecisionManagerDeleteConfig;
+    let profile_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        profile_id.clone(),
+        |state, auth: auth::AuthenticationData, profile_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            dispute_decision_config::delete_dispute_decision_config_for_profile(
+                state,
+                merchant_context,
+                profile_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id,
+                required_permission: Permission::ProfileDisputeWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::DisputesAggregate))]
 pub async fn get_disputes_aggregate(
     state: web::Data<AppState>,
@@ -0,0 +1,42 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::AppState;
+use crate::{
+    core::{api_locking, drain},
+    services::{api, authentication as auth},
+};
+
+/// Puts this instance into drain mode, in which it stops accepting new payments and shuts down
+/// once the flows in progress are complete
+#[instrument(skip_all, fields(flow = ?Flow::DrainStart))]
+pub async fn start_drain(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    let flow = Flow::DrainStart;
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| drain::start_drain(state),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DrainStatus))]
+pub async fn retrieve_drain_status(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    let flow = Flow::DrainStatus;
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |_, _, _, _| drain::retrieve_drain_status(),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
@@ -3,3 +3,5 @@ pub const REFUND_ID_PREFIX: &str = "dummy_ref";
 pub const THREE_DS_CSS: &str = include_str!("threeds_page.css");
 pub const DUMMY_CONNECTOR_UPI_FAILURE_VPA_ID: &str = "failure@upi";
 pub const DUMMY_CONNECTOR_UPI_SUCCESS_VPA_ID: &str = "success@upi";
+pub const DUMMY_CONNECTOR_DEFAULT_DECLINE_MESSAGE: &str = "Payment declined";
+pub const DUMMY_CONNECTOR_DEFAULT_WEBHOOK_INTERVAL_IN_MS: u64 = 1000;
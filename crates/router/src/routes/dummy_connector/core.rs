@@ -1,11 +1,14 @@
 use app::SessionState;
 use common_utils::generate_id_with_default_len;
+use common_utils::request::RequestContent;
 use error_stack::ResultExt;
+use router_env::tracing::Instrument;
 
 use super::{errors, types, utils};
 use crate::{
+    logger,
     routes::{app, dummy_connector::consts},
-    services::api,
+    services::{self, api},
     utils::OptionExt,
 };
 
@@ -14,11 +17,22 @@ pub async fn payment(
     state: SessionState,
     req: types::DummyConnectorPaymentRequest,
 ) -> types::DummyConnectorResponse<types::DummyConnectorPaymentResponse> {
-    utils::tokio_mock_sleep(
-        state.conf.dummy_connector.payment_duration,
-        state.conf.dummy_connector.payment_tolerance,
-    )
-    .await;
+    let simulation = req.get_simulation().cloned();
+    let webhook_url = req.webhook_url.clone();
+
+    match simulation
+        .as_ref()
+        .and_then(|simulation| simulation.latency_in_ms)
+    {
+        Some(latency_in_ms) => utils::tokio_sleep(latency_in_ms).await,
+        None => {
+            utils::tokio_mock_sleep(
+                state.conf.dummy_connector.payment_duration,
+                state.conf.dummy_connector.payment_tolerance,
+            )
+            .await
+        }
+    }
 
     let payment_attempt: types::DummyConnectorPaymentAttempt = req.into();
     let payment_data =
@@ -38,9 +52,79 @@ pub async fn payment(
         state.conf.dummy_connector.payment_ttl,
     )
     .await?;
+
+    if let Some((simulation, webhook_url)) = simulation
+        .filter(|simulation| !simulation.webhook_events.is_empty())
+        .zip(webhook_url)
+    {
+        let state = state.clone();
+        let payment_data = payment_data.clone();
+        tokio::spawn(
+            async move {
+                send_simulated_webhooks(&state, payment_data, &simulation, &webhook_url).await
+            }
+            .in_current_span(),
+        );
+    }
+
     Ok(api::ApplicationResponse::Json(payment_data.into()))
 }
 
+/// Sends the webhooks the payment is simulated to have, updating the status of the payment
+/// before each of them
+#[cfg(feature = "dummy_connector")]
+async fn send_simulated_webhooks(
+    state: &SessionState,
+    mut payment_data: types::DummyConnectorPaymentData,
+    simulation: &types::DummyConnectorSimulation,
+    webhook_url: &str,
+) {
+    let webhook_interval_in_ms = simulation
+        .webhook_interval_in_ms
+        .unwrap_or(consts::DUMMY_CONNECTOR_DEFAULT_WEBHOOK_INTERVAL_IN_MS);
+
+    for event_type in simulation.webhook_events.iter().copied() {
+        utils::tokio_sleep(webhook_interval_in_ms).await;
+
+        payment_data.status = event_type.get_payment_status();
+        if let Err(error) = utils::store_data_in_redis(
+            state,
+            payment_data.payment_id.get_string_repr().to_owned(),
+            payment_data.clone(),
+            state.conf.dummy_connector.payment_ttl,
+        )
+        .await
+        {
+            logger::error!(
+                ?error,
+                "Failed to update the status of the simulated payment"
+            );
+            return;
+        }
+
+        let webhook = types::DummyConnectorWebhook {
+            event_type,
+            data: payment_data.clone().into(),
+        };
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(webhook_url)
+            .attach_default_headers()
+            .set_body(RequestContent::Json(Box::new(webhook)))
+            .build();
+
+        match services::call_connector_api(state, request, "dummy_connector_webhook").await {
+            Ok(Ok(_)) => logger::info!(?event_type, "Sent simulated webhook"),
+            Ok(Err(response)) => logger::warn!(
+                ?event_type,
+                status_code = response.status_code,
+                "Simulated webhook was not accepted"
+            ),
+            Err(error) => logger::error!(?error, ?event_type, "Failed to send simulated webhook"),
+        }
+    }
+}
+
 pub async fn payment_data(
     state: SessionState,
     req: types::DummyConnectorPaymentRetrieveRequest,
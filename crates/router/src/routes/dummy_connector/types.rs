@@ -22,6 +22,12 @@ pub enum Flow {
 
 impl FlowMetric for Flow {}
 
+impl crate::routes::lock_utils::GetApiIdentifier for Flow {
+    fn get_api_identifier(&self) -> Option<crate::routes::lock_utils::ApiIdentifier> {
+        None
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, strum::Display, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
@@ -39,6 +45,7 @@ pub enum DummyConnectors {
     AdyenTest,
     CheckoutTest,
     PaypalTest,
+    DummyAdvanced,
 }
 
 impl DummyConnectors {
@@ -107,6 +114,46 @@ impl DummyConnectorPaymentAttempt {
             payment_method_type: self.payment_request.payment_method_data.into(),
             next_action,
             return_url,
+            amount_captured: None,
+            error: None,
+        }
+    }
+
+    /// Builds the payment data with the outcome the payment is simulated to have, irrespective of
+    /// its payment method data
+    pub fn build_simulated_payment_data(
+        self,
+        simulation: &DummyConnectorSimulation,
+        redirect_url: String,
+    ) -> DummyConnectorPaymentData {
+        if let Some(code) = simulation.decline_code.clone() {
+            let error = DummyConnectorPaymentError {
+                code,
+                message: simulation
+                    .decline_message
+                    .clone()
+                    .unwrap_or_else(|| consts::DUMMY_CONNECTOR_DEFAULT_DECLINE_MESSAGE.to_string()),
+                reason: simulation.decline_message.clone(),
+            };
+            return DummyConnectorPaymentData {
+                error: Some(error),
+                ..self.build_payment_data(DummyConnectorStatus::Failed, None, None)
+            };
+        }
+
+        let payment_data = if simulation.three_ds_challenge {
+            let return_url = self.payment_request.return_url.clone();
+            self.build_payment_data(
+                DummyConnectorStatus::Processing,
+                Some(DummyConnectorNextAction::RedirectToUrl(redirect_url)),
+                return_url,
+            )
+        } else {
+            self.build_payment_data(DummyConnectorStatus::Succeeded, None, None)
+        };
+        DummyConnectorPaymentData {
+            amount_captured: simulation.captured_amount,
+            ..payment_data
         }
     }
 }
@@ -118,6 +165,74 @@ pub struct DummyConnectorPaymentRequest {
     pub payment_method_data: DummyConnectorPaymentMethodData,
     pub return_url: Option<String>,
     pub connector: DummyConnectors,
+    #[serde(default)]
+    pub simulation: Option<DummyConnectorSimulation>,
+    /// URL to which the webhooks of the payment are sent
+    #[serde(default)]
+    pub webhook_url: Option<String>,
+}
+
+impl DummyConnectorPaymentRequest {
+    /// Outcome the payment is simulated to have, which is honoured only by the `dummy_advanced`
+    /// connector
+    pub fn get_simulation(&self) -> Option<&DummyConnectorSimulation> {
+        self.simulation
+            .as_ref()
+            .filter(|_| self.connector == DummyConnectors::DummyAdvanced)
+    }
+}
+
+/// Outcome of a payment of the `dummy_advanced` connector, which is configured per payment
+#[derive(Clone, Debug, Default, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+pub struct DummyConnectorSimulation {
+    /// Decline the payment with this error code
+    pub decline_code: Option<String>,
+    /// Message of the error the payment is declined with
+    pub decline_message: Option<String>,
+    /// Time taken to respond to the payment request, instead of the configured payment duration
+    pub latency_in_ms: Option<u64>,
+    /// Require the customer to complete a 3DS challenge
+    #[serde(default)]
+    pub three_ds_challenge: bool,
+    /// Capture only this amount of the payment
+    pub captured_amount: Option<i64>,
+    /// Webhooks sent for the payment, in order
+    #[serde(default)]
+    pub webhook_events: Vec<DummyConnectorWebhookEventType>,
+    /// Time between two webhooks sent for the payment
+    pub webhook_interval_in_ms: Option<u64>,
+}
+
+#[derive(Clone, Copy, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorWebhookEventType {
+    PaymentProcessing,
+    PaymentSucceeded,
+    PaymentFailed,
+}
+
+impl DummyConnectorWebhookEventType {
+    /// Status the payment is updated to before the webhook is sent
+    pub fn get_payment_status(self) -> DummyConnectorStatus {
+        match self {
+            Self::PaymentProcessing => DummyConnectorStatus::Processing,
+            Self::PaymentSucceeded => DummyConnectorStatus::Succeeded,
+            Self::PaymentFailed => DummyConnectorStatus::Failed,
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DummyConnectorWebhook {
+    pub event_type: DummyConnectorWebhookEventType,
+    pub data: DummyConnectorPaymentResponse,
+}
+
+#[derive(Clone, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+pub struct DummyConnectorPaymentError {
+    pub code: String,
+    pub message: String,
+    pub reason: Option<String>,
 }
 
 pub trait GetPaymentMethodDetails {
@@ -296,6 +411,10 @@ pub struct DummyConnectorPaymentData {
     pub connector: DummyConnectors,
     pub next_action: Option<DummyConnectorNextAction>,
     pub return_url: Option<String>,
+    #[serde(default)]
+    pub amount_captured: Option<i64>,
+    #[serde(default)]
+    pub error: Option<DummyConnectorPaymentError>,
 }
 
 impl DummyConnectorPaymentData {
@@ -330,6 +449,8 @@ pub struct DummyConnectorPaymentResponse {
     pub created: PrimitiveDateTime,
     pub payment_method_type: DummyConnectorPaymentMethodType,
     pub next_action: Option<DummyConnectorNextAction>,
+    pub amount_captured: Option<i64>,
+    pub error: Option<DummyConnectorPaymentError>,
 }
 
 impl From<DummyConnectorPaymentData> for DummyConnectorPaymentResponse {
@@ -342,6 +463,8 @@ impl From<DummyConnectorPaymentData> for DummyConnectorPaymentResponse {
             created: value.created,
             payment_method_type: value.payment_method_type,
             next_action: value.next_action,
+            amount_captured: value.amount_captured,
+            error: value.error,
         }
     }
 }
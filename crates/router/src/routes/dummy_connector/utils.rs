@@ -28,6 +28,10 @@ pub async fn tokio_mock_sleep(delay: u64, tolerance: u64) {
     .await
 }
 
+pub async fn tokio_sleep(delay: u64) {
+    tokio::sleep(tokio::Duration::from_millis(delay)).await
+}
+
 pub async fn store_data_in_redis(
     state: &SessionState,
     key: String,
@@ -411,6 +415,9 @@ impl types::DummyConnectorPaymentData {
             "{}/dummy-connector/authorize/{}",
             state.base_url, payment_attempt.attempt_id
         );
+        if let Some(simulation) = payment_attempt.payment_request.get_simulation().cloned() {
+            return Ok(payment_attempt.build_simulated_payment_data(&simulation, redirect_url));
+        }
         payment_attempt
             .clone()
             .payment_request
@@ -0,0 +1,71 @@
+use actix_web::{http::header, web, HttpRequest, HttpResponse};
+use api_models::event_stream as event_stream_api;
+use common_utils::errors::CustomResult;
+use error_stack::report;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, errors, event_stream},
+    services::{
+        api, authentication as auth, authorization::permissions::Permission, ApplicationResponse,
+    },
+    types::domain,
+};
+
+/// Events - Stream
+///
+/// To stream the status changes of the payments, refunds and disputes of the merchant as
+/// Server-Sent Events. The request is authenticated and recorded like any other request, and the
+/// stream is only opened once it succeeds.
+#[instrument(skip_all, fields(flow = ?Flow::EventStream))]
+pub async fn stream_events(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::EventStream;
+    let subscription: CustomResult<
+        ApplicationResponse<event_stream_api::EventStreamSubscription>,
+        api_models::errors::types::ApiErrorResponse,
+    > = Box::pin(api::server_wrap_util(
+        &flow,
+        state.clone(),
+        req.headers(),
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            event_stream::open_event_stream(state, merchant_context)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await;
+
+    let subscription = match subscription {
+        Ok(ApplicationResponse::Json(subscription)) => subscription,
+        Ok(_) => {
+            return api::log_and_return_error_response(report!(
+                errors::ApiErrorResponse::InternalServerError
+            ))
+        }
+        Err(error) => return api::log_and_return_error_response(error),
+    };
+
+    match event_stream::get_event_stream(&state.conf.events, subscription) {
+        Ok(stream) => HttpResponse::Ok()
+            .content_type("text/event-stream")
+            .insert_header((header::CACHE_CONTROL, "no-cache"))
+            .insert_header(("X-Accel-Buffering", "no"))
+            .streaming(stream),
+        Err(error) => api::log_and_return_error_response(error),
+    }
+}
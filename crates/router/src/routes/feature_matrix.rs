@@ -1,6 +1,9 @@
+use std::{collections::HashSet, str::FromStr};
+
 use actix_web::{web, HttpRequest, Responder};
 use api_models::{connector_enums::Connector, feature_matrix};
 use common_enums::enums;
+use error_stack::{report, ResultExt};
 use hyperswitch_domain_models::{
     api::ApplicationResponse, router_response_types::PaymentMethodTypeMetadata,
 };
@@ -10,7 +13,10 @@ use strum::IntoEnumIterator;
 
 use crate::{
     self as app,
-    core::{api_locking::LockAction, errors::RouterResponse},
+    core::{
+        api_locking::LockAction,
+        errors::{self, RouterResponse},
+    },
     services::{api, authentication as auth, connector_integration_interface::ConnectorEnum},
     settings,
     types::api::{self as api_types, payments as payment_types},
@@ -72,6 +78,147 @@ pub async fn generate_feature_matrix(
     ))
 }
 
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorCapabilities))]
+pub async fn fetch_connector_capabilities(
+    state: web::Data<app::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ConnectorCapabilities;
+    let connector_name = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        connector_name,
+        |state, (), connector_name, _| get_connector_capabilities(state, connector_name),
+        &auth::NoAuth,
+        LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn get_connector_capabilities(
+    state: app::SessionState,
+    connector_name: String,
+) -> RouterResponse<feature_matrix::ConnectorCapabilitiesResponse> {
+    let connector_name = Connector::from_str(&connector_name).change_context(
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Invalid connector name: {connector_name}"),
+        },
+    )?;
+    let connector = api_types::feature_matrix::FeatureMatrixConnectorData::convert_connector(
+        &connector_name.to_string(),
+    )
+    .change_context(errors::ApiErrorResponse::GenericNotFoundError {
+        message: format!("Capabilities of connector {connector_name} are not available"),
+    })?;
+
+    let feature_details = build_connector_feature_details(
+        &state,
+        connector,
+        connector_name.to_string(),
+    )
+    .ok_or_else(|| {
+        report!(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Capabilities of connector {connector_name} are not available"),
+        })
+    })?;
+
+    Ok(ApplicationResponse::Json(build_connector_capabilities(
+        &state,
+        connector_name,
+        feature_details,
+    )))
+}
+
+/// Aggregates the payment method wise features of the connector into the flows it supports
+fn build_connector_capabilities(
+    state: &app::SessionState,
+    connector_name: Connector,
+    feature_details: feature_matrix::ConnectorFeatureMatrixResponse,
+) -> feature_matrix::ConnectorCapabilitiesResponse {
+    let supported_payment_methods = feature_details
+        .supported_payment_methods
+        .unwrap_or_default();
+
+    let supported_capture_methods = supported_payment_methods
+        .iter()
+        .flat_map(|payment_method| payment_method.supported_capture_methods.iter().copied())
+        .fold(Vec::new(), |mut capture_methods, capture_method| {
+            if !capture_methods.contains(&capture_method) {
+                capture_methods.push(capture_method);
+            }
+            capture_methods
+        });
+
+    // Currencies are restricted only if every payment method is restricted to specific currencies
+    let supported_currencies = supported_payment_methods
+        .iter()
+        .map(|payment_method| payment_method.supported_currencies.as_ref())
+        .try_fold(
+            HashSet::new(),
+            |mut currencies, payment_method_currencies| {
+                currencies.extend(payment_method_currencies?.iter().copied());
+                Some(currencies)
+            },
+        )
+        .filter(|_| !supported_payment_methods.is_empty());
+
+    let feature_status = |is_supported: bool| {
+        if is_supported {
+            enums::FeatureStatus::Supported
+        } else {
+            enums::FeatureStatus::NotSupported
+        }
+    };
+
+    let flows = feature_matrix::ConnectorFlowCapabilities {
+        supported_capture_methods,
+        refunds: feature_status(
+            supported_payment_methods
+                .iter()
+                .any(|payment_method| payment_method.refunds == enums::FeatureStatus::Supported),
+        ),
+        refund_window_in_days: state.conf.refund.max_age,
+        mandates: feature_status(
+            supported_payment_methods
+                .iter()
+                .any(|payment_method| payment_method.mandates == enums::FeatureStatus::Supported),
+        ),
+        network_tokens: feature_status(
+            state
+                .conf
+                .network_tokenization_supported_connectors
+                .connector_list
+                .contains(&connector_name),
+        ),
+        network_transaction_id: feature_status(
+            state
+                .conf
+                .network_transaction_id_supported_connectors
+                .connector_list
+                .contains(&connector_name),
+        ),
+        incremental_authorization: feature_status(
+            feature_details.supports_incremental_authorization,
+        ),
+        supported_webhook_flows: feature_details.supported_webhook_flows.unwrap_or_default(),
+    };
+
+    feature_matrix::ConnectorCapabilitiesResponse {
+        name: feature_details.name,
+        display_name: feature_details.display_name,
+        description: feature_details.description,
+        category: feature_details.category,
+        integration_status: feature_details.integration_status,
+        supported_payment_methods,
+        supported_currencies,
+        flows,
+    }
+}
+
 fn build_connector_feature_details(
     state: &app::SessionState,
     connector: ConnectorEnum,
@@ -95,6 +242,9 @@ fn build_connector_feature_details(
     let supported_webhook_flows = connector
         .get_supported_webhook_flows()
         .map(|webhook_flows| webhook_flows.to_vec());
+    let supports_incremental_authorization = connector.is_incremental_authorization_supported();
+    let supports_dcc = connector.is_dcc_supported();
+    let supports_account_updater = connector.is_account_updater_supported();
     let connector_about = connector.get_connector_about();
 
     connector_about.map(
@@ -106,6 +256,9 @@ fn build_connector_feature_details(
             category: connector_about.connector_type,
             supported_webhook_flows,
             supported_payment_methods,
+            supports_incremental_authorization,
+            supports_dcc,
+            supports_account_updater,
         },
     )
 }
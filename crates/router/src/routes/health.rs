@@ -1,13 +1,15 @@
 use actix_web::{web, HttpRequest};
 use api_models::health_check::RouterHealthCheckResponse;
+use error_stack::report;
 use router_env::{instrument, logger, tracing, Flow};
+use storage_impl::redis::kv_store::RedisConnInterface;
 
 use super::app;
 use crate::{
     core::{api_locking, health_check::HealthCheckInterface},
     errors::{self, RouterResponse},
     routes::metrics,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, drain},
 };
 /// .
 // #[logger::instrument(skip_all, name = "name1", level = "warn", fields( key1 = "val1" ))]
@@ -20,6 +22,85 @@ pub async fn health() -> impl actix_web::Responder {
     actix_web::HttpResponse::Ok().body("health is good")
 }
 
+/// Renders the metrics in the Prometheus text format, for scraping. The depth of the scheduler
+/// queue and the connection pools of each tenant are recorded on every scrape.
+#[instrument(skip_all, fields(flow = ?Flow::PrometheusMetrics))]
+pub async fn prometheus_metrics(state: web::Data<app::AppState>) -> impl actix_web::Responder {
+    record_scheduler_queue_depth(&state).await;
+    record_connection_pool_metrics(&state);
+
+    match router_env::metrics::prometheus::render_metrics() {
+        Some(Ok(rendered_metrics)) => actix_web::HttpResponse::Ok()
+            .content_type(router_env::metrics::prometheus::PROMETHEUS_CONTENT_TYPE)
+            .body(rendered_metrics),
+        Some(Err(error)) => {
+            logger::error!(?error, "Failed to collect the metrics");
+            actix_web::HttpResponse::InternalServerError().finish()
+        }
+        None => actix_web::HttpResponse::NotFound().finish(),
+    }
+}
+
+async fn record_scheduler_queue_depth(state: &app::AppState) {
+    let Some(scheduler_settings) = state.conf.scheduler.as_ref() else {
+        return;
+    };
+
+    for (tenant_id, store) in &state.stores {
+        let queue_depth = match store.get_redis_conn() {
+            Ok(redis_conn) => {
+                redis_conn
+                    .stream_get_length(&scheduler_settings.stream.as_str().into())
+                    .await
+            }
+            Err(error) => Err(error),
+        };
+
+        match queue_depth {
+            Ok(queue_depth) => metrics::SCHEDULER_QUEUE_DEPTH.record(
+                u64::try_from(queue_depth).unwrap_or(u64::MAX),
+                router_env::metric_attributes!(
+                    ("tenant", tenant_id.get_string_repr().to_owned()),
+                    ("stream", scheduler_settings.stream.clone()),
+                ),
+            ),
+            Err(error) => logger::warn!(
+                ?error,
+                tenant_id = tenant_id.get_string_repr(),
+                "Failed to get the depth of the scheduler queue"
+            ),
+        }
+    }
+}
+
+fn record_connection_pool_metrics(state: &app::AppState) {
+    for (tenant_id, store) in &state.stores {
+        let tenant = tenant_id.get_string_repr();
+
+        for pool in store.get_database_pools() {
+            pool.get_stats().record_metrics(tenant);
+        }
+
+        if let Ok(redis_conn) = store.get_redis_conn() {
+            let stats = redis_conn.get_pool_stats();
+            let attributes = router_env::metric_attributes!(("tenant", tenant.to_owned()));
+
+            storage_impl::metrics::REDIS_POOL_SIZE.record(
+                u64::try_from(stats.pool_size).unwrap_or(u64::MAX),
+                attributes,
+            );
+            storage_impl::metrics::REDIS_POOL_CONNECTED_CLIENTS.record(
+                u64::try_from(stats.connected_clients).unwrap_or(u64::MAX),
+                attributes,
+            );
+            storage_impl::metrics::REDIS_POOL_QUEUED_COMMANDS.record(
+                u64::try_from(stats.queued_commands).unwrap_or(u64::MAX),
+                attributes,
+            );
+        }
+    }
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::DeepHealthCheck))]
 pub async fn deep_health_check(
     state: web::Data<app::AppState>,
@@ -46,6 +127,14 @@ async fn deep_health_check_func(
 ) -> RouterResponse<RouterHealthCheckResponse> {
     logger::info!("Deep health check was called");
 
+    // A draining instance is reported as not ready, so that the load balancers stop sending
+    // requests to it
+    if drain::is_draining() {
+        return Err(report!(errors::ApiErrorResponse::ServiceDraining {
+            retry_after_secs: state.conf.drain.retry_after_in_secs,
+        }));
+    }
+
     logger::debug!("Database health check begin");
 
     let db_status = state.health_check_db().await.map_err(|error| {
@@ -165,6 +254,18 @@ async fn deep_health_check_func(
 
     logger::debug!("Unified Connector Service health check end");
 
+    logger::debug!("Key Manager health check begin");
+
+    let key_manager_status = state.health_check_key_manager().await.map_err(|error| {
+        let message = error.to_string();
+        error.change_context(errors::ApiErrorResponse::HealthCheckError {
+            component: "Key Manager",
+            message,
+        })
+    })?;
+
+    logger::debug!("Key Manager health check end");
+
     let response = RouterHealthCheckResponse {
         database: db_status.into(),
         redis: redis_status.into(),
@@ -179,6 +280,7 @@ async fn deep_health_check_func(
         #[cfg(feature = "dynamic_routing")]
         decision_engine: decision_engine_health_check.into(),
         unified_connector_service: unified_connector_service_status.into(),
+        key_manager: key_manager_status,
     };
 
     Ok(api::ApplicationResponse::Json(response))
@@ -0,0 +1,140 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::list_views as list_views_api;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, list_views},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+/// List Views - Create
+///
+/// To save a named set of filters of a list endpoint, which can be applied by passing its
+/// `view_id` to the list endpoint
+#[instrument(skip_all, fields(flow = ?Flow::ListViewCreate))]
+pub async fn create_list_view(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<list_views_api::ListViewCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::ListViewCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, user: auth::UserFromToken, req, _| list_views::create_list_view(state, user, req),
+        &auth::JWTAuth {
+            permission: Permission::ProfileAccountWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// List Views - List
+///
+/// To list the views created by the user and the views shared within the merchant
+#[instrument(skip_all, fields(flow = ?Flow::ListViewList))]
+pub async fn list_list_views(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<list_views_api::ListViewListQuery>,
+) -> impl Responder {
+    let flow = Flow::ListViewList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, user: auth::UserFromToken, query, _| {
+            list_views::list_list_views(state, user, query)
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileAccountRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// List Views - Retrieve
+///
+/// To retrieve a view created by the user or shared within the merchant
+#[instrument(skip_all, fields(flow = ?Flow::ListViewRetrieve))]
+pub async fn retrieve_list_view(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ListViewRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, user: auth::UserFromToken, view_id, _| {
+            list_views::retrieve_list_view(state, user, view_id)
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileAccountRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// List Views - Update
+///
+/// To update a view created by the user
+#[instrument(skip_all, fields(flow = ?Flow::ListViewUpdate))]
+pub async fn update_list_view(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<list_views_api::ListViewUpdateRequest>,
+) -> impl Responder {
+    let flow = Flow::ListViewUpdate;
+    let view_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, user: auth::UserFromToken, req, _| {
+            list_views::update_list_view(state, user, view_id.clone(), req)
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileAccountWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// List Views - Delete
+///
+/// To delete a view created by the user
+#[instrument(skip_all, fields(flow = ?Flow::ListViewDelete))]
+pub async fn delete_list_view(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ListViewDelete;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, user: auth::UserFromToken, view_id, _| {
+            list_views::delete_list_view(state, user, view_id)
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileAccountWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
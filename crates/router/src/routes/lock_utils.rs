@@ -51,6 +51,42 @@ pub enum ApiIdentifier {
     ThreeDsDecisionRule,
     GenericTokenization,
     RecoveryDataBackfill,
+    AdminAudit,
+    ConnectorAuditLog,
+    PaymentReview,
+    DataRetention,
+    ListViews,
+    Reports,
+    EventStream,
+    AuthRateAlerts,
+    ConnectionPools,
+}
+
+/// Flows which are grouped under an [`ApiIdentifier`], flows not defined in `router_env` do not
+/// belong to any group
+pub trait GetApiIdentifier {
+    fn get_api_identifier(&self) -> Option<ApiIdentifier>;
+
+    /// Whether the flow creates payments, such flows are rejected while the instance is draining
+    fn is_payment_creation(&self) -> bool {
+        false
+    }
+}
+
+impl GetApiIdentifier for Flow {
+    fn get_api_identifier(&self) -> Option<ApiIdentifier> {
+        Some(ApiIdentifier::from(self.clone()))
+    }
+
+    fn is_payment_creation(&self) -> bool {
+        matches!(
+            self,
+            Self::PaymentsCreate
+                | Self::PaymentsCreateIntent
+                | Self::PaymentsCreateAndConfirmIntent
+                | Self::PaymentsBatchCreate
+        )
+    }
 }
 
 impl From<Flow> for ApiIdentifier {
@@ -61,6 +97,10 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantsAccountUpdate
             | Flow::MerchantsAccountDelete
             | Flow::MerchantTransferKey
+            | Flow::MerchantKeyRotate
+            | Flow::MerchantKeyMigrate
+            | Flow::MerchantKeyMigrationRetrieve
+            | Flow::KeyManagerCertificatesReload
             | Flow::MerchantAccountList
             | Flow::EnablePlatformAccount => Self::MerchantAccount,
 
@@ -80,21 +120,49 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RoutingDeleteConfig
             | Flow::DecisionManagerDeleteConfig
             | Flow::DecisionManagerRetrieveConfig
+            | Flow::DecisionManagerValidateConfig
+            | Flow::SurchargePreview
             | Flow::ToggleDynamicRouting
             | Flow::CreateDynamicRoutingConfig
             | Flow::UpdateDynamicRoutingConfigs
             | Flow::DecisionManagerUpsertConfig
             | Flow::RoutingEvaluateRule
+            | Flow::RoutingSimulateConfig
+            | Flow::RoutingRetrieveVersionHistory
+            | Flow::RoutingRollbackConfig
+            | Flow::RoutingListScheduledActivations
+            | Flow::RoutingCancelScheduledActivation
+            | Flow::LatencyBasedRoutingUpdateConfig
+            | Flow::LatencyBasedRoutingRetrieveConfig
+            | Flow::RoutingUpsertFeeSchedule
+            | Flow::RoutingRetrieveFeeSchedule
+            | Flow::RoutingDeleteFeeSchedule
+            | Flow::RoutingRetrieveAuditLog
+            | Flow::RoutingStartExperiment
+            | Flow::RoutingStopExperiment
+            | Flow::RoutingRetrieveExperimentResults
+            | Flow::RoutingUpdatePaymentMethodDefaultConfig
+            | Flow::RoutingRetrievePaymentMethodDefaultConfig
+            | Flow::RoutingDeletePaymentMethodDefaultConfig
+            | Flow::RoutingExportConfig
+            | Flow::RoutingImportConfig
+            | Flow::RoutingRetrieveConnectorHealth
+            | Flow::RoutingDiffConfig
             | Flow::DecisionEngineRuleMigration
             | Flow::VolumeSplitOnRoutingType
             | Flow::DecisionEngineDecideGatewayCall
             | Flow::DecisionEngineGatewayFeedbackCall => Self::Routing,
 
-            Flow::CreateSubscription => Self::Subscription,
+            Flow::CreateSubscription
+            | Flow::RetrieveSubscription
+            | Flow::AttachSubscriptionPaymentMethod
+            | Flow::CreateSubscriptionPlan
+            | Flow::RetrieveSubscriptionPlan => Self::Subscription,
 
-            Flow::RetrieveForexFlow => Self::Forex,
+            Flow::RetrieveForexFlow | Flow::FxQuoteCreate | Flow::FxQuoteRetrieve => Self::Forex,
 
             Flow::AddToBlocklist => Self::Blocklist,
+            Flow::BulkAddToBlocklist => Self::Blocklist,
             Flow::DeleteFromBlocklist => Self::Blocklist,
             Flow::ListBlocklist => Self::Blocklist,
             Flow::ToggleBlocklistGuard => Self::Blocklist,
@@ -116,11 +184,18 @@ impl From<Flow> for ApiIdentifier {
             | Flow::CustomersUpdate
             | Flow::CustomersDelete
             | Flow::CustomersGetMandates
+            | Flow::CustomersMerge
+            | Flow::CustomersRedact
+            | Flow::CustomersExport
             | Flow::CustomersList => Self::Customers,
 
             Flow::EphemeralKeyCreate | Flow::EphemeralKeyDelete => Self::Ephemeral,
 
-            Flow::DeepHealthCheck | Flow::HealthCheck => Self::Health,
+            Flow::DeepHealthCheck
+            | Flow::HealthCheck
+            | Flow::PrometheusMetrics
+            | Flow::DrainStart
+            | Flow::DrainStatus => Self::Health,
             Flow::MandatesRetrieve | Flow::MandatesRevoke | Flow::MandatesList => Self::Mandates,
 
             Flow::PaymentMethodsCreate
@@ -137,12 +212,17 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ListCountriesCurrencies
             | Flow::DefaultPaymentMethodsSet
             | Flow::PaymentMethodSave
-            | Flow::TotalPaymentMethodCount => Self::PaymentMethods,
+            | Flow::TotalPaymentMethodCount
+            | Flow::PaymentMethodHealthList
+            | Flow::PaymentMethodHealthRetrieve => Self::PaymentMethods,
 
             Flow::PmAuthLinkTokenCreate | Flow::PmAuthExchangeToken => Self::PaymentMethodAuth,
 
             Flow::PaymentsCreate
             | Flow::PaymentsRetrieve
+            | Flow::PaymentsBatchCreate
+            | Flow::PaymentsBatchUpload
+            | Flow::PaymentsBatchRetrieve
             | Flow::PaymentsRetrieveForceSync
             | Flow::PaymentsUpdate
             | Flow::PaymentsConfirm
@@ -156,6 +236,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsList
             | Flow::PaymentsFilters
             | Flow::PaymentsAggregate
+            | Flow::PaymentsSearch
             | Flow::PaymentsRedirect
             | Flow::PaymentsIncrementalAuthorization
             | Flow::PaymentsExternalAuthentication
@@ -169,6 +250,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsGetIntent
             | Flow::GiftCardBalanceCheck
             | Flow::PaymentsPostSessionTokens
+            | Flow::PaymentsDccOffer
             | Flow::PaymentsUpdateMetadata
             | Flow::PaymentsUpdateIntent
             | Flow::PaymentsCreateAndConfirmIntent
@@ -183,6 +265,14 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PayoutsUpdate
             | Flow::PayoutsCancel
             | Flow::PayoutsFulfill
+            | Flow::PayoutsBatchCreate
+            | Flow::PayoutsBatchRetrieve
+            | Flow::PayoutsBatchCancel
+            | Flow::PayoutBeneficiaryCreate
+            | Flow::PayoutBeneficiaryRetrieve
+            | Flow::PayoutBeneficiaryUpdate
+            | Flow::PayoutBeneficiaryDelete
+            | Flow::PayoutBeneficiaryList
             | Flow::PayoutsList
             | Flow::PayoutsFilter
             | Flow::PayoutsAccounts
@@ -193,6 +283,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RefundsRetrieve
             | Flow::RefundsRetrieveForceSync
             | Flow::RefundsUpdate
+            | Flow::RefundsCancel
+            | Flow::RefundsApprove
+            | Flow::RefundsReject
             | Flow::RefundsList
             | Flow::RefundsFilters
             | Flow::RefundsAggregate
@@ -205,6 +298,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::WebhookEventInitialDeliveryAttemptList
             | Flow::WebhookEventDeliveryAttemptList
             | Flow::WebhookEventDeliveryRetry
+            | Flow::WebhookEventDeliveryHealth
             | Flow::RecoveryIncomingWebhookReceive
             | Flow::IncomingNetworkTokenWebhookReceive => Self::Webhooks,
 
@@ -212,7 +306,11 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ApiKeyRetrieve
             | Flow::ApiKeyUpdate
             | Flow::ApiKeyRevoke
-            | Flow::ApiKeyList => Self::ApiKeys,
+            | Flow::ApiKeyRotate
+            | Flow::ApiKeyList
+            | Flow::SigningKeyCreate
+            | Flow::SigningKeyList
+            | Flow::SigningKeyRevoke => Self::ApiKeys,
 
             Flow::DisputesRetrieve
             | Flow::DisputesList
@@ -221,7 +319,10 @@ impl From<Flow> for ApiIdentifier {
             | Flow::AttachDisputeEvidence
             | Flow::RetrieveDisputeEvidence
             | Flow::DisputesAggregate
-            | Flow::DeleteDisputeEvidence => Self::Disputes,
+            | Flow::DeleteDisputeEvidence
+            | Flow::DisputeDecisionManagerUpsertConfig
+            | Flow::DisputeDecisionManagerRetrieveConfig
+            | Flow::DisputeDecisionManagerDeleteConfig => Self::Disputes,
 
             Flow::CardsInfo
             | Flow::CardsInfoCreate
@@ -238,13 +339,20 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ProfileDelete
             | Flow::ProfileList
             | Flow::ToggleExtendedCardInfo
-            | Flow::ToggleConnectorAgnosticMit => Self::Profile,
+            | Flow::ToggleConnectorAgnosticMit
+            | Flow::FrmVelocityRulesUpsert
+            | Flow::FrmVelocityRulesRetrieve
+            | Flow::FrmVelocityRulesDelete => Self::Profile,
 
             Flow::PaymentLinkRetrieve
             | Flow::PaymentLinkInitiate
             | Flow::PaymentSecureLinkInitiate
             | Flow::PaymentLinkList
-            | Flow::PaymentLinkStatus => Self::PaymentLink,
+            | Flow::PaymentLinkStatus
+            | Flow::PaymentLinkCreate
+            | Flow::PaymentLinkUpdate
+            | Flow::PaymentLinkDeactivate
+            | Flow::PaymentLinkStatusRetrieve => Self::PaymentLink,
 
             Flow::Verification => Self::Verification,
 
@@ -294,6 +402,18 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RecoveryCodesGenerate
             | Flow::TerminateTwoFactorAuth
             | Flow::TwoFactorAuthStatus
+            | Flow::PasskeyRegisterBegin
+            | Flow::PasskeyRegisterFinish
+            | Flow::PasskeyList
+            | Flow::PasskeyUpdate
+            | Flow::PasskeyDelete
+            | Flow::PasskeyVerifyBegin
+            | Flow::PasskeyVerify
+            | Flow::PasskeySignInBegin
+            | Flow::PasskeySignIn
+            | Flow::UserSessionList
+            | Flow::UserSessionRevoke
+            | Flow::UserSessionRevokeAll
             | Flow::CreateUserAuthenticationMethod
             | Flow::UpdateUserAuthenticationMethod
             | Flow::ListUserAuthenticationMethods
@@ -318,6 +438,15 @@ impl From<Flow> for ApiIdentifier {
             | Flow::UploadFileToUserThemeStorage
             | Flow::GetUserThemeUsingLineage
             | Flow::ListAllThemesInLineage
+            | Flow::ScimUserList
+            | Flow::ScimUserCreate
+            | Flow::ScimUserRetrieve
+            | Flow::ScimUserReplace
+            | Flow::ScimUserUpdate
+            | Flow::ScimUserDelete
+            | Flow::ScimGroupList
+            | Flow::ScimGroupRetrieve
+            | Flow::ScimGroupUpdate
             | Flow::CloneConnector => Self::User,
 
             Flow::GetDataFromHyperswitchAiFlow | Flow::ListAllChatInteractions => Self::AiWorkflow,
@@ -349,11 +478,16 @@ impl From<Flow> for ApiIdentifier {
             Flow::ReconMerchantUpdate
             | Flow::ReconTokenRequest
             | Flow::ReconServiceRequest
-            | Flow::ReconVerifyToken => Self::Recon,
+            | Flow::ReconVerifyToken
+            | Flow::SettlementReportUpload
+            | Flow::SettlementReportPull
+            | Flow::SettlementReportRetrieve
+            | Flow::ReconExceptionsList
+            | Flow::PaymentReconStatusRetrieve => Self::Recon,
 
             Flow::RetrievePollStatus => Self::Poll,
 
-            Flow::FeatureMatrix => Self::Documentation,
+            Flow::FeatureMatrix | Flow::ConnectorCapabilities => Self::Documentation,
 
             Flow::TokenizeCard
             | Flow::TokenizeCardUsingPaymentMethodId
@@ -386,6 +520,42 @@ impl From<Flow> for ApiIdentifier {
             }
 
             Flow::RecoveryDataBackfill => Self::RecoveryDataBackfill,
+
+            Flow::AdminAuditEventsList | Flow::OrganizationAdminAuditEventsList => Self::AdminAudit,
+
+            Flow::ConnectorAuditLogsList => Self::ConnectorAuditLog,
+
+            Flow::PaymentReviewList | Flow::PaymentReviewApprove | Flow::PaymentReviewDecline => {
+                Self::PaymentReview
+            }
+
+            Flow::DataRetentionPolicyRetrieve
+            | Flow::DataRetentionPolicyUpsert
+            | Flow::DataRetentionPurge
+            | Flow::DataRetentionPurgeRunsList => Self::DataRetention,
+
+            Flow::ListViewCreate
+            | Flow::ListViewList
+            | Flow::ListViewRetrieve
+            | Flow::ListViewUpdate
+            | Flow::ListViewDelete => Self::ListViews,
+
+            Flow::ReportTemplateCreate
+            | Flow::ReportTemplateList
+            | Flow::ReportTemplateRetrieve
+            | Flow::ReportTemplateUpdate
+            | Flow::ReportTemplateDelete
+            | Flow::ReportRun
+            | Flow::ReportRunsList
+            | Flow::ReportRunFileDownload => Self::Reports,
+
+            Flow::EventStream => Self::EventStream,
+
+            Flow::AuthRateAlertConfigUpsert
+            | Flow::AuthRateAlertConfigRetrieve
+            | Flow::AuthRateAlertsList => Self::AuthRateAlerts,
+
+            Flow::ConnectionPoolsRetrieve | Flow::ConnectionPoolResize => Self::ConnectionPools,
         }
     }
 }
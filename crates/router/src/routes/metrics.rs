@@ -1,11 +1,12 @@
 pub mod bg_metrics_collector;
 pub mod request;
 
-use router_env::{counter_metric, global_meter, histogram_metric_f64};
+use router_env::{counter_metric, gauge_metric, global_meter, histogram_metric_f64};
 
 global_meter!(GLOBAL_METER, "ROUTER_API");
 
 counter_metric!(HEALTH_METRIC, GLOBAL_METER); // No. of health API hits
+gauge_metric!(SCHEDULER_QUEUE_DEPTH, GLOBAL_METER); // Recorded when the metrics are scraped
 counter_metric!(KV_MISS, GLOBAL_METER); // No. of KV misses
 
 // API Level Metrics
@@ -49,6 +50,7 @@ counter_metric!(CUSTOMER_REDACTED, GLOBAL_METER);
 
 counter_metric!(API_KEY_CREATED, GLOBAL_METER);
 counter_metric!(API_KEY_REVOKED, GLOBAL_METER);
+counter_metric!(API_KEY_ROTATED, GLOBAL_METER);
 
 counter_metric!(MCA_CREATE, GLOBAL_METER);
 
@@ -58,6 +60,7 @@ histogram_metric_f64!(CONNECTOR_REQUEST_TIME, GLOBAL_METER);
 counter_metric!(SESSION_TOKEN_CREATED, GLOBAL_METER);
 
 counter_metric!(CONNECTOR_CALL_COUNT, GLOBAL_METER); // Attributes needed
+counter_metric!(CONNECTOR_RESPONSE_COUNT, GLOBAL_METER); // Attributes needed
 
 counter_metric!(THREE_DS_PAYMENT_COUNT, GLOBAL_METER);
 counter_metric!(THREE_DS_DOWNGRADE_COUNT, GLOBAL_METER);
@@ -143,3 +146,36 @@ counter_metric!(PAYMENT_METHOD_TYPES_MISCONFIGURATION_METRIC, GLOBAL_METER);
 
 // AI chat metric to track number of chat request
 counter_metric!(CHAT_REQUEST_COUNT, GLOBAL_METER);
+
+// Rate limiting metrics
+//
+// A counter to indicate the number of requests rejected for exceeding a rate limit
+counter_metric!(RATE_LIMIT_EXCEEDED, GLOBAL_METER);
+
+// A counter to indicate the number of requests allowed as the rate limits could not be checked
+counter_metric!(RATE_LIMIT_CHECK_FAILURE, GLOBAL_METER);
+
+// A counter to indicate the number of connector requests which waited for the outbound limits of
+// the merchant connector account
+counter_metric!(CONNECTOR_RATE_LIMIT_QUEUED, GLOBAL_METER);
+
+// A counter to indicate the number of connector requests failed as the outbound limits of the
+// merchant connector account did not allow them within the maximum queue wait
+counter_metric!(CONNECTOR_RATE_LIMIT_EXCEEDED, GLOBAL_METER);
+
+// A counter to indicate the number of connector requests sent as the outbound limits could not be
+// checked
+counter_metric!(CONNECTOR_RATE_LIMIT_CHECK_FAILURE, GLOBAL_METER);
+
+// A histogram to measure the time for which connector requests waited for the outbound limits
+histogram_metric_f64!(CONNECTOR_RATE_LIMIT_QUEUE_WAIT_TIME, GLOBAL_METER);
+
+// A counter to indicate the number of payments which triggered an FRM velocity rule
+counter_metric!(FRM_VELOCITY_RULE_TRIGGERED, GLOBAL_METER);
+
+// A counter to indicate the number of payments which could not be checked against the FRM
+// velocity rules
+counter_metric!(FRM_VELOCITY_CHECK_FAILURE, GLOBAL_METER);
+
+// A counter to indicate the number of rows of the payment tables moved to their archive tables
+counter_metric!(PAYMENT_ROWS_ARCHIVED, GLOBAL_METER);
@@ -24,6 +24,9 @@ pub fn spawn_metrics_collector(metrics_collection_interval_in_secs: Option<u16>)
             for instance in cache_instances {
                 instance.record_entry_count_metric().await
             }
+            if let Some(instance) = cache::DATA_KEY_CACHE.get() {
+                instance.record_entry_count_metric().await
+            }
 
             tokio::time::sleep(std::time::Duration::from_secs(
                 metrics_collection_interval.into(),
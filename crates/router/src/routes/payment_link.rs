@@ -179,3 +179,129 @@ pub async fn payment_link_status(
     ))
     .await
 }
+
+/// Payment Link - Create
+///
+/// To create a shareable payment link along with the payment backing it
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkCreate))]
+pub async fn payment_link_create(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<api_models::payments::PaymentLinkCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            create_payment_link(state, req_state, merchant_context, auth.profile_id, payload)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payment Link - Update
+///
+/// To update the description and branding of a payment link
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkUpdate))]
+pub async fn payment_link_update(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_models::payments::PaymentLinkUpdateRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkUpdate;
+    let payment_link_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            update_payment_link(
+                state,
+                auth.merchant_account,
+                payment_link_id.clone(),
+                payload,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payment Link - Deactivate
+///
+/// To expire a payment link before its expiry time
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkDeactivate))]
+pub async fn payment_link_deactivate(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkDeactivate;
+    let payment_link_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_link_id,
+        |state, auth: auth::AuthenticationData, payment_link_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            deactivate_payment_link(state, merchant_context, payment_link_id)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payment Link - Status
+///
+/// To retrieve the status of a payment link and the payment backing it
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkStatusRetrieve))]
+pub async fn payment_link_status_retrieve(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkStatusRetrieve;
+    let payment_link_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_link_id,
+        |state, auth: auth::AuthenticationData, payment_link_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            retrieve_payment_link_status(state, merchant_context, payment_link_id)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
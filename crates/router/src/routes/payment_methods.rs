@@ -953,6 +953,85 @@ pub async fn list_countries_currencies_for_connector_payment_method(
     .await
 }
 
+/// Payment Method Health - List
+///
+/// To list the usage and expiry of the saved payment methods of the merchant
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodHealthList))]
+pub async fn list_payment_method_health(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<api_models::payment_methods::PaymentMethodHealthListRequest>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodHealthList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            payment_methods_routes::health::list_payment_method_health(state, merchant_context, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantCustomerRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payment Method Health - Retrieve
+///
+/// To retrieve the usage and expiry of a saved payment method
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodHealthRetrieve))]
+pub async fn retrieve_payment_method_health(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodHealthRetrieve;
+    let payment_method_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_method_id,
+        |state, auth: auth::AuthenticationData, payment_method_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            payment_methods_routes::health::retrieve_payment_method_health(
+                state,
+                merchant_context,
+                payment_method_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantCustomerRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(feature = "v2")]
 #[instrument(skip_all, fields(flow = ?Flow::ListCountriesCurrencies))]
 pub async fn list_countries_currencies_for_connector_payment_method(
@@ -1110,10 +1189,11 @@ impl ParentPaymentMethodToken {
     }
 
     pub fn should_delete_payment_method_token(&self, status: IntentStatus) -> bool {
-        // RequiresMerchantAction: When the payment goes for merchant review incase of potential fraud allow payment_method_token to be stored until resolved
+        // RequiresMerchantAction, RequiresMerchantReview: When the payment goes for merchant review incase of potential fraud allow payment_method_token to be stored until resolved
         ![
             IntentStatus::RequiresCustomerAction,
             IntentStatus::RequiresMerchantAction,
+            IntentStatus::RequiresMerchantReview,
         ]
         .contains(&status)
     }
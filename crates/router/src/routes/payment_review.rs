@@ -0,0 +1,165 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::payment_review as review_api;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, payment_review},
+    routes::lock_utils,
+    services::{api, authentication as auth, authorization::permissions::Permission},
+    types::domain,
+};
+
+/// Payment Reviews - List
+///
+/// To list the payments held for a review by the merchant
+#[instrument(skip_all, fields(flow = ?Flow::PaymentReviewList))]
+pub async fn list_payment_reviews(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<review_api::PaymentReviewListRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentReviewList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, query, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            payment_review::list_payment_reviews(state, merchant_context, auth.profile_id, query)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentReviewRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payment Reviews - Approve
+///
+/// To approve a payment held for review, which captures it
+#[instrument(skip_all, fields(flow = ?Flow::PaymentReviewApprove, payment_id))]
+pub async fn approve_payment_review(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<review_api::PaymentReviewApproveRequest>,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentReviewApprove;
+    let payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+    let locking_action = get_locking_action(&payment_id);
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            let decided_by = req_state
+                .auth_type
+                .as_ref()
+                .and_then(|auth_type| auth_type.get_created_by());
+            payment_review::approve_payment_review(
+                state,
+                req_state,
+                merchant_context,
+                auth.profile_id,
+                payment_id.clone(),
+                req,
+                decided_by,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentReviewWrite,
+            },
+            req.headers(),
+        ),
+        locking_action,
+    ))
+    .await
+}
+
+/// Payment Reviews - Decline
+///
+/// To decline a payment held for review, which voids it and optionally blocklists its details
+#[instrument(skip_all, fields(flow = ?Flow::PaymentReviewDecline, payment_id))]
+pub async fn decline_payment_review(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<review_api::PaymentReviewDeclineRequest>,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentReviewDecline;
+    let payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+    let locking_action = get_locking_action(&payment_id);
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            let decided_by = req_state
+                .auth_type
+                .as_ref()
+                .and_then(|auth_type| auth_type.get_created_by());
+            payment_review::decline_payment_review(
+                state,
+                req_state,
+                merchant_context,
+                auth.profile_id,
+                payment_id.clone(),
+                req,
+                decided_by,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentReviewWrite,
+            },
+            req.headers(),
+        ),
+        locking_action,
+    ))
+    .await
+}
+
+/// Decisions run payment operations, hence they hold the same lock as the other operations on
+/// the payment
+fn get_locking_action(payment_id: &common_utils::id_type::PaymentId) -> api_locking::LockAction {
+    api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: payment_id.get_string_repr().to_owned(),
+            api_identifier: lock_utils::ApiIdentifier::Payments,
+            override_lock_retries: None,
+        },
+    }
+}
@@ -14,6 +14,8 @@ use super::app::ReqState;
 use crate::core::gift_card;
 #[cfg(feature = "v2")]
 use crate::core::revenue_recovery::api as recovery;
+#[cfg(feature = "v1")]
+use crate::services::idempotency;
 use crate::{
     self as app,
     core::{
@@ -119,6 +121,127 @@ pub async fn payments_create(
     .await
 }
 
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsBatchCreate))]
+pub async fn payments_batch_create(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsBatchRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentsBatchCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            payments::batch::create_payments_batch(
+                state,
+                req_state,
+                merchant_context,
+                auth.profile_id,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: true,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsBatchUpload))]
+pub async fn payments_batch_upload(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    actix_multipart::form::MultipartForm(form): actix_multipart::form::MultipartForm<
+        payments::batch::PaymentsBatchUploadForm,
+    >,
+) -> impl Responder {
+    let flow = Flow::PaymentsBatchUpload;
+    let payload =
+        match payments::batch::get_payments_batch_request_from_csv(form.file.data.to_bytes()) {
+            Ok(payload) => payload,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            payments::batch::create_payments_batch(
+                state,
+                req_state,
+                merchant_context,
+                auth.profile_id,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: true,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsBatchRetrieve))]
+pub async fn payments_batch_retrieve(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentsBatchRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, batch_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            payments::batch::retrieve_payments_batch(state, merchant_context, batch_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: true,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(feature = "v2")]
 pub async fn recovery_payments_create(
     state: web::Data<app::AppState>,
@@ -812,6 +935,40 @@ pub async fn payments_post_session_tokens(
     .await
 }
 
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsDccOffer, payment_id))]
+pub async fn payments_dcc_offer(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsDccOfferRequest>,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentsDccOffer;
+
+    let payment_id = path.into_inner();
+    let payload = payment_types::PaymentsDccOfferRequest {
+        payment_id,
+        ..json_payload.into_inner()
+    };
+    tracing::Span::current().record("payment_id", payload.payment_id.get_string_repr());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            payments::dcc::get_dcc_offer(state, merchant_context, req)
+        },
+        &auth::PublishableKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(feature = "v1")]
 #[instrument(skip_all, fields(flow = ?Flow::PaymentsUpdateMetadata, payment_id))]
 pub async fn payments_update_metadata(
@@ -969,6 +1126,10 @@ pub async fn payments_capture(
     };
 
     let locking_action = payload.get_locking_input(flow.clone());
+    let idempotency_key = match idempotency::get_idempotency_key(req.headers()) {
+        Ok(idempotency_key) => idempotency_key,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
 
     Box::pin(api::server_wrap(
         flow,
@@ -976,28 +1137,42 @@ pub async fn payments_capture(
         &req,
         payload,
         |state, auth: auth::AuthenticationData, payload, req_state| {
-            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
-                domain::Context(auth.merchant_account, auth.key_store),
-            ));
-            payments::payments_core::<
-                api_types::Capture,
-                payment_types::PaymentsResponse,
-                _,
-                _,
-                _,
-                payments::PaymentData<api_types::Capture>,
-            >(
-                state,
-                req_state,
-                merchant_context,
-                auth.profile_id,
-                payments::PaymentCapture,
-                payload,
-                api::AuthFlow::Merchant,
-                payments::CallConnectorAction::Trigger,
-                None,
-                HeaderPayload::default(),
-            )
+            let idempotency_key = idempotency_key.clone();
+            async move {
+                let merchant_id = auth.merchant_account.get_id().clone();
+                let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                    domain::Context(auth.merchant_account, auth.key_store),
+                ));
+                idempotency::process_idempotently(
+                    &state,
+                    &merchant_id,
+                    Flow::PaymentsCapture,
+                    idempotency_key,
+                    payload,
+                    |payload| {
+                        payments::payments_core::<
+                            api_types::Capture,
+                            payment_types::PaymentsResponse,
+                            _,
+                            _,
+                            _,
+                            payments::PaymentData<api_types::Capture>,
+                        >(
+                            state.clone(),
+                            req_state,
+                            merchant_context,
+                            auth.profile_id,
+                            payments::PaymentCapture,
+                            payload,
+                            api::AuthFlow::Merchant,
+                            payments::CallConnectorAction::Trigger,
+                            None,
+                            HeaderPayload::default(),
+                        )
+                    },
+                )
+                .await
+            }
         },
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
@@ -1491,34 +1666,52 @@ pub async fn payments_cancel(
 
     payload.payment_id = payment_id;
     let locking_action = payload.get_locking_input(flow.clone());
+    let idempotency_key = match idempotency::get_idempotency_key(req.headers()) {
+        Ok(idempotency_key) => idempotency_key,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
         payload,
         |state, auth: auth::AuthenticationData, req, req_state| {
-            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
-                domain::Context(auth.merchant_account, auth.key_store),
-            ));
-            payments::payments_core::<
-                api_types::Void,
-                payment_types::PaymentsResponse,
-                _,
-                _,
-                _,
-                payments::PaymentData<api_types::Void>,
-            >(
-                state,
-                req_state,
-                merchant_context,
-                auth.profile_id,
-                payments::PaymentCancel,
-                req,
-                api::AuthFlow::Merchant,
-                payments::CallConnectorAction::Trigger,
-                None,
-                HeaderPayload::default(),
-            )
+            let idempotency_key = idempotency_key.clone();
+            async move {
+                let merchant_id = auth.merchant_account.get_id().clone();
+                let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                    domain::Context(auth.merchant_account, auth.key_store),
+                ));
+                idempotency::process_idempotently(
+                    &state,
+                    &merchant_id,
+                    Flow::PaymentsCancel,
+                    idempotency_key,
+                    req,
+                    |req| {
+                        payments::payments_core::<
+                            api_types::Void,
+                            payment_types::PaymentsResponse,
+                            _,
+                            _,
+                            _,
+                            payments::PaymentData<api_types::Void>,
+                        >(
+                            state.clone(),
+                            req_state,
+                            merchant_context,
+                            auth.profile_id,
+                            payments::PaymentCancel,
+                            req,
+                            api::AuthFlow::Merchant,
+                            payments::CallConnectorAction::Trigger,
+                            None,
+                            HeaderPayload::default(),
+                        )
+                    },
+                )
+                .await
+            }
         },
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
@@ -1868,6 +2061,69 @@ pub async fn get_payment_filters_profile(
     .await
 }
 
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsSearch))]
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn payments_search(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    payload: web::Json<api_models::analytics::search::PaymentSearchRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentsSearch;
+    let payload = payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            payments::search::search_payments(state, merchant_context, None, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsSearch))]
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn profile_payments_search(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    payload: web::Json<api_models::analytics::search::PaymentSearchRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentsSearch;
+    let payload = payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            payments::search::search_payments(state, merchant_context, auth.profile_id, req)
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfilePaymentRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::PaymentsAggregate))]
 #[cfg(feature = "olap")]
 pub async fn get_payments_aggregates(
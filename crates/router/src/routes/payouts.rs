@@ -12,6 +12,7 @@ use crate::{
         api,
         authentication::{self as auth},
         authorization::permissions::Permission,
+        idempotency,
     },
     types::{api::payouts as payout_types, domain},
 };
@@ -24,6 +25,10 @@ pub async fn payouts_create(
     json_payload: web::Json<payout_types::PayoutCreateRequest>,
 ) -> HttpResponse {
     let flow = Flow::PayoutsCreate;
+    let idempotency_key = match idempotency::get_idempotency_key(req.headers()) {
+        Ok(idempotency_key) => idempotency_key,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
 
     Box::pin(api::server_wrap(
         flow,
@@ -31,10 +36,22 @@ pub async fn payouts_create(
         &req,
         json_payload.into_inner(),
         |state, auth: auth::AuthenticationData, req, _| {
-            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
-                domain::Context(auth.merchant_account, auth.key_store),
-            ));
-            payouts_create_core(state, merchant_context, req)
+            let idempotency_key = idempotency_key.clone();
+            async move {
+                let merchant_id = auth.merchant_account.get_id().clone();
+                let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                    domain::Context(auth.merchant_account, auth.key_store),
+                ));
+                idempotency::process_idempotently(
+                    &state,
+                    &merchant_id,
+                    Flow::PayoutsCreate,
+                    idempotency_key,
+                    req,
+                    |req| payouts_create_core(state.clone(), merchant_context, req),
+                )
+                .await
+            }
         },
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
@@ -219,6 +236,258 @@ pub async fn payouts_fulfill(
     .await
 }
 
+#[cfg(all(feature = "v1", feature = "payouts"))]
+/// Payouts - Batch Create
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsBatchCreate))]
+pub async fn payouts_batch_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutBatchCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsBatchCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            batch::create_payout_batch(state, merchant_context, auth.profile_id, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", feature = "payouts"))]
+/// Payouts - Batch Retrieve
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsBatchRetrieve))]
+pub async fn payouts_batch_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsBatchRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, batch_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            batch::retrieve_payout_batch(state, merchant_context, batch_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePayoutRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", feature = "payouts"))]
+/// Payouts - Batch Cancel
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsBatchCancel))]
+pub async fn payouts_batch_cancel(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsBatchCancel;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, batch_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            batch::cancel_payout_batch(state, merchant_context, batch_id)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", feature = "payouts"))]
+/// Payouts - Beneficiary Create
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBeneficiaryCreate))]
+pub async fn payout_beneficiary_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payout_types::BeneficiaryCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBeneficiaryCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            beneficiary::create_beneficiary(state, merchant_context, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", feature = "payouts"))]
+/// Payouts - Beneficiary Retrieve
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBeneficiaryRetrieve))]
+pub async fn payout_beneficiary_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBeneficiaryRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, beneficiary_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            beneficiary::retrieve_beneficiary(state, merchant_context, beneficiary_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPayoutRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", feature = "payouts"))]
+/// Payouts - Beneficiary Update
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBeneficiaryUpdate))]
+pub async fn payout_beneficiary_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<payout_types::BeneficiaryUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBeneficiaryUpdate;
+    let beneficiary_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            beneficiary::update_beneficiary(state, merchant_context, beneficiary_id.clone(), req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", feature = "payouts"))]
+/// Payouts - Beneficiary Delete
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBeneficiaryDelete))]
+pub async fn payout_beneficiary_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBeneficiaryDelete;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, beneficiary_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            beneficiary::delete_beneficiary(state, merchant_context, beneficiary_id)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", feature = "payouts"))]
+/// Payouts - Beneficiary List
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBeneficiaryList))]
+pub async fn payout_beneficiary_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<payout_types::BeneficiaryListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBeneficiaryList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, constraints, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            beneficiary::list_beneficiaries(state, merchant_context, constraints)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPayoutRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Payouts - List
 #[cfg(feature = "olap")]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsList))]
@@ -2,6 +2,8 @@ use actix_web::{web, HttpRequest, HttpResponse};
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use crate::core::frm_velocity_rules;
 use crate::{
     core::{admin::*, api_locking, errors},
     services::{api, authentication as auth, authorization::permissions},
@@ -474,3 +476,128 @@ pub async fn payment_connector_list_profile(
     )
     .await
 }
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::FrmVelocityRulesUpsert))]
+pub async fn upsert_frm_velocity_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+    json_payload: web::Json<api_models::frm_velocity_rules::FrmVelocityRulesConfigReq>,
+) -> HttpResponse {
+    let flow = Flow::FrmVelocityRulesUpsert;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (profile_id.clone(), json_payload.into_inner()),
+        |state, auth: auth::AuthenticationData, (profile_id, request), _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            frm_velocity_rules::upsert_frm_velocity_rules_for_profile(
+                state,
+                merchant_context,
+                profile_id,
+                request,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id)),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id,
+                required_permission: permissions::Permission::ProfileAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::FrmVelocityRulesRetrieve))]
+pub async fn retrieve_frm_velocity_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::FrmVelocityRulesRetrieve;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        profile_id.clone(),
+        |state, auth: auth::AuthenticationData, profile_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            frm_velocity_rules::retrieve_frm_velocity_rules_for_profile(
+                state,
+                merchant_context,
+                profile_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id)),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id,
+                required_permission: permissions::Permission::ProfileAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::FrmVelocityRulesDelete))]
+pub async fn delete_frm_velocity_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::FrmVelocityRulesDelete;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        profile_id.clone(),
+        |state, auth: auth::AuthenticationData, profile_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            frm_velocity_rules::delete_frm_velocity_rules_for_profile(
+                state,
+                merchant_context,
+                profile_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id)),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id,
+                required_permission: permissions::Permission::ProfileAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
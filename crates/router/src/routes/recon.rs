@@ -1,8 +1,12 @@
+#[cfg(feature = "v1")]
+use actix_multipart::form::MultipartForm;
 use actix_web::{web, HttpRequest, HttpResponse};
 use api_models::recon as recon_api;
 use router_env::Flow;
 
 use super::AppState;
+#[cfg(feature = "v1")]
+use crate::{core::recon::settlement, types::domain};
 use crate::{
     core::{api_locking, recon},
     services::{api, authentication, authorization::permissions::Permission},
@@ -77,3 +81,172 @@ pub async fn verify_recon_token(state: web::Data<AppState>, http_req: HttpReques
     ))
     .await
 }
+
+#[cfg(feature = "v1")]
+pub async fn upload_settlement_report(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    MultipartForm(form): MultipartForm<settlement::SettlementReportUploadForm>,
+) -> HttpResponse {
+    let flow = Flow::SettlementReportUpload;
+    let (payload, file) = match settlement::get_settlement_report_upload_request(form) {
+        Ok(upload) => upload,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: authentication::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            settlement::upload_settlement_report(state, merchant_context, req, file.clone())
+        },
+        authentication::auth_type(
+            &authentication::HeaderAuth(authentication::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &authentication::JWTAuth {
+                permission: Permission::MerchantReconUploadWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+pub async fn pull_settlement_report(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<recon_api::SettlementReportPullRequest>,
+) -> HttpResponse {
+    let flow = Flow::SettlementReportPull;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: authentication::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            settlement::pull_settlement_report(state, merchant_context, req)
+        },
+        authentication::auth_type(
+            &authentication::HeaderAuth(authentication::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &authentication::JWTAuth {
+                permission: Permission::MerchantRunReconWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+pub async fn retrieve_settlement_report(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::SettlementReportRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: authentication::AuthenticationData, report_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            settlement::retrieve_settlement_report(state, merchant_context, report_id)
+        },
+        authentication::auth_type(
+            &authentication::HeaderAuth(authentication::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &authentication::JWTAuth {
+                permission: Permission::MerchantReconReportsRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+pub async fn list_recon_exceptions(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<recon_api::ReconExceptionListRequest>,
+) -> HttpResponse {
+    let flow = Flow::ReconExceptionsList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: authentication::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            settlement::list_recon_exceptions(state, merchant_context, req)
+        },
+        authentication::auth_type(
+            &authentication::HeaderAuth(authentication::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &authentication::JWTAuth {
+                permission: Permission::MerchantReconReportsRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+pub async fn retrieve_payment_recon_status(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> HttpResponse {
+    let flow = Flow::PaymentReconStatusRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: authentication::AuthenticationData, payment_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            settlement::retrieve_payment_recon_status(state, merchant_context, payment_id)
+        },
+        authentication::auth_type(
+            &authentication::HeaderAuth(authentication::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &authentication::JWTAuth {
+                permission: Permission::MerchantReconReportsRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -7,6 +7,8 @@ use super::app::AppState;
 use crate::core::refunds::*;
 #[cfg(feature = "v2")]
 use crate::core::refunds_v2::*;
+#[cfg(feature = "v1")]
+use crate::services::idempotency;
 use crate::{
     core::api_locking,
     services::{api, authentication as auth, authorization::permissions::Permission},
@@ -56,16 +58,44 @@ pub async fn refunds_create(
     json_payload: web::Json<refunds::RefundRequest>,
 ) -> HttpResponse {
     let flow = Flow::RefundsCreate;
+    let idempotency_key = match idempotency::get_idempotency_key(req.headers()) {
+        Ok(idempotency_key) => idempotency_key,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
         json_payload.into_inner(),
-        |state, auth: auth::AuthenticationData, req, _| {
-            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
-                domain::Context(auth.merchant_account, auth.key_store),
-            ));
-            refund_create_core(state, merchant_context, auth.profile_id, req)
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            let idempotency_key = idempotency_key.clone();
+            let created_by = req_state
+                .auth_type
+                .as_ref()
+                .and_then(|auth_type| auth_type.get_created_by());
+            async move {
+                let merchant_id = auth.merchant_account.get_id().clone();
+                let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                    domain::Context(auth.merchant_account, auth.key_store),
+                ));
+                idempotency::process_idempotently(
+                    &state,
+                    &merchant_id,
+                    Flow::RefundsCreate,
+                    idempotency_key,
+                    req,
+                    |req| {
+                        refund_create_core(
+                            state.clone(),
+                            merchant_context,
+                            auth.profile_id,
+                            req,
+                            created_by,
+                        )
+                    },
+                )
+                .await
+            }
         },
         auth::auth_type(
             &auth::HeaderAuth(auth::ApiKeyAuth {
@@ -384,6 +414,125 @@ pub async fn refunds_update(
     .await
 }
 
+#[cfg(feature = "v1")]
+/// Refunds - Cancel
+///
+/// To cancel a scheduled refund, before it is sent to the connector
+#[instrument(skip_all, fields(flow = ?Flow::RefundsCancel))]
+// #[post("/{id}/cancel")]
+pub async fn refunds_cancel(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::RefundsCancel;
+    let refund_cancel_req = refunds::RefundCancelRequest {
+        refund_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        refund_cancel_req,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            refund_cancel_core(state, merchant_context, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+/// Refunds - Approve
+///
+/// To approve a refund pending approval, which sends it to the connector
+#[instrument(skip_all, fields(flow = ?Flow::RefundsApprove))]
+pub async fn refunds_approve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::RefundsApprove;
+    let refund_approve_req = refunds::RefundApproveRequest {
+        refund_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        refund_approve_req,
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            refund_approve_core(
+                state,
+                merchant_context,
+                auth.profile_id,
+                req,
+                req_state
+                    .auth_type
+                    .as_ref()
+                    .and_then(|auth_type| auth_type.get_created_by()),
+            )
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileRefundApprovalWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+/// Refunds - Reject
+///
+/// To reject a refund pending approval, which cancels it
+#[instrument(skip_all, fields(flow = ?Flow::RefundsReject))]
+pub async fn refunds_reject(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<refunds::RefundRejectRequest>,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::RefundsReject;
+    let mut refund_reject_req = json_payload.into_inner();
+    refund_reject_req.refund_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        refund_reject_req,
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            refund_reject_core(
+                state,
+                merchant_context,
+                auth.profile_id,
+                req,
+                req_state
+                    .auth_type
+                    .as_ref()
+                    .and_then(|auth_type| auth_type.get_created_by()),
+            )
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileRefundApprovalWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(feature = "v2")]
 #[instrument(skip_all, fields(flow = ?Flow::RefundsUpdate))]
 pub async fn refunds_metadata_update(
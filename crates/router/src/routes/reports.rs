@@ -0,0 +1,314 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::reports as reports_api;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, reports},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+    types::domain,
+};
+
+/// Reports - Create Template
+///
+/// To create a report template, whose report is generated and delivered on the frequency of the
+/// template
+#[instrument(skip_all, fields(flow = ?Flow::ReportTemplateCreate))]
+pub async fn create_report_template(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<reports_api::ReportTemplateCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::ReportTemplateCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            let created_by = req_state
+                .auth_type
+                .as_ref()
+                .and_then(|auth_type| auth_type.get_created_by());
+            reports::create_report_template(state, merchant_context, req, created_by)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Reports - List Templates
+///
+/// To list the report templates of the merchant, the latest template at the top
+#[instrument(skip_all, fields(flow = ?Flow::ReportTemplateList))]
+pub async fn list_report_templates(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    let flow = Flow::ReportTemplateList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            reports::list_report_templates(state, merchant_context)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantReportRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Reports - Retrieve Template
+///
+/// To retrieve a report template of the merchant
+#[instrument(skip_all, fields(flow = ?Flow::ReportTemplateRetrieve))]
+pub async fn retrieve_report_template(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ReportTemplateRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, template_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            reports::retrieve_report_template(state, merchant_context, template_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantReportRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Reports - Update Template
+///
+/// To update a report template, the changes apply from the next report of the template
+#[instrument(skip_all, fields(flow = ?Flow::ReportTemplateUpdate))]
+pub async fn update_report_template(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<reports_api::ReportTemplateUpdateRequest>,
+) -> impl Responder {
+    let flow = Flow::ReportTemplateUpdate;
+    let template_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            reports::update_report_template(state, merchant_context, template_id.clone(), req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Reports - Delete Template
+///
+/// To delete a report template, which stops its scheduled reports
+#[instrument(skip_all, fields(flow = ?Flow::ReportTemplateDelete))]
+pub async fn delete_report_template(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ReportTemplateDelete;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, template_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            reports::delete_report_template(state, merchant_context, template_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Reports - Run
+///
+/// To generate and deliver the report of a template on demand
+#[instrument(skip_all, fields(flow = ?Flow::ReportRun))]
+pub async fn run_report(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<reports_api::ReportRunRequest>,
+) -> impl Responder {
+    let flow = Flow::ReportRun;
+    let template_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            let triggered_by = req_state
+                .auth_type
+                .as_ref()
+                .and_then(|auth_type| auth_type.get_created_by());
+            reports::run_report(
+                state,
+                merchant_context,
+                template_id.clone(),
+                req,
+                triggered_by,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantReportRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Reports - List Runs
+///
+/// To list the scheduled and on demand runs of the reports of the merchant, the latest run at the
+/// top
+#[instrument(skip_all, fields(flow = ?Flow::ReportRunsList))]
+pub async fn list_report_runs(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<reports_api::ReportRunListQuery>,
+) -> impl Responder {
+    let flow = Flow::ReportRunsList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, query, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            reports::list_report_runs(state, merchant_context, query)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantReportRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Reports - Download Run File
+///
+/// To download the file of the report generated by a run
+#[instrument(skip_all, fields(flow = ?Flow::ReportRunFileDownload))]
+pub async fn download_report_run_file(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ReportRunFileDownload;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, run_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            reports::download_report_run_file(state, merchant_context, run_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantReportRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -23,10 +23,10 @@ use crate::{
     core::{
         api_locking, conditional_config,
         payments::routing::utils::{DecisionEngineApiHandler, EuclidApiClient},
-        routing, surcharge_decision_config,
+        routing, surcharge_decision_config, three_ds_decision_rule,
     },
     db::errors::StorageErrorExt,
-    routes::AppState,
+    routes::{lock_utils, AppState},
     services,
     services::{api as oss_api, authentication as auth, authorization::permissions::Permission},
     types::domain,
@@ -45,7 +45,7 @@ pub async fn routing_create_config(
         state,
         &req,
         json_payload.into_inner(),
-        |state, auth: auth::AuthenticationData, payload, _| {
+        |state, auth: auth::AuthenticationData, payload, req_state| {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
@@ -57,6 +57,10 @@ pub async fn routing_create_config(
                 transaction_type
                     .or(payload.transaction_type)
                     .unwrap_or(enums::TransactionType::Payment),
+                req_state
+                    .auth_type
+                    .as_ref()
+                    .and_then(|auth_type| auth_type.get_created_by()),
             )
         },
         auth::auth_type(
@@ -135,7 +139,7 @@ pub async fn routing_link_config(
         state,
         &req,
         path.into_inner(),
-        |state, auth: auth::AuthenticationData, algorithm, _| {
+        |state, auth: auth::AuthenticationData, algorithm, req_state| {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
@@ -147,6 +151,10 @@ pub async fn routing_link_config(
                 transaction_type
                     .or(json_payload.transaction_type)
                     .unwrap_or(enums::TransactionType::Payment),
+                req_state
+                    .auth_type
+                    .as_ref()
+                    .and_then(|auth_type| auth_type.get_created_by()),
             )
         },
         auth::auth_type(
@@ -258,77 +266,134 @@ pub async fn routing_retrieve_config(
     .await
 }
 
-#[cfg(all(feature = "olap", feature = "v2"))]
+#[cfg(all(feature = "olap", feature = "v1"))]
 #[instrument(skip_all)]
-pub async fn routing_retrieve_config(
+pub async fn routing_diff_config(
     state: web::Data<AppState>,
     req: HttpRequest,
-    path: web::Path<common_utils::id_type::RoutingId>,
+    path: web::Path<(
+        common_utils::id_type::RoutingId,
+        common_utils::id_type::RoutingId,
+    )>,
 ) -> impl Responder {
-    let algorithm_id = path.into_inner();
-    let flow = Flow::RoutingRetrieveConfig;
+    let flow = Flow::RoutingDiffConfig;
+    let (algorithm_id, other_algorithm_id) = path.into_inner();
+    let request = routing_types::RoutingAlgorithmDiffRequest {
+        algorithm_id,
+        other_algorithm_id,
+    };
     Box::pin(oss_api::server_wrap(
         flow,
         state,
         &req,
-        algorithm_id,
-        |state, auth: auth::AuthenticationData, algorithm_id, _| {
+        request,
+        |state, auth: auth::AuthenticationData, request, _| {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            routing::retrieve_routing_algorithm_from_algorithm_id(
-                state,
-                merchant_context,
-                Some(auth.profile.get_id().clone()),
-                algorithm_id,
-            )
+            routing::diff_routing_algorithms(state, merchant_context, auth.profile_id, request)
         },
-        #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::V2ApiKeyAuth {
+            &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
-            },
+            }),
             &auth::JWTAuth {
                 permission: Permission::ProfileRoutingRead,
             },
             req.headers(),
         ),
-        #[cfg(feature = "release")]
-        &auth::JWTAuth {
-            permission: Permission::ProfileRoutingRead,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_retrieve_version_history(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::ProfileId>,
+    query: web::Query<routing_types::RoutingActivatePayload>,
+) -> impl Responder {
+    let flow = Flow::RoutingRetrieveVersionHistory;
+    let request = routing_types::RoutingVersionHistoryRequest {
+        profile_id: path.into_inner(),
+        transaction_type: query.into_inner().transaction_type,
+    };
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        request.clone(),
+        |state, auth: auth::AuthenticationData, request, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::retrieve_routing_version_history(
+                state,
+                merchant_context,
+                auth.profile_id,
+                request,
+            )
         },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id: request.profile_id,
+                required_permission: Permission::ProfileRoutingRead,
+            },
+            req.headers(),
+        ),
         api_locking::LockAction::NotApplicable,
     ))
     .await
 }
 
-#[cfg(feature = "olap")]
+#[cfg(all(feature = "olap", feature = "v1"))]
 #[instrument(skip_all)]
-pub async fn list_routing_configs(
+pub async fn routing_rollback_config(
     state: web::Data<AppState>,
     req: HttpRequest,
-    query: web::Query<RoutingRetrieveQuery>,
-    transaction_type: Option<enums::TransactionType>,
+    path: web::Path<(common_utils::id_type::ProfileId, u32)>,
+    json_payload: Option<web::Json<routing_types::RoutingActivatePayload>>,
 ) -> impl Responder {
-    let flow = Flow::RoutingRetrieveDictionary;
+    let flow = Flow::RoutingRollbackConfig;
+    let (profile_id, version) = path.into_inner();
+    // Rollbacks of a profile are serialized, as each of them activates a version of its history
+    let lock_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: profile_id.get_string_repr().to_owned(),
+            api_identifier: lock_utils::ApiIdentifier::Routing,
+            override_lock_retries: None,
+        },
+    };
+    let request = routing_types::RoutingRollbackRequest {
+        profile_id,
+        version,
+        transaction_type: json_payload.and_then(|payload| payload.into_inner().transaction_type),
+    };
     Box::pin(oss_api::server_wrap(
         flow,
         state,
         &req,
-        query.into_inner(),
-        |state, auth: auth::AuthenticationData, query_params, _| {
+        request.clone(),
+        |state, auth: auth::AuthenticationData, request, req_state| {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            routing::retrieve_merchant_routing_dictionary(
+            routing::rollback_routing_config(
                 state,
                 merchant_context,
-                None,
-                query_params.clone(),
-                transaction_type
-                    .or(query_params.transaction_type)
-                    .unwrap_or(enums::TransactionType::Payment),
+                auth.profile_id,
+                request,
+                req_state
+                    .auth_type
+                    .as_ref()
+                    .and_then(|auth_type| auth_type.get_created_by()),
             )
         },
         auth::auth_type(
@@ -336,8 +401,50 @@ pub async fn list_routing_configs(
                 is_connected_allowed: false,
                 is_platform_allowed: false,
             }),
-            &auth::JWTAuth {
-                permission: Permission::MerchantRoutingRead,
+            &auth::JWTAuthProfileFromRoute {
+                profile_id: request.profile_id,
+                required_permission: Permission::ProfileRoutingWrite,
+            },
+            req.headers(),
+        ),
+        lock_action,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_list_scheduled_activations(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::ProfileId>,
+) -> impl Responder {
+    let flow = Flow::RoutingListScheduledActivations;
+    let profile_id = path.into_inner();
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        profile_id.clone(),
+        |state, auth: auth::AuthenticationData, profile_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::list_scheduled_routing_activations(
+                state,
+                merchant_context,
+                auth.profile_id,
+                profile_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id,
+                required_permission: Permission::ProfileRoutingRead,
             },
             req.headers(),
         ),
@@ -348,32 +455,105 @@ pub async fn list_routing_configs(
 
 #[cfg(all(feature = "olap", feature = "v1"))]
 #[instrument(skip_all)]
-pub async fn list_routing_configs_for_profile(
+pub async fn routing_retrieve_connector_health(
     state: web::Data<AppState>,
     req: HttpRequest,
-    query: web::Query<RoutingRetrieveQuery>,
-    transaction_type: Option<enums::TransactionType>,
+    query: web::Query<routing_types::ConnectorHealthQuery>,
 ) -> impl Responder {
-    let flow = Flow::RoutingRetrieveDictionary;
+    let flow = Flow::RoutingRetrieveConnectorHealth;
     Box::pin(oss_api::server_wrap(
         flow,
         state,
         &req,
         query.into_inner(),
-        |state, auth: auth::AuthenticationData, query_params, _| {
+        |state, auth: auth::AuthenticationData, query, _| {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            routing::retrieve_merchant_routing_dictionary(
+            routing::retrieve_connector_health(state, merchant_context, auth.profile_id, query)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileRoutingRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_cancel_scheduled_activation(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::ProfileId,
+        common_utils::id_type::RoutingId,
+    )>,
+) -> impl Responder {
+    let flow = Flow::RoutingCancelScheduledActivation;
+    let (profile_id, algorithm_id) = path.into_inner();
+    let request = routing_types::RoutingScheduledActivationRequest {
+        profile_id,
+        algorithm_id,
+    };
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        request.clone(),
+        |state, auth: auth::AuthenticationData, request, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::cancel_scheduled_routing_activation(
                 state,
                 merchant_context,
-                auth.profile_id.map(|profile_id| vec![profile_id]),
-                query_params.clone(),
-                transaction_type
-                    .or(query_params.transaction_type)
-                    .unwrap_or(enums::TransactionType::Payment),
+                auth.profile_id,
+                request,
             )
         },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id: request.profile_id,
+                required_permission: Permission::ProfileRoutingWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_retrieve_audit_log(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<routing_types::RoutingAuditLogQuery>,
+) -> impl Responder {
+    let flow = Flow::RoutingRetrieveAuditLog;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, query, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::retrieve_routing_audit_log(state, merchant_context, auth.profile_id, query)
+        },
         auth::auth_type(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
@@ -389,49 +569,50 @@ pub async fn list_routing_configs_for_profile(
     .await
 }
 
-#[cfg(all(feature = "olap", feature = "v2"))]
+#[cfg(all(feature = "olap", feature = "v1"))]
 #[instrument(skip_all)]
-pub async fn routing_unlink_config(
+pub async fn start_routing_experiment(
     state: web::Data<AppState>,
     req: HttpRequest,
     path: web::Path<common_utils::id_type::ProfileId>,
-    transaction_type: &enums::TransactionType,
+    json_payload: web::Json<routing_types::RoutingExperimentRequest>,
 ) -> impl Responder {
-    let flow = Flow::RoutingUnlinkConfig;
-    let path = path.into_inner();
+    let flow = Flow::RoutingStartExperiment;
+    let payload = routing_types::RoutingExperimentRequestWrapper {
+        profile_id: path.into_inner(),
+        request: json_payload.into_inner(),
+    };
     Box::pin(oss_api::server_wrap(
         flow,
         state,
         &req,
-        path.clone(),
-        |state, auth: auth::AuthenticationData, path, _| {
+        payload.clone(),
+        |state, auth: auth::AuthenticationData, payload, req_state| {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            routing::unlink_routing_config_under_profile(
+            routing::start_routing_experiment(
                 state,
                 merchant_context,
-                path,
-                transaction_type,
+                auth.profile_id,
+                payload,
+                req_state
+                    .auth_type
+                    .as_ref()
+                    .and_then(|auth_type| auth_type.get_created_by()),
             )
         },
-        #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::V2ApiKeyAuth {
+            &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
-            },
+            }),
             &auth::JWTAuthProfileFromRoute {
-                profile_id: path,
-                required_permission: Permission::MerchantRoutingWrite,
+                profile_id: payload.profile_id,
+                required_permission: Permission::ProfileRoutingWrite,
             },
             req.headers(),
         ),
-        #[cfg(feature = "release")]
-        &auth::JWTAuthProfileFromRoute {
-            profile_id: path,
-            required_permission: Permission::MerchantRoutingWrite,
-        },
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -439,30 +620,31 @@ pub async fn routing_unlink_config(
 
 #[cfg(all(feature = "olap", feature = "v1"))]
 #[instrument(skip_all)]
-pub async fn routing_unlink_config(
+pub async fn stop_routing_experiment(
     state: web::Data<AppState>,
     req: HttpRequest,
-    payload: web::Json<routing_types::RoutingConfigRequest>,
-    transaction_type: Option<enums::TransactionType>,
+    path: web::Path<common_utils::id_type::ProfileId>,
 ) -> impl Responder {
-    let flow = Flow::RoutingUnlinkConfig;
+    let flow = Flow::RoutingStopExperiment;
+    let profile_id = path.into_inner();
     Box::pin(oss_api::server_wrap(
         flow,
         state,
         &req,
-        payload.into_inner(),
-        |state, auth: auth::AuthenticationData, payload_req, _| {
+        profile_id.clone(),
+        |state, auth: auth::AuthenticationData, profile_id, req_state| {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            routing::unlink_routing_config(
+            routing::stop_routing_experiment(
                 state,
                 merchant_context,
-                payload_req.clone(),
                 auth.profile_id,
-                transaction_type
-                    .or(payload_req.transaction_type)
-                    .unwrap_or(enums::TransactionType::Payment),
+                profile_id,
+                req_state
+                    .auth_type
+                    .as_ref()
+                    .and_then(|auth_type| auth_type.get_created_by()),
             )
         },
         auth::auth_type(
@@ -470,8 +652,9 @@ pub async fn routing_unlink_config(
                 is_connected_allowed: false,
                 is_platform_allowed: false,
             }),
-            &auth::JWTAuth {
-                permission: Permission::ProfileRoutingWrite,
+            &auth::JWTAuthProfileFromRoute {
+                profile_id,
+                required_permission: Permission::ProfileRoutingWrite,
             },
             req.headers(),
         ),
@@ -480,48 +663,918 @@ pub async fn routing_unlink_config(
     .await
 }
 
-#[cfg(all(feature = "olap", feature = "v2"))]
+#[cfg(all(feature = "olap", feature = "v1"))]
 #[instrument(skip_all)]
-pub async fn routing_update_default_config(
+pub async fn retrieve_routing_experiment_results(
     state: web::Data<AppState>,
     req: HttpRequest,
     path: web::Path<common_utils::id_type::ProfileId>,
-    json_payload: web::Json<Vec<routing_types::RoutableConnectorChoice>>,
 ) -> impl Responder {
-    let wrapper = routing_types::ProfileDefaultRoutingConfig {
-        profile_id: path.into_inner(),
-        connectors: json_payload.into_inner(),
-    };
+    let flow = Flow::RoutingRetrieveExperimentResults;
+    let profile_id = path.into_inner();
     Box::pin(oss_api::server_wrap(
-        Flow::RoutingUpdateDefaultConfig,
+        flow,
         state,
         &req,
-        wrapper,
-        |state, auth: auth::AuthenticationData, wrapper, _| {
+        profile_id.clone(),
+        |state, auth: auth::AuthenticationData, profile_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::retrieve_routing_experiment_results(
+                state,
+                merchant_context,
+                auth.profile_id,
+                profile_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id,
+                required_permission: Permission::ProfileRoutingRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn export_routing_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::ProfileId>,
+) -> impl Responder {
+    let flow = Flow::RoutingExportConfig;
+    let profile_id = path.into_inner();
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        profile_id.clone(),
+        |state, auth: auth::AuthenticationData, profile_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::export_routing_config(state, merchant_context, auth.profile_id, profile_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id,
+                required_permission: Permission::ProfileRoutingRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn import_routing_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::ProfileId>,
+    json_payload: web::Json<routing_types::RoutingConfigImportRequest>,
+) -> impl Responder {
+    let flow = Flow::RoutingImportConfig;
+    let payload = routing_types::RoutingConfigImportRequestWrapper {
+        profile_id: path.into_inner(),
+        request: json_payload.into_inner(),
+    };
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.clone(),
+        |state, auth: auth::AuthenticationData, payload, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::import_routing_config(
+                state,
+                merchant_context,
+                auth.profile_id,
+                payload,
+                req_state
+                    .auth_type
+                    .as_ref()
+                    .and_then(|auth_type| auth_type.get_created_by()),
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id: payload.profile_id,
+                required_permission: Permission::ProfileRoutingWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn upsert_latency_based_routing_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::ProfileId>,
+    json_payload: web::Json<routing_types::LatencyBasedRoutingConfig>,
+) -> impl Responder {
+    let flow = Flow::LatencyBasedRoutingUpdateConfig;
+    let payload = routing_types::LatencyBasedRoutingConfigWrapper {
+        profile_id: path.into_inner(),
+        config: json_payload.into_inner(),
+    };
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.clone(),
+        |state, auth: auth::AuthenticationData, payload, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::configure_latency_based_routing(
+                state,
+                merchant_context,
+                auth.profile_id,
+                payload,
+                req_state
+                    .auth_type
+                    .as_ref()
+                    .and_then(|auth_type| auth_type.get_created_by()),
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id: payload.profile_id,
+                required_permission: Permission::ProfileRoutingWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn retrieve_latency_based_routing_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::ProfileId>,
+) -> impl Responder {
+    let flow = Flow::LatencyBasedRoutingRetrieveConfig;
+    let profile_id = path.into_inner();
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        profile_id.clone(),
+        |state, auth: auth::AuthenticationData, profile_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::retrieve_latency_based_routing(
+                state,
+                merchant_context,
+                auth.profile_id,
+                profile_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id,
+                required_permission: Permission::ProfileRoutingRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn upsert_connector_fee_schedule(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::ProfileId,
+        common_utils::id_type::MerchantConnectorAccountId,
+    )>,
+    json_payload: web::Json<routing_types::ConnectorFeeScheduleRequest>,
+) -> impl Responder {
+    let flow = Flow::RoutingUpsertFeeSchedule;
+    let (profile_id, merchant_connector_id) = path.into_inner();
+    let payload = routing_types::ConnectorFeeScheduleWrapper {
+        profile_id,
+        merchant_connector_id,
+        rules: json_payload.into_inner().rules,
+    };
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.clone(),
+        |state, auth: auth::AuthenticationData, payload, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::upsert_connector_fee_schedule(
+                state,
+                merchant_context,
+                auth.profile_id,
+                payload,
+                req_state
+                    .auth_type
+                    .as_ref()
+                    .and_then(|auth_type| auth_type.get_created_by()),
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id: payload.profile_id,
+                required_permission: Permission::ProfileRoutingWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn retrieve_connector_fee_schedule(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::ProfileId,
+        common_utils::id_type::MerchantConnectorAccountId,
+    )>,
+) -> impl Responder {
+    let flow = Flow::RoutingRetrieveFeeSchedule;
+    let (profile_id, merchant_connector_id) = path.into_inner();
+    let payload = routing_types::ConnectorFeeScheduleQuery {
+        profile_id,
+        merchant_connector_id,
+    };
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.clone(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::retrieve_connector_fee_schedule(
+                state,
+                merchant_context,
+                auth.profile_id,
+                payload,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id: payload.profile_id,
+                required_permission: Permission::ProfileRoutingRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn delete_connector_fee_schedule(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::ProfileId,
+        common_utils::id_type::MerchantConnectorAccountId,
+    )>,
+) -> impl Responder {
+    let flow = Flow::RoutingDeleteFeeSchedule;
+    let (profile_id, merchant_connector_id) = path.into_inner();
+    let payload = routing_types::ConnectorFeeScheduleQuery {
+        profile_id,
+        merchant_connector_id,
+    };
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.clone(),
+        |state, auth: auth::AuthenticationData, payload, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::delete_connector_fee_schedule(
+                state,
+                merchant_context,
+                auth.profile_id,
+                payload,
+                req_state
+                    .auth_type
+                    .as_ref()
+                    .and_then(|auth_type| auth_type.get_created_by()),
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id: payload.profile_id,
+                required_permission: Permission::ProfileRoutingWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_simulate_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<routing_types::RoutingSimulateRequest>,
+) -> impl Responder {
+    let flow = Flow::RoutingSimulateConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::simulate_routing_algorithm(state, merchant_context, auth.profile_id, payload)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileRoutingRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v2"))]
+#[instrument(skip_all)]
+pub async fn routing_retrieve_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::RoutingId>,
+) -> impl Responder {
+    let algorithm_id = path.into_inner();
+    let flow = Flow::RoutingRetrieveConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        algorithm_id,
+        |state, auth: auth::AuthenticationData, algorithm_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::retrieve_routing_algorithm_from_algorithm_id(
+                state,
+                merchant_context,
+                Some(auth.profile.get_id().clone()),
+                algorithm_id,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::V2ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileRoutingRead,
+            },
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth {
+            permission: Permission::ProfileRoutingRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn list_routing_configs(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<RoutingRetrieveQuery>,
+    transaction_type: Option<enums::TransactionType>,
+) -> impl Responder {
+    let flow = Flow::RoutingRetrieveDictionary;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, query_params, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::retrieve_merchant_routing_dictionary(
+                state,
+                merchant_context,
+                None,
+                query_params.clone(),
+                transaction_type
+                    .or(query_params.transaction_type)
+                    .unwrap_or(enums::TransactionType::Payment),
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantRoutingRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn list_routing_configs_for_profile(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<RoutingRetrieveQuery>,
+    transaction_type: Option<enums::TransactionType>,
+) -> impl Responder {
+    let flow = Flow::RoutingRetrieveDictionary;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, query_params, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::retrieve_merchant_routing_dictionary(
+                state,
+                merchant_context,
+                auth.profile_id.map(|profile_id| vec![profile_id]),
+                query_params.clone(),
+                transaction_type
+                    .or(query_params.transaction_type)
+                    .unwrap_or(enums::TransactionType::Payment),
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileRoutingRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v2"))]
+#[instrument(skip_all)]
+pub async fn routing_unlink_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::ProfileId>,
+    transaction_type: &enums::TransactionType,
+) -> impl Responder {
+    let flow = Flow::RoutingUnlinkConfig;
+    let path = path.into_inner();
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.clone(),
+        |state, auth: auth::AuthenticationData, path, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::unlink_routing_config_under_profile(
+                state,
+                merchant_context,
+                path,
+                transaction_type,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::V2ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            },
+            &auth::JWTAuthProfileFromRoute {
+                profile_id: path,
+                required_permission: Permission::MerchantRoutingWrite,
+            },
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuthProfileFromRoute {
+            profile_id: path,
+            required_permission: Permission::MerchantRoutingWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_unlink_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Json<routing_types::RoutingConfigRequest>,
+    transaction_type: Option<enums::TransactionType>,
+) -> impl Responder {
+    let flow = Flow::RoutingUnlinkConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload_req, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::unlink_routing_config(
+                state,
+                merchant_context,
+                payload_req.clone(),
+                auth.profile_id,
+                transaction_type
+                    .or(payload_req.transaction_type)
+                    .unwrap_or(enums::TransactionType::Payment),
+                req_state
+                    .auth_type
+                    .as_ref()
+                    .and_then(|auth_type| auth_type.get_created_by()),
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileRoutingWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v2"))]
+#[instrument(skip_all)]
+pub async fn routing_update_default_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::ProfileId>,
+    json_payload: web::Json<Vec<routing_types::RoutableConnectorChoice>>,
+) -> impl Responder {
+    let wrapper = routing_types::ProfileDefaultRoutingConfig {
+        profile_id: path.into_inner(),
+        connectors: json_payload.into_inner(),
+    };
+    Box::pin(oss_api::server_wrap(
+        Flow::RoutingUpdateDefaultConfig,
+        state,
+        &req,
+        wrapper,
+        |state, auth: auth::AuthenticationData, wrapper, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::update_default_fallback_routing(
+                state,
+                merchant_context,
+                wrapper.profile_id,
+                wrapper.connectors,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::V2ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantRoutingWrite,
+            },
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth {
+            permission: Permission::MerchantRoutingWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_update_default_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<Vec<routing_types::RoutableConnectorChoice>>,
+    transaction_type: &enums::TransactionType,
+) -> impl Responder {
+    Box::pin(oss_api::server_wrap(
+        Flow::RoutingUpdateDefaultConfig,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, updated_config, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::update_default_routing_config(
+                state,
+                merchant_context,
+                updated_config,
+                transaction_type,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantRoutingWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v2"))]
+#[instrument(skip_all)]
+pub async fn routing_retrieve_default_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::ProfileId>,
+) -> impl Responder {
+    let path = path.into_inner();
+    Box::pin(oss_api::server_wrap(
+        Flow::RoutingRetrieveDefaultConfig,
+        state,
+        &req,
+        path.clone(),
+        |state, auth: auth::AuthenticationData, profile_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::retrieve_default_fallback_algorithm_for_profile(
+                state,
+                merchant_context,
+                profile_id,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::V2ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            },
+            &auth::JWTAuthProfileFromRoute {
+                profile_id: path,
+                required_permission: Permission::MerchantRoutingRead,
+            },
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuthProfileFromRoute {
+            profile_id: path,
+            required_permission: Permission::MerchantRoutingRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_retrieve_default_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    transaction_type: &enums::TransactionType,
+) -> impl Responder {
+    Box::pin(oss_api::server_wrap(
+        Flow::RoutingRetrieveDefaultConfig,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::retrieve_default_routing_config(
+                state,
+                auth.profile_id,
+                merchant_context,
+                transaction_type,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileRoutingRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn upsert_surcharge_decision_manager_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::surcharge_decision_configs::SurchargeDecisionConfigReq>,
+) -> impl Responder {
+    let flow = Flow::DecisionManagerUpsertConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, update_decision, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            surcharge_decision_config::upsert_surcharge_decision_config(
+                state,
+                merchant_context,
+                update_decision,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantSurchargeDecisionManagerWrite,
+            },
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth {
+            permission: Permission::MerchantSurchargeDecisionManagerWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn delete_surcharge_decision_manager_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> impl Responder {
+    let flow = Flow::DecisionManagerDeleteConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, (), _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            surcharge_decision_config::delete_surcharge_decision_config(state, merchant_context)
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantSurchargeDecisionManagerWrite,
+            },
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth {
+            permission: Permission::MerchantSurchargeDecisionManagerWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn retrieve_surcharge_decision_manager_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> impl Responder {
+    let flow = Flow::DecisionManagerRetrieveConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            routing::update_default_fallback_routing(
-                state,
-                merchant_context,
-                wrapper.profile_id,
-                wrapper.connectors,
-            )
+            surcharge_decision_config::retrieve_surcharge_decision_config(state, merchant_context)
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::V2ApiKeyAuth {
+            &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
-            },
+            }),
             &auth::JWTAuth {
-                permission: Permission::MerchantRoutingWrite,
+                permission: Permission::MerchantSurchargeDecisionManagerRead,
             },
             req.headers(),
         ),
         #[cfg(feature = "release")]
         &auth::JWTAuth {
-            permission: Permission::MerchantRoutingWrite,
+            permission: Permission::MerchantSurchargeDecisionManagerRead,
         },
         api_locking::LockAction::NotApplicable,
     ))
@@ -530,26 +1583,28 @@ pub async fn routing_update_default_config(
 
 #[cfg(all(feature = "olap", feature = "v1"))]
 #[instrument(skip_all)]
-pub async fn routing_update_default_config(
+pub async fn upsert_surcharge_decision_manager_config_for_profile(
     state: web::Data<AppState>,
     req: HttpRequest,
-    json_payload: web::Json<Vec<routing_types::RoutableConnectorChoice>>,
-    transaction_type: &enums::TransactionType,
+    path: web::Path<common_utils::id_type::ProfileId>,
+    json_payload: web::Json<api_models::surcharge_decision_configs::SurchargeDecisionConfigReq>,
 ) -> impl Responder {
+    let flow = Flow::DecisionManagerUpsertConfig;
+    let profile_id = path.into_inner();
     Box::pin(oss_api::server_wrap(
-        Flow::RoutingUpdateDefaultConfig,
+        flow,
         state,
         &req,
-        json_payload.into_inner(),
-        |state, auth: auth::AuthenticationData, updated_config, _| {
+        (profile_id.clone(), json_payload.into_inner()),
+        |state, auth: auth::AuthenticationData, (profile_id, update_decision), _| {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            routing::update_default_routing_config(
+            surcharge_decision_config::upsert_surcharge_decision_config_for_profile(
                 state,
                 merchant_context,
-                updated_config,
-                transaction_type,
+                profile_id,
+                update_decision,
             )
         },
         auth::auth_type(
@@ -557,8 +1612,9 @@ pub async fn routing_update_default_config(
                 is_connected_allowed: false,
                 is_platform_allowed: false,
             }),
-            &auth::JWTAuth {
-                permission: Permission::MerchantRoutingWrite,
+            &auth::JWTAuthProfileFromRoute {
+                profile_id,
+                required_permission: Permission::ProfileSurchargeDecisionManagerWrite,
             },
             req.headers(),
         ),
@@ -567,46 +1623,41 @@ pub async fn routing_update_default_config(
     .await
 }
 
-#[cfg(all(feature = "olap", feature = "v2"))]
+#[cfg(all(feature = "olap", feature = "v1"))]
 #[instrument(skip_all)]
-pub async fn routing_retrieve_default_config(
+pub async fn delete_surcharge_decision_manager_config_for_profile(
     state: web::Data<AppState>,
     req: HttpRequest,
     path: web::Path<common_utils::id_type::ProfileId>,
 ) -> impl Responder {
-    let path = path.into_inner();
+    let flow = Flow::DecisionManagerDeleteConfig;
+    let profile_id = path.into_inner();
     Box::pin(oss_api::server_wrap(
-        Flow::RoutingRetrieveDefaultConfig,
+        flow,
         state,
         &req,
-        path.clone(),
+        profile_id.clone(),
         |state, auth: auth::AuthenticationData, profile_id, _| {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            routing::retrieve_default_fallback_algorithm_for_profile(
+            surcharge_decision_config::delete_surcharge_decision_config_for_profile(
                 state,
                 merchant_context,
                 profile_id,
             )
         },
-        #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::V2ApiKeyAuth {
+            &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
-            },
+            }),
             &auth::JWTAuthProfileFromRoute {
-                profile_id: path,
-                required_permission: Permission::MerchantRoutingRead,
+                profile_id,
+                required_permission: Permission::ProfileSurchargeDecisionManagerWrite,
             },
             req.headers(),
         ),
-        #[cfg(feature = "release")]
-        &auth::JWTAuthProfileFromRoute {
-            profile_id: path,
-            required_permission: Permission::MerchantRoutingRead,
-        },
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -614,25 +1665,26 @@ pub async fn routing_retrieve_default_config(
 
 #[cfg(all(feature = "olap", feature = "v1"))]
 #[instrument(skip_all)]
-pub async fn routing_retrieve_default_config(
+pub async fn retrieve_surcharge_decision_manager_config_for_profile(
     state: web::Data<AppState>,
     req: HttpRequest,
-    transaction_type: &enums::TransactionType,
+    path: web::Path<common_utils::id_type::ProfileId>,
 ) -> impl Responder {
+    let flow = Flow::DecisionManagerRetrieveConfig;
+    let profile_id = path.into_inner();
     Box::pin(oss_api::server_wrap(
-        Flow::RoutingRetrieveDefaultConfig,
+        flow,
         state,
         &req,
-        (),
-        |state, auth: auth::AuthenticationData, _, _| {
+        profile_id.clone(),
+        |state, auth: auth::AuthenticationData, profile_id, _| {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            routing::retrieve_default_routing_config(
+            surcharge_decision_config::retrieve_surcharge_decision_config_for_profile(
                 state,
-                auth.profile_id,
                 merchant_context,
-                transaction_type,
+                profile_id,
             )
         },
         auth::auth_type(
@@ -640,8 +1692,9 @@ pub async fn routing_retrieve_default_config(
                 is_connected_allowed: false,
                 is_platform_allowed: false,
             }),
-            &auth::JWTAuth {
-                permission: Permission::ProfileRoutingRead,
+            &auth::JWTAuthProfileFromRoute {
+                profile_id,
+                required_permission: Permission::ProfileSurchargeDecisionManagerRead,
             },
             req.headers(),
         ),
@@ -650,12 +1703,14 @@ pub async fn routing_retrieve_default_config(
     .await
 }
 
-#[cfg(feature = "olap")]
+#[cfg(all(feature = "olap", feature = "v1"))]
 #[instrument(skip_all)]
-pub async fn upsert_surcharge_decision_manager_config(
+pub async fn upsert_three_ds_decision_manager_config(
     state: web::Data<AppState>,
     req: HttpRequest,
-    json_payload: web::Json<api_models::surcharge_decision_configs::SurchargeDecisionConfigReq>,
+    json_payload: web::Json<
+        api_models::three_ds_decision_rule::ThreeDsDecisionManagerConfigRequest,
+    >,
 ) -> impl Responder {
     let flow = Flow::DecisionManagerUpsertConfig;
     Box::pin(oss_api::server_wrap(
@@ -663,14 +1718,14 @@ pub async fn upsert_surcharge_decision_manager_config(
         state,
         &req,
         json_payload.into_inner(),
-        |state, auth: auth::AuthenticationData, update_decision, _| {
+        |state, auth: auth::AuthenticationData, payload, _| {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            surcharge_decision_config::upsert_surcharge_decision_config(
+            three_ds_decision_rule::upsert_three_ds_decision_manager_config(
                 state,
                 merchant_context,
-                update_decision,
+                payload,
             )
         },
         #[cfg(not(feature = "release"))]
@@ -680,21 +1735,22 @@ pub async fn upsert_surcharge_decision_manager_config(
                 is_platform_allowed: false,
             }),
             &auth::JWTAuth {
-                permission: Permission::MerchantSurchargeDecisionManagerWrite,
+                permission: Permission::MerchantThreeDsDecisionManagerWrite,
             },
             req.headers(),
         ),
         #[cfg(feature = "release")]
         &auth::JWTAuth {
-            permission: Permission::MerchantSurchargeDecisionManagerWrite,
+            permission: Permission::MerchantThreeDsDecisionManagerWrite,
         },
         api_locking::LockAction::NotApplicable,
     ))
     .await
 }
-#[cfg(feature = "olap")]
+
+#[cfg(all(feature = "olap", feature = "v1"))]
 #[instrument(skip_all)]
-pub async fn delete_surcharge_decision_manager_config(
+pub async fn delete_three_ds_decision_manager_config(
     state: web::Data<AppState>,
     req: HttpRequest,
 ) -> impl Responder {
@@ -708,7 +1764,7 @@ pub async fn delete_surcharge_decision_manager_config(
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            surcharge_decision_config::delete_surcharge_decision_config(state, merchant_context)
+            three_ds_decision_rule::delete_three_ds_decision_manager_config(state, merchant_context)
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
@@ -717,22 +1773,22 @@ pub async fn delete_surcharge_decision_manager_config(
                 is_platform_allowed: false,
             }),
             &auth::JWTAuth {
-                permission: Permission::MerchantSurchargeDecisionManagerWrite,
+                permission: Permission::MerchantThreeDsDecisionManagerWrite,
             },
             req.headers(),
         ),
         #[cfg(feature = "release")]
         &auth::JWTAuth {
-            permission: Permission::MerchantSurchargeDecisionManagerWrite,
+            permission: Permission::MerchantThreeDsDecisionManagerWrite,
         },
         api_locking::LockAction::NotApplicable,
     ))
     .await
 }
 
-#[cfg(feature = "olap")]
+#[cfg(all(feature = "olap", feature = "v1"))]
 #[instrument(skip_all)]
-pub async fn retrieve_surcharge_decision_manager_config(
+pub async fn retrieve_three_ds_decision_manager_config(
     state: web::Data<AppState>,
     req: HttpRequest,
 ) -> impl Responder {
@@ -746,7 +1802,10 @@ pub async fn retrieve_surcharge_decision_manager_config(
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            surcharge_decision_config::retrieve_surcharge_decision_config(state, merchant_context)
+            three_ds_decision_rule::retrieve_three_ds_decision_manager_config(
+                state,
+                merchant_context,
+            )
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
@@ -755,13 +1814,13 @@ pub async fn retrieve_surcharge_decision_manager_config(
                 is_platform_allowed: false,
             }),
             &auth::JWTAuth {
-                permission: Permission::MerchantSurchargeDecisionManagerRead,
+                permission: Permission::MerchantThreeDsDecisionManagerRead,
             },
             req.headers(),
         ),
         #[cfg(feature = "release")]
         &auth::JWTAuth {
-            permission: Permission::MerchantSurchargeDecisionManagerRead,
+            permission: Permission::MerchantThreeDsDecisionManagerRead,
         },
         api_locking::LockAction::NotApplicable,
     ))
@@ -807,6 +1866,42 @@ pub async fn upsert_decision_manager_config(
     .await
 }
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn validate_decision_manager_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::conditional_configs::DecisionManager>,
+) -> impl Responder {
+    let flow = Flow::DecisionManagerValidateConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |_, _: auth::AuthenticationData, decision, _| {
+            conditional_config::validate_conditional_config(decision)
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantThreeDsDecisionManagerWrite,
+            },
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth {
+            permission: Permission::MerchantThreeDsDecisionManagerWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(feature = "olap", feature = "v2"))]
 #[instrument(skip_all)]
 pub async fn upsert_decision_manager_config(
@@ -1163,7 +2258,7 @@ pub async fn routing_update_default_config_for_profile(
         state,
         &req,
         routing_payload_wrapper.clone(),
-        |state, auth: auth::AuthenticationData, wrapper, _| {
+        |state, auth: auth::AuthenticationData, wrapper, req_state| {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
@@ -1173,6 +2268,10 @@ pub async fn routing_update_default_config_for_profile(
                 wrapper.updated_config,
                 wrapper.profile_id,
                 transaction_type,
+                req_state
+                    .auth_type
+                    .as_ref()
+                    .and_then(|auth_type| auth_type.get_created_by()),
             )
         },
         auth::auth_type(
@@ -1191,6 +2290,151 @@ pub async fn routing_update_default_config_for_profile(
     .await
 }
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_update_payment_method_default_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::ProfileId, enums::PaymentMethodType)>,
+    json_payload: web::Json<Vec<routing_types::RoutableConnectorChoice>>,
+) -> impl Responder {
+    let flow = Flow::RoutingUpdatePaymentMethodDefaultConfig;
+    let (profile_id, payment_method_type) = path.into_inner();
+    let payload = routing_types::PaymentMethodDefaultRoutingConfig {
+        profile_id,
+        payment_method_type,
+        connectors: json_payload.into_inner(),
+    };
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.clone(),
+        |state, auth: auth::AuthenticationData, payload, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::update_payment_method_default_routing_config(
+                state,
+                merchant_context,
+                auth.profile_id,
+                payload,
+                req_state
+                    .auth_type
+                    .as_ref()
+                    .and_then(|auth_type| auth_type.get_created_by()),
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id: payload.profile_id,
+                required_permission: Permission::ProfileRoutingWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_retrieve_payment_method_default_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::ProfileId, enums::PaymentMethodType)>,
+) -> impl Responder {
+    let flow = Flow::RoutingRetrievePaymentMethodDefaultConfig;
+    let (profile_id, payment_method_type) = path.into_inner();
+    let query = routing_types::PaymentMethodDefaultRoutingConfigQuery {
+        profile_id,
+        payment_method_type,
+    };
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.clone(),
+        |state, auth: auth::AuthenticationData, query, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::retrieve_payment_method_default_routing_config(
+                state,
+                merchant_context,
+                auth.profile_id,
+                query,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id: query.profile_id,
+                required_permission: Permission::ProfileRoutingRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_delete_payment_method_default_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::ProfileId, enums::PaymentMethodType)>,
+) -> impl Responder {
+    let flow = Flow::RoutingDeletePaymentMethodDefaultConfig;
+    let (profile_id, payment_method_type) = path.into_inner();
+    let query = routing_types::PaymentMethodDefaultRoutingConfigQuery {
+        profile_id,
+        payment_method_type,
+    };
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.clone(),
+        |state, auth: auth::AuthenticationData, query, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::delete_payment_method_default_routing_config(
+                state,
+                merchant_context,
+                auth.profile_id,
+                query,
+                req_state
+                    .auth_type
+                    .as_ref()
+                    .and_then(|auth_type| auth_type.get_created_by()),
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id: query.profile_id,
+                required_permission: Permission::ProfileRoutingWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(feature = "olap", feature = "v1", feature = "dynamic_routing"))]
 #[instrument(skip_all)]
 pub async fn toggle_success_based_routing(
@@ -1212,7 +2456,7 @@ pub async fn toggle_success_based_routing(
         |state,
          auth: auth::AuthenticationData,
          wrapper: routing_types::ToggleDynamicRoutingWrapper,
-         _| {
+         req_state| {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
@@ -1222,6 +2466,10 @@ pub async fn toggle_success_based_routing(
                 wrapper.feature_to_enable,
                 wrapper.profile_id,
                 api_models::routing::DynamicRoutingType::SuccessRateBasedRouting,
+                req_state
+                    .auth_type
+                    .as_ref()
+                    .and_then(|auth_type| auth_type.get_created_by()),
             )
         },
         auth::auth_type(
@@ -1265,7 +2513,7 @@ pub async fn create_success_based_routing(
         |state,
          auth: auth::AuthenticationData,
          wrapper: routing_types::CreateDynamicRoutingWrapper,
-         _| {
+         req_state| {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
@@ -1276,6 +2524,10 @@ pub async fn create_success_based_routing(
                 wrapper.profile_id,
                 api_models::routing::DynamicRoutingType::SuccessRateBasedRouting,
                 wrapper.payload,
+                req_state
+                    .auth_type
+                    .as_ref()
+                    .and_then(|auth_type| auth_type.get_created_by()),
             )
         },
         auth::auth_type(
@@ -1313,12 +2565,23 @@ pub async fn success_based_routing_update_configs(
         state,
         &req,
         routing_payload_wrapper.clone(),
-        |state, _, wrapper: routing_types::SuccessBasedRoutingPayloadWrapper, _| async {
+        |state,
+         auth: auth::AuthenticationData,
+         wrapper: routing_types::SuccessBasedRoutingPayloadWrapper,
+         req_state| async move {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
             Box::pin(routing::success_based_routing_update_configs(
                 state,
                 wrapper.updated_config,
+                merchant_context,
                 wrapper.algorithm_id,
                 wrapper.profile_id,
+                req_state
+                    .auth_type
+                    .as_ref()
+                    .and_then(|auth_type| auth_type.get_created_by()),
             ))
             .await
         },
@@ -1357,12 +2620,23 @@ pub async fn elimination_routing_update_configs(
         state,
         &req,
         routing_payload_wrapper.clone(),
-        |state, _, wrapper: routing_types::EliminationRoutingPayloadWrapper, _| async {
+        |state,
+         auth: auth::AuthenticationData,
+         wrapper: routing_types::EliminationRoutingPayloadWrapper,
+         req_state| async move {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
             Box::pin(routing::elimination_routing_update_configs(
                 state,
                 wrapper.updated_config,
+                merchant_context,
                 wrapper.algorithm_id,
                 wrapper.profile_id,
+                req_state
+                    .auth_type
+                    .as_ref()
+                    .and_then(|auth_type| auth_type.get_created_by()),
             ))
             .await
         },
@@ -1405,7 +2679,7 @@ pub async fn contract_based_routing_setup_config(
         |state,
          auth: auth::AuthenticationData,
          wrapper: routing_types::ContractBasedRoutingSetupPayloadWrapper,
-         _| async move {
+         req_state| async move {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
@@ -1415,6 +2689,10 @@ pub async fn contract_based_routing_setup_config(
                 wrapper.profile_id,
                 wrapper.features_to_enable,
                 wrapper.config,
+                req_state
+                    .auth_type
+                    .as_ref()
+                    .and_then(|auth_type| auth_type.get_created_by()),
             ))
             .await
         },
@@ -1456,7 +2734,7 @@ pub async fn contract_based_routing_update_configs(
         |state,
          auth: auth::AuthenticationData,
          wrapper: routing_types::ContractBasedRoutingPayloadWrapper,
-         _| async {
+         req_state| async move {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
@@ -1466,6 +2744,10 @@ pub async fn contract_based_routing_update_configs(
                 merchant_context,
                 wrapper.algorithm_id,
                 wrapper.profile_id,
+                req_state
+                    .auth_type
+                    .as_ref()
+                    .and_then(|auth_type| auth_type.get_created_by()),
             ))
             .await
         },
@@ -1506,7 +2788,7 @@ pub async fn toggle_elimination_routing(
         |state,
          auth: auth::AuthenticationData,
          wrapper: routing_types::ToggleDynamicRoutingWrapper,
-         _| {
+         req_state| {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
@@ -1516,6 +2798,10 @@ pub async fn toggle_elimination_routing(
                 wrapper.feature_to_enable,
                 wrapper.profile_id,
                 api_models::routing::DynamicRoutingType::EliminationRouting,
+                req_state
+                    .auth_type
+                    .as_ref()
+                    .and_then(|auth_type| auth_type.get_created_by()),
             )
         },
         auth::auth_type(
@@ -1559,7 +2845,7 @@ pub async fn create_elimination_routing(
         |state,
          auth: auth::AuthenticationData,
          wrapper: routing_types::CreateDynamicRoutingWrapper,
-         _| {
+         req_state| {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
@@ -1570,6 +2856,10 @@ pub async fn create_elimination_routing(
                 wrapper.profile_id,
                 api_models::routing::DynamicRoutingType::EliminationRouting,
                 wrapper.payload,
+                req_state
+                    .auth_type
+                    .as_ref()
+                    .and_then(|auth_type| auth_type.get_created_by()),
             )
         },
         auth::auth_type(
@@ -1614,7 +2904,7 @@ pub async fn set_dynamic_routing_volume_split(
         |state,
          auth: auth::AuthenticationData,
          payload: api_models::routing::RoutingVolumeSplitWrapper,
-         _| {
+         req_state| {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
@@ -1623,6 +2913,10 @@ pub async fn set_dynamic_routing_volume_split(
                 merchant_context,
                 payload.profile_id,
                 payload.routing_info,
+                req_state
+                    .auth_type
+                    .as_ref()
+                    .and_then(|auth_type| auth_type.get_created_by()),
             )
         },
         auth::auth_type(
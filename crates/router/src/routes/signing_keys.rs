@@ -0,0 +1,103 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, signing_keys},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::SigningKeyCreate))]
+pub async fn signing_key_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> impl Responder {
+    let flow = Flow::SigningKeyCreate;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth_data, _, _| signing_keys::create_signing_key(state, auth_data.key_store),
+        auth::auth_type(
+            &auth::PlatformOrgAdminAuthWithMerchantIdFromRoute {
+                merchant_id_from_route: merchant_id.clone(),
+                is_admin_auth_allowed: true,
+            },
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantApiKeyWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::SigningKeyList))]
+pub async fn signing_key_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> impl Responder {
+    let flow = Flow::SigningKeyList;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id.clone(),
+        |state, _, merchant_id, _| signing_keys::list_signing_keys(state, merchant_id),
+        auth::auth_type(
+            &auth::PlatformOrgAdminAuthWithMerchantIdFromRoute {
+                merchant_id_from_route: merchant_id.clone(),
+                is_admin_auth_allowed: true,
+            },
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantApiKeyRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::SigningKeyRevoke))]
+pub async fn signing_key_revoke(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+) -> impl Responder {
+    let flow = Flow::SigningKeyRevoke;
+    let (merchant_id, key_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (merchant_id.clone(), key_id),
+        |state, _, (merchant_id, key_id), _| {
+            signing_keys::revoke_signing_key(state, merchant_id, key_id)
+        },
+        auth::auth_type(
+            &auth::PlatformOrgAdminAuthWithMerchantIdFromRoute {
+                merchant_id_from_route: merchant_id.clone(),
+                is_admin_auth_allowed: true,
+            },
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantApiKeyWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
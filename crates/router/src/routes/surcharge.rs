@@ -0,0 +1,39 @@
+use actix_web::{web, Responder};
+use hyperswitch_domain_models::merchant_context::{Context, MerchantContext};
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    self as app,
+    core::{api_locking, surcharge_decision_config},
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::SurchargePreview))]
+#[cfg(feature = "oltp")]
+pub async fn preview_surcharge(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    payload: web::Json<api_models::surcharge_decision_configs::SurchargePreviewRequest>,
+) -> impl Responder {
+    let flow = Flow::SurchargePreview;
+    let payload = payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = MerchantContext::NormalMerchant(Box::new(Context(
+                auth.merchant_account,
+                auth.key_store,
+            )));
+            surcharge_decision_config::preview_surcharge(state, merchant_context, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -1,3 +1,7 @@
+pub mod passkey;
+#[cfg(feature = "v1")]
+pub mod scim;
+pub mod session;
 pub mod theme;
 
 use actix_web::{web, HttpRequest, HttpResponse};
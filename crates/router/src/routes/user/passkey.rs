@@ -0,0 +1,173 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::user as user_api;
+use common_enums::TokenPurpose;
+use router_env::Flow;
+
+use crate::{
+    core::{api_locking, user::passkey as passkey_core},
+    routes::AppState,
+    services::{api, authentication as auth},
+};
+
+pub async fn begin_passkey_registration(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::PasskeyRegisterBegin;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, user, _, _| passkey_core::begin_passkey_registration(state, user),
+        &auth::SinglePurposeOrLoginTokenAuth(TokenPurpose::TOTP),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn finish_passkey_registration(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_api::FinishPasskeyRegistrationRequest>,
+) -> HttpResponse {
+    let flow = Flow::PasskeyRegisterFinish;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, user, req_body, _| passkey_core::finish_passkey_registration(state, user, req_body),
+        &auth::SinglePurposeOrLoginTokenAuth(TokenPurpose::TOTP),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn list_passkeys(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::PasskeyList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, user, _, _| passkey_core::list_passkeys(state, user),
+        &auth::DashboardNoPermissionAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn update_passkey(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<user_api::UpdatePasskeyRequest>,
+) -> HttpResponse {
+    let flow = Flow::PasskeyUpdate;
+    let passkey_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, user, req_body, _| {
+            passkey_core::update_passkey(state, user, passkey_id.clone(), req_body)
+        },
+        &auth::DashboardNoPermissionAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn delete_passkey(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PasskeyDelete;
+    let passkey_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        passkey_id,
+        |state, user, passkey_id, _| passkey_core::delete_passkey(state, user, passkey_id),
+        &auth::DashboardNoPermissionAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn begin_passkey_verification(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::PasskeyVerifyBegin;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, user, _, _| passkey_core::begin_passkey_verification(state, user),
+        &auth::SinglePurposeOrLoginTokenAuth(TokenPurpose::TOTP),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn verify_passkey(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_api::VerifyPasskeyRequest>,
+) -> HttpResponse {
+    let flow = Flow::PasskeyVerify;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, user, req_body, _| passkey_core::verify_passkey(state, user, req_body),
+        &auth::SinglePurposeOrLoginTokenAuth(TokenPurpose::TOTP),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn begin_passkey_signin(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_api::BeginPasskeySignInRequest>,
+) -> HttpResponse {
+    let flow = Flow::PasskeySignInBegin;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _: (), req_body, _| passkey_core::begin_passkey_signin(state, req_body),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn passkey_signin(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_api::PasskeySignInRequest>,
+) -> HttpResponse {
+    let flow = Flow::PasskeySignIn;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _: (), req_body, _| passkey_core::passkey_signin(state, req_body),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,204 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::user::scim as scim_api;
+use router_env::Flow;
+
+use crate::{
+    core::{api_locking, user::scim as scim_core},
+    routes::AppState,
+    services::{api, authentication as auth},
+};
+
+pub async fn list_users(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<scim_api::ScimListQuery>,
+) -> HttpResponse {
+    let flow = Flow::ScimUserList;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, query, _| {
+            scim_core::list_users(state, auth.merchant_account, query)
+        },
+        &auth::ScimAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn create_user(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<scim_api::ScimUserRequest>,
+) -> HttpResponse {
+    let flow = Flow::ScimUserCreate;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            scim_core::create_user(state, auth.merchant_account, payload)
+        },
+        &auth::ScimAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn retrieve_user(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ScimUserRetrieve;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, user_id, _| {
+            scim_core::retrieve_user(state, auth.merchant_account, user_id)
+        },
+        &auth::ScimAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn replace_user(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<scim_api::ScimUserRequest>,
+) -> HttpResponse {
+    let flow = Flow::ScimUserReplace;
+    let user_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            scim_core::replace_user(state, auth.merchant_account, user_id.clone(), payload)
+        },
+        &auth::ScimAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn patch_user(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<scim_api::ScimPatchRequest>,
+) -> HttpResponse {
+    let flow = Flow::ScimUserUpdate;
+    let user_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            scim_core::patch_user(state, auth.merchant_account, user_id.clone(), payload)
+        },
+        &auth::ScimAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn delete_user(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ScimUserDelete;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, user_id, _| {
+            scim_core::delete_user(state, auth.merchant_account, user_id)
+        },
+        &auth::ScimAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn list_groups(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<scim_api::ScimListQuery>,
+) -> HttpResponse {
+    let flow = Flow::ScimGroupList;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, query, _| {
+            scim_core::list_groups(state, auth.merchant_account, query)
+        },
+        &auth::ScimAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn retrieve_group(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ScimGroupRetrieve;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, group_id, _| {
+            scim_core::retrieve_group(state, auth.merchant_account, group_id)
+        },
+        &auth::ScimAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn patch_group(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<scim_api::ScimPatchRequest>,
+) -> HttpResponse {
+    let flow = Flow::ScimGroupUpdate;
+    let group_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            scim_core::patch_group(state, auth.merchant_account, group_id.clone(), payload)
+        },
+        &auth::ScimAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,59 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::Flow;
+
+use crate::{
+    core::{api_locking, user::session as session_core},
+    routes::AppState,
+    services::{api, authentication as auth},
+};
+
+pub async fn list_user_sessions(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::UserSessionList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, user, _, _| session_core::list_user_sessions(state, user),
+        &auth::DashboardNoPermissionAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn revoke_user_session(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::UserSessionRevoke;
+    let session_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, user, _, _| session_core::revoke_user_session(state, user, session_id.clone()),
+        &auth::DashboardNoPermissionAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn revoke_all_user_sessions(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::UserSessionRevokeAll;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, user, _, _| session_core::revoke_all_user_sessions(state, user),
+        &auth::DashboardNoPermissionAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -11,6 +11,7 @@ use crate::{
     },
     types::api::webhook_events::{
         EventListConstraints, EventListRequestInternal, WebhookDeliveryAttemptListRequestInternal,
+        WebhookDeliveryHealthConstraints, WebhookDeliveryHealthRequestInternal,
         WebhookDeliveryRetryRequestInternal,
     },
 };
@@ -174,3 +175,40 @@ pub async fn retry_webhook_delivery_attempt(
     ))
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventDeliveryHealth))]
+pub async fn get_webhook_delivery_health(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<WebhookDeliveryHealthConstraints>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventDeliveryHealth;
+    let constraints = query.into_inner();
+
+    let request_internal = WebhookDeliveryHealthRequestInternal {
+        merchant_id: common_utils::id_type::MerchantId::default(),
+        constraints,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, auth: UserFromToken, mut request_internal, _| {
+            request_internal.merchant_id = auth.merchant_id;
+
+            webhook_events::get_delivery_health(
+                state,
+                request_internal.merchant_id,
+                auth.profile_id,
+                request_internal.constraints,
+            )
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileWebhookEventRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -2,18 +2,24 @@ pub mod api;
 pub mod authentication;
 pub mod authorization;
 pub mod connector_integration_interface;
+pub mod connector_rate_limiter;
+pub mod drain;
 #[cfg(feature = "email")]
 pub mod email;
 pub mod encryption;
+pub mod event_sink;
+pub mod idempotency;
 #[cfg(feature = "olap")]
 pub mod jwt;
 pub mod kafka;
+pub mod key_manager;
 pub mod logger;
 pub mod pm_auth;
 
 pub mod card_testing_guard;
 #[cfg(feature = "olap")]
 pub mod openidconnect;
+pub mod rate_limiter;
 
 use std::sync::Arc;
 
@@ -65,7 +71,11 @@ pub async fn get_store(
     #[cfg(feature = "olap")]
     // this would get abstracted, for all cases
     #[allow(clippy::useless_conversion)]
-    let conf = (master_config.into(), replica_config.into());
+    let conf = (
+        master_config.into(),
+        replica_config.into(),
+        config.read_replica.clone().into(),
+    );
 
     let store: RouterStore<StoreType> = if test_transaction {
         RouterStore::test_store(conf, tenant, &config.redis, master_enc_key).await?
@@ -46,20 +46,26 @@ pub use hyperswitch_interfaces::{
     },
 };
 use masking::{Maskable, PeekInterface, Secret};
-use router_env::{instrument, tracing, tracing_actix_web::RequestId, Tag};
+use router_env::{
+    instrument,
+    tracing::{self, Instrument},
+    tracing_actix_web::RequestId,
+    Tag,
+};
 use serde::Serialize;
 use serde_json::json;
 use tera::{Context, Error as TeraError, Tera};
 
 use super::{
-    authentication::AuthenticateAndFetch,
+    authentication::{self, AuthenticateAndFetch},
     connector_integration_interface::BoxedConnectorIntegrationInterface,
+    connector_rate_limiter, drain, rate_limiter,
 };
 use crate::{
     configs::Settings,
     consts,
     core::{
-        api_locking,
+        admin_audit, api_locking, connector_audit_log,
         errors::{self, CustomResult},
         payments, unified_connector_service,
     },
@@ -69,8 +75,8 @@ use crate::{
     },
     headers, logger,
     routes::{
-        app::{AppStateInfo, ReqState, SessionStateInfo},
-        metrics, AppState, SessionState,
+        app::{AppStateInfo, ClientDetails, ReqState, SessionStateInfo},
+        lock_utils, metrics, AppState, SessionState,
     },
     services::{
         connector_integration_interface::RouterDataConversion,
@@ -209,6 +215,48 @@ where
     Ok(())
 }
 
+/// Records the outcome and the latency of a connector call, labelled by the connector, the flow,
+/// the merchant and the merchant connector account. An error response from the connector is
+/// recorded as a `failure`, and a failure to receive a response as an `error`.
+fn record_connector_response_metrics<T, Req, Resp>(
+    router_data: &types::RouterData<T, Req, Resp>,
+    flow_name: &str,
+    response: &CustomResult<Result<types::Response, types::Response>, errors::ApiClientError>,
+    connector_latency: Duration,
+) {
+    let outcome = match response {
+        Ok(Ok(_)) => "success",
+        Ok(Err(_)) => "failure",
+        Err(_) => "error",
+    };
+    let attributes = router_env::metric_attributes!(
+        ("connector", router_data.connector.clone()),
+        ("flow", flow_name.to_string()),
+        ("outcome", outcome),
+        (
+            "merchant_id",
+            router_env::metrics::bounded_attribute_value(
+                "merchant_id",
+                router_data.merchant_id.get_string_repr(),
+            )
+        ),
+        (
+            "merchant_connector_id",
+            router_env::metrics::bounded_attribute_value(
+                "merchant_connector_id",
+                router_data
+                    .merchant_connector_id
+                    .as_ref()
+                    .map(|merchant_connector_id| merchant_connector_id.get_string_repr())
+                    .unwrap_or_default(),
+            )
+        ),
+    );
+
+    metrics::CONNECTOR_RESPONSE_COUNT.add(1, attributes);
+    metrics::CONNECTOR_REQUEST_TIME.record(connector_latency.as_secs_f64(), attributes);
+}
+
 /// Handle the flow by interacting with connector module
 /// `connector_request` is applicable only in case if the `CallConnectorAction` is `Trigger`
 /// In other cases, It will be created if required, even if it is not passed
@@ -277,17 +325,15 @@ where
             Ok(router_data)
         }
         payments::CallConnectorAction::Trigger => {
+            let flow_name = std::any::type_name::<T>()
+                .split("::")
+                .last()
+                .unwrap_or_default();
             metrics::CONNECTOR_CALL_COUNT.add(
                 1,
                 router_env::metric_attributes!(
                     ("connector", req.connector.to_string()),
-                    (
-                        "flow",
-                        std::any::type_name::<T>()
-                            .split("::")
-                            .last()
-                            .unwrap_or_default()
-                    ),
+                    ("flow", flow_name),
                 ),
             );
 
@@ -328,11 +374,54 @@ where
                     };
                     let request_url = request.url.clone();
                     let request_method = request.method;
+                    let concurrency_slot = match connector_rate_limiter::acquire_connector_permit(
+                        state,
+                        req.merchant_connector_id.as_ref(),
+                        &req.connector,
+                    )
+                    .await
+                    {
+                        connector_rate_limiter::ConnectorPermit::Granted(concurrency_slot) => {
+                            concurrency_slot
+                        }
+                        // The request is failed without being sent, so it can be retried safely
+                        connector_rate_limiter::ConnectorPermit::Denied => {
+                            router_data.response = Err(ErrorResponse {
+                                code: consts::CONNECTOR_RATE_LIMITED_ERROR_CODE.to_string(),
+                                message: consts::CONNECTOR_RATE_LIMITED_ERROR_MESSAGE.to_string(),
+                                reason: Some(
+                                    consts::CONNECTOR_RATE_LIMITED_ERROR_MESSAGE.to_string(),
+                                ),
+                                status_code: 429,
+                                attempt_status: None,
+                                connector_transaction_id: None,
+                                network_advice_code: None,
+                                network_decline_code: None,
+                                network_error_message: None,
+                                connector_metadata: None,
+                            });
+                            return Ok(router_data);
+                        }
+                    };
                     let current_time = Instant::now();
                     let response =
                         call_connector_api(state, request, "execute_connector_processing_step")
+                            .instrument(tracing::info_span!(
+                                "connector_call",
+                                connector = %req.connector,
+                                flow = flow_name,
+                                merchant_id = %req.merchant_id.get_string_repr(),
+                                payment_id = %req.payment_id,
+                                attempt_id = %req.attempt_id,
+                            ))
+                            .await;
+                    if let Some(concurrency_slot) = concurrency_slot {
+                        connector_rate_limiter::release_concurrency_slot(state, concurrency_slot)
                             .await;
-                    let external_latency = current_time.elapsed().as_millis();
+                    }
+                    let connector_latency = current_time.elapsed();
+                    let external_latency = connector_latency.as_millis();
+                    record_connector_response_metrics(req, flow_name, &response, connector_latency);
                     logger::info!(raw_connector_request=?masked_request_body);
                     let status_code = response
                         .as_ref()
@@ -381,6 +470,11 @@ where
                                     match handle_response_result {
                                         Ok(mut data) => {
                                             state.event_handler().log_event(&connector_event);
+                                            connector_audit_log::capture_connector_audit_log(
+                                                state,
+                                                req,
+                                                &connector_event,
+                                            );
                                             data.connector_http_status_code =
                                                 connector_http_status_code;
                                             // Add up multiple external latencies in case of multiple external calls within the same request.
@@ -404,6 +498,11 @@ where
                                                 .set_error(json!({"error": err.to_string()}));
 
                                             state.event_handler().log_event(&connector_event);
+                                            connector_audit_log::capture_connector_audit_log(
+                                                state,
+                                                req,
+                                                &connector_event,
+                                            );
                                             Err(err)
                                         }
                                     }?
@@ -437,6 +536,11 @@ where
                                                     Some(&mut connector_event),
                                                 )?;
                                             state.event_handler().log_event(&connector_event);
+                                            connector_audit_log::capture_connector_audit_log(
+                                                state,
+                                                req,
+                                                &connector_event,
+                                            );
                                             error_res
                                         }
                                         _ => {
@@ -449,6 +553,11 @@ where
                                                 router_data.status = status;
                                             };
                                             state.event_handler().log_event(&connector_event);
+                                            connector_audit_log::capture_connector_audit_log(
+                                                state,
+                                                req,
+                                                &connector_event,
+                                            );
                                             error_res
                                         }
                                     };
@@ -463,6 +572,11 @@ where
                         Err(error) => {
                             connector_event.set_error(json!({"error": error.to_string()}));
                             state.event_handler().log_event(&connector_event);
+                            connector_audit_log::capture_connector_audit_log(
+                                state,
+                                req,
+                                &connector_event,
+                            );
                             if error.current_context().is_upstream_timeout() {
                                 let error_response = ErrorResponse {
                                     code: consts::REQUEST_TIMEOUT_ERROR_CODE.to_string(),
@@ -498,7 +612,7 @@ where
     }
 }
 
-#[instrument(skip_all)]
+#[instrument(skip_all, fields(flow_name = flow_name))]
 pub async fn call_connector_api(
     state: &SessionState,
     request: Request,
@@ -629,7 +743,7 @@ pub enum AuthFlow {
     fields(merchant_id)
 )]
 pub async fn server_wrap_util<'a, 'b, U, T, Q, F, Fut, E, OErr>(
-    flow: &'a impl router_env::types::FlowMetric,
+    flow: &'a (impl router_env::types::FlowMetric + lock_utils::GetApiIdentifier),
     state: web::Data<AppState>,
     incoming_request_header: &HeaderMap,
     request: &'a HttpRequest,
@@ -653,6 +767,14 @@ where
         .attach_printable("Unable to extract request id from request")
         .change_context(errors::ApiErrorResponse::InternalServerError.switch())?;
 
+    let _in_flight_flow = drain::track_flow();
+    if flow.is_payment_creation() && drain::is_draining() {
+        return Err(report!(errors::ApiErrorResponse::ServiceDraining {
+            retry_after_secs: state.conf.drain.retry_after_in_secs,
+        }
+        .switch()));
+    }
+
     let mut app_state = state.get_ref().clone();
 
     let start_instant = Instant::now();
@@ -702,6 +824,8 @@ where
             .switch()
         })?;
     session_state.add_request_id(request_id);
+    session_state.client_details =
+        ClientDetails::from_request(request, state.conf.api_keys.get_inner().trusted_proxy_count);
     let mut request_state = session_state.get_req_state();
 
     request_state.event_context.record_info(request_id);
@@ -720,7 +844,21 @@ where
         .await
         .switch()?;
 
+    authentication::check_api_key_scopes(
+        &auth_type,
+        request.method(),
+        &request
+            .match_pattern()
+            .unwrap_or_else(|| request.path().to_owned()),
+    )
+    .switch()?;
+
+    rate_limiter::check_rate_limits(&session_state, &auth_type, flow.get_api_identifier())
+        .await
+        .switch()?;
+
     request_state.event_context.record_info(auth_type.clone());
+    request_state.auth_type = Some(auth_type.clone());
 
     let merchant_id = auth_type
         .get_merchant_id()
@@ -791,6 +929,17 @@ where
         }
     };
 
+    admin_audit::record_admin_audit_event(
+        &session_state,
+        flow,
+        request,
+        &auth_type,
+        Some(request_id.as_hyphenated().to_string()),
+        status_code,
+        &serialized_request,
+        serialized_response.as_ref(),
+    );
+
     let infra = extract_mapped_fields(
         &serialized_request,
         state.enhancement.as_ref(),
@@ -825,7 +974,7 @@ where
     fields(request_method, request_url_path, status_code)
 )]
 pub async fn server_wrap<'a, T, U, Q, F, Fut, E>(
-    flow: impl router_env::types::FlowMetric,
+    flow: impl router_env::types::FlowMetric + lock_utils::GetApiIdentifier,
     state: web::Data<AppState>,
     request: &'a HttpRequest,
     payload: T,
@@ -52,6 +52,10 @@ use crate::{
 pub mod blacklist;
 pub mod cookies;
 pub mod decision;
+pub mod ip_allowlist;
+#[cfg(feature = "v1")]
+pub mod request_signing;
+pub mod user_sessions;
 
 #[cfg(feature = "partial-auth")]
 mod detached;
@@ -106,16 +110,23 @@ pub struct UserFromTokenWithRoleInfo {
     pub role_info: authorization::roles::RoleInfo,
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, strum::IntoStaticStr)]
 #[serde(
     tag = "api_auth_type",
     content = "authentication_data",
     rename_all = "snake_case"
 )]
+#[strum(serialize_all = "snake_case")]
 pub enum AuthenticationType {
     ApiKey {
         merchant_id: id_type::MerchantId,
         key_id: id_type::ApiKeyId,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        scopes: Option<Vec<common_enums::ApiKeyScope>>,
+    },
+    SignedRequest {
+        merchant_id: id_type::MerchantId,
+        key_id: String,
     },
     AdminApiKey,
     AdminApiAuthWithMerchantId {
@@ -175,7 +186,9 @@ impl AuthenticationType {
             Self::ApiKey {
                 merchant_id,
                 key_id: _,
+                scopes: _,
             }
+            | Self::SignedRequest { merchant_id, .. }
             | Self::AdminApiAuthWithMerchantId { merchant_id }
             | Self::MerchantId { merchant_id }
             | Self::PublishableKey { merchant_id }
@@ -193,6 +206,56 @@ impl AuthenticationType {
             | Self::NoAuth => None,
         }
     }
+
+    /// Identifies the merchant or the user on whose behalf the request was made
+    pub fn get_created_by(&self) -> Option<common_utils::types::CreatedBy> {
+        match self {
+            Self::ApiKey { merchant_id, .. }
+            | Self::SignedRequest { merchant_id, .. }
+            | Self::AdminApiAuthWithMerchantId { merchant_id } => {
+                Some(common_utils::types::CreatedBy::Api {
+                    merchant_id: merchant_id.get_string_repr().to_owned(),
+                })
+            }
+            Self::OrganizationJwt { user_id, .. }
+            | Self::MerchantJwtWithProfileId { user_id, .. }
+            | Self::UserJwt { user_id }
+            | Self::SinglePurposeJwt { user_id, .. }
+            | Self::SinglePurposeOrLoginJwt { user_id, .. } => {
+                Some(common_utils::types::CreatedBy::Jwt {
+                    user_id: user_id.to_owned(),
+                })
+            }
+            Self::MerchantJwt { user_id, .. } => user_id
+                .to_owned()
+                .map(|user_id| common_utils::types::CreatedBy::Jwt { user_id }),
+            Self::AdminApiKey
+            | Self::MerchantId { .. }
+            | Self::PublishableKey { .. }
+            | Self::WebhookAuth { .. }
+            | Self::NoAuth => None,
+        }
+    }
+
+    /// Identifies the API key or the user that made the request
+    pub fn get_actor_id(&self) -> Option<String> {
+        match self {
+            Self::ApiKey { key_id, .. } => Some(key_id.get_string_repr().to_owned()),
+            Self::SignedRequest { key_id, .. } => Some(key_id.to_owned()),
+            Self::OrganizationJwt { user_id, .. }
+            | Self::MerchantJwtWithProfileId { user_id, .. }
+            | Self::UserJwt { user_id }
+            | Self::SinglePurposeJwt { user_id, .. }
+            | Self::SinglePurposeOrLoginJwt { user_id, .. } => Some(user_id.to_owned()),
+            Self::MerchantJwt { user_id, .. } => user_id.to_owned(),
+            Self::AdminApiKey
+            | Self::AdminApiAuthWithMerchantId { .. }
+            | Self::MerchantId { .. }
+            | Self::PublishableKey { .. }
+            | Self::WebhookAuth { .. }
+            | Self::NoAuth => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, serde::Deserialize, strum::Display)]
@@ -256,10 +319,13 @@ pub struct AuthToken {
     pub org_id: id_type::OrganizationId,
     pub profile_id: id_type::ProfileId,
     pub tenant_id: Option<id_type::TenantId>,
+    /// Absent in the tokens issued before sessions were tracked
+    pub session_id: Option<String>,
 }
 
 #[cfg(feature = "olap")]
 impl AuthToken {
+    #[allow(clippy::too_many_arguments)]
     pub async fn new_token(
         user_id: String,
         merchant_id: id_type::MerchantId,
@@ -268,6 +334,7 @@ impl AuthToken {
         org_id: id_type::OrganizationId,
         profile_id: id_type::ProfileId,
         tenant_id: Option<id_type::TenantId>,
+        session_id: String,
     ) -> UserResult<String> {
         let exp_duration = std::time::Duration::from_secs(consts::JWT_TOKEN_TIME_IN_SECS);
         let exp = jwt::generate_exp(exp_duration)?.as_secs();
@@ -279,6 +346,7 @@ impl AuthToken {
             org_id,
             profile_id,
             tenant_id,
+            session_id: Some(session_id),
         };
         jwt::generate_jwt(&token_payload, settings).await
     }
@@ -292,6 +360,7 @@ pub struct UserFromToken {
     pub org_id: id_type::OrganizationId,
     pub profile_id: id_type::ProfileId,
     pub tenant_id: Option<id_type::TenantId>,
+    pub session_id: Option<String>,
 }
 
 pub struct UserIdFromAuth {
@@ -480,6 +549,8 @@ where
             .await
             .to_not_found_response(errors::ApiErrorResponse::Unauthorized)?;
 
+        ip_allowlist::check_ip_allowlists(state, request_headers, &stored_api_key, &merchant)?;
+
         // Get connected merchant account if API call is done by Platform merchant account on behalf of connected merchant account
         let (merchant, platform_merchant_account) = if state.conf().platform.enabled {
             get_platform_merchant_account(state, request_headers, merchant).await?
@@ -525,6 +596,7 @@ where
             auth.clone(),
             AuthenticationType::ApiKey {
                 merchant_id: auth.merchant_account.get_id().clone(),
+                scopes: stored_api_key.get_scopes(),
                 key_id: stored_api_key.key_id,
             },
         ))
@@ -542,6 +614,11 @@ where
         request_headers: &HeaderMap,
         state: &A,
     ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
+        if request_signing::is_signed_request(request_headers) {
+            return request_signing::authenticate_signed_request(self, request_headers, state)
+                .await;
+        }
+
         let api_key = get_api_key(request_headers)
             .change_context(errors::ApiErrorResponse::Unauthorized)?
             .trim();
@@ -607,6 +684,8 @@ where
             .await
             .to_not_found_response(errors::ApiErrorResponse::Unauthorized)?;
 
+        ip_allowlist::check_ip_allowlists(state, request_headers, &stored_api_key, &merchant)?;
+
         let (merchant, platform_merchant_account) = if state.conf().platform.enabled {
             get_platform_merchant_account(state, request_headers, merchant).await?
         } else {
@@ -645,6 +724,7 @@ where
             auth.clone(),
             AuthenticationType::ApiKey {
                 merchant_id: auth.merchant_account.get_id().clone(),
+                scopes: stored_api_key.get_scopes(),
                 key_id: stored_api_key.key_id,
             },
         ))
@@ -693,6 +773,62 @@ where
     }
 }
 
+/// Authentication for the SCIM client of an identity provider, which sends an API key of the
+/// merchant account as a bearer token. Only API keys explicitly scoped to users are accepted.
+#[derive(Debug)]
+pub struct ScimAuth;
+
+#[cfg(feature = "v1")]
+#[async_trait]
+impl<A> AuthenticateAndFetch<AuthenticationData, A> for ScimAuth
+where
+    A: SessionStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
+        let api_key = HeaderMapStruct::new(request_headers)
+            .get_auth_string_from_header()?
+            .strip_prefix("Bearer ")
+            .ok_or(errors::ApiErrorResponse::Unauthorized)
+            .attach_printable("SCIM request without a bearer token")?;
+
+        let mut api_key_headers = request_headers.clone();
+        api_key_headers.insert(
+            actix_web::http::header::HeaderName::from_static("api-key"),
+            api_key
+                .parse()
+                .change_context(errors::ApiErrorResponse::Unauthorized)
+                .attach_printable("Invalid bearer token in SCIM request")?,
+        );
+
+        let api_auth = ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        };
+        let (auth_data, auth_type) = api_auth
+            .authenticate_and_fetch(&api_key_headers, state)
+            .await?;
+
+        if !matches!(
+            auth_type,
+            AuthenticationType::ApiKey {
+                scopes: Some(_),
+                ..
+            }
+        ) {
+            return Err(report!(errors::ApiErrorResponse::AccessForbidden {
+                resource: "scim".to_string(),
+            }))
+            .attach_printable("SCIM requests require an API key scoped to users");
+        }
+
+        Ok((auth_data, auth_type))
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct PlatformOrgAdminAuth {
     pub is_admin_auth_allowed: bool,
@@ -783,6 +919,13 @@ where
             .to_not_found_response(errors::ApiErrorResponse::Unauthorized)
             .attach_printable("Merchant account not found")?;
 
+        ip_allowlist::check_ip_allowlists(
+            state,
+            request_headers,
+            &stored_api_key,
+            &merchant_account,
+        )?;
+
         if !(state.conf().platform.enabled && merchant_account.is_platform_account()) {
             return Err(report!(errors::ApiErrorResponse::Unauthorized)
                 .attach_printable("Platform authentication check failed"));
@@ -801,6 +944,7 @@ where
             }),
             AuthenticationType::ApiKey {
                 merchant_id: merchant_account.get_id().clone(),
+                scopes: stored_api_key.get_scopes(),
                 key_id: stored_api_key.key_id,
             },
         ))
@@ -872,6 +1016,13 @@ where
             .to_not_found_response(errors::ApiErrorResponse::Unauthorized)
             .attach_printable("Merchant account not found")?;
 
+        ip_allowlist::check_ip_allowlists(
+            state,
+            request_headers,
+            &stored_api_key,
+            &merchant_account,
+        )?;
+
         if !(state.conf().platform.enabled && merchant_account.is_platform_account()) {
             return Err(report!(errors::ApiErrorResponse::Unauthorized)
                 .attach_printable("Platform authentication check failed"));
@@ -895,6 +1046,7 @@ where
             auth.clone(),
             AuthenticationType::ApiKey {
                 merchant_id: auth.merchant_account.get_id().clone(),
+                scopes: stored_api_key.get_scopes(),
                 key_id: stored_api_key.key_id,
             },
         ))
@@ -1001,6 +1153,13 @@ where
         let (_, platform_merchant) =
             Self::fetch_key_store_and_account(&stored_api_key.merchant_id, state).await?;
 
+        ip_allowlist::check_ip_allowlists(
+            state,
+            request_headers,
+            &stored_api_key,
+            &platform_merchant,
+        )?;
+
         if !(state.conf().platform.enabled && platform_merchant.is_platform_account()) {
             return Err(report!(errors::ApiErrorResponse::Unauthorized))
                 .attach_printable("Platform authentication check failed");
@@ -1025,6 +1184,7 @@ where
             auth.clone(),
             AuthenticationType::ApiKey {
                 merchant_id: platform_merchant.get_id().clone(),
+                scopes: stored_api_key.get_scopes(),
                 key_id: stored_api_key.key_id,
             },
         ))
@@ -1099,6 +1259,26 @@ where
                     merchant_id: Some(merchant_id),
                     key_id: Some(key_id),
                 } => {
+                    // The detached payload only vouches for the key id, the scopes and the IP
+                    // allowlist of the key are enforced from the stored key as on the regular path
+                    let stored_api_key = state
+                        .store()
+                        .find_api_key_by_merchant_id_key_id_optional(&merchant_id, &key_id)
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to retrieve API key")?
+                        .ok_or(report!(errors::ApiErrorResponse::Unauthorized))
+                        .attach_printable("API key not found for the detached payload")?;
+
+                    if stored_api_key
+                        .expires_at
+                        .map(|expires_at| expires_at < date_time::now())
+                        .unwrap_or(false)
+                    {
+                        return Err(report!(errors::ApiErrorResponse::Unauthorized))
+                            .attach_printable("API key has expired");
+                    }
+
                     let auth = construct_authentication_data(
                         state,
                         &merchant_id,
@@ -1106,10 +1286,21 @@ where
                         profile_id,
                     )
                     .await?;
+
+                    ip_allowlist::check_ip_allowlists(
+                        state,
+                        request_headers,
+                        &stored_api_key,
+                        auth.platform_merchant_account
+                            .as_ref()
+                            .unwrap_or(&auth.merchant_account),
+                    )?;
+
                     Ok((
                         auth.clone(),
                         AuthenticationType::ApiKey {
                             merchant_id: auth.merchant_account.get_id().clone(),
+                            scopes: stored_api_key.get_scopes(),
                             key_id,
                         },
                     ))
@@ -1743,6 +1934,8 @@ where
             .await
             .to_not_found_response(errors::ApiErrorResponse::Unauthorized)?;
 
+        ip_allowlist::check_ip_allowlists(state, request_headers, &stored_api_key, &merchant)?;
+
         if let Some(ref organization_id) = self.organization_id {
             if organization_id != merchant.get_org_id() {
                 return Err(
@@ -1762,6 +1955,7 @@ where
                     organization_id: merchant.organization_id,
                 }),
                 AuthenticationType::ApiKey {
+                    scopes: stored_api_key.get_scopes(),
                     merchant_id: stored_api_key.merchant_id,
                     key_id: stored_api_key.key_id,
                 },
@@ -2514,6 +2708,8 @@ where
             .await
             .to_not_found_response(errors::ApiErrorResponse::Unauthorized)?;
 
+        ip_allowlist::check_ip_allowlists(state, request_headers, &stored_api_key, &merchant)?;
+
         // Get connected merchant account if API call is done by Platform merchant account on behalf of connected merchant account
         let (merchant, platform_merchant_account) = if state.conf().platform.enabled {
             get_platform_merchant_account(state, request_headers, merchant).await?
@@ -2559,6 +2755,7 @@ where
             auth.clone(),
             AuthenticationType::ApiKey {
                 merchant_id: auth.merchant_account.get_id().clone(),
+                scopes: stored_api_key.get_scopes(),
                 key_id: stored_api_key.key_id,
             },
         ))
@@ -2880,6 +3077,7 @@ where
                 role_id: payload.role_id,
                 profile_id: payload.profile_id,
                 tenant_id: payload.tenant_id,
+                session_id: payload.session_id,
             },
             AuthenticationType::MerchantJwt {
                 merchant_id: payload.merchant_id,
@@ -4056,6 +4254,7 @@ where
                 role_id: payload.role_id,
                 profile_id: payload.profile_id,
                 tenant_id: payload.tenant_id,
+                session_id: payload.session_id,
             },
             AuthenticationType::MerchantJwt {
                 merchant_id: payload.merchant_id,
@@ -4440,6 +4639,80 @@ where
     default_auth
 }
 
+/// Checks whether the scopes of the API key used to authenticate the request permit accessing
+/// the requested route. API keys created without any scopes retain access to every route.
+pub fn check_api_key_scopes(
+    auth_type: &AuthenticationType,
+    method: &actix_web::http::Method,
+    path: &str,
+) -> RouterResult<()> {
+    let AuthenticationType::ApiKey {
+        scopes: Some(scopes),
+        ..
+    } = auth_type
+    else {
+        return Ok(());
+    };
+
+    let is_permitted =
+        get_required_api_key_scope(method, path).is_some_and(|(resource, permission_scope)| {
+            scopes
+                .iter()
+                .any(|scope| scope.permits(resource, permission_scope))
+        });
+
+    fp_utils::when(!is_permitted, || {
+        Err(report!(errors::ApiErrorResponse::AccessForbidden {
+            resource: path.to_string(),
+        }))
+        .attach_printable("API key scopes do not permit accessing the requested route")
+    })
+}
+
+/// Resolves the resource and the permission scope required for a route. Routes that cannot be
+/// mapped to a resource cannot be accessed by scoped API keys.
+fn get_required_api_key_scope(
+    method: &actix_web::http::Method,
+    path: &str,
+) -> Option<(common_enums::Resource, common_enums::PermissionScope)> {
+    let segments: Vec<&str> = path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .skip_while(|segment| *segment == "v2")
+        .collect();
+
+    let resource = match segments.first().copied()? {
+        "payments" => common_enums::Resource::Payment,
+        "refunds" => common_enums::Resource::Refund,
+        "disputes" => common_enums::Resource::Dispute,
+        "mandates" => common_enums::Resource::Mandate,
+        "customers" | "payment_methods" => common_enums::Resource::Customer,
+        "payouts" => common_enums::Resource::Payout,
+        "routing" => common_enums::Resource::Routing,
+        "api_keys" => common_enums::Resource::ApiKey,
+        "scim" => common_enums::Resource::User,
+        "connector-accounts" => common_enums::Resource::Connector,
+        "account" if segments.contains(&"connectors") => common_enums::Resource::Connector,
+        _ => return None,
+    };
+
+    let is_read_only = matches!(
+        *method,
+        actix_web::http::Method::GET | actix_web::http::Method::HEAD
+    ) || matches!(
+        segments.last().copied(),
+        Some("list" | "filter" | "aggregate")
+    );
+
+    let permission_scope = if is_read_only {
+        common_enums::PermissionScope::Read
+    } else {
+        common_enums::PermissionScope::Write
+    };
+
+    Some((resource, permission_scope))
+}
+
 #[cfg(feature = "recon")]
 #[async_trait]
 impl<A> AuthenticateAndFetch<AuthenticationDataWithUser, A> for JWTAuth
@@ -4629,6 +4902,7 @@ where
             role_id: payload.role_id,
             profile_id: payload.profile_id,
             tenant_id: payload.tenant_id,
+            session_id: payload.session_id,
         };
 
         Ok((
@@ -4728,3 +5002,88 @@ impl ExternalToken {
         )?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use actix_web::http::Method;
+
+    use super::*;
+
+    #[test]
+    fn test_get_required_api_key_scope() {
+        use common_enums::{PermissionScope, Resource};
+
+        let test_cases = [
+            (
+                Method::POST,
+                "/payments",
+                Some((Resource::Payment, PermissionScope::Write)),
+            ),
+            (
+                Method::GET,
+                "/payments/{payment_id}",
+                Some((Resource::Payment, PermissionScope::Read)),
+            ),
+            (
+                Method::POST,
+                "/payments/list",
+                Some((Resource::Payment, PermissionScope::Read)),
+            ),
+            (
+                Method::POST,
+                "/v2/refunds",
+                Some((Resource::Refund, PermissionScope::Write)),
+            ),
+            (
+                Method::GET,
+                "/payment_methods/{payment_method_id}",
+                Some((Resource::Customer, PermissionScope::Read)),
+            ),
+            (
+                Method::POST,
+                "/api_keys/{merchant_id}",
+                Some((Resource::ApiKey, PermissionScope::Write)),
+            ),
+            (
+                Method::POST,
+                "/account/{account_id}/connectors",
+                Some((Resource::Connector, PermissionScope::Write)),
+            ),
+            // Only the first path segment decides the resource
+            (
+                Method::POST,
+                "/refunds/payments",
+                Some((Resource::Refund, PermissionScope::Write)),
+            ),
+            (Method::POST, "/account/{account_id}", None),
+            (Method::GET, "/health", None),
+            (Method::GET, "/", None),
+        ];
+
+        for (method, path, expected) in test_cases {
+            assert_eq!(
+                get_required_api_key_scope(&method, path),
+                expected,
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_check_api_key_scopes() {
+        let get_auth_type = |scopes| AuthenticationType::ApiKey {
+            merchant_id: id_type::MerchantId::get_merchant_id_not_found(),
+            key_id: id_type::ApiKeyId::generate_key_id("key"),
+            scopes,
+        };
+
+        let unscoped = get_auth_type(None);
+        assert!(check_api_key_scopes(&unscoped, &Method::POST, "/account/{account_id}").is_ok());
+
+        let scoped = get_auth_type(Some(vec![common_enums::ApiKeyScope::PaymentsRead]));
+        assert!(check_api_key_scopes(&scoped, &Method::GET, "/payments/{payment_id}").is_ok());
+        assert!(check_api_key_scopes(&scoped, &Method::POST, "/payments").is_err());
+        assert!(check_api_key_scopes(&scoped, &Method::GET, "/refunds/{refund_id}").is_err());
+        assert!(check_api_key_scopes(&scoped, &Method::GET, "/health").is_err());
+    }
+}
@@ -5,7 +5,7 @@ use common_utils::date_time;
 use error_stack::ResultExt;
 use redis_interface::RedisConnectionPool;
 
-use super::AuthToken;
+use super::{user_sessions, AuthToken};
 #[cfg(feature = "olap")]
 use super::{SinglePurposeOrLoginToken, SinglePurposeToken};
 #[cfg(feature = "email")]
@@ -74,14 +74,23 @@ pub async fn check_user_in_blacklist<A: SessionStateInfo>(
     user_id: &str,
     token_expiry: u64,
 ) -> RouterResult<bool> {
-    let token = format!("{USER_BLACKLIST_PREFIX}{user_id}");
     let token_issued_at = expiry_to_i64(token_expiry - JWT_TOKEN_TIME_IN_SECS)?;
+    get_user_blacklisted_at(state, user_id)
+        .await
+        .map(|timestamp| timestamp > Some(token_issued_at))
+}
+
+/// Returns the time at which all the tokens of the user issued before it were blacklisted
+pub async fn get_user_blacklisted_at<A: SessionStateInfo>(
+    state: &A,
+    user_id: &str,
+) -> RouterResult<Option<i64>> {
+    let token = format!("{USER_BLACKLIST_PREFIX}{user_id}");
     let redis_conn = get_redis_connection_for_global_tenant(state)?;
     redis_conn
         .get_key::<Option<i64>>(&token.as_str().into())
         .await
         .change_context(ApiErrorResponse::InternalServerError)
-        .map(|timestamp| timestamp > Some(token_issued_at))
 }
 
 pub async fn check_role_in_blacklist<A: SessionStateInfo>(
@@ -128,7 +137,7 @@ pub async fn check_email_token_in_blacklist(state: &SessionState, token: &str) -
     Ok(())
 }
 
-fn get_redis_connection_for_global_tenant<A: SessionStateInfo>(
+pub(super) fn get_redis_connection_for_global_tenant<A: SessionStateInfo>(
     state: &A,
 ) -> RouterResult<Arc<RedisConnectionPool>> {
     state
@@ -138,7 +147,7 @@ fn get_redis_connection_for_global_tenant<A: SessionStateInfo>(
         .attach_printable("Failed to get redis connection")
 }
 
-fn expiry_to_i64(expiry: u64) -> RouterResult<i64> {
+pub(super) fn expiry_to_i64(expiry: u64) -> RouterResult<i64> {
     i64::try_from(expiry).change_context(ApiErrorResponse::InternalServerError)
 }
 
@@ -157,7 +166,9 @@ impl BlackList for AuthToken {
     {
         Ok(
             check_user_in_blacklist(state, &self.user_id, self.exp).await?
-                || check_role_in_blacklist(state, &self.role_id, self.exp).await?,
+                || check_role_in_blacklist(state, &self.role_id, self.exp).await?
+                || user_sessions::check_user_session_revoked(state, self.session_id.as_deref())
+                    .await?,
         )
     }
 }
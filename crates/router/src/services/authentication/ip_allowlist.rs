@@ -0,0 +1,207 @@
+use std::net::IpAddr;
+
+use actix_web::http::header::HeaderMap;
+use error_stack::{report, ResultExt};
+use ipnet::IpNet;
+use serde::Serialize;
+
+use crate::{
+    core::errors::{ApiErrorResponse, RouterResult},
+    events::{
+        self,
+        audit_events::{AuditEvent, AuditEventType},
+    },
+    headers,
+    routes::app::SessionStateInfo,
+    types::{domain, storage},
+};
+
+/// The entity whose IP allowlist rejected a request.
+#[derive(Clone, Copy, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IpAllowlistOwner {
+    ApiKey,
+    MerchantAccount,
+}
+
+/// Parses an IP address range in CIDR notation, a bare IP address being treated as a range
+/// containing only that address.
+pub fn parse_ip_range(ip_range: &str) -> Option<IpNet> {
+    ip_range
+        .parse::<IpNet>()
+        .ok()
+        .or_else(|| ip_range.parse::<IpAddr>().ok().map(IpNet::from))
+}
+
+/// Validates the IP address ranges of an allowlist being updated. An empty list removes the
+/// allowlist, permitting requests from any IP address.
+pub fn get_allowed_ip_ranges_update(
+    allowed_ip_ranges: Option<Vec<String>>,
+) -> RouterResult<Option<Option<Vec<String>>>> {
+    allowed_ip_ranges
+        .map(|allowed_ip_ranges| {
+            if let Some(invalid_ip_range) = allowed_ip_ranges
+                .iter()
+                .find(|ip_range| parse_ip_range(ip_range).is_none())
+            {
+                return Err(report!(ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "allowed_ip_ranges contains an invalid IP address range: {invalid_ip_range}"
+                    ),
+                }));
+            }
+
+            Ok((!allowed_ip_ranges.is_empty()).then_some(allowed_ip_ranges))
+        })
+        .transpose()
+}
+
+/// Obtains the IP address of the client from the `X-Forwarded-For` header. Since the entries
+/// preceding the ones appended by the trusted proxies can be forged by the client, the IP address
+/// is the entry appended by the outermost trusted proxy.
+pub fn get_client_ip_address(
+    request_headers: &HeaderMap,
+    trusted_proxy_count: usize,
+) -> Option<IpAddr> {
+    request_headers
+        .get(headers::X_FORWARDED_FOR)?
+        .to_str()
+        .ok()?
+        .rsplit(',')
+        .nth(trusted_proxy_count.saturating_sub(1))
+        .and_then(|ip_address| ip_address.trim().parse().ok())
+}
+
+/// Checks if the IP address lies in any of the IP address ranges.
+pub fn is_ip_address_allowed(ip_address: IpAddr, allowed_ip_ranges: &[String]) -> bool {
+    allowed_ip_ranges
+        .iter()
+        .filter_map(|ip_range| parse_ip_range(ip_range))
+        .any(|ip_range| ip_range.contains(&ip_address))
+}
+
+/// Checks the IP address of the request against the allowlists of the API key and of the merchant
+/// account it belongs to, emitting an audit event when the request is rejected. Requests whose IP
+/// address cannot be determined are rejected whenever an allowlist is configured.
+pub fn check_ip_allowlists<A: SessionStateInfo>(
+    state: &A,
+    request_headers: &HeaderMap,
+    api_key: &storage::ApiKey,
+    merchant_account: &domain::MerchantAccount,
+) -> RouterResult<()> {
+    let client_ip_address = get_client_ip_address(
+        request_headers,
+        state.conf().api_keys.get_inner().trusted_proxy_count,
+    );
+    let allowlists = [
+        (IpAllowlistOwner::ApiKey, api_key.get_allowed_ip_ranges()),
+        (
+            IpAllowlistOwner::MerchantAccount,
+            merchant_account.allowed_ip_ranges.clone(),
+        ),
+    ];
+
+    for (owner, allowed_ip_ranges) in allowlists {
+        let Some(allowed_ip_ranges) = allowed_ip_ranges else {
+            continue;
+        };
+
+        if client_ip_address
+            .is_some_and(|ip_address| is_ip_address_allowed(ip_address, &allowed_ip_ranges))
+        {
+            continue;
+        }
+
+        let ip_address = client_ip_address.map(|ip_address| ip_address.to_string());
+
+        let mut event_context = events::EventContext::new(state.event_handler());
+        if let Some(request_id) = state.get_request_id() {
+            event_context.record_info(("request_id".to_string(), request_id));
+        }
+        event_context.emit(AuditEvent::new(AuditEventType::IpAddressRejected {
+            merchant_id: api_key.merchant_id.clone(),
+            key_id: api_key.key_id.clone(),
+            ip_address: ip_address.clone(),
+            rejected_by: owner,
+        }));
+
+        let ip_address = ip_address.unwrap_or_else(|| "unknown".to_string());
+        let error = match owner {
+            IpAllowlistOwner::ApiKey => ApiErrorResponse::ApiKeyIpAddressNotAllowed { ip_address },
+            IpAllowlistOwner::MerchantAccount => {
+                ApiErrorResponse::MerchantIpAddressNotAllowed { ip_address }
+            }
+        };
+
+        return Err(report!(error)).attach_printable_lazy(|| {
+            format!(
+                "Request rejected by the IP allowlist of the {owner:?} for API key {:?}",
+                api_key.key_id
+            )
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use actix_web::http::header::{HeaderName, HeaderValue};
+
+    use super::*;
+
+    fn forwarded_for(value: &'static str) -> HeaderMap {
+        let mut request_headers = HeaderMap::new();
+        request_headers.insert(
+            HeaderName::from_static("x-forwarded-for"),
+            HeaderValue::from_static(value),
+        );
+        request_headers
+    }
+
+    #[test]
+    fn test_client_ip_address_ignores_forged_entries() {
+        let request_headers = forwarded_for("1.1.1.1, 203.0.113.7, 10.0.0.2");
+
+        assert_eq!(
+            get_client_ip_address(&request_headers, 1),
+            Some("10.0.0.2".parse().unwrap())
+        );
+        assert_eq!(
+            get_client_ip_address(&request_headers, 2),
+            Some("203.0.113.7".parse().unwrap())
+        );
+        assert_eq!(get_client_ip_address(&request_headers, 4), None);
+        assert_eq!(get_client_ip_address(&HeaderMap::new(), 1), None);
+    }
+
+    #[test]
+    fn test_ip_address_allowed() {
+        let allowed_ip_ranges = vec!["203.0.113.0/24".to_string(), "2001:db8::1".to_string()];
+
+        assert!(is_ip_address_allowed(
+            "203.0.113.7".parse().unwrap(),
+            &allowed_ip_ranges
+        ));
+        assert!(is_ip_address_allowed(
+            "2001:db8::1".parse().unwrap(),
+            &allowed_ip_ranges
+        ));
+        assert!(!is_ip_address_allowed(
+            "198.51.100.1".parse().unwrap(),
+            &allowed_ip_ranges
+        ));
+    }
+
+    #[test]
+    fn test_allowed_ip_ranges_update() {
+        assert_eq!(get_allowed_ip_ranges_update(None).unwrap(), None);
+        assert_eq!(
+            get_allowed_ip_ranges_update(Some(vec![])).unwrap(),
+            Some(None)
+        );
+        assert!(get_allowed_ip_ranges_update(Some(vec!["10.0.0.0/33".to_string()])).is_err());
+    }
+}
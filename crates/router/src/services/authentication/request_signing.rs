@@ -0,0 +1,257 @@
+use std::str::FromStr;
+
+use actix_web::http::header::HeaderMap;
+use common_utils::{
+    crypto::{self, VerifySignature},
+    date_time, id_type, type_name,
+    types::keymanager::Identifier,
+};
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+use redis_interface::SetnxReply;
+
+use super::{
+    get_header_value_by_key, get_platform_merchant_account, ip_allowlist, ApiKeyAuth,
+    AuthenticationData, AuthenticationType,
+};
+use crate::{
+    consts,
+    core::errors::{self, ApiErrorResponse, RouterResult, StorageErrorExt},
+    headers,
+    routes::app::SessionStateInfo,
+    types::{domain, storage},
+};
+
+/// Requests carrying a signature are authenticated with the signing key they were signed with,
+/// instead of an API key.
+pub fn is_signed_request(request_headers: &HeaderMap) -> bool {
+    request_headers.contains_key(headers::X_SIGNATURE)
+}
+
+/// Authenticates a request signed with the signing secret of a merchant. The signature is the hex
+/// encoded HMAC-SHA256 of the payload built by `middleware::AddSignaturePayloadHeader`, which
+/// covers the timestamp, the nonce, the method, the path and the body of the request. Requests
+/// whose timestamp is outside the configured tolerance, or whose nonce has already been used with
+/// the signing key, are rejected.
+pub async fn authenticate_signed_request<A>(
+    auth: &ApiKeyAuth,
+    request_headers: &HeaderMap,
+    state: &A,
+) -> RouterResult<(AuthenticationData, AuthenticationType)>
+where
+    A: SessionStateInfo + Sync,
+{
+    let get_required_header = |key: &str| {
+        get_header_value_by_key(key.to_string(), request_headers)?
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .ok_or(report!(ApiErrorResponse::Unauthorized))
+            .attach_printable_lazy(|| format!("Missing header {key} in signed request"))
+    };
+    let key_id = get_required_header(headers::X_SIGNATURE_KEY_ID)?;
+    let timestamp = get_required_header(headers::X_SIGNATURE_TIMESTAMP)?;
+    let nonce = get_required_header(headers::X_SIGNATURE_NONCE)?;
+    let signature = get_required_header(headers::X_SIGNATURE)?;
+    let signature_payload = get_required_header(headers::X_SIGNATURE_PAYLOAD)?;
+
+    let timestamp = timestamp
+        .parse::<i64>()
+        .change_context(ApiErrorResponse::Unauthorized)
+        .attach_printable("Signed request timestamp is not a unix timestamp")?;
+    let tolerance = i64::from(
+        state
+            .conf()
+            .api_keys
+            .get_inner()
+            .signed_request_tolerance_in_secs,
+    );
+    if (date_time::now_unix_timestamp() - timestamp).abs() > tolerance {
+        return Err(report!(ApiErrorResponse::Unauthorized))
+            .attach_printable("Signed request timestamp is outside the tolerance");
+    }
+
+    let signing_key = state
+        .store()
+        .find_merchant_signing_key_by_key_id_optional(key_id)
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve signing key")?
+        .ok_or(report!(ApiErrorResponse::Unauthorized))
+        .attach_printable("Signing key does not exist")?;
+
+    let key_manager_state = &(&state.session_state()).into();
+    let key_store = state
+        .store()
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &signing_key.merchant_id,
+            &state.store().get_master_key().to_vec().into(),
+        )
+        .await
+        .change_context(ApiErrorResponse::Unauthorized)
+        .attach_printable("Failed to fetch merchant key store for the merchant id")?;
+
+    verify_signature(
+        state,
+        &signing_key,
+        &key_store,
+        signature_payload,
+        signature,
+    )
+    .await?;
+    check_nonce_not_used(state, key_id, nonce, tolerance).await?;
+
+    let profile_id = get_header_value_by_key(headers::X_PROFILE_ID.to_string(), request_headers)?
+        .map(id_type::ProfileId::from_str)
+        .transpose()
+        .change_context(errors::ValidationError::IncorrectValueProvided {
+            field_name: "X-Profile-Id",
+        })
+        .change_context(ApiErrorResponse::Unauthorized)?;
+
+    let merchant = state
+        .store()
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            &signing_key.merchant_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(ApiErrorResponse::Unauthorized)?;
+
+    if let Some(allowed_ip_ranges) = &merchant.allowed_ip_ranges {
+        let client_ip_address = ip_allowlist::get_client_ip_address(
+            request_headers,
+            state.conf().api_keys.get_inner().trusted_proxy_count,
+        );
+        if !client_ip_address.is_some_and(|ip_address| {
+            ip_allowlist::is_ip_address_allowed(ip_address, allowed_ip_ranges)
+        }) {
+            return Err(report!(ApiErrorResponse::MerchantIpAddressNotAllowed {
+                ip_address: client_ip_address
+                    .map(|ip_address| ip_address.to_string())
+                    .unwrap_or_else(|| "unknown".to_string()),
+            }))
+            .attach_printable_lazy(|| {
+                format!(
+                    "Request rejected by the IP allowlist of the merchant for signing key {key_id}"
+                )
+            });
+        }
+    }
+
+    let (merchant, platform_merchant_account) = if state.conf().platform.enabled {
+        get_platform_merchant_account(state, request_headers, merchant).await?
+    } else {
+        (merchant, None)
+    };
+
+    if platform_merchant_account.is_some() && !auth.is_platform_allowed {
+        return Err(report!(ApiErrorResponse::PlatformAccountAuthNotSupported))
+            .attach_printable("Platform not authorized to access the resource");
+    }
+
+    let key_store = if platform_merchant_account.is_some() {
+        state
+            .store()
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                merchant.get_id(),
+                &state.store().get_master_key().to_vec().into(),
+            )
+            .await
+            .change_context(ApiErrorResponse::Unauthorized)
+            .attach_printable("Failed to fetch merchant key store for the merchant id")?
+    } else {
+        key_store
+    };
+
+    let auth = AuthenticationData {
+        merchant_account: merchant,
+        platform_merchant_account,
+        key_store,
+        profile_id,
+    };
+    Ok((
+        auth.clone(),
+        AuthenticationType::SignedRequest {
+            merchant_id: auth.merchant_account.get_id().clone(),
+            key_id: signing_key.key_id,
+        },
+    ))
+}
+
+async fn verify_signature<A>(
+    state: &A,
+    signing_key: &storage::MerchantSigningKey,
+    key_store: &domain::MerchantKeyStore,
+    signature_payload: &str,
+    signature: &str,
+) -> RouterResult<()>
+where
+    A: SessionStateInfo + Sync,
+{
+    let signing_secret = domain::types::crypto_operation::<String, masking::WithType>(
+        &(&state.session_state()).into(),
+        type_name!(storage::MerchantSigningKey),
+        domain::types::CryptoOperation::Decrypt(signing_key.signing_secret.clone()),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to decrypt signing secret")?;
+
+    let signature = hex::decode(signature)
+        .change_context(ApiErrorResponse::Unauthorized)
+        .attach_printable("Signature is not hex encoded")?;
+    let is_signature_valid = crypto::HmacSha256
+        .verify_signature(
+            signing_secret.get_inner().peek().as_bytes(),
+            &signature,
+            signature_payload.as_bytes(),
+        )
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to verify signature")?;
+
+    if !is_signature_valid {
+        return Err(report!(ApiErrorResponse::Unauthorized))
+            .attach_printable("Signature does not match the request");
+    }
+
+    Ok(())
+}
+
+/// Nonces only need to be remembered as long as the timestamps they were sent with are accepted,
+/// which is for twice the tolerance in the worst case.
+async fn check_nonce_not_used<A>(
+    state: &A,
+    key_id: &str,
+    nonce: &str,
+    tolerance: i64,
+) -> RouterResult<()>
+where
+    A: SessionStateInfo + Sync,
+{
+    let redis_conn = state
+        .store()
+        .get_redis_conn()
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let reply = redis_conn
+        .set_key_if_not_exists_with_expiry(
+            &format!("{}{key_id}:{nonce}", consts::SIGNED_REQUEST_NONCE_PREFIX).into(),
+            date_time::now_unix_timestamp(),
+            Some(2 * tolerance),
+        )
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store signed request nonce")?;
+
+    match reply {
+        SetnxReply::KeySet => Ok(()),
+        SetnxReply::KeyNotSet => Err(report!(ApiErrorResponse::Unauthorized))
+            .attach_printable("Signed request nonce has already been used"),
+    }
+}
@@ -0,0 +1,303 @@
+#[cfg(feature = "olap")]
+use std::collections::HashMap;
+
+#[cfg(feature = "olap")]
+use common_utils::date_time;
+use error_stack::ResultExt;
+#[cfg(feature = "olap")]
+use redis_interface::{errors::RedisError, RedisKey};
+#[cfg(feature = "olap")]
+use router_env::logger;
+
+use super::blacklist::get_redis_connection_for_global_tenant;
+#[cfg(feature = "olap")]
+use super::blacklist::{expiry_to_i64, get_user_blacklisted_at};
+use crate::{
+    consts::USER_SESSION_PREFIX,
+    core::errors::{ApiErrorResponse, RouterResult},
+    routes::app::SessionStateInfo,
+};
+#[cfg(feature = "olap")]
+use crate::{
+    consts::{JWT_TOKEN_TIME_IN_SECS, USER_SESSIONS_LIST_PREFIX},
+    core::errors::{UserErrors, UserResult},
+    routes::SessionState,
+};
+
+/// A sign in of a dashboard user. All the JWTs issued for the sign in, including the ones issued
+/// when switching between entities, share the session, which lives as long as the latest of them.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct UserSession {
+    pub session_id: String,
+    pub user_id: String,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub issued_at: time::PrimitiveDateTime,
+    pub expires_at: time::PrimitiveDateTime,
+    /// Whether the user signed in through SSO, in which case the second factor is left to the
+    /// identity provider
+    #[serde(default)]
+    pub signed_in_with_sso: bool,
+}
+
+#[cfg(feature = "olap")]
+pub async fn create_user_session(
+    state: &SessionState,
+    user_id: &str,
+    signed_in_with_sso: bool,
+) -> UserResult<String> {
+    let expiry =
+        expiry_to_i64(JWT_TOKEN_TIME_IN_SECS).change_context(UserErrors::InternalServerError)?;
+    let issued_at = date_time::now();
+    let user_session = UserSession {
+        session_id: common_utils::generate_time_ordered_id("session"),
+        user_id: user_id.to_owned(),
+        ip_address: state.client_details.ip_address.clone(),
+        user_agent: state.client_details.user_agent.clone(),
+        issued_at,
+        expires_at: issued_at.saturating_add(time::Duration::seconds(expiry)),
+        signed_in_with_sso,
+    };
+
+    let redis_conn = get_redis_connection_for_global_tenant(state)
+        .change_context(UserErrors::InternalServerError)?;
+    redis_conn
+        .serialize_and_set_key_with_expiry(
+            &get_user_session_key(&user_session.session_id).into(),
+            &user_session,
+            expiry,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+    redis_conn
+        .set_hash_fields(
+            &get_user_sessions_list_key(user_id).into(),
+            HashMap::from([(
+                user_session.session_id.clone(),
+                user_session.issued_at.to_string(),
+            )]),
+            Some(expiry),
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok(user_session.session_id)
+}
+
+/// Extends the session to the expiry of a new token issued for it
+#[cfg(feature = "olap")]
+pub async fn extend_user_session(
+    state: &SessionState,
+    user_id: &str,
+    session_id: &str,
+) -> UserResult<()> {
+    let expiry =
+        expiry_to_i64(JWT_TOKEN_TIME_IN_SECS).change_context(UserErrors::InternalServerError)?;
+    let mut user_session = get_user_session(state, user_id, session_id).await?;
+    user_session.expires_at = date_time::now().saturating_add(time::Duration::seconds(expiry));
+
+    let redis_conn = get_redis_connection_for_global_tenant(state)
+        .change_context(UserErrors::InternalServerError)?;
+    redis_conn
+        .serialize_and_set_key_with_expiry(
+            &get_user_session_key(session_id).into(),
+            &user_session,
+            expiry,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+    redis_conn
+        .set_expiry(&get_user_sessions_list_key(user_id).into(), expiry)
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+/// Lists the sessions of the user which can still be used, latest first. The sessions which have
+/// expired are removed from the sessions of the user, as the list itself is kept alive by every
+/// sign in.
+#[cfg(feature = "olap")]
+pub async fn list_user_sessions(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<Vec<UserSession>> {
+    let session_ids = get_user_session_ids(state, user_id).await?;
+    if session_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+    let session_keys = session_ids
+        .iter()
+        .map(|session_id| get_user_session_key(session_id).into())
+        .collect::<Vec<RedisKey>>();
+
+    let redis_conn = get_redis_connection_for_global_tenant(state)
+        .change_context(UserErrors::InternalServerError)?;
+    let blacklisted_at = get_user_blacklisted_at(state, user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+    let (user_sessions, expired_session_ids): (Vec<_>, Vec<_>) = redis_conn
+        .get_and_deserialize_multiple_keys::<UserSession>(&session_keys, "UserSession")
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into_iter()
+        .zip(session_ids)
+        .partition(|(user_session, _)| user_session.is_some());
+
+    let expired_session_ids = expired_session_ids
+        .into_iter()
+        .map(|(_, session_id)| session_id)
+        .collect::<Vec<_>>();
+    if !expired_session_ids.is_empty() {
+        // Failing to remove the expired sessions doesn't affect the listing
+        if let Err(error) = redis_conn
+            .delete_hash_fields(
+                &get_user_sessions_list_key(user_id).into(),
+                expired_session_ids,
+            )
+            .await
+        {
+            logger::warn!(?error, "Failed to remove expired user sessions");
+        }
+    }
+
+    let mut user_sessions = user_sessions
+        .into_iter()
+        .filter_map(|(user_session, _)| user_session)
+        // Sessions signed in before the user was blacklisted can't be used anymore
+        .filter(|user_session| {
+            !matches!(
+                blacklisted_at,
+                Some(blacklisted_at) if blacklisted_at > user_session.issued_at.assume_utc().unix_timestamp()
+            )
+        })
+        .collect::<Vec<_>>();
+    user_sessions.sort_by(|a, b| b.issued_at.cmp(&a.issued_at));
+
+    Ok(user_sessions)
+}
+
+#[cfg(feature = "olap")]
+pub async fn revoke_user_session(
+    state: &SessionState,
+    user_id: &str,
+    session_id: &str,
+) -> UserResult<()> {
+    get_user_session(state, user_id, session_id).await?;
+
+    let redis_conn = get_redis_connection_for_global_tenant(state)
+        .change_context(UserErrors::InternalServerError)?;
+    redis_conn
+        .delete_key(&get_user_session_key(session_id).into())
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+    redis_conn
+        .delete_hash_fields(
+            &get_user_sessions_list_key(user_id).into(),
+            vec![session_id.to_owned()],
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .map(|_| ())
+}
+
+/// Revokes all the sessions of the user, other than the one specified in `except_session_id`
+#[cfg(feature = "olap")]
+pub async fn revoke_all_user_sessions(
+    state: &SessionState,
+    user_id: &str,
+    except_session_id: Option<&str>,
+) -> UserResult<()> {
+    let revoked_session_ids = get_user_session_ids(state, user_id)
+        .await?
+        .into_iter()
+        .filter(|session_id| Some(session_id.as_str()) != except_session_id)
+        .collect::<Vec<_>>();
+    if revoked_session_ids.is_empty() {
+        return Ok(());
+    }
+    let mut keys = revoked_session_ids
+        .iter()
+        .map(|session_id| get_user_session_key(session_id).into())
+        .collect::<Vec<RedisKey>>();
+    if except_session_id.is_none() {
+        keys.push(get_user_sessions_list_key(user_id).into());
+    }
+
+    let redis_conn = get_redis_connection_for_global_tenant(state)
+        .change_context(UserErrors::InternalServerError)?;
+    redis_conn
+        .delete_multiple_keys(&keys)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+    if except_session_id.is_some() {
+        redis_conn
+            .delete_hash_fields(
+                &get_user_sessions_list_key(user_id).into(),
+                revoked_session_ids,
+            )
+            .await
+            .change_context(UserErrors::InternalServerError)?;
+    }
+
+    Ok(())
+}
+
+/// Tokens without a session were issued before sessions were tracked, they are only checked
+/// against the blacklists.
+pub async fn check_user_session_revoked<A: SessionStateInfo>(
+    state: &A,
+    session_id: Option<&str>,
+) -> RouterResult<bool> {
+    let Some(session_id) = session_id else {
+        return Ok(false);
+    };
+
+    let redis_conn = get_redis_connection_for_global_tenant(state)?;
+    redis_conn
+        .exists::<()>(&get_user_session_key(session_id).into())
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .map(|exists| !exists)
+}
+
+#[cfg(feature = "olap")]
+pub async fn get_user_session(
+    state: &SessionState,
+    user_id: &str,
+    session_id: &str,
+) -> UserResult<UserSession> {
+    let redis_conn = get_redis_connection_for_global_tenant(state)
+        .change_context(UserErrors::InternalServerError)?;
+    let user_session: UserSession = redis_conn
+        .get_and_deserialize_key(&get_user_session_key(session_id).into(), "UserSession")
+        .await
+        .map_err(|error| match error.current_context() {
+            RedisError::NotFound => error.change_context(UserErrors::SessionNotFound),
+            _ => error.change_context(UserErrors::InternalServerError),
+        })?;
+
+    if user_session.user_id != user_id {
+        return Err(UserErrors::SessionNotFound.into());
+    }
+
+    Ok(user_session)
+}
+
+#[cfg(feature = "olap")]
+async fn get_user_session_ids(state: &SessionState, user_id: &str) -> UserResult<Vec<String>> {
+    let redis_conn = get_redis_connection_for_global_tenant(state)
+        .change_context(UserErrors::InternalServerError)?;
+    redis_conn
+        .get_hash_fields::<HashMap<String, String>>(&get_user_sessions_list_key(user_id).into())
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .map(|sessions| sessions.into_keys().collect())
+}
+
+fn get_user_session_key(session_id: &str) -> String {
+    format!("{USER_SESSION_PREFIX}{session_id}")
+}
+
+#[cfg(feature = "olap")]
+fn get_user_sessions_list_key(user_id: &str) -> String {
+    format!("{USER_SESSIONS_LIST_PREFIX}{user_id}")
+}
@@ -50,6 +50,9 @@ fn get_group_description(group: PermissionGroup) -> Option<&'static str> {
         PermissionGroup::ReconOpsManage => Some("Manage all reconciliation operations including reports and analytics"),
         PermissionGroup::ThemeView => Some("View Themes"),
         PermissionGroup::ThemeManage => Some("Manage Themes"),
+        PermissionGroup::RefundApprovalManage => Some("Approve and reject Refunds created by other Users"),
+        PermissionGroup::PaymentReviewView => Some("View Payments held for manual review"),
+        PermissionGroup::PaymentReviewManage => Some("Approve and decline Payments held for manual review"),
         PermissionGroup::InternalManage => None, // Internal group, no user-facing description
     }
 }
@@ -65,6 +68,8 @@ pub fn get_parent_group_description(group: ParentGroup) -> Option<&'static str>
         ParentGroup::ReconOps => Some("View, manage reconciliation operations like upload and process files, run reconciliation etc"),
         ParentGroup::ReconReports => Some("View, manage reconciliation reports and analytics"),
         ParentGroup::Theme => Some("Manage and view themes for the organization"),
+        ParentGroup::RefundApproval => Some("Approve and reject Refunds which require a second approval"),
+        ParentGroup::PaymentReview => Some("View, approve and decline Payments held for manual review by fraud checks"),
         ParentGroup::Internal => None, // Internal group, no user-facing description
     }
 }
@@ -24,7 +24,8 @@ impl PermissionGroupExt for PermissionGroup {
             | Self::AccountView
             | Self::ReconOpsView
             | Self::ReconReportsView
-            | Self::ThemeView => PermissionScope::Read,
+            | Self::ThemeView
+            | Self::PaymentReviewView => PermissionScope::Read,
 
             Self::OperationsManage
             | Self::ConnectorsManage
@@ -36,7 +37,9 @@ impl PermissionGroupExt for PermissionGroup {
             | Self::ReconOpsManage
             | Self::ReconReportsManage
             | Self::InternalManage
-            | Self::ThemeManage => PermissionScope::Write,
+            | Self::ThemeManage
+            | Self::RefundApprovalManage
+            | Self::PaymentReviewManage => PermissionScope::Write,
         }
     }
 
@@ -57,6 +60,8 @@ impl PermissionGroupExt for PermissionGroup {
             Self::ReconOpsView | Self::ReconOpsManage => ParentGroup::ReconOps,
             Self::ReconReportsView | Self::ReconReportsManage => ParentGroup::ReconReports,
             Self::InternalManage => ParentGroup::Internal,
+            Self::RefundApprovalManage => ParentGroup::RefundApproval,
+            Self::PaymentReviewView | Self::PaymentReviewManage => ParentGroup::PaymentReview,
         }
     }
 
@@ -109,6 +114,13 @@ impl PermissionGroupExt for PermissionGroup {
             Self::InternalManage => vec![Self::InternalManage],
             Self::ThemeView => vec![Self::ThemeView, Self::AccountView],
             Self::ThemeManage => vec![Self::ThemeManage, Self::AccountView],
+            Self::RefundApprovalManage => vec![Self::RefundApprovalManage, Self::OperationsView],
+            Self::PaymentReviewView => vec![Self::PaymentReviewView, Self::OperationsView],
+            Self::PaymentReviewManage => vec![
+                Self::PaymentReviewView,
+                Self::PaymentReviewManage,
+                Self::OperationsView,
+            ],
         }
     }
 }
@@ -135,6 +147,8 @@ impl ParentGroupExt for ParentGroup {
             Self::ReconReports => RECON_REPORTS.to_vec(),
             Self::Internal => INTERNAL.to_vec(),
             Self::Theme => THEME.to_vec(),
+            Self::RefundApproval => REFUND_APPROVAL.to_vec(),
+            Self::PaymentReview => PAYMENT_REVIEW.to_vec(),
         }
     }
 
@@ -229,3 +243,7 @@ pub static RECON_REPORTS: [Resource; 4] = [
 ];
 
 pub static THEME: [Resource; 1] = [Resource::Theme];
+
+pub static REFUND_APPROVAL: [Resource; 1] = [Resource::RefundApproval];
+
+pub static PAYMENT_REVIEW: [Resource; 1] = [Resource::PaymentReview];
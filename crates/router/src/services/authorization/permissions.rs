@@ -53,7 +53,7 @@ generate_permissions! {
         },
         SurchargeDecisionManager: {
             scopes: [Read, Write],
-            entities: [Merchant]
+            entities: [Merchant, Profile]
         },
         Analytics: {
             scopes: [Read],
@@ -110,6 +110,14 @@ generate_permissions! {
         Theme: {
             scopes: [Read,Write],
             entities: [Organization]
+        },
+        RefundApproval: {
+            scopes: [Write],
+            entities: [Profile, Merchant]
+        },
+        PaymentReview: {
+            scopes: [Read, Write],
+            entities: [Profile, Merchant]
         }
     ]
 }
@@ -147,6 +155,8 @@ pub fn get_resource_name(resource: Resource, entity_type: EntityType) -> Option<
         (Resource::Account, EntityType::Organization) => Some("Organization Account"),
         (Resource::Account, EntityType::Tenant) => Some("Tenant Account"),
         (Resource::Theme, _) => Some("Themes"),
+        (Resource::RefundApproval, _) => Some("Refund Approvals"),
+        (Resource::PaymentReview, _) => Some("Payment Reviews"),
         (Resource::InternalConnector, _) => None,
     }
 }
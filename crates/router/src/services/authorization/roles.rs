@@ -4,7 +4,7 @@ use std::collections::HashSet;
 
 #[cfg(feature = "recon")]
 use api_models::enums::ReconPermissionScope;
-use common_enums::{EntityType, PermissionGroup, Resource, RoleScope};
+use common_enums::{EntityType, PermissionGroup, Resource, RolePermission, RoleScope};
 use common_utils::{errors::CustomResult, id_type};
 
 #[cfg(feature = "recon")]
@@ -19,6 +19,8 @@ pub struct RoleInfo {
     role_id: String,
     role_name: String,
     groups: Vec<PermissionGroup>,
+    #[serde(default)]
+    permissions: Vec<RolePermission>,
     scope: RoleScope,
     entity_type: EntityType,
     is_invitable: bool,
@@ -45,6 +47,10 @@ impl RoleInfo {
             .collect()
     }
 
+    pub fn get_permissions(&self) -> &[RolePermission] {
+        &self.permissions
+    }
+
     pub fn get_scope(&self) -> RoleScope {
         self.scope
     }
@@ -73,15 +79,22 @@ impl RoleInfo {
         self.get_permission_groups()
             .iter()
             .flat_map(|group| group.resources())
+            .chain(
+                self.permissions
+                    .iter()
+                    .map(|permission| permission.resource),
+            )
             .collect()
     }
 
     pub fn check_permission_exists(&self, required_permission: Permission) -> bool {
         required_permission.entity_type() <= self.entity_type
-            && self.get_permission_groups().iter().any(|group| {
+            && (self.get_permission_groups().iter().any(|group| {
                 required_permission.scope() <= group.scope()
                     && group.resources().contains(&required_permission.resource())
-            })
+            }) || self.permissions.iter().any(|permission| {
+                permission.permits(required_permission.resource(), required_permission.scope())
+            }))
     }
 
     #[cfg(feature = "recon")]
@@ -169,6 +182,7 @@ impl From<diesel_models::role::Role> for RoleInfo {
         Self {
             role_id: role.role_id,
             role_name: role.role_name,
+            permissions: role.get_permissions(),
             groups: role.groups,
             scope: role.scope,
             entity_type: role.entity_type,
@@ -179,3 +193,65 @@ impl From<diesel_models::role::Role> for RoleInfo {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_enums::PermissionScope;
+
+    use super::*;
+
+    fn get_custom_role(groups: Vec<PermissionGroup>, permissions: Vec<RolePermission>) -> RoleInfo {
+        RoleInfo {
+            role_id: "custom_role".to_string(),
+            role_name: "Custom Role".to_string(),
+            groups,
+            permissions,
+            scope: RoleScope::Merchant,
+            entity_type: EntityType::Merchant,
+            is_invitable: true,
+            is_deletable: true,
+            is_updatable: true,
+            is_internal: false,
+        }
+    }
+
+    #[test]
+    fn test_check_permission_exists_for_permission_only_role() {
+        let role_info = get_custom_role(
+            vec![],
+            vec![
+                RolePermission {
+                    resource: Resource::Refund,
+                    scope: PermissionScope::Write,
+                },
+                RolePermission {
+                    resource: Resource::Payment,
+                    scope: PermissionScope::Read,
+                },
+            ],
+        );
+
+        assert!(role_info.check_permission_exists(Permission::ProfileRefundWrite));
+        assert!(role_info.check_permission_exists(Permission::MerchantRefundRead));
+        assert!(role_info.check_permission_exists(Permission::ProfilePaymentRead));
+        assert!(!role_info.check_permission_exists(Permission::ProfilePaymentWrite));
+        assert!(!role_info.check_permission_exists(Permission::MerchantApiKeyRead));
+        assert!(!role_info.check_permission_exists(Permission::OrganizationAccountRead));
+    }
+
+    #[test]
+    fn test_get_resources_set_includes_permissions() {
+        let role_info = get_custom_role(
+            vec![],
+            vec![RolePermission {
+                resource: Resource::ApiKey,
+                scope: PermissionScope::Read,
+            }],
+        );
+
+        assert_eq!(
+            role_info.get_resources_set(),
+            HashSet::from([Resource::ApiKey])
+        );
+    }
+}
@@ -15,6 +15,9 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             groups: vec![
                 PermissionGroup::OperationsView,
                 PermissionGroup::OperationsManage,
+                PermissionGroup::RefundApprovalManage,
+                PermissionGroup::PaymentReviewView,
+                PermissionGroup::PaymentReviewManage,
                 PermissionGroup::ConnectorsView,
                 PermissionGroup::ConnectorsManage,
                 PermissionGroup::WorkflowsView,
@@ -40,6 +43,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: false,
             is_updatable: false,
             is_internal: true,
+            permissions: vec![],
         },
     );
     roles.insert(
@@ -64,6 +68,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: false,
             is_updatable: false,
             is_internal: true,
+            permissions: vec![],
         },
     );
     roles.insert(
@@ -89,6 +94,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: false,
             is_updatable: false,
             is_internal: true,
+            permissions: vec![],
         },
     );
 
@@ -99,6 +105,9 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             groups: vec![
                 PermissionGroup::OperationsView,
                 PermissionGroup::OperationsManage,
+                PermissionGroup::RefundApprovalManage,
+                PermissionGroup::PaymentReviewView,
+                PermissionGroup::PaymentReviewManage,
                 PermissionGroup::ConnectorsView,
                 PermissionGroup::ConnectorsManage,
                 PermissionGroup::WorkflowsView,
@@ -124,6 +133,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: false,
             is_updatable: false,
             is_internal: false,
+            permissions: vec![],
         },
     );
 
@@ -134,6 +144,9 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             groups: vec![
                 PermissionGroup::OperationsView,
                 PermissionGroup::OperationsManage,
+                PermissionGroup::RefundApprovalManage,
+                PermissionGroup::PaymentReviewView,
+                PermissionGroup::PaymentReviewManage,
                 PermissionGroup::ConnectorsView,
                 PermissionGroup::ConnectorsManage,
                 PermissionGroup::WorkflowsView,
@@ -161,6 +174,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            permissions: vec![],
         },
     );
 
@@ -171,6 +185,9 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             groups: vec![
                 PermissionGroup::OperationsView,
                 PermissionGroup::OperationsManage,
+                PermissionGroup::RefundApprovalManage,
+                PermissionGroup::PaymentReviewView,
+                PermissionGroup::PaymentReviewManage,
                 PermissionGroup::ConnectorsView,
                 PermissionGroup::ConnectorsManage,
                 PermissionGroup::WorkflowsView,
@@ -195,6 +212,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            permissions: vec![],
         },
     );
     roles.insert(
@@ -219,6 +237,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            permissions: vec![],
         },
     );
     roles.insert(
@@ -240,6 +259,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            permissions: vec![],
         },
     );
     roles.insert(
@@ -265,6 +285,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            permissions: vec![],
         },
     );
     roles.insert(
@@ -291,6 +312,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            permissions: vec![],
         },
     );
     roles.insert(
@@ -313,6 +335,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            permissions: vec![],
         },
     );
 
@@ -323,6 +346,9 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             groups: vec![
                 PermissionGroup::OperationsView,
                 PermissionGroup::OperationsManage,
+                PermissionGroup::RefundApprovalManage,
+                PermissionGroup::PaymentReviewView,
+                PermissionGroup::PaymentReviewManage,
                 PermissionGroup::ConnectorsView,
                 PermissionGroup::ConnectorsManage,
                 PermissionGroup::WorkflowsView,
@@ -343,6 +369,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            permissions: vec![],
         },
     );
     roles.insert(
@@ -365,6 +392,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            permissions: vec![],
         },
     );
     roles.insert(
@@ -386,6 +414,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            permissions: vec![],
         },
     );
     roles.insert(
@@ -409,6 +438,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            permissions: vec![],
         },
     );
     roles.insert(
@@ -432,6 +462,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            permissions: vec![],
         },
     );
     roles.insert(
@@ -452,6 +483,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            permissions: vec![],
         },
     );
     roles
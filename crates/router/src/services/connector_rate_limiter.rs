@@ -0,0 +1,295 @@
+use std::time::{Duration, Instant};
+
+use common_utils::{ext_traits::StringExt, id_type};
+use error_stack::ResultExt;
+use router_env::logger;
+use serde::Deserialize;
+
+use crate::{
+    consts,
+    core::errors::{ApiErrorResponse, RouterResult},
+    routes::{metrics, SessionState},
+};
+
+/// Takes a token from the bucket of the merchant connector account and a concurrency slot, only if
+/// both are available. Limits set to 0 are not enforced. Concurrency slots which were not released
+/// expire after the given TTL. Returns 1 if the request is allowed and 0 otherwise.
+const ACQUIRE_PERMIT_SCRIPT: &str = r#"
+    local time = redis.call("TIME")
+    local now = tonumber(time[1]) + tonumber(time[2]) / 1000000
+    local requests_per_second = tonumber(ARGV[1])
+    local max_concurrency = tonumber(ARGV[2])
+    local slot_ttl = tonumber(ARGV[3])
+    if max_concurrency > 0 then
+        redis.call("ZREMRANGEBYSCORE", KEYS[2], "-inf", now)
+        if redis.call("ZCARD", KEYS[2]) >= max_concurrency then
+            return 0
+        end
+    end
+    if requests_per_second > 0 then
+        local bucket = redis.call("HMGET", KEYS[1], "tokens", "updated_at")
+        local available = tonumber(bucket[1]) or requests_per_second
+        local updated_at = tonumber(bucket[2]) or now
+        local tokens = math.min(
+            requests_per_second,
+            available + math.max(0, now - updated_at) * requests_per_second
+        )
+        if tokens < 1 then
+            return 0
+        end
+        redis.call("HSET", KEYS[1], "tokens", tostring(tokens - 1), "updated_at", tostring(now))
+        redis.call("EXPIRE", KEYS[1], 2)
+    end
+    if max_concurrency > 0 then
+        redis.call("ZADD", KEYS[2], now + slot_ttl, ARGV[4])
+        redis.call("EXPIRE", KEYS[2], slot_ttl)
+    end
+    return 1
+"#;
+
+const RELEASE_SLOT_SCRIPT: &str = r#"
+    return redis.call("ZREM", KEYS[1], ARGV[1])
+"#;
+
+/// Outbound limits of a merchant connector account, stored as JSON under the key returned by
+/// [`id_type::MerchantConnectorAccountId::get_outbound_rate_limit_key`]. Limits which are not
+/// configured are not enforced.
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+struct ConnectorRateLimitConfig {
+    /// Number of requests which can be sent to the connector every second
+    requests_per_second: Option<u32>,
+    /// Number of requests which can be awaiting a response from the connector at a time
+    max_concurrency: Option<u32>,
+    /// Overrides the maximum time for which a request waits for the limits to allow it
+    max_queue_wait_in_ms: Option<u64>,
+}
+
+impl ConnectorRateLimitConfig {
+    fn is_limited(&self) -> bool {
+        self.requests_per_second.is_some_and(|limit| limit > 0)
+            || self.max_concurrency.is_some_and(|limit| limit > 0)
+    }
+}
+
+/// Concurrency slot held by a request while it awaits a response from the connector
+#[derive(Debug)]
+pub struct ConcurrencySlot {
+    key: String,
+    slot_id: String,
+}
+
+#[derive(Debug)]
+pub enum ConnectorPermit {
+    /// The request can be sent to the connector, holding a concurrency slot if the concurrency of
+    /// the merchant connector account is capped
+    Granted(Option<ConcurrencySlot>),
+    /// The limits did not allow the request within the maximum queue wait
+    Denied,
+}
+
+/// Waits until the outbound limits of the merchant connector account allow a request to be sent
+/// to the connector, for at most the maximum queue wait. Requests are allowed if the limits can't
+/// be checked, so that an unavailable redis does not stop payments.
+pub async fn acquire_connector_permit(
+    state: &SessionState,
+    merchant_connector_id: Option<&id_type::MerchantConnectorAccountId>,
+    connector: &str,
+) -> ConnectorPermit {
+    let settings = &state.conf.connector_rate_limit;
+    let Some(merchant_connector_id) = merchant_connector_id.filter(|_| settings.enabled) else {
+        return ConnectorPermit::Granted(None);
+    };
+
+    let config = match get_connector_rate_limit_config(state, merchant_connector_id).await {
+        Ok(config) if config.is_limited() => config,
+        Ok(_) => return ConnectorPermit::Granted(None),
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch the outbound rate limits");
+            metrics::CONNECTOR_RATE_LIMIT_CHECK_FAILURE.add(
+                1,
+                router_env::metric_attributes!(("connector", connector.to_owned())),
+            );
+            return ConnectorPermit::Granted(None);
+        }
+    };
+
+    let max_queue_wait = Duration::from_millis(
+        config
+            .max_queue_wait_in_ms
+            .unwrap_or(settings.max_queue_wait_in_ms),
+    );
+    let poll_interval = Duration::from_millis(settings.queue_poll_interval_in_ms);
+    let (tokens_key, slots_key) = get_connector_rate_limit_keys(merchant_connector_id);
+    let slot_id = uuid::Uuid::new_v4().to_string();
+    let started_at = Instant::now();
+
+    loop {
+        match try_acquire_permit(state, &config, &tokens_key, &slots_key, &slot_id).await {
+            Ok(true) => break,
+            Ok(false) if started_at.elapsed() + poll_interval <= max_queue_wait => {
+                tokio::time::sleep(poll_interval).await;
+            }
+            Ok(false) => {
+                logger::warn!(
+                    connector,
+                    ?merchant_connector_id,
+                    "Outbound rate limit of the merchant connector account exceeded"
+                );
+                metrics::CONNECTOR_RATE_LIMIT_EXCEEDED.add(
+                    1,
+                    router_env::metric_attributes!(("connector", connector.to_owned())),
+                );
+                return ConnectorPermit::Denied;
+            }
+            Err(error) => {
+                logger::error!(?error, "Failed to check the outbound rate limits");
+                metrics::CONNECTOR_RATE_LIMIT_CHECK_FAILURE.add(
+                    1,
+                    router_env::metric_attributes!(("connector", connector.to_owned())),
+                );
+                return ConnectorPermit::Granted(None);
+            }
+        }
+    }
+
+    let queue_wait = started_at.elapsed();
+    if queue_wait >= poll_interval {
+        metrics::CONNECTOR_RATE_LIMIT_QUEUED.add(
+            1,
+            router_env::metric_attributes!(("connector", connector.to_owned())),
+        );
+        metrics::CONNECTOR_RATE_LIMIT_QUEUE_WAIT_TIME.record(
+            queue_wait.as_secs_f64(),
+            router_env::metric_attributes!(("connector", connector.to_owned())),
+        );
+    }
+
+    ConnectorPermit::Granted(config.max_concurrency.filter(|limit| *limit > 0).map(|_| {
+        ConcurrencySlot {
+            key: slots_key,
+            slot_id,
+        }
+    }))
+}
+
+/// Releases the concurrency slot once the connector has responded. Slots which could not be
+/// released expire after the configured TTL.
+pub async fn release_concurrency_slot(state: &SessionState, slot: ConcurrencySlot) {
+    let result: RouterResult<u64> = async {
+        let redis_conn = state
+            .store
+            .get_redis_conn()
+            .change_context(ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get redis connection")?;
+        redis_conn
+            .evaluate_redis_script::<_, u64>(
+                RELEASE_SLOT_SCRIPT,
+                vec![redis_conn.add_prefix(&slot.key)],
+                vec![slot.slot_id],
+            )
+            .await
+            .change_context(ApiErrorResponse::InternalServerError)
+    }
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to release the connector concurrency slot");
+    }
+}
+
+async fn get_connector_rate_limit_config(
+    state: &SessionState,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<ConnectorRateLimitConfig> {
+    // Merchant connector accounts without limits get an empty config, which is cached
+    let config = state
+        .store
+        .find_config_by_key_unwrap_or(
+            &merchant_connector_id.get_outbound_rate_limit_key(),
+            Some("{}".to_string()),
+        )
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)?;
+
+    config
+        .config
+        .parse_struct("ConnectorRateLimitConfig")
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid outbound rate limit config")
+}
+
+async fn try_acquire_permit(
+    state: &SessionState,
+    config: &ConnectorRateLimitConfig,
+    tokens_key: &str,
+    slots_key: &str,
+    slot_id: &str,
+) -> RouterResult<bool> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    // Scripts are not given the tenant prefix of the connection, so it is added to the keys here
+    let keys = vec![
+        redis_conn.add_prefix(tokens_key),
+        redis_conn.add_prefix(slots_key),
+    ];
+    let values = vec![
+        config.requests_per_second.unwrap_or_default().to_string(),
+        config.max_concurrency.unwrap_or_default().to_string(),
+        state
+            .conf
+            .connector_rate_limit
+            .concurrency_slot_ttl_in_secs
+            .to_string(),
+        slot_id.to_owned(),
+    ];
+
+    redis_conn
+        .evaluate_redis_script::<_, u64>(ACQUIRE_PERMIT_SCRIPT, keys, values)
+        .await
+        .map(|is_allowed| is_allowed == 1)
+        .change_context(ApiErrorResponse::InternalServerError)
+}
+
+/// Both keys of a merchant connector account share a hash tag, so that they are in the same slot
+/// when redis runs in cluster mode, which is required for a script to access them together.
+fn get_connector_rate_limit_keys(
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> (String, String) {
+    let prefix = format!(
+        "{}{}",
+        consts::CONNECTOR_RATE_LIMIT_PREFIX,
+        redis_interface::hash_tag(merchant_connector_id.get_string_repr())
+    );
+    (format!("{prefix}:tokens"), format!("{prefix}:in_flight"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connector_rate_limit_config() {
+        let config: ConnectorRateLimitConfig = "{}"
+            .to_string()
+            .parse_struct("ConnectorRateLimitConfig")
+            .unwrap();
+        assert!(!config.is_limited());
+
+        let config: ConnectorRateLimitConfig = r#"{"requests_per_second": 0}"#
+            .to_string()
+            .parse_struct("ConnectorRateLimitConfig")
+            .unwrap();
+        assert!(!config.is_limited());
+
+        let config: ConnectorRateLimitConfig =
+            r#"{"requests_per_second": 50, "max_concurrency": 10}"#
+                .to_string()
+                .parse_struct("ConnectorRateLimitConfig")
+                .unwrap();
+        assert!(config.is_limited());
+    }
+}
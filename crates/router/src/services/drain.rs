@@ -0,0 +1,109 @@
+//! Drain mode of the instance, for deployments without downtime. A draining instance rejects the
+//! requests creating payments and fails its readiness check, then shuts down once the flows in
+//! progress are complete.
+
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        OnceLock,
+    },
+    time::{Duration, Instant},
+};
+
+use actix_web::dev::ServerHandle;
+use router_env::{logger, tracing::Instrument};
+
+use crate::configs::settings::DrainSettings;
+
+const IN_FLIGHT_FLOWS_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+static DRAIN_STATE: DrainState = DrainState {
+    draining_since: OnceLock::new(),
+    in_flight_flows: AtomicUsize::new(0),
+    server_handle: OnceLock::new(),
+};
+
+struct DrainState {
+    draining_since: OnceLock<Instant>,
+    in_flight_flows: AtomicUsize,
+    server_handle: OnceLock<ServerHandle>,
+}
+
+/// A flow in progress, which the instance waits for before shutting down once draining
+#[derive(Debug)]
+pub struct InFlightFlow(());
+
+impl Drop for InFlightFlow {
+    fn drop(&mut self) {
+        DRAIN_STATE.in_flight_flows.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Tracks a flow as in progress until the returned guard is dropped
+pub fn track_flow() -> InFlightFlow {
+    DRAIN_STATE.in_flight_flows.fetch_add(1, Ordering::AcqRel);
+    InFlightFlow(())
+}
+
+/// Registers the server which is stopped once the instance is drained
+pub fn register_server(server_handle: ServerHandle) {
+    if DRAIN_STATE.server_handle.set(server_handle).is_err() {
+        logger::warn!("A server is already registered to be stopped once drained");
+    }
+}
+
+pub fn is_draining() -> bool {
+    DRAIN_STATE.draining_since.get().is_some()
+}
+
+/// Returns the time since which the instance is draining, if it is draining
+pub fn get_draining_duration() -> Option<Duration> {
+    DRAIN_STATE
+        .draining_since
+        .get()
+        .map(|draining_since| draining_since.elapsed())
+}
+
+pub fn get_in_flight_flows() -> usize {
+    DRAIN_STATE.in_flight_flows.load(Ordering::Acquire)
+}
+
+/// Starts draining the instance, unless it is already draining. Once the grace period is over,
+/// the flows in progress are awaited up to the configured timeout, and the server is stopped
+/// gracefully.
+pub fn start_drain(settings: &DrainSettings) {
+    if DRAIN_STATE.draining_since.set(Instant::now()).is_err() {
+        return;
+    }
+    logger::info!("Started draining the instance");
+
+    let grace_period = Duration::from_secs(settings.grace_period_in_secs);
+    let in_flight_timeout = Duration::from_secs(settings.in_flight_timeout_in_secs);
+    tokio::spawn(
+        async move {
+            tokio::time::sleep(grace_period).await;
+
+            let in_flight_flows_completed = tokio::time::timeout(in_flight_timeout, async {
+                while get_in_flight_flows() > 0 {
+                    tokio::time::sleep(IN_FLIGHT_FLOWS_POLL_INTERVAL).await;
+                }
+            })
+            .await;
+            if in_flight_flows_completed.is_err() {
+                logger::warn!(
+                    in_flight_flows = get_in_flight_flows(),
+                    "Timed out waiting for the flows in progress to complete"
+                );
+            }
+
+            match DRAIN_STATE.server_handle.get() {
+                Some(server_handle) => {
+                    logger::info!("Stopping the drained server");
+                    server_handle.stop(true).await;
+                }
+                None => logger::warn!("No server is registered to be stopped once drained"),
+            }
+        }
+        .in_current_span(),
+    );
+}
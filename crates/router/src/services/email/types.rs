@@ -1,5 +1,5 @@
 use api_models::user::dashboard_metadata::ProdIntent;
-use common_enums::{EntityType, MerchantProductType};
+use common_enums::{AuthRateAlertStatus, EntityType, MerchantProductType};
 use common_utils::{errors::CustomResult, pii, types::user::EmailThemeConfig};
 use error_stack::ResultExt;
 use external_services::email::{EmailContents, EmailData, EmailError};
@@ -81,6 +81,24 @@ pub enum EmailBody {
         prefix: String,
     },
     WelcomeToCommunity,
+    ScheduledReport {
+        report_name: String,
+        run_id: String,
+        format: String,
+        start_time: String,
+        end_time: String,
+        row_count: usize,
+        report_table: String,
+    },
+    AuthRateAlert {
+        alert_id: String,
+        alert_summary: String,
+        connector: String,
+        payment_method: String,
+        baseline_rate: String,
+        current_rate: String,
+        attempt_count: i64,
+    },
 }
 
 pub mod html {
@@ -242,6 +260,42 @@ Email         : {user_email}
             EmailBody::WelcomeToCommunity => {
                 include_str!("assets/welcome_to_community.html").to_string()
             }
+            EmailBody::ScheduledReport {
+                report_name,
+                run_id,
+                format,
+                start_time,
+                end_time,
+                row_count,
+                report_table,
+            } => format!(
+                include_str!("assets/scheduled_report.html"),
+                report_name = report_name,
+                run_id = run_id,
+                format = format,
+                start_time = start_time,
+                end_time = end_time,
+                row_count = row_count,
+                report_table = report_table,
+            ),
+            EmailBody::AuthRateAlert {
+                alert_id,
+                alert_summary,
+                connector,
+                payment_method,
+                baseline_rate,
+                current_rate,
+                attempt_count,
+            } => format!(
+                include_str!("assets/auth_rate_alert.html"),
+                alert_id = alert_id,
+                alert_summary = alert_summary,
+                connector = connector,
+                payment_method = payment_method,
+                baseline_rate = baseline_rate,
+                current_rate = current_rate,
+                attempt_count = attempt_count,
+            ),
         }
     }
 }
@@ -678,3 +732,86 @@ impl EmailData for WelcomeToCommunity {
         })
     }
 }
+
+pub struct ScheduledReport {
+    pub recipient_email: domain::UserEmail,
+    pub report_name: String,
+    pub run_id: String,
+    pub format: String,
+    pub start_time: String,
+    pub end_time: String,
+    pub row_count: usize,
+    /// The rows of the report rendered as an HTML table, which is expected to be escaped
+    pub report_table: String,
+}
+
+#[async_trait::async_trait]
+impl EmailData for ScheduledReport {
+    async fn get_email_data(&self, _base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        let body = html::get_html_body(EmailBody::ScheduledReport {
+            report_name: self.report_name.clone(),
+            run_id: self.run_id.clone(),
+            format: self.format.clone(),
+            start_time: self.start_time.clone(),
+            end_time: self.end_time.clone(),
+            row_count: self.row_count,
+            report_table: self.report_table.clone(),
+        });
+
+        Ok(EmailContents {
+            subject: format!("Your scheduled report {} is ready", self.report_name),
+            body: external_services::email::IntermediateString::new(body),
+            recipient: self.recipient_email.clone().into_inner(),
+        })
+    }
+}
+
+pub struct AuthRateAlert {
+    pub recipient_email: domain::UserEmail,
+    pub alert_id: String,
+    pub status: AuthRateAlertStatus,
+    pub connector: String,
+    pub payment_method: String,
+    pub baseline_rate: f64,
+    pub current_rate: f64,
+    pub attempt_count: i64,
+}
+
+#[async_trait::async_trait]
+impl EmailData for AuthRateAlert {
+    async fn get_email_data(&self, _base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        let (subject, alert_summary) = match self.status {
+            AuthRateAlertStatus::Active => (
+                format!(
+                    "Authorization rate drop on {} {} payments",
+                    self.connector, self.payment_method
+                ),
+                "The authorization rate of your payments has dropped below its baseline."
+                    .to_string(),
+            ),
+            AuthRateAlertStatus::Resolved => (
+                format!(
+                    "Authorization rate recovered on {} {} payments",
+                    self.connector, self.payment_method
+                ),
+                "The authorization rate of your payments has recovered to its baseline."
+                    .to_string(),
+            ),
+        };
+        let body = html::get_html_body(EmailBody::AuthRateAlert {
+            alert_id: self.alert_id.clone(),
+            alert_summary,
+            connector: self.connector.clone(),
+            payment_method: self.payment_method.clone(),
+            baseline_rate: format!("{:.2}", self.baseline_rate),
+            current_rate: format!("{:.2}", self.current_rate),
+            attempt_count: self.attempt_count,
+        });
+
+        Ok(EmailContents {
+            subject,
+            body: external_services::email::IntermediateString::new(body),
+            recipient: self.recipient_email.clone().into_inner(),
+        })
+    }
+}
@@ -0,0 +1,209 @@
+use std::{collections::HashMap, time::Duration};
+
+use common_types::domain::EventSinkDestination;
+use common_utils::{errors::CustomResult, id_type};
+use error_stack::{report, ResultExt};
+#[cfg(feature = "aws_event_sink")]
+use external_services::aws_event_sink::{AwsEventSink, AwsEventSinkConfig};
+use masking::PeekInterface;
+use rdkafka::{
+    message::{Header, OwnedHeaders},
+    producer::{FutureProducer, FutureRecord},
+    ClientConfig,
+};
+use tokio::sync::RwLock;
+
+/// Time after which publishing an event to an event sink is considered failed
+const PUBLISH_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, thiserror::Error)]
+pub enum EventSinkError {
+    #[error("Failed to create the client of the event sink")]
+    ClientCreationFailed,
+    #[error("Failed to publish the event to the event sink")]
+    PublishFailed,
+    #[error("Publishing events to {0} is not supported by this build")]
+    DestinationNotSupported(&'static str),
+}
+
+/// Message published to an event sink
+#[derive(Debug)]
+pub struct EventSinkMessage {
+    /// Messages for the same object share the same key, so that their order is preserved
+    pub key: String,
+    /// Identifier used by FIFO topics and queues to discard messages published more than once
+    pub deduplication_id: String,
+    pub payload: String,
+    /// Sent as Kafka headers or as AWS message attributes
+    pub attributes: HashMap<String, String>,
+}
+
+#[derive(Clone)]
+enum EventSinkPublisher {
+    Kafka {
+        producer: FutureProducer,
+        topic: String,
+    },
+    #[cfg(feature = "aws_event_sink")]
+    Aws(AwsEventSink),
+}
+
+impl EventSinkPublisher {
+    async fn create(destination: &EventSinkDestination) -> CustomResult<Self, EventSinkError> {
+        match destination {
+            EventSinkDestination::Kafka {
+                brokers,
+                topic,
+                sasl_credentials,
+            } => {
+                let mut client_config = ClientConfig::new();
+                client_config
+                    .set("bootstrap.servers", brokers.join(","))
+                    .set(
+                        "message.timeout.ms",
+                        PUBLISH_TIMEOUT.as_millis().to_string(),
+                    );
+                // Brokers operated by merchants are reached over the internet, so the connection
+                // is always encrypted
+                match sasl_credentials {
+                    Some(sasl_credentials) => client_config
+                        .set("security.protocol", "SASL_SSL")
+                        .set("sasl.mechanism", sasl_credentials.mechanism.to_string())
+                        .set("sasl.username", sasl_credentials.username.peek())
+                        .set("sasl.password", sasl_credentials.password.peek()),
+                    None => client_config.set("security.protocol", "SSL"),
+                };
+
+                Ok(Self::Kafka {
+                    producer: client_config
+                        .create()
+                        .change_context(EventSinkError::ClientCreationFailed)?,
+                    topic: topic.clone(),
+                })
+            }
+            #[cfg(feature = "aws_event_sink")]
+            EventSinkDestination::AwsSns {
+                topic_arn,
+                region,
+                role_arn,
+                external_id,
+            } => Ok(Self::Aws(
+                AwsEventSink::create_sns(
+                    &AwsEventSinkConfig {
+                        region: region.clone(),
+                        role_arn: role_arn.clone(),
+                        external_id: external_id.as_ref().map(|id| id.peek().clone()),
+                    },
+                    topic_arn.clone(),
+                )
+                .await,
+            )),
+            #[cfg(feature = "aws_event_sink")]
+            EventSinkDestination::AwsSqs {
+                queue_url,
+                region,
+                role_arn,
+                external_id,
+            } => Ok(Self::Aws(
+                AwsEventSink::create_sqs(
+                    &AwsEventSinkConfig {
+                        region: region.clone(),
+                        role_arn: role_arn.clone(),
+                        external_id: external_id.as_ref().map(|id| id.peek().clone()),
+                    },
+                    queue_url.clone(),
+                )
+                .await,
+            )),
+            #[cfg(not(feature = "aws_event_sink"))]
+            EventSinkDestination::AwsSns { .. } | EventSinkDestination::AwsSqs { .. } => {
+                Err(report!(EventSinkError::DestinationNotSupported(
+                    destination.get_name()
+                )))
+            }
+        }
+    }
+
+    async fn publish(&self, message: EventSinkMessage) -> CustomResult<(), EventSinkError> {
+        match self {
+            Self::Kafka { producer, topic } => {
+                let headers =
+                    message
+                        .attributes
+                        .iter()
+                        .fold(OwnedHeaders::new(), |headers, (key, value)| {
+                            headers.insert(Header {
+                                key: key.as_str(),
+                                value: Some(value),
+                            })
+                        });
+                producer
+                    .send(
+                        FutureRecord::to(topic)
+                            .key(&message.key)
+                            .payload(&message.payload)
+                            .headers(headers),
+                        PUBLISH_TIMEOUT,
+                    )
+                    .await
+                    .map_err(|(error, _)| report!(error))
+                    .change_context(EventSinkError::PublishFailed)?;
+                Ok(())
+            }
+            #[cfg(feature = "aws_event_sink")]
+            Self::Aws(aws_event_sink) => aws_event_sink
+                .publish(
+                    message.payload,
+                    &message.attributes,
+                    &message.key,
+                    &message.deduplication_id,
+                )
+                .await
+                .change_context(EventSinkError::PublishFailed),
+        }
+    }
+}
+
+/// Client publishing events to the event sinks configured on profiles. The Kafka producer or AWS
+/// client created for a profile is reused until the destination configured on the profile changes.
+#[derive(Default)]
+pub struct EventSinkClient {
+    publishers: RwLock<HashMap<id_type::ProfileId, (EventSinkDestination, EventSinkPublisher)>>,
+}
+
+impl EventSinkClient {
+    pub async fn publish(
+        &self,
+        profile_id: &id_type::ProfileId,
+        destination: &EventSinkDestination,
+        message: EventSinkMessage,
+    ) -> CustomResult<(), EventSinkError> {
+        self.get_or_create_publisher(profile_id, destination)
+            .await?
+            .publish(message)
+            .await
+    }
+
+    async fn get_or_create_publisher(
+        &self,
+        profile_id: &id_type::ProfileId,
+        destination: &EventSinkDestination,
+    ) -> CustomResult<EventSinkPublisher, EventSinkError> {
+        if let Some((_, publisher)) = self
+            .publishers
+            .read()
+            .await
+            .get(profile_id)
+            .filter(|(cached_destination, _)| cached_destination == destination)
+        {
+            return Ok(publisher.clone());
+        }
+
+        let publisher = EventSinkPublisher::create(destination).await?;
+        self.publishers.write().await.insert(
+            profile_id.to_owned(),
+            (destination.clone(), publisher.clone()),
+        );
+        Ok(publisher)
+    }
+}
@@ -0,0 +1,423 @@
+use std::future::Future;
+
+use actix_web::http::header::HeaderMap;
+use common_utils::{
+    crypto::{GenerateDigest, Sha256},
+    events::{ApiEventMetric, ApiEventsType},
+    id_type,
+};
+use error_stack::{report, ResultExt};
+use masking::{Maskable, PeekInterface, Secret};
+use redis_interface::{RedisKey, SetnxReply};
+use router_env::{logger, Flow};
+
+use crate::{
+    consts,
+    core::errors::{ApiErrorResponse, RouterResponse, RouterResult},
+    headers,
+    routes::{app::SessionStateInfo, SessionState},
+    services::ApplicationResponse,
+};
+
+/// Response of a request made with an idempotency key, which is either the response of the
+/// request itself or the stored response of the first request made with the same key
+#[derive(Debug, serde::Serialize)]
+#[serde(untagged)]
+pub enum IdempotentResponse<Q> {
+    Processed(Q),
+    Replayed(serde_json::Value),
+}
+
+impl<Q: ApiEventMetric> ApiEventMetric for IdempotentResponse<Q> {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        match self {
+            Self::Processed(response) => response.get_api_event_type(),
+            Self::Replayed(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum IdempotencyRecord {
+    InProgress {
+        request_hash: String,
+    },
+    Completed {
+        request_hash: String,
+        response: serde_json::Value,
+        #[serde(default)]
+        headers: Vec<StoredHeader>,
+    },
+}
+
+/// Header of a stored response, which is replayed along with its body
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct StoredHeader {
+    name: String,
+    value: String,
+    is_masked: bool,
+}
+
+impl From<&(String, Maskable<String>)> for StoredHeader {
+    fn from((name, value): &(String, Maskable<String>)) -> Self {
+        let (value, is_masked) = match value {
+            Maskable::Masked(value) => (value.peek().clone(), true),
+            Maskable::Normal(value) => (value.clone(), false),
+        };
+        Self {
+            name: name.clone(),
+            value,
+            is_masked,
+        }
+    }
+}
+
+impl From<StoredHeader> for (String, Maskable<String>) {
+    fn from(header: StoredHeader) -> Self {
+        let value = if header.is_masked {
+            Maskable::new_masked(Secret::new(header.value))
+        } else {
+            Maskable::new_normal(header.value)
+        };
+        (header.name, value)
+    }
+}
+
+/// Reads the idempotency key of a request from the `Idempotency-Key` header
+pub fn get_idempotency_key(headers: &HeaderMap) -> RouterResult<Option<String>> {
+    let Some(header_value) = headers.get(headers::IDEMPOTENCY_KEY) else {
+        return Ok(None);
+    };
+    let idempotency_key = header_value
+        .to_str()
+        .change_context(ApiErrorResponse::InvalidRequestData {
+            message: format!("`{}` header is invalid", headers::IDEMPOTENCY_KEY),
+        })?
+        .trim();
+
+    if idempotency_key.is_empty() || idempotency_key.len() > consts::MAX_IDEMPOTENCY_KEY_LENGTH {
+        return Err(report!(ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`{}` header must have between 1 and {} characters",
+                headers::IDEMPOTENCY_KEY,
+                consts::MAX_IDEMPOTENCY_KEY_LENGTH
+            ),
+        }));
+    }
+
+    Ok(Some(idempotency_key.to_owned()))
+}
+
+/// Processes a request made with an idempotency key only once. The response of the first request
+/// made with a key is stored, and is replayed for the requests made with the same key and the same
+/// body until it expires. Reusing a key with a different body, or while the first request is still
+/// being processed, is rejected. Failed requests are not stored, so that they can be retried.
+pub async fn process_idempotently<T, Q, F, Fut>(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    flow: Flow,
+    idempotency_key: Option<String>,
+    request: T,
+    func: F,
+) -> RouterResponse<IdempotentResponse<Q>>
+where
+    T: serde::Serialize,
+    Q: serde::Serialize,
+    F: FnOnce(T) -> Fut,
+    Fut: Future<Output = RouterResponse<Q>>,
+{
+    let Some(idempotency_key) = idempotency_key else {
+        return func(request).await.map(into_idempotent_response);
+    };
+
+    let request_hash = get_request_hash(flow, &request)?;
+    let redis_key = RedisKey::from(format!(
+        "{}{}_{}",
+        consts::IDEMPOTENCY_KEY_PREFIX,
+        merchant_id.get_string_repr(),
+        idempotency_key
+    ));
+    let redis_conn = state
+        .store()
+        .get_redis_conn()
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    // The key is claimed until the request is processed. The claim only expires on its own if the
+    // request never completes, so its TTL is well above the time taken to process a request.
+    let claim_result = redis_conn
+        .serialize_and_set_key_if_not_exist(
+            &redis_key,
+            IdempotencyRecord::InProgress {
+                request_hash: request_hash.clone(),
+            },
+            Some(state.conf.idempotency.in_progress_ttl_secs),
+        )
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to claim the idempotency key")?;
+
+    if claim_result == SetnxReply::KeyNotSet {
+        let record = redis_conn
+            .get_and_deserialize_key::<IdempotencyRecord>(&redis_key, "IdempotencyRecord")
+            .await
+            .change_context(ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the idempotency record")?;
+
+        return get_stored_response(record, &request_hash, idempotency_key);
+    }
+
+    let result = func(request).await;
+
+    let store_result = match get_completed_record(&result, request_hash) {
+        Some(record) => redis_conn
+            .serialize_and_set_key_with_expiry(&redis_key, record, state.conf.idempotency.ttl_secs)
+            .await
+            .map(|_| ()),
+        None => redis_conn.delete_key(&redis_key).await.map(|_| ()),
+    };
+    if let Err(error) = store_result {
+        logger::error!(?error, %idempotency_key, "Failed to update the idempotency record");
+    }
+
+    result.map(into_idempotent_response)
+}
+
+/// Response of a request whose key is already claimed by an earlier request
+fn get_stored_response<Q>(
+    record: IdempotencyRecord,
+    request_hash: &str,
+    idempotency_key: String,
+) -> RouterResponse<IdempotentResponse<Q>> {
+    match record {
+        IdempotencyRecord::InProgress {
+            request_hash: stored_request_hash,
+        }
+        | IdempotencyRecord::Completed {
+            request_hash: stored_request_hash,
+            ..
+        } if stored_request_hash != request_hash => {
+            Err(report!(ApiErrorResponse::IdempotencyKeyReused {
+                idempotency_key
+            }))
+        }
+        IdempotencyRecord::InProgress { .. } => {
+            Err(report!(ApiErrorResponse::IdempotencyKeyInProgress {
+                idempotency_key
+            }))
+        }
+        IdempotencyRecord::Completed {
+            response, headers, ..
+        } => {
+            logger::info!(%idempotency_key, "Replaying the stored response of the request");
+            let response = IdempotentResponse::Replayed(response);
+            Ok(if headers.is_empty() {
+                ApplicationResponse::Json(response)
+            } else {
+                ApplicationResponse::JsonWithHeaders((
+                    response,
+                    headers.into_iter().map(Into::into).collect(),
+                ))
+            })
+        }
+    }
+}
+
+/// Record of a processed request, if its response is to be replayed
+fn get_completed_record<Q: serde::Serialize>(
+    result: &RouterResponse<Q>,
+    request_hash: String,
+) -> Option<IdempotencyRecord> {
+    let (response, headers) = match result {
+        Ok(ApplicationResponse::Json(response)) => (response, Vec::new()),
+        Ok(ApplicationResponse::JsonWithHeaders((response, headers))) => {
+            (response, headers.iter().map(StoredHeader::from).collect())
+        }
+        _ => return None,
+    };
+    let response = serde_json::to_value(response)
+        .map_err(|error| logger::error!(?error, "Failed to serialize the response"))
+        .ok()?;
+
+    Some(IdempotencyRecord::Completed {
+        request_hash,
+        response,
+        headers,
+    })
+}
+
+/// The flow is a part of the hash, so that a key can't be reused for a different operation
+fn get_request_hash<T: serde::Serialize>(flow: Flow, request: &T) -> RouterResult<String> {
+    let request_bytes = serde_json::to_vec(&(flow.to_string(), request))
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the request")?;
+    let digest = Sha256
+        .generate_digest(&request_bytes)
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate the digest of the request")?;
+    Ok(hex::encode(digest))
+}
+
+fn into_idempotent_response<Q>(
+    response: ApplicationResponse<Q>,
+) -> ApplicationResponse<IdempotentResponse<Q>> {
+    match response {
+        ApplicationResponse::Json(response) => {
+            ApplicationResponse::Json(IdempotentResponse::Processed(response))
+        }
+        ApplicationResponse::JsonWithHeaders((response, headers)) => {
+            ApplicationResponse::JsonWithHeaders((IdempotentResponse::Processed(response), headers))
+        }
+        ApplicationResponse::StatusOk => ApplicationResponse::StatusOk,
+        ApplicationResponse::TextPlain(text) => ApplicationResponse::TextPlain(text),
+        ApplicationResponse::JsonForRedirection(response) => {
+            ApplicationResponse::JsonForRedirection(response)
+        }
+        ApplicationResponse::Form(form) => ApplicationResponse::Form(form),
+        ApplicationResponse::PaymentLinkForm(form) => ApplicationResponse::PaymentLinkForm(form),
+        ApplicationResponse::FileData(file_data) => ApplicationResponse::FileData(file_data),
+        ApplicationResponse::GenericLinkForm(form) => ApplicationResponse::GenericLinkForm(form),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used, clippy::indexing_slicing)]
+
+    use super::*;
+
+    fn completed_record(headers: Vec<StoredHeader>) -> IdempotencyRecord {
+        IdempotencyRecord::Completed {
+            request_hash: "hash".to_string(),
+            response: serde_json::json!({ "payment_id": "pay_1" }),
+            headers,
+        }
+    }
+
+    #[test]
+    fn test_completed_request_is_replayed_with_its_headers() {
+        let result: RouterResponse<serde_json::Value> = Ok(ApplicationResponse::JsonWithHeaders((
+            serde_json::json!({ "payment_id": "pay_1" }),
+            vec![
+                (
+                    "x-request-id".to_string(),
+                    Maskable::new_normal("req_1".to_string()),
+                ),
+                (
+                    "x-client-secret".to_string(),
+                    Maskable::new_masked(Secret::new("secret".to_string())),
+                ),
+            ],
+        )));
+        let record = get_completed_record(&result, "hash".to_string()).unwrap();
+
+        let replayed =
+            get_stored_response::<serde_json::Value>(record, "hash", "key".to_string()).unwrap();
+
+        let ApplicationResponse::JsonWithHeaders((IdempotentResponse::Replayed(body), headers)) =
+            replayed
+        else {
+            panic!("expected a replayed response with headers");
+        };
+        assert_eq!(body, serde_json::json!({ "payment_id": "pay_1" }));
+        assert_eq!(headers.len(), 2);
+        assert_eq!(headers[0].0, "x-request-id");
+        assert!(headers[0].1.is_normal());
+        assert_eq!(headers[0].1.clone().into_inner(), "req_1");
+        assert!(headers[1].1.is_masked());
+        assert_eq!(headers[1].1.clone().into_inner(), "secret");
+    }
+
+    #[test]
+    fn test_completed_request_without_headers_is_replayed_as_json() {
+        let replayed = get_stored_response::<serde_json::Value>(
+            completed_record(vec![]),
+            "hash",
+            "key".into(),
+        )
+        .unwrap();
+
+        assert!(matches!(
+            replayed,
+            ApplicationResponse::Json(IdempotentResponse::Replayed(_))
+        ));
+    }
+
+    #[test]
+    fn test_record_without_headers_is_deserialized() {
+        let record: IdempotencyRecord = serde_json::from_value(serde_json::json!({
+            "status": "completed",
+            "request_hash": "hash",
+            "response": {},
+        }))
+        .unwrap();
+
+        assert!(matches!(
+            record,
+            IdempotencyRecord::Completed { headers, .. } if headers.is_empty()
+        ));
+    }
+
+    #[test]
+    fn test_key_reused_with_a_different_body_is_rejected() {
+        for record in [
+            completed_record(vec![]),
+            IdempotencyRecord::InProgress {
+                request_hash: "hash".to_string(),
+            },
+        ] {
+            let error =
+                get_stored_response::<serde_json::Value>(record, "other_hash", "key".into())
+                    .unwrap_err();
+
+            assert!(matches!(
+                error.current_context(),
+                ApiErrorResponse::IdempotencyKeyReused { .. }
+            ));
+        }
+    }
+
+    #[test]
+    fn test_key_of_an_in_progress_request_is_rejected() {
+        let record = IdempotencyRecord::InProgress {
+            request_hash: "hash".to_string(),
+        };
+
+        let error =
+            get_stored_response::<serde_json::Value>(record, "hash", "key".into()).unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            ApiErrorResponse::IdempotencyKeyInProgress { .. }
+        ));
+    }
+
+    #[test]
+    fn test_failed_request_is_not_stored() {
+        let result: RouterResponse<serde_json::Value> =
+            Err(report!(ApiErrorResponse::InternalServerError));
+
+        assert!(get_completed_record(&result, "hash".to_string()).is_none());
+        assert!(get_completed_record(
+            &Ok::<_, error_stack::Report<ApiErrorResponse>>(
+                ApplicationResponse::<serde_json::Value>::StatusOk
+            ),
+            "hash".to_string()
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_request_hash_depends_on_the_flow_and_the_body() {
+        let body = serde_json::json!({ "amount": 100 });
+        let hash = get_request_hash(Flow::PaymentsCreate, &body).unwrap();
+
+        assert_eq!(hash, get_request_hash(Flow::PaymentsCreate, &body).unwrap());
+        assert_ne!(hash, get_request_hash(Flow::RefundsCreate, &body).unwrap());
+        assert_ne!(
+            hash,
+            get_request_hash(Flow::PaymentsCreate, &serde_json::json!({ "amount": 200 })).unwrap()
+        );
+    }
+}
@@ -6,6 +6,7 @@ use events::{EventsError, Message, MessagingInterface};
 use num_traits::ToPrimitive;
 use rdkafka::{
     config::FromClientConfig,
+    consumer::{Consumer, StreamConsumer},
     message::{Header, OwnedHeaders},
     producer::{BaseRecord, DefaultProducerContext, Producer, ThreadedProducer},
 };
@@ -167,6 +168,25 @@ pub struct KafkaSettings {
 }
 
 impl KafkaSettings {
+    /// Creates a consumer of the consolidated events topic which only receives the events
+    /// produced after it subscribes. Every consumer is in a consumer group of its own, hence each
+    /// consumer receives all the events.
+    pub fn create_consolidated_events_consumer(&self, group_id: &str) -> MQResult<StreamConsumer> {
+        let consumer: StreamConsumer = rdkafka::ClientConfig::new()
+            .set("bootstrap.servers", self.brokers.join(","))
+            .set("group.id", group_id)
+            .set("auto.offset.reset", "latest")
+            .set("enable.auto.commit", "false")
+            .create()
+            .change_context(KafkaError::InitializationError)?;
+        consumer
+            .subscribe(&[self.consolidated_events_topic.as_str()])
+            .change_context(KafkaError::InitializationError)
+            .attach_printable("Failed to subscribe to the consolidated events topic")?;
+
+        Ok(consumer)
+    }
+
     pub fn validate(&self) -> Result<(), crate::core::errors::ApplicationError> {
         use common_utils::ext_traits::ConfigExt;
 
@@ -38,6 +38,8 @@ pub struct KafkaDisputeEvent<'a> {
     pub profile_id: Option<&'a common_utils::id_type::ProfileId>,
     pub merchant_connector_id: Option<&'a common_utils::id_type::MerchantConnectorAccountId>,
     pub organization_id: &'a common_utils::id_type::OrganizationId,
+    #[serde(default, with = "time::serde::timestamp::nanoseconds::option")]
+    pub evidence_submitted_at: Option<OffsetDateTime>,
 }
 
 impl<'a> KafkaDisputeEvent<'a> {
@@ -80,6 +82,7 @@ impl<'a> KafkaDisputeEvent<'a> {
             profile_id: dispute.profile_id.as_ref(),
             merchant_connector_id: dispute.merchant_connector_id.as_ref(),
             organization_id: &dispute.organization_id,
+            evidence_submitted_at: dispute.evidence_submitted_at.map(|i| i.assume_utc()),
         }
     }
 }
@@ -0,0 +1,532 @@
+use std::{collections::HashMap, fmt, sync::Arc};
+#[cfg(feature = "keymanager_mtls")]
+use std::{
+    path::Path,
+    sync::{PoisonError, RwLock},
+    time::Duration,
+};
+
+use base64::Engine;
+use common_utils::{
+    consts::BASE64_ENGINE,
+    crypto::{self, DecodeMessage, EncodeMessage},
+    errors::{CustomResult, KeyManagerClientError},
+    keymanager::KeyManagerInterface,
+    types::keymanager::{
+        BatchDecryptDataRequest, BatchDecryptDataResponse, BatchEncryptDataRequest,
+        BatchEncryptDataResponse, DataKeyCreateResponse, DecryptDataRequest, DecryptDataResponse,
+        DecryptedData, DecryptedDataGroup, EncryptDataRequest, EncryptDataResponse, EncryptedData,
+        EncryptedDataGroup, EncryptionCreateRequest, EncryptionRotateRequest,
+        EncryptionTransferRequest,
+    },
+};
+use error_stack::{report, ResultExt};
+use hyperswitch_interfaces::encryption_interface::{
+    EncryptionError, EncryptionManagementInterface,
+};
+use masking::{PeekInterface, Secret, StrongSecret};
+#[cfg(feature = "keymanager_mtls")]
+use router_env::logger;
+use router_env::{instrument, tracing};
+
+use crate::configs::settings::KeyManagerBackendConfig;
+#[cfg(feature = "keymanager_mtls")]
+use crate::{
+    configs::settings::{KeyManagerCertificateReloadConfig, KeyManagerConfig},
+    core::errors::ApplicationError,
+};
+
+/// Version prefix of the values encrypted by [`KmsKeyManager`]
+const KMS_ENCRYPTION_VERSION: &str = "kms";
+
+impl KeyManagerBackendConfig {
+    /// Builds the backend serving the key manager requests in the application, `None` if the
+    /// requests are to be sent to the key manager service
+    pub async fn get_key_manager_backend(
+        &self,
+    ) -> CustomResult<Option<Arc<dyn KeyManagerInterface>>, EncryptionError> {
+        Ok(match self {
+            Self::KeyManagerService => None,
+            Self::Kms { kms } => Some(Arc::new(KmsKeyManager::new(
+                kms.get_encryption_management_client().await?,
+            ))),
+        })
+    }
+}
+
+/// Serves the key manager requests with envelope encryption. Every request encrypts its data
+/// with a freshly generated data key, which is wrapped by the KMS and stored along with the
+/// ciphertext as `kms:{base64_encoded_wrapped_key}:{base64_encoded_ciphertext}`. As the KMS holds
+/// the only long lived key, there are no data keys to be created, transferred or rotated.
+pub struct KmsKeyManager {
+    kms: Arc<dyn EncryptionManagementInterface>,
+}
+
+impl fmt::Debug for KmsKeyManager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KmsKeyManager").finish_non_exhaustive()
+    }
+}
+
+impl KmsKeyManager {
+    pub fn new(kms: Arc<dyn EncryptionManagementInterface>) -> Self {
+        Self { kms }
+    }
+
+    async fn generate_data_key(
+        &self,
+    ) -> CustomResult<(StrongSecret<Vec<u8>>, String), KeyManagerClientError> {
+        let data_key = crypto::generate_cryptographically_secure_random_bytes::<32>();
+        // The data key is base64 encoded before wrapping, as KMS clients may expect the
+        // decrypted output to be valid UTF-8
+        let wrapped_key = self
+            .kms
+            .encrypt(BASE64_ENGINE.encode(data_key).as_bytes())
+            .await
+            .change_context(KeyManagerClientError::InternalServerError(
+                "Failed to wrap the data key with the KMS".into(),
+            ))?;
+
+        Ok((
+            StrongSecret::new(data_key.to_vec()),
+            BASE64_ENGINE.encode(wrapped_key),
+        ))
+    }
+
+    async fn unwrap_data_key(
+        &self,
+        wrapped_key: &str,
+    ) -> CustomResult<StrongSecret<Vec<u8>>, KeyManagerClientError> {
+        let wrapped_key = BASE64_ENGINE
+            .decode(wrapped_key)
+            .change_context(bad_request("Invalid wrapped data key"))?;
+        let data_key = self.kms.decrypt(&wrapped_key).await.change_context(
+            KeyManagerClientError::InternalServerError(
+                "Failed to unwrap the data key with the KMS".into(),
+            ),
+        )?;
+
+        BASE64_ENGINE
+            .decode(data_key)
+            .map(StrongSecret::new)
+            .change_context(KeyManagerClientError::InternalServerError(
+                "Invalid data key returned by the KMS".into(),
+            ))
+    }
+
+    /// Unwraps the distinct data keys of the given encrypted values with one KMS call each
+    async fn unwrap_data_keys<'a>(
+        &self,
+        values: impl Iterator<Item = &'a EnvelopeEncryptedValue>,
+    ) -> CustomResult<HashMap<String, StrongSecret<Vec<u8>>>, KeyManagerClientError> {
+        let mut wrapped_keys = values
+            .map(|value| value.wrapped_key.clone())
+            .collect::<Vec<_>>();
+        wrapped_keys.sort_unstable();
+        wrapped_keys.dedup();
+
+        let data_keys = futures::future::try_join_all(
+            wrapped_keys
+                .iter()
+                .map(|wrapped_key| self.unwrap_data_key(wrapped_key)),
+        )
+        .await?;
+
+        Ok(wrapped_keys.into_iter().zip(data_keys).collect())
+    }
+}
+
+struct EnvelopeEncryptedValue {
+    wrapped_key: String,
+    ciphertext: Vec<u8>,
+}
+
+impl EnvelopeEncryptedValue {
+    fn encrypt(
+        data_key: &[u8],
+        wrapped_key: &str,
+        data: DecryptedData,
+    ) -> CustomResult<EncryptedData, KeyManagerClientError> {
+        let ciphertext = crypto::GcmAes256
+            .encode_message(data_key, data.inner().peek())
+            .change_context(KeyManagerClientError::InternalServerError(
+                "Failed to encrypt the data".into(),
+            ))?;
+        let encoded = format!(
+            "{KMS_ENCRYPTION_VERSION}:{wrapped_key}:{}",
+            BASE64_ENGINE.encode(ciphertext)
+        );
+
+        Ok(EncryptedData {
+            data: StrongSecret::new(encoded.into_bytes()),
+        })
+    }
+
+    fn parse(encrypted: &StrongSecret<String>) -> CustomResult<Self, KeyManagerClientError> {
+        let mut parts = encrypted.peek().splitn(3, ':');
+        let (Some(KMS_ENCRYPTION_VERSION), Some(wrapped_key), Some(ciphertext)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(report!(bad_request(
+                "Data is not encrypted by the KMS key manager"
+            )));
+        };
+
+        Ok(Self {
+            wrapped_key: wrapped_key.to_string(),
+            ciphertext: BASE64_ENGINE
+                .decode(ciphertext)
+                .change_context(bad_request("Invalid ciphertext"))?,
+        })
+    }
+
+    fn decrypt(self, data_key: &[u8]) -> CustomResult<DecryptedData, KeyManagerClientError> {
+        crypto::GcmAes256
+            .decode_message(data_key, Secret::new(self.ciphertext))
+            .map(|data| DecryptedData::from_data(StrongSecret::new(data)))
+            .change_context(bad_request("Failed to decrypt the data"))
+    }
+}
+
+fn bad_request(message: &'static str) -> KeyManagerClientError {
+    KeyManagerClientError::BadRequest(message.into())
+}
+
+#[async_trait::async_trait]
+impl KeyManagerInterface for KmsKeyManager {
+    async fn create_key(
+        &self,
+        request: EncryptionCreateRequest,
+    ) -> CustomResult<DataKeyCreateResponse, KeyManagerClientError> {
+        Ok(DataKeyCreateResponse {
+            identifier: request.identifier,
+            key_version: KMS_ENCRYPTION_VERSION.to_string(),
+        })
+    }
+
+    async fn transfer_key(
+        &self,
+        request: EncryptionTransferRequest,
+    ) -> CustomResult<DataKeyCreateResponse, KeyManagerClientError> {
+        Ok(DataKeyCreateResponse {
+            identifier: request.identifier,
+            key_version: KMS_ENCRYPTION_VERSION.to_string(),
+        })
+    }
+
+    async fn rotate_key(
+        &self,
+        _request: EncryptionRotateRequest,
+    ) -> CustomResult<DataKeyCreateResponse, KeyManagerClientError> {
+        Err(report!(bad_request(
+            "Keys are rotated in the KMS when using the KMS key manager"
+        )))
+    }
+
+    #[instrument(skip_all)]
+    async fn encrypt_data(
+        &self,
+        request: EncryptDataRequest,
+    ) -> CustomResult<EncryptDataResponse, KeyManagerClientError> {
+        let (data_key, wrapped_key) = self.generate_data_key().await?;
+
+        Ok(EncryptDataResponse {
+            data: EnvelopeEncryptedValue::encrypt(data_key.peek(), &wrapped_key, request.data)?,
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn batch_encrypt_data(
+        &self,
+        request: BatchEncryptDataRequest,
+    ) -> CustomResult<BatchEncryptDataResponse, KeyManagerClientError> {
+        let (data_key, wrapped_key) = self.generate_data_key().await?;
+        let data = request
+            .data
+            .0
+            .into_iter()
+            .map(|(key, value)| {
+                EnvelopeEncryptedValue::encrypt(data_key.peek(), &wrapped_key, value)
+                    .map(|encrypted| (key, encrypted))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(BatchEncryptDataResponse {
+            data: EncryptedDataGroup(data),
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn decrypt_data(
+        &self,
+        request: DecryptDataRequest,
+    ) -> CustomResult<DecryptDataResponse, KeyManagerClientError> {
+        let value = EnvelopeEncryptedValue::parse(&request.data)?;
+        let data_key = self.unwrap_data_key(&value.wrapped_key).await?;
+
+        Ok(DecryptDataResponse {
+            data: value.decrypt(data_key.peek())?,
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn batch_decrypt_data(
+        &self,
+        request: BatchDecryptDataRequest,
+    ) -> CustomResult<BatchDecryptDataResponse, KeyManagerClientError> {
+        let values = request
+            .data
+            .iter()
+            .map(|(key, value)| {
+                EnvelopeEncryptedValue::parse(value).map(|value| (key.clone(), value))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        // Values encrypted in the same batch share the data key, which is unwrapped only once
+        let data_keys = self
+            .unwrap_data_keys(values.iter().map(|(_, value)| value))
+            .await?;
+
+        let data = values
+            .into_iter()
+            .map(|(key, value)| {
+                let data_key = data_keys.get(&value.wrapped_key).ok_or_else(|| {
+                    report!(KeyManagerClientError::InternalServerError(
+                        "Data key was not unwrapped".into()
+                    ))
+                })?;
+                value.decrypt(data_key.peek()).map(|data| (key, data))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(BatchDecryptDataResponse {
+            data: DecryptedDataGroup(data),
+        })
+    }
+}
+
+/// mTLS certificate and CA presented to the key manager service
+#[cfg(feature = "keymanager_mtls")]
+#[derive(Debug, Clone)]
+pub struct KeyManagerMtlsCertificates {
+    pub cert: Secret<String>,
+    pub ca: Secret<String>,
+}
+
+/// Holds the mTLS certificates of the key manager service, shared by all the sessions. When the
+/// certificates are read from files, they are reloaded on change, and the key manager client is
+/// rebuilt with the new certificates on the next request.
+#[cfg(feature = "keymanager_mtls")]
+#[derive(Debug, Clone)]
+pub struct KeyManagerCertificates {
+    certificates: Arc<RwLock<KeyManagerMtlsCertificates>>,
+    reload_config: KeyManagerCertificateReloadConfig,
+}
+
+#[cfg(feature = "keymanager_mtls")]
+impl KeyManagerCertificates {
+    /// Uses the certificates configured in `key_manager`, unless they are to be read from files
+    pub async fn new(config: &KeyManagerConfig) -> CustomResult<Self, ApplicationError> {
+        let certificates = Self {
+            certificates: Arc::new(RwLock::new(KeyManagerMtlsCertificates {
+                cert: config.cert.clone(),
+                ca: config.ca.clone(),
+            })),
+            reload_config: config.certificate_reload.clone(),
+        };
+        certificates.reload().await?;
+
+        Ok(certificates)
+    }
+
+    pub fn get(&self) -> KeyManagerMtlsCertificates {
+        self.certificates
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Reads the certificates from the configured files, returning whether they have changed.
+    /// The certificates are replaced only if both of them are valid, so that a partially written
+    /// file does not break the key manager client.
+    pub async fn reload(&self) -> CustomResult<bool, ApplicationError> {
+        let current = self.get();
+
+        let cert = match &self.reload_config.cert_path {
+            Some(path) => {
+                let cert = read_certificate_file(path).await?;
+                reqwest::Identity::from_pem(cert.as_bytes())
+                    .change_context(ApplicationError::ConfigurationError)
+                    .attach_printable_lazy(|| {
+                        format!("Invalid key manager certificate in {}", path.display())
+                    })?;
+                Secret::new(cert)
+            }
+            None => current.cert.clone(),
+        };
+
+        let ca = match &self.reload_config.ca_path {
+            Some(path) => {
+                let ca = read_certificate_file(path).await?;
+                reqwest::Certificate::from_pem(ca.as_bytes())
+                    .change_context(ApplicationError::ConfigurationError)
+                    .attach_printable_lazy(|| {
+                        format!("Invalid key manager CA in {}", path.display())
+                    })?;
+                Secret::new(ca)
+            }
+            None => current.ca.clone(),
+        };
+
+        if cert.peek() == current.cert.peek() && ca.peek() == current.ca.peek() {
+            return Ok(false);
+        }
+
+        *self
+            .certificates
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = KeyManagerMtlsCertificates { cert, ca };
+
+        Ok(true)
+    }
+
+    /// Spawns a task checking the certificate files for changes at the configured interval
+    pub fn spawn_watcher(&self) {
+        if self.reload_config.cert_path.is_none() && self.reload_config.ca_path.is_none() {
+            return;
+        }
+
+        let certificates = self.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(
+                certificates.reload_config.interval_in_secs,
+            ));
+            // The certificates have already been loaded during startup
+            interval.tick().await;
+
+            loop {
+                interval.tick().await;
+                match certificates.reload().await {
+                    Ok(true) => logger::info!("Reloaded the key manager mTLS certificates"),
+                    Ok(false) => {}
+                    Err(error) => {
+                        logger::error!(?error, "Failed to reload the key manager mTLS certificates")
+                    }
+                }
+            }
+        });
+    }
+}
+
+#[cfg(feature = "keymanager_mtls")]
+async fn read_certificate_file(path: &Path) -> CustomResult<String, ApplicationError> {
+    tokio::fs::read_to_string(path)
+        .await
+        .change_context(ApplicationError::ConfigurationError)
+        .attach_printable_lazy(|| format!("Failed to read {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+
+    use common_utils::{id_type, types::keymanager::Identifier};
+    use external_services::no_encryption::core::NoEncryption;
+    use rustc_hash::FxHashMap;
+
+    use super::*;
+
+    fn identifier() -> Identifier {
+        Identifier::Merchant(
+            id_type::MerchantId::try_from(std::borrow::Cow::from("merchant1")).unwrap(),
+        )
+    }
+
+    fn decrypted(data: &str) -> DecryptedData {
+        DecryptedData::from_data(StrongSecret::new(data.as_bytes().to_vec()))
+    }
+
+    fn encrypted(data: EncryptedData) -> StrongSecret<String> {
+        StrongSecret::new(String::from_utf8(data.data.peek().clone()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_envelope_encryption_round_trip() {
+        let key_manager = KmsKeyManager::new(Arc::new(NoEncryption));
+
+        let encrypted_data = key_manager
+            .encrypt_data(EncryptDataRequest {
+                identifier: identifier(),
+                data: decrypted("4242424242424242"),
+            })
+            .await
+            .expect("Failed to encrypt data")
+            .data;
+        let decrypted_data = key_manager
+            .decrypt_data(DecryptDataRequest {
+                identifier: identifier(),
+                data: encrypted(encrypted_data),
+            })
+            .await
+            .expect("Failed to decrypt data")
+            .data;
+
+        assert_eq!(decrypted_data.inner().peek(), b"4242424242424242");
+    }
+
+    #[tokio::test]
+    async fn test_batch_envelope_encryption_round_trip() {
+        let key_manager = KmsKeyManager::new(Arc::new(NoEncryption));
+        let data = FxHashMap::from_iter([
+            ("name".to_string(), decrypted("John Doe")),
+            ("email".to_string(), decrypted("john@example.com")),
+        ]);
+
+        let encrypted_data = key_manager
+            .batch_encrypt_data(BatchEncryptDataRequest {
+                identifier: identifier(),
+                data: DecryptedDataGroup(data),
+            })
+            .await
+            .expect("Failed to encrypt data")
+            .data
+            .0
+            .into_iter()
+            .map(|(key, value)| (key, encrypted(value)))
+            .collect();
+        let decrypted_data = key_manager
+            .batch_decrypt_data(BatchDecryptDataRequest {
+                identifier: identifier(),
+                data: encrypted_data,
+            })
+            .await
+            .expect("Failed to decrypt data")
+            .data
+            .0;
+
+        assert_eq!(
+            decrypted_data.get("name").unwrap().clone().inner().peek(),
+            b"John Doe"
+        );
+        assert_eq!(
+            decrypted_data.get("email").unwrap().clone().inner().peek(),
+            b"john@example.com"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_decrypt_rejects_data_not_encrypted_by_kms() {
+        let key_manager = KmsKeyManager::new(Arc::new(NoEncryption));
+
+        let result = key_manager
+            .decrypt_data(DecryptDataRequest {
+                identifier: identifier(),
+                data: StrongSecret::new("v1:c2VjcmV0".to_string()),
+            })
+            .await;
+
+        assert!(matches!(
+            result.map_err(|err| err.current_context().to_string()),
+            Err(message) if message.starts_with("Bad request")
+        ));
+    }
+}
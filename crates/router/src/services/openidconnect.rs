@@ -4,7 +4,7 @@ use external_services::http_client::client;
 use masking::{ExposeInterface, Secret};
 use oidc::TokenResponse;
 use openidconnect::{self as oidc, core as oidc_core};
-use redis_interface::RedisConnectionPool;
+use redis_interface::{errors::RedisError, RedisConnectionPool};
 use storage_impl::errors::ApiClientError;
 
 use crate::{
@@ -14,6 +14,14 @@ use crate::{
     types::domain::user::UserEmail,
 };
 
+/// The values bound to an authorization request, which are needed to complete the sign in once
+/// the OpenID provider redirects the user back.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct OidcAuthorizationState {
+    nonce: String,
+    pkce_verifier: Secret<String>,
+}
+
 pub async fn get_authorization_url(
     state: SessionState,
     redirect_url: String,
@@ -22,6 +30,7 @@ pub async fn get_authorization_url(
     client_id: Secret<String>,
 ) -> UserResult<url::Url> {
     let discovery_document = get_discovery_document(base_url, &state).await?;
+    let (pkce_challenge, pkce_verifier) = oidc::PkceCodeChallenge::new_random_sha256();
 
     let (auth_url, csrf_token, nonce) =
         get_oidc_core_client(discovery_document, client_id, None, redirect_url)?
@@ -31,15 +40,24 @@ pub async fn get_authorization_url(
                 oidc::Nonce::new_random,
             )
             .add_scope(oidc::Scope::new("email".to_string()))
+            .set_pkce_challenge(pkce_challenge)
             .url();
 
-    // Save csrf & nonce as key value respectively
+    // Save the nonce & pkce verifier against the csrf
     let key = get_oidc_redis_key(csrf_token.secret());
+    let authorization_state = OidcAuthorizationState {
+        nonce: nonce.secret().to_owned(),
+        pkce_verifier: Secret::new(pkce_verifier.secret().to_owned()),
+    };
     get_redis_connection_for_global_tenant(&state)?
-        .set_key_with_expiry(&key.into(), nonce.secret(), consts::user::REDIS_SSO_TTL)
+        .serialize_and_set_key_with_expiry(
+            &key.into(),
+            authorization_state,
+            consts::user::REDIS_SSO_TTL,
+        )
         .await
         .change_context(UserErrors::InternalServerError)
-        .attach_printable("Failed to save csrf-nonce in redis")?;
+        .attach_printable("Failed to save oidc authorization state in redis")?;
 
     Ok(auth_url)
 }
@@ -53,7 +71,8 @@ pub async fn get_user_email_from_oidc_provider(
     authorization_code: Secret<String>,
     client_secret: Secret<String>,
 ) -> UserResult<UserEmail> {
-    let nonce = get_nonce_from_redis(state, &redirect_state).await?;
+    let authorization_state = get_authorization_state_from_redis(state, &redirect_state).await?;
+    let nonce = oidc::Nonce::new(authorization_state.nonce);
     let discovery_document = get_discovery_document(base_url, state).await?;
     let client = get_oidc_core_client(
         discovery_document,
@@ -74,6 +93,9 @@ pub async fn get_user_email_from_oidc_provider(
     // Send request to OpenId provider with authorization code
     let token_response = client
         .exchange_code(oidc::AuthorizationCode::new(authorization_code.expose()))
+        .set_pkce_verifier(oidc::PkceCodeVerifier::new(
+            authorization_state.pkce_verifier.expose(),
+        ))
         .request_async(|req| get_oidc_reqwest_client(state, req))
         .await
         .map_err(|e| match e {
@@ -142,20 +164,20 @@ fn get_oidc_core_client(
     )
 }
 
-async fn get_nonce_from_redis(
+async fn get_authorization_state_from_redis(
     state: &SessionState,
     redirect_state: &Secret<String>,
-) -> UserResult<oidc::Nonce> {
+) -> UserResult<OidcAuthorizationState> {
     let redis_connection = get_redis_connection_for_global_tenant(state)?;
     let redirect_state = redirect_state.clone().expose();
     let key = get_oidc_redis_key(&redirect_state);
     redis_connection
-        .get_key::<Option<String>>(&key.into())
+        .get_and_deserialize_key::<OidcAuthorizationState>(&key.into(), "OidcAuthorizationState")
         .await
-        .change_context(UserErrors::InternalServerError)
-        .attach_printable("Error Fetching CSRF from redis")?
-        .map(oidc::Nonce::new)
-        .ok_or(UserErrors::SSOFailed)
+        .map_err(|error| match error.current_context() {
+            RedisError::NotFound => error.change_context(UserErrors::SSOFailed),
+            _ => error.change_context(UserErrors::InternalServerError),
+        })
         .attach_printable("Cannot find csrf in redis. Csrf invalid or expired")
 }
 
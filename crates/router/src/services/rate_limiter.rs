@@ -0,0 +1,329 @@
+use common_utils::id_type;
+use error_stack::{report, ResultExt};
+use router_env::logger;
+
+use crate::{
+    configs::settings::{RateLimitBucket, RateLimitSettings},
+    consts,
+    core::errors::{ApiErrorResponse, RouterResult},
+    routes::{app::SessionStateInfo, lock_utils::ApiIdentifier, metrics, SessionState},
+    services::authentication::AuthenticationType,
+};
+
+/// Refills every bucket for the time elapsed since it was last used and takes a token from each
+/// of them, only if all of them have a token left. Returns the number of seconds after which each
+/// bucket will have a token again, which is 0 for the buckets which had a token left.
+const TOKEN_BUCKET_SCRIPT: &str = r#"
+    local time = redis.call("TIME")
+    local now = tonumber(time[1]) + tonumber(time[2]) / 1000000
+    local tokens = {}
+    local retry_after = {}
+    local is_allowed = true
+    for i = 1, #KEYS do
+        local capacity = tonumber(ARGV[2 * i - 1])
+        local refill_per_sec = tonumber(ARGV[2 * i])
+        local bucket = redis.call("HMGET", KEYS[i], "tokens", "updated_at")
+        local available = tonumber(bucket[1]) or capacity
+        local updated_at = tonumber(bucket[2]) or now
+        tokens[i] = math.min(capacity, available + math.max(0, now - updated_at) * refill_per_sec)
+        if tokens[i] < 1 then
+            retry_after[i] = math.ceil((1 - tokens[i]) / refill_per_sec)
+            is_allowed = false
+        else
+            retry_after[i] = 0
+        end
+    end
+    for i = 1, #KEYS do
+        local capacity = tonumber(ARGV[2 * i - 1])
+        local refill_per_sec = tonumber(ARGV[2 * i])
+        if is_allowed then
+            tokens[i] = tokens[i] - 1
+        end
+        redis.call("HSET", KEYS[i], "tokens", tostring(tokens[i]), "updated_at", tostring(now))
+        redis.call("EXPIRE", KEYS[i], math.ceil(capacity / refill_per_sec) + 1)
+    end
+    return retry_after
+"#;
+
+#[derive(Debug, Clone, Copy, PartialEq, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+enum RateLimitScope {
+    ApiKey,
+    Merchant,
+    RouteGroup,
+}
+
+/// Checks the request against the rate limits of the merchant it was authenticated for, the API
+/// key it was made with and the route group it belongs to. Requests not authenticated for a
+/// merchant are not rate limited, and requests are allowed if the limits can't be checked.
+pub async fn check_rate_limits(
+    state: &SessionState,
+    auth_type: &AuthenticationType,
+    api_identifier: Option<ApiIdentifier>,
+) -> RouterResult<()> {
+    let rate_limit = &state.conf.rate_limit;
+    if !rate_limit.enabled {
+        return Ok(());
+    }
+    let Some(merchant_id) = auth_type.get_merchant_id() else {
+        return Ok(());
+    };
+
+    let buckets = get_rate_limit_buckets(rate_limit, merchant_id, auth_type, api_identifier);
+    let retry_after = match consume_tokens(state, &buckets).await {
+        Ok(retry_after) => retry_after,
+        Err(error) => {
+            logger::error!(?error, "Failed to check rate limits");
+            metrics::RATE_LIMIT_CHECK_FAILURE.add(1, &[]);
+            return Ok(());
+        }
+    };
+
+    let mut retry_after_secs = 0;
+    for ((scope, _, _), bucket_retry_after) in buckets.iter().zip(retry_after) {
+        if bucket_retry_after > 0 {
+            metrics::RATE_LIMIT_EXCEEDED.add(
+                1,
+                router_env::metric_attributes!(("scope", scope.to_string())),
+            );
+            retry_after_secs = retry_after_secs.max(bucket_retry_after);
+        }
+    }
+
+    if retry_after_secs > 0 {
+        return Err(report!(ApiErrorResponse::TooManyRequests {
+            retry_after_secs
+        }))
+        .attach_printable(format!("Rate limit exceeded for merchant {merchant_id:?}"));
+    }
+
+    Ok(())
+}
+
+/// Buckets the request takes a token from, which are the bucket of the merchant, the bucket of
+/// the key the request was made with, and the bucket of the route group, if one is configured
+fn get_rate_limit_buckets(
+    rate_limit: &RateLimitSettings,
+    merchant_id: &id_type::MerchantId,
+    auth_type: &AuthenticationType,
+    api_identifier: Option<ApiIdentifier>,
+) -> Vec<(RateLimitScope, String, RateLimitBucket)> {
+    let mut buckets = vec![(
+        RateLimitScope::Merchant,
+        get_rate_limit_key(merchant_id, "merchant"),
+        rate_limit.merchant,
+    )];
+    // Signing keys are limited in the same way as API keys
+    let key_bucket = match auth_type {
+        AuthenticationType::ApiKey { key_id, .. } => {
+            Some(format!("api_key:{}", key_id.get_string_repr()))
+        }
+        AuthenticationType::SignedRequest { key_id, .. } => Some(format!("signing_key:{key_id}")),
+        _ => None,
+    };
+    if let Some(key_bucket) = key_bucket {
+        buckets.push((
+            RateLimitScope::ApiKey,
+            get_rate_limit_key(merchant_id, &key_bucket),
+            rate_limit.api_key,
+        ));
+    }
+    if let Some((route_group, bucket)) = api_identifier.and_then(|api_identifier| {
+        let route_group = api_identifier.to_string();
+        rate_limit
+            .route_groups
+            .get(&route_group)
+            .map(|bucket| (route_group, *bucket))
+    }) {
+        buckets.push((
+            RateLimitScope::RouteGroup,
+            get_rate_limit_key(merchant_id, &format!("route_group:{route_group}")),
+            bucket,
+        ));
+    }
+
+    buckets
+}
+
+async fn consume_tokens(
+    state: &SessionState,
+    buckets: &[(RateLimitScope, String, RateLimitBucket)],
+) -> RouterResult<Vec<u64>> {
+    let redis_conn = state
+        .store()
+        .get_redis_conn()
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    // Scripts are not given the tenant prefix of the connection, so it is added to the keys here
+    let keys = buckets
+        .iter()
+        .map(|(_, key, _)| redis_conn.add_prefix(key))
+        .collect::<Vec<_>>();
+    let values = buckets
+        .iter()
+        .flat_map(|(_, _, bucket)| [bucket.capacity, bucket.refill_per_sec])
+        .collect::<Vec<_>>();
+
+    redis_conn
+        .evaluate_redis_script(TOKEN_BUCKET_SCRIPT, keys, values)
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+}
+
+/// All the keys of a merchant share a hash tag, so that they are in the same slot when redis runs
+/// in cluster mode, which is required for a script to access them together.
+fn get_rate_limit_key(merchant_id: &id_type::MerchantId, bucket: &str) -> String {
+    format!(
+        "{}{}:{}",
+        consts::RATE_LIMIT_PREFIX,
+        redis_interface::hash_tag(merchant_id.get_string_repr()),
+        bucket
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used, clippy::indexing_slicing)]
+
+    use std::collections::HashMap;
+
+    use actix_web::{http::header, ResponseError};
+    use common_utils::errors::ErrorSwitch;
+    use redis_interface::{RedisConnectionPool, RedisSettings};
+
+    use super::*;
+
+    fn get_rate_limit_settings() -> RateLimitSettings {
+        RateLimitSettings {
+            enabled: true,
+            api_key: RateLimitBucket {
+                capacity: 10,
+                refill_per_sec: 1,
+            },
+            merchant: RateLimitBucket {
+                capacity: 100,
+                refill_per_sec: 10,
+            },
+            route_groups: HashMap::from([(
+                ApiIdentifier::Refunds.to_string(),
+                RateLimitBucket {
+                    capacity: 5,
+                    refill_per_sec: 1,
+                },
+            )]),
+        }
+    }
+
+    #[test]
+    fn test_get_rate_limit_buckets() {
+        let rate_limit = get_rate_limit_settings();
+        let merchant_id = id_type::MerchantId::get_merchant_id_not_found();
+        let auth_type = AuthenticationType::ApiKey {
+            merchant_id: merchant_id.clone(),
+            key_id: id_type::ApiKeyId::generate_key_id("key"),
+            scopes: None,
+        };
+
+        let buckets = get_rate_limit_buckets(
+            &rate_limit,
+            &merchant_id,
+            &auth_type,
+            Some(ApiIdentifier::Refunds),
+        );
+        let scopes = buckets
+            .iter()
+            .map(|(scope, _, _)| *scope)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            scopes,
+            vec![
+                RateLimitScope::Merchant,
+                RateLimitScope::ApiKey,
+                RateLimitScope::RouteGroup
+            ]
+        );
+        let (_, route_group_key, route_group_bucket) = buckets.last().unwrap();
+        assert!(route_group_key.ends_with(&format!("route_group:{}", ApiIdentifier::Refunds)));
+        assert_eq!(route_group_bucket.capacity, 5);
+
+        // Route groups without a configured limit only take from the merchant and key buckets
+        let buckets = get_rate_limit_buckets(
+            &rate_limit,
+            &merchant_id,
+            &auth_type,
+            Some(ApiIdentifier::Payments),
+        );
+        assert_eq!(buckets.len(), 2);
+
+        // Requests authenticated without a key only take from the merchant bucket
+        let buckets = get_rate_limit_buckets(
+            &rate_limit,
+            &merchant_id,
+            &AuthenticationType::MerchantJwt {
+                merchant_id: merchant_id.clone(),
+                user_id: None,
+            },
+            None,
+        );
+        assert_eq!(buckets.len(), 1);
+    }
+
+    #[test]
+    fn test_too_many_requests_retry_after_header() {
+        let error: api_models::errors::types::ApiErrorResponse =
+            ApiErrorResponse::TooManyRequests {
+                retry_after_secs: 7,
+            }
+            .switch();
+        let response = error.error_response();
+
+        assert_eq!(response.status().as_u16(), 429);
+        assert_eq!(response.headers().get(header::RETRY_AFTER).unwrap(), "7");
+    }
+
+    #[tokio::test]
+    async fn test_token_bucket_script() {
+        let retry_after = tokio::task::spawn_blocking(move || {
+            futures::executor::block_on(async {
+                let redis_conn = RedisConnectionPool::new(&RedisSettings::default())
+                    .await
+                    .expect("failed to create redis connection pool");
+                let keys = vec![
+                    "test_rate_limit_small".to_string(),
+                    "test_rate_limit_large".to_string(),
+                ];
+                let _ = redis_conn.delete_key(&keys[0].as_str().into()).await;
+                let _ = redis_conn.delete_key(&keys[1].as_str().into()).await;
+
+                // The small bucket holds 2 tokens and the large one 100, both refilled a token a second
+                let values = vec![2u32, 1, 100, 1];
+                let mut retry_after = Vec::new();
+                for _ in 0..3 {
+                    retry_after.push(
+                        redis_conn
+                            .evaluate_redis_script::<_, Vec<u64>>(
+                                TOKEN_BUCKET_SCRIPT,
+                                keys.clone(),
+                                values.clone(),
+                            )
+                            .await
+                            .unwrap(),
+                    );
+                }
+                let large_bucket_tokens = redis_conn
+                    .get_hash_field::<String>(&keys[1].as_str().into(), "tokens")
+                    .await
+                    .unwrap();
+                (retry_after, large_bucket_tokens)
+            })
+        })
+        .await
+        .expect("Spawn block failure");
+
+        let (retry_after, large_bucket_tokens) = retry_after;
+        assert_eq!(retry_after, vec![vec![0, 0], vec![0, 0], vec![1, 0]]);
+        // The rejected request doesn't take a token from the bucket which had one left
+        assert!(large_bucket_tokens.parse::<f64>().unwrap() >= 98.0);
+    }
+}
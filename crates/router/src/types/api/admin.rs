@@ -76,6 +76,7 @@ impl ForeignFrom<diesel_models::organization::Organization> for OrganizationResp
             modified_at: org.modified_at,
             created_at: org.created_at,
             organization_type: org.organization_type,
+            is_passkey_required: org.is_passkey_required.unwrap_or(false),
         }
     }
 }
@@ -120,6 +121,7 @@ impl ForeignTryFrom<domain::MerchantAccount> for MerchantAccountResponse {
             pm_collect_link_config,
             product_type: item.product_type,
             merchant_account_type: item.merchant_account_type,
+            allowed_ip_ranges: item.allowed_ip_ranges,
         })
     }
 }
@@ -146,6 +148,7 @@ impl ForeignTryFrom<domain::MerchantAccount> for MerchantAccountResponse {
             organization_id: item.organization_id,
             recon_status: item.recon_status,
             product_type: item.product_type,
+            allowed_ip_ranges: item.allowed_ip_ranges,
         })
     }
 }
@@ -169,6 +172,32 @@ impl ForeignTryFrom<domain::Profile> for ProfileResponse {
         let masked_outgoing_webhook_custom_http_headers =
             outgoing_webhook_custom_http_headers.map(MaskedHeaders::from_headers);
 
+        let mut webhook_endpoint_secrets = item
+            .webhook_endpoint_secrets
+            .map(|webhook_endpoint_secrets| {
+                webhook_endpoint_secrets
+                    .into_inner()
+                    .expose()
+                    .parse_value::<HashMap<
+                        String,
+                        diesel_models::business_profile::WebhookEndpointSecrets,
+                    >>("WebhookEndpointSecrets")
+            })
+            .transpose()?
+            .unwrap_or_default();
+        // Only the masked custom headers of the webhook endpoints are returned, the client
+        // certificate key is never returned
+        let webhook_details = item.webhook_details.map(|webhook_details| {
+            let mut webhook_details: WebhookDetails = webhook_details.foreign_into();
+            for webhook_endpoint in webhook_details.webhook_endpoints.iter_mut().flatten() {
+                webhook_endpoint.custom_headers = webhook_endpoint_secrets
+                    .remove(&webhook_endpoint.endpoint_id)
+                    .and_then(|secrets| secrets.custom_headers)
+                    .map(MaskedHeaders::mask_header_values);
+            }
+            webhook_details
+        });
+
         let card_testing_guard_config = item
             .card_testing_guard_config
             .or(Some(CardTestingGuardConfig::default()));
@@ -181,7 +210,7 @@ impl ForeignTryFrom<domain::Profile> for ProfileResponse {
             enable_payment_response_hash: item.enable_payment_response_hash,
             payment_response_hash_key: item.payment_response_hash_key,
             redirect_to_merchant_with_http_post: item.redirect_to_merchant_with_http_post,
-            webhook_details: item.webhook_details.map(ForeignInto::foreign_into),
+            webhook_details,
             metadata: item.metadata,
             routing_algorithm: item.routing_algorithm,
             intent_fulfillment_time: item.intent_fulfillment_time,
@@ -235,6 +264,13 @@ impl ForeignTryFrom<domain::Profile> for ProfileResponse {
             dispute_polling_interval: item.dispute_polling_interval,
             is_manual_retry_enabled: item.is_manual_retry_enabled,
             always_enable_overcapture: item.always_enable_overcapture,
+            retry_policy: item.retry_policy,
+            intent_expiry: item.intent_expiry,
+            refund_approval_threshold: item.refund_approval_threshold,
+            webhook_retry_policy: item.webhook_retry_policy,
+            event_sink_config: item.event_sink_config,
+            is_connector_audit_capture_enabled: item.is_connector_audit_capture_enabled,
+            soft_decline_retry_config: item.soft_decline_retry_config,
         })
     }
 }
@@ -345,14 +381,15 @@ pub async fn create_profile_from_merchant_account(
 
     let current_time = common_utils::date_time::now();
 
-    let webhook_details = request.webhook_details.map(ForeignInto::foreign_into);
-
     let payment_response_hash_key = request
         .payment_response_hash_key
         .or(merchant_account.payment_response_hash_key)
         .unwrap_or(common_utils::crypto::generate_cryptographically_secure_random_string(64));
 
     let payment_link_config = request.payment_link_config.map(ForeignInto::foreign_into);
+    core::admin::validate_outgoing_webhook_custom_http_headers(
+        request.outgoing_webhook_custom_http_headers.as_ref(),
+    )?;
     let key_manager_state = state.into();
     let outgoing_webhook_custom_http_headers = request
         .outgoing_webhook_custom_http_headers
@@ -368,6 +405,15 @@ pub async fn create_profile_from_merchant_account(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Unable to encrypt outgoing webhook custom HTTP headers")?;
 
+    let webhook_endpoint_secrets = core::admin::get_encrypted_webhook_endpoint_secrets(
+        &key_manager_state,
+        key_store,
+        request.webhook_details.as_ref(),
+        HashMap::new(),
+    )
+    .await?;
+    let webhook_details = request.webhook_details.map(ForeignInto::foreign_into);
+
     let payout_link_config = request
         .payout_link_config
         .map(|payout_conf| match payout_conf.config.validate() {
@@ -380,6 +426,54 @@ pub async fn create_profile_from_merchant_account(
         })
         .transpose()?;
 
+    if let Some(intent_expiry) = request.intent_expiry {
+        core::payments::helpers::validate_intent_expiry(intent_expiry)?;
+    }
+
+    let retry_policy = request
+        .retry_policy
+        .map(|retry_policy| match retry_policy.validate() {
+            Ok(_) => Ok(retry_policy),
+            Err(e) => Err(error_stack::report!(
+                errors::ApiErrorResponse::InvalidRequestData { message: e }
+            )),
+        })
+        .transpose()?;
+
+    let webhook_retry_policy = request
+        .webhook_retry_policy
+        .map(
+            |webhook_retry_policy| match webhook_retry_policy.validate() {
+                Ok(_) => Ok(webhook_retry_policy),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData { message: e }
+                )),
+            },
+        )
+        .transpose()?;
+
+    let event_sink_config = request
+        .event_sink_config
+        .map(|event_sink_config| match event_sink_config.validate() {
+            Ok(_) => Ok(event_sink_config),
+            Err(e) => Err(error_stack::report!(
+                errors::ApiErrorResponse::InvalidRequestData { message: e }
+            )),
+        })
+        .transpose()?;
+
+    let soft_decline_retry_config = request
+        .soft_decline_retry_config
+        .map(
+            |soft_decline_retry_config| match soft_decline_retry_config.validate() {
+                Ok(_) => Ok(soft_decline_retry_config),
+                Err(e) => Err(error_stack::report!(
+                    errors::ApiErrorResponse::InvalidRequestData { message: e }
+                )),
+            },
+        )
+        .transpose()?;
+
     let key = key_store.key.clone().into_inner();
     let key_manager_state = state.into();
 
@@ -495,5 +589,13 @@ pub async fn create_profile_from_merchant_account(
         dispute_polling_interval: request.dispute_polling_interval,
         is_manual_retry_enabled: request.is_manual_retry_enabled,
         always_enable_overcapture: request.always_enable_overcapture,
+        retry_policy,
+        intent_expiry: request.intent_expiry.map(i64::from),
+        refund_approval_threshold: request.refund_approval_threshold,
+        webhook_retry_policy,
+        event_sink_config,
+        is_connector_audit_capture_enabled: request.is_connector_audit_capture_enabled,
+        soft_decline_retry_config,
+        webhook_endpoint_secrets,
     }))
 }
@@ -1,4 +1,5 @@
 pub use api_models::api_keys::{
-    ApiKeyExpiration, CreateApiKeyRequest, CreateApiKeyResponse, ListApiKeyConstraints,
-    RetrieveApiKeyResponse, RevokeApiKeyResponse, UpdateApiKeyRequest,
+    ApiKeyExpiration, CreateApiKeyRequest, CreateApiKeyResponse, CreateSigningKeyResponse,
+    ListApiKeyConstraints, RetrieveApiKeyResponse, RevokeApiKeyResponse, RevokeSigningKeyResponse,
+    RotateApiKeyRequest, RotateApiKeyResponse, SigningKeyResponse, UpdateApiKeyRequest,
 };
@@ -241,6 +241,10 @@ impl ConnectorData {
                     connector::DummyConnector::<7>::new(),
                 ))),
                 #[cfg(feature = "dummy_connector")]
+                enums::Connector::DummyAdvancedConnector => Ok(ConnectorEnum::Old(Box::new(
+                    connector::DummyConnector::<9>::new(),
+                ))),
+                #[cfg(feature = "dummy_connector")]
                 enums::Connector::DummyBillingConnector => Ok(ConnectorEnum::Old(Box::new(
                     connector::DummyConnector::<8>::new(),
                 ))),
@@ -269,6 +273,9 @@ impl ConnectorData {
                 enums::Connector::Forte => {
                     Ok(ConnectorEnum::Old(Box::new(connector::Forte::new())))
                 }
+                enums::Connector::Genericrest => {
+                    Ok(ConnectorEnum::Old(Box::new(connector::Genericrest::new())))
+                }
                 enums::Connector::Getnet => {
                     Ok(ConnectorEnum::Old(Box::new(connector::Getnet::new())))
                 }
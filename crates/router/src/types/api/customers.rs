@@ -1,4 +1,10 @@
 use api_models::customers;
+#[cfg(feature = "v1")]
+pub use api_models::customers::{
+    CustomerDataExportMandate, CustomerDataExportPayment, CustomerDataExportPaymentMethod,
+    CustomerDataExportResponse, CustomerMergeRequest, CustomerMergeRequestInternal,
+    CustomerMergeResponse, CustomerRedactResponse,
+};
 pub use api_models::customers::{
     CustomerDeleteResponse, CustomerListRequest, CustomerRequest, CustomerUpdateRequest,
     CustomerUpdateRequestInternal,
@@ -40,6 +46,7 @@ impl ForeignFrom<(domain::Customer, Option<payments::AddressDetails>)> for Custo
             address,
             default_payment_method_id: cust.default_payment_method_id,
             tax_registration_id: cust.tax_registration_id,
+            merged_into_customer_id: cust.merged_into_customer_id,
         }
         .into()
     }
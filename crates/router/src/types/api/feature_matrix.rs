@@ -157,6 +157,10 @@ impl FeatureMatrixConnectorData {
                     connector::DummyConnector::<7>::new(),
                 ))),
                 #[cfg(feature = "dummy_connector")]
+                enums::Connector::DummyAdvancedConnector => Ok(ConnectorEnum::Old(Box::new(
+                    connector::DummyConnector::<9>::new(),
+                ))),
+                #[cfg(feature = "dummy_connector")]
                 enums::Connector::DummyBillingConnector => Ok(ConnectorEnum::Old(Box::new(
                     connector::DummyConnector::<8>::new(),
                 ))),
@@ -185,6 +189,9 @@ impl FeatureMatrixConnectorData {
                 enums::Connector::Flexiti => {
                     Ok(ConnectorEnum::Old(Box::new(connector::Flexiti::new())))
                 }
+                enums::Connector::Genericrest => {
+                    Ok(ConnectorEnum::Old(Box::new(connector::Genericrest::new())))
+                }
                 enums::Connector::Getnet => {
                     Ok(ConnectorEnum::Old(Box::new(connector::Getnet::new())))
                 }
@@ -1,5 +1,7 @@
 #[cfg(feature = "v1")]
-pub use api_models::refunds::RefundRequest;
+pub use api_models::refunds::{
+    RefundApproveRequest, RefundCancelRequest, RefundRejectRequest, RefundRequest,
+};
 pub use api_models::refunds::{
     RefundListRequest, RefundListResponse, RefundResponse, RefundStatus, RefundType,
     RefundUpdateRequest, RefundsRetrieveBody, RefundsRetrieveRequest,
@@ -19,6 +21,9 @@ impl ForeignFrom<storage_enums::RefundStatus> for RefundStatus {
             storage_enums::RefundStatus::ManualReview => Self::Review,
             storage_enums::RefundStatus::Pending => Self::Pending,
             storage_enums::RefundStatus::Success => Self::Succeeded,
+            storage_enums::RefundStatus::Scheduled => Self::Scheduled,
+            storage_enums::RefundStatus::Cancelled => Self::Cancelled,
+            storage_enums::RefundStatus::PendingApproval => Self::PendingApproval,
         }
     }
 }
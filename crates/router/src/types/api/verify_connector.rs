@@ -69,6 +69,8 @@ impl VerifyConnectorData {
             payment_channel: None,
             enable_partial_authorization: None,
             enable_overcapture: None,
+            mit_category: None,
+            dcc_data: None,
         }
     }
 
@@ -106,6 +108,7 @@ impl VerifyConnectorData {
             connector_wallets_details: None,
             payment_method_token: None,
             connector_api_version: None,
+            merchant_connector_id: None,
             recurring_mandate_payment_data: None,
             payment_method_status: None,
             connector_request_reference_id: attempt_id,
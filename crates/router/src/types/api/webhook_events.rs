@@ -2,5 +2,6 @@ pub use api_models::webhook_events::{
     EventListConstraints, EventListConstraintsInternal, EventListItemResponse,
     EventListRequestInternal, EventRetrieveResponse, OutgoingWebhookRequestContent,
     OutgoingWebhookResponseContent, TotalEventsResponse, WebhookDeliveryAttemptListRequestInternal,
-    WebhookDeliveryRetryRequestInternal,
+    WebhookDeliveryHealthConstraints, WebhookDeliveryHealthRequestInternal,
+    WebhookDeliveryHealthResponse, WebhookDeliveryRetryRequestInternal,
 };
@@ -71,6 +71,16 @@ pub struct Event {
 
     /// Indicates whether the event was ultimately delivered.
     pub is_overall_delivery_successful: Option<bool>,
+
+    /// The timestamp when the automatic retries of the event were exhausted without delivering it.
+    pub dead_lettered_at: Option<time::PrimitiveDateTime>,
+
+    /// The identifier of the additional profile webhook endpoint the event is delivered to, if
+    /// the event is not delivered to the primary webhook URL of the profile.
+    pub webhook_endpoint_id: Option<String>,
+
+    /// The time taken by the webhook endpoint to respond to the delivery attempt, in milliseconds.
+    pub delivery_latency_in_ms: Option<i32>,
 }
 
 #[derive(Debug)]
@@ -78,10 +88,14 @@ pub enum EventUpdate {
     UpdateResponse {
         is_webhook_notified: bool,
         response: OptionalEncryptableSecretString,
+        delivery_latency_in_ms: Option<i32>,
     },
     OverallDeliveryStatusUpdate {
         is_overall_delivery_successful: bool,
     },
+    DeadLetterUpdate {
+        dead_lettered_at: time::PrimitiveDateTime,
+    },
 }
 
 impl From<EventUpdate> for EventUpdateInternal {
@@ -90,10 +104,13 @@ impl From<EventUpdate> for EventUpdateInternal {
             EventUpdate::UpdateResponse {
                 is_webhook_notified,
                 response,
+                delivery_latency_in_ms,
             } => Self {
                 is_webhook_notified: Some(is_webhook_notified),
                 response: response.map(Into::into),
                 is_overall_delivery_successful: None,
+                dead_lettered_at: None,
+                delivery_latency_in_ms,
             },
             EventUpdate::OverallDeliveryStatusUpdate {
                 is_overall_delivery_successful,
@@ -101,6 +118,15 @@ impl From<EventUpdate> for EventUpdateInternal {
                 is_webhook_notified: None,
                 response: None,
                 is_overall_delivery_successful: Some(is_overall_delivery_successful),
+                dead_lettered_at: None,
+                delivery_latency_in_ms: None,
+            },
+            EventUpdate::DeadLetterUpdate { dead_lettered_at } => Self {
+                is_webhook_notified: None,
+                response: None,
+                is_overall_delivery_successful: None,
+                dead_lettered_at: Some(dead_lettered_at),
+                delivery_latency_in_ms: None,
             },
         }
     }
@@ -130,6 +156,9 @@ impl super::behaviour::Conversion for Event {
             delivery_attempt: self.delivery_attempt,
             metadata: self.metadata,
             is_overall_delivery_successful: self.is_overall_delivery_successful,
+            dead_lettered_at: self.dead_lettered_at,
+            webhook_endpoint_id: self.webhook_endpoint_id,
+            delivery_latency_in_ms: self.delivery_latency_in_ms,
         })
     }
 
@@ -180,6 +209,9 @@ impl super::behaviour::Conversion for Event {
             delivery_attempt: item.delivery_attempt,
             metadata: item.metadata,
             is_overall_delivery_successful: item.is_overall_delivery_successful,
+            dead_lettered_at: item.dead_lettered_at,
+            webhook_endpoint_id: item.webhook_endpoint_id,
+            delivery_latency_in_ms: item.delivery_latency_in_ms,
         })
     }
 
@@ -202,6 +234,9 @@ impl super::behaviour::Conversion for Event {
             delivery_attempt: self.delivery_attempt,
             metadata: self.metadata,
             is_overall_delivery_successful: self.is_overall_delivery_successful,
+            dead_lettered_at: self.dead_lettered_at,
+            webhook_endpoint_id: self.webhook_endpoint_id,
+            delivery_latency_in_ms: self.delivery_latency_in_ms,
         })
     }
 }
@@ -9,6 +9,8 @@ use crate::routes::app;
 impl From<&app::SessionState> for KeyManagerState {
     fn from(state: &app::SessionState) -> Self {
         let conf = state.conf.key_manager.get_inner();
+        #[cfg(feature = "keymanager_mtls")]
+        let certificates = state.key_manager_certificates.get();
         Self {
             global_tenant_id: state.conf.multitenancy.global_tenant.tenant_id.clone(),
             tenant_id: state.tenant.tenant_id.clone(),
@@ -18,10 +20,13 @@ impl From<&app::SessionState> for KeyManagerState {
             #[cfg(feature = "km_forward_x_request_id")]
             request_id: state.request_id,
             #[cfg(feature = "keymanager_mtls")]
-            cert: conf.cert.clone(),
+            cert: certificates.cert,
             #[cfg(feature = "keymanager_mtls")]
-            ca: conf.ca.clone(),
+            ca: certificates.ca,
             infra_values: app::AppState::process_env_mappings(state.conf.infra_values.clone()),
+            retry_config: conf.retry,
+            circuit_breaker_config: conf.circuit_breaker,
+            backend: state.key_manager_backend.clone(),
         }
     }
 }
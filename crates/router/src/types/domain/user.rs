@@ -358,6 +358,18 @@ impl From<(user_api::CreateTenantUserRequest, MerchantAccountIdentifier)> for Ne
     }
 }
 
+impl From<ProvisionedUser> for NewUserOrganization {
+    fn from((_email, _name, merchant_account_identifier): ProvisionedUser) -> Self {
+        let new_organization = api_org::OrganizationNew {
+            org_id: merchant_account_identifier.org_id,
+            org_type: common_enums::OrganizationType::Standard,
+            org_name: None,
+        };
+        let db_organization = ForeignFrom::foreign_from(new_organization);
+        Self(db_organization)
+    }
+}
+
 impl ForeignFrom<api_models::user::UserOrgMerchantCreateRequest>
     for diesel_models::organization::OrganizationNew
 {
@@ -734,6 +746,20 @@ impl From<(user_api::CreateTenantUserRequest, MerchantAccountIdentifier)> for Ne
     }
 }
 
+impl From<ProvisionedUser> for NewUserMerchant {
+    fn from(value: ProvisionedUser) -> Self {
+        let merchant_id = value.2.merchant_id.clone();
+        let new_organization = NewUserOrganization::from(value);
+        Self {
+            company_name: None,
+            merchant_id,
+            new_organization,
+            product_type: None,
+            merchant_account_type: None,
+        }
+    }
+}
+
 type UserMerchantCreateRequestWithToken =
     (UserFromStorage, user_api::UserMerchantCreate, UserFromToken);
 
@@ -785,6 +811,10 @@ pub struct MerchantAccountIdentifier {
     pub org_id: id_type::OrganizationId,
 }
 
+/// A user being provisioned by an identity provider, either on their first sign in through SSO or
+/// through SCIM, along with their name if provided and the merchant account they are added to.
+pub type ProvisionedUser = (UserEmail, Option<UserName>, MerchantAccountIdentifier);
+
 #[derive(Clone)]
 pub struct NewUser {
     user_id: String,
@@ -1115,6 +1145,29 @@ impl TryFrom<(user_api::CreateTenantUserRequest, MerchantAccountIdentifier)> for
     }
 }
 
+impl TryFrom<ProvisionedUser> for NewUser {
+    type Error = error_stack::Report<UserErrors>;
+
+    fn try_from(value: ProvisionedUser) -> UserResult<Self> {
+        let user_id = uuid::Uuid::new_v4().to_string();
+        let email = value.0.clone();
+        let name = value
+            .1
+            .clone()
+            .map(Ok)
+            .unwrap_or_else(|| UserName::try_from(email.clone().into_inner()))?;
+        let new_merchant = NewUserMerchant::from(value);
+
+        Ok(Self {
+            user_id,
+            name,
+            email,
+            password: None,
+            new_merchant,
+        })
+    }
+}
+
 #[derive(Clone)]
 pub struct UserFromStorage(pub storage_user::User);
 
@@ -1306,6 +1359,7 @@ impl ForeignFrom<UserStatus> for user_role_api::UserStatus {
         match value {
             UserStatus::Active => Self::Active,
             UserStatus::InvitationSent => Self::InvitationSent,
+            UserStatus::Deactivated => Self::Deactivated,
         }
     }
 }
@@ -199,12 +199,35 @@ impl JWTFlow {
             }
         };
 
+        // The identity provider is responsible for the second factor of users signing in with SSO
+        let signed_in_with_sso = next_flow.path.contains(&TokenPurpose::SSO);
+        if !signed_in_with_sso {
+            utils::user::two_factor_auth::validate_two_factor_auth_for_role(
+                state,
+                user_id,
+                &new_lineage_context.role_id,
+                &new_lineage_context.org_id,
+                &new_lineage_context.tenant_id,
+            )
+            .await?;
+            utils::user::passkey::validate_passkey_for_org(
+                state,
+                user_id,
+                &new_lineage_context.org_id,
+                true,
+            )
+            .await?;
+        }
+
         utils::user::spawn_async_lineage_context_update_to_db(
             state,
             user_id,
             new_lineage_context.clone(),
         );
 
+        let session_id =
+            auth::user_sessions::create_user_session(state, user_id, signed_in_with_sso).await?;
+
         auth::AuthToken::new_token(
             new_lineage_context.user_id,
             new_lineage_context.merchant_id,
@@ -213,6 +236,7 @@ impl JWTFlow {
             new_lineage_context.org_id,
             new_lineage_context.profile_id,
             Some(new_lineage_context.tenant_id),
+            session_id,
         )
         .await
         .map(|token| token.into())
@@ -245,6 +269,7 @@ impl JWTFlow {
 pub enum Origin {
     #[serde(rename = "sign_in_with_sso")]
     SignInWithSSO,
+    SignInWithPasskey,
     SignIn,
     SignUp,
     MagicLink,
@@ -257,6 +282,7 @@ impl Origin {
     fn get_flows(&self) -> &'static [UserFlow] {
         match self {
             Self::SignInWithSSO => &SIGNIN_WITH_SSO_FLOW,
+            Self::SignInWithPasskey => &SIGNIN_WITH_PASSKEY_FLOW,
             Self::SignIn => &SIGNIN_FLOW,
             Self::SignUp => &SIGNUP_FLOW,
             Self::VerifyEmail => &VERIFY_EMAIL_FLOW,
@@ -272,6 +298,11 @@ const SIGNIN_WITH_SSO_FLOW: [UserFlow; 2] = [
     UserFlow::JWTFlow(JWTFlow::UserInfo),
 ];
 
+const SIGNIN_WITH_PASSKEY_FLOW: [UserFlow; 2] = [
+    UserFlow::SPTFlow(SPTFlow::MerchantSelect),
+    UserFlow::JWTFlow(JWTFlow::UserInfo),
+];
+
 const SIGNIN_FLOW: [UserFlow; 4] = [
     UserFlow::SPTFlow(SPTFlow::TOTP),
     UserFlow::SPTFlow(SPTFlow::ForceSetPassword),
@@ -1,5 +1,8 @@
 pub mod address;
+pub mod admin_audit_event;
 pub mod api_keys;
+pub mod archival;
+pub mod auth_rate_alert;
 pub mod authentication;
 pub mod authorization;
 pub mod blocklist;
@@ -10,8 +13,10 @@ pub mod callback_mapper;
 pub mod capture;
 pub mod cards_info;
 pub mod configs;
+pub mod connector_audit_log;
 pub mod customers;
 pub mod dashboard_metadata;
+pub mod data_retention;
 pub mod dispute;
 pub mod dynamic_routing_stats;
 pub mod enums;
@@ -19,22 +24,31 @@ pub mod ephemeral_key;
 pub mod events;
 pub mod file;
 pub mod fraud_check;
+pub mod fx_quote;
 pub mod generic_link;
 pub mod gsm;
 pub mod hyperswitch_ai_interaction;
 #[cfg(feature = "kv_store")]
 pub mod kv;
+pub mod list_view;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod merchant_key_store;
+pub mod merchant_signing_key;
 pub mod payment_attempt;
+pub mod payment_batch;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_method_health;
+pub mod payment_review;
 pub mod payout_attempt;
+pub mod payout_batch;
+pub mod payout_beneficiary;
 pub mod payouts;
 pub mod refund;
+pub mod report;
 #[cfg(feature = "v2")]
 pub mod revenue_recovery;
 #[cfg(feature = "v2")]
@@ -42,10 +56,14 @@ pub mod revenue_recovery_redis_operation;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod routing_audit_log;
+pub mod routing_experiment_payment;
+pub mod settlement_recon;
 pub mod subscription;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
+pub mod user_passkey;
 pub mod user_role;
 
 pub use diesel_models::{
@@ -72,13 +90,17 @@ pub use hyperswitch_domain_models::{
 pub use scheduler::db::process_tracker;
 
 pub use self::{
-    address::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
-    blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, callback_mapper::*,
-    capture::*, cards_info::*, configs::*, customers::*, dashboard_metadata::*, dispute::*,
-    dynamic_routing_stats::*, ephemeral_key::*, events::*, file::*, fraud_check::*,
-    generic_link::*, gsm::*, hyperswitch_ai_interaction::*, locker_mock_up::*, mandate::*,
-    merchant_account::*, merchant_connector_account::*, merchant_key_store::*, payment_link::*,
-    payment_method::*, process_tracker::*, refund::*, reverse_lookup::*, role::*,
-    routing_algorithm::*, subscription::*, unified_translations::*, user::*,
-    user_authentication_method::*, user_role::*,
+    address::*, admin_audit_event::*, api_keys::*, archival::*, auth_rate_alert::*,
+    authentication::*, authorization::*, blocklist::*, blocklist_fingerprint::*,
+    blocklist_lookup::*, business_profile::*, callback_mapper::*, capture::*, cards_info::*,
+    configs::*, connector_audit_log::*, customers::*, dashboard_metadata::*, data_retention::*,
+    dispute::*, dynamic_routing_stats::*, ephemeral_key::*, events::*, file::*, fraud_check::*,
+    fx_quote::*, generic_link::*, gsm::*, hyperswitch_ai_interaction::*, list_view::*,
+    locker_mock_up::*, mandate::*, merchant_account::*, merchant_connector_account::*,
+    merchant_key_store::*, merchant_signing_key::*, payment_batch::*, payment_link::*,
+    payment_method::*, payment_method_health::*, payment_review::*, payout_batch::*,
+    payout_beneficiary::*, process_tracker::*, refund::*, report::*, reverse_lookup::*, role::*,
+    routing_algorithm::*, routing_audit_log::*, routing_experiment_payment::*, settlement_recon::*,
+    subscription::*, unified_translations::*, user::*, user_authentication_method::*,
+    user_passkey::*, user_role::*,
 };
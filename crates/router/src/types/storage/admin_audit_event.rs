@@ -0,0 +1,3 @@
+pub use diesel_models::admin_audit_event::{
+    AdminAuditEvent, AdminAuditEventListConstraints, AdminAuditEventNew,
+};
@@ -0,0 +1,9 @@
+pub use diesel_models::auth_rate_alert::{
+    AuthRateAlert, AuthRateAlertConfig, AuthRateAlertConfigNew, AuthRateAlertConfigUpdate,
+    AuthRateAlertNew, AuthRateAlertUpdate,
+};
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct AuthRateAlertTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
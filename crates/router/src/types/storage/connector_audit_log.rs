@@ -0,0 +1 @@
+pub use diesel_models::connector_audit_log::{ConnectorAuditLog, ConnectorAuditLogNew};
@@ -0,0 +1,9 @@
+pub use diesel_models::data_retention::{
+    DataRetentionPolicy, DataRetentionPolicyNew, DataRetentionPolicyUpdate, DataRetentionPurgeRun,
+    DataRetentionPurgeRunNew,
+};
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct DataRetentionPurgeTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
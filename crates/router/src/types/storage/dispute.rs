@@ -34,6 +34,7 @@ impl DisputeDbExt for Dispute {
         let mut filter = <Self as HasTable>::table()
             .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
             .order(dsl::modified_at.desc())
+            .then_order_by(dsl::dispute_id.desc())
             .into_boxed();
 
         let mut search_by_payment_or_dispute_id = false;
@@ -94,8 +95,21 @@ impl DisputeDbExt for Dispute {
         if let Some(limit) = dispute_list_constraints.limit {
             filter = filter.limit(limit.into());
         }
-        if let Some(offset) = dispute_list_constraints.offset {
-            filter = filter.offset(offset.into());
+        match (
+            &dispute_list_constraints.cursor,
+            dispute_list_constraints.offset,
+        ) {
+            (Some(cursor), _) => {
+                filter = filter.filter(
+                    dsl::modified_at.lt(cursor.sort_key).or(dsl::modified_at
+                        .eq(cursor.sort_key)
+                        .and(dsl::dispute_id.lt(cursor.id.clone()))),
+                );
+            }
+            (None, Some(offset)) => {
+                filter = filter.offset(offset.into());
+            }
+            (None, None) => {}
         }
 
         logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
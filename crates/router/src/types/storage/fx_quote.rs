@@ -0,0 +1 @@
+pub use diesel_models::fx_quote::{FxQuote, FxQuoteNew, FxQuoteUpdate};
@@ -0,0 +1 @@
+pub use diesel_models::list_view::{ListView, ListViewNew, ListViewUpdate};
@@ -0,0 +1 @@
+pub use diesel_models::merchant_signing_key::{MerchantSigningKey, MerchantSigningKeyNew};
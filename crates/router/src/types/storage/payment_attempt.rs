@@ -118,6 +118,19 @@ impl AttemptStatusExt for enums::AttemptStatus {
     }
 }
 
+/// Tracking data of the process tracker task which retries a merchant initiated payment declined
+/// with a retriable decline
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct SoftDeclineRetryTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    /// Attempt whose decline is to be retried
+    pub attempt_id: String,
+    /// Times at which the payment was declined, starting with the original decline
+    pub declined_at: Vec<time::PrimitiveDateTime>,
+}
+
 #[cfg(test)]
 #[cfg(all(
     feature = "v1", // Ignoring tests for v2 since they aren't actively running
@@ -229,6 +242,8 @@ mod tests {
             connector_request_reference_id: Default::default(),
             network_transaction_id: Default::default(),
             network_details: Default::default(),
+            dcc_details: Default::default(),
+            device_fingerprint: Default::default(),
         };
 
         let store = state
@@ -323,6 +338,8 @@ mod tests {
             connector_request_reference_id: Default::default(),
             network_transaction_id: Default::default(),
             network_details: Default::default(),
+            dcc_details: Default::default(),
+            device_fingerprint: Default::default(),
         };
         let store = state
             .stores
@@ -430,6 +447,8 @@ mod tests {
             connector_request_reference_id: Default::default(),
             network_transaction_id: Default::default(),
             network_details: Default::default(),
+            dcc_details: Default::default(),
+            device_fingerprint: Default::default(),
         };
         let store = state
             .stores
@@ -0,0 +1,3 @@
+pub use diesel_models::payment_batch::{
+    PaymentBatch, PaymentBatchItem, PaymentBatchItems, PaymentBatchNew, PaymentBatchUpdate,
+};
@@ -1,7 +1,7 @@
 use async_bb8_diesel::AsyncRunQueryDsl;
 use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
 pub use diesel_models::{
-    payment_link::{PaymentLink, PaymentLinkNew},
+    payment_link::{PaymentLink, PaymentLinkExpiryTrackingData, PaymentLinkNew, PaymentLinkUpdate},
     schema::payment_link::dsl,
 };
 use error_stack::ResultExt;
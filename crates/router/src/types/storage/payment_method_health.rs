@@ -0,0 +1,9 @@
+pub use diesel_models::payment_method_health::{
+    PaymentMethodHealth, PaymentMethodHealthNew, PaymentMethodHealthUpdate,
+};
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct PaymentMethodExpiryTrackingData {
+    pub payment_method_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
@@ -0,0 +1 @@
+pub use diesel_models::payment_review::{PaymentReview, PaymentReviewNew, PaymentReviewUpdate};
@@ -80,9 +80,22 @@ impl RefundDbExt for Refund {
         let mut filter = <Self as HasTable>::table()
             .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
             .order(dsl::modified_at.desc())
+            .then_order_by(dsl::refund_id.desc())
             .into_boxed();
         let mut search_by_pay_or_ref_id = false;
 
+        // A cursor already marks where the page starts, so the offset is not applied on top of it
+        let offset = if let Some(cursor) = &refund_list_details.cursor {
+            filter = filter.filter(
+                dsl::modified_at.lt(cursor.sort_key).or(dsl::modified_at
+                    .eq(cursor.sort_key)
+                    .and(dsl::refund_id.lt(cursor.id.clone()))),
+            );
+            0
+        } else {
+            offset
+        };
+
         if let (Some(pid), Some(ref_id)) = (
             &refund_list_details.payment_id,
             &refund_list_details.refund_id,
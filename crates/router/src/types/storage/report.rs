@@ -0,0 +1,9 @@
+pub use diesel_models::report::{
+    ReportRun, ReportRunNew, ReportTemplate, ReportTemplateNew, ReportTemplateUpdate,
+};
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct ReportGenerationTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub template_id: String,
+}
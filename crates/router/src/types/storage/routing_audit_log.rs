@@ -0,0 +1 @@
+pub use diesel_models::routing_audit_log::{RoutingAuditLog, RoutingAuditLogNew};
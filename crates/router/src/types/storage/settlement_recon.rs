@@ -0,0 +1,3 @@
+pub use diesel_models::settlement_recon::{
+    ReconRecord, ReconRecordNew, SettlementReport, SettlementReportNew, SettlementReportUpdate,
+};
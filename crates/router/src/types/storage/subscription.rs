@@ -1 +1,4 @@
-pub use diesel_models::subscription::{Subscription, SubscriptionNew, SubscriptionUpdate};
+pub use diesel_models::subscription::{
+    Subscription, SubscriptionBillingTrackingData, SubscriptionNew, SubscriptionPlan,
+    SubscriptionPlanNew, SubscriptionUpdate,
+};
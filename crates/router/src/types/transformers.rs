@@ -83,6 +83,17 @@ impl ForeignFrom<api_models::refunds::RefundType> for storage_enums::RefundType
     }
 }
 
+impl ForeignFrom<storage_enums::RefundType> for api_models::refunds::RefundType {
+    fn foreign_from(item: storage_enums::RefundType) -> Self {
+        match item {
+            storage_enums::RefundType::InstantRefund => Self::Instant,
+            storage_enums::RefundType::RegularRefund | storage_enums::RefundType::RetryRefund => {
+                Self::Scheduled
+            }
+        }
+    }
+}
+
 #[cfg(feature = "v1")]
 impl
     ForeignFrom<(
@@ -173,6 +184,7 @@ impl ForeignTryFrom<storage_enums::AttemptStatus> for storage_enums::CaptureStat
             | storage_enums::AttemptStatus::ConfirmationAwaited
             | storage_enums::AttemptStatus::DeviceDataCollectionPending
             | storage_enums::AttemptStatus::PartiallyAuthorized
+            | storage_enums::AttemptStatus::IncrementalAuthorizationPending
             | storage_enums::AttemptStatus::PartialChargedAndChargeable | storage_enums::AttemptStatus::Expired => {
                 Err(errors::ApiErrorResponse::PreconditionFailed {
                     message: "AttemptStatus must be one of these for multiple partial captures [Charged, PartialCharged, Pending, CaptureInitiated, Failure, CaptureFailed]".into(),
@@ -623,6 +635,8 @@ impl
             api_key: StrongSecret::from(plaintext_api_key.peek().to_owned()),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            scopes: api_key.get_scopes(),
+            allowed_ip_ranges: api_key.get_allowed_ip_ranges(),
         }
     }
 }
@@ -634,6 +648,8 @@ impl ForeignFrom<diesel_models::api_keys::ApiKey> for api_models::api_keys::Retr
             merchant_id: api_key.merchant_id,
             name: api_key.name,
             description: api_key.description,
+            scopes: api_key.get_scopes(),
+            allowed_ip_ranges: api_key.get_allowed_ip_ranges(),
             prefix: api_key.prefix.into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
@@ -650,6 +666,10 @@ impl ForeignFrom<api_models::api_keys::UpdateApiKeyRequest>
             description: api_key.description,
             expires_at: api_key.expiration.map(Into::into),
             last_used: None,
+            allowed_ip_ranges: api_key.allowed_ip_ranges.map(|allowed_ip_ranges| {
+                (!allowed_ip_ranges.is_empty())
+                    .then(|| allowed_ip_ranges.into_iter().map(Some).collect())
+            }),
         }
     }
 }
@@ -708,6 +728,15 @@ impl ForeignFrom<storage::Dispute> for api_models::disputes::DisputeResponse {
             created_at: dispute.created_at,
             profile_id: dispute.profile_id,
             merchant_connector_id: dispute.merchant_connector_id,
+            automated_action: dispute.automated_action.map(|automated_action| {
+                api_models::disputes::DisputeAutomatedAction {
+                    rule_name: automated_action.rule_name,
+                    action: automated_action.action,
+                    succeeded: automated_action.succeeded,
+                    error_message: automated_action.error_message,
+                    performed_at: automated_action.performed_at,
+                }
+            }),
         }
     }
 }
@@ -1563,6 +1592,49 @@ impl ForeignFrom<(storage::PaymentLink, payments::PaymentLinkStatus)>
     }
 }
 
+impl ForeignFrom<storage::Subscription> for api_models::subscription::SubscriptionResponse {
+    fn foreign_from(subscription: storage::Subscription) -> Self {
+        Self {
+            status: subscription
+                .status
+                .parse()
+                .unwrap_or(api_models::subscription::SubscriptionStatus::Created),
+            id: subscription.id,
+            merchant_reference_id: subscription.merchant_reference_id,
+            plan_id: subscription.plan_id,
+            customer_id: subscription.customer_id,
+            profile_id: subscription.profile_id,
+            merchant_id: subscription.merchant_id,
+            payment_method_id: subscription.payment_method_id,
+            mandate_id: subscription.mandate_id,
+            trial_end: subscription.trial_end,
+            current_period_start: subscription.current_period_start,
+            current_period_end: subscription.current_period_end,
+            last_payment_id: subscription.last_payment_id,
+            created_at: subscription.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::SubscriptionPlan> for api_models::subscription::SubscriptionPlanResponse {
+    fn foreign_from(plan: storage::SubscriptionPlan) -> Self {
+        Self {
+            id: plan.id,
+            name: plan.name,
+            merchant_id: plan.merchant_id,
+            profile_id: plan.profile_id,
+            amount: plan.amount,
+            currency: plan.currency,
+            billing_interval: plan.billing_interval,
+            billing_interval_count: plan.billing_interval_count,
+            trial_period_days: plan.trial_period_days,
+            max_payment_retries: plan.max_payment_retries,
+            payment_retry_interval_hours: plan.payment_retry_interval_hours,
+            created_at: plan.created_at,
+        }
+    }
+}
+
 impl From<domain::Address> for payments::AddressDetails {
     fn from(addr: domain::Address) -> Self {
         Self {
@@ -1843,6 +1915,8 @@ impl TryFrom<domain::Event> for api_models::webhook_events::EventListItemRespons
             event_type: item.event_type,
             event_class: item.event_class,
             is_delivery_successful: item.is_overall_delivery_successful,
+            dead_lettered_at: item.dead_lettered_at,
+            webhook_endpoint_id: item.webhook_endpoint_id,
             initial_attempt_id,
             created: item.created_at,
         })
@@ -1999,6 +2073,12 @@ impl ForeignFrom<api_models::admin::WebhookDetails>
             payment_statuses_enabled: item.payment_statuses_enabled,
             refund_statuses_enabled: item.refund_statuses_enabled,
             payout_statuses_enabled: item.payout_statuses_enabled,
+            webhook_endpoints: item.webhook_endpoints.map(|webhook_endpoints| {
+                webhook_endpoints
+                    .into_iter()
+                    .map(ForeignFrom::foreign_from)
+                    .collect()
+            }),
         }
     }
 }
@@ -2018,6 +2098,45 @@ impl ForeignFrom<diesel_models::business_profile::WebhookDetails>
             payment_statuses_enabled: item.payment_statuses_enabled,
             refund_statuses_enabled: item.refund_statuses_enabled,
             payout_statuses_enabled: item.payout_statuses_enabled,
+            webhook_endpoints: item.webhook_endpoints.map(|webhook_endpoints| {
+                webhook_endpoints
+                    .into_iter()
+                    .map(ForeignFrom::foreign_from)
+                    .collect()
+            }),
+        }
+    }
+}
+
+impl ForeignFrom<api_models::admin::WebhookEndpoint>
+    for diesel_models::business_profile::WebhookEndpoint
+{
+    fn foreign_from(item: api_models::admin::WebhookEndpoint) -> Self {
+        Self {
+            endpoint_id: item.endpoint_id,
+            url: item.url,
+            secret: item.secret,
+            enabled_events: item.enabled_events,
+            client_certificate: item.client_certificate,
+            ca_certificate: item.ca_certificate,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::WebhookEndpoint>
+    for api_models::admin::WebhookEndpoint
+{
+    fn foreign_from(item: diesel_models::business_profile::WebhookEndpoint) -> Self {
+        Self {
+            endpoint_id: item.endpoint_id,
+            url: item.url,
+            secret: item.secret,
+            enabled_events: item.enabled_events,
+            // The credentials are stored encrypted separately from the webhook details
+            custom_headers: None,
+            client_certificate: item.client_certificate,
+            client_certificate_key: None,
+            ca_certificate: item.ca_certificate,
         }
     }
 }
@@ -6,20 +6,22 @@ use std::{
 };
 
 use api_models::enums;
-use common_utils::{date_time, errors::CustomResult, events::ApiEventMetric, ext_traits::AsyncExt};
+use common_utils::{
+    date_time, errors::CustomResult, events::ApiEventMetric, ext_traits::AsyncExt, types::MinorUnit,
+};
 use currency_conversion::types::{CurrencyFactors, ExchangeRates};
 use error_stack::ResultExt;
 use masking::PeekInterface;
 use redis_interface::DelReply;
 use router_env::{instrument, tracing};
-use rust_decimal::Decimal;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use strum::IntoEnumIterator;
 use tokio::sync::RwLock;
 use tracing_futures::Instrument;
 
 use crate::{
     logger,
-    routes::app::settings::{Conversion, DefaultExchangeRates},
+    routes::app::settings::{Conversion, DefaultExchangeRates, ForexProvider},
     services, SessionState,
 };
 const REDIX_FOREX_CACHE_KEY: &str = "{forex_cache}_lock";
@@ -206,9 +208,13 @@ async fn call_forex_api_and_save_data_to_cache_and_redis(
     stale_redis_data: Option<FxExchangeRatesCacheEntry>,
 ) -> CustomResult<FxExchangeRatesCacheEntry, ForexError> {
     // spawn a new thread and do the api fetch and write operations on redis.
-    let forex_api_key = state.conf.forex_api.get_inner().api_key.peek();
-    if forex_api_key.is_empty() {
-        Err(ForexError::ConfigurationError("api_keys not provided".into()).into())
+    let forex_api = state.conf.forex_api.get_inner();
+    if !forex_api
+        .providers
+        .iter()
+        .any(|provider| forex_api.is_provider_configured(provider))
+    {
+        Err(ForexError::ConfigurationError("forex providers not configured".into()).into())
     } else {
         let state = state.clone();
         tokio::spawn(
@@ -234,25 +240,46 @@ async fn acquire_redis_lock_and_call_forex_api(
         Err(ForexError::CouldNotAcquireLock.into())
     } else {
         logger::debug!("forex_log: redis lock acquired");
-        let api_rates = fetch_forex_rates_from_primary_api(state).await;
-        match api_rates {
-            Ok(rates) => save_forex_data_to_cache_and_redis(state, rates).await,
-            Err(error) => {
-                logger::error!(forex_error=?error,"primary_forex_error");
-                // API not able to fetch data call secondary service
-                let secondary_api_rates = fetch_forex_rates_from_fallback_api(state).await;
-                match secondary_api_rates {
-                    Ok(rates) => save_forex_data_to_cache_and_redis(state, rates).await,
-                    Err(error) => {
-                        release_redis_lock(state).await?;
-                        Err(error)
-                    }
+        let forex_api = state.conf.forex_api.get_inner();
+        for provider in forex_api
+            .providers
+            .iter()
+            .filter(|provider| forex_api.is_provider_configured(provider))
+        {
+            // Providers are tried in the order of preference, until one of them returns the rates
+            match fetch_forex_rates_from_provider(state, provider).await {
+                Ok(rates) => return save_forex_data_to_cache_and_redis(state, rates).await,
+                Err(error) => {
+                    logger::error!(forex_error=?error, ?provider, "forex_provider_error");
                 }
             }
         }
+        release_redis_lock(state).await?;
+        Err(ForexError::ForexDataUnavailable.into())
+    }
+}
+
+async fn fetch_forex_rates_from_provider(
+    state: &SessionState,
+    provider: &ForexProvider,
+) -> CustomResult<FxExchangeRatesCacheEntry, ForexError> {
+    match provider {
+        ForexProvider::OpenExchangeRates => fetch_forex_rates_from_primary_api(state).await,
+        ForexProvider::CurrencyLayer => fetch_forex_rates_from_fallback_api(state).await,
+        ForexProvider::Fixed => fetch_forex_rates_from_fixed_rates(state),
     }
 }
 
+fn fetch_forex_rates_from_fixed_rates(
+    state: &SessionState,
+) -> CustomResult<FxExchangeRatesCacheEntry, ForexError> {
+    logger::debug!("forex_log: Using fixed forex rates");
+    let fixed_rates = state.conf.forex_api.get_inner().fixed_rates.clone().ok_or(
+        ForexError::ConfigurationError("fixed rates not provided".into()),
+    )?;
+    ExchangeRates::try_from(fixed_rates).map(FxExchangeRatesCacheEntry::new)
+}
+
 async fn save_forex_data_to_cache_and_redis(
     state: &SessionState,
     forex: FxExchangeRatesCacheEntry,
@@ -351,7 +378,7 @@ async fn fetch_forex_rates_from_primary_api(
     )))
 }
 
-pub async fn fetch_forex_rates_from_fallback_api(
+async fn fetch_forex_rates_from_fallback_api(
     state: &SessionState,
 ) -> CustomResult<FxExchangeRatesCacheEntry, ForexError> {
     let fallback_forex_api_key = state.conf.forex_api.get_inner().fallback_api_key.peek();
@@ -424,15 +451,10 @@ pub async fn fetch_forex_rates_from_fallback_api(
         };
     }
 
-    let rates =
-        FxExchangeRatesCacheEntry::new(ExchangeRates::new(enums::Currency::USD, conversions));
-    match acquire_redis_lock(state).await {
-        Ok(_) => {
-            save_forex_data_to_cache_and_redis(state, rates.clone()).await?;
-            Ok(rates)
-        }
-        Err(e) => Err(e),
-    }
+    Ok(FxExchangeRatesCacheEntry::new(ExchangeRates::new(
+        enums::Currency::USD,
+        conversions,
+    )))
 }
 
 async fn release_redis_lock(
@@ -545,3 +567,115 @@ pub async fn convert_currency(
         currency: to_currency.to_string(),
     })
 }
+
+/// Rate at which a major unit of `from_currency` is converted to `to_currency`
+pub fn get_conversion_rate(
+    rates: &ExchangeRates,
+    from_currency: enums::Currency,
+    to_currency: enums::Currency,
+) -> CustomResult<Decimal, ForexError> {
+    if from_currency == to_currency {
+        return Ok(Decimal::ONE);
+    }
+    let major_unit = 10_i64.pow(u32::from(
+        from_currency.number_of_digits_after_decimal_point(),
+    ));
+    currency_conversion::conversion::convert(rates, from_currency, to_currency, major_unit)
+        .change_context(ForexError::ConversionError)
+        .attach_printable("Unable to compute the conversion rate")
+}
+
+/// Converts an amount in the minor unit of `from_currency` to the minor unit of `to_currency` at
+/// the given rate, rounding to the nearest minor unit
+pub fn convert_minor_amount(
+    amount: MinorUnit,
+    from_currency: enums::Currency,
+    to_currency: enums::Currency,
+    rate: Decimal,
+) -> CustomResult<MinorUnit, ForexError> {
+    let from_minor_unit_factor = Decimal::from(10_i64.pow(u32::from(
+        from_currency.number_of_digits_after_decimal_point(),
+    )));
+    let to_minor_unit_factor = Decimal::from(10_i64.pow(u32::from(
+        to_currency.number_of_digits_after_decimal_point(),
+    )));
+    Decimal::from(amount.get_amount_as_i64())
+        .checked_mul(rate)
+        .and_then(|amount| amount.checked_mul(to_minor_unit_factor))
+        .and_then(|amount| amount.checked_div(from_minor_unit_factor))
+        .and_then(|amount| amount.round().to_i64())
+        .map(MinorUnit::new)
+        .ok_or(ForexError::ConversionError)
+        .attach_printable("Unable to convert the amount at the conversion rate")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_exchange_rates() -> ExchangeRates {
+        ExchangeRates::new(
+            enums::Currency::USD,
+            HashMap::from([
+                (
+                    enums::Currency::USD,
+                    CurrencyFactors::new(Decimal::ONE, Decimal::ONE),
+                ),
+                (
+                    enums::Currency::EUR,
+                    CurrencyFactors::new(Decimal::new(8, 1), Decimal::new(125, 2)),
+                ),
+                (
+                    enums::Currency::JPY,
+                    CurrencyFactors::new(Decimal::from(150), Decimal::new(1, 2)),
+                ),
+            ]),
+        )
+    }
+
+    #[test]
+    fn test_get_conversion_rate() {
+        let rates = get_exchange_rates();
+
+        assert_eq!(
+            get_conversion_rate(&rates, enums::Currency::EUR, enums::Currency::EUR).unwrap(),
+            Decimal::ONE
+        );
+        assert_eq!(
+            get_conversion_rate(&rates, enums::Currency::USD, enums::Currency::EUR).unwrap(),
+            Decimal::new(8, 1)
+        );
+        assert_eq!(
+            get_conversion_rate(&rates, enums::Currency::EUR, enums::Currency::JPY).unwrap(),
+            Decimal::new(18750, 2)
+        );
+        assert!(get_conversion_rate(&rates, enums::Currency::USD, enums::Currency::INR).is_err());
+    }
+
+    #[test]
+    fn test_convert_minor_amount() {
+        // 10.00 EUR at 187.50 is 1875 JPY, which has no minor unit
+        assert_eq!(
+            convert_minor_amount(
+                MinorUnit::new(1000),
+                enums::Currency::EUR,
+                enums::Currency::JPY,
+                Decimal::new(18750, 2),
+            )
+            .unwrap(),
+            MinorUnit::new(1875)
+        );
+        // 1875 JPY at 0.00533 is 9.99375 EUR, which is rounded to 9.99 EUR
+        assert_eq!(
+            convert_minor_amount(
+                MinorUnit::new(1875),
+                enums::Currency::JPY,
+                enums::Currency::EUR,
+                Decimal::new(533, 5),
+            )
+            .unwrap(),
+            MinorUnit::new(999)
+        );
+    }
+}
@@ -5,7 +5,7 @@ use api_models::admin as admin_api;
 use api_models::user as user_api;
 #[cfg(feature = "v1")]
 use common_enums::connector_enums;
-use common_enums::UserAuthType;
+use common_enums::{EntityType, UserAuthType};
 #[cfg(feature = "v1")]
 use common_utils::ext_traits::ValueExt;
 use common_utils::{
@@ -15,7 +15,7 @@ use common_utils::{
     types::{keymanager::Identifier, user::LineageContext},
 };
 use diesel_models::organization::{self, OrganizationBridge};
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
 #[cfg(feature = "v1")]
 use hyperswitch_domain_models::merchant_connector_account::MerchantConnectorAccount as DomainMerchantConnectorAccount;
 #[cfg(feature = "v1")]
@@ -28,10 +28,10 @@ use router_env::{env, logger};
 use crate::types::AdditionalMerchantData;
 use crate::{
     consts::user::{REDIS_SSO_PREFIX, REDIS_SSO_TTL},
-    core::errors::{StorageError, UserErrors, UserResult},
+    core::errors::{StorageError, StorageErrorExt, UserErrors, UserResult},
     routes::SessionState,
     services::{
-        authentication::{AuthToken, UserFromToken},
+        authentication::{user_sessions, AuthToken, UserFromToken},
         authorization::roles::RoleInfo,
     },
     types::{
@@ -41,6 +41,7 @@ use crate::{
 };
 
 pub mod dashboard_metadata;
+pub mod passkey;
 pub mod password;
 #[cfg(feature = "dummy_connector")]
 pub mod sample_data;
@@ -111,7 +112,42 @@ pub async fn generate_jwt_auth_token_with_attributes(
     role_id: String,
     profile_id: id_type::ProfileId,
     tenant_id: Option<id_type::TenantId>,
+    session_id: Option<String>,
 ) -> UserResult<Secret<String>> {
+    // Switching between entities keeps the session of the token it was made with. Tokens issued
+    // before sessions were tracked get a new session, which is not considered to be signed in
+    // through SSO.
+    let signed_in_with_sso = match session_id.as_deref() {
+        Some(session_id) => {
+            user_sessions::get_user_session(state, &user_id, session_id)
+                .await?
+                .signed_in_with_sso
+        }
+        None => false,
+    };
+
+    // The identity provider is responsible for the second factor of users signed in through SSO,
+    // same as when the token of the sign in is issued
+    if !signed_in_with_sso {
+        two_factor_auth::validate_two_factor_auth_for_role(
+            state,
+            &user_id,
+            &role_id,
+            &org_id,
+            tenant_id.as_ref().unwrap_or(&state.tenant.tenant_id),
+        )
+        .await?;
+        passkey::validate_passkey_for_org(state, &user_id, &org_id, false).await?;
+    }
+
+    let session_id = match session_id {
+        Some(session_id) => {
+            user_sessions::extend_user_session(state, &user_id, &session_id).await?;
+            session_id
+        }
+        None => user_sessions::create_user_session(state, &user_id, false).await?,
+    };
+
     let token = AuthToken::new_token(
         user_id,
         merchant_id,
@@ -120,6 +156,7 @@ pub async fn generate_jwt_auth_token_with_attributes(
         org_id,
         profile_id,
         tenant_id,
+        session_id,
     )
     .await?;
     Ok(Secret::new(token))
@@ -354,6 +391,84 @@ pub async fn validate_email_domain_auth_type_using_db(
     .ok_or(UserErrors::InvalidUserAuthMethodOperation.into())
 }
 
+pub fn get_oidc_jit_provisioning_config(
+    auth_config: &user_api::AuthConfig,
+) -> Option<&user_api::OpenIdConnectJitProvisioningConfig> {
+    match auth_config {
+        user_api::AuthConfig::OpenIdConnect { public_config, .. } => {
+            public_config.jit_provisioning.as_ref()
+        }
+        user_api::AuthConfig::Password | user_api::AuthConfig::MagicLink => None,
+    }
+}
+
+/// Validates that users can be provisioned through an OpenID Connect authentication method,
+/// returning the organization they are provisioned into. The organization is the owner of the
+/// authentication method, which maps the email domain of the authentication method to it.
+pub async fn validate_oidc_jit_provisioning_config(
+    state: &SessionState,
+    owner_type: common_enums::Owner,
+    owner_id: &str,
+    jit_provisioning: &user_api::OpenIdConnectJitProvisioningConfig,
+) -> UserResult<id_type::OrganizationId> {
+    if owner_type != common_enums::Owner::Organization {
+        return Err(report!(UserErrors::InvalidAuthMethodOperationWithMessage(
+            "JIT provisioning is only supported for organization auth methods".to_string()
+        )));
+    }
+
+    let org_id = id_type::OrganizationId::try_from(std::borrow::Cow::from(owner_id.to_owned()))
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Invalid organization id for the auth method owner")?;
+
+    let invalid_merchant_error = || {
+        UserErrors::InvalidAuthMethodOperationWithMessage(
+            "JIT provisioning merchant not found in the organization".to_string(),
+        )
+    };
+    let key_manager_state = &state.into();
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &jit_provisioning.merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(invalid_merchant_error())?;
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            &jit_provisioning.merchant_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(invalid_merchant_error())?;
+
+    if merchant_account.organization_id != org_id {
+        return Err(report!(invalid_merchant_error()));
+    }
+
+    let role_info = RoleInfo::from_role_id_org_id_tenant_id(
+        state,
+        &jit_provisioning.role_id,
+        &org_id,
+        &state.tenant.tenant_id,
+    )
+    .await
+    .to_not_found_response(UserErrors::InvalidRoleId)?;
+
+    if role_info.get_entity_type() != EntityType::Merchant || !role_info.is_invitable() {
+        return Err(report!(UserErrors::InvalidRoleId)).attach_printable(format!(
+            "role_id = {} cannot be assigned to provisioned users",
+            jit_provisioning.role_id
+        ));
+    }
+
+    Ok(org_id)
+}
+
 pub fn spawn_async_lineage_context_update_to_db(
     state: &SessionState,
     user_id: &str,
@@ -0,0 +1,303 @@
+use common_utils::{ext_traits::ValueExt, id_type};
+use diesel_models::user_passkey::UserPasskey;
+use error_stack::{report, ResultExt};
+use redis_interface::errors::RedisError;
+use webauthn_rs::prelude::{
+    Passkey, PasskeyAuthentication, PasskeyRegistration, Url, Uuid, Webauthn, WebauthnBuilder,
+};
+
+use crate::{
+    consts,
+    core::errors::{UserErrors, UserResult},
+    routes::SessionState,
+};
+
+/// Builds the WebAuthn relying party from the dashboard base url, which is the origin the
+/// browser reports in every ceremony.
+pub fn get_webauthn(state: &SessionState) -> UserResult<Webauthn> {
+    let rp_origin = Url::parse(&state.conf.user.base_url)
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to parse user base url")?;
+    let rp_id = rp_origin
+        .host_str()
+        .ok_or(UserErrors::InternalServerError)
+        .attach_printable("User base url does not have a host")?;
+
+    WebauthnBuilder::new(rp_id, &rp_origin)
+        .change_context(UserErrors::InternalServerError)?
+        .rp_name(&state.conf.user.totp_issuer_name)
+        .build()
+        .change_context(UserErrors::InternalServerError)
+}
+
+/// User ids are UUIDs, so they are used as is for the WebAuthn user handle.
+pub fn get_webauthn_user_handle(user_id: &str) -> UserResult<Uuid> {
+    Uuid::parse_str(user_id)
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to parse user_id as uuid")
+}
+
+pub fn parse_passkey(user_passkey: &UserPasskey) -> UserResult<Passkey> {
+    user_passkey
+        .passkey
+        .clone()
+        .parse_value("Passkey")
+        .change_context(UserErrors::InternalServerError)
+}
+
+pub async fn get_passkeys_for_user(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<Vec<UserPasskey>> {
+    state
+        .global_store
+        .list_user_passkeys_by_user_id(user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+pub async fn insert_passkey_registration_in_redis(
+    state: &SessionState,
+    user_id: &str,
+    registration: &PasskeyRegistration,
+) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    redis_conn
+        .serialize_and_set_key_with_expiry(
+            &get_passkey_registration_key(user_id).into(),
+            registration,
+            consts::user::REDIS_PASSKEY_CHALLENGE_TTL_IN_SECS,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+pub async fn get_passkey_registration_from_redis(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<PasskeyRegistration> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    let key = get_passkey_registration_key(user_id).into();
+    let registration = redis_conn
+        .get_and_deserialize_key(&key, "PasskeyRegistration")
+        .await
+        .map_err(|error| match error.current_context() {
+            RedisError::NotFound => error.change_context(UserErrors::PasskeyChallengeNotFound),
+            _ => error.change_context(UserErrors::InternalServerError),
+        })?;
+
+    // Challenges are single use
+    redis_conn
+        .delete_key(&key)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok(registration)
+}
+
+fn get_passkey_registration_key(user_id: &str) -> String {
+    format!(
+        "{}{}",
+        consts::user::REDIS_PASSKEY_REGISTRATION_PREFIX,
+        user_id
+    )
+}
+
+pub async fn insert_passkey_authentication_in_redis(
+    state: &SessionState,
+    user_id: &str,
+    authentication: &PasskeyAuthentication,
+) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    redis_conn
+        .serialize_and_set_key_with_expiry(
+            &get_passkey_authentication_key(user_id).into(),
+            authentication,
+            consts::user::REDIS_PASSKEY_CHALLENGE_TTL_IN_SECS,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+pub async fn get_passkey_authentication_from_redis(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<PasskeyAuthentication> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    let key = get_passkey_authentication_key(user_id).into();
+    let authentication = redis_conn
+        .get_and_deserialize_key(&key, "PasskeyAuthentication")
+        .await
+        .map_err(|error| match error.current_context() {
+            RedisError::NotFound => error.change_context(UserErrors::PasskeyChallengeNotFound),
+            _ => error.change_context(UserErrors::InternalServerError),
+        })?;
+
+    // Challenges are single use
+    redis_conn
+        .delete_key(&key)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok(authentication)
+}
+
+fn get_passkey_authentication_key(user_id: &str) -> String {
+    format!(
+        "{}{}",
+        consts::user::REDIS_PASSKEY_AUTHENTICATION_PREFIX,
+        user_id
+    )
+}
+
+/// State of a passkey sign in. It is keyed by a random ceremony id instead of the user id, as
+/// sign in is unauthenticated and anyone could otherwise replace the pending challenge of a user.
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct PasskeySignInCeremony {
+    pub user_id: String,
+    pub authentication: PasskeyAuthentication,
+}
+
+pub async fn insert_passkey_signin_ceremony_in_redis(
+    state: &SessionState,
+    ceremony_id: &str,
+    ceremony: &PasskeySignInCeremony,
+) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    redis_conn
+        .serialize_and_set_key_with_expiry(
+            &get_passkey_signin_ceremony_key(ceremony_id).into(),
+            ceremony,
+            consts::user::REDIS_PASSKEY_CHALLENGE_TTL_IN_SECS,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+pub async fn get_passkey_signin_ceremony_from_redis(
+    state: &SessionState,
+    ceremony_id: &str,
+) -> UserResult<PasskeySignInCeremony> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    let key = get_passkey_signin_ceremony_key(ceremony_id).into();
+    let ceremony = redis_conn
+        .get_and_deserialize_key(&key, "PasskeySignInCeremony")
+        .await
+        .map_err(|error| match error.current_context() {
+            RedisError::NotFound => error.change_context(UserErrors::PasskeyChallengeNotFound),
+            _ => error.change_context(UserErrors::InternalServerError),
+        })?;
+
+    // Challenges are single use
+    redis_conn
+        .delete_key(&key)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok(ceremony)
+}
+
+fn get_passkey_signin_ceremony_key(ceremony_id: &str) -> String {
+    format!(
+        "{}{}",
+        consts::user::REDIS_PASSKEY_SIGNIN_PREFIX,
+        ceremony_id
+    )
+}
+
+pub async fn check_passkey_in_redis(state: &SessionState, user_id: &str) -> UserResult<bool> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    let key = format!("{}{}", consts::user::REDIS_PASSKEY_PREFIX, user_id);
+    redis_conn
+        .exists::<()>(&key.into())
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+pub async fn insert_passkey_in_redis(state: &SessionState, user_id: &str) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    let key = format!("{}{}", consts::user::REDIS_PASSKEY_PREFIX, user_id);
+    redis_conn
+        .set_key_with_expiry(
+            &key.as_str().into(),
+            common_utils::date_time::now_unix_timestamp(),
+            state.conf.user.two_factor_auth_expiry_in_secs,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+/// Verifies the assertion against the passkeys of the user and persists the updated signature
+/// counter of the passkey which was used.
+pub async fn verify_passkey_authentication(
+    state: &SessionState,
+    user_id: &str,
+    authentication: &PasskeyAuthentication,
+    credential: serde_json::Value,
+) -> UserResult<()> {
+    let credential = credential
+        .parse_value("PublicKeyCredential")
+        .change_context(UserErrors::InvalidPasskey)?;
+
+    let authentication_result = get_webauthn(state)?
+        .finish_passkey_authentication(&credential, authentication)
+        .change_context(UserErrors::InvalidPasskey)?;
+
+    let user_passkeys = get_passkeys_for_user(state, user_id).await?;
+    for user_passkey in user_passkeys {
+        let mut passkey = parse_passkey(&user_passkey)?;
+        if passkey.cred_id() != authentication_result.cred_id() {
+            continue;
+        }
+
+        passkey.update_credential(&authentication_result);
+        state
+            .global_store
+            .update_user_passkey_by_user_id_passkey_id(
+                user_id,
+                &user_passkey.id,
+                diesel_models::user_passkey::UserPasskeyUpdate::CredentialUsed {
+                    passkey: serde_json::to_value(passkey)
+                        .change_context(UserErrors::InternalServerError)?,
+                },
+            )
+            .await
+            .change_context(UserErrors::InternalServerError)?;
+
+        return Ok(());
+    }
+
+    Err(report!(UserErrors::InvalidPasskey))
+        .attach_printable("Passkey used for authentication is not registered for the user")
+}
+
+/// Ensures that the user has authenticated with a passkey in this session when the organization
+/// requires passkeys. When `is_session_check` is false, having a registered passkey is enough.
+pub async fn validate_passkey_for_org(
+    state: &SessionState,
+    user_id: &str,
+    org_id: &id_type::OrganizationId,
+    is_session_check: bool,
+) -> UserResult<()> {
+    let organization = state
+        .accounts_store
+        .find_organization_by_org_id(org_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+    if !organization.is_passkey_required.unwrap_or(false) {
+        return Ok(());
+    }
+
+    let is_passkey_verified = if is_session_check {
+        check_passkey_in_redis(state, user_id).await?
+    } else {
+        !get_passkeys_for_user(state, user_id).await?.is_empty()
+    };
+
+    if !is_passkey_verified {
+        return Err(report!(UserErrors::PasskeyRequired))
+            .attach_printable(format!("Organization {org_id:?} requires passkeys"));
+    }
+
+    Ok(())
+}
@@ -294,6 +294,8 @@ pub async fn generate_sample_data(
             order_date: None,
             discount_amount: None,
             duty_amount: None,
+            purchase_order_number: None,
+            mit_category: None,
             tax_status: None,
             shipping_amount_tax: None,
             enable_partial_authorization: None,
@@ -394,6 +396,8 @@ pub async fn generate_sample_data(
             connector_request_reference_id: None,
             network_transaction_id: None,
             network_details: None,
+            dcc_details: None,
+            device_fingerprint: None,
         };
 
         let refund = if refunds_count < number_of_refunds && !is_failed_payment {
@@ -435,6 +439,7 @@ pub async fn generate_sample_data(
                 organization_id: org_id.clone(),
                 processor_refund_data: None,
                 processor_transaction_data,
+                created_by: None,
             })
         } else {
             None
@@ -477,6 +482,7 @@ pub async fn generate_sample_data(
                     dispute_amount: MinorUnit::new(amount * 100),
                     organization_id: org_id.clone(),
                     dispute_currency: Some(payment_intent.currency.unwrap_or_default()),
+                    automated_action: None,
                 })
             } else {
                 None
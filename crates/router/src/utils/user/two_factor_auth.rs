@@ -1,12 +1,19 @@
-use common_utils::pii;
-use error_stack::ResultExt;
+use std::collections::HashSet;
+
+use common_enums::{PermissionGroup, RolePermission};
+use common_utils::{id_type, pii};
+use diesel_models::enums::{TotpStatus, UserStatus};
+use error_stack::{report, ResultExt};
 use masking::{ExposeInterface, PeekInterface};
+use router_env::logger;
 use totp_rs::{Algorithm, TOTP};
 
 use crate::{
     consts,
     core::errors::{UserErrors, UserResult},
+    db::user_role::ListUserRolesByUserIdPayload,
     routes::SessionState,
+    services::authorization::{permission_groups::PermissionGroupExt, roles::RoleInfo},
 };
 
 pub fn generate_default_totp(
@@ -226,3 +233,169 @@ pub async fn delete_recovery_code_attempts_from_redis(
         .change_context(UserErrors::InternalServerError)
         .map(|_| ())
 }
+
+/// Checks if the role is sensitive enough to require two factor authentication, which is the case
+/// for every role when it is forced for all users.
+pub fn is_two_factor_auth_required_for_role(state: &SessionState, role_info: &RoleInfo) -> bool {
+    state.conf.user.force_two_factor_auth
+        || grants_two_factor_auth_required_groups(
+            &state.conf.user.two_factor_auth_required_groups,
+            &role_info.get_permission_groups(),
+            role_info.get_permissions(),
+        )
+}
+
+/// Checks if the groups or the individual permissions of a role grant any of the access of the
+/// groups which require two factor authentication
+fn grants_two_factor_auth_required_groups(
+    required_groups: &HashSet<PermissionGroup>,
+    groups: &[PermissionGroup],
+    permissions: &[RolePermission],
+) -> bool {
+    groups.iter().any(|group| required_groups.contains(group))
+        || required_groups.iter().any(|required_group| {
+            permissions.iter().any(|permission| {
+                permission.scope >= required_group.scope()
+                    && required_group.resources().contains(&permission.resource)
+            })
+        })
+}
+
+/// Checks if any of the active roles of the user requires two factor authentication
+pub async fn is_two_factor_auth_required_for_user(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<bool> {
+    if state.conf.user.force_two_factor_auth {
+        return Ok(true);
+    }
+    if state.conf.user.two_factor_auth_required_groups.is_empty() {
+        return Ok(false);
+    }
+
+    let user_roles = state
+        .global_store
+        .list_user_roles_by_user_id(ListUserRolesByUserIdPayload {
+            user_id,
+            tenant_id: &state.tenant.tenant_id,
+            org_id: None,
+            merchant_id: None,
+            profile_id: None,
+            entity_id: None,
+            version: None,
+            status: Some(UserStatus::Active),
+            limit: None,
+        })
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    for user_role in user_roles {
+        // A role which can't be found, such as a deleted custom role, grants no access
+        let role_info = match user_role.org_id {
+            Some(org_id) => RoleInfo::from_role_id_org_id_tenant_id(
+                state,
+                &user_role.role_id,
+                &org_id,
+                &user_role.tenant_id,
+            )
+            .await
+            .inspect_err(|error| {
+                logger::warn!(?error, role_id = %user_role.role_id, "Failed to fetch role info");
+            })
+            .ok(),
+            None => RoleInfo::from_predefined_roles(&user_role.role_id),
+        };
+        let Some(role_info) = role_info else {
+            logger::warn!(role_id = %user_role.role_id, "Skipping role in two factor auth check");
+            continue;
+        };
+
+        if is_two_factor_auth_required_for_role(state, &role_info) {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// Ensures that the user has set up two factor authentication before issuing them a token for a
+/// role which requires it.
+pub async fn validate_two_factor_auth_for_role(
+    state: &SessionState,
+    user_id: &str,
+    role_id: &str,
+    org_id: &id_type::OrganizationId,
+    tenant_id: &id_type::TenantId,
+) -> UserResult<()> {
+    if !state.conf.user.force_two_factor_auth
+        && state.conf.user.two_factor_auth_required_groups.is_empty()
+    {
+        return Ok(());
+    }
+
+    let role_info = RoleInfo::from_role_id_org_id_tenant_id(state, role_id, org_id, tenant_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+    if !is_two_factor_auth_required_for_role(state, &role_info) {
+        return Ok(());
+    }
+
+    let user = state
+        .global_store
+        .find_user_by_id(user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+    if user.totp_status != TotpStatus::Set
+        && super::passkey::get_passkeys_for_user(state, user_id)
+            .await?
+            .is_empty()
+    {
+        return Err(report!(UserErrors::TwoFactorAuthNotSetup)).attach_printable(format!(
+            "Role {role_id} requires two factor authentication to be set up"
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use common_enums::{PermissionScope, Resource};
+
+    use super::*;
+
+    #[test]
+    fn test_grants_two_factor_auth_required_groups() {
+        let required_groups = HashSet::from([PermissionGroup::UsersManage]);
+
+        assert!(grants_two_factor_auth_required_groups(
+            &required_groups,
+            &[PermissionGroup::UsersManage],
+            &[],
+        ));
+        assert!(grants_two_factor_auth_required_groups(
+            &required_groups,
+            &[],
+            &[RolePermission {
+                resource: Resource::User,
+                scope: PermissionScope::Write,
+            }],
+        ));
+        assert!(!grants_two_factor_auth_required_groups(
+            &required_groups,
+            &[PermissionGroup::UsersView],
+            &[RolePermission {
+                resource: Resource::User,
+                scope: PermissionScope::Read,
+            }],
+        ));
+        assert!(!grants_two_factor_auth_required_groups(
+            &required_groups,
+            &[],
+            &[RolePermission {
+                resource: Resource::Payment,
+                scope: PermissionScope::Write,
+            }],
+        ));
+    }
+}
@@ -4,7 +4,7 @@ use std::{
 };
 
 use api_models::user_role::role as role_api;
-use common_enums::{EntityType, ParentGroup, PermissionGroup};
+use common_enums::{EntityType, ParentGroup, PermissionGroup, RolePermission};
 use common_utils::id_type;
 use diesel_models::{
     enums::{UserRoleVersion, UserStatus},
@@ -55,6 +55,53 @@ pub fn validate_role_groups(groups: &[PermissionGroup]) -> UserResult<()> {
     Ok(())
 }
 
+/// Validates the groups and the individual permissions of a custom role, which must grant at
+/// least one of them.
+pub fn validate_role_groups_and_permissions(
+    groups: &[PermissionGroup],
+    permissions: &[RolePermission],
+) -> UserResult<()> {
+    if permissions.is_empty() {
+        return validate_role_groups(groups);
+    }
+
+    if !groups.is_empty() {
+        validate_role_groups(groups)?;
+    }
+
+    let unique_permissions: HashSet<_> = permissions.iter().copied().collect();
+
+    if unique_permissions.len() != permissions.len() {
+        return Err(report!(UserErrors::InvalidRoleOperation))
+            .attach_printable("Duplicate permission found");
+    }
+
+    if !permissions
+        .iter()
+        .all(|permission| is_grantable_permission(*permission))
+    {
+        return Err(report!(UserErrors::InvalidRoleOperation))
+            .attach_printable("Invalid permissions present in the custom role");
+    }
+
+    Ok(())
+}
+
+/// Checks if the permission can be granted to a custom role, which is the case for the resource
+/// and scope pairs of the groups that can be granted to it
+fn is_grantable_permission(permission: RolePermission) -> bool {
+    PermissionGroup::iter()
+        .filter(|group| {
+            !matches!(
+                group,
+                PermissionGroup::OrganizationManage | PermissionGroup::InternalManage
+            )
+        })
+        .any(|group| {
+            group.scope() == permission.scope && group.resources().contains(&permission.resource)
+        })
+}
+
 pub async fn validate_role_name(
     state: &SessionState,
     role_name: &domain::RoleName,
@@ -582,3 +629,38 @@ pub fn permission_groups_to_parent_group_info(
         })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use common_enums::{PermissionScope, Resource};
+
+    use super::*;
+
+    #[test]
+    fn test_validate_role_groups_and_permissions() {
+        let refund_write = RolePermission {
+            resource: Resource::Refund,
+            scope: PermissionScope::Write,
+        };
+
+        assert!(validate_role_groups_and_permissions(&[], &[refund_write]).is_ok());
+        assert!(validate_role_groups_and_permissions(&[], &[]).is_err());
+        assert!(validate_role_groups_and_permissions(&[], &[refund_write, refund_write]).is_err());
+        assert!(validate_role_groups_and_permissions(
+            &[],
+            &[RolePermission {
+                resource: Resource::Analytics,
+                scope: PermissionScope::Write,
+            }]
+        )
+        .is_err());
+        assert!(validate_role_groups_and_permissions(
+            &[],
+            &[RolePermission {
+                resource: Resource::InternalConnector,
+                scope: PermissionScope::Write,
+            }]
+        )
+        .is_err());
+    }
+}
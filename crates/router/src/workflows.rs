@@ -15,3 +15,32 @@ pub mod revenue_recovery;
 pub mod process_dispute;
 
 pub mod dispute_list;
+
+pub mod routing_activation;
+
+pub mod merchant_key_re_encryption;
+
+pub mod merchant_key_migration;
+
+pub mod payment_link_expiry;
+
+pub mod payment_intent_expiry;
+
+pub mod subscription_billing;
+
+pub mod soft_decline_retry;
+
+pub mod payment_method_expiry;
+
+pub mod data_retention_purge;
+
+pub mod payment_archival;
+
+#[cfg(feature = "olap")]
+pub mod report_generation;
+
+#[cfg(feature = "olap")]
+pub mod auth_rate_alert;
+
+#[cfg(feature = "payouts")]
+pub mod payout_batch;
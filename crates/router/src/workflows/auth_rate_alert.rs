@@ -0,0 +1,159 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+#[cfg(feature = "v1")]
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+#[cfg(feature = "v1")]
+use crate::{
+    core::auth_rate_alerts, db::StorageInterface,
+    types::storage::auth_rate_alert::AuthRateAlertTrackingData,
+};
+use crate::{errors, routes::SessionState, types::storage};
+
+pub struct AuthRateAlertWorkflow;
+
+/// Number of times a failed evaluation is retried before it is skipped for the window
+#[cfg(feature = "v1")]
+const MAX_AUTH_RATE_EVALUATION_RETRIES: i32 = 3;
+
+/// Delay between the retries of a failed evaluation
+#[cfg(feature = "v1")]
+const AUTH_RATE_EVALUATION_RETRY_DELAY_IN_SECONDS: i64 = 60;
+
+/// This workflow evaluates the authorization rates of a merchant against their baselines, and
+/// reschedules itself to the end of the next evaluation window. The evaluation is skipped while
+/// the alerting is disabled. A failed evaluation is retried a few times, and then skipped for the
+/// window.
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for AuthRateAlertWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: AuthRateAlertTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AuthRateAlertTrackingData")?;
+
+        let config = match db
+            .find_optional_auth_rate_alert_config(&tracking_data.merchant_id)
+            .await
+        {
+            Ok(config) => config,
+            Err(error) => return retry_or_skip_evaluation(db, process, None, error.into()).await,
+        };
+        let Some(config) = config else {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?;
+            return Ok(());
+        };
+
+        if config.is_enabled {
+            if let Err(error) = run_evaluation(state, &config).await {
+                return retry_or_skip_evaluation(db, process, Some(&config), error).await;
+            }
+        } else {
+            logger::info!(
+                merchant_id = ?config.merchant_id,
+                "Authorization rate alerting is disabled, skipping the evaluation"
+            );
+        }
+
+        db.as_scheduler()
+            .reset_process(
+                process,
+                auth_rate_alerts::get_next_evaluation_time(&config, common_utils::date_time::now()),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+#[cfg(feature = "v1")]
+async fn run_evaluation(
+    state: &SessionState,
+    config: &storage::AuthRateAlertConfig,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let db: &dyn StorageInterface = &*state.store;
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &config.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(key_manager_state, &config.merchant_id, &key_store)
+        .await?;
+
+    auth_rate_alerts::evaluate_auth_rates(state, &merchant_account, &key_store, config).await?;
+
+    Ok(())
+}
+
+/// Retries the evaluation after a delay. Once the retries are exhausted, the evaluation of the
+/// window is skipped and the task is rescheduled to the end of the next window. When the config
+/// itself couldn't be fetched, the error is left to the error handler instead.
+#[cfg(feature = "v1")]
+async fn retry_or_skip_evaluation(
+    db: &dyn StorageInterface,
+    process: storage::ProcessTracker,
+    config: Option<&storage::AuthRateAlertConfig>,
+    error: sch_errors::ProcessTrackerError,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    logger::error!(?error, %process.id, "Failed to evaluate the authorization rates");
+    let now = common_utils::date_time::now();
+    if process.retry_count < MAX_AUTH_RATE_EVALUATION_RETRIES {
+        let retry_at = now.saturating_add(time::Duration::seconds(
+            AUTH_RATE_EVALUATION_RETRY_DELAY_IN_SECONDS,
+        ));
+        db.as_scheduler().retry_process(process, retry_at).await?;
+        return Ok(());
+    }
+
+    let Some(config) = config else {
+        return Err(error);
+    };
+    logger::warn!(
+        merchant_id = ?config.merchant_id,
+        "Retries of the authorization rate evaluation exhausted, skipping the window"
+    );
+    db.as_scheduler()
+        .reset_process(
+            process,
+            auth_rate_alerts::get_next_evaluation_time(config, now),
+        )
+        .await?;
+
+    Ok(())
+}
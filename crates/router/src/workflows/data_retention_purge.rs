@@ -0,0 +1,168 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+#[cfg(feature = "v1")]
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+#[cfg(feature = "v1")]
+use crate::{
+    consts,
+    core::data_retention,
+    db::StorageInterface,
+    types::{domain, storage::data_retention::DataRetentionPurgeTrackingData},
+};
+use crate::{errors, routes::SessionState, types::storage};
+
+pub struct DataRetentionPurgeWorkflow;
+
+/// Number of times a failed purge is retried before the run is skipped
+#[cfg(feature = "v1")]
+const MAX_DATA_RETENTION_PURGE_RETRIES: i32 = 3;
+
+/// Delay between the retries of a failed purge
+#[cfg(feature = "v1")]
+const DATA_RETENTION_PURGE_RETRY_DELAY_IN_SECONDS: i64 = 600;
+
+/// This workflow purges the data of a merchant which is past their retention policy, once a day.
+/// The task is rescheduled right away while inactive customers are left to be redacted, and is
+/// only finished once the merchant no longer has a policy. A failed purge is retried a few times,
+/// and then skipped until the next run.
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for DataRetentionPurgeWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: DataRetentionPurgeTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("DataRetentionPurgeTrackingData")?;
+
+        let Some(policy) = db
+            .find_optional_data_retention_policy(&tracking_data.merchant_id)
+            .await?
+        else {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?;
+            return Ok(());
+        };
+
+        let outcome = match purge_merchant_data(state, &tracking_data, &policy).await {
+            Ok(outcome) => outcome,
+            Err(error) => return retry_or_skip_purge(db, process, error).await,
+        };
+
+        let schedule_time = if outcome.has_pending_customers {
+            logger::info!(
+                purge_run_id = %outcome.purge_run.id,
+                "Inactive customers are left to be redacted, rescheduling the purge"
+            );
+            common_utils::date_time::now()
+        } else {
+            common_utils::date_time::now()
+                + time::Duration::seconds(consts::DATA_RETENTION_PURGE_INTERVAL_IN_SECONDS)
+        };
+        db.as_scheduler()
+            .reset_process(process, schedule_time)
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+#[cfg(feature = "v1")]
+async fn purge_merchant_data(
+    state: &SessionState,
+    tracking_data: &DataRetentionPurgeTrackingData,
+    policy: &storage::DataRetentionPolicy,
+) -> Result<data_retention::DataRetentionPurgeOutcome, sch_errors::ProcessTrackerError> {
+    let db: &dyn StorageInterface = &*state.store;
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &key_store,
+        )
+        .await?;
+    let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+        merchant_account,
+        key_store,
+    )));
+
+    Ok(data_retention::run_data_retention_purge(
+        state,
+        &merchant_context,
+        policy,
+        false,
+        "scheduler".to_string(),
+    )
+    .await?)
+}
+
+/// Retries the purge after a delay. Once the retries are exhausted, the purge is skipped and the
+/// task is rescheduled to the next run.
+#[cfg(feature = "v1")]
+async fn retry_or_skip_purge(
+    db: &dyn StorageInterface,
+    process: storage::ProcessTracker,
+    error: sch_errors::ProcessTrackerError,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    logger::error!(?error, %process.id, "Failed to purge the data past the retention policy");
+    let now = common_utils::date_time::now();
+    if process.retry_count < MAX_DATA_RETENTION_PURGE_RETRIES {
+        let retry_at = now.saturating_add(time::Duration::seconds(
+            DATA_RETENTION_PURGE_RETRY_DELAY_IN_SECONDS,
+        ));
+        db.as_scheduler().retry_process(process, retry_at).await?;
+        return Ok(());
+    }
+
+    logger::warn!(
+        "Retries of the data retention purge exhausted, skipping the purge until the next run"
+    );
+    db.as_scheduler()
+        .reset_process(
+            process,
+            now.saturating_add(time::Duration::seconds(
+                consts::DATA_RETENTION_PURGE_INTERVAL_IN_SECONDS,
+            )),
+        )
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::{Encode, ValueExt};
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+#[cfg(feature = "v1")]
+use crate::{core::encryption, types::storage::enums as storage_enums};
+use crate::{errors, logger::error, routes::SessionState, types::storage};
+
+/// Maximum number of times a failing batch is retried before the task is given up
+#[cfg(feature = "v1")]
+const MAX_MIGRATION_RETRIES: i32 = 5;
+
+/// Delay in seconds before a failed batch is retried
+#[cfg(feature = "v1")]
+const MIGRATION_RETRY_DELAY_IN_SECONDS: i64 = 300;
+
+pub struct MerchantKeyMigrationWorkflow;
+
+/// This workflow transfers the locally encrypted merchant keys to the key manager, so that the
+/// data of the merchants is encrypted and decrypted with keys managed by the key manager.
+///
+/// Every run migrates a single batch of merchant keys and persists the progress in the tracking
+/// data before rescheduling itself, so that the task resumes from the last successful batch if it
+/// is interrupted or fails.
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for MerchantKeyMigrationWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::MerchantKeyMigrationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("MerchantKeyMigrationTrackingData")?;
+        let offset = tracking_data.offset;
+
+        let (next_tracking_data, is_completed) =
+            match encryption::migrate_merchant_keys(state, tracking_data).await {
+                Ok(result) => result,
+                Err(error) => {
+                    error!(
+                        ?error,
+                        %process.id,
+                        offset,
+                        "Failed to migrate merchant keys"
+                    );
+                    if process.retry_count >= MAX_MIGRATION_RETRIES {
+                        db.as_scheduler()
+                            .finish_process_with_business_status(
+                                process,
+                                business_status::RETRIES_EXCEEDED,
+                            )
+                            .await?;
+                    } else {
+                        let schedule_time = common_utils::date_time::now().saturating_add(
+                            time::Duration::seconds(MIGRATION_RETRY_DELAY_IN_SECONDS),
+                        );
+                        db.as_scheduler()
+                            .retry_process(process, schedule_time)
+                            .await?;
+                    }
+                    return Ok(());
+                }
+            };
+
+        let now = common_utils::date_time::now();
+        let process_tracker_update = storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: Some(0),
+            schedule_time: Some(now),
+            tracking_data: Some(next_tracking_data.encode_to_value()?),
+            business_status: None,
+            status: Some(storage_enums::ProcessTrackerStatus::New),
+            updated_at: Some(now),
+        };
+        let process = db
+            .as_scheduler()
+            .update_process(process, process_tracker_update)
+            .await?;
+
+        if is_completed {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::{Encode, ValueExt};
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+#[cfg(feature = "v1")]
+use crate::{core::encryption, types::storage::enums as storage_enums};
+use crate::{errors, logger::error, routes::SessionState, types::storage};
+
+/// Maximum number of times a failing batch is retried before the task is given up
+#[cfg(feature = "v1")]
+const MAX_RE_ENCRYPTION_RETRIES: i32 = 5;
+
+/// Delay in seconds before a failed batch is retried
+#[cfg(feature = "v1")]
+const RE_ENCRYPTION_RETRY_DELAY_IN_SECONDS: i64 = 300;
+
+pub struct MerchantKeyReEncryptionWorkflow;
+
+/// This workflow re-encrypts the encrypted data of a merchant once the merchant key has been
+/// rotated in the key manager.
+///
+/// Every run re-encrypts a single batch of records and persists the position reached in the
+/// tracking data before rescheduling itself, so that the task resumes from the last successful
+/// batch if it is interrupted or fails.
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for MerchantKeyReEncryptionWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::MerchantKeyReEncryptionTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("MerchantKeyReEncryptionTrackingData")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let re_encrypted_count = match encryption::re_encrypt_merchant_data(
+            state,
+            &merchant_account,
+            &key_store,
+            tracking_data.stage,
+            tracking_data.offset,
+        )
+        .await
+        {
+            Ok(count) => count,
+            Err(error) => {
+                error!(
+                    ?error,
+                    %process.id,
+                    stage = ?tracking_data.stage,
+                    offset = tracking_data.offset,
+                    "Failed to re-encrypt merchant data"
+                );
+                if process.retry_count >= MAX_RE_ENCRYPTION_RETRIES {
+                    db.as_scheduler()
+                        .finish_process_with_business_status(
+                            process,
+                            business_status::RETRIES_EXCEEDED,
+                        )
+                        .await?;
+                } else {
+                    let schedule_time = common_utils::date_time::now().saturating_add(
+                        time::Duration::seconds(RE_ENCRYPTION_RETRY_DELAY_IN_SECONDS),
+                    );
+                    db.as_scheduler()
+                        .retry_process(process, schedule_time)
+                        .await?;
+                }
+                return Ok(());
+            }
+        };
+
+        let next_tracking_data =
+            if re_encrypted_count < usize::from(encryption::RE_ENCRYPTION_BATCH_SIZE) {
+                tracking_data.stage.next().map(|stage| {
+                    storage::MerchantKeyReEncryptionTrackingData {
+                        stage,
+                        offset: 0,
+                        ..tracking_data.clone()
+                    }
+                })
+            } else {
+                let re_encrypted_count = u32::try_from(re_encrypted_count)
+                    .map_err(|_| errors::ProcessTrackerError::TypeConversionError)?;
+                Some(storage::MerchantKeyReEncryptionTrackingData {
+                    offset: tracking_data.offset.saturating_add(re_encrypted_count),
+                    ..tracking_data.clone()
+                })
+            };
+
+        match next_tracking_data {
+            Some(next_tracking_data) => {
+                let now = common_utils::date_time::now();
+                let process_tracker_update = storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: Some(0),
+                    schedule_time: Some(now),
+                    tracking_data: Some(next_tracking_data.encode_to_value()?),
+                    business_status: None,
+                    status: Some(storage_enums::ProcessTrackerStatus::New),
+                    updated_at: Some(now),
+                };
+                db.as_scheduler()
+                    .update_process(process, process_tracker_update)
+                    .await?;
+            }
+            None => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
@@ -120,6 +120,9 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             delivery_attempt: Some(delivery_attempt),
             metadata: initial_event.metadata,
             is_overall_delivery_successful: Some(false),
+            dead_lettered_at: None,
+            webhook_endpoint_id: initial_event.webhook_endpoint_id,
+            delivery_latency_in_ms: None,
         };
 
         let event = db
@@ -188,6 +191,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                             &merchant_context,
                             outgoing_webhook,
                             &business_profile,
+                            event.webhook_endpoint_id.as_deref(),
                         )
                         .map_err(|error| {
                             logger::error!(
@@ -254,7 +258,11 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
 
 /// Get the schedule time for the specified retry count.
 ///
-/// The schedule time can be configured in configs with this key: `pt_mapping_outgoing_webhooks`.
+/// If the business profile has a webhook retry policy configured, the schedule time is computed
+/// from the policy, and no schedule time is returned once the retries are exhausted or the retry
+/// would happen outside the retry window measured from `retry_window_start`.
+///
+/// Otherwise, the schedule time can be configured in configs with this key: `pt_mapping_outgoing_webhooks`.
 ///
 /// ```json
 /// {
@@ -284,9 +292,21 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
 #[instrument(skip_all)]
 pub(crate) async fn get_webhook_delivery_retry_schedule_time(
     db: &dyn StorageInterface,
-    merchant_id: &id_type::MerchantId,
+    business_profile: &domain::Profile,
     retry_count: i32,
+    retry_window_start: time::PrimitiveDateTime,
 ) -> Option<time::PrimitiveDateTime> {
+    if let Some(webhook_retry_policy) = &business_profile.webhook_retry_policy {
+        let schedule_time = scheduler_utils::get_time_from_delta(
+            webhook_retry_policy.get_retry_delay_in_seconds(retry_count),
+        )?;
+        let elapsed_seconds = (schedule_time - retry_window_start).whole_seconds();
+
+        return webhook_retry_policy
+            .is_within_retry_window(elapsed_seconds)
+            .then_some(schedule_time);
+    }
+
     let key = "pt_mapping_outgoing_webhooks";
 
     let result = db
@@ -318,23 +338,31 @@ pub(crate) async fn get_webhook_delivery_retry_schedule_time(
 
     let time_delta = scheduler_utils::get_outgoing_webhook_retry_schedule_time(
         mapping,
-        merchant_id,
+        &business_profile.merchant_id,
         retry_count,
     );
 
     scheduler_utils::get_time_from_delta(time_delta)
 }
 
-/// Schedule the webhook delivery task for retry
+/// Schedule the webhook delivery task for retry, dead-lettering the initial delivery attempt once
+/// the retries are exhausted
 #[cfg(feature = "v1")]
 #[instrument(skip_all)]
 pub(crate) async fn retry_webhook_delivery_task(
-    db: &dyn StorageInterface,
-    merchant_id: &id_type::MerchantId,
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    merchant_key_store: &domain::MerchantKeyStore,
     process: storage::ProcessTracker,
 ) -> errors::CustomResult<(), errors::StorageError> {
-    let schedule_time =
-        get_webhook_delivery_retry_schedule_time(db, merchant_id, process.retry_count + 1).await;
+    let db = &*state.store;
+    let schedule_time = get_webhook_delivery_retry_schedule_time(
+        db,
+        business_profile,
+        process.retry_count + 1,
+        process.created_at,
+    )
+    .await;
 
     match schedule_time {
         Some(schedule_time) => {
@@ -343,9 +371,40 @@ pub(crate) async fn retry_webhook_delivery_task(
                 .await
         }
         None => {
+            let initial_attempt_id = process
+                .tracking_data
+                .clone()
+                .parse_value::<OutgoingWebhookTrackingData>("OutgoingWebhookTrackingData")
+                .map(|tracking_data| tracking_data.initial_attempt_id);
+
             db.as_scheduler()
                 .finish_process_with_business_status(process, business_status::RETRIES_EXCEEDED)
-                .await
+                .await?;
+
+            match initial_attempt_id {
+                Ok(Some(initial_attempt_id)) => db
+                    .update_event_by_merchant_id_event_id(
+                        &state.into(),
+                        &business_profile.merchant_id,
+                        &initial_attempt_id,
+                        domain::EventUpdate::DeadLetterUpdate {
+                            dead_lettered_at: common_utils::date_time::now(),
+                        },
+                        merchant_key_store,
+                    )
+                    .await
+                    .map(|_| ()),
+                // Tracking data inserted by old version of application does not contain the
+                // initial attempt ID, skip dead-lettering the event
+                Ok(None) => Ok(()),
+                Err(error) => {
+                    logger::error!(
+                        ?error,
+                        "Failed to parse tracking data, skipping dead-lettering the event"
+                    );
+                    Ok(())
+                }
+            }
         }
     }
 }
@@ -571,5 +630,19 @@ async fn get_outgoing_webhook_content_and_event_type(
                 event_type,
             ))
         }
+        // Routing events are always persisted along with their request content, and a routing
+        // config change cannot be fetched again in its state at the time of the event
+        diesel_models::enums::EventClass::Routing => {
+            Err(errors::ProcessTrackerError::ResourceFetchingFailed {
+                resource_name: tracking_data.primary_object_id.clone(),
+            })
+        }
+        // Subscription events describe the outcome of a single billing cycle, which is not
+        // recoverable from the current state of the subscription
+        diesel_models::enums::EventClass::Subscriptions => {
+            Err(errors::ProcessTrackerError::ResourceFetchingFailed {
+                resource_name: tracking_data.primary_object_id.clone(),
+            })
+        }
     }
 }
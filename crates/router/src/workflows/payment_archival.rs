@@ -0,0 +1,97 @@
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    consts, core::payment_archival, db::StorageInterface, errors, routes::SessionState,
+    types::storage,
+};
+
+pub struct PaymentArchivalWorkflow;
+
+/// Number of times a failed archival is retried before the run is skipped
+const MAX_PAYMENT_ARCHIVAL_RETRIES: i32 = 3;
+
+/// Delay between the retries of a failed archival
+const PAYMENT_ARCHIVAL_RETRY_DELAY_IN_SECONDS: i64 = 600;
+
+/// This workflow moves the payments past the archival age to their archive tables, once a day.
+/// The task is rescheduled right away while rows are left to be archived. The archival is skipped
+/// while it is disabled, so that the task resumes once it is enabled again. A failed archival is
+/// retried a few times, and then skipped until the next run, so that the task is never finished.
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PaymentArchivalWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+
+        let has_pending_rows = if state.conf.payment_archival.enabled {
+            match payment_archival::archive_payments(state).await {
+                Ok(has_pending_rows) => has_pending_rows,
+                Err(error) => return retry_or_skip_archival(db, process, error.into()).await,
+            }
+        } else {
+            logger::info!("Payment archival is disabled, skipping the archival");
+            false
+        };
+
+        let schedule_time = if has_pending_rows {
+            logger::info!("Rows are left to be archived, rescheduling the archival");
+            common_utils::date_time::now()
+        } else {
+            common_utils::date_time::now()
+                + time::Duration::seconds(consts::PAYMENT_ARCHIVAL_INTERVAL_IN_SECONDS)
+        };
+        db.as_scheduler()
+            .reset_process(process, schedule_time)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Retries the archival after a delay. Once the retries are exhausted, the archival is skipped
+/// and the task is rescheduled to the next run.
+async fn retry_or_skip_archival(
+    db: &dyn StorageInterface,
+    process: storage::ProcessTracker,
+    error: sch_errors::ProcessTrackerError,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    logger::error!(?error, %process.id, "Failed to archive the payments");
+    let now = common_utils::date_time::now();
+    if process.retry_count < MAX_PAYMENT_ARCHIVAL_RETRIES {
+        let retry_at = now.saturating_add(time::Duration::seconds(
+            PAYMENT_ARCHIVAL_RETRY_DELAY_IN_SECONDS,
+        ));
+        db.as_scheduler().retry_process(process, retry_at).await?;
+        return Ok(());
+    }
+
+    logger::warn!(
+        "Retries of the payment archival exhausted, skipping the archival until the next run"
+    );
+    db.as_scheduler()
+        .reset_process(
+            process,
+            now.saturating_add(time::Duration::seconds(
+                consts::PAYMENT_ARCHIVAL_INTERVAL_IN_SECONDS,
+            )),
+        )
+        .await?;
+
+    Ok(())
+}
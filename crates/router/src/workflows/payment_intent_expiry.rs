@@ -0,0 +1,183 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::{OptionExt, ValueExt};
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+#[cfg(feature = "v1")]
+use error_stack::ResultExt;
+#[cfg(feature = "v1")]
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+#[cfg(feature = "v1")]
+use crate::{
+    consts,
+    core::{
+        errors::StorageErrorExt,
+        payments::{self as payment_flows, operations},
+    },
+    db::StorageInterface,
+    services,
+    types::{api, domain, storage::enums},
+    utils,
+};
+use crate::{errors, routes::SessionState, types::storage};
+
+pub struct PaymentIntentExpiryWorkflow;
+
+/// This workflow cancels a payment which is still awaiting a payment method or confirmation once
+/// the intent expiry configured on its profile has elapsed, and notifies the merchant about it.
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PaymentIntentExpiryWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: api::PaymentsRetrieveRequest = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentsRetrieveRequest")?;
+        let merchant_id = tracking_data
+            .merchant_id
+            .clone()
+            .get_required_value("merchant_id")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(key_manager_state, &merchant_id, &key_store)
+            .await?;
+        let storage_scheme = merchant_account.storage_scheme;
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store.clone(),
+        )));
+
+        let (mut payment_data, _, customer, _, _) =
+            Box::pin(payment_flows::payments_operation_core::<
+                api::PSync,
+                _,
+                _,
+                _,
+                payment_flows::PaymentData<api::PSync>,
+            >(
+                state,
+                state.get_req_state(),
+                &merchant_context,
+                None,
+                operations::PaymentStatus,
+                tracking_data,
+                payment_flows::CallConnectorAction::Avoid,
+                services::AuthFlow::Merchant,
+                None,
+                hyperswitch_domain_models::payments::HeaderPayload::default(),
+            ))
+            .await?;
+
+        if matches!(
+            payment_data.payment_intent.status,
+            enums::IntentStatus::RequiresPaymentMethod | enums::IntentStatus::RequiresConfirmation
+        ) {
+            let cancellation_reason =
+                Some(consts::PAYMENT_INTENT_EXPIRED_CANCELLATION_REASON.to_string());
+            let payment_attempt_update = storage::PaymentAttemptUpdate::VoidUpdate {
+                status: enums::AttemptStatus::Voided,
+                cancellation_reason,
+                updated_by: storage_scheme.to_string(),
+            };
+            payment_data.payment_attempt = db
+                .update_payment_attempt_with_attempt_id(
+                    payment_data.payment_attempt,
+                    payment_attempt_update,
+                    storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+            let payment_intent_update = storage::PaymentIntentUpdate::PGStatusUpdate {
+                status: enums::IntentStatus::Cancelled,
+                updated_by: storage_scheme.to_string(),
+                incremental_authorization_allowed: None,
+                feature_metadata: payment_data
+                    .payment_intent
+                    .feature_metadata
+                    .clone()
+                    .map(masking::Secret::new),
+            };
+            payment_data.payment_intent = db
+                .update_payment_intent(
+                    key_manager_state,
+                    payment_data.payment_intent,
+                    payment_intent_update,
+                    &key_store,
+                    storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+            let profile_id = payment_data
+                .payment_intent
+                .profile_id
+                .as_ref()
+                .get_required_value("profile_id")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Could not find profile_id in payment intent")?;
+
+            let business_profile = db
+                .find_business_profile_by_profile_id(key_manager_state, &key_store, profile_id)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                    id: profile_id.get_string_repr().to_owned(),
+                })?;
+
+            // Trigger the outgoing webhook to notify the merchant about the cancelled payment
+            Box::pin(utils::trigger_payments_webhook(
+                merchant_context,
+                business_profile,
+                payment_data,
+                customer,
+                state,
+                operations::PaymentStatus,
+            ))
+            .await
+            .map_err(|error| logger::warn!(payments_outgoing_webhook_error=?error))
+            .ok();
+        }
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,82 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+#[cfg(feature = "v1")]
+use crate::{core::payment_link, types::domain};
+use crate::{errors, logger::error, routes::SessionState, types::storage};
+
+pub struct PaymentLinkExpiryWorkflow;
+
+/// This workflow expires the payment backing a payment link once the link reaches its
+/// fulfilment time without being paid.
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PaymentLinkExpiryWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::PaymentLinkExpiryTrackingData =
+            process
+                .tracking_data
+                .clone()
+                .parse_value("PaymentLinkExpiryTrackingData")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        let payment_link = db
+            .find_payment_link_by_payment_link_id(&tracking_data.payment_link_id)
+            .await?;
+
+        payment_link::expire_payment_link_payment(state, &merchant_context, &payment_link).await?;
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+#[cfg(feature = "v1")]
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+#[cfg(feature = "v1")]
+use crate::{
+    core::errors::StorageErrorExt,
+    db::StorageInterface,
+    types::storage::{enums, payment_method_health::PaymentMethodExpiryTrackingData},
+};
+use crate::{errors, routes::SessionState, types::storage};
+
+pub struct PaymentMethodExpiryWorkflow;
+
+/// This workflow deactivates a saved card once it has expired. If the card was refreshed by the
+/// account updater in the meantime, the task is rescheduled to the new expiry of the card instead.
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PaymentMethodExpiryWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: PaymentMethodExpiryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentMethodExpiryTrackingData")?;
+
+        let card_expires_at = db
+            .find_optional_payment_method_health(
+                &tracking_data.merchant_id,
+                &tracking_data.payment_method_id,
+            )
+            .await?
+            .and_then(|payment_method_health| payment_method_health.card_expires_at);
+        if let Some(card_expires_at) = card_expires_at
+            .filter(|card_expires_at| *card_expires_at > common_utils::date_time::now())
+        {
+            db.as_scheduler()
+                .reset_process(process, card_expires_at)
+                .await?;
+            return Ok(());
+        }
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+        let storage_scheme = merchant_account.storage_scheme;
+
+        let payment_method = db
+            .find_payment_method(
+                key_manager_state,
+                &key_store,
+                &tracking_data.payment_method_id,
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+        if payment_method.status == enums::PaymentMethodStatus::Active {
+            db.update_payment_method(
+                key_manager_state,
+                &key_store,
+                payment_method,
+                storage::PaymentMethodUpdate::StatusUpdate {
+                    status: Some(enums::PaymentMethodStatus::Inactive),
+                },
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+            logger::info!(
+                payment_method_id = %tracking_data.payment_method_id,
+                "Deactivated the payment method as its card has expired"
+            );
+        }
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,97 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+#[cfg(feature = "v1")]
+use crate::{core::payouts::batch, db::StorageInterface, types::domain};
+use crate::{errors, routes::SessionState, types::storage};
+
+pub struct PayoutBatchWorkflow;
+
+/// This workflow executes the payouts of a batch once its cutoff is reached, unless the batch was
+/// cancelled in the meantime.
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PayoutBatchWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::PayoutBatchTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PayoutBatchTrackingData")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        let payout_batch = db
+            .find_payout_batch_by_merchant_id_batch_id(
+                &tracking_data.merchant_id,
+                &tracking_data.batch_id,
+            )
+            .await?;
+
+        let business_status = if payout_batch.status == storage::enums::PayoutBatchStatus::Cancelled
+        {
+            business_status::REVOKED
+        } else {
+            Box::pin(batch::execute_payout_batch(
+                state,
+                &merchant_context,
+                payout_batch,
+            ))
+            .await?;
+            business_status::COMPLETED_BY_PT
+        };
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status)
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,170 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+#[cfg(feature = "v1")]
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+#[cfg(feature = "v1")]
+use crate::{
+    core::reports, db::StorageInterface, types::storage::report::ReportGenerationTrackingData,
+};
+use crate::{errors, routes::SessionState, types::storage};
+
+pub struct ReportGenerationWorkflow;
+
+/// Number of times the report of a period is retried before it is skipped
+#[cfg(feature = "v1")]
+const MAX_REPORT_GENERATION_RETRIES: i32 = 3;
+
+/// Delay between the retries of the report of a period
+#[cfg(feature = "v1")]
+const REPORT_GENERATION_RETRY_DELAY_IN_SECONDS: i64 = 600;
+
+/// This workflow generates and delivers the report of a template for the last complete period,
+/// and reschedules itself to the end of the next period. The report of an inactive template is
+/// skipped, and the task is only finished once the template is deleted. A report which fails to
+/// be generated is retried a few times, and then skipped for the period.
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for ReportGenerationWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: ReportGenerationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ReportGenerationTrackingData")?;
+
+        let template = match db
+            .find_report_template_by_merchant_id_template_id(
+                &tracking_data.merchant_id,
+                &tracking_data.template_id,
+            )
+            .await
+        {
+            Ok(template) => template,
+            Err(error) if error.current_context().is_db_not_found() => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?;
+                return Ok(());
+            }
+            Err(error) => return retry_or_skip_report(db, process, None, error.into()).await,
+        };
+
+        let now = common_utils::date_time::now();
+        if template.is_active {
+            if let Err(error) = run_scheduled_report(state, &template, now).await {
+                return retry_or_skip_report(db, process, Some(&template), error).await;
+            }
+        } else {
+            logger::info!(
+                template_id = %template.id,
+                "Report template is inactive, skipping the scheduled report"
+            );
+        }
+
+        db.as_scheduler()
+            .reset_process(
+                process,
+                reports::get_next_report_schedule_time(template.frequency, now),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+#[cfg(feature = "v1")]
+async fn run_scheduled_report(
+    state: &SessionState,
+    template: &storage::ReportTemplate,
+    now: time::PrimitiveDateTime,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let db: &dyn StorageInterface = &*state.store;
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &template.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(key_manager_state, &template.merchant_id, &key_store)
+        .await?;
+
+    reports::generate_report(
+        state,
+        &merchant_account,
+        template,
+        reports::get_last_report_period(template.frequency, now),
+        "scheduler".to_string(),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Retries the report of the period after a delay. Once the retries are exhausted, the report of
+/// the period is skipped and the task is rescheduled to the end of the next period. When the
+/// template itself couldn't be fetched, the error is left to the error handler instead.
+#[cfg(feature = "v1")]
+async fn retry_or_skip_report(
+    db: &dyn StorageInterface,
+    process: storage::ProcessTracker,
+    template: Option<&storage::ReportTemplate>,
+    error: sch_errors::ProcessTrackerError,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    logger::error!(?error, %process.id, "Failed to generate the scheduled report");
+    let now = common_utils::date_time::now();
+    if process.retry_count < MAX_REPORT_GENERATION_RETRIES {
+        let retry_at = now.saturating_add(time::Duration::seconds(
+            REPORT_GENERATION_RETRY_DELAY_IN_SECONDS,
+        ));
+        db.as_scheduler().retry_process(process, retry_at).await?;
+        return Ok(());
+    }
+
+    let Some(template) = template else {
+        return Err(error);
+    };
+    logger::warn!(
+        template_id = %template.id,
+        "Retries of the scheduled report exhausted, skipping the report of the period"
+    );
+    db.as_scheduler()
+        .reset_process(
+            process,
+            reports::get_next_report_schedule_time(template.frequency, now),
+        )
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,166 @@
+#[cfg(feature = "v1")]
+use api_models::routing as routing_types;
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+#[cfg(feature = "v1")]
+use scheduler::consumer;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+#[cfg(feature = "v1")]
+use crate::{core::routing, types::domain};
+use crate::{errors, logger::error, routes::SessionState, types::storage};
+
+pub struct RoutingActivationWorkflow;
+
+/// This workflow activates a routing algorithm for a profile at the time
+/// specified while creating the algorithm.
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for RoutingActivationWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::RoutingActivationTrackingData =
+            process
+                .tracking_data
+                .clone()
+                .parse_value("RoutingActivationTrackingData")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        let is_cancelled =
+            routing::helpers::get_routing_scheduled_activations(db, &tracking_data.profile_id)
+                .await?
+                .activations
+                .iter()
+                .find(|activation| activation.algorithm_id == tracking_data.algorithm_id)
+                .is_some_and(|activation| !activation.is_scheduled());
+        if is_cancelled {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status::REVOKED)
+                .await?;
+            return Ok(());
+        }
+
+        let response = Box::pin(routing::link_routing_config(
+            state.clone(),
+            merchant_context,
+            Some(tracking_data.profile_id.clone()),
+            tracking_data.algorithm_id.clone(),
+            tracking_data.transaction_type,
+            tracking_data.created_by,
+        ))
+        .await;
+
+        let (business_status, activation_status, failure_reason) = match response {
+            Ok(_) => (
+                business_status::COMPLETED_BY_PT,
+                routing_types::RoutingActivationStatus::Completed,
+                None,
+            ),
+            Err(error) => {
+                error!(?error, %process.id, "Failed to activate scheduled routing algorithm");
+                (
+                    business_status::FAILURE,
+                    routing_types::RoutingActivationStatus::Failed,
+                    Some(error.current_context().to_string()),
+                )
+            }
+        };
+
+        routing::helpers::update_routing_scheduled_activation_status(
+            db,
+            &tracking_data.profile_id,
+            &tracking_data.algorithm_id,
+            activation_status,
+            failure_reason,
+        )
+        .await
+        .map_err(
+            |error| error!(?error, %process.id, "Failed to record the routing activation status"),
+        )
+        .ok();
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status)
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    #[cfg(feature = "v1")]
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: Result<storage::RoutingActivationTrackingData, _> = process
+            .tracking_data
+            .clone()
+            .parse_value("RoutingActivationTrackingData");
+
+        // The task is not retried, so the activation is reported as failed
+        if let Ok(tracking_data) = tracking_data {
+            routing::helpers::update_routing_scheduled_activation_status(
+                db,
+                &tracking_data.profile_id,
+                &tracking_data.algorithm_id,
+                routing_types::RoutingActivationStatus::Failed,
+                Some("Failed to execute the routing activation task".to_string()),
+            )
+            .await
+            .map_err(|error| {
+                error!(?error, %process.id, "Failed to record the routing activation failure")
+            })
+            .ok();
+        }
+
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+
+    #[cfg(feature = "v2")]
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
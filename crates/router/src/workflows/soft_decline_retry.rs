@@ -0,0 +1,245 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::{Encode, OptionExt, ValueExt};
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+#[cfg(feature = "v1")]
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+#[cfg(feature = "v1")]
+use crate::{
+    core::{
+        errors::StorageErrorExt,
+        payments::{self as payment_flows, soft_decline_retry},
+    },
+    db::StorageInterface,
+    services,
+    types::{
+        api, domain,
+        storage::{enums, payment_attempt::SoftDeclineRetryTrackingData},
+    },
+};
+use crate::{errors, routes::SessionState, types::storage};
+
+pub struct SoftDeclineRetryWorkflow;
+
+/// This workflow retries a merchant initiated payment declined with a retriable decline, with its
+/// saved payment method. If the retry is declined as well, the next retry is scheduled as per the
+/// soft decline retry config of the profile and the limits of the card network.
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for SoftDeclineRetryWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let mut tracking_data: SoftDeclineRetryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("SoftDeclineRetryTrackingData")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+        let storage_scheme = merchant_account.storage_scheme;
+
+        let business_profile = db
+            .find_business_profile_by_profile_id(
+                key_manager_state,
+                &key_store,
+                &tracking_data.profile_id,
+            )
+            .await?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                key_manager_state,
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                &key_store,
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        // The payment may have been retried by the merchant or the retries disabled meanwhile
+        let recurring_details = match business_profile.soft_decline_retry_config.as_ref() {
+            Some(_)
+                if payment_intent.status == enums::IntentStatus::Failed
+                    && payment_intent.active_attempt.get_id() == tracking_data.attempt_id =>
+            {
+                let payment_attempt = db
+                    .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                        &tracking_data.payment_id,
+                        &tracking_data.merchant_id,
+                        &tracking_data.attempt_id,
+                        storage_scheme,
+                    )
+                    .await
+                    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+                soft_decline_retry::get_recurring_details(&payment_intent, &payment_attempt)
+            }
+            _ => None,
+        };
+        let Some(recurring_details) = recurring_details else {
+            logger::info!(
+                payment_id = ?tracking_data.payment_id,
+                "Payment is no longer eligible for soft decline retries"
+            );
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?;
+            return Ok(());
+        };
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store.clone(),
+        )));
+        let payments_request = api_models::payments::PaymentsRequest {
+            payment_id: Some(api_models::payments::PaymentIdType::PaymentIntentId(
+                tracking_data.payment_id.clone(),
+            )),
+            recurring_details: Some(recurring_details),
+            off_session: Some(true),
+            confirm: Some(true),
+            ..Default::default()
+        };
+
+        // Errors are returned before the retry reaches the connector, in which case the task is
+        // handled by the consumer error handler
+        Box::pin(payment_flows::payments_core::<
+            api::Authorize,
+            api_models::payments::PaymentsResponse,
+            _,
+            _,
+            _,
+            payment_flows::PaymentData<api::Authorize>,
+        >(
+            state.clone(),
+            state.get_req_state(),
+            merchant_context,
+            Some(tracking_data.profile_id.clone()),
+            payment_flows::PaymentConfirm,
+            payments_request,
+            services::AuthFlow::Merchant,
+            payment_flows::CallConnectorAction::Trigger,
+            None,
+            hyperswitch_domain_models::payments::HeaderPayload::with_source(
+                enums::PaymentSource::ScheduledRetry,
+            ),
+        ))
+        .await?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                key_manager_state,
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                &key_store,
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+        let payment_attempt = db
+            .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                &payment_intent.active_attempt.get_id(),
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        if payment_intent.status != enums::IntentStatus::Failed {
+            logger::info!(
+                payment_id = ?tracking_data.payment_id,
+                status = ?payment_intent.status,
+                "Soft declined payment was retried successfully"
+            );
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?;
+            return Ok(());
+        }
+
+        tracking_data
+            .declined_at
+            .push(common_utils::date_time::now());
+        let config = business_profile
+            .soft_decline_retry_config
+            .as_ref()
+            .get_required_value("soft_decline_retry_config")?;
+        match soft_decline_retry::get_next_retry_time_for_decline(
+            state,
+            config,
+            &payment_intent,
+            &payment_attempt,
+            &tracking_data.declined_at,
+        )
+        .await
+        {
+            Some(schedule_time) => {
+                tracking_data.attempt_id = payment_attempt.attempt_id;
+                let process_tracker_update = storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: Some(process.retry_count + 1),
+                    schedule_time: Some(schedule_time),
+                    tracking_data: Some(tracking_data.encode_to_value()?),
+                    business_status: None,
+                    status: Some(enums::ProcessTrackerStatus::Pending),
+                    updated_at: Some(common_utils::date_time::now()),
+                };
+                db.as_scheduler()
+                    .update_process(process, process_tracker_update)
+                    .await?;
+            }
+            None => {
+                logger::info!(
+                    payment_id = ?tracking_data.payment_id,
+                    "Soft decline retries of the payment are exhausted"
+                );
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::RETRIES_EXCEEDED)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,194 @@
+#[cfg(feature = "v1")]
+use std::str::FromStr;
+
+#[cfg(feature = "v1")]
+use api_models::subscription::SubscriptionStatus;
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+#[cfg(feature = "v1")]
+use crate::{
+    core::subscription,
+    logger,
+    types::{domain, storage::enums},
+};
+use crate::{errors, logger::error, routes::SessionState, types::storage};
+
+pub struct SubscriptionBillingWorkflow;
+
+/// This workflow charges a subscription at the end of each billing period. Failed payments are
+/// retried as configured on the plan, after which the subscription is deactivated.
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for SubscriptionBillingWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::SubscriptionBillingTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("SubscriptionBillingTrackingData")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let business_profile = db
+            .find_business_profile_by_profile_id(
+                key_manager_state,
+                &key_store,
+                &tracking_data.profile_id,
+            )
+            .await?;
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        let subscription_id = tracking_data.subscription_id.get_string_repr().to_string();
+        let subscription = db
+            .find_by_merchant_id_subscription_id(
+                &tracking_data.merchant_id,
+                subscription_id.clone(),
+            )
+            .await?;
+
+        let is_billable = SubscriptionStatus::from_str(&subscription.status)
+            .is_ok_and(|status| status.is_billable());
+        let Some(plan_id) = subscription.plan_id.clone().filter(|_| is_billable) else {
+            logger::info!(
+                %subscription_id,
+                status = %subscription.status,
+                "Subscription is no longer billable"
+            );
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?;
+            return Ok(());
+        };
+        let plan =
+            subscription::find_subscription_plan(db, &tracking_data.merchant_id, &plan_id).await?;
+
+        let payment_id = common_utils::id_type::PaymentId::default();
+        let payment_status = subscription::create_billing_cycle_payment(
+            state,
+            state.get_req_state(),
+            &merchant_context,
+            &subscription,
+            &plan,
+            payment_id.clone(),
+        )
+        .await
+        .map(|payments_response| payments_response.status)
+        .inspect_err(
+            |error| logger::error!(?error, %subscription_id, "Subscription billing payment failed"),
+        )
+        .unwrap_or(enums::IntentStatus::Failed);
+
+        let now = common_utils::date_time::now();
+        let mut subscription_update = storage::SubscriptionUpdate::new(None, None);
+        subscription_update.last_payment_id = Some(payment_id);
+
+        let event_type = match payment_status {
+            enums::IntentStatus::Succeeded
+            | enums::IntentStatus::Processing
+            | enums::IntentStatus::RequiresCapture
+            | enums::IntentStatus::PartiallyCaptured
+            | enums::IntentStatus::PartiallyCapturedAndCapturable => {
+                let period_start = subscription.current_period_end.unwrap_or(now);
+                let period_end = subscription::add_billing_interval(
+                    period_start,
+                    plan.billing_interval,
+                    plan.billing_interval_count,
+                );
+                subscription_update.status = Some(SubscriptionStatus::Active.to_string());
+                subscription_update.current_period_start = Some(period_start);
+                subscription_update.current_period_end = Some(period_end);
+
+                db.as_scheduler().reset_process(process, period_end).await?;
+
+                if payment_status == enums::IntentStatus::Processing {
+                    enums::EventType::SubscriptionPaymentProcessing
+                } else {
+                    enums::EventType::SubscriptionPaymentSucceeded
+                }
+            }
+            _ if process.retry_count < plan.max_payment_retries => {
+                subscription_update.status = Some(SubscriptionStatus::PastDue.to_string());
+
+                let retry_at = now.saturating_add(time::Duration::hours(
+                    plan.payment_retry_interval_hours.into(),
+                ));
+                db.as_scheduler().retry_process(process, retry_at).await?;
+
+                enums::EventType::SubscriptionPaymentFailed
+            }
+            _ => {
+                subscription_update.status = Some(SubscriptionStatus::InActive.to_string());
+
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::RETRIES_EXCEEDED)
+                    .await?;
+
+                enums::EventType::SubscriptionInactive
+            }
+        };
+
+        let subscription = db
+            .update_subscription_entry(
+                &tracking_data.merchant_id,
+                subscription_id,
+                subscription_update,
+            )
+            .await?;
+
+        subscription::trigger_subscription_webhook(
+            state,
+            &merchant_context,
+            &business_profile,
+            &subscription,
+            event_type,
+        )
+        .await;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
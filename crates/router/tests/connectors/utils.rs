@@ -541,6 +541,7 @@ pub trait ConnectorActions: Connector {
             test_mode: None,
             payment_method_balance: None,
             connector_api_version: None,
+            merchant_connector_id: None,
             connector_http_status_code: None,
             apple_pay_flow: None,
             external_latency: None,
@@ -1004,6 +1005,8 @@ impl Default for PaymentAuthorizeType {
             payment_channel: None,
             enable_partial_authorization: None,
             enable_overcapture: None,
+            mit_category: None,
+            dcc_data: None,
         };
         Self(data)
     }
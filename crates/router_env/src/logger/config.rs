@@ -103,6 +103,11 @@ pub struct LogTelemetry {
     pub route_to_trace: Option<Vec<String>>,
     /// Interval for collecting the metrics (such as gauge) in background thread
     pub bg_metrics_collection_interval_in_secs: Option<u16>,
+    /// Whether the metrics are exposed in the Prometheus text format, for scraping.
+    pub prometheus_enabled: bool,
+    /// Maximum number of distinct values recorded for each bounded metric attribute (such as the
+    /// merchant or profile), beyond which the values are recorded as `other`.
+    pub max_metric_attribute_cardinality: Option<usize>,
 }
 
 /// Telemetry / tracing.
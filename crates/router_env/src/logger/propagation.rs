@@ -0,0 +1,19 @@
+//! Propagation of the trace context to the services called by the application.
+
+use std::collections::HashMap;
+
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// Returns the headers which propagate the context of the current span to an outbound request, in
+/// the W3C Trace Context format (`traceparent` and `tracestate`). No headers are returned when the
+/// traces pipeline is not enabled, as no propagator is configured then.
+pub fn get_trace_context_headers() -> Vec<(String, String)> {
+    let context = tracing::Span::current().context();
+    let mut headers = HashMap::new();
+
+    opentelemetry::global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&context, &mut headers)
+    });
+
+    headers.into_iter().collect()
+}
@@ -33,7 +33,7 @@ pub fn setup(
         None
     };
 
-    if config.telemetry.metrics_enabled {
+    if config.telemetry.metrics_enabled || config.telemetry.prometheus_enabled {
         setup_metrics_pipeline(&config.telemetry)
     };
 
@@ -295,44 +295,57 @@ fn setup_tracing_pipeline(
 fn setup_metrics_pipeline(config: &config::LogTelemetry) {
     use opentelemetry_otlp::WithExportConfig;
 
-    let exporter_result = opentelemetry_otlp::MetricExporter::builder()
-        .with_tonic()
-        .with_temporality(opentelemetry_sdk::metrics::Temporality::Cumulative)
-        .with_export_config(get_opentelemetry_exporter_config(config))
-        .build();
-
-    let exporter = if config.ignore_errors {
-        #[allow(clippy::print_stderr)] // The logger hasn't been initialized yet
-        exporter_result
-            .inspect_err(|error| eprintln!("Failed to build metrics exporter: {error:?}"))
-            .ok();
-        return;
-    } else {
-        // Safety: This is conditional, there is an option to avoid this behavior at runtime.
-        #[allow(clippy::expect_used)]
-        exporter_result.expect("Failed to build metrics exporter")
-    };
-
-    let reader = opentelemetry_sdk::metrics::PeriodicReader::builder(
-        exporter,
-        // The runtime would have to be updated if a different web framework is used
-        opentelemetry_sdk::runtime::TokioCurrentThread,
-    )
-    .with_interval(Duration::from_secs(3))
-    .with_timeout(Duration::from_secs(10))
-    .build();
-
-    let provider = opentelemetry_sdk::metrics::SdkMeterProvider::builder()
-        .with_reader(reader)
+    let mut provider_builder = opentelemetry_sdk::metrics::SdkMeterProvider::builder()
         .with_resource(opentelemetry_sdk::Resource::new([
             opentelemetry::KeyValue::new(
                 "pod",
                 std::env::var("POD_NAME").unwrap_or(String::from("hyperswitch-server-default")),
             ),
-        ]))
-        .build();
+        ]));
+
+    if config.metrics_enabled {
+        let exporter_result = opentelemetry_otlp::MetricExporter::builder()
+            .with_tonic()
+            .with_temporality(opentelemetry_sdk::metrics::Temporality::Cumulative)
+            .with_export_config(get_opentelemetry_exporter_config(config))
+            .build();
+
+        let exporter = if config.ignore_errors {
+            #[allow(clippy::print_stderr)] // The logger hasn't been initialized yet
+            exporter_result
+                .inspect_err(|error| eprintln!("Failed to build metrics exporter: {error:?}"))
+                .ok()
+        } else {
+            // Safety: This is conditional, there is an option to avoid this behavior at runtime.
+            #[allow(clippy::expect_used)]
+            Some(exporter_result.expect("Failed to build metrics exporter"))
+        };
+
+        if let Some(exporter) = exporter {
+            let reader = opentelemetry_sdk::metrics::PeriodicReader::builder(
+                exporter,
+                // The runtime would have to be updated if a different web framework is used
+                opentelemetry_sdk::runtime::TokioCurrentThread,
+            )
+            .with_interval(Duration::from_secs(3))
+            .with_timeout(Duration::from_secs(10))
+            .build();
+
+            provider_builder = provider_builder.with_reader(reader);
+        } else if !config.prometheus_enabled {
+            return;
+        }
+    }
+
+    if config.prometheus_enabled {
+        provider_builder = provider_builder.with_reader(crate::metrics::prometheus::setup_reader());
+    }
+
+    if let Some(max_cardinality) = config.max_metric_attribute_cardinality {
+        crate::metrics::set_max_attribute_cardinality(max_cardinality);
+    }
 
-    opentelemetry::global::set_meter_provider(provider);
+    opentelemetry::global::set_meter_provider(provider_builder.build());
 }
 
 fn get_envfilter(
@@ -84,6 +84,14 @@ pub enum Flow {
     MerchantConnectorsList,
     /// Merchant Transfer Keys
     MerchantTransferKey,
+    /// Merchant key rotation flow.
+    MerchantKeyRotate,
+    /// Merchant keys migration to the key manager flow.
+    MerchantKeyMigrate,
+    /// Merchant keys migration retrieve flow.
+    MerchantKeyMigrationRetrieve,
+    /// Key manager mTLS certificates reload flow.
+    KeyManagerCertificatesReload,
     /// ConfigKey create flow.
     ConfigKeyCreate,
     /// ConfigKey fetch flow.
@@ -104,6 +112,12 @@ pub enum Flow {
     CustomersDelete,
     /// Customers get mandates flow.
     CustomersGetMandates,
+    /// Customers merge flow.
+    CustomersMerge,
+    /// Customers redact flow.
+    CustomersRedact,
+    /// Customers data export flow.
+    CustomersExport,
     /// Create an Ephemeral Key.
     EphemeralKeyCreate,
     /// Delete an Ephemeral Key.
@@ -146,6 +160,12 @@ pub enum Flow {
     PaymentsCreate,
     /// Payments Retrieve flow.
     PaymentsRetrieve,
+    /// Payments batch create flow.
+    PaymentsBatchCreate,
+    /// Payments batch upload flow.
+    PaymentsBatchUpload,
+    /// Payments batch retrieve flow.
+    PaymentsBatchRetrieve,
     /// Payments Retrieve force sync flow.
     PaymentsRetrieveForceSync,
     /// Payments Retrieve using merchant reference id
@@ -174,6 +194,8 @@ pub enum Flow {
     PaymentsFilters,
     /// Payments aggregates flow
     PaymentsAggregate,
+    /// Payments search flow
+    PaymentsSearch,
     /// Payments Create Intent flow
     PaymentsCreateIntent,
     /// Payments Get Intent flow
@@ -203,6 +225,22 @@ pub enum Flow {
     #[cfg(feature = "payouts")]
     /// Payouts fulfill flow.
     PayoutsFulfill,
+    /// Payouts batch create flow.
+    PayoutsBatchCreate,
+    /// Payouts batch retrieve flow.
+    PayoutsBatchRetrieve,
+    /// Payouts batch cancel flow.
+    PayoutsBatchCancel,
+    /// Payout beneficiary create flow.
+    PayoutBeneficiaryCreate,
+    /// Payout beneficiary retrieve flow.
+    PayoutBeneficiaryRetrieve,
+    /// Payout beneficiary update flow.
+    PayoutBeneficiaryUpdate,
+    /// Payout beneficiary delete flow.
+    PayoutBeneficiaryDelete,
+    /// Payout beneficiary list flow.
+    PayoutBeneficiaryList,
     #[cfg(feature = "payouts")]
     /// Payouts list flow.
     PayoutsList,
@@ -225,6 +263,12 @@ pub enum Flow {
     RefundsRetrieveForceSync,
     /// Refunds update flow.
     RefundsUpdate,
+    /// Scheduled refunds cancel flow.
+    RefundsCancel,
+    /// Refunds approve flow.
+    RefundsApprove,
+    /// Refunds reject flow.
+    RefundsReject,
     /// Refunds list flow.
     RefundsList,
     /// Refunds filters flow
@@ -233,6 +277,10 @@ pub enum Flow {
     RefundsAggregate,
     // Retrieve forex flow.
     RetrieveForexFlow,
+    /// Create a quote guaranteeing a conversion rate
+    FxQuoteCreate,
+    /// Retrieve a quote guaranteeing a conversion rate
+    FxQuoteRetrieve,
     /// Toggles recon service for a merchant.
     ReconMerchantUpdate,
     /// Recon token request flow.
@@ -241,6 +289,16 @@ pub enum Flow {
     ReconServiceRequest,
     /// Recon token verification flow
     ReconVerifyToken,
+    /// Settlement report upload flow
+    SettlementReportUpload,
+    /// Settlement report pull flow
+    SettlementReportPull,
+    /// Settlement report retrieve flow
+    SettlementReportRetrieve,
+    /// Recon exceptions list flow
+    ReconExceptionsList,
+    /// Payment recon status retrieve flow
+    PaymentReconStatusRetrieve,
     /// Routing create flow,
     RoutingCreateConfig,
     /// Routing link config
@@ -265,6 +323,14 @@ pub enum Flow {
     RoutingDeleteConfig,
     /// Subscription create flow,
     CreateSubscription,
+    /// Subscription retrieve flow,
+    RetrieveSubscription,
+    /// Subscription payment method attach flow,
+    AttachSubscriptionPaymentMethod,
+    /// Subscription plan create flow,
+    CreateSubscriptionPlan,
+    /// Subscription plan retrieve flow,
+    RetrieveSubscriptionPlan,
     /// Create dynamic routing
     CreateDynamicRoutingConfig,
     /// Toggle dynamic routing
@@ -273,6 +339,8 @@ pub enum Flow {
     UpdateDynamicRoutingConfigs,
     /// Add record to blocklist
     AddToBlocklist,
+    /// Bulk add entries to blocklist
+    BulkAddToBlocklist,
     /// Delete record from blocklist
     DeleteFromBlocklist,
     /// List entries from blocklist
@@ -293,8 +361,16 @@ pub enum Flow {
     ApiKeyUpdate,
     /// API Key revoke flow
     ApiKeyRevoke,
+    /// API Key rotate flow
+    ApiKeyRotate,
     /// API Key list flow
     ApiKeyList,
+    /// Signing key create flow
+    SigningKeyCreate,
+    /// Signing key list flow
+    SigningKeyList,
+    /// Signing key revoke flow
+    SigningKeyRevoke,
     /// Dispute Retrieve flow
     DisputesRetrieve,
     /// Dispute List flow
@@ -321,6 +397,12 @@ pub enum Flow {
     DisputesAggregate,
     /// Retrieve Dispute Evidence flow
     RetrieveDisputeEvidence,
+    /// Dispute decision manager upsert config flow
+    DisputeDecisionManagerUpsertConfig,
+    /// Dispute decision manager retrieve config flow
+    DisputeDecisionManagerRetrieveConfig,
+    /// Dispute decision manager delete config flow
+    DisputeDecisionManagerDeleteConfig,
     /// Invalidate cache flow
     CacheInvalidate,
     /// Payment Link Retrieve flow
@@ -333,6 +415,14 @@ pub enum Flow {
     PaymentLinkList,
     /// Payment Link Status
     PaymentLinkStatus,
+    /// Payment Link Create flow
+    PaymentLinkCreate,
+    /// Payment Link Update flow
+    PaymentLinkUpdate,
+    /// Payment Link Deactivate flow
+    PaymentLinkDeactivate,
+    /// Payment Link Status Retrieve flow
+    PaymentLinkStatusRetrieve,
     /// Create a profile
     ProfileCreate,
     /// Update a profile
@@ -377,10 +467,16 @@ pub enum Flow {
     DecisionManagerDeleteConfig,
     /// Retrieve Decision Manager Config
     DecisionManagerRetrieveConfig,
+    /// Validate Decision Manager Config
+    DecisionManagerValidateConfig,
+    /// Preview the surcharge for a hypothetical payment
+    SurchargePreview,
     /// Manual payment fulfillment acknowledgement
     FrmFulfillment,
     /// Get connectors feature matrix
     FeatureMatrix,
+    /// Get the capabilities of a connector
+    ConnectorCapabilities,
     /// Change password flow
     ChangePassword,
     /// Signout flow
@@ -501,6 +597,30 @@ pub enum Flow {
     TerminateTwoFactorAuth,
     /// Check 2FA status
     TwoFactorAuthStatus,
+    /// Begin passkey registration
+    PasskeyRegisterBegin,
+    /// Finish passkey registration
+    PasskeyRegisterFinish,
+    /// List passkeys of the user
+    PasskeyList,
+    /// Rename a passkey
+    PasskeyUpdate,
+    /// Delete a passkey
+    PasskeyDelete,
+    /// Begin passkey verification for 2FA
+    PasskeyVerifyBegin,
+    /// Verify passkey for 2FA
+    PasskeyVerify,
+    /// Begin sign in with passkey
+    PasskeySignInBegin,
+    /// Sign in with passkey
+    PasskeySignIn,
+    /// List active sessions of the user
+    UserSessionList,
+    /// Revoke a session of the user
+    UserSessionRevoke,
+    /// Revoke all other sessions of the user
+    UserSessionRevokeAll,
     /// Create user authentication method
     CreateUserAuthenticationMethod,
     /// Update user authentication method
@@ -549,18 +669,44 @@ pub enum Flow {
     ListAllThemesInLineage,
     /// Get user theme using lineage
     GetUserThemeUsingLineage,
+    /// List the users provisioned through SCIM
+    ScimUserList,
+    /// Provision a user through SCIM
+    ScimUserCreate,
+    /// Retrieve a user provisioned through SCIM
+    ScimUserRetrieve,
+    /// Replace a user provisioned through SCIM
+    ScimUserReplace,
+    /// Patch a user provisioned through SCIM
+    ScimUserUpdate,
+    /// Deprovision a user through SCIM
+    ScimUserDelete,
+    /// List the groups exposed through SCIM
+    ScimGroupList,
+    /// Retrieve a group exposed through SCIM
+    ScimGroupRetrieve,
+    /// Patch the members of a group through SCIM
+    ScimGroupUpdate,
     /// List initial webhook delivery attempts
     WebhookEventInitialDeliveryAttemptList,
     /// List delivery attempts for a webhook event
     WebhookEventDeliveryAttemptList,
     /// Manually retry the delivery for a webhook event
     WebhookEventDeliveryRetry,
+    /// Retrieve the aggregated delivery health of webhooks of a profile
+    WebhookEventDeliveryHealth,
     /// Retrieve status of the Poll
     RetrievePollStatus,
     /// Toggles the extended card info feature in profile level
     ToggleExtendedCardInfo,
     /// Toggles the extended card info feature in profile level
     ToggleConnectorAgnosticMit,
+    /// FRM velocity rules upsert flow
+    FrmVelocityRulesUpsert,
+    /// FRM velocity rules retrieve flow
+    FrmVelocityRulesRetrieve,
+    /// FRM velocity rules delete flow
+    FrmVelocityRulesDelete,
     /// Get the extended card info associated to a payment_id
     GetExtendedCardInfo,
     /// Manually update the refund details like status, error code, error message etc.
@@ -572,6 +718,8 @@ pub enum Flow {
     ProxyConfirmIntent,
     /// Payments post session tokens flow
     PaymentsPostSessionTokens,
+    /// Payments dynamic currency conversion offer flow
+    PaymentsDccOffer,
     /// Payments Update Metadata
     PaymentsUpdateMetadata,
     /// Payments start redirection flow
@@ -580,6 +728,48 @@ pub enum Flow {
     VolumeSplitOnRoutingType,
     /// Routing evaluate rule flow
     RoutingEvaluateRule,
+    /// Routing simulate config flow
+    RoutingSimulateConfig,
+    /// Routing retrieve version history flow
+    RoutingRetrieveVersionHistory,
+    /// Routing rollback config flow
+    RoutingRollbackConfig,
+    /// Routing scheduled activations list flow
+    RoutingListScheduledActivations,
+    /// Routing scheduled activation cancel flow
+    RoutingCancelScheduledActivation,
+    /// Latency based routing config update flow
+    LatencyBasedRoutingUpdateConfig,
+    /// Latency based routing config retrieve flow
+    LatencyBasedRoutingRetrieveConfig,
+    /// Connector fee schedule upsert flow
+    RoutingUpsertFeeSchedule,
+    /// Connector fee schedule retrieve flow
+    RoutingRetrieveFeeSchedule,
+    /// Connector fee schedule delete flow
+    RoutingDeleteFeeSchedule,
+    /// Routing audit log retrieve flow
+    RoutingRetrieveAuditLog,
+    /// Routing experiment start flow
+    RoutingStartExperiment,
+    /// Routing experiment stop flow
+    RoutingStopExperiment,
+    /// Routing experiment results retrieve flow
+    RoutingRetrieveExperimentResults,
+    /// Payment method default fallback config update flow
+    RoutingUpdatePaymentMethodDefaultConfig,
+    /// Payment method default fallback config retrieve flow
+    RoutingRetrievePaymentMethodDefaultConfig,
+    /// Payment method default fallback config delete flow
+    RoutingDeletePaymentMethodDefaultConfig,
+    /// Routing config export flow
+    RoutingExportConfig,
+    /// Routing config import flow
+    RoutingImportConfig,
+    /// Routing connector health retrieve flow
+    RoutingRetrieveConnectorHealth,
+    /// Routing config diff flow
+    RoutingDiffConfig,
     /// Relay flow
     Relay,
     /// Relay retrieve flow
@@ -618,6 +808,10 @@ pub enum Flow {
     CardsInfoMigrate,
     ///Total payment method count for merchant
     TotalPaymentMethodCount,
+    /// List the usage and expiry of the saved payment methods flow
+    PaymentMethodHealthList,
+    /// Retrieve the usage and expiry of a saved payment method flow
+    PaymentMethodHealthRetrieve,
     /// Process Tracker Revenue Recovery Workflow Retrieve
     RevenueRecoveryRetrieve,
     /// Tokenization flow
@@ -658,6 +852,70 @@ pub enum Flow {
     RecoveryDataBackfill,
     /// Gift card balance check flow
     GiftCardBalanceCheck,
+    /// Admin audit events list flow
+    AdminAuditEventsList,
+    /// Organization admin audit events list flow
+    OrganizationAdminAuditEventsList,
+    /// Connector audit logs list flow
+    ConnectorAuditLogsList,
+    /// Payment review queue list flow
+    PaymentReviewList,
+    /// Payment review approve flow
+    PaymentReviewApprove,
+    /// Payment review decline flow
+    PaymentReviewDecline,
+    /// Data retention policy retrieve flow
+    DataRetentionPolicyRetrieve,
+    /// Data retention policy upsert flow
+    DataRetentionPolicyUpsert,
+    /// Data retention purge flow
+    DataRetentionPurge,
+    /// Data retention purge runs list flow
+    DataRetentionPurgeRunsList,
+    /// List view create flow
+    ListViewCreate,
+    /// List views list flow
+    ListViewList,
+    /// List view retrieve flow
+    ListViewRetrieve,
+    /// List view update flow
+    ListViewUpdate,
+    /// List view delete flow
+    ListViewDelete,
+    /// Report template create flow
+    ReportTemplateCreate,
+    /// Report templates list flow
+    ReportTemplateList,
+    /// Report template retrieve flow
+    ReportTemplateRetrieve,
+    /// Report template update flow
+    ReportTemplateUpdate,
+    /// Report template delete flow
+    ReportTemplateDelete,
+    /// Report run flow
+    ReportRun,
+    /// Report runs list flow
+    ReportRunsList,
+    /// Report run file download flow
+    ReportRunFileDownload,
+    /// Event stream flow
+    EventStream,
+    /// Authorization rate alert config upsert flow
+    AuthRateAlertConfigUpsert,
+    /// Authorization rate alert config retrieve flow
+    AuthRateAlertConfigRetrieve,
+    /// Authorization rate alerts list flow
+    AuthRateAlertsList,
+    /// Prometheus metrics flow
+    PrometheusMetrics,
+    /// Connection pools retrieve flow
+    ConnectionPoolsRetrieve,
+    /// Connection pool resize flow
+    ConnectionPoolResize,
+    /// Drain start flow
+    DrainStart,
+    /// Drain status flow
+    DrainStatus,
 }
 
 /// Trait for providing generic behaviour to flow metric
@@ -133,8 +133,59 @@ macro_rules! metric_attributes {
     };
 }
 
+pub use cardinality::{
+    bounded_attribute_value, set_max_attribute_cardinality, OVERFLOW_ATTRIBUTE_VALUE,
+};
 pub use helpers::f64_histogram_buckets;
 
+pub mod prometheus;
+
+mod cardinality {
+    use std::{
+        collections::{HashMap, HashSet},
+        sync::{LazyLock, Mutex, OnceLock},
+    };
+
+    /// The value recorded for a bounded attribute once its maximum cardinality is reached
+    pub const OVERFLOW_ATTRIBUTE_VALUE: &str = "other";
+
+    const DEFAULT_MAX_ATTRIBUTE_CARDINALITY: usize = 500;
+
+    static MAX_ATTRIBUTE_CARDINALITY: OnceLock<usize> = OnceLock::new();
+
+    static ATTRIBUTE_VALUES: LazyLock<Mutex<HashMap<&'static str, HashSet<String>>>> =
+        LazyLock::new(|| Mutex::new(HashMap::new()));
+
+    /// Sets the maximum number of distinct values recorded for each bounded attribute. The
+    /// maximum can only be set once, before any bounded attribute value is recorded.
+    pub fn set_max_attribute_cardinality(max_cardinality: usize) {
+        let _ = MAX_ATTRIBUTE_CARDINALITY.set(max_cardinality);
+    }
+
+    /// Returns the value to be recorded for an attribute of unbounded cardinality, such as the
+    /// merchant or profile. The first values seen for the attribute are recorded as is, and any
+    /// other value is recorded as [`OVERFLOW_ATTRIBUTE_VALUE`] once the maximum cardinality is
+    /// reached, which bounds the number of time series of the metrics.
+    pub fn bounded_attribute_value(key: &'static str, value: impl Into<String>) -> String {
+        let value = value.into();
+        let max_cardinality =
+            *MAX_ATTRIBUTE_CARDINALITY.get_or_init(|| DEFAULT_MAX_ATTRIBUTE_CARDINALITY);
+        let mut attribute_values = ATTRIBUTE_VALUES
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let values = attribute_values.entry(key).or_default();
+
+        if !values.contains(&value) {
+            if values.len() >= max_cardinality {
+                return OVERFLOW_ATTRIBUTE_VALUE.to_string();
+            }
+            values.insert(value.clone());
+        }
+
+        value
+    }
+}
+
 mod helpers {
     /// Returns the buckets to be used for a f64 histogram
     #[inline(always)]
@@ -0,0 +1,310 @@
+//! Exposition of the metrics in the Prometheus text format.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    sync::{Arc, OnceLock, Weak},
+};
+
+use opentelemetry::KeyValue;
+use opentelemetry_sdk::metrics::{
+    data::{self, ResourceMetrics},
+    reader::MetricReader,
+    InstrumentKind, ManualReader, MetricResult, Pipeline, Temporality,
+};
+
+/// The content type of the metrics rendered in the Prometheus text format
+pub const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+static PROMETHEUS_READER: OnceLock<PrometheusReader> = OnceLock::new();
+
+/// A metric reader which collects the metrics when they are rendered, on every scrape.
+#[derive(Debug, Clone)]
+pub struct PrometheusReader {
+    reader: Arc<ManualReader>,
+}
+
+impl MetricReader for PrometheusReader {
+    fn register(&self, pipeline: Weak<Pipeline>) {
+        self.reader.register(pipeline)
+    }
+
+    fn collect(&self, resource_metrics: &mut ResourceMetrics) -> MetricResult<()> {
+        self.reader.collect(resource_metrics)
+    }
+
+    fn force_flush(&self) -> MetricResult<()> {
+        self.reader.force_flush()
+    }
+
+    fn shutdown(&self) -> MetricResult<()> {
+        self.reader.shutdown()
+    }
+
+    fn temporality(&self, _kind: InstrumentKind) -> Temporality {
+        Temporality::Cumulative
+    }
+}
+
+/// Returns the reader to be registered with the meter provider, for rendering the metrics in the
+/// Prometheus text format.
+pub(crate) fn setup_reader() -> PrometheusReader {
+    PROMETHEUS_READER
+        .get_or_init(|| PrometheusReader {
+            reader: Arc::new(ManualReader::default()),
+        })
+        .clone()
+}
+
+/// Collects the metrics and renders them in the Prometheus text format. Returns `None` if the
+/// Prometheus exposition of the metrics is not enabled.
+pub fn render_metrics() -> Option<MetricResult<String>> {
+    let reader = PROMETHEUS_READER.get()?;
+    let mut resource_metrics = ResourceMetrics {
+        resource: opentelemetry_sdk::Resource::empty(),
+        scope_metrics: Vec::new(),
+    };
+
+    Some(
+        reader
+            .collect(&mut resource_metrics)
+            .map(|()| encode(&resource_metrics)),
+    )
+}
+
+/// The samples of a metric family, which must be rendered together
+struct MetricFamily {
+    metric_type: &'static str,
+    help: String,
+    samples: String,
+}
+
+fn encode(resource_metrics: &ResourceMetrics) -> String {
+    let mut families: BTreeMap<String, MetricFamily> = BTreeMap::new();
+
+    for metric in resource_metrics
+        .scope_metrics
+        .iter()
+        .flat_map(|scope_metrics| scope_metrics.metrics.iter())
+    {
+        let data = metric.data.as_any();
+        let name = sanitize_name(&metric.name);
+
+        if let Some(sum) = data.downcast_ref::<data::Sum<u64>>() {
+            encode_sum(&mut families, &name, &metric.description, sum);
+        } else if let Some(sum) = data.downcast_ref::<data::Sum<i64>>() {
+            encode_sum(&mut families, &name, &metric.description, sum);
+        } else if let Some(sum) = data.downcast_ref::<data::Sum<f64>>() {
+            encode_sum(&mut families, &name, &metric.description, sum);
+        } else if let Some(gauge) = data.downcast_ref::<data::Gauge<u64>>() {
+            encode_gauge(&mut families, &name, &metric.description, gauge);
+        } else if let Some(gauge) = data.downcast_ref::<data::Gauge<i64>>() {
+            encode_gauge(&mut families, &name, &metric.description, gauge);
+        } else if let Some(gauge) = data.downcast_ref::<data::Gauge<f64>>() {
+            encode_gauge(&mut families, &name, &metric.description, gauge);
+        } else if let Some(histogram) = data.downcast_ref::<data::Histogram<f64>>() {
+            encode_histogram(&mut families, &name, &metric.description, histogram);
+        } else if let Some(histogram) = data.downcast_ref::<data::Histogram<u64>>() {
+            encode_histogram(&mut families, &name, &metric.description, histogram);
+        }
+    }
+
+    let mut output = String::new();
+    for (name, family) in families {
+        if !family.help.is_empty() {
+            let _ = writeln!(output, "# HELP {name} {}", escape_help(&family.help));
+        }
+        let _ = writeln!(output, "# TYPE {name} {}", family.metric_type);
+        output.push_str(&family.samples);
+    }
+
+    output
+}
+
+fn get_family<'a>(
+    families: &'a mut BTreeMap<String, MetricFamily>,
+    name: &str,
+    metric_type: &'static str,
+    description: &str,
+) -> &'a mut MetricFamily {
+    families
+        .entry(name.to_string())
+        .or_insert_with(|| MetricFamily {
+            metric_type,
+            help: description.to_string(),
+            samples: String::new(),
+        })
+}
+
+fn encode_sum<T: PrometheusValue>(
+    families: &mut BTreeMap<String, MetricFamily>,
+    name: &str,
+    description: &str,
+    sum: &data::Sum<T>,
+) {
+    let (name, metric_type) = if sum.is_monotonic {
+        (format!("{name}_total"), "counter")
+    } else {
+        (name.to_string(), "gauge")
+    };
+    let family = get_family(families, &name, metric_type, description);
+
+    for data_point in &sum.data_points {
+        write_sample(
+            &mut family.samples,
+            &name,
+            &data_point.attributes,
+            None,
+            &data_point.value.to_prometheus_value(),
+        );
+    }
+}
+
+fn encode_gauge<T: PrometheusValue>(
+    families: &mut BTreeMap<String, MetricFamily>,
+    name: &str,
+    description: &str,
+    gauge: &data::Gauge<T>,
+) {
+    let family = get_family(families, name, "gauge", description);
+
+    for data_point in &gauge.data_points {
+        write_sample(
+            &mut family.samples,
+            name,
+            &data_point.attributes,
+            None,
+            &data_point.value.to_prometheus_value(),
+        );
+    }
+}
+
+fn encode_histogram<T: PrometheusValue>(
+    families: &mut BTreeMap<String, MetricFamily>,
+    name: &str,
+    description: &str,
+    histogram: &data::Histogram<T>,
+) {
+    let family = get_family(families, name, "histogram", description);
+    let bucket_name = format!("{name}_bucket");
+
+    for data_point in &histogram.data_points {
+        // The bucket counts of the data point are not cumulative, unlike the Prometheus buckets
+        let mut cumulative_count = 0;
+        for (bound, count) in data_point.bounds.iter().zip(&data_point.bucket_counts) {
+            cumulative_count += count;
+            write_sample(
+                &mut family.samples,
+                &bucket_name,
+                &data_point.attributes,
+                Some(&bound.to_prometheus_value()),
+                &cumulative_count.to_string(),
+            );
+        }
+        write_sample(
+            &mut family.samples,
+            &bucket_name,
+            &data_point.attributes,
+            Some("+Inf"),
+            &data_point.count.to_string(),
+        );
+        write_sample(
+            &mut family.samples,
+            &format!("{name}_sum"),
+            &data_point.attributes,
+            None,
+            &data_point.sum.to_prometheus_value(),
+        );
+        write_sample(
+            &mut family.samples,
+            &format!("{name}_count"),
+            &data_point.attributes,
+            None,
+            &data_point.count.to_string(),
+        );
+    }
+}
+
+fn write_sample(
+    output: &mut String,
+    name: &str,
+    attributes: &[KeyValue],
+    bucket_bound: Option<&str>,
+    value: &str,
+) {
+    let mut labels = attributes
+        .iter()
+        .map(|attribute| {
+            format!(
+                "{}=\"{}\"",
+                sanitize_name(attribute.key.as_str()),
+                escape_label_value(&attribute.value.as_str())
+            )
+        })
+        .collect::<Vec<_>>();
+    if let Some(bucket_bound) = bucket_bound {
+        labels.push(format!("le=\"{bucket_bound}\""));
+    }
+
+    if labels.is_empty() {
+        let _ = writeln!(output, "{name} {value}");
+    } else {
+        let _ = writeln!(output, "{name}{{{}}} {value}", labels.join(","));
+    }
+}
+
+/// Converts the metric or attribute name to a valid Prometheus name, which is in lower snake case
+/// by convention.
+fn sanitize_name(name: &str) -> String {
+    name.chars()
+        .enumerate()
+        .map(|(index, character)| match character {
+            'a'..='z' | 'A'..='Z' | '_' => character.to_ascii_lowercase(),
+            '0'..='9' if index > 0 => character,
+            _ => '_',
+        })
+        .collect()
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn escape_help(help: &str) -> String {
+    help.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+trait PrometheusValue {
+    fn to_prometheus_value(&self) -> String;
+}
+
+impl PrometheusValue for u64 {
+    fn to_prometheus_value(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl PrometheusValue for i64 {
+    fn to_prometheus_value(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl PrometheusValue for f64 {
+    fn to_prometheus_value(&self) -> String {
+        if self.is_nan() {
+            "NaN".to_string()
+        } else if self.is_infinite() {
+            if self.is_sign_positive() {
+                "+Inf".to_string()
+            } else {
+                "-Inf".to_string()
+            }
+        } else {
+            self.to_string()
+        }
+    }
+}
@@ -33,6 +33,18 @@ impl DbConnectionParams for Database {
     }
 }
 
+/// Routing of the read-only queries to the read replicas of the database
+#[derive(Debug, Clone)]
+pub struct ReadReplicaConfig {
+    /// Hosts of the replicas in addition to the configured replica database, which are connected
+    /// to with the configuration of the replica database
+    pub additional_hosts: Vec<String>,
+    /// Replication lag beyond which a replica is not queried, until it catches up
+    pub max_replication_lag: std::time::Duration,
+    /// Interval between the checks of the replication lag of the replicas
+    pub lag_check_interval: std::time::Duration,
+}
+
 pub trait TenantConfig: Send + Sync {
     fn get_tenant_id(&self) -> &id_type::TenantId;
     fn get_schema(&self) -> &str;
@@ -1,7 +1,6 @@
 use bb8::PooledConnection;
 use common_utils::errors;
 use diesel::PgConnection;
-use error_stack::ResultExt;
 
 pub type PgPool = bb8::Pool<async_bb8_diesel::ConnectionManager<PgConnection>>;
 
@@ -41,9 +40,19 @@ pub async fn pg_connection_read<T: crate::DatabaseStore>(
     ))]
     let pool = store.get_master_pool();
 
-    pool.get()
-        .await
-        .change_context(crate::errors::StorageError::DatabaseConnectionError)
+    pool.get_connection().await
+}
+
+/// Returns a connection to a read replica, for the read-only queries which can be served with
+/// slightly stale data, such as the list queries of the dashboard. The connection is to the master
+/// database if there are no replicas, or if all the replicas are stale.
+pub async fn pg_connection_read_replica<T: crate::DatabaseStore>(
+    store: &T,
+) -> errors::CustomResult<
+    PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>>,
+    crate::errors::StorageError,
+> {
+    store.get_replica_pool().get_connection().await
 }
 
 pub async fn pg_connection_write<T: crate::DatabaseStore>(
@@ -55,7 +64,5 @@ pub async fn pg_connection_write<T: crate::DatabaseStore>(
     // Since all writes should happen to master DB only choose master DB.
     let pool = store.get_master_pool();
 
-    pool.get()
-        .await
-        .change_context(crate::errors::StorageError::DatabaseConnectionError)
+    pool.get_connection().await
 }
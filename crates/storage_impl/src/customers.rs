@@ -17,7 +17,7 @@ use crate::{
     kv_router_store,
     redis::kv_store::{decide_storage_scheme, KvStorePartition, Op, PartitionKey},
     store::enums::MerchantStorageScheme,
-    utils::{pg_connection_read, pg_connection_write},
+    utils::{pg_connection_read, pg_connection_read_replica, pg_connection_write},
     CustomResult, DatabaseStore, KeyManagerState, MockDb, RouterStore,
 };
 
@@ -655,7 +655,7 @@ impl<T: DatabaseStore> domain::CustomerInterface for RouterStore<T> {
         key_store: &MerchantKeyStore,
         constraints: domain::CustomerListConstraints,
     ) -> CustomResult<Vec<domain::Customer>, StorageError> {
-        let conn = pg_connection_read(self).await?;
+        let conn = pg_connection_read_replica(self).await?;
         let customer_list_constraints =
             diesel_models::query::customers::CustomerListConstraints::from(constraints);
         self.find_resources(
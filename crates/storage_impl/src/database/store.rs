@@ -1,12 +1,22 @@
-use async_bb8_diesel::{AsyncConnection, ConnectionError};
-use bb8::CustomizeConnection;
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, RwLock, Weak,
+    },
+    time::{Duration, Instant},
+};
+
+use async_bb8_diesel::{AsyncConnection, AsyncRunQueryDsl, ConnectionError};
+use bb8::{CustomizeConnection, PooledConnection};
 use common_utils::DbConnectionParams;
 use diesel::PgConnection;
 use error_stack::ResultExt;
+use router_env::logger;
 
 use crate::{
-    config::{Database, TenantConfig},
+    config::{Database, ReadReplicaConfig, TenantConfig},
     errors::{StorageError, StorageResult},
+    metrics,
 };
 
 pub type PgPool = bb8::Pool<async_bb8_diesel::ConnectionManager<PgConnection>>;
@@ -20,16 +30,167 @@ pub trait DatabaseStore: Clone + Send + Sync {
         tenant_config: &dyn TenantConfig,
         test_transaction: bool,
     ) -> StorageResult<Self>;
-    fn get_master_pool(&self) -> &PgPool;
-    fn get_replica_pool(&self) -> &PgPool;
-    fn get_accounts_master_pool(&self) -> &PgPool;
-    fn get_accounts_replica_pool(&self) -> &PgPool;
+    fn get_master_pool(&self) -> &DynamicPgPool;
+    fn get_replica_pool(&self) -> &DynamicPgPool;
+    fn get_accounts_master_pool(&self) -> &DynamicPgPool;
+    fn get_accounts_replica_pool(&self) -> &DynamicPgPool;
+    /// Returns all the connection pools of the store
+    fn get_pools(&self) -> Vec<DynamicPgPool>;
+}
+
+/// A connection pool which can be resized at runtime. The pool is resized by replacing it with a
+/// pool of the new size, and the connections checked out of the replaced pool are closed once
+/// they are returned.
+#[derive(Debug, Clone)]
+pub struct DynamicPgPool {
+    name: &'static str,
+    inner: Arc<RwLock<DynamicPgPoolInner>>,
+}
+
+#[derive(Debug)]
+struct DynamicPgPoolInner {
+    pool: PgPool,
+    database: Database,
+    schema: String,
+    test_transaction: bool,
+}
+
+/// The statistics of a connection pool
+#[derive(Debug, Clone)]
+pub struct PgPoolStats {
+    pub name: &'static str,
+    pub host: String,
+    pub schema: String,
+    pub pool_size: u32,
+    pub connections: u32,
+    pub idle_connections: u32,
+}
+
+impl PgPoolStats {
+    pub fn in_use_connections(&self) -> u32 {
+        self.connections.saturating_sub(self.idle_connections)
+    }
+
+    /// Records the size and the connections of the pool of the tenant
+    pub fn record_metrics(&self, tenant: &str) {
+        let attributes = router_env::metric_attributes!(
+            ("tenant", tenant.to_owned()),
+            ("pool", self.name),
+            ("host", self.host.clone()),
+        );
+
+        metrics::DATABASE_POOL_SIZE.record(self.pool_size.into(), attributes);
+        metrics::DATABASE_POOL_IDLE_CONNECTIONS.record(self.idle_connections.into(), attributes);
+        metrics::DATABASE_POOL_IN_USE_CONNECTIONS
+            .record(self.in_use_connections().into(), attributes);
+    }
+}
+
+impl DynamicPgPool {
+    pub async fn new(
+        name: &'static str,
+        database: &Database,
+        schema: &str,
+        test_transaction: bool,
+    ) -> StorageResult<Self> {
+        let pool = diesel_make_pg_pool(database, schema, test_transaction).await?;
+
+        Ok(Self {
+            name,
+            inner: Arc::new(RwLock::new(DynamicPgPoolInner {
+                pool,
+                database: database.clone(),
+                schema: schema.to_owned(),
+                test_transaction,
+            })),
+        })
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the current pool, which is a cheap handle to the connections of the pool
+    pub fn get_pool(&self) -> PgPool {
+        self.read_inner().pool.clone()
+    }
+
+    /// Checks out a connection from the pool, and records the time spent waiting for it
+    pub async fn get_connection(
+        &self,
+    ) -> StorageResult<PooledConnection<'static, async_bb8_diesel::ConnectionManager<PgConnection>>>
+    {
+        let pool = self.get_pool();
+        let started_at = Instant::now();
+        let connection = pool.get_owned().await;
+
+        metrics::DATABASE_POOL_CONNECTION_WAIT_TIME.record(
+            started_at.elapsed().as_secs_f64(),
+            router_env::metric_attributes!(("pool", self.name)),
+        );
+
+        connection.change_context(StorageError::DatabaseConnectionError)
+    }
+
+    pub fn get_stats(&self) -> PgPoolStats {
+        let inner = self.read_inner();
+        let state = inner.pool.state();
+
+        PgPoolStats {
+            name: self.name,
+            host: inner.database.host.clone(),
+            schema: inner.schema.clone(),
+            pool_size: inner.database.pool_size,
+            connections: state.connections,
+            idle_connections: state.idle_connections,
+        }
+    }
+
+    /// Replaces the pool with a pool of the given size. The minimum number of idle connections is
+    /// lowered to the new size if it exceeds it.
+    pub async fn resize(&self, pool_size: u32) -> StorageResult<()> {
+        if pool_size == 0 {
+            return Err(StorageError::InitializationError)
+                .attach_printable("The size of a connection pool must be greater than zero");
+        }
+
+        let (database, schema, test_transaction) = {
+            let inner = self.read_inner();
+            let database = Database {
+                pool_size,
+                min_idle: inner
+                    .database
+                    .min_idle
+                    .map(|min_idle| min_idle.min(pool_size)),
+                ..inner.database.clone()
+            };
+            (database, inner.schema.clone(), inner.test_transaction)
+        };
+        let pool = diesel_make_pg_pool(&database, &schema, test_transaction).await?;
+
+        let mut inner = self
+            .inner
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        inner.pool = pool;
+        inner.database = database;
+
+        logger::info!(pool = self.name, pool_size, "Resized the connection pool");
+
+        Ok(())
+    }
+
+    fn read_inner(&self) -> std::sync::RwLockReadGuard<'_, DynamicPgPoolInner> {
+        self.inner
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct Store {
-    pub master_pool: PgPool,
-    pub accounts_pool: PgPool,
+    pub master_pool: DynamicPgPool,
+    pub accounts_pool: DynamicPgPool,
 }
 
 #[async_trait::async_trait]
@@ -41,9 +202,15 @@ impl DatabaseStore for Store {
         test_transaction: bool,
     ) -> StorageResult<Self> {
         Ok(Self {
-            master_pool: diesel_make_pg_pool(&config, tenant_config.get_schema(), test_transaction)
-                .await?,
-            accounts_pool: diesel_make_pg_pool(
+            master_pool: DynamicPgPool::new(
+                "master",
+                &config,
+                tenant_config.get_schema(),
+                test_transaction,
+            )
+            .await?,
+            accounts_pool: DynamicPgPool::new(
+                "accounts",
                 &config,
                 tenant_config.get_accounts_schema(),
                 test_transaction,
@@ -52,89 +219,224 @@ impl DatabaseStore for Store {
         })
     }
 
-    fn get_master_pool(&self) -> &PgPool {
+    fn get_master_pool(&self) -> &DynamicPgPool {
         &self.master_pool
     }
 
-    fn get_replica_pool(&self) -> &PgPool {
+    fn get_replica_pool(&self) -> &DynamicPgPool {
         &self.master_pool
     }
 
-    fn get_accounts_master_pool(&self) -> &PgPool {
+    fn get_accounts_master_pool(&self) -> &DynamicPgPool {
         &self.accounts_pool
     }
 
-    fn get_accounts_replica_pool(&self) -> &PgPool {
+    fn get_accounts_replica_pool(&self) -> &DynamicPgPool {
         &self.accounts_pool
     }
+
+    fn get_pools(&self) -> Vec<DynamicPgPool> {
+        vec![self.master_pool.clone(), self.accounts_pool.clone()]
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct ReplicaStore {
-    pub master_pool: PgPool,
-    pub replica_pool: PgPool,
-    pub accounts_master_pool: PgPool,
-    pub accounts_replica_pool: PgPool,
+    pub master_pool: DynamicPgPool,
+    pub accounts_master_pool: DynamicPgPool,
+    pub replicas: Arc<[Replica]>,
+    next_replica: Arc<AtomicUsize>,
+}
+
+/// A read replica of the database, which is only queried while its replication lag is within the
+/// configured limit
+#[derive(Debug)]
+pub struct Replica {
+    pub host: String,
+    pub pool: DynamicPgPool,
+    pub accounts_pool: DynamicPgPool,
+    is_fresh: AtomicBool,
+}
+
+/// The replication lag of the database in milliseconds, which is zero on the primary and on a
+/// replica which has replayed all the changes it received
+const REPLICATION_LAG_QUERY: &str = "CASE \
+    WHEN NOT pg_is_in_recovery() THEN 0 \
+    WHEN pg_last_wal_receive_lsn() = pg_last_wal_replay_lsn() THEN 0 \
+    ELSE COALESCE(EXTRACT(EPOCH FROM now() - pg_last_xact_replay_timestamp()) * 1000, 0) \
+    END::BIGINT";
+
+impl ReplicaStore {
+    /// Returns the next replica which is not stale, the replicas being chosen in turn
+    fn get_fresh_replica(&self) -> Option<&Replica> {
+        let replica_count = self.replicas.len();
+        let start = self.next_replica.fetch_add(1, Ordering::Relaxed);
+
+        (0..replica_count)
+            .filter_map(|offset| {
+                self.replicas
+                    .get(start.wrapping_add(offset) % replica_count)
+            })
+            .find(|replica| replica.is_fresh.load(Ordering::Relaxed))
+    }
 }
 
 #[async_trait::async_trait]
 impl DatabaseStore for ReplicaStore {
-    type Config = (Database, Database);
+    type Config = (Database, Database, ReadReplicaConfig);
     async fn new(
-        config: (Database, Database),
+        config: (Database, Database, ReadReplicaConfig),
         tenant_config: &dyn TenantConfig,
         test_transaction: bool,
     ) -> StorageResult<Self> {
-        let (master_config, replica_config) = config;
-        let master_pool =
-            diesel_make_pg_pool(&master_config, tenant_config.get_schema(), test_transaction)
-                .await
-                .attach_printable("failed to create master pool")?;
-        let accounts_master_pool = diesel_make_pg_pool(
+        let (master_config, replica_config, read_replica_config) = config;
+        let master_pool = DynamicPgPool::new(
+            "master",
             &master_config,
-            tenant_config.get_accounts_schema(),
-            test_transaction,
-        )
-        .await
-        .attach_printable("failed to create accounts master pool")?;
-        let replica_pool = diesel_make_pg_pool(
-            &replica_config,
             tenant_config.get_schema(),
             test_transaction,
         )
         .await
-        .attach_printable("failed to create replica pool")?;
-
-        let accounts_replica_pool = diesel_make_pg_pool(
-            &replica_config,
+        .attach_printable("failed to create master pool")?;
+        let accounts_master_pool = DynamicPgPool::new(
+            "accounts_master",
+            &master_config,
             tenant_config.get_accounts_schema(),
             test_transaction,
         )
         .await
-        .attach_printable("failed to create accounts pool")?;
+        .attach_printable("failed to create accounts master pool")?;
+
+        let replica_configs = std::iter::once(replica_config.clone()).chain(
+            read_replica_config
+                .additional_hosts
+                .iter()
+                .map(|host| Database {
+                    host: host.clone(),
+                    ..replica_config.clone()
+                }),
+        );
+        let mut replicas = Vec::new();
+        for replica_config in replica_configs {
+            let pool = DynamicPgPool::new(
+                "replica",
+                &replica_config,
+                tenant_config.get_schema(),
+                test_transaction,
+            )
+            .await
+            .attach_printable("failed to create replica pool")?;
+            let accounts_pool = DynamicPgPool::new(
+                "accounts_replica",
+                &replica_config,
+                tenant_config.get_accounts_schema(),
+                test_transaction,
+            )
+            .await
+            .attach_printable("failed to create accounts pool")?;
+
+            replicas.push(Replica {
+                host: replica_config.host,
+                pool,
+                accounts_pool,
+                is_fresh: AtomicBool::new(true),
+            });
+        }
+        let replicas: Arc<[Replica]> = replicas.into();
+
+        spawn_replication_lag_monitor(Arc::downgrade(&replicas), read_replica_config);
+
         Ok(Self {
             master_pool,
-            replica_pool,
             accounts_master_pool,
-            accounts_replica_pool,
+            replicas,
+            next_replica: Arc::new(AtomicUsize::new(0)),
         })
     }
 
-    fn get_master_pool(&self) -> &PgPool {
+    fn get_master_pool(&self) -> &DynamicPgPool {
         &self.master_pool
     }
 
-    fn get_replica_pool(&self) -> &PgPool {
-        &self.replica_pool
+    /// Returns the pool of a replica which is not stale, or the master pool if all the replicas
+    /// are stale
+    fn get_replica_pool(&self) -> &DynamicPgPool {
+        self.get_fresh_replica()
+            .map_or(&self.master_pool, |replica| &replica.pool)
     }
 
-    fn get_accounts_master_pool(&self) -> &PgPool {
+    fn get_accounts_master_pool(&self) -> &DynamicPgPool {
         &self.accounts_master_pool
     }
 
-    fn get_accounts_replica_pool(&self) -> &PgPool {
-        &self.accounts_replica_pool
+    fn get_accounts_replica_pool(&self) -> &DynamicPgPool {
+        self.get_fresh_replica()
+            .map_or(&self.accounts_master_pool, |replica| &replica.accounts_pool)
     }
+
+    fn get_pools(&self) -> Vec<DynamicPgPool> {
+        [self.master_pool.clone(), self.accounts_master_pool.clone()]
+            .into_iter()
+            .chain(
+                self.replicas
+                    .iter()
+                    .flat_map(|replica| [replica.pool.clone(), replica.accounts_pool.clone()]),
+            )
+            .collect()
+    }
+}
+
+/// Checks the replication lag of the replicas periodically, and marks the replicas whose lag
+/// exceeds the configured limit as stale. A replica whose lag cannot be checked is marked as
+/// stale as well. The checks stop once the store is dropped.
+fn spawn_replication_lag_monitor(replicas: Weak<[Replica]>, config: ReadReplicaConfig) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(config.lag_check_interval);
+
+        loop {
+            interval.tick().await;
+            let Some(replicas) = replicas.upgrade() else {
+                break;
+            };
+
+            for replica in replicas.iter() {
+                let is_fresh = match get_replication_lag(&replica.pool).await {
+                    Ok(replication_lag) => replication_lag <= config.max_replication_lag,
+                    Err(error) => {
+                        logger::warn!(
+                            ?error,
+                            host = %replica.host,
+                            "Failed to check the replication lag of the replica"
+                        );
+                        false
+                    }
+                };
+
+                if replica.is_fresh.swap(is_fresh, Ordering::Relaxed) != is_fresh {
+                    logger::warn!(
+                        host = %replica.host,
+                        is_fresh,
+                        "Freshness of the replica changed"
+                    );
+                }
+            }
+        }
+    });
+}
+
+async fn get_replication_lag(pool: &DynamicPgPool) -> StorageResult<Duration> {
+    let conn = pool.get_connection().await?;
+    let replication_lag_in_ms: i64 = diesel::select(diesel::dsl::sql::<diesel::sql_types::BigInt>(
+        REPLICATION_LAG_QUERY,
+    ))
+    .get_result_async(&*conn)
+    .await
+    .change_context(StorageError::DatabaseConnectionError)
+    .attach_printable("Failed to query the replication lag")?;
+
+    Ok(Duration::from_millis(
+        u64::try_from(replication_lag_in_ms).unwrap_or_default(),
+    ))
 }
 
 pub async fn diesel_make_pg_pool(
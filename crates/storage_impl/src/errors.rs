@@ -299,6 +299,16 @@ pub enum HealthCheckDecisionEngineError {
     FailedToCallDecisionEngineService,
 }
 
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum HealthCheckKeyManagerError {
+    #[error("Failed to establish Key Manager connection")]
+    FailedToCallKeyManager,
+    #[error("Failed to complete the mTLS handshake with the Key Manager")]
+    MtlsHandshakeFailed,
+    #[error("Key Manager responded with an unhealthy status")]
+    UnhealthyResponse,
+}
+
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum HealthCheckUnifiedConnectorServiceError {
     #[error("Failed to establish Unified Connector Service connection")]
@@ -19,7 +19,7 @@ use serde::de;
 pub use crate::database::store::Store;
 use crate::{
     config::TenantConfig,
-    database::store::PgPool,
+    database::store::DynamicPgPool,
     diesel_error_to_data_error,
     errors::{self, RedisErrorExt, StorageResult},
     lookup::ReverseLookupInterface,
@@ -121,20 +121,24 @@ where
             soft_kill_mode,
         ))
     }
-    fn get_master_pool(&self) -> &PgPool {
+    fn get_master_pool(&self) -> &DynamicPgPool {
         self.router_store.get_master_pool()
     }
-    fn get_replica_pool(&self) -> &PgPool {
+    fn get_replica_pool(&self) -> &DynamicPgPool {
         self.router_store.get_replica_pool()
     }
 
-    fn get_accounts_master_pool(&self) -> &PgPool {
+    fn get_accounts_master_pool(&self) -> &DynamicPgPool {
         self.router_store.get_accounts_master_pool()
     }
 
-    fn get_accounts_replica_pool(&self) -> &PgPool {
+    fn get_accounts_replica_pool(&self) -> &DynamicPgPool {
         self.router_store.get_accounts_replica_pool()
     }
+
+    fn get_pools(&self) -> Vec<DynamicPgPool> {
+        self.router_store.get_pools()
+    }
 }
 
 impl<T: DatabaseStore> RedisConnInterface for KVRouterStore<T> {
@@ -168,7 +172,11 @@ impl<T: DatabaseStore> KVRouterStore<T> {
     }
 
     pub fn get_drainer_stream_name(&self, shard_key: &str) -> String {
-        format!("{{{}}}_{}", shard_key, self.drainer_stream_name)
+        format!(
+            "{}_{}",
+            redis_interface::hash_tag(shard_key),
+            self.drainer_stream_name
+        )
     }
 
     pub async fn push_to_drainer_stream<R>(
@@ -31,7 +31,7 @@ mod reverse_lookup;
 pub mod utils;
 
 use common_utils::{errors::CustomResult, types::keymanager::KeyManagerState};
-use database::store::PgPool;
+use database::store::DynamicPgPool;
 pub mod tokenization;
 #[cfg(not(feature = "payouts"))]
 use hyperswitch_domain_models::{PayoutAttemptInterface, PayoutsInterface};
@@ -85,20 +85,24 @@ where
             .attach_printable("failed to create store")
         }
     }
-    fn get_master_pool(&self) -> &PgPool {
+    fn get_master_pool(&self) -> &DynamicPgPool {
         self.db_store.get_master_pool()
     }
-    fn get_replica_pool(&self) -> &PgPool {
+    fn get_replica_pool(&self) -> &DynamicPgPool {
         self.db_store.get_replica_pool()
     }
 
-    fn get_accounts_master_pool(&self) -> &PgPool {
+    fn get_accounts_master_pool(&self) -> &DynamicPgPool {
         self.db_store.get_accounts_master_pool()
     }
 
-    fn get_accounts_replica_pool(&self) -> &PgPool {
+    fn get_accounts_replica_pool(&self) -> &DynamicPgPool {
         self.db_store.get_accounts_replica_pool()
     }
+
+    fn get_pools(&self) -> Vec<DynamicPgPool> {
+        self.db_store.get_pools()
+    }
 }
 
 impl<T: DatabaseStore> RedisConnInterface for RouterStore<T> {
@@ -219,34 +223,25 @@ impl<T: DatabaseStore> RouterStore<T> {
         execute_query: R,
     ) -> error_stack::Result<Vec<D>, StorageError>
     where
-        D: Debug + Sync + Conversion,
+        D: Debug + Sync + Send + Conversion<DstType = M>,
         R: futures::Future<
                 Output = error_stack::Result<Vec<M>, diesel_models::errors::DatabaseError>,
             > + Send,
-        M: ReverseConversion<D>,
+        M: Send,
     {
-        let resource_futures = execute_query
-            .await
-            .map_err(|error| {
-                let new_err = diesel_error_to_data_error(*error.current_context());
-                error.change_context(new_err)
-            })?
-            .into_iter()
-            .map(|resource| async {
-                resource
-                    .convert(
-                        state,
-                        key_store.key.get_inner(),
-                        key_store.merchant_id.clone().into(),
-                    )
-                    .await
-                    .change_context(StorageError::DecryptionError)
-            })
-            .collect::<Vec<_>>();
-
-        let resources = futures::future::try_join_all(resource_futures).await?;
-
-        Ok(resources)
+        let resources = execute_query.await.map_err(|error| {
+            let new_err = diesel_error_to_data_error(*error.current_context());
+            error.change_context(new_err)
+        })?;
+
+        D::convert_back_multiple(
+            state,
+            resources,
+            key_store.key.get_inner(),
+            key_store.merchant_id.clone().into(),
+        )
+        .await
+        .change_context(StorageError::DecryptionError)
     }
 
     /// # Panics
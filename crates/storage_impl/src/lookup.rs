@@ -38,11 +38,7 @@ impl<T: DatabaseStore> ReverseLookupInterface for RouterStore<T> {
         new: DieselReverseLookupNew,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<DieselReverseLookup, errors::StorageError> {
-        let conn = self
-            .get_master_pool()
-            .get()
-            .await
-            .change_context(errors::StorageError::DatabaseConnectionError)?;
+        let conn = self.get_master_pool().get_connection().await?;
         new.insert(&conn).await.map_err(|er| {
             let new_err = diesel_error_to_data_error(*er.current_context());
             er.change_context(new_err)
@@ -1,4 +1,4 @@
-use router_env::{counter_metric, gauge_metric, global_meter};
+use router_env::{counter_metric, gauge_metric, global_meter, histogram_metric_f64};
 
 global_meter!(GLOBAL_METER, "ROUTER_API");
 
@@ -16,3 +16,14 @@ gauge_metric!(IN_MEMORY_CACHE_ENTRY_COUNT, GLOBAL_METER);
 counter_metric!(IN_MEMORY_CACHE_HIT, GLOBAL_METER);
 counter_metric!(IN_MEMORY_CACHE_MISS, GLOBAL_METER);
 counter_metric!(IN_MEMORY_CACHE_EVICTION_COUNT, GLOBAL_METER);
+
+// Metrics for the database connection pools
+histogram_metric_f64!(DATABASE_POOL_CONNECTION_WAIT_TIME, GLOBAL_METER); // Time spent waiting for a connection
+gauge_metric!(DATABASE_POOL_SIZE, GLOBAL_METER);
+gauge_metric!(DATABASE_POOL_IDLE_CONNECTIONS, GLOBAL_METER);
+gauge_metric!(DATABASE_POOL_IN_USE_CONNECTIONS, GLOBAL_METER);
+
+// Metrics for the Redis connection pools
+gauge_metric!(REDIS_POOL_SIZE, GLOBAL_METER);
+gauge_metric!(REDIS_POOL_CONNECTED_CLIENTS, GLOBAL_METER);
+gauge_metric!(REDIS_POOL_QUEUED_COMMANDS, GLOBAL_METER);
@@ -241,6 +241,8 @@ impl PaymentAttemptInterface for MockDb {
             network_transaction_id: payment_attempt.network_transaction_id,
             is_overcapture_enabled: None,
             network_details: payment_attempt.network_details,
+            dcc_details: payment_attempt.dcc_details,
+            device_fingerprint: payment_attempt.device_fingerprint,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
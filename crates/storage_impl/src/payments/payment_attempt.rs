@@ -46,7 +46,10 @@ use crate::{
     kv_router_store::KVRouterStore,
     lookup::ReverseLookupInterface,
     redis::kv_store::{decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey},
-    utils::{pg_connection_read, pg_connection_write, try_redis_get_else_try_database_get},
+    utils::{
+        pg_connection_read, pg_connection_read_replica, pg_connection_write,
+        try_redis_get_else_try_database_get,
+    },
     DataModelExt, DatabaseStore, RouterStore,
 };
 
@@ -335,7 +338,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
     ) -> CustomResult<PaymentListFilters, errors::StorageError> {
         use hyperswitch_domain_models::behaviour::Conversion;
 
-        let conn = pg_connection_read(self).await?;
+        let conn = pg_connection_read_replica(self).await?;
         let intents = futures::future::try_join_all(pi.iter().cloned().map(|pi| async {
             Conversion::convert(pi)
                 .await
@@ -510,12 +513,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         card_discovery: Option<Vec<common_enums::CardDiscovery>>,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<i64, errors::StorageError> {
-        let conn = self
-            .db_store
-            .get_replica_pool()
-            .get()
-            .await
-            .change_context(errors::StorageError::DatabaseConnectionError)?;
+        let conn = self.db_store.get_replica_pool().get_connection().await?;
         let connector_strings = connector.as_ref().map(|connector| {
             connector
                 .iter()
@@ -554,12 +552,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         card_network: Option<Vec<common_enums::CardNetwork>>,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<i64, errors::StorageError> {
-        let conn = self
-            .db_store
-            .get_replica_pool()
-            .get()
-            .await
-            .change_context(errors::StorageError::DatabaseConnectionError)?;
+        let conn = self.db_store.get_replica_pool().get_connection().await?;
 
         DieselPaymentAttempt::get_total_count_of_attempts(
             &conn,
@@ -695,6 +688,8 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     network_transaction_id: payment_attempt.network_transaction_id.clone(),
                     is_overcapture_enabled: None,
                     network_details: payment_attempt.network_details.clone(),
+                    dcc_details: payment_attempt.dcc_details.clone(),
+                    device_fingerprint: payment_attempt.device_fingerprint.clone(),
                 };
 
                 let field = format!("pa_{}", created_attempt.attempt_id);
@@ -1906,6 +1901,8 @@ impl DataModelExt for PaymentAttempt {
             network_transaction_id: self.network_transaction_id,
             is_overcapture_enabled: self.is_overcapture_enabled,
             network_details: self.network_details,
+            dcc_details: self.dcc_details,
+            device_fingerprint: self.device_fingerprint,
         }
     }
 
@@ -2002,6 +1999,8 @@ impl DataModelExt for PaymentAttempt {
             network_transaction_id: storage_model.network_transaction_id,
             is_overcapture_enabled: storage_model.is_overcapture_enabled,
             network_details: storage_model.network_details,
+            dcc_details: storage_model.dcc_details,
+            device_fingerprint: storage_model.device_fingerprint,
         }
     }
 }
@@ -2095,6 +2094,8 @@ impl DataModelExt for PaymentAttemptNew {
             connector_request_reference_id: self.connector_request_reference_id,
             network_transaction_id: self.network_transaction_id,
             network_details: self.network_details,
+            dcc_details: self.dcc_details,
+            device_fingerprint: self.device_fingerprint,
         }
     }
 
@@ -2181,6 +2182,8 @@ impl DataModelExt for PaymentAttemptNew {
             connector_request_reference_id: storage_model.connector_request_reference_id,
             network_transaction_id: storage_model.network_transaction_id,
             network_details: storage_model.network_details,
+            dcc_details: storage_model.dcc_details,
+            device_fingerprint: storage_model.device_fingerprint,
         }
     }
 }
@@ -9,7 +9,9 @@ use common_utils::{
     types::keymanager::KeyManagerState,
 };
 #[cfg(feature = "olap")]
-use diesel::{associations::HasTable, ExpressionMethods, JoinOnDsl, QueryDsl};
+use diesel::{
+    associations::HasTable, BoolExpressionMethods, ExpressionMethods, JoinOnDsl, QueryDsl,
+};
 #[cfg(feature = "v1")]
 use diesel_models::payment_intent::PaymentIntentUpdate as DieselPaymentIntentUpdate;
 #[cfg(feature = "v2")]
@@ -944,7 +946,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
     ) -> error_stack::Result<Vec<PaymentIntent>, StorageError> {
         use futures::{future::try_join_all, FutureExt};
 
-        let conn = connection::pg_connection_read(self).await?;
+        let conn = connection::pg_connection_read_replica(self).await?;
         let conn = async_bb8_diesel::Connection::as_async_conn(&conn);
 
         //[#350]: Replace this with Boxable Expression and pass it into generic filter
@@ -952,6 +954,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
         let mut query = <DieselPaymentIntent as HasTable>::table()
             .filter(pi_dsl::merchant_id.eq(merchant_id.to_owned()))
             .order(pi_dsl::created_at.desc())
+            .then_order_by(pi_dsl::payment_id.desc())
             .into_boxed();
 
         match filters {
@@ -1008,7 +1011,14 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                     (None, None) => query,
                 };
 
-                query = query.offset(params.offset.into());
+                query = match &params.cursor {
+                    Some(cursor) => query.filter(
+                        pi_dsl::created_at.lt(cursor.sort_key).or(pi_dsl::created_at
+                            .eq(cursor.sort_key)
+                            .and(pi_dsl::payment_id.lt(cursor.id.clone()))),
+                    ),
+                    None => query.offset(params.offset.into()),
+                };
 
                 query = match &params.currency {
                     Some(currency) => query.filter(pi_dsl::currency.eq_any(currency.clone())),
@@ -1087,7 +1097,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
         profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
         time_range: &common_utils::types::TimeRange,
     ) -> error_stack::Result<Vec<(common_enums::IntentStatus, i64)>, StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
+        let conn = connection::pg_connection_read_replica(self).await?;
         let conn = async_bb8_diesel::Connection::as_async_conn(&conn);
 
         let mut query = <DieselPaymentIntent as HasTable>::table()
@@ -1137,7 +1147,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
 
         use crate::DataModelExt;
 
-        let conn = connection::pg_connection_read(self).await?;
+        let conn = connection::pg_connection_read_replica(self).await?;
         let conn = async_bb8_diesel::Connection::as_async_conn(&conn);
         let mut query = DieselPaymentIntent::table()
             .filter(pi_dsl::merchant_id.eq(merchant_id.to_owned()))
@@ -1168,7 +1178,9 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                     Order {
                         on: SortOn::Created,
                         by: SortBy::Desc,
-                    } => query.order(pi_dsl::created_at.desc()),
+                    } => query
+                        .order(pi_dsl::created_at.desc())
+                        .then_order_by(pi_dsl::payment_id.desc()),
                 };
 
                 if let Some(limit) = params.limit {
@@ -1227,7 +1239,14 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                     (None, None) => query,
                 };
 
-                query = query.offset(params.offset.into());
+                query = match &params.cursor {
+                    Some(cursor) => query.filter(
+                        pi_dsl::created_at.lt(cursor.sort_key).or(pi_dsl::created_at
+                            .eq(cursor.sort_key)
+                            .and(pi_dsl::payment_id.lt(cursor.id.clone()))),
+                    ),
+                    None => query.offset(params.offset.into()),
+                };
 
                 query = match params.amount_filter {
                     Some(AmountFilter {
@@ -1349,7 +1368,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
         use diesel::NullableExpressionMethods as _;
         use futures::{future::try_join_all, FutureExt};
 
-        let conn = connection::pg_connection_read(self).await?;
+        let conn = connection::pg_connection_read_replica(self).await?;
         let conn = async_bb8_diesel::Connection::as_async_conn(&conn);
         let mut query = DieselPaymentIntent::table()
             .filter(pi_dsl::merchant_id.eq(merchant_id.to_owned()))
@@ -1553,7 +1572,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
         constraints: &PaymentIntentFetchConstraints,
         _storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<Vec<Option<String>>, StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
+        let conn = connection::pg_connection_read_replica(self).await?;
         let conn = async_bb8_diesel::Connection::as_async_conn(&conn);
         let mut query = DieselPaymentIntent::table()
             .select(pi_dsl::active_attempt_id)
@@ -1641,7 +1660,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
         constraints: &PaymentIntentFetchConstraints,
         _storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<Vec<String>, StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
+        let conn = connection::pg_connection_read_replica(self).await?;
         let conn = async_bb8_diesel::Connection::as_async_conn(&conn);
         let mut query = DieselPaymentIntent::table()
             .select(pi_dsl::active_attempt_id)
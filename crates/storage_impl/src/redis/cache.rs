@@ -2,7 +2,7 @@ use std::{
     any::Any,
     borrow::Cow,
     fmt::Debug,
-    sync::{Arc, LazyLock},
+    sync::{Arc, LazyLock, OnceLock},
 };
 
 use common_utils::{
@@ -106,6 +106,26 @@ pub static CONTRACT_BASED_DYNAMIC_ALGORITHM_CACHE: LazyLock<Cache> = LazyLock::n
     )
 });
 
+/// Time to live of a resolved IP address country, 24 hours
+const GEO_IP_CACHE_TTL: u64 = 24 * 60 * 60;
+
+/// Max number of IP addresses whose country is held
+const GEO_IP_CACHE_MAX_ENTRIES: u64 = 100_000;
+
+/// Countries resolved for IP addresses by the GeoIP provider
+pub static GEO_IP_CACHE: LazyLock<Cache> =
+    LazyLock::new(|| Cache::new_lru("GEO_IP_CACHE", GEO_IP_CACHE_TTL, GEO_IP_CACHE_MAX_ENTRIES));
+
+/// Decrypted merchant data keys, only populated once initialised with [`init_data_key_cache`]
+/// as the time to live is configurable
+pub static DATA_KEY_CACHE: OnceLock<Cache> = OnceLock::new();
+
+/// Initialises the [`DATA_KEY_CACHE`] with the given time to live in seconds and the maximum
+/// number of data keys to be held
+pub fn init_data_key_cache(time_to_live: u64, max_entries: u64) {
+    DATA_KEY_CACHE.get_or_init(|| Cache::new_lru("DATA_KEY_CACHE", time_to_live, max_entries));
+}
+
 /// Trait which defines the behaviour of types that's gonna be stored in Cache
 pub trait Cacheable: Any + Send + Sync + DynClone {
     fn as_any(&self) -> &dyn Any;
@@ -129,6 +149,7 @@ pub enum CacheKind<'a> {
     EliminationBasedDynamicRoutingCache(Cow<'a, str>),
     ContractBasedDynamicRoutingCache(Cow<'a, str>),
     PmFiltersCGraph(Cow<'a, str>),
+    DataKey(Cow<'a, str>),
     All(Cow<'a, str>),
 }
 
@@ -145,6 +166,7 @@ impl CacheKind<'_> {
             | CacheKind::EliminationBasedDynamicRoutingCache(key)
             | CacheKind::ContractBasedDynamicRoutingCache(key)
             | CacheKind::PmFiltersCGraph(key)
+            | CacheKind::DataKey(key)
             | CacheKind::All(key) => key,
         }
     }
@@ -248,6 +270,30 @@ impl Cache {
         }
     }
 
+    /// Creates a moka cache which evicts the least recently used entries once it holds
+    /// `max_entries` entries, and expires them `time_to_live` seconds after they are inserted.
+    pub fn new_lru(name: &'static str, time_to_live: u64, max_entries: u64) -> Self {
+        let eviction_listener = move |_, _, cause| {
+            metrics::IN_MEMORY_CACHE_EVICTION_COUNT.add(
+                1,
+                router_env::metric_attributes!(
+                    ("cache_type", name.to_owned()),
+                    ("removal_cause", format!("{:?}", cause)),
+                ),
+            );
+        };
+
+        Self {
+            name,
+            inner: MokaCache::builder()
+                .time_to_live(std::time::Duration::from_secs(time_to_live))
+                .eviction_policy(moka::policy::EvictionPolicy::lru())
+                .max_capacity(max_entries)
+                .eviction_listener(eviction_listener)
+                .build(),
+        }
+    }
+
     pub async fn push<T: Cacheable>(&self, key: CacheKey, val: T) {
         self.inner.insert(key.into(), Arc::new(val)).await;
     }
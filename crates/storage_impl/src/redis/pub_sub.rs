@@ -6,7 +6,7 @@ use router_env::{logger, tracing::Instrument};
 
 use crate::redis::cache::{
     CacheKey, CacheKind, CacheRedact, ACCOUNTS_CACHE, CGRAPH_CACHE, CONFIG_CACHE,
-    CONTRACT_BASED_DYNAMIC_ALGORITHM_CACHE, DECISION_MANAGER_CACHE,
+    CONTRACT_BASED_DYNAMIC_ALGORITHM_CACHE, DATA_KEY_CACHE, DECISION_MANAGER_CACHE,
     ELIMINATION_BASED_DYNAMIC_ALGORITHM_CACHE, PM_FILTERS_CGRAPH_CACHE, ROUTING_CACHE,
     SUCCESS_BASED_DYNAMIC_ALGORITHM_CACHE, SURCHARGE_CACHE,
 };
@@ -193,6 +193,17 @@ impl PubSubInterface for std::sync::Arc<redis_interface::RedisConnectionPool> {
                                 .await;
                             key
                         }
+                        CacheKind::DataKey(key) => {
+                            if let Some(cache) = DATA_KEY_CACHE.get() {
+                                cache
+                                    .remove(CacheKey {
+                                        key: key.to_string(),
+                                        prefix: message.tenant.clone(),
+                                    })
+                                    .await;
+                            }
+                            key
+                        }
                         CacheKind::All(key) => {
                             CONFIG_CACHE
                                 .remove(CacheKey {
@@ -254,6 +265,14 @@ impl PubSubInterface for std::sync::Arc<redis_interface::RedisConnectionPool> {
                                     prefix: message.tenant.clone(),
                                 })
                                 .await;
+                            if let Some(cache) = DATA_KEY_CACHE.get() {
+                                cache
+                                    .remove(CacheKey {
+                                        key: key.to_string(),
+                                        prefix: message.tenant.clone(),
+                                    })
+                                    .await;
+                            }
 
                             key
                         }
@@ -1,6 +1,5 @@
 use bb8::PooledConnection;
 use diesel::PgConnection;
-use error_stack::ResultExt;
 
 use crate::{
     errors::{RedisErrorExt, StorageError},
@@ -28,9 +27,19 @@ pub async fn pg_connection_read<T: DatabaseStore>(
     ))]
     let pool = store.get_master_pool();
 
-    pool.get()
-        .await
-        .change_context(StorageError::DatabaseConnectionError)
+    pool.get_connection().await
+}
+
+/// Returns a connection to a read replica, for the read-only queries which can be served with
+/// slightly stale data, such as the list queries of the dashboard. The connection is to the master
+/// database if there are no replicas, or if all the replicas are stale.
+pub async fn pg_connection_read_replica<T: DatabaseStore>(
+    store: &T,
+) -> error_stack::Result<
+    PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>>,
+    StorageError,
+> {
+    store.get_replica_pool().get_connection().await
 }
 
 pub async fn pg_connection_write<T: DatabaseStore>(
@@ -42,9 +51,7 @@ pub async fn pg_connection_write<T: DatabaseStore>(
     // Since all writes should happen to master DB only choose master DB.
     let pool = store.get_master_pool();
 
-    pool.get()
-        .await
-        .change_context(StorageError::DatabaseConnectionError)
+    pool.get_connection().await
 }
 
 pub async fn try_redis_get_else_try_database_get<F, RFut, DFut, T>(
@@ -0,0 +1,72 @@
+import http from "k6/http";
+import { check } from "k6";
+import { Counter } from "k6/metrics";
+import { setup_merchant_apikey } from "./helper/setup.js";
+import { random_string } from "./helper/misc.js";
+import { readBaseline, storeResult } from "./helper/compare-result.js";
+
+export const requests = new Counter("http_reqs");
+
+const baseline = readBaseline("payment-create");
+
+export const options = {
+    stages: [
+        { duration: "10s", target: 25 },        // ramp up users to 25 in 10 seconds
+        { duration: "10s", target: 25 },        // maintain 25 users for 10 seconds
+        { duration: "10s", target: 0 }          // ramp down to 0 users in 10 seconds
+    ],
+    thresholds: {
+        'http_req_duration': ['p(90) < 500'],   // 90% of requests must finish within 500ms.
+    },
+};
+
+export function setup() {
+    return setup_merchant_apikey();
+}
+
+export default function(data) {
+    const address = {
+        "address": {
+            "line1": "1467",
+            "line2": "Harrison Street",
+            "line3": "Harrison Street",
+            "city": "San Fransico",
+            "state": "California",
+            "zip": "94122",
+            "country": "US",
+            "first_name": "John",
+            "last_name": "Doe"
+        },
+        "phone": {
+            "number": "9123456789",
+            "country_code": "+1"
+        }
+    };
+    const create_payment_payload = {
+        "amount": 6540,
+        "currency": "USD",
+        "confirm": false,
+        "capture_method": "automatic",
+        "customer_id": random_string(),
+        "description": "Its my first payment request",
+        "return_url": "http://example.com/payments",
+        "authentication_type": "no_three_ds",
+        "shipping": address,
+        "billing": address,
+        "statement_descriptor_name": "Juspay",
+        "statement_descriptor_suffix": "Router"
+    };
+    let create_payment_res = http.post("http://router-server:8080/payments", JSON.stringify(create_payment_payload), {
+        "headers": {
+            "Content-Type": "application/json",
+            "api-key" : data.api_key
+        },
+    });
+    check(create_payment_res, {
+        "create payment status 200": (r) => r.status === 200,
+    });
+};
+
+export function handleSummary(data) {
+    return storeResult("payment-create", baseline, data)
+}
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS routing_audit_log_merchant_id_profile_id_created_at_index;
+
+DROP TABLE IF EXISTS routing_audit_log;
@@ -0,0 +1,15 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS routing_audit_log (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    action VARCHAR(32) NOT NULL,
+    transaction_type "TransactionType" NOT NULL,
+    previous_algorithm_id VARCHAR(64),
+    new_algorithm_id VARCHAR(64),
+    config_diff JSONB,
+    created_by VARCHAR(255),
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS routing_audit_log_merchant_id_profile_id_created_at_index ON routing_audit_log (merchant_id, profile_id, created_at);
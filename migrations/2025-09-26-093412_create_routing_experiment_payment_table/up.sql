@@ -0,0 +1,16 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS routing_experiment_payment (
+    experiment_id VARCHAR(64) NOT NULL,
+    attempt_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    arm VARCHAR(32) NOT NULL,
+    algorithm_id VARCHAR(64) NOT NULL,
+    merchant_connector_ids VARCHAR(64)[] NOT NULL,
+    connector VARCHAR(64),
+    attempt_status "AttemptStatus",
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (experiment_id, attempt_id)
+);
@@ -0,0 +1,10 @@
+-- Your SQL goes here
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'routing';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'routing_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'routing_config_linked';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'routing_config_unlinked';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'routing_default_updated';
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE api_keys DROP COLUMN IF EXISTS allowed_ip_ranges;
+
+ALTER TABLE merchant_account DROP COLUMN IF EXISTS allowed_ip_ranges;
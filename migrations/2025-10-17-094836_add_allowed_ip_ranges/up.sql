@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE api_keys ADD COLUMN IF NOT EXISTS allowed_ip_ranges TEXT[];
+
+ALTER TABLE merchant_account ADD COLUMN IF NOT EXISTS allowed_ip_ranges TEXT[];
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TYPE "UserStatus" ADD VALUE IF NOT EXISTS 'deactivated';
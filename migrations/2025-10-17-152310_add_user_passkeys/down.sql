@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE organization DROP COLUMN IF EXISTS is_passkey_required;
+
+DROP INDEX IF EXISTS user_passkeys_user_id_index;
+
+DROP TABLE IF EXISTS user_passkeys;
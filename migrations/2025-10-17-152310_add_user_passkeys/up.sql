@@ -0,0 +1,13 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS user_passkeys (
+    id VARCHAR(64) PRIMARY KEY,
+    user_id VARCHAR(64) NOT NULL,
+    name VARCHAR(64) NOT NULL,
+    passkey JSONB NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    last_used_at TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS user_passkeys_user_id_index ON user_passkeys (user_id);
+
+ALTER TABLE organization ADD COLUMN IF NOT EXISTS is_passkey_required BOOLEAN;
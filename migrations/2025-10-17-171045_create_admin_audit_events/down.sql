@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS admin_audit_events_merchant_id_id_index;
+
+DROP TABLE IF EXISTS admin_audit_events;
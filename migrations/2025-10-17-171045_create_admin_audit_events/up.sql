@@ -0,0 +1,21 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS admin_audit_events (
+    id VARCHAR(64) PRIMARY KEY,
+    org_id VARCHAR(32),
+    merchant_id VARCHAR(64),
+    profile_id VARCHAR(64),
+    actor_id VARCHAR(255),
+    auth_type VARCHAR(64) NOT NULL,
+    ip_address VARCHAR(64),
+    api_flow VARCHAR(64) NOT NULL,
+    http_method VARCHAR(16) NOT NULL,
+    url_path VARCHAR(2048) NOT NULL,
+    request_id VARCHAR(64),
+    status_code BIGINT NOT NULL,
+    request_body JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS admin_audit_events_merchant_id_id_index ON admin_audit_events (merchant_id, id);
+
+CREATE INDEX IF NOT EXISTS admin_audit_events_org_id_id_index ON admin_audit_events (org_id, id);
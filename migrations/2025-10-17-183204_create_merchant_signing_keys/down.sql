@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS merchant_signing_keys_merchant_id_index;
+
+DROP TABLE IF EXISTS merchant_signing_keys;
@@ -0,0 +1,9 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS merchant_signing_keys (
+    key_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    signing_secret BYTEA NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS merchant_signing_keys_merchant_id_index ON merchant_signing_keys (merchant_id);
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TYPE "IntentStatus" ADD VALUE IF NOT EXISTS 'incremental_authorization_pending';
+
+ALTER TYPE "AttemptStatus" ADD VALUE IF NOT EXISTS 'incremental_authorization_pending';
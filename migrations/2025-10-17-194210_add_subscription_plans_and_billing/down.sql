@@ -0,0 +1,12 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE subscription
+    DROP COLUMN IF EXISTS plan_id,
+    DROP COLUMN IF EXISTS mandate_id,
+    DROP COLUMN IF EXISTS trial_end,
+    DROP COLUMN IF EXISTS current_period_start,
+    DROP COLUMN IF EXISTS current_period_end,
+    DROP COLUMN IF EXISTS last_payment_id;
+
+DROP INDEX IF EXISTS subscription_plan_merchant_id_index;
+
+DROP TABLE IF EXISTS subscription_plan;
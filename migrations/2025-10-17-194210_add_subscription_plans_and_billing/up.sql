@@ -0,0 +1,38 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS subscription_plan (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    billing_interval VARCHAR(32) NOT NULL,
+    billing_interval_count INTEGER NOT NULL DEFAULT 1,
+    trial_period_days INTEGER,
+    max_payment_retries INTEGER NOT NULL DEFAULT 3,
+    payment_retry_interval_hours INTEGER NOT NULL DEFAULT 24,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS subscription_plan_merchant_id_index ON subscription_plan (merchant_id);
+
+ALTER TABLE subscription
+    ADD COLUMN IF NOT EXISTS plan_id VARCHAR(64),
+    ADD COLUMN IF NOT EXISTS mandate_id VARCHAR(64),
+    ADD COLUMN IF NOT EXISTS trial_end TIMESTAMP,
+    ADD COLUMN IF NOT EXISTS current_period_start TIMESTAMP,
+    ADD COLUMN IF NOT EXISTS current_period_end TIMESTAMP,
+    ADD COLUMN IF NOT EXISTS last_payment_id VARCHAR(64);
+
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'subscriptions';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'subscription_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_payment_succeeded';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_payment_processing';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_payment_failed';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_inactive';
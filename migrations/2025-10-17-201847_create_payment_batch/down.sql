@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_batch_merchant_id_index;
+
+DROP TABLE IF EXISTS payment_batch;
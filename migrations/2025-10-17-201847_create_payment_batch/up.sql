@@ -0,0 +1,13 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payment_batch (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64),
+    status VARCHAR(32) NOT NULL,
+    total_count INTEGER NOT NULL,
+    items JSONB NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS payment_batch_merchant_id_index ON payment_batch (merchant_id);
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE payment_intent
+ADD COLUMN IF NOT EXISTS purchase_order_number VARCHAR(255) DEFAULT NULL;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TYPE "RefundStatus" ADD VALUE IF NOT EXISTS 'scheduled';
+
+ALTER TYPE "RefundStatus" ADD VALUE IF NOT EXISTS 'cancelled';
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE refund
+DROP COLUMN IF EXISTS created_by,
+DROP COLUMN IF EXISTS reviewed_by;
+
+ALTER TABLE business_profile DROP COLUMN IF EXISTS refund_approval_threshold;
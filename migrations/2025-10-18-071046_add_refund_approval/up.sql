@@ -0,0 +1,9 @@
+-- Your SQL goes here
+ALTER TYPE "RefundStatus" ADD VALUE IF NOT EXISTS 'pending_approval';
+
+ALTER TABLE business_profile
+ADD COLUMN IF NOT EXISTS refund_approval_threshold BIGINT DEFAULT NULL;
+
+ALTER TABLE refund
+ADD COLUMN IF NOT EXISTS created_by VARCHAR(255) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS reviewed_by VARCHAR(255) DEFAULT NULL;
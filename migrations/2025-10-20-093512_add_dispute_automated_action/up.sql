@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE dispute ADD COLUMN IF NOT EXISTS automated_action JSONB;
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE dispute ADD COLUMN IF NOT EXISTS evidence_submitted_at TIMESTAMP;
@@ -0,0 +1,15 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payout_beneficiary (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64),
+    payout_type "PayoutType" NOT NULL,
+    payout_method_data BYTEA NOT NULL,
+    additional_payout_method_data JSONB,
+    kyc_status VARCHAR(32) NOT NULL,
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS payout_beneficiary_merchant_id_customer_id_index ON payout_beneficiary (merchant_id, customer_id);
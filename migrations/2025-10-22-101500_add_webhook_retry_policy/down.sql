@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE business_profile DROP COLUMN IF EXISTS webhook_retry_policy;
+
+ALTER TABLE events DROP COLUMN IF EXISTS dead_lettered_at;
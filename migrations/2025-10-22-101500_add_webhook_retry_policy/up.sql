@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TABLE business_profile
+ADD COLUMN IF NOT EXISTS webhook_retry_policy JSONB DEFAULT NULL;
+
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS dead_lettered_at TIMESTAMP DEFAULT NULL;
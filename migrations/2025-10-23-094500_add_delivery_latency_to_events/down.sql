@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE events DROP COLUMN IF EXISTS delivery_latency_in_ms;
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS delivery_latency_in_ms INTEGER DEFAULT NULL;
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS connector_audit_logs_merchant_id_payment_id_index;
+
+DROP TABLE IF EXISTS connector_audit_logs;
+
+ALTER TABLE business_profile DROP COLUMN IF EXISTS is_connector_audit_capture_enabled;
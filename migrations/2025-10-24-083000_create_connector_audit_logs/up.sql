@@ -0,0 +1,26 @@
+-- Your SQL goes here
+ALTER TABLE business_profile
+ADD COLUMN IF NOT EXISTS is_connector_audit_capture_enabled BOOLEAN DEFAULT NULL;
+
+CREATE TABLE IF NOT EXISTS connector_audit_logs (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    attempt_id VARCHAR(64) NOT NULL,
+    refund_id VARCHAR(64),
+    dispute_id VARCHAR(64),
+    connector VARCHAR(64) NOT NULL,
+    flow VARCHAR(64) NOT NULL,
+    http_method VARCHAR(16) NOT NULL,
+    url VARCHAR(2048) NOT NULL,
+    masked_request JSONB,
+    masked_response JSONB,
+    error JSONB,
+    status_code INTEGER NOT NULL,
+    latency_in_ms BIGINT NOT NULL,
+    request_id VARCHAR(64),
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS connector_audit_logs_merchant_id_payment_id_index ON connector_audit_logs (merchant_id, payment_id);
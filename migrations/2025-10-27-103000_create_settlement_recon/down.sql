@@ -0,0 +1,10 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS recon_record_merchant_id_recon_status_index;
+
+DROP INDEX IF EXISTS recon_record_merchant_id_payment_id_index;
+
+DROP TABLE IF EXISTS recon_record;
+
+DROP INDEX IF EXISTS settlement_report_merchant_id_index;
+
+DROP TABLE IF EXISTS settlement_report;
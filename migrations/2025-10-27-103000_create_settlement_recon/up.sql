@@ -0,0 +1,45 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS settlement_report (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    connector VARCHAR(64) NOT NULL,
+    merchant_connector_id VARCHAR(64),
+    source VARCHAR(32) NOT NULL,
+    file_name VARCHAR(255),
+    status VARCHAR(32) NOT NULL,
+    transactions_from TIMESTAMP,
+    transactions_to TIMESTAMP,
+    total_count INTEGER NOT NULL DEFAULT 0,
+    matched_count INTEGER NOT NULL DEFAULT 0,
+    mismatched_count INTEGER NOT NULL DEFAULT 0,
+    missing_count INTEGER NOT NULL DEFAULT 0,
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS settlement_report_merchant_id_index ON settlement_report (merchant_id);
+
+CREATE TABLE IF NOT EXISTS recon_record (
+    id VARCHAR(64) PRIMARY KEY,
+    report_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    connector VARCHAR(64) NOT NULL,
+    transaction_type VARCHAR(32) NOT NULL,
+    connector_transaction_id VARCHAR(255),
+    payment_id VARCHAR(64),
+    attempt_id VARCHAR(64),
+    refund_id VARCHAR(64),
+    recon_status VARCHAR(32) NOT NULL,
+    settled_amount BIGINT,
+    settled_currency "Currency",
+    expected_amount BIGINT,
+    expected_currency "Currency",
+    fee BIGINT,
+    settled_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS recon_record_merchant_id_payment_id_index ON recon_record (merchant_id, payment_id);
+
+CREATE INDEX IF NOT EXISTS recon_record_merchant_id_recon_status_index ON recon_record (merchant_id, recon_status, created_at);
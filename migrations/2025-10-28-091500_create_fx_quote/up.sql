@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS fx_quote (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    amount BIGINT NOT NULL,
+    from_currency "Currency" NOT NULL,
+    converted_amount BIGINT NOT NULL,
+    to_currency "Currency" NOT NULL,
+    rate VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64),
+    expires_at TIMESTAMP NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
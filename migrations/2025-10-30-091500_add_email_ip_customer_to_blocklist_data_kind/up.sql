@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'email';
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'ip_address';
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'customer_id';
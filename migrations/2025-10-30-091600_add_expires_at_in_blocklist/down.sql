@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE blocklist DROP COLUMN IF EXISTS expires_at;
+
+ALTER TABLE blocklist ALTER COLUMN fingerprint_id TYPE VARCHAR(64);
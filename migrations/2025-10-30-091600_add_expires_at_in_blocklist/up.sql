@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE blocklist ADD COLUMN IF NOT EXISTS expires_at TIMESTAMP;
+
+-- Emails are stored as the fingerprint of their blocklist entries
+ALTER TABLE blocklist ALTER COLUMN fingerprint_id TYPE VARCHAR(255);
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TYPE "IntentStatus" ADD VALUE IF NOT EXISTS 'requires_merchant_review';
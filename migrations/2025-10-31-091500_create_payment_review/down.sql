@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_review_merchant_id_payment_id_index;
+
+DROP INDEX IF EXISTS payment_review_merchant_id_status_index;
+
+DROP TABLE IF EXISTS payment_review;
@@ -0,0 +1,22 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payment_review (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    attempt_id VARCHAR(64) NOT NULL,
+    source VARCHAR(32) NOT NULL,
+    reason VARCHAR(255),
+    status VARCHAR(32) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency",
+    decided_by VARCHAR(255),
+    decision_reason VARCHAR(255),
+    decided_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS payment_review_merchant_id_status_index ON payment_review (merchant_id, status, created_at);
+
+CREATE INDEX IF NOT EXISTS payment_review_merchant_id_payment_id_index ON payment_review (merchant_id, payment_id);
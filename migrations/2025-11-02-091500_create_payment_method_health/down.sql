@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_method_health_merchant_id_card_expires_at_index;
+
+DROP TABLE IF EXISTS payment_method_health;
@@ -0,0 +1,18 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payment_method_health (
+    merchant_id VARCHAR(64) NOT NULL,
+    payment_method_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    last_successful_use_at TIMESTAMP,
+    last_failed_use_at TIMESTAMP,
+    successful_uses INTEGER NOT NULL DEFAULT 0,
+    failed_uses INTEGER NOT NULL DEFAULT 0,
+    consecutive_failures INTEGER NOT NULL DEFAULT 0,
+    card_expires_at TIMESTAMP,
+    account_updated_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (merchant_id, payment_method_id)
+);
+
+CREATE INDEX IF NOT EXISTS payment_method_health_merchant_id_card_expires_at_index ON payment_method_health (merchant_id, card_expires_at);
@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE customers DROP COLUMN IF EXISTS merged_into_customer_id;
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE customers ADD COLUMN IF NOT EXISTS merged_into_customer_id VARCHAR(64);
@@ -0,0 +1,10 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS customers_merchant_id_modified_at_index;
+
+DROP INDEX IF EXISTS connector_audit_logs_merchant_id_created_at_index;
+
+DROP INDEX IF EXISTS data_retention_purge_run_merchant_id_created_at_index;
+
+DROP TABLE IF EXISTS data_retention_purge_run;
+
+DROP TABLE IF EXISTS data_retention_policy;
@@ -0,0 +1,27 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS data_retention_policy (
+    merchant_id VARCHAR(64) PRIMARY KEY,
+    connector_payload_retention_days INTEGER,
+    inactive_customer_retention_days INTEGER,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE TABLE IF NOT EXISTS data_retention_purge_run (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    is_dry_run BOOLEAN NOT NULL,
+    triggered_by VARCHAR(255) NOT NULL,
+    connector_payloads_created_before TIMESTAMP,
+    connector_payloads_purged BIGINT NOT NULL DEFAULT 0,
+    customers_inactive_since TIMESTAMP,
+    redacted_customer_ids JSONB NOT NULL DEFAULT '[]'::JSONB,
+    failed_customer_ids JSONB NOT NULL DEFAULT '[]'::JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS data_retention_purge_run_merchant_id_created_at_index ON data_retention_purge_run (merchant_id, created_at);
+
+CREATE INDEX IF NOT EXISTS connector_audit_logs_merchant_id_created_at_index ON connector_audit_logs (merchant_id, created_at);
+
+CREATE INDEX IF NOT EXISTS customers_merchant_id_modified_at_index ON customers (merchant_id, modified_at);
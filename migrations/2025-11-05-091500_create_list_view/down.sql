@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS list_view_merchant_id_created_by_name_index;
+
+DROP TABLE IF EXISTS list_view;
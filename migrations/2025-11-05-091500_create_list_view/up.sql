@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS list_view (
+    view_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    created_by VARCHAR(64) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    entity VARCHAR(32) NOT NULL,
+    filters JSONB NOT NULL,
+    is_shared BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS list_view_merchant_id_created_by_name_index ON list_view (merchant_id, created_by, name);
@@ -0,0 +1,10 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS report_run_merchant_id_template_id_created_at_index;
+
+DROP INDEX IF EXISTS report_run_merchant_id_created_at_index;
+
+DROP TABLE IF EXISTS report_run;
+
+DROP INDEX IF EXISTS report_template_merchant_id_created_at_index;
+
+DROP TABLE IF EXISTS report_template;
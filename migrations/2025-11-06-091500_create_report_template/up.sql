@@ -0,0 +1,39 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS report_template (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    created_by VARCHAR(64) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    domain VARCHAR(32) NOT NULL,
+    metrics JSONB NOT NULL,
+    dimensions JSONB NOT NULL DEFAULT '[]'::JSONB,
+    filters JSONB NOT NULL DEFAULT '{}'::JSONB,
+    format VARCHAR(16) NOT NULL,
+    frequency VARCHAR(16) NOT NULL,
+    delivery_method VARCHAR(32) NOT NULL,
+    recipients JSONB NOT NULL DEFAULT '[]'::JSONB,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS report_template_merchant_id_created_at_index ON report_template (merchant_id, created_at);
+
+CREATE TABLE IF NOT EXISTS report_run (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    template_id VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    triggered_by VARCHAR(255) NOT NULL,
+    start_time TIMESTAMP NOT NULL,
+    end_time TIMESTAMP NOT NULL,
+    format VARCHAR(16) NOT NULL,
+    row_count BIGINT,
+    file_key VARCHAR(255),
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS report_run_merchant_id_created_at_index ON report_run (merchant_id, created_at);
+
+CREATE INDEX IF NOT EXISTS report_run_merchant_id_template_id_created_at_index ON report_run (merchant_id, template_id, created_at);
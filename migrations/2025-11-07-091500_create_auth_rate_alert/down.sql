@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS auth_rate_alert_merchant_id_triggered_at_index;
+
+DROP INDEX IF EXISTS auth_rate_alert_merchant_id_status_index;
+
+DROP TABLE IF EXISTS auth_rate_alert;
+
+DROP TABLE IF EXISTS auth_rate_alert_config;
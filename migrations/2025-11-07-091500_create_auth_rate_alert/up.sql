@@ -0,0 +1,31 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS auth_rate_alert_config (
+    merchant_id VARCHAR(64) PRIMARY KEY,
+    drop_threshold_percentage DOUBLE PRECISION NOT NULL,
+    baseline_window_in_days INTEGER NOT NULL,
+    evaluation_window_in_minutes INTEGER NOT NULL,
+    min_attempts BIGINT NOT NULL,
+    webhook_url VARCHAR(2048),
+    recipients JSONB NOT NULL DEFAULT '[]'::JSONB,
+    is_enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE TABLE IF NOT EXISTS auth_rate_alert (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    connector VARCHAR(64) NOT NULL,
+    payment_method VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    baseline_rate DOUBLE PRECISION NOT NULL,
+    current_rate DOUBLE PRECISION NOT NULL,
+    attempt_count BIGINT NOT NULL,
+    triggered_at TIMESTAMP NOT NULL DEFAULT now(),
+    resolved_at TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS auth_rate_alert_merchant_id_status_index ON auth_rate_alert (merchant_id, status);
+
+CREATE INDEX IF NOT EXISTS auth_rate_alert_merchant_id_triggered_at_index ON auth_rate_alert (merchant_id, triggered_at);
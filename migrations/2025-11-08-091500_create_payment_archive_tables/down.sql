@@ -0,0 +1,16 @@
+-- This file should undo anything in `up.sql`
+DROP FUNCTION IF EXISTS archive_rows_created_before(TEXT, TEXT, TIMESTAMP, INTEGER);
+
+DROP FUNCTION IF EXISTS create_archive_partition(TEXT, TIMESTAMP);
+
+DROP INDEX IF EXISTS refund_created_at_index;
+
+DROP INDEX IF EXISTS payment_attempt_created_at_index;
+
+DROP INDEX IF EXISTS payment_intent_created_at_index;
+
+DROP TABLE IF EXISTS refund_archive;
+
+DROP TABLE IF EXISTS payment_attempt_archive;
+
+DROP TABLE IF EXISTS payment_intent_archive;
@@ -0,0 +1,113 @@
+-- Your SQL goes here
+-- Cold storage tables which the payments are moved to once they are past the archival age. The
+-- tables are partitioned by month, so that partitions which are no longer queried can be detached
+-- and exported. Partitions are created by the archival job as rows are moved to them.
+CREATE TABLE IF NOT EXISTS payment_intent_archive (LIKE payment_intent) PARTITION BY RANGE (created_at);
+
+CREATE TABLE IF NOT EXISTS payment_attempt_archive (LIKE payment_attempt) PARTITION BY RANGE (created_at);
+
+CREATE TABLE IF NOT EXISTS refund_archive (LIKE refund) PARTITION BY RANGE (created_at);
+
+CREATE INDEX IF NOT EXISTS payment_intent_archive_payment_id_merchant_id_index ON payment_intent_archive (payment_id, merchant_id);
+
+CREATE INDEX IF NOT EXISTS payment_attempt_archive_payment_id_merchant_id_index ON payment_attempt_archive (payment_id, merchant_id);
+
+CREATE INDEX IF NOT EXISTS payment_attempt_archive_attempt_id_merchant_id_index ON payment_attempt_archive (attempt_id, merchant_id);
+
+CREATE INDEX IF NOT EXISTS refund_archive_refund_id_merchant_id_index ON refund_archive (refund_id, merchant_id);
+
+CREATE INDEX IF NOT EXISTS refund_archive_payment_id_merchant_id_index ON refund_archive (payment_id, merchant_id);
+
+-- The archival job looks up the oldest rows of the hot tables. On large tables, these indexes
+-- should be created `CONCURRENTLY` before running this migration, to avoid blocking writes.
+CREATE INDEX IF NOT EXISTS payment_intent_created_at_index ON payment_intent (created_at);
+
+CREATE INDEX IF NOT EXISTS payment_attempt_created_at_index ON payment_attempt (created_at);
+
+CREATE INDEX IF NOT EXISTS refund_created_at_index ON refund (created_at);
+
+-- Creates the monthly partition of the archive table which starts at `partition_start`
+CREATE OR REPLACE FUNCTION create_archive_partition(
+    archive_table TEXT,
+    partition_start TIMESTAMP
+) RETURNS VOID AS $$
+BEGIN
+    EXECUTE format(
+        'CREATE TABLE IF NOT EXISTS %I PARTITION OF %I FOR VALUES FROM (%L) TO (%L)',
+        archive_table || '_' || to_char(partition_start, 'YYYY_MM'),
+        archive_table,
+        partition_start,
+        partition_start + INTERVAL '1 month'
+    );
+END;
+$$ LANGUAGE plpgsql;
+
+-- Moves up to `batch_size` of the oldest rows created before `created_before` from the source
+-- table to its archive table, and returns the number of rows moved. The rows of a batch are from
+-- a single month, so that they are moved to a single partition.
+CREATE OR REPLACE FUNCTION archive_rows_created_before(
+    source_table TEXT,
+    archive_table TEXT,
+    created_before TIMESTAMP,
+    batch_size INTEGER
+) RETURNS BIGINT AS $$
+DECLARE
+    missing_columns TEXT;
+    archived_columns TEXT;
+    oldest_created_at TIMESTAMP;
+    partition_start TIMESTAMP;
+    archived_rows BIGINT;
+BEGIN
+    -- Rows are not moved while the archive table is missing a column of the source table, such as
+    -- one added to the source table later on, so that no data is lost
+    SELECT string_agg(source_column.column_name, ', ')
+    INTO missing_columns
+    FROM information_schema.columns source_column
+    WHERE source_column.table_schema = current_schema()
+        AND source_column.table_name = source_table
+        AND NOT EXISTS (
+            SELECT 1
+            FROM information_schema.columns archive_column
+            WHERE archive_column.table_schema = current_schema()
+                AND archive_column.table_name = archive_table
+                AND archive_column.column_name = source_column.column_name
+        );
+
+    IF missing_columns IS NOT NULL THEN
+        RAISE EXCEPTION 'Columns % of % are missing in %', missing_columns, source_table, archive_table;
+    END IF;
+
+    SELECT string_agg(quote_ident(column_name), ', ' ORDER BY ordinal_position)
+    INTO archived_columns
+    FROM information_schema.columns
+    WHERE table_schema = current_schema()
+        AND table_name = source_table;
+
+    EXECUTE format('SELECT min(created_at) FROM %I WHERE created_at < $1', source_table)
+    INTO oldest_created_at
+    USING created_before;
+
+    IF oldest_created_at IS NULL THEN
+        RETURN 0;
+    END IF;
+
+    partition_start := date_trunc('month', oldest_created_at);
+    PERFORM create_archive_partition(archive_table, partition_start);
+
+    EXECUTE format(
+        'WITH archived AS (
+            DELETE FROM %1$I
+            WHERE ctid IN (SELECT ctid FROM %1$I WHERE created_at < $1 LIMIT $2)
+            RETURNING %3$s
+        )
+        INSERT INTO %2$I (%3$s) SELECT %3$s FROM archived',
+        source_table,
+        archive_table,
+        archived_columns
+    )
+    USING LEAST(partition_start + INTERVAL '1 month', created_before), batch_size;
+
+    GET DIAGNOSTICS archived_rows = ROW_COUNT;
+    RETURN archived_rows;
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE business_profile
+ADD COLUMN IF NOT EXISTS webhook_endpoint_secrets BYTEA DEFAULT NULL;
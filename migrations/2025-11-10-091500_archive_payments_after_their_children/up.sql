@@ -0,0 +1,93 @@
+-- Your SQL goes here
+CREATE OR REPLACE FUNCTION archive_rows_created_before(
+    source_table TEXT,
+    archive_table TEXT,
+    created_before TIMESTAMP,
+    batch_size INTEGER
+) RETURNS BIGINT AS $$
+DECLARE
+    missing_columns TEXT;
+    archived_columns TEXT;
+    oldest_created_at TIMESTAMP;
+    partition_start TIMESTAMP;
+    archived_rows BIGINT;
+    hot_children_filter TEXT;
+BEGIN
+    -- Rows are not moved while the archive table is missing a column of the source table, such as
+    -- one added to the source table later on, so that no data is lost
+    SELECT string_agg(source_column.column_name, ', ')
+    INTO missing_columns
+    FROM information_schema.columns source_column
+    WHERE source_column.table_schema = current_schema()
+        AND source_column.table_name = source_table
+        AND NOT EXISTS (
+            SELECT 1
+            FROM information_schema.columns archive_column
+            WHERE archive_column.table_schema = current_schema()
+                AND archive_column.table_name = archive_table
+                AND archive_column.column_name = source_column.column_name
+        );
+
+    IF missing_columns IS NOT NULL THEN
+        RAISE EXCEPTION 'Columns % of % are missing in %', missing_columns, source_table, archive_table;
+    END IF;
+
+    SELECT string_agg(quote_ident(column_name), ', ' ORDER BY ordinal_position)
+    INTO archived_columns
+    FROM information_schema.columns
+    WHERE table_schema = current_schema()
+        AND table_name = source_table;
+
+    -- Rows are not moved while a row of the same payment is still in a hot table, so that a payment
+    -- is never archived before its attempts and refunds, which may have been created later on
+    hot_children_filter := CASE source_table
+        WHEN 'payment_intent' THEN
+            'NOT EXISTS (
+                SELECT 1 FROM payment_attempt child
+                WHERE child.merchant_id = s.merchant_id AND child.payment_id = s.payment_id
+            )
+            AND NOT EXISTS (
+                SELECT 1 FROM refund child
+                WHERE child.merchant_id = s.merchant_id AND child.payment_id = s.payment_id
+            )'
+        WHEN 'payment_attempt' THEN
+            'NOT EXISTS (
+                SELECT 1 FROM refund child
+                WHERE child.merchant_id = s.merchant_id AND child.payment_id = s.payment_id
+            )'
+        ELSE 'TRUE'
+    END;
+
+    EXECUTE format(
+        'SELECT min(s.created_at) FROM %I s WHERE s.created_at < $1 AND %s',
+        source_table,
+        hot_children_filter
+    )
+    INTO oldest_created_at
+    USING created_before;
+
+    IF oldest_created_at IS NULL THEN
+        RETURN 0;
+    END IF;
+
+    partition_start := date_trunc('month', oldest_created_at);
+    PERFORM create_archive_partition(archive_table, partition_start);
+
+    EXECUTE format(
+        'WITH archived AS (
+            DELETE FROM %1$I
+            WHERE ctid IN (SELECT s.ctid FROM %1$I s WHERE s.created_at < $1 AND %4$s LIMIT $2)
+            RETURNING %3$s
+        )
+        INSERT INTO %2$I (%3$s) SELECT %3$s FROM archived',
+        source_table,
+        archive_table,
+        archived_columns,
+        hot_children_filter
+    )
+    USING LEAST(partition_start + INTERVAL '1 month', created_before), batch_size;
+
+    GET DIAGNOSTICS archived_rows = ROW_COUNT;
+    RETURN archived_rows;
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TYPE "RoutingAlgorithmKind" ADD VALUE IF NOT EXISTS 'cost';
@@ -0,0 +1,10 @@
+-- Your SQL goes here
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'routing_dynamic_updated';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'routing_volume_split_updated';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'routing_experiment_updated';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'routing_latency_config_updated';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'routing_fee_schedule_updated';